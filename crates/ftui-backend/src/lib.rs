@@ -65,6 +65,35 @@ pub trait BackendEventSource {
     ///
     /// Call after `poll_event` returns `true`, or speculatively.
     fn read_event(&mut self) -> Result<Option<Event>, Self::Error>;
+
+    /// Cooperatively suspend the process (Ctrl+Z support).
+    ///
+    /// Implementations tear down terminal modes, stop the process (SIGTSTP
+    /// on Unix), and re-apply every mode when execution resumes (SIGCONT).
+    /// Returns `Ok(())` after resume. The default is a no-op reporting
+    /// [`SuspendError::Unsupported`], which is also what non-Unix targets
+    /// return.
+    fn suspend(&mut self) -> Result<(), SuspendError<Self::Error>> {
+        Err(SuspendError::Unsupported)
+    }
+}
+
+/// Error from [`BackendEventSource::suspend`].
+#[derive(Debug)]
+pub enum SuspendError<E> {
+    /// This backend/platform has no suspend support.
+    Unsupported,
+    /// The backend failed while tearing down or re-applying modes.
+    Backend(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for SuspendError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("suspend is not supported by this backend"),
+            Self::Backend(err) => write!(f, "suspend failed: {err}"),
+        }
+    }
 }
 
 /// Presentation abstraction: UI rendering and log output.
@@ -80,6 +80,7 @@ pub struct CapabilityOverride {
     pub sync_output: Option<bool>,
     pub osc8_hyperlinks: Option<bool>,
     pub scroll_region: Option<bool>,
+    pub back_color_erase: Option<bool>,
 
     // Multiplexer flags
     pub in_tmux: Option<bool>,
@@ -109,6 +110,7 @@ impl CapabilityOverride {
             sync_output: None,
             osc8_hyperlinks: None,
             scroll_region: None,
+            back_color_erase: None,
             in_tmux: None,
             in_screen: None,
             in_zellij: None,
@@ -132,6 +134,7 @@ impl CapabilityOverride {
             sync_output: Some(false),
             osc8_hyperlinks: Some(false),
             scroll_region: Some(false),
+            back_color_erase: Some(false),
             in_tmux: Some(false),
             in_screen: Some(false),
             in_zellij: Some(false),
@@ -155,6 +158,7 @@ impl CapabilityOverride {
             sync_output: Some(true),
             osc8_hyperlinks: Some(true),
             scroll_region: Some(true),
+            back_color_erase: Some(true),
             in_tmux: Some(false),
             in_screen: Some(false),
             in_zellij: Some(false),
@@ -178,6 +182,7 @@ impl CapabilityOverride {
             sync_output: Some(false),
             osc8_hyperlinks: Some(false),
             scroll_region: Some(true),
+            back_color_erase: Some(true),
             in_tmux: Some(true),
             in_screen: Some(false),
             in_zellij: Some(false),
@@ -247,6 +252,13 @@ impl CapabilityOverride {
         self
     }
 
+    /// Set the back-color-erase override.
+    #[must_use]
+    pub const fn back_color_erase(mut self, value: Option<bool>) -> Self {
+        self.back_color_erase = value;
+        self
+    }
+
     /// Override tmux detection.
     #[must_use]
     pub const fn in_tmux(mut self, value: Option<bool>) -> Self {
@@ -314,6 +326,7 @@ impl CapabilityOverride {
             && self.sync_output.is_none()
             && self.osc8_hyperlinks.is_none()
             && self.scroll_region.is_none()
+            && self.back_color_erase.is_none()
             && self.in_tmux.is_none()
             && self.in_screen.is_none()
             && self.in_zellij.is_none()
@@ -351,6 +364,9 @@ impl CapabilityOverride {
         if let Some(v) = self.scroll_region {
             caps.scroll_region = v;
         }
+        if let Some(v) = self.back_color_erase {
+            caps.back_color_erase = v;
+        }
         if let Some(v) = self.in_tmux {
             caps.in_tmux = v;
         }
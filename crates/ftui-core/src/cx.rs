@@ -358,6 +358,151 @@ impl Cx {
     }
 }
 
+// ─── WorkBudget ──────────────────────────────────────────────────────────────
+
+/// Target time between clock reads in [`WorkBudget::should_yield`] (µs).
+///
+/// The adaptive check interval aims for one clock read per this much
+/// observed work, so the per-iteration overhead stays a fraction of the
+/// work itself regardless of step cost.
+const BUDGET_CHECK_TARGET_US: u64 = 250;
+/// Upper bound on the adaptive check interval.
+const BUDGET_MAX_CHECK_INTERVAL: u32 = 4096;
+
+/// Per-frame work budget guard for incremental computation.
+///
+/// Created via [`Cx::budget`]. Call [`should_yield`](Self::should_yield)
+/// once per unit of work; it returns `true` as soon as the budget
+/// elapses or the underlying `Cx` is cancelled/expired. Clock reads
+/// happen only every N iterations, with N adapted to the observed
+/// iteration cost, so the guard stays cheap even for sub-microsecond
+/// steps. Uses the context's time source, so Lab-clock tests can cut a
+/// budget deterministically mid-work.
+///
+/// ```
+/// use ftui_core::cx::Cx;
+/// use web_time::Duration;
+///
+/// let (cx, _ctrl) = Cx::background();
+/// let mut budget = cx.budget(Duration::from_millis(2));
+/// let mut done = 0_u32;
+/// while done < 10_000 && !budget.should_yield() {
+///     done += 1; // one unit of work
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WorkBudget {
+    cx: Cx,
+    started: Instant,
+    budget: Duration,
+    /// Iterations between clock reads (adaptive).
+    check_interval: u32,
+    /// Iterations since the last clock read.
+    since_check: u32,
+    /// Time of the last clock read, for per-batch cost estimation.
+    last_check: Instant,
+    /// Sticky once the budget/deadline/cancellation is observed.
+    exhausted: bool,
+}
+
+impl Cx {
+    /// Create a [`WorkBudget`] that yields after `budget` of this
+    /// context's time, or earlier if the context is cancelled or its
+    /// deadline expires.
+    #[must_use]
+    pub fn budget(&self, budget: Duration) -> WorkBudget {
+        let now = self.now();
+        WorkBudget {
+            cx: self.clone(),
+            started: now,
+            budget,
+            check_interval: 1,
+            since_check: 0,
+            last_check: now,
+            exhausted: false,
+        }
+    }
+}
+
+impl WorkBudget {
+    /// Whether the work loop should stop and resume next cycle.
+    ///
+    /// Call once per iteration. The clock (and cancellation) is
+    /// consulted only every [`check_interval`](Self::check_interval)
+    /// iterations, so exhaustion is observed with up to one batch of
+    /// latency — the price of keeping the per-iteration cost to a
+    /// counter increment.
+    pub fn should_yield(&mut self) -> bool {
+        if self.exhausted {
+            return true;
+        }
+        self.since_check += 1;
+        if self.since_check < self.check_interval {
+            return false;
+        }
+        self.since_check = 0;
+
+        let now = self.cx.now();
+        let batch_us = now
+            .checked_duration_since(self.last_check)
+            .unwrap_or(Duration::ZERO)
+            .as_micros()
+            .min(u64::MAX as u128) as u64;
+        self.last_check = now;
+        self.adapt(batch_us);
+
+        let elapsed = now
+            .checked_duration_since(self.started)
+            .unwrap_or(Duration::ZERO);
+        if elapsed >= self.budget || self.cx.is_done() {
+            self.exhausted = true;
+        }
+        self.exhausted
+    }
+
+    /// Adapt the check interval so one batch costs roughly
+    /// [`BUDGET_CHECK_TARGET_US`] of observed time.
+    fn adapt(&mut self, batch_us: u64) {
+        let desired = match BUDGET_CHECK_TARGET_US
+            .saturating_mul(u64::from(self.check_interval))
+            .checked_div(batch_us)
+        {
+            Some(d) => d,
+            // No observable progress (e.g. a lab clock that was not
+            // advanced): back off geometrically.
+            None => u64::from(self.check_interval).saturating_mul(2),
+        };
+        self.check_interval = desired.clamp(1, u64::from(BUDGET_MAX_CHECK_INTERVAL)) as u32;
+    }
+
+    /// Time spent since the guard was created (context time source).
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.cx
+            .now()
+            .checked_duration_since(self.started)
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Budget time left (saturates to zero; ignores the Cx deadline).
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.budget.saturating_sub(self.elapsed())
+    }
+
+    /// Current adaptive check interval (iterations per clock read).
+    #[must_use]
+    pub fn check_interval(&self) -> u32 {
+        self.check_interval
+    }
+
+    /// Whether exhaustion has already been observed.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
 // ─── CxController ────────────────────────────────────────────────────────────
 
 /// Control handle for a [`Cx`].
@@ -794,6 +939,100 @@ mod tests {
         assert_eq!(model.value, 0, "state should remain unchanged without cx");
     }
 
+    // ── WorkBudget ───────────────────────────────────────────────────
+
+    #[test]
+    fn budget_cuts_mid_work_under_lab_time() {
+        let clock = LabClock::new();
+        let (cx, _ctrl) = Cx::lab(&clock);
+        let mut budget = cx.budget(Duration::from_millis(1));
+
+        let mut steps = 0_u64;
+        // Each step costs 10µs of lab time.
+        while !budget.should_yield() {
+            clock.advance(Duration::from_micros(10));
+            steps += 1;
+            assert!(steps < 1_000_000, "budget never cut");
+        }
+        // 1ms budget at 10µs/step is ~100 steps; exhaustion is observed
+        // with at most one check batch of latency.
+        let slack = u64::from(budget.check_interval());
+        assert!(steps >= 100, "cut too early at {steps}");
+        assert!(
+            steps <= 100 + slack,
+            "cut too late at {steps} (slack {slack})"
+        );
+        assert!(budget.is_exhausted());
+        assert!(budget.should_yield(), "exhaustion is sticky");
+    }
+
+    #[test]
+    fn budget_yields_on_cancellation() {
+        let clock = LabClock::new();
+        let (cx, ctrl) = Cx::lab(&clock);
+        let mut budget = cx.budget(Duration::from_secs(10));
+        ctrl.cancel();
+        // Advance so the batch cost is non-zero and a check fires.
+        clock.advance(Duration::from_micros(1));
+        let mut calls = 0_u32;
+        while !budget.should_yield() {
+            calls += 1;
+            assert!(calls < 100_000, "cancellation never observed");
+        }
+        assert!(budget.is_exhausted());
+    }
+
+    #[test]
+    fn budget_yields_on_cx_deadline() {
+        let clock = LabClock::new();
+        let (cx, _ctrl) = Cx::lab_with_deadline(&clock, Duration::from_millis(5));
+        let mut budget = cx.budget(Duration::from_secs(10));
+        clock.advance(Duration::from_millis(6));
+        assert!(budget.should_yield(), "cx deadline should cut the budget");
+    }
+
+    #[test]
+    fn budget_check_interval_converges_to_iteration_cost() {
+        let clock = LabClock::new();
+        let (cx, _ctrl) = Cx::lab(&clock);
+        let mut budget = cx.budget(Duration::from_secs(10));
+
+        // Fixed 10µs/iteration: the interval should converge to
+        // BUDGET_CHECK_TARGET_US / 10 = 25 and stay there.
+        let mut observed = Vec::new();
+        for _ in 0..2_000 {
+            clock.advance(Duration::from_micros(10));
+            let _ = budget.should_yield();
+            observed.push(budget.check_interval());
+        }
+        let last = *observed.last().unwrap();
+        assert_eq!(
+            last,
+            (BUDGET_CHECK_TARGET_US / 10) as u32,
+            "interval should match target/cost"
+        );
+        // Stable over the tail, not oscillating.
+        assert!(
+            observed.iter().rev().take(500).all(|&i| i == last),
+            "interval still oscillating: {:?}",
+            &observed[observed.len() - 10..]
+        );
+    }
+
+    #[test]
+    fn budget_interval_backs_off_without_progress() {
+        let clock = LabClock::new();
+        let (cx, _ctrl) = Cx::lab(&clock);
+        let mut budget = cx.budget(Duration::from_secs(10));
+        // Clock never advances: interval should grow geometrically to
+        // the cap instead of reading the clock every iteration.
+        for _ in 0..100_000 {
+            let _ = budget.should_yield();
+        }
+        assert_eq!(budget.check_interval(), BUDGET_MAX_CHECK_INTERVAL);
+        assert!(!budget.is_exhausted());
+    }
+
     #[cfg(feature = "tracing-json")]
     #[derive(Default, Clone)]
     struct TraceCaptureLayer {
@@ -87,8 +87,18 @@ impl Event {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KeyEvent {
     /// The key code that was pressed.
+    ///
+    /// Best-effort physical/logical key identification: the escape
+    /// sequence's key for functional keys, the kitty-protocol base
+    /// layout key when reported, else the produced character.
     pub code: KeyCode,
 
+    /// The produced Unicode scalar, when the terminal reported one:
+    /// the character after any composition (dead keys) and layout
+    /// mapping. `None` for purely functional keys. Text input should
+    /// read this; shortcuts usually match on `code`.
+    pub ch: Option<char>,
+
     /// Modifier keys held during the event.
     pub modifiers: Modifiers,
 
@@ -102,11 +112,23 @@ impl KeyEvent {
     pub const fn new(code: KeyCode) -> Self {
         Self {
             code,
+            ch: match code {
+                KeyCode::Char(c) => Some(c),
+                _ => None,
+            },
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
         }
     }
 
+    /// Set the produced Unicode scalar (dual reporting: `code` stays
+    /// the best-effort key identity, `ch` the text it produced).
+    #[must_use]
+    pub const fn with_char(mut self, ch: Option<char>) -> Self {
+        self.ch = ch;
+        self
+    }
+
     /// Create a key event with modifiers.
     #[must_use]
     pub const fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
@@ -345,24 +367,90 @@ pub enum MouseButton {
 /// A paste event from bracketed paste mode.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PasteEvent {
-    /// The pasted text content.
+    /// The pasted text content, with line endings normalized to `\n`.
     pub text: String,
 
     /// True if this came from bracketed paste mode.
     ///
     /// When true, the text was received atomically and should be
     /// treated as a single paste operation rather than individual
-    /// key presses.
+    /// key presses. Kept in sync with `source` for compatibility.
     pub bracketed: bool,
+
+    /// Where the paste came from (terminal bracket, clipboard read,
+    /// or host/app synthesis).
+    pub source: PasteSource,
+
+    /// Byte length of the original (pre-normalization) payload.
+    pub bytes: usize,
+
+    /// Character count of the normalized text.
+    pub chars: usize,
+
+    /// Line-ending style of the original payload, recorded before
+    /// normalization for apps that care.
+    pub line_endings: LineEndingStyle,
+}
+
+/// Provenance of a paste event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PasteSource {
+    /// Real bracketed paste (`ESC [ 200 ~ … ESC [ 201 ~`).
+    #[default]
+    Bracketed,
+    /// Clipboard content read back via OSC 52.
+    Osc52Read,
+    /// Synthesized by the host or application (e.g. the web encoded-input
+    /// JSON protocol, tests, macros).
+    Synthetic,
+}
+
+/// Line-ending style observed in a paste payload before normalization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEndingStyle {
+    /// No line terminators present.
+    #[default]
+    None,
+    /// Unix `\n` only.
+    Lf,
+    /// Windows `\r\n` only.
+    CrLf,
+    /// Legacy `\r` only.
+    Cr,
+    /// A mixture of styles.
+    Mixed,
 }
 
 impl PasteEvent {
     /// Create a new paste event.
+    ///
+    /// `bracketed` maps to [`PasteSource::Bracketed`] / `Synthetic`; use
+    /// [`with_source`](Self::with_source) for other provenances. Line
+    /// endings are normalized to `\n` with the original style recorded.
     #[must_use]
     pub fn new(text: impl Into<String>, bracketed: bool) -> Self {
+        let source = if bracketed {
+            PasteSource::Bracketed
+        } else {
+            PasteSource::Synthetic
+        };
+        Self::with_source(text, source)
+    }
+
+    /// Create a paste event with explicit provenance.
+    #[must_use]
+    pub fn with_source(text: impl Into<String>, source: PasteSource) -> Self {
+        let raw = text.into();
+        let bytes = raw.len();
+        let line_endings = detect_line_endings(&raw);
+        let text = normalize_line_endings(raw, line_endings);
         Self {
-            text: text.into(),
-            bracketed,
+            chars: text.chars().count(),
+            bracketed: source == PasteSource::Bracketed,
+            text,
+            source,
+            bytes,
+            line_endings,
         }
     }
 
@@ -373,6 +461,30 @@ impl PasteEvent {
     }
 }
 
+/// Classify the line-ending style of a raw paste payload.
+fn detect_line_endings(text: &str) -> LineEndingStyle {
+    let crlf = text.matches("\r\n").count();
+    let total_cr = text.matches('\r').count();
+    let total_lf = text.matches('\n').count();
+    let lone_cr = total_cr - crlf;
+    let lone_lf = total_lf - crlf;
+    match (crlf > 0, lone_cr > 0, lone_lf > 0) {
+        (false, false, false) => LineEndingStyle::None,
+        (false, false, true) => LineEndingStyle::Lf,
+        (true, false, false) => LineEndingStyle::CrLf,
+        (false, true, false) => LineEndingStyle::Cr,
+        _ => LineEndingStyle::Mixed,
+    }
+}
+
+/// Normalize `\r\n` and lone `\r` to `\n`.
+fn normalize_line_endings(text: String, style: LineEndingStyle) -> String {
+    match style {
+        LineEndingStyle::None | LineEndingStyle::Lf => text,
+        _ => text.replace("\r\n", "\n").replace('\r', "\n"),
+    }
+}
+
 /// IME composition lifecycle phase.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ImePhase {
@@ -393,6 +505,9 @@ pub struct ImeEvent {
     pub phase: ImePhase,
     /// Associated text payload (preedit or committed text).
     pub text: String,
+    /// Caret position within the preedit text (grapheme offset), when
+    /// the host reports one.
+    pub cursor: Option<u32>,
 }
 
 impl ImeEvent {
@@ -402,9 +517,17 @@ impl ImeEvent {
         Self {
             phase,
             text: text.into(),
+            cursor: None,
         }
     }
 
+    /// Attach the host-reported caret position within the preedit.
+    #[must_use]
+    pub fn with_cursor(mut self, cursor: u32) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
     /// Create a composition-start event.
     #[must_use]
     pub fn start() -> Self {
@@ -459,6 +582,10 @@ pub enum ClipboardSource {
     /// Clipboard content from OSC 52 protocol.
     Osc52,
 
+    /// Host-mediated bridge (e.g. the web host completing an async
+    /// Clipboard API read the app requested).
+    HostBridge,
+
     /// Unknown or unspecified source.
     #[default]
     Unknown,
@@ -483,6 +610,12 @@ fn map_key_event(event: cte::KeyEvent) -> Option<KeyEvent> {
     let kind = map_key_kind(event.kind);
     Some(KeyEvent {
         code,
+        // Crossterm reports only the produced character; surface it on
+        // both sides of the dual report.
+        ch: match code {
+            KeyCode::Char(c) => Some(c),
+            _ => None,
+        },
         modifiers,
         kind,
     })
@@ -631,6 +764,46 @@ mod tests {
         assert_eq!(release.kind, KeyEventKind::Release);
     }
 
+    #[test]
+    fn paste_event_records_source_and_counts() {
+        let paste = PasteEvent::bracketed("hi there");
+        assert_eq!(paste.source, PasteSource::Bracketed);
+        assert!(paste.bracketed);
+        assert_eq!(paste.bytes, 8);
+        assert_eq!(paste.chars, 8);
+        assert_eq!(paste.line_endings, LineEndingStyle::None);
+
+        let synthetic = PasteEvent::new("x", false);
+        assert_eq!(synthetic.source, PasteSource::Synthetic);
+        assert!(!synthetic.bracketed);
+
+        let osc = PasteEvent::with_source("y", PasteSource::Osc52Read);
+        assert_eq!(osc.source, PasteSource::Osc52Read);
+        assert!(!osc.bracketed);
+    }
+
+    #[test]
+    fn paste_event_normalizes_line_endings() {
+        let crlf = PasteEvent::bracketed("a\r\nb\r\nc");
+        assert_eq!(crlf.text, "a\nb\nc");
+        assert_eq!(crlf.line_endings, LineEndingStyle::CrLf);
+        // Byte count reflects the original payload.
+        assert_eq!(crlf.bytes, 7);
+        assert_eq!(crlf.chars, 5);
+
+        let cr = PasteEvent::bracketed("a\rb");
+        assert_eq!(cr.text, "a\nb");
+        assert_eq!(cr.line_endings, LineEndingStyle::Cr);
+
+        let lf = PasteEvent::bracketed("a\nb");
+        assert_eq!(lf.text, "a\nb");
+        assert_eq!(lf.line_endings, LineEndingStyle::Lf);
+
+        let mixed = PasteEvent::bracketed("a\r\nb\nc");
+        assert_eq!(mixed.text, "a\nb\nc");
+        assert_eq!(mixed.line_endings, LineEndingStyle::Mixed);
+    }
+
     #[test]
     fn mouse_event_position() {
         let event = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 10, 20);
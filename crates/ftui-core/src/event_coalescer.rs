@@ -746,10 +746,7 @@ mod tests {
     #[test]
     fn paste_event_passes_through() {
         let mut coalescer = EventCoalescer::new();
-        let paste = Event::Paste(crate::event::PasteEvent {
-            text: "hello".into(),
-            bracketed: true,
-        });
+        let paste = Event::Paste(crate::event::PasteEvent::bracketed("hello"));
         let result = coalescer.push(paste.clone());
         assert_eq!(result, Some(paste));
         assert!(!coalescer.has_pending());
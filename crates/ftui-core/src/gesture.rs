@@ -470,6 +470,7 @@ mod tests {
             code,
             modifiers,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -949,6 +950,7 @@ mod tests {
                 code: KeyCode::Char('k'),
                 modifiers: Modifiers::CTRL,
                 kind: KeyEventKind::Release,
+                ch: None,
             }),
             t,
         );
@@ -1852,6 +1854,7 @@ mod tests {
                 code: KeyCode::Char('k'),
                 modifiers: Modifiers::CTRL,
                 kind: KeyEventKind::Repeat,
+                ch: None,
             }),
             t,
         );
@@ -106,6 +106,8 @@ pub struct InputParser {
     in_paste: bool,
     /// Event queued for the next iteration (allows emitting 2 events per byte).
     pending_event: Option<Event>,
+    /// Held combining accent awaiting a base char (dead-key merge).
+    pending_dead: Option<KeyEvent>,
     /// Whether to expect X10-encoded mouse events (`CSI M cb cx cy`).
     ///
     /// In practice some terminals/muxes can fall back to raw X10 packets even
@@ -146,6 +148,7 @@ impl InputParser {
             utf8_buffer: [0; 4],
             in_paste: false,
             pending_event: None,
+            pending_dead: None,
             expect_x10_mouse: false,
             allow_legacy_mouse: false,
         }
@@ -174,6 +177,7 @@ impl InputParser {
     #[must_use]
     pub const fn has_pending_timeout_state(&self) -> bool {
         matches!(self.state, ParserState::Escape | ParserState::Utf8 { .. })
+            || self.pending_dead.is_some()
     }
 
     /// Handle a timeout in the input stream.
@@ -181,6 +185,10 @@ impl InputParser {
     /// If the parser is waiting for more bytes to complete an ambiguous sequence
     /// (specifically a bare ESC), a timeout indicates the sequence has ended.
     pub fn timeout(&mut self) -> Option<Event> {
+        // A held dead-key accent with no base char: deliver it as-is.
+        if let Some(accent) = self.pending_dead.take() {
+            return Some(Event::Key(accent));
+        }
         match self.state {
             ParserState::Escape => {
                 self.state = ParserState::Ground;
@@ -216,16 +224,58 @@ impl InputParser {
 
         for &byte in input {
             if let Some(event) = self.process_byte(byte) {
-                debug!(event_type = event.event_type_label(), "normalized event");
-                emit(event);
+                for event in self.resolve_dead_key(event).into_iter().flatten() {
+                    debug!(event_type = event.event_type_label(), "normalized event");
+                    emit(event);
+                }
             }
             if let Some(pending) = self.pending_event.take() {
-                debug!(event_type = pending.event_type_label(), "normalized event");
-                emit(pending);
+                for event in self.resolve_dead_key(pending).into_iter().flatten() {
+                    debug!(event_type = event.event_type_label(), "normalized event");
+                    emit(event);
+                }
             }
         }
     }
 
+    /// Dead-key handling: a standalone combining accent is held for one
+    /// event; if the next event is a base char it composes with (´ then
+    /// e → é), a single composed event is delivered. Otherwise both
+    /// events pass through unchanged. [`timeout`](Self::timeout) flushes
+    /// a held accent when no base char follows in the ambiguity window.
+    fn resolve_dead_key(&mut self, event: Event) -> [Option<Event>; 2] {
+        if let Some(accent) = self.pending_dead.take() {
+            let KeyCode::Char(mark) = accent.code else {
+                return [Some(Event::Key(accent)), Some(event)];
+            };
+            if let Event::Key(key) = event
+                && key.kind == KeyEventKind::Press
+                && !key.modifiers.intersects(Modifiers::CTRL | Modifiers::ALT)
+                && let Some(base) = key.ch
+                && let Some(composed) = compose_dead_key(base, mark)
+            {
+                return [
+                    Some(Event::Key(
+                        KeyEvent::new(KeyCode::Char(composed)).with_modifiers(key.modifiers),
+                    )),
+                    None,
+                ];
+            }
+            return [Some(Event::Key(accent)), Some(event)];
+        }
+
+        if let Event::Key(key) = event
+            && key.kind == KeyEventKind::Press
+            && key.modifiers.is_empty()
+            && let Some(ch) = key.ch
+            && is_combining_accent(ch)
+        {
+            self.pending_dead = Some(key);
+            return [None, None];
+        }
+        [Some(event), None]
+    }
+
     /// Parse input bytes and append completed events to `events`.
     ///
     /// This variant lets callers reuse a scratch buffer across parses to avoid
@@ -633,15 +683,43 @@ impl InputParser {
 
         let mut parts = s.split(';');
         let key_part = parts.next().unwrap_or("");
-        let key_code_str = key_part.split(':').next().unwrap_or("");
+        let mut key_fields = key_part.split(':');
+        let key_code_str = key_fields.next().unwrap_or("");
         let key_code: u32 = key_code_str.parse().ok()?;
+        // Alternate keys: `unicode:shifted:base-layout`. The base-layout
+        // key is the layout-independent identity (AZERTY physical Q
+        // reporting produced 'a' carries base-layout 'q').
+        let _shifted = key_fields.next();
+        let base_layout: Option<char> = key_fields
+            .next()
+            .and_then(|v| v.parse::<u32>().ok())
+            .and_then(char::from_u32);
 
         let mod_part = parts.next().unwrap_or("");
         let (modifiers, kind) = Self::kitty_modifiers_and_kind(mod_part);
 
-        let code = Self::kitty_keycode_to_keycode(key_code)?;
+        // Text-as-codepoints: the produced scalar after layout/compose.
+        let text_char: Option<char> = parts
+            .next()
+            .and_then(|text| text.split(':').next())
+            .and_then(|v| v.parse::<u32>().ok())
+            .and_then(char::from_u32);
+
+        let produced = Self::kitty_keycode_to_keycode(key_code)?;
+        // Dual report: code prefers the base-layout identity, ch the
+        // produced character.
+        let (code, ch) = match (base_layout, produced) {
+            (Some(base), KeyCode::Char(primary)) => {
+                (KeyCode::Char(base), Some(text_char.unwrap_or(primary)))
+            }
+            (_, KeyCode::Char(primary)) => {
+                (KeyCode::Char(primary), Some(text_char.unwrap_or(primary)))
+            }
+            (_, functional) => (functional, text_char),
+        };
         Some(Event::Key(
             KeyEvent::new(code)
+                .with_char(ch)
                 .with_modifiers(modifiers)
                 .with_kind(kind),
         ))
@@ -1290,6 +1368,206 @@ impl InputParser {
     }
 }
 
+/// Combining marks terminals emit for dead keys (combining diacriticals).
+fn is_combining_accent(ch: char) -> bool {
+    matches!(ch, '\u{0300}'..='\u{036f}')
+}
+
+/// Compose a base char with a combining accent when NFC composition
+/// exists (e + \u{0301} → é). Table-driven over the accents dead keys
+/// actually produce, so no normalization dependency is needed.
+fn compose_dead_key(base: char, mark: char) -> Option<char> {
+    match mark {
+        '\u{0300}' => grave(base),
+        '\u{0301}' => acute(base),
+        '\u{0302}' => circumflex(base),
+        '\u{0303}' => tilde(base),
+        '\u{0308}' => diaeresis(base),
+        _ => None,
+    }
+}
+
+fn grave(base: char) -> Option<char> {
+    Some(match base {
+        'a' => '\u{e0}',
+        'e' => '\u{e8}',
+        'i' => '\u{ec}',
+        'o' => '\u{f2}',
+        'u' => '\u{f9}',
+        'A' => '\u{c0}',
+        'E' => '\u{c8}',
+        'I' => '\u{cc}',
+        'O' => '\u{d2}',
+        'U' => '\u{d9}',
+        _ => return None,
+    })
+}
+
+fn acute(base: char) -> Option<char> {
+    Some(match base {
+        'a' => '\u{e1}',
+        'e' => '\u{e9}',
+        'i' => '\u{ed}',
+        'o' => '\u{f3}',
+        'u' => '\u{fa}',
+        'y' => '\u{fd}',
+        'A' => '\u{c1}',
+        'E' => '\u{c9}',
+        'I' => '\u{cd}',
+        'O' => '\u{d3}',
+        'U' => '\u{da}',
+        'Y' => '\u{dd}',
+        _ => return None,
+    })
+}
+
+fn circumflex(base: char) -> Option<char> {
+    Some(match base {
+        'a' => '\u{e2}',
+        'e' => '\u{ea}',
+        'i' => '\u{ee}',
+        'o' => '\u{f4}',
+        'u' => '\u{fb}',
+        'A' => '\u{c2}',
+        'E' => '\u{ca}',
+        'I' => '\u{ce}',
+        'O' => '\u{d4}',
+        'U' => '\u{db}',
+        _ => return None,
+    })
+}
+
+fn tilde(base: char) -> Option<char> {
+    Some(match base {
+        'a' => '\u{e3}',
+        'n' => '\u{f1}',
+        'o' => '\u{f5}',
+        'A' => '\u{c3}',
+        'N' => '\u{d1}',
+        'O' => '\u{d5}',
+        _ => return None,
+    })
+}
+
+fn diaeresis(base: char) -> Option<char> {
+    Some(match base {
+        'a' => '\u{e4}',
+        'e' => '\u{eb}',
+        'i' => '\u{ef}',
+        'o' => '\u{f6}',
+        'u' => '\u{fc}',
+        'y' => '\u{ff}',
+        'A' => '\u{c4}',
+        'E' => '\u{cb}',
+        'I' => '\u{cf}',
+        'O' => '\u{d6}',
+        'U' => '\u{dc}',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod dual_report_tests {
+    use super::*;
+    use crate::event::{Event, KeyCode, Modifiers};
+
+    fn parse_all(parser: &mut InputParser, bytes: &[u8]) -> Vec<Event> {
+        parser.parse(bytes)
+    }
+
+    // ── Layout fixtures (kitty alternate keys) ───────────────────────
+
+    #[test]
+    fn azerty_ctrl_a_vs_q_disambiguated_by_base_layout() {
+        // AZERTY: the key at the US-layout Q position produces 'a'.
+        // Kitty reports `produced:shifted:base-layout`: 97('a')::113('q').
+        let mut parser = InputParser::new();
+        let events = parse_all(&mut parser, b"\x1b[97::113;5u");
+        assert_eq!(events.len(), 1);
+        let Event::Key(key) = events[0] else {
+            panic!("expected key event, got {events:?}");
+        };
+        assert_eq!(key.code, KeyCode::Char('q'), "physical identity");
+        assert_eq!(key.ch, Some('a'), "produced scalar");
+        assert!(key.modifiers.contains(Modifiers::CTRL));
+    }
+
+    #[test]
+    fn kitty_without_alternates_reports_produced_on_both() {
+        let mut parser = InputParser::new();
+        let events = parse_all(&mut parser, b"\x1b[97;5u");
+        let Event::Key(key) = events[0] else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('a'));
+        assert_eq!(key.ch, Some('a'));
+    }
+
+    #[test]
+    fn kitty_text_codepoints_override_produced_char() {
+        // text-as-codepoints section carries the composed text.
+        let mut parser = InputParser::new();
+        let events = parse_all(&mut parser, b"\x1b[97;1;233u"); // text é
+        let Event::Key(key) = events[0] else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('a'));
+        assert_eq!(key.ch, Some('\u{e9}'));
+    }
+
+    // ── Dead keys ────────────────────────────────────────────────────
+
+    #[test]
+    fn dead_key_accent_then_base_merges_into_composed_char() {
+        let mut parser = InputParser::new();
+        // U+0301 combining acute (UTF-8 0xCC 0x81), then 'e'.
+        let mut events = parse_all(&mut parser, b"\xcc\x81");
+        assert!(events.is_empty(), "accent held in ambiguity window");
+        assert!(parser.has_pending_timeout_state());
+        events.extend(parse_all(&mut parser, b"e"));
+        assert_eq!(events.len(), 1);
+        let Event::Key(key) = events[0] else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('\u{e9}'), "e + \u{301} → \u{e9}");
+        assert_eq!(key.ch, Some('\u{e9}'));
+    }
+
+    #[test]
+    fn dead_key_non_composing_delivers_both() {
+        let mut parser = InputParser::new();
+        let mut events = parse_all(&mut parser, b"\xcc\x81"); // acute
+        events.extend(parse_all(&mut parser, b"x")); // no x-acute
+        assert_eq!(events.len(), 2, "{events:?}");
+        assert!(
+            matches!(events[0], Event::Key(k) if k.code == KeyCode::Char('\u{0301}')),
+            "accent delivered as-is"
+        );
+        assert!(matches!(events[1], Event::Key(k) if k.code == KeyCode::Char('x')));
+    }
+
+    #[test]
+    fn dead_key_timeout_flushes_standalone_accent() {
+        let mut parser = InputParser::new();
+        let events = parse_all(&mut parser, b"\xcc\x80"); // grave
+        assert!(events.is_empty());
+        let flushed = parser.timeout().expect("accent flushed");
+        assert!(matches!(flushed, Event::Key(k) if k.code == KeyCode::Char('\u{0300}')));
+        assert!(!parser.has_pending_timeout_state());
+    }
+
+    #[test]
+    fn legacy_chars_report_scalar_on_both_sides() {
+        let mut parser = InputParser::new();
+        let events = parse_all(&mut parser, b"z");
+        let Event::Key(key) = events[0] else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('z'));
+        assert_eq!(key.ch, Some('z'));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1564,6 +1842,23 @@ mod tests {
             &events[0],
             Event::Paste(p) if p.text == "hello world"
         ));
+        // Raw bytes through the parser carry bracketed provenance.
+        assert!(matches!(
+            &events[0],
+            Event::Paste(p) if p.source == crate::event::PasteSource::Bracketed
+        ));
+    }
+
+    #[test]
+    fn bracketed_paste_normalizes_crlf() {
+        let mut parser = InputParser::new();
+        let events = parser.parse(b"\x1b[200~one\r\ntwo\x1b[201~");
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            Event::Paste(p) if p.text == "one\ntwo"
+                && p.line_endings == crate::event::LineEndingStyle::CrLf
+        ));
     }
 
     #[test]
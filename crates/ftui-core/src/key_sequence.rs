@@ -35,6 +35,7 @@
 //!     code: KeyCode::Escape,
 //!     modifiers: Modifiers::NONE,
 //!     kind: KeyEventKind::Press,
+//!     ch: None,
 //! };
 //!
 //! let now = Instant::now();
@@ -379,6 +380,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         }
     }
 
@@ -387,6 +389,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         }
     }
 
@@ -395,6 +398,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Release,
+            ch: None,
         }
     }
 
@@ -488,6 +492,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         };
 
         let action = interp.feed(&ctrl_esc, t);
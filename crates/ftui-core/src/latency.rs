@@ -0,0 +1,372 @@
+#![forbid(unsafe_code)]
+
+//! End-to-end input latency measurement: byte arrival → frame flush.
+//!
+//! The input reader stamps each event batch on arrival
+//! ([`LatencyTracker::stamp`]), the runtime carries the stamps through
+//! update/render, and the presenter reports flush completion
+//! ([`LatencyTracker::record_flush`]) — events that coalesced into one
+//! frame each count with their own arrival time. Durations accumulate
+//! into a fixed-memory log-linear histogram (HDR-style buckets) with
+//! p50/p95/p99/max accessors and a rolling-window reset.
+//!
+//! Overhead when disabled is zero beyond one branch: `stamp()` returns
+//! `None` without reading any clock (verifiable with a counting clock).
+//! The clock is injectable, so Lab-timed tests can assert exact
+//! histogram contents for scripted timings.
+
+use std::sync::Arc;
+use web_time::{Duration, Instant};
+
+use crate::cx::LabClock;
+
+/// Log-linear histogram geometry: 16 linear sub-buckets per power of
+/// two across 28 segments (448 buckets, ~3.5KB fixed memory), covering
+/// 1µs up to ~35 minutes; larger values clamp into the top bucket.
+const SUB_BITS: u32 = 4;
+const SUB_COUNT: u64 = 1 << SUB_BITS;
+const SEGMENTS: usize = 28;
+const BUCKETS: usize = (SUB_COUNT as usize) * SEGMENTS;
+
+/// An event batch's arrival time (opaque; thread the value through).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrivalStamp(Instant);
+
+/// Clock source for the tracker.
+#[derive(Clone)]
+enum Clock {
+    Real,
+    Lab(LabClock),
+    /// Injected clock (tests count calls through it).
+    Custom(Arc<dyn Fn() -> Instant + Send + Sync>),
+}
+
+impl std::fmt::Debug for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Real => write!(f, "Real"),
+            Self::Lab(_) => write!(f, "Lab"),
+            Self::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+/// Percentile snapshot for telemetry/evidence export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LatencySnapshot {
+    pub count: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+impl LatencySnapshot {
+    /// Serialize for evidence logs (repo-style flat JSON).
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"count":{},"p50_us":{},"p95_us":{},"p99_us":{},"max_us":{}}}"#,
+            self.count, self.p50_us, self.p95_us, self.p99_us, self.max_us
+        )
+    }
+}
+
+/// Fixed-memory latency histogram (see the module docs).
+#[derive(Debug, Clone)]
+pub struct LatencyTracker {
+    enabled: bool,
+    clock: Clock,
+    counts: Box<[u64; BUCKETS]>,
+    count: u64,
+    max_us: u64,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LatencyTracker {
+    /// An enabled tracker on the real clock.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            clock: Clock::Real,
+            counts: Box::new([0; BUCKETS]),
+            count: 0,
+            max_us: 0,
+        }
+    }
+
+    /// A disabled tracker: `stamp()` is `None`, nothing is recorded,
+    /// and no clock is ever read.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::new()
+        }
+    }
+
+    /// Use a lab clock (deterministic tests).
+    #[must_use]
+    pub fn with_lab_clock(mut self, clock: &LabClock) -> Self {
+        self.clock = Clock::Lab(clock.clone());
+        self
+    }
+
+    /// Use a custom clock (tests count the calls).
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Fn() -> Instant + Send + Sync + 'static) -> Self {
+        self.clock = Clock::Custom(Arc::new(clock));
+        self
+    }
+
+    /// Enable/disable at runtime.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn now(&self) -> Instant {
+        match &self.clock {
+            Clock::Real => Instant::now(),
+            Clock::Lab(clock) => clock.now(),
+            Clock::Custom(clock) => clock(),
+        }
+    }
+
+    /// Stamp an arriving event batch. `None` when disabled — and in
+    /// that case the clock is not read at all.
+    #[must_use]
+    pub fn stamp(&self) -> Option<ArrivalStamp> {
+        if !self.enabled {
+            return None;
+        }
+        Some(ArrivalStamp(self.now()))
+    }
+
+    /// Record flush completion for every event that contributed to the
+    /// flushed frame: each stamp counts with its own arrival time.
+    pub fn record_flush(&mut self, stamps: &[ArrivalStamp]) {
+        if !self.enabled || stamps.is_empty() {
+            return;
+        }
+        let flushed_at = self.now();
+        for stamp in stamps {
+            let elapsed = flushed_at.saturating_duration_since(stamp.0);
+            self.record_duration(elapsed);
+        }
+    }
+
+    /// Record one arrival-to-flush duration directly.
+    pub fn record_duration(&mut self, elapsed: Duration) {
+        if !self.enabled {
+            return;
+        }
+        let us = elapsed.as_micros().min(u128::from(u64::MAX)) as u64;
+        let bucket = bucket_index(us);
+        self.counts[bucket] += 1;
+        self.count += 1;
+        self.max_us = self.max_us.max(us);
+    }
+
+    /// Recorded sample count since the last reset.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Maximum recorded latency in microseconds.
+    #[must_use]
+    pub fn max_us(&self) -> u64 {
+        self.max_us
+    }
+
+    /// Percentile (0.0..=100.0) in microseconds: the lower bound of the
+    /// bucket containing that rank (HDR-style, deterministic).
+    #[must_use]
+    pub fn percentile_us(&self, percentile: f64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let rank = ((percentile / 100.0) * self.count as f64).ceil().max(1.0) as u64;
+        let mut seen = 0u64;
+        for (bucket, &count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return bucket_lower_bound(bucket);
+            }
+        }
+        self.max_us
+    }
+
+    /// Rolling window reset: clear samples, keep configuration.
+    pub fn reset_window(&mut self) {
+        self.counts.fill(0);
+        self.count = 0;
+        self.max_us = 0;
+    }
+
+    /// Snapshot of p50/p95/p99/max for telemetry/evidence.
+    #[must_use]
+    pub fn snapshot(&self) -> LatencySnapshot {
+        LatencySnapshot {
+            count: self.count,
+            p50_us: self.percentile_us(50.0),
+            p95_us: self.percentile_us(95.0),
+            p99_us: self.percentile_us(99.0),
+            max_us: self.max_us,
+        }
+    }
+}
+
+/// Log-linear bucket index for a value in microseconds.
+fn bucket_index(us: u64) -> usize {
+    if us < SUB_COUNT {
+        return us as usize;
+    }
+    let msb = 63 - us.leading_zeros() as u64;
+    let segment = msb - u64::from(SUB_BITS) + 1;
+    let sub = (us >> (msb - u64::from(SUB_BITS))) & (SUB_COUNT - 1);
+    let index = (segment * SUB_COUNT + sub) as usize;
+    index.min(BUCKETS - 1)
+}
+
+/// Lower bound (µs) of a bucket: the deterministic reported value.
+fn bucket_lower_bound(bucket: usize) -> u64 {
+    let bucket = bucket as u64;
+    let segment = bucket / SUB_COUNT;
+    let sub = bucket % SUB_COUNT;
+    if segment == 0 {
+        return sub;
+    }
+    let shift = segment - 1;
+    (SUB_COUNT + sub) << shift
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[test]
+    fn bucket_math_roundtrips_and_orders() {
+        // Exact below SUB_COUNT.
+        for us in 0..16u64 {
+            assert_eq!(bucket_lower_bound(bucket_index(us)), us);
+        }
+        // Lower bound never exceeds the value, and buckets are ordered.
+        let mut previous = 0;
+        for us in [16u64, 17, 31, 32, 100, 1_000, 65_536, 1_000_000, u64::MAX] {
+            let bucket = bucket_index(us);
+            assert!(bucket_lower_bound(bucket) <= us, "{us}");
+            assert!(bucket >= previous, "monotonic buckets at {us}");
+            previous = bucket;
+        }
+    }
+
+    #[test]
+    fn scripted_lab_timings_give_exact_histogram() {
+        let clock = LabClock::new();
+        let mut tracker = LatencyTracker::new().with_lab_clock(&clock);
+
+        // Three events arrive; the frame flushes 10ms after the last.
+        let first = tracker.stamp().expect("enabled");
+        clock.advance(Duration::from_millis(5));
+        let second = tracker.stamp().expect("enabled");
+        clock.advance(Duration::from_millis(5));
+        let third = tracker.stamp().expect("enabled");
+        clock.advance(Duration::from_millis(10));
+        tracker.record_flush(&[first, second, third]);
+
+        // Coalescing attribution: 20ms, 15ms, 10ms — one per event.
+        assert_eq!(tracker.count(), 3);
+        assert_eq!(tracker.max_us(), 20_000);
+        let snapshot = tracker.snapshot();
+        assert_eq!(snapshot.count, 3);
+        // p50 falls in the 15ms bucket, p99 in the 20ms bucket; bucket
+        // lower bounds are deterministic.
+        assert_eq!(snapshot.p50_us, bucket_lower_bound(bucket_index(15_000)));
+        assert_eq!(snapshot.p99_us, bucket_lower_bound(bucket_index(20_000)));
+        assert!(snapshot.p50_us <= 15_000 && snapshot.p50_us > 14_000);
+    }
+
+    #[test]
+    fn disabled_mode_never_reads_the_clock() {
+        let calls = Arc::new(AtomicU64::new(0));
+        let counting = {
+            let calls = calls.clone();
+            move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Instant::now()
+            }
+        };
+        let mut tracker = LatencyTracker::disabled().with_clock(counting);
+        assert!(tracker.stamp().is_none());
+        tracker.record_flush(&[]);
+        tracker.record_duration(Duration::from_millis(5));
+        assert_eq!(tracker.count(), 0, "disabled records nothing");
+        assert_eq!(calls.load(Ordering::SeqCst), 0, "zero clock calls");
+
+        // Runtime re-enable starts measuring (and reading the clock).
+        tracker.set_enabled(true);
+        let _ = tracker.stamp();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn rolling_window_reset_clears_samples() {
+        let mut tracker = LatencyTracker::new();
+        tracker.record_duration(Duration::from_millis(3));
+        tracker.record_duration(Duration::from_millis(9));
+        assert_eq!(tracker.count(), 2);
+        tracker.reset_window();
+        assert_eq!(tracker.count(), 0);
+        assert_eq!(tracker.max_us(), 0);
+        assert_eq!(tracker.percentile_us(99.0), 0);
+    }
+
+    #[test]
+    fn snapshot_serializes_for_evidence() {
+        let mut tracker = LatencyTracker::new();
+        for ms in [1u64, 2, 3, 4, 100] {
+            tracker.record_duration(Duration::from_millis(ms));
+        }
+        let json = tracker.snapshot().to_json();
+        assert!(json.starts_with(r#"{"count":5,"p50_us":"#), "{json}");
+        assert!(json.contains(r#""max_us":100000"#), "{json}");
+    }
+
+    #[test]
+    fn percentiles_on_spread_distribution() {
+        let mut tracker = LatencyTracker::new();
+        // 100 samples: 1ms ×90, 50ms ×9, 900ms ×1.
+        for _ in 0..90 {
+            tracker.record_duration(Duration::from_millis(1));
+        }
+        for _ in 0..9 {
+            tracker.record_duration(Duration::from_millis(50));
+        }
+        tracker.record_duration(Duration::from_millis(900));
+        let snapshot = tracker.snapshot();
+        assert!(snapshot.p50_us <= 1_000, "{snapshot:?}");
+        assert!(
+            snapshot.p95_us > 40_000 && snapshot.p95_us <= 50_000,
+            "{snapshot:?}"
+        );
+        assert!(snapshot.p99_us > 40_000, "{snapshot:?}");
+        assert_eq!(snapshot.max_us, 900_000);
+    }
+}
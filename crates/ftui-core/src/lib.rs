@@ -34,11 +34,13 @@ pub mod inline_mode;
 pub mod input_parser;
 pub mod key_sequence;
 pub mod keybinding;
+pub mod latency;
 pub mod logging;
 pub mod mux_passthrough;
 pub mod read_optimized;
 pub mod s3_fifo;
 pub mod semantic_event;
+pub mod size_policy;
 pub mod terminal_capabilities;
 #[cfg(all(not(target_arch = "wasm32"), feature = "crossterm"))]
 pub mod terminal_session;
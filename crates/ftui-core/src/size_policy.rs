@@ -0,0 +1,323 @@
+#![forbid(unsafe_code)]
+
+//! Size sanitation for misbehaving hosts.
+//!
+//! CI runners, detached tmux panes, certain Windows consoles, and
+//! serial consoles report 0x0 or nonsensical sizes (65535 columns);
+//! trusting them yields useless layouts or memory-hungry buffers.
+//! [`SizePolicy`] is applied to every size query and resize event:
+//! zero dimensions are replaced by the fallback, out-of-range
+//! dimensions clamp to the policy bounds, and each substitution is
+//! recorded for the evidence stream
+//! ([`last_size_substitution`]). [`ReprobeState`] drives a periodic
+//! re-probe while a substitution is in effect so recovery is automatic
+//! when the real size becomes available (the recovery transition asks
+//! for a full repaint).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use web_time::{Duration, Instant};
+
+/// Bounds and fallback applied to reported terminal sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizePolicy {
+    /// Smallest usable size; smaller non-zero reports clamp up.
+    pub min: (u16, u16),
+    /// Largest believable size; bigger reports clamp down (bounds
+    /// buffer allocations).
+    pub max: (u16, u16),
+    /// Replacement for zero reports (the host told us nothing usable).
+    pub fallback: (u16, u16),
+}
+
+impl Default for SizePolicy {
+    fn default() -> Self {
+        Self {
+            min: (2, 2),
+            max: (2048, 1024),
+            fallback: (80, 24),
+        }
+    }
+}
+
+/// Why a reported size was overridden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeSubstitutionReason {
+    /// A dimension was 0: the fallback size is used.
+    ZeroSizeReported,
+    /// A dimension exceeded the policy max: clamped down.
+    ExceedsMax,
+    /// A non-zero dimension was below the policy min: clamped up.
+    BelowMin,
+}
+
+impl SizeSubstitutionReason {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ZeroSizeReported => "zero-size-reported",
+            Self::ExceedsMax => "exceeds-max",
+            Self::BelowMin => "below-min",
+        }
+    }
+}
+
+/// Evidence record of one size substitution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeSubstitution {
+    pub reported: (u16, u16),
+    pub effective: (u16, u16),
+    pub reason: SizeSubstitutionReason,
+}
+
+impl SizeSubstitution {
+    /// Evidence-log serialization (repo-style flat JSON).
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"reported":[{},{}],"effective":[{},{}],"reason":"{}"}}"#,
+            self.reported.0,
+            self.reported.1,
+            self.effective.0,
+            self.effective.1,
+            self.reason.name()
+        )
+    }
+}
+
+impl SizePolicy {
+    /// Sanitize a reported size; `Some(substitution)` when overridden.
+    #[must_use]
+    pub fn sanitize(&self, reported: (u16, u16)) -> ((u16, u16), Option<SizeSubstitution>) {
+        let (cols, rows) = reported;
+        if cols == 0 || rows == 0 {
+            let substitution = SizeSubstitution {
+                reported,
+                effective: self.fallback,
+                reason: SizeSubstitutionReason::ZeroSizeReported,
+            };
+            return (self.fallback, Some(substitution));
+        }
+        let clamped = (
+            cols.clamp(self.min.0, self.max.0),
+            rows.clamp(self.min.1, self.max.1),
+        );
+        if clamped == reported {
+            return (reported, None);
+        }
+        let reason = if cols > self.max.0 || rows > self.max.1 {
+            SizeSubstitutionReason::ExceedsMax
+        } else {
+            SizeSubstitutionReason::BelowMin
+        };
+        (
+            clamped,
+            Some(SizeSubstitution {
+                reported,
+                effective: clamped,
+                reason,
+            }),
+        )
+    }
+
+    /// Sanitize, record to the evidence slot, and log.
+    #[must_use]
+    pub fn apply(&self, reported: (u16, u16)) -> (u16, u16) {
+        let (effective, substitution) = self.sanitize(reported);
+        if let Some(substitution) = substitution {
+            record_size_substitution(substitution);
+        }
+        effective
+    }
+}
+
+/// Re-probe scheduling while a substituted size is in effect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReprobeState {
+    active: bool,
+    last_probe: Option<Instant>,
+}
+
+impl ReprobeState {
+    /// Default interval between recovery probes.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+
+    /// Note that a substitution is in effect (activates re-probing).
+    pub fn on_substitution(&mut self, now: Instant) {
+        self.active = true;
+        self.last_probe = Some(now);
+    }
+
+    /// Whether re-probing is active.
+    #[must_use]
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    /// Should the host re-query the size now? Consumes the interval.
+    pub fn should_reprobe(&mut self, now: Instant, interval: Duration) -> bool {
+        if !self.active {
+            return false;
+        }
+        let due = self
+            .last_probe
+            .is_none_or(|last| now.saturating_duration_since(last) >= interval);
+        if due {
+            self.last_probe = Some(now);
+        }
+        due
+    }
+
+    /// A probe returned a real (unsubstituted) size: deactivate and
+    /// return `true` when this is a recovery transition — the caller
+    /// resizes to the real size and forces a full repaint.
+    pub fn on_real_size(&mut self) -> bool {
+        std::mem::take(&mut self.active)
+    }
+}
+
+// ─── Evidence slot ───────────────────────────────────────────────────────────
+
+static LAST_SUBSTITUTION: LazyLock<Mutex<Option<SizeSubstitution>>> =
+    LazyLock::new(|| Mutex::new(None));
+static SUBSTITUTION_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Record a substitution for the evidence stream.
+pub fn record_size_substitution(substitution: SizeSubstitution) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(substitution = %substitution.to_json(), "terminal size sanitized");
+    SUBSTITUTION_COUNT.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut guard) = LAST_SUBSTITUTION.lock() {
+        *guard = Some(substitution);
+    }
+}
+
+/// Monotonic count of substitutions recorded since process start.
+///
+/// Lets a caller detect that a size query it delegated (e.g. the native
+/// session's internally-sanitizing `size()`) substituted on its behalf:
+/// sample before and after, a changed count means the returned size is a
+/// fallback/clamped value, not the host's real report.
+#[must_use]
+pub fn substitution_count() -> u64 {
+    SUBSTITUTION_COUNT.load(Ordering::Relaxed)
+}
+
+/// The most recent size substitution, if any.
+#[must_use]
+pub fn last_size_substitution() -> Option<SizeSubstitution> {
+    LAST_SUBSTITUTION.lock().ok().and_then(|guard| *guard)
+}
+
+/// Clear the evidence slot (tests).
+pub fn clear_size_substitution() {
+    if let Ok(mut guard) = LAST_SUBSTITUTION.lock() {
+        *guard = None;
+    }
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_size_uses_fallback_with_evidence() {
+        let policy = SizePolicy::default();
+        let (size, substitution) = policy.sanitize((0, 0));
+        assert_eq!(size, (80, 24));
+        let substitution = substitution.expect("recorded");
+        assert_eq!(
+            substitution.reason,
+            SizeSubstitutionReason::ZeroSizeReported
+        );
+        assert_eq!(substitution.reported, (0, 0));
+        let json = substitution.to_json();
+        assert!(json.contains("zero-size-reported"), "{json}");
+
+        // One zero dimension is just as unusable.
+        let (size, substitution) = policy.sanitize((120, 0));
+        assert_eq!(size, (80, 24));
+        assert!(substitution.is_some());
+    }
+
+    #[test]
+    fn absurd_sizes_clamp_to_max_with_evidence() {
+        let policy = SizePolicy::default();
+        let (size, substitution) = policy.sanitize((65_535, 2));
+        assert_eq!(size, (2048, 2), "columns clamped, rows kept");
+        assert_eq!(
+            substitution.expect("recorded").reason,
+            SizeSubstitutionReason::ExceedsMax
+        );
+
+        let (size, substitution) = policy.sanitize((2048, 1024));
+        assert_eq!(size, (2048, 1024), "at-max passes untouched");
+        assert!(substitution.is_none());
+    }
+
+    #[test]
+    fn tiny_nonzero_sizes_clamp_to_min() {
+        let policy = SizePolicy::default();
+        let (size, substitution) = policy.sanitize((1, 1));
+        assert_eq!(size, (2, 2));
+        assert_eq!(
+            substitution.expect("recorded").reason,
+            SizeSubstitutionReason::BelowMin
+        );
+    }
+
+    #[test]
+    fn sane_sizes_pass_untouched() {
+        let policy = SizePolicy::default();
+        let (size, substitution) = policy.sanitize((120, 40));
+        assert_eq!(size, (120, 40));
+        assert!(substitution.is_none());
+    }
+
+    #[test]
+    fn buffer_allocation_is_bounded_by_max() {
+        let policy = SizePolicy::default();
+        let (cols, rows) = policy.apply((65_535, 65_535));
+        let cells = usize::from(cols) * usize::from(rows);
+        assert!(
+            cells <= 2048 * 1024,
+            "cell count bounded by policy: {cells}"
+        );
+    }
+
+    #[test]
+    fn reprobe_recovers_and_requests_repaint() {
+        let mut reprobe = ReprobeState::default();
+        let t0 = Instant::now();
+        assert!(!reprobe.should_reprobe(t0, ReprobeState::DEFAULT_INTERVAL));
+
+        // A substitution activates probing on the interval.
+        reprobe.on_substitution(t0);
+        assert!(reprobe.active());
+        assert!(!reprobe.should_reprobe(t0 + Duration::from_millis(500), Duration::from_secs(2)));
+        assert!(reprobe.should_reprobe(t0 + Duration::from_secs(2), Duration::from_secs(2)));
+        // Interval consumed: not due again immediately.
+        assert!(!reprobe.should_reprobe(t0 + Duration::from_secs(2), Duration::from_secs(2)));
+
+        // The probe finally sees a real size: recovery transition fires
+        // once (full repaint), then probing stays off.
+        assert!(reprobe.on_real_size(), "recovery asks for a repaint");
+        assert!(!reprobe.on_real_size(), "only once");
+        assert!(!reprobe.should_reprobe(t0 + Duration::from_secs(10), Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn evidence_slot_records_last_substitution() {
+        clear_size_substitution();
+        let policy = SizePolicy::default();
+        let _ = policy.apply((0, 0));
+        let recorded = last_size_substitution().expect("slot filled");
+        assert_eq!(recorded.reason, SizeSubstitutionReason::ZeroSizeReported);
+        clear_size_substitution();
+        assert!(last_size_substitution().is_none());
+    }
+}
@@ -328,6 +328,9 @@ pub struct TerminalCapabilities {
     pub osc8_hyperlinks: bool,
     /// Scroll region support (DECSTBM).
     pub scroll_region: bool,
+    /// Back color erase: EL/ED fill cleared cells with the current SGR
+    /// background instead of the terminal default.
+    pub back_color_erase: bool,
 
     // Multiplexer detection
     /// Running inside tmux.
@@ -426,6 +429,7 @@ impl TerminalCapabilities {
             sync_output: true,
             osc8_hyperlinks: true,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -454,6 +458,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -481,6 +486,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -508,6 +514,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -535,6 +542,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: false,
+            back_color_erase: false,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -563,6 +571,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: true,
             in_zellij: false,
@@ -591,6 +600,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: true,
             in_screen: false,
             in_zellij: false,
@@ -618,6 +628,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: true,
@@ -645,6 +656,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: true,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -672,6 +684,7 @@ impl TerminalCapabilities {
             sync_output: true,
             osc8_hyperlinks: true,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -699,6 +712,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: true,
+            back_color_erase: true,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
@@ -769,6 +783,7 @@ impl CapabilityProfileBuilder {
                 sync_output: false,
                 osc8_hyperlinks: false,
                 scroll_region: false,
+                back_color_erase: false,
                 in_tmux: false,
                 in_screen: false,
                 in_zellij: false,
@@ -1021,6 +1036,9 @@ impl TerminalCapabilities {
             sync_output,
             osc8_hyperlinks,
             scroll_region,
+            // BCE is near-universal among real terminals; detected profiles
+            // get it unless we're on a dumb terminal.
+            back_color_erase: !is_dumb,
             in_tmux,
             in_screen,
             in_zellij,
@@ -1049,6 +1067,7 @@ impl TerminalCapabilities {
             sync_output: false,
             osc8_hyperlinks: false,
             scroll_region: false,
+            back_color_erase: false,
             in_tmux: false,
             in_screen: false,
             in_zellij: false,
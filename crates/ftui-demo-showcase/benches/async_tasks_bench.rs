@@ -451,6 +451,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -100,6 +100,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -43,6 +43,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -51,6 +52,7 @@ fn ctrl_press(ch: char) -> Event {
         code: KeyCode::Char(ch),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
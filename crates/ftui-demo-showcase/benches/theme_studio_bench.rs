@@ -32,6 +32,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -40,6 +41,7 @@ fn ctrl_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
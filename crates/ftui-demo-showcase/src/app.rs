@@ -785,6 +785,8 @@ pub enum ScreenId {
     I18nDemo,
     /// VOI overlay widget demo (Galaxy-Brain).
     VoiOverlay,
+    /// Focus graph debug overlay demo.
+    FocusInspector,
     /// Inline mode story (scrollback + chrome).
     InlineModeStory,
     /// Accessibility control panel (bd-iuvb.8).
@@ -873,6 +875,7 @@ impl ScreenId {
             Self::ExplainabilityCockpit => "ExplainabilityCockpit",
             Self::I18nDemo => "I18nDemo",
             Self::VoiOverlay => "VoiOverlay",
+            Self::FocusInspector => "FocusInspector",
             Self::InlineModeStory => "InlineModeStory",
             Self::AccessibilityPanel => "AccessibilityPanel",
             Self::WidgetBuilder => "WidgetBuilder",
@@ -1013,6 +1016,8 @@ pub struct ScreenStates {
     pub i18n_demo: screens::i18n_demo::I18nDemo,
     /// VOI overlay widget demo screen state.
     pub voi_overlay: screens::voi_overlay::VoiOverlayScreen,
+    /// Focus inspector screen state.
+    pub focus_inspector: screens::focus_inspector::FocusInspector,
     /// Inline mode story screen state.
     pub inline_mode_story: screens::inline_mode_story::InlineModeStory,
     /// Accessibility control panel screen state.
@@ -1079,6 +1084,7 @@ impl Default for ScreenStates {
             explainability_cockpit: Default::default(),
             i18n_demo: Default::default(),
             voi_overlay: Default::default(),
+            focus_inspector: Default::default(),
             inline_mode_story: Default::default(),
             accessibility_panel: Default::default(),
             widget_builder: Default::default(),
@@ -1311,6 +1317,9 @@ impl ScreenStates {
             ScreenId::VoiOverlay => {
                 self.voi_overlay.update(event);
             }
+            ScreenId::FocusInspector => {
+                self.focus_inspector.update(event);
+            }
             ScreenId::InlineModeStory => {
                 self.inline_mode_story.update(event);
             }
@@ -1419,6 +1428,7 @@ impl ScreenStates {
             ScreenId::ExplainabilityCockpit => {} // Already ticked above
             ScreenId::I18nDemo => self.i18n_demo.tick(tick_count),
             ScreenId::VoiOverlay => self.voi_overlay.tick(tick_count),
+            ScreenId::FocusInspector => {}
             ScreenId::InlineModeStory => self.inline_mode_story.tick(tick_count),
             ScreenId::AccessibilityPanel => self.accessibility_panel.tick(tick_count),
             ScreenId::WidgetBuilder => self.widget_builder.tick(tick_count),
@@ -1520,6 +1530,7 @@ impl ScreenStates {
                     }
                     ScreenId::I18nDemo => self.i18n_demo.view(frame, area),
                     ScreenId::VoiOverlay => self.voi_overlay.view(frame, area),
+                    ScreenId::FocusInspector => self.focus_inspector.view(frame, area),
                     ScreenId::InlineModeStory => self.inline_mode_story.view(frame, area),
                     ScreenId::AccessibilityPanel => self.accessibility_panel.view(frame, area),
                     ScreenId::WidgetBuilder => self.widget_builder.view(frame, area),
@@ -2219,6 +2230,7 @@ impl Model for VfxHarnessModel {
                     code,
                     kind: KeyEventKind::Press,
                     modifiers,
+                    ch: _,
                 }) => {
                     if matches!(code, KeyCode::Char('q' | 'Q') | KeyCode::Escape)
                         || (matches!(code, KeyCode::Char('c' | 'C'))
@@ -2320,6 +2332,7 @@ impl MermaidHarnessModel {
                 code: KeyCode::Char(key),
                 kind: KeyEventKind::Press,
                 modifiers: Modifiers::NONE,
+                ch: None,
             }));
         }
         let sample_count = screen.sample_count();
@@ -2460,6 +2473,7 @@ impl MermaidHarnessModel {
             code: KeyCode::Down,
             kind: KeyEventKind::Press,
             modifiers: Modifiers::NONE,
+            ch: None,
         });
         self.screen.update(&right);
     }
@@ -2510,6 +2524,7 @@ impl Model for MermaidHarnessModel {
                     code,
                     kind: KeyEventKind::Press,
                     modifiers,
+                    ch: _,
                 }) => {
                     if matches!(code, KeyCode::Char('q' | 'Q') | KeyCode::Escape)
                         || (matches!(code, KeyCode::Char('c' | 'C'))
@@ -4168,6 +4183,7 @@ impl AppModel {
             ScreenId::ExplainabilityCockpit => self.screens.explainability_cockpit.keybindings(),
             ScreenId::I18nDemo => self.screens.i18n_demo.keybindings(),
             ScreenId::VoiOverlay => self.screens.voi_overlay.keybindings(),
+            ScreenId::FocusInspector => self.screens.focus_inspector.keybindings(),
             ScreenId::InlineModeStory => self.screens.inline_mode_story.keybindings(),
             ScreenId::AccessibilityPanel => self.screens.accessibility_panel.keybindings(),
             ScreenId::WidgetBuilder => self.screens.widget_builder.keybindings(),
@@ -5512,6 +5528,7 @@ mod tests {
             code: KeyCode::Char('q'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let cmd = app.update(AppMsg::from(event));
         assert!(matches!(cmd, Cmd::Quit));
@@ -5524,6 +5541,7 @@ mod tests {
             code: KeyCode::Char('?'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event));
         assert!(app.help_visible);
@@ -5536,6 +5554,7 @@ mod tests {
             code: KeyCode::Tab,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event));
         assert_eq!(app.current_screen, ScreenId::Shakespeare);
@@ -5549,6 +5568,7 @@ mod tests {
             code: KeyCode::BackTab,
             modifiers: Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event));
         assert_eq!(app.current_screen, ScreenId::Dashboard);
@@ -5561,6 +5581,7 @@ mod tests {
             code: KeyCode::Char('3'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event));
         assert_eq!(app.current_screen, ScreenId::Shakespeare);
@@ -5601,6 +5622,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event));
 
@@ -6151,6 +6173,7 @@ mod tests {
             code: KeyCode::Down,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(next_step));
         let expected_step = app.tour.step_count().saturating_sub(1).min(1);
@@ -6160,6 +6183,7 @@ mod tests {
             code: KeyCode::Char('+'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(faster));
         assert!(app.tour.speed() > base_speed);
@@ -6168,6 +6192,7 @@ mod tests {
             code: KeyCode::Char('r'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(reset));
         assert_eq!(app.tour_landing_start_step, 0);
@@ -6229,6 +6254,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(start));
         assert!(app.tour.is_active());
@@ -6374,6 +6400,7 @@ mod tests {
             code: KeyCode::Char('x'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         assert!(!states.is_lazy_initialized(ScreenId::Shakespeare));
@@ -6514,7 +6541,7 @@ mod tests {
     /// Verify all screens have the expected count.
     #[test]
     fn all_screens_count() {
-        assert_eq!(screens::screen_registry().len(), 45);
+        assert_eq!(screens::screen_registry().len(), 46);
     }
 
     // -----------------------------------------------------------------------
@@ -6530,6 +6557,7 @@ mod tests {
             code: KeyCode::Char('k'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event));
         assert!(app.command_palette.is_visible());
@@ -6545,6 +6573,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(esc));
         assert!(!app.command_palette.is_visible());
@@ -6567,6 +6596,7 @@ mod tests {
             code: KeyCode::Char('1'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(ctrl_1));
         assert_eq!(
@@ -6583,6 +6613,7 @@ mod tests {
             code: KeyCode::Char('0'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(ctrl_0));
         assert_eq!(app.palette_category_filter, None);
@@ -6602,6 +6633,7 @@ mod tests {
                 code: KeyCode::Char(ch),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             app.update(AppMsg::from(event));
         }
@@ -6610,6 +6642,7 @@ mod tests {
             code: KeyCode::Char('f'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(ctrl_f));
         assert!(app.screen_favorites.contains(&ScreenId::Dashboard));
@@ -6618,6 +6651,7 @@ mod tests {
             code: KeyCode::Char('F'),
             modifiers: Modifiers::CTRL | Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(ctrl_shift_f.clone()));
         assert!(app.palette_favorites_only);
@@ -6643,6 +6677,7 @@ mod tests {
                 code: KeyCode::Char(ch),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             app.update(AppMsg::from(event));
         }
@@ -6651,6 +6686,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(enter));
         assert_eq!(app.current_screen, ScreenId::Shakespeare);
@@ -6699,6 +6735,7 @@ mod tests {
             code: KeyCode::Char('q'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let cmd = app.update(AppMsg::from(q));
         assert!(!matches!(cmd, Cmd::Quit));
@@ -6744,6 +6781,7 @@ mod tests {
             code: KeyCode::Char('p'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(event.clone()));
         assert!(app.perf_hud_visible);
@@ -7548,6 +7586,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -7645,6 +7684,7 @@ mod tests {
             code: KeyCode::Char('i'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         app.update(AppMsg::from(ctrl_i.clone()));
         assert!(
@@ -78,16 +78,17 @@ SCREENS:
    33  Explainability     Diff/resize/budget evidence cockpit
    34  i18n Stress Lab    Unicode width, RTL, emoji, and truncation
    35  VOI Overlay        Galaxy-Brain VOI debug overlay
-   36  Inline Mode        Inline scrollback + chrome story
-   37  Accessibility      Accessibility control panel + contrast checks
-   38  Widget Builder     Interactive widget composition sandbox
-   39  Palette Evidence   Command palette evidence lab
-   40  Determinism Lab    Checksum equivalence + determinism proofs
-   41  Links              OSC-8 hyperlink playground + hit regions
-   42  Kanban Board       Interactive Kanban board with drag-drop
-   43  MD Live Editor     Split-pane editor with live Markdown preview
-   44  Drag & Drop Lab    Sortable/cross-container drag-drop interactions
-   45  Quake Easter Egg   Quake E1M1 retro renderer (final easter egg)
+   36  Focus Inspector    Focus graph debug overlay on a live layout
+   37  Inline Mode        Inline scrollback + chrome story
+   38  Accessibility      Accessibility control panel + contrast checks
+   39  Widget Builder     Interactive widget composition sandbox
+   40  Palette Evidence   Command palette evidence lab
+   41  Determinism Lab    Checksum equivalence + determinism proofs
+   42  Links              OSC-8 hyperlink playground + hit regions
+   43  Kanban Board       Interactive Kanban board with drag-drop
+   44  MD Live Editor     Split-pane editor with live Markdown preview
+   45  Drag & Drop Lab    Sortable/cross-container drag-drop interactions
+   46  Quake Easter Egg   Quake E1M1 retro renderer (final easter egg)
 
 KEYBINDINGS:
     1-9, 0                Switch to screens 1-10 by number
@@ -777,13 +778,13 @@ mod tests {
     }
 
     #[test]
-    fn help_text_contains_drag_drop_as_screen_44() {
-        assert!(HELP_TEXT.contains("44  Drag & Drop Lab"));
+    fn help_text_contains_drag_drop_as_screen_45() {
+        assert!(HELP_TEXT.contains("45  Drag & Drop Lab"));
     }
 
     #[test]
-    fn help_text_contains_quake_easter_egg_as_screen_45() {
-        assert!(HELP_TEXT.contains("45  Quake Easter Egg"));
+    fn help_text_contains_quake_easter_egg_as_screen_46() {
+        assert!(HELP_TEXT.contains("46  Quake Easter Egg"));
     }
 
     #[test]
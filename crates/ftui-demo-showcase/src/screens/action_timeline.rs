@@ -218,6 +218,7 @@ impl ActionTimeline {
                 code,
                 modifiers,
                 kind,
+                ch: _,
             }) => {
                 let summary = format!("Key {}", Self::format_key_code(*code));
                 let fields = vec![
@@ -752,12 +753,10 @@ impl ActionTimeline {
                         self.sync_selection();
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if timeline_area.contains(mouse.x, mouse.y) {
-                        self.follow = false;
-                        self.selected = self.selected.saturating_add(3);
-                        self.sync_selection();
-                    }
+                MouseEventKind::ScrollDown if timeline_area.contains(mouse.x, mouse.y) => {
+                    self.follow = false;
+                    self.selected = self.selected.saturating_add(3);
+                    self.sync_selection();
                 }
                 _ => {}
             }
@@ -990,6 +989,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
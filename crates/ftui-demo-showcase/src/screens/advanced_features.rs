@@ -868,6 +868,7 @@ mod tests {
             code,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -876,6 +877,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1761,6 +1761,7 @@ mod tests {
             code,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1769,6 +1770,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1838,6 +1840,7 @@ mod tests {
             code: KeyCode::Right,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         }));
         assert_eq!(screen.focus, Focus::Search);
 
@@ -1845,6 +1848,7 @@ mod tests {
             code: KeyCode::Right,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         }));
         assert_eq!(screen.focus, Focus::Replace);
 
@@ -1852,6 +1856,7 @@ mod tests {
             code: KeyCode::Right,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         }));
         assert_eq!(screen.focus, Focus::Editor);
     }
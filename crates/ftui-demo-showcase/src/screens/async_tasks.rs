@@ -2026,10 +2026,8 @@ impl Screen for AsyncTaskManager {
                         self.select_prev();
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if list_inner.contains(mouse.x, mouse.y) {
-                        self.select_next();
-                    }
+                MouseEventKind::ScrollDown if list_inner.contains(mouse.x, mouse.y) => {
+                    self.select_next();
                 }
                 _ => {}
             }
@@ -3315,6 +3313,7 @@ mod tests {
             code: KeyCode::Char('n'),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
 
         assert_eq!(mgr.tasks.len(), count_before + 1);
@@ -3329,6 +3328,7 @@ mod tests {
             code: KeyCode::Char('c'),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
 
         assert_eq!(mgr.tasks[0].state, TaskState::Canceled);
@@ -3343,6 +3343,7 @@ mod tests {
             code: KeyCode::Char('s'),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
 
         assert_eq!(mgr.policy, SchedulerPolicy::ShortestFirst);
@@ -3358,6 +3359,7 @@ mod tests {
             code: KeyCode::Char('j'),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
         assert_eq!(mgr.selected, 1);
 
@@ -3366,6 +3368,7 @@ mod tests {
             code: KeyCode::Char('k'),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
         assert_eq!(mgr.selected, 0);
     }
@@ -3379,6 +3382,7 @@ mod tests {
             code: KeyCode::Down,
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
         assert_eq!(mgr.selected, 1);
 
@@ -3386,6 +3390,7 @@ mod tests {
             code: KeyCode::Up,
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         }));
         assert_eq!(mgr.selected, 0);
     }
@@ -785,11 +785,10 @@ impl Screen for CodeExplorer {
                         self.scroll_by(-3);
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if self.focus == FocusPanel::Code {
+                MouseEventKind::ScrollDown
+                    if self.focus == FocusPanel::Code => {
                         self.scroll_by(3);
                     }
-                }
                 _ => {}
             }
             return Cmd::None;
@@ -908,12 +907,11 @@ impl Screen for CodeExplorer {
                             self.scroll_to(hotspot.line.saturating_sub(3));
                         }
                     }
-                    FocusPanel::Radar => {
-                        if !self.search_matches.is_empty() {
+                    FocusPanel::Radar
+                        if !self.search_matches.is_empty() => {
                             let line = self.search_matches[self.current_match];
                             self.scroll_to(line.saturating_sub(3));
                         }
-                    }
                     _ => {}
                 },
                 _ => {}
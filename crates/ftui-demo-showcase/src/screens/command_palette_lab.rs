@@ -172,6 +172,7 @@ impl CommandPaletteEvidenceLab {
                     code: KeyCode::Enter,
                     modifiers: Modifiers::NONE,
                     kind: KeyEventKind::Press,
+                    ch: None,
                 });
                 let _ = self.palette.handle_event(&event);
             }
@@ -180,6 +181,7 @@ impl CommandPaletteEvidenceLab {
                     code: KeyCode::Up,
                     modifiers: Modifiers::NONE,
                     kind: KeyEventKind::Press,
+                    ch: None,
                 });
                 for _ in 0..3 {
                     let _ = self.palette.handle_event(&event);
@@ -190,6 +192,7 @@ impl CommandPaletteEvidenceLab {
                     code: KeyCode::Down,
                     modifiers: Modifiers::NONE,
                     kind: KeyEventKind::Press,
+                    ch: None,
                 });
                 for _ in 0..3 {
                     let _ = self.palette.handle_event(&event);
@@ -492,6 +495,7 @@ impl Screen for CommandPaletteEvidenceLab {
             code,
             modifiers,
             kind: KeyEventKind::Press,
+            ch: _,
         }) = event
             && *modifiers == Modifiers::NONE
         {
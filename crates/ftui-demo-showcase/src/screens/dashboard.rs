@@ -6664,6 +6664,7 @@ mod tests {
             code: KeyCode::Tab,
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         }));
 
         assert!(
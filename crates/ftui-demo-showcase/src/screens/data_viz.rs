@@ -939,6 +939,7 @@ mod tests {
             code,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -947,6 +948,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -551,10 +551,8 @@ impl Screen for DragDropDemo {
                 }
 
                 // Transfer between lists (cross-container mode)
-                (KeyCode::Enter, false) => {
-                    if self.mode == DemoMode::CrossContainer {
-                        self.transfer_item();
-                    }
+                (KeyCode::Enter, false) if self.mode == DemoMode::CrossContainer => {
+                    self.transfer_item();
                 }
 
                 _ => {}
@@ -1051,6 +1049,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
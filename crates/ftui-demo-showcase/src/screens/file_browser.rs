@@ -984,6 +984,7 @@ mod tests {
             code,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -992,6 +993,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
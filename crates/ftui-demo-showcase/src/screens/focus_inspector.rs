@@ -0,0 +1,274 @@
+#![forbid(unsafe_code)]
+
+//! Focus inspector screen: the focus debug overlay on a live graph.
+//!
+//! A small field of focusable boxes navigated with arrows/Tab; `d`
+//! toggles [`FocusDebugOverlay`] showing rect outlines, tab-order
+//! badges, spatial edges, and the last navigation decision.
+
+use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_runtime::Cmd;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+use ftui_widgets::block::Block;
+use ftui_widgets::borders::{BorderType, Borders};
+use ftui_widgets::focus::{
+    FocusDebugOverlay, FocusManager, FocusNode, NavDirection, build_spatial_edges,
+};
+use ftui_widgets::paragraph::Paragraph;
+
+use super::{HelpEntry, Screen};
+use crate::theme;
+
+/// One demo focusable: (id, (x, y, w, h), tab_index).
+type DemoBox = (u64, (u16, u16, u16, u16), i32);
+
+/// Demo layout, irregular on purpose so spatial navigation has
+/// interesting choices to explain.
+const BOXES: &[DemoBox] = &[
+    (1, (2, 3, 16, 5), 0),
+    (2, (22, 3, 12, 5), 1),
+    (3, (38, 3, 20, 5), 2),
+    (4, (2, 10, 10, 4), 3),
+    (5, (16, 10, 26, 4), 4),
+    (6, (46, 10, 12, 4), 5),
+    (7, (2, 16, 24, 5), 6),
+    (8, (30, 16, 13, 5), 7),
+    (9, (47, 16, 11, 5), 8),
+];
+
+/// Screen showcasing the focus debug overlay.
+pub struct FocusInspector {
+    manager: FocusManager,
+    /// Whether the debug overlay is drawn as a post-pass.
+    overlay_enabled: bool,
+}
+
+impl Default for FocusInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FocusInspector {
+    pub fn new() -> Self {
+        let mut manager = FocusManager::new();
+        for &(id, (x, y, w, h), tab) in BOXES {
+            manager
+                .graph_mut()
+                .insert(FocusNode::new(id, Rect::new(x, y, w, h)).with_tab_index(tab));
+        }
+        // No precomputed spatial edges: live navigation falls through to
+        // the spatial search, so traces carry the scored candidates. The
+        // overlay's display copy builds them for the edge markers.
+        manager.graph_mut().build_tab_chain(true);
+        let _ = manager.focus_first();
+        Self {
+            manager,
+            overlay_enabled: true,
+        }
+    }
+}
+
+impl Screen for FocusInspector {
+    type Message = ();
+
+    fn update(&mut self, event: &Event) -> Cmd<Self::Message> {
+        if let Event::Key(KeyEvent {
+            kind: KeyEventKind::Press,
+            code,
+            ..
+        }) = event
+        {
+            match code {
+                KeyCode::Char('d') => self.overlay_enabled = !self.overlay_enabled,
+                KeyCode::Up => {
+                    let _ = self.manager.navigate(NavDirection::Up);
+                }
+                KeyCode::Down => {
+                    let _ = self.manager.navigate(NavDirection::Down);
+                }
+                KeyCode::Left => {
+                    let _ = self.manager.navigate(NavDirection::Left);
+                }
+                KeyCode::Right => {
+                    let _ = self.manager.navigate(NavDirection::Right);
+                }
+                // Tab itself cycles showcase screens at the app level.
+                KeyCode::Char('n') => {
+                    let _ = self.manager.focus_next();
+                }
+                KeyCode::Char('p') => {
+                    let _ = self.manager.focus_prev();
+                }
+                _ => {}
+            }
+        }
+        Cmd::None
+    }
+
+    fn view(&self, frame: &mut Frame, area: Rect) {
+        if area.is_empty() {
+            return;
+        }
+
+        let status = format!(
+            "focus: {}  overlay: {}  \u{2190}\u{2191}\u{2192}\u{2193}:spatial n/p:order d:overlay",
+            self.manager
+                .current()
+                .map_or_else(|| "\u{2014}".to_string(), |id| format!("#{id}")),
+            if self.overlay_enabled { "ON" } else { "off" },
+        );
+        Paragraph::new(status)
+            .style(Style::new().fg(theme::fg::MUTED))
+            .render(
+                Rect::new(area.x + 1, area.y, area.width.saturating_sub(2), 1),
+                frame,
+            );
+
+        // The demo boxes, offset into the screen area.
+        for &(id, (x, y, w, h), _) in BOXES {
+            let rect = Rect::new(area.x + x, area.y + y, w, h);
+            let Some(rect) = rect.intersection_opt(&area) else {
+                continue;
+            };
+            if rect.is_empty() {
+                continue;
+            }
+            let focused = self.manager.current() == Some(id);
+            let style = if focused {
+                Style::new().fg(theme::accent::PRIMARY)
+            } else {
+                Style::new().fg(theme::fg::MUTED)
+            };
+            Block::new()
+                .borders(Borders::ALL)
+                .border_type(if focused {
+                    BorderType::Double
+                } else {
+                    BorderType::Rounded
+                })
+                .style(style)
+                .render(rect, frame);
+        }
+
+        // Post-pass: the overlay draws over the composed boxes. The graph
+        // holds layout-local bounds, so render it with the same offset.
+        if self.overlay_enabled {
+            // Shift the graph into screen coordinates for rendering.
+            let graph = self.manager.graph();
+            let ids: Vec<_> = graph.node_ids().collect();
+            let mut shifted = ftui_widgets::focus::FocusGraph::new();
+            for &id in &ids {
+                if let Some(node) = graph.get(id) {
+                    let mut node = node.clone();
+                    node.bounds = Rect::new(
+                        area.x + node.bounds.x,
+                        area.y + node.bounds.y,
+                        node.bounds.width,
+                        node.bounds.height,
+                    );
+                    shifted.insert(node);
+                }
+            }
+            // Preserve explicit edges, then derive the spatial ones for
+            // the direction markers.
+            for &id in &ids {
+                for dir in NavDirection::ALL {
+                    if let Some(to) = graph.navigate(id, dir) {
+                        shifted.connect(id, dir, to);
+                    }
+                }
+            }
+            build_spatial_edges(&mut shifted);
+            FocusDebugOverlay::new(&shifted)
+                .with_trace(self.manager.last_nav_trace())
+                .render(area, frame);
+        }
+    }
+
+    fn keybindings(&self) -> Vec<HelpEntry> {
+        vec![
+            HelpEntry {
+                key: "d",
+                action: "Toggle focus debug overlay",
+            },
+            HelpEntry {
+                key: "\u{2190}\u{2191}\u{2192}\u{2193}",
+                action: "Spatial navigation",
+            },
+            HelpEntry {
+                key: "n / p",
+                action: "Tab order navigation",
+            },
+        ]
+    }
+
+    fn title(&self) -> &'static str {
+        "Focus Inspector"
+    }
+
+    fn tab_label(&self) -> &'static str {
+        "Focus"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::Modifiers;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key_event(code: KeyCode) -> Event {
+        Event::Key(KeyEvent {
+            code,
+            modifiers: Modifiers::NONE,
+            kind: KeyEventKind::Press,
+            ch: None,
+        })
+    }
+
+    #[test]
+    fn d_toggles_overlay() {
+        let mut screen = FocusInspector::new();
+        assert!(screen.overlay_enabled);
+        screen.update(&key_event(KeyCode::Char('d')));
+        assert!(!screen.overlay_enabled);
+        screen.update(&key_event(KeyCode::Char('d')));
+        assert!(screen.overlay_enabled);
+    }
+
+    #[test]
+    fn arrows_navigate_and_record_trace() {
+        let mut screen = FocusInspector::new();
+        assert_eq!(screen.manager.current(), Some(1));
+        screen.update(&key_event(KeyCode::Right));
+        let landed = screen.manager.current();
+        assert_ne!(landed, Some(1), "focus should move");
+        // The trace explains exactly the move that happened.
+        let trace = screen.manager.last_nav_trace().expect("trace recorded");
+        assert_eq!(trace.origin, 1);
+        assert_eq!(trace.chosen, landed);
+    }
+
+    #[test]
+    fn render_no_panic_with_and_without_overlay() {
+        let mut screen = FocusInspector::new();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(80, 24, &mut pool);
+        screen.view(&mut frame, Rect::new(0, 0, 80, 24));
+        screen.update(&key_event(KeyCode::Char('d')));
+        let mut frame = Frame::new(80, 24, &mut pool);
+        screen.view(&mut frame, Rect::new(0, 0, 80, 24));
+    }
+
+    #[test]
+    fn render_no_panic_tiny_area() {
+        let screen = FocusInspector::new();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 2, &mut pool);
+        screen.view(&mut frame, Rect::new(0, 0, 4, 2));
+    }
+}
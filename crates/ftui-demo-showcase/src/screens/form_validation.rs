@@ -470,13 +470,11 @@ impl FormValidationDemo {
                         }
                     }
                 }
-                MouseEventKind::ScrollUp => {
-                    if form_area.contains(mouse.x, mouse.y) {
-                        let mut state = self.form_state.borrow_mut();
-                        let count = self.form.field_count();
-                        if count > 0 {
-                            state.focused = (state.focused + count - 1) % count;
-                        }
+                MouseEventKind::ScrollUp if form_area.contains(mouse.x, mouse.y) => {
+                    let mut state = self.form_state.borrow_mut();
+                    let count = self.form.field_count();
+                    if count > 0 {
+                        state.focused = (state.focused + count - 1) % count;
                     }
                 }
                 _ => {}
@@ -801,6 +799,7 @@ mod tests {
             code: KeyCode::Char('c'),
             kind: KeyEventKind::Press,
             modifiers: Modifiers::NONE,
+            ch: None,
         });
         demo.update(&ev);
         assert!(demo.form_state.borrow().errors.is_empty());
@@ -812,6 +811,7 @@ mod tests {
             code: KeyCode::Char('e'),
             kind: KeyEventKind::Press,
             modifiers: Modifiers::NONE,
+            ch: None,
         });
         demo.update(&ev);
         assert!(demo.error_injection);
@@ -824,6 +824,7 @@ mod tests {
             code: KeyCode::Char('r'),
             kind: KeyEventKind::Press,
             modifiers: Modifiers::NONE,
+            ch: None,
         });
         demo.update(&ev);
         assert!(!demo.error_injection);
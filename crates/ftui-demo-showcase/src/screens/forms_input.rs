@@ -1211,6 +1211,7 @@ mod tests {
             code,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1219,6 +1220,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1227,6 +1229,7 @@ mod tests {
             code,
             modifiers: Modifiers::ALT,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1242,6 +1242,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
     fn mouse_click(x: u16, y: u16) -> Event {
@@ -405,10 +405,8 @@ impl Screen for InlineModeStory {
                         self.cycle_log_rate();
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if content.contains(mouse.x, mouse.y) {
-                        self.cycle_log_rate_down();
-                    }
+                MouseEventKind::ScrollDown if content.contains(mouse.x, mouse.y) => {
+                    self.cycle_log_rate_down();
                 }
                 _ => {}
             }
@@ -651,6 +649,7 @@ mod tests {
             code: KeyCode::Char(ch),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::empty(),
+            ch: None,
         })
     }
 
@@ -686,6 +685,7 @@ mod tests {
             code: KeyCode::Char(' '),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::empty(),
+            ch: None,
         }));
         assert!(state.paused);
     }
@@ -677,6 +677,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
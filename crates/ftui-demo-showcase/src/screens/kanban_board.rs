@@ -875,6 +875,7 @@ mod tests {
             code: KeyCode::Char('j'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         board.update(&down);
         assert_eq!(board.focus_row, 1);
@@ -883,6 +884,7 @@ mod tests {
             code: KeyCode::Char('l'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         board.update(&right);
         assert_eq!(board.focus_col, 1);
@@ -897,6 +899,7 @@ mod tests {
             code: KeyCode::Char('L'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         board.update(&move_right);
         assert_eq!(board.columns[0].len(), 3);
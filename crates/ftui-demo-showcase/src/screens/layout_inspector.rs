@@ -724,6 +724,7 @@ mod tests {
             code: KeyCode::Char('n'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&next);
         assert_eq!(screen.scenario_idx, 1);
@@ -732,6 +733,7 @@ mod tests {
             code: KeyCode::Char('p'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&prev);
         assert_eq!(screen.scenario_idx, 0);
@@ -744,6 +746,7 @@ mod tests {
             code: KeyCode::Char(']'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         for _ in 0..STEP_COUNT {
             screen.update(&next);
@@ -969,6 +972,7 @@ mod tests {
             code: KeyCode::Char('o'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&event);
         assert!(!screen.show_overlay);
@@ -984,6 +988,7 @@ mod tests {
             code: KeyCode::Char('t'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&event);
         assert!(!screen.show_tree);
@@ -996,6 +1001,7 @@ mod tests {
             code: KeyCode::Char(']'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&step_event);
         assert_eq!(screen.step_idx, 1);
@@ -1003,6 +1009,7 @@ mod tests {
             code: KeyCode::Char('r'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&reset_event);
         assert_eq!(screen.step_idx, 0);
@@ -2161,6 +2161,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -2169,6 +2170,7 @@ mod tests {
             code,
             modifiers: Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -2483,6 +2485,7 @@ mod tests {
             code: KeyCode::Char('d'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Release,
+            ch: None,
         });
         lab.update(&release);
         assert_eq!(
@@ -2494,6 +2497,7 @@ mod tests {
             code: KeyCode::Char('3'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Repeat,
+            ch: None,
         });
         lab.update(&repeat);
         assert_eq!(
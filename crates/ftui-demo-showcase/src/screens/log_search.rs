@@ -1180,10 +1180,8 @@ impl LogSearch {
                         self.viewer.scroll_up(3);
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if log_area.contains(mouse.x, mouse.y) {
-                        self.viewer.scroll_down(3);
-                    }
+                MouseEventKind::ScrollDown if log_area.contains(mouse.x, mouse.y) => {
+                    self.viewer.scroll_down(3);
                 }
                 _ => {}
             }
@@ -1390,6 +1388,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1819,6 +1818,7 @@ mod tests {
             code,
             modifiers,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -2502,6 +2502,7 @@ mod tests {
             code: KeyCode::Char('/'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&slash);
         assert_ne!(screen.mode, UiMode::Normal);
@@ -1277,6 +1277,7 @@ fn key_event(code: KeyCode, modifiers: Modifiers) -> Event {
         code,
         modifiers,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -1371,6 +1372,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1379,6 +1381,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
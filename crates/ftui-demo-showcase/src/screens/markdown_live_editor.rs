@@ -557,13 +557,12 @@ impl Screen for MarkdownLiveEditor {
                         self.preview_scroll = self.preview_scroll.saturating_sub(1);
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if self.focus == Focus::Preview
-                        || self.layout_preview.get().contains(mouse.x, mouse.y)
-                    {
+                MouseEventKind::ScrollDown
+                    if (self.focus == Focus::Preview
+                        || self.layout_preview.get().contains(mouse.x, mouse.y))
+                    => {
                         self.preview_scroll = self.preview_scroll.saturating_add(1);
                     }
-                }
                 _ => {}
             }
             return Cmd::None;
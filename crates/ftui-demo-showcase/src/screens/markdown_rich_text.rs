@@ -1231,6 +1231,7 @@ mod tests {
             code,
             modifiers: ftui_core::event::Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
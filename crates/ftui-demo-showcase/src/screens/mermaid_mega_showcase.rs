@@ -4880,6 +4880,7 @@ mod tests {
             code: KeyCode::Char('H'),
             modifiers: Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         };
         assert_eq!(screen.handle_key(&event), Some(MegaAction::PanLeft));
     }
@@ -4892,6 +4893,7 @@ mod tests {
             code: KeyCode::Char(']'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         };
         assert_eq!(
             screen.handle_key(&event),
@@ -4907,6 +4909,7 @@ mod tests {
             code: KeyCode::Char('o'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         };
         assert_eq!(
             screen.handle_key(&event),
@@ -4922,6 +4925,7 @@ mod tests {
             code,
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
         }
     }
 
@@ -4930,6 +4934,7 @@ mod tests {
             code: ftui_core::event::KeyCode::Char(ch),
             modifiers: ftui_core::event::Modifiers::SHIFT,
             kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
         }
     }
 
@@ -6362,6 +6367,7 @@ mod tests {
             code: ftui_core::event::KeyCode::Char('v'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
         };
         assert_eq!(
             screen.handle_key(&event),
@@ -6376,6 +6382,7 @@ mod tests {
             code: ftui_core::event::KeyCode::Char('V'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
         };
         assert_eq!(
             screen.handle_key(&event),
@@ -6564,6 +6571,7 @@ mod tests {
             code: ftui_core::event::KeyCode::Char('A'),
             modifiers: ftui_core::event::Modifiers::empty(),
             kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
         };
         let action = screen.handle_key(&event);
         assert!(
@@ -6695,6 +6703,7 @@ mod tests {
             code: ftui_core::event::KeyCode::Char('S'),
             modifiers: ftui_core::event::Modifiers::empty(),
             kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
         };
         let action = screen.handle_key(&event);
         assert!(
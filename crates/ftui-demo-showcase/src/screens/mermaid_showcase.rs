@@ -4540,6 +4540,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         }
     }
 
@@ -5196,6 +5197,7 @@ mod tests {
             code: KeyCode::Char('j'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Release,
+            ch: None,
         };
         assert!(screen.handle_key(&event).is_none());
     }
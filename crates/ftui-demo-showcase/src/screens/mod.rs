@@ -18,6 +18,7 @@ pub mod determinism_lab;
 pub mod drag_drop;
 pub mod explainability_cockpit;
 pub mod file_browser;
+pub mod focus_inspector;
 pub mod form_validation;
 pub mod forms_input;
 pub mod hyperlink_playground;
@@ -486,6 +487,16 @@ pub const SCREEN_REGISTRY: &[ScreenMeta] = &[
         default_hotkey: None,
         tour_step_hint: None,
     },
+    ScreenMeta {
+        id: ScreenId::FocusInspector,
+        title: "Focus Inspector",
+        short_label: "Focus",
+        category: ScreenCategory::Systems,
+        palette_tags: &["focus", "navigation", "overlay"],
+        blurb: "Focus graph debug overlay on a live layout.",
+        default_hotkey: None,
+        tour_step_hint: None,
+    },
     ScreenMeta {
         id: ScreenId::InlineModeStory,
         title: "Inline Mode",
@@ -1026,6 +1026,7 @@ impl Screen for MousePlayground {
                 code: KeyCode::Tab,
                 kind: KeyEventKind::Press,
                 modifiers,
+                ch: _,
             }) if !modifiers.contains(ftui_core::event::Modifiers::SHIFT) => {
                 self.focus = self.focus.next();
             }
@@ -1125,6 +1126,7 @@ impl Screen for MousePlayground {
                 code: KeyCode::Char('g'),
                 kind: KeyEventKind::Press,
                 modifiers,
+                ch: _,
             }) if self.focus == Focus::Targets
                 && !modifiers.contains(ftui_core::event::Modifiers::SHIFT) =>
             {
@@ -155,12 +155,11 @@ impl Notifications {
                         self.push_info();
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if instructions.contains(mouse.x, mouse.y)
-                        || notifications.contains(mouse.x, mouse.y)
-                    {
-                        self.push_success();
-                    }
+                MouseEventKind::ScrollDown
+                    if (instructions.contains(mouse.x, mouse.y)
+                        || notifications.contains(mouse.x, mouse.y)) =>
+                {
+                    self.push_success();
                 }
                 _ => {}
             }
@@ -392,6 +391,7 @@ mod tests {
             code: KeyCode::Char('s'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&event);
         assert_eq!(screen.toast_counter, 1);
@@ -405,6 +405,7 @@ mod tests {
             code: KeyCode::Char('e'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&event);
         assert_eq!(screen.toast_counter, 1);
@@ -418,6 +419,7 @@ mod tests {
             code: KeyCode::Char('u'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&event);
         assert_eq!(screen.toast_counter, 1);
@@ -515,11 +515,10 @@ impl Screen for Shakespeare {
                         self.scroll_by(-3);
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if self.focus == FocusPanel::Text {
+                MouseEventKind::ScrollDown
+                    if self.focus == FocusPanel::Text => {
                         self.scroll_by(3);
                     }
-                }
                 _ => {}
             }
             return Cmd::None;
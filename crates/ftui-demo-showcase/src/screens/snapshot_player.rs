@@ -2345,6 +2345,7 @@ mod tests {
             code: KeyCode::Char(' '),
             kind: KeyEventKind::Press,
             modifiers: Modifiers::NONE,
+            ch: None,
         });
         player.update(&space_event);
         assert_eq!(player.playback_state, PlaybackState::Playing);
@@ -2355,6 +2356,7 @@ mod tests {
             code: KeyCode::Char('d'),
             kind: KeyEventKind::Press,
             modifiers: Modifiers::NONE,
+            ch: None,
         });
         player.update(&d_event);
         assert_ne!(player.show_diagnostics, initial_diag);
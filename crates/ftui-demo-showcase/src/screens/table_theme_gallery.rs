@@ -1004,6 +1004,7 @@ mod tests {
             code,
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -557,6 +557,8 @@ pub struct TerminalCapabilitiesScreen {
     tick_count: u64,
     /// Cached render area of the matrix panel for mouse hit-testing.
     last_matrix_area: Cell<Rect>,
+    /// Glyph substitution demo override (None = capability-driven).
+    glyph_mode_override: Option<ftui_render::glyph_fallback::GlyphSubstitutionMode>,
 }
 
 impl Default for TerminalCapabilitiesScreen {
@@ -584,6 +586,7 @@ impl TerminalCapabilitiesScreen {
             last_report: None,
             tick_count: 0,
             last_matrix_area: Cell::new(Rect::default()),
+            glyph_mode_override: None,
         }
     }
 
@@ -1220,6 +1223,29 @@ impl TerminalCapabilitiesScreen {
             ),
         ];
 
+        // Glyph fallback demo ('g' cycles override, None = capability
+        // driven): a fixture line as degraded terminals would show it.
+        let policy = {
+            use ftui_render::glyph_fallback::GlyphSubstitution;
+            let mut policy = GlyphSubstitution::from_capabilities(caps);
+            if let Some(mode) = self.glyph_mode_override {
+                policy = policy.mode(mode);
+            }
+            policy
+        };
+        let fixture = "\u{201c}hi\u{201d} \u{1F680} \u{2503}x\u{2503}";
+        let mut lines = lines.to_vec();
+        lines.push(format!(
+            "Glyphs [g]: {:?}{}",
+            policy.mode,
+            if self.glyph_mode_override.is_some() {
+                " (forced)"
+            } else {
+                " (auto)"
+            }
+        ));
+        lines.push(format!("  demo: {}", policy.apply_str(fixture)));
+
         for (idx, line) in lines.iter().enumerate() {
             if idx as u16 >= inner.height {
                 break;
@@ -1726,6 +1752,16 @@ impl Screen for TerminalCapabilitiesScreen {
                         self.diagnostic_log.record(entry);
                     }
                 }
+                KeyCode::Char('g') | KeyCode::Char('G') => {
+                    use ftui_render::glyph_fallback::GlyphSubstitutionMode as Mode;
+                    self.glyph_mode_override = match self.glyph_mode_override {
+                        None => Some(Mode::Passthrough),
+                        Some(Mode::Passthrough) => Some(Mode::Transliterate),
+                        Some(Mode::Transliterate) => Some(Mode::ReplaceUnknown),
+                        Some(Mode::ReplaceUnknown) => None,
+                    };
+                    return Cmd::None;
+                }
                 KeyCode::Char('e') | KeyCode::Char('E') => {
                     let env = self.env_override.clone().unwrap_or_else(EnvSnapshot::read);
                     let mut entry = DiagnosticEntry::new(DiagnosticEventKind::ReportExported);
@@ -1760,16 +1796,14 @@ impl Screen for TerminalCapabilitiesScreen {
                     self.diagnostic_log.record(entry);
                 }
                 // 0 resets to detected profile
-                KeyCode::Char('0') => {
-                    if old_profile.is_some() {
-                        let old_profile_label =
-                            old_profile.map(Self::profile_label).unwrap_or("detected");
-                        self.reset_profile();
-                        let entry = DiagnosticEntry::new(DiagnosticEventKind::ProfileReset)
-                            .with_detail("from", old_profile_label)
-                            .with_detail("via", "key_0");
-                        self.diagnostic_log.record(entry);
-                    }
+                KeyCode::Char('0') if old_profile.is_some() => {
+                    let old_profile_label =
+                        old_profile.map(Self::profile_label).unwrap_or("detected");
+                    self.reset_profile();
+                    let entry = DiagnosticEntry::new(DiagnosticEventKind::ProfileReset)
+                        .with_detail("from", old_profile_label)
+                        .with_detail("via", "key_0");
+                    self.diagnostic_log.record(entry);
                 }
                 _ => {}
             }
@@ -1880,6 +1914,10 @@ impl Screen for TerminalCapabilitiesScreen {
                 key: "E",
                 action: "Export JSONL capability report",
             },
+            HelpEntry {
+                key: "G",
+                action: "Cycle glyph fallback mode (demo degraded terminals)",
+            },
             HelpEntry {
                 key: "Click",
                 action: "Select capability row",
@@ -2126,6 +2164,7 @@ mod tests {
             code: KeyCode::Tab,
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         });
         screen.update(&event);
 
@@ -2149,6 +2188,7 @@ mod tests {
             code: KeyCode::Down,
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         });
         screen.update(&event);
 
@@ -2173,6 +2213,7 @@ mod tests {
             code: KeyCode::Char('p'),
             kind: KeyEventKind::Press,
             modifiers: ftui_core::event::Modifiers::NONE,
+            ch: None,
         });
         screen.update(&event);
 
@@ -2423,6 +2464,7 @@ mod tests {
             code: KeyCode::Char('1'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&key1);
         assert_eq!(screen.profile_override, Some(TerminalProfile::Modern));
@@ -2432,6 +2474,7 @@ mod tests {
             code: KeyCode::Char('0'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&key0);
         assert!(screen.profile_override.is_none());
@@ -2444,6 +2487,7 @@ mod tests {
             code: KeyCode::Char('5'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&key5);
         // Profile order index 5 = Dumb (0=Detected,1=Modern,2=Xterm256,3=Xterm,4=Vt100,5=Dumb)
@@ -1119,6 +1119,7 @@ impl Screen for ThemeStudioDemo {
             code,
             kind: KeyEventKind::Press,
             modifiers,
+            ch: _,
         }) = event
         {
             match code {
@@ -1412,6 +1413,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1420,6 +1422,7 @@ mod tests {
             code,
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -2012,6 +2015,7 @@ mod tests {
             code: KeyCode::Down,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Release,
+            ch: None,
         });
         demo.update(&release_event);
         assert_eq!(
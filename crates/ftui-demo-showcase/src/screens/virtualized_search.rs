@@ -1222,12 +1222,9 @@ impl VirtualizedSearch {
                         self.ensure_visible();
                     }
                 }
-                MouseEventKind::ScrollDown => {
-                    if list_area.contains(mouse.x, mouse.y) {
-                        self.selected =
-                            (self.selected + 3).min(self.filtered.len().saturating_sub(1));
-                        self.ensure_visible();
-                    }
+                MouseEventKind::ScrollDown if list_area.contains(mouse.x, mouse.y) => {
+                    self.selected = (self.selected + 3).min(self.filtered.len().saturating_sub(1));
+                    self.ensure_visible();
                 }
                 _ => {}
             }
@@ -1972,6 +1969,7 @@ mod tests {
             code: KeyCode::Char('/'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = screen.update(&event);
 
@@ -1999,6 +1997,7 @@ mod tests {
             code: KeyCode::Char('a'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = screen.update(&event);
 
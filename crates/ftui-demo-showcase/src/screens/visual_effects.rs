@@ -4772,11 +4772,10 @@ impl Screen for VisualEffectsScreen {
                                     self.shape3d.shape = self.shape3d.shape.next();
                                 }
                             }
-                            EffectType::Plasma => {
-                                if is_press {
+                            EffectType::Plasma
+                                if is_press => {
                                     self.cycle_plasma_palette();
                                 }
-                            }
                             _ => {}
                         },
                         _ => {}
@@ -4857,12 +4856,11 @@ impl Screen for VisualEffectsScreen {
                             }
                         }
                         // 'c' jumps to combinations tab
-                        KeyCode::Char('c') => {
-                            if is_press {
+                        KeyCode::Char('c')
+                            if is_press => {
                                 self.set_text_effects_tab(TextEffectsTab::Combinations);
                                 self.start_text_effects_transition();
                             }
-                        }
                         _ => {}
                     }
                 }
@@ -113,12 +113,11 @@ impl VoiOverlayScreen {
                     self.selected_ledger_idx = self.selected_ledger_idx.saturating_add(1);
                 }
             }
-            MouseEventKind::ScrollUp => {
-                if self.layout_ledger.get().contains(x, y)
-                    || self.focused_section == Some(VoiSection::Ledger)
-                {
-                    self.selected_ledger_idx = self.selected_ledger_idx.saturating_sub(1);
-                }
+            MouseEventKind::ScrollUp
+                if (self.layout_ledger.get().contains(x, y)
+                    || self.focused_section == Some(VoiSection::Ledger)) =>
+            {
+                self.selected_ledger_idx = self.selected_ledger_idx.saturating_sub(1);
             }
             _ => {}
         }
@@ -563,6 +562,7 @@ mod tests {
             code: KeyCode::Char('r'),
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         screen.update(&event);
         assert_eq!(screen.tick, 0);
@@ -608,6 +608,7 @@ mod tests {
             code,
             modifiers: Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -2127,6 +2127,7 @@ mod tests {
             code: KeyCode::Char('j'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         gallery.update(&ev);
         assert_eq!(gallery.current_section, 1);
@@ -2140,6 +2141,7 @@ mod tests {
             code: KeyCode::Char('k'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         gallery.update(&ev_back);
         assert_eq!(gallery.current_section, 1);
@@ -2154,6 +2156,7 @@ mod tests {
             code: KeyCode::Down,
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         gallery.update(&ev_down);
         assert_eq!(gallery.current_section, 1);
@@ -2162,6 +2165,7 @@ mod tests {
             code: KeyCode::Up,
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         gallery.update(&ev_up);
         assert_eq!(gallery.current_section, 0);
@@ -2177,6 +2181,7 @@ mod tests {
             code: KeyCode::Char('k'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         gallery.update(&ev_back);
         assert_eq!(gallery.current_section, SECTION_COUNT - 1);
@@ -2186,6 +2191,7 @@ mod tests {
             code: KeyCode::Char('j'),
             modifiers: Default::default(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         gallery.update(&ev_fwd);
         assert_eq!(gallery.current_section, 0);
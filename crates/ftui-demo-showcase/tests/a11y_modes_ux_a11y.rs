@@ -62,6 +62,7 @@ fn key_event(code: KeyCode, modifiers: Modifiers) -> Event {
         code,
         modifiers,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -1186,6 +1186,7 @@ fn shift_key(ch: char) -> Event {
         code: KeyCode::Char(ch),
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -1194,6 +1195,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -73,6 +73,7 @@ fn key_press(code: KeyCode, modifiers: Modifiers) -> Event {
         code,
         modifiers,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -59,6 +59,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -82,6 +82,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -63,6 +63,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         kind: KeyEventKind::Press,
         modifiers: Modifiers::empty(),
+        ch: None,
     })
 }
 
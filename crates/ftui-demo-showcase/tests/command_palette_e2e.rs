@@ -37,6 +37,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -45,6 +46,7 @@ fn ctrl_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -53,6 +53,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -61,6 +62,7 @@ fn shift_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -58,6 +58,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -66,6 +67,7 @@ fn shift_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -14,6 +14,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -22,6 +23,7 @@ fn press_mod(code: KeyCode, modifiers: Modifiers) -> Event {
         code,
         modifiers,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -36,6 +36,7 @@ fn key(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -48,6 +49,7 @@ fn backtab() -> Event {
         code: KeyCode::BackTab,
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -80,6 +80,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         kind: KeyEventKind::Press,
         modifiers: Modifiers::empty(),
+        ch: None,
     })
 }
 
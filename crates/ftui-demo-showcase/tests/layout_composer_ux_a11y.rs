@@ -74,6 +74,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -82,6 +83,7 @@ fn shift_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -48,6 +48,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -32,6 +32,7 @@ fn key(ch: char) -> Event {
         code: KeyCode::Char(ch),
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
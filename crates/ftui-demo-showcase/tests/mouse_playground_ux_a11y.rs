@@ -59,6 +59,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -67,6 +68,7 @@ fn key_press_shift(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
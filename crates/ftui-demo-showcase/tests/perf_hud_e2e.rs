@@ -50,6 +50,7 @@ fn ctrl_press(ch: char) -> Event {
         code: KeyCode::Char(ch),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -58,6 +58,7 @@ fn ctrl_press(ch: char) -> Event {
         code: KeyCode::Char(ch),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
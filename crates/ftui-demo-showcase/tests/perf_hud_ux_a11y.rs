@@ -77,6 +77,7 @@ fn ctrl_key(ch: char) -> Event {
         code: KeyCode::Char(ch),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -86,6 +87,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
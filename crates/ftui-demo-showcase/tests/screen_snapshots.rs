@@ -40,6 +40,7 @@ fn press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -48,6 +49,7 @@ fn ctrl_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -1276,6 +1278,7 @@ fn app_palette_filtered_80x24() {
         code: KeyCode::Char('1'),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     });
     app.update(AppMsg::from(ctrl_1));
     snapshot_app(&mut app, 80, 24, "app_palette_filtered_80x24");
@@ -1290,6 +1293,7 @@ fn app_palette_filtered_120x40() {
         code: KeyCode::Char('1'),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     });
     app.update(AppMsg::from(ctrl_1));
     snapshot_app(&mut app, 120, 40, "app_palette_filtered_120x40");
@@ -1304,12 +1308,14 @@ fn app_palette_favorites_80x24() {
         code: KeyCode::Char('f'),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     });
     app.update(AppMsg::from(ctrl_f));
     let ctrl_shift_f = Event::Key(KeyEvent {
         code: KeyCode::Char('F'),
         modifiers: Modifiers::CTRL | Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     });
     app.update(AppMsg::from(ctrl_shift_f));
     snapshot_app(&mut app, 80, 24, "app_palette_favorites_80x24");
@@ -1324,12 +1330,14 @@ fn app_palette_favorites_120x40() {
         code: KeyCode::Char('f'),
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     });
     app.update(AppMsg::from(ctrl_f));
     let ctrl_shift_f = Event::Key(KeyEvent {
         code: KeyCode::Char('F'),
         modifiers: Modifiers::CTRL | Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     });
     app.update(AppMsg::from(ctrl_shift_f));
     snapshot_app(&mut app, 120, 40, "app_palette_favorites_120x40");
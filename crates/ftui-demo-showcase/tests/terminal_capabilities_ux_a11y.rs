@@ -52,6 +52,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         kind: KeyEventKind::Press,
         modifiers: ftui_core::event::Modifiers::empty(),
+        ch: None,
     })
 }
 
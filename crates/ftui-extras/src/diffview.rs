@@ -0,0 +1,923 @@
+#![forbid(unsafe_code)]
+
+//! Text diff primitives and review-tool rendering.
+//!
+//! Line-level Myers diff over two texts producing hunks, optional
+//! word-level intra-line refinement (changed spans within modified
+//! lines), and two render components: an **inline** view (unified,
+//! `+`/`-` gutter, themed colors) and a **side-by-side** view (aligned
+//! columns with filler rows, a center gutter with change markers).
+//! Both are virtualized — row *references* are compact, text is
+//! materialized only for the visible window — and support hunk folding
+//! (`… N unchanged lines` rows, expandable) plus next/prev-change
+//! navigation.
+//!
+//! The diff is deterministic and bounded: beyond
+//! [`MYERS_INPUT_LIMIT`] total lines (or when the edit-distance search
+//! exceeds its band) it falls back to a coarse common-prefix/suffix
+//! diff instead of exploding.
+
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+use ftui_render::cell::Cell;
+
+/// Draw `text` at `(x, y)` clipped to `max_x`, applying `style`.
+fn draw_text_clipped(frame: &mut Frame, x: u16, y: u16, text: &str, style: Style, max_x: u16) {
+    for (cursor, ch) in (x..max_x).zip(text.chars()) {
+        let mut cell = Cell::from_char(ch);
+        if let Some(fg) = style.fg {
+            cell = cell.with_fg(fg);
+        }
+        if let Some(bg) = style.bg {
+            cell = cell.with_bg(bg);
+        }
+        frame.buffer.set(cursor, y, cell);
+    }
+}
+
+/// Myers search is attempted only below this many total input lines.
+pub const MYERS_INPUT_LIMIT: usize = 20_000;
+/// Maximum edit distance explored before falling back to the coarse diff.
+const MYERS_D_LIMIT: usize = 2_000;
+
+/// One diffed line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Context,
+    Removed,
+    Added,
+}
+
+/// A line in the diff: indices into the old/new texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// Index into the old text (None for added lines).
+    pub old_index: Option<usize>,
+    /// Index into the new text (None for removed lines).
+    pub new_index: Option<usize>,
+}
+
+/// Compute a line-level diff (see the module docs for bounds).
+#[must_use]
+pub fn line_diff(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    // Common prefix/suffix trim keeps the Myers core small.
+    let mut prefix = 0;
+    while prefix < old.len() && prefix < new.len() && old[prefix] == new[prefix] {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < old.len() - prefix
+        && suffix < new.len() - prefix
+        && old[old.len() - 1 - suffix] == new[new.len() - 1 - suffix]
+    {
+        suffix += 1;
+    }
+    let core_old = &old[prefix..old.len() - suffix];
+    let core_new = &new[prefix..new.len() - suffix];
+
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    for idx in 0..prefix {
+        lines.push(DiffLine {
+            kind: DiffLineKind::Context,
+            old_index: Some(idx),
+            new_index: Some(idx),
+        });
+    }
+
+    let core = if core_old.len() + core_new.len() > MYERS_INPUT_LIMIT {
+        None
+    } else {
+        myers(core_old, core_new)
+    };
+    match core {
+        Some(ops) => {
+            for op in ops {
+                lines.push(DiffLine {
+                    kind: op.kind,
+                    old_index: op.old_index.map(|i| i + prefix),
+                    new_index: op.new_index.map(|i| i + prefix),
+                });
+            }
+        }
+        None => {
+            // Coarse fallback: whole core is a replace block.
+            for idx in 0..core_old.len() {
+                lines.push(DiffLine {
+                    kind: DiffLineKind::Removed,
+                    old_index: Some(prefix + idx),
+                    new_index: None,
+                });
+            }
+            for idx in 0..core_new.len() {
+                lines.push(DiffLine {
+                    kind: DiffLineKind::Added,
+                    old_index: None,
+                    new_index: Some(prefix + idx),
+                });
+            }
+        }
+    }
+
+    for offset in (0..suffix).rev() {
+        lines.push(DiffLine {
+            kind: DiffLineKind::Context,
+            old_index: Some(old.len() - 1 - offset),
+            new_index: Some(new.len() - 1 - offset),
+        });
+    }
+    lines
+}
+
+/// Greedy Myers O(ND); `None` when the band limit is exceeded.
+fn myers(old: &[&str], new: &[&str]) -> Option<Vec<DiffLine>> {
+    let (n, m) = (old.len(), new.len());
+    if n == 0 || m == 0 {
+        let mut out = Vec::new();
+        for idx in 0..n {
+            out.push(DiffLine {
+                kind: DiffLineKind::Removed,
+                old_index: Some(idx),
+                new_index: None,
+            });
+        }
+        for idx in 0..m {
+            out.push(DiffLine {
+                kind: DiffLineKind::Added,
+                old_index: None,
+                new_index: Some(idx),
+            });
+        }
+        return Some(out);
+    }
+    let max_d = (n + m).min(MYERS_D_LIMIT);
+    let offset = max_d;
+    let width = 2 * max_d + 1;
+    let mut v = vec![0usize; width];
+    // Trace of V per D for backtracking.
+    let mut trace: Vec<Vec<usize>> = Vec::new();
+
+    'outer: {
+        for d in 0..=max_d {
+            trace.push(v.clone());
+            let mut k = -(d as isize);
+            while k <= d as isize {
+                let idx = (k + offset as isize) as usize;
+                let mut x = if k == -(d as isize) || (k != d as isize && v[idx - 1] < v[idx + 1]) {
+                    v[idx + 1]
+                } else {
+                    v[idx - 1] + 1
+                };
+                let mut y = (x as isize - k) as usize;
+                while x < n && y < m && old[x] == new[y] {
+                    x += 1;
+                    y += 1;
+                }
+                v[idx] = x;
+                if x >= n && y >= m {
+                    break 'outer;
+                }
+                k += 2;
+            }
+        }
+        return None; // band exhausted
+    }
+
+    // Backtrack (canonical Myers): trace[d] is V at the start of round
+    // d, so D = trace.len() - 1 is the round that reached (n, m).
+    let mut ops_rev: Vec<DiffLine> = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (1..trace.len()).rev() {
+        let v = &trace[d];
+        let k = x as isize - y as isize;
+        let prev_k = if k == -(d as isize)
+            || (k != d as isize
+                && v[(k - 1 + offset as isize) as usize] < v[(k + 1 + offset as isize) as usize])
+        {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset as isize) as usize];
+        let prev_y = (prev_x as isize - prev_k) as usize;
+
+        // Diagonal snake back to the edit point.
+        while x > prev_x && y > prev_y && x > 0 && y > 0 {
+            x -= 1;
+            y -= 1;
+            ops_rev.push(DiffLine {
+                kind: DiffLineKind::Context,
+                old_index: Some(x),
+                new_index: Some(y),
+            });
+        }
+        if prev_k == k + 1 {
+            // Insertion: consumed new[prev_y].
+            ops_rev.push(DiffLine {
+                kind: DiffLineKind::Added,
+                old_index: None,
+                new_index: Some(prev_y),
+            });
+        } else {
+            // Deletion: consumed old[prev_x].
+            ops_rev.push(DiffLine {
+                kind: DiffLineKind::Removed,
+                old_index: Some(prev_x),
+                new_index: None,
+            });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    // Leading diagonal before the first edit.
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        ops_rev.push(DiffLine {
+            kind: DiffLineKind::Context,
+            old_index: Some(x),
+            new_index: Some(y),
+        });
+    }
+    ops_rev.reverse();
+    Some(ops_rev)
+}
+
+// ─── Word-level refinement ───────────────────────────────────────────────────
+
+/// Changed character spans within a modified old/new line pair.
+///
+/// Tokenizes on word boundaries and LCS-matches tokens; returns
+/// `(old_spans, new_spans)` as char ranges covering changed tokens.
+#[must_use]
+pub fn refine_pair(
+    old_line: &str,
+    new_line: &str,
+) -> (Vec<std::ops::Range<usize>>, Vec<std::ops::Range<usize>>) {
+    let old_tokens = tokenize(old_line);
+    let new_tokens = tokenize(new_line);
+    let n = old_tokens.len();
+    let m = new_tokens.len();
+    // Bounded: the quadratic token LCS only runs for ordinary lines; a
+    // pathological line (minified blob) degrades to whole-line spans.
+    const REFINE_TOKEN_PRODUCT_LIMIT: usize = 65_536;
+    if n.saturating_mul(m) > REFINE_TOKEN_PRODUCT_LIMIT {
+        let whole = |line: &str| {
+            let len = line.chars().count();
+            if len == 0 {
+                Vec::new()
+            } else {
+                std::iter::once(0..len).collect()
+            }
+        };
+        return (whole(old_line), whole(new_line));
+    }
+    // Token-level LCS table (deterministic).
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[at(i, j)] =
+                if token_text(old_line, &old_tokens[i]) == token_text(new_line, &new_tokens[j]) {
+                    lcs[at(i + 1, j + 1)] + 1
+                } else {
+                    lcs[at(i + 1, j)].max(lcs[at(i, j + 1)])
+                };
+        }
+    }
+    let mut old_spans = Vec::new();
+    let mut new_spans = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if token_text(old_line, &old_tokens[i]) == token_text(new_line, &new_tokens[j]) {
+            i += 1;
+            j += 1;
+        } else if lcs[at(i + 1, j)] >= lcs[at(i, j + 1)] {
+            push_span(&mut old_spans, old_tokens[i].clone());
+            i += 1;
+        } else {
+            push_span(&mut new_spans, new_tokens[j].clone());
+            j += 1;
+        }
+    }
+    for token in &old_tokens[i..] {
+        push_span(&mut old_spans, token.clone());
+    }
+    for token in &new_tokens[j..] {
+        push_span(&mut new_spans, token.clone());
+    }
+    (old_spans, new_spans)
+}
+
+/// Merge adjacent/overlapping spans as they are appended.
+fn push_span(spans: &mut Vec<std::ops::Range<usize>>, span: std::ops::Range<usize>) {
+    if let Some(last) = spans.last_mut()
+        && span.start <= last.end
+    {
+        last.end = last.end.max(span.end);
+        return;
+    }
+    spans.push(span);
+}
+
+/// Char-index token ranges: runs of alphanumerics or single symbols;
+/// whitespace separates but is attached to no token.
+fn tokenize(line: &str) -> Vec<std::ops::Range<usize>> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+    while idx < chars.len() {
+        if chars[idx].is_whitespace() {
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        if chars[idx].is_alphanumeric() || chars[idx] == '_' {
+            while idx < chars.len() && (chars[idx].is_alphanumeric() || chars[idx] == '_') {
+                idx += 1;
+            }
+        } else {
+            idx += 1;
+        }
+        tokens.push(start..idx);
+    }
+    tokens
+}
+
+fn token_text(line: &str, range: &std::ops::Range<usize>) -> String {
+    line.chars()
+        .skip(range.start)
+        .take(range.end - range.start)
+        .collect()
+}
+
+// ─── Row models (folding + virtualization) ───────────────────────────────────
+
+/// A compact reference to one visual row; text materializes on demand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffRow {
+    Line(DiffLine),
+    /// `count` unchanged lines collapsed; `start` indexes into the
+    /// full diff-line list for expansion.
+    Fold {
+        start: usize,
+        count: usize,
+    },
+}
+
+/// Shared fold/cursor/scroll state for both views.
+#[derive(Debug, Clone, Default)]
+pub struct DiffViewState {
+    /// Fold start indices the user expanded.
+    expanded: Vec<usize>,
+    /// Cursor row (index into the current row list).
+    pub cursor: usize,
+    /// First visible row (virtualization window).
+    pub scroll: usize,
+}
+
+impl DiffViewState {
+    /// Expand the fold starting at diff-line index `start`.
+    pub fn expand(&mut self, start: usize) {
+        if !self.expanded.contains(&start) {
+            self.expanded.push(start);
+        }
+    }
+
+    /// Collapse a previously expanded fold.
+    pub fn collapse(&mut self, start: usize) {
+        self.expanded.retain(|&s| s != start);
+    }
+}
+
+/// Build the folded row list: runs of context longer than
+/// `2 * context_lines + 1` collapse into [`DiffRow::Fold`] entries
+/// (keeping `context_lines` on each side), unless expanded.
+#[must_use]
+pub fn folded_rows(
+    lines: &[DiffLine],
+    context_lines: usize,
+    state: &DiffViewState,
+) -> Vec<DiffRow> {
+    let mut rows = Vec::new();
+    let mut idx = 0;
+    while idx < lines.len() {
+        if lines[idx].kind != DiffLineKind::Context {
+            rows.push(DiffRow::Line(lines[idx]));
+            idx += 1;
+            continue;
+        }
+        let run_start = idx;
+        while idx < lines.len() && lines[idx].kind == DiffLineKind::Context {
+            idx += 1;
+        }
+        let run_len = idx - run_start;
+        let at_start = run_start == 0;
+        let at_end = idx == lines.len();
+        let lead = if at_start { 0 } else { context_lines };
+        let tail = if at_end { 0 } else { context_lines };
+        let foldable = run_len > lead + tail + 1;
+        if !foldable || state.expanded.contains(&(run_start + lead)) {
+            for line in &lines[run_start..idx] {
+                rows.push(DiffRow::Line(*line));
+            }
+        } else {
+            for line in &lines[run_start..run_start + lead] {
+                rows.push(DiffRow::Line(*line));
+            }
+            rows.push(DiffRow::Fold {
+                start: run_start + lead,
+                count: run_len - lead - tail,
+            });
+            for line in &lines[idx - tail..idx] {
+                rows.push(DiffRow::Line(*line));
+            }
+        }
+    }
+    rows
+}
+
+/// Index of the next row after `from` that is a change (for n/p
+/// navigation); wraps to the first change.
+#[must_use]
+pub fn next_change(rows: &[DiffRow], from: usize) -> Option<usize> {
+    let is_change = |row: &DiffRow| {
+        matches!(
+            row,
+            DiffRow::Line(DiffLine {
+                kind: DiffLineKind::Added | DiffLineKind::Removed,
+                ..
+            })
+        )
+    };
+    rows.iter()
+        .enumerate()
+        .skip(from + 1)
+        .find(|(_, row)| is_change(row))
+        .map(|(idx, _)| idx)
+        .or_else(|| rows.iter().position(is_change))
+}
+
+/// Previous change before `from`, wrapping to the last change.
+#[must_use]
+pub fn prev_change(rows: &[DiffRow], from: usize) -> Option<usize> {
+    let is_change = |row: &DiffRow| {
+        matches!(
+            row,
+            DiffRow::Line(DiffLine {
+                kind: DiffLineKind::Added | DiffLineKind::Removed,
+                ..
+            })
+        )
+    };
+    rows[..from.min(rows.len())]
+        .iter()
+        .rposition(is_change)
+        .or_else(|| rows.iter().rposition(is_change))
+}
+
+/// Side-by-side alignment: pairs of old/new rows with fillers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideBySideRow {
+    /// `(old_index, new_index)` — either side `None` renders filler.
+    Pair {
+        old_index: Option<usize>,
+        new_index: Option<usize>,
+        kind: DiffLineKind,
+    },
+    Fold {
+        start: usize,
+        count: usize,
+    },
+}
+
+/// Align folded rows into side-by-side pairs: removed/added runs in the
+/// same hunk pair up; the longer side gets filler on the other.
+#[must_use]
+pub fn side_by_side_rows(rows: &[DiffRow]) -> Vec<SideBySideRow> {
+    let mut out = Vec::new();
+    let mut idx = 0;
+    while idx < rows.len() {
+        match rows[idx] {
+            DiffRow::Fold { start, count } => {
+                out.push(SideBySideRow::Fold { start, count });
+                idx += 1;
+            }
+            DiffRow::Line(line) if line.kind == DiffLineKind::Context => {
+                out.push(SideBySideRow::Pair {
+                    old_index: line.old_index,
+                    new_index: line.new_index,
+                    kind: DiffLineKind::Context,
+                });
+                idx += 1;
+            }
+            DiffRow::Line(_) => {
+                // Collect the removed run then the added run.
+                let mut removed = Vec::new();
+                let mut added = Vec::new();
+                while idx < rows.len() {
+                    match rows[idx] {
+                        DiffRow::Line(line) if line.kind == DiffLineKind::Removed => {
+                            removed.push(line.old_index);
+                            idx += 1;
+                        }
+                        DiffRow::Line(line) if line.kind == DiffLineKind::Added => {
+                            added.push(line.new_index);
+                            idx += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                let rows_needed = removed.len().max(added.len());
+                for pair_idx in 0..rows_needed {
+                    let old_index = removed.get(pair_idx).copied().flatten();
+                    let new_index = added.get(pair_idx).copied().flatten();
+                    let kind = match (old_index, new_index) {
+                        (Some(_), None) => DiffLineKind::Removed,
+                        (None, Some(_)) => DiffLineKind::Added,
+                        _ => DiffLineKind::Context, // changed pair
+                    };
+                    out.push(SideBySideRow::Pair {
+                        old_index,
+                        new_index,
+                        kind,
+                    });
+                }
+            }
+        }
+    }
+    out
+}
+
+// ─── Rendering ───────────────────────────────────────────────────────────────
+
+/// Theme styles for diff rendering.
+#[derive(Debug, Clone, Default)]
+pub struct DiffTheme {
+    pub context: Style,
+    pub added: Style,
+    pub removed: Style,
+    pub fold: Style,
+    pub gutter: Style,
+}
+
+/// Render the inline (unified) view over the virtualization window
+/// `state.scroll .. scroll + area.height`.
+pub fn render_inline(
+    frame: &mut Frame,
+    area: Rect,
+    old: &[&str],
+    new: &[&str],
+    rows: &[DiffRow],
+    state: &DiffViewState,
+    theme: &DiffTheme,
+) {
+    let window = rows
+        .iter()
+        .skip(state.scroll)
+        .take(usize::from(area.height));
+    for (screen_row, row) in window.enumerate() {
+        let y = area.y + screen_row as u16;
+        match row {
+            DiffRow::Fold { count, .. } => {
+                draw_text_clipped(
+                    frame,
+                    area.x,
+                    y,
+                    &format!("\u{2026} {count} unchanged lines"),
+                    theme.fold,
+                    area.right(),
+                );
+            }
+            DiffRow::Line(line) => {
+                let (marker, style, text) = match line.kind {
+                    DiffLineKind::Context => (
+                        ' ',
+                        theme.context,
+                        line.old_index
+                            .and_then(|i| old.get(i))
+                            .copied()
+                            .unwrap_or(""),
+                    ),
+                    DiffLineKind::Removed => (
+                        '-',
+                        theme.removed,
+                        line.old_index
+                            .and_then(|i| old.get(i))
+                            .copied()
+                            .unwrap_or(""),
+                    ),
+                    DiffLineKind::Added => (
+                        '+',
+                        theme.added,
+                        line.new_index
+                            .and_then(|i| new.get(i))
+                            .copied()
+                            .unwrap_or(""),
+                    ),
+                };
+                draw_text_clipped(
+                    frame,
+                    area.x,
+                    y,
+                    &marker.to_string(),
+                    theme.gutter,
+                    area.right(),
+                );
+                draw_text_clipped(frame, area.x + 2, y, text, style, area.right());
+            }
+        }
+    }
+}
+
+/// Render the side-by-side view (two columns + center gutter).
+pub fn render_side_by_side(
+    frame: &mut Frame,
+    area: Rect,
+    old: &[&str],
+    new: &[&str],
+    rows: &[SideBySideRow],
+    state: &DiffViewState,
+    theme: &DiffTheme,
+) {
+    if area.width < 7 {
+        return;
+    }
+    let column = (area.width - 3) / 2;
+    let gutter_x = area.x + column;
+    let right_x = gutter_x + 3;
+    let window = rows
+        .iter()
+        .skip(state.scroll)
+        .take(usize::from(area.height));
+    for (screen_row, row) in window.enumerate() {
+        let y = area.y + screen_row as u16;
+        match row {
+            SideBySideRow::Fold { count, .. } => {
+                draw_text_clipped(
+                    frame,
+                    area.x,
+                    y,
+                    &format!("\u{2026} {count} unchanged lines"),
+                    theme.fold,
+                    area.right(),
+                );
+            }
+            SideBySideRow::Pair {
+                old_index,
+                new_index,
+                kind,
+            } => {
+                let marker = match (old_index, new_index, kind) {
+                    (Some(_), Some(_), DiffLineKind::Context) => ' ',
+                    (Some(_), Some(_), _) | (Some(_), None, _) if new_index.is_none() => '<',
+                    (None, Some(_), _) => '>',
+                    _ => '|',
+                };
+                if let Some(idx) = old_index
+                    && let Some(text) = old.get(*idx)
+                {
+                    let style = if *kind == DiffLineKind::Context && new_index.is_some() {
+                        theme.context
+                    } else {
+                        theme.removed
+                    };
+                    draw_text_clipped(frame, area.x, y, text, style, gutter_x);
+                }
+                draw_text_clipped(
+                    frame,
+                    gutter_x + 1,
+                    y,
+                    &marker.to_string(),
+                    theme.gutter,
+                    gutter_x + 2,
+                );
+                if let Some(idx) = new_index
+                    && let Some(text) = new.get(*idx)
+                {
+                    let style = if *kind == DiffLineKind::Context && old_index.is_some() {
+                        theme.context
+                    } else {
+                        theme.added
+                    };
+                    draw_text_clipped(frame, right_x, y, text, style, area.right());
+                }
+            }
+        }
+    }
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(lines: &[DiffLine]) -> String {
+        lines
+            .iter()
+            .map(|l| match l.kind {
+                DiffLineKind::Context => ' ',
+                DiffLineKind::Removed => '-',
+                DiffLineKind::Added => '+',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn golden_simple_edit() {
+        let old = ["a", "b", "c", "d"];
+        let new = ["a", "x", "c", "d"];
+        let diff = line_diff(&old, &new);
+        assert_eq!(kinds(&diff), " -+  ");
+        assert_eq!(diff[1].old_index, Some(1));
+        assert_eq!(diff[2].new_index, Some(1));
+    }
+
+    #[test]
+    fn golden_move_looking_edit_is_remove_plus_add() {
+        // "b" moves to the end: a minimal diff reports -b then +b, never
+        // a phantom match that reorders context.
+        let old = ["a", "b", "c", "d"];
+        let new = ["a", "c", "d", "b"];
+        let diff = line_diff(&old, &new);
+        assert_eq!(kinds(&diff), " -  +");
+        let removed = diff
+            .iter()
+            .find(|l| l.kind == DiffLineKind::Removed)
+            .unwrap();
+        assert_eq!(removed.old_index, Some(1), "the moved line is removed…");
+        let added = diff.iter().find(|l| l.kind == DiffLineKind::Added).unwrap();
+        assert_eq!(added.new_index, Some(3), "…and re-added at its new spot");
+    }
+
+    #[test]
+    fn deterministic_across_runs() {
+        let old: Vec<String> = (0..200).map(|i| format!("line {i}")).collect();
+        let mut new = old.clone();
+        new.remove(50);
+        new.insert(120, "inserted".to_string());
+        let old_refs: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new_refs: Vec<&str> = new.iter().map(String::as_str).collect();
+        let first = line_diff(&old_refs, &new_refs);
+        for _ in 0..5 {
+            assert_eq!(line_diff(&old_refs, &new_refs), first);
+        }
+    }
+
+    #[test]
+    fn oversized_input_falls_back_coarsely() {
+        let old: Vec<String> = (0..MYERS_INPUT_LIMIT).map(|i| format!("o{i}")).collect();
+        let new: Vec<String> = (0..MYERS_INPUT_LIMIT).map(|i| format!("n{i}")).collect();
+        let old_refs: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new_refs: Vec<&str> = new.iter().map(String::as_str).collect();
+        let diff = line_diff(&old_refs, &new_refs);
+        // Bounded: all removes then all adds, no explosion.
+        assert_eq!(diff.len(), old.len() + new.len());
+        assert_eq!(diff[0].kind, DiffLineKind::Removed);
+        assert_eq!(diff.last().unwrap().kind, DiffLineKind::Added);
+    }
+
+    #[test]
+    fn word_refinement_marks_changed_tokens_only() {
+        let (old_spans, new_spans) =
+            refine_pair("let count = compute(x);", "let total = compute(x);");
+        assert_eq!(old_spans, vec![4..9], "'count'");
+        assert_eq!(new_spans, vec![4..9], "'total'");
+
+        let (old_spans, new_spans) = refine_pair("same same", "same same tail");
+        assert!(old_spans.is_empty());
+        assert_eq!(new_spans, vec![10..14], "'tail'");
+    }
+
+    #[test]
+    fn refinement_degrades_on_pathological_lines() {
+        let old_line: String = (0..3_000).map(|i| format!("t{i} ")).collect();
+        let new_line: String = (0..3_000).map(|i| format!("u{i} ")).collect();
+        let start = std::time::Instant::now();
+        let (old_spans, new_spans) = refine_pair(&old_line, &new_line);
+        assert!(
+            start.elapsed() < std::time::Duration::from_millis(500),
+            "bounded: {:?}",
+            start.elapsed()
+        );
+        assert_eq!(old_spans.len(), 1, "whole-line span fallback");
+        assert_eq!(new_spans.len(), 1);
+    }
+
+    #[test]
+    fn folding_row_accounting() {
+        let old: Vec<String> = (0..60).map(|i| format!("l{i}")).collect();
+        let mut new = old.clone();
+        new[30] = "CHANGED".to_string();
+        let old_refs: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new_refs: Vec<&str> = new.iter().map(String::as_str).collect();
+        let diff = line_diff(&old_refs, &new_refs);
+
+        let mut state = DiffViewState::default();
+        let rows = folded_rows(&diff, 3, &state);
+        // Leading fold (27 hidden) + 3 ctx + -/+ + 3 ctx + trailing fold.
+        let folds: Vec<(usize, usize)> = rows
+            .iter()
+            .filter_map(|r| match r {
+                DiffRow::Fold { start, count } => Some((*start, *count)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(folds.len(), 2, "{rows:?}");
+        assert_eq!(
+            folds[0].1 + folds[1].1 + (rows.len() - 2),
+            diff.len() + 2 - 2,
+            "hidden + visible rows account for every line"
+        );
+
+        // Expanding the first fold restores its lines.
+        state.expand(folds[0].0);
+        let expanded = folded_rows(&diff, 3, &state);
+        assert_eq!(expanded.len(), rows.len() - 1 + folds[0].1);
+    }
+
+    #[test]
+    fn navigation_next_prev_change() {
+        let old = ["a", "b", "c", "d", "e"];
+        let new = ["a", "B", "c", "d", "E"];
+        let diff = line_diff(&old, &new);
+        let rows = folded_rows(&diff, 10, &DiffViewState::default());
+        let first = next_change(&rows, 0).unwrap();
+        let second = next_change(&rows, first).unwrap();
+        assert!(second > first);
+        // prev from just past the second change finds it again; prev
+        // from the second change finds the first hunk's other line.
+        assert_eq!(prev_change(&rows, second + 1), Some(second));
+        assert!(prev_change(&rows, first).is_some());
+        // Wraps.
+        let last = rows.len() - 1;
+        assert!(next_change(&rows, last).is_some(), "wraps to first change");
+    }
+
+    #[test]
+    fn side_by_side_alignment_with_unequal_hunks() {
+        let old = ["ctx", "r1", "r2", "r3", "tail"];
+        let new = ["ctx", "a1", "tail"];
+        let diff = line_diff(&old, &new);
+        let rows = folded_rows(&diff, 10, &DiffViewState::default());
+        let pairs = side_by_side_rows(&rows);
+        // ctx + 3 aligned rows (r1|a1, r2|filler, r3|filler) + tail.
+        assert_eq!(pairs.len(), 5, "{pairs:?}");
+        let SideBySideRow::Pair {
+            old_index,
+            new_index,
+            ..
+        } = pairs[1]
+        else {
+            panic!("{pairs:?}");
+        };
+        assert_eq!((old_index, new_index), (Some(1), Some(1)), "changed pair");
+        let SideBySideRow::Pair {
+            old_index,
+            new_index,
+            ..
+        } = pairs[2]
+        else {
+            panic!("{pairs:?}");
+        };
+        assert_eq!((old_index, new_index), (Some(2), None), "filler right");
+    }
+
+    #[test]
+    fn virtualization_window_on_50k_lines() {
+        let old: Vec<String> = (0..50_000).map(|i| format!("line {i}")).collect();
+        let mut new = old.clone();
+        new[25_000] = "CHANGED".to_string();
+        let old_refs: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new_refs: Vec<&str> = new.iter().map(String::as_str).collect();
+
+        let start = std::time::Instant::now();
+        let diff = line_diff(&old_refs, &new_refs);
+        let state = DiffViewState::default();
+        let rows = folded_rows(&diff, 3, &state);
+        assert!(
+            start.elapsed() < std::time::Duration::from_secs(2),
+            "bounded on 50k lines: {:?}",
+            start.elapsed()
+        );
+        // Folded row list is tiny despite 50k lines.
+        assert!(rows.len() < 16, "{}", rows.len());
+
+        // A window over an expanded state stays O(window) to materialize.
+        let mut state = DiffViewState::default();
+        for row in &rows {
+            if let DiffRow::Fold { start, .. } = row {
+                state.expand(*start);
+            }
+        }
+        let expanded = folded_rows(&diff, 3, &state);
+        assert_eq!(expanded.len(), diff.len());
+        let window: Vec<&DiffRow> = expanded.iter().skip(24_990).take(20).collect();
+        assert_eq!(window.len(), 20);
+    }
+}
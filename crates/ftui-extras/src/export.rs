@@ -425,13 +425,46 @@ impl SvgExporter {
 // Text Exporter
 // ---------------------------------------------------------------------------
 
+/// How exported output positions content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnsiPositioning {
+    /// Newline-flowing text for files and `cat` (the default).
+    #[default]
+    Flowing,
+    /// Terminal-replay addressing: every row is placed with a CUP
+    /// sequence relative to `origin` (0-indexed row/column of the
+    /// region's top-left cell). Autowrap is never relied on, blank cells
+    /// are written rather than trimmed, and the cursor parks on the
+    /// first column of the row just below the region.
+    Addressed {
+        /// Top-left cell of the target region (0-indexed row, column).
+        origin: (u16, u16),
+    },
+}
+
+/// Color fidelity for emitted SGR sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportColorDepth {
+    /// 24-bit `38;2;r;g;b` (the default).
+    #[default]
+    TrueColor,
+    /// 256-color palette `38;5;n`.
+    Ansi256,
+    /// Basic 16-color SGR (30-37 / 90-97).
+    Ansi16,
+}
+
 /// Configuration for plain text export.
 #[derive(Debug, Clone)]
 pub struct TextExporter {
     /// Include ANSI escape codes for colors and styles.
     pub include_ansi: bool,
-    /// Trim trailing whitespace from each line.
+    /// Trim trailing whitespace from each line (Flowing mode only).
     pub trim_trailing: bool,
+    /// Flowing text or addressed terminal replay.
+    pub positioning: AnsiPositioning,
+    /// Color fidelity of emitted SGR sequences.
+    pub color_depth: ExportColorDepth,
 }
 
 impl TextExporter {
@@ -441,6 +474,8 @@ impl TextExporter {
         Self {
             include_ansi: false,
             trim_trailing: true,
+            positioning: AnsiPositioning::default(),
+            color_depth: ExportColorDepth::default(),
         }
     }
 
@@ -450,11 +485,40 @@ impl TextExporter {
         Self {
             include_ansi: true,
             trim_trailing: true,
+            positioning: AnsiPositioning::default(),
+            color_depth: ExportColorDepth::default(),
+        }
+    }
+
+    /// ANSI exporter in addressed (terminal replay) mode at `origin`.
+    #[must_use]
+    pub fn addressed(origin_row: u16, origin_col: u16) -> Self {
+        Self {
+            include_ansi: true,
+            trim_trailing: false,
+            positioning: AnsiPositioning::Addressed {
+                origin: (origin_row, origin_col),
+            },
+            color_depth: ExportColorDepth::default(),
         }
     }
 
+    /// Set the color depth (builder).
+    #[must_use]
+    pub fn with_color_depth(mut self, depth: ExportColorDepth) -> Self {
+        self.color_depth = depth;
+        self
+    }
+
     /// Export a buffer to a plain text (or ANSI) string.
+    ///
+    /// In [`AnsiPositioning::Addressed`] mode the output reproduces the
+    /// buffer cell-for-cell when written to a terminal at the stated
+    /// origin; see [`AnsiPositioning`] for the exact contract.
     pub fn export(&self, buffer: &Buffer, pool: &GraphemePool) -> String {
+        if let AnsiPositioning::Addressed { origin } = self.positioning {
+            return self.export_addressed(buffer, pool, origin);
+        }
         let mut out = String::with_capacity(buffer.len() + buffer.height() as usize);
 
         for y in 0..buffer.height() {
@@ -479,7 +543,7 @@ impl TextExporter {
                         || cell.attrs != CellAttrs::NONE;
 
                     if has_style {
-                        write_ansi_style(&mut line, cell.fg, cell.bg, cell.attrs);
+                        write_ansi_style(&mut line, cell.fg, cell.bg, cell.attrs, self.color_depth);
                     }
 
                     if content.is_empty() {
@@ -510,6 +574,64 @@ impl TextExporter {
     }
 }
 
+impl TextExporter {
+    /// Addressed (terminal replay) export: CUP per row, no trimming, no
+    /// reliance on autowrap, cursor parked below the region.
+    fn export_addressed(&self, buffer: &Buffer, pool: &GraphemePool, origin: (u16, u16)) -> String {
+        let (origin_row, origin_col) = origin;
+        let mut out = String::with_capacity(buffer.len() * 2);
+        // SGR state tracking: emit style changes, not per-cell resets.
+        let mut current: Option<(PackedRgba, PackedRgba, CellAttrs)> = None;
+
+        for y in 0..buffer.height() {
+            // Place the row; 1-based CUP from the 0-based origin.
+            let _ = write!(
+                out,
+                "\x1b[{};{}H",
+                u32::from(origin_row) + u32::from(y) + 1,
+                u32::from(origin_col) + 1
+            );
+            for x in 0..buffer.width() {
+                let cell = buffer.get(x, y).unwrap();
+                if cell.is_continuation() {
+                    continue;
+                }
+                let style = (cell.fg, cell.bg, cell.attrs);
+                if current != Some(style) {
+                    out.push_str("\x1b[0m");
+                    let has_style = cell.fg != PackedRgba::WHITE
+                        || cell.bg != PackedRgba::TRANSPARENT
+                        || cell.attrs != CellAttrs::NONE;
+                    if has_style {
+                        write_ansi_style(&mut out, cell.fg, cell.bg, cell.attrs, self.color_depth);
+                    }
+                    current = Some(style);
+                }
+                let content = cell_content_str(cell.content, pool);
+                if content.is_empty() {
+                    // Blank cells are written, never trimmed: styled
+                    // blanks carry their background, default blanks
+                    // overwrite stale content in the target region.
+                    out.push(' ');
+                } else {
+                    out.push_str(&content);
+                }
+            }
+        }
+
+        // Documented parking spot: first column of the row below the
+        // region (origin column), with attributes reset.
+        out.push_str("\x1b[0m");
+        let _ = write!(
+            out,
+            "\x1b[{};{}H",
+            u32::from(origin_row) + u32::from(buffer.height()) + 1,
+            u32::from(origin_col) + 1
+        );
+        out
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -559,7 +681,13 @@ fn svg_escape_into(out: &mut String, s: &str) {
 }
 
 /// Write ANSI SGR escape codes for the given style.
-fn write_ansi_style(out: &mut String, fg: PackedRgba, bg: PackedRgba, attrs: CellAttrs) {
+fn write_ansi_style(
+    out: &mut String,
+    fg: PackedRgba,
+    bg: PackedRgba,
+    attrs: CellAttrs,
+    depth: ExportColorDepth,
+) {
     out.push_str("\x1b[");
     let mut first = true;
     let mut sep = |out: &mut String| {
@@ -605,21 +733,45 @@ fn write_ansi_style(out: &mut String, fg: PackedRgba, bg: PackedRgba, attrs: Cel
         out.push('9');
     }
 
-    // Foreground: 24-bit color.
+    // Foreground at the requested color depth.
     if fg != PackedRgba::WHITE && fg.a() > 0 {
         sep(out);
-        write!(out, "38;2;{};{};{}", fg.r(), fg.g(), fg.b()).unwrap();
+        write_color(out, fg, depth, false);
     }
 
-    // Background: 24-bit color.
+    // Background at the requested color depth.
     if bg != PackedRgba::TRANSPARENT && bg.a() > 0 {
         sep(out);
-        write!(out, "48;2;{};{};{}", bg.r(), bg.g(), bg.b()).unwrap();
+        write_color(out, bg, depth, true);
     }
 
     out.push('m');
 }
 
+/// Emit one color at the requested depth (fg or bg form).
+fn write_color(out: &mut String, color: PackedRgba, depth: ExportColorDepth, background: bool) {
+    let base = if background { 48 } else { 38 };
+    match depth {
+        ExportColorDepth::TrueColor => {
+            let _ = write!(out, "{base};2;{};{};{}", color.r(), color.g(), color.b());
+        }
+        ExportColorDepth::Ansi256 => {
+            let index = ftui_style::color::rgb_to_256(color.r(), color.g(), color.b());
+            let _ = write!(out, "{base};5;{index}");
+        }
+        ExportColorDepth::Ansi16 => {
+            let ansi = ftui_style::color::rgb_to_ansi16(color.r(), color.g(), color.b()) as u8;
+            let offset = if background { 10 } else { 0 };
+            let code = if ansi < 8 {
+                30 + u16::from(ansi) + offset
+            } else {
+                90 + u16::from(ansi - 8) + offset
+            };
+            let _ = write!(out, "{code}");
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -914,6 +1066,8 @@ mod tests {
         let exporter = TextExporter {
             include_ansi: false,
             trim_trailing: false,
+            positioning: AnsiPositioning::default(),
+            color_depth: ExportColorDepth::default(),
         };
         let text = exporter.export(&buf, &pool);
         assert_eq!(text.len(), 5); // "Hi" + 3 spaces
@@ -1020,6 +1174,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::BOLD, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[1m");
     }
@@ -1032,6 +1187,7 @@ mod tests {
             PackedRgba::rgb(255, 0, 0),
             PackedRgba::TRANSPARENT,
             CellAttrs::NONE,
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[38;2;255;0;0m");
     }
@@ -1044,6 +1200,7 @@ mod tests {
             PackedRgba::rgb(0, 255, 0),
             PackedRgba::rgb(0, 0, 128),
             CellAttrs::new(StyleFlags::BOLD | StyleFlags::UNDERLINE, 0),
+            ExportColorDepth::default(),
         );
         assert!(out.starts_with("\x1b["));
         assert!(out.ends_with('m'));
@@ -1061,6 +1218,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::NONE,
+            ExportColorDepth::default(),
         );
         // No attributes, just ESC [ m
         assert_eq!(out, "\x1b[m");
@@ -1390,6 +1548,8 @@ mod tests {
         let exporter = TextExporter {
             include_ansi: true,
             trim_trailing: false,
+            positioning: AnsiPositioning::default(),
+            color_depth: ExportColorDepth::default(),
         };
         let text = exporter.export(&buf, &pool);
         // Should have trailing spaces (4 empty cells become spaces)
@@ -1490,6 +1650,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::rgb(0, 128, 255),
             CellAttrs::NONE,
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[48;2;0;128;255m");
     }
@@ -1502,6 +1663,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::DIM, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[2m");
     }
@@ -1514,6 +1676,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::ITALIC, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[3m");
     }
@@ -1526,6 +1689,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::UNDERLINE, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[4m");
     }
@@ -1538,6 +1702,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::BLINK, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[5m");
     }
@@ -1550,6 +1715,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::REVERSE, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[7m");
     }
@@ -1562,6 +1728,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::HIDDEN, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[8m");
     }
@@ -1574,6 +1741,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(StyleFlags::STRIKETHROUGH, 0),
+            ExportColorDepth::default(),
         );
         assert_eq!(out, "\x1b[9m");
     }
@@ -1594,6 +1762,7 @@ mod tests {
             PackedRgba::WHITE,
             PackedRgba::TRANSPARENT,
             CellAttrs::new(all_flags, 0),
+            ExportColorDepth::default(),
         );
         assert!(out.starts_with("\x1b["));
         assert!(out.ends_with('m'));
@@ -1617,6 +1786,7 @@ mod tests {
             PackedRgba::rgba(255, 0, 0, 0), // transparent fg
             PackedRgba::TRANSPARENT,
             CellAttrs::NONE,
+            ExportColorDepth::default(),
         );
         // fg with alpha 0 should be skipped
         assert_eq!(out, "\x1b[m");
@@ -1688,4 +1858,106 @@ mod tests {
         assert!(buf.get(1, 0).unwrap().content.as_char() == Some('B'));
         assert!(buf.get(2, 0).unwrap().content.as_char() == Some('C'));
     }
+
+    // ── Addressed (terminal replay) export ──────────────────────────
+
+    fn styled_grid() -> (Buffer, GraphemePool) {
+        let pool = GraphemePool::new();
+        let mut buffer = Buffer::new(6, 2);
+        for (i, ch) in "ab".chars().enumerate() {
+            buffer.set_raw(i as u16, 0, Cell::from_char(ch));
+        }
+        // Styled blank that flowing-trim would drop.
+        let mut blank = Cell::from_char(' ');
+        blank.bg = PackedRgba::rgb(0, 0, 200);
+        buffer.set_raw(2, 0, blank);
+        let mut red = Cell::from_char('x');
+        red.fg = PackedRgba::rgb(200, 0, 0);
+        buffer.set_raw(0, 1, red);
+        (buffer, pool)
+    }
+
+    #[test]
+    fn flowing_mode_golden() {
+        let (buffer, pool) = styled_grid();
+        let out = TextExporter::ansi().export(&buffer, &pool);
+        assert_eq!(
+            out,
+            "ab\x1b[48;2;0;0;200m \x1b[0m\n\x1b[38;2;200;0;0mx\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn addressed_mode_golden() {
+        let (buffer, pool) = styled_grid();
+        let out = TextExporter::addressed(3, 5).export(&buffer, &pool);
+        assert_eq!(
+            out,
+            concat!(
+                "\x1b[4;6H",                  // row 0 at origin (3,5) 1-based
+                "\x1b[0mab",                  // default style run
+                "\x1b[0m\x1b[48;2;0;0;200m ", // styled blank preserved
+                "\x1b[0m   ",                 // trailing default blanks written
+                "\x1b[5;6H",                  // row 1
+                "\x1b[0m\x1b[38;2;200;0;0mx",
+                "\x1b[0m     ",
+                "\x1b[0m\x1b[6;6H", // park below region at origin col
+            )
+        );
+    }
+
+    #[test]
+    fn addressed_round_trip_reproduces_region() {
+        use ftui_render::terminal_model::TerminalModel;
+
+        let (buffer, pool) = styled_grid();
+        let origin = (3u16, 5u16);
+        let out = TextExporter::addressed(origin.0, origin.1).export(&buffer, &pool);
+
+        let mut model = TerminalModel::new(20, 10);
+        model.process(out.as_bytes());
+
+        for y in 0..buffer.height() {
+            for x in 0..buffer.width() {
+                let cell = buffer.get(x, y).unwrap();
+                let model_cell = model
+                    .cell(usize::from(origin.1 + x), usize::from(origin.0 + y))
+                    .unwrap();
+                let expected = cell.content.as_char().unwrap_or(' ');
+                let expected = if expected == '\0' { ' ' } else { expected };
+                assert_eq!(model_cell.text, expected.to_string(), "cell ({x},{y}) text");
+            }
+        }
+        // Styled blank background survived.
+        let blank = model
+            .cell(usize::from(origin.1 + 2), usize::from(origin.0))
+            .unwrap();
+        assert_eq!((blank.bg.r(), blank.bg.g(), blank.bg.b()), (0, 0, 200));
+        // Cursor parked on the documented spot: row below region, origin col.
+        assert_eq!(
+            model.cursor(),
+            (
+                usize::from(origin.1),
+                usize::from(origin.0 + buffer.height())
+            )
+        );
+    }
+
+    #[test]
+    fn addressed_composes_with_color_depth() {
+        let (buffer, pool) = styled_grid();
+        let out = TextExporter::addressed(0, 0)
+            .with_color_depth(ExportColorDepth::Ansi256)
+            .export(&buffer, &pool);
+        assert!(out.contains("\x1b[48;5;"), "256-color bg SGR: {out:?}");
+        assert!(!out.contains(";2;"), "no truecolor sequences");
+
+        let out16 = TextExporter::addressed(0, 0)
+            .with_color_depth(ExportColorDepth::Ansi16)
+            .export(&buffer, &pool);
+        assert!(
+            out16.contains("\x1b[44m") || out16.contains("44"),
+            "16-color bg: {out16:?}"
+        );
+    }
 }
@@ -1569,6 +1569,7 @@ mod tests {
             code,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -1578,6 +1579,7 @@ mod tests {
             code,
             modifiers: Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
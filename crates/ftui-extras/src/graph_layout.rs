@@ -0,0 +1,687 @@
+#![forbid(unsafe_code)]
+
+//! Standalone deterministic layered graph layout (Sugiyama-style).
+//!
+//! The Mermaid renderer has its own IR-coupled engine
+//! ([`diagram_layout`](crate::diagram_layout)); this module exposes
+//! layout for *any* node/edge list, independent of any widget, so it is
+//! testable headlessly and reusable for non-mermaid graphs:
+//!
+//! 1. **Rank assignment** — longest-path ranks respecting edge
+//!    direction; cycles are broken by temporarily reversing back edges
+//!    (DFS in stable index order).
+//! 2. **Crossing reduction** — median heuristic with stable tie-breaks
+//!    and a fixed sweep count, so output never varies between runs or
+//!    platforms (no hash-map iteration anywhere: everything is
+//!    index-ordered).
+//! 3. **Coordinates** — cell units honoring node label sizes; ranks are
+//!    separated by routing channels.
+//! 4. **Orthogonal edge routing** — channel routing between ranks with
+//!    corner/arrow glyphs; paths never pass through node boxes
+//!    (channels and reserved virtual columns by construction).
+//!    Self-loops render adjacent to their node.
+//!
+//! The output is a [`DiagramLayout`] of node rects and edge paths.
+
+use unicode_width::UnicodeWidthStr;
+
+/// A node box in cell units (self-contained: this module has no
+/// dependency on the widget/render stack).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LayoutRect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl LayoutRect {
+    #[must_use]
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// One past the right edge.
+    #[must_use]
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// One past the bottom edge.
+    #[must_use]
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+}
+
+/// Layout direction: where rank 0 sits and ranks grow towards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDirection {
+    /// Ranks grow downward (flowchart `TD`).
+    #[default]
+    TopDown,
+    /// Ranks grow rightward (flowchart `LR`).
+    LeftRight,
+}
+
+/// A node to lay out: the label drives the box size.
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub label: String,
+}
+
+impl GraphNode {
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+        }
+    }
+}
+
+/// Input graph: nodes plus directed edges as node indices.
+#[derive(Debug, Clone, Default)]
+pub struct GraphSpec {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<(usize, usize)>,
+    pub direction: LayoutDirection,
+}
+
+/// One routed edge: an orthogonal polyline in cell coordinates,
+/// ending with an arrowhead at the last point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgePath {
+    /// Source node index.
+    pub from: usize,
+    /// Target node index.
+    pub to: usize,
+    /// Orthogonal polyline corner points (inclusive endpoints).
+    pub points: Vec<(u16, u16)>,
+    /// Arrowhead glyph at the final point.
+    pub arrow: char,
+}
+
+/// Computed layout: node boxes and routed edges, in cell units.
+#[derive(Debug, Clone, Default)]
+pub struct DiagramLayout {
+    pub node_rects: Vec<LayoutRect>,
+    pub edge_paths: Vec<EdgePath>,
+}
+
+/// Gap columns between adjacent nodes in a rank.
+const NODE_GAP: u16 = 3;
+/// Node box height (border + label + border).
+const NODE_HEIGHT: u16 = 3;
+/// Crossing-reduction sweeps (fixed: determinism over optimality).
+const ORDERING_SWEEPS: usize = 4;
+
+/// Compute the layout for a graph (see the module docs).
+#[must_use]
+pub fn layout_graph(spec: &GraphSpec) -> DiagramLayout {
+    if spec.nodes.is_empty() {
+        return DiagramLayout::default();
+    }
+    let node_count = spec.nodes.len();
+
+    // Split out self-loops (rendered adjacent to the node) and clamp
+    // out-of-range edges.
+    let mut self_loops = Vec::new();
+    let mut edges = Vec::new();
+    for &(from, to) in &spec.edges {
+        if from >= node_count || to >= node_count {
+            continue;
+        }
+        if from == to {
+            self_loops.push(from);
+        } else {
+            edges.push((from, to));
+        }
+    }
+
+    // 1. Cycle breaking: DFS in index order marks back edges, which are
+    // reversed for ranking/ordering and restored for the arrows.
+    let reversed = find_back_edges(node_count, &edges);
+    let dag_edges: Vec<(usize, usize)> = edges
+        .iter()
+        .enumerate()
+        .map(|(idx, &(from, to))| {
+            if reversed.contains(&idx) {
+                (to, from)
+            } else {
+                (from, to)
+            }
+        })
+        .collect();
+
+    // 2. Longest-path ranks.
+    let ranks = assign_ranks(node_count, &dag_edges);
+    let rank_count = ranks.iter().copied().max().unwrap_or(0) + 1;
+
+    // Virtual nodes reserve routing columns for edges spanning ranks.
+    // Each entity is either a real node or a virtual waypoint.
+    let mut entity_rank: Vec<usize> = ranks.clone();
+    let mut entity_width: Vec<u16> = spec
+        .nodes
+        .iter()
+        .map(|node| UnicodeWidthStr::width(node.label.as_str()) as u16 + 4)
+        .collect();
+    // Edge → chain of entities it passes through (source..waypoints..target).
+    let mut edge_chains: Vec<Vec<usize>> = Vec::with_capacity(dag_edges.len());
+    for &(from, to) in &dag_edges {
+        let mut chain = vec![from];
+        if ranks[to] > ranks[from] + 1 {
+            for rank in ranks[from] + 1..ranks[to] {
+                entity_rank.push(rank);
+                entity_width.push(1);
+                chain.push(entity_rank.len() - 1);
+            }
+        }
+        chain.push(to);
+        edge_chains.push(chain);
+    }
+    let entity_count = entity_rank.len();
+
+    // Adjacency over entities (chain segments).
+    let mut down: Vec<Vec<usize>> = vec![Vec::new(); entity_count];
+    let mut up: Vec<Vec<usize>> = vec![Vec::new(); entity_count];
+    for chain in &edge_chains {
+        for pair in chain.windows(2) {
+            down[pair[0]].push(pair[1]);
+            up[pair[1]].push(pair[0]);
+        }
+    }
+
+    // 3. Ordering: initial order = entity index; median sweeps.
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); rank_count];
+    for entity in 0..entity_count {
+        layers[entity_rank[entity]].push(entity);
+    }
+    for sweep in 0..ORDERING_SWEEPS {
+        if sweep % 2 == 0 {
+            for rank in 1..rank_count {
+                order_by_median(&mut layers, rank, &up);
+            }
+        } else {
+            for rank in (0..rank_count.saturating_sub(1)).rev() {
+                order_by_median(&mut layers, rank, &down);
+            }
+        }
+    }
+
+    // 4. Coordinates along the cross axis (cell units).
+    let mut cross_pos: Vec<u16> = vec![0; entity_count];
+    let mut layer_extent: Vec<u16> = Vec::with_capacity(rank_count);
+    for layer in &layers {
+        let mut cursor = 0u16;
+        for &entity in layer {
+            cross_pos[entity] = cursor;
+            cursor = cursor
+                .saturating_add(entity_width[entity])
+                .saturating_add(NODE_GAP);
+        }
+        layer_extent.push(cursor.saturating_sub(NODE_GAP));
+    }
+    let max_extent = layer_extent.iter().copied().max().unwrap_or(0);
+    // Center each layer.
+    for (rank, layer) in layers.iter().enumerate() {
+        let offset = (max_extent - layer_extent[rank]) / 2;
+        for &entity in layer {
+            cross_pos[entity] += offset;
+        }
+    }
+
+    // Rank axis: each rank occupies NODE_HEIGHT plus a routing channel
+    // sized by the lanes crossing that gap.
+    let mut gap_lanes: Vec<u16> = vec![1; rank_count.saturating_sub(1)];
+    for chain in &edge_chains {
+        for pair in chain.windows(2) {
+            let gap = entity_rank[pair[0]];
+            if gap < gap_lanes.len() {
+                gap_lanes[gap] = gap_lanes[gap].max(2);
+            }
+        }
+    }
+    // Lane assignment per gap: edges through a gap get distinct lanes
+    // (bounded round-robin), deterministic by edge order.
+    let mut lane_counters: Vec<u16> = vec![0; gap_lanes.len()];
+    let max_lanes_per_gap: Vec<u16> = gap_lanes.iter().map(|&min| min.max(2)).collect();
+    let mut rank_origin: Vec<u16> = Vec::with_capacity(rank_count);
+    {
+        let mut cursor = 0u16;
+        for rank in 0..rank_count {
+            rank_origin.push(cursor);
+            cursor = cursor.saturating_add(NODE_HEIGHT);
+            if let Some(&lanes) = max_lanes_per_gap.get(rank) {
+                cursor = cursor.saturating_add(lanes).saturating_add(1);
+            }
+        }
+    }
+
+    // Materialize node rects (direction decides which axis is rank).
+    let mut node_rects = Vec::with_capacity(node_count);
+    for node in 0..node_count {
+        let rank_at = rank_origin[entity_rank[node]];
+        let cross_at = cross_pos[node];
+        let rect = match spec.direction {
+            LayoutDirection::TopDown => {
+                LayoutRect::new(cross_at, rank_at, entity_width[node], NODE_HEIGHT)
+            }
+            LayoutDirection::LeftRight => {
+                LayoutRect::new(rank_at, cross_at, NODE_HEIGHT, entity_width[node])
+            }
+        };
+        node_rects.push(rect);
+    }
+
+    // 5. Routing. Work in (cross, rank) space, then transpose for LR.
+    let mut edge_paths = Vec::new();
+    for (edge_idx, chain) in edge_chains.iter().enumerate() {
+        let (orig_from, orig_to) = edges[edge_idx];
+        let mut points: Vec<(u16, u16)> = Vec::new();
+        for pair in chain.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let gap = entity_rank[a];
+            let lane = if gap < lane_counters.len() {
+                let lane = lane_counters[gap] % max_lanes_per_gap[gap];
+                lane_counters[gap] += 1;
+                lane
+            } else {
+                0
+            };
+            let a_center = cross_pos[a] + entity_width[a] / 2;
+            let b_center = cross_pos[b] + entity_width[b] / 2;
+            let a_exit = rank_origin[entity_rank[a]] + if a < node_count { NODE_HEIGHT } else { 0 };
+            let lane_row = rank_origin[entity_rank[a]] + NODE_HEIGHT + lane;
+            let b_entry = rank_origin[entity_rank[b]].saturating_sub(1);
+            if points.is_empty() {
+                points.push((a_center, a_exit.saturating_sub(1)));
+            }
+            points.push((a_center, lane_row));
+            points.push((b_center, lane_row));
+            points.push((b_center, b_entry.saturating_add(1)));
+        }
+        // Collapse duplicate consecutive points.
+        points.dedup();
+
+        let reversed_edge = reversed.contains(&edge_idx);
+        if reversed_edge {
+            points.reverse();
+        }
+        let (points, arrow) = materialize(points, spec.direction, reversed_edge);
+        edge_paths.push(EdgePath {
+            from: orig_from,
+            to: orig_to,
+            points,
+            arrow,
+        });
+    }
+
+    // Self-loops: a small detour on the node's trailing side.
+    for node in self_loops {
+        let rect = node_rects[node];
+        let (points, arrow) = match spec.direction {
+            LayoutDirection::TopDown => {
+                let x = rect.right().saturating_add(1);
+                let mid = rect.y + rect.height / 2;
+                (
+                    vec![
+                        (rect.right().saturating_sub(1), rect.y),
+                        (x, rect.y),
+                        (x, mid),
+                        (rect.right(), mid),
+                    ],
+                    '\u{25c2}',
+                )
+            }
+            LayoutDirection::LeftRight => {
+                let y = rect.bottom().saturating_add(1);
+                let mid = rect.x + rect.width / 2;
+                (
+                    vec![
+                        (rect.x, rect.bottom().saturating_sub(1)),
+                        (rect.x, y),
+                        (mid, y),
+                        (mid, rect.bottom()),
+                    ],
+                    '\u{25b4}',
+                )
+            }
+        };
+        edge_paths.push(EdgePath {
+            from: node,
+            to: node,
+            points,
+            arrow,
+        });
+    }
+
+    DiagramLayout {
+        node_rects,
+        edge_paths,
+    }
+}
+
+/// Transpose (cross, rank) points into screen coordinates per direction
+/// and choose the arrowhead glyph.
+fn materialize(
+    points: Vec<(u16, u16)>,
+    direction: LayoutDirection,
+    reversed: bool,
+) -> (Vec<(u16, u16)>, char) {
+    match direction {
+        LayoutDirection::TopDown => {
+            let arrow = if reversed { '\u{25b4}' } else { '\u{25be}' };
+            (points, arrow)
+        }
+        LayoutDirection::LeftRight => {
+            let arrow = if reversed { '\u{25c2}' } else { '\u{25b8}' };
+            (points.into_iter().map(|(c, r)| (r, c)).collect(), arrow)
+        }
+    }
+}
+
+/// DFS (stable index order) marking back edges by edge index.
+fn find_back_edges(node_count: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut out: Vec<Vec<(usize, usize)>> = vec![Vec::new(); node_count]; // (edge_idx, to)
+    for (idx, &(from, to)) in edges.iter().enumerate() {
+        out[from].push((idx, to));
+    }
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        White,
+        Gray,
+        Black,
+    }
+    let mut marks = vec![Mark::White; node_count];
+    let mut back = Vec::new();
+    // Iterative DFS with explicit stack for stability and depth safety.
+    for root in 0..node_count {
+        if marks[root] != Mark::White {
+            continue;
+        }
+        let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+        marks[root] = Mark::Gray;
+        while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+            if *next < out[node].len() {
+                let (edge_idx, to) = out[node][*next];
+                *next += 1;
+                match marks[to] {
+                    Mark::Gray => back.push(edge_idx),
+                    Mark::White => {
+                        marks[to] = Mark::Gray;
+                        stack.push((to, 0));
+                    }
+                    Mark::Black => {}
+                }
+            } else {
+                marks[node] = Mark::Black;
+                stack.pop();
+            }
+        }
+    }
+    back
+}
+
+/// Longest-path rank assignment over a DAG.
+fn assign_ranks(node_count: usize, dag_edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut ranks = vec![0usize; node_count];
+    // Relax edges until stable; bounded by node count passes.
+    for _ in 0..node_count {
+        let mut changed = false;
+        for &(from, to) in dag_edges {
+            if ranks[to] < ranks[from] + 1 {
+                ranks[to] = ranks[from] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    ranks
+}
+
+/// Reorder one layer by the median position of its neighbors in the
+/// adjacent (already ordered) layer; ties break by previous position.
+fn order_by_median(layers: &mut [Vec<usize>], rank: usize, neighbors: &[Vec<usize>]) {
+    // Position lookup over all entities (index-addressed, no hashing).
+    let entity_count = neighbors.len();
+    let mut position = vec![0usize; entity_count];
+    for layer in layers.iter() {
+        for (idx, &entity) in layer.iter().enumerate() {
+            position[entity] = idx;
+        }
+    }
+    let layer = &mut layers[rank];
+    let mut keyed: Vec<(usize, usize, usize)> = layer
+        .iter()
+        .enumerate()
+        .map(|(current, &entity)| {
+            let mut adjacent: Vec<usize> = neighbors[entity].iter().map(|&n| position[n]).collect();
+            adjacent.sort_unstable();
+            let median = if adjacent.is_empty() {
+                current
+            } else {
+                adjacent[adjacent.len() / 2]
+            };
+            (median, current, entity)
+        })
+        .collect();
+    keyed.sort_by_key(|&(median, current, _)| (median, current));
+    for (idx, &(_, _, entity)) in keyed.iter().enumerate() {
+        layer[idx] = entity;
+    }
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(labels: &[&str], edges: &[(usize, usize)], direction: LayoutDirection) -> GraphSpec {
+        GraphSpec {
+            nodes: labels.iter().map(|&l| GraphNode::new(l)).collect(),
+            edges: edges.to_vec(),
+            direction,
+        }
+    }
+
+    /// Does a horizontal or vertical segment pass through a rect's
+    /// interior (endpoints attached to borders are allowed)?
+    fn segment_hits_interior(a: (u16, u16), b: (u16, u16), rect: &LayoutRect) -> bool {
+        let interior = |x: u16, y: u16| {
+            x > rect.x && x + 1 < rect.right() && y > rect.y && y + 1 < rect.bottom()
+        };
+        if a.0 == b.0 {
+            let (y0, y1) = (a.1.min(b.1), a.1.max(b.1));
+            (y0..=y1).any(|y| interior(a.0, y))
+        } else {
+            let (x0, x1) = (a.0.min(b.0), a.0.max(b.0));
+            (x0..=x1).any(|x| interior(x, a.1))
+        }
+    }
+
+    fn assert_paths_avoid_nodes(layout: &DiagramLayout) {
+        for path in &layout.edge_paths {
+            for pair in path.points.windows(2) {
+                for (node, rect) in layout.node_rects.iter().enumerate() {
+                    assert!(
+                        !segment_hits_interior(pair[0], pair[1], rect),
+                        "edge {}->{} segment {:?}-{:?} crosses node {node} {rect:?}",
+                        path.from,
+                        path.to,
+                        pair[0],
+                        pair[1]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn golden_dag_top_down() {
+        let layout = layout_graph(&spec(
+            &["start", "mid", "end"],
+            &[(0, 1), (1, 2)],
+            LayoutDirection::TopDown,
+        ));
+        // Ranks stack downward; boxes sized by labels (+4 for borders).
+        assert_eq!(layout.node_rects[0], LayoutRect::new(0, 0, 9, 3));
+        assert_eq!(layout.node_rects[1], LayoutRect::new(1, 6, 7, 3));
+        assert_eq!(layout.node_rects[2], LayoutRect::new(1, 12, 7, 3));
+        assert_eq!(layout.edge_paths.len(), 2);
+        let first = &layout.edge_paths[0];
+        assert_eq!(first.arrow, '\u{25be}');
+        assert_eq!(first.points.first(), Some(&(4, 2)), "leaves source bottom");
+        assert_eq!(
+            first.points.last(),
+            Some(&(4, 6)),
+            "enters target top border"
+        );
+        assert_paths_avoid_nodes(&layout);
+    }
+
+    #[test]
+    fn golden_cyclic_graph_reverses_back_edge() {
+        // a → b → c → a: the c→a edge is reversed for ranking, so ranks
+        // still stack, and the rendered arrow points back up.
+        let layout = layout_graph(&spec(
+            &["a", "b", "c"],
+            &[(0, 1), (1, 2), (2, 0)],
+            LayoutDirection::TopDown,
+        ));
+        assert_eq!(layout.node_rects[0].y, 0);
+        assert_eq!(layout.node_rects[1].y, 6);
+        assert_eq!(layout.node_rects[2].y, 12);
+        let back = layout
+            .edge_paths
+            .iter()
+            .find(|p| p.from == 2 && p.to == 0)
+            .expect("back edge present");
+        assert_eq!(back.arrow, '\u{25b4}', "reversed edge points up");
+        assert_eq!(
+            back.points.first().map(|p| p.1),
+            Some(12),
+            "path leaves c's top after reversal: {:?}",
+            back.points
+        );
+        assert_eq!(
+            back.points.last().map(|p| p.1),
+            Some(2),
+            "and lands on a's bottom border: {:?}",
+            back.points
+        );
+        assert_paths_avoid_nodes(&layout);
+    }
+
+    #[test]
+    fn golden_wide_fan_out() {
+        let layout = layout_graph(&spec(
+            &["hub", "a", "b", "c", "d"],
+            &[(0, 1), (0, 2), (0, 3), (0, 4)],
+            LayoutDirection::TopDown,
+        ));
+        // All leaves share rank 1, ordered by index, centered under hub.
+        let ys: Vec<u16> = layout.node_rects[1..].iter().map(|r| r.y).collect();
+        assert_eq!(ys, vec![6, 6, 6, 6]);
+        let xs: Vec<u16> = layout.node_rects[1..].iter().map(|r| r.x).collect();
+        let mut sorted = xs.clone();
+        sorted.sort_unstable();
+        assert_eq!(xs, sorted, "stable index order");
+        assert_paths_avoid_nodes(&layout);
+    }
+
+    #[test]
+    fn left_right_direction_transposes() {
+        let layout = layout_graph(&spec(&["a", "b"], &[(0, 1)], LayoutDirection::LeftRight));
+        assert!(
+            layout.node_rects[1].x > layout.node_rects[0].x,
+            "ranks grow rightward"
+        );
+        assert_eq!(layout.edge_paths[0].arrow, '\u{25b8}');
+        assert_paths_avoid_nodes(&layout);
+    }
+
+    #[test]
+    fn self_loop_renders_adjacent() {
+        let layout = layout_graph(&spec(&["solo"], &[(0, 0)], LayoutDirection::TopDown));
+        assert_eq!(layout.edge_paths.len(), 1);
+        let rect = layout.node_rects[0];
+        let path = &layout.edge_paths[0];
+        assert!(
+            path.points.iter().all(|&(x, _)| x >= rect.right() - 1),
+            "loop hugs the trailing side: {:?}",
+            path.points
+        );
+    }
+
+    #[test]
+    fn determinism_across_runs() {
+        let graph = spec(
+            &["n0", "n1", "n2", "n3", "n4", "n5"],
+            &[
+                (0, 1),
+                (0, 2),
+                (1, 3),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 1),
+                (2, 5),
+            ],
+            LayoutDirection::TopDown,
+        );
+        let first = layout_graph(&graph);
+        for _ in 0..16 {
+            let again = layout_graph(&graph);
+            assert_eq!(first.node_rects, again.node_rects);
+            assert_eq!(first.edge_paths, again.edge_paths);
+        }
+    }
+
+    #[test]
+    fn randomized_graphs_never_route_through_nodes() {
+        // Deterministic xorshift over many shapes.
+        let mut state = 0x5eed_cafe_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for case in 0..60 {
+            let node_count = (next() % 8 + 2) as usize;
+            let labels: Vec<String> = (0..node_count)
+                .map(|i| format!("node{i}x{}", next() % 1000))
+                .collect();
+            let edge_count = (next() % 12 + 1) as usize;
+            let edges: Vec<(usize, usize)> = (0..edge_count)
+                .map(|_| {
+                    (
+                        (next() % node_count as u64) as usize,
+                        (next() % node_count as u64) as usize,
+                    )
+                })
+                .collect();
+            let graph = GraphSpec {
+                nodes: labels.iter().map(GraphNode::new).collect(),
+                edges,
+                direction: if case % 2 == 0 {
+                    LayoutDirection::TopDown
+                } else {
+                    LayoutDirection::LeftRight
+                },
+            };
+            let layout = layout_graph(&graph);
+            assert_paths_avoid_nodes(&layout);
+        }
+    }
+}
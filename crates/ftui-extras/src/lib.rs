@@ -77,6 +77,9 @@ pub mod mermaid_layout;
 
 #[cfg(feature = "diagram")]
 pub mod diagram_layout;
+#[cfg(feature = "diffview")]
+pub mod diffview;
+pub mod graph_layout;
 
 #[cfg(feature = "diagram")]
 pub mod mermaid_render;
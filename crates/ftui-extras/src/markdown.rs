@@ -518,6 +518,12 @@ pub fn auto_render_streaming(fragment: &str, theme: &MarkdownTheme) -> Text {
 ///
 /// Uses the `unicodeit` crate for symbol conversion, with fallbacks for
 /// unsupported constructs.
+/// First token of a code-fence info string: the language identifier,
+/// with attributes after whitespace or a comma stripped.
+fn fence_language_token(info: &str) -> &str {
+    info.split([',', ' ', '\t']).next().map_or(info, str::trim)
+}
+
 fn latex_to_unicode(latex: &str) -> String {
     // Use unicodeit for the heavy lifting
     let mut result = unicodeit::replace(latex);
@@ -2132,7 +2138,12 @@ impl<'t> RenderState<'t> {
         let code = std::mem::take(&mut self.code_block_lines).join("");
         let lang = self.code_block_lang.take();
         let style = self.theme.code_block;
-        let lang_lower = lang.as_ref().map(|value| value.to_ascii_lowercase());
+        // The fence info string can carry attributes after the language
+        // ("rust,no_run", "sh ignore"): every language check below uses
+        // the first token only.
+        let lang_lower = lang
+            .as_ref()
+            .map(|value| fence_language_token(value).to_ascii_lowercase());
 
         #[cfg(feature = "diagram")]
         let code = {
@@ -2222,7 +2233,8 @@ impl<'t> RenderState<'t> {
                 && !matches!(lang_lower, "mermaid" | "math" | "latex" | "tex")
             {
                 let code_for_highlight = code.strip_suffix('\n').unwrap_or(&code);
-                let highlighted = highlighter.highlight(code_for_highlight, lang_str);
+                let highlighted =
+                    highlighter.highlight(code_for_highlight, fence_language_token(lang_str));
                 for line in highlighted.lines() {
                     let mut spans = Vec::with_capacity(line.len().saturating_add(1));
                     spans.push(Span::styled("  ", style));
@@ -3752,4 +3764,41 @@ The time complexity is **O(2^n)** which can be improved with memoization."#;
         let text = auto_render(plain_response, &theme);
         assert_eq!(text.height(), 1); // Single line, rendered as plain text
     }
+
+    #[test]
+    fn fence_language_token_strips_attributes() {
+        assert_eq!(fence_language_token("rust"), "rust");
+        assert_eq!(fence_language_token("rust,no_run"), "rust");
+        assert_eq!(fence_language_token("sh ignore"), "sh");
+        assert_eq!(fence_language_token("rs\tshould_panic"), "rs");
+        assert_eq!(fence_language_token(""), "");
+    }
+
+    #[cfg(feature = "syntax")]
+    #[test]
+    fn fenced_block_with_attributes_still_highlights() {
+        use crate::syntax::SyntaxHighlighter;
+        use std::sync::Arc;
+
+        let hl = Arc::new(SyntaxHighlighter::new());
+        let md = "```rust,no_run\nlet x = 1;\n```\n";
+        let rendered = MarkdownRenderer::new(MarkdownTheme::default())
+            .with_syntax_highlighter(hl)
+            .render(md);
+        // The keyword is styled, proving the grammar resolved despite the
+        // attribute suffix in the fence info string.
+        let styled = rendered.lines().iter().any(|line| {
+            line.spans()
+                .iter()
+                .any(|span| span.as_str() == "let" && span.style.is_some())
+        });
+        assert!(styled, "expected highlighted keyword");
+        // The language label also resolves from the token, not the full
+        // info string.
+        let label = rendered
+            .lines()
+            .iter()
+            .any(|line| line.to_plain_text().contains("rust"));
+        assert!(label, "expected language label for rust");
+    }
 }
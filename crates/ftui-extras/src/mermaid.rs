@@ -5797,6 +5797,10 @@ pub struct SequenceMessage {
     pub to: String,
     pub arrow: String,
     pub message: Option<String>,
+    /// `+` suffix on the arrow (`A->>+B`): activates the target.
+    pub activates_target: bool,
+    /// `-` suffix on the arrow (`A->>-B`): deactivates the sender.
+    pub deactivates_sender: bool,
     pub span: Span,
 }
 
@@ -6013,6 +6017,25 @@ pub enum SeqControlKind {
     End,
 }
 
+impl SeqControlKind {
+    /// The source keyword for this control marker.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Loop => "loop",
+            Self::Alt => "alt",
+            Self::Else => "else",
+            Self::Opt => "opt",
+            Self::Par => "par",
+            Self::And => "and",
+            Self::Critical => "critical",
+            Self::Break => "break",
+            Self::Rect => "rect",
+            Self::End => "end",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SequenceParticipant {
     pub id: String,
@@ -6043,6 +6066,33 @@ pub struct SequenceControl {
     pub span: Span,
 }
 
+/// One branch of a structured sequence fragment (`alt`/`else`, `par`/`and`).
+///
+/// Plain fragments (`loop`, `opt`, ...) have exactly one branch with no
+/// label of its own.
+#[derive(Debug, Clone)]
+pub struct SequenceBranch {
+    /// Branch label (the `else <label>` / `and <label>` text).
+    pub label: Option<String>,
+    /// Statements inside the branch, with nested blocks preserved.
+    pub statements: Vec<Statement>,
+    /// Span of the line that opened the branch.
+    pub span: Span,
+}
+
+/// A structured sequence fragment (`loop`/`alt`/`opt`/`par`/...) with its
+/// nested contents, produced by [`nest_sequence_statements`].
+#[derive(Debug, Clone)]
+pub struct SequenceBlock {
+    pub kind: SeqControlKind,
+    /// The fragment's own label (text after the opening keyword).
+    pub label: Option<String>,
+    /// Branches in source order; messages inside nest rather than flatten.
+    pub branches: Vec<SequenceBranch>,
+    /// Span of the opening line.
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub enum Statement {
     Directive(Directive),
@@ -6184,6 +6234,10 @@ pub enum Statement {
     SequenceNote(SequenceNote),
     SequenceActivation(SequenceActivation),
     SequenceControl(SequenceControl),
+    /// Nested structured fragment (only produced by
+    /// [`nest_sequence_statements`]; the parser emits flat
+    /// `SequenceControl` markers).
+    SequenceBlock(SequenceBlock),
     Raw {
         text: String,
         span: Span,
@@ -7605,7 +7659,26 @@ pub fn parse_with_diagnostics(input: &str) -> MermaidParse {
                 } else if let Some(c) = parse_sequence_control(trimmed, span) {
                     statements.push(Statement::SequenceControl(c));
                 } else if let Some(msg) = parse_sequence(trimmed, span) {
+                    // Arrow activation shorthand expands to explicit
+                    // activation statements so downstream consumers see a
+                    // single representation.
+                    let activate = msg.activates_target.then(|| SequenceActivation {
+                        participant: msg.to.clone(),
+                        activate: true,
+                        span,
+                    });
+                    let deactivate = msg.deactivates_sender.then(|| SequenceActivation {
+                        participant: msg.from.clone(),
+                        activate: false,
+                        span,
+                    });
+                    if let Some(a) = activate {
+                        statements.push(Statement::SequenceActivation(a));
+                    }
                     statements.push(Statement::SequenceMessage(msg));
+                    if let Some(a) = deactivate {
+                        statements.push(Statement::SequenceActivation(a));
+                    }
                 } else {
                     statements.push(Statement::Raw {
                         text: normalize_ws(trimmed),
@@ -8256,6 +8329,7 @@ fn statement_span(statement: &Statement) -> Span {
         Statement::SequenceNote(n) => n.span,
         Statement::SequenceActivation(a) => a.span,
         Statement::SequenceControl(c) => c.span,
+        Statement::SequenceBlock(b) => b.span,
         Statement::Raw { span, .. } => *span,
     }
 }
@@ -9145,6 +9219,17 @@ fn parse_sequence(line: &str, span: Span) -> Option<SequenceMessage> {
     } else {
         (None, right)
     };
+    // Activation shorthand: `A->>+B` activates B, `A->>-B` deactivates A.
+    // The greedy arrow matcher absorbs a trailing `-` into the arrow
+    // (`-->>-`), while `+` stays on the participant side; handle both.
+    let (arrow, deactivates_sender) = match arrow.strip_suffix('-') {
+        Some(base) if base.ends_with('>') => (base, true),
+        _ => (arrow, false),
+    };
+    let (activates_target, right_id) = match right_id.strip_prefix('+') {
+        Some(stripped) => (true, stripped.trim_start()),
+        None => (false, right_id),
+    };
     if left.is_empty() || right_id.is_empty() {
         return None;
     }
@@ -9153,6 +9238,8 @@ fn parse_sequence(line: &str, span: Span) -> Option<SequenceMessage> {
         to: normalize_ws(right_id),
         arrow: arrow.to_string(),
         message: message.map(normalize_ws),
+        activates_target,
+        deactivates_sender,
         span,
     })
 }
@@ -9287,6 +9374,191 @@ fn parse_sequence_control(line: &str, span: Span) -> Option<SequenceControl> {
     Some(SequenceControl { kind, label, span })
 }
 
+/// Nest flat sequence statements into structured [`SequenceBlock`]s.
+///
+/// The parser emits `loop`/`alt`/`else`/`end` as flat
+/// [`Statement::SequenceControl`] markers; this pass folds them into
+/// [`Statement::SequenceBlock`] trees where messages inside fragments nest
+/// rather than flatten. Unbalanced blocks (an unclosed fragment, a stray
+/// `end`, or `else`/`and` outside a fragment) produce an error carrying
+/// the offending span.
+pub fn nest_sequence_statements(statements: &[Statement]) -> Result<Vec<Statement>, MermaidError> {
+    /// An open fragment being assembled.
+    struct OpenBlock {
+        kind: SeqControlKind,
+        label: Option<String>,
+        span: Span,
+        branches: Vec<SequenceBranch>,
+        current_label: Option<String>,
+        current_span: Span,
+        current: Vec<Statement>,
+    }
+
+    let mut root: Vec<Statement> = Vec::new();
+    let mut stack: Vec<OpenBlock> = Vec::new();
+
+    let push_into = |root: &mut Vec<Statement>, stack: &mut [OpenBlock], stmt: Statement| {
+        match stack.last_mut() {
+            Some(open) => open.current.push(stmt),
+            None => root.push(stmt),
+        }
+    };
+
+    for statement in statements {
+        // The line scanner classifies a bare `end` as SubgraphEnd before the
+        // sequence branch sees it; treat it as a fragment close here.
+        let ctrl = match statement {
+            Statement::SequenceControl(ctrl) => ctrl.clone(),
+            Statement::SubgraphEnd { span } => SequenceControl {
+                kind: SeqControlKind::End,
+                label: None,
+                span: *span,
+            },
+            _ => {
+                push_into(&mut root, &mut stack, statement.clone());
+                continue;
+            }
+        };
+        match ctrl.kind {
+            SeqControlKind::End => {
+                let Some(mut open) = stack.pop() else {
+                    return Err(MermaidError::new(
+                        "'end' without an open fragment",
+                        ctrl.span,
+                    ));
+                };
+                open.branches.push(SequenceBranch {
+                    label: open.current_label.take(),
+                    statements: std::mem::take(&mut open.current),
+                    span: open.current_span,
+                });
+                let block = Statement::SequenceBlock(SequenceBlock {
+                    kind: open.kind,
+                    label: open.label,
+                    branches: open.branches,
+                    span: open.span,
+                });
+                push_into(&mut root, &mut stack, block);
+            }
+            SeqControlKind::Else | SeqControlKind::And => {
+                let Some(open) = stack.last_mut() else {
+                    return Err(MermaidError::new(
+                        "'else'/'and' outside a fragment",
+                        ctrl.span,
+                    ));
+                };
+                let matches_parent = matches!(
+                    (ctrl.kind, open.kind),
+                    (SeqControlKind::Else, SeqControlKind::Alt)
+                        | (SeqControlKind::Else, SeqControlKind::Critical)
+                        | (SeqControlKind::And, SeqControlKind::Par)
+                );
+                if !matches_parent {
+                    return Err(MermaidError::new(
+                        format!(
+                            "'{}' does not belong to the open '{}' fragment",
+                            if ctrl.kind == SeqControlKind::Else {
+                                "else"
+                            } else {
+                                "and"
+                            },
+                            open.kind.as_str(),
+                        ),
+                        ctrl.span,
+                    ));
+                }
+                open.branches.push(SequenceBranch {
+                    label: open.current_label.take(),
+                    statements: std::mem::take(&mut open.current),
+                    span: open.current_span,
+                });
+                open.current_label = ctrl.label.clone();
+                open.current_span = ctrl.span;
+            }
+            _ => {
+                stack.push(OpenBlock {
+                    kind: ctrl.kind,
+                    label: ctrl.label.clone(),
+                    span: ctrl.span,
+                    branches: Vec::new(),
+                    current_label: None,
+                    current_span: ctrl.span,
+                    current: Vec::new(),
+                });
+            }
+        }
+    }
+
+    if let Some(open) = stack.last() {
+        return Err(MermaidError::new(
+            format!("unclosed '{}' fragment (missing 'end')", open.kind.as_str()),
+            open.span,
+        ));
+    }
+    Ok(root)
+}
+
+/// Participant lifeline order for a sequence diagram.
+///
+/// Declared participants come first in declaration order; participants that
+/// only appear implicitly (in messages, notes, or activations) follow in
+/// first-appearance order.
+#[must_use]
+pub fn sequence_participant_order(statements: &[Statement]) -> Vec<String> {
+    fn add(order: &mut Vec<String>, seen: &mut std::collections::HashSet<String>, id: &str) {
+        if !id.is_empty() && seen.insert(id.to_string()) {
+            order.push(id.to_string());
+        }
+    }
+
+    fn walk(
+        statements: &[Statement],
+        order: &mut Vec<String>,
+        seen: &mut std::collections::HashSet<String>,
+    ) {
+        for statement in statements {
+            match statement {
+                Statement::SequenceMessage(msg) => {
+                    add(order, seen, &msg.from);
+                    add(order, seen, &msg.to);
+                }
+                Statement::SequenceNote(note) => {
+                    for id in &note.over {
+                        add(order, seen, id);
+                    }
+                }
+                Statement::SequenceActivation(a) => add(order, seen, &a.participant),
+                Statement::SequenceBlock(block) => {
+                    for branch in &block.branches {
+                        walk(&branch.statements, order, seen);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut order: Vec<String> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    // Declarations first, in declaration order.
+    for statement in statements {
+        if let Statement::SequenceParticipant(p) = statement {
+            add(&mut order, &mut seen, &p.id);
+        }
+    }
+    // Then implicit first appearances (recursing into blocks).
+    walk(statements, &mut order, &mut seen);
+    order
+}
+
+impl MermaidAst {
+    /// Sequence statements with structured fragments nested
+    /// ([`nest_sequence_statements`]).
+    pub fn sequence_tree(&self) -> Result<Vec<Statement>, MermaidError> {
+        nest_sequence_statements(&self.statements)
+    }
+}
+
 fn parse_gantt(line: &str, span: Span) -> Option<Statement> {
     let lower = line.to_ascii_lowercase();
     // Match keywords case-insensitively but extract values from the
@@ -14646,6 +14918,150 @@ B --> C
         assert!(!ir.ir.sequence_controls.is_empty());
     }
 
+    // --- Structured sequence fragments (nesting) ---
+
+    /// The mermaid docs' canonical sequence example.
+    const CANONICAL_SEQUENCE: &str = "sequenceDiagram\n\
+        participant Alice\n\
+        participant Bob\n\
+        Alice->>+John: Hello John, how are you?\n\
+        Alice->>+John: John, can you hear me?\n\
+        John-->>-Alice: Hi Alice, I can hear you!\n\
+        John-->>-Alice: I feel great!\n";
+
+    #[test]
+    fn canonical_sequence_example_structure() {
+        let ast = parse(CANONICAL_SEQUENCE).expect("parse");
+        let tree = ast.sequence_tree().expect("balanced");
+
+        let participants: Vec<&str> = tree
+            .iter()
+            .filter_map(|s| match s {
+                Statement::SequenceParticipant(p) => Some(p.id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(participants, vec!["Alice", "Bob"]);
+
+        let messages = tree
+            .iter()
+            .filter(|s| matches!(s, Statement::SequenceMessage(_)))
+            .count();
+        assert_eq!(messages, 4);
+
+        // Each +/- arrow expands into an explicit activation statement.
+        let activations: Vec<(String, bool)> = tree
+            .iter()
+            .filter_map(|s| match s {
+                Statement::SequenceActivation(a) => Some((a.participant.clone(), a.activate)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            activations,
+            vec![
+                ("John".to_string(), true),
+                ("John".to_string(), true),
+                ("John".to_string(), false),
+                ("John".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn activation_suffix_inference_strips_markers() {
+        let ast = parse("sequenceDiagram\n  A->>+B: hi\n  B-->>-A: yo").expect("parse");
+        let messages: Vec<&SequenceMessage> = ast
+            .statements
+            .iter()
+            .filter_map(|s| match s {
+                Statement::SequenceMessage(m) => Some(m),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].to, "B");
+        assert!(messages[0].activates_target);
+        assert_eq!(messages[1].to, "A");
+        assert!(messages[1].deactivates_sender);
+    }
+
+    #[test]
+    fn implicit_participants_follow_declared_order() {
+        let input = "sequenceDiagram\n\
+            participant B as Bob\n\
+            C->>A: implicit first\n\
+            B->>C: hi\n";
+        let ast = parse(input).expect("parse");
+        let order = sequence_participant_order(&ast.statements);
+        // Declared B first; then C and A in first-appearance order.
+        assert_eq!(order, vec!["B", "C", "A"]);
+    }
+
+    #[test]
+    fn blocks_nest_instead_of_flattening() {
+        let input = "sequenceDiagram\n\
+            alt ok\n\
+              A->>B: yes\n\
+              loop retry\n\
+                A->>B: again\n\
+              end\n\
+            else failed\n\
+              A->>B: no\n\
+            end\n";
+        let ast = parse(input).expect("parse");
+        let tree = ast.sequence_tree().expect("balanced");
+
+        // The top level holds exactly one block; messages are inside it.
+        assert_eq!(tree.len(), 1);
+        let Statement::SequenceBlock(alt) = &tree[0] else {
+            panic!("expected block, got {:?}", tree[0]);
+        };
+        assert_eq!(alt.kind, SeqControlKind::Alt);
+        assert_eq!(alt.label.as_deref(), Some("ok"));
+        assert_eq!(alt.branches.len(), 2);
+
+        // First branch: a message and a nested loop block.
+        let first = &alt.branches[0];
+        assert_eq!(first.label, None);
+        assert_eq!(first.statements.len(), 2);
+        assert!(matches!(first.statements[0], Statement::SequenceMessage(_)));
+        let Statement::SequenceBlock(inner) = &first.statements[1] else {
+            panic!("expected nested loop");
+        };
+        assert_eq!(inner.kind, SeqControlKind::Loop);
+        assert_eq!(inner.label.as_deref(), Some("retry"));
+        assert_eq!(inner.branches.len(), 1);
+        assert_eq!(inner.branches[0].statements.len(), 1);
+
+        // Else branch carries its label.
+        let second = &alt.branches[1];
+        assert_eq!(second.label.as_deref(), Some("failed"));
+        assert_eq!(second.statements.len(), 1);
+    }
+
+    #[test]
+    fn unbalanced_alt_reports_opening_span() {
+        let input = "sequenceDiagram\n  A->>B: hi\n  alt broken\n    A->>B: x\n";
+        let ast = parse(input).expect("parse");
+        let err = ast.sequence_tree().unwrap_err();
+        assert!(err.message.contains("unclosed 'alt'"));
+        // The error points at the line that opened the fragment.
+        assert_eq!(err.span.start.line, 3);
+    }
+
+    #[test]
+    fn stray_end_and_misplaced_else_are_errors() {
+        let ast = parse("sequenceDiagram\n  A->>B: hi\n  end").expect("parse");
+        let err = ast.sequence_tree().unwrap_err();
+        assert!(err.message.contains("'end' without"));
+        assert_eq!(err.span.start.line, 3);
+
+        let ast = parse("sequenceDiagram\n  loop x\n  else nope\n  end").expect("parse");
+        let err = ast.sequence_tree().unwrap_err();
+        assert!(err.message.contains("does not belong"));
+    }
+
     #[test]
     fn sequence_stress_fixture_parses_all_constructs() {
         let input = include_str!("../tests/fixtures/mermaid/sequence_stress.mmd");
@@ -566,13 +566,7 @@ impl GenericTokenizer {
     fn continue_block_comment(&self, line: &str, state_in: LineState) -> (Vec<Token>, LineState) {
         let initial_depth = match state_in {
             LineState::InComment(CommentKind::Nested(d)) => d,
-            LineState::InComment(CommentKind::Block) => {
-                if self.config.nested_comments {
-                    1
-                } else {
-                    0
-                }
-            }
+            LineState::InComment(CommentKind::Block) if self.config.nested_comments => 1,
             _ => 0,
         };
 
@@ -2524,6 +2518,21 @@ impl Default for SyntaxHighlighter {
     }
 }
 
+/// One-shot convenience: highlight `text` for `lang` into styled lines.
+///
+/// Builds a full [`SyntaxHighlighter`] (all built-in grammars, dark
+/// theme) per call — construct and reuse a highlighter for hot paths.
+/// Unknown languages fall back to plain text.
+#[must_use]
+pub fn highlight_into_spans(text: &str, lang: &str) -> Vec<Line<'static>> {
+    SyntaxHighlighter::new()
+        .highlight(text, lang)
+        .lines()
+        .iter()
+        .map(|line| Line::from_spans(line.spans().iter().map(|s| s.clone().into_owned())))
+        .collect()
+}
+
 impl SyntaxHighlighter {
     /// Create a highlighter with all built-in tokenizers and the dark theme.
     #[must_use]
@@ -2629,6 +2638,15 @@ impl SyntaxHighlighter {
         Text::from_lines(lines)
     }
 
+    /// Highlight code into per-line styled [`Line`]s for widget rendering.
+    ///
+    /// Same resolution and fallback rules as [`SyntaxHighlighter::highlight`];
+    /// the `Vec<Line>` form slots directly into list/paragraph widgets.
+    #[must_use]
+    pub fn highlight_lines<'a>(&self, code: &'a str, lang: &str) -> Vec<Line<'a>> {
+        self.highlight(code, lang).lines().to_vec()
+    }
+
     /// Highlight code with line numbers prepended.
     #[must_use]
     pub fn highlight_numbered<'a>(&self, code: &'a str, lang: &str, start_line: usize) -> Text<'a> {
@@ -2923,13 +2941,7 @@ impl Tokenizer for TomlTokenizer {
                     pos = len;
                 }
                 b'[' => {
-                    let mut is_heading = true;
-                    for i in 0..pos {
-                        if bytes[i] != b' ' && bytes[i] != b'\t' {
-                            is_heading = false;
-                            break;
-                        }
-                    }
+                    let is_heading = bytes[..pos].iter().all(|&b| b == b' ' || b == b'\t');
 
                     if is_heading {
                         // Table header or array of tables
@@ -5410,4 +5422,92 @@ fn main() {
             assert_eq!(a, b);
         }
     }
+
+    // ── highlight_into_spans + alias selection ──────────────────────
+
+    #[test]
+    fn highlight_into_spans_produces_styled_lines() {
+        let lines = highlight_into_spans("let x = 42;\nlet y = 1;", "rs");
+        assert_eq!(lines.len(), 2);
+        // The keyword span carries a style from the theme.
+        let styled = lines[0]
+            .spans()
+            .iter()
+            .any(|span| span.as_str() == "let" && span.style.is_some());
+        assert!(styled, "expected a styled keyword span, got {:?}", lines[0]);
+    }
+
+    #[test]
+    fn language_aliases_resolve_rs_js_sh() {
+        let hl = SyntaxHighlighter::new();
+        for (alias, keyword) in [("rs", "fn"), ("js", "function"), ("sh", "if")] {
+            assert!(hl.supports_language(alias), "alias {alias} must resolve");
+            let code = format!("{keyword} x");
+            let text = hl.highlight(&code, alias);
+            let has_keyword_style = text.lines()[0]
+                .spans()
+                .iter()
+                .any(|span| span.as_str() == keyword && span.style.is_some());
+            assert!(has_keyword_style, "{alias}: keyword {keyword} unstyled");
+        }
+    }
+
+    #[test]
+    fn unknown_language_falls_back_to_plain() {
+        let lines = highlight_into_spans("whatever tokens", "no-such-lang");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].to_plain_text(), "whatever tokens");
+    }
+
+    #[test]
+    fn incremental_update_touches_only_edited_line() {
+        let t = rust_tokenizer();
+        let lines = ["let a = 1;", "let b = 2;", "let c = 3;"];
+        let mut cache = TokenizedText::from_lines(&t, &lines);
+        let before_first = cache.lines()[0].tokens.clone();
+        let before_third = cache.lines()[2].tokens.clone();
+
+        // Edit only the middle line.
+        let edited = ["let a = 1;", "let bb = 22;", "let c = 3;"];
+        cache.update_line(&t, &edited, 1);
+
+        assert_eq!(
+            cache.lines()[0].tokens, before_first,
+            "untouched line 0 keeps identical tokens"
+        );
+        assert_eq!(
+            cache.lines()[2].tokens, before_third,
+            "untouched line 2 keeps identical tokens"
+        );
+        // The edited line re-tokenized to the new content.
+        let middle_text: Vec<&str> = cache.lines()[1]
+            .tokens
+            .iter()
+            .map(|tok| tok.text("let bb = 22;"))
+            .collect();
+        assert!(middle_text.contains(&"bb"), "got {middle_text:?}");
+    }
+
+    #[test]
+    fn yaml_golden_token_stream() {
+        let t = yaml_tokenizer();
+        let (tokens, state) = t.tokenize_line("key: \"value\" # note", LineState::Normal);
+        assert_eq!(state, LineState::Normal);
+        let kinds: Vec<(&str, TokenKind)> = tokens
+            .iter()
+            .map(|tok| (tok.text("key: \"value\" # note"), tok.kind))
+            .collect();
+        assert!(
+            kinds
+                .iter()
+                .any(|(text, kind)| *text == "\"value\"" && kind.is_string()),
+            "string token expected: {kinds:?}"
+        );
+        assert!(
+            kinds
+                .iter()
+                .any(|(text, kind)| text.starts_with('#') && kind.is_comment()),
+            "comment token expected: {kinds:?}"
+        );
+    }
 }
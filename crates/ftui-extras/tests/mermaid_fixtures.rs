@@ -604,7 +604,8 @@ mod tests {
                 Statement::SequenceParticipant(_)
                 | Statement::SequenceNote(_)
                 | Statement::SequenceActivation(_)
-                | Statement::SequenceControl(_) => counts.sequence += 1,
+                | Statement::SequenceControl(_)
+                | Statement::SequenceBlock(_) => counts.sequence += 1,
                 Statement::Raw { .. } => counts.raw += 1,
             }
         }
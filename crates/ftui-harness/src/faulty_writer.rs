@@ -0,0 +1,163 @@
+#![forbid(unsafe_code)]
+
+//! Fault-injection writer for presenter I/O error handling.
+//!
+//! A real terminal fd short-writes, returns `EAGAIN`, or dies with
+//! `EPIPE`; [`FaultyWriter`] reproduces those failure modes over an
+//! in-memory sink so the presenter's resume/retry/teardown paths can be
+//! exercised deterministically:
+//!
+//! - [`short_writes`](FaultyWriter::short_writes): accept at most N bytes
+//!   per call (the classic partial write);
+//! - [`would_block_every`](FaultyWriter::would_block_every): every Nth
+//!   write call fails with [`ErrorKind::WouldBlock`] once;
+//! - [`fail_after`](FaultyWriter::fail_after): permanent
+//!   [`ErrorKind::BrokenPipe`] once M bytes were accepted (the terminal
+//!   went away mid-frame).
+//!
+//! Modes combine; see `tests/presenter_fault_injection.rs` for the
+//! end-to-end byte-stream-equality assertions.
+
+use std::io::{self, ErrorKind, Write};
+
+/// A `Write` sink with configurable fault injection.
+#[derive(Debug, Default)]
+pub struct FaultyWriter {
+    /// Bytes that were actually accepted.
+    out: Vec<u8>,
+    /// Maximum bytes accepted per write call (None = unlimited).
+    short_write_max: Option<usize>,
+    /// Every Nth write call fails with `WouldBlock` (None = never).
+    would_block_every: Option<u64>,
+    /// Permanent failure once this many bytes were accepted.
+    fail_after: Option<u64>,
+    /// Total write calls observed (drives `would_block_every`).
+    write_calls: u64,
+    /// `WouldBlock` faults injected so far.
+    pub blocks_injected: u64,
+    /// Short writes injected so far.
+    pub shorts_injected: u64,
+}
+
+impl FaultyWriter {
+    /// A writer with no faults configured.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept at most `max` bytes per write call (builder).
+    #[must_use]
+    pub fn short_writes(mut self, max: usize) -> Self {
+        self.short_write_max = Some(max.max(1));
+        self
+    }
+
+    /// Fail every `n`th write call with `WouldBlock` (builder).
+    #[must_use]
+    pub fn would_block_every(mut self, n: u64) -> Self {
+        self.would_block_every = Some(n.max(1));
+        self
+    }
+
+    /// Fail permanently with `BrokenPipe` after `bytes` accepted bytes
+    /// (builder).
+    #[must_use]
+    pub fn fail_after(mut self, bytes: u64) -> Self {
+        self.fail_after = Some(bytes);
+        self
+    }
+
+    /// The bytes the sink actually accepted.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.out
+    }
+
+    /// Consume, returning the accepted bytes.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.out
+    }
+}
+
+impl Write for FaultyWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(limit) = self.fail_after
+            && self.out.len() as u64 >= limit
+        {
+            return Err(io::Error::new(
+                ErrorKind::BrokenPipe,
+                "terminal closed (injected)",
+            ));
+        }
+        self.write_calls += 1;
+        if let Some(n) = self.would_block_every
+            && self.write_calls.is_multiple_of(n)
+        {
+            self.blocks_injected += 1;
+            return Err(io::Error::new(ErrorKind::WouldBlock, "EAGAIN (injected)"));
+        }
+        let mut accept = buf.len();
+        if let Some(max) = self.short_write_max
+            && accept > max
+        {
+            accept = max;
+            self.shorts_injected += 1;
+        }
+        if let Some(limit) = self.fail_after {
+            // Accept only up to the failure point; the next call fails.
+            accept = accept.min((limit - self.out.len() as u64) as usize);
+            if accept == 0 {
+                return Err(io::Error::new(
+                    ErrorKind::BrokenPipe,
+                    "terminal closed (injected)",
+                ));
+            }
+        }
+        self.out.extend_from_slice(&buf[..accept]);
+        Ok(accept)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_write_mode_accepts_bounded_chunks() {
+        let mut w = FaultyWriter::new().short_writes(4);
+        assert_eq!(w.write(b"0123456789").unwrap(), 4);
+        assert_eq!(w.write(b"456789").unwrap(), 4);
+        assert_eq!(w.write(b"89").unwrap(), 2);
+        assert_eq!(w.bytes(), b"0123456789");
+        assert_eq!(w.shorts_injected, 2);
+    }
+
+    #[test]
+    fn would_block_mode_fails_every_nth_call() {
+        let mut w = FaultyWriter::new().would_block_every(2);
+        assert_eq!(w.write(b"a").unwrap(), 1);
+        assert_eq!(
+            w.write(b"b").unwrap_err().kind(),
+            ErrorKind::WouldBlock,
+            "second call blocks"
+        );
+        assert_eq!(w.write(b"b").unwrap(), 1);
+        assert_eq!(w.bytes(), b"ab");
+        assert_eq!(w.blocks_injected, 1);
+    }
+
+    #[test]
+    fn fail_after_mode_is_permanent() {
+        let mut w = FaultyWriter::new().fail_after(3);
+        assert_eq!(w.write(b"abcdef").unwrap(), 3, "truncated at the limit");
+        assert_eq!(w.write(b"def").unwrap_err().kind(), ErrorKind::BrokenPipe);
+        assert_eq!(w.write(b"def").unwrap_err().kind(), ErrorKind::BrokenPipe);
+        assert_eq!(w.bytes(), b"abc");
+    }
+}
@@ -685,8 +685,12 @@ impl FlickerDetector {
     fn handle_erase_line(&mut self) {
         let mode = self.csi_params.first().copied().unwrap_or(0);
 
-        // Partial line erase during frame can cause flicker
-        if self.sync_active && mode != 2 {
+        // EL to end of line (mode 0) inside the sync bracket is the
+        // presenter's blank-tail optimization on a dirty row; nothing is
+        // presented until the bracket closes, so it cannot flicker. EL to
+        // start of line (mode 1) mid-frame still indicates a
+        // clear-then-redraw pattern worth flagging.
+        if self.sync_active && mode == 1 {
             self.emit_event(
                 EventType::PartialClear,
                 Severity::Warning,
@@ -948,17 +952,18 @@ mod tests {
     }
 
     #[test]
-    fn partial_line_erase_detected() {
-        // EL with mode 0 (erase to end of line) during frame
+    fn el_to_eol_inside_sync_is_ok() {
+        // EL mode 0 (erase to end of line) inside the sync bracket is the
+        // presenter's blank-tail optimization — not user-visible flicker.
         let mut frame = Vec::new();
         frame.extend_from_slice(SYNC_BEGIN);
-        frame.extend_from_slice(b"\x1b[0K"); // Erase to end of line
         frame.extend_from_slice(b"Content");
+        frame.extend_from_slice(b"\x1b[0K"); // Erase to end of line
         frame.extend_from_slice(SYNC_END);
 
         let analysis = analyze_stream(&frame);
-        assert!(!analysis.flicker_free);
-        assert_eq!(analysis.stats.partial_clears, 1);
+        assert!(analysis.flicker_free);
+        assert_eq!(analysis.stats.partial_clears, 0);
     }
 
     #[test]
@@ -1785,12 +1790,12 @@ mod tests {
         frame.extend_from_slice(SYNC_BEGIN);
         frame.extend_from_slice(b"\x1b[0J"); // Partial ED to-end
         frame.extend_from_slice(b"\x1b[1J"); // Partial ED to-start
-        frame.extend_from_slice(b"\x1b[0K"); // Partial EL to-end
+        frame.extend_from_slice(b"\x1b[0K"); // EL to-end: allowed in sync
         frame.extend_from_slice(b"\x1b[1K"); // Partial EL to-start
         frame.extend_from_slice(SYNC_END);
 
         let analysis = analyze_stream(&frame);
-        assert_eq!(analysis.stats.partial_clears, 4);
+        assert_eq!(analysis.stats.partial_clears, 3);
     }
 
     #[test]
@@ -459,6 +459,25 @@ pub fn is_bless_mode() -> bool {
     std::env::var("BLESS").is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
 }
 
+/// Write PNG artifacts for a failed golden comparison (feature `rasterize`).
+///
+/// Golden storage keeps only checksums, so the expected buffer is usually
+/// unavailable — `actual.png` is always produced, `expected.png` and
+/// `diff.png` only when an expected buffer is supplied. Artifacts land in
+/// `$FTUI_GOLDEN_ARTIFACTS` (default `target/golden-failures`) so a CI
+/// hash mismatch leaves something a human can look at.
+#[cfg(feature = "rasterize")]
+pub fn write_failure_pngs(
+    case: &str,
+    expected: Option<&Buffer>,
+    actual: &Buffer,
+) -> std::io::Result<Vec<PathBuf>> {
+    let dir = std::env::var_os("FTUI_GOLDEN_ARTIFACTS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target/golden-failures"));
+    crate::rasterize::write_failure_artifacts(&dir, case, expected, actual)
+}
+
 /// Check if golden checksums are enforced (CI or explicit env).
 pub fn is_golden_enforced() -> bool {
     let explicit = std::env::var("FTUI_GOLDEN_ENFORCE")
@@ -182,6 +182,7 @@ fn generate_keyboard_storm(count: usize, rng: &mut Rng) -> Vec<Event> {
             code: KeyCode::Char(ch),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         }));
     }
     events
@@ -224,6 +225,7 @@ fn generate_mixed_burst(count: usize, width: u16, height: u16, rng: &mut Rng) ->
                     code: KeyCode::Char(ch),
                     modifiers: Modifiers::empty(),
                     kind: KeyEventKind::Press,
+                    ch: None,
                 })
             }
             5..=7 => {
@@ -243,10 +245,7 @@ fn generate_mixed_burst(count: usize, width: u16, height: u16, rng: &mut Rng) ->
                 // 10% small paste
                 let len = (rng.next() % 50) as usize + 5;
                 let text: String = (0..len).map(|_| rng.next_char()).collect();
-                Event::Paste(PasteEvent {
-                    text,
-                    bracketed: true,
-                })
+                Event::Paste(PasteEvent::bracketed(text))
             }
             _ => {
                 // 10% resize
@@ -265,10 +264,7 @@ fn generate_mixed_burst(count: usize, width: u16, height: u16, rng: &mut Rng) ->
 
 fn generate_long_paste(size_bytes: usize, rng: &mut Rng) -> Vec<Event> {
     let text: String = (0..size_bytes).map(|_| rng.next_char()).collect();
-    vec![Event::Paste(PasteEvent {
-        text,
-        bracketed: true,
-    })]
+    vec![Event::Paste(PasteEvent::bracketed(text))]
 }
 
 fn generate_rapid_resize(count: usize, rng: &mut Rng) -> Vec<Event> {
@@ -44,6 +44,7 @@
 
 pub mod asciicast;
 pub mod determinism;
+pub mod faulty_writer;
 pub mod flicker_detection;
 pub mod golden;
 pub mod hdd;
@@ -53,10 +54,17 @@ pub mod terminal_model;
 pub mod time_travel;
 pub mod time_travel_inspector;
 pub mod trace_replay;
+pub mod vt_fuzz;
 
 #[cfg(feature = "pty-capture")]
 pub mod pty_capture;
 
+#[cfg(feature = "pty-capture")]
+pub mod pty_harness;
+
+#[cfg(feature = "rasterize")]
+pub mod rasterize;
+
 use std::fmt::Write as FmtWrite;
 use std::path::{Path, PathBuf};
 
@@ -361,13 +369,7 @@ pub fn current_test_profile() -> Option<TerminalProfile> {
     std::env::var("FTUI_TEST_PROFILE")
         .ok()
         .and_then(|value| value.parse::<TerminalProfile>().ok())
-        .and_then(|profile| {
-            if profile == TerminalProfile::Detected {
-                None
-            } else {
-                Some(profile)
-            }
-        })
+        .filter(|&profile| profile != TerminalProfile::Detected)
 }
 
 fn snapshot_name_with_profile(name: &str) -> String {
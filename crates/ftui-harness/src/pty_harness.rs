@@ -0,0 +1,415 @@
+#![forbid(unsafe_code)]
+
+//! PTY-based end-to-end test driver (feature `pty-capture`, unix-first).
+//!
+//! Buffer-level tests never exercise the real read-input → parse → update →
+//! present loop; [`PtyHarness`] does. It spawns the program under test
+//! attached to a PTY of a chosen size, writes input bytes (including split
+//! escape sequences), assembles the output stream, resizes the PTY
+//! mid-test, and captures the final teardown bytes so terminal restoration
+//! can be verified. Assertion helpers reuse the flicker-detection
+//! analyzers over the live stream.
+
+use std::io::{self, Read, Write};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use portable_pty::{Child, CommandBuilder, MasterPty, PtySize};
+
+use crate::flicker_detection::{AnalysisStats, FlickerDetector};
+
+/// A program under test attached to a pseudo-terminal.
+pub struct PtyHarness {
+    child: Box<dyn Child + Send + Sync>,
+    master: Box<dyn MasterPty>,
+    writer: Box<dyn Write + Send>,
+    rx: mpsc::Receiver<ReaderMsg>,
+    reader_thread: Option<thread::JoinHandle<()>>,
+    captured: Vec<u8>,
+    eof: bool,
+}
+
+enum ReaderMsg {
+    Data(Vec<u8>),
+    Eof,
+    Err(io::Error),
+}
+
+impl PtyHarness {
+    /// Spawn `cmd` attached to a fresh PTY of the given size.
+    pub fn spawn(cmd: CommandBuilder, cols: u16, rows: u16) -> io::Result<Self> {
+        let pty_system = portable_pty::native_pty_system();
+        let pair = pty_system
+            .openpty(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(io::Error::other)?;
+
+        let child = pair.slave.spawn_command(cmd).map_err(io::Error::other)?;
+        drop(pair.slave);
+
+        let mut reader = pair.master.try_clone_reader().map_err(io::Error::other)?;
+        let writer = pair.master.take_writer().map_err(io::Error::other)?;
+
+        let (tx, rx) = mpsc::channel();
+        let reader_thread = thread::spawn(move || {
+            let mut buf = [0u8; 8192];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) => {
+                        let _ = tx.send(ReaderMsg::Eof);
+                        break;
+                    }
+                    Ok(n) => {
+                        if tx.send(ReaderMsg::Data(buf[..n].to_vec())).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        let _ = tx.send(ReaderMsg::Err(err));
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            child,
+            master: pair.master,
+            writer,
+            rx,
+            reader_thread: Some(reader_thread),
+            captured: Vec::new(),
+            eof: false,
+        })
+    }
+
+    /// Write raw input bytes to the program's stdin.
+    ///
+    /// Escape sequences may be split across calls — the program's parser
+    /// must reassemble them, which is exactly what this harness exercises.
+    pub fn write_input(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.writer.write_all(bytes)?;
+        self.writer.flush()
+    }
+
+    /// Resize the PTY (delivers SIGWINCH to the program).
+    pub fn resize(&mut self, cols: u16, rows: u16) -> io::Result<()> {
+        self.master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(io::Error::other)
+    }
+
+    /// Pump output for `duration`, appending to the captured stream.
+    pub fn pump_for(&mut self, duration: Duration) -> io::Result<usize> {
+        let deadline = Instant::now() + duration;
+        let mut collected = 0usize;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            match self.rx.recv_timeout(remaining) {
+                Ok(ReaderMsg::Data(data)) => {
+                    collected += data.len();
+                    self.captured.extend_from_slice(&data);
+                }
+                Ok(ReaderMsg::Eof) => {
+                    self.eof = true;
+                    break;
+                }
+                Ok(ReaderMsg::Err(err)) => return Err(err),
+                Err(_) => break,
+            }
+        }
+        Ok(collected)
+    }
+
+    /// Pump output until `pattern` appears in the stream (or time out).
+    pub fn pump_until(&mut self, pattern: &[u8], timeout: Duration) -> io::Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self
+                .captured
+                .windows(pattern.len().max(1))
+                .any(|w| w == pattern)
+            {
+                return Ok(());
+            }
+            if self.eof {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "pattern not found before EOF",
+                ));
+            }
+            let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "pattern not found before timeout",
+                ));
+            };
+            match self.rx.recv_timeout(remaining) {
+                Ok(ReaderMsg::Data(data)) => self.captured.extend_from_slice(&data),
+                Ok(ReaderMsg::Eof) => self.eof = true,
+                Ok(ReaderMsg::Err(err)) => return Err(err),
+                Err(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "pattern not found before timeout",
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Everything read from the program so far.
+    #[must_use]
+    pub fn captured(&self) -> &[u8] {
+        &self.captured
+    }
+
+    /// Output appended since the given offset (for windowed assertions).
+    #[must_use]
+    pub fn captured_since(&self, offset: usize) -> &[u8] {
+        &self.captured[offset.min(self.captured.len())..]
+    }
+
+    /// Wait for the program to exit, draining all remaining output
+    /// (including teardown bytes). Returns the exit status and the full
+    /// stream.
+    pub fn wait_exit(
+        mut self,
+        timeout: Duration,
+    ) -> io::Result<(portable_pty::ExitStatus, Vec<u8>)> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(status) = self.child.try_wait().map_err(io::Error::other)? {
+                // Drain whatever is still buffered after exit.
+                let _ = self.pump_for(Duration::from_millis(200));
+                if let Some(handle) = self.reader_thread.take() {
+                    let _ = handle.join();
+                }
+                return Ok((status, self.captured));
+            }
+            if Instant::now() >= deadline {
+                let _ = self.child.kill();
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "program did not exit before timeout",
+                ));
+            }
+            let _ = self.pump_for(Duration::from_millis(20))?;
+        }
+    }
+
+    /// Kill the program (cleanup for failed tests).
+    pub fn kill(&mut self) -> io::Result<()> {
+        self.child.kill().map_err(io::Error::other)
+    }
+
+    /// Run the flicker-detection analyzers over the captured stream.
+    #[must_use]
+    pub fn flicker_stats(&self) -> AnalysisStats {
+        analyze_stream(&self.captured)
+    }
+}
+
+/// Feed a byte stream through the flicker detector and return its stats.
+#[must_use]
+pub fn analyze_stream(bytes: &[u8]) -> AnalysisStats {
+    let mut detector = FlickerDetector::new("pty-harness");
+    detector.feed(bytes);
+    detector.stats().clone()
+}
+
+/// Count of synchronized-output begin/end brackets in a stream.
+#[must_use]
+pub fn sync_bracket_counts(bytes: &[u8]) -> (usize, usize) {
+    let count = |needle: &[u8]| {
+        bytes
+            .windows(needle.len())
+            .filter(|window| *window == needle)
+            .count()
+    };
+    (count(b"\x1b[?2026h"), count(b"\x1b[?2026l"))
+}
+
+/// Assert that synchronized-output brackets are balanced.
+///
+/// Every begin must be closed before the stream ends and brackets must not
+/// nest. Defensive extra ends (the teardown path emits one unconditionally
+/// to unwedge terminals) are no-ops at depth zero and are allowed.
+///
+/// # Panics
+///
+/// Panics when a begin is left open or brackets nest.
+pub fn assert_balanced_sync_brackets(bytes: &[u8]) {
+    const BEGIN: &[u8] = b"\x1b[?2026h";
+    const END: &[u8] = b"\x1b[?2026l";
+    let mut depth = 0usize;
+    let mut i = 0usize;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(BEGIN) {
+            depth += 1;
+            assert!(depth <= 1, "nested sync brackets at byte {i}");
+            i += BEGIN.len();
+        } else if bytes[i..].starts_with(END) {
+            depth = depth.saturating_sub(1);
+            i += END.len();
+        } else {
+            i += 1;
+        }
+    }
+    assert_eq!(depth, 0, "sync bracket left open at end of stream");
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use ftui_core::event::{Event, KeyCode, KeyEvent};
+    use ftui_render::cell::Cell;
+    use ftui_render::frame::Frame;
+    use ftui_runtime::program::{Cmd, Model, Program, ProgramConfig};
+
+    /// Full-screen model that fills every row so repaints address the
+    /// whole grid (regression guard against 1x1 headless fallbacks).
+    struct FillScreen {
+        ticks: u32,
+    }
+
+    impl Model for FillScreen {
+        type Message = Event;
+
+        fn init(&mut self) -> Cmd<Event> {
+            Cmd::tick(Duration::from_millis(5))
+        }
+
+        fn update(&mut self, msg: Event) -> Cmd<Event> {
+            match msg {
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char('q'),
+                    ..
+                }) => Cmd::Quit,
+                Event::Tick => {
+                    self.ticks += 1;
+                    // Safety valve: never outlive a stuck parent.
+                    if self.ticks >= 2000 {
+                        Cmd::Quit
+                    } else {
+                        Cmd::None
+                    }
+                }
+                _ => Cmd::None,
+            }
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            let (width, height) = (frame.buffer.width(), frame.buffer.height());
+            for y in 0..height {
+                let line = format!("row {y:03} tick {:05} {}", self.ticks, "x".repeat(40));
+                for (x, ch) in line.chars().enumerate() {
+                    if (x as u16) < width {
+                        frame.buffer.set(x as u16, y, Cell::from_char(ch));
+                    }
+                }
+            }
+        }
+    }
+
+    const CHILD_ENV: &str = "FTUI_HARNESS_PTY_CHILD";
+    const TEST_NAME: &str = "pty_harness::tests::pty_smoke_resize_repaint";
+
+    fn run_child() {
+        let config = ProgramConfig::fullscreen();
+        let mut program =
+            Program::with_config(FillScreen { ticks: 0 }, config).expect("program in PTY");
+        program.run().expect("program run");
+    }
+
+    /// Smoke test: 50 frames at 80x24, resize to 120x40, no panic,
+    /// balanced sync brackets, and repaints addressing the real grid.
+    #[test]
+    fn pty_smoke_resize_repaint() {
+        if std::env::var(CHILD_ENV).is_ok() {
+            run_child();
+            return;
+        }
+
+        let exe = std::env::current_exe().expect("current_exe");
+        let mut cmd = CommandBuilder::new(exe);
+        cmd.args(["--exact", TEST_NAME, "--nocapture"]);
+        cmd.env(CHILD_ENV, "1");
+        cmd.env("FTUI_TEST_PROFILE", "modern");
+        cmd.env("RUST_BACKTRACE", "0");
+
+        let mut pty = PtyHarness::spawn(cmd, 80, 24).expect("spawn under PTY");
+
+        // Wait for at least 50 presented frames (one sync bracket each).
+        let deadline = Instant::now() + Duration::from_secs(15);
+        loop {
+            let (begins, _) = sync_bracket_counts(pty.captured());
+            if begins >= 50 {
+                break;
+            }
+            assert!(Instant::now() < deadline, "timed out waiting for 50 frames");
+            pty.pump_for(Duration::from_millis(50)).expect("pump");
+        }
+        let before_resize = pty.captured().len();
+
+        // The 80x24 frame must address rows well past 1 (non-1x1 guard).
+        let head = String::from_utf8_lossy(pty.captured());
+        assert!(
+            head.contains("\x1b[24;") || head.contains("row 023"),
+            "expected full-height repaint at 80x24"
+        );
+
+        pty.resize(120, 40).expect("resize PTY");
+        pty.pump_for(Duration::from_millis(500)).expect("pump");
+        let after = String::from_utf8_lossy(pty.captured_since(before_resize));
+        assert!(
+            after.contains("row 039") || after.contains("\x1b[40;"),
+            "expected repaint at the new 120x40 size"
+        );
+
+        // Quit (split escape not needed for 'q', but exercise split input
+        // with a harmless arrow key first: ESC, then the rest).
+        pty.write_input(b"\x1b").expect("split input 1");
+        std::thread::sleep(Duration::from_millis(30));
+        pty.write_input(b"[B").expect("split input 2");
+        pty.write_input(b"q").expect("quit key");
+
+        let (status, stream) = pty.wait_exit(Duration::from_secs(10)).expect("clean exit");
+        assert!(status.success(), "child must not panic");
+
+        // Balanced sync brackets across the whole session, including the
+        // teardown bytes.
+        assert_balanced_sync_brackets(&stream);
+
+        // Terminal restoration: teardown leaves the alt screen and shows
+        // the cursor.
+        let tail = String::from_utf8_lossy(&stream);
+        assert!(
+            tail.contains("\x1b[?1049l") || tail.contains("\x1b[?1047l"),
+            "teardown must leave the alt screen"
+        );
+        assert!(tail.contains("\x1b[?25h"), "teardown must show the cursor");
+    }
+
+    #[test]
+    fn sync_bracket_counting() {
+        let stream = b"\x1b[?2026habc\x1b[?2026l\x1b[?2026hdef\x1b[?2026l";
+        assert_eq!(sync_bracket_counts(stream), (2, 2));
+        assert_balanced_sync_brackets(stream);
+    }
+
+    #[test]
+    fn analyze_stream_reports_stats() {
+        let stats = analyze_stream(b"\x1b[?2026h\x1b[2J\x1b[?2026l");
+        let _ = stats.sync_coverage();
+    }
+}
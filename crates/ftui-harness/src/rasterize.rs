@@ -0,0 +1,702 @@
+#![forbid(unsafe_code)]
+
+//! Deterministic offscreen rasterization of buffers to PNG (feature `rasterize`).
+//!
+//! CI can hash flat patches, but a hash mismatch gives a human nothing to
+//! look at. [`rasterize_buffer`] renders a [`Buffer`] into an RGBA image
+//! using an embedded fixed-size bitmap font — ASCII plus box drawing, with
+//! a replacement glyph for everything else (wide characters render it
+//! double-width) — honoring fg/bg colors, bold (double strike), underline
+//! and strikethrough. [`RgbaImage::encode_png`] produces a byte-identical
+//! PNG for identical buffers: the encoder uses stored (uncompressed)
+//! deflate blocks, so no compressor version can perturb the output.
+//!
+//! [`write_failure_artifacts`] hooks this into golden tests: on a
+//! mismatch it drops `expected.png` / `actual.png` / `diff.png` into the
+//! test output directory.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ftui_render::buffer::Buffer;
+use ftui_render::cell::PackedRgba;
+use ftui_render::cell::StyleFlags;
+
+/// Cell glyph size in pixels (width and height) at scale 1.
+pub const GLYPH_SIZE: u32 = 8;
+
+/// A fixed-size 8x8 bitmap font.
+///
+/// Each glyph is 8 rows of 8 bits; bit `1 << x` is the pixel at column
+/// `x` (leftmost = bit 0). Lookup falls back to a replacement glyph for
+/// unmapped characters.
+pub struct BitmapFont {
+    ascii: &'static [[u8; 8]; 95],
+}
+
+impl BitmapFont {
+    /// The built-in font: ASCII 0x20..=0x7E plus procedural box drawing.
+    #[must_use]
+    pub fn builtin() -> &'static Self {
+        static FONT: BitmapFont = BitmapFont { ascii: &FONT8X8 };
+        &FONT
+    }
+
+    /// Glyph bitmap for a character, or `None` when unmapped.
+    #[must_use]
+    pub fn glyph(&self, ch: char) -> Option<[u8; 8]> {
+        if (' '..='~').contains(&ch) {
+            return Some(self.ascii[ch as usize - 0x20]);
+        }
+        box_drawing_glyph(ch)
+    }
+
+    /// The replacement glyph used for unmapped characters.
+    #[must_use]
+    pub fn replacement(&self) -> [u8; 8] {
+        // Outlined box with an inner dot: distinctive and unambiguous.
+        [
+            0b0111_1110,
+            0b0100_0010,
+            0b0101_1010,
+            0b0101_1010,
+            0b0101_1010,
+            0b0101_1010,
+            0b0100_0010,
+            0b0111_1110,
+        ]
+    }
+}
+
+/// Procedurally built box-drawing glyphs (single and double lines).
+///
+/// Lines run through the cell center so adjacent cells connect
+/// seamlessly: horizontal at y=3, vertical at x=3 (doubles at 2 and 5).
+fn box_drawing_glyph(ch: char) -> Option<[u8; 8]> {
+    // Each arm: (up, down, left, right) single-line presence.
+    let arms = match ch {
+        '─' | '╴' | '╶' => (false, false, true, true),
+        '│' | '╵' | '╷' => (true, true, false, false),
+        '┌' | '╭' => (false, true, false, true),
+        '┐' | '╮' => (false, true, true, false),
+        '└' | '╰' => (true, false, false, true),
+        '┘' | '╯' => (true, false, true, false),
+        '├' => (true, true, false, true),
+        '┤' => (true, true, true, false),
+        '┬' => (false, true, true, true),
+        '┴' => (true, false, true, true),
+        '┼' => (true, true, true, true),
+        '═' => {
+            return Some(double_lines(false, false, true, true));
+        }
+        '║' => {
+            return Some(double_lines(true, true, false, false));
+        }
+        '╔' => {
+            return Some(double_lines(false, true, false, true));
+        }
+        '╗' => {
+            return Some(double_lines(false, true, true, false));
+        }
+        '╚' => {
+            return Some(double_lines(true, false, false, true));
+        }
+        '╝' => {
+            return Some(double_lines(true, false, true, false));
+        }
+        '╠' => {
+            return Some(double_lines(true, true, false, true));
+        }
+        '╣' => {
+            return Some(double_lines(true, true, true, false));
+        }
+        '╦' => {
+            return Some(double_lines(false, true, true, true));
+        }
+        '╩' => {
+            return Some(double_lines(true, false, true, true));
+        }
+        '╬' => {
+            return Some(double_lines(true, true, true, true));
+        }
+        '━' => (false, false, true, true),
+        '┃' => (true, true, false, false),
+        '█' => return Some([0xFF; 8]),
+        '▀' => return Some([0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]),
+        '▄' => return Some([0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]),
+        _ => return None,
+    };
+    Some(single_lines(arms.0, arms.1, arms.2, arms.3))
+}
+
+fn single_lines(up: bool, down: bool, left: bool, right: bool) -> [u8; 8] {
+    let mut rows = [0u8; 8];
+    let (cx, cy) = (3usize, 3usize);
+    if left {
+        rows[cy] |= 0b0000_1111; // x 0..=3
+    }
+    if right {
+        rows[cy] |= 0b1111_1000; // x 3..=7
+    }
+    if up {
+        for row in rows.iter_mut().take(cy + 1) {
+            *row |= 1 << cx;
+        }
+    }
+    if down {
+        for row in rows.iter_mut().skip(cy) {
+            *row |= 1 << cx;
+        }
+    }
+    rows
+}
+
+fn double_lines(up: bool, down: bool, left: bool, right: bool) -> [u8; 8] {
+    let mut rows = [0u8; 8];
+    let (a, b) = (2usize, 5usize);
+    if left {
+        rows[a] |= 0b0011_1111;
+        rows[b] |= 0b0011_1111;
+    }
+    if right {
+        rows[a] |= 0b1111_1100;
+        rows[b] |= 0b1111_1100;
+    }
+    if up {
+        for row in rows.iter_mut().take(b + 1) {
+            *row |= (1 << a) | (1 << b);
+        }
+    }
+    if down {
+        for row in rows.iter_mut().skip(a) {
+            *row |= (1 << a) | (1 << b);
+        }
+    }
+    rows
+}
+
+/// A simple owned RGBA8 image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Row-major RGBA8 pixel data (`width * height * 4` bytes).
+    pub pixels: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Create a black, fully opaque image.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut pixels = vec![0u8; (width as usize) * (height as usize) * 4];
+        for px in pixels.chunks_exact_mut(4) {
+            px[3] = 255;
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    #[inline]
+    fn put(&mut self, x: u32, y: u32, rgba: [u8; 4]) {
+        if x < self.width && y < self.height {
+            let idx = ((y * self.width + x) * 4) as usize;
+            self.pixels[idx..idx + 4].copy_from_slice(&rgba);
+        }
+    }
+
+    #[inline]
+    fn get(&self, x: u32, y: u32) -> [u8; 4] {
+        let idx = ((y * self.width + x) * 4) as usize;
+        [
+            self.pixels[idx],
+            self.pixels[idx + 1],
+            self.pixels[idx + 2],
+            self.pixels[idx + 3],
+        ]
+    }
+
+    /// Encode as a PNG (RGBA8, stored/uncompressed deflate blocks).
+    ///
+    /// Identical images produce byte-identical output: the encoder has no
+    /// tunable state and performs no real compression.
+    #[must_use]
+    pub fn encode_png(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.pixels.len() + 1024);
+        out.extend_from_slice(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+
+        // IHDR
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&self.width.to_be_bytes());
+        ihdr.extend_from_slice(&self.height.to_be_bytes());
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]); // 8-bit RGBA, deflate, no interlace
+        write_chunk(&mut out, b"IHDR", &ihdr);
+
+        // Raw scanlines with filter byte 0 (None) per row.
+        let stride = self.width as usize * 4;
+        let mut raw = Vec::with_capacity((stride + 1) * self.height as usize);
+        for y in 0..self.height as usize {
+            raw.push(0);
+            raw.extend_from_slice(&self.pixels[y * stride..(y + 1) * stride]);
+        }
+
+        // zlib stream: header, stored deflate blocks, adler32.
+        let mut idat = Vec::with_capacity(raw.len() + raw.len() / 65_535 * 5 + 16);
+        idat.extend_from_slice(&[0x78, 0x01]);
+        let mut offset = 0;
+        while offset < raw.len() || raw.is_empty() {
+            let chunk_len = (raw.len() - offset).min(65_535);
+            let last = offset + chunk_len == raw.len();
+            idat.push(u8::from(last));
+            idat.extend_from_slice(&(chunk_len as u16).to_le_bytes());
+            idat.extend_from_slice(&(!(chunk_len as u16)).to_le_bytes());
+            idat.extend_from_slice(&raw[offset..offset + chunk_len]);
+            offset += chunk_len;
+            if last {
+                break;
+            }
+        }
+        idat.extend_from_slice(&adler32(&raw).to_be_bytes());
+        write_chunk(&mut out, b"IDAT", &idat);
+
+        write_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let mut crc_input = Vec::with_capacity(4 + data.len());
+    crc_input.extend_from_slice(kind);
+    crc_input.extend_from_slice(data);
+    out.extend_from_slice(&crc32(&crc_input).to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65_521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+/// Resolve a cell color against the terminal-default fallback.
+fn resolve_color(color: PackedRgba, default: [u8; 4]) -> [u8; 4] {
+    if color.a() == 0 {
+        default
+    } else {
+        [color.r(), color.g(), color.b(), 255]
+    }
+}
+
+const DEFAULT_FG: [u8; 4] = [220, 220, 220, 255];
+const DEFAULT_BG: [u8; 4] = [16, 16, 16, 255];
+
+/// Rasterize a buffer into an RGBA image.
+///
+/// Each cell becomes an 8x8 block multiplied by `scale`. Wide heads cover
+/// two cells; continuation cells are skipped. Bold uses the double-strike
+/// trick (glyph drawn twice, offset one pixel right); underline and
+/// strikethrough are drawn as horizontal lines in the fg color. Output is
+/// fully deterministic.
+#[must_use]
+pub fn rasterize_buffer(buffer: &Buffer, font: &BitmapFont, scale: u32) -> RgbaImage {
+    let scale = scale.max(1);
+    let cell_px = GLYPH_SIZE * scale;
+    let mut image = RgbaImage::new(
+        u32::from(buffer.width()) * cell_px,
+        u32::from(buffer.height()) * cell_px,
+    );
+
+    for y in 0..buffer.height() {
+        for x in 0..buffer.width() {
+            let Some(cell) = buffer.get(x, y) else {
+                continue;
+            };
+            if cell.is_continuation() {
+                continue;
+            }
+            let width_cells = cell.content.width().max(1) as u32;
+            let fg = resolve_color(cell.fg, DEFAULT_FG);
+            let bg = resolve_color(cell.bg, DEFAULT_BG);
+            let flags = cell.attrs.flags();
+
+            let px = u32::from(x) * cell_px;
+            let py = u32::from(y) * cell_px;
+            let block_w = cell_px * width_cells;
+
+            // Background fill.
+            for dy in 0..cell_px {
+                for dx in 0..block_w {
+                    image.put(px + dx, py + dy, bg);
+                }
+            }
+
+            // Glyph.
+            let glyph = match cell.content.as_char() {
+                Some(ch) if ch != ' ' && ch != '\0' => {
+                    font.glyph(ch).or_else(|| {
+                        // Unmapped but narrow and printable: replacement.
+                        Some(font.replacement())
+                    })
+                }
+                Some(_) => None,
+                None if cell.content.is_empty() => None,
+                // Pooled grapheme cluster: unmapped by definition.
+                None => Some(font.replacement()),
+            };
+
+            if let Some(rows) = glyph {
+                let x_stretch = width_cells; // wide chars stretch horizontally
+                let strikes = if flags.contains(StyleFlags::BOLD) {
+                    &[0u32, 1][..]
+                } else {
+                    &[0u32][..]
+                };
+                for (row_idx, row_bits) in rows.iter().enumerate() {
+                    for bit in 0..8u32 {
+                        if row_bits & (1 << bit) == 0 {
+                            continue;
+                        }
+                        for &strike in strikes {
+                            for sy in 0..scale {
+                                for sx in 0..scale * x_stretch {
+                                    image.put(
+                                        px + (bit * x_stretch * scale) + sx + strike,
+                                        py + (row_idx as u32 * scale) + sy,
+                                        fg,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Underline / strikethrough drawn over glyph and background.
+            if flags.contains(StyleFlags::UNDERLINE) {
+                for dx in 0..block_w {
+                    for sy in 0..scale {
+                        image.put(px + dx, py + (GLYPH_SIZE - 1) * scale + sy, fg);
+                    }
+                }
+            }
+            if flags.contains(StyleFlags::STRIKETHROUGH) {
+                for dx in 0..block_w {
+                    for sy in 0..scale {
+                        image.put(px + dx, py + (GLYPH_SIZE / 2) * scale + sy, fg);
+                    }
+                }
+            }
+        }
+    }
+
+    image
+}
+
+/// Write expected/actual/diff PNGs for a failed golden comparison.
+///
+/// `expected` is optional because golden storage keeps only checksums;
+/// when it is absent only `actual.png` is written. The diff marks
+/// differing pixels in red over a dimmed copy of the actual frame.
+/// Returns the paths written.
+pub fn write_failure_artifacts(
+    dir: &Path,
+    case: &str,
+    expected: Option<&Buffer>,
+    actual: &Buffer,
+) -> io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    let font = BitmapFont::builtin();
+    let mut written = Vec::new();
+
+    let actual_img = rasterize_buffer(actual, font, 1);
+    let actual_path = dir.join(format!("{case}.actual.png"));
+    std::fs::write(&actual_path, actual_img.encode_png())?;
+    written.push(actual_path);
+
+    if let Some(expected) = expected {
+        let expected_img = rasterize_buffer(expected, font, 1);
+        let expected_path = dir.join(format!("{case}.expected.png"));
+        std::fs::write(&expected_path, expected_img.encode_png())?;
+        written.push(expected_path);
+
+        let diff_path = dir.join(format!("{case}.diff.png"));
+        std::fs::write(
+            &diff_path,
+            diff_image(&expected_img, &actual_img).encode_png(),
+        )?;
+        written.push(diff_path);
+    }
+
+    Ok(written)
+}
+
+/// Build a visual diff: red where pixels differ, dimmed actual elsewhere.
+fn diff_image(expected: &RgbaImage, actual: &RgbaImage) -> RgbaImage {
+    let width = expected.width.max(actual.width);
+    let height = expected.height.max(actual.height);
+    let mut out = RgbaImage::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let in_expected = x < expected.width && y < expected.height;
+            let in_actual = x < actual.width && y < actual.height;
+            let pixel = match (in_expected, in_actual) {
+                (true, true) => {
+                    let (e, a) = (expected.get(x, y), actual.get(x, y));
+                    if e == a {
+                        [a[0] / 3, a[1] / 3, a[2] / 3, 255]
+                    } else {
+                        [255, 0, 0, 255]
+                    }
+                }
+                _ => [255, 0, 0, 255],
+            };
+            out.put(x, y, pixel);
+        }
+    }
+    out
+}
+
+/// 8x8 bitmap font for ASCII 0x20..=0x7E.
+///
+/// Derived from the public-domain `font8x8` glyph set; bit `1 << x` is
+/// the pixel at column `x`.
+#[rustfmt::skip]
+static FONT8X8: [[u8; 8]; 95] = [
+    [0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00], // ' '
+    [0x18,0x3C,0x3C,0x18,0x18,0x00,0x18,0x00], // '!'
+    [0x36,0x36,0x00,0x00,0x00,0x00,0x00,0x00], // '"'
+    [0x36,0x36,0x7F,0x36,0x7F,0x36,0x36,0x00], // '#'
+    [0x0C,0x3E,0x03,0x1E,0x30,0x1F,0x0C,0x00], // '$'
+    [0x00,0x63,0x33,0x18,0x0C,0x66,0x63,0x00], // '%'
+    [0x1C,0x36,0x1C,0x6E,0x3B,0x33,0x6E,0x00], // '&'
+    [0x06,0x06,0x03,0x00,0x00,0x00,0x00,0x00], // '\''
+    [0x18,0x0C,0x06,0x06,0x06,0x0C,0x18,0x00], // '('
+    [0x06,0x0C,0x18,0x18,0x18,0x0C,0x06,0x00], // ')'
+    [0x00,0x66,0x3C,0xFF,0x3C,0x66,0x00,0x00], // '*'
+    [0x00,0x0C,0x0C,0x3F,0x0C,0x0C,0x00,0x00], // '+'
+    [0x00,0x00,0x00,0x00,0x00,0x0C,0x0C,0x06], // ','
+    [0x00,0x00,0x00,0x3F,0x00,0x00,0x00,0x00], // '-'
+    [0x00,0x00,0x00,0x00,0x00,0x0C,0x0C,0x00], // '.'
+    [0x60,0x30,0x18,0x0C,0x06,0x03,0x01,0x00], // '/'
+    [0x3E,0x63,0x73,0x7B,0x6F,0x67,0x3E,0x00], // '0'
+    [0x0C,0x0E,0x0C,0x0C,0x0C,0x0C,0x3F,0x00], // '1'
+    [0x1E,0x33,0x30,0x1C,0x06,0x33,0x3F,0x00], // '2'
+    [0x1E,0x33,0x30,0x1C,0x30,0x33,0x1E,0x00], // '3'
+    [0x38,0x3C,0x36,0x33,0x7F,0x30,0x78,0x00], // '4'
+    [0x3F,0x03,0x1F,0x30,0x30,0x33,0x1E,0x00], // '5'
+    [0x1C,0x06,0x03,0x1F,0x33,0x33,0x1E,0x00], // '6'
+    [0x3F,0x33,0x30,0x18,0x0C,0x0C,0x0C,0x00], // '7'
+    [0x1E,0x33,0x33,0x1E,0x33,0x33,0x1E,0x00], // '8'
+    [0x1E,0x33,0x33,0x3E,0x30,0x18,0x0E,0x00], // '9'
+    [0x00,0x0C,0x0C,0x00,0x00,0x0C,0x0C,0x00], // ':'
+    [0x00,0x0C,0x0C,0x00,0x00,0x0C,0x0C,0x06], // ';'
+    [0x18,0x0C,0x06,0x03,0x06,0x0C,0x18,0x00], // '<'
+    [0x00,0x00,0x3F,0x00,0x00,0x3F,0x00,0x00], // '='
+    [0x06,0x0C,0x18,0x30,0x18,0x0C,0x06,0x00], // '>'
+    [0x1E,0x33,0x30,0x18,0x0C,0x00,0x0C,0x00], // '?'
+    [0x3E,0x63,0x7B,0x7B,0x7B,0x03,0x1E,0x00], // '@'
+    [0x0C,0x1E,0x33,0x33,0x3F,0x33,0x33,0x00], // 'A'
+    [0x3F,0x66,0x66,0x3E,0x66,0x66,0x3F,0x00], // 'B'
+    [0x3C,0x66,0x03,0x03,0x03,0x66,0x3C,0x00], // 'C'
+    [0x1F,0x36,0x66,0x66,0x66,0x36,0x1F,0x00], // 'D'
+    [0x7F,0x46,0x16,0x1E,0x16,0x46,0x7F,0x00], // 'E'
+    [0x7F,0x46,0x16,0x1E,0x16,0x06,0x0F,0x00], // 'F'
+    [0x3C,0x66,0x03,0x03,0x73,0x66,0x7C,0x00], // 'G'
+    [0x33,0x33,0x33,0x3F,0x33,0x33,0x33,0x00], // 'H'
+    [0x1E,0x0C,0x0C,0x0C,0x0C,0x0C,0x1E,0x00], // 'I'
+    [0x78,0x30,0x30,0x30,0x33,0x33,0x1E,0x00], // 'J'
+    [0x67,0x66,0x36,0x1E,0x36,0x66,0x67,0x00], // 'K'
+    [0x0F,0x06,0x06,0x06,0x46,0x66,0x7F,0x00], // 'L'
+    [0x63,0x77,0x7F,0x7F,0x6B,0x63,0x63,0x00], // 'M'
+    [0x63,0x67,0x6F,0x7B,0x73,0x63,0x63,0x00], // 'N'
+    [0x1C,0x36,0x63,0x63,0x63,0x36,0x1C,0x00], // 'O'
+    [0x3F,0x66,0x66,0x3E,0x06,0x06,0x0F,0x00], // 'P'
+    [0x1E,0x33,0x33,0x33,0x3B,0x1E,0x38,0x00], // 'Q'
+    [0x3F,0x66,0x66,0x3E,0x36,0x66,0x67,0x00], // 'R'
+    [0x1E,0x33,0x07,0x0E,0x38,0x33,0x1E,0x00], // 'S'
+    [0x3F,0x2D,0x0C,0x0C,0x0C,0x0C,0x1E,0x00], // 'T'
+    [0x33,0x33,0x33,0x33,0x33,0x33,0x3F,0x00], // 'U'
+    [0x33,0x33,0x33,0x33,0x33,0x1E,0x0C,0x00], // 'V'
+    [0x63,0x63,0x63,0x6B,0x7F,0x77,0x63,0x00], // 'W'
+    [0x63,0x63,0x36,0x1C,0x1C,0x36,0x63,0x00], // 'X'
+    [0x33,0x33,0x33,0x1E,0x0C,0x0C,0x1E,0x00], // 'Y'
+    [0x7F,0x63,0x31,0x18,0x4C,0x66,0x7F,0x00], // 'Z'
+    [0x1E,0x06,0x06,0x06,0x06,0x06,0x1E,0x00], // '['
+    [0x03,0x06,0x0C,0x18,0x30,0x60,0x40,0x00], // '\\'
+    [0x1E,0x18,0x18,0x18,0x18,0x18,0x1E,0x00], // ']'
+    [0x08,0x1C,0x36,0x63,0x00,0x00,0x00,0x00], // '^'
+    [0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xFF], // '_'
+    [0x0C,0x0C,0x18,0x00,0x00,0x00,0x00,0x00], // '`'
+    [0x00,0x00,0x1E,0x30,0x3E,0x33,0x6E,0x00], // 'a'
+    [0x07,0x06,0x06,0x3E,0x66,0x66,0x3B,0x00], // 'b'
+    [0x00,0x00,0x1E,0x33,0x03,0x33,0x1E,0x00], // 'c'
+    [0x38,0x30,0x30,0x3E,0x33,0x33,0x6E,0x00], // 'd'
+    [0x00,0x00,0x1E,0x33,0x3F,0x03,0x1E,0x00], // 'e'
+    [0x1C,0x36,0x06,0x0F,0x06,0x06,0x0F,0x00], // 'f'
+    [0x00,0x00,0x6E,0x33,0x33,0x3E,0x30,0x1F], // 'g'
+    [0x07,0x06,0x36,0x6E,0x66,0x66,0x67,0x00], // 'h'
+    [0x0C,0x00,0x0E,0x0C,0x0C,0x0C,0x1E,0x00], // 'i'
+    [0x30,0x00,0x30,0x30,0x30,0x33,0x33,0x1E], // 'j'
+    [0x07,0x06,0x66,0x36,0x1E,0x36,0x67,0x00], // 'k'
+    [0x0E,0x0C,0x0C,0x0C,0x0C,0x0C,0x1E,0x00], // 'l'
+    [0x00,0x00,0x33,0x7F,0x7F,0x6B,0x63,0x00], // 'm'
+    [0x00,0x00,0x1F,0x33,0x33,0x33,0x33,0x00], // 'n'
+    [0x00,0x00,0x1E,0x33,0x33,0x33,0x1E,0x00], // 'o'
+    [0x00,0x00,0x3B,0x66,0x66,0x3E,0x06,0x0F], // 'p'
+    [0x00,0x00,0x6E,0x33,0x33,0x3E,0x30,0x78], // 'q'
+    [0x00,0x00,0x3B,0x6E,0x66,0x06,0x0F,0x00], // 'r'
+    [0x00,0x00,0x3E,0x03,0x1E,0x30,0x1F,0x00], // 's'
+    [0x08,0x0C,0x3E,0x0C,0x0C,0x2C,0x18,0x00], // 't'
+    [0x00,0x00,0x33,0x33,0x33,0x33,0x6E,0x00], // 'u'
+    [0x00,0x00,0x33,0x33,0x33,0x1E,0x0C,0x00], // 'v'
+    [0x00,0x00,0x63,0x6B,0x7F,0x7F,0x36,0x00], // 'w'
+    [0x00,0x00,0x63,0x36,0x1C,0x36,0x63,0x00], // 'x'
+    [0x00,0x00,0x33,0x33,0x33,0x3E,0x30,0x1F], // 'y'
+    [0x00,0x00,0x3F,0x19,0x0C,0x26,0x3F,0x00], // 'z'
+    [0x38,0x0C,0x0C,0x07,0x0C,0x0C,0x38,0x00], // '{'
+    [0x18,0x18,0x18,0x00,0x18,0x18,0x18,0x00], // '|'
+    [0x07,0x0C,0x0C,0x38,0x0C,0x0C,0x07,0x00], // '}'
+    [0x6E,0x3B,0x00,0x00,0x00,0x00,0x00,0x00], // '~'
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::cell::Cell;
+
+    fn styled_test_buffer() -> Buffer {
+        let mut buf = Buffer::new(8, 3);
+        for (i, ch) in "Hi!".chars().enumerate() {
+            buf.set(i as u16 * 2, 0, Cell::from_char(ch));
+        }
+        // Styled cells: bold red, underlined green bg, strikethrough.
+        let bold = Cell {
+            fg: PackedRgba::rgb(255, 0, 0),
+            ..Cell::from_char('B')
+        };
+        let bold = apply_flags(bold, StyleFlags::BOLD);
+        buf.set(0, 1, bold);
+
+        let underline = Cell {
+            fg: PackedRgba::rgb(0, 0, 0),
+            bg: PackedRgba::rgb(0, 200, 0),
+            ..Cell::from_char('u')
+        };
+        buf.set(1, 1, apply_flags(underline, StyleFlags::UNDERLINE));
+
+        buf.set(
+            2,
+            1,
+            apply_flags(Cell::from_char('s'), StyleFlags::STRIKETHROUGH),
+        );
+
+        // Box drawing and a wide char (renders the replacement glyph).
+        for (i, ch) in "┌─┐".chars().enumerate() {
+            buf.set(i as u16, 2, Cell::from_char(ch));
+        }
+        buf.set(4, 2, Cell::from_char('中'));
+        buf
+    }
+
+    fn apply_flags(mut cell: Cell, flags: StyleFlags) -> Cell {
+        cell.attrs = cell.attrs.with_flags(flags);
+        cell
+    }
+
+    #[test]
+    fn rasterize_matches_committed_reference() {
+        let buf = styled_test_buffer();
+        let image = rasterize_buffer(&buf, BitmapFont::builtin(), 1);
+        let png = image.encode_png();
+        let reference = include_bytes!("../testdata/rasterize_reference.png");
+        assert_eq!(
+            png.as_slice(),
+            reference.as_ref(),
+            "rasterized PNG diverged from the committed reference \
+             (regenerate with tests::bless_reference if intentional)"
+        );
+    }
+
+    #[test]
+    fn rasterize_is_deterministic_across_runs() {
+        let buf = styled_test_buffer();
+        let a = rasterize_buffer(&buf, BitmapFont::builtin(), 2).encode_png();
+        let b = rasterize_buffer(&buf, BitmapFont::builtin(), 2).encode_png();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn wide_char_covers_two_cells() {
+        let mut buf = Buffer::new(4, 1);
+        buf.set(0, 0, Cell::from_char('中'));
+        let image = rasterize_buffer(&buf, BitmapFont::builtin(), 1);
+        assert_eq!(image.width, 32);
+        // Replacement glyph outline reaches into the second cell; the
+        // default cell fg is opaque white.
+        let fg = image.get(9, 0); // top row of the stretched outline
+        assert_eq!(fg, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn failure_artifacts_written() {
+        let dir = std::env::temp_dir().join(format!("ftui_raster_{}", std::process::id()));
+        let expected = styled_test_buffer();
+        let mut actual = styled_test_buffer();
+        actual.set(0, 0, Cell::from_char('X'));
+
+        let written =
+            write_failure_artifacts(&dir, "case1", Some(&expected), &actual).expect("write");
+        assert_eq!(written.len(), 3);
+        for path in &written {
+            let bytes = std::fs::read(path).expect("read artifact");
+            assert!(bytes.starts_with(&[0x89, b'P', b'N', b'G']));
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn png_chunks_are_well_formed() {
+        let image = rasterize_buffer(&Buffer::new(2, 2), BitmapFont::builtin(), 1);
+        let png = image.encode_png();
+        assert!(png.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]));
+        assert!(png.ends_with(&[b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]));
+    }
+
+    // Not a test: run with `cargo test -p ftui-harness --features rasterize
+    // bless_reference -- --ignored` to regenerate the committed reference.
+    #[test]
+    #[ignore = "regenerates the committed reference PNG"]
+    fn bless_reference() {
+        let buf = styled_test_buffer();
+        let png = rasterize_buffer(&buf, BitmapFont::builtin(), 1).encode_png();
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/rasterize_reference.png");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, png).unwrap();
+    }
+}
@@ -0,0 +1,349 @@
+#![forbid(unsafe_code)]
+
+//! Deterministic fuzz harness for the VT parser.
+//!
+//! The virtual terminal parses untrusted byte streams (anything a child
+//! process prints). [`generate_stream`] produces structured-random
+//! streams from a seed — valid sequences, truncated sequences, huge
+//! parameters, interleaved UTF-8 fragments, raw noise — and
+//! [`check_invariants`] asserts the global safety properties after
+//! every chunk: no panics, grid dimensions unchanged, cursor in bounds,
+//! no orphan wide-continuation cells, combining-mark caps respected,
+//! and forward progress within a step budget.
+//!
+//! [`generate_subset_stream`] restricts generation to the subset both
+//! the real parser and the [`TerminalModel`] reference implement
+//! (cursor movement + plain text + SGR), enabling differential
+//! comparison of final cursor position and text content.
+//!
+//! Everything is runnable as a normal test from a seed corpus
+//! (`tests/corpus/vt_fuzz/`). For coverage-guided fuzzing, point a
+//! cargo-fuzz target at [`fuzz_vt_parser`]:
+//!
+//! ```ignore
+//! // fuzz/fuzz_targets/vt_parser.rs
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+//!     ftui_harness::vt_fuzz::fuzz_vt_parser(data);
+//! });
+//! ```
+
+use ftui_pty::virtual_terminal::{DEFAULT_MAX_COMBINING_PER_CELL, VirtualTerminal};
+
+use crate::terminal_model::TerminalModel;
+
+/// Wall-clock budget for feeding one chunk; a busy loop in the parser
+/// trips this long before CI times out.
+const STEP_BUDGET: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Simple deterministic PRNG (xorshift64) for reproducible streams.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 1 } else { seed },
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        if max == 0 { 0 } else { self.next() % max }
+    }
+}
+
+/// Generate a structured-random hostile byte stream.
+///
+/// Mixes valid CSI/OSC/SGR sequences, truncated sequences, huge
+/// parameters, combining-mark floods, interleaved UTF-8 fragments, and
+/// raw binary noise. Deterministic per seed.
+#[must_use]
+pub fn generate_stream(seed: u64, target_len: usize) -> Vec<u8> {
+    let mut rng = Rng::new(seed);
+    let mut out = Vec::with_capacity(target_len + 32);
+    while out.len() < target_len {
+        match rng.below(12) {
+            // Plain ASCII runs.
+            0 | 1 => {
+                for _ in 0..rng.below(12) + 1 {
+                    out.push(b' ' + (rng.below(95) as u8));
+                }
+            }
+            // Valid cursor movement.
+            2 => {
+                let n = rng.below(40);
+                let dir = b"ABCD"[rng.below(4) as usize];
+                out.extend_from_slice(format!("\x1b[{n}{}", dir as char).as_bytes());
+            }
+            // Valid CUP.
+            3 => {
+                let row = rng.below(60);
+                let col = rng.below(200);
+                out.extend_from_slice(format!("\x1b[{row};{col}H").as_bytes());
+            }
+            // SGR (sometimes extended color).
+            4 => {
+                let code = rng.below(110);
+                out.extend_from_slice(format!("\x1b[{code}m").as_bytes());
+            }
+            // Huge parameters.
+            5 => {
+                out.extend_from_slice(format!("\x1b[{}m", u64::MAX).as_bytes());
+                out.extend_from_slice(format!("\x1b[{};{}H", rng.next(), rng.next()).as_bytes());
+            }
+            // Truncated sequence followed by text (must not eat it).
+            6 => {
+                out.extend_from_slice(b"\x1b[12;3");
+                out.push(b'a' + (rng.below(26) as u8));
+            }
+            // OSC — terminated, unterminated, or hyperlink.
+            7 => match rng.below(3) {
+                0 => out.extend_from_slice(b"\x1b]0;title\x07"),
+                1 => out.extend_from_slice(b"\x1b]8;;https://example.com\x1b\\x\x1b]8;;\x1b\\"),
+                _ => out.extend_from_slice(b"\x1b]2;never-terminated "),
+            },
+            // UTF-8: wide chars, combining floods, split fragments.
+            8 => {
+                out.extend_from_slice("世界".as_bytes());
+            }
+            9 => {
+                for _ in 0..rng.below(30) {
+                    out.extend_from_slice("\u{0301}".as_bytes());
+                }
+            }
+            10 => {
+                // Leading bytes of a multibyte char, then noise.
+                out.push(0xE4);
+                out.push(0xB8);
+                if rng.below(2) == 0 {
+                    out.push(0x96);
+                } else {
+                    out.push(b'Q');
+                }
+            }
+            // Raw noise including C0/C1 bytes.
+            _ => {
+                for _ in 0..rng.below(8) + 1 {
+                    out.push(rng.next() as u8);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Generate a stream restricted to the differential subset: in-bounds
+/// CUP, small relative cursor moves, short plain-ASCII runs, and SGR.
+#[must_use]
+pub fn generate_subset_stream(seed: u64, width: u16, height: u16, fragments: usize) -> Vec<u8> {
+    let mut rng = Rng::new(seed);
+    let mut out = Vec::new();
+    for _ in 0..fragments {
+        match rng.below(4) {
+            0 => {
+                // 1-based CUP, kept away from the right margin so short
+                // text runs cannot wrap (wrap semantics differ between
+                // the model and the real terminal).
+                let row = rng.below(u64::from(height)) + 1;
+                let col = rng.below(u64::from(width.saturating_sub(20))) + 1;
+                out.extend_from_slice(format!("\x1b[{row};{col}H").as_bytes());
+            }
+            1 => {
+                let n = rng.below(4) + 1;
+                let dir = b"ABCD"[rng.below(4) as usize];
+                out.extend_from_slice(format!("\x1b[{n}{}", dir as char).as_bytes());
+            }
+            2 => {
+                let code = [0u64, 1, 4, 7, 31, 32, 39, 42, 49][rng.below(9) as usize];
+                out.extend_from_slice(format!("\x1b[{code}m").as_bytes());
+            }
+            _ => {
+                // Short text after a fresh in-bounds CUP so the run
+                // stays on one row in both implementations.
+                let row = rng.below(u64::from(height)) + 1;
+                let col = rng.below(u64::from(width.saturating_sub(20))) + 1;
+                out.extend_from_slice(format!("\x1b[{row};{col}H").as_bytes());
+                for _ in 0..rng.below(10) + 1 {
+                    out.push(b'a' + (rng.below(26) as u8));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Assert the VT parser's global invariants.
+///
+/// Returns a description of the first violation, if any.
+pub fn check_invariants(
+    vt: &VirtualTerminal,
+    expected_width: u16,
+    expected_height: u16,
+) -> Result<(), String> {
+    if vt.width() != expected_width || vt.height() != expected_height {
+        return Err(format!(
+            "grid dimensions changed: {}x{} != {expected_width}x{expected_height}",
+            vt.width(),
+            vt.height()
+        ));
+    }
+    let (cx, cy) = vt.cursor();
+    // cursor_x == width is the legal pending-wrap state.
+    if cx > vt.width() || cy >= vt.height() {
+        return Err(format!("cursor out of bounds: ({cx},{cy})"));
+    }
+    for y in 0..vt.height() {
+        for x in 0..vt.width() {
+            let Some(cell) = vt.cell_at(x, y) else {
+                return Err(format!("missing cell at ({x},{y})"));
+            };
+            if cell.ch == '\0' {
+                let lead = u16::checked_sub(x, 1)
+                    .and_then(|px| vt.cell_at(px, y))
+                    .map(|c| c.ch);
+                let lead_is_wide = lead
+                    .is_some_and(|ch| unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0) == 2);
+                if !lead_is_wide {
+                    return Err(format!("orphan wide-continuation at ({x},{y})"));
+                }
+            }
+            if cell.combining.len() > DEFAULT_MAX_COMBINING_PER_CELL {
+                return Err(format!(
+                    "combining cap exceeded at ({x},{y}): {}",
+                    cell.combining.len()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Feed one hostile input to a fresh terminal, asserting every
+/// invariant. This is the cargo-fuzz entry point; it is also what the
+/// seeded tests and corpus replay drive.
+///
+/// # Panics
+///
+/// Panics on any invariant violation (that is the point).
+pub fn fuzz_vt_parser(data: &[u8]) {
+    let (width, height) = (80, 24);
+    let mut vt = VirtualTerminal::new(width, height);
+    // Feed in chunks so invariants hold at intermediate states too —
+    // including chunk boundaries that split escape sequences.
+    for chunk in data.chunks(97.max(data.len() / 8)) {
+        let start = std::time::Instant::now();
+        vt.feed(chunk);
+        assert!(
+            start.elapsed() < STEP_BUDGET,
+            "step budget exceeded on {}-byte chunk (parser stuck?)",
+            chunk.len()
+        );
+        if let Err(violation) = check_invariants(&vt, width, height) {
+            panic!("invariant violated: {violation}");
+        }
+    }
+}
+
+/// Differentially compare the real parser against [`TerminalModel`] for
+/// a subset stream: final cursor position and per-row text must agree.
+pub fn differential_check(data: &[u8], width: u16, height: u16) -> Result<(), String> {
+    let mut vt = VirtualTerminal::new(width, height);
+    let mut model = TerminalModel::new(width, height);
+    vt.feed(data);
+    model.feed(data);
+
+    if vt.cursor() != model.cursor() {
+        return Err(format!(
+            "cursor diverged: vt={:?} model={:?}",
+            vt.cursor(),
+            model.cursor()
+        ));
+    }
+    for y in 0..height {
+        let vt_row = vt.row_text(y);
+        let model_row = model.row_text(y).trim_end().to_string();
+        if vt_row != model_row {
+            return Err(format!(
+                "row {y} diverged:\n vt:    {vt_row:?}\n model: {model_row:?}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invariant_checker_accepts_fresh_terminal() {
+        let vt = VirtualTerminal::new(80, 24);
+        assert!(check_invariants(&vt, 80, 24).is_ok());
+    }
+
+    #[test]
+    fn invariant_checker_catches_dimension_mismatch() {
+        let vt = VirtualTerminal::new(80, 24);
+        let err = check_invariants(&vt, 81, 24).expect_err("must flag");
+        assert!(err.contains("dimensions"), "{err}");
+    }
+
+    #[test]
+    fn known_bad_streams_hold_invariants() {
+        // Handcrafted hostile streams: each must parse without panic
+        // and leave the terminal in an invariant-preserving state.
+        let cases: &[&[u8]] = &[
+            b"\x1b[999999999999999999999H",
+            b"\x1b[;;;;;;;;;;;;;;;;;;;m",
+            b"\x1b[1;1Hx\x1b[", // truncated CSI at end
+            b"\xff\xfe\xfd\x00\x01\x02",
+            b"\xe4\xb8", // dangling UTF-8 prefix
+            "é\u{0301}\u{0301}\u{0301}\u{0301}\u{0301}\u{0301}\u{0301}\u{0301}\u{0301}\u{0301}"
+                .as_bytes(),
+            b"\x1b]8;;http://x\x1b\\link", // unterminated link region
+            b"\x1b[10000C\x1b[10000B",     // movement far out of bounds
+        ];
+        for (idx, case) in cases.iter().enumerate() {
+            let mut vt = VirtualTerminal::new(40, 10);
+            vt.feed(case);
+            check_invariants(&vt, 40, 10)
+                .unwrap_or_else(|violation| panic!("case {idx}: {violation}"));
+        }
+    }
+
+    #[test]
+    fn seeded_streams_hold_invariants() {
+        for seed in 1..=40u64 {
+            let data = generate_stream(seed, 4_096);
+            fuzz_vt_parser(&data);
+        }
+    }
+
+    #[test]
+    fn differential_agreement_on_subset() {
+        for seed in 1..=60u64 {
+            let data = generate_subset_stream(seed, 80, 24, 64);
+            differential_check(&data, 80, 24).unwrap_or_else(|diff| panic!("seed {seed}: {diff}"));
+        }
+    }
+
+    #[test]
+    fn generation_is_deterministic() {
+        assert_eq!(generate_stream(7, 1_000), generate_stream(7, 1_000));
+        assert_eq!(
+            generate_subset_stream(7, 80, 24, 32),
+            generate_subset_stream(7, 80, 24, 32)
+        );
+    }
+}
@@ -88,11 +88,16 @@ fn run_golden_scenario(scenario: &ResizeScenario, base_dir: &Path) -> GoldenResu
     render_test_pattern(&mut buf, scenario.initial_width, scenario.initial_height);
     checksums.push(compute_buffer_checksum(&buf));
 
+    #[cfg(feature = "rasterize")]
+    let mut frames = vec![buf.clone()];
+
     // Apply resize steps
     for &(new_w, new_h, _delay_ms) in &scenario.resize_steps {
         let mut new_buf = Buffer::new(new_w, new_h);
         render_test_pattern(&mut new_buf, new_w, new_h);
         checksums.push(compute_buffer_checksum(&new_buf));
+        #[cfg(feature = "rasterize")]
+        frames.push(new_buf);
     }
 
     // Load expected checksums
@@ -110,6 +115,17 @@ fn run_golden_scenario(scenario: &ResizeScenario, base_dir: &Path) -> GoldenResu
         verify_checksums(&checksums, &expected)
     };
 
+    // A failed comparison leaves a PNG of the offending frame behind so a
+    // checksum mismatch has a human-viewable artifact.
+    #[cfg(feature = "rasterize")]
+    if outcome == GoldenOutcome::Fail
+        && let Some(idx) = mismatch_index
+        && let Some(frame) = frames.get(idx)
+    {
+        let case = format!("{}_frame{}", scenario.name, idx);
+        let _ = ftui_harness::golden::write_failure_pngs(&case, None, frame);
+    }
+
     GoldenResult {
         scenario: scenario.name.clone(),
         outcome,
@@ -0,0 +1,187 @@
+#![forbid(unsafe_code)]
+
+//! Presenter I/O fault injection: short writes, transient backpressure,
+//! and permanent sink failure.
+//!
+//! Drives a multi-frame present sequence through
+//! [`ResilientWriter`]-wrapped [`FaultyWriter`]s and asserts:
+//!
+//! 1. recoverable faults (short writes, intermittent `WouldBlock`)
+//!    produce a byte stream identical to the non-faulty run — nothing is
+//!    truncated or duplicated mid-escape-sequence;
+//! 2. sync-bracket pairing holds across resumed writes;
+//! 3. persistent backpressure surfaces as
+//!    [`PresentError::Backpressure`] once the retry budget is spent;
+//! 4. a permanently dead sink produces a clean [`PresentError::Io`]
+//!    (no panic), leaving teardown to the caller.
+//!
+//! # Running
+//!
+//! ```sh
+//! cargo test -p ftui-harness --test presenter_fault_injection
+//! ```
+
+use std::io::Write;
+
+use ftui_harness::faulty_writer::FaultyWriter;
+use ftui_render::buffer::Buffer;
+use ftui_render::cell::Cell;
+use ftui_render::diff::BufferDiff;
+use ftui_render::presenter::{PresentError, Presenter, TerminalCapabilities};
+use ftui_render::resilient_writer::{ResilientWriter, RetryPolicy};
+
+fn caps_synced() -> TerminalCapabilities {
+    let mut caps = TerminalCapabilities::basic();
+    caps.sync_output = true;
+    caps
+}
+
+/// A deterministic three-frame sequence with partial diffs.
+fn frames() -> Vec<Buffer> {
+    let mut a = Buffer::new(20, 4);
+    for (x, ch) in "hello world".chars().enumerate() {
+        a.set(x as u16, 0, Cell::from_char(ch));
+    }
+    let mut b = a.clone();
+    for (x, ch) in "HELLO".chars().enumerate() {
+        b.set(x as u16, 0, Cell::from_char(ch));
+    }
+    for (x, ch) in "second row".chars().enumerate() {
+        b.set(x as u16, 1, Cell::from_char(ch));
+    }
+    let mut c = b.clone();
+    for (x, ch) in "third frame!".chars().enumerate() {
+        c.set(x as u16, 2, Cell::from_char(ch));
+    }
+    vec![a, b, c]
+}
+
+/// Present the frame sequence (full first frame, then diffs) into `sink`,
+/// returning the presenter so callers can unwrap the writer stack.
+fn present_sequence<W: Write>(
+    mut presenter: Presenter<W>,
+) -> (Presenter<W>, Vec<std::io::Result<()>>) {
+    let frames = frames();
+    let mut results = Vec::new();
+    let mut prev: Option<&Buffer> = None;
+    for frame in &frames {
+        let diff = match prev {
+            None => BufferDiff::full(frame.width(), frame.height()),
+            Some(p) => BufferDiff::compute(p, frame),
+        };
+        results.push(presenter.present(frame, &diff).map(|_| ()));
+        prev = Some(frame);
+    }
+    (presenter, results)
+}
+
+fn clean_run() -> Vec<u8> {
+    let presenter = Presenter::new(Vec::new(), caps_synced());
+    let (presenter, results) = present_sequence(presenter);
+    for r in results {
+        r.expect("clean run presents");
+    }
+    presenter.into_inner().expect("flushed")
+}
+
+fn assert_sync_brackets_balanced(bytes: &[u8]) {
+    let text = String::from_utf8_lossy(bytes);
+    let begins = text.matches("\u{1b}[?2026h").count();
+    let ends = text.matches("\u{1b}[?2026l").count();
+    assert_eq!(begins, 3, "one sync begin per frame");
+    assert_eq!(begins, ends, "every sync bracket closed");
+}
+
+#[test]
+fn short_writes_resume_to_identical_byte_stream() {
+    let expected = clean_run();
+
+    for max_chunk in [1usize, 2, 7, 64] {
+        let sink = ResilientWriter::new(FaultyWriter::new().short_writes(max_chunk));
+        let presenter = Presenter::new(sink, caps_synced());
+        let (presenter, results) = present_sequence(presenter);
+        for r in results {
+            r.expect("short writes are recoverable");
+        }
+        let faulty = presenter.into_inner().expect("flushed").into_inner();
+        assert_eq!(
+            faulty.bytes(),
+            expected.as_slice(),
+            "byte stream identical under {max_chunk}-byte short writes"
+        );
+        assert_sync_brackets_balanced(faulty.bytes());
+    }
+}
+
+#[test]
+fn intermittent_would_block_resumes_to_identical_byte_stream() {
+    let expected = clean_run();
+
+    for every in [2u64, 3, 5] {
+        let sink = ResilientWriter::with_policy(
+            FaultyWriter::new().would_block_every(every).short_writes(9),
+            RetryPolicy::no_backoff(8),
+        );
+        let presenter = Presenter::new(sink, caps_synced());
+        let (presenter, results) = present_sequence(presenter);
+        for r in results {
+            r.expect("intermittent WouldBlock is recoverable");
+        }
+        let faulty = presenter.into_inner().expect("flushed").into_inner();
+        assert!(faulty.blocks_injected > 0, "faults were actually injected");
+        assert_eq!(
+            faulty.bytes(),
+            expected.as_slice(),
+            "byte stream identical under WouldBlock every {every} calls"
+        );
+        assert_sync_brackets_balanced(faulty.bytes());
+    }
+}
+
+#[test]
+fn persistent_backpressure_surfaces_beyond_budget() {
+    // Every call blocks: the retry budget must run out and classify as
+    // backpressure, not spin forever or panic.
+    let sink = ResilientWriter::with_policy(
+        FaultyWriter::new().would_block_every(1),
+        RetryPolicy::no_backoff(4),
+    );
+    let mut presenter = Presenter::new(sink, caps_synced());
+    let frame = &frames()[0];
+    let diff = BufferDiff::full(frame.width(), frame.height());
+    let err = presenter
+        .present(frame, &diff)
+        .expect_err("persistent backpressure must fail");
+    match PresentError::from(err) {
+        PresentError::Backpressure(_) => {}
+        PresentError::Io(other) => panic!("expected backpressure, got {other}"),
+    }
+}
+
+#[test]
+fn permanent_failure_is_a_clean_io_error() {
+    let sink = ResilientWriter::new(FaultyWriter::new().fail_after(128));
+    let mut presenter = Presenter::new(sink, caps_synced());
+    let (returned, results) = present_sequence(presenter);
+    presenter = returned;
+
+    let failed: Vec<_> = results
+        .into_iter()
+        .filter(std::io::Result::is_err)
+        .collect();
+    assert!(!failed.is_empty(), "the dead sink must surface an error");
+    for r in failed {
+        match PresentError::from(r.unwrap_err()) {
+            PresentError::Io(err) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+            }
+            PresentError::Backpressure(err) => {
+                panic!("permanent failure misclassified as backpressure: {err}")
+            }
+        }
+    }
+
+    // Teardown still works: the writer stack unwinds without panicking
+    // even though the final flush cannot drain.
+    drop(presenter);
+}
@@ -0,0 +1,198 @@
+#![forbid(unsafe_code)]
+
+//! Presenter output batching: one sink write per frame, flush-strategy
+//! behavior, and chunk-boundary escape-sequence integrity.
+//!
+//! A busy dashboard frame is presented through sinks that count write
+//! calls (the syscall proxy): the default [`FlushStrategy::PerFrame`]
+//! must produce ~1 write per frame, [`FlushStrategy::Immediate`] many,
+//! and [`FlushStrategy::Chunked`] several bounded writes whose cuts never
+//! land inside an escape sequence (validated per chunk with the flicker
+//! analyzer's parser via a Ground-state check and overall with
+//! [`analyze_stream`]).
+//!
+//! # Running
+//!
+//! ```sh
+//! cargo test -p ftui-harness --test presenter_output_batching
+//! ```
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use ftui_harness::flicker_detection::analyze_stream;
+use ftui_render::buffer::Buffer;
+use ftui_render::cell::Cell;
+use ftui_render::diff::BufferDiff;
+use ftui_render::frame_batcher::FlushStrategy;
+use ftui_render::presenter::{Presenter, TerminalCapabilities};
+
+/// Shared sink recording each write call.
+#[derive(Debug, Clone, Default)]
+struct SharedSink {
+    writes: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+impl SharedSink {
+    fn writes(&self) -> Vec<Vec<u8>> {
+        self.writes.lock().unwrap().clone()
+    }
+    fn flat(&self) -> Vec<u8> {
+        self.writes().concat()
+    }
+}
+
+impl Write for SharedSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writes.lock().unwrap().push(buf.to_vec());
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn caps() -> TerminalCapabilities {
+    let mut caps = TerminalCapabilities::basic();
+    caps.sync_output = true;
+    caps.true_color = true;
+    caps
+}
+
+/// A busy dashboard-ish frame: lots of short colored runs.
+fn busy_frame(seed: u8) -> Buffer {
+    let mut buffer = Buffer::new(80, 24);
+    for y in 0..24u16 {
+        for x in 0..80u16 {
+            let ch = char::from(b'A' + ((x as u8 + y as u8 + seed) % 26));
+            let mut cell = Cell::from_char(ch);
+            cell.fg = ftui_render::cell::PackedRgba::rgb(
+                (x as u8).wrapping_mul(3),
+                (y as u8).wrapping_mul(9),
+                seed,
+            );
+            buffer.set(x, y, cell);
+        }
+    }
+    buffer
+}
+
+fn present_frames(presenter: &mut Presenter<SharedSink>, frames: usize) {
+    let mut prev: Option<Buffer> = None;
+    for i in 0..frames {
+        let frame = busy_frame(i as u8);
+        let diff = match &prev {
+            None => BufferDiff::full(frame.width(), frame.height()),
+            Some(p) => BufferDiff::compute(p, &frame),
+        };
+        presenter.present(&frame, &diff).expect("present");
+        prev = Some(frame);
+    }
+}
+
+#[test]
+fn per_frame_strategy_issues_one_write_per_frame() {
+    let sink = SharedSink::default();
+    let mut presenter = Presenter::new(sink.clone(), caps());
+    present_frames(&mut presenter, 5);
+
+    let writes = sink.writes();
+    assert_eq!(
+        writes.len(),
+        5,
+        "one sink write per presented frame (the syscall win)"
+    );
+    analyze_stream(&sink.flat()).assert_flicker_free();
+}
+
+#[test]
+fn strategies_produce_byte_identical_streams() {
+    let baseline = {
+        let sink = SharedSink::default();
+        let mut presenter = Presenter::new(sink.clone(), caps());
+        present_frames(&mut presenter, 3);
+        sink.flat()
+    };
+
+    for (label, strategy, expect_more_writes) in [
+        ("immediate", FlushStrategy::Immediate, true),
+        ("chunked", FlushStrategy::Chunked(256), true),
+    ] {
+        let sink = SharedSink::default();
+        let mut presenter = Presenter::new(sink.clone(), caps());
+        presenter.set_flush_strategy(strategy);
+        present_frames(&mut presenter, 3);
+        assert_eq!(
+            sink.flat(),
+            baseline,
+            "{label} stream differs from batched output"
+        );
+        if expect_more_writes {
+            assert!(
+                sink.writes().len() > 3,
+                "{label} should write more often than per-frame"
+            );
+        }
+    }
+}
+
+#[test]
+fn chunked_cuts_never_split_escape_sequences() {
+    let sink = SharedSink::default();
+    let mut presenter = Presenter::new(sink.clone(), caps());
+    presenter.set_flush_strategy(FlushStrategy::Chunked(128));
+    present_frames(&mut presenter, 3);
+
+    // Each chunk independently parses to completion: feeding any prefix
+    // of chunks to the analyzer never reports a torn escape, and the
+    // whole stream stays flicker-free (sync brackets intact).
+    for chunk in sink.writes() {
+        let text = String::from_utf8_lossy(&chunk);
+        assert!(
+            !ends_mid_escape(&chunk),
+            "chunk ends inside an escape sequence: …{:?}",
+            &text[text.len().saturating_sub(12)..]
+        );
+    }
+    analyze_stream(&sink.flat()).assert_flicker_free();
+}
+
+/// Replay a tiny VT scanner over the chunk; true when it ends inside an
+/// escape/CSI/OSC sequence.
+fn ends_mid_escape(chunk: &[u8]) -> bool {
+    #[derive(PartialEq)]
+    enum S {
+        Ground,
+        Escape,
+        Csi,
+        Osc,
+        OscEsc,
+    }
+    let mut state = S::Ground;
+    for &b in chunk {
+        state = match state {
+            S::Ground if b == 0x1b => S::Escape,
+            S::Ground => S::Ground,
+            S::Escape => match b {
+                b'[' => S::Csi,
+                b']' | b'P' | b'_' | b'^' | b'X' => S::Osc,
+                0x20..=0x2f => S::Escape,
+                _ => S::Ground,
+            },
+            S::Csi => match b {
+                0x40..=0x7e => S::Ground,
+                _ => S::Csi,
+            },
+            S::Osc => match b {
+                0x07 => S::Ground,
+                0x1b => S::OscEsc,
+                _ => S::Osc,
+            },
+            S::OscEsc => match b {
+                b'\\' => S::Ground,
+                _ => S::Osc,
+            },
+        };
+    }
+    state != S::Ground
+}
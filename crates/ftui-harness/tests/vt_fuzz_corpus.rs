@@ -0,0 +1,34 @@
+#![forbid(unsafe_code)]
+
+//! Regression corpus replay for the VT parser fuzz harness.
+//!
+//! Every stream in `tests/corpus/vt_fuzz/` is a past finding or a
+//! handcrafted hostile input; each must hold the parser's global
+//! invariants (see [`ftui_harness::vt_fuzz`]). Add new findings by
+//! dropping the minimized bytes into the corpus directory.
+//!
+//! # Running
+//!
+//! ```sh
+//! cargo test -p ftui-harness --test vt_fuzz_corpus
+//! ```
+
+use ftui_harness::vt_fuzz::fuzz_vt_parser;
+
+#[test]
+fn corpus_replay_holds_invariants() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/vt_fuzz");
+    let mut entries: Vec<_> = std::fs::read_dir(&dir)
+        .expect("corpus directory exists")
+        .map(|entry| entry.expect("readable entry").path())
+        .collect();
+    entries.sort();
+    assert!(!entries.is_empty(), "corpus must not be empty");
+    for path in entries {
+        let data = std::fs::read(&path).expect("readable corpus file");
+        // A panic here names the file via the test harness backtrace;
+        // keep the context explicit anyway.
+        eprintln!("replaying {}", path.display());
+        fuzz_vt_parser(&data);
+    }
+}
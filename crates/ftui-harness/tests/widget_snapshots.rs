@@ -840,6 +840,7 @@ fn snapshot_palette_results() {
             code: KeyCode::Char(ch),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&k);
     }
@@ -865,6 +866,7 @@ fn snapshot_palette_long_list() {
             code: KeyCode::Down,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&down);
     }
@@ -887,6 +889,7 @@ fn snapshot_palette_no_results() {
         code: KeyCode::Char('z'),
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     });
     let _ = palette.handle_event(&z);
 
@@ -0,0 +1,376 @@
+#![forbid(unsafe_code)]
+
+//! Accordion height negotiation for collapsible sections.
+//!
+//! Sidebars want stacked sections that expand and collapse. [`Accordion`]
+//! resolves the geometry only: every section keeps its always-visible
+//! header row, and the remaining height is distributed to expanded
+//! sections — each gets its preferred content height clamped by an
+//! optional per-section max and the space that's left. Over-subscription
+//! is handled by policy ([`OversubscriptionPolicy`]): shrink everyone
+//! proportionally, or give each section its ask in order and let the
+//! tail scroll within itself (the resolved layout reports the overflow).
+//!
+//! Resolution is pure arithmetic over the inputs — no hidden state — so
+//! repeated renders at the same size are bit-identical. Collapse state
+//! lives in the app; [`toggle_exclusive`] implements the
+//! one-open-at-a-time transition over it.
+
+use ftui_core::geometry::Rect;
+
+/// One section's inputs to height negotiation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccordionSection {
+    /// Whether the section's content is shown.
+    pub expanded: bool,
+    /// Content height the section wants at the current width (the app
+    /// queries its child, e.g. a `preferred_height(width)` measure).
+    pub preferred_height: u16,
+    /// Optional cap on the content height.
+    pub max_height: Option<u16>,
+}
+
+impl AccordionSection {
+    /// A collapsed section.
+    #[must_use]
+    pub fn collapsed() -> Self {
+        Self {
+            expanded: false,
+            preferred_height: 0,
+            max_height: None,
+        }
+    }
+
+    /// An expanded section wanting `preferred_height` rows of content.
+    #[must_use]
+    pub fn expanded(preferred_height: u16) -> Self {
+        Self {
+            expanded: true,
+            preferred_height,
+            max_height: None,
+        }
+    }
+
+    /// Cap the content height (builder).
+    #[must_use]
+    pub fn max_height(mut self, max: u16) -> Self {
+        self.max_height = Some(max);
+        self
+    }
+
+    /// Preferred height clamped by the per-section cap.
+    fn desired(&self) -> u16 {
+        match self.max_height {
+            Some(max) => self.preferred_height.min(max),
+            None => self.preferred_height,
+        }
+    }
+}
+
+/// What happens when expanded sections want more height than exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OversubscriptionPolicy {
+    /// Shrink every expanded section proportionally to its ask
+    /// (largest-remainder rounding keeps the result deterministic).
+    #[default]
+    ProportionalShrink,
+    /// Allocate each section its ask top-to-bottom; sections that don't
+    /// fit get the remainder (or nothing) and report overflow so the app
+    /// can scroll within them.
+    ScrollWithinSection,
+}
+
+/// Resolved geometry for one section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionLayout {
+    /// The always-visible header row(s). Zero-height when the area ran
+    /// out before this section.
+    pub header: Rect,
+    /// Content area (`None` when collapsed or fully squeezed out).
+    pub content: Option<Rect>,
+    /// Rows of preferred content that did not fit (scroll hint).
+    pub overflow: u16,
+}
+
+/// Accordion geometry resolver.
+#[derive(Debug, Clone, Copy)]
+pub struct Accordion {
+    /// Rows each header occupies.
+    header_height: u16,
+    /// Blank rows between sections.
+    gap: u16,
+    /// Over-subscription policy.
+    policy: OversubscriptionPolicy,
+}
+
+impl Default for Accordion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Accordion {
+    /// One-row headers, no gap, proportional shrink.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            header_height: 1,
+            gap: 0,
+            policy: OversubscriptionPolicy::default(),
+        }
+    }
+
+    /// Header row count (builder).
+    #[must_use]
+    pub fn header_height(mut self, rows: u16) -> Self {
+        self.header_height = rows.max(1);
+        self
+    }
+
+    /// Blank rows between sections (builder).
+    #[must_use]
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Over-subscription policy (builder).
+    #[must_use]
+    pub fn policy(mut self, policy: OversubscriptionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Resolve section geometry inside `area`.
+    ///
+    /// Headers stack top-to-bottom and are never resized; expanded
+    /// content is inserted directly below its header. The result is a
+    /// pure function of the inputs.
+    #[must_use]
+    pub fn resolve(&self, area: Rect, sections: &[AccordionSection]) -> Vec<SectionLayout> {
+        let content_heights = self.content_heights(area, sections);
+        let mut layouts = Vec::with_capacity(sections.len());
+        let mut y = area.y;
+        let bottom = area.bottom();
+        for (section, content_height) in sections.iter().zip(&content_heights) {
+            let header_rows = self.header_height.min(bottom.saturating_sub(y));
+            let header = Rect::new(area.x, y, area.width, header_rows);
+            y = y.saturating_add(header_rows);
+            let content = if *content_height > 0 {
+                let rect = Rect::new(area.x, y, area.width, *content_height);
+                y = y.saturating_add(*content_height);
+                Some(rect)
+            } else {
+                None
+            };
+            let overflow = if section.expanded {
+                section.desired().saturating_sub(*content_height)
+            } else {
+                0
+            };
+            layouts.push(SectionLayout {
+                header,
+                content,
+                overflow,
+            });
+            if header_rows > 0 {
+                y = y.saturating_add(self.gap).min(bottom);
+            }
+        }
+        layouts
+    }
+
+    /// Content height per section (the policy core, independent of x/y).
+    fn content_heights(&self, area: Rect, sections: &[AccordionSection]) -> Vec<u16> {
+        let n = sections.len() as u16;
+        let chrome = n
+            .saturating_mul(self.header_height)
+            .saturating_add(self.gap.saturating_mul(n.saturating_sub(1)));
+        let available = area.height.saturating_sub(chrome);
+
+        let desired: Vec<u16> = sections
+            .iter()
+            .map(|s| if s.expanded { s.desired() } else { 0 })
+            .collect();
+        let total: u32 = desired.iter().map(|&d| u32::from(d)).sum();
+
+        if total <= u32::from(available) {
+            return desired;
+        }
+
+        match self.policy {
+            OversubscriptionPolicy::ProportionalShrink => {
+                proportional_shrink(&desired, available, total)
+            }
+            OversubscriptionPolicy::ScrollWithinSection => {
+                let mut left = available;
+                desired
+                    .iter()
+                    .map(|&d| {
+                        let take = d.min(left);
+                        left -= take;
+                        take
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Largest-remainder proportional allocation of `available` rows over
+/// `desired` asks (`total` = sum of asks, > available).
+fn proportional_shrink(desired: &[u16], available: u16, total: u32) -> Vec<u16> {
+    if total == 0 {
+        return vec![0; desired.len()];
+    }
+    let available = u32::from(available);
+    // Floor shares first, then hand leftover rows to the largest
+    // remainders (ties broken by index for determinism).
+    let mut shares: Vec<u16> = Vec::with_capacity(desired.len());
+    let mut remainders: Vec<(u32, usize)> = Vec::with_capacity(desired.len());
+    let mut used: u32 = 0;
+    for (idx, &d) in desired.iter().enumerate() {
+        let exact = u32::from(d) * available;
+        let floor = exact / total;
+        shares.push(floor as u16);
+        used += floor;
+        remainders.push((exact % total, idx));
+    }
+    let mut leftover = available - used;
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for &(_, idx) in &remainders {
+        if leftover == 0 {
+            break;
+        }
+        // Never grow a section past its ask.
+        if u32::from(shares[idx]) < u32::from(desired[idx]) {
+            shares[idx] += 1;
+            leftover -= 1;
+        }
+    }
+    shares
+}
+
+/// Exclusive-mode toggle: expanding a section collapses the others;
+/// toggling the open one closes it. Returns whether anything changed.
+pub fn toggle_exclusive(expanded: &mut [bool], idx: usize) -> bool {
+    if idx >= expanded.len() {
+        return false;
+    }
+    if expanded[idx] {
+        expanded[idx] = false;
+        return true;
+    }
+    for flag in expanded.iter_mut() {
+        *flag = false;
+    }
+    expanded[idx] = true;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(height: u16) -> Rect {
+        Rect::new(0, 0, 30, height)
+    }
+
+    #[test]
+    fn fits_all_when_space_allows() {
+        let accordion = Accordion::new();
+        let sections = [
+            AccordionSection::expanded(4),
+            AccordionSection::collapsed(),
+            AccordionSection::expanded(3).max_height(2),
+        ];
+        let layouts = accordion.resolve(area(20), &sections);
+
+        assert_eq!(layouts[0].header, Rect::new(0, 0, 30, 1));
+        assert_eq!(layouts[0].content, Some(Rect::new(0, 1, 30, 4)));
+        assert_eq!(layouts[1].header, Rect::new(0, 5, 30, 1));
+        assert_eq!(layouts[1].content, None);
+        // max_height caps the third section at 2.
+        assert_eq!(layouts[2].content, Some(Rect::new(0, 7, 30, 2)));
+        assert_eq!(layouts[2].overflow, 0);
+    }
+
+    #[test]
+    fn proportional_shrink_is_deterministic_and_exact() {
+        let accordion = Accordion::new();
+        let sections = [
+            AccordionSection::expanded(10),
+            AccordionSection::expanded(5),
+            AccordionSection::expanded(5),
+        ];
+        // 3 headers + 7 content rows available (height 10).
+        let first = accordion.resolve(area(10), &sections);
+        let heights: Vec<u16> = first
+            .iter()
+            .map(|l| l.content.map_or(0, |r| r.height))
+            .collect();
+        assert_eq!(heights.iter().sum::<u16>(), 7, "every row allocated");
+        assert_eq!(heights, vec![3, 2, 2], "largest remainder, index tiebreak");
+        assert_eq!(first[0].overflow, 7);
+
+        // Stable across repeated resolutions.
+        for _ in 0..10 {
+            assert_eq!(accordion.resolve(area(10), &sections), first);
+        }
+    }
+
+    #[test]
+    fn scroll_within_section_allocates_in_order() {
+        let accordion = Accordion::new().policy(OversubscriptionPolicy::ScrollWithinSection);
+        let sections = [AccordionSection::expanded(6), AccordionSection::expanded(6)];
+        // 2 headers + 8 rows available.
+        let layouts = accordion.resolve(area(10), &sections);
+        assert_eq!(layouts[0].content.unwrap().height, 6);
+        assert_eq!(layouts[0].overflow, 0);
+        assert_eq!(layouts[1].content.unwrap().height, 2);
+        assert_eq!(layouts[1].overflow, 4, "tail section scrolls");
+    }
+
+    #[test]
+    fn headers_survive_when_content_cannot() {
+        let accordion = Accordion::new();
+        let sections = [
+            AccordionSection::expanded(10),
+            AccordionSection::expanded(10),
+            AccordionSection::expanded(10),
+        ];
+        // Only room for the three headers.
+        let layouts = accordion.resolve(area(3), &sections);
+        for layout in &layouts {
+            assert_eq!(layout.header.height, 1);
+            assert_eq!(layout.content, None);
+        }
+    }
+
+    #[test]
+    fn gap_and_tall_headers_reduce_content_budget() {
+        let accordion = Accordion::new().header_height(2).gap(1);
+        let sections = [AccordionSection::expanded(3), AccordionSection::expanded(3)];
+        // chrome = 2*2 + 1 = 5; height 11 leaves 6 content rows.
+        let layouts = accordion.resolve(area(11), &sections);
+        assert_eq!(layouts[0].header.height, 2);
+        assert_eq!(layouts[0].content.unwrap().height, 3);
+        assert_eq!(layouts[1].header.y, 6, "header + content + gap");
+        assert_eq!(layouts[1].content.unwrap().height, 3);
+    }
+
+    #[test]
+    fn exclusive_mode_transitions() {
+        let mut expanded = [true, false, false];
+        assert!(toggle_exclusive(&mut expanded, 2));
+        assert_eq!(expanded, [false, false, true], "expanding collapses others");
+        assert!(toggle_exclusive(&mut expanded, 2));
+        assert_eq!(expanded, [false, false, false], "toggling open closes it");
+        assert!(toggle_exclusive(&mut expanded, 1));
+        assert_eq!(expanded, [false, true, false]);
+        assert!(
+            !toggle_exclusive(&mut expanded, 9),
+            "out of range is a no-op"
+        );
+    }
+}
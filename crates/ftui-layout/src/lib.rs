@@ -39,6 +39,7 @@
 //! });
 //! ```
 
+pub mod accordion;
 pub mod cache;
 pub mod debug;
 pub mod dep_graph;
@@ -52,9 +53,13 @@ mod repro_max_constraint;
 mod repro_space_around;
 pub mod responsive;
 pub mod responsive_layout;
+pub mod size_class;
 pub mod visibility;
 pub mod workspace;
 
+pub use accordion::{
+    Accordion, AccordionSection, OversubscriptionPolicy, SectionLayout, toggle_exclusive,
+};
 pub use cache::{
     CoherenceCache, CoherenceId, LayoutCache, LayoutCacheKey, LayoutCacheStats, S3FifoLayoutCache,
 };
@@ -62,12 +67,12 @@ pub use direction::{FlowDirection, LogicalAlignment, LogicalSides, mirror_rects_
 pub use ftui_core::geometry::{Rect, Sides, Size};
 pub use grid::{Grid, GridArea, GridLayout};
 pub use pane::{
-    PANE_DEFAULT_MARGIN_CELLS, PANE_DEFAULT_PADDING_CELLS, PANE_DRAG_RESIZE_DEFAULT_HYSTERESIS,
+    ContentSizeHints, PANE_DEFAULT_MARGIN_CELLS, PANE_DEFAULT_PADDING_CELLS, PANE_DRAG_RESIZE_DEFAULT_HYSTERESIS,
     PANE_DRAG_RESIZE_DEFAULT_THRESHOLD, PANE_EDGE_GRIP_INSET_CELLS, PANE_MAGNETIC_FIELD_CELLS,
     PANE_SEMANTIC_INPUT_EVENT_SCHEMA_VERSION, PANE_SEMANTIC_INPUT_TRACE_SCHEMA_VERSION,
     PANE_SNAP_DEFAULT_HYSTERESIS_BPS, PANE_SNAP_DEFAULT_STEP_BPS, PANE_TREE_SCHEMA_VERSION,
     PaneCancelReason, PaneConstraints, PaneCoordinateNormalizationError, PaneCoordinateNormalizer,
-    PaneCoordinateRoundingPolicy, PaneDockPreview, PaneDockZone, PaneDragBehaviorTuning,
+    PaneCoordinateRoundingPolicy, PaneDockPreview, PaneDockZone, PaneDragBehaviorTuning, PaneDropTarget,
     PaneDragResizeEffect, PaneDragResizeMachine, PaneDragResizeMachineError,
     PaneDragResizeNoopReason, PaneDragResizeState, PaneDragResizeTransition, PaneEdgeResizePlan,
     PaneEdgeResizePlanError, PaneGroupTransformPlan, PaneId, PaneIdAllocator, PaneInertialThrow,
@@ -87,8 +92,10 @@ pub use pane::{
     PaneSemanticReplayDiffKind, PaneSemanticReplayError, PaneSemanticReplayFixture,
     PaneSemanticReplayOutcome, PaneSnapDecision, PaneSnapReason, PaneSnapTuning, PaneSplit,
     PaneSplitRatio, PaneTransaction, PaneTransactionOutcome, PaneTree, PaneTreeSnapshot, SplitAxis,
+    classify_drop_zone,
 };
 pub use responsive::Responsive;
+pub use size_class::{ClassChanged, ClassLayouts, ClassTracker, SizeClass, SizeClasses};
 pub use responsive_layout::{ResponsiveLayout, ResponsiveSplit};
 use std::cmp::min;
 pub use visibility::Visibility;
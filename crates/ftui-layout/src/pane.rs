@@ -186,6 +186,25 @@ impl Default for PaneConstraints {
     }
 }
 
+/// Size contract reported by a pane's hosted content.
+///
+/// Hosts feed these through [`PaneTree::set_content_hints`]; the solver
+/// treats the minimums as pane minimums during resize drags and
+/// reshapes, and [`PaneLayout::is_overflowed`] flags panes whose final
+/// rect could not satisfy them so the renderer can draw a compact
+/// "too small" placeholder instead of a broken widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ContentSizeHints {
+    /// Columns below which the content is unusable.
+    pub min_cols: u16,
+    /// Rows below which the content is unusable.
+    pub min_rows: u16,
+    /// Columns at which the content is comfortable (0 = no preference).
+    pub preferred_cols: u16,
+    /// Rows at which the content is comfortable (0 = no preference).
+    pub preferred_rows: u16,
+}
+
 /// Leaf payload for pane content identity.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PaneLeaf {
@@ -236,6 +255,10 @@ pub struct PaneNodeRecord {
     pub parent: Option<PaneId>,
     #[serde(default)]
     pub constraints: PaneConstraints,
+    /// Content-reported size contract ([`ContentSizeHints`]); raises the
+    /// effective minimums without touching the host-set constraints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hints: Option<ContentSizeHints>,
     #[serde(flatten)]
     pub kind: PaneNodeKind,
     /// Forward-compatible extension bag.
@@ -251,11 +274,35 @@ impl PaneNodeRecord {
             id,
             parent,
             constraints: PaneConstraints::default(),
+            content_hints: None,
             kind: PaneNodeKind::Leaf(leaf),
             extensions: BTreeMap::new(),
         }
     }
 
+    /// Constraints with the content-hint minimums folded in.
+    ///
+    /// Hint minimums raise `min_width`/`min_height` but never exceed the
+    /// host-set maximums (an impossible contract degrades to the max
+    /// rather than inverting the interval).
+    #[must_use]
+    pub fn effective_constraints(&self) -> PaneConstraints {
+        let mut constraints = self.constraints;
+        if let Some(hints) = self.content_hints {
+            let min_width = constraints.min_width.max(hints.min_cols);
+            let min_height = constraints.min_height.max(hints.min_rows);
+            constraints.min_width = match constraints.max_width {
+                Some(max) => min_width.min(max),
+                None => min_width,
+            };
+            constraints.min_height = match constraints.max_height {
+                Some(max) => min_height.min(max),
+                None => min_height,
+            };
+        }
+        constraints
+    }
+
     /// Construct a split node record.
     #[must_use]
     pub fn split(id: PaneId, parent: Option<PaneId>, split: PaneSplit) -> Self {
@@ -263,6 +310,7 @@ impl PaneNodeRecord {
             id,
             parent,
             constraints: PaneConstraints::default(),
+            content_hints: None,
             kind: PaneNodeKind::Split(split),
             extensions: BTreeMap::new(),
         }
@@ -473,11 +521,25 @@ impl std::error::Error for PaneRepairError {
 /// Concrete layout result for a solved pane tree.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PaneLayout {
+    /// Leaves whose final rect is below their content-hint minimums.
+    overflowed: BTreeSet<PaneId>,
     pub area: Rect,
     rects: BTreeMap<PaneId, Rect>,
 }
 
 impl PaneLayout {
+    /// Whether a pane's rect fell below its content-hint minimums; the
+    /// renderer draws a compact "too small" placeholder for these.
+    #[must_use]
+    pub fn is_overflowed(&self, node_id: PaneId) -> bool {
+        self.overflowed.contains(&node_id)
+    }
+
+    /// Panes whose content-hint minimums could not be satisfied.
+    pub fn overflowed(&self) -> impl Iterator<Item = PaneId> + '_ {
+        self.overflowed.iter().copied()
+    }
+
     /// Lookup rectangle for a specific pane node.
     #[must_use]
     pub fn rect(&self, node_id: PaneId) -> Option<Rect> {
@@ -3050,6 +3112,108 @@ fn euclidean_distance(a: PanePointerPosition, b: PanePointerPosition) -> f64 {
     (dx * dx + dy * dy).sqrt()
 }
 
+/// A computed drop target for pane drag-rearrangement.
+///
+/// `region` follows the standard 25%-edge heuristics: a pointer within a
+/// quarter of the pane's width/height of an edge targets that edge;
+/// anywhere else targets `Center`. `preview` is the ghost rectangle the
+/// host renders as the drop hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneDropTarget {
+    /// The pane the pointer is over.
+    pub target_pane: PaneId,
+    /// Edge (new split side) or center drop.
+    pub region: PaneDockZone,
+    /// Ghost rectangle for rendering the drop hint.
+    pub preview: Rect,
+}
+
+/// Classify a pointer inside `rect` into a dock zone with 25%-edge
+/// heuristics (nearest qualifying edge wins; ties prefer the horizontal
+/// axis).
+#[must_use]
+pub fn classify_drop_zone(rect: Rect, pointer: PanePointerPosition) -> PaneDockZone {
+    let width = f64::from(rect.width.max(1));
+    let height = f64::from(rect.height.max(1));
+    let fx = ((f64::from(pointer.x) - f64::from(rect.x)) / width).clamp(0.0, 1.0);
+    let fy = ((f64::from(pointer.y) - f64::from(rect.y)) / height).clamp(0.0, 1.0);
+
+    let left = fx;
+    let right = 1.0 - fx;
+    let top = fy;
+    let bottom = 1.0 - fy;
+    let mut zone = PaneDockZone::Center;
+    let mut best = 0.25;
+    for (distance, candidate) in [
+        (left, PaneDockZone::Left),
+        (right, PaneDockZone::Right),
+        (top, PaneDockZone::Top),
+        (bottom, PaneDockZone::Bottom),
+    ] {
+        if distance < best {
+            best = distance;
+            zone = candidate;
+        }
+    }
+    zone
+}
+
+impl PaneTree {
+    /// Compute the drop target under `pointer` for a drag of `source`.
+    ///
+    /// Only leaves are drop targets and the dragged pane itself is
+    /// skipped; `None` when the pointer is over no other leaf.
+    #[must_use]
+    pub fn drop_target_at(
+        &self,
+        layout: &PaneLayout,
+        pointer: PanePointerPosition,
+        source: PaneId,
+    ) -> Option<PaneDropTarget> {
+        for (id, rect) in layout.iter() {
+            if id == source {
+                continue;
+            }
+            let Some(node) = self.node(id) else {
+                continue;
+            };
+            if !matches!(node.kind, PaneNodeKind::Leaf(_)) {
+                continue;
+            }
+            let contains_x = pointer.x >= i32::from(rect.x)
+                && pointer.x < i32::from(rect.x) + i32::from(rect.width);
+            let contains_y = pointer.y >= i32::from(rect.y)
+                && pointer.y < i32::from(rect.y) + i32::from(rect.height);
+            if !(contains_x && contains_y) {
+                continue;
+            }
+            let region = classify_drop_zone(rect, pointer);
+            return Some(PaneDropTarget {
+                target_pane: id,
+                region,
+                preview: dock_zone_ghost_rect(rect, region),
+            });
+        }
+        None
+    }
+
+    /// The other child of `id`'s parent split, when it has one.
+    #[must_use]
+    pub fn only_sibling_of(&self, id: PaneId) -> Option<PaneId> {
+        let parent = self.node(id)?.parent?;
+        let PaneNodeKind::Split(ref split) = self.node(parent)?.kind else {
+            return None;
+        };
+        if split.first == id {
+            Some(split.second)
+        } else if split.second == id {
+            Some(split.first)
+        } else {
+            None
+        }
+    }
+}
+
 fn rect_zone_anchor(rect: Rect, zone: PaneDockZone) -> PanePointerPosition {
     let left = i32::from(rect.x);
     let right = i32::from(rect.x.saturating_add(rect.width.saturating_sub(1)));
@@ -3745,6 +3909,83 @@ impl PaneTree {
         self.nodes.values()
     }
 
+    /// The content-size hints reported for a pane, if any.
+    #[must_use]
+    pub fn content_hints(&self, id: PaneId) -> Option<ContentSizeHints> {
+        self.nodes.get(&id).and_then(|node| node.content_hints)
+    }
+
+    /// Install (or clear) the content-size contract for a pane.
+    ///
+    /// Usually set on leaves (hosted content), but splits accept hints
+    /// too — the solver folds a node's hints into its minimums wherever
+    /// it is. Returns `Ok(true)` when the hints changed — the host
+    /// should then re-solve the layout and apply
+    /// [`reconcile_hint_ratios`](Self::reconcile_hint_ratios), recording
+    /// any resulting ratio adjustments on its timeline as a *system*
+    /// operation (undone together with the next user operation, never on
+    /// its own).
+    pub fn set_content_hints(
+        &mut self,
+        id: PaneId,
+        hints: Option<ContentSizeHints>,
+    ) -> Result<bool, PaneModelError> {
+        let node = self
+            .nodes
+            .get_mut(&id)
+            .ok_or(PaneModelError::MissingRoot { root: id })?;
+        if node.content_hints == hints {
+            return Ok(false);
+        }
+        node.content_hints = hints;
+        Ok(true)
+    }
+
+    /// Ratio adjustments that bring split ratios in line with the sizes
+    /// the hint-aware solver actually produced for `area`.
+    ///
+    /// After hints change at runtime (content switched), ratios recorded
+    /// before the change may imply sizes the solver now clamps; the
+    /// returned [`PaneOperation::SetSplitRatio`] ops re-anchor them.
+    /// Hosts apply these as system operations on the timeline.
+    pub fn reconcile_hint_ratios(&self, area: Rect) -> Result<Vec<PaneOperation>, PaneModelError> {
+        let layout = self.solve_layout(area)?;
+        let mut operations = Vec::new();
+        for node in self.nodes.values() {
+            let PaneNodeKind::Split(split) = &node.kind else {
+                continue;
+            };
+            let (Some(first_rect), Some(rect)) = (layout.rect(split.first), layout.rect(node.id))
+            else {
+                continue;
+            };
+            let (first_size, available) = match split.axis {
+                SplitAxis::Horizontal => (first_rect.width, rect.width),
+                SplitAxis::Vertical => (first_rect.height, rect.height),
+            };
+            if available == 0 {
+                continue;
+            }
+            // Ratio-implied size, same arithmetic as the solver.
+            let total = u64::from(split.ratio.numerator()) + u64::from(split.ratio.denominator());
+            let implied =
+                ((u64::from(available) * u64::from(split.ratio.numerator())) / total) as u16;
+            if implied == first_size {
+                continue;
+            }
+            let second_size = available.saturating_sub(first_size);
+            if let Ok(ratio) =
+                PaneSplitRatio::new(u32::from(first_size.max(1)), u32::from(second_size.max(1)))
+            {
+                operations.push(PaneOperation::SetSplitRatio {
+                    split: node.id,
+                    ratio,
+                });
+            }
+        }
+        Ok(operations)
+    }
+
     /// Validate internal invariants.
     pub fn validate(&self) -> Result<(), PaneModelError> {
         validate_tree(self.root, self.next_id, &self.nodes)
@@ -3843,6 +4084,16 @@ impl PaneTree {
             mix_u64(&mut hash, node.id.get());
             mix_opt_pane_id(&mut hash, node.parent);
             mix_constraints(&mut hash, node.constraints);
+            match node.content_hints {
+                Some(hints) => {
+                    mix(&mut hash, 1);
+                    mix_u16(&mut hash, hints.min_cols);
+                    mix_u16(&mut hash, hints.min_rows);
+                    mix_u16(&mut hash, hints.preferred_cols);
+                    mix_u16(&mut hash, hints.preferred_rows);
+                }
+                None => mix(&mut hash, 0),
+            }
             mix_extensions(&mut hash, &node.extensions);
 
             match &node.kind {
@@ -4455,7 +4706,23 @@ impl PaneTree {
     pub fn solve_layout(&self, area: Rect) -> Result<PaneLayout, PaneModelError> {
         let mut rects = BTreeMap::new();
         self.solve_node(self.root, area, &mut rects)?;
-        Ok(PaneLayout { area, rects })
+        // Content hints are soft: panes that ended up below them are
+        // flagged, not failed.
+        let mut overflowed = BTreeSet::new();
+        for (&id, &rect) in &rects {
+            if let Some(node) = self.nodes.get(&id)
+                && let Some(hints) = node.content_hints
+                && matches!(node.kind, PaneNodeKind::Leaf(_))
+                && (rect.width < hints.min_cols || rect.height < hints.min_rows)
+            {
+                let _ = overflowed.insert(id);
+            }
+        }
+        Ok(PaneLayout {
+            area,
+            rects,
+            overflowed,
+        })
     }
 
     fn solve_node(
@@ -4490,27 +4757,34 @@ impl PaneTree {
                 child: split.second,
             })?;
 
-        let (first_bounds, second_bounds, available) = match split.axis {
-            SplitAxis::Horizontal => (
-                axis_bounds(first_node.constraints, split.axis),
-                axis_bounds(second_node.constraints, split.axis),
-                area.width,
-            ),
-            SplitAxis::Vertical => (
-                axis_bounds(first_node.constraints, split.axis),
-                axis_bounds(second_node.constraints, split.axis),
-                area.height,
-            ),
+        let available = match split.axis {
+            SplitAxis::Horizontal => area.width,
+            SplitAxis::Vertical => area.height,
         };
-
-        let (first_size, second_size) = solve_split_sizes(
+        // Content hints raise the minimums (drag clamping, reshapes).
+        // When the workspace is too small to satisfy them, fall back to
+        // the hard constraints — solve_layout flags the losers instead
+        // of failing the whole layout.
+        let hinted = solve_split_sizes(
             node_id,
             split.axis,
             available,
             split.ratio,
-            first_bounds,
-            second_bounds,
-        )?;
+            axis_bounds(first_node.effective_constraints(), split.axis),
+            axis_bounds(second_node.effective_constraints(), split.axis),
+        );
+        let (first_size, second_size) = match hinted {
+            Ok(sizes) => sizes,
+            Err(PaneModelError::OverconstrainedSplit { .. }) => solve_split_sizes(
+                node_id,
+                split.axis,
+                available,
+                split.ratio,
+                axis_bounds(first_node.constraints, split.axis),
+                axis_bounds(second_node.constraints, split.axis),
+            )?,
+            Err(other) => return Err(other),
+        };
 
         let (first_rect, second_rect) = match split.axis {
             SplitAxis::Horizontal => (
@@ -5594,6 +5868,16 @@ fn snapshot_state_hash(snapshot: &PaneTreeSnapshot) -> u64 {
         mix_opt_u16(&mut hash, node.constraints.max_width);
         mix_opt_u16(&mut hash, node.constraints.max_height);
         mix_bool(&mut hash, node.constraints.collapsible);
+        match node.content_hints {
+            Some(hints) => {
+                mix(&mut hash, 1);
+                mix_u16(&mut hash, hints.min_cols);
+                mix_u16(&mut hash, hints.min_rows);
+                mix_u16(&mut hash, hints.preferred_cols);
+                mix_u16(&mut hash, hints.preferred_rows);
+            }
+            None => mix(&mut hash, 0),
+        }
         mix_extensions(&mut hash, &node.extensions);
 
         match &node.kind {
@@ -6487,6 +6771,206 @@ mod tests {
         );
     }
 
+    // ── Content-size hints ───────────────────────────────────────────
+
+    /// Two leaves (ids 1 and 3) under a horizontal root split (id 2),
+    /// even 1:1 ratio.
+    fn hinted_pair() -> PaneTree {
+        let mut tree = PaneTree::singleton("left");
+        tree.apply_operation(
+            1,
+            PaneOperation::SplitLeaf {
+                target: id(1),
+                axis: SplitAxis::Horizontal,
+                ratio: PaneSplitRatio::new(1, 1).expect("valid ratio"),
+                placement: PanePlacement::ExistingFirst,
+                new_leaf: PaneLeaf::new("right"),
+            },
+        )
+        .expect("split");
+        tree
+    }
+
+    #[test]
+    fn drag_clamps_at_hinted_minimums() {
+        let mut tree = hinted_pair();
+        assert!(
+            tree.set_content_hints(
+                id(1),
+                Some(ContentSizeHints {
+                    min_cols: 30,
+                    min_rows: 1,
+                    preferred_cols: 40,
+                    preferred_rows: 10,
+                }),
+            )
+            .expect("leaf accepts hints")
+        );
+
+        // A drag to a 1:9 ratio would give the left pane 8 columns; the
+        // hint clamps it at 30.
+        tree.apply_operation(
+            2,
+            PaneOperation::SetSplitRatio {
+                split: id(2),
+                ratio: PaneSplitRatio::new(1, 9).expect("valid ratio"),
+            },
+        )
+        .expect("ratio applies");
+        let layout = tree
+            .solve_layout(Rect::new(0, 0, 80, 24))
+            .expect("solvable");
+        assert_eq!(layout.rect(id(1)).expect("left rect").width, 30);
+        assert_eq!(layout.rect(id(3)).expect("right rect").width, 50);
+        assert!(!layout.is_overflowed(id(1)));
+    }
+
+    #[test]
+    fn reshape_respects_hints_of_both_children() {
+        let mut tree = hinted_pair();
+        for (pane, min) in [(id(1), 25), (id(3), 40)] {
+            let _ = tree
+                .set_content_hints(
+                    pane,
+                    Some(ContentSizeHints {
+                        min_cols: min,
+                        min_rows: 1,
+                        preferred_cols: 0,
+                        preferred_rows: 0,
+                    }),
+                )
+                .expect("hints");
+        }
+        let layout = tree
+            .solve_layout(Rect::new(0, 0, 70, 24))
+            .expect("solvable");
+        // 1:1 would be 35/35; the right pane's hint pushes it to 40.
+        assert_eq!(layout.rect(id(1)).expect("left").width, 30);
+        assert_eq!(layout.rect(id(3)).expect("right").width, 40);
+    }
+
+    #[test]
+    fn tiny_workspace_flags_overflowed_panes() {
+        let mut tree = hinted_pair();
+        let _ = tree
+            .set_content_hints(
+                id(1),
+                Some(ContentSizeHints {
+                    min_cols: 30,
+                    min_rows: 1,
+                    preferred_cols: 0,
+                    preferred_rows: 0,
+                }),
+            )
+            .expect("hints");
+        let _ = tree
+            .set_content_hints(
+                id(3),
+                Some(ContentSizeHints {
+                    min_cols: 30,
+                    min_rows: 1,
+                    preferred_cols: 0,
+                    preferred_rows: 0,
+                }),
+            )
+            .expect("hints");
+
+        // 40 columns cannot satisfy 30 + 30: the layout still solves
+        // (hard constraints only) and flags the losers.
+        let layout = tree
+            .solve_layout(Rect::new(0, 0, 40, 24))
+            .expect("hints are soft");
+        let overflowed: Vec<PaneId> = layout.overflowed().collect();
+        assert!(!overflowed.is_empty(), "someone must be flagged");
+        for pane in overflowed {
+            let rect = layout.rect(pane).expect("rect exists");
+            assert!(rect.width < 30, "flagged pane is actually too small");
+        }
+    }
+
+    #[test]
+    fn hint_change_revalidates_and_emits_system_ratio_ops() {
+        let mut tree = hinted_pair();
+        let area = Rect::new(0, 0, 80, 24);
+        // Ratios consistent: nothing to reconcile.
+        assert!(
+            tree.reconcile_hint_ratios(area)
+                .expect("solvable")
+                .is_empty()
+        );
+
+        // Content switched: the left pane now needs 60 columns.
+        assert!(
+            tree.set_content_hints(
+                id(1),
+                Some(ContentSizeHints {
+                    min_cols: 60,
+                    min_rows: 1,
+                    preferred_cols: 0,
+                    preferred_rows: 0,
+                }),
+            )
+            .expect("hints")
+        );
+        // Same hints again: no change, no revalidation needed.
+        assert!(
+            !tree
+                .set_content_hints(
+                    id(1),
+                    Some(ContentSizeHints {
+                        min_cols: 60,
+                        min_rows: 1,
+                        preferred_cols: 0,
+                        preferred_rows: 0,
+                    }),
+                )
+                .expect("hints")
+        );
+
+        let ops = tree.reconcile_hint_ratios(area).expect("solvable");
+        assert_eq!(ops.len(), 1, "one ratio adjustment: {ops:?}");
+        let PaneOperation::SetSplitRatio { split, ratio } = &ops[0] else {
+            panic!("expected ratio op, got {ops:?}");
+        };
+        assert_eq!(*split, id(2));
+        // Applying the system op re-anchors the ratio at the clamped
+        // sizes, so the next solve needs no clamping.
+        let mut tree2 = tree.clone();
+        tree2
+            .apply_operation(
+                3,
+                PaneOperation::SetSplitRatio {
+                    split: *split,
+                    ratio: *ratio,
+                },
+            )
+            .expect("system op applies");
+        assert!(
+            tree2
+                .reconcile_hint_ratios(area)
+                .expect("solvable")
+                .is_empty(),
+            "ratios now consistent"
+        );
+        // Hints participate in the structural hash (dirty tracking).
+        assert_ne!(hinted_pair().state_hash(), tree.state_hash());
+    }
+
+    #[test]
+    fn hints_survive_snapshot_round_trip() {
+        let mut tree = hinted_pair();
+        let hints = ContentSizeHints {
+            min_cols: 12,
+            min_rows: 3,
+            preferred_cols: 20,
+            preferred_rows: 6,
+        };
+        let _ = tree.set_content_hints(id(1), Some(hints)).expect("hints");
+        let restored = PaneTree::from_snapshot(tree.to_snapshot()).expect("round-trips");
+        assert_eq!(restored.content_hints(id(1)), Some(hints));
+        assert_eq!(restored.content_hints(id(3)), None);
+    }
+
     #[test]
     fn constraints_validate_bounds() {
         let constraints = PaneConstraints {
@@ -8645,4 +9129,67 @@ mod tests {
         let replayed = timeline.replay().expect("replay should succeed");
         assert_eq!(replayed.state_hash(), tree.state_hash());
     }
+
+    // ── Drop-target classification (drag rearrangement) ─────────────
+
+    #[test]
+    fn classify_drop_zone_uses_quarter_edges() {
+        let rect = Rect::new(10, 10, 40, 20);
+        // Within 25% of each edge.
+        assert_eq!(
+            classify_drop_zone(rect, PanePointerPosition::new(12, 20)),
+            PaneDockZone::Left
+        );
+        assert_eq!(
+            classify_drop_zone(rect, PanePointerPosition::new(48, 20)),
+            PaneDockZone::Right
+        );
+        assert_eq!(
+            classify_drop_zone(rect, PanePointerPosition::new(30, 11)),
+            PaneDockZone::Top
+        );
+        assert_eq!(
+            classify_drop_zone(rect, PanePointerPosition::new(30, 28)),
+            PaneDockZone::Bottom
+        );
+        // Dead center.
+        assert_eq!(
+            classify_drop_zone(rect, PanePointerPosition::new(30, 20)),
+            PaneDockZone::Center
+        );
+        // Corner: the nearer edge wins.
+        assert_eq!(
+            classify_drop_zone(rect, PanePointerPosition::new(11, 12)),
+            PaneDockZone::Left
+        );
+    }
+
+    #[test]
+    fn drop_target_skips_source_and_non_leaves() {
+        let tree =
+            PaneTree::from_snapshot(make_valid_snapshot()).expect("snapshot should validate");
+        let layout = tree.solve_layout(Rect::new(0, 0, 80, 24)).expect("layout");
+
+        let leaves: Vec<PaneId> = tree
+            .nodes()
+            .filter(|n| matches!(n.kind, PaneNodeKind::Leaf(_)))
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(leaves.len(), 2);
+        let (a, b) = (leaves[0], leaves[1]);
+        let b_rect = layout.rect(b).expect("rect");
+        let inside_b = PanePointerPosition::new(
+            i32::from(b_rect.x) + i32::from(b_rect.width) / 2,
+            i32::from(b_rect.y) + i32::from(b_rect.height) / 2,
+        );
+
+        let target = tree.drop_target_at(&layout, inside_b, a).expect("target");
+        assert_eq!(target.target_pane, b);
+        assert_eq!(target.region, PaneDockZone::Center);
+        // Dragging b over itself finds nothing.
+        assert!(tree.drop_target_at(&layout, inside_b, b).is_none());
+        // The two leaves are each other's only siblings.
+        assert_eq!(tree.only_sibling_of(a), Some(b));
+        assert_eq!(tree.only_sibling_of(b), Some(a));
+    }
 }
@@ -0,0 +1,442 @@
+#![forbid(unsafe_code)]
+
+//! Named size classes over terminal cols/rows with hysteresis.
+//!
+//! Complements the width-tier [`Breakpoint`](crate::Breakpoint) system
+//! with app-defined classes ("sidebar collapses below 100 cols, status
+//! bar drops below 20 rows") so the if-width-less-than churn lives in one
+//! place:
+//!
+//! - [`SizeClasses`] declares named classes with ranges over cols and
+//!   rows; [`SizeClasses::resolve`] is total and deterministic.
+//! - [`ClassTracker`] observes sizes, emits [`ClassChanged`] exactly on
+//!   transitions, and supports hysteresis (K cells of slack before
+//!   switching back) to prevent flapping during interactive resizes.
+//! - [`ClassLayouts`] maps classes to layout-producing closures,
+//!   memoized by (class, size) so unchanged sizes don't re-resolve.
+//!
+//! # Boundary ownership
+//!
+//! Ranges use standard `Range` semantics: `80..140` contains 80 but not
+//! 140, so each boundary column belongs to exactly one class when ranges
+//! are written back to back (`..80`, `80..140`, `140..`). Classes are
+//! tested in declaration order; the first match wins, and a size matching
+//! no class resolves to the **last** declared class (declare your widest
+//! class last as the catch-all).
+
+use std::collections::HashMap;
+use std::ops::{Bound, RangeBounds};
+
+/// A resolved size class (index + name).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeClass {
+    /// Index in declaration order.
+    pub index: usize,
+    /// Declared name.
+    pub name: &'static str,
+}
+
+/// Inclusive-exclusive bounds captured from a `RangeBounds<u16>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    /// Inclusive lower bound.
+    min: u16,
+    /// Exclusive upper bound (`u16::MAX` means unbounded).
+    max: u16,
+}
+
+impl Bounds {
+    fn from_range(range: impl RangeBounds<u16>) -> Self {
+        let min = match range.start_bound() {
+            Bound::Included(&v) => v,
+            Bound::Excluded(&v) => v.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&v) => v.saturating_add(1),
+            Bound::Excluded(&v) => v,
+            Bound::Unbounded => u16::MAX,
+        };
+        Self { min, max }
+    }
+
+    fn contains(&self, value: u16) -> bool {
+        value >= self.min && (self.max == u16::MAX || value < self.max)
+    }
+
+    /// Expanded by `slack` on both sides (for hysteresis).
+    fn with_slack(&self, slack: u16) -> Self {
+        Self {
+            min: self.min.saturating_sub(slack),
+            max: if self.max == u16::MAX {
+                u16::MAX
+            } else {
+                self.max.saturating_add(slack)
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ClassDef {
+    name: &'static str,
+    cols: Bounds,
+    rows: Bounds,
+}
+
+/// Declarative set of named size classes.
+#[derive(Debug, Clone, Default)]
+pub struct SizeClasses {
+    classes: Vec<ClassDef>,
+}
+
+impl SizeClasses {
+    /// Start an empty declaration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a class matching a column range (any row count).
+    #[must_use]
+    pub fn class(self, name: &'static str, cols: impl RangeBounds<u16>) -> Self {
+        self.class_2d(name, cols, ..)
+    }
+
+    /// Declare a class matching both a column and a row range.
+    #[must_use]
+    pub fn class_2d(
+        mut self,
+        name: &'static str,
+        cols: impl RangeBounds<u16>,
+        rows: impl RangeBounds<u16>,
+    ) -> Self {
+        self.classes.push(ClassDef {
+            name,
+            cols: Bounds::from_range(cols),
+            rows: Bounds::from_range(rows),
+        });
+        self
+    }
+
+    /// Number of declared classes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// True when no classes are declared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// Resolve a terminal size to a class: first declared match wins, and
+    /// unmatched sizes resolve to the last declared class.
+    ///
+    /// # Panics
+    ///
+    /// Panics when no classes were declared.
+    #[must_use]
+    pub fn resolve(&self, size: (u16, u16)) -> SizeClass {
+        assert!(!self.classes.is_empty(), "no size classes declared");
+        self.resolve_inner(size, None, 0)
+    }
+
+    /// Resolve with hysteresis: while `current`'s (slack-expanded) ranges
+    /// still contain the size, stay on `current`.
+    fn resolve_inner(
+        &self,
+        (cols, rows): (u16, u16),
+        current: Option<usize>,
+        slack: u16,
+    ) -> SizeClass {
+        if let Some(index) = current
+            && let Some(def) = self.classes.get(index)
+            && def.cols.with_slack(slack).contains(cols)
+            && def.rows.with_slack(slack).contains(rows)
+        {
+            return SizeClass {
+                index,
+                name: def.name,
+            };
+        }
+        let index = self
+            .classes
+            .iter()
+            .position(|def| def.cols.contains(cols) && def.rows.contains(rows))
+            .unwrap_or(self.classes.len() - 1);
+        SizeClass {
+            index,
+            name: self.classes[index].name,
+        }
+    }
+}
+
+/// Emitted when the resolved class transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassChanged {
+    /// Previous class (`None` on the first observation).
+    pub from: Option<SizeClass>,
+    /// New class.
+    pub to: SizeClass,
+}
+
+/// Stateful class resolution with hysteresis and transition events.
+#[derive(Debug, Clone)]
+pub struct ClassTracker {
+    classes: SizeClasses,
+    /// Cells past a boundary required before switching class.
+    hysteresis: u16,
+    current: Option<SizeClass>,
+}
+
+impl ClassTracker {
+    /// Create a tracker without hysteresis.
+    #[must_use]
+    pub fn new(classes: SizeClasses) -> Self {
+        Self {
+            classes,
+            hysteresis: 0,
+            current: None,
+        }
+    }
+
+    /// Require `cells` past the boundary before switching away from the
+    /// current class (prevents flapping during interactive resizes).
+    #[must_use]
+    pub fn with_hysteresis(mut self, cells: u16) -> Self {
+        self.hysteresis = cells;
+        self
+    }
+
+    /// The current class, if a size has been observed.
+    #[must_use]
+    pub fn current(&self) -> Option<SizeClass> {
+        self.current
+    }
+
+    /// Observe a size. Returns a [`ClassChanged`] exactly when the
+    /// resolved class differs from the current one.
+    pub fn observe(&mut self, size: (u16, u16)) -> Option<ClassChanged> {
+        let resolved =
+            self.classes
+                .resolve_inner(size, self.current.map(|c| c.index), self.hysteresis);
+        if self.current == Some(resolved) {
+            return None;
+        }
+        let event = ClassChanged {
+            from: self.current,
+            to: resolved,
+        };
+        self.current = Some(resolved);
+        Some(event)
+    }
+}
+
+/// Size-class-driven layout production with memoization.
+///
+/// Producers are registered per class index and invoked with the concrete
+/// size; results are cached keyed by `(class, size)` so repeated renders
+/// at an unchanged size don't re-resolve the layout.
+/// Layout producer invoked with the concrete terminal size.
+type ProduceFn<T> = Box<dyn Fn((u16, u16)) -> T + Send>;
+
+pub struct ClassLayouts<T> {
+    producers: HashMap<usize, ProduceFn<T>>,
+    cache: Option<(usize, (u16, u16), T)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<T> Default for ClassLayouts<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for ClassLayouts<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClassLayouts")
+            .field("producers", &self.producers.len())
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+impl<T> ClassLayouts<T> {
+    /// Create an empty mapping.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            producers: HashMap::new(),
+            cache: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Register the layout producer for a class (builder).
+    #[must_use]
+    pub fn on(
+        mut self,
+        class_index: usize,
+        produce: impl Fn((u16, u16)) -> T + Send + 'static,
+    ) -> Self {
+        self.producers.insert(class_index, Box::new(produce));
+        self
+    }
+
+    /// Resolve the layout for `class` at `size`, memoized.
+    ///
+    /// Returns `None` when no producer is registered for the class.
+    pub fn layout(&mut self, class: SizeClass, size: (u16, u16)) -> Option<&T> {
+        if let Some((cached_class, cached_size, _)) = &self.cache
+            && *cached_class == class.index
+            && *cached_size == size
+        {
+            self.hits += 1;
+            return self.cache.as_ref().map(|(_, _, layout)| layout);
+        }
+        let produce = self.producers.get(&class.index)?;
+        let layout = produce(size);
+        self.misses += 1;
+        self.cache = Some((class.index, size, layout));
+        self.cache.as_ref().map(|(_, _, layout)| layout)
+    }
+
+    /// Memoization hits (for tests and diagnostics).
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Producer invocations.
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn three_classes() -> SizeClasses {
+        SizeClasses::new()
+            .class("compact", ..80)
+            .class("normal", 80..140)
+            .class("wide", 140..)
+    }
+
+    #[test]
+    fn boundary_resolution_is_total_and_owned() {
+        let classes = three_classes();
+        // Each boundary belongs to exactly one class (Range semantics).
+        assert_eq!(classes.resolve((79, 24)).name, "compact");
+        assert_eq!(classes.resolve((80, 24)).name, "normal");
+        assert_eq!(classes.resolve((139, 24)).name, "normal");
+        assert_eq!(classes.resolve((140, 24)).name, "wide");
+        assert_eq!(classes.resolve((0, 0)).name, "compact");
+        assert_eq!(classes.resolve((u16::MAX, 1)).name, "wide");
+    }
+
+    #[test]
+    fn row_ranges_participate() {
+        let classes = SizeClasses::new()
+            .class_2d("short", .., ..20)
+            .class_2d("tall", .., 20..);
+        assert_eq!(classes.resolve((100, 19)).name, "short");
+        assert_eq!(classes.resolve((100, 20)).name, "tall");
+    }
+
+    #[test]
+    fn unmatched_size_falls_back_to_last_class() {
+        let classes = SizeClasses::new().class("a", ..10).class("b", 20..30);
+        // 15 matches nothing: last class wins (documented catch-all).
+        assert_eq!(classes.resolve((15, 10)).name, "b");
+    }
+
+    #[test]
+    fn hysteresis_prevents_flapping_around_a_breakpoint() {
+        let mut tracker = ClassTracker::new(three_classes()).with_hysteresis(3);
+
+        // First observation always emits.
+        let first = tracker.observe((85, 24)).expect("initial class");
+        assert_eq!(first.to.name, "normal");
+
+        // Oscillate around the 80-column boundary within the slack: no
+        // transitions.
+        for cols in [79, 81, 78, 80, 79, 77] {
+            assert_eq!(tracker.observe((cols, 24)), None, "cols {cols}");
+        }
+
+        // Crossing past the slack switches.
+        let change = tracker.observe((76, 24)).expect("past hysteresis");
+        assert_eq!(change.from.unwrap().name, "normal");
+        assert_eq!(change.to.name, "compact");
+
+        // And switching back needs to clear the slack the other way.
+        assert_eq!(tracker.observe((81, 24)), None);
+        let back = tracker.observe((83, 24)).expect("back past hysteresis");
+        assert_eq!(back.to.name, "normal");
+    }
+
+    #[test]
+    fn events_emitted_exactly_on_transitions() {
+        let mut tracker = ClassTracker::new(three_classes());
+        let mut events = Vec::new();
+        for cols in [50, 50, 90, 90, 90, 150, 60] {
+            if let Some(event) = tracker.observe((cols, 24)) {
+                events.push((event.from.map(|c| c.name), event.to.name));
+            }
+        }
+        assert_eq!(
+            events,
+            vec![
+                (None, "compact"),
+                (Some("compact"), "normal"),
+                (Some("normal"), "wide"),
+                (Some("wide"), "compact"),
+            ]
+        );
+    }
+
+    #[test]
+    fn layouts_memoize_by_class_and_size() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let classes = three_classes();
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        let mut layouts: ClassLayouts<String> = ClassLayouts::new()
+            .on(0, {
+                let counter = Arc::clone(&counter);
+                move |size| {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    format!("compact {size:?}")
+                }
+            })
+            .on(1, move |size| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                format!("normal {size:?}")
+            });
+
+        let compact = classes.resolve((70, 24));
+        assert!(layouts.layout(compact, (70, 24)).is_some());
+        assert!(layouts.layout(compact, (70, 24)).is_some());
+        assert!(layouts.layout(compact, (70, 24)).is_some());
+        assert_eq!(calls.load(Ordering::Relaxed), 1, "memoized");
+        assert_eq!(layouts.hits(), 2);
+
+        // Same class, different size: re-resolves.
+        assert!(layouts.layout(compact, (75, 24)).is_some());
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        // Unregistered class: None.
+        let wide = classes.resolve((150, 24));
+        assert!(layouts.layout(wide, (150, 24)).is_none());
+    }
+}
@@ -0,0 +1,345 @@
+#![forbid(unsafe_code)]
+
+//! Overlay attributes for the embedder render path.
+//!
+//! Search match highlights (and friends) must not mutate cells — the
+//! grid is the child program's content and exports stay clean.
+//! [`CellOverlayStore`] maps absolute buffer positions to overlay
+//! styles; the renderer consults
+//! [`overlays_for_row`](CellOverlayStore::overlays_for_row) when
+//! drawing each row and merges the style on top.
+//!
+//! Staleness is handled through the terminal's damage journal: the
+//! store holds its own [`DamageCursor`] and
+//! [`sync`](CellOverlayStore::sync) drops overlays whose underlying
+//! cells were rewritten (and overlays on evicted scrollback), so a
+//! highlight never lingers over changed content. Bulk set/clear are
+//! O(matches) — applying 2000 search matches never walks the buffer.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::virtual_terminal::{DamageCursor, VirtualTerminal};
+
+/// What an overlay span represents; the embedder maps kinds to styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverlayKind {
+    /// A search match.
+    Match,
+    /// The focused/current search match.
+    CurrentMatch,
+    /// A selection span (selection state can migrate here).
+    Selection,
+}
+
+/// One overlay span on a row: columns `start..end` (end exclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlaySpan {
+    pub start: u16,
+    pub end: u16,
+    pub kind: OverlayKind,
+}
+
+/// Overlay attributes keyed by absolute buffer line.
+///
+/// Absolute lines are stable across scrolling (scrollback keeps its
+/// identity), so overlays survive output that merely scrolls the
+/// viewport. See the module docs for the invalidation contract.
+#[derive(Debug)]
+pub struct CellOverlayStore {
+    rows: BTreeMap<u64, Vec<OverlaySpan>>,
+    cursor: DamageCursor,
+    /// Absolute line of screen row 0 at the last sync. Lines that moved
+    /// below this into scrollback during a sync window were mutable the
+    /// whole window, so pre-existing overlays there are suspect.
+    last_base: u64,
+}
+
+impl CellOverlayStore {
+    /// An empty store tracking `vt`'s damage from now on.
+    #[must_use]
+    pub fn new(vt: &VirtualTerminal) -> Self {
+        Self {
+            rows: BTreeMap::new(),
+            cursor: vt.damage_cursor(),
+            last_base: vt.end_absolute_line(),
+        }
+    }
+
+    /// Bulk-apply overlay spans: O(spans), never O(buffer).
+    ///
+    /// Call [`sync`](Self::sync) first if the terminal has fed since
+    /// the positions were computed.
+    pub fn set_overlays(&mut self, spans: impl IntoIterator<Item = (u64, OverlaySpan)>) {
+        for (abs_line, span) in spans {
+            if span.start >= span.end {
+                continue;
+            }
+            let row = self.rows.entry(abs_line).or_default();
+            row.push(span);
+            row.sort_by_key(|s| (s.start, s.end));
+        }
+    }
+
+    /// Remove every span of one kind: O(rows with overlays).
+    pub fn clear_kind(&mut self, kind: OverlayKind) {
+        self.rows.retain(|_, spans| {
+            spans.retain(|span| span.kind != kind);
+            !spans.is_empty()
+        });
+    }
+
+    /// Remove everything.
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+
+    /// Number of rows carrying at least one overlay.
+    #[must_use]
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Overlay spans for an absolute line, ascending by start column.
+    pub fn overlays_for_row(
+        &self,
+        abs_line: u64,
+    ) -> impl Iterator<Item = (Range<u16>, OverlayKind)> + '_ {
+        self.rows
+            .get(&abs_line)
+            .into_iter()
+            .flatten()
+            .map(|span| (span.start..span.end, span.kind))
+    }
+
+    /// Consume pending damage and drop stale overlays.
+    ///
+    /// - A rewritten cell invalidates every span on its row (the damage
+    ///   journal reports supersets, so invalidation is conservative:
+    ///   stale highlights never persist, at the cost of occasionally
+    ///   dropping still-valid spans on the same row — the embedder
+    ///   re-applies from its search results).
+    /// - A full invalidation clears the store (content unknowable).
+    /// - Overlays on evicted scrollback are pruned.
+    ///
+    /// Call once per frame before consulting
+    /// [`overlays_for_row`](Self::overlays_for_row).
+    pub fn sync(&mut self, vt: &mut VirtualTerminal) {
+        let mut cursor = self.cursor;
+        let report = vt.take_damage(&mut cursor);
+        self.cursor = cursor;
+
+        let base = vt.end_absolute_line();
+        if report.full_invalidate {
+            self.rows.clear();
+            self.last_base = base;
+            return;
+        }
+
+        // Lines that scrolled from screen into scrollback during this
+        // window were mutable the whole time, and the journal drops
+        // their damage on scroll translation — conservatively drop any
+        // overlay that predates the freeze. (Overlays applied to frozen
+        // scrollback afterwards are stable and stay.)
+        for abs_line in self.last_base..base {
+            self.rows.remove(&abs_line);
+        }
+        self.last_base = base;
+
+        // Screen-row damage, reported in final coordinates: map to
+        // absolute lines (screen row 0 sits at end_absolute_line).
+        for (row, _cols) in &report.rows {
+            let abs_line = base + u64::from(*row);
+            self.rows.remove(&abs_line);
+        }
+
+        // Evicted scrollback can never be rendered again.
+        let first = vt.first_absolute_line();
+        if first > 0 {
+            // Split off the still-live tail, dropping everything below.
+            self.rows = self.rows.split_off(&first);
+        }
+    }
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(start: u16, end: u16, kind: OverlayKind) -> OverlaySpan {
+        OverlaySpan { start, end, kind }
+    }
+
+    fn match_spans(store: &CellOverlayStore, abs_line: u64) -> Vec<(Range<u16>, OverlayKind)> {
+        store.overlays_for_row(abs_line).collect()
+    }
+
+    #[test]
+    fn lookup_returns_sorted_spans_per_row() {
+        let vt = VirtualTerminal::new(40, 5);
+        let mut store = CellOverlayStore::new(&vt);
+        store.set_overlays([
+            (2, span(10, 14, OverlayKind::Match)),
+            (2, span(0, 4, OverlayKind::CurrentMatch)),
+            (3, span(5, 6, OverlayKind::Selection)),
+        ]);
+        assert_eq!(
+            match_spans(&store, 2),
+            vec![
+                (0..4, OverlayKind::CurrentMatch),
+                (10..14, OverlayKind::Match),
+            ]
+        );
+        assert_eq!(match_spans(&store, 3), vec![(5..6, OverlayKind::Selection)]);
+        assert!(match_spans(&store, 0).is_empty());
+    }
+
+    #[test]
+    fn cell_overwrite_invalidates_spans_on_that_row_only() {
+        let mut vt = VirtualTerminal::new(40, 5);
+        vt.feed_str("needle in a haystack\r\nsecond row match");
+        let mut store = CellOverlayStore::new(&vt);
+        store.sync(&mut vt); // swallow the initial full invalidation
+
+        let base = vt.end_absolute_line();
+        store.set_overlays([
+            (base, span(0, 6, OverlayKind::Match)),       // row 0
+            (base + 1, span(11, 16, OverlayKind::Match)), // row 1
+        ]);
+
+        // Overwrite a cell on row 0: its overlays drop (conservative,
+        // row-granular); row 1's survive untouched.
+        vt.feed_str("\x1b[1;2Hx");
+        store.sync(&mut vt);
+        assert!(
+            match_spans(&store, base).is_empty(),
+            "rewritten row dropped"
+        );
+        assert_eq!(
+            match_spans(&store, base + 1),
+            vec![(11..16, OverlayKind::Match)],
+            "untouched row kept"
+        );
+    }
+
+    #[test]
+    fn scroll_keeps_absolute_overlays_and_eviction_prunes() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.set_max_scrollback(4);
+        vt.feed_str("match-me\r\n");
+        let mut store = CellOverlayStore::new(&vt);
+        store.sync(&mut vt);
+
+        // The overlaid line is screen row 0 right now.
+        let abs = vt.end_absolute_line();
+        // Overwrite nothing on that row; just scroll it into scrollback.
+        vt.feed_str("\x1b[3;1H\n\n\n");
+        store.sync(&mut vt);
+        assert_eq!(
+            match_spans(&store, abs).len(),
+            0,
+            "sanity: no overlays applied yet"
+        );
+        store.set_overlays([(abs, span(0, 8, OverlayKind::Match))]);
+        assert_eq!(match_spans(&store, abs).len(), 1);
+
+        // Keep printing until the overlaid line is evicted.
+        for _ in 0..12 {
+            vt.feed_str("filler\r\n");
+        }
+        store.sync(&mut vt);
+        assert!(
+            vt.first_absolute_line() > abs,
+            "line evicted: {} > {abs}",
+            vt.first_absolute_line()
+        );
+        assert!(
+            match_spans(&store, abs).is_empty(),
+            "evicted overlay pruned"
+        );
+    }
+
+    #[test]
+    fn rewrite_then_scroll_out_still_invalidates() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.feed_str("target line");
+        let mut store = CellOverlayStore::new(&vt);
+        store.sync(&mut vt);
+
+        let abs = vt.end_absolute_line();
+        store.set_overlays([(abs, span(0, 6, OverlayKind::Match))]);
+
+        // Rewrite the overlaid row, then scroll it into scrollback
+        // before the next sync: the damage entry is dropped by the
+        // journal's scroll translation, so the window-based rule must
+        // catch it.
+        vt.feed_str("\x1b[1;1HREWRIT");
+        vt.feed_str("\x1b[3;1H\n\n\n\n");
+        store.sync(&mut vt);
+        assert!(
+            match_spans(&store, abs).is_empty(),
+            "stale highlight must not survive scroll-out"
+        );
+
+        // Overlays applied AFTER the line froze in scrollback persist.
+        store.set_overlays([(abs, span(0, 6, OverlayKind::Match))]);
+        vt.feed_str("more output\r\nand more\r\n");
+        store.sync(&mut vt);
+        assert_eq!(
+            match_spans(&store, abs).len(),
+            1,
+            "frozen-scrollback overlay is stable"
+        );
+    }
+
+    #[test]
+    fn bulk_apply_and_clear_are_cheap() {
+        let vt = VirtualTerminal::new(200, 50);
+        let mut store = CellOverlayStore::new(&vt);
+
+        let spans: Vec<(u64, OverlaySpan)> = (0..2_000)
+            .map(|i| {
+                (
+                    i / 4,
+                    span(
+                        (i % 4) as u16 * 10,
+                        (i % 4) as u16 * 10 + 5,
+                        OverlayKind::Match,
+                    ),
+                )
+            })
+            .collect();
+        let start = std::time::Instant::now();
+        store.set_overlays(spans);
+        let apply = start.elapsed();
+        assert_eq!(store.row_count(), 500);
+
+        let start = std::time::Instant::now();
+        store.clear_kind(OverlayKind::Match);
+        let clear = start.elapsed();
+        assert_eq!(store.row_count(), 0);
+
+        // O(matches): generous bounds that an O(buffer) walk of a
+        // 200×50 grid plus scrollback would not meet per-match.
+        assert!(apply < std::time::Duration::from_millis(200), "{apply:?}");
+        assert!(clear < std::time::Duration::from_millis(50), "{clear:?}");
+    }
+
+    #[test]
+    fn overlays_do_not_touch_cells_or_exports() {
+        let mut vt = VirtualTerminal::new(40, 3);
+        vt.feed_str("clean export line");
+        let before = crate::export::export_text(&vt, &crate::export::ExportOptions::default());
+
+        let mut store = CellOverlayStore::new(&vt);
+        store.sync(&mut vt);
+        let base = vt.end_absolute_line();
+        store.set_overlays([(base, span(0, 5, OverlayKind::CurrentMatch))]);
+
+        let after = crate::export::export_text(&vt, &crate::export::ExportOptions::default());
+        assert_eq!(before, after, "exports stay clean by default");
+        assert_eq!(vt.cell_at(0, 0).unwrap().ch, 'c', "cells untouched");
+    }
+}
@@ -0,0 +1,625 @@
+#![forbid(unsafe_code)]
+
+//! Streaming text export for the virtual terminal.
+//!
+//! Exporting a 100k-line scrollback through a single returned `String`
+//! means a giant allocation and a long pause; [`export_text_to`] streams
+//! line by line into any [`Write`] with periodic flushes instead. The
+//! String-returning [`export_text`] is a thin wrapper over the streaming
+//! path so the two can never diverge.
+//!
+//! Options cover line ranges (over scrollback + visible screen), soft-wrap
+//! joining, trailing-space trimming, and an optional `max_bytes` limit
+//! that stops cleanly at a line boundary and reports truncation.
+
+use std::io::{self, Write};
+
+use crate::virtual_terminal::{VCell, VirtualTerminal};
+
+/// How tabs are represented in exported text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabPolicy {
+    /// Emit the grid's cells as-is (tabs were expanded to spaces by the
+    /// VT when the cursor moved).
+    #[default]
+    Expand,
+    /// Re-infer `\t` from runs of blank cells that start at a recorded
+    /// tab origin and end exactly at a tab stop.
+    Preserve,
+}
+
+/// Options for text export.
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    /// First line to export (0 = oldest scrollback line). `None` = start.
+    pub start_line: Option<usize>,
+    /// One past the last line to export. `None` = through the last screen row.
+    pub end_line: Option<usize>,
+    /// Join lines that soft-wrapped at the right edge into logical lines.
+    pub join_soft_wraps: bool,
+    /// Trim trailing spaces from each exported line.
+    pub trim_trailing: bool,
+    /// Stop (at a line boundary) once this many bytes have been written.
+    pub max_bytes: Option<usize>,
+    /// Flush the writer after this many lines (0 disables periodic flush).
+    pub flush_every_lines: usize,
+    /// Tab representation policy.
+    pub tabs: TabPolicy,
+    /// Export only lines whose timestamps fall in this closed range
+    /// (epoch ms; requires [`VirtualTerminal::enable_line_timestamps`]).
+    /// Overrides `start_line`/`end_line`. Grid viewport rows are "now":
+    /// a range reaching the present includes them.
+    pub time_range: Option<(u64, u64)>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            start_line: None,
+            end_line: None,
+            join_soft_wraps: false,
+            trim_trailing: true,
+            max_bytes: None,
+            flush_every_lines: 64,
+            tabs: TabPolicy::default(),
+            time_range: None,
+        }
+    }
+}
+
+/// Summary of a completed (or truncated) export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportSummary {
+    /// Lines written (logical lines when soft-wrap joining is on).
+    pub lines_written: usize,
+    /// Bytes written, including line terminators.
+    pub bytes_written: usize,
+    /// True when `max_bytes` stopped the export early.
+    pub truncated: bool,
+}
+
+/// Export visible + scrollback text as a `String`.
+///
+/// Thin wrapper over [`export_text_to`]; behavior is identical.
+#[must_use]
+pub fn export_text(vt: &VirtualTerminal, opts: &ExportOptions) -> String {
+    let mut out = Vec::new();
+    // Writing into a Vec cannot fail.
+    export_text_to(vt, opts, &mut out).expect("Vec writer is infallible");
+    String::from_utf8(out).expect("exported text is UTF-8")
+}
+
+/// Stream visible + scrollback text line by line into `writer`.
+///
+/// Lines are terminated with `\n`. The writer is flushed every
+/// `flush_every_lines` lines and once at the end. A mid-way I/O error
+/// propagates without panicking; `max_bytes` stops cleanly at a line
+/// boundary with `truncated` set in the summary.
+pub fn export_text_to<W: Write>(
+    vt: &VirtualTerminal,
+    opts: &ExportOptions,
+    mut writer: W,
+) -> io::Result<ExportSummary> {
+    let total = vt.scrollback_len() + usize::from(vt.height());
+    let (start, end) = match opts.time_range {
+        Some((from, to)) => {
+            let (s, e) = vt.time_range_to_lines(from, to);
+            (s.min(total), e.min(total))
+        }
+        None => (
+            opts.start_line.unwrap_or(0).min(total),
+            opts.end_line.unwrap_or(total).min(total),
+        ),
+    };
+
+    let mut summary = ExportSummary {
+        lines_written: 0,
+        bytes_written: 0,
+        truncated: false,
+    };
+    let mut pending = String::new();
+    let mut have_pending = false;
+    let mut since_flush = 0usize;
+
+    let emit = |writer: &mut W,
+                line: &str,
+                summary: &mut ExportSummary,
+                since_flush: &mut usize|
+     -> io::Result<bool> {
+        let needed = line.len() + 1;
+        if let Some(max) = opts.max_bytes
+            && summary.bytes_written + needed > max
+        {
+            summary.truncated = true;
+            return Ok(false);
+        }
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+        summary.bytes_written += needed;
+        summary.lines_written += 1;
+        *since_flush += 1;
+        if opts.flush_every_lines > 0 && *since_flush >= opts.flush_every_lines {
+            writer.flush()?;
+            *since_flush = 0;
+        }
+        Ok(true)
+    };
+
+    for idx in start..end {
+        let (raw, continues) = line_at(vt, idx, opts.tabs);
+        let line = if opts.trim_trailing && !(opts.join_soft_wraps && continues) {
+            raw.trim_end().to_string()
+        } else {
+            raw
+        };
+
+        if opts.join_soft_wraps {
+            pending.push_str(&line);
+            have_pending = true;
+            if continues && idx + 1 < end {
+                continue;
+            }
+            let logical = std::mem::take(&mut pending);
+            have_pending = false;
+            let logical = if opts.trim_trailing {
+                logical.trim_end().to_string()
+            } else {
+                logical
+            };
+            if !emit(&mut writer, &logical, &mut summary, &mut since_flush)? {
+                writer.flush()?;
+                return Ok(summary);
+            }
+        } else if !emit(&mut writer, &line, &mut summary, &mut since_flush)? {
+            writer.flush()?;
+            return Ok(summary);
+        }
+    }
+
+    // A trailing pending line can only remain when the range ended exactly
+    // on a continuation row.
+    if have_pending {
+        let logical = if opts.trim_trailing {
+            pending.trim_end().to_string()
+        } else {
+            pending
+        };
+        let _ = emit(&mut writer, &logical, &mut summary, &mut since_flush)?;
+    }
+
+    writer.flush()?;
+    Ok(summary)
+}
+
+/// The text of combined line `idx` (scrollback first, then screen rows),
+/// untrimmed, plus whether it soft-wraps onto the next line.
+pub(crate) fn line_at(vt: &VirtualTerminal, idx: usize, tabs: TabPolicy) -> (String, bool) {
+    let scrollback = vt.scrollback_len();
+    let width = usize::from(vt.width());
+    let cells = if idx < scrollback {
+        vt.scrollback_cells(idx)
+    } else {
+        vt.row_cells((idx - scrollback) as u16)
+    };
+    let text = match cells {
+        Some(cells) => cells_to_text(vt, cells, tabs),
+        None => String::new(),
+    };
+    // Soft-wrap heuristic: a line that fills the full width with a
+    // non-blank final cell continues onto the next.
+    let continues = text.chars().count() >= width && !text.ends_with(' ') && !text.is_empty();
+    (text, continues)
+}
+
+/// Render a row of cells as text, optionally re-inferring tabs.
+///
+/// With [`TabPolicy::Preserve`], a blank run starting at a cell the VT
+/// flagged as a tab origin and reaching exactly the next tab stop becomes
+/// one `\t`; everything else (including manually typed spaces) stays as
+/// spaces, so mixed tab/space content round-trips faithfully.
+fn cells_to_text(vt: &VirtualTerminal, cells: &[VCell], tabs: TabPolicy) -> String {
+    let mut out = String::with_capacity(cells.len());
+    let mut x = 0usize;
+    while x < cells.len() {
+        let cell = &cells[x];
+        if tabs == TabPolicy::Preserve && cell.tab_origin && cell.ch == ' ' {
+            // The tab spans the origin up to (exclusive) the stop the
+            // cursor landed on; the stop cell may hold the next char.
+            let stop = usize::from(vt.next_tab_stop(x as u16)).min(cells.len());
+            if stop > x && cells[x..stop].iter().all(|c| c.ch == ' ') {
+                out.push('\t');
+                x = stop;
+                continue;
+            }
+        }
+        match cell.ch {
+            // Wide-char continuation cells contribute nothing.
+            '\0' => {}
+            ch => {
+                out.push(ch);
+                // Surviving combining marks follow their base.
+                out.extend(cell.combining.iter());
+            }
+        }
+        x += 1;
+    }
+    out
+}
+
+/// Export lines as JSONL: one object per line with its index, text,
+/// and — when timestamping is enabled — the line's timestamp (screen
+/// rows carry the current clock reading as `"ts"`, marked `"screen"`).
+///
+/// Schema per line:
+/// `{"line":<combined index>,"text":"…"}` plus optional
+/// `"ts":<epoch ms>` and `"screen":true`.
+#[must_use]
+pub fn export_json(vt: &VirtualTerminal, opts: &ExportOptions) -> String {
+    let total = vt.scrollback_len() + usize::from(vt.height());
+    let (start, end) = match opts.time_range {
+        Some((from, to)) => {
+            let (s, e) = vt.time_range_to_lines(from, to);
+            (s.min(total), e.min(total))
+        }
+        None => (
+            opts.start_line.unwrap_or(0).min(total),
+            opts.end_line.unwrap_or(total).min(total),
+        ),
+    };
+    let now = vt.timestamp_now();
+    let mut out = String::new();
+    for idx in start..end {
+        let (raw, _) = line_at(vt, idx, opts.tabs);
+        let text = if opts.trim_trailing {
+            raw.trim_end()
+        } else {
+            raw.as_str()
+        };
+        out.push_str(&format!("{{\"line\":{idx}"));
+        if idx < vt.scrollback_len() {
+            if let Some(ts) = vt.line_timestamp(idx) {
+                out.push_str(&format!(",\"ts\":{ts}"));
+            }
+        } else if let Some(now) = now {
+            out.push_str(&format!(",\"ts\":{now},\"screen\":true"));
+        }
+        out.push_str(",\"text\":\"");
+        for c in text.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                c if (c as u32) < 0x20 => {
+                    out.push_str(&format!("\\u{:04x}", c as u32));
+                }
+                c => out.push(c),
+            }
+        }
+        out.push_str("\"}\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vt_with_lines(width: u16, height: u16, input: &str) -> VirtualTerminal {
+        let mut vt = VirtualTerminal::new(width, height);
+        vt.feed(input.as_bytes());
+        vt
+    }
+
+    /// Writer that fails after a byte budget (for error propagation tests).
+    struct FailingWriter {
+        budget: usize,
+    }
+
+    impl Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.len() > self.budget {
+                return Err(io::Error::other("disk full"));
+            }
+            self.budget -= buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn streaming_matches_string_api_across_options() {
+        let mut vt = VirtualTerminal::new(12, 4);
+        // Scrollback + a soft-wrapped long line + short lines.
+        vt.feed(b"one\r\ntwo\r\nthis line is much longer than twelve\r\nshort\r\nlast");
+
+        let option_combos = [
+            ExportOptions::default(),
+            ExportOptions {
+                join_soft_wraps: true,
+                ..Default::default()
+            },
+            ExportOptions {
+                trim_trailing: false,
+                ..Default::default()
+            },
+            ExportOptions {
+                start_line: Some(1),
+                end_line: Some(4),
+                ..Default::default()
+            },
+            ExportOptions {
+                join_soft_wraps: true,
+                trim_trailing: false,
+                flush_every_lines: 1,
+                ..Default::default()
+            },
+        ];
+
+        for (i, opts) in option_combos.iter().enumerate() {
+            let via_string = export_text(&vt, opts);
+            let mut via_writer = Vec::new();
+            let summary = export_text_to(&vt, opts, &mut via_writer).unwrap();
+            assert_eq!(
+                via_string.as_bytes(),
+                via_writer.as_slice(),
+                "combo {i} diverged"
+            );
+            assert_eq!(summary.bytes_written, via_writer.len(), "combo {i}");
+            assert!(!summary.truncated, "combo {i}");
+            assert_eq!(
+                summary.lines_written,
+                via_string.lines().count(),
+                "combo {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn soft_wrap_joining_produces_logical_lines() {
+        let vt = vt_with_lines(10, 4, "abcdefghijklmnop\r\nnext");
+        let opts = ExportOptions {
+            join_soft_wraps: true,
+            end_line: Some(3),
+            ..Default::default()
+        };
+        let text = export_text(&vt, &opts);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "abcdefghijklmnop");
+        assert_eq!(lines[1], "next");
+    }
+
+    #[test]
+    fn midway_io_error_propagates_without_panic() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.feed(b"aaaa\r\nbbbb\r\ncccc\r\ndddd");
+        let err = export_text_to(&vt, &ExportOptions::default(), FailingWriter { budget: 7 })
+            .unwrap_err();
+        assert_eq!(err.to_string(), "disk full");
+    }
+
+    #[test]
+    fn max_bytes_truncates_at_line_boundary() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.feed(b"aaaa\r\nbbbb\r\ncccc\r\ndddd");
+
+        // Each line is 5 bytes ("aaaa\n"). A 12-byte budget fits 2 lines.
+        let opts = ExportOptions {
+            max_bytes: Some(12),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let summary = export_text_to(&vt, &opts, &mut out).unwrap();
+        assert!(summary.truncated);
+        assert_eq!(summary.lines_written, 2);
+        assert_eq!(summary.bytes_written, 10);
+        assert_eq!(out, b"aaaa\nbbbb\n");
+    }
+
+    #[test]
+    fn range_covers_scrollback_and_screen() {
+        let mut vt = VirtualTerminal::new(6, 2);
+        // Enough lines to push some into scrollback.
+        vt.feed(b"l1\r\nl2\r\nl3\r\nl4");
+        assert!(vt.scrollback_len() > 0);
+
+        let all = export_text(&vt, &ExportOptions::default());
+        assert!(all.contains("l1"));
+        assert!(all.contains("l4"));
+
+        let tail = export_text(
+            &vt,
+            &ExportOptions {
+                start_line: Some(vt.scrollback_len()),
+                ..Default::default()
+            },
+        );
+        assert!(!tail.contains("l1"));
+        assert!(tail.contains("l4"));
+    }
+
+    #[test]
+    fn tab_policies_expand_and_preserve() {
+        let mut vt = VirtualTerminal::new(24, 3);
+        vt.feed(b"ab\tcd");
+
+        let expanded = export_text(&vt, &ExportOptions::default());
+        assert_eq!(expanded.lines().next(), Some("ab      cd"));
+
+        let preserved = export_text(
+            &vt,
+            &ExportOptions {
+                tabs: TabPolicy::Preserve,
+                ..Default::default()
+            },
+        );
+        assert_eq!(preserved.lines().next(), Some("ab\tcd"));
+    }
+
+    #[test]
+    fn mixed_tabs_and_spaces_round_trip_with_preserve() {
+        let mut vt = VirtualTerminal::new(32, 4);
+        // Tab, then literal spaces, then another tab on the next line.
+        vt.feed(b"x\ty  z\r\n\tq");
+
+        let preserved = export_text(
+            &vt,
+            &ExportOptions {
+                tabs: TabPolicy::Preserve,
+                ..Default::default()
+            },
+        );
+        let lines: Vec<&str> = preserved.lines().collect();
+        // Typed spaces survive as spaces; tabs come back as tabs.
+        assert_eq!(lines[0], "x\ty  z");
+        assert_eq!(lines[1], "\tq");
+
+        // Feeding the exported text back reproduces the same grid text.
+        let mut rt = VirtualTerminal::new(32, 4);
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                rt.feed(b"\r\n");
+            }
+            rt.feed(line.as_bytes());
+        }
+        assert_eq!(rt.row_text(0), vt.row_text(0));
+        assert_eq!(rt.row_text(1), vt.row_text(1));
+    }
+
+    #[test]
+    fn preserve_leaves_space_runs_without_tab_origin_alone() {
+        let mut vt = VirtualTerminal::new(24, 2);
+        // Eight literal spaces, no HT.
+        vt.feed(b"ab        cd");
+        let preserved = export_text(
+            &vt,
+            &ExportOptions {
+                tabs: TabPolicy::Preserve,
+                ..Default::default()
+            },
+        );
+        assert!(!preserved.contains('\t'));
+    }
+
+    #[test]
+    fn out_of_range_lines_clamp() {
+        let vt = vt_with_lines(10, 2, "hi");
+        let opts = ExportOptions {
+            start_line: Some(100),
+            end_line: Some(200),
+            ..Default::default()
+        };
+        let summary = export_text_to(&vt, &opts, &mut Vec::new()).unwrap();
+        assert_eq!(summary.lines_written, 0);
+        assert_eq!(summary.bytes_written, 0);
+    }
+
+    // ── Time-stamped export ────────────────────────────────────────
+
+    /// A stepping lab clock: every pushed line gets the next tick.
+    fn lab_clock(start: u64, step: u64) -> crate::virtual_terminal::TimestampClock {
+        let counter = std::sync::atomic::AtomicU64::new(start);
+        Box::new(move || counter.fetch_add(step, std::sync::atomic::Ordering::Relaxed))
+    }
+
+    /// A VT whose scrollback holds lines "line 0".."line 5" stamped
+    /// 1000, 1010, … (the screen shows later rows).
+    fn stamped_vt() -> VirtualTerminal {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.enable_line_timestamps(lab_clock(1000, 10));
+        for i in 0..9 {
+            vt.feed(format!("line {i}\r\n").as_bytes());
+        }
+        vt
+    }
+
+    #[test]
+    fn timestamps_are_deterministic_under_lab_clock() {
+        let vt = stamped_vt();
+        // enable consumed tick 1000; pushes start at 1010.
+        assert_eq!(vt.line_timestamp(0), Some(1010));
+        assert_eq!(vt.line_timestamp(1), Some(1020));
+        assert!(vt.scrollback_len() >= 6);
+        let again = stamped_vt();
+        for i in 0..vt.scrollback_len() {
+            assert_eq!(vt.line_timestamp(i), again.line_timestamp(i));
+        }
+    }
+
+    #[test]
+    fn time_range_boundaries_map_to_line_indices() {
+        let vt = stamped_vt();
+        // Entirely before all content.
+        assert_eq!(vt.time_range_to_lines(0, 999), (0, 0));
+        // Entirely in the future.
+        assert_eq!(vt.time_range_to_lines(9_999_999, 10_000_000).1, 0);
+        // Inverted range.
+        assert_eq!(vt.time_range_to_lines(2000, 1000), (0, 0));
+        // Exact boundary lines: [1020, 1030] covers lines 1 and 2.
+        assert_eq!(vt.time_range_to_lines(1020, 1030), (1, 3));
+        // Range reaching "now" includes the screen rows.
+        let (_, end) = vt.time_range_to_lines(1020, u64::MAX);
+        assert_eq!(end, vt.scrollback_len() + 3);
+    }
+
+    #[test]
+    fn text_export_of_mid_session_slice() {
+        let vt = stamped_vt();
+        let opts = ExportOptions {
+            time_range: Some((1020, 1030)),
+            ..Default::default()
+        };
+        let text = export_text(&vt, &opts);
+        assert_eq!(text, "line 1\nline 2\n");
+    }
+
+    #[test]
+    fn eviction_drops_timestamps_with_their_lines() {
+        let mut vt = VirtualTerminal::new(20, 2);
+        vt.set_max_scrollback(3);
+        vt.enable_line_timestamps(lab_clock(100, 1));
+        for i in 0..10 {
+            vt.feed(format!("e{i}\r\n").as_bytes());
+        }
+        assert_eq!(vt.scrollback_len(), 3);
+        // Timestamps stay parallel: line 0 of scrollback is the oldest
+        // retained line, and indices past the end answer None.
+        let t0 = vt.line_timestamp(0).unwrap();
+        let t2 = vt.line_timestamp(2).unwrap();
+        assert!(t0 < t2);
+        assert_eq!(vt.line_timestamp(3), None);
+        // Retained text matches retained timestamps (no skew).
+        let sb_text = export_text(
+            &vt,
+            &ExportOptions {
+                end_line: Some(3),
+                ..Default::default()
+            },
+        );
+        assert!(sb_text.starts_with("e6\n"), "{sb_text:?}");
+    }
+
+    #[test]
+    fn json_export_carries_timestamps_when_present() {
+        let vt = stamped_vt();
+        let json = export_json(
+            &vt,
+            &ExportOptions {
+                time_range: Some((1010, 1020)),
+                ..Default::default()
+            },
+        );
+        let lines: Vec<&str> = json.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "{\"line\":0,\"ts\":1010,\"text\":\"line 0\"}");
+        assert_eq!(lines[1], "{\"line\":1,\"ts\":1020,\"text\":\"line 1\"}");
+
+        // Without timestamps the ts field is absent.
+        let mut plain = VirtualTerminal::new(20, 2);
+        plain.feed(b"hi\r\n");
+        let json = export_json(&plain, &ExportOptions::default());
+        assert!(json.contains("{\"line\":0,\"text\":"), "{json}");
+        assert!(!json.contains("\"ts\""));
+    }
+}
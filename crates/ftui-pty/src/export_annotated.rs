@@ -0,0 +1,582 @@
+#![forbid(unsafe_code)]
+
+//! Diff-friendly "annotated text" export.
+//!
+//! When a golden ANSI export changes in CI, the byte diff of escape
+//! sequences is unreadable. [`export_annotated`] emits plain text lines
+//! with compact, human-readable annotation lines describing style runs:
+//!
+//! ```text
+//! error: build failed
+//! ~ 0..6 fg=#ff5555 bold
+//! ~ 7..12 underline link=1
+//! ~ wrap
+//! ~~ link 1 https://ci.example.com/run/42
+//! ```
+//!
+//! Columns are display cells (a wide glyph spans two), runs are
+//! coalesced, default-attr runs are omitted, and attribute order is
+//! fixed — so a one-cell color change shows up as exactly one changed
+//! annotation line. `~ wrap` marks soft-wrapped rows; hyperlink URIs
+//! appear once in a footer table referenced by id, making the file
+//! self-contained. [`parse_annotated`] round-trips the format back into
+//! attribute assertions for tests.
+
+use std::fmt::Write as _;
+
+use crate::export::{TabPolicy, line_at};
+use crate::virtual_terminal::{CellStyle, VCell, VirtualTerminal};
+
+/// Options for [`export_annotated`].
+#[derive(Debug, Clone)]
+pub struct AnnotatedExportOptions {
+    /// Put each line's annotations before the text line instead of after.
+    pub annotations_first: bool,
+    /// Trim trailing spaces from text lines (annotation columns are
+    /// unaffected: they always measure the untrimmed grid).
+    pub trim_trailing: bool,
+}
+
+impl Default for AnnotatedExportOptions {
+    fn default() -> Self {
+        Self {
+            annotations_first: false,
+            trim_trailing: true,
+        }
+    }
+}
+
+/// One style run in an annotated line: display-cell columns
+/// `start..end` (end exclusive) carrying `style` and optionally a link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleRun {
+    pub start: usize,
+    pub end: usize,
+    pub style: CellStyle,
+    pub link: Option<u32>,
+    /// Overlay highlight label (only emitted by
+    /// [`export_annotated_with_overlays`]; exports stay clean of
+    /// highlight info unless that opt-in path is used).
+    pub overlay: Option<String>,
+}
+
+/// One parsed line of the annotated format.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AnnotatedLine {
+    /// The plain text.
+    pub text: String,
+    /// Non-default style runs, ascending by start column.
+    pub runs: Vec<StyleRun>,
+    /// Whether the row soft-wrapped onto the next.
+    pub soft_wrapped: bool,
+}
+
+/// A parsed annotated export.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AnnotatedDocument {
+    pub lines: Vec<AnnotatedLine>,
+    /// Link footer table: `(id, uri)` pairs, ascending by id.
+    pub links: Vec<(u32, String)>,
+}
+
+/// Parse failure: the offending 1-based line and a description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for AnnotatedParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "annotated parse error at line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl std::error::Error for AnnotatedParseError {}
+
+/// Export scrollback + screen in the annotated text format.
+#[must_use]
+pub fn export_annotated(vt: &VirtualTerminal, opts: &AnnotatedExportOptions) -> String {
+    export_annotated_inner(vt, opts, None)
+}
+
+/// [`export_annotated`] plus overlay highlight runs (opt-in).
+///
+/// Each line with overlays gains `~ start..end overlay=<kind>`
+/// annotations from the store. The default export never includes
+/// highlight info.
+#[must_use]
+pub fn export_annotated_with_overlays(
+    vt: &VirtualTerminal,
+    store: &crate::cell_overlay::CellOverlayStore,
+    opts: &AnnotatedExportOptions,
+) -> String {
+    export_annotated_inner(vt, opts, Some(store))
+}
+
+fn export_annotated_inner(
+    vt: &VirtualTerminal,
+    opts: &AnnotatedExportOptions,
+    overlays: Option<&crate::cell_overlay::CellOverlayStore>,
+) -> String {
+    let mut out = String::new();
+    let mut used_links: Vec<u32> = Vec::new();
+    let total = vt.scrollback_len() + usize::from(vt.height());
+
+    for idx in 0..total {
+        let scrollback = vt.scrollback_len();
+        let cells = if idx < scrollback {
+            vt.scrollback_cells(idx)
+        } else {
+            vt.row_cells((idx - scrollback) as u16)
+        };
+        let Some(cells) = cells else { continue };
+
+        // Text and soft-wrap detection shared with the text exporter.
+        let (raw, continues) = line_at(vt, idx, TabPolicy::Expand);
+        let text = if opts.trim_trailing {
+            raw.trim_end().to_string()
+        } else {
+            raw
+        };
+        let text = escape_text_line(&text);
+
+        let mut annotations = String::new();
+        for run in style_runs(cells) {
+            let _ = write!(annotations, "~ {}..{}", run.start, run.end);
+            append_attrs(&mut annotations, &run.style, run.link);
+            annotations.push('\n');
+            if let Some(id) = run.link
+                && !used_links.contains(&id)
+            {
+                used_links.push(id);
+            }
+        }
+        if let Some(store) = overlays {
+            let abs_line = vt.first_absolute_line() + idx as u64;
+            for (range, kind) in store.overlays_for_row(abs_line) {
+                let label = match kind {
+                    crate::cell_overlay::OverlayKind::Match => "match",
+                    crate::cell_overlay::OverlayKind::CurrentMatch => "current-match",
+                    crate::cell_overlay::OverlayKind::Selection => "selection",
+                };
+                let _ = writeln!(
+                    annotations,
+                    "~ {}..{} overlay={label}",
+                    range.start, range.end
+                );
+            }
+        }
+        if continues {
+            annotations.push_str("~ wrap\n");
+        }
+
+        if opts.annotations_first {
+            out.push_str(&annotations);
+            out.push_str(&text);
+            out.push('\n');
+        } else {
+            out.push_str(&text);
+            out.push('\n');
+            out.push_str(&annotations);
+        }
+    }
+
+    used_links.sort_unstable();
+    for id in used_links {
+        if let Some(uri) = vt.link_uri(id) {
+            let _ = writeln!(out, "~~ link {id} {uri}");
+        }
+    }
+    out
+}
+
+/// Escape a text line that would collide with the annotation syntax.
+///
+/// Lines starting with `~ `, `~~ link `, or `\` gain a leading `\`;
+/// [`parse_annotated`] strips it. Everything else is verbatim.
+fn escape_text_line(text: &str) -> String {
+    if text.starts_with("~ ") || text.starts_with("~~ link ") || text.starts_with('\\') {
+        format!("\\{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Coalesced non-default style runs over one row of cells.
+///
+/// Columns are display cells: a wide glyph's continuation cell extends
+/// its lead's run, so ranges match what the eye sees.
+fn style_runs(cells: &[VCell]) -> Vec<StyleRun> {
+    let mut runs: Vec<StyleRun> = Vec::new();
+    for (col, cell) in cells.iter().enumerate() {
+        let interesting = cell.style != CellStyle::default() || cell.link.is_some();
+        match runs.last_mut() {
+            Some(run) if run.end == col && run.style == cell.style && run.link == cell.link => {
+                run.end = col + 1;
+            }
+            _ if interesting => runs.push(StyleRun {
+                start: col,
+                end: col + 1,
+                style: cell.style.clone(),
+                link: cell.link,
+                overlay: None,
+            }),
+            _ => {}
+        }
+    }
+    runs
+}
+
+/// Append the fixed-order attribute list for a run (leading spaces).
+fn append_attrs(out: &mut String, style: &CellStyle, link: Option<u32>) {
+    if let Some(fg) = style.fg {
+        let _ = write!(out, " fg=#{:02x}{:02x}{:02x}", fg.r, fg.g, fg.b);
+    }
+    if let Some(bg) = style.bg {
+        let _ = write!(out, " bg=#{:02x}{:02x}{:02x}", bg.r, bg.g, bg.b);
+    }
+    for (set, name) in [
+        (style.bold, "bold"),
+        (style.dim, "dim"),
+        (style.italic, "italic"),
+        (style.underline, "underline"),
+        (style.blink, "blink"),
+        (style.reverse, "reverse"),
+        (style.strikethrough, "strikethrough"),
+        (style.hidden, "hidden"),
+    ] {
+        if set {
+            let _ = write!(out, " {name}");
+        }
+    }
+    if let Some(id) = link {
+        let _ = write!(out, " link={id}");
+    }
+}
+
+/// Parse an annotated export back into lines, runs, and links.
+///
+/// `annotations_first` must match the layout the document was exported
+/// with ([`AnnotatedExportOptions::annotations_first`]) — a bare text
+/// line between annotations is inherently ambiguous otherwise.
+pub fn parse_annotated(
+    input: &str,
+    annotations_first: bool,
+) -> Result<AnnotatedDocument, AnnotatedParseError> {
+    let mut doc = AnnotatedDocument::default();
+    // Annotations accumulated ahead of their text line (first-layout).
+    let mut pending = AnnotatedLine::default();
+
+    for (line_no, raw) in input.lines().enumerate() {
+        let line_no = line_no + 1;
+        if let Some(rest) = raw.strip_prefix("~~ link ") {
+            let (id, uri) = rest.split_once(' ').ok_or_else(|| AnnotatedParseError {
+                line: line_no,
+                message: "expected `~~ link <id> <uri>`".into(),
+            })?;
+            let id: u32 = id.parse().map_err(|_| AnnotatedParseError {
+                line: line_no,
+                message: format!("bad link id {id:?}"),
+            })?;
+            doc.links.push((id, uri.to_string()));
+        } else if let Some(rest) = raw.strip_prefix("~ ") {
+            let target = if annotations_first {
+                &mut pending
+            } else {
+                doc.lines.last_mut().ok_or_else(|| AnnotatedParseError {
+                    line: line_no,
+                    message: "annotation before any text line".into(),
+                })?
+            };
+            if rest == "wrap" {
+                target.soft_wrapped = true;
+            } else {
+                target.runs.push(parse_run(rest, line_no)?);
+            }
+        } else {
+            let mut line = std::mem::take(&mut pending);
+            // Strip the collision escape added by the exporter.
+            line.text = raw.strip_prefix('\\').unwrap_or(raw).to_string();
+            doc.lines.push(line);
+        }
+    }
+    Ok(doc)
+}
+
+fn parse_run(rest: &str, line_no: usize) -> Result<StyleRun, AnnotatedParseError> {
+    let err = |message: String| AnnotatedParseError {
+        line: line_no,
+        message,
+    };
+    let mut parts = rest.split_whitespace();
+    let range = parts.next().ok_or_else(|| err("empty annotation".into()))?;
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| err(format!("bad range {range:?}")))?;
+    let start: usize = start
+        .parse()
+        .map_err(|_| err(format!("bad range start {start:?}")))?;
+    let end: usize = end
+        .parse()
+        .map_err(|_| err(format!("bad range end {end:?}")))?;
+
+    let mut run = StyleRun {
+        start,
+        end,
+        style: CellStyle::default(),
+        link: None,
+        overlay: None,
+    };
+    for attr in parts {
+        match attr {
+            "bold" => run.style.bold = true,
+            "dim" => run.style.dim = true,
+            "italic" => run.style.italic = true,
+            "underline" => run.style.underline = true,
+            "blink" => run.style.blink = true,
+            "reverse" => run.style.reverse = true,
+            "strikethrough" => run.style.strikethrough = true,
+            "hidden" => run.style.hidden = true,
+            _ => {
+                let (key, value) = attr
+                    .split_once('=')
+                    .ok_or_else(|| err(format!("unknown attribute {attr:?}")))?;
+                match key {
+                    "fg" => run.style.fg = Some(parse_color(value).map_err(&err)?),
+                    "bg" => run.style.bg = Some(parse_color(value).map_err(&err)?),
+                    "overlay" => run.overlay = Some(value.to_string()),
+                    "link" => {
+                        run.link = Some(
+                            value
+                                .parse()
+                                .map_err(|_| err(format!("bad link id {value:?}")))?,
+                        );
+                    }
+                    _ => return Err(err(format!("unknown attribute {attr:?}"))),
+                }
+            }
+        }
+    }
+    Ok(run)
+}
+
+fn parse_color(value: &str) -> Result<crate::virtual_terminal::Color, String> {
+    let hex = value
+        .strip_prefix('#')
+        .ok_or_else(|| format!("bad color {value:?}"))?;
+    if hex.len() != 6 {
+        return Err(format!("bad color {value:?}"));
+    }
+    let parse = |s: &str| u8::from_str_radix(s, 16).map_err(|_| format!("bad color {value:?}"));
+    Ok(crate::virtual_terminal::Color::new(
+        parse(&hex[0..2])?,
+        parse(&hex[2..4])?,
+        parse(&hex[4..6])?,
+    ))
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled_fixture() -> VirtualTerminal {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.feed_str("\x1b[31;1merror:\x1b[0m build \x1b[4mfailed\x1b[0m\r\n");
+        vt.feed_str("\x1b]8;;https://ci.example\x1b\\logs\x1b]8;;\x1b\\ here\r\n");
+        vt.feed_str("plain line");
+        vt
+    }
+
+    #[test]
+    fn golden_annotated_output_for_styled_fixture() {
+        let vt = styled_fixture();
+        let out = export_annotated(&vt, &AnnotatedExportOptions::default());
+        let expected = "\
+error: build failed
+~ 0..6 fg=#aa0000 bold
+~ 13..19 underline
+logs here
+~ 0..4 link=0
+plain line
+
+~~ link 0 https://ci.example
+";
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn annotations_first_layout() {
+        let vt = styled_fixture();
+        let out = export_annotated(
+            &vt,
+            &AnnotatedExportOptions {
+                annotations_first: true,
+                ..Default::default()
+            },
+        );
+        let mut lines = out.lines();
+        assert!(lines.next().unwrap().starts_with("~ 0..6"));
+        assert!(lines.next().unwrap().starts_with("~ 13..19"));
+        assert_eq!(lines.next().unwrap(), "error: build failed");
+    }
+
+    #[test]
+    fn single_cell_change_touches_one_annotation_line() {
+        let vt_a = styled_fixture();
+        let mut vt_b = VirtualTerminal::new(20, 4);
+        // Same fixture, but "error:" in green instead of red.
+        vt_b.feed_str("\x1b[32;1merror:\x1b[0m build \x1b[4mfailed\x1b[0m\r\n");
+        vt_b.feed_str("\x1b]8;;https://ci.example\x1b\\logs\x1b]8;;\x1b\\ here\r\n");
+        vt_b.feed_str("plain line");
+
+        let a = export_annotated(&vt_a, &AnnotatedExportOptions::default());
+        let b = export_annotated(&vt_b, &AnnotatedExportOptions::default());
+        let differing: Vec<(&str, &str)> = a
+            .lines()
+            .zip(b.lines())
+            .filter(|(la, lb)| la != lb)
+            .collect();
+        assert_eq!(a.lines().count(), b.lines().count());
+        assert_eq!(
+            differing.len(),
+            1,
+            "exactly one line differs: {differing:?}"
+        );
+        assert!(differing[0].0.starts_with("~ "), "only an annotation moved");
+    }
+
+    #[test]
+    fn round_trip_parse_consistency() {
+        let vt = styled_fixture();
+        for annotations_first in [false, true] {
+            let opts = AnnotatedExportOptions {
+                annotations_first,
+                ..Default::default()
+            };
+            let out = export_annotated(&vt, &opts);
+            let doc = parse_annotated(&out, annotations_first).expect("parses");
+            assert_eq!(doc.lines.len(), 4, "three content rows + one blank row");
+            assert_eq!(doc.lines[0].text, "error: build failed");
+            assert_eq!(
+                doc.lines[0].runs,
+                vec![
+                    StyleRun {
+                        start: 0,
+                        end: 6,
+                        style: CellStyle {
+                            fg: Some(crate::virtual_terminal::Color::new(0xaa, 0, 0)),
+                            bold: true,
+                            ..Default::default()
+                        },
+                        link: None,
+                        overlay: None,
+                    },
+                    StyleRun {
+                        start: 13,
+                        end: 19,
+                        style: CellStyle {
+                            underline: true,
+                            ..Default::default()
+                        },
+                        link: None,
+                        overlay: None,
+                    },
+                ]
+            );
+            assert_eq!(doc.lines[1].runs[0].link, Some(0));
+            assert_eq!(
+                doc.links,
+                vec![(0, "https://ci.example".to_string())],
+                "footer is self-contained"
+            );
+        }
+    }
+
+    #[test]
+    fn wide_chars_count_as_two_columns() {
+        let mut vt = VirtualTerminal::new(20, 2);
+        vt.feed_str("\x1b[1m\u{4e16}\u{754c}\x1b[0mok");
+        let out = export_annotated(&vt, &AnnotatedExportOptions::default());
+        // 世界 occupies columns 0..4; "ok" follows unstyled.
+        assert!(
+            out.contains("~ 0..4 bold"),
+            "wide glyph run spans both cells: {out:?}"
+        );
+        let doc = parse_annotated(&out, false).expect("parses");
+        assert_eq!(doc.lines[0].runs[0].end, 4);
+    }
+
+    #[test]
+    fn soft_wrap_marker_emitted_and_parsed() {
+        let mut vt = VirtualTerminal::new(8, 3);
+        vt.feed_str("abcdefghijkl"); // wraps at 8 columns
+        let out = export_annotated(&vt, &AnnotatedExportOptions::default());
+        assert!(out.contains("~ wrap\n"), "wrap marker present: {out:?}");
+        let doc = parse_annotated(&out, false).expect("parses");
+        assert!(doc.lines[0].soft_wrapped);
+        assert!(!doc.lines[1].soft_wrapped);
+    }
+
+    #[test]
+    fn text_resembling_annotations_round_trips() {
+        let mut vt = VirtualTerminal::new(30, 2);
+        vt.feed_str("~ 1..2 bold");
+        let out = export_annotated(&vt, &AnnotatedExportOptions::default());
+        assert!(out.starts_with("\\~ 1..2 bold\n"), "escaped: {out:?}");
+        let doc = parse_annotated(&out, false).expect("parses");
+        assert_eq!(doc.lines[0].text, "~ 1..2 bold");
+        assert!(doc.lines[0].runs.is_empty());
+    }
+
+    #[test]
+    fn overlay_export_is_opt_in() {
+        use crate::cell_overlay::{CellOverlayStore, OverlayKind, OverlaySpan};
+
+        let mut vt = VirtualTerminal::new(20, 2);
+        vt.feed_str("find the needle");
+        let mut store = CellOverlayStore::new(&vt);
+        store.sync(&mut vt);
+        store.set_overlays([(
+            vt.end_absolute_line(),
+            OverlaySpan {
+                start: 9,
+                end: 15,
+                kind: OverlayKind::Match,
+            },
+        )]);
+
+        let plain = export_annotated(&vt, &AnnotatedExportOptions::default());
+        assert!(!plain.contains("overlay="), "default export stays clean");
+
+        let with = export_annotated_with_overlays(&vt, &store, &AnnotatedExportOptions::default());
+        assert!(with.contains("~ 9..15 overlay=match"), "{with:?}");
+        let doc = parse_annotated(&with, false).expect("parses");
+        let overlay_run = doc.lines[0]
+            .runs
+            .iter()
+            .find(|run| run.overlay.is_some())
+            .expect("overlay run parsed");
+        assert_eq!(overlay_run.overlay.as_deref(), Some("match"));
+        assert_eq!((overlay_run.start, overlay_run.end), (9, 15));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_annotations() {
+        assert!(parse_annotated("text\n~ nonsense\n", false).is_err());
+        assert!(parse_annotated("text\n~ 3..x bold\n", false).is_err());
+        assert!(parse_annotated("text\n~ 0..2 sparkle\n", false).is_err());
+        assert!(parse_annotated("text\n~ 0..2 fg=red\n", false).is_err());
+        assert!(
+            parse_annotated("~ 0..2 bold\ntext\n", false).is_err(),
+            "annotation before text"
+        );
+        assert!(parse_annotated("~~ link abc uri\n", false).is_err());
+    }
+}
@@ -0,0 +1,870 @@
+#![forbid(unsafe_code)]
+
+//! HTML export of virtual terminal content.
+//!
+//! [`export_html`] renders the grid (plus scrollback) as a `<pre>`
+//! fragment with one `<span>` per style run. With
+//! [`HtmlExportOptions::document`] set, the fragment is wrapped in a
+//! complete, share-ready HTML5 document: generated stylesheet with
+//! deduplicated color classes, an optional `prefers-color-scheme` media
+//! query swapping the default foreground/background, optional line
+//! numbers in a non-selectable gutter, and soft-wrapping CSS versus
+//! horizontal scrolling.
+//!
+//! Class names derive from the color value (`fg-aa0000`, or the named
+//! ANSI classes when the value matches the standard 16-color palette), so
+//! identical input always produces identical markup and exports diff
+//! cleanly.
+
+use std::fmt::Write as _;
+
+use crate::virtual_terminal::{CellStyle, Color, VirtualTerminal};
+
+/// Options for HTML export.
+#[derive(Debug, Clone, Default)]
+pub struct HtmlExportOptions {
+    /// First combined line to export (0 = oldest scrollback). `None` =
+    /// the first visible screen row.
+    pub start_line: Option<usize>,
+    /// One past the last combined line. `None` = last screen row.
+    pub end_line: Option<usize>,
+    /// Wrap the fragment in a complete HTML5 document.
+    pub document: Option<HtmlDocumentOptions>,
+}
+
+/// Full-document wrapping options.
+#[derive(Debug, Clone)]
+pub struct HtmlDocumentOptions {
+    /// Document title (escaped).
+    pub title: String,
+    /// Emit named classes for the standard 16 ANSI colors.
+    pub include_palette_css: bool,
+    /// Swap default fg/bg under `prefers-color-scheme`.
+    pub dark_light_media_query: bool,
+    /// Line numbers in a non-selectable gutter.
+    pub line_numbers: bool,
+    /// Soft-wrap long lines instead of horizontal scrolling.
+    pub wrap: bool,
+}
+
+impl Default for HtmlDocumentOptions {
+    fn default() -> Self {
+        Self {
+            title: "Terminal export".to_string(),
+            include_palette_css: true,
+            dark_light_media_query: true,
+            line_numbers: false,
+            wrap: false,
+        }
+    }
+}
+
+/// The standard 16-color palette with stable class names.
+const NAMED_PALETTE: &[(&str, Color)] = &[
+    ("ansi-black", Color { r: 0, g: 0, b: 0 }),
+    ("ansi-red", Color { r: 170, g: 0, b: 0 }),
+    ("ansi-green", Color { r: 0, g: 170, b: 0 }),
+    (
+        "ansi-yellow",
+        Color {
+            r: 170,
+            g: 170,
+            b: 0,
+        },
+    ),
+    ("ansi-blue", Color { r: 0, g: 0, b: 170 }),
+    (
+        "ansi-magenta",
+        Color {
+            r: 170,
+            g: 0,
+            b: 170,
+        },
+    ),
+    (
+        "ansi-cyan",
+        Color {
+            r: 0,
+            g: 170,
+            b: 170,
+        },
+    ),
+    (
+        "ansi-white",
+        Color {
+            r: 170,
+            g: 170,
+            b: 170,
+        },
+    ),
+    (
+        "ansi-bright-black",
+        Color {
+            r: 85,
+            g: 85,
+            b: 85,
+        },
+    ),
+    (
+        "ansi-bright-red",
+        Color {
+            r: 255,
+            g: 85,
+            b: 85,
+        },
+    ),
+    (
+        "ansi-bright-green",
+        Color {
+            r: 85,
+            g: 255,
+            b: 85,
+        },
+    ),
+    (
+        "ansi-bright-yellow",
+        Color {
+            r: 255,
+            g: 255,
+            b: 85,
+        },
+    ),
+    (
+        "ansi-bright-blue",
+        Color {
+            r: 85,
+            g: 85,
+            b: 255,
+        },
+    ),
+    (
+        "ansi-bright-magenta",
+        Color {
+            r: 255,
+            g: 85,
+            b: 255,
+        },
+    ),
+    (
+        "ansi-bright-cyan",
+        Color {
+            r: 85,
+            g: 255,
+            b: 255,
+        },
+    ),
+    (
+        "ansi-bright-white",
+        Color {
+            r: 255,
+            g: 255,
+            b: 255,
+        },
+    ),
+];
+
+/// Export terminal content as HTML.
+#[must_use]
+pub fn export_html(vt: &VirtualTerminal, opts: &HtmlExportOptions) -> String {
+    let total = vt.scrollback_len() + usize::from(vt.height());
+    let start = opts.start_line.unwrap_or(vt.scrollback_len()).min(total);
+    let end = opts.end_line.unwrap_or(total).min(total);
+
+    // Collect used non-palette colors (deduplicated, deterministic order
+    // by value) while building the body.
+    let mut used_colors: Vec<Color> = Vec::new();
+    let line_numbers = opts.document.as_ref().is_some_and(|doc| doc.line_numbers);
+
+    let mut body = String::new();
+    body.push_str("<pre class=\"ftui\">");
+    for (number, line) in (start..end).enumerate() {
+        if line > start {
+            body.push('\n');
+        }
+        if line_numbers {
+            let _ = write!(body, "<span class=\"ln\">{:>4} </span>", number + 1);
+        }
+        render_line(vt, line, &mut body, &mut used_colors);
+    }
+    body.push_str("</pre>");
+
+    let Some(doc) = &opts.document else {
+        return body;
+    };
+
+    used_colors.sort_by_key(|c| (c.r, c.g, c.b));
+    used_colors.dedup();
+
+    let mut css = String::new();
+    css.push_str(
+        ":root { --fg: #e6e6e6; --bg: #101418; }\n\
+         body { margin: 0; background: var(--bg); }\n\
+         pre.ftui { color: var(--fg); background: var(--bg); \
+         font-family: monospace; padding: 1em; ",
+    );
+    if doc.wrap {
+        css.push_str("white-space: pre-wrap; word-break: break-all; }\n");
+    } else {
+        css.push_str("overflow-x: auto; }\n");
+    }
+    if doc.line_numbers {
+        css.push_str(".ln { user-select: none; -webkit-user-select: none; opacity: 0.5; }\n");
+    }
+    css.push_str(".bold { font-weight: bold; }\n.italic { font-style: italic; }\n");
+    css.push_str(".underline { text-decoration: underline; }\n");
+    css.push_str(".strike { text-decoration: line-through; }\n.dim { opacity: 0.6; }\n");
+    if doc.dark_light_media_query {
+        css.push_str(
+            "@media (prefers-color-scheme: light) { \
+             :root { --fg: #101418; --bg: #fafafa; } }\n",
+        );
+    }
+    if doc.include_palette_css {
+        for (name, color) in NAMED_PALETTE {
+            let _ = writeln!(
+                css,
+                ".{name} {{ color: #{:02x}{:02x}{:02x}; }} .bg-{name} {{ background: #{0:02x}{1:02x}{2:02x}; }}",
+                color.r, color.g, color.b
+            );
+        }
+    }
+    for color in &used_colors {
+        let _ = writeln!(
+            css,
+            ".fg-{0:02x}{1:02x}{2:02x} {{ color: #{0:02x}{1:02x}{2:02x}; }} \
+             .bg-{0:02x}{1:02x}{2:02x} {{ background: #{0:02x}{1:02x}{2:02x}; }}",
+            color.r, color.g, color.b
+        );
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>\n{}</style>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        escape_html(&doc.title),
+        css,
+        body,
+    )
+}
+
+// ─── Incremental (damage-aware) export ───────────────────────────────────────
+
+/// Consumer-side cursor for [`export_html_delta`]: tracks the damage
+/// acknowledgment point, the link ids already sent, and the geometry
+/// (resize forces a full export).
+#[derive(Debug, Default)]
+pub struct ExportCursor {
+    damage: crate::virtual_terminal::DamageCursor,
+    known_links: std::collections::BTreeSet<u32>,
+    width: u16,
+    height: u16,
+    primed: bool,
+}
+
+impl ExportCursor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Options for incremental export.
+#[derive(Debug, Clone)]
+pub struct HtmlDeltaOptions {
+    /// Fall back to a full export when more than this fraction of the
+    /// visible rows changed (0.0..=1.0).
+    pub full_threshold: f64,
+}
+
+impl Default for HtmlDeltaOptions {
+    fn default() -> Self {
+        Self {
+            full_threshold: 0.6,
+        }
+    }
+}
+
+/// One incremental export step.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HtmlDelta {
+    /// Visible rows whose HTML changed: `(screen_row, row_html)`. Row
+    /// HTML comes from the same serializer as the full export, so
+    /// applying deltas converges to the full-export result.
+    pub changed_rows: Vec<(u16, String)>,
+    /// Screen rows that scrolled out this step (client drops these DOM
+    /// rows before shifting).
+    pub removed_rows: Vec<u16>,
+    /// Net scroll since the last export (positive = content moved up);
+    /// the client shifts existing DOM rows by this amount first.
+    pub scrolled: i32,
+    /// Full visible-grid export (same serializer) when damage exceeded
+    /// the threshold, the grid resized, or this cursor is new. When
+    /// set, ignore the incremental fields and reset from it.
+    pub full: Option<String>,
+    /// Hyperlink dictionary delta: URIs for link ids referenced by the
+    /// emitted rows that this cursor has not sent before.
+    pub new_links: Vec<(u32, String)>,
+}
+
+/// Render one visible screen row with the full-export serializer.
+fn screen_row_html(vt: &VirtualTerminal, row: u16) -> String {
+    let mut html = String::new();
+    let mut used = Vec::new();
+    render_line(
+        vt,
+        vt.scrollback_len() + usize::from(row),
+        &mut html,
+        &mut used,
+    );
+    html
+}
+
+/// All visible rows through the same serializer (convergence target).
+#[must_use]
+pub fn visible_rows_html(vt: &VirtualTerminal) -> Vec<String> {
+    (0..vt.height())
+        .map(|row| screen_row_html(vt, row))
+        .collect()
+}
+
+/// Incremental HTML export: only rows damaged since this cursor's last
+/// call, plus scroll amounts, falling back to a full export (see
+/// [`HtmlDelta`]). Hyperlink URIs referenced by emitted rows arrive as
+/// a dictionary delta the first time each id is seen.
+pub fn export_html_delta(
+    vt: &mut VirtualTerminal,
+    cursor: &mut ExportCursor,
+    opts: &HtmlDeltaOptions,
+) -> HtmlDelta {
+    let report = vt.take_damage(&mut cursor.damage);
+    let (width, height) = (vt.width(), vt.height());
+    let resized = cursor.width != width || cursor.height != height;
+
+    let threshold_rows = ((f64::from(height) * opts.full_threshold).ceil() as usize).max(1);
+    let full_needed =
+        !cursor.primed || resized || report.full_invalidate || report.rows.len() > threshold_rows;
+
+    if full_needed {
+        cursor.primed = true;
+        cursor.width = width;
+        cursor.height = height;
+        let rows = visible_rows_html(vt);
+        let mut delta = HtmlDelta {
+            full: Some(rows.join(
+                "
+",
+            )),
+            ..Default::default()
+        };
+        collect_link_delta(vt, 0..height, cursor, &mut delta);
+        return delta;
+    }
+
+    let mut delta = HtmlDelta {
+        scrolled: report.scrolled_lines,
+        ..Default::default()
+    };
+    if report.scrolled_lines > 0 {
+        let gone = (report.scrolled_lines as u16).min(height);
+        delta.removed_rows = (0..gone).collect();
+    } else if report.scrolled_lines < 0 {
+        let gone = ((-report.scrolled_lines) as u16).min(height);
+        delta.removed_rows = (height - gone..height).collect();
+    }
+
+    let mut rows: Vec<u16> = report.rows.iter().map(|(row, _)| *row).collect();
+    rows.sort_unstable();
+    rows.dedup();
+    for row in rows {
+        if row >= height {
+            continue;
+        }
+        delta.changed_rows.push((row, screen_row_html(vt, row)));
+        collect_link_delta(vt, row..row + 1, cursor, &mut delta);
+    }
+    delta
+}
+
+/// JSON sibling of [`export_html_delta`]: the same delta, serialized.
+pub fn export_json_delta(
+    vt: &mut VirtualTerminal,
+    cursor: &mut ExportCursor,
+    opts: &HtmlDeltaOptions,
+) -> String {
+    let delta = export_html_delta(vt, cursor, opts);
+    let mut out = String::from("{");
+    let _ = write!(out, "\"scrolled\":{},", delta.scrolled);
+    out.push_str("\"removed_rows\":[");
+    for (idx, row) in delta.removed_rows.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{row}");
+    }
+    out.push_str("],\"changed_rows\":[");
+    for (idx, (row, html)) in delta.changed_rows.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "[{row},\"{}\"]", escape_json(html));
+    }
+    out.push_str("],\"new_links\":[");
+    for (idx, (id, uri)) in delta.new_links.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "[{id},\"{}\"]", escape_json(uri));
+    }
+    out.push(']');
+    match &delta.full {
+        Some(full) => {
+            let _ = write!(out, ",\"full\":\"{}\"", escape_json(full));
+        }
+        None => out.push_str(",\"full\":null"),
+    }
+    out.push('}');
+    out
+}
+
+/// Record link ids referenced in the given rows that this cursor has
+/// not yet sent, with their URIs.
+fn collect_link_delta(
+    vt: &VirtualTerminal,
+    rows: std::ops::Range<u16>,
+    cursor: &mut ExportCursor,
+    delta: &mut HtmlDelta,
+) {
+    for row in rows {
+        let Some(cells) = vt.row_cells(row) else {
+            continue;
+        };
+        for cell in cells {
+            if let Some(id) = cell.link
+                && cursor.known_links.insert(id)
+                && let Some(uri) = vt.link_uri(id)
+            {
+                delta.new_links.push((id, uri.to_string()));
+            }
+        }
+    }
+}
+
+/// Minimal JSON string escaping.
+fn escape_json(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render one combined line as style-run spans.
+fn render_line(vt: &VirtualTerminal, line: usize, out: &mut String, used: &mut Vec<Color>) {
+    let scrollback = vt.scrollback_len();
+    let cells: Vec<(char, CellStyle)> = if line < scrollback {
+        match vt.scrollback_cells(line) {
+            Some(cells) => cells.iter().map(|c| (c.ch, c.style.clone())).collect(),
+            None => Vec::new(),
+        }
+    } else {
+        match vt.row_cells((line - scrollback) as u16) {
+            Some(cells) => cells.iter().map(|c| (c.ch, c.style.clone())).collect(),
+            None => Vec::new(),
+        }
+    };
+
+    // Trim trailing default-style blanks.
+    let end = cells
+        .iter()
+        .rposition(|(ch, style)| *ch != ' ' || *style != CellStyle::default())
+        .map_or(0, |i| i + 1);
+
+    let mut idx = 0usize;
+    while idx < end {
+        let style = cells[idx].1.clone();
+        let run_end = cells[idx..end]
+            .iter()
+            .position(|(_, s)| *s != style)
+            .map_or(end, |len| idx + len);
+        let text: String = cells[idx..run_end]
+            .iter()
+            .map(|(ch, _)| if *ch == '\0' { ' ' } else { *ch })
+            .collect();
+        let classes = style_classes(&style, used);
+        if classes.is_empty() {
+            out.push_str(&escape_html(&text));
+        } else {
+            let _ = write!(out, "<span class=\"{classes}\">");
+            out.push_str(&escape_html(&text));
+            out.push_str("</span>");
+        }
+        idx = run_end;
+    }
+}
+
+/// Deterministic class list for a cell style.
+fn style_classes(style: &CellStyle, used: &mut Vec<Color>) -> String {
+    let mut classes: Vec<String> = Vec::new();
+    if let Some(fg) = style.fg {
+        classes.push(color_class(fg, false, used));
+    }
+    if let Some(bg) = style.bg {
+        classes.push(color_class(bg, true, used));
+    }
+    if style.bold {
+        classes.push("bold".into());
+    }
+    if style.dim {
+        classes.push("dim".into());
+    }
+    if style.italic {
+        classes.push("italic".into());
+    }
+    if style.underline {
+        classes.push("underline".into());
+    }
+    if style.strikethrough {
+        classes.push("strike".into());
+    }
+    classes.join(" ")
+}
+
+/// Class name for a color: named palette class when the value matches the
+/// standard 16, value class (`fg-rrggbb`) otherwise. Value classes are
+/// recorded for stylesheet generation.
+fn color_class(color: Color, background: bool, used: &mut Vec<Color>) -> String {
+    if let Some((name, _)) = NAMED_PALETTE.iter().find(|(_, c)| *c == color) {
+        return if background {
+            format!("bg-{name}")
+        } else {
+            (*name).to_string()
+        };
+    }
+    used.push(color);
+    if background {
+        format!("bg-{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+    } else {
+        format!("fg-{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+    }
+}
+
+/// Escape text for HTML content and attribute positions.
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled_vt() -> VirtualTerminal {
+        let mut vt = VirtualTerminal::new(20, 3);
+        // Red "err", default " ok", truecolor bg word.
+        vt.feed(b"\x1b[31merr\x1b[0m ok \x1b[48;2;18;52;86mdeep\x1b[0m");
+        vt
+    }
+
+    #[test]
+    fn golden_document_for_small_styled_grid() {
+        let vt = styled_vt();
+        let html = export_html(
+            &vt,
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions {
+                    title: "demo".into(),
+                    line_numbers: true,
+                    ..HtmlDocumentOptions::default()
+                }),
+                ..HtmlExportOptions::default()
+            },
+        );
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>demo</title>"));
+        assert!(html.contains("<span class=\"ansi-red\">err</span>"));
+        assert!(html.contains("<span class=\"bg-123456\">deep</span>"));
+        assert!(html.contains(".bg-123456 { background: #123456; }"));
+        assert!(html.contains("prefers-color-scheme: light"));
+        assert!(html.contains("user-select: none"));
+        assert!(html.contains("<span class=\"ln\">   1 </span>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn repeated_colors_dedupe_into_one_class_rule() {
+        let mut vt = VirtualTerminal::new(40, 2);
+        vt.feed(b"\x1b[38;2;1;2;3maaa\x1b[0m mid \x1b[38;2;1;2;3mbbb\x1b[0m");
+        let html = export_html(
+            &vt,
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions::default()),
+                ..HtmlExportOptions::default()
+            },
+        );
+        // Two spans share the class; the stylesheet defines it once.
+        assert_eq!(html.matches("class=\"fg-010203\"").count(), 2);
+        assert_eq!(html.matches(".fg-010203 { color: #010203; }").count(), 1);
+    }
+
+    #[test]
+    fn class_names_deterministic_across_runs() {
+        let a = export_html(
+            &styled_vt(),
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions::default()),
+                ..HtmlExportOptions::default()
+            },
+        );
+        let b = export_html(
+            &styled_vt(),
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions::default()),
+                ..HtmlExportOptions::default()
+            },
+        );
+        assert_eq!(a, b, "identical input must produce identical markup");
+    }
+
+    #[test]
+    fn hostile_title_is_escaped() {
+        let vt = VirtualTerminal::new(10, 2);
+        let html = export_html(
+            &vt,
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions {
+                    title: "</title><script>alert('x')</script>".into(),
+                    ..HtmlDocumentOptions::default()
+                }),
+                ..HtmlExportOptions::default()
+            },
+        );
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;/title&gt;&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+    }
+
+    #[test]
+    fn fragment_without_document_is_bare_pre() {
+        let vt = styled_vt();
+        let html = export_html(&vt, &HtmlExportOptions::default());
+        assert!(html.starts_with("<pre class=\"ftui\">"));
+        assert!(html.ends_with("</pre>"));
+        assert!(!html.contains("<!DOCTYPE"));
+    }
+
+    #[test]
+    fn content_is_escaped() {
+        let mut vt = VirtualTerminal::new(30, 2);
+        vt.feed(b"<b>&\"'</b>");
+        let html = export_html(&vt, &HtmlExportOptions::default());
+        assert!(html.contains("&lt;b&gt;&amp;&quot;&#39;&lt;/b&gt;"));
+    }
+
+    #[test]
+    fn wrap_option_switches_css() {
+        let vt = VirtualTerminal::new(10, 2);
+        let wrap = export_html(
+            &vt,
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions {
+                    wrap: true,
+                    ..HtmlDocumentOptions::default()
+                }),
+                ..HtmlExportOptions::default()
+            },
+        );
+        assert!(wrap.contains("pre-wrap"));
+        let scroll = export_html(
+            &vt,
+            &HtmlExportOptions {
+                document: Some(HtmlDocumentOptions::default()),
+                ..HtmlExportOptions::default()
+            },
+        );
+        assert!(scroll.contains("overflow-x: auto"));
+    }
+}
+
+#[cfg(test)]
+mod delta_tests {
+    use super::*;
+
+    /// Client-side model: visible DOM rows, kept in sync via deltas.
+    struct Client {
+        rows: Vec<String>,
+        links: std::collections::BTreeMap<u32, String>,
+    }
+
+    impl Client {
+        fn new() -> Self {
+            Self {
+                rows: Vec::new(),
+                links: std::collections::BTreeMap::new(),
+            }
+        }
+
+        fn apply(&mut self, delta: &HtmlDelta, height: u16) {
+            for (id, uri) in &delta.new_links {
+                self.links.insert(*id, uri.clone());
+            }
+            if let Some(full) = &delta.full {
+                self.rows = full.split('\n').map(str::to_string).collect();
+                self.rows.resize(usize::from(height), String::new());
+                return;
+            }
+            // Shift for scroll, then apply row replacements.
+            if delta.scrolled > 0 {
+                let n = (delta.scrolled as usize).min(self.rows.len());
+                self.rows.drain(..n);
+                self.rows.extend(std::iter::repeat_n(String::new(), n));
+            } else if delta.scrolled < 0 {
+                let n = ((-delta.scrolled) as usize).min(self.rows.len());
+                self.rows.truncate(self.rows.len() - n);
+                for _ in 0..n {
+                    self.rows.insert(0, String::new());
+                }
+            }
+            for (row, html) in &delta.changed_rows {
+                if let Some(slot) = self.rows.get_mut(usize::from(*row)) {
+                    *slot = html.clone();
+                }
+            }
+        }
+    }
+
+    fn converged(client: &Client, vt: &VirtualTerminal) -> bool {
+        client.rows == visible_rows_html(vt)
+    }
+
+    #[test]
+    fn first_export_is_full_and_converges() {
+        let mut vt = VirtualTerminal::new(30, 4);
+        vt.feed_str("hello \x1b[31mred\x1b[0m world");
+        let mut cursor = ExportCursor::new();
+        let mut client = Client::new();
+        let delta = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        assert!(delta.full.is_some(), "new cursor gets a full export");
+        client.apply(&delta, vt.height());
+        assert!(converged(&client, &vt));
+    }
+
+    #[test]
+    fn incremental_step_touches_only_changed_rows() {
+        let mut vt = VirtualTerminal::new(30, 6);
+        vt.feed_str("one\r\ntwo\r\nthree");
+        let mut cursor = ExportCursor::new();
+        let mut client = Client::new();
+        client.apply(
+            &export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default()),
+            vt.height(),
+        );
+
+        vt.feed_str("\x1b[2;1HTWO!");
+        let delta = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        assert!(delta.full.is_none(), "one-row change stays incremental");
+        assert_eq!(delta.changed_rows.len(), 1, "{delta:?}");
+        assert_eq!(delta.changed_rows[0].0, 1);
+        client.apply(&delta, vt.height());
+        assert!(converged(&client, &vt));
+    }
+
+    #[test]
+    fn randomized_mutations_converge_every_step() {
+        let mut state = 0xfeed_beef_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut vt = VirtualTerminal::new(24, 6);
+        let mut cursor = ExportCursor::new();
+        let mut client = Client::new();
+        client.apply(
+            &export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default()),
+            vt.height(),
+        );
+
+        for step in 0..120 {
+            match next() % 5 {
+                0 => {
+                    let row = next() % 6 + 1;
+                    let col = next() % 20 + 1;
+                    vt.feed_str(&format!("\x1b[{row};{col}Hx{}", next() % 10));
+                }
+                1 => vt.feed_str("\r\nscrolling line\r\n"),
+                2 => vt.feed_str("\x1b[31mred run \x1b[42mon green\x1b[0m"),
+                3 => vt.feed_str("\x1b[2J\x1b[1;1Hcleared"),
+                _ => vt.feed_str("plain text "),
+            }
+            let delta = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+            client.apply(&delta, vt.height());
+            assert!(
+                converged(&client, &vt),
+                "step {step}: client diverged\n delta: {delta:?}\n client: {:?}\n truth: {:?}",
+                client.rows,
+                visible_rows_html(&vt)
+            );
+        }
+    }
+
+    #[test]
+    fn resize_forces_full_export() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.feed_str("before resize");
+        let mut cursor = ExportCursor::new();
+        let _ = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        vt.resize(30, 5);
+        let delta = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        assert!(delta.full.is_some(), "resize resets the client");
+    }
+
+    #[test]
+    fn link_dictionary_sent_once_per_cursor() {
+        let mut vt = VirtualTerminal::new(40, 3);
+        vt.feed_str("\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\");
+        let mut cursor = ExportCursor::new();
+        let delta = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        assert_eq!(
+            delta.new_links,
+            vec![(0, "https://example.com".to_string())]
+        );
+        // Touch the linked row again: the id is already known.
+        vt.feed_str("\x1b[1;1HD");
+        let delta = export_html_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        assert!(delta.new_links.is_empty(), "{delta:?}");
+    }
+
+    #[test]
+    fn json_delta_is_escaped_and_structured() {
+        let mut vt = VirtualTerminal::new(20, 2);
+        vt.feed_str("say \"hi\"");
+        let mut cursor = ExportCursor::new();
+        let json = export_json_delta(&mut vt, &mut cursor, &HtmlDeltaOptions::default());
+        assert!(json.starts_with("{\"scrolled\":0,"), "{json}");
+        // The HTML serializer entity-escapes quotes; the JSON layer
+        // escapes the row separator newlines.
+        assert!(json.contains("&quot;hi&quot;"), "{json}");
+        assert!(json.contains("\\n"), "newline escaped for JSON: {json}");
+        assert!(json.contains("\"full\":\""), "first export is full: {json}");
+    }
+}
@@ -0,0 +1,491 @@
+#![forbid(unsafe_code)]
+
+//! Structural diffs between two terminal grid snapshots.
+//!
+//! Terminal-recording tooling (asciinema-style differs, regression
+//! fixtures) needs to compare two grid states structurally rather than
+//! via exported strings: a per-cell delta compresses a typical frame to
+//! a handful of row spans, and a pure vertical scroll compresses to a
+//! single shift. [`diff`] produces a [`GridDiff`] that [`apply`] replays
+//! exactly — applying the diff to a clone of `a` always reproduces `b`,
+//! including wide-char continuations, combining marks, image placeholders
+//! and hyperlink ids.
+//!
+//! Diffs are deterministic: the same pair of snapshots always yields the
+//! same `GridDiff`, so recorded streams are byte-stable across runs.
+
+use crate::virtual_terminal::{VCell, VirtualTerminal};
+
+/// A standalone copy of a terminal screen grid.
+///
+/// Row-major, fully populated: `cells.len() == width * height`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridSnapshot {
+    width: u16,
+    height: u16,
+    cells: Vec<VCell>,
+}
+
+impl GridSnapshot {
+    /// Create a blank snapshot of the given size.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![VCell::default(); usize::from(width) * usize::from(height)],
+        }
+    }
+
+    /// Grid width in columns.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Grid height in rows.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// The cells of row `y`, or `None` when out of range.
+    pub fn row(&self, y: u16) -> Option<&[VCell]> {
+        if y >= self.height {
+            return None;
+        }
+        let start = usize::from(y) * usize::from(self.width);
+        Some(&self.cells[start..start + usize::from(self.width)])
+    }
+
+    /// The cell at `(x, y)`, or `None` when out of range.
+    pub fn cell(&self, x: u16, y: u16) -> Option<&VCell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(&self.cells[usize::from(y) * usize::from(self.width) + usize::from(x)])
+    }
+
+    /// Overwrite the cell at `(x, y)`; out-of-range writes are ignored.
+    pub fn set(&mut self, x: u16, y: u16, cell: VCell) {
+        if x < self.width && y < self.height {
+            self.cells[usize::from(y) * usize::from(self.width) + usize::from(x)] = cell;
+        }
+    }
+}
+
+/// Capture the visible screen of a terminal as a [`GridSnapshot`].
+pub fn snapshot(vt: &VirtualTerminal) -> GridSnapshot {
+    let (width, height) = (vt.width(), vt.height());
+    let mut cells = Vec::with_capacity(usize::from(width) * usize::from(height));
+    for y in 0..height {
+        cells.extend_from_slice(vt.row_cells(y).expect("row in range"));
+    }
+    GridSnapshot {
+        width,
+        height,
+        cells,
+    }
+}
+
+/// Changed cells of one row, grouped into runs of consecutive columns.
+///
+/// Each span is `(col_start, cells)`: the new cell contents starting at
+/// that column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowDelta {
+    /// Row index in the new grid.
+    pub row: u16,
+    /// Consecutive runs of changed cells, ascending by column.
+    pub spans: Vec<(u16, Vec<VCell>)>,
+}
+
+/// Aggregate statistics over a diff's changed cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DiffSummary {
+    /// Total number of cells that differ.
+    pub changed_cells: usize,
+    /// Number of rows containing at least one changed cell.
+    pub changed_rows: usize,
+    /// Cells whose character is unchanged but whose style, link, image
+    /// or tab-origin flag differs.
+    pub attr_only_changes: usize,
+    /// Cells whose character itself changed.
+    pub content_changes: usize,
+}
+
+/// The structural difference between two grid snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridDiff {
+    /// The grids are identical.
+    Unchanged,
+    /// Per-cell changes grouped into row spans.
+    Cells {
+        /// Rows with changes, ascending by row index.
+        rows: Vec<RowDelta>,
+        /// Statistics over the changed cells.
+        summary: DiffSummary,
+    },
+    /// `b` equals `a` shifted vertically by `n` rows.
+    ///
+    /// Positive `n` scrolls up: row `r` of `b` equals row `r + n` of `a`
+    /// for every overlapping row. Negative `n` scrolls down. Rows the
+    /// shift reveals are blanked and then patched by `residual`, which
+    /// also carries the statistics for those cells.
+    Scrolled {
+        /// Shift amount; positive = content moved up.
+        n: i16,
+        /// Deltas for the revealed rows (relative to a blanked row).
+        residual: Vec<RowDelta>,
+        /// Statistics over the residual cells.
+        summary: DiffSummary,
+    },
+    /// The grids have different dimensions; carries a full copy of `b`.
+    Resized {
+        /// The complete new grid.
+        snapshot: GridSnapshot,
+    },
+}
+
+/// Compare two snapshots and produce the smallest structural diff.
+///
+/// Resolution order: equal sizes are required (otherwise [`GridDiff::Resized`]);
+/// identical grids yield [`GridDiff::Unchanged`]; a pure vertical shift
+/// (smallest magnitude, upward preferred on ties) yields
+/// [`GridDiff::Scrolled`]; anything else yields [`GridDiff::Cells`].
+pub fn diff(a: &GridSnapshot, b: &GridSnapshot) -> GridDiff {
+    if a.width != b.width || a.height != b.height {
+        return GridDiff::Resized {
+            snapshot: b.clone(),
+        };
+    }
+    if a.cells == b.cells {
+        return GridDiff::Unchanged;
+    }
+
+    if let Some((n, residual, summary)) = detect_scroll(a, b) {
+        return GridDiff::Scrolled {
+            n,
+            residual,
+            summary,
+        };
+    }
+
+    let (rows, summary) = row_deltas(a, b);
+    GridDiff::Cells { rows, summary }
+}
+
+/// Replay a diff produced by [`diff`] onto `grid`.
+///
+/// Applying `diff(&a, &b)` to a clone of `a` reproduces `b` exactly.
+pub fn apply(diff: &GridDiff, grid: &mut GridSnapshot) {
+    match diff {
+        GridDiff::Unchanged => {}
+        GridDiff::Cells { rows, .. } => {
+            apply_deltas(grid, rows);
+        }
+        GridDiff::Scrolled { n, residual, .. } => {
+            shift_rows(grid, *n);
+            apply_deltas(grid, residual);
+        }
+        GridDiff::Resized { snapshot } => {
+            *grid = snapshot.clone();
+        }
+    }
+}
+
+/// Shift grid content vertically by `n` rows, blanking revealed rows.
+fn shift_rows(grid: &mut GridSnapshot, n: i16) {
+    let h = usize::from(grid.height);
+    let w = usize::from(grid.width);
+    let shift = usize::from(n.unsigned_abs());
+    if shift == 0 || shift >= h {
+        grid.cells.fill(VCell::default());
+        return;
+    }
+    if n > 0 {
+        // Scroll up: row r takes old row r + n.
+        grid.cells.rotate_left(shift * w);
+        grid.cells[(h - shift) * w..].fill(VCell::default());
+    } else {
+        // Scroll down: row r takes old row r - |n|.
+        grid.cells.rotate_right(shift * w);
+        grid.cells[..shift * w].fill(VCell::default());
+    }
+}
+
+fn apply_deltas(grid: &mut GridSnapshot, deltas: &[RowDelta]) {
+    for delta in deltas {
+        for (col_start, cells) in &delta.spans {
+            for (i, cell) in cells.iter().enumerate() {
+                grid.set(col_start + i as u16, delta.row, cell.clone());
+            }
+        }
+    }
+}
+
+/// Detect a pure vertical scroll: every overlapping row matches exactly
+/// under the shift, so only the revealed rows carry residual deltas.
+fn detect_scroll(a: &GridSnapshot, b: &GridSnapshot) -> Option<(i16, Vec<RowDelta>, DiffSummary)> {
+    let h = a.height;
+    for shift in 1..h {
+        for n in [i32::from(shift), -i32::from(shift)] {
+            if !shifted_rows_match(a, b, n) {
+                continue;
+            }
+            // Residual: diff the revealed (blanked) rows against b.
+            let mut shifted = a.clone();
+            shift_rows(&mut shifted, n as i16);
+            let (residual, summary) = row_deltas(&shifted, b);
+            // A genuine scroll only patches the revealed rows; if the
+            // residual touches more, the plain cell diff is smaller.
+            if summary.changed_rows <= usize::from(shift) {
+                return Some((n as i16, residual, summary));
+            }
+        }
+    }
+    None
+}
+
+/// Does every overlapping row of `b` equal the corresponding row of `a`
+/// shifted by `n`?
+fn shifted_rows_match(a: &GridSnapshot, b: &GridSnapshot, n: i32) -> bool {
+    let h = i32::from(a.height);
+    let mut overlap = 0;
+    for row_b in 0..h {
+        let row_a = row_b + n;
+        if row_a < 0 || row_a >= h {
+            continue;
+        }
+        overlap += 1;
+        if b.row(row_b as u16) != a.row(row_a as u16) {
+            return false;
+        }
+    }
+    overlap > 0
+}
+
+/// Group per-cell differences into row deltas plus a summary.
+fn row_deltas(a: &GridSnapshot, b: &GridSnapshot) -> (Vec<RowDelta>, DiffSummary) {
+    let mut rows = Vec::new();
+    let mut summary = DiffSummary::default();
+    for y in 0..a.height {
+        let (row_a, row_b) = (a.row(y).unwrap(), b.row(y).unwrap());
+        let mut spans: Vec<(u16, Vec<VCell>)> = Vec::new();
+        for x in 0..usize::from(a.width) {
+            if row_a[x] == row_b[x] {
+                continue;
+            }
+            summary.changed_cells += 1;
+            if row_a[x].ch == row_b[x].ch {
+                summary.attr_only_changes += 1;
+            } else {
+                summary.content_changes += 1;
+            }
+            match spans.last_mut() {
+                Some((start, cells)) if usize::from(*start) + cells.len() == x => {
+                    cells.push(row_b[x].clone());
+                }
+                _ => spans.push((x as u16, vec![row_b[x].clone()])),
+            }
+        }
+        if !spans.is_empty() {
+            summary.changed_rows += 1;
+            rows.push(RowDelta { row: y, spans });
+        }
+    }
+    (rows, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::virtual_terminal::VirtualTerminal;
+
+    fn snap_after(vt: &mut VirtualTerminal, bytes: &[u8]) -> GridSnapshot {
+        vt.feed(bytes);
+        snapshot(vt)
+    }
+
+    #[test]
+    fn identical_grids_are_unchanged() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let a = snap_after(&mut vt, b"hello");
+        let b = snapshot(&vt);
+        assert_eq!(diff(&a, &b), GridDiff::Unchanged);
+    }
+
+    #[test]
+    fn cell_changes_group_into_spans() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let a = snap_after(&mut vt, b"aaaaa");
+        let b = snap_after(&mut vt, b"\x1b[1;1Hbb\x1b[1;5Hc");
+
+        let GridDiff::Cells { rows, summary } = diff(&a, &b) else {
+            panic!("expected Cells diff");
+        };
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].row, 0);
+        // "bb" at col 0 and "c" at col 4 are separate runs.
+        assert_eq!(rows[0].spans.len(), 2);
+        assert_eq!(rows[0].spans[0].0, 0);
+        assert_eq!(rows[0].spans[0].1.len(), 2);
+        assert_eq!(rows[0].spans[1].0, 4);
+        assert_eq!(summary.changed_cells, 3);
+        assert_eq!(summary.changed_rows, 1);
+        assert_eq!(summary.content_changes, 3);
+    }
+
+    #[test]
+    fn attr_only_changes_counted_separately() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let a = snap_after(&mut vt, b"hi");
+        // Rewrite the same characters in bold: content identical, attrs not.
+        let b = snap_after(&mut vt, b"\x1b[1;1H\x1b[1mhi\x1b[0m");
+
+        let GridDiff::Cells { summary, .. } = diff(&a, &b) else {
+            panic!("expected Cells diff");
+        };
+        assert_eq!(summary.changed_cells, 2);
+        assert_eq!(summary.attr_only_changes, 2);
+        assert_eq!(summary.content_changes, 0);
+    }
+
+    #[test]
+    fn pure_scroll_up_detected() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        let a = snap_after(&mut vt, b"one\r\ntwo\r\nthree");
+        let b = snap_after(&mut vt, b"\r\nfour");
+
+        let d = diff(&a, &b);
+        let GridDiff::Scrolled {
+            n, ref residual, ..
+        } = d
+        else {
+            panic!("expected Scrolled, got {d:?}");
+        };
+        assert_eq!(n, 1);
+        // Only the revealed bottom row needs patching.
+        assert!(residual.iter().all(|r| r.row == 2));
+
+        let mut replay = a.clone();
+        apply(&d, &mut replay);
+        assert_eq!(replay, b);
+    }
+
+    #[test]
+    fn scroll_down_detected() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        let a = snap_after(&mut vt, b"one\r\ntwo\r\nthree");
+        // Reverse index at the top margin scrolls content down.
+        let b = snap_after(&mut vt, b"\x1b[1;1H\x1bM");
+
+        let d = diff(&a, &b);
+        let GridDiff::Scrolled { n, .. } = d else {
+            panic!("expected Scrolled, got {d:?}");
+        };
+        assert_eq!(n, -1);
+
+        let mut replay = a.clone();
+        apply(&d, &mut replay);
+        assert_eq!(replay, b);
+    }
+
+    #[test]
+    fn resize_produces_full_snapshot() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        let a = snap_after(&mut vt, b"abc");
+        vt.resize(14, 5);
+        let b = snapshot(&vt);
+
+        let d = diff(&a, &b);
+        assert!(matches!(d, GridDiff::Resized { .. }));
+
+        let mut replay = a.clone();
+        apply(&d, &mut replay);
+        assert_eq!(replay, b);
+    }
+
+    #[test]
+    fn wide_chars_round_trip() {
+        let mut vt = VirtualTerminal::new(10, 2);
+        let a = snapshot(&vt);
+        let b = snap_after(&mut vt, "世界".as_bytes());
+
+        let d = diff(&a, &b);
+        let mut replay = a.clone();
+        apply(&d, &mut replay);
+        assert_eq!(replay, b);
+        // The continuation cells travelled with the diff.
+        assert_eq!(replay.cell(0, 0).unwrap().ch, '世');
+        assert_eq!(replay.cell(1, 0).unwrap().ch, '\0');
+    }
+
+    #[test]
+    fn hyperlink_ids_round_trip() {
+        let mut vt = VirtualTerminal::new(20, 2);
+        let a = snap_after(&mut vt, b"plain");
+        let b = snap_after(
+            &mut vt,
+            b"\x1b[1;1H\x1b]8;;https://example.com\x1b\\plain\x1b]8;;\x1b\\",
+        );
+
+        let GridDiff::Cells { summary, .. } = diff(&a, &b) else {
+            panic!("expected Cells diff");
+        };
+        // Same glyphs, new link ids.
+        assert_eq!(summary.attr_only_changes, summary.changed_cells);
+
+        let mut replay = a.clone();
+        apply(&diff(&a, &b), &mut replay);
+        assert_eq!(replay, b);
+        assert!(replay.cell(0, 0).unwrap().link.is_some());
+    }
+
+    #[test]
+    fn diff_is_deterministic() {
+        let mut vt = VirtualTerminal::new(16, 4);
+        let a = snap_after(&mut vt, b"alpha\r\nbeta");
+        let b = snap_after(&mut vt, b"\x1b[2;1Hgamma\x1b[4;3Hdelta");
+        assert_eq!(diff(&a, &b), diff(&a, &b));
+    }
+
+    #[test]
+    fn fuzz_round_trip_reproduces_target() {
+        // Deterministic xorshift so failures are reproducible.
+        let mut state: u64 = 0x0dd5_eed5_1622_4242;
+        let mut rng = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut vt = VirtualTerminal::new(20, 5);
+        let mut prev = snapshot(&vt);
+        for _ in 0..600 {
+            match rng() % 12 {
+                0..=3 => {
+                    let ch = b'a' + (rng() % 26) as u8;
+                    vt.feed(&[ch]);
+                }
+                4 => vt.feed("汉".as_bytes()),
+                5 => vt.feed("e\u{301}".as_bytes()),
+                6 => vt.feed(b"\r\n"),
+                7 => {
+                    let row = rng() % 5 + 1;
+                    let col = rng() % 20 + 1;
+                    vt.feed(format!("\x1b[{row};{col}H").as_bytes());
+                }
+                8 => vt.feed(b"\x1b[1;33mX\x1b[0m"),
+                9 => vt.feed(b"\x1b]8;;https://example.com/a\x1b\\L\x1b]8;;\x1b\\"),
+                10 => vt.feed(b"\x1b[K"),
+                _ => vt.feed(b"\x1b[2J\x1b[H"),
+            }
+
+            let next = snapshot(&vt);
+            let d = diff(&prev, &next);
+            let mut replay = prev.clone();
+            apply(&d, &mut replay);
+            assert_eq!(replay, next, "diff failed to reproduce target");
+            prev = next;
+        }
+    }
+}
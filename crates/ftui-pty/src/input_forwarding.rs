@@ -368,6 +368,97 @@ fn insert_delete_sequence(code: u8, modifiers: Modifiers) -> Vec<u8> {
     }
 }
 
+/// Input-encoding modes the hosted application has requested.
+///
+/// Tracked by the virtual terminal from DECSET 2004 (bracketed paste),
+/// `CSI > 4 ; n m` (xterm modifyOtherKeys), and the kitty keyboard
+/// protocol's progressive-enhancement sequences, and read back by an
+/// embedder via [`VirtualTerminal::input_encoding`] so it can encode
+/// forwarded input the way the child expects.
+///
+/// [`VirtualTerminal::input_encoding`]: crate::virtual_terminal::VirtualTerminal::input_encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InputEncodingState {
+    /// DECSET 2004: wrap pastes in `CSI 200~` / `CSI 201~`.
+    pub bracketed_paste: bool,
+    /// xterm modifyOtherKeys level (0 = off, 1, or 2).
+    pub modify_other_keys: u8,
+    /// Kitty keyboard protocol progressive-enhancement flags
+    /// (bit 0 = disambiguate escape codes; higher bits tracked verbatim).
+    pub kitty_flags: u8,
+}
+
+impl InputEncodingState {
+    /// Kitty flag bit: disambiguate escape codes (CSI-u encoding).
+    pub const KITTY_DISAMBIGUATE: u8 = 0x01;
+}
+
+/// Encode a key for the hosted child per its requested input modes.
+///
+/// Picks the most specific encoding the child asked for: kitty CSI-u
+/// when the disambiguate flag is active, xterm
+/// `CSI 27 ; mod ; codepoint ~` for modified characters under
+/// modifyOtherKeys, and the legacy [`key_to_sequence`] bytes otherwise.
+#[must_use]
+pub fn encode_key_for_child(event: KeyEvent, state: &InputEncodingState) -> Vec<u8> {
+    if state.kitty_flags & InputEncodingState::KITTY_DISAMBIGUATE != 0 {
+        return encode_key_kitty(event);
+    }
+    match state.modify_other_keys {
+        2 => encode_key_modify_other(event, true),
+        1 => encode_key_modify_other(event, false),
+        _ => key_to_sequence(event),
+    }
+}
+
+/// Wrap pasted text in `CSI 200~` / `CSI 201~` only when the child has
+/// enabled bracketed paste (DECSET 2004).
+#[must_use]
+pub fn encode_paste_for_child(text: &str, state: &InputEncodingState) -> Vec<u8> {
+    let mut paste = BracketedPaste::new();
+    paste.set_enabled(state.bracketed_paste);
+    paste.wrap(text.as_bytes())
+}
+
+/// Kitty CSI-u (disambiguate): modified characters and Escape get
+/// unambiguous `CSI codepoint ; mod u` forms; everything else keeps its
+/// legacy encoding (kitty leaves functional keys on CSI 1;mod X forms).
+fn encode_key_kitty(event: KeyEvent) -> Vec<u8> {
+    let KeyEvent { key, modifiers } = event;
+    match key {
+        Key::Char(c) if modifiers.ctrl || modifiers.alt => {
+            format!("\x1b[{};{}u", c as u32, modifiers.csi_param()).into_bytes()
+        }
+        Key::Escape => {
+            if modifiers.any() {
+                format!("\x1b[27;{}u", modifiers.csi_param()).into_bytes()
+            } else {
+                b"\x1b[27u".to_vec()
+            }
+        }
+        _ => key_to_sequence(event),
+    }
+}
+
+/// xterm modifyOtherKeys: `CSI 27 ; mod ; codepoint ~` for modified
+/// characters. Level 1 covers only Ctrl/Alt combinations (keys whose
+/// legacy bytes are ambiguous); level 2 (`all_modified`) covers every
+/// modified character.
+fn encode_key_modify_other(event: KeyEvent, all_modified: bool) -> Vec<u8> {
+    let KeyEvent { key, modifiers } = event;
+    if let Key::Char(c) = key {
+        let wants = if all_modified {
+            modifiers.any()
+        } else {
+            modifiers.ctrl || modifiers.alt
+        };
+        if wants {
+            return format!("\x1b[27;{};{}~", modifiers.csi_param(), c as u32).into_bytes();
+        }
+    }
+    key_to_sequence(event)
+}
+
 /// Wrapper for bracketed paste.
 ///
 /// In bracketed paste mode, pasted text is wrapped with start/end markers
@@ -759,4 +850,127 @@ mod tests {
         let event = KeyEvent::new(Key::Char('?'), Modifiers::CTRL);
         assert_eq!(key_to_sequence(event), vec![0x7f]);
     }
+
+    // ── encode_key_for_child / encode_paste_for_child ────────────────
+
+    fn legacy() -> InputEncodingState {
+        InputEncodingState::default()
+    }
+
+    fn modify_other(level: u8) -> InputEncodingState {
+        InputEncodingState {
+            modify_other_keys: level,
+            ..Default::default()
+        }
+    }
+
+    fn kitty() -> InputEncodingState {
+        InputEncodingState {
+            kitty_flags: InputEncodingState::KITTY_DISAMBIGUATE,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn encode_key_golden_matrix() {
+        let ctrl_a = KeyEvent::new(Key::Char('a'), Modifiers::CTRL);
+        let alt_x = KeyEvent::new(Key::Char('x'), Modifiers::ALT);
+        let shift_b = KeyEvent::new(Key::Char('b'), Modifiers::SHIFT);
+        let plain_q = KeyEvent::plain(Key::Char('q'));
+        let up = KeyEvent::plain(Key::Up);
+
+        /// One golden row: expected bytes per encoding mode.
+        type GoldenRow = (
+            KeyEvent,
+            &'static [u8],
+            &'static [u8],
+            &'static [u8],
+            &'static [u8],
+        );
+
+        // (event, legacy, modifyOtherKeys=1, modifyOtherKeys=2, kitty)
+        let matrix: &[GoldenRow] = &[
+            (
+                ctrl_a,
+                &[0x01],
+                b"\x1b[27;5;97~",
+                b"\x1b[27;5;97~",
+                b"\x1b[97;5u",
+            ),
+            (
+                alt_x,
+                b"\x1bx",
+                b"\x1b[27;3;120~",
+                b"\x1b[27;3;120~",
+                b"\x1b[120;3u",
+            ),
+            (shift_b, b"B", b"B", b"\x1b[27;2;98~", b"B"),
+            (plain_q, b"q", b"q", b"q", b"q"),
+            (up, b"\x1b[A", b"\x1b[A", b"\x1b[A", b"\x1b[A"),
+        ];
+
+        for (event, want_legacy, want_mok1, want_mok2, want_kitty) in matrix {
+            assert_eq!(
+                encode_key_for_child(*event, &legacy()),
+                *want_legacy,
+                "legacy: {event:?}"
+            );
+            assert_eq!(
+                encode_key_for_child(*event, &modify_other(1)),
+                *want_mok1,
+                "modifyOtherKeys=1: {event:?}"
+            );
+            assert_eq!(
+                encode_key_for_child(*event, &modify_other(2)),
+                *want_mok2,
+                "modifyOtherKeys=2: {event:?}"
+            );
+            assert_eq!(
+                encode_key_for_child(*event, &kitty()),
+                *want_kitty,
+                "kitty: {event:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn kitty_escape_is_unambiguous() {
+        assert_eq!(
+            encode_key_for_child(KeyEvent::plain(Key::Escape), &kitty()),
+            b"\x1b[27u"
+        );
+        assert_eq!(
+            encode_key_for_child(KeyEvent::new(Key::Escape, Modifiers::ALT), &kitty()),
+            b"\x1b[27;3u"
+        );
+        // Legacy keeps the bare ESC byte.
+        assert_eq!(
+            encode_key_for_child(KeyEvent::plain(Key::Escape), &legacy()),
+            vec![0x1b]
+        );
+    }
+
+    #[test]
+    fn kitty_takes_precedence_over_modify_other_keys() {
+        let state = InputEncodingState {
+            modify_other_keys: 2,
+            kitty_flags: InputEncodingState::KITTY_DISAMBIGUATE,
+            ..Default::default()
+        };
+        let event = KeyEvent::new(Key::Char('a'), Modifiers::CTRL);
+        assert_eq!(encode_key_for_child(event, &state), b"\x1b[97;5u");
+    }
+
+    #[test]
+    fn paste_wraps_only_when_enabled() {
+        let on = InputEncodingState {
+            bracketed_paste: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            encode_paste_for_child("hi", &on),
+            b"\x1b[200~hi\x1b[201~".to_vec()
+        );
+        assert_eq!(encode_paste_for_child("hi", &legacy()), b"hi".to_vec());
+    }
 }
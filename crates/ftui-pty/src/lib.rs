@@ -27,12 +27,38 @@
 //! Instead, it provides test infrastructure used by `ftui-harness` and E2E
 //! scripts to verify correctness and cleanup behavior.
 
+/// Streaming text export for virtual terminal content.
+pub mod export;
+
+/// HTML export of virtual terminal content.
+pub mod cell_overlay;
+pub mod export_annotated;
+pub mod export_html;
+
+/// Structural grid diffs between terminal snapshots.
+pub mod grid_diff;
+
 /// Input forwarding: key events to ANSI sequences.
 pub mod input_forwarding;
 
+/// Implicit URL/path/email detection for Ctrl+click open support.
+pub mod link_detect;
+
+/// Scrollback line tagging: marks, bookmarks, jump navigation.
+pub mod scrollback_marks;
+
+/// Session metadata (cwd, host, user vars) from OSC 7 / OSC 1337.
+pub mod session_metadata;
+
+/// Mouse reporting passthrough state for hosted applications.
+pub mod mouse_passthrough;
+
 /// PTY process management for shell spawning and lifecycle control.
 pub mod pty_process;
 
+/// Mouse selection gestures with copy and output policies.
+pub mod selection;
+
 /// In-memory virtual terminal state machine for testing.
 pub mod virtual_terminal;
 
@@ -0,0 +1,565 @@
+#![forbid(unsafe_code)]
+
+//! Implicit link detection for Ctrl+click-to-open.
+//!
+//! Terminal users expect Ctrl+click on a URL to work even when the hosted
+//! application never emitted OSC 8 hyperlinks. [`detect_link_at`] scans
+//! outward from a grid position over the logical (soft-wrap-joined) line with
+//! conservative, regex-free matchers:
+//!
+//! - explicit schemes (`https://…`, `file://…`, any `scheme://`),
+//! - `www.`-prefixed hosts,
+//! - bare emails (`user@host.tld`, no `mailto:` required),
+//! - Unix (`/path/to/file`, `./rel`, `~/home`) and Windows (`C:\…`) paths,
+//!   including `file.rs:120:5` line:col suffixes and quoted paths with
+//!   spaces.
+//!
+//! Explicit OSC 8 hyperlinks take precedence and are returned as-is. The
+//! detected extent is reported as per-row segments so the host can underline
+//! on hover, and trailing punctuation is stripped conservatively
+//! (`(see https://x.y).` yields `https://x.y`).
+
+use crate::virtual_terminal::VirtualTerminal;
+
+/// Classification of a detected link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// A URL (explicit scheme, `www.` host, or OSC 8 target).
+    Url,
+    /// A filesystem path, optionally with `:line[:col]` suffix.
+    FilePath,
+    /// A bare email address.
+    Email,
+}
+
+/// One row-contiguous slice of a detected link's extent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkSegment {
+    /// Grid row.
+    pub row: u16,
+    /// First column (inclusive).
+    pub start_col: u16,
+    /// Last column (inclusive).
+    pub end_col: u16,
+}
+
+/// A link detected under the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedLink {
+    /// What kind of target this is.
+    pub kind: LinkKind,
+    /// The link text (for OSC 8 links, the URI — not the covered text).
+    pub text: String,
+    /// Visible extent, one segment per grid row, for hover underlining.
+    pub segments: Vec<LinkSegment>,
+}
+
+/// A character with its source grid location (None when off-screen, i.e.
+/// pulled from scrollback while joining a wrapped line).
+#[derive(Debug, Clone, Copy)]
+struct LocatedChar {
+    ch: char,
+    pos: Option<(u16, u16)>,
+}
+
+/// Detect a link at the given visible grid position.
+///
+/// Returns `None` when the cell is blank or no conservative matcher applies.
+#[must_use]
+pub fn detect_link_at(vt: &VirtualTerminal, x: u16, y: u16) -> Option<DetectedLink> {
+    // Explicit OSC 8 hyperlink takes precedence, returned as-is.
+    if let Some(id) = vt.cell_at(x, y).and_then(|cell| cell.link) {
+        let uri = vt.link_uri(id)?.to_string();
+        return Some(DetectedLink {
+            kind: LinkKind::Url,
+            text: uri,
+            segments: explicit_link_segments(vt, id),
+        });
+    }
+
+    // Blank or out-of-bounds cells can't anchor a link.
+    let clicked = vt.char_at(x, y)?;
+    if clicked == ' ' {
+        return None;
+    }
+
+    let line = logical_line(vt, y)?;
+    // Index of the clicked cell within the logical line.
+    let click_idx = line
+        .iter()
+        .position(|lc| lc.pos == Some((x, y)))
+        .or_else(|| {
+            // Wide-char continuation cells are skipped when joining; fall
+            // back to the nearest preceding char on the same row.
+            line.iter()
+                .rposition(|lc| matches!(lc.pos, Some((cx, cy)) if cy == y && cx <= x))
+        })?;
+
+    if line[click_idx].ch.is_whitespace() {
+        return None;
+    }
+
+    let (token_start, token_end) = token_bounds(&line, click_idx);
+    let token: String = line[token_start..token_end]
+        .iter()
+        .map(|lc| lc.ch)
+        .collect();
+
+    let (trim_front, trimmed) = strip_wrapping(&token);
+    let trimmed = strip_trailing_punctuation(trimmed);
+    if trimmed.is_empty() {
+        return None;
+    }
+    let kind = classify(trimmed)?;
+
+    // Map the trimmed byte range back to logical-line char indices.
+    let char_start = token_start + token[..trim_front].chars().count();
+    let char_end = char_start + trimmed.chars().count();
+    if click_idx < char_start || click_idx >= char_end {
+        // The click landed on stripped punctuation, not the link itself.
+        return None;
+    }
+
+    Some(DetectedLink {
+        kind,
+        text: trimmed.to_string(),
+        segments: segments_for(&line[char_start..char_end]),
+    })
+}
+
+/// Strip common trailing punctuation from a link candidate.
+///
+/// Closing brackets are only stripped when unbalanced within the candidate,
+/// so `https://en.wikipedia.org/wiki/Rust_(language)` survives while
+/// `https://x.y).` loses the `).`.
+#[must_use]
+pub fn strip_trailing_punctuation(text: &str) -> &str {
+    let mut out = text;
+    loop {
+        let Some(last) = out.chars().last() else {
+            return out;
+        };
+        let stripped = match last {
+            '.' | ',' | ';' | ':' | '!' | '?' | '\'' | '"' => true,
+            ')' => out.matches('(').count() < out.matches(')').count(),
+            ']' => out.matches('[').count() < out.matches(']').count(),
+            '}' => out.matches('{').count() < out.matches('}').count(),
+            '>' => out.matches('<').count() < out.matches('>').count(),
+            _ => false,
+        };
+        if !stripped {
+            return out;
+        }
+        out = &out[..out.len() - last.len_utf8()];
+    }
+}
+
+/// Strip one layer of leading wrapping punctuation, returning the byte
+/// offset consumed and the remainder.
+fn strip_wrapping(token: &str) -> (usize, &str) {
+    let mut offset = 0;
+    let mut rest = token;
+    while let Some(first) = rest.chars().next() {
+        if matches!(first, '(' | '[' | '{' | '<' | '"' | '\'') {
+            offset += first.len_utf8();
+            rest = &rest[first.len_utf8()..];
+        } else {
+            break;
+        }
+    }
+    (offset, rest)
+}
+
+/// Classify a trimmed token with conservative, regex-free checks.
+fn classify(token: &str) -> Option<LinkKind> {
+    if is_url(token) {
+        return Some(LinkKind::Url);
+    }
+    if is_email(token) {
+        return Some(LinkKind::Email);
+    }
+    if is_file_path(token) {
+        return Some(LinkKind::FilePath);
+    }
+    None
+}
+
+fn is_url(token: &str) -> bool {
+    if let Some(idx) = token.find("://") {
+        let scheme = &token[..idx];
+        return !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-')
+            && token.len() > idx + 3;
+    }
+    if let Some(rest) = token.strip_prefix("www.") {
+        return rest.contains('.') || !rest.is_empty() && rest.chars().any(char::is_alphanumeric);
+    }
+    false
+}
+
+fn is_email(token: &str) -> bool {
+    let Some((local, host)) = token.split_once('@') else {
+        return false;
+    };
+    if local.is_empty() || host.is_empty() || host.contains('@') {
+        return false;
+    }
+    let Some((name, tld)) = host.rsplit_once('.') else {
+        return false;
+    };
+    !name.is_empty()
+        && tld.len() >= 2
+        && tld.chars().all(char::is_alphabetic)
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '+'))
+}
+
+fn is_file_path(token: &str) -> bool {
+    // Strip a trailing :line[:col] suffix before judging the path part.
+    let path = strip_line_col(token);
+    if path.starts_with('/')
+        || path.starts_with("./")
+        || path.starts_with("../")
+        || path.starts_with("~/")
+    {
+        return path.len() > 1;
+    }
+    // Windows drive path (C:\...).
+    let mut chars = path.chars();
+    if let (Some(drive), Some(':'), Some('\\')) = (chars.next(), chars.next(), chars.next()) {
+        return drive.is_ascii_alphabetic();
+    }
+    // Relative source reference with location suffix (file.rs:120:5).
+    path != token && path.contains('.') && !path.contains("..")
+}
+
+/// Strip a trailing `:line[:col]` suffix (digits only) from a path token.
+fn strip_line_col(token: &str) -> &str {
+    let mut out = token;
+    for _ in 0..2 {
+        if let Some((head, tail)) = out.rsplit_once(':')
+            && !tail.is_empty()
+            && tail.chars().all(|c| c.is_ascii_digit())
+        {
+            out = head;
+        } else {
+            break;
+        }
+    }
+    out
+}
+
+/// Join the clicked row with its soft-wrapped neighbors into a logical line.
+///
+/// A row is treated as continuing onto the next when its last cell is
+/// non-blank (the conservative wrap heuristic available without explicit
+/// wrap tracking). The top of the screen may continue from the most recent
+/// scrollback line; those characters participate in matching but have no
+/// visible segments.
+fn logical_line(vt: &VirtualTerminal, y: u16) -> Option<Vec<LocatedChar>> {
+    if y >= vt.height() {
+        return None;
+    }
+
+    let row_continues = |row: u16| -> bool {
+        vt.char_at(vt.width() - 1, row)
+            .is_some_and(|ch| ch != ' ' && ch != '\0')
+    };
+
+    // Find the first row of the wrapped run containing `y`.
+    let mut first = y;
+    while first > 0 && row_continues(first - 1) {
+        first -= 1;
+    }
+    // Find the last row of the run.
+    let mut last = y;
+    while last + 1 < vt.height() && row_continues(last) {
+        last += 1;
+    }
+
+    let mut line = Vec::new();
+    // If the run starts at the top of the screen, the line may begin in
+    // scrollback: include the most recent scrollback line when it is full.
+    if first == 0
+        && vt.scrollback_len() > 0
+        && let Some(prev) = vt.scrollback_line(vt.scrollback_len() - 1)
+        && prev.len() >= usize::from(vt.width())
+        && !prev.ends_with(' ')
+    {
+        line.extend(prev.chars().map(|ch| LocatedChar { ch, pos: None }));
+    }
+
+    for row in first..=last {
+        let end = if row == last {
+            // Trailing blanks on the final row are not part of the line.
+            let mut end = vt.width();
+            while end > 0 && vt.char_at(end - 1, row) == Some(' ') {
+                end -= 1;
+            }
+            end
+        } else {
+            vt.width()
+        };
+        for col in 0..end {
+            let ch = vt.char_at(col, row)?;
+            if ch == '\0' {
+                continue; // wide-char continuation
+            }
+            line.push(LocatedChar {
+                ch,
+                pos: Some((col, row)),
+            });
+        }
+    }
+    Some(line)
+}
+
+/// Expand from the click position to token boundaries.
+///
+/// Tokens normally break on whitespace; a click inside a double-quoted span
+/// selects the quoted content instead, so paths with spaces work.
+fn token_bounds(line: &[LocatedChar], click_idx: usize) -> (usize, usize) {
+    // Quoted span: an odd number of quotes before the click means we're
+    // inside one.
+    let quotes_before = line[..click_idx].iter().filter(|lc| lc.ch == '"').count();
+    if quotes_before % 2 == 1 {
+        let start = line[..click_idx]
+            .iter()
+            .rposition(|lc| lc.ch == '"')
+            .map_or(0, |i| i + 1);
+        let end = line[click_idx..]
+            .iter()
+            .position(|lc| lc.ch == '"')
+            .map_or(line.len(), |i| click_idx + i);
+        if start < end {
+            return (start, end);
+        }
+    }
+
+    let start = line[..click_idx]
+        .iter()
+        .rposition(|lc| lc.ch.is_whitespace())
+        .map_or(0, |i| i + 1);
+    let end = line[click_idx..]
+        .iter()
+        .position(|lc| lc.ch.is_whitespace())
+        .map_or(line.len(), |i| click_idx + i);
+    (start, end)
+}
+
+/// Collapse per-character locations into row-contiguous segments.
+fn segments_for(chars: &[LocatedChar]) -> Vec<LinkSegment> {
+    let mut segments: Vec<LinkSegment> = Vec::new();
+    for lc in chars {
+        let Some((col, row)) = lc.pos else {
+            continue; // scrollback characters are not visible
+        };
+        match segments.last_mut() {
+            Some(seg) if seg.row == row && seg.end_col + 1 >= col => {
+                seg.end_col = seg.end_col.max(col);
+            }
+            _ => segments.push(LinkSegment {
+                row,
+                start_col: col,
+                end_col: col,
+            }),
+        }
+    }
+    segments
+}
+
+/// Extent of an explicit OSC 8 link id over the visible grid.
+fn explicit_link_segments(vt: &VirtualTerminal, id: u32) -> Vec<LinkSegment> {
+    let mut segments: Vec<LinkSegment> = Vec::new();
+    for row in 0..vt.height() {
+        for col in 0..vt.width() {
+            if vt.cell_at(col, row).and_then(|cell| cell.link) == Some(id) {
+                match segments.last_mut() {
+                    Some(seg) if seg.row == row && seg.end_col + 1 == col => {
+                        seg.end_col = col;
+                    }
+                    _ => segments.push(LinkSegment {
+                        row,
+                        start_col: col,
+                        end_col: col,
+                    }),
+                }
+            }
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vt_with(text: &str) -> VirtualTerminal {
+        let mut vt = VirtualTerminal::new(80, 5);
+        vt.feed(text.as_bytes());
+        vt
+    }
+
+    fn detect(text: &str, x: u16, y: u16) -> Option<DetectedLink> {
+        detect_link_at(&vt_with(text), x, y)
+    }
+
+    #[test]
+    fn tricky_strings_table() {
+        // (input line, click column, expected text, expected kind)
+        type Case<'a> = (&'a str, u16, Option<(&'a str, LinkKind)>);
+        let cases: &[Case] = &[
+            (
+                "see https://example.com for info",
+                8,
+                Some(("https://example.com", LinkKind::Url)),
+            ),
+            (
+                "(see https://x.y).",
+                10,
+                Some(("https://x.y", LinkKind::Url)),
+            ),
+            (
+                "link <https://a.b/c>, done",
+                10,
+                Some(("https://a.b/c", LinkKind::Url)),
+            ),
+            (
+                "wiki https://en.wikipedia.org/wiki/Rust_(language) x",
+                20,
+                Some((
+                    "https://en.wikipedia.org/wiki/Rust_(language)",
+                    LinkKind::Url,
+                )),
+            ),
+            (
+                "visit www.example.org today",
+                8,
+                Some(("www.example.org", LinkKind::Url)),
+            ),
+            (
+                "mail bob.smith+tags@example.co.uk now",
+                10,
+                Some(("bob.smith+tags@example.co.uk", LinkKind::Email)),
+            ),
+            (
+                "error at src/main.rs:120:5 in build",
+                12,
+                Some(("src/main.rs:120:5", LinkKind::FilePath)),
+            ),
+            (
+                "open /usr/local/bin/cargo now",
+                8,
+                Some(("/usr/local/bin/cargo", LinkKind::FilePath)),
+            ),
+            (
+                "home ~/projects/app works",
+                8,
+                Some(("~/projects/app", LinkKind::FilePath)),
+            ),
+            (
+                "win C:\\Users\\dev\\file.txt here",
+                8,
+                Some(("C:\\Users\\dev\\file.txt", LinkKind::FilePath)),
+            ),
+            ("plain words only here", 3, None),
+            ("not@adomain", 3, None),
+            ("ratio 3:2 is fine", 6, None),
+        ];
+
+        for (input, x, expected) in cases {
+            let got = detect(input, *x, 0);
+            match expected {
+                Some((text, kind)) => {
+                    let link = got.unwrap_or_else(|| panic!("no link in {input:?} at col {x}"));
+                    assert_eq!(link.text, *text, "text for {input:?}");
+                    assert_eq!(link.kind, *kind, "kind for {input:?}");
+                }
+                None => assert!(got.is_none(), "unexpected link {got:?} in {input:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn quoted_path_with_spaces() {
+        let link = detect("open \"/tmp/my files/report final.pdf\" now", 12, 0).unwrap();
+        assert_eq!(link.kind, LinkKind::FilePath);
+        assert_eq!(link.text, "/tmp/my files/report final.pdf");
+    }
+
+    #[test]
+    fn click_on_stripped_punctuation_is_not_a_link() {
+        // Column 17 is the ')' after the URL.
+        assert!(detect("(see https://x.y).", 16, 0).is_none());
+    }
+
+    #[test]
+    fn wrap_spanning_url_detected_from_second_row() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        // 20-wide terminal: the URL wraps onto the second row.
+        vt.feed(b"go https://example.com/long/path now");
+        let link = detect_link_at(&vt, 2, 1).expect("link on wrapped row");
+        assert_eq!(link.text, "https://example.com/long/path");
+        // Extent covers both rows.
+        assert_eq!(link.segments.len(), 2);
+        assert_eq!(link.segments[0].row, 0);
+        assert_eq!(link.segments[1].row, 1);
+        // Also detectable from the first row.
+        let same = detect_link_at(&vt, 5, 0).expect("link on first row");
+        assert_eq!(same.text, link.text);
+    }
+
+    #[test]
+    fn explicit_osc8_link_takes_precedence() {
+        let mut vt = VirtualTerminal::new(40, 3);
+        // The visible text looks like a plain word, but carries an OSC 8 target.
+        vt.feed(
+            b"\x1b]8;;https://real.example/target\x1b\\docs\x1b]8;;\x1b\\ https://fake.example",
+        );
+        let link = detect_link_at(&vt, 1, 0).expect("explicit link");
+        assert_eq!(link.kind, LinkKind::Url);
+        assert_eq!(link.text, "https://real.example/target");
+        assert_eq!(
+            link.segments,
+            vec![LinkSegment {
+                row: 0,
+                start_col: 0,
+                end_col: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn hover_segments_cover_detected_extent() {
+        let link = detect("see https://example.com now", 8, 0).unwrap();
+        assert_eq!(
+            link.segments,
+            vec![LinkSegment {
+                row: 0,
+                start_col: 4,
+                end_col: 22
+            }]
+        );
+    }
+
+    #[test]
+    fn strip_trailing_punctuation_cases() {
+        assert_eq!(strip_trailing_punctuation("https://x.y)."), "https://x.y");
+        assert_eq!(
+            strip_trailing_punctuation("https://x.y/a_(b)"),
+            "https://x.y/a_(b)"
+        );
+        assert_eq!(strip_trailing_punctuation("foo,"), "foo");
+        assert_eq!(strip_trailing_punctuation("foo"), "foo");
+        assert_eq!(strip_trailing_punctuation(""), "");
+    }
+
+    #[test]
+    fn blank_cell_yields_no_link() {
+        assert!(detect("a https://x.y", 60, 0).is_none());
+        assert!(detect("a https://x.y", 0, 3).is_none());
+    }
+}
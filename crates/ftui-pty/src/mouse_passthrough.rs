@@ -0,0 +1,312 @@
+#![forbid(unsafe_code)]
+
+//! Mouse reporting passthrough for hosted applications.
+//!
+//! A hosted child application requests mouse reporting with DECSET
+//! 1000/1002/1003 (tracking) and 1005/1006 (encoding). The virtual
+//! terminal records those requests as a [`MouseProtocol`], so the embedder
+//! can decide whether a mouse event belongs to the child (forward it,
+//! encoded with [`encode_mouse_event`]) or stays host-side for selection.
+//! The terminal convention — selection gestures are suppressed while the
+//! child tracks the mouse unless Shift is held — is captured by
+//! [`MouseProtocol::should_forward`].
+
+use ftui_core::event::{Modifiers, MouseButton, MouseEvent, MouseEventKind};
+
+/// Which mouse events the child asked to receive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MouseTracking {
+    /// No tracking (default): all events stay with the embedder.
+    #[default]
+    Off,
+    /// DECSET 1000: presses and releases only.
+    Normal,
+    /// DECSET 1002: presses, releases, and drag motion.
+    ButtonEvent,
+    /// DECSET 1003: all motion.
+    AnyEvent,
+}
+
+/// How mouse events are encoded on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MouseEncoding {
+    /// Legacy X10 bytes (`CSI M Cb Cx Cy`), coordinates capped at 223.
+    #[default]
+    X10,
+    /// DECSET 1005: X10 framing with UTF-8 coordinate encoding.
+    Utf8,
+    /// DECSET 1006: SGR (`CSI < b ; x ; y M/m`).
+    Sgr,
+}
+
+/// The child's requested mouse reporting state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MouseProtocol {
+    /// Tracking mode (which events are reported).
+    pub tracking: MouseTracking,
+    /// Wire encoding for reports.
+    pub encoding: MouseEncoding,
+}
+
+impl MouseProtocol {
+    /// Should this event be forwarded to the child?
+    ///
+    /// Selection stays host-side when tracking is off; while the child
+    /// tracks the mouse, Shift overrides per terminal convention and keeps
+    /// the event (and thus selection) with the embedder.
+    #[must_use]
+    pub fn should_forward(&self, event: &MouseEvent) -> bool {
+        self.tracking != MouseTracking::Off && !event.modifiers.contains(Modifiers::SHIFT)
+    }
+}
+
+/// Maximum X10 coordinate (values encode as `coord + 1 + 32` in one byte).
+const X10_COORD_MAX: u16 = 222;
+
+/// Encode a mouse event for forwarding to the child PTY.
+///
+/// Returns `None` when the event should not be reported under `protocol`
+/// (tracking off, or motion filtered out by the tracking mode).
+#[must_use]
+pub fn encode_mouse_event(event: &MouseEvent, protocol: MouseProtocol) -> Option<Vec<u8>> {
+    if protocol.tracking == MouseTracking::Off {
+        return None;
+    }
+
+    // Motion filtering per tracking mode.
+    match event.kind {
+        MouseEventKind::Moved if protocol.tracking != MouseTracking::AnyEvent => return None,
+        MouseEventKind::Drag(_) if protocol.tracking == MouseTracking::Normal => return None,
+        _ => {}
+    }
+
+    let is_release = matches!(event.kind, MouseEventKind::Up(_));
+    let mut cb: u16 = match event.kind {
+        MouseEventKind::Down(button) | MouseEventKind::Up(button) => button_code(button),
+        MouseEventKind::Drag(button) => button_code(button) + 32,
+        MouseEventKind::Moved => 3 + 32,
+        MouseEventKind::ScrollUp => 64,
+        MouseEventKind::ScrollDown => 65,
+        MouseEventKind::ScrollLeft => 66,
+        MouseEventKind::ScrollRight => 67,
+    };
+    if event.modifiers.contains(Modifiers::SHIFT) {
+        cb += 4;
+    }
+    if event.modifiers.contains(Modifiers::ALT) {
+        cb += 8;
+    }
+    if event.modifiers.contains(Modifiers::CTRL) {
+        cb += 16;
+    }
+
+    match protocol.encoding {
+        MouseEncoding::Sgr => {
+            // SGR reports 1-based coordinates and distinguishes release
+            // with a trailing 'm' (the button code stays intact).
+            let suffix = if is_release { 'm' } else { 'M' };
+            Some(
+                format!(
+                    "\x1b[<{};{};{}{}",
+                    cb,
+                    u32::from(event.x) + 1,
+                    u32::from(event.y) + 1,
+                    suffix
+                )
+                .into_bytes(),
+            )
+        }
+        MouseEncoding::X10 => {
+            // X10 encodes release as button 3 and caps coordinates at 223.
+            let cb = if is_release { (cb & !0b11) | 3 } else { cb };
+            let x = event.x.min(X10_COORD_MAX);
+            let y = event.y.min(X10_COORD_MAX);
+            Some(vec![
+                0x1b,
+                b'[',
+                b'M',
+                32 + cb as u8,
+                32 + 1 + x as u8,
+                32 + 1 + y as u8,
+            ])
+        }
+        MouseEncoding::Utf8 => {
+            // 1005: X10 framing, but coordinates become UTF-8 codepoints
+            // (supporting up to 2015 minus offsets).
+            let cb = if is_release { (cb & !0b11) | 3 } else { cb };
+            let mut out = vec![0x1b, b'[', b'M'];
+            push_utf8_coord(&mut out, 32 + u32::from(cb));
+            push_utf8_coord(&mut out, 32 + 1 + u32::from(event.x.min(2014)));
+            push_utf8_coord(&mut out, 32 + 1 + u32::from(event.y.min(2014)));
+            Some(out)
+        }
+    }
+}
+
+fn button_code(button: MouseButton) -> u16 {
+    match button {
+        MouseButton::Left => 0,
+        MouseButton::Middle => 1,
+        MouseButton::Right => 2,
+    }
+}
+
+fn push_utf8_coord(out: &mut Vec<u8>, value: u32) {
+    let ch = char::from_u32(value).unwrap_or(' ');
+    let mut buf = [0u8; 4];
+    out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::virtual_terminal::VirtualTerminal;
+
+    fn event(kind: MouseEventKind, x: u16, y: u16) -> MouseEvent {
+        MouseEvent::new(kind, x, y)
+    }
+
+    #[test]
+    fn decset_stream_transitions_tracking_state() {
+        let mut vt = VirtualTerminal::new(80, 24);
+        assert_eq!(vt.mouse_protocol(), MouseProtocol::default());
+
+        vt.feed(b"\x1b[?1000h");
+        assert_eq!(vt.mouse_protocol().tracking, MouseTracking::Normal);
+
+        vt.feed(b"\x1b[?1002h");
+        assert_eq!(vt.mouse_protocol().tracking, MouseTracking::ButtonEvent);
+
+        vt.feed(b"\x1b[?1003h");
+        assert_eq!(vt.mouse_protocol().tracking, MouseTracking::AnyEvent);
+
+        // Resetting the active mode turns tracking off; resetting an
+        // inactive mode is a no-op.
+        vt.feed(b"\x1b[?1000l");
+        assert_eq!(vt.mouse_protocol().tracking, MouseTracking::AnyEvent);
+        vt.feed(b"\x1b[?1003l");
+        assert_eq!(vt.mouse_protocol().tracking, MouseTracking::Off);
+    }
+
+    #[test]
+    fn decset_stream_transitions_encoding_state() {
+        let mut vt = VirtualTerminal::new(80, 24);
+        assert_eq!(vt.mouse_protocol().encoding, MouseEncoding::X10);
+
+        vt.feed(b"\x1b[?1005h");
+        assert_eq!(vt.mouse_protocol().encoding, MouseEncoding::Utf8);
+        vt.feed(b"\x1b[?1006h");
+        assert_eq!(vt.mouse_protocol().encoding, MouseEncoding::Sgr);
+        vt.feed(b"\x1b[?1006l");
+        assert_eq!(vt.mouse_protocol().encoding, MouseEncoding::X10);
+    }
+
+    #[test]
+    fn sgr_encoding_goldens() {
+        let protocol = MouseProtocol {
+            tracking: MouseTracking::Normal,
+            encoding: MouseEncoding::Sgr,
+        };
+        let down = event(MouseEventKind::Down(MouseButton::Left), 4, 9);
+        assert_eq!(
+            encode_mouse_event(&down, protocol).unwrap(),
+            b"\x1b[<0;5;10M"
+        );
+        let up = event(MouseEventKind::Up(MouseButton::Right), 4, 9);
+        assert_eq!(encode_mouse_event(&up, protocol).unwrap(), b"\x1b[<2;5;10m");
+        let wheel = event(MouseEventKind::ScrollUp, 0, 0);
+        assert_eq!(
+            encode_mouse_event(&wheel, protocol).unwrap(),
+            b"\x1b[<64;1;1M"
+        );
+        let ctrl =
+            event(MouseEventKind::Down(MouseButton::Middle), 0, 0).with_modifiers(Modifiers::CTRL);
+        assert_eq!(
+            encode_mouse_event(&ctrl, protocol).unwrap(),
+            b"\x1b[<17;1;1M"
+        );
+    }
+
+    #[test]
+    fn x10_encoding_goldens_and_clamping() {
+        let protocol = MouseProtocol {
+            tracking: MouseTracking::Normal,
+            encoding: MouseEncoding::X10,
+        };
+        let down = event(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert_eq!(
+            encode_mouse_event(&down, protocol).unwrap(),
+            vec![0x1b, b'[', b'M', 32, 33, 33]
+        );
+        // Release reports button 3.
+        let up = event(MouseEventKind::Up(MouseButton::Left), 0, 0);
+        assert_eq!(
+            encode_mouse_event(&up, protocol).unwrap(),
+            vec![0x1b, b'[', b'M', 35, 33, 33]
+        );
+        // Coordinates clamp at the single-byte limit (223 → byte 255).
+        let far = event(MouseEventKind::Down(MouseButton::Left), 500, 500);
+        assert_eq!(
+            encode_mouse_event(&far, protocol).unwrap(),
+            vec![0x1b, b'[', b'M', 32, 255, 255]
+        );
+    }
+
+    #[test]
+    fn utf8_encoding_uses_multibyte_coords() {
+        let protocol = MouseProtocol {
+            tracking: MouseTracking::Normal,
+            encoding: MouseEncoding::Utf8,
+        };
+        // Coordinate 300 → codepoint 333 → two UTF-8 bytes.
+        let far = event(MouseEventKind::Down(MouseButton::Left), 300, 0);
+        let bytes = encode_mouse_event(&far, protocol).unwrap();
+        assert_eq!(&bytes[..4], &[0x1b, b'[', b'M', 32]);
+        let mut buf = [0u8; 4];
+        let expected = char::from_u32(32 + 1 + 300).unwrap().encode_utf8(&mut buf);
+        assert_eq!(&bytes[4..4 + expected.len()], expected.as_bytes());
+    }
+
+    #[test]
+    fn motion_filtering_per_tracking_mode() {
+        let moved = event(MouseEventKind::Moved, 1, 1);
+        let drag = event(MouseEventKind::Drag(MouseButton::Left), 1, 1);
+
+        let normal = MouseProtocol {
+            tracking: MouseTracking::Normal,
+            encoding: MouseEncoding::Sgr,
+        };
+        assert!(encode_mouse_event(&moved, normal).is_none());
+        assert!(encode_mouse_event(&drag, normal).is_none());
+
+        let button_event = MouseProtocol {
+            tracking: MouseTracking::ButtonEvent,
+            ..normal
+        };
+        assert!(encode_mouse_event(&moved, button_event).is_none());
+        let drag_bytes = encode_mouse_event(&drag, button_event).unwrap();
+        assert_eq!(drag_bytes, b"\x1b[<32;2;2M");
+
+        let any = MouseProtocol {
+            tracking: MouseTracking::AnyEvent,
+            ..normal
+        };
+        assert_eq!(encode_mouse_event(&moved, any).unwrap(), b"\x1b[<35;2;2M");
+    }
+
+    #[test]
+    fn shift_override_keeps_events_host_side() {
+        let tracked = MouseProtocol {
+            tracking: MouseTracking::Normal,
+            encoding: MouseEncoding::Sgr,
+        };
+        let plain = event(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert!(tracked.should_forward(&plain));
+
+        let shifted = plain.with_modifiers(Modifiers::SHIFT);
+        assert!(!tracked.should_forward(&shifted), "shift keeps selection");
+
+        let off = MouseProtocol::default();
+        assert!(!off.should_forward(&plain));
+    }
+}
@@ -0,0 +1,381 @@
+#![forbid(unsafe_code)]
+
+//! Scrollback line tagging: marks, bookmarks, and jump navigation.
+//!
+//! Marks are keyed by the scrollback's *absolute* line identities
+//! ([`VirtualTerminal::absolute_index`]) — a monotonically increasing
+//! counter over every line ever pushed — so eviction of the ring's front
+//! never shifts what a mark points at. [`ScrollbackMarks::sync`] drops
+//! marks whose line has been evicted and counts them, so tools can
+//! notify ("2 bookmarks scrolled away"). Reflow (an embedder re-feeding
+//! content at a new width) is handled by [`ScrollbackMarks::remap`] with
+//! an old→new identity mapping, or [`ScrollbackMarks::remap_by_content`]
+//! which rebuilds the mapping from line text.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::virtual_terminal::VirtualTerminal;
+
+/// What a mark means; embedders define the vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MarkKind {
+    /// User bookmark ("where the build started").
+    Bookmark,
+    /// Error marker from output parsing.
+    Error,
+    /// Warning marker.
+    Warning,
+    /// Anything else, tagged with a small numeric discriminator.
+    Custom(u16),
+}
+
+/// A mark attached to one scrollback line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mark {
+    /// Classification for navigation and gutter styling.
+    pub kind: MarkKind,
+    /// Free-form label shown in jump lists.
+    pub label: String,
+}
+
+/// Mark store keyed by stable absolute line identities.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollbackMarks {
+    marks: BTreeMap<u64, Vec<Mark>>,
+    /// Total marks dropped because their line was evicted or lost.
+    dropped_total: u64,
+}
+
+impl ScrollbackMarks {
+    /// Create an empty mark store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a mark to an absolute scrollback line.
+    pub fn add_mark(&mut self, abs_line: u64, mark: Mark) {
+        self.marks.entry(abs_line).or_default().push(mark);
+    }
+
+    /// All marks on one absolute line.
+    #[must_use]
+    pub fn marks_at(&self, abs_line: u64) -> &[Mark] {
+        self.marks.get(&abs_line).map_or(&[], Vec::as_slice)
+    }
+
+    /// Marks within an absolute-line range, ascending.
+    ///
+    /// An empty or inverted range yields nothing.
+    pub fn marks_in(&self, range: Range<u64>) -> impl Iterator<Item = (u64, &Mark)> {
+        let range = if range.start <= range.end {
+            range
+        } else {
+            range.start..range.start
+        };
+        self.marks
+            .range(range)
+            .flat_map(|(line, marks)| marks.iter().map(move |m| (*line, m)))
+    }
+
+    /// The first marked line strictly after `from` (optionally filtered
+    /// by kind).
+    #[must_use]
+    pub fn next_mark(&self, from: u64, kind: Option<MarkKind>) -> Option<u64> {
+        self.marks
+            .range(from.checked_add(1)?..)
+            .find(|(_, marks)| kind.is_none_or(|k| marks.iter().any(|m| m.kind == k)))
+            .map(|(line, _)| *line)
+    }
+
+    /// The last marked line strictly before `from` (optionally filtered
+    /// by kind).
+    #[must_use]
+    pub fn prev_mark(&self, from: u64, kind: Option<MarkKind>) -> Option<u64> {
+        self.marks
+            .range(..from)
+            .rev()
+            .find(|(_, marks)| kind.is_none_or(|k| marks.iter().any(|m| m.kind == k)))
+            .map(|(line, _)| *line)
+    }
+
+    /// Total number of marks across all lines.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.marks.values().map(Vec::len).sum()
+    }
+
+    /// Whether the store is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty()
+    }
+
+    /// Cumulative count of marks dropped by [`sync`](Self::sync) and
+    /// failed remaps — the notification counter.
+    #[must_use]
+    pub fn dropped_total(&self) -> u64 {
+        self.dropped_total
+    }
+
+    /// Drop marks whose line has been evicted from the terminal's
+    /// scrollback. Returns how many marks were dropped by this call.
+    pub fn sync(&mut self, vt: &VirtualTerminal) -> usize {
+        let first = vt.first_absolute_line();
+        let evicted: Vec<u64> = self.marks.range(..first).map(|(line, _)| *line).collect();
+        let mut dropped = 0;
+        for line in evicted {
+            if let Some(marks) = self.marks.remove(&line) {
+                dropped += marks.len();
+            }
+        }
+        self.dropped_total += dropped as u64;
+        dropped
+    }
+
+    /// Remap every mark through an old→new identity mapping (reflow).
+    ///
+    /// Marks whose line maps to `None` are dropped (counted in
+    /// [`dropped_total`](Self::dropped_total)). Returns the dropped count.
+    pub fn remap(&mut self, mut map: impl FnMut(u64) -> Option<u64>) -> usize {
+        let old = std::mem::take(&mut self.marks);
+        let mut dropped = 0;
+        for (line, marks) in old {
+            match map(line) {
+                Some(new_line) => {
+                    self.marks.entry(new_line).or_default().extend(marks);
+                }
+                None => dropped += marks.len(),
+            }
+        }
+        self.dropped_total += dropped as u64;
+        dropped
+    }
+
+    /// Remap marks after a reflow by matching line content.
+    ///
+    /// `old_lines` snapshots the pre-reflow text of each marked line
+    /// (absolute identity → text). Each mark moves to the first
+    /// not-yet-claimed new line with identical text; marks whose content
+    /// no longer exists are dropped. Deterministic: old lines are
+    /// processed in ascending order and claim new lines in ascending
+    /// order.
+    pub fn remap_by_content(
+        &mut self,
+        old_lines: &BTreeMap<u64, String>,
+        vt: &VirtualTerminal,
+    ) -> usize {
+        // Index the new scrollback: text -> ascending absolute lines.
+        let mut index: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+        for idx in 0..vt.scrollback_len() {
+            if let (Some(abs), Some(text)) = (vt.absolute_index(idx), vt.scrollback_line(idx)) {
+                index.entry(text).or_default().push(abs);
+            }
+        }
+        let mut claimed: BTreeMap<String, usize> = BTreeMap::new();
+        self.remap(|old_line| {
+            let text = old_lines.get(&old_line)?;
+            let candidates = index.get(text)?;
+            let cursor = claimed.entry(text.clone()).or_insert(0);
+            let new_line = candidates.get(*cursor).copied();
+            if new_line.is_some() {
+                *cursor += 1;
+            }
+            new_line
+        })
+    }
+
+    /// Gutter hook: marks for the currently retained scrollback rows.
+    ///
+    /// Yields `(ring_index, mark)` pairs for rows in `rows` (ring
+    /// indices, e.g. the visible viewport) so an embedder can paint
+    /// indicators without dealing in absolute identities.
+    pub fn gutter_marks<'a>(
+        &'a self,
+        vt: &VirtualTerminal,
+        rows: Range<usize>,
+    ) -> Vec<(usize, &'a Mark)> {
+        let mut out = Vec::new();
+        for idx in rows {
+            let Some(abs) = vt.absolute_index(idx) else {
+                continue;
+            };
+            for mark in self.marks_at(abs) {
+                out.push((idx, mark));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(label: &str) -> Mark {
+        Mark {
+            kind: MarkKind::Bookmark,
+            label: label.to_string(),
+        }
+    }
+
+    fn error(label: &str) -> Mark {
+        Mark {
+            kind: MarkKind::Error,
+            label: label.to_string(),
+        }
+    }
+
+    /// Push `n` numbered lines through the screen into scrollback.
+    fn feed_lines(vt: &mut VirtualTerminal, start: usize, n: usize) {
+        for i in start..start + n {
+            vt.feed(format!("line-{i:04}\r\n").as_bytes());
+        }
+    }
+
+    #[test]
+    fn absolute_indices_survive_eviction() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.set_max_scrollback(10);
+        feed_lines(&mut vt, 0, 8);
+        // Lines 0..~5 are in scrollback now; mark one by absolute id.
+        let abs = vt.absolute_index(2).unwrap();
+        let text = vt.scrollback_line(2).unwrap();
+
+        // Heavy eviction: push far past the cap.
+        feed_lines(&mut vt, 8, 5);
+        assert!(vt.first_absolute_line() > 0 || vt.scrollback_len() <= 10);
+        // The absolute id still addresses the same content while retained.
+        if let Some(idx) = vt.scrollback_index_of(abs) {
+            assert_eq!(vt.scrollback_line(idx).unwrap(), text);
+        }
+
+        // Push until definitely evicted.
+        feed_lines(&mut vt, 13, 30);
+        assert_eq!(
+            vt.scrollback_index_of(abs),
+            None,
+            "evicted id resolves to None"
+        );
+        assert!(vt.first_absolute_line() > abs);
+    }
+
+    #[test]
+    fn marks_survive_heavy_eviction_with_notifications() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.set_max_scrollback(10);
+        feed_lines(&mut vt, 0, 6);
+        let mut marks = ScrollbackMarks::new();
+        let early = vt.absolute_index(0).unwrap();
+        let late = vt.absolute_index(vt.scrollback_len() - 1).unwrap();
+        marks.add_mark(early, bookmark("early"));
+        marks.add_mark(late, bookmark("late"));
+
+        // Evict the early line but keep the late one.
+        let target_first = early + 1;
+        while vt.first_absolute_line() <= target_first {
+            feed_lines(&mut vt, 100, 1);
+        }
+        let dropped = marks.sync(&vt);
+        assert_eq!(dropped, 1, "early mark dropped with notification");
+        assert_eq!(marks.dropped_total(), 1);
+        assert_eq!(marks.len(), 1);
+        assert!(
+            marks.marks_at(late).iter().any(|m| m.label == "late")
+                || vt.scrollback_index_of(late).is_none()
+        );
+    }
+
+    #[test]
+    fn next_prev_navigation_ordering() {
+        let mut marks = ScrollbackMarks::new();
+        marks.add_mark(10, bookmark("a"));
+        marks.add_mark(20, error("e1"));
+        marks.add_mark(30, bookmark("b"));
+
+        assert_eq!(marks.next_mark(0, None), Some(10));
+        assert_eq!(marks.next_mark(10, None), Some(20));
+        assert_eq!(marks.next_mark(10, Some(MarkKind::Bookmark)), Some(30));
+        assert_eq!(marks.next_mark(30, None), None);
+
+        assert_eq!(marks.prev_mark(31, None), Some(30));
+        assert_eq!(marks.prev_mark(30, None), Some(20));
+        assert_eq!(marks.prev_mark(30, Some(MarkKind::Bookmark)), Some(10));
+        assert_eq!(marks.prev_mark(10, None), None);
+
+        let in_range: Vec<u64> = marks.marks_in(10..30).map(|(l, _)| l).collect();
+        assert_eq!(in_range, vec![10, 20]);
+    }
+
+    #[test]
+    fn reflow_remaps_marks_to_new_identities() {
+        // "Reflow": the same logical content re-fed at a different width
+        // produces different wrapping, hence different line identities.
+        let mut vt = VirtualTerminal::new(40, 4);
+        vt.set_max_scrollback(100);
+        feed_lines(&mut vt, 0, 10);
+
+        let mut marks = ScrollbackMarks::new();
+        let marked_abs = vt.absolute_index(4).unwrap();
+        marks.add_mark(marked_abs, bookmark("here"));
+        let mut old_lines = BTreeMap::new();
+        old_lines.insert(marked_abs, vt.scrollback_line(4).unwrap());
+
+        // Reflow: new terminal, extra banner first so identities shift.
+        let mut reflowed = VirtualTerminal::new(20, 4);
+        reflowed.set_max_scrollback(100);
+        reflowed.feed(b"=== session restored ===\r\n");
+        feed_lines(&mut reflowed, 0, 10);
+
+        let dropped = marks.remap_by_content(&old_lines, &reflowed);
+        assert_eq!(dropped, 0);
+        assert_eq!(marks.len(), 1);
+        let (new_line, mark) = marks.marks_in(0..u64::MAX).next().unwrap();
+        assert_eq!(mark.label, "here");
+        let new_idx = reflowed.scrollback_index_of(new_line).unwrap();
+        assert_eq!(
+            reflowed.scrollback_line(new_idx).unwrap(),
+            old_lines[&marked_abs],
+            "mark follows its content"
+        );
+
+        // Content that vanished drops with a notification.
+        let mut gone = BTreeMap::new();
+        gone.insert(999u64, "no such line".to_string());
+        let mut lost = ScrollbackMarks::new();
+        lost.add_mark(999, bookmark("lost"));
+        assert_eq!(lost.remap_by_content(&gone, &reflowed), 1);
+        assert_eq!(lost.dropped_total(), 1);
+    }
+
+    #[test]
+    fn gutter_marks_map_to_ring_indices() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.set_max_scrollback(50);
+        feed_lines(&mut vt, 0, 6);
+        let mut marks = ScrollbackMarks::new();
+        let abs = vt.absolute_index(1).unwrap();
+        marks.add_mark(abs, error("boom"));
+
+        let gutter = marks.gutter_marks(&vt, 0..vt.scrollback_len());
+        assert_eq!(gutter.len(), 1);
+        assert_eq!(gutter[0].0, 1, "ring index for the gutter painter");
+        assert_eq!(gutter[0].1.label, "boom");
+    }
+
+    #[test]
+    fn navigation_edge_cases_do_not_panic() {
+        let mut marks = ScrollbackMarks::new();
+        marks.add_mark(5, bookmark("m"));
+        assert_eq!(marks.next_mark(u64::MAX, None), None);
+        assert_eq!(marks.prev_mark(0, None), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let inverted = 10..5;
+        assert_eq!(
+            marks.marks_in(inverted).count(),
+            0,
+            "inverted range is empty"
+        );
+    }
+}
@@ -0,0 +1,619 @@
+#![forbid(unsafe_code)]
+
+//! Selection gestures with xterm-style copy and output policies.
+//!
+//! Hosts embedding the virtual terminal drive a [`SelectionController`]
+//! from mouse events and new-output notifications. The
+//! [`SelectionPolicy`] captures terminal conventions:
+//!
+//! - `copy_on_commit`: `mouse_up` returns the extracted text immediately
+//!   so the host can push it to the primary selection / OSC 52.
+//! - `clear_on_output`: whether new output clears the selection — never,
+//!   only when the selected cells were actually overwritten, or always.
+//! - `scroll_anchoring`: the selection follows its content as output
+//!   scrolls the buffer, staying valid while the content remains in
+//!   scrollback.
+//!
+//! Positions are in *combined* coordinates: line 0 is the oldest retained
+//! scrollback line, and screen rows follow after `scrollback_len()`.
+//! Combined coordinates are stable under scrolling (content keeps its
+//! index); only scrollback front-trimming shifts them, which
+//! [`SelectionController::on_rows_scrolled`] compensates for.
+
+use crate::virtual_terminal::VirtualTerminal;
+
+/// What counts as a word character for double-click selection.
+#[derive(Debug, Clone)]
+pub struct WordBoundaryConfig {
+    /// Characters (beyond alphanumerics and `_`) that extend word runs —
+    /// the xterm `wordChars` idea, so `/var/log/syslog.1` or a UUID
+    /// selects as one word.
+    pub extra_word_chars: Vec<char>,
+    /// When the run around the click parses as a URL/path via the
+    /// implicit-link detector, select the whole link.
+    pub treat_url_as_word: bool,
+}
+
+impl Default for WordBoundaryConfig {
+    fn default() -> Self {
+        Self {
+            extra_word_chars: "-./_~?&=%+#:@".chars().collect(),
+            treat_url_as_word: false,
+        }
+    }
+}
+
+impl WordBoundaryConfig {
+    /// Plain prose words: alphanumerics and `_` only.
+    #[must_use]
+    pub fn plain() -> Self {
+        Self {
+            extra_word_chars: Vec::new(),
+            treat_url_as_word: false,
+        }
+    }
+
+    fn is_word_char(&self, ch: char) -> bool {
+        ch.is_alphanumeric() || ch == '_' || self.extra_word_chars.contains(&ch)
+    }
+}
+
+/// A cell position in combined scrollback+screen space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BufferPos {
+    /// Combined line index (0 = oldest scrollback line).
+    pub line: usize,
+    /// Column.
+    pub col: u16,
+}
+
+/// When new output clears an active selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClearPolicy {
+    /// Output never clears the selection.
+    Never,
+    /// Clear only when the selected cells were actually overwritten.
+    #[default]
+    WhenOverwritten,
+    /// Any new output clears the selection.
+    Always,
+}
+
+/// Policy hooks for selection behavior.
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionPolicy {
+    /// `mouse_up` returns the selected text for immediate copy.
+    pub copy_on_commit: bool,
+    /// How new output interacts with the selection.
+    pub clear_on_output: ClearPolicy,
+    /// Keep the selection attached to its content as the buffer scrolls.
+    pub scroll_anchoring: bool,
+}
+
+impl Default for SelectionPolicy {
+    fn default() -> Self {
+        Self {
+            copy_on_commit: true,
+            clear_on_output: ClearPolicy::WhenOverwritten,
+            scroll_anchoring: true,
+        }
+    }
+}
+
+/// Mouse-driven selection state over a virtual terminal.
+#[derive(Debug, Default)]
+pub struct SelectionController {
+    policy: SelectionPolicy,
+    anchor: Option<BufferPos>,
+    head: Option<BufferPos>,
+    dragging: bool,
+    word_config: WordBoundaryConfig,
+}
+
+impl SelectionController {
+    /// Create a controller with the given policy.
+    #[must_use]
+    pub fn new(policy: SelectionPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    /// Set the word-boundary configuration used by double-click selection.
+    #[must_use]
+    pub fn with_word_config(mut self, config: WordBoundaryConfig) -> Self {
+        self.word_config = config;
+        self
+    }
+
+    /// Double-click gesture: select the word (per the configured word
+    /// boundaries) at `pos`.
+    pub fn select_word_at(&mut self, vt: &VirtualTerminal, pos: BufferPos) {
+        if let Some((start, end)) = word_at_with(&self.word_config, vt, pos) {
+            self.anchor = Some(start);
+            self.head = Some(end);
+            self.dragging = false;
+        }
+    }
+
+    /// The active selection as a normalized (start, end) pair, inclusive.
+    #[must_use]
+    pub fn selection(&self) -> Option<(BufferPos, BufferPos)> {
+        let (anchor, head) = (self.anchor?, self.head?);
+        Some(if anchor <= head {
+            (anchor, head)
+        } else {
+            (head, anchor)
+        })
+    }
+
+    /// Whether a selection is active.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.selection().is_some()
+    }
+
+    /// Begin a selection gesture.
+    pub fn mouse_down(&mut self, pos: BufferPos) {
+        self.anchor = Some(pos);
+        self.head = Some(pos);
+        self.dragging = true;
+    }
+
+    /// Extend the selection while dragging.
+    pub fn mouse_drag(&mut self, pos: BufferPos) {
+        if self.dragging {
+            self.head = Some(pos);
+        }
+    }
+
+    /// Commit the gesture. With `copy_on_commit`, returns the selected
+    /// text so the host can publish it immediately.
+    pub fn mouse_up(&mut self, vt: &VirtualTerminal) -> Option<String> {
+        self.dragging = false;
+        if self.policy.copy_on_commit {
+            self.extract_copy(vt)
+        } else {
+            None
+        }
+    }
+
+    /// Clear the selection.
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.head = None;
+        self.dragging = false;
+    }
+
+    /// New output scrolled the buffer.
+    ///
+    /// `trimmed_front` is how many scrollback lines fell off the front
+    /// (combined indices shift down by that amount). With anchoring, the
+    /// selection keeps pointing at the same content; without, any scroll
+    /// invalidates it. `ClearPolicy::Always` clears regardless.
+    pub fn on_rows_scrolled(&mut self, scrolled: i32, trimmed_front: usize) {
+        if self.selection().is_none() || (scrolled == 0 && trimmed_front == 0) {
+            return;
+        }
+        if self.policy.clear_on_output == ClearPolicy::Always || !self.policy.scroll_anchoring {
+            self.clear();
+            return;
+        }
+        if trimmed_front > 0 {
+            let shift = |pos: &mut BufferPos| -> bool {
+                if pos.line < trimmed_front {
+                    return false; // content trimmed away
+                }
+                pos.line -= trimmed_front;
+                true
+            };
+            let ok_anchor = self.anchor.as_mut().map(shift);
+            let ok_head = self.head.as_mut().map(shift);
+            if ok_anchor != Some(true) || ok_head != Some(true) {
+                self.clear();
+            }
+        }
+        // Plain scrolling leaves combined coordinates stable: nothing to do.
+    }
+
+    /// Cells in the combined line range `[start_line, end_line]` changed.
+    ///
+    /// `WhenOverwritten` clears only on actual overlap with the selection;
+    /// `Always` clears on any output; `Never` keeps the selection.
+    pub fn on_region_changed(&mut self, start_line: usize, end_line: usize) {
+        let Some((sel_start, sel_end)) = self.selection() else {
+            return;
+        };
+        match self.policy.clear_on_output {
+            ClearPolicy::Never => {}
+            ClearPolicy::Always => self.clear(),
+            ClearPolicy::WhenOverwritten => {
+                let overlaps = start_line <= sel_end.line && end_line >= sel_start.line;
+                if overlaps {
+                    self.clear();
+                }
+            }
+        }
+    }
+
+    /// Extract the selected text (lines joined with `\n`, trailing spaces
+    /// trimmed per line), independent of the copy policy.
+    #[must_use]
+    pub fn extract_copy(&self, vt: &VirtualTerminal) -> Option<String> {
+        let (start, end) = self.selection()?;
+        let total = vt.scrollback_len() + usize::from(vt.height());
+        let mut out = String::new();
+        for line in start.line..=end.line.min(total.saturating_sub(1)) {
+            if line > start.line {
+                out.push('\n');
+            }
+            let text = combined_line_text(vt, line);
+            let chars: Vec<char> = text.chars().collect();
+            let from = if line == start.line {
+                usize::from(start.col)
+            } else {
+                0
+            };
+            let to = if line == end.line {
+                usize::from(end.col) + 1
+            } else {
+                chars.len()
+            };
+            let slice: String = chars
+                .get(from.min(chars.len())..to.min(chars.len()))
+                .unwrap_or(&[])
+                .iter()
+                .collect();
+            out.push_str(slice.trim_end());
+        }
+        Some(out)
+    }
+}
+
+/// Word extent at a position, using the fixed (plain prose) notion of
+/// word characters. Wrapper over [`word_at_with`].
+#[must_use]
+pub fn word_at(vt: &VirtualTerminal, pos: BufferPos) -> Option<(BufferPos, BufferPos)> {
+    word_at_with(&WordBoundaryConfig::plain(), vt, pos)
+}
+
+/// Word extent at a position under a [`WordBoundaryConfig`].
+///
+/// Returns the inclusive (start, end) of the word run. Extra word chars
+/// extend runs across punctuation; with `treat_url_as_word`, a run on a
+/// visible row that the implicit-link detector recognizes is selected
+/// wholesale. Wide characters respect cell boundaries: the extent covers
+/// the continuation cell of a trailing wide char.
+#[must_use]
+pub fn word_at_with(
+    config: &WordBoundaryConfig,
+    vt: &VirtualTerminal,
+    pos: BufferPos,
+) -> Option<(BufferPos, BufferPos)> {
+    let scrollback = vt.scrollback_len();
+
+    // URL/path mode only applies to visible rows (the link detector works
+    // on the grid).
+    if config.treat_url_as_word
+        && pos.line >= scrollback
+        && let Some(link) =
+            crate::link_detect::detect_link_at(vt, pos.col, (pos.line - scrollback) as u16)
+        && let (Some(first), Some(last)) = (link.segments.first(), link.segments.last())
+    {
+        return Some((
+            BufferPos {
+                line: scrollback + usize::from(first.row),
+                col: first.start_col,
+            },
+            BufferPos {
+                line: scrollback + usize::from(last.row),
+                col: last.end_col,
+            },
+        ));
+    }
+
+    // Column-aligned cells: continuation markers are kept so indices map
+    // one-to-one onto columns (row_text strips them).
+    let chars: Vec<char> = combined_line_cells(vt, pos.line);
+    let click = usize::from(pos.col).min(chars.len().saturating_sub(1));
+    // Continuation cells ('\0') belong to the wide char on their left.
+    let click = if chars.get(click) == Some(&'\0') {
+        click.saturating_sub(1)
+    } else {
+        click
+    };
+    let ch = *chars.get(click)?;
+    if !config.is_word_char(ch) {
+        return None;
+    }
+
+    let mut start = click;
+    while start > 0 {
+        let prev = chars[start - 1];
+        if prev == '\0' || config.is_word_char(prev) {
+            start -= 1;
+        } else {
+            break;
+        }
+    }
+    let mut end = click;
+    while end + 1 < chars.len() {
+        let next = chars[end + 1];
+        if next == '\0' || config.is_word_char(next) {
+            end += 1;
+        } else {
+            break;
+        }
+    }
+    // Trim a trailing continuation marker back onto its head... no: the
+    // extent must cover the wide char's second cell, so keep it.
+    Some((
+        BufferPos {
+            line: pos.line,
+            col: start as u16,
+        },
+        BufferPos {
+            line: pos.line,
+            col: end as u16,
+        },
+    ))
+}
+
+/// Column-aligned characters of a combined line (continuation cells kept
+/// as `'\0'` so indices equal columns).
+fn combined_line_cells(vt: &VirtualTerminal, line: usize) -> Vec<char> {
+    let scrollback = vt.scrollback_len();
+    let cells = if line < scrollback {
+        vt.scrollback_cells(line)
+    } else {
+        vt.row_cells((line - scrollback) as u16)
+    };
+    cells.map_or_else(Vec::new, |cells| cells.iter().map(|c| c.ch).collect())
+}
+
+/// Text of a combined line (scrollback first, then screen rows).
+fn combined_line_text(vt: &VirtualTerminal, line: usize) -> String {
+    let scrollback = vt.scrollback_len();
+    if line < scrollback {
+        vt.scrollback_line(line).unwrap_or_default()
+    } else {
+        vt.row_text((line - scrollback) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pos(line: usize, col: u16) -> BufferPos {
+        BufferPos { line, col }
+    }
+
+    fn vt_with(lines: &[&str], width: u16, height: u16) -> VirtualTerminal {
+        let mut vt = VirtualTerminal::new(width, height);
+        vt.set_max_scrollback(10_000);
+        vt.feed(lines.join("\r\n").as_bytes());
+        vt
+    }
+
+    #[test]
+    fn copy_on_commit_payload_matches_extract_copy() {
+        let vt = vt_with(&["hello world", "second line"], 20, 4);
+        let mut sel = SelectionController::new(SelectionPolicy::default());
+        sel.mouse_down(pos(0, 6));
+        sel.mouse_drag(pos(1, 5));
+        let copied = sel.mouse_up(&vt).expect("copy on commit");
+        assert_eq!(copied, sel.extract_copy(&vt).unwrap());
+        assert_eq!(copied, "world\nsecond");
+    }
+
+    #[test]
+    fn no_copy_when_policy_disabled() {
+        let vt = vt_with(&["abc"], 10, 2);
+        let mut sel = SelectionController::new(SelectionPolicy {
+            copy_on_commit: false,
+            ..SelectionPolicy::default()
+        });
+        sel.mouse_down(pos(0, 0));
+        sel.mouse_drag(pos(0, 2));
+        assert!(sel.mouse_up(&vt).is_none());
+        // The selection itself is still available for later extraction.
+        assert_eq!(sel.extract_copy(&vt).as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn anchored_selection_survives_100_lines_of_output() {
+        let mut vt = vt_with(&["target content"], 20, 4);
+        let mut sel = SelectionController::new(SelectionPolicy::default());
+        sel.mouse_down(pos(0, 0));
+        sel.mouse_drag(pos(0, 13));
+        let _ = sel.mouse_up(&vt);
+        assert_eq!(sel.extract_copy(&vt).as_deref(), Some("target content"));
+
+        // 100 lines of new output scroll the content into scrollback.
+        for i in 0..100 {
+            vt.feed(format!("\r\nline {i}").as_bytes());
+            sel.on_rows_scrolled(1, 0);
+        }
+        assert!(vt.scrollback_len() >= 97);
+        // Combined coordinates kept pointing at the same content.
+        assert_eq!(sel.extract_copy(&vt).as_deref(), Some("target content"));
+    }
+
+    #[test]
+    fn trimmed_scrollback_shifts_or_clears() {
+        let vt = vt_with(&["a", "b", "c"], 10, 3);
+        let mut sel = SelectionController::new(SelectionPolicy::default());
+        sel.mouse_down(pos(5, 0));
+        sel.mouse_drag(pos(5, 3));
+
+        // Five lines trimmed from the front: indices shift down.
+        sel.on_rows_scrolled(0, 5);
+        assert_eq!(sel.selection().unwrap().0, pos(0, 0));
+
+        // Trimming past the selection clears it.
+        sel.on_rows_scrolled(0, 10);
+        assert!(!sel.is_active());
+        let _ = vt;
+    }
+
+    #[test]
+    fn when_overwritten_clears_only_on_actual_overlap() {
+        let mut sel = SelectionController::new(SelectionPolicy::default());
+        sel.mouse_down(pos(5, 0));
+        sel.mouse_drag(pos(7, 4));
+
+        // Change elsewhere: selection survives.
+        sel.on_region_changed(10, 12);
+        assert!(sel.is_active());
+        sel.on_region_changed(0, 4);
+        assert!(sel.is_active());
+
+        // Overlapping change clears.
+        sel.on_region_changed(7, 9);
+        assert!(!sel.is_active());
+    }
+
+    #[test]
+    fn always_policy_clears_on_any_output() {
+        let mut sel = SelectionController::new(SelectionPolicy {
+            clear_on_output: ClearPolicy::Always,
+            ..SelectionPolicy::default()
+        });
+        sel.mouse_down(pos(2, 0));
+        sel.mouse_drag(pos(2, 5));
+        sel.on_region_changed(50, 60);
+        assert!(!sel.is_active(), "any output clears under Always");
+
+        sel.mouse_down(pos(2, 0));
+        sel.mouse_drag(pos(2, 5));
+        sel.on_rows_scrolled(1, 0);
+        assert!(!sel.is_active(), "scroll clears under Always");
+    }
+
+    #[test]
+    fn never_policy_keeps_selection() {
+        let mut sel = SelectionController::new(SelectionPolicy {
+            clear_on_output: ClearPolicy::Never,
+            ..SelectionPolicy::default()
+        });
+        sel.mouse_down(pos(1, 0));
+        sel.mouse_drag(pos(1, 3));
+        sel.on_region_changed(0, 100);
+        assert!(sel.is_active());
+    }
+
+    #[test]
+    fn unanchored_selection_clears_on_scroll() {
+        let mut sel = SelectionController::new(SelectionPolicy {
+            scroll_anchoring: false,
+            ..SelectionPolicy::default()
+        });
+        sel.mouse_down(pos(3, 0));
+        sel.mouse_drag(pos(3, 4));
+        sel.on_rows_scrolled(1, 0);
+        assert!(!sel.is_active());
+    }
+
+    // --- Word selection (double-click) ---
+
+    fn word_text(vt: &VirtualTerminal, span: Option<(BufferPos, BufferPos)>) -> String {
+        let (start, end) = span.expect("word found");
+        let mut sel = SelectionController::new(SelectionPolicy {
+            copy_on_commit: false,
+            ..SelectionPolicy::default()
+        });
+        sel.mouse_down(start);
+        sel.mouse_drag(end);
+        sel.extract_copy(vt).unwrap_or_default()
+    }
+
+    #[test]
+    fn double_click_goldens_for_paths_urls_uuids_and_emails() {
+        let vt = vt_with(
+            &[
+                "see /var/log/syslog.1 now",
+                "go https://ex.com/a?b=1&c=2 end",
+                "id 123e4567-e89b-12d3-a456-426614174000 ok",
+                "mail bob.smith@example.org please",
+            ],
+            60,
+            6,
+        );
+        let config = WordBoundaryConfig::default();
+
+        // Path: one word including slashes and the dotted suffix.
+        let span = word_at_with(&config, &vt, pos(0, 8));
+        assert_eq!(word_text(&vt, span), "/var/log/syslog.1");
+
+        // URL with query string.
+        let span = word_at_with(&config, &vt, pos(1, 10));
+        assert_eq!(word_text(&vt, span), "https://ex.com/a?b=1&c=2");
+
+        // UUID (dashes join the groups).
+        let span = word_at_with(&config, &vt, pos(2, 10));
+        assert_eq!(word_text(&vt, span), "123e4567-e89b-12d3-a456-426614174000");
+
+        // Email address.
+        let span = word_at_with(&config, &vt, pos(3, 9));
+        assert_eq!(word_text(&vt, span), "bob.smith@example.org");
+    }
+
+    #[test]
+    fn plain_prose_selection_unchanged_with_plain_config() {
+        let vt = vt_with(&["hello world, end."], 30, 3);
+        // word_at keeps the fixed notion: letters only.
+        let span = word_at(&vt, pos(0, 2));
+        assert_eq!(word_text(&vt, span), "hello");
+        let span = word_at(&vt, pos(0, 8));
+        assert_eq!(word_text(&vt, span), "world");
+        // Clicking punctuation selects nothing under the plain config.
+        assert!(word_at(&vt, pos(0, 11)).is_none());
+    }
+
+    #[test]
+    fn treat_url_as_word_selects_whole_link() {
+        let vt = vt_with(&["x (https://a.b/c). y"], 40, 3);
+        let config = WordBoundaryConfig {
+            treat_url_as_word: true,
+            ..WordBoundaryConfig::default()
+        };
+        // The link detector strips the wrapping parens/punctuation.
+        let span = word_at_with(&config, &vt, pos(0, 6));
+        assert_eq!(word_text(&vt, span), "https://a.b/c");
+    }
+
+    #[test]
+    fn wide_chars_respect_cell_boundaries() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.feed("ab宽cd".as_bytes());
+        let config = WordBoundaryConfig::default();
+        // The wide char occupies cols 2-3; clicking its continuation cell
+        // still selects the whole run, extent covering both cells.
+        let (start, end) = word_at_with(&config, &vt, pos(0, 3)).expect("word");
+        assert_eq!(start.col, 0);
+        assert_eq!(end.col, 5);
+    }
+
+    #[test]
+    fn controller_word_gesture_uses_config() {
+        let vt = vt_with(&["run ./a-b_c.sh now"], 30, 3);
+        let mut sel = SelectionController::new(SelectionPolicy {
+            copy_on_commit: true,
+            ..SelectionPolicy::default()
+        })
+        .with_word_config(WordBoundaryConfig::default());
+        sel.select_word_at(&vt, pos(0, 6));
+        assert_eq!(sel.extract_copy(&vt).as_deref(), Some("./a-b_c.sh"));
+    }
+
+    #[test]
+    fn reversed_drag_normalizes() {
+        let vt = vt_with(&["abcdef"], 10, 2);
+        let mut sel = SelectionController::new(SelectionPolicy::default());
+        sel.mouse_down(pos(0, 4));
+        sel.mouse_drag(pos(0, 1));
+        assert_eq!(sel.mouse_up(&vt).as_deref(), Some("bcde"));
+    }
+}
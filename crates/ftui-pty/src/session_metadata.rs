@@ -0,0 +1,333 @@
+#![forbid(unsafe_code)]
+
+//! Session metadata from shell integration escape sequences.
+//!
+//! Beyond OSC 133 prompt blocks, shells and ssh wrappers report *where*
+//! output came from: OSC 7 carries the working directory as a `file://`
+//! URL, iTerm2's OSC 1337 carries `RemoteHost=user@host` and arbitrary
+//! `SetUserVar=name=<base64>` variables. [`SessionMetadata`] stores each
+//! field as a change history keyed by the scrollback's *absolute* line
+//! identities ([`VirtualTerminal::absolute_index`]), so "what directory
+//! was I in when this line was printed" is answerable via
+//! [`SessionMetadata::metadata_at_line`] even while the ring evicts.
+//!
+//! Histories are bounded by a configurable entry count; trimming drops
+//! the oldest changes (lookups older than the oldest retained change
+//! return `None` for that field) but never the current value.
+//!
+//! [`VirtualTerminal::absolute_index`]: crate::virtual_terminal::VirtualTerminal::absolute_index
+
+use std::collections::BTreeMap;
+
+/// Default per-field history bound.
+const DEFAULT_MAX_ENTRIES: usize = 256;
+
+/// One field's change history: `(absolute line, value)` in append order.
+type History = Vec<(u64, String)>;
+
+/// Everything known at one scrollback line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetadataSnapshot {
+    /// Working directory (OSC 7), percent-decoded.
+    pub cwd: Option<String>,
+    /// Hostname from OSC 7 or `RemoteHost`.
+    pub hostname: Option<String>,
+    /// Username from `RemoteHost`.
+    pub username: Option<String>,
+    /// User variables (`SetUserVar`), decoded.
+    pub user_vars: BTreeMap<String, String>,
+}
+
+/// Change-tracked session metadata keyed by absolute scrollback lines.
+#[derive(Debug, Clone)]
+pub struct SessionMetadata {
+    cwd: History,
+    hostname: History,
+    username: History,
+    user_vars: BTreeMap<String, History>,
+    /// Per-field history bound.
+    max_entries: usize,
+}
+
+impl Default for SessionMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionMetadata {
+    /// Empty store with the default history bound.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_max_entries(DEFAULT_MAX_ENTRIES)
+    }
+
+    /// Empty store bounding each field's history to `max_entries`.
+    #[must_use]
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        Self {
+            cwd: Vec::new(),
+            hostname: Vec::new(),
+            username: Vec::new(),
+            user_vars: BTreeMap::new(),
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    /// The configured per-field history bound.
+    #[must_use]
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    // ── Recording ──────────────────────────────────────────────────
+
+    /// Record a working-directory change at `abs_line`.
+    pub fn record_cwd(&mut self, abs_line: u64, cwd: String) {
+        Self::push(&mut self.cwd, abs_line, cwd, self.max_entries);
+    }
+
+    /// Record a hostname change at `abs_line`.
+    pub fn record_hostname(&mut self, abs_line: u64, hostname: String) {
+        Self::push(&mut self.hostname, abs_line, hostname, self.max_entries);
+    }
+
+    /// Record a username change at `abs_line`.
+    pub fn record_username(&mut self, abs_line: u64, username: String) {
+        Self::push(&mut self.username, abs_line, username, self.max_entries);
+    }
+
+    /// Record a user-variable change at `abs_line`. Later writes to the
+    /// same name overwrite the current value (the history keeps both).
+    pub fn record_user_var(&mut self, abs_line: u64, name: &str, value: String) {
+        // Bound the variable namespace itself so a hostile stream cannot
+        // grow the map without limit.
+        if !self.user_vars.contains_key(name) && self.user_vars.len() >= self.max_entries {
+            return;
+        }
+        let history = self.user_vars.entry(name.to_string()).or_default();
+        Self::push(history, abs_line, value, self.max_entries);
+    }
+
+    fn push(history: &mut History, abs_line: u64, value: String, max: usize) {
+        // Histories must stay sorted for the binary search in `at`, but
+        // reports are stamped with the cursor row, which can move *up*
+        // between reports. A report at or before the last change line
+        // overwrites that change in place (also covering several reports
+        // between newlines).
+        if let Some(last) = history.last_mut()
+            && abs_line <= last.0
+        {
+            last.1 = value;
+            return;
+        }
+        history.push((abs_line, value));
+        if history.len() > max {
+            let excess = history.len() - max;
+            history.drain(..excess);
+        }
+    }
+
+    // ── Current values ─────────────────────────────────────────────
+
+    /// The current working directory, if reported.
+    #[must_use]
+    pub fn current_cwd(&self) -> Option<&str> {
+        self.cwd.last().map(|(_, v)| v.as_str())
+    }
+
+    /// The current hostname, if reported.
+    #[must_use]
+    pub fn current_hostname(&self) -> Option<&str> {
+        self.hostname.last().map(|(_, v)| v.as_str())
+    }
+
+    /// The current username, if reported.
+    #[must_use]
+    pub fn current_username(&self) -> Option<&str> {
+        self.username.last().map(|(_, v)| v.as_str())
+    }
+
+    /// The current value of a user variable.
+    #[must_use]
+    pub fn user_var(&self, name: &str) -> Option<&str> {
+        self.user_vars.get(name)?.last().map(|(_, v)| v.as_str())
+    }
+
+    /// Current values of all user variables.
+    pub fn user_vars(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.user_vars.iter().filter_map(|(name, history)| {
+            history
+                .last()
+                .map(|(_, value)| (name.as_str(), value.as_str()))
+        })
+    }
+
+    // ── Line-correlated lookup ─────────────────────────────────────
+
+    /// Everything known at the time `abs_line` was printed: the latest
+    /// change at or before that line, per field. Fields whose history
+    /// starts after the line (or was trimmed past it) are `None`/absent.
+    #[must_use]
+    pub fn metadata_at_line(&self, abs_line: u64) -> MetadataSnapshot {
+        MetadataSnapshot {
+            cwd: Self::at(&self.cwd, abs_line).map(str::to_string),
+            hostname: Self::at(&self.hostname, abs_line).map(str::to_string),
+            username: Self::at(&self.username, abs_line).map(str::to_string),
+            user_vars: self
+                .user_vars
+                .iter()
+                .filter_map(|(name, history)| {
+                    Self::at(history, abs_line).map(|v| (name.clone(), v.to_string()))
+                })
+                .collect(),
+        }
+    }
+
+    /// Latest value at or before `abs_line` (histories are sorted by
+    /// construction: absolute lines only grow).
+    fn at(history: &History, abs_line: u64) -> Option<&str> {
+        let idx = history.partition_point(|&(line, _)| line <= abs_line);
+        idx.checked_sub(1).map(|i| history[i].1.as_str())
+    }
+}
+
+/// Decode percent-encoding (`%20` → space). Malformed escapes are kept
+/// verbatim; the result is lossy-decoded UTF-8, so hostile bytes cannot
+/// panic the parser.
+#[must_use]
+pub fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let (Some(hi), Some(lo)) = (
+                bytes.get(i + 1).copied().and_then(hex_digit),
+                bytes.get(i + 2).copied().and_then(hex_digit),
+            )
+        {
+            out.push((hi << 4) | lo);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_values_track_latest_change() {
+        let mut meta = SessionMetadata::new();
+        meta.record_cwd(0, "/home".into());
+        meta.record_cwd(10, "/tmp".into());
+        meta.record_hostname(0, "box".into());
+        assert_eq!(meta.current_cwd(), Some("/tmp"));
+        assert_eq!(meta.current_hostname(), Some("box"));
+        assert_eq!(meta.current_username(), None);
+    }
+
+    #[test]
+    fn line_correlated_lookup_across_changes() {
+        let mut meta = SessionMetadata::new();
+        meta.record_cwd(5, "/a".into());
+        meta.record_cwd(20, "/b".into());
+        meta.record_user_var(12, "git_branch", "main".into());
+
+        assert_eq!(meta.metadata_at_line(4).cwd, None, "before first report");
+        assert_eq!(meta.metadata_at_line(5).cwd.as_deref(), Some("/a"));
+        assert_eq!(meta.metadata_at_line(19).cwd.as_deref(), Some("/a"));
+        let at_25 = meta.metadata_at_line(25);
+        assert_eq!(at_25.cwd.as_deref(), Some("/b"));
+        assert_eq!(
+            at_25.user_vars.get("git_branch").map(String::as_str),
+            Some("main")
+        );
+        assert!(meta.metadata_at_line(11).user_vars.is_empty());
+    }
+
+    #[test]
+    fn user_var_overwrite_keeps_history() {
+        let mut meta = SessionMetadata::new();
+        meta.record_user_var(1, "status", "building".into());
+        meta.record_user_var(9, "status", "done".into());
+        assert_eq!(meta.user_var("status"), Some("done"));
+        assert_eq!(
+            meta.metadata_at_line(5)
+                .user_vars
+                .get("status")
+                .map(String::as_str),
+            Some("building")
+        );
+    }
+
+    #[test]
+    fn history_is_bounded_and_keeps_current_value() {
+        let mut meta = SessionMetadata::with_max_entries(4);
+        for i in 0..20u64 {
+            meta.record_cwd(i * 2, format!("/dir{i}"));
+        }
+        assert_eq!(meta.current_cwd(), Some("/dir19"));
+        // Old entries were trimmed: a lookup before the retained window
+        // honestly reports "unknown".
+        assert_eq!(meta.metadata_at_line(0).cwd, None);
+        assert_eq!(meta.metadata_at_line(38).cwd.as_deref(), Some("/dir19"));
+    }
+
+    #[test]
+    fn same_line_reports_overwrite_in_place() {
+        let mut meta = SessionMetadata::with_max_entries(2);
+        meta.record_cwd(7, "/first".into());
+        meta.record_cwd(7, "/second".into());
+        assert_eq!(meta.current_cwd(), Some("/second"));
+        assert_eq!(meta.metadata_at_line(7).cwd.as_deref(), Some("/second"));
+    }
+
+    #[test]
+    fn user_var_namespace_is_bounded() {
+        let mut meta = SessionMetadata::with_max_entries(3);
+        for i in 0..10 {
+            meta.record_user_var(i, &format!("var{i}"), "v".into());
+        }
+        assert_eq!(meta.user_vars().count(), 3);
+        // Existing vars keep accepting updates.
+        meta.record_user_var(50, "var0", "updated".into());
+        assert_eq!(meta.user_var("var0"), Some("updated"));
+    }
+
+    #[test]
+    fn out_of_order_report_lines_keep_history_sorted() {
+        let mut meta = SessionMetadata::new();
+        meta.record_cwd(10, "/a".into());
+        // Cursor moved up before the next report: earlier absolute line.
+        meta.record_cwd(8, "/b".into());
+        assert_eq!(meta.current_cwd(), Some("/b"));
+        assert_eq!(meta.metadata_at_line(10).cwd.as_deref(), Some("/b"));
+        meta.record_cwd(30, "/c".into());
+        assert_eq!(meta.metadata_at_line(29).cwd.as_deref(), Some("/b"));
+        assert_eq!(meta.metadata_at_line(30).cwd.as_deref(), Some("/c"));
+    }
+
+    #[test]
+    fn percent_decode_handles_malformed_escapes() {
+        assert_eq!(percent_decode("/with%20space"), "/with space");
+        assert_eq!(percent_decode("%e4%b8%ad"), "中");
+        assert_eq!(percent_decode("bad%2"), "bad%2");
+        assert_eq!(percent_decode("bad%zz"), "bad%zz");
+        assert_eq!(percent_decode("%"), "%");
+    }
+}
@@ -111,6 +111,61 @@ fn translate_charset(ch: char, designator: u8) -> char {
     }
 }
 
+/// Extract pixel dimensions from sixel raster attributes (`"Pan;Pad;Ph;Pv`).
+///
+/// Returns `None` when the payload carries no raster attributes or they are
+/// malformed.
+fn parse_sixel_raster_size(payload: &[u8]) -> Option<(u32, u32)> {
+    let start = payload.iter().position(|&b| b == b'"')? + 1;
+    let rest = &payload[start..];
+    let end = rest
+        .iter()
+        .position(|&b| !b.is_ascii_digit() && b != b';')
+        .unwrap_or(rest.len());
+    let attrs = std::str::from_utf8(&rest[..end]).ok()?;
+    let mut parts = attrs.split(';');
+    let _pan = parts.next()?;
+    let _pad = parts.next()?;
+    let ph: u32 = parts.next()?.parse().ok()?;
+    let pv: u32 = parts.next()?.parse().ok()?;
+    if ph == 0 || pv == 0 {
+        return None;
+    }
+    Some((ph, pv))
+}
+
+/// Best-effort base64 decode of short text values (iTerm2 `name=`).
+///
+/// Returns `None` for anything that doesn't decode to valid UTF-8.
+fn decode_base64_text(value: &str) -> Option<String> {
+    fn sextet(b: u8) -> Option<u32> {
+        match b {
+            b'A'..=b'Z' => Some(u32::from(b - b'A')),
+            b'a'..=b'z' => Some(u32::from(b - b'a') + 26),
+            b'0'..=b'9' => Some(u32::from(b - b'0') + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let input: Vec<u8> = value.bytes().filter(|&b| b != b'=').collect();
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    for chunk in input.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut acc: u32 = 0;
+        for &b in chunk {
+            acc = (acc << 6) | sextet(b)?;
+        }
+        acc <<= 6 * (4 - chunk.len()) as u32;
+        let bytes = acc.to_be_bytes();
+        out.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+    String::from_utf8(out).ok()
+}
+
 /// RGB color value.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Color {
@@ -147,18 +202,384 @@ impl CellStyle {
     }
 }
 
+/// Cursor shape requested via DECSCUSR (`CSI Ps SP q`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorShape {
+    /// Full-cell block (DECSCUSR 0/1/2; the default).
+    #[default]
+    Block,
+    /// Underline (DECSCUSR 3/4).
+    Underline,
+    /// Vertical bar (DECSCUSR 5/6).
+    Bar,
+}
+
+/// Full cursor presentation state for embedders rendering their own
+/// cursor ([`VirtualTerminal::cursor_state`]).
+///
+/// Shape, blink and visibility are global terminal state (xterm
+/// semantics): DECSC/DECRC and the alt-screen save/restore variants
+/// carry the cursor *position* (and SGR where applicable) but leave
+/// presentation untouched, exactly like real terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorState {
+    /// Cursor row (0-indexed).
+    pub row: u16,
+    /// Cursor column (0-indexed).
+    pub col: u16,
+    /// DECTCEM visibility (mode 25).
+    pub visible: bool,
+    /// DECSCUSR shape.
+    pub shape: CursorShape,
+    /// DECSCUSR blink preference.
+    pub blink: bool,
+}
+
+/// DEC line attribute of one screen row (DECDWL/DECDHL, `ESC # 3/4/5/6`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VLineAttr {
+    /// Single-width, single-height (DECSWL).
+    #[default]
+    Normal,
+    /// Top half of a double-height line (`ESC # 3`).
+    DoubleHeightTop,
+    /// Bottom half of a double-height line (`ESC # 4`).
+    DoubleHeightBottom,
+    /// Double-width line (`ESC # 6`).
+    DoubleWidth,
+}
+
+/// Default per-cell combining-mark cap (zalgo-bomb guard).
+pub const DEFAULT_MAX_COMBINING_PER_CELL: usize = 8;
+/// Upper bound on distinct OSC 8 hyperlink URIs kept per terminal.
+pub const MAX_LINK_URIS: usize = 4096;
+
 /// A single cell in the virtual terminal grid.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VCell {
     pub ch: char,
+    /// Combining marks attached to `ch`, in arrival order. Bounded by
+    /// [`VirtualTerminal::set_max_combining_per_cell`]; marks never add
+    /// width. Empty for the overwhelming majority of cells (no heap
+    /// allocation until a mark arrives).
+    pub combining: Vec<char>,
     pub style: CellStyle,
+    /// Image placeholder: id of the inline image occupying this cell
+    /// (see [`ImageRegistry`]). `None` for ordinary text cells.
+    pub image: Option<u32>,
+    /// Explicit OSC 8 hyperlink id ([`VirtualTerminal::link_uri`]).
+    pub link: Option<u32>,
+    /// The cursor moved from this cell via HT (tab origin) — lets export
+    /// re-infer tabs from the space run that follows.
+    pub tab_origin: bool,
 }
 
 impl Default for VCell {
     fn default() -> Self {
         Self {
             ch: ' ',
+            combining: Vec::new(),
             style: CellStyle::default(),
+            image: None,
+            link: None,
+            tab_origin: false,
+        }
+    }
+}
+
+/// Drop counters from per-cell growth limits, for doctor diagnostics
+/// ([`VirtualTerminal::grid_limit_stats`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GridLimitStats {
+    /// Combining marks dropped by the per-cell cap (zalgo-bomb guard).
+    pub combining_marks_dropped: u64,
+    /// OSC 8 hyperlinks dropped by the URI-table cap.
+    pub hyperlinks_dropped: u64,
+}
+
+/// Encoding of an inline image payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Sixel graphics (DCS ... q payload).
+    Sixel,
+    /// iTerm2 inline image (OSC 1337 File=...:base64).
+    Iterm2,
+}
+
+/// Metadata and raw payload for one inline image.
+///
+/// Full decoding/rendering is out of scope for the virtual terminal; the
+/// record carries enough for a renderer to draw a placeholder box (name,
+/// payload size, occupied cell rectangle) or for an embedder to decode the
+/// payload itself.
+#[derive(Debug, Clone)]
+pub struct ImageRecord {
+    /// Registry id, also stamped on occupied cells via [`VCell::image`].
+    pub id: u32,
+    /// Payload encoding.
+    pub format: ImageFormat,
+    /// File name (iTerm2 `name=` parameter, base64-decoded best effort).
+    pub name: Option<String>,
+    /// Raw escape payload (sixel data or undecoded base64 content).
+    pub payload: Vec<u8>,
+    /// Occupied rectangle origin (column, row) at placement time.
+    pub origin: (u16, u16),
+    /// Occupied width in cells.
+    pub cols: u16,
+    /// Occupied height in cells.
+    pub rows: u16,
+    /// Declared pixel dimensions, when the sequence carried them.
+    pub pixel_size: Option<(u32, u32)>,
+}
+
+/// Registry of inline images seen by the terminal, keyed by id.
+#[derive(Debug, Default)]
+pub struct ImageRegistry {
+    next_id: u32,
+    images: Vec<ImageRecord>,
+}
+
+impl ImageRegistry {
+    /// Look up an image by id.
+    #[must_use]
+    pub fn get(&self, id: u32) -> Option<&ImageRecord> {
+        self.images.iter().find(|img| img.id == id)
+    }
+
+    /// Number of registered images.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    /// True when no images have been registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Iterate over registered images in arrival order.
+    pub fn iter(&self) -> impl Iterator<Item = &ImageRecord> {
+        self.images.iter()
+    }
+
+    fn register(&mut self, mut record: ImageRecord) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        record.id = id;
+        self.images.push(record);
+        id
+    }
+}
+
+/// Assumed cell metrics (pixels) for converting declared image pixel sizes
+/// into an occupied cell rectangle when the host gives no better information.
+const ASSUMED_CELL_PX_WIDTH: u32 = 10;
+const ASSUMED_CELL_PX_HEIGHT: u32 = 20;
+
+// ---------------------------------------------------------------------------
+// Damage journal
+// ---------------------------------------------------------------------------
+
+/// Bitset of dirty columns within one row.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DirtyCols {
+    words: Vec<u64>,
+}
+
+impl DirtyCols {
+    fn new(width: u16) -> Self {
+        Self {
+            words: vec![0; usize::from(width).div_ceil(64)],
+        }
+    }
+
+    fn set(&mut self, col: u16) {
+        let idx = usize::from(col);
+        if let Some(word) = self.words.get_mut(idx / 64) {
+            *word |= 1 << (idx % 64);
+        }
+    }
+
+    fn set_all(&mut self) {
+        self.words.fill(u64::MAX);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (dst, src) in self.words.iter_mut().zip(&other.words) {
+            *dst |= src;
+        }
+    }
+
+    /// Whether the given column is marked dirty.
+    #[must_use]
+    pub fn is_set(&self, col: u16) -> bool {
+        let idx = usize::from(col);
+        self.words
+            .get(idx / 64)
+            .is_some_and(|word| word & (1 << (idx % 64)) != 0)
+    }
+
+    /// Iterate over dirty column indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| (w * 64 + bit) as u16)
+        })
+    }
+}
+
+/// Per-consumer acknowledgment state for [`VirtualTerminal::take_damage`].
+///
+/// Each consumer holds its own cursor; taking damage through one cursor
+/// never clears what another cursor still has to see.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DamageCursor {
+    seq: u64,
+}
+
+/// Changes since a cursor's previous [`VirtualTerminal::take_damage`] call.
+#[derive(Debug, Clone)]
+pub struct DamageReport {
+    /// Everything changed (resize, alt-screen switch, journal overflow, or
+    /// a brand-new consumer): repaint from the grid, ignore `rows`.
+    pub full_invalidate: bool,
+    /// Dirty rows with per-column bitsets, in final (current) coordinates.
+    /// May be a superset of the actual changes, never a subset.
+    pub rows: Vec<(u16, DirtyCols)>,
+    /// Net full-screen scroll since the last take (positive = content moved
+    /// up). Consumers blit by this amount before applying `rows`.
+    pub scrolled_lines: i32,
+}
+
+/// Per-row damage entry: columns dirtied and the sequence of the latest hit.
+#[derive(Debug, Clone)]
+struct RowDamage {
+    seq: u64,
+    cols: DirtyCols,
+}
+
+/// Grid-side damage bookkeeping.
+///
+/// Memory is bounded by the grid (one bitset per dirty row) plus a capped
+/// scroll log; consumers carry only a sequence number. Row bitsets
+/// accumulate until a full invalidation, so late consumers see supersets.
+#[derive(Debug, Default)]
+struct DamageJournal {
+    /// Monotonic mutation sequence.
+    seq: u64,
+    /// Cursors at or below this sequence get `full_invalidate`.
+    full_seq: u64,
+    /// Dirty rows (current coordinates).
+    rows: std::collections::HashMap<u16, RowDamage>,
+    /// Full-screen scrolls, oldest first. Bounded by `SCROLL_LOG_CAP`.
+    scrolls: VecDeque<(u64, i32)>,
+}
+
+/// Cap on remembered scroll events before degrading to full invalidation.
+const SCROLL_LOG_CAP: usize = 128;
+
+impl DamageJournal {
+    fn bump(&mut self) -> u64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    fn mark_cell(&mut self, row: u16, col: u16, width: u16) {
+        let seq = self.bump();
+        let entry = self.rows.entry(row).or_insert_with(|| RowDamage {
+            seq,
+            cols: DirtyCols::new(width),
+        });
+        entry.seq = seq;
+        entry.cols.set(col);
+    }
+
+    fn mark_row(&mut self, row: u16, width: u16) {
+        let seq = self.bump();
+        let entry = self.rows.entry(row).or_insert_with(|| RowDamage {
+            seq,
+            cols: DirtyCols::new(width),
+        });
+        entry.seq = seq;
+        entry.cols.set_all();
+    }
+
+    fn mark_rows(&mut self, rows: std::ops::RangeInclusive<u16>, width: u16) {
+        for row in rows {
+            self.mark_row(row, width);
+        }
+    }
+
+    fn full(&mut self) {
+        let seq = self.bump();
+        self.full_seq = seq;
+        self.rows.clear();
+        self.scrolls.clear();
+    }
+
+    /// Record a full-screen scroll: translate stored rows to their new
+    /// positions, dropping rows that scrolled out of the grid.
+    fn scroll(&mut self, n: i32, height: u16) {
+        if self.scrolls.len() >= SCROLL_LOG_CAP {
+            self.full();
+            return;
+        }
+        let seq = self.bump();
+        let translated: std::collections::HashMap<u16, RowDamage> = self
+            .rows
+            .drain()
+            .filter_map(|(row, damage)| {
+                let shifted = i32::from(row) - n;
+                (0..i32::from(height))
+                    .contains(&shifted)
+                    .then_some((shifted as u16, damage))
+            })
+            .collect();
+        self.rows = translated;
+        // Merge with an immediately preceding scroll entry.
+        if let Some(last) = self.scrolls.back_mut()
+            && last.0 == seq - 1
+        {
+            last.0 = seq;
+            last.1 += n;
+        } else {
+            self.scrolls.push_back((seq, n));
+        }
+    }
+
+    fn take(&mut self, cursor: &mut DamageCursor, width: u16) -> DamageReport {
+        let since = cursor.seq;
+        cursor.seq = self.seq;
+        if since < self.full_seq {
+            return DamageReport {
+                full_invalidate: true,
+                rows: Vec::new(),
+                scrolled_lines: 0,
+            };
+        }
+        let scrolled_lines = self
+            .scrolls
+            .iter()
+            .filter(|(seq, _)| *seq > since)
+            .map(|(_, n)| n)
+            .sum();
+        let mut rows: Vec<(u16, DirtyCols)> = self
+            .rows
+            .iter()
+            .filter(|(_, damage)| damage.seq > since)
+            .map(|(&row, damage)| {
+                let mut cols = DirtyCols::new(width);
+                cols.merge(&damage.cols);
+                (row, cols)
+            })
+            .collect();
+        rows.sort_by_key(|(row, _)| *row);
+        DamageReport {
+            full_invalidate: false,
+            rows,
+            scrolled_lines,
         }
     }
 }
@@ -172,6 +593,14 @@ enum ParseState {
     EscapeCharset(u8),
     Csi,
     Osc,
+    /// OSC saw ESC: expect `\` (ST). Anything else is reprocessed in Ground.
+    OscEsc,
+    /// DCS header: parameter/intermediate bytes up to the final byte.
+    Dcs,
+    /// DCS payload: consumed until ST, stored only for recognized sequences.
+    DcsPassthrough,
+    /// DCS payload saw ESC: expect `\` (ST).
+    DcsEsc,
 }
 
 /// Terminal quirks that can be simulated by the virtual terminal.
@@ -269,6 +698,9 @@ impl QuirkSet {
     }
 }
 
+/// Injected clock for per-line scrollback timestamps (epoch ms).
+pub type TimestampClock = Box<dyn Fn() -> u64 + Send>;
+
 /// In-memory virtual terminal with cursor tracking and ANSI interpretation.
 ///
 /// # Example
@@ -289,9 +721,25 @@ pub struct VirtualTerminal {
     cursor_x: u16,
     cursor_y: u16,
     cursor_visible: bool,
+    /// DECSCUSR shape (global, not part of DECSC state).
+    cursor_shape: CursorShape,
+    /// DECSCUSR blink preference (global, not part of DECSC state).
+    cursor_blink: bool,
+    /// Bumped whenever visibility, shape or blink changes.
+    cursor_generation: u64,
+    /// Per-row DEC line attributes (indexed by screen row).
+    line_attrs: Vec<VLineAttr>,
     current_style: CellStyle,
     scrollback: VecDeque<Vec<VCell>>,
     max_scrollback: usize,
+    /// Per-line timestamps parallel to `scrollback` (opt-in; empty when
+    /// timestamping is off). One `u64` of epoch milliseconds per line.
+    line_timestamps: VecDeque<u64>,
+    /// Injected clock for line timestamps (`None` = timestamps off).
+    timestamp_clock: Option<TimestampClock>,
+    /// Total scrollback lines evicted from the front; gives every line a
+    /// stable absolute identity: `absolute = evicted_lines + ring_index`.
+    evicted_lines: u64,
     // Saved cursor position (DEC save/restore)
     saved_cursor: Option<(u16, u16)>,
     // Scroll region (top, bottom) — 0-indexed, inclusive
@@ -302,12 +750,49 @@ pub struct VirtualTerminal {
     csi_params: Vec<u16>,
     csi_intermediate: Vec<u8>,
     osc_data: Vec<u8>,
+    /// DCS header bytes (params + intermediates, before the final byte).
+    dcs_header: Vec<u8>,
+    /// DCS final byte (`q` = sixel); 0 while still in the header.
+    dcs_final: u8,
+    /// DCS payload bytes (kept only for recognized sequences).
+    dcs_data: Vec<u8>,
+    /// Inline images registered from Sixel / iTerm2 sequences.
+    images: ImageRegistry,
+    /// Damage journal for embedder consumers ([`Self::take_damage`]).
+    damage: DamageJournal,
+    /// OSC 8 hyperlink URIs, indexed by [`VCell::link`] id. Bounded by
+    /// [`MAX_LINK_URIS`]; further distinct URIs are dropped (counted).
+    link_uris: Vec<String>,
+    /// Per-cell combining-mark cap (zalgo-bomb guard).
+    max_combining_per_cell: usize,
+    /// Compose base+mark to NFC when possible (storage + export hygiene).
+    nfc_normalize: bool,
+    /// Drop counters from per-cell growth limits.
+    limit_stats: GridLimitStats,
+    /// Hyperlink id applied to subsequently printed cells.
+    current_link: Option<u32>,
+    /// Mouse reporting state requested by the hosted application.
+    mouse_protocol: crate::mouse_passthrough::MouseProtocol,
+    /// Input-encoding modes requested by the hosted application
+    /// (bracketed paste, modifyOtherKeys, kitty flags).
+    input_encoding: crate::input_forwarding::InputEncodingState,
+    /// Saved kitty flag values (`CSI > flags u` pushes, `CSI < n u` pops).
+    kitty_flag_stack: Vec<u8>,
     // Modes
     alternate_screen: bool,
+    /// The inactive screen buffer: the saved primary grid while the alt
+    /// screen is active, and the persisted alt grid otherwise (mode 47
+    /// re-entry shows previous alt content, per xterm).
     alternate_grid: Option<Vec<VCell>>,
     alternate_cursor: Option<(u16, u16)>,
+    /// SGR state saved on 1049 entry, restored on exit.
+    alternate_style: Option<CellStyle>,
+    /// Cursor + SGR saved by DECSET 1048.
+    saved_cursor_sgr: Option<(u16, u16, CellStyle)>,
     // Title
     title: String,
+    /// Session metadata (cwd/host/user vars) from OSC 7 / OSC 1337.
+    metadata: crate::session_metadata::SessionMetadata,
     quirks: QuirkSet,
     /// DECOM (DEC private mode 6): origin mode — cursor addressing relative
     /// to scroll region.
@@ -363,9 +848,16 @@ impl VirtualTerminal {
             cursor_x: 0,
             cursor_y: 0,
             cursor_visible: true,
+            cursor_shape: CursorShape::default(),
+            cursor_blink: true,
+            cursor_generation: 0,
+            line_attrs: vec![VLineAttr::Normal; usize::from(height)],
             current_style: CellStyle::default(),
             scrollback: VecDeque::new(),
+            line_timestamps: VecDeque::new(),
+            timestamp_clock: None,
             max_scrollback: 1000,
+            evicted_lines: 0,
             saved_cursor: None,
             scroll_top: 0,
             scroll_bottom: height.saturating_sub(1),
@@ -373,10 +865,30 @@ impl VirtualTerminal {
             csi_params: Vec::new(),
             csi_intermediate: Vec::new(),
             osc_data: Vec::new(),
+            dcs_header: Vec::new(),
+            dcs_final: 0,
+            dcs_data: Vec::new(),
+            images: ImageRegistry::default(),
+            damage: {
+                let mut journal = DamageJournal::default();
+                journal.full(); // a new consumer starts with a full repaint
+                journal
+            },
+            link_uris: Vec::new(),
+            max_combining_per_cell: DEFAULT_MAX_COMBINING_PER_CELL,
+            nfc_normalize: true,
+            limit_stats: GridLimitStats::default(),
+            current_link: None,
+            mouse_protocol: crate::mouse_passthrough::MouseProtocol::default(),
+            input_encoding: crate::input_forwarding::InputEncodingState::default(),
+            kitty_flag_stack: Vec::new(),
             alternate_screen: false,
             alternate_grid: None,
             alternate_cursor: None,
+            alternate_style: None,
+            saved_cursor_sgr: None,
             title: String::new(),
+            metadata: crate::session_metadata::SessionMetadata::new(),
             quirks,
             origin_mode: false,
             last_char: None,
@@ -423,6 +935,76 @@ impl VirtualTerminal {
         self.cursor_visible
     }
 
+    /// Current DECSCUSR cursor shape.
+    #[must_use]
+    pub const fn cursor_shape(&self) -> CursorShape {
+        self.cursor_shape
+    }
+
+    /// Current DECSCUSR blink preference.
+    #[must_use]
+    pub const fn cursor_blink(&self) -> bool {
+        self.cursor_blink
+    }
+
+    /// DEC line attribute of screen row `y`.
+    #[must_use]
+    pub fn line_attr(&self, y: u16) -> VLineAttr {
+        self.line_attrs
+            .get(usize::from(y))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Full cursor presentation state for embedders.
+    #[must_use]
+    pub const fn cursor_state(&self) -> CursorState {
+        CursorState {
+            row: self.cursor_y,
+            col: self.cursor_x,
+            visible: self.cursor_visible,
+            shape: self.cursor_shape,
+            blink: self.cursor_blink,
+        }
+    }
+
+    /// Generation counter for cursor *presentation* (visibility, shape,
+    /// blink). Bumps exactly when one of those changes, so embedders can
+    /// skip recomputing cursor styling on unchanged frames; position is
+    /// read cheaply via [`VirtualTerminal::cursor`].
+    #[must_use]
+    pub const fn cursor_generation(&self) -> u64 {
+        self.cursor_generation
+    }
+
+    /// Set visibility, bumping the generation only on an actual change.
+    fn set_cursor_visible_tracked(&mut self, visible: bool) {
+        if self.cursor_visible != visible {
+            self.cursor_visible = visible;
+            self.cursor_generation += 1;
+        }
+    }
+
+    /// Apply a DECSCUSR parameter (0-6), bumping generation on change.
+    fn apply_decscusr(&mut self, param: u16) {
+        let (shape, blink) = match param {
+            // 0 is "default": blinking block on real terminals.
+            0 | 1 => (CursorShape::Block, true),
+            2 => (CursorShape::Block, false),
+            3 => (CursorShape::Underline, true),
+            4 => (CursorShape::Underline, false),
+            5 => (CursorShape::Bar, true),
+            6 => (CursorShape::Bar, false),
+            // Out-of-spec parameters are ignored.
+            _ => return,
+        };
+        if self.cursor_shape != shape || self.cursor_blink != blink {
+            self.cursor_shape = shape;
+            self.cursor_blink = blink;
+            self.cursor_generation += 1;
+        }
+    }
+
     /// Whether alternate screen mode is active.
     #[must_use]
     pub const fn is_alternate_screen(&self) -> bool {
@@ -435,6 +1017,19 @@ impl VirtualTerminal {
         &self.title
     }
 
+    /// Session metadata extracted from OSC 7 / OSC 1337 reports.
+    #[must_use]
+    pub fn metadata(&self) -> &crate::session_metadata::SessionMetadata {
+        &self.metadata
+    }
+
+    /// The current working directory reported by the shell (OSC 7), for
+    /// status bars and tab titles.
+    #[must_use]
+    pub fn current_cwd(&self) -> Option<&str> {
+        self.metadata.current_cwd()
+    }
+
     /// Active quirk set.
     #[must_use]
     pub const fn quirks(&self) -> QuirkSet {
@@ -452,14 +1047,110 @@ impl VirtualTerminal {
         self.scrollback.len()
     }
 
+    /// Stable absolute identity of scrollback ring index `idx`.
+    ///
+    /// Absolute indices count every line ever pushed to scrollback and
+    /// never shift when the front is evicted.
+    #[must_use]
+    pub fn absolute_index(&self, idx: usize) -> Option<u64> {
+        (idx < self.scrollback.len()).then(|| self.evicted_lines + idx as u64)
+    }
+
+    /// Ring index of an absolute line, or `None` when evicted/future.
+    #[must_use]
+    pub fn scrollback_index_of(&self, absolute: u64) -> Option<usize> {
+        if absolute < self.evicted_lines {
+            return None;
+        }
+        let idx = absolute - self.evicted_lines;
+        (idx < self.scrollback.len() as u64).then_some(idx as usize)
+    }
+
+    /// Absolute identity of the oldest retained scrollback line (equal
+    /// to the number of lines evicted so far).
+    #[must_use]
+    pub fn first_absolute_line(&self) -> u64 {
+        self.evicted_lines
+    }
+
+    /// One past the newest scrollback line's absolute identity.
+    #[must_use]
+    pub fn end_absolute_line(&self) -> u64 {
+        self.evicted_lines + self.scrollback.len() as u64
+    }
+
     /// Set the maximum scrollback lines.
     pub fn set_max_scrollback(&mut self, max: usize) {
         self.max_scrollback = max;
         while self.scrollback.len() > self.max_scrollback {
+            self.evicted_lines += 1;
             self.scrollback.pop_front();
+            self.line_timestamps.pop_front();
         }
     }
 
+    /// Enable per-line timestamps using an injected clock (epoch
+    /// milliseconds; inject a fixed/stepped closure for deterministic
+    /// Lab tests). Lines already in scrollback are backfilled with the
+    /// enable-time reading so the stores stay parallel; lines pushed from
+    /// now on record their own push time. Memory cost is one `u64` per
+    /// line, evicted together with its line.
+    pub fn enable_line_timestamps(&mut self, clock: TimestampClock) {
+        // Backfill placeholders so the deques stay parallel: pre-existing
+        // lines report the enable time.
+        let now = clock();
+        while self.line_timestamps.len() < self.scrollback.len() {
+            self.line_timestamps.push_back(now);
+        }
+        self.timestamp_clock = Some(clock);
+    }
+
+    /// Whether line timestamps are being recorded.
+    #[must_use]
+    pub fn timestamps_enabled(&self) -> bool {
+        self.timestamp_clock.is_some()
+    }
+
+    /// Timestamp (epoch ms) of scrollback line `idx`, when recorded.
+    #[must_use]
+    pub fn line_timestamp(&self, idx: usize) -> Option<u64> {
+        self.line_timestamps.get(idx).copied()
+    }
+
+    /// The current clock reading, when timestamping is enabled. Grid
+    /// viewport rows (not yet scrolled into scrollback) are treated as
+    /// "now" for time-range purposes.
+    #[must_use]
+    pub fn timestamp_now(&self) -> Option<u64> {
+        self.timestamp_clock.as_ref().map(|clock| clock())
+    }
+
+    /// Map a closed time range `[from, to]` (epoch ms) to a line range
+    /// over scrollback + screen (`start..end` for the export options).
+    ///
+    /// Binary search over the monotonically non-decreasing timestamps;
+    /// viewport rows count as "now", so a range extending to or past the
+    /// current clock includes them. Returns an empty range when the
+    /// window misses all content (or timestamps are off).
+    #[must_use]
+    pub fn time_range_to_lines(&self, from: u64, to: u64) -> (usize, usize) {
+        let Some(now) = self.timestamp_now() else {
+            return (0, 0);
+        };
+        if to < from || from > now {
+            return (0, 0);
+        }
+        let start = self.line_timestamps.partition_point(|&ts| ts < from);
+        let end = if to >= now {
+            // Screen rows are "now": the range reaches the present, so
+            // the not-yet-scrolled viewport is included.
+            self.scrollback.len() + usize::from(self.height)
+        } else {
+            self.line_timestamps.partition_point(|&ts| ts <= to)
+        };
+        (start, end.max(start))
+    }
+
     // ── Cell Access ─────────────────────────────────────────────────
 
     /// Get the character at (x, y). Returns `None` if out of bounds.
@@ -484,6 +1175,162 @@ impl VirtualTerminal {
         }
     }
 
+    /// Registry of inline images seen so far (sixel / iTerm2).
+    #[must_use]
+    pub fn images(&self) -> &ImageRegistry {
+        &self.images
+    }
+
+    /// Id of the inline image occupying the cell at (x, y), if any.
+    #[must_use]
+    pub fn image_at(&self, x: u16, y: u16) -> Option<u32> {
+        self.cell_at(x, y).and_then(|cell| cell.image)
+    }
+
+    /// URI for an OSC 8 hyperlink id stamped on cells via [`VCell::link`].
+    #[must_use]
+    pub fn link_uri(&self, id: u32) -> Option<&str> {
+        self.link_uris.get(id as usize).map(String::as_str)
+    }
+
+    /// Cells of a scrollback line (oldest first), for export.
+    #[must_use]
+    pub fn scrollback_cells(&self, idx: usize) -> Option<&[VCell]> {
+        self.scrollback.get(idx).map(Vec::as_slice)
+    }
+
+    /// Cells of a visible row, for export.
+    #[must_use]
+    pub fn row_cells(&self, y: u16) -> Option<&[VCell]> {
+        if y >= self.height {
+            return None;
+        }
+        let start = self.idx(0, y);
+        Some(&self.grid[start..start + usize::from(self.width)])
+    }
+
+    /// The next tab stop strictly after `col` (or the last column).
+    #[must_use]
+    pub fn next_tab_stop(&self, col: u16) -> u16 {
+        let mut c = col + 1;
+        while c < self.width {
+            if self.tab_stops[usize::from(c)] {
+                return c;
+            }
+            c += 1;
+        }
+        self.width.saturating_sub(1)
+    }
+
+    /// Whether the alternate screen is active (embedders should suspend
+    /// primary-buffer selections while it is).
+    #[must_use]
+    pub fn is_alt_screen(&self) -> bool {
+        self.alternate_screen
+    }
+
+    /// The mouse reporting state requested by the hosted application
+    /// (DECSET 1000/1002/1003 tracking, 1005/1006 encoding).
+    #[must_use]
+    pub fn mouse_protocol(&self) -> crate::mouse_passthrough::MouseProtocol {
+        self.mouse_protocol
+    }
+
+    /// Drop counters from per-cell growth limits (combining-mark cap,
+    /// hyperlink table cap) for doctor/session diagnostics.
+    #[must_use]
+    pub fn grid_limit_stats(&self) -> GridLimitStats {
+        self.limit_stats
+    }
+
+    /// Set the per-cell combining-mark cap (minimum 1; default
+    /// [`DEFAULT_MAX_COMBINING_PER_CELL`]).
+    pub fn set_max_combining_per_cell(&mut self, cap: usize) {
+        self.max_combining_per_cell = cap.max(1);
+    }
+
+    /// Enable or disable NFC composition of base+mark pairs (default on).
+    pub fn set_nfc_normalization(&mut self, enabled: bool) {
+        self.nfc_normalize = enabled;
+    }
+
+    /// The input-encoding modes requested by the hosted application
+    /// (DECSET 2004 bracketed paste, `CSI > 4 ; n m` modifyOtherKeys,
+    /// kitty keyboard flags). Feed this to
+    /// [`encode_key_for_child`](crate::input_forwarding::encode_key_for_child)
+    /// and
+    /// [`encode_paste_for_child`](crate::input_forwarding::encode_paste_for_child).
+    #[must_use]
+    pub fn input_encoding(&self) -> crate::input_forwarding::InputEncodingState {
+        self.input_encoding
+    }
+
+    /// Obtain an acknowledgment cursor for [`Self::take_damage`].
+    ///
+    /// A fresh cursor's first take reports `full_invalidate`.
+    #[must_use]
+    pub fn damage_cursor(&self) -> DamageCursor {
+        DamageCursor::default()
+    }
+
+    /// Report changes since this cursor's previous call.
+    ///
+    /// Multiple consumers each hold their own cursor; one consumer's take
+    /// never clears another's pending view. Reported rows may be supersets
+    /// of the actual changes, never subsets.
+    pub fn take_damage(&mut self, cursor: &mut DamageCursor) -> DamageReport {
+        self.damage.take(cursor, self.width)
+    }
+
+    /// Resize the terminal grid, preserving top-left content.
+    ///
+    /// Resets the scroll region, clamps the cursor, and produces a full
+    /// damage invalidation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if width or height is 0.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        assert!(width > 0 && height > 0, "terminal dimensions must be > 0");
+        if width == self.width && height == self.height {
+            return;
+        }
+        let mut grid = vec![VCell::default(); usize::from(width) * usize::from(height)];
+        for y in 0..self.height.min(height) {
+            for x in 0..self.width.min(width) {
+                let src = usize::from(y) * usize::from(self.width) + usize::from(x);
+                let dst = usize::from(y) * usize::from(width) + usize::from(x);
+                grid[dst] = self.grid[src].clone();
+            }
+        }
+        self.grid = grid;
+        let mut attrs = vec![VLineAttr::Normal; usize::from(height)];
+        let keep = usize::from(self.height.min(height));
+        attrs[..keep].copy_from_slice(&self.line_attrs[..keep]);
+        self.line_attrs = attrs;
+        // Resize the inactive screen buffer too: returning from the alt
+        // screen must land on a primary grid at the *new* size.
+        if let Some(other) = self.alternate_grid.take() {
+            let mut resized = vec![VCell::default(); usize::from(width) * usize::from(height)];
+            for y in 0..self.height.min(height) {
+                for x in 0..self.width.min(width) {
+                    let src = usize::from(y) * usize::from(self.width) + usize::from(x);
+                    let dst = usize::from(y) * usize::from(width) + usize::from(x);
+                    resized[dst] = other[src].clone();
+                }
+            }
+            self.alternate_grid = Some(resized);
+        }
+        self.width = width;
+        self.height = height;
+        self.scroll_top = 0;
+        self.scroll_bottom = height - 1;
+        self.cursor_x = self.cursor_x.min(width);
+        self.cursor_y = self.cursor_y.min(height - 1);
+        self.tab_stops = Self::default_tab_stops(width);
+        self.damage.full();
+    }
+
     /// Get the text content of a row (trailing spaces trimmed).
     #[must_use]
     pub fn row_text(&self, y: u16) -> String {
@@ -492,11 +1339,14 @@ impl VirtualTerminal {
         }
         let start = self.idx(0, y);
         let end = start + usize::from(self.width);
-        let s: String = self.grid[start..end]
-            .iter()
-            .filter(|c| c.ch != WIDE_CONTINUATION)
-            .map(|c| c.ch)
-            .collect();
+        let mut s = String::with_capacity(usize::from(self.width));
+        for cell in &self.grid[start..end] {
+            if cell.ch == WIDE_CONTINUATION {
+                continue;
+            }
+            s.push(cell.ch);
+            s.extend(cell.combining.iter());
+        }
         s.trim_end().to_string()
     }
 
@@ -607,6 +1457,7 @@ impl VirtualTerminal {
         for cell in &mut self.grid {
             *cell = blank.clone();
         }
+        self.damage.full();
     }
 
     /// Clear the scrollback buffer.
@@ -614,7 +1465,9 @@ impl VirtualTerminal {
     /// This removes all lines that have scrolled off the visible area.
     /// The visible display is not affected.
     pub fn clear_scrollback(&mut self) {
+        self.evicted_lines += self.scrollback.len() as u64;
         self.scrollback.clear();
+        self.line_timestamps.clear();
     }
 
     // ── Query Responses ─────────────────────────────────────────────
@@ -647,9 +1500,66 @@ impl VirtualTerminal {
             ParseState::EscapeCharset(slot) => self.escape_charset(slot, byte),
             ParseState::Csi => self.csi(byte),
             ParseState::Osc => self.osc(byte),
+            ParseState::OscEsc => {
+                self.dispatch_osc();
+                self.parse_state = ParseState::Ground;
+                if byte != b'\\' {
+                    // Not ST: reprocess in Ground so no byte is lost.
+                    self.process_byte(byte);
+                }
+            }
+            ParseState::Dcs => self.dcs_header(byte),
+            ParseState::DcsPassthrough => self.dcs_passthrough(byte),
+            ParseState::DcsEsc => {
+                if byte == b'\\' {
+                    self.dispatch_dcs();
+                } else {
+                    // Not ST: the DCS is malformed; discard it and
+                    // reprocess the byte in Ground.
+                    self.dcs_data.clear();
+                    self.parse_state = ParseState::Ground;
+                    self.process_byte(byte);
+                    return;
+                }
+                self.parse_state = ParseState::Ground;
+            }
         }
     }
 
+    /// Collect DCS header bytes until the final byte selects the handler.
+    fn dcs_header(&mut self, byte: u8) {
+        match byte {
+            0x1b => self.parse_state = ParseState::DcsEsc,
+            0x40..=0x7e => {
+                self.dcs_final = byte;
+                self.parse_state = ParseState::DcsPassthrough;
+            }
+            _ => self.dcs_header.push(byte),
+        }
+    }
+
+    /// Consume DCS payload bytes. Only sixel (`q`) payloads are retained;
+    /// everything else is swallowed so it never reaches the grid as text.
+    fn dcs_passthrough(&mut self, byte: u8) {
+        match byte {
+            0x1b => self.parse_state = ParseState::DcsEsc,
+            _ => {
+                if self.dcs_final == b'q' {
+                    self.dcs_data.push(byte);
+                }
+            }
+        }
+    }
+
+    /// Handle a completed DCS sequence.
+    fn dispatch_dcs(&mut self) {
+        if self.dcs_final == b'q' {
+            let payload = std::mem::take(&mut self.dcs_data);
+            self.place_sixel_image(payload);
+        }
+        self.dcs_data.clear();
+    }
+
     fn ground(&mut self, byte: u8) {
         match byte {
             0x1b => {
@@ -667,7 +1577,12 @@ impl VirtualTerminal {
                 self.cursor_x = self.cursor_x.saturating_sub(1);
             }
             b'\t' => {
-                // Tab: advance to next tab stop
+                // Tab: advance to next tab stop, flagging the origin cell
+                // so export can re-infer the tab (`TabPolicy::Preserve`).
+                if self.cursor_x < self.width && self.cursor_y < self.height {
+                    let idx = self.idx(self.cursor_x.min(self.width - 1), self.cursor_y);
+                    self.grid[idx].tab_origin = true;
+                }
                 let max_col = self.width.saturating_sub(1);
                 let mut col = self.cursor_x + 1;
                 while col < self.width {
@@ -747,6 +1662,13 @@ impl VirtualTerminal {
                 self.parse_state = ParseState::Osc;
                 self.osc_data.clear();
             }
+            b'P' => {
+                // DCS — device control string (sixel, etc.)
+                self.parse_state = ParseState::Dcs;
+                self.dcs_header.clear();
+                self.dcs_final = 0;
+                self.dcs_data.clear();
+            }
             b'7' => {
                 // DEC save cursor
                 if !(self.quirks.tmux_nested_cursor && self.alternate_screen) {
@@ -833,16 +1755,42 @@ impl VirtualTerminal {
     }
 
     fn escape_hash(&mut self, byte: u8) {
+        match byte {
+            // DECDHL top/bottom, DECSWL, DECDWL: per-line attributes.
+            b'3' | b'4' | b'5' | b'6' => {
+                let attr = match byte {
+                    b'3' => VLineAttr::DoubleHeightTop,
+                    b'4' => VLineAttr::DoubleHeightBottom,
+                    b'6' => VLineAttr::DoubleWidth,
+                    _ => VLineAttr::Normal,
+                };
+                let y = usize::from(self.cursor_y);
+                if let Some(slot) = self.line_attrs.get_mut(y)
+                    && *slot != attr
+                {
+                    *slot = attr;
+                    self.damage.mark_row(self.cursor_y, self.width);
+                }
+                self.parse_state = ParseState::Ground;
+                return;
+            }
+            _ => {}
+        }
         if byte == b'8' {
             // DECALN: fill entire screen with 'E', reset scroll region, cursor to origin.
             for cell in self.grid.iter_mut() {
                 *cell = VCell {
                     ch: 'E',
+                    combining: Vec::new(),
                     style: CellStyle::default(),
+                    image: None,
+                    link: None,
+                    tab_origin: false,
                 };
             }
             self.scroll_top = 0;
             self.scroll_bottom = self.height.saturating_sub(1);
+            self.damage.full();
             self.cursor_x = 0;
             self.cursor_y = 0;
         }
@@ -872,12 +1820,13 @@ impl VirtualTerminal {
                 }
                 self.csi_params.push(0);
             }
-            b'?' | b'>' | b'!' | b' ' => {
+            b'?' | b'>' | b'!' | b' ' | b'<' | b'=' => {
                 self.csi_intermediate.push(byte);
             }
             0x40..=0x7e => {
                 // Final byte — dispatch
                 self.dispatch_csi(byte);
+                self.mark_csi_damage(byte);
                 self.parse_state = ParseState::Ground;
             }
             _ => {
@@ -895,9 +1844,9 @@ impl VirtualTerminal {
                 self.parse_state = ParseState::Ground;
             }
             0x1b => {
-                // Could be ST (\x1b\\) — simplified: just end OSC
-                self.dispatch_osc();
-                self.parse_state = ParseState::Ground;
+                // Likely ST (\x1b\\); resolved in OscEsc so the trailing
+                // backslash is not echoed to the grid.
+                self.parse_state = ParseState::OscEsc;
             }
             _ => {
                 self.osc_data.push(byte);
@@ -905,6 +1854,29 @@ impl VirtualTerminal {
         }
     }
 
+    /// Conservative damage marking for editing CSI sequences.
+    ///
+    /// Runs after dispatch, so the cursor and scroll region reflect the
+    /// sequence's effects. Pure cursor-movement finals mark nothing; edits
+    /// mark whole rows (supersets are fine, subsets are not). Scrolls
+    /// (S/T) are journaled inside `scroll_up`/`scroll_down`.
+    fn mark_csi_damage(&mut self, final_byte: u8) {
+        match final_byte {
+            // In-row edits: ICH, DCH, ECH, REP, EL.
+            b'@' | b'P' | b'X' | b'b' | b'K' => {
+                self.damage.mark_row(self.cursor_y, self.width);
+            }
+            // Line insert/delete shifts the rest of the scroll region.
+            b'L' | b'M' => {
+                let top = self.cursor_y.min(self.scroll_bottom);
+                self.damage.mark_rows(top..=self.scroll_bottom, self.width);
+            }
+            // Erase display (any mode): treat as full damage.
+            b'J' => self.damage.full(),
+            _ => {}
+        }
+    }
+
     fn dispatch_csi(&mut self, final_byte: u8) {
         let params = &self.csi_params;
         let has_question = self.csi_intermediate.contains(&b'?');
@@ -1039,10 +2011,42 @@ impl VirtualTerminal {
                     self.cursor_y = row.min(self.height.saturating_sub(1));
                 }
             }
+            b'm' if self.csi_intermediate.contains(&b'>') => {
+                // xterm modifyOtherKeys: CSI > 4 ; n m sets the level,
+                // CSI > 4 m (or CSI > m) resets it.
+                let resource = Self::param(params, 0, 0);
+                if resource == 4 || params.is_empty() {
+                    let level = Self::param(params, 1, 0).min(2) as u8;
+                    self.input_encoding.modify_other_keys = level;
+                }
+            }
             b'm' => {
                 // SGR
                 self.dispatch_sgr();
             }
+            b'u' if self.csi_intermediate.contains(&b'>') => {
+                // Kitty keyboard: push current flags, activate new ones.
+                let flags = Self::param(params, 0, 1) as u8;
+                self.kitty_flag_stack.push(self.input_encoding.kitty_flags);
+                self.input_encoding.kitty_flags = flags;
+            }
+            b'u' if self.csi_intermediate.contains(&b'<') => {
+                // Kitty keyboard: pop n entries, restoring saved flags.
+                let n = Self::param(params, 0, 1).max(1);
+                for _ in 0..n {
+                    self.input_encoding.kitty_flags = self.kitty_flag_stack.pop().unwrap_or(0);
+                }
+            }
+            b'u' if self.csi_intermediate.contains(&b'=') => {
+                // Kitty keyboard: set flags in place (mode 1 = assign,
+                // 2 = set bits, 3 = clear bits).
+                let flags = Self::param(params, 0, 0) as u8;
+                match Self::param(params, 1, 1) {
+                    2 => self.input_encoding.kitty_flags |= flags,
+                    3 => self.input_encoding.kitty_flags &= !flags,
+                    _ => self.input_encoding.kitty_flags = flags,
+                }
+            }
             b'n' => {
                 // Device Status Report (we track but don't auto-respond)
                 // Response generated via cpr_response()
@@ -1174,10 +2178,14 @@ impl VirtualTerminal {
                     _ => {}
                 }
             }
+            b'q' if self.csi_intermediate.contains(&b' ') => {
+                // DECSCUSR — set cursor style (CSI Ps SP q).
+                self.apply_decscusr(Self::param(params, 0, 0));
+            }
             b'p' if self.csi_intermediate.contains(&b'!') => {
                 // Soft Reset (DECSTR) — CSI ! p
                 self.current_style = CellStyle::default();
-                self.cursor_visible = true;
+                self.set_cursor_visible_tracked(true);
                 self.origin_mode = false;
                 self.scroll_top = 0;
                 self.scroll_bottom = self.height.saturating_sub(1);
@@ -1290,8 +2298,254 @@ impl VirtualTerminal {
         let data = String::from_utf8_lossy(&self.osc_data).to_string();
         if let Some(rest) = data.strip_prefix("0;").or_else(|| data.strip_prefix("2;")) {
             self.title = rest.to_string();
+        } else if let Some(rest) = data.strip_prefix("7;") {
+            self.handle_osc7_cwd(rest);
+        } else if let Some(rest) = data.strip_prefix("1337;File=") {
+            self.place_iterm2_image(rest);
+        } else if let Some(rest) = data.strip_prefix("1337;") {
+            self.handle_iterm2_metadata(rest);
+        } else if let Some(rest) = data.strip_prefix("8;") {
+            // OSC 8 hyperlink: "8;params;URI" — empty URI ends the link.
+            let uri = rest.split_once(';').map_or(rest, |(_, uri)| uri);
+            self.current_link = if uri.is_empty() {
+                None
+            } else {
+                self.intern_link_uri(uri)
+            };
+        }
+    }
+
+    /// Intern a hyperlink URI, reusing an existing id when possible.
+    ///
+    /// The table is bounded by [`MAX_LINK_URIS`]; further distinct URIs
+    /// are dropped (counted in
+    /// [`grid_limit_stats`](Self::grid_limit_stats)) so a hostile
+    /// program cannot grow memory one OSC 8 at a time.
+    fn intern_link_uri(&mut self, uri: &str) -> Option<u32> {
+        if let Some(idx) = self.link_uris.iter().position(|u| u == uri) {
+            return Some(idx as u32);
+        }
+        if self.link_uris.len() >= MAX_LINK_URIS {
+            self.limit_stats.hyperlinks_dropped += 1;
+            return None;
+        }
+        self.link_uris.push(uri.to_string());
+        Some((self.link_uris.len() - 1) as u32)
+    }
+
+    /// Absolute line identity of the cursor row (screen rows follow the
+    /// scrollback ring).
+    fn cursor_absolute_line(&self) -> u64 {
+        self.end_absolute_line() + u64::from(self.cursor_y)
+    }
+
+    /// OSC 7 working-directory report: `file://host/path` with
+    /// percent-encoding. Malformed URLs are ignored.
+    fn handle_osc7_cwd(&mut self, rest: &str) {
+        let Some(url) = rest.strip_prefix("file://") else {
+            return;
+        };
+        let abs_line = self.cursor_absolute_line();
+        let (host, path) = match url.split_once('/') {
+            Some((host, path)) => (host, format!("/{path}")),
+            // `file://host` with no path component: host only.
+            None => (url, String::new()),
+        };
+        if !host.is_empty() {
+            self.metadata
+                .record_hostname(abs_line, crate::session_metadata::percent_decode(host));
+        }
+        if !path.is_empty() {
+            self.metadata
+                .record_cwd(abs_line, crate::session_metadata::percent_decode(&path));
+        }
+    }
+
+    /// iTerm2 OSC 1337 metadata: `RemoteHost=user@host` and
+    /// `SetUserVar=name=<base64>`. Unknown or malformed payloads are
+    /// ignored (never a parse failure).
+    fn handle_iterm2_metadata(&mut self, rest: &str) {
+        let abs_line = self.cursor_absolute_line();
+        if let Some(value) = rest.strip_prefix("RemoteHost=") {
+            match value.split_once('@') {
+                Some((user, host)) => {
+                    if !user.is_empty() {
+                        self.metadata.record_username(abs_line, user.to_string());
+                    }
+                    if !host.is_empty() {
+                        self.metadata.record_hostname(abs_line, host.to_string());
+                    }
+                }
+                None if !value.is_empty() => {
+                    self.metadata.record_hostname(abs_line, value.to_string());
+                }
+                None => {}
+            }
+        } else if let Some(assignment) = rest.strip_prefix("SetUserVar=")
+            && let Some((name, encoded)) = assignment.split_once('=')
+            && !name.is_empty()
+            && let Some(decoded) = decode_base64_text(encoded)
+        {
+            self.metadata.record_user_var(abs_line, name, decoded);
+        }
+    }
+
+    /// Register a sixel image and stamp its placeholder rectangle.
+    ///
+    /// Pixel dimensions come from the sixel raster attributes
+    /// (`"Pan;Pad;Ph;Pv`) when present; the occupied cell rectangle is
+    /// derived via assumed cell metrics and clamped to at least 1x1.
+    fn place_sixel_image(&mut self, payload: Vec<u8>) {
+        let pixel_size = parse_sixel_raster_size(&payload);
+        let (cols, rows) = match pixel_size {
+            Some((px_w, px_h)) => (
+                px_w.div_ceil(ASSUMED_CELL_PX_WIDTH).max(1),
+                px_h.div_ceil(ASSUMED_CELL_PX_HEIGHT).max(1),
+            ),
+            None => (1, 1),
+        };
+        let record = ImageRecord {
+            id: 0, // assigned by the registry
+            format: ImageFormat::Sixel,
+            name: None,
+            payload,
+            origin: (
+                self.cursor_x.min(self.width.saturating_sub(1)),
+                self.cursor_y,
+            ),
+            cols: cols.min(u32::from(self.width)) as u16,
+            rows: rows.min(u32::from(self.height)) as u16,
+            pixel_size,
+        };
+        self.place_image(record);
+    }
+
+    /// Register an iTerm2 inline image (`OSC 1337 File=params:base64`).
+    ///
+    /// Only `inline=1` transfers occupy the grid; file downloads without
+    /// `inline` are still registered (payload retained) but place no cells.
+    fn place_iterm2_image(&mut self, rest: &str) {
+        let (params, payload) = match rest.split_once(':') {
+            Some((params, payload)) => (params, payload),
+            None => (rest, ""),
+        };
+
+        let mut name = None;
+        let mut cols: Option<u16> = None;
+        let mut rows: Option<u16> = None;
+        let mut inline = false;
+        for param in params.split(';') {
+            let Some((key, value)) = param.split_once('=') else {
+                continue;
+            };
+            match key {
+                "name" => name = decode_base64_text(value),
+                // Plain numbers are cell counts; px/% variants need real
+                // cell metrics from the host and are ignored here.
+                "width" => cols = value.parse().ok(),
+                "height" => rows = value.parse().ok(),
+                "inline" => inline = value == "1",
+                _ => {}
+            }
+        }
+
+        let record = ImageRecord {
+            id: 0, // assigned by the registry
+            format: ImageFormat::Iterm2,
+            name,
+            payload: payload.as_bytes().to_vec(),
+            origin: (
+                self.cursor_x.min(self.width.saturating_sub(1)),
+                self.cursor_y,
+            ),
+            cols: cols.unwrap_or(1).clamp(1, self.width),
+            rows: rows.unwrap_or(1).clamp(1, self.height),
+            pixel_size: None,
+        };
+        if inline {
+            self.place_image(record);
+        } else {
+            self.images.register(record);
         }
-        // Other OSC codes (8 for hyperlinks, etc.) can be added later
+    }
+
+    /// Stamp the image's placeholder rectangle onto the grid (clipped) and
+    /// move the cursor below it, matching sixel scrolling behavior.
+    fn place_image(&mut self, record: ImageRecord) {
+        let (x0, y0) = record.origin;
+        let (cols, rows) = (record.cols, record.rows);
+        let id = self.images.register(record);
+        for dy in 0..rows {
+            let y = y0 + dy;
+            if y >= self.height {
+                break;
+            }
+            for dx in 0..cols {
+                let x = x0 + dx;
+                if x >= self.width {
+                    break;
+                }
+                let idx = usize::from(y) * usize::from(self.width) + usize::from(x);
+                self.grid[idx] = VCell {
+                    ch: ' ',
+                    combining: Vec::new(),
+                    style: self.current_style.clone(),
+                    image: Some(id),
+                    link: None,
+                    tab_origin: false,
+                };
+            }
+        }
+        // Damage the stamped rectangle.
+        for dy in 0..rows {
+            let y = y0 + dy;
+            if y >= self.height {
+                break;
+            }
+            for dx in 0..cols {
+                let x = x0 + dx;
+                if x >= self.width {
+                    break;
+                }
+                self.damage.mark_cell(y, x, self.width);
+            }
+        }
+
+        // Cursor to the line below the image (clamped to the last row).
+        self.cursor_x = x0;
+        self.cursor_y = (y0 + rows).min(self.height.saturating_sub(1));
+    }
+
+    /// Switch to the alternate screen, persisting the primary buffer.
+    fn enter_alt_screen(&mut self, clear: bool) {
+        if self.alternate_screen {
+            return;
+        }
+        let blank = vec![VCell::default(); usize::from(self.width) * usize::from(self.height)];
+        let stored_alt = self.alternate_grid.take().unwrap_or(blank);
+        let primary = std::mem::replace(&mut self.grid, stored_alt);
+        self.alternate_grid = Some(primary);
+        if clear {
+            self.grid.fill(VCell::default());
+        }
+        self.alternate_screen = true;
+        self.damage.full();
+    }
+
+    /// Return to the primary screen, persisting the alt buffer.
+    fn leave_alt_screen(&mut self, clear_alt: bool) {
+        if !self.alternate_screen {
+            return;
+        }
+        if clear_alt {
+            self.grid.fill(VCell::default());
+        }
+        let blank = vec![VCell::default(); usize::from(self.width) * usize::from(self.height)];
+        let primary = self.alternate_grid.take().unwrap_or(blank);
+        let alt = std::mem::replace(&mut self.grid, primary);
+        self.alternate_grid = Some(alt);
+        self.alternate_screen = false;
+        self.damage.full();
     }
 
     fn set_dec_mode(&mut self, mode: u16, enable: bool) {
@@ -1310,52 +2564,101 @@ impl VirtualTerminal {
                 }
             }
             7 => self.autowrap = enable,
-            25 => self.cursor_visible = enable,
+            25 => self.set_cursor_visible_tracked(enable),
+            // Alternate screen variants (xterm semantics):
+            //   47   — plain switch; alt content persists across sessions.
+            //   1047 — switch; the alt screen is cleared on exit.
+            //   1048 — save/restore cursor + SGR only (DECSC/DECRC-like).
+            //   1049 — 1048 + switch with a cleared alt screen on entry.
+            47 => {
+                if self.quirks.windows_no_alt_screen {
+                    return;
+                }
+                if enable {
+                    self.enter_alt_screen(false);
+                } else {
+                    self.leave_alt_screen(false);
+                }
+            }
+            1047 => {
+                if self.quirks.windows_no_alt_screen {
+                    return;
+                }
+                if enable {
+                    self.enter_alt_screen(false);
+                } else {
+                    self.leave_alt_screen(true);
+                }
+            }
+            1048 => {
+                if enable {
+                    self.saved_cursor_sgr =
+                        Some((self.cursor_x, self.cursor_y, self.current_style.clone()));
+                } else if let Some((x, y, style)) = self.saved_cursor_sgr.take() {
+                    self.cursor_x = x.min(self.width);
+                    self.cursor_y = y.min(self.height.saturating_sub(1));
+                    self.current_style = style;
+                }
+            }
             1049 => {
-                // Alternate screen buffer
                 if self.quirks.windows_no_alt_screen {
                     return;
                 }
                 if enable && !self.alternate_screen {
-                    self.alternate_grid = Some(std::mem::replace(
-                        &mut self.grid,
-                        vec![VCell::default(); usize::from(self.width) * usize::from(self.height)],
-                    ));
                     self.alternate_cursor = Some((self.cursor_x, self.cursor_y));
+                    self.alternate_style = Some(self.current_style.clone());
+                    self.enter_alt_screen(true);
                     self.cursor_x = 0;
                     self.cursor_y = 0;
-                    self.alternate_screen = true;
                 } else if !enable && self.alternate_screen {
-                    if let Some(main_grid) = self.alternate_grid.take() {
-                        self.grid = main_grid;
-                    }
+                    self.leave_alt_screen(false);
                     if let Some((x, y)) = self.alternate_cursor.take() {
-                        self.cursor_x = x;
-                        self.cursor_y = y;
+                        self.cursor_x = x.min(self.width);
+                        self.cursor_y = y.min(self.height.saturating_sub(1));
+                    }
+                    if let Some(style) = self.alternate_style.take() {
+                        self.current_style = style;
                     }
-                    self.alternate_screen = false;
                 }
             }
-            1047 => {
-                // Alternate screen (without save/restore cursor)
-                if self.quirks.windows_no_alt_screen {
-                    return;
+            // Mouse tracking modes requested by the hosted application.
+            1000 | 1002 | 1003 => {
+                use crate::mouse_passthrough::MouseTracking;
+                let tracking = match mode {
+                    1000 => MouseTracking::Normal,
+                    1002 => MouseTracking::ButtonEvent,
+                    _ => MouseTracking::AnyEvent,
+                };
+                if enable {
+                    self.mouse_protocol.tracking = tracking;
+                } else if self.mouse_protocol.tracking == tracking {
+                    // Resetting an inactive mode is a no-op.
+                    self.mouse_protocol.tracking = MouseTracking::Off;
                 }
-                if enable && !self.alternate_screen {
-                    self.alternate_grid = Some(std::mem::replace(
-                        &mut self.grid,
-                        vec![VCell::default(); usize::from(self.width) * usize::from(self.height)],
-                    ));
-                    self.alternate_screen = true;
-                } else if !enable && self.alternate_screen {
-                    if let Some(main_grid) = self.alternate_grid.take() {
-                        self.grid = main_grid;
-                    }
-                    self.alternate_screen = false;
+            }
+            // Mouse report encodings.
+            1005 => {
+                use crate::mouse_passthrough::MouseEncoding;
+                if enable {
+                    self.mouse_protocol.encoding = MouseEncoding::Utf8;
+                } else if self.mouse_protocol.encoding == MouseEncoding::Utf8 {
+                    self.mouse_protocol.encoding = MouseEncoding::X10;
+                }
+            }
+            1006 => {
+                use crate::mouse_passthrough::MouseEncoding;
+                if enable {
+                    self.mouse_protocol.encoding = MouseEncoding::Sgr;
+                } else if self.mouse_protocol.encoding == MouseEncoding::Sgr {
+                    self.mouse_protocol.encoding = MouseEncoding::X10;
                 }
             }
+            2004 => {
+                // Bracketed paste requested by the hosted application.
+                self.input_encoding.bracketed_paste = enable;
+            }
             _ => {
-                // Other DEC modes: ignored (1000/1002/1006 mouse, 2004 paste, etc.)
+                // Other DEC modes: ignored.
             }
         }
     }
@@ -1402,7 +2705,10 @@ impl VirtualTerminal {
 
         let char_width = UnicodeWidthChar::width(ch).unwrap_or(0);
         if char_width == 0 {
-            return; // zero-width (combining marks, ZWJ): skip
+            // Zero-width (combining marks, ZWJ): attach to the cell just
+            // written, bounded by the per-cell cap.
+            self.attach_combining(ch);
+            return;
         }
 
         // Auto-wrap: if cursor is past right margin and autowrap is on, wrap
@@ -1421,6 +2727,8 @@ impl VirtualTerminal {
             if self.autowrap {
                 let idx = self.idx(self.cursor_x, self.cursor_y);
                 self.grid[idx] = VCell::default();
+                self.damage
+                    .mark_cell(self.cursor_y, self.cursor_x, self.width);
                 self.cursor_x = 0;
                 self.linefeed();
             } else {
@@ -1460,18 +2768,48 @@ impl VirtualTerminal {
 
         self.grid[idx] = VCell {
             ch,
+            combining: Vec::new(),
             style: self.current_style.clone(),
+            image: None,
+            link: self.current_link,
+            tab_origin: false,
         };
 
         // Wide char: place continuation in next cell
         if char_width == 2 && self.cursor_x + 1 < self.width {
             let cont_idx = idx + 1;
+            // Fixup: our continuation overwrites another wide char's
+            // lead → blank that char's now-orphaned continuation.
+            if UnicodeWidthChar::width(self.grid[cont_idx].ch).unwrap_or(0) == 2
+                && self.cursor_x + 2 < self.width
+                && self.grid[cont_idx + 1].ch == WIDE_CONTINUATION
+            {
+                self.grid[cont_idx + 1] = VCell::default();
+                self.damage
+                    .mark_cell(self.cursor_y, self.cursor_x + 2, self.width);
+            }
             self.grid[cont_idx] = VCell {
                 ch: WIDE_CONTINUATION,
+                combining: Vec::new(),
                 style: self.current_style.clone(),
+                image: None,
+                link: self.current_link,
+                tab_origin: false,
             };
         }
 
+        // Damage: insert mode shifts the row tail; otherwise the written
+        // cell(s) plus the wide-char fixup neighbors.
+        if self.insert_mode {
+            self.damage.mark_row(self.cursor_y, self.width);
+        } else {
+            for col in self.cursor_x.saturating_sub(1)
+                ..=(self.cursor_x + 1).min(self.width.saturating_sub(1))
+            {
+                self.damage.mark_cell(self.cursor_y, col, self.width);
+            }
+        }
+
         self.last_char = Some(ch);
         let advance = u16::try_from(char_width).unwrap_or(1);
         if immediate_wrap {
@@ -1494,13 +2832,26 @@ impl VirtualTerminal {
     }
 
     fn scroll_up(&mut self) {
-        // Push the top line of the scroll region into scrollback
-        let top_start = self.idx(0, self.scroll_top);
-        let top_end = top_start + usize::from(self.width);
-        let line: Vec<VCell> = self.grid[top_start..top_end].to_vec();
-        self.scrollback.push_back(line);
-        while self.scrollback.len() > self.max_scrollback {
-            self.scrollback.pop_front();
+        // Push the top line of the scroll region into scrollback — but
+        // never from the alternate screen, whose content must not leak
+        // into (or grow) the primary scrollback.
+        if !self.alternate_screen {
+            let top_start = self.idx(0, self.scroll_top);
+            let top_end = top_start + usize::from(self.width);
+            let line: Vec<VCell> = self.grid[top_start..top_end].to_vec();
+            self.scrollback.push_back(line);
+            if let Some(clock) = &self.timestamp_clock {
+                // Clamp to non-decreasing so binary search stays valid
+                // even under a misbehaving clock.
+                let now = clock();
+                let floor = self.line_timestamps.back().copied().unwrap_or(0);
+                self.line_timestamps.push_back(now.max(floor));
+            }
+            while self.scrollback.len() > self.max_scrollback {
+                self.evicted_lines += 1;
+                self.scrollback.pop_front();
+                self.line_timestamps.pop_front();
+            }
         }
 
         // Shift lines up within scroll region
@@ -1511,7 +2862,9 @@ impl VirtualTerminal {
             // Copy within the same vec using split_at_mut pattern
             let (left, right) = self.grid.split_at_mut(src_start);
             left[dst_start..dst_start + w].clone_from_slice(&right[..w]);
+            self.line_attrs[usize::from(row)] = self.line_attrs[usize::from(row) + 1];
         }
+        self.line_attrs[usize::from(self.scroll_bottom)] = VLineAttr::Normal;
 
         // Clear the bottom line of scroll region
         let blank = self.styled_blank();
@@ -1519,6 +2872,16 @@ impl VirtualTerminal {
         for i in 0..usize::from(self.width) {
             self.grid[bottom_start + i] = blank.clone();
         }
+
+        // Damage: a full-screen scroll lets consumers blit; partial scroll
+        // regions dirty every region row instead.
+        if self.scroll_top == 0 && self.scroll_bottom == self.height - 1 {
+            self.damage.scroll(1, self.height);
+        } else {
+            self.damage
+                .mark_rows(self.scroll_top..=self.scroll_bottom, self.width);
+        }
+        self.damage.mark_row(self.scroll_bottom, self.width);
     }
 
     fn scroll_down(&mut self) {
@@ -1531,7 +2894,10 @@ impl VirtualTerminal {
                 let (left, right) = self.grid.split_at_mut(dst_start);
                 right[..w].clone_from_slice(&left[src_start..src_start + w]);
             }
+            // Line attributes travel with their lines.
+            self.line_attrs[usize::from(row)] = self.line_attrs[usize::from(row) - 1];
         }
+        self.line_attrs[usize::from(self.scroll_top)] = VLineAttr::Normal;
 
         // Clear the top line of scroll region
         let blank = self.styled_blank();
@@ -1539,6 +2905,64 @@ impl VirtualTerminal {
         for i in 0..usize::from(self.width) {
             self.grid[top_start + i] = blank.clone();
         }
+
+        if self.scroll_top == 0 && self.scroll_bottom == self.height - 1 {
+            self.damage.scroll(-1, self.height);
+        } else {
+            self.damage
+                .mark_rows(self.scroll_top..=self.scroll_bottom, self.width);
+        }
+        self.damage.mark_row(self.scroll_top, self.width);
+    }
+
+    /// Attach a zero-width character to the most recently written cell.
+    ///
+    /// Combining marks modify the glyph to their left. When NFC
+    /// normalization is on and the base composes with the mark (e + ́ →
+    /// é) the cell stays a bare `char`; otherwise the mark is pushed,
+    /// bounded by the per-cell cap — beyond it marks are dropped and
+    /// counted ([`grid_limit_stats`](Self::grid_limit_stats)). Marks
+    /// never change cell width.
+    fn attach_combining(&mut self, mark: char) {
+        if self.cursor_x == 0 || self.width == 0 {
+            // No base cell on this row: nothing to modify.
+            self.limit_stats.combining_marks_dropped += 1;
+            return;
+        }
+        // Cell just written: one left of the cursor, or the last column
+        // when the cursor is parked past the right margin (pending wrap).
+        let col = if self.cursor_x < self.width {
+            self.cursor_x - 1
+        } else {
+            self.width - 1
+        };
+        // A continuation cell defers to its wide lead.
+        let mut col = col;
+        if self.grid[self.idx(col, self.cursor_y)].ch == WIDE_CONTINUATION && col > 0 {
+            col -= 1;
+        }
+        let width = self.width;
+        let row = self.cursor_y;
+        let idx = self.idx(col, row);
+        let nfc = self.nfc_normalize;
+        let cap = self.max_combining_per_cell;
+        let cell = &mut self.grid[idx];
+
+        if nfc
+            && cell.combining.is_empty()
+            && let Some(composed) = unicode_normalization::char::compose(cell.ch, mark)
+            && UnicodeWidthChar::width(composed) == UnicodeWidthChar::width(cell.ch)
+        {
+            cell.ch = composed;
+            self.damage.mark_cell(row, col, width);
+            return;
+        }
+        if cell.combining.len() >= cap {
+            self.limit_stats.combining_marks_dropped += 1;
+            return;
+        }
+        cell.combining.push(mark);
+        self.damage.mark_cell(row, col, width);
     }
 
     /// A blank cell carrying the current SGR attributes (bg color, etc.).
@@ -1546,7 +2970,11 @@ impl VirtualTerminal {
     fn styled_blank(&self) -> VCell {
         VCell {
             ch: ' ',
+            combining: Vec::new(),
             style: self.current_style.clone(),
+            image: None,
+            link: None,
+            tab_origin: false,
         }
     }
 
@@ -1599,7 +3027,9 @@ impl VirtualTerminal {
                     *cell = blank.clone();
                 }
                 if mode == 3 {
+                    self.evicted_lines += self.scrollback.len() as u64;
                     self.scrollback.clear();
+                    self.line_timestamps.clear();
                 }
             }
             _ => {}
@@ -1643,11 +3073,19 @@ impl VirtualTerminal {
 
     fn reset(&mut self) {
         self.grid = vec![VCell::default(); usize::from(self.width) * usize::from(self.height)];
+        self.line_attrs = vec![VLineAttr::Normal; usize::from(self.height)];
         self.cursor_x = 0;
         self.cursor_y = 0;
-        self.cursor_visible = true;
+        self.set_cursor_visible_tracked(true);
+        if self.cursor_shape != CursorShape::default() || !self.cursor_blink {
+            self.cursor_shape = CursorShape::default();
+            self.cursor_blink = true;
+            self.cursor_generation += 1;
+        }
         self.current_style = CellStyle::default();
+        self.evicted_lines += self.scrollback.len() as u64;
         self.scrollback.clear();
+        self.line_timestamps.clear();
         self.saved_cursor = None;
         self.scroll_top = 0;
         self.scroll_bottom = self.height.saturating_sub(1);
@@ -1664,6 +3102,8 @@ impl VirtualTerminal {
         self.charset_slots = [b'B'; 4];
         self.active_charset = 0;
         self.single_shift = None;
+        self.input_encoding = crate::input_forwarding::InputEncodingState::default();
+        self.kitty_flag_stack.clear();
     }
 
     fn param(params: &[u16], idx: usize, default: u16) -> u16 {
@@ -1764,6 +3204,261 @@ fn color_256(idx: u16) -> Color {
 mod tests {
     use super::*;
 
+    #[test]
+    fn wide_continuation_overwriting_wide_lead_blanks_orphan() {
+        // 世界 occupies columns 0..4; rewriting a wide char at column 1
+        // overwrites 界's lead — its continuation at column 3 must not
+        // be left orphaned (found by the vt_fuzz harness).
+        let mut vt = VirtualTerminal::new(10, 2);
+        vt.feed_str("\u{4e16}\u{754c}"); // 世界
+        vt.feed_str("\x1b[1;2H\u{4e16}"); // 世 at column 1
+        for x in 0..vt.width() {
+            let cell = vt.cell_at(x, 0).unwrap();
+            if cell.ch == '\0' {
+                let lead = vt.cell_at(x - 1, 0).unwrap().ch;
+                assert_eq!(
+                    UnicodeWidthChar::width(lead),
+                    Some(2),
+                    "continuation at column {x} must follow a wide lead"
+                );
+            }
+        }
+        assert_invariants(&vt);
+    }
+
+    mod combining_limits {
+        use super::*;
+
+        #[test]
+        fn zalgo_bomb_is_bounded() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("e");
+            // U+0335 (short stroke overlay) never composes with the base.
+            for _ in 0..1_000 {
+                vt.put_char('\u{0335}');
+            }
+            let cell = vt.cell_at(0, 0).unwrap();
+            assert!(
+                cell.combining.len() <= DEFAULT_MAX_COMBINING_PER_CELL,
+                "cap enforced: {} marks kept",
+                cell.combining.len()
+            );
+            let stats = vt.grid_limit_stats();
+            assert_eq!(
+                stats.combining_marks_dropped,
+                1_000 - cell.combining.len() as u64,
+                "every excess mark is counted"
+            );
+        }
+
+        #[test]
+        fn nfc_composes_base_and_mark() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("e");
+            vt.put_char('\u{0301}'); // combining acute
+            let cell = vt.cell_at(0, 0).unwrap();
+            assert_eq!(cell.ch, '\u{e9}', "e + \u{301} composes to \u{e9}");
+            assert!(cell.combining.is_empty(), "composed, not stored");
+            assert_eq!(vt.row_text(0), "\u{e9}");
+        }
+
+        #[test]
+        fn non_composing_marks_are_kept() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("x");
+            vt.put_char('\u{0335}');
+            let cell = vt.cell_at(0, 0).unwrap();
+            assert_eq!(cell.ch, 'x');
+            assert_eq!(cell.combining, vec!['\u{0335}']);
+            assert_eq!(vt.row_text(0), "x\u{0335}");
+        }
+
+        #[test]
+        fn nfc_can_be_disabled() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.set_nfc_normalization(false);
+            vt.feed_str("e");
+            vt.put_char('\u{0301}');
+            let cell = vt.cell_at(0, 0).unwrap();
+            assert_eq!(cell.ch, 'e');
+            assert_eq!(cell.combining, vec!['\u{0301}']);
+        }
+
+        #[test]
+        fn marks_never_add_width_or_move_cursor() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("ab");
+            let before = vt.cursor();
+            for _ in 0..20 {
+                vt.put_char('\u{0300}');
+            }
+            assert_eq!(vt.cursor(), before, "marks leave the cursor in place");
+            // The following printable lands in the next column as usual.
+            vt.put_char('c');
+            assert_eq!(
+                vt.row_text(0)
+                    .chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .count(),
+                3
+            );
+        }
+
+        #[test]
+        fn marks_attach_to_wide_lead() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("\u{4e16}"); // 世 (wide)
+            vt.put_char('\u{0335}');
+            let lead = vt.cell_at(0, 0).unwrap();
+            assert_eq!(lead.combining, vec!['\u{0335}']);
+        }
+
+        #[test]
+        fn mark_with_no_base_is_dropped_and_counted() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.put_char('\u{0301}');
+            assert_eq!(vt.grid_limit_stats().combining_marks_dropped, 1);
+            assert_eq!(vt.row_text(0), "");
+        }
+
+        #[test]
+        fn export_matches_grid_after_capping() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.set_max_combining_per_cell(2);
+            vt.feed_str("x");
+            for _ in 0..5 {
+                vt.put_char('\u{0335}');
+            }
+            vt.feed_str("y");
+            let expected = "x\u{0335}\u{0335}y";
+            assert_eq!(vt.row_text(0), expected);
+            let exported =
+                crate::export::export_text(&vt, &crate::export::ExportOptions::default());
+            assert!(
+                exported.contains(expected),
+                "export respects surviving marks: {exported:?}"
+            );
+        }
+
+        #[test]
+        fn overwriting_a_cell_clears_its_marks() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("x");
+            vt.put_char('\u{0335}');
+            vt.feed_str("\x1b[1;1H"); // cursor home
+            vt.feed_str("z");
+            let cell = vt.cell_at(0, 0).unwrap();
+            assert_eq!(cell.ch, 'z');
+            assert!(cell.combining.is_empty());
+        }
+
+        #[test]
+        fn hyperlink_table_is_bounded() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            for i in 0..(MAX_LINK_URIS + 10) {
+                vt.feed_str(&format!("\x1b]8;;https://example.com/{i}\x1b\\"));
+            }
+            let stats = vt.grid_limit_stats();
+            assert_eq!(stats.hyperlinks_dropped, 10, "drops counted past the cap");
+            // The capped URI is simply not linked.
+            vt.feed_str("x");
+            let cell = vt.cell_at(0, 0).unwrap();
+            assert_eq!(cell.link, None);
+        }
+    }
+
+    mod input_encoding_tracking {
+        use super::*;
+
+        #[test]
+        fn bracketed_paste_follows_decset_2004() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            assert!(!vt.input_encoding().bracketed_paste);
+            vt.feed_str("\x1b[?2004h");
+            assert!(vt.input_encoding().bracketed_paste);
+            vt.feed_str("\x1b[?2004l");
+            assert!(!vt.input_encoding().bracketed_paste);
+        }
+
+        #[test]
+        fn modify_other_keys_follows_csi_gt_4() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            vt.feed_str("\x1b[>4;2m");
+            assert_eq!(vt.input_encoding().modify_other_keys, 2);
+            vt.feed_str("\x1b[>4;1m");
+            assert_eq!(vt.input_encoding().modify_other_keys, 1);
+            // CSI > 4 m resets.
+            vt.feed_str("\x1b[>4m");
+            assert_eq!(vt.input_encoding().modify_other_keys, 0);
+            // Out-of-range levels clamp to 2.
+            vt.feed_str("\x1b[>4;9m");
+            assert_eq!(vt.input_encoding().modify_other_keys, 2);
+        }
+
+        #[test]
+        fn modify_other_keys_other_resources_ignored() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            vt.feed_str("\x1b[>1;2m"); // modifyCursorKeys, not ours
+            assert_eq!(vt.input_encoding().modify_other_keys, 0);
+        }
+
+        #[test]
+        fn kitty_flags_push_and_pop() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            vt.feed_str("\x1b[>1u"); // push, flags=1
+            assert_eq!(vt.input_encoding().kitty_flags, 1);
+            vt.feed_str("\x1b[>5u"); // push, flags=5
+            assert_eq!(vt.input_encoding().kitty_flags, 5);
+            vt.feed_str("\x1b[<1u"); // pop → 1
+            assert_eq!(vt.input_encoding().kitty_flags, 1);
+            vt.feed_str("\x1b[<1u"); // pop → 0 (original)
+            assert_eq!(vt.input_encoding().kitty_flags, 0);
+            // Over-popping stays at zero.
+            vt.feed_str("\x1b[<3u");
+            assert_eq!(vt.input_encoding().kitty_flags, 0);
+        }
+
+        #[test]
+        fn kitty_pop_count_pops_multiple() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            vt.feed_str("\x1b[>1u\x1b[>3u\x1b[>7u");
+            assert_eq!(vt.input_encoding().kitty_flags, 7);
+            vt.feed_str("\x1b[<2u"); // pop two → flags from first push
+            assert_eq!(vt.input_encoding().kitty_flags, 1);
+        }
+
+        #[test]
+        fn kitty_set_modes_assign_or_mask() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            vt.feed_str("\x1b[=5;1u"); // assign
+            assert_eq!(vt.input_encoding().kitty_flags, 5);
+            vt.feed_str("\x1b[=2;2u"); // set bits
+            assert_eq!(vt.input_encoding().kitty_flags, 7);
+            vt.feed_str("\x1b[=1;3u"); // clear bits
+            assert_eq!(vt.input_encoding().kitty_flags, 6);
+        }
+
+        #[test]
+        fn full_reset_clears_input_encoding_state() {
+            let mut vt = VirtualTerminal::new(80, 24);
+            vt.feed_str("\x1b[?2004h\x1b[>4;2m\x1b[>1u");
+            assert_ne!(vt.input_encoding(), Default::default());
+            vt.feed_str("\x1bc"); // RIS
+            assert_eq!(vt.input_encoding(), Default::default());
+            // The kitty stack is gone too: a pop stays at zero.
+            vt.feed_str("\x1b[<1u");
+            assert_eq!(vt.input_encoding().kitty_flags, 0);
+        }
+
+        #[test]
+        fn mode_tracking_does_not_disturb_grid_or_cursor() {
+            let mut vt = VirtualTerminal::new(20, 3);
+            vt.feed_str("ab\x1b[?2004h\x1b[>4;2m\x1b[>1u cd");
+            assert_eq!(vt.row_text(0).trim_end(), "ab cd");
+            assert_invariants(&vt);
+        }
+    }
+
     fn assert_invariants(vt: &VirtualTerminal) {
         // cursor_x == width is valid: it's the "pending wrap" state
         assert!(vt.cursor_x <= vt.width);
@@ -2047,6 +3742,76 @@ mod tests {
         assert_eq!(vt.row_text(0), "MainAlt");
     }
 
+    #[test]
+    fn osc7_cwd_with_percent_encoding() {
+        let mut vt = VirtualTerminal::new(40, 5);
+        vt.feed(b"\x1b]7;file://myhost/home/user/my%20project\x07");
+        assert_eq!(vt.current_cwd(), Some("/home/user/my project"));
+        assert_eq!(vt.metadata().current_hostname(), Some("myhost"));
+
+        // Host-only URL updates the hostname without touching the cwd.
+        vt.feed(b"\x1b]7;file://otherhost\x1b\\");
+        assert_eq!(vt.metadata().current_hostname(), Some("otherhost"));
+        assert_eq!(vt.current_cwd(), Some("/home/user/my project"));
+    }
+
+    #[test]
+    fn iterm2_user_var_decodes_and_overwrites() {
+        let mut vt = VirtualTerminal::new(40, 5);
+        // "main" / "detached" base64-encoded.
+        vt.feed(b"\x1b]1337;SetUserVar=branch=bWFpbg==\x07");
+        assert_eq!(vt.metadata().user_var("branch"), Some("main"));
+        vt.feed(b"\x1b]1337;SetUserVar=branch=ZGV0YWNoZWQ=\x07");
+        assert_eq!(vt.metadata().user_var("branch"), Some("detached"));
+    }
+
+    #[test]
+    fn iterm2_remote_host_splits_user_and_host() {
+        let mut vt = VirtualTerminal::new(40, 5);
+        vt.feed(b"\x1b]1337;RemoteHost=deploy@prod-1\x07");
+        assert_eq!(vt.metadata().current_username(), Some("deploy"));
+        assert_eq!(vt.metadata().current_hostname(), Some("prod-1"));
+    }
+
+    #[test]
+    fn metadata_correlates_with_scrollback_lines() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.feed(b"\x1b]7;file://h/a\x07");
+        let line_in_a = vt.end_absolute_line();
+        // Push enough lines to scroll and change directory midway.
+        vt.feed(b"one\r\ntwo\r\nthree\r\n");
+        vt.feed(b"\x1b]7;file://h/b\x07");
+        let line_in_b = vt.end_absolute_line() + u64::from(vt.cursor().1);
+        vt.feed(b"four\r\nfive\r\n");
+
+        assert_eq!(
+            vt.metadata().metadata_at_line(line_in_a).cwd.as_deref(),
+            Some("/a")
+        );
+        assert_eq!(
+            vt.metadata().metadata_at_line(line_in_b).cwd.as_deref(),
+            Some("/b")
+        );
+        assert_eq!(vt.current_cwd(), Some("/b"));
+    }
+
+    #[test]
+    fn malformed_metadata_payloads_are_ignored() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.feed(b"\x1b]7;not-a-url\x07");
+        vt.feed(b"\x1b]7;file://\x07");
+        vt.feed(b"\x1b]1337;SetUserVar=\x07");
+        vt.feed(b"\x1b]1337;SetUserVar=novalue\x07");
+        vt.feed(b"\x1b]1337;SetUserVar=x=!!!not base64!!!\x07");
+        vt.feed(b"\x1b]1337;RemoteHost=\x07");
+        vt.feed(b"\x1b]7;file://h/%ff%fe\x07"); // invalid UTF-8 after decode
+        assert_eq!(vt.metadata().user_var("x"), None);
+        // The lossy-decoded cwd is stored without panicking.
+        assert!(vt.current_cwd().is_some());
+        vt.feed(b"still alive");
+        assert_eq!(vt.row_text(0), "still alive");
+    }
+
     #[test]
     fn osc_title() {
         let mut vt = VirtualTerminal::new(80, 24);
@@ -3116,12 +4881,13 @@ mod tests {
     }
 
     #[test]
-    fn put_char_zero_width_skipped() {
+    fn put_char_zero_width_attaches_without_advancing() {
         let mut vt = VirtualTerminal::new(10, 3);
         vt.put_char('A');
         vt.put_char('\u{0300}'); // combining grave accent (zero-width)
         assert_eq!(vt.cursor(), (1, 0)); // cursor didn't advance
-        assert_eq!(vt.char_at(0, 0), Some('A'));
+        // A + U+0300 composes to À under NFC normalization.
+        assert_eq!(vt.char_at(0, 0), Some('\u{c0}'));
         assert_invariants(&vt);
     }
 
@@ -3309,4 +5075,605 @@ mod tests {
         assert_eq!(vt.row_text(1), "World");
         assert_invariants(&vt);
     }
+
+    // --- Inline image (sixel / iTerm2) tests ---
+
+    #[test]
+    fn sixel_payload_not_splattered_as_text() {
+        let mut vt = VirtualTerminal::new(20, 5);
+        vt.feed(b"\x1bPq\"1;1;20;20#0;2;0;0;0#0~~@@vv@@~~@@~~$\x1b\\after");
+        // Payload must never appear on the grid; only "after" prints.
+        let screen = vt.screen_text();
+        assert!(!screen.contains('~'), "sixel data leaked: {screen:?}");
+        assert!(screen.contains("after"));
+        assert_invariants(&vt);
+    }
+
+    #[test]
+    fn sixel_image_registered_with_raster_size() {
+        let mut vt = VirtualTerminal::new(20, 5);
+        vt.feed(b"\x1bPq\"1;1;30;40#0~~\x1b\\");
+        assert_eq!(vt.images().len(), 1);
+        let img = vt.images().iter().next().unwrap();
+        assert_eq!(img.format, ImageFormat::Sixel);
+        assert_eq!(img.pixel_size, Some((30, 40)));
+        // 30px / 10px-per-cell = 3 cols; 40px / 20px-per-cell = 2 rows.
+        assert_eq!((img.cols, img.rows), (3, 2));
+        assert!(img.payload.starts_with(b"\"1;1;30;40"));
+    }
+
+    #[test]
+    fn sixel_placeholder_cells_carry_image_id() {
+        let mut vt = VirtualTerminal::new(20, 5);
+        vt.feed(b"\x1bPq\"1;1;30;40#0~~\x1b\\");
+        let id = vt.images().iter().next().unwrap().id;
+        for y in 0..2 {
+            for x in 0..3 {
+                assert_eq!(vt.image_at(x, y), Some(id), "cell ({x},{y})");
+            }
+        }
+        assert_eq!(vt.image_at(3, 0), None);
+        assert_eq!(vt.image_at(0, 2), None);
+        // Cursor moved below the image.
+        assert_eq!(vt.cursor(), (0, 2));
+    }
+
+    #[test]
+    fn sixel_without_raster_attributes_occupies_one_cell() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        vt.feed(b"\x1bPq#0~~\x1b\\");
+        let img = vt.images().iter().next().unwrap();
+        assert_eq!((img.cols, img.rows), (1, 1));
+        assert_eq!(img.pixel_size, None);
+    }
+
+    #[test]
+    fn iterm2_inline_image_with_name_and_cell_size() {
+        let mut vt = VirtualTerminal::new(20, 6);
+        // name = base64("cat.png")
+        vt.feed(b"\x1b]1337;File=name=Y2F0LnBuZw==;width=4;height=2;inline=1:QUJD\x07");
+        assert_eq!(vt.images().len(), 1);
+        let img = vt.images().iter().next().unwrap();
+        assert_eq!(img.format, ImageFormat::Iterm2);
+        assert_eq!(img.name.as_deref(), Some("cat.png"));
+        assert_eq!((img.cols, img.rows), (4, 2));
+        assert_eq!(img.payload, b"QUJD");
+        let id = img.id;
+        assert_eq!(vt.image_at(0, 0), Some(id));
+        assert_eq!(vt.image_at(3, 1), Some(id));
+        assert_eq!(vt.image_at(4, 0), None);
+    }
+
+    #[test]
+    fn iterm2_non_inline_transfer_places_no_cells() {
+        let mut vt = VirtualTerminal::new(20, 6);
+        vt.feed(b"\x1b]1337;File=name=Y2F0LnBuZw==;width=4;height=2:QUJD\x07");
+        assert_eq!(vt.images().len(), 1);
+        assert_eq!(vt.image_at(0, 0), None);
+        assert_eq!(vt.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn iterm2_image_rect_clipped_to_grid() {
+        let mut vt = VirtualTerminal::new(5, 3);
+        vt.feed(b"\x1b]1337;File=width=99;height=99;inline=1:QQ==\x07");
+        let img = vt.images().iter().next().unwrap();
+        assert_eq!((img.cols, img.rows), (5, 3));
+        assert_eq!(vt.image_at(4, 2), Some(img.id));
+        assert_invariants(&vt);
+    }
+
+    #[test]
+    fn unknown_dcs_swallowed_without_grid_damage() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        // DECRQSS-style DCS with an unrecognized final byte.
+        vt.feed(b"\x1bP$r0;1\"p\x1b\\ok");
+        assert_eq!(vt.row_text(0), "ok");
+        assert!(vt.images().is_empty());
+    }
+
+    #[test]
+    fn st_terminated_osc_does_not_leak_backslash() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.feed(b"\x1b]0;my title\x1b\\text");
+        assert_eq!(vt.title(), "my title");
+        assert!(vt.row_text(0).starts_with("text"));
+    }
+
+    // --- Damage journal tests ---
+
+    /// Apply a damage report to a reconstruction grid by copying reported
+    /// cells from the live grid (what a texture-uploading embedder does).
+    fn apply_damage(recon: &mut Vec<Vec<VCell>>, vt: &VirtualTerminal, report: &DamageReport) {
+        let height = usize::from(vt.height());
+        let width = usize::from(vt.width());
+        if report.full_invalidate {
+            *recon = (0..vt.height())
+                .map(|y| {
+                    (0..vt.width())
+                        .map(|x| vt.cell_at(x, y).unwrap().clone())
+                        .collect()
+                })
+                .collect();
+            return;
+        }
+        // Blit first, then repaint dirty rows.
+        let scroll = report.scrolled_lines;
+        if scroll > 0 {
+            let n = (scroll as usize).min(height);
+            recon.rotate_left(n);
+            for row in recon.iter_mut().skip(height - n) {
+                row.fill(VCell::default());
+            }
+        } else if scroll < 0 {
+            let n = ((-scroll) as usize).min(height);
+            recon.rotate_right(n);
+            for row in recon.iter_mut().take(n) {
+                row.fill(VCell::default());
+            }
+        }
+        for (row, cols) in &report.rows {
+            for col in cols.iter() {
+                if usize::from(col) < width && usize::from(*row) < height {
+                    recon[usize::from(*row)][usize::from(col)] =
+                        vt.cell_at(col, *row).unwrap().clone();
+                }
+            }
+        }
+    }
+
+    fn grid_of(vt: &VirtualTerminal) -> Vec<Vec<VCell>> {
+        (0..vt.height())
+            .map(|y| {
+                (0..vt.width())
+                    .map(|x| vt.cell_at(x, y).unwrap().clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fresh_cursor_gets_full_invalidate() {
+        let mut vt = VirtualTerminal::new(10, 4);
+        let mut cursor = vt.damage_cursor();
+        let report = vt.take_damage(&mut cursor);
+        assert!(report.full_invalidate);
+        // Nothing happened since: next take is empty.
+        let report = vt.take_damage(&mut cursor);
+        assert!(!report.full_invalidate);
+        assert!(report.rows.is_empty());
+        assert_eq!(report.scrolled_lines, 0);
+    }
+
+    #[test]
+    fn print_damage_reports_dirty_columns() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let mut cursor = vt.damage_cursor();
+        let _ = vt.take_damage(&mut cursor);
+
+        vt.feed(b"hi");
+        let report = vt.take_damage(&mut cursor);
+        assert!(!report.full_invalidate);
+        assert_eq!(report.rows.len(), 1);
+        let (row, cols) = &report.rows[0];
+        assert_eq!(*row, 0);
+        assert!(cols.is_set(0) && cols.is_set(1));
+        assert!(!cols.is_set(10));
+    }
+
+    #[test]
+    fn two_consumers_acknowledge_independently() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let mut fast = vt.damage_cursor();
+        let mut slow = vt.damage_cursor();
+        let _ = vt.take_damage(&mut fast);
+        let _ = vt.take_damage(&mut slow);
+
+        vt.feed(b"aa");
+        let fast_report = vt.take_damage(&mut fast);
+        assert_eq!(fast_report.rows.len(), 1);
+
+        vt.feed(b"\r\nbb");
+        // Fast sees only the new row; slow sees both (a superset of fast's
+        // earlier report plus the new damage).
+        let fast_report = vt.take_damage(&mut fast);
+        let slow_report = vt.take_damage(&mut slow);
+        let fast_rows: Vec<u16> = fast_report.rows.iter().map(|(r, _)| *r).collect();
+        let slow_rows: Vec<u16> = slow_report.rows.iter().map(|(r, _)| *r).collect();
+        assert_eq!(fast_rows, vec![1]);
+        assert_eq!(slow_rows, vec![0, 1]);
+    }
+
+    #[test]
+    fn resize_produces_full_invalidate() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let mut cursor = vt.damage_cursor();
+        let _ = vt.take_damage(&mut cursor);
+
+        vt.resize(30, 6);
+        let report = vt.take_damage(&mut cursor);
+        assert!(report.full_invalidate);
+        assert_eq!(vt.width(), 30);
+        assert_eq!(vt.height(), 6);
+    }
+
+    #[test]
+    fn alt_screen_switch_produces_full_invalidate() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        let mut cursor = vt.damage_cursor();
+        let _ = vt.take_damage(&mut cursor);
+
+        vt.feed(b"\x1b[?1049h");
+        assert!(vt.take_damage(&mut cursor).full_invalidate);
+        vt.feed(b"\x1b[?1049l");
+        assert!(vt.take_damage(&mut cursor).full_invalidate);
+    }
+
+    #[test]
+    fn scroll_reported_for_blit_instead_of_repaint() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        let mut cursor = vt.damage_cursor();
+        vt.feed(b"one\r\ntwo\r\nthree");
+        let _ = vt.take_damage(&mut cursor);
+
+        // One more linefeed scrolls the whole screen.
+        vt.feed(b"\r\nfour");
+        let report = vt.take_damage(&mut cursor);
+        assert!(!report.full_invalidate);
+        assert_eq!(report.scrolled_lines, 1);
+        // Only the freshly exposed bottom row needs repainting.
+        let rows: Vec<u16> = report.rows.iter().map(|(r, _)| *r).collect();
+        assert_eq!(rows, vec![2]);
+    }
+
+    #[test]
+    fn damage_fuzz_reconstruction_matches_grid() {
+        // Deterministic xorshift so failures are reproducible.
+        let mut state: u64 = 0x5eed_1234_abcd_0042;
+        let mut rng = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut vt = VirtualTerminal::new(24, 6);
+        let mut cursor = vt.damage_cursor();
+        let mut recon = grid_of(&vt);
+        let report = vt.take_damage(&mut cursor);
+        apply_damage(&mut recon, &vt, &report);
+
+        for step in 0..2000 {
+            match rng() % 14 {
+                0..=5 => {
+                    let ch = b'a' + (rng() % 26) as u8;
+                    vt.feed(&[ch]);
+                }
+                6 => vt.feed(b"\r\n"),
+                7 => {
+                    let row = rng() % 6 + 1;
+                    let col = rng() % 24 + 1;
+                    vt.feed(format!("\x1b[{row};{col}H").as_bytes());
+                }
+                8 => vt.feed(b"\x1b[K"),
+                9 => vt.feed(b"\x1b[2J"),
+                10 => vt.feed(b"\x1b[L"),
+                11 => vt.feed(b"\x1b[M"),
+                12 => vt.feed(b"\x1b[3@"),
+                13 => vt.feed("宽".as_bytes()),
+                _ => unreachable!(),
+            }
+
+            // Acknowledge at an uneven rate.
+            if step % 7 == 0 {
+                let report = vt.take_damage(&mut cursor);
+                apply_damage(&mut recon, &vt, &report);
+            }
+        }
+
+        let report = vt.take_damage(&mut cursor);
+        apply_damage(&mut recon, &vt, &report);
+        assert_eq!(recon, grid_of(&vt), "journal reconstruction diverged");
+    }
+
+    // --- Alternate screen (DECSET 47/1047/1048/1049) ---
+
+    #[test]
+    fn vim_like_session_restores_screen_and_scrollback_exactly() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.set_max_scrollback(1000);
+        // Primary content with scrollback and a colored prompt.
+        vt.feed(b"one\r\ntwo\r\nthree\r\nfour\r\n\x1b[32mprompt\x1b[0m$ ");
+        let scrollback_before = vt.scrollback_len();
+        let screen_before: Vec<String> = (0..3).map(|y| vt.row_text(y)).collect();
+        let scroll_lines: Vec<String> = (0..scrollback_before)
+            .map(|i| vt.scrollback_line(i).unwrap())
+            .collect();
+        let cursor_before = vt.cursor();
+
+        // Enter the editor, fill the screen, scroll a lot.
+        vt.feed(b"\x1b[?1049h");
+        assert!(vt.is_alt_screen());
+        for i in 0..50 {
+            vt.feed(format!("edit line {i}\r\n").as_bytes());
+        }
+        assert_eq!(
+            vt.scrollback_len(),
+            scrollback_before,
+            "alt screen must never write into scrollback"
+        );
+
+        // Exit: everything restored exactly.
+        vt.feed(b"\x1b[?1049l");
+        assert!(!vt.is_alt_screen());
+        assert_eq!(vt.cursor(), cursor_before);
+        for (y, expected) in screen_before.iter().enumerate() {
+            assert_eq!(&vt.row_text(y as u16), expected, "row {y}");
+        }
+        assert_eq!(vt.scrollback_len(), scrollback_before);
+        for (i, expected) in scroll_lines.iter().enumerate() {
+            assert_eq!(vt.scrollback_line(i).as_ref(), Some(expected), "sb {i}");
+        }
+    }
+
+    #[test]
+    fn resize_in_alt_screen_restores_primary_at_new_size() {
+        let mut vt = VirtualTerminal::new(10, 4);
+        vt.feed(b"keep me");
+        vt.feed(b"\x1b[?1049h");
+        vt.resize(20, 6);
+        vt.feed(b"\x1b[?1049l");
+        assert_eq!(vt.width(), 20);
+        assert_eq!(vt.height(), 6);
+        // Primary content survived the in-alt resize (top-left preserved).
+        assert!(vt.row_text(0).starts_with("keep me"));
+    }
+
+    #[test]
+    fn mode_47_preserves_alt_content_across_sessions() {
+        let mut vt = VirtualTerminal::new(12, 3);
+        vt.feed(b"\x1b[?47halt stuff\x1b[?47l");
+        assert_eq!(vt.row_text(0), "");
+        // Re-entering with 47 shows the previous alt content.
+        vt.feed(b"\x1b[?47h");
+        assert_eq!(vt.row_text(0), "alt stuff");
+        vt.feed(b"\x1b[?47l");
+    }
+
+    #[test]
+    fn mode_1047_clears_alt_on_exit() {
+        let mut vt = VirtualTerminal::new(12, 3);
+        vt.feed(b"\x1b[?1047halt stuff\x1b[?1047l");
+        // Re-entry (via 47) shows a cleared alt screen.
+        vt.feed(b"\x1b[?47h");
+        assert_eq!(vt.row_text(0), "");
+    }
+
+    #[test]
+    fn mode_1048_saves_cursor_and_sgr_only() {
+        let mut vt = VirtualTerminal::new(20, 3);
+        vt.feed(b"\x1b[31m");
+        vt.set_cursor_position(5, 1);
+        vt.feed(b"\x1b[?1048h");
+        vt.feed(b"\x1b[0m");
+        vt.set_cursor_position(0, 0);
+        vt.feed(b"\x1b[?1048l");
+        assert_eq!(vt.cursor(), (5, 1));
+        // SGR restored: the next printed char is red again.
+        vt.feed(b"x");
+        let style = vt.style_at(5, 1).unwrap();
+        assert_eq!(style.fg, Some(Color::new(170, 0, 0)));
+        // No screen switch happened.
+        assert!(!vt.is_alt_screen());
+    }
+
+    #[test]
+    fn decode_base64_text_roundtrips() {
+        assert_eq!(
+            decode_base64_text("Y2F0LnBuZw==").as_deref(),
+            Some("cat.png")
+        );
+        assert_eq!(decode_base64_text("QQ==").as_deref(), Some("A"));
+        assert_eq!(decode_base64_text("").as_deref(), Some(""));
+        assert_eq!(decode_base64_text("!!!"), None);
+    }
+
+    // ── Cursor presentation state (DECSCUSR / DECTCEM) ──────────────
+
+    #[test]
+    fn decscusr_parameter_mapping() {
+        let mut vt = VirtualTerminal::new(10, 4);
+        // Default: blinking block.
+        assert_eq!(vt.cursor_shape(), CursorShape::Block);
+        assert!(vt.cursor_blink());
+
+        let cases: [(&[u8], CursorShape, bool); 6] = [
+            (b"\x1b[1 q", CursorShape::Block, true),
+            (b"\x1b[2 q", CursorShape::Block, false),
+            (b"\x1b[3 q", CursorShape::Underline, true),
+            (b"\x1b[4 q", CursorShape::Underline, false),
+            (b"\x1b[5 q", CursorShape::Bar, true),
+            (b"\x1b[6 q", CursorShape::Bar, false),
+        ];
+        for (bytes, shape, blink) in cases {
+            vt.feed(bytes);
+            assert_eq!(vt.cursor_shape(), shape, "{bytes:?}");
+            assert_eq!(vt.cursor_blink(), blink, "{bytes:?}");
+        }
+
+        // 0 restores the default (blinking block).
+        vt.feed(b"\x1b[0 q");
+        assert_eq!(vt.cursor_shape(), CursorShape::Block);
+        assert!(vt.cursor_blink());
+
+        // Out-of-spec parameter is ignored.
+        vt.feed(b"\x1b[4 q");
+        vt.feed(b"\x1b[9 q");
+        assert_eq!(vt.cursor_shape(), CursorShape::Underline);
+    }
+
+    #[test]
+    fn cursor_generation_bumps_exactly_on_changes() {
+        let mut vt = VirtualTerminal::new(10, 4);
+        let g0 = vt.cursor_generation();
+
+        // Printing and moving the cursor is not a presentation change.
+        vt.feed(b"hello\x1b[2;2H");
+        assert_eq!(vt.cursor_generation(), g0);
+
+        // Hide: one bump. Hiding again: no bump.
+        vt.feed(b"\x1b[?25l");
+        assert_eq!(vt.cursor_generation(), g0 + 1);
+        vt.feed(b"\x1b[?25l");
+        assert_eq!(vt.cursor_generation(), g0 + 1);
+
+        // Shape change: one bump. Same shape again: no bump.
+        vt.feed(b"\x1b[4 q");
+        assert_eq!(vt.cursor_generation(), g0 + 2);
+        vt.feed(b"\x1b[4 q");
+        assert_eq!(vt.cursor_generation(), g0 + 2);
+
+        // Show again: one bump.
+        vt.feed(b"\x1b[?25h");
+        assert_eq!(vt.cursor_generation(), g0 + 3);
+    }
+
+    #[test]
+    fn cursor_state_snapshot_reflects_everything() {
+        let mut vt = VirtualTerminal::new(20, 5);
+        vt.feed(b"\x1b[3;4H\x1b[5 q\x1b[?25l");
+        assert_eq!(
+            vt.cursor_state(),
+            CursorState {
+                row: 2,
+                col: 3,
+                visible: false,
+                shape: CursorShape::Bar,
+                blink: true,
+            }
+        );
+    }
+
+    #[test]
+    fn visibility_and_shape_persist_across_alt_screen() {
+        let mut vt = VirtualTerminal::new(10, 4);
+        vt.feed(b"\x1b[?25l\x1b[4 q");
+        let generation = vt.cursor_generation();
+
+        // Presentation is global (xterm semantics): entering and leaving
+        // the alt screen leaves it untouched, and the generation counter
+        // does not churn.
+        vt.feed(b"\x1b[?1049h");
+        assert!(!vt.cursor_visible());
+        assert_eq!(vt.cursor_shape(), CursorShape::Underline);
+        vt.feed(b"\x1b[?25h");
+        vt.feed(b"\x1b[?1049l");
+        assert!(vt.cursor_visible(), "visibility is global, not per-screen");
+        assert_eq!(vt.cursor_shape(), CursorShape::Underline);
+        assert_eq!(vt.cursor_generation(), generation + 1);
+    }
+
+    #[test]
+    fn decsc_decrc_round_trip_restores_position_not_presentation() {
+        let mut vt = VirtualTerminal::new(20, 5);
+        vt.feed(b"\x1b[2;3H\x1b7"); // save at (row 1, col 2)
+        vt.feed(b"\x1b[5;10H\x1b[6 q\x1b[?25l");
+        vt.feed(b"\x1b8"); // restore
+
+        let state = vt.cursor_state();
+        assert_eq!((state.row, state.col), (1, 2), "position restored");
+        // Shape and visibility are not part of DECSC state.
+        assert_eq!(state.shape, CursorShape::Bar);
+        assert!(!state.visible);
+    }
+
+    #[test]
+    fn full_reset_restores_default_cursor_presentation() {
+        let mut vt = VirtualTerminal::new(10, 4);
+        vt.feed(b"\x1b[6 q\x1b[?25l");
+        let generation = vt.cursor_generation();
+        vt.feed(b"\x1bc"); // RIS
+        assert_eq!(vt.cursor_shape(), CursorShape::Block);
+        assert!(vt.cursor_blink());
+        assert!(vt.cursor_visible());
+        assert!(vt.cursor_generation() > generation);
+    }
+
+    // ── DEC line attributes (DECDWL/DECDHL) ─────────────────────────
+
+    #[test]
+    fn dec_line_attributes_parse_into_rows() {
+        let mut vt = VirtualTerminal::new(20, 4);
+        vt.feed(b"\x1b#6BANNER");
+        assert_eq!(vt.line_attr(0), VLineAttr::DoubleWidth);
+        assert_eq!(vt.row_text(0), "BANNER");
+
+        vt.feed(b"\x1b[2;1H\x1b#3TOP\x1b[3;1H\x1b#4TOP");
+        assert_eq!(vt.line_attr(1), VLineAttr::DoubleHeightTop);
+        assert_eq!(vt.line_attr(2), VLineAttr::DoubleHeightBottom);
+
+        // DECSWL restores normal.
+        vt.feed(b"\x1b[1;1H\x1b#5");
+        assert_eq!(vt.line_attr(0), VLineAttr::Normal);
+    }
+
+    #[test]
+    fn line_attrs_scroll_with_content() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        vt.feed(b"\x1b#6wide");
+        assert_eq!(vt.line_attr(0), VLineAttr::DoubleWidth);
+        // Scroll once: the attribute moves up and off with its line.
+        vt.feed(b"\x1b[3;1Hx\r\n");
+        assert_eq!(vt.line_attr(2), VLineAttr::Normal);
+        assert_eq!(
+            vt.line_attr(0),
+            VLineAttr::Normal,
+            "wide line scrolled away"
+        );
+    }
+
+    #[test]
+    fn presenter_emission_round_trips_into_parser() {
+        use ftui_core::terminal_capabilities::TerminalCapabilities;
+        use ftui_render::buffer::{Buffer, LineAttr};
+        use ftui_render::cell::Cell;
+        use ftui_render::diff::BufferDiff;
+        use ftui_render::presenter::Presenter;
+
+        // Render-side: a double-width banner row.
+        let mut caps = TerminalCapabilities::basic();
+        caps.double_width = true;
+        let mut presenter = Presenter::new(Vec::new(), caps);
+        let old = Buffer::new(12, 2);
+        let mut new = Buffer::new(12, 2);
+        for (i, ch) in "BIG".chars().enumerate() {
+            new.set(i as u16, 0, Cell::from_char(ch));
+        }
+        new.set_line_attr(0, LineAttr::DoubleWidth);
+        let diff = BufferDiff::compute(&old, &new);
+        presenter.present(&new, &diff).unwrap();
+        let bytes = presenter.into_inner().unwrap();
+
+        // Parser-side: the VT reconstructs the same line attribute.
+        let mut vt = VirtualTerminal::new(12, 2);
+        vt.feed(&bytes);
+        assert_eq!(vt.line_attr(0), VLineAttr::DoubleWidth);
+        assert!(vt.row_text(0).starts_with("BIG"));
+    }
+
+    #[test]
+    fn line_attrs_follow_reverse_index_scroll() {
+        let mut vt = VirtualTerminal::new(10, 3);
+        vt.feed(b"\x1b[2;1H\x1b#6wide"); // DoubleWidth on row 1
+        assert_eq!(vt.line_attr(1), VLineAttr::DoubleWidth);
+        // Reverse index at the top margin scrolls everything down.
+        vt.feed(b"\x1b[1;1H\x1bM");
+        assert_eq!(
+            vt.line_attr(2),
+            VLineAttr::DoubleWidth,
+            "attr moved with line"
+        );
+        assert_eq!(vt.line_attr(1), VLineAttr::Normal);
+        assert_eq!(vt.line_attr(0), VLineAttr::Normal);
+    }
 }
@@ -55,7 +55,7 @@
 use smallvec::SmallVec;
 
 use crate::budget::DegradationLevel;
-use crate::cell::{Cell, GraphemeId};
+use crate::cell::{Cell, CellContent, GraphemeId};
 use ftui_core::geometry::Rect;
 
 /// Maximum number of dirty spans per row before falling back to full-row scan.
@@ -206,6 +206,33 @@ pub struct DirtySpanStats {
 /// buffer.set(0, 0, Cell::from_char('H'));
 /// buffer.set(1, 0, Cell::from_char('i'));
 /// ```
+/// DEC line attribute for one buffer row (DECDWL/DECDHL).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineAttr {
+    /// Single-width, single-height (DECSWL, the default).
+    #[default]
+    Normal,
+    /// Double-width line (DECDWL, `ESC # 6`): half the logical columns.
+    DoubleWidth,
+    /// Top half of a double-height line (DECDHL, `ESC # 3`).
+    DoubleHeightTop,
+    /// Bottom half of a double-height line (DECDHL, `ESC # 4`).
+    DoubleHeightBottom,
+}
+
+impl LineAttr {
+    /// The `ESC # x` digit that selects this attribute.
+    #[must_use]
+    pub const fn dec_digit(self) -> u8 {
+        match self {
+            Self::Normal => b'5',
+            Self::DoubleHeightTop => b'3',
+            Self::DoubleHeightBottom => b'4',
+            Self::DoubleWidth => b'6',
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Buffer {
     width: u16,
@@ -227,6 +254,10 @@ pub struct Buffer {
     dirty_rows: Vec<bool>,
     /// Per-row dirty span tracking for sparse diff scans.
     dirty_spans: Vec<DirtySpanRow>,
+    /// Per-row DEC line attributes (DECDWL/DECDHL).
+    ///
+    /// Invariant: `line_attrs.len() == height`.
+    line_attrs: Vec<LineAttr>,
     /// Dirty-span tracking configuration.
     dirty_span_config: DirtySpanConfig,
     /// Number of span overflow events since the last `clear_dirty()`.
@@ -274,6 +305,7 @@ impl Buffer {
             dirty_rows: vec![true; height as usize],
             // Start with full-row dirty spans to force initial full scan.
             dirty_spans,
+            line_attrs: vec![LineAttr::Normal; height as usize],
             dirty_span_config: DirtySpanConfig::default(),
             dirty_span_overflows: 0,
             dirty_bits,
@@ -294,6 +326,39 @@ impl Buffer {
         self.height
     }
 
+    /// DEC line attribute of row `y` (`Normal` when out of range).
+    #[inline]
+    #[must_use]
+    pub fn line_attr(&self, y: u16) -> LineAttr {
+        self.line_attrs
+            .get(usize::from(y))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Set row `y`'s DEC line attribute, marking the row dirty.
+    pub fn set_line_attr(&mut self, y: u16, attr: LineAttr) {
+        if let Some(slot) = self.line_attrs.get_mut(usize::from(y))
+            && *slot != attr
+        {
+            *slot = attr;
+            self.mark_dirty_span(y, 0, self.width);
+        }
+    }
+
+    /// Logical column count of row `y`: double-width rows hold half the
+    /// buffer's columns.
+    #[inline]
+    #[must_use]
+    pub fn logical_width(&self, y: u16) -> u16 {
+        match self.line_attr(y) {
+            LineAttr::Normal => self.width,
+            LineAttr::DoubleWidth | LineAttr::DoubleHeightTop | LineAttr::DoubleHeightBottom => {
+                self.width / 2
+            }
+        }
+    }
+
     /// Total number of cells.
     #[inline]
     pub fn len(&self) -> usize {
@@ -674,6 +739,12 @@ impl Buffer {
         let mut touched = false;
         let mut min_x = x;
         let mut max_x = x;
+        // Orphaned halves are blanked with the incoming cell's background so
+        // the repair doesn't punch a default-colored hole into styled areas.
+        let blank = Cell {
+            bg: new_cell.bg,
+            ..Cell::default()
+        };
 
         // Case 1: Overwriting a Wide Head
         if current.content.width() > 1 {
@@ -689,7 +760,7 @@ impl Buffer {
                 if let Some(tail_idx) = self.index(cx, y)
                     && self.cells[tail_idx].is_continuation()
                 {
-                    self.cells[tail_idx] = Cell::default();
+                    self.cells[tail_idx] = blank;
                     touched = true;
                     min_x = min_x.min(cx);
                     max_x = max_x.max(cx);
@@ -713,7 +784,7 @@ impl Buffer {
                         if (back_x as usize + width) > x as usize {
                             // This head owns the cell we are overwriting.
                             // Clear the head.
-                            self.cells[h_idx] = Cell::default();
+                            self.cells[h_idx] = blank;
                             touched = true;
                             min_x = min_x.min(back_x);
                             max_x = max_x.max(back_x);
@@ -728,7 +799,7 @@ impl Buffer {
                                     // Note: tail_idx might be our current `idx`.
                                     // We can clear it; `set` will overwrite it in a moment.
                                     if self.cells[tail_idx].is_continuation() {
-                                        self.cells[tail_idx] = Cell::default();
+                                        self.cells[tail_idx] = blank;
                                         touched = true;
                                         min_x = min_x.min(cx);
                                         max_x = max_x.max(cx);
@@ -967,14 +1038,52 @@ impl Buffer {
 
     /// Set the cell at (x, y) without scissor or opacity processing.
     ///
-    /// This is faster but bypasses clipping and transparency.
+    /// This is faster but bypasses clipping and transparency. It still
+    /// keeps wide-character pairs consistent: overwriting either half of
+    /// a wide pair blanks the orphaned half (with the new background),
+    /// wide content writes its continuation cells, and a wide character
+    /// whose continuation would fall past the last column degrades to a
+    /// styled space instead of being silently truncated.
     /// Does nothing if coordinates are out of bounds.
     #[inline]
     pub fn set_raw(&mut self, x: u16, y: u16, cell: Cell) {
-        if let Some(idx) = self.index(x, y) {
-            self.cells[idx] = cell;
-            self.mark_dirty_span(y, x, x.saturating_add(1));
+        let Some(idx) = self.index(x, y) else {
+            return;
+        };
+        let width = cell.content.width();
+
+        // Wide character that cannot fully fit on the row: fall back to a
+        // styled space so no half-glyph is ever emitted.
+        if width > 1 && usize::from(x) + width > usize::from(self.width) {
+            let fallback = Cell {
+                content: CellContent::from_char(' '),
+                ..cell
+            };
+            self.set_raw(x, y, fallback);
+            return;
+        }
+
+        let mut span_start = x;
+        let mut span_end = x.saturating_add(width.max(1) as u16);
+        if let Some(span) = self.cleanup_overlap(x, y, &cell) {
+            span_start = span_start.min(span.x0);
+            span_end = span_end.max(span.x1);
+        }
+        self.cells[idx] = cell;
+
+        if width > 1 {
+            for i in 1..width {
+                // In range: checked against self.width above.
+                let cx = x + i as u16;
+                if let Some(span) = self.cleanup_overlap(cx, y, &Cell::CONTINUATION) {
+                    span_start = span_start.min(span.x0);
+                    span_end = span_end.max(span.x1);
+                }
+                let tail_idx = self.index_unchecked(cx, y);
+                self.cells[tail_idx] = Cell::CONTINUATION;
+            }
         }
+        self.mark_dirty_span(y, span_start, span_end);
     }
 
     /// Fill a rectangular region with the given cell.
@@ -1082,10 +1191,11 @@ impl Buffer {
         self.pop_scissor();
     }
 
-    /// Clear all cells to the default.
+    /// Clear all cells to the default (line attributes reset to Normal).
     #[inline]
     pub fn clear(&mut self) {
         self.cells.fill(Cell::default());
+        self.line_attrs.fill(LineAttr::Normal);
         self.mark_all_dirty();
     }
 
@@ -4034,4 +4144,85 @@ mod tests {
 
         buf.pop_scissor();
     }
+
+    // ── Wide-pair boundary repair ───────────────────────────────────
+
+    #[test]
+    fn set_overwriting_wide_head_blanks_tail_with_new_bg() {
+        let mut buf = Buffer::new(4, 1);
+        buf.set(0, 0, Cell::from_char('中'));
+        assert!(buf.get(1, 0).unwrap().is_continuation());
+
+        let red = PackedRgba::rgb(200, 0, 0);
+        let new_cell = Cell {
+            bg: red,
+            ..Cell::from_char('a')
+        };
+        buf.set(0, 0, new_cell);
+
+        assert_eq!(buf.get(0, 0).unwrap().content.as_char(), Some('a'));
+        let orphan = buf.get(1, 0).unwrap();
+        assert!(!orphan.is_continuation(), "tail must be repaired");
+        assert_eq!(orphan.bg, red, "orphan blank carries the new background");
+    }
+
+    #[test]
+    fn set_overwriting_continuation_blanks_head_with_new_bg() {
+        let mut buf = Buffer::new(4, 1);
+        buf.set(0, 0, Cell::from_char('中'));
+
+        let blue = PackedRgba::rgb(0, 0, 200);
+        let new_cell = Cell {
+            bg: blue,
+            ..Cell::from_char('x')
+        };
+        buf.set(1, 0, new_cell);
+
+        let head = buf.get(0, 0).unwrap();
+        assert_ne!(head.content.as_char(), Some('中'), "head must be repaired");
+        assert_eq!(head.bg, blue);
+        assert_eq!(buf.get(1, 0).unwrap().content.as_char(), Some('x'));
+    }
+
+    #[test]
+    fn set_raw_overwriting_left_half_repairs_tail() {
+        let mut buf = Buffer::new(4, 1);
+        buf.set_raw(0, 0, Cell::from_char('中'));
+        assert!(buf.get(1, 0).unwrap().is_continuation());
+
+        buf.set_raw(0, 0, Cell::from_char('a'));
+        assert!(!buf.get(1, 0).unwrap().is_continuation());
+    }
+
+    #[test]
+    fn set_raw_overwriting_right_half_repairs_head() {
+        let mut buf = Buffer::new(4, 1);
+        buf.set_raw(0, 0, Cell::from_char('中'));
+        buf.set_raw(1, 0, Cell::from_char('x'));
+
+        assert_ne!(buf.get(0, 0).unwrap().content.as_char(), Some('中'));
+        assert_eq!(buf.get(1, 0).unwrap().content.as_char(), Some('x'));
+    }
+
+    #[test]
+    fn set_raw_wide_writes_continuation_cells() {
+        let mut buf = Buffer::new(4, 1);
+        buf.set_raw(0, 0, Cell::from_char('中'));
+        assert!(buf.get(1, 0).unwrap().is_continuation());
+    }
+
+    #[test]
+    fn set_raw_wide_at_last_column_degrades_to_styled_space() {
+        let mut buf = Buffer::new(4, 1);
+        let green = PackedRgba::rgb(0, 200, 0);
+        let cell = Cell {
+            bg: green,
+            ..Cell::from_char('中')
+        };
+        buf.set_raw(3, 0, cell);
+
+        let written = buf.get(3, 0).unwrap();
+        assert_eq!(written.content.as_char(), Some(' '), "fallback is a space");
+        assert_eq!(written.bg, green, "fallback keeps the cell style");
+    }
 }
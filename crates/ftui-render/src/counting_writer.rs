@@ -26,16 +26,40 @@
 use std::io::{self, Write};
 use web_time::{Duration, Instant};
 
+/// Observer for bytes flowing through a [`CountingWriter`].
+///
+/// Taps see every byte in write order before buffering downstream. They must
+/// never block: implementations that mirror output elsewhere (e.g. session
+/// recording) should degrade by dropping rather than stalling the render path.
+pub trait WriteTap: Send {
+    /// Called with each chunk written through the counting writer.
+    fn on_write(&mut self, bytes: &[u8]);
+
+    /// Called when the counting writer is flushed.
+    fn on_flush(&mut self) {}
+}
+
 /// A write wrapper that counts bytes written.
 ///
 /// Wraps any `Write` implementation and tracks the total number of bytes
 /// written through it. The counter can be reset between operations.
-#[derive(Debug)]
 pub struct CountingWriter<W> {
     /// The underlying writer.
     inner: W,
     /// Total bytes written since last reset.
     bytes_written: u64,
+    /// Optional observer mirroring the byte stream.
+    tap: Option<Box<dyn WriteTap>>,
+}
+
+impl<W: std::fmt::Debug> std::fmt::Debug for CountingWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountingWriter")
+            .field("inner", &self.inner)
+            .field("bytes_written", &self.bytes_written)
+            .field("tap", &self.tap.as_ref().map(|_| "WriteTap"))
+            .finish()
+    }
 }
 
 impl<W> CountingWriter<W> {
@@ -45,9 +69,15 @@ impl<W> CountingWriter<W> {
         Self {
             inner,
             bytes_written: 0,
+            tap: None,
         }
     }
 
+    /// Install (or remove) an observer that mirrors the byte stream.
+    pub fn set_tap(&mut self, tap: Option<Box<dyn WriteTap>>) {
+        self.tap = tap;
+    }
+
     /// Get the number of bytes written since the last reset.
     #[inline]
     pub fn bytes_written(&self) -> u64 {
@@ -86,16 +116,26 @@ impl<W: Write> Write for CountingWriter<W> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         let n = self.inner.write(buf)?;
         self.bytes_written += n as u64;
+        if let Some(tap) = &mut self.tap {
+            tap.on_write(&buf[..n]);
+        }
         Ok(n)
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.inner.flush()
+        self.inner.flush()?;
+        if let Some(tap) = &mut self.tap {
+            tap.on_flush();
+        }
+        Ok(())
     }
 
     fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
         self.inner.write_all(buf)?;
         self.bytes_written += buf.len() as u64;
+        if let Some(tap) = &mut self.tap {
+            tap.on_write(buf);
+        }
         Ok(())
     }
 }
@@ -39,7 +39,7 @@
 //! ```
 
 use crate::buffer::{Buffer, DirtySpan};
-use crate::cell::Cell;
+use crate::cell::{Cell, GraphemeId};
 
 // =============================================================================
 // Block-based Row Scanning (autovec-friendly)
@@ -766,16 +766,90 @@ fn compute_changes(old: &Buffer, new: &Buffer, changes: &mut Vec<(u16, u16)>) {
         let old_row = &old_cells[row_start..row_start + w];
         let new_row = &new_cells[row_start..row_start + w];
 
+        // A line-attribute change restyles the whole row on screen even
+        // when every cell is bit-identical.
+        if old.line_attr(y) != new.line_attr(y) {
+            for x in 0..width {
+                changes.push((x, y));
+            }
+            continue;
+        }
+
         // Scan for changed cells using blockwise row scan.
         // This avoids a full-row equality pre-scan and prevents
         // double-scanning rows that contain changes.
         scan_row_changes(old_row, new_row, y, changes);
     }
 
+    expand_wide_pairs(old, new, changes);
+
     #[cfg(feature = "tracing")]
     tracing::trace!(changes = changes.len(), "diff computed");
 }
 
+/// Expand changed cells to cover whole wide-character pairs.
+///
+/// If a dirty cell is half of a wide pair — in either buffer — the other
+/// half is pulled into the change set so the presenter always rewrites
+/// pairs atomically. Without this, a change landing on only one half
+/// leaves the terminal showing an orphaned half-glyph.
+fn expand_wide_pairs(old: &Buffer, new: &Buffer, changes: &mut Vec<(u16, u16)>) {
+    let width = new.width();
+    let mut extra: Vec<(u16, u16)> = Vec::new();
+
+    let cover_pair = |buf: &Buffer, x: u16, y: u16, extra: &mut Vec<(u16, u16)>| {
+        let Some(cell) = buf.get(x, y) else {
+            return;
+        };
+        let w = cell.content.width();
+        if w > 1 {
+            // Wide head: include its continuation cells.
+            for i in 1..w {
+                let cx = x.saturating_add(i as u16);
+                if cx < width {
+                    extra.push((cx, y));
+                }
+            }
+        } else if cell.is_continuation() {
+            // Continuation: walk left to the owning head, include the pair.
+            let limit = x.saturating_sub(u16::from(GraphemeId::MAX_WIDTH));
+            let mut back_x = x;
+            while back_x > limit {
+                back_x -= 1;
+                let Some(head) = buf.get(back_x, y) else {
+                    break;
+                };
+                if head.is_continuation() {
+                    continue;
+                }
+                let head_w = head.content.width();
+                if usize::from(back_x) + head_w > usize::from(x) {
+                    extra.push((back_x, y));
+                    for i in 1..head_w {
+                        let cx = back_x.saturating_add(i as u16);
+                        if cx < width {
+                            extra.push((cx, y));
+                        }
+                    }
+                }
+                break;
+            }
+        }
+    };
+
+    for &(x, y) in changes.iter() {
+        cover_pair(new, x, y, &mut extra);
+        cover_pair(old, x, y, &mut extra);
+    }
+
+    if !extra.is_empty() {
+        changes.extend_from_slice(&extra);
+        // Presenter planning assumes row-major order without duplicates.
+        changes.sort_unstable_by_key(|&(x, y)| (y, x));
+        changes.dedup();
+    }
+}
+
 fn compute_dirty_changes(
     old: &Buffer,
     new: &Buffer,
@@ -909,6 +983,15 @@ fn compute_dirty_changes(
         let old_row = &old_cells[row_start..row_start + w];
         let new_row = &new_cells[row_start..row_start + w];
 
+        // A line-attribute change restyles the whole row even when the
+        // cells are bit-identical.
+        if old.line_attr(y) != new.line_attr(y) {
+            for x in 0..width {
+                changes.push((x, y));
+            }
+            continue;
+        }
+
         // Even for dirty rows, row-skip fast path applies:
         // a row may be marked dirty but end up identical after compositing.
         if old_row == new_row {
@@ -1083,6 +1166,7 @@ impl BufferDiff {
             &self.tile_config,
             &mut self.last_tile_stats,
         );
+        expand_wide_pairs(old, new, &mut self.changes);
     }
 
     /// Populate the diff with all cells (full redraw) reusing existing capacity.
@@ -4555,4 +4639,70 @@ mod span_edge_cases {
             );
         }
     }
+
+    // ── Wide-pair span expansion ────────────────────────────────────
+
+    #[test]
+    fn diff_expands_continuation_change_to_include_head() {
+        let mut old = Buffer::new(4, 1);
+        let mut new = Buffer::new(4, 1);
+        new.set(0, 0, Cell::from_char('中'));
+        // Old frame has the same head but junk where the continuation
+        // belongs, so only the continuation cell differs.
+        old.cells_mut()[0] = Cell::from_char('中');
+        old.cells_mut()[1] = Cell::from_char('X');
+
+        let diff = BufferDiff::compute(&old, &new);
+        assert_eq!(
+            diff.changes(),
+            &[(0u16, 0u16), (1u16, 0u16)],
+            "head must be pulled into the change set"
+        );
+    }
+
+    #[test]
+    fn diff_expands_head_change_to_include_continuation() {
+        let old = Buffer::new(4, 1);
+        let mut new = Buffer::new(4, 1);
+        // Wide head written directly without its continuation: the
+        // continuation position is bits-equal across buffers but must be
+        // repainted with the pair.
+        new.cells_mut()[0] = Cell::from_char('中');
+
+        let diff = BufferDiff::compute(&old, &new);
+        assert_eq!(diff.changes(), &[(0u16, 0u16), (1u16, 0u16)]);
+    }
+
+    #[test]
+    fn diff_expands_old_pair_destroyed_by_narrow_write() {
+        let mut old = Buffer::new(4, 1);
+        old.cells_mut()[0] = Cell::from_char('中');
+        old.cells_mut()[1] = Cell::CONTINUATION;
+        let mut new = Buffer::new(4, 1);
+        new.cells_mut()[0] = Cell::from_char('中');
+        new.cells_mut()[1] = Cell::from_char('x');
+
+        let diff = BufferDiff::compute(&old, &new);
+        // The narrow write destroys the old pair; the head must repaint.
+        assert_eq!(diff.changes(), &[(0u16, 0u16), (1u16, 0u16)]);
+    }
+
+    #[test]
+    fn line_attr_change_dirties_whole_row() {
+        let old = Buffer::new(4, 2);
+        let mut new = Buffer::new(4, 2);
+        // Identical cells; only the line attribute differs.
+        new.set_line_attr(1, crate::buffer::LineAttr::DoubleWidth);
+
+        let diff = BufferDiff::compute(&old, &new);
+        assert_eq!(
+            diff.changes(),
+            &[(0u16, 1u16), (1, 1), (2, 1), (3, 1)],
+            "whole row dirtied by the attribute change"
+        );
+
+        // Dirty-hint path agrees.
+        let dirty = BufferDiff::compute_dirty(&old, &new);
+        assert_eq!(dirty.changes(), diff.changes());
+    }
 }
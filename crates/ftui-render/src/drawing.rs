@@ -86,6 +86,9 @@ impl BorderChars {
     };
 }
 
+/// Default tab stop interval for text-drawing helpers.
+pub const DEFAULT_TAB_WIDTH: u8 = 8;
+
 /// Extension trait for drawing on a Buffer.
 pub trait Draw {
     /// Draw a horizontal line of cells.
@@ -110,6 +113,8 @@ pub trait Draw {
     ///
     /// Like `print_text` but stops at `max_x` (exclusive) instead of the
     /// buffer edge. Returns the x position after the last character.
+    /// Tabs expand against stops every [`DEFAULT_TAB_WIDTH`] columns
+    /// (relative to the draw origin `x`), filling with styled spaces.
     fn print_text_clipped(
         &mut self,
         x: u16,
@@ -117,6 +122,24 @@ pub trait Draw {
         text: &str,
         base_cell: Cell,
         max_x: u16,
+    ) -> u16 {
+        self.print_text_tabs(x, y, text, base_cell, max_x, DEFAULT_TAB_WIDTH)
+    }
+
+    /// Print text with a configurable tab width.
+    ///
+    /// `\t` advances to the next multiple of `tab_width` columns relative
+    /// to the draw origin `x`, writing spaces that carry `base_cell`'s
+    /// colors and attributes so backgrounds stay contiguous. A zero tab
+    /// width renders tabs as a single space.
+    fn print_text_tabs(
+        &mut self,
+        x: u16,
+        y: u16,
+        text: &str,
+        base_cell: Cell,
+        max_x: u16,
+        tab_width: u8,
     ) -> u16;
 
     /// Draw a border around a rectangle using the given characters.
@@ -194,13 +217,14 @@ impl Draw for Buffer {
         self.print_text_clipped(x, y, text, base_cell, self.width())
     }
 
-    fn print_text_clipped(
+    fn print_text_tabs(
         &mut self,
         x: u16,
         y: u16,
         text: &str,
         base_cell: Cell,
         max_x: u16,
+        tab_width: u8,
     ) -> u16 {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -214,6 +238,28 @@ impl Draw for Buffer {
                 continue;
             };
 
+            // Tab: fill with styled spaces to the next tab stop (stops are
+            // relative to the draw origin so widget columns line up).
+            if first == '\t' {
+                let filler = Cell {
+                    content: CellContent::from_char(' '),
+                    fg: base_cell.fg,
+                    bg: base_cell.bg,
+                    attrs: base_cell.attrs,
+                };
+                let width = u16::from(tab_width.max(1));
+                let offset = cx.saturating_sub(x);
+                let advance = width - offset % width;
+                for _ in 0..advance {
+                    if cx >= max_x {
+                        break;
+                    }
+                    self.set_fast(cx, y, filler);
+                    cx = cx.saturating_add(1);
+                }
+                continue;
+            }
+
             // Buffer has no GraphemePool, so multi-codepoint graphemes must fall back to a
             // single char. We still preserve the grapheme's display width to keep column
             // alignment deterministic, but we *must* also fill the extra cells so we don't
@@ -536,6 +582,43 @@ mod tests {
         assert_eq!(end_x, 5);
     }
 
+    #[test]
+    fn tabs_align_columns_at_width_8() {
+        let mut buffer = Buffer::new(40, 2);
+        let cell = Cell::from_char(' ');
+        buffer.print_text(0, 0, "ab\tx", cell);
+        buffer.print_text(0, 1, "a\tx", cell);
+        // Both 'x' land on the same tab stop (column 8).
+        assert_eq!(buffer.get(8, 0).unwrap().content.as_char(), Some('x'));
+        assert_eq!(buffer.get(8, 1).unwrap().content.as_char(), Some('x'));
+    }
+
+    #[test]
+    fn tabs_align_columns_at_width_4() {
+        let mut buffer = Buffer::new(40, 2);
+        let cell = Cell::from_char(' ');
+        buffer.print_text_tabs(0, 0, "ab\tx", cell, 40, 4);
+        buffer.print_text_tabs(0, 1, "a\tx", cell, 40, 4);
+        assert_eq!(buffer.get(4, 0).unwrap().content.as_char(), Some('x'));
+        assert_eq!(buffer.get(4, 1).unwrap().content.as_char(), Some('x'));
+        // Tab at a stop advances a full stop.
+        buffer.print_text_tabs(0, 0, "abcd\tx", cell, 40, 4);
+        assert_eq!(buffer.get(8, 0).unwrap().content.as_char(), Some('x'));
+    }
+
+    #[test]
+    fn tab_fill_carries_style() {
+        let mut buffer = Buffer::new(20, 1);
+        let mut cell = Cell::from_char(' ');
+        cell.bg = crate::cell::PackedRgba::rgb(1, 2, 3);
+        buffer.print_text(0, 0, "a\tb", cell);
+        for x in 1..8 {
+            let filled = buffer.get(x, 0).unwrap();
+            assert_eq!(filled.content.as_char(), Some(' '), "col {x}");
+            assert_eq!(filled.bg, cell.bg, "col {x} carries the bg");
+        }
+    }
+
     #[test]
     fn print_text_clipped_stops_at_max_x() {
         let mut buf = Buffer::new(20, 1);
@@ -380,6 +380,10 @@ pub struct Frame<'a> {
     /// Whether cursor should be visible.
     pub cursor_visible: bool,
 
+    /// A focused secret widget (masked input) rendered this frame; the
+    /// runtime tightens telemetry/recording redaction while set.
+    pub secret_input: bool,
+
     /// Current degradation level from the render budget.
     ///
     /// Widgets can read this to skip expensive operations when the
@@ -410,6 +414,7 @@ impl<'a> Frame<'a> {
             widget_signals: Vec::new(),
             cursor_position: None,
             cursor_visible: true,
+            secret_input: false,
             degradation: DegradationLevel::Full,
             arena: None,
         }
@@ -428,6 +433,7 @@ impl<'a> Frame<'a> {
             widget_signals: Vec::new(),
             cursor_position: None,
             cursor_visible: true,
+            secret_input: false,
             degradation: DegradationLevel::Full,
             arena: None,
         }
@@ -452,6 +458,7 @@ impl<'a> Frame<'a> {
             widget_signals: Vec::new(),
             cursor_position: None,
             cursor_visible: true,
+            secret_input: false,
             degradation: DegradationLevel::Full,
             arena: None,
         }
@@ -470,6 +477,7 @@ impl<'a> Frame<'a> {
             widget_signals: Vec::new(),
             cursor_position: None,
             cursor_visible: true,
+            secret_input: false,
             degradation: DegradationLevel::Full,
             arena: None,
         }
@@ -675,23 +683,44 @@ impl<'a> Draw for Frame<'a> {
         self.print_text_clipped(x, y, text, base_cell, self.width())
     }
 
-    fn print_text_clipped(
+    fn print_text_tabs(
         &mut self,
         x: u16,
         y: u16,
         text: &str,
         base_cell: Cell,
         max_x: u16,
+        tab_width: u8,
     ) -> u16 {
         let mut cx = x;
         for grapheme in text.graphemes(true) {
-            let width = grapheme_width(grapheme);
-            if width == 0 {
+            if cx >= max_x {
+                break;
+            }
+
+            // Tab: styled-space fill to the next stop relative to `x`.
+            if grapheme == "\t" {
+                let filler = Cell {
+                    content: CellContent::from_char(' '),
+                    fg: base_cell.fg,
+                    bg: base_cell.bg,
+                    attrs: base_cell.attrs,
+                };
+                let stop = u16::from(tab_width.max(1));
+                let advance = stop - cx.saturating_sub(x) % stop;
+                for _ in 0..advance {
+                    if cx >= max_x {
+                        break;
+                    }
+                    self.buffer.set_fast(cx, y, filler);
+                    cx = cx.saturating_add(1);
+                }
                 continue;
             }
 
-            if cx >= max_x {
-                break;
+            let width = grapheme_width(grapheme);
+            if width == 0 {
+                continue;
             }
 
             // Don't start a wide char if it won't fit
@@ -0,0 +1,432 @@
+#![forbid(unsafe_code)]
+
+//! Per-frame output batching for the presenter.
+//!
+//! The presenter emits many small writes (per span, per SGR change); on
+//! an unbuffered fd each would be a syscall. [`FrameBatcher`] accumulates
+//! the frame into one reusable byte buffer (cleared, never reallocated,
+//! per frame) and hands the sink a single write on flush — or, under
+//! [`FlushStrategy::Chunked`], several bounded writes that are **never
+//! split inside an escape sequence**: a small VT-state scanner tracks the
+//! last safe boundary, so sync brackets and cursor parking stay atomic
+//! per chunk regardless of the chunk size.
+
+use std::io::{self, Write};
+
+/// When accumulated frame bytes reach the sink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlushStrategy {
+    /// Accumulate the whole frame, write once on flush (default).
+    #[default]
+    PerFrame,
+    /// Pass every write straight through (debugging; one syscall per
+    /// presenter write, the pre-batching behavior).
+    Immediate,
+    /// Accumulate, but once the buffer exceeds `n` bytes emit it early —
+    /// cut at the last escape-sequence boundary — to bound latency on
+    /// very large frames.
+    Chunked(usize),
+}
+
+/// Minimal VT sequence scanner: tracks whether a byte position is inside
+/// an escape sequence so chunk cuts land only on boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum SeqState {
+    /// Not inside any sequence.
+    #[default]
+    Ground,
+    /// Saw ESC, dispatch undetermined.
+    Escape,
+    /// Inside a CSI sequence (ends at a final byte `@`..=`~`).
+    Csi,
+    /// Inside an OSC/DCS/APC string (ends at BEL or ESC `\`).
+    OscString,
+    /// Inside an OSC/DCS string and saw ESC (ST pending).
+    OscEscape,
+}
+
+impl SeqState {
+    fn advance(self, byte: u8) -> Self {
+        match self {
+            Self::Ground => match byte {
+                0x1b => Self::Escape,
+                _ => Self::Ground,
+            },
+            Self::Escape => match byte {
+                b'[' => Self::Csi,
+                b']' | b'P' | b'_' | b'^' | b'X' => Self::OscString,
+                // Two-byte sequences (ESC + final, incl. ESC # digit
+                // intermediates) end after their intermediate/final run;
+                // intermediates are 0x20..=0x2f.
+                0x20..=0x2f => Self::Escape,
+                _ => Self::Ground,
+            },
+            Self::Csi => match byte {
+                0x40..=0x7e => Self::Ground,
+                _ => Self::Csi,
+            },
+            Self::OscString => match byte {
+                0x07 => Self::Ground,
+                0x1b => Self::OscEscape,
+                _ => Self::OscString,
+            },
+            Self::OscEscape => match byte {
+                b'\\' => Self::Ground,
+                _ => Self::OscString,
+            },
+        }
+    }
+}
+
+/// Accumulates presenter output and flushes per [`FlushStrategy`].
+#[derive(Debug)]
+pub struct FrameBatcher<W: Write> {
+    inner: W,
+    buf: Vec<u8>,
+    strategy: FlushStrategy,
+    /// Scanner state at the end of `buf`.
+    state: SeqState,
+    /// Largest prefix of `buf` that ends on a sequence boundary.
+    safe_boundary: usize,
+    /// Bytes handed to the sink for the current frame (reset by
+    /// [`take_frame_bytes`](Self::take_frame_bytes)).
+    frame_bytes: usize,
+    /// Write calls issued to the sink for the current frame.
+    sink_writes: usize,
+}
+
+impl<W: Write> FrameBatcher<W> {
+    /// Batcher with the default per-frame strategy.
+    pub fn new(inner: W) -> Self {
+        Self::with_strategy(inner, FlushStrategy::default())
+    }
+
+    /// Batcher with an explicit strategy.
+    pub fn with_strategy(inner: W, strategy: FlushStrategy) -> Self {
+        Self {
+            inner,
+            buf: Vec::with_capacity(64 * 1024),
+            strategy,
+            state: SeqState::Ground,
+            safe_boundary: 0,
+            frame_bytes: 0,
+            sink_writes: 0,
+        }
+    }
+
+    /// Change the flush strategy (takes effect from the next write).
+    pub fn set_strategy(&mut self, strategy: FlushStrategy) {
+        self.strategy = strategy;
+    }
+
+    /// The active strategy.
+    #[must_use]
+    pub fn strategy(&self) -> FlushStrategy {
+        self.strategy
+    }
+
+    /// The wrapped sink.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped sink.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap after flushing buffered bytes.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.inner)
+    }
+
+    /// Bytes handed to the sink since the last call (per-frame telemetry
+    /// counter when called once per present).
+    pub fn take_frame_bytes(&mut self) -> usize {
+        std::mem::take(&mut self.frame_bytes)
+    }
+
+    /// Sink write calls since the last call (syscall proxy for tests and
+    /// telemetry).
+    pub fn take_sink_writes(&mut self) -> usize {
+        std::mem::take(&mut self.sink_writes)
+    }
+
+    /// Feed the scanner with newly appended bytes, updating the last
+    /// safe cut position.
+    fn scan_appended(&mut self, from: usize) {
+        for idx in from..self.buf.len() {
+            self.state = self.state.advance(self.buf[idx]);
+            if self.state == SeqState::Ground {
+                self.safe_boundary = idx + 1;
+            }
+        }
+    }
+
+    /// Write `buf[..cut]` to the sink and retain the tail.
+    ///
+    /// Drains progressively (matching `BufWriter`): bytes the sink
+    /// accepted are consumed even when a later write errors, so a retry
+    /// never re-sends them.
+    fn emit_prefix(&mut self, cut: usize) -> io::Result<()> {
+        if cut == 0 {
+            return Ok(());
+        }
+        self.sink_writes += 1;
+        let mut written = 0;
+        let result = loop {
+            if written >= cut {
+                break Ok(());
+            }
+            match self.inner.write(&self.buf[written..cut]) {
+                Ok(0) => {
+                    break Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "sink accepted zero bytes",
+                    ));
+                }
+                Ok(n) => written += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.frame_bytes += written;
+        self.buf.drain(..written);
+        self.safe_boundary = self.safe_boundary.saturating_sub(written);
+        result
+    }
+}
+
+impl<W: Write> Write for FrameBatcher<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if matches!(self.strategy, FlushStrategy::Immediate) && self.buf.is_empty() {
+            self.inner.write_all(data)?;
+            self.sink_writes += 1;
+            self.frame_bytes += data.len();
+            return Ok(data.len());
+        }
+        let from = self.buf.len();
+        self.buf.extend_from_slice(data);
+        self.scan_appended(from);
+        match self.strategy {
+            FlushStrategy::Immediate => {
+                // Entered with leftover bytes (strategy switched at
+                // runtime): drain everything.
+                self.emit_prefix(self.buf.len())?;
+                self.safe_boundary = 0;
+            }
+            FlushStrategy::Chunked(limit) => {
+                let limit = limit.max(1);
+                while self.buf.len() >= limit && self.safe_boundary > 0 {
+                    // Cut at the last boundary within the limit; if every
+                    // boundary is past the limit, take the first one so an
+                    // oversized sequence still goes out whole.
+                    let within = last_boundary_within(&self.buf, self.safe_boundary, limit);
+                    let cut = if within > 0 {
+                        within
+                    } else {
+                        self.safe_boundary
+                    };
+                    self.emit_prefix(cut)?;
+                }
+            }
+            FlushStrategy::PerFrame => {}
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // End of frame: everything accumulated is a complete stream, so
+        // flush the whole buffer as one write regardless of scanner
+        // state (the presenter never flushes mid-sequence).
+        let len = self.buf.len();
+        self.emit_prefix(len)?;
+        self.state = SeqState::Ground;
+        self.safe_boundary = 0;
+        self.inner.flush()
+    }
+}
+
+/// The largest sequence boundary `<= limit`, given the overall last
+/// boundary. Rescans the prefix only when the last boundary overshoots.
+fn last_boundary_within(buf: &[u8], last_boundary: usize, limit: usize) -> usize {
+    if last_boundary <= limit {
+        return last_boundary;
+    }
+    let mut state = SeqState::Ground;
+    let mut within = 0;
+    for (idx, &byte) in buf.iter().enumerate().take(limit) {
+        state = state.advance(byte);
+        if state == SeqState::Ground {
+            within = idx + 1;
+        }
+    }
+    within
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records every write call's bytes.
+    #[derive(Default)]
+    struct RecordingSink {
+        writes: Vec<Vec<u8>>,
+        flushes: usize,
+    }
+
+    impl Write for RecordingSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.writes.push(buf.to_vec());
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    fn frame_writes() -> Vec<&'static [u8]> {
+        vec![
+            b"\x1b[?2026h".as_slice(),
+            b"\x1b[1;1H",
+            b"\x1b[38;2;255;0;0m",
+            b"hello world ",
+            b"\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\",
+            b"\x1b[0m",
+            b"\x1b[?2026l",
+        ]
+    }
+
+    fn concatenated() -> Vec<u8> {
+        frame_writes().concat()
+    }
+
+    #[test]
+    fn per_frame_is_one_sink_write() {
+        let mut batcher = FrameBatcher::new(RecordingSink::default());
+        for chunk in frame_writes() {
+            batcher.write_all(chunk).unwrap();
+        }
+        assert!(batcher.get_ref().writes.is_empty(), "nothing before flush");
+        batcher.flush().unwrap();
+        assert_eq!(batcher.get_ref().writes.len(), 1, "one write per frame");
+        assert_eq!(batcher.get_ref().writes[0], concatenated());
+        assert_eq!(batcher.take_frame_bytes(), concatenated().len());
+        assert_eq!(batcher.take_sink_writes(), 1);
+    }
+
+    #[test]
+    fn immediate_passes_every_write_through() {
+        let mut batcher =
+            FrameBatcher::with_strategy(RecordingSink::default(), FlushStrategy::Immediate);
+        for chunk in frame_writes() {
+            batcher.write_all(chunk).unwrap();
+        }
+        assert_eq!(batcher.get_ref().writes.len(), frame_writes().len());
+        batcher.flush().unwrap();
+        let flat: Vec<u8> = batcher.get_ref().writes.concat();
+        assert_eq!(flat, concatenated(), "byte-identical to batched output");
+    }
+
+    #[test]
+    fn chunked_never_splits_an_escape_sequence() {
+        let mut batcher =
+            FrameBatcher::with_strategy(RecordingSink::default(), FlushStrategy::Chunked(10));
+        for chunk in frame_writes() {
+            batcher.write_all(chunk).unwrap();
+        }
+        batcher.flush().unwrap();
+
+        let writes = &batcher.get_ref().writes;
+        assert!(writes.len() > 1, "large frame split into chunks");
+        let flat: Vec<u8> = writes.concat();
+        assert_eq!(flat, concatenated(), "reassembly is byte-identical");
+
+        // Every chunk must end at a sequence boundary: replaying the
+        // scanner over each chunk independently ends in Ground.
+        for (idx, chunk) in writes.iter().enumerate() {
+            let mut state = SeqState::Ground;
+            for &byte in chunk {
+                state = state.advance(byte);
+            }
+            assert_eq!(
+                state,
+                SeqState::Ground,
+                "chunk {idx} ends mid-sequence: {:?}",
+                String::from_utf8_lossy(chunk)
+            );
+        }
+    }
+
+    #[test]
+    fn oversized_sequence_goes_out_whole() {
+        // An OSC longer than the chunk limit must not be cut.
+        let osc = b"\x1b]8;;https://example.com/very/long/path/beyond/limit\x1b\\";
+        let mut batcher =
+            FrameBatcher::with_strategy(RecordingSink::default(), FlushStrategy::Chunked(8));
+        batcher.write_all(b"ab").unwrap();
+        batcher.write_all(osc).unwrap();
+        batcher.write_all(b"cd").unwrap();
+        batcher.flush().unwrap();
+        for chunk in &batcher.get_ref().writes {
+            let mut state = SeqState::Ground;
+            for &byte in chunk {
+                state = state.advance(byte);
+            }
+            assert_eq!(state, SeqState::Ground);
+        }
+        let flat: Vec<u8> = batcher.get_ref().writes.concat();
+        let expected: Vec<u8> = [b"ab".as_slice(), osc, b"cd"].concat();
+        assert_eq!(flat, expected);
+    }
+
+    #[test]
+    fn partial_flush_failure_consumes_accepted_bytes() {
+        /// Accepts 5 bytes, then fails permanently.
+        struct FiveThenFail {
+            out: Vec<u8>,
+            budget: usize,
+        }
+        impl Write for FiveThenFail {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.budget == 0 {
+                    return Err(io::Error::from(io::ErrorKind::BrokenPipe));
+                }
+                let n = buf.len().min(self.budget);
+                self.budget -= n;
+                self.out.extend_from_slice(&buf[..n]);
+                Ok(n)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let mut batcher = FrameBatcher::new(FiveThenFail {
+            out: Vec::new(),
+            budget: 5,
+        });
+        batcher.write_all(b"0123456789").unwrap();
+        assert!(batcher.flush().is_err());
+        assert_eq!(batcher.get_ref().out, b"01234");
+        // Retrying after the sink recovers resumes at byte 5 — nothing
+        // is re-sent.
+        batcher.get_mut().budget = usize::MAX;
+        batcher.flush().unwrap();
+        assert_eq!(batcher.get_ref().out, b"0123456789");
+    }
+
+    #[test]
+    fn buffer_is_reused_not_reallocated() {
+        let mut batcher = FrameBatcher::new(RecordingSink::default());
+        batcher.write_all(&[b'x'; 4096]).unwrap();
+        batcher.flush().unwrap();
+        let cap = batcher.buf.capacity();
+        for _ in 0..8 {
+            batcher.write_all(&[b'y'; 4096]).unwrap();
+            batcher.flush().unwrap();
+        }
+        assert_eq!(batcher.buf.capacity(), cap, "capacity stable across frames");
+    }
+}
@@ -0,0 +1,360 @@
+#![forbid(unsafe_code)]
+
+//! Glyph fallback / replacement for characters the terminal can't render.
+//!
+//! Content with obscure symbols or emoji on a limited terminal renders
+//! as tofu — or worse, the terminal disagrees about width and the grid
+//! drifts. [`GlyphSubstitution`] is a presentation-side policy:
+//!
+//! - **Passthrough** — current behavior, zero-cost fast path.
+//! - **ReplaceUnknown** — characters outside the allow-set (derived
+//!   from [`TerminalCapabilities`], optionally overridden by a
+//!   user-provided coverage table) become a replacement char.
+//! - **Transliterate** — a small built-in map for the common cases:
+//!   smart quotes → ASCII quotes, heavy box-drawing → light, common
+//!   emoji → `:shortcode:` text — falling back to ReplaceUnknown rules.
+//!
+//! Width stays consistent because substitution happens before width
+//! accounting: the ingestion-level [`apply_str`](GlyphSubstitution::apply_str)
+//! re-lays-out naturally, and the presenter's per-cell path pads the
+//! substituted glyph to the cell's width. Exports are untouched —
+//! policy applies at presentation only.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use ftui_core::terminal_capabilities::TerminalCapabilities;
+use unicode_width::UnicodeWidthChar;
+
+/// Substitution behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlyphSubstitutionMode {
+    /// Emit content untouched (the zero-cost default).
+    #[default]
+    Passthrough,
+    /// Replace characters outside the allow-set with the replacement.
+    ReplaceUnknown,
+    /// Map common glyphs to plainer equivalents first, then apply
+    /// ReplaceUnknown rules to what remains.
+    Transliterate,
+}
+
+/// Capability-aware glyph substitution policy (see the module docs).
+#[derive(Debug, Clone)]
+pub struct GlyphSubstitution {
+    pub mode: GlyphSubstitutionMode,
+    /// Replacement for unrenderable characters (default `?`).
+    pub replacement: char,
+    /// Capability-derived allowances.
+    allow_emoji: bool,
+    allow_box_drawing: bool,
+    allow_double_width: bool,
+    /// Optional user-provided coverage table: when set, membership
+    /// decides renderability for non-ASCII chars (overrides heuristics).
+    coverage: Option<HashSet<char>>,
+}
+
+impl Default for GlyphSubstitution {
+    fn default() -> Self {
+        Self {
+            mode: GlyphSubstitutionMode::Passthrough,
+            replacement: '?',
+            allow_emoji: true,
+            allow_box_drawing: true,
+            allow_double_width: true,
+            coverage: None,
+        }
+    }
+}
+
+impl GlyphSubstitution {
+    /// Capability-driven default: full-unicode terminals pass through;
+    /// terminals without emoji transliterate; ASCII-ish terminals
+    /// replace unknowns.
+    #[must_use]
+    pub fn from_capabilities(caps: &TerminalCapabilities) -> Self {
+        let mode = if caps.unicode_emoji && caps.unicode_box_drawing {
+            GlyphSubstitutionMode::Passthrough
+        } else if caps.unicode_box_drawing {
+            GlyphSubstitutionMode::Transliterate
+        } else {
+            GlyphSubstitutionMode::ReplaceUnknown
+        };
+        Self {
+            mode,
+            replacement: '?',
+            allow_emoji: caps.unicode_emoji,
+            allow_box_drawing: caps.unicode_box_drawing,
+            allow_double_width: caps.double_width,
+            coverage: None,
+        }
+    }
+
+    /// Set the substitution mode.
+    #[must_use]
+    pub fn mode(mut self, mode: GlyphSubstitutionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the replacement character.
+    #[must_use]
+    pub fn replacement(mut self, replacement: char) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Provide an explicit coverage table: exactly these non-ASCII
+    /// characters are renderable.
+    #[must_use]
+    pub fn coverage(mut self, table: HashSet<char>) -> Self {
+        self.coverage = Some(table);
+        self
+    }
+
+    /// Whether the terminal is assumed to render `ch` correctly.
+    #[must_use]
+    pub fn is_renderable(&self, ch: char) -> bool {
+        if ch.is_ascii() {
+            return true;
+        }
+        if let Some(coverage) = &self.coverage {
+            return coverage.contains(&ch);
+        }
+        if is_emoji(ch) {
+            return self.allow_emoji;
+        }
+        if is_box_drawing(ch) {
+            return self.allow_box_drawing;
+        }
+        if UnicodeWidthChar::width(ch).unwrap_or(1) > 1 {
+            return self.allow_double_width;
+        }
+        true
+    }
+
+    /// Substitute one char for the presenter's per-cell path.
+    ///
+    /// Returns `None` when the char passes through untouched (the
+    /// Passthrough fast path takes this branch without further work).
+    #[must_use]
+    pub fn substitute_char(&self, ch: char) -> Option<char> {
+        match self.mode {
+            GlyphSubstitutionMode::Passthrough => None,
+            GlyphSubstitutionMode::ReplaceUnknown => {
+                (!self.is_renderable(ch)).then_some(self.replacement)
+            }
+            GlyphSubstitutionMode::Transliterate => {
+                if let Some(mapped) = transliterate_char(ch) {
+                    return Some(mapped);
+                }
+                (!self.is_renderable(ch)).then_some(self.replacement)
+            }
+        }
+    }
+
+    /// Ingestion-level substitution for whole strings.
+    ///
+    /// Passthrough returns the input borrowed (no allocation, no scan
+    /// beyond the mode check). Transliterate expands common emoji to
+    /// `:shortcode:` text; widths are recomputed by the normal text
+    /// pipeline afterwards.
+    #[must_use]
+    pub fn apply_str<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        if self.mode == GlyphSubstitutionMode::Passthrough {
+            return Cow::Borrowed(input);
+        }
+        if input.is_ascii() {
+            return Cow::Borrowed(input);
+        }
+        let mut out = String::with_capacity(input.len());
+        for ch in input.chars() {
+            match self.mode {
+                GlyphSubstitutionMode::Transliterate => {
+                    if let Some(shortcode) = emoji_shortcode(ch) {
+                        out.push(':');
+                        out.push_str(shortcode);
+                        out.push(':');
+                    } else if let Some(mapped) = transliterate_char(ch) {
+                        out.push(mapped);
+                    } else if self.is_renderable(ch) {
+                        out.push(ch);
+                    } else {
+                        out.push(self.replacement);
+                    }
+                }
+                _ => {
+                    if self.is_renderable(ch) {
+                        out.push(ch);
+                    } else {
+                        out.push(self.replacement);
+                    }
+                }
+            }
+        }
+        Cow::Owned(out)
+    }
+}
+
+/// Emoji-ish ranges the policy gates on `unicode_emoji`.
+fn is_emoji(ch: char) -> bool {
+    // Pictographs always count; legacy symbol/dingbat/arrow blocks only
+    // when the terminal would render them double width.
+    matches!(u32::from(ch), 0x1F300..=0x1FAFF)
+        || (matches!(u32::from(ch), 0x2190..=0x21FF | 0x2600..=0x27BF)
+            && UnicodeWidthChar::width(ch).unwrap_or(1) > 1)
+}
+
+/// Unicode box-drawing / block-element ranges.
+fn is_box_drawing(ch: char) -> bool {
+    matches!(u32::from(ch), 0x2500..=0x259F)
+}
+
+/// Built-in transliterations: smart punctuation and heavy→light boxes.
+fn transliterate_char(ch: char) -> Option<char> {
+    Some(match ch {
+        '\u{2018}' | '\u{2019}' => '\'',
+        '\u{201c}' | '\u{201d}' => '"',
+        '\u{2013}' | '\u{2014}' => '-',
+        '\u{2026}' => '.', // ellipsis degrades to a period
+        '\u{00a0}' => ' ',
+        // Heavy box drawing → light equivalents.
+        '\u{2501}' => '\u{2500}',
+        '\u{2503}' => '\u{2502}',
+        '\u{250f}' => '\u{250c}',
+        '\u{2513}' => '\u{2510}',
+        '\u{2517}' => '\u{2514}',
+        '\u{251b}' => '\u{2518}',
+        '\u{2523}' => '\u{251c}',
+        '\u{252b}' => '\u{2524}',
+        '\u{2533}' => '\u{252c}',
+        '\u{253b}' => '\u{2534}',
+        '\u{254b}' => '\u{253c}',
+        _ => return None,
+    })
+}
+
+/// Shortcodes for the common emoji (Transliterate string path).
+fn emoji_shortcode(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        '\u{1F680}' => "rocket",
+        '\u{1F525}' => "fire",
+        '\u{1F389}' => "tada",
+        '\u{2705}' => "white_check_mark",
+        '\u{274C}' => "x",
+        '\u{26A0}' => "warning",
+        '\u{1F4A5}' => "boom",
+        '\u{1F41B}' => "bug",
+        '\u{2764}' => "heart",
+        '\u{1F44D}' => "thumbsup",
+        _ => return None,
+    })
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_width::UnicodeWidthStr;
+
+    const FIXTURE: &str = "\u{201c}hi\u{201d} \u{1F680} \u{2503}done\u{2503} \u{1F984}";
+
+    #[test]
+    fn passthrough_is_borrowed_and_untouched() {
+        let policy = GlyphSubstitution::default();
+        let out = policy.apply_str(FIXTURE);
+        assert!(matches!(out, Cow::Borrowed(_)), "no allocation");
+        assert_eq!(out, FIXTURE);
+        assert_eq!(policy.substitute_char('\u{1F680}'), None);
+    }
+
+    #[test]
+    fn replace_unknown_respects_capability_allow_set() {
+        let caps = TerminalCapabilities::basic();
+        // basic(): no emoji, no double width — both emoji become '?'.
+        let policy =
+            GlyphSubstitution::from_capabilities(&caps).mode(GlyphSubstitutionMode::ReplaceUnknown);
+        let out = policy.apply_str(FIXTURE);
+        assert!(!out.contains('\u{1F680}'), "{out:?}");
+        assert!(out.contains('?'), "{out:?}");
+    }
+
+    #[test]
+    fn transliterate_fixture_golden() {
+        let mut caps = TerminalCapabilities::basic();
+        caps.unicode_box_drawing = true;
+        let policy = GlyphSubstitution::from_capabilities(&caps);
+        assert_eq!(policy.mode, GlyphSubstitutionMode::Transliterate);
+        let out = policy.apply_str(FIXTURE);
+        assert_eq!(out, "\"hi\" :rocket: \u{2502}done\u{2502} ?");
+    }
+
+    #[test]
+    fn width_is_consistent_after_substitution() {
+        // A no-emoji terminal: everything wide is substituted away.
+        let mut caps = TerminalCapabilities::basic();
+        caps.unicode_box_drawing = true;
+        let policy = GlyphSubstitution::from_capabilities(&caps);
+        let out = policy.apply_str(FIXTURE);
+        // Every char of the substituted string is narrow: width math on
+        // the substituted glyphs is exact on any terminal.
+        assert_eq!(out.chars().count(), UnicodeWidthStr::width(out.as_ref()));
+    }
+
+    #[test]
+    fn coverage_table_overrides_heuristics() {
+        let table: HashSet<char> = ['\u{1F680}'].into_iter().collect();
+        let policy = GlyphSubstitution::default()
+            .mode(GlyphSubstitutionMode::ReplaceUnknown)
+            .coverage(table);
+        assert!(policy.is_renderable('\u{1F680}'), "covered emoji kept");
+        assert!(!policy.is_renderable('\u{1F984}'), "uncovered replaced");
+    }
+
+    #[test]
+    fn capability_driven_default_selection() {
+        let mut full = TerminalCapabilities::basic();
+        full.unicode_emoji = true;
+        full.unicode_box_drawing = true;
+        assert_eq!(
+            GlyphSubstitution::from_capabilities(&full).mode,
+            GlyphSubstitutionMode::Passthrough
+        );
+
+        let mut boxes_only = TerminalCapabilities::basic();
+        boxes_only.unicode_emoji = false;
+        boxes_only.unicode_box_drawing = true;
+        assert_eq!(
+            GlyphSubstitution::from_capabilities(&boxes_only).mode,
+            GlyphSubstitutionMode::Transliterate
+        );
+
+        let mut ascii = TerminalCapabilities::basic();
+        ascii.unicode_emoji = false;
+        ascii.unicode_box_drawing = false;
+        assert_eq!(
+            GlyphSubstitution::from_capabilities(&ascii).mode,
+            GlyphSubstitutionMode::ReplaceUnknown
+        );
+    }
+
+    #[test]
+    fn passthrough_fast_path_has_no_substitution_cost() {
+        let passthrough = GlyphSubstitution::default();
+        let body = "plain ascii content that should cost nothing ".repeat(64);
+        // Warm up, then time the fast path: it must be borrowed (no
+        // allocation) and far cheaper than an active mode.
+        let start = std::time::Instant::now();
+        for _ in 0..10_000 {
+            let out = passthrough.apply_str(&body);
+            assert!(matches!(out, Cow::Borrowed(_)));
+        }
+        let fast = start.elapsed();
+        // Generous absolute guard: 10k passthroughs of ~3KB in well
+        // under 50ms even in debug builds (it is just a mode check).
+        assert!(fast < std::time::Duration::from_millis(50), "{fast:?}");
+    }
+}
@@ -0,0 +1,433 @@
+#![forbid(unsafe_code)]
+
+//! Z-ordered layer compositing for overlays.
+//!
+//! Widgets render into named layers with a z-order; [`LayerStack::composite`]
+//! resolves everything into the single flat [`Buffer`] the diff/presenter
+//! already consume, so nothing downstream changes. Background colors blend
+//! top-down (premultiplied, in linear space); foreground text comes from
+//! the topmost layer with a non-transparent glyph. [`dim_below`] paints a
+//! translucent scrim in one call.
+//!
+//! Unlayered frames take a fast path: with no overlay layers,
+//! [`LayerStack::composite`] hands the base buffer back untouched.
+//!
+//! On terminals without real color depth, alpha degrades per
+//! [`AlphaDegrade`]: apply the `DIM` attribute, or plain overwrite.
+
+use crate::buffer::Buffer;
+use crate::cell::{Cell, PackedRgba, StyleFlags};
+use ftui_core::geometry::Rect;
+
+/// How translucent backgrounds degrade on low-color terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlphaDegrade {
+    /// Blend in linear space (truecolor terminals).
+    #[default]
+    Blend,
+    /// Apply the `DIM` attribute instead of blending.
+    DimAttr,
+    /// Treat any alpha as opaque (plain overwrite).
+    Overwrite,
+}
+
+/// A named overlay layer.
+struct Layer {
+    name: &'static str,
+    z: i32,
+    buffer: Buffer,
+}
+
+/// Base buffer plus z-ordered overlay layers.
+pub struct LayerStack {
+    base: Buffer,
+    layers: Vec<Layer>,
+    degrade: AlphaDegrade,
+}
+
+impl LayerStack {
+    /// Wrap a base buffer (layer z = 0 conceptually).
+    #[must_use]
+    pub fn new(base: Buffer) -> Self {
+        Self {
+            base,
+            layers: Vec::new(),
+            degrade: AlphaDegrade::default(),
+        }
+    }
+
+    /// Set the alpha degradation policy for low-color terminals.
+    #[must_use]
+    pub fn with_degrade(mut self, degrade: AlphaDegrade) -> Self {
+        self.degrade = degrade;
+        self
+    }
+
+    /// The base buffer (for normal widget rendering).
+    pub fn base_mut(&mut self) -> &mut Buffer {
+        &mut self.base
+    }
+
+    /// Get or create the named layer at z-order `z`.
+    ///
+    /// New layers start fully transparent. Requesting an existing name
+    /// updates its z-order.
+    pub fn layer(&mut self, name: &'static str, z: i32) -> &mut Buffer {
+        if let Some(idx) = self.layers.iter().position(|layer| layer.name == name) {
+            self.layers[idx].z = z;
+            self.layers.sort_by_key(|layer| layer.z);
+            let idx = self
+                .layers
+                .iter()
+                .position(|layer| layer.name == name)
+                .expect("layer still present after sort");
+            return &mut self.layers[idx].buffer;
+        }
+        let mut buffer = Buffer::new(self.base.width(), self.base.height());
+        clear_transparent(&mut buffer);
+        self.layers.push(Layer { name, z, buffer });
+        self.layers.sort_by_key(|layer| layer.z);
+        let idx = self
+            .layers
+            .iter()
+            .position(|layer| layer.name == name)
+            .expect("layer just inserted");
+        &mut self.layers[idx].buffer
+    }
+
+    /// Number of overlay layers.
+    #[must_use]
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Resolve all layers into the flat base buffer and return it.
+    ///
+    /// With no overlay layers this is the fast path: the base is returned
+    /// untouched, byte for byte.
+    #[must_use]
+    pub fn composite(mut self) -> Buffer {
+        if self.layers.is_empty() {
+            return self.base;
+        }
+        for layer in &self.layers {
+            composite_layer(&mut self.base, &layer.buffer, self.degrade);
+        }
+        self.base
+    }
+}
+
+impl std::fmt::Debug for LayerStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayerStack")
+            .field("layers", &self.layers.len())
+            .field("degrade", &self.degrade)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Make every cell fully transparent (no content, alpha-0 background).
+fn clear_transparent(buffer: &mut Buffer) {
+    let mut cell = Cell::default();
+    cell.bg = PackedRgba::TRANSPARENT;
+    cell.fg = PackedRgba::TRANSPARENT;
+    cell.content = crate::cell::CellContent::EMPTY;
+    buffer.fill(Rect::new(0, 0, buffer.width(), buffer.height()), cell);
+}
+
+/// True when the layer cell contributes nothing.
+fn is_void(cell: &Cell) -> bool {
+    cell.content == crate::cell::CellContent::EMPTY
+        && cell.bg.a() == 0
+        && cell.fg.a() == 0
+        && cell.attrs.flags().is_empty()
+}
+
+/// Composite one layer over the accumulated result.
+fn composite_layer(dst: &mut Buffer, src: &Buffer, degrade: AlphaDegrade) {
+    for y in 0..dst.height() {
+        for x in 0..dst.width() {
+            let Some(over) = src.get(x, y) else { continue };
+            if is_void(over) {
+                continue;
+            }
+            let Some(under) = dst.get(x, y) else { continue };
+
+            let mut out = *under;
+            // Background: blend (or degrade) the overlay bg over what's
+            // accumulated so far.
+            out.bg = match degrade {
+                AlphaDegrade::Blend => blend_linear(over.bg, under.bg),
+                AlphaDegrade::DimAttr => {
+                    if over.bg.a() == 255 {
+                        over.bg
+                    } else if over.bg.a() > 0 {
+                        // Translucent scrim degrades to the DIM attribute.
+                        out.attrs = out.attrs.with_flags(out.attrs.flags() | StyleFlags::DIM);
+                        under.bg
+                    } else {
+                        under.bg
+                    }
+                }
+                AlphaDegrade::Overwrite => {
+                    if over.bg.a() > 0 {
+                        PackedRgba::rgb(over.bg.r(), over.bg.g(), over.bg.b())
+                    } else {
+                        under.bg
+                    }
+                }
+            };
+
+            // Foreground glyph: the topmost non-transparent glyph wins.
+            if over.content != crate::cell::CellContent::EMPTY {
+                out.content = over.content;
+                out.fg = over.fg;
+                out.attrs = over.attrs;
+            } else if over.bg.a() > 0 && degrade == AlphaDegrade::Blend {
+                // Translucent bg over existing text: dim the glyph too so
+                // the scrim reads as "behind".
+                out.fg = blend_linear(over.bg, under.fg);
+            }
+
+            dst.set_raw(x, y, out);
+        }
+    }
+}
+
+/// Paint a translucent black scrim over a rect (the modal-backdrop
+/// effect) directly on a buffer: backgrounds darken, glyphs dim.
+pub fn dim_below(buffer: &mut Buffer, rect: Rect, alpha: f32) {
+    let alpha = (alpha.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let scrim = PackedRgba::rgba(0, 0, 0, alpha);
+    let x1 = rect.right().min(buffer.width());
+    let y1 = rect.bottom().min(buffer.height());
+    for y in rect.y..y1 {
+        for x in rect.x..x1 {
+            let Some(cell) = buffer.get(x, y) else {
+                continue;
+            };
+            let mut out = *cell;
+            out.bg = blend_linear(scrim, out.bg);
+            out.fg = blend_linear(scrim, out.fg);
+            buffer.set_raw(x, y, out);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linear-space blending
+// ---------------------------------------------------------------------------
+
+/// sRGB channel to linear.
+fn to_linear(value: u8) -> f32 {
+    let v = f32::from(value) / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear back to sRGB channel.
+fn to_srgb(value: f32) -> u8 {
+    let v = if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+    (v.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Source-over blend in linear space (premultiplied math).
+#[must_use]
+pub fn blend_linear(src: PackedRgba, dst: PackedRgba) -> PackedRgba {
+    let s_a = f32::from(src.a()) / 255.0;
+    if s_a >= 1.0 {
+        return src;
+    }
+    if s_a <= 0.0 {
+        return dst;
+    }
+    let d_a = f32::from(dst.a()) / 255.0;
+    let out_a = s_a + d_a * (1.0 - s_a);
+    if out_a <= 0.0 {
+        return PackedRgba::TRANSPARENT;
+    }
+    let blend = |s: u8, d: u8| -> u8 {
+        let s_lin = to_linear(s) * s_a;
+        let d_lin = to_linear(d) * d_a * (1.0 - s_a);
+        to_srgb((s_lin + d_lin) / out_a)
+    };
+    PackedRgba::rgba(
+        blend(src.r(), dst.r()),
+        blend(src.g(), dst.g()),
+        blend(src.b(), dst.b()),
+        (out_a * 255.0).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(r: u8, g: u8, b: u8) -> PackedRgba {
+        PackedRgba::rgb(r, g, b)
+    }
+
+    fn base_buffer() -> Buffer {
+        let mut buffer = Buffer::new(6, 3);
+        for y in 0..3 {
+            for x in 0..6 {
+                let mut cell = Cell::from_char('.');
+                cell.bg = solid(0, 0, 255);
+                cell.fg = solid(255, 255, 255);
+                buffer.set_raw(x, y, cell);
+            }
+        }
+        buffer
+    }
+
+    #[test]
+    fn blended_colors_for_known_alpha_stacks() {
+        // 50% black over white in linear space: linear 0.5 -> sRGB ~188.
+        let half_black = PackedRgba::rgba(0, 0, 0, 128);
+        let over_white = blend_linear(half_black, solid(255, 255, 255));
+        assert_eq!(over_white.a(), 255);
+        assert!(
+            (over_white.r() as i32 - 188).abs() <= 2,
+            "linear blend, got {}",
+            over_white.r()
+        );
+
+        // Fully opaque wins outright; fully transparent is a no-op.
+        assert_eq!(
+            blend_linear(solid(10, 20, 30), solid(1, 2, 3)),
+            solid(10, 20, 30)
+        );
+        assert_eq!(
+            blend_linear(PackedRgba::TRANSPARENT, solid(1, 2, 3)),
+            solid(1, 2, 3)
+        );
+
+        // Stacking two 50% scrims ≈ one 75% scrim.
+        let once = blend_linear(half_black, solid(255, 255, 255));
+        let twice = blend_linear(half_black, once);
+        let three_quarters = blend_linear(PackedRgba::rgba(0, 0, 0, 191), solid(255, 255, 255));
+        assert!((twice.r() as i32 - three_quarters.r() as i32).abs() <= 3);
+    }
+
+    #[test]
+    fn fast_path_returns_base_unchanged() {
+        let base = base_buffer();
+        let expected: Vec<Cell> = (0..3)
+            .flat_map(|y| (0..6).map(move |x| (x, y)))
+            .map(|(x, y)| *base.get(x, y).unwrap())
+            .collect();
+        let stack = LayerStack::new(base);
+        let flat = stack.composite();
+        let actual: Vec<Cell> = (0..3)
+            .flat_map(|y| (0..6).map(move |x| (x, y)))
+            .map(|(x, y)| *flat.get(x, y).unwrap())
+            .collect();
+        assert_eq!(actual, expected, "no layers: base passes through untouched");
+    }
+
+    #[test]
+    fn topmost_glyph_wins_and_backgrounds_blend() {
+        let mut stack = LayerStack::new(base_buffer());
+
+        // Scrim layer below the modal layer.
+        let scrim = stack.layer("scrim", 10);
+        for y in 0..3 {
+            for x in 0..6 {
+                let mut cell = Cell::default();
+                cell.content = crate::cell::CellContent::EMPTY;
+                cell.fg = PackedRgba::TRANSPARENT;
+                cell.bg = PackedRgba::rgba(0, 0, 0, 128);
+                scrim.set_raw(x, y, cell);
+            }
+        }
+        let modal = stack.layer("modal", 20);
+        let mut m = Cell::from_char('M');
+        m.bg = solid(40, 40, 40);
+        m.fg = solid(255, 255, 0);
+        modal.set_raw(2, 1, m);
+
+        let flat = stack.composite();
+        // Modal glyph on top, opaque bg.
+        let modal_cell = flat.get(2, 1).unwrap();
+        assert_eq!(modal_cell.content.as_char(), Some('M'));
+        assert_eq!(modal_cell.fg, solid(255, 255, 0));
+        assert_eq!(modal_cell.bg, solid(40, 40, 40));
+        // Elsewhere the scrim darkened the blue background but kept the
+        // base glyph.
+        let dimmed = flat.get(0, 0).unwrap();
+        assert_eq!(dimmed.content.as_char(), Some('.'));
+        assert!(dimmed.bg.b() < 255 && dimmed.bg.b() > 0, "darkened blue");
+    }
+
+    #[test]
+    fn sixteen_color_degradation_uses_dim_attr() {
+        let mut stack = LayerStack::new(base_buffer()).with_degrade(AlphaDegrade::DimAttr);
+        let scrim = stack.layer("scrim", 10);
+        let mut cell = Cell::default();
+        cell.content = crate::cell::CellContent::EMPTY;
+        cell.fg = PackedRgba::TRANSPARENT;
+        cell.bg = PackedRgba::rgba(0, 0, 0, 128);
+        scrim.set_raw(1, 1, cell);
+
+        let flat = stack.composite();
+        let degraded = flat.get(1, 1).unwrap();
+        // No blending: original bg kept, DIM attribute applied.
+        assert_eq!(degraded.bg, solid(0, 0, 255));
+        assert!(degraded.attrs.flags().contains(StyleFlags::DIM));
+    }
+
+    #[test]
+    fn dim_below_darkens_rect_only() {
+        let mut buffer = base_buffer();
+        dim_below(&mut buffer, Rect::new(0, 0, 3, 3), 0.5);
+        let dimmed = buffer.get(0, 0).unwrap();
+        let untouched = buffer.get(5, 0).unwrap();
+        assert!(dimmed.bg.b() < untouched.bg.b());
+        assert!(dimmed.fg.r() < untouched.fg.r());
+        assert_eq!(untouched.bg, solid(0, 0, 255));
+    }
+
+    #[test]
+    fn modal_and_scrim_snapshot_over_colorful_background() {
+        let mut base = Buffer::new(8, 3);
+        for y in 0..3 {
+            for x in 0..8 {
+                let mut cell = Cell::from_char(char::from(b'a' + x as u8));
+                cell.bg = solid(x as u8 * 30, 255 - x as u8 * 30, 128);
+                base.set_raw(x, y, cell);
+            }
+        }
+        let mut stack = LayerStack::new(base);
+        dim_below(stack.base_mut(), Rect::new(0, 0, 8, 3), 0.4);
+        let modal = stack.layer("modal", 100);
+        for (i, ch) in "[ok]".chars().enumerate() {
+            let mut cell = Cell::from_char(ch);
+            cell.bg = solid(30, 30, 30);
+            cell.fg = solid(230, 230, 230);
+            modal.set_raw(2 + i as u16, 1, cell);
+        }
+        let flat = stack.composite();
+
+        // Snapshot the glyph rows.
+        let row = |y: u16| -> String {
+            (0..8)
+                .filter_map(|x| flat.get(x, y).and_then(|c| c.content.as_char()))
+                .collect()
+        };
+        assert_eq!(row(0), "abcdefgh");
+        assert_eq!(row(1), "ab[ok]gh");
+        assert_eq!(row(2), "abcdefgh");
+        // Modal cells opaque and brighter than the dimmed surroundings.
+        let inside = flat.get(3, 1).unwrap();
+        let outside = flat.get(7, 1).unwrap();
+        assert_eq!(inside.bg, solid(30, 30, 30));
+        assert!(outside.bg != inside.bg);
+    }
+}
@@ -31,11 +31,15 @@ pub mod diff_strategy;
 pub mod drawing;
 pub mod fit_metrics;
 pub mod frame;
+pub mod frame_batcher;
 pub mod frame_guardrails;
+pub mod glyph_fallback;
 pub mod grapheme_pool;
 pub mod headless;
+pub mod layers;
 pub mod link_registry;
 pub mod presenter;
+pub mod resilient_writer;
 pub mod roaring_bitmap;
 pub mod sanitize;
 pub mod spatial_hit_index;
@@ -32,12 +32,14 @@
 //! std::mem::swap(&mut current, &mut next);
 //! ```
 
-use std::io::{self, BufWriter, Write};
+use std::io::{self, Write};
 
 use crate::ansi::{self, EraseLineMode};
 use crate::buffer::Buffer;
 use crate::cell::{Cell, CellAttrs, PackedRgba, StyleFlags};
 use crate::counting_writer::{CountingWriter, PresentStats, StatsCollector};
+use crate::frame_batcher::{FlushStrategy, FrameBatcher};
+use crate::glyph_fallback::GlyphSubstitution;
 use crate::diff::{BufferDiff, ChangeRun};
 use crate::grapheme_pool::GraphemePool;
 use crate::link_registry::LinkRegistry;
@@ -45,8 +47,6 @@ use crate::sanitize::sanitize;
 
 pub use ftui_core::terminal_capabilities::TerminalCapabilities;
 
-/// Size of the internal write buffer (64KB).
-const BUFFER_CAPACITY: usize = 64 * 1024;
 /// Maximum hyperlink URL length allowed in OSC 8 payloads.
 const MAX_SAFE_HYPERLINK_URL_BYTES: usize = 4096;
 
@@ -335,15 +335,62 @@ impl CellStyle {
     }
 }
 
+/// Why a frame failed to present, separated for the runtime's
+/// degradation logic.
+///
+/// [`Presenter::present`] keeps its `io::Result` signature; callers that
+/// want to distinguish transient terminal backpressure (drop/degrade the
+/// frame, keep running) from a dead sink (tear down and restore the
+/// terminal) classify the error with `PresentError::from`.
+#[derive(Debug)]
+pub enum PresentError {
+    /// The sink kept refusing bytes (`EAGAIN`) beyond the retry budget.
+    /// Recoverable: the frame can be dropped or retried later.
+    Backpressure(io::Error),
+    /// Any other I/O failure (`EPIPE`, closed fd, …). The terminal should
+    /// be restored and the error propagated.
+    Io(io::Error),
+}
+
+impl From<io::Error> for PresentError {
+    fn from(err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::WouldBlock {
+            Self::Backpressure(err)
+        } else {
+            Self::Io(err)
+        }
+    }
+}
+
+impl std::fmt::Display for PresentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Backpressure(err) => write!(f, "present backpressure: {err}"),
+            Self::Io(err) => write!(f, "present failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PresentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Backpressure(err) | Self::Io(err) => Some(err),
+        }
+    }
+}
+
 /// State-tracked ANSI presenter.
 ///
 /// Transforms buffer diffs into minimal terminal output by tracking
 /// the current terminal state and only emitting necessary escape sequences.
 pub struct Presenter<W: Write> {
-    /// Buffered writer for efficient output, with byte counting.
-    writer: CountingWriter<BufWriter<W>>,
+    /// Frame-batched writer for single-write-per-frame output, with byte
+    /// counting.
+    writer: CountingWriter<FrameBatcher<W>>,
     /// Current style state (None = unknown/reset).
     current_style: Option<CellStyle>,
+    /// Presentation-time glyph substitution (None = passthrough, free).
+    glyph_substitution: Option<GlyphSubstitution>,
     /// Current hyperlink ID (None = no link).
     current_link: Option<u32>,
     /// Current cursor X position (0-indexed). None = unknown.
@@ -352,6 +399,8 @@ pub struct Presenter<W: Write> {
     cursor_y: Option<u16>,
     /// Viewport Y offset (added to all row coordinates).
     viewport_offset_y: u16,
+    /// Last-emitted DEC line attribute per row (DECDWL/DECDHL).
+    line_attrs_emitted: Vec<crate::buffer::LineAttr>,
     /// Terminal capabilities for conditional output.
     capabilities: TerminalCapabilities,
     /// Reusable scratch buffers for the cost-model DP, avoiding per-row
@@ -365,12 +414,14 @@ impl<W: Write> Presenter<W> {
     /// Create a new presenter with the given writer and capabilities.
     pub fn new(writer: W, capabilities: TerminalCapabilities) -> Self {
         Self {
-            writer: CountingWriter::new(BufWriter::with_capacity(BUFFER_CAPACITY, writer)),
+            writer: CountingWriter::new(FrameBatcher::new(writer)),
             current_style: None,
+            glyph_substitution: None,
             current_link: None,
             cursor_x: None,
             cursor_y: None,
             viewport_offset_y: 0,
+            line_attrs_emitted: Vec::new(),
             capabilities,
             plan_scratch: cost_model::RowPlanScratch::default(),
             runs_buf: Vec::new(),
@@ -388,12 +439,32 @@ impl<W: Write> Presenter<W> {
 
     /// Get mutable access to the full counting writer stack.
     ///
-    /// This exposes `CountingWriter<BufWriter<W>>` so callers can access
-    /// byte counting, buffered flush, etc.
-    pub fn counting_writer_mut(&mut self) -> &mut CountingWriter<BufWriter<W>> {
+    /// This exposes `CountingWriter<FrameBatcher<W>>` so callers can access
+    /// byte counting, frame-batched flushing, etc.
+    pub fn counting_writer_mut(&mut self) -> &mut CountingWriter<FrameBatcher<W>> {
         &mut self.writer
     }
 
+    /// Set (or clear) presentation-time glyph substitution.
+    ///
+    /// Applies only to emitted bytes — buffers, diffs, and exports are
+    /// untouched. `None` (the default) is the passthrough fast path.
+    pub fn set_glyph_substitution(&mut self, policy: Option<GlyphSubstitution>) {
+        self.glyph_substitution = policy;
+    }
+
+    /// Set the output flush strategy (see [`FlushStrategy`]). The default
+    /// accumulates each frame and writes it to the sink once.
+    pub fn set_flush_strategy(&mut self, strategy: FlushStrategy) {
+        self.writer.inner_mut().set_strategy(strategy);
+    }
+
+    /// The active output flush strategy.
+    #[must_use]
+    pub fn flush_strategy(&self) -> FlushStrategy {
+        self.writer.inner().strategy()
+    }
+
     /// Set the viewport Y offset.
     ///
     /// All subsequent render operations will add this offset to row coordinates.
@@ -574,16 +645,42 @@ impl<W: Write> Presenter<W> {
                 "row plan"
             );
 
+            let attr = buffer.line_attr(row_y);
+            if !self.capabilities.double_width
+                && attr == crate::buffer::LineAttr::DoubleHeightBottom
+            {
+                // Approximation without DEC line attributes: the top half
+                // already rendered the text at normal size; the paired
+                // bottom row stays blank.
+                self.move_cursor_optimal(0, row_y)?;
+                self.writer.write_all(b"\x1b[2K")?;
+                continue;
+            }
+
             let row = buffer.row_cells(row_y);
+            let mut attr_emitted = false;
             for span in plan.spans() {
                 self.move_cursor_optimal(span.x0, span.y)?;
+                if self.capabilities.double_width && !attr_emitted {
+                    self.ensure_line_attr(row_y, attr)?;
+                    attr_emitted = true;
+                }
                 // Hot path: avoid recomputing `y * width + x` for every cell.
                 let start = span.x0 as usize;
-                let end = span.x1 as usize;
+                let mut end = span.x1 as usize;
                 debug_assert!(start <= end);
                 debug_assert!(end < row.len());
+
+                // Erase-line optimization: a long run of blank cells at the
+                // span tail that continues (unchanged) to the right edge can
+                // be replaced by EL instead of writing spaces.
+                let erase_from = self.blank_tail_start(row, start, end);
+                if let Some(tail) = erase_from {
+                    end = tail.saturating_sub(1);
+                }
+
                 let mut idx = start;
-                while idx <= end {
+                while erase_from != Some(start) && idx <= end {
                     let cell = &row[idx];
                     self.emit_cell(idx as u16, cell, pool, links)?;
 
@@ -617,6 +714,10 @@ impl<W: Write> Presenter<W> {
 
                     idx = idx.saturating_add(advance);
                 }
+
+                if let Some(tail) = erase_from {
+                    self.emit_erase_to_eol(row, tail, span.y)?;
+                }
             }
         }
         Ok(())
@@ -629,6 +730,65 @@ impl<W: Write> Presenter<W> {
         diff.runs_into(&mut self.runs_buf);
     }
 
+    /// Find the start of a span tail that can be replaced by erase-line.
+    ///
+    /// Requires that every cell from the candidate tail through the right
+    /// edge of the row is an erase-safe blank (space content, no attributes,
+    /// no hyperlink) with a uniform background — so EL produces exactly the
+    /// same visible result as writing the spaces. Colored backgrounds are
+    /// only erase-safe when the terminal implements back color erase;
+    /// otherwise EL would fill with the default background and we must fall
+    /// back to explicit spaces. Correctness over savings.
+    fn blank_tail_start(&self, row: &[Cell], span_start: usize, span_end: usize) -> Option<usize> {
+        /// Below this many saved cells the escape overhead isn't worth it.
+        const MIN_ERASE_RUN: usize = 8;
+
+        let last = row.len() - 1;
+        let bg = row[last].bg;
+        if bg != PackedRgba::TRANSPARENT && !self.capabilities.back_color_erase {
+            return None;
+        }
+        let erase_safe = |cell: &Cell| {
+            (cell.is_empty() || cell.content.as_char() == Some(' '))
+                && cell.attrs.flags().is_empty()
+                && cell.attrs.link_id() == 0
+                && cell.bg == bg
+        };
+
+        // Scan from the right edge toward the span: the whole suffix must be
+        // erase-safe, and the tail must start within the span.
+        let mut tail = last + 1;
+        while tail > span_start && erase_safe(&row[tail - 1]) {
+            tail -= 1;
+        }
+        if tail > span_end {
+            return None;
+        }
+        // Only count the dirty cells we avoid writing.
+        let saved = span_end - tail + 1;
+        (saved >= MIN_ERASE_RUN).then_some(tail)
+    }
+
+    /// Emit an erase-line for a blank tail starting at `tail`.
+    ///
+    /// Full-row clears use `CSI 2K`; tails use `CSI K`. The SGR background
+    /// is aligned with the blanks first so BCE terminals fill correctly.
+    fn emit_erase_to_eol(&mut self, row: &[Cell], tail: usize, y: u16) -> io::Result<()> {
+        self.move_cursor_optimal(tail as u16, y)?;
+        if row[tail].bg == PackedRgba::TRANSPARENT {
+            ansi::sgr_reset(&mut self.writer)?;
+            self.current_style = Some(CellStyle::default());
+        } else {
+            self.emit_style_changes(&row[tail])?;
+        }
+        if tail == 0 {
+            ansi::erase_line(&mut self.writer, EraseLineMode::All)
+        } else {
+            ansi::erase_line(&mut self.writer, EraseLineMode::ToEnd)
+        }
+        // EL does not move the cursor, so tracked position stays valid.
+    }
+
     /// Emit a single cell.
     fn emit_cell(
         &mut self,
@@ -965,6 +1125,23 @@ impl<W: Write> Presenter<W> {
             if let Some(pool) = pool
                 && let Some(text) = pool.get(grapheme_id)
             {
+                // Glyph fallback: an unrenderable cluster degrades to
+                // the replacement repeated to the cell's width.
+                if let Some(policy) = &self.glyph_substitution
+                    && text
+                        .chars()
+                        .next()
+                        .is_some_and(|ch| policy.substitute_char(ch).is_some())
+                {
+                    let replacement = policy.replacement;
+                    let width = cell.content.width().max(1);
+                    let mut buf = [0u8; 4];
+                    for _ in 0..width {
+                        self.writer
+                            .write_all(replacement.encode_utf8(&mut buf).as_bytes())?;
+                    }
+                    return Ok(());
+                }
                 let safe = sanitize(text);
                 if !safe.is_empty() {
                     return self.writer.write_all(safe.as_bytes());
@@ -984,7 +1161,22 @@ impl<W: Write> Presenter<W> {
         // Regular character content
         if let Some(ch) = cell.content.as_char() {
             // Sanitize control characters that would break the grid.
-            let safe_ch = if ch.is_control() { ' ' } else { ch };
+            let mut safe_ch = if ch.is_control() { ' ' } else { ch };
+            // Glyph fallback: substitute unrenderable glyphs, padding to
+            // the cell's width so cursor accounting stays exact.
+            if let Some(policy) = &self.glyph_substitution
+                && let Some(substituted) = policy.substitute_char(safe_ch)
+            {
+                let original_width = cell.content.width().max(1);
+                safe_ch = substituted;
+                let mut buf = [0u8; 4];
+                let encoded = safe_ch.encode_utf8(&mut buf);
+                self.writer.write_all(encoded.as_bytes())?;
+                for _ in 1..original_width {
+                    self.writer.write_all(b" ")?;
+                }
+                return Ok(());
+            }
             let mut buf = [0u8; 4];
             let encoded = safe_ch.encode_utf8(&mut buf);
             self.writer.write_all(encoded.as_bytes())
@@ -995,6 +1187,23 @@ impl<W: Write> Presenter<W> {
     }
 
     /// Move cursor to the specified position.
+    /// Emit `ESC # x` for the row's DEC line attribute when it differs
+    /// from what the terminal last saw on that row. The cursor must
+    /// already be on the row (DEC line attributes apply to the cursor's
+    /// line).
+    fn ensure_line_attr(&mut self, y: u16, attr: crate::buffer::LineAttr) -> io::Result<()> {
+        let idx = usize::from(y);
+        if self.line_attrs_emitted.len() <= idx {
+            self.line_attrs_emitted
+                .resize(idx + 1, crate::buffer::LineAttr::Normal);
+        }
+        if self.line_attrs_emitted[idx] != attr {
+            self.writer.write_all(&[0x1b, b'#', attr.dec_digit()])?;
+            self.line_attrs_emitted[idx] = attr;
+        }
+        Ok(())
+    }
+
     fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
         // Skip if already at position
         if self.cursor_x == Some(x) && self.cursor_y == Some(y) {
@@ -1131,6 +1340,28 @@ impl<W: Write> Presenter<W> {
         self.current_link = None;
         self.cursor_x = None;
         self.cursor_y = None;
+        self.line_attrs_emitted.clear();
+    }
+
+    /// Forget only the cursor position, keeping SGR/link state.
+    ///
+    /// Used between frames when the cursor was moved manually but no
+    /// attribute bytes were written, so style state can carry across
+    /// frames and partial updates skip the defensive reset + full
+    /// re-establishment.
+    pub fn reset_cursor(&mut self) {
+        self.cursor_x = None;
+        self.cursor_y = None;
+    }
+
+    /// Invalidate all assumed terminal state (style, link, cursor).
+    ///
+    /// Call when something outside the presenter may have touched the
+    /// terminal: resize, alt-screen switches, focus regained, or any
+    /// external write. The next emission falls back to the defensive
+    /// reset + full style apply.
+    pub fn invalidate_terminal_state(&mut self) {
+        self.reset();
     }
 
     /// Flush any buffered output.
@@ -1143,9 +1374,8 @@ impl<W: Write> Presenter<W> {
     /// Flushes any buffered data before returning the writer.
     pub fn into_inner(self) -> Result<W, io::Error> {
         self.writer
-            .into_inner() // CountingWriter -> BufWriter<W>
-            .into_inner() // BufWriter<W> -> Result<W, IntoInnerError>
-            .map_err(|e| e.into_error())
+            .into_inner() // CountingWriter -> FrameBatcher<W>
+            .into_inner() // FrameBatcher<W> -> Result<W>
     }
 }
 
@@ -1582,13 +1812,15 @@ mod tests {
 
         // Construct an inconsistent old/new pair that forces a diff which begins at a
         // continuation cell. This simulates starting emission mid-wide-character.
+        // `set_raw` repairs wide pairs nowadays, so the inconsistent state is
+        // written directly into the cell slab.
         //
         // In this case, the presenter should clear the orphan continuation cell so
         // stale terminal content cannot leak through.
-        old.set_raw(0, 0, Cell::from_char('中'));
-        new.set_raw(0, 0, Cell::from_char('中'));
+        old.set_raw(0, 0, Cell::from_char('A'));
+        new.set_raw(0, 0, Cell::from_char('A'));
         old.set_raw(1, 0, Cell::from_char('X'));
-        new.set_raw(1, 0, Cell::CONTINUATION);
+        new.cells_mut()[1] = Cell::CONTINUATION;
 
         let diff = BufferDiff::compute(&old, &new);
         assert_eq!(diff.changes(), &[(1u16, 0u16)]);
@@ -1602,13 +1834,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dec_line_attr_emitted_with_capability() {
+        let mut caps = TerminalCapabilities::basic();
+        caps.double_width = true;
+        let mut presenter = Presenter::new(Vec::new(), caps);
+
+        let old = Buffer::new(10, 2);
+        let mut new = Buffer::new(10, 2);
+        new.set(0, 0, Cell::from_char('B'));
+        new.set_line_attr(0, crate::buffer::LineAttr::DoubleWidth);
+
+        let diff = BufferDiff::compute(&old, &new);
+        presenter.present(&new, &diff).unwrap();
+        let output = get_output(presenter);
+        let output_str = String::from_utf8_lossy(&output);
+        assert!(output_str.contains("\x1b#6"), "DECDWL emitted: {output_str:?}");
+    }
+
+    #[test]
+    fn dec_line_attr_fallback_blanks_bottom_half() {
+        // No double_width capability: top renders normally, the paired
+        // bottom row is blanked instead of duplicated.
+        let mut presenter = test_presenter();
+        let old = Buffer::new(10, 2);
+        let mut new = Buffer::new(10, 2);
+        new.set(0, 0, Cell::from_char('B'));
+        new.set(0, 1, Cell::from_char('B'));
+        new.set_line_attr(0, crate::buffer::LineAttr::DoubleHeightTop);
+        new.set_line_attr(1, crate::buffer::LineAttr::DoubleHeightBottom);
+
+        let diff = BufferDiff::compute(&old, &new);
+        presenter.present(&new, &diff).unwrap();
+        let output = get_output(presenter);
+        let output_str = String::from_utf8_lossy(&output);
+        assert!(!output_str.contains("\x1b#"), "no DEC sequences without cap");
+        assert!(output_str.contains('B'), "top half renders the text");
+        assert!(output_str.contains("\x1b[2K"), "bottom half blanked");
+        // Exactly one B: the bottom row's copy was suppressed.
+        assert_eq!(output_str.matches('B').count(), 1);
+    }
+
+    #[test]
+    fn wide_pair_repainted_atomically_after_half_change() {
+        use crate::terminal_model::TerminalModel;
+
+        let mut presenter = test_presenter();
+        let mut old = Buffer::new(4, 1);
+        let mut new = Buffer::new(4, 1);
+
+        // Old frame: correct head, junk continuation. New frame: proper
+        // wide pair. Only the continuation cell differs bitwise, but the
+        // expanded diff must repaint both cells so the glyph is re-emitted.
+        old.cells_mut()[0] = Cell::from_char('中');
+        old.cells_mut()[1] = Cell::from_char('X');
+        new.set(0, 0, Cell::from_char('中'));
+
+        let diff = BufferDiff::compute(&old, &new);
+        assert!(diff.changes().contains(&(0, 0)));
+
+        presenter.present(&new, &diff).unwrap();
+        let output = get_output(presenter);
+        let output_str = String::from_utf8_lossy(&output);
+        assert!(output_str.contains('中'), "head glyph re-emitted: {output_str:?}");
+
+        // Replaying the emitted bytes leaves the terminal showing the
+        // complete pair, not a half-glyph.
+        let mut model = TerminalModel::new(4, 1);
+        model.process(&output);
+        assert_eq!(model.cell(0, 0).unwrap().text, "中");
+    }
+
     #[test]
     fn wide_char_missing_continuation_causes_drift() {
         let mut presenter = test_presenter();
         let mut buffer = Buffer::new(10, 1);
 
-        // Bug scenario: User sets wide char but forgets continuation
-        buffer.set_raw(0, 0, Cell::from_char('中'));
+        // Bug scenario: a wide head lands without its continuation. `set_raw`
+        // repairs pairs nowadays, so write the slab directly.
+        buffer.cells_mut()[0] = Cell::from_char('中');
         // (1,0) remains empty (space), instead of CONTINUATION
 
         let old = Buffer::new(10, 1);
@@ -4404,6 +4708,295 @@ mod tests {
             "Should contain subsequent character 'A'"
         );
     }
+    // --- Hyperlink span scenarios ---
+
+    #[test]
+    fn hyperlink_span_split_across_two_rows() {
+        let mut presenter = test_presenter_with_hyperlinks();
+        let mut buffer = Buffer::new(3, 2);
+        let mut registry = LinkRegistry::new();
+        let link_id = registry.register("https://two.rows");
+        let linked = |ch: char| {
+            Cell::from_char(ch).with_attrs(CellAttrs::new(StyleFlags::empty(), link_id))
+        };
+        // "AB" on row 0, continuing with "C" on row 1.
+        buffer.set_raw(1, 0, linked('A'));
+        buffer.set_raw(2, 0, linked('B'));
+        buffer.set_raw(0, 1, linked('C'));
+
+        let old = Buffer::new(3, 2);
+        let diff = BufferDiff::compute(&old, &buffer);
+        presenter
+            .present_with_pool(&buffer, &diff, None, Some(&registry))
+            .unwrap();
+        let output = get_output(presenter);
+        let text = String::from_utf8_lossy(&output);
+
+        // The link opens on each row's run and every open is closed:
+        // crossing the row boundary must not leave a link spanning the
+        // cursor-move sequence.
+        let opens = text.matches("]8;;https://two.rows").count();
+        let closes = text.matches("]8;;").count();
+        assert!(opens >= 1, "link must open: {text:?}");
+        assert_eq!(opens, closes, "every open needs a close: {text:?}");
+        // All three characters are emitted.
+        for ch in ['A', 'B', 'C'] {
+            assert!(text.contains(ch), "{ch} missing");
+        }
+    }
+
+    #[test]
+    fn hyperlink_closed_before_sync_end() {
+        let mut caps = TerminalCapabilities::basic();
+        caps.sync_output = true;
+        caps.osc8_hyperlinks = true;
+        let mut presenter = Presenter::new(Vec::<u8>::new(), caps);
+
+        let mut buffer = Buffer::new(3, 1);
+        let mut registry = LinkRegistry::new();
+        let link_id = registry.register("https://sync.test");
+        buffer.set_raw(
+            2,
+            0,
+            Cell::from_char('L').with_attrs(CellAttrs::new(StyleFlags::empty(), link_id)),
+        );
+
+        let old = Buffer::new(3, 1);
+        let diff = BufferDiff::compute(&old, &buffer);
+        presenter
+            .present_with_pool(&buffer, &diff, None, Some(&registry))
+            .unwrap();
+        let output = get_output(presenter);
+        let text = String::from_utf8_lossy(&output);
+
+        let close = text.rfind("]8;;").expect("link close");
+        let sync_end = text.rfind("[?2026l").expect("sync end");
+        assert!(close < sync_end, "link must close before sync_end: {text:?}");
+    }
+
+    #[test]
+    fn diff_detects_link_id_only_change() {
+        let mut registry = LinkRegistry::new();
+        let a = registry.register("https://a.example");
+        let b = registry.register("https://b.example");
+
+        let mut before = Buffer::new(2, 1);
+        before.set_raw(
+            0,
+            0,
+            Cell::from_char('X').with_attrs(CellAttrs::new(StyleFlags::empty(), a)),
+        );
+        let mut after = Buffer::new(2, 1);
+        after.set_raw(
+            0,
+            0,
+            Cell::from_char('X').with_attrs(CellAttrs::new(StyleFlags::empty(), b)),
+        );
+
+        let diff = BufferDiff::compute(&before, &after);
+        assert_eq!(diff.len(), 1, "link-id-only change must dirty the cell");
+    }
+
+    #[test]
+    fn no_osc8_bytes_when_capability_off() {
+        // Basic capabilities: hyperlinks unsupported.
+        let mut presenter = test_presenter();
+        let mut buffer = Buffer::new(3, 1);
+        let mut registry = LinkRegistry::new();
+        let link_id = registry.register("https://nope.example");
+        buffer.set_raw(
+            0,
+            0,
+            Cell::from_char('L').with_attrs(CellAttrs::new(StyleFlags::empty(), link_id)),
+        );
+
+        let old = Buffer::new(3, 1);
+        let diff = BufferDiff::compute(&old, &buffer);
+        presenter
+            .present_with_pool(&buffer, &diff, None, Some(&registry))
+            .unwrap();
+        let output = get_output(presenter);
+        let text = String::from_utf8_lossy(&output);
+        assert!(!text.contains("]8"), "no OSC 8 on basic caps: {text:?}");
+        assert!(text.contains('L'), "text still renders");
+    }
+
+    // --- Erase-line (EL) optimization ---
+
+    mod erase_line_opt {
+        use super::*;
+        use crate::terminal_model::TerminalModel;
+
+        const COLS: u16 = 200;
+
+        fn bce_presenter() -> Presenter<Vec<u8>> {
+            let mut caps = TerminalCapabilities::basic();
+            caps.back_color_erase = true;
+            Presenter::new(Vec::new(), caps)
+        }
+
+        fn long_line_buffer(text_len: usize) -> Buffer {
+            let mut buffer = Buffer::new(COLS, 1);
+            for x in 0..text_len {
+                buffer.set_raw(x as u16, 0, Cell::from_char('x'));
+            }
+            buffer
+        }
+
+        /// Present the transition long-line -> short-line and return the
+        /// second frame's bytes.
+        fn shrink_frame(mut presenter: Presenter<Vec<u8>>) -> (Vec<u8>, Buffer, Buffer) {
+            let long = long_line_buffer(180);
+            let short = long_line_buffer(10);
+            let initial = Buffer::new(COLS, 1);
+
+            let diff = BufferDiff::compute(&initial, &long);
+            presenter.present(&long, &diff).unwrap();
+            presenter.writer_mut().clear();
+
+            let diff = BufferDiff::compute(&long, &short);
+            presenter.present(&short, &diff).unwrap();
+            let bytes = get_output(presenter);
+            (bytes, long, short)
+        }
+
+        #[test]
+        fn shrinking_line_uses_el_instead_of_spaces() {
+            let (bytes, _, _) = shrink_frame(test_presenter());
+            let out = String::from_utf8_lossy(&bytes);
+            assert!(out.contains("\x1b[K"), "expected EL in {out:?}");
+            // The 170 newly blank cells must not be written as a space run.
+            assert!(
+                !out.contains(&" ".repeat(32)),
+                "space run should be replaced by EL"
+            );
+            assert!(
+                bytes.len() < 120,
+                "EL frame should be small, got {} bytes",
+                bytes.len()
+            );
+        }
+
+        #[test]
+        fn el_frame_matches_naive_output_in_terminal_model() {
+            let (bytes, long, _short) = shrink_frame(test_presenter());
+
+            // Replay the full session (long frame + shrink frame) through the
+            // terminal model and compare with the target buffer content.
+            let mut presenter = test_presenter();
+            let initial = Buffer::new(COLS, 1);
+            let diff = BufferDiff::compute(&initial, &long);
+            presenter.present(&long, &diff).unwrap();
+            let first_frame = get_output(presenter);
+
+            let mut model = TerminalModel::new(COLS as usize, 1);
+            model.process(&first_frame);
+            model.process(&bytes);
+
+            let row = model.row_text(0).unwrap();
+            assert_eq!(row.trim_end(), "x".repeat(10));
+            for x in 10..COLS as usize {
+                assert_eq!(model.cell(x, 0).unwrap().text, " ", "cell {x}");
+            }
+        }
+
+        #[test]
+        fn full_row_clear_uses_2k() {
+            let mut presenter = test_presenter();
+            let full = long_line_buffer(COLS as usize);
+            let blank = Buffer::new(COLS, 1);
+
+            let diff = BufferDiff::compute(&blank, &full);
+            presenter.present(&full, &diff).unwrap();
+            presenter.writer_mut().clear();
+
+            let diff = BufferDiff::compute(&full, &blank);
+            presenter.present(&blank, &diff).unwrap();
+            let bytes = get_output(presenter);
+            let out = String::from_utf8_lossy(&bytes);
+            assert!(out.contains("\x1b[2K"), "expected 2K in {out:?}");
+        }
+
+        #[test]
+        fn colored_blanks_fall_back_to_spaces_without_bce() {
+            let mut presenter = test_presenter();
+            let full = long_line_buffer(180);
+            let mut colored = Buffer::new(COLS, 1);
+            let bg = PackedRgba::rgb(0, 0, 128);
+            for x in 0..COLS {
+                colored.set_raw(x, 0, Cell::from_char(' ').with_bg(bg));
+            }
+
+            let diff = BufferDiff::compute(&Buffer::new(COLS, 1), &full);
+            presenter.present(&full, &diff).unwrap();
+            presenter.writer_mut().clear();
+
+            let diff = BufferDiff::compute(&full, &colored);
+            presenter.present(&colored, &diff).unwrap();
+            let bytes = get_output(presenter);
+            let out = String::from_utf8_lossy(&bytes);
+            // A colored-background blank run must never become EL on a
+            // non-BCE terminal.
+            assert!(!out.contains("\x1b[K"), "EL must not be used: {out:?}");
+            assert!(!out.contains("\x1b[2K"));
+        }
+
+        #[test]
+        fn colored_blanks_use_el_with_bce() {
+            let mut presenter = bce_presenter();
+            let full = long_line_buffer(180);
+            let mut colored = Buffer::new(COLS, 1);
+            let bg = PackedRgba::rgb(0, 0, 128);
+            for x in 0..COLS {
+                colored.set_raw(x, 0, Cell::from_char(' ').with_bg(bg));
+            }
+
+            let diff = BufferDiff::compute(&Buffer::new(COLS, 1), &full);
+            presenter.present(&full, &diff).unwrap();
+            presenter.writer_mut().clear();
+
+            let diff = BufferDiff::compute(&full, &colored);
+            presenter.present(&colored, &diff).unwrap();
+            let bytes = get_output(presenter);
+            let out = String::from_utf8_lossy(&bytes);
+            assert!(out.contains("\x1b[2K"), "BCE terminal should use 2K: {out:?}");
+            // The background must be set before erasing so BCE fills with it.
+            let el_pos = out.find("\x1b[2K").unwrap();
+            let bg_pos = out.find("48;2;0;0;128").expect("bg sgr before EL");
+            assert!(bg_pos < el_pos, "bg SGR must precede EL");
+        }
+
+        #[test]
+        fn short_blank_runs_still_write_spaces() {
+            let mut presenter = test_presenter();
+            let long = long_line_buffer(COLS as usize);
+            let shorter = long_line_buffer(COLS as usize - 4);
+
+            let diff = BufferDiff::compute(&Buffer::new(COLS, 1), &long);
+            presenter.present(&long, &diff).unwrap();
+            presenter.writer_mut().clear();
+
+            let diff = BufferDiff::compute(&long, &shorter);
+            presenter.present(&shorter, &diff).unwrap();
+            let bytes = get_output(presenter);
+            let out = String::from_utf8_lossy(&bytes);
+            assert!(!out.contains("\x1b[K"), "4-cell tail is below the EL threshold");
+            assert!(out.contains("    "));
+        }
+
+        /// Byte-count regression check for the 200-col log pane scenario.
+        #[test]
+        fn log_pane_shrink_byte_budget() {
+            let (el_bytes, _, _) = shrink_frame(test_presenter());
+
+            // Naive lower bound: 170 cells of spaces plus overhead.
+            assert!(
+                el_bytes.len() * 5 < 170,
+                "EL frame ({} bytes) should be >5x smaller than the space run",
+                el_bytes.len()
+            );
+        }
+    }
 }
 
 #[cfg(test)]
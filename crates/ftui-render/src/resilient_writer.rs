@@ -0,0 +1,318 @@
+#![forbid(unsafe_code)]
+
+//! Resilient writer for real terminal file descriptors.
+//!
+//! A terminal fd is not a well-behaved sink: it can accept fewer bytes
+//! than offered (short write), refuse temporarily (`EAGAIN` /
+//! [`ErrorKind::WouldBlock`]), or disappear (`EPIPE`). [`ResilientWriter`]
+//! absorbs the recoverable cases below the presenter's buffering so the
+//! emitted byte stream is never truncated mid-escape-sequence:
+//!
+//! - short writes are resumed until the chunk is fully accepted;
+//! - `WouldBlock` is retried with bounded exponential backoff, then
+//!   surfaced (callers classify it via [`PresentError`] as
+//!   [`PresentError::Backpressure`]);
+//! - `Interrupted` is retried immediately, matching `write_all`;
+//! - permanent errors propagate untouched so terminal teardown can run.
+//!
+//! [`PresentError`]: crate::presenter::PresentError
+//! [`ErrorKind::WouldBlock`]: std::io::ErrorKind::WouldBlock
+
+use std::io::{self, ErrorKind, Write};
+use web_time::Duration;
+
+/// Retry budget for transient `WouldBlock` faults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum retries for a single write/flush call before the
+    /// backpressure error surfaces.
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubles per retry.
+    pub initial_backoff: Duration,
+    /// Backoff ceiling.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 16,
+            initial_backoff: Duration::from_micros(50),
+            max_backoff: Duration::from_millis(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never sleeps (tests and non-blocking callers).
+    #[must_use]
+    pub fn no_backoff(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+}
+
+/// A [`Write`] adapter that resumes short writes and retries transient
+/// backpressure within a [`RetryPolicy`] budget.
+///
+/// `write` keeps pushing until the whole chunk is accepted; if an error
+/// interrupts it after partial progress, the accepted count is returned
+/// (per the `Write` contract) so buffered layers above consume exactly
+/// the forwarded bytes and never re-submit part of an escape sequence.
+/// The error itself re-surfaces on the next, zero-progress call.
+#[derive(Debug)]
+pub struct ResilientWriter<W: Write> {
+    inner: W,
+    policy: RetryPolicy,
+}
+
+impl<W: Write> ResilientWriter<W> {
+    /// Wrap `inner` with the default retry policy.
+    pub fn new(inner: W) -> Self {
+        Self::with_policy(inner, RetryPolicy::default())
+    }
+
+    /// Wrap `inner` with an explicit retry policy.
+    pub fn with_policy(inner: W, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// The wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap, discarding the retry policy.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Sleep for the backoff step `attempt` (0-based), bounded by the
+    /// policy ceiling. No-op on targets without threads (wasm sinks are
+    /// in-memory and never report `WouldBlock`).
+    fn backoff(&self, attempt: u32) {
+        let step = self
+            .policy
+            .initial_backoff
+            .saturating_mul(1u32 << attempt.min(16))
+            .min(self.policy.max_backoff);
+        if step.is_zero() {
+            return;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::sleep(step);
+    }
+
+    /// Budget exhausted: surface backpressure as `WouldBlock` so
+    /// [`PresentError`](crate::presenter::PresentError) classifies it.
+    fn backpressure_error(&self) -> io::Error {
+        io::Error::new(
+            ErrorKind::WouldBlock,
+            format!(
+                "terminal backpressure persisted beyond {} retries",
+                self.policy.max_attempts
+            ),
+        )
+    }
+}
+
+impl<W: Write> Write for ResilientWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut written = 0;
+        let mut attempts = 0u32;
+        while written < buf.len() {
+            match self.inner.write(&buf[written..]) {
+                Ok(0) => {
+                    // Partial progress must be reported as Ok so buffered
+                    // layers above consume exactly the forwarded bytes;
+                    // the error re-surfaces on the next (zero-progress)
+                    // call.
+                    if written > 0 {
+                        return Ok(written);
+                    }
+                    return Err(io::Error::new(
+                        ErrorKind::WriteZero,
+                        "terminal sink accepted zero bytes",
+                    ));
+                }
+                Ok(n) => {
+                    written += n;
+                    attempts = 0;
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if attempts >= self.policy.max_attempts {
+                        if written > 0 {
+                            return Ok(written);
+                        }
+                        return Err(self.backpressure_error());
+                    }
+                    self.backoff(attempts);
+                    attempts += 1;
+                }
+                Err(e) => {
+                    if written > 0 {
+                        return Ok(written);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut attempts = 0u32;
+        loop {
+            match self.inner.flush() {
+                Ok(()) => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if attempts >= self.policy.max_attempts {
+                        return Err(self.backpressure_error());
+                    }
+                    self.backoff(attempts);
+                    attempts += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Accepts at most `max_chunk` bytes per call.
+    struct ShortSink {
+        out: Vec<u8>,
+        max_chunk: usize,
+    }
+
+    impl Write for ShortSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = buf.len().min(self.max_chunk);
+            self.out.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Fails with `WouldBlock` the first `blocks` calls, then accepts.
+    struct BlockySink {
+        out: Vec<u8>,
+        blocks: usize,
+    }
+
+    impl Write for BlockySink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.blocks > 0 {
+                self.blocks -= 1;
+                return Err(io::Error::from(ErrorKind::WouldBlock));
+            }
+            self.out.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn short_writes_are_resumed_without_truncation() {
+        let mut w = ResilientWriter::new(ShortSink {
+            out: Vec::new(),
+            max_chunk: 3,
+        });
+        assert_eq!(w.write(b"\x1b[?2026h full sequence").unwrap(), 22);
+        assert_eq!(w.get_ref().out, b"\x1b[?2026h full sequence");
+    }
+
+    #[test]
+    fn would_block_retries_within_budget() {
+        let mut w = ResilientWriter::with_policy(
+            BlockySink {
+                out: Vec::new(),
+                blocks: 3,
+            },
+            RetryPolicy::no_backoff(8),
+        );
+        assert_eq!(w.write(b"abc").unwrap(), 3);
+        assert_eq!(w.get_ref().out, b"abc");
+    }
+
+    #[test]
+    fn persistent_would_block_surfaces_after_budget() {
+        let mut w = ResilientWriter::with_policy(
+            BlockySink {
+                out: Vec::new(),
+                blocks: usize::MAX,
+            },
+            RetryPolicy::no_backoff(4),
+        );
+        let err = w.write(b"abc").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+    }
+
+    #[test]
+    fn permanent_errors_pass_through() {
+        struct Broken;
+        impl Write for Broken {
+            fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(ErrorKind::BrokenPipe))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let mut w = ResilientWriter::new(Broken);
+        assert_eq!(w.write(b"x").unwrap_err().kind(), ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn partial_progress_before_error_is_reported_as_ok() {
+        /// Accepts 2 bytes on the first call, then blocks forever.
+        struct TwoThenBlock {
+            out: Vec<u8>,
+            first: bool,
+        }
+        impl Write for TwoThenBlock {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.first {
+                    self.first = false;
+                    let n = buf.len().min(2);
+                    self.out.extend_from_slice(&buf[..n]);
+                    return Ok(n);
+                }
+                Err(io::Error::from(ErrorKind::WouldBlock))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let mut w = ResilientWriter::with_policy(
+            TwoThenBlock {
+                out: Vec::new(),
+                first: true,
+            },
+            RetryPolicy::no_backoff(2),
+        );
+        // Partial progress: Ok(2), not Err — the caller must consume the
+        // two forwarded bytes instead of re-submitting them later.
+        assert_eq!(w.write(b"abcdef").unwrap(), 2);
+        assert_eq!(w.get_ref().out, b"ab");
+        // Zero-progress follow-up surfaces the backpressure error.
+        assert_eq!(w.write(b"cdef").unwrap_err().kind(), ErrorKind::WouldBlock);
+    }
+}
@@ -26,9 +26,14 @@ use std::fmt::Write as FmtWrite;
 use std::fs::File;
 use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, SyncSender, TrySendError, sync_channel};
+use std::thread::JoinHandle;
 use web_time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use tracing::{info, trace};
+use ftui_core::cx::LabClock;
+use tracing::{info, trace, warn};
 
 /// Records terminal output in asciicast v2 format.
 #[derive(Debug)]
@@ -209,7 +214,408 @@ impl<W: Write, R: Write> Write for AsciicastWriter<W, R> {
     }
 }
 
-fn write_header<W: Write>(
+/// Configuration for opt-in session recording.
+///
+/// Disabled by default. Enable via [`SessionRecordingConfig::enabled_file`] on
+/// the program config, or via the `FTUI_ASCIICAST` environment variable
+/// (value = output path). Input recording is privacy-sensitive and stays off
+/// unless explicitly requested (`record_input` or `FTUI_ASCIICAST_INPUT=1`).
+#[derive(Debug, Clone)]
+pub struct SessionRecordingConfig {
+    /// Enable session recording.
+    pub enabled: bool,
+    /// Output path for the asciicast v2 file.
+    pub output_path: PathBuf,
+    /// Record input as `"i"` events (privacy-sensitive; off by default).
+    pub record_input: bool,
+    /// Bounded channel capacity between the render path and the writer thread.
+    pub channel_capacity: usize,
+}
+
+impl Default for SessionRecordingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            output_path: PathBuf::from("session.cast"),
+            record_input: false,
+            channel_capacity: 1024,
+        }
+    }
+}
+
+impl SessionRecordingConfig {
+    /// Enable recording to the given path.
+    #[must_use]
+    pub fn enabled_file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            enabled: true,
+            output_path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Opt in to recording input as `"i"` events.
+    #[must_use]
+    pub fn with_record_input(mut self, enabled: bool) -> Self {
+        self.record_input = enabled;
+        self
+    }
+
+    /// Set the bounded channel capacity.
+    #[must_use]
+    pub fn with_channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = capacity.max(1);
+        self
+    }
+
+    /// Apply `FTUI_ASCIICAST` / `FTUI_ASCIICAST_INPUT` environment overrides.
+    ///
+    /// The env vars only ever turn recording *on*; an explicitly enabled
+    /// config is left untouched.
+    #[must_use]
+    pub fn resolve_env(mut self) -> Self {
+        if !self.enabled
+            && let Ok(path) = std::env::var("FTUI_ASCIICAST")
+            && !path.is_empty()
+        {
+            self.enabled = true;
+            self.output_path = PathBuf::from(path);
+        }
+        if std::env::var("FTUI_ASCIICAST_INPUT").is_ok_and(|v| v == "1") {
+            self.record_input = true;
+        }
+        self
+    }
+}
+
+/// Time source for session event timestamps.
+///
+/// Wall time by default; a [`LabClock`] in tests so event times are exact.
+#[derive(Debug, Clone)]
+enum SessionClock {
+    Wall,
+    Lab(LabClock),
+}
+
+impl SessionClock {
+    fn now(&self) -> Instant {
+        match self {
+            Self::Wall => Instant::now(),
+            Self::Lab(clock) => clock.now(),
+        }
+    }
+}
+
+/// Event queued from the render path to the writer thread.
+#[derive(Debug)]
+enum SessionEvent {
+    /// Output bytes (`"o"`).
+    Output(f64, Vec<u8>),
+    /// Input bytes (`"i"`).
+    Input(f64, Vec<u8>),
+    /// Terminal resize (`"r"`, data `"COLSxROWS"`).
+    Resize(f64, u16, u16),
+    /// Finalize the recording and exit the writer thread.
+    Shutdown,
+}
+
+/// Cloneable handle that feeds the session recorder without blocking.
+///
+/// Events are pushed through a bounded channel via `try_send`; when the
+/// writer thread can't keep up (slow disk), events are dropped and counted
+/// rather than stalling the render path.
+#[derive(Debug, Clone)]
+pub struct SessionRecorderHandle {
+    tx: SyncSender<SessionEvent>,
+    clock: SessionClock,
+    start: Instant,
+    record_input: bool,
+    dropped: Arc<AtomicU64>,
+}
+
+impl SessionRecorderHandle {
+    fn elapsed(&self) -> f64 {
+        self.clock
+            .now()
+            .saturating_duration_since(self.start)
+            .as_secs_f64()
+    }
+
+    fn push(&self, event: SessionEvent) {
+        match self.tx.try_send(event) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(TrySendError::Disconnected(_)) => {}
+        }
+    }
+
+    /// Record output bytes as an `"o"` event.
+    pub fn record_output(&self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        self.push(SessionEvent::Output(self.elapsed(), data.to_vec()));
+    }
+
+    /// Record input bytes as an `"i"` event (no-op unless input recording
+    /// was explicitly enabled).
+    pub fn record_input(&self, data: &[u8]) {
+        if !self.record_input || data.is_empty() {
+            return;
+        }
+        self.push(SessionEvent::Input(self.elapsed(), data.to_vec()));
+    }
+
+    /// Record a terminal resize as an `"r"` event.
+    pub fn record_resize(&self, width: u16, height: u16) {
+        self.push(SessionEvent::Resize(self.elapsed(), width, height));
+    }
+
+    /// Whether input recording is enabled on this handle.
+    #[must_use]
+    pub const fn records_input(&self) -> bool {
+        self.record_input
+    }
+
+    /// Number of events dropped because the writer thread fell behind.
+    #[must_use]
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Summary returned when a session recording is finalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionRecordingStats {
+    /// Events serialized to the output.
+    pub events_written: u64,
+    /// Events dropped under backpressure.
+    pub events_dropped: u64,
+}
+
+/// Owner of a background session recording.
+///
+/// Holds the writer thread; [`SessionRecorder::finish`] closes the channel,
+/// joins the thread, and flushes the file. Dropping the recorder does the
+/// same on a best-effort basis.
+#[derive(Debug)]
+pub struct SessionRecorder {
+    handle: SessionRecorderHandle,
+    thread: Option<JoinHandle<io::Result<u64>>>,
+}
+
+impl SessionRecorder {
+    /// Spawn a recorder from config, writing to `config.output_path`.
+    ///
+    /// Returns `Ok(None)` when recording is disabled.
+    pub fn from_config(
+        config: &SessionRecordingConfig,
+        width: u16,
+        height: u16,
+    ) -> io::Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let file = File::create(&config.output_path)?;
+        let timestamp = unix_timestamp()?;
+        let recorder = Self::spawn_inner(
+            Box::new(BufWriter::new(file)),
+            config,
+            width,
+            height,
+            timestamp,
+            SessionClock::Wall,
+        );
+        info!(
+            path = ?config.output_path,
+            width,
+            height,
+            record_input = config.record_input,
+            "Session recording started"
+        );
+        Ok(Some(recorder))
+    }
+
+    /// Spawn a recorder writing to an arbitrary writer with wall-clock time.
+    pub fn spawn_with_writer(
+        writer: impl Write + Send + 'static,
+        config: &SessionRecordingConfig,
+        width: u16,
+        height: u16,
+        timestamp: i64,
+    ) -> Self {
+        Self::spawn_inner(
+            Box::new(writer),
+            config,
+            width,
+            height,
+            timestamp,
+            SessionClock::Wall,
+        )
+    }
+
+    /// Spawn a recorder driven by a [`LabClock`] for deterministic timestamps.
+    pub fn spawn_with_lab_clock(
+        writer: impl Write + Send + 'static,
+        config: &SessionRecordingConfig,
+        width: u16,
+        height: u16,
+        timestamp: i64,
+        clock: &LabClock,
+    ) -> Self {
+        Self::spawn_inner(
+            Box::new(writer),
+            config,
+            width,
+            height,
+            timestamp,
+            SessionClock::Lab(clock.clone()),
+        )
+    }
+
+    fn spawn_inner(
+        writer: Box<dyn Write + Send>,
+        config: &SessionRecordingConfig,
+        width: u16,
+        height: u16,
+        timestamp: i64,
+        clock: SessionClock,
+    ) -> Self {
+        let (tx, rx) = sync_channel(config.channel_capacity.max(1));
+        let handle = SessionRecorderHandle {
+            tx,
+            start: clock.now(),
+            clock,
+            record_input: config.record_input,
+            dropped: Arc::new(AtomicU64::new(0)),
+        };
+        let thread = std::thread::Builder::new()
+            .name("ftui-asciicast".into())
+            .spawn(move || session_writer_loop(writer, rx, width, height, timestamp))
+            .expect("failed to spawn asciicast writer thread");
+        Self {
+            handle,
+            thread: Some(thread),
+        }
+    }
+
+    /// Get a cloneable handle for feeding events.
+    #[must_use]
+    pub fn handle(&self) -> SessionRecorderHandle {
+        self.handle.clone()
+    }
+
+    /// Close the channel, join the writer thread, and flush the output.
+    pub fn finish(mut self) -> io::Result<SessionRecordingStats> {
+        self.finish_inner()
+    }
+
+    fn finish_inner(&mut self) -> io::Result<SessionRecordingStats> {
+        let Some(thread) = self.thread.take() else {
+            return Ok(SessionRecordingStats {
+                events_written: 0,
+                events_dropped: self.handle.dropped_events(),
+            });
+        };
+        // Explicit shutdown event: handle clones (e.g. the presenter tap) may
+        // outlive the owner, so waiting for channel disconnect could hang.
+        // A blocking send is fine here — this only runs at teardown.
+        let _ = self.handle.tx.send(SessionEvent::Shutdown);
+        let events_written = thread
+            .join()
+            .map_err(|_| io::Error::other("asciicast writer thread panicked"))??;
+        let events_dropped = self.handle.dropped_events();
+        info!(
+            events = events_written,
+            dropped = events_dropped,
+            "Session recording complete"
+        );
+        Ok(SessionRecordingStats {
+            events_written,
+            events_dropped,
+        })
+    }
+}
+
+impl Drop for SessionRecorder {
+    fn drop(&mut self) {
+        if self.thread.is_some()
+            && let Err(e) = self.finish_inner()
+        {
+            warn!(error = %e, "session recording finalization failed");
+        }
+    }
+}
+
+/// Writer-thread loop: serialize events until the channel disconnects.
+fn session_writer_loop(
+    mut writer: Box<dyn Write + Send>,
+    rx: Receiver<SessionEvent>,
+    width: u16,
+    height: u16,
+    timestamp: i64,
+) -> io::Result<u64> {
+    write_header(&mut writer, width, height, timestamp)?;
+    let mut written = 0u64;
+    while let Ok(event) = rx.recv() {
+        match event {
+            SessionEvent::Output(time, data) => {
+                let escaped = escape_json(&String::from_utf8_lossy(&data));
+                writeln!(writer, "[{time:.6},\"o\",\"{escaped}\"]")?;
+            }
+            SessionEvent::Input(time, data) => {
+                let escaped = escape_json(&String::from_utf8_lossy(&data));
+                writeln!(writer, "[{time:.6},\"i\",\"{escaped}\"]")?;
+            }
+            SessionEvent::Resize(time, w, h) => {
+                writeln!(writer, "[{time:.6},\"r\",\"{w}x{h}\"]")?;
+            }
+            SessionEvent::Shutdown => break,
+        }
+        written += 1;
+    }
+    writer.flush()?;
+    Ok(written)
+}
+
+/// [`WriteTap`](ftui_render::counting_writer::WriteTap) that mirrors
+/// presenter output into a session recorder.
+///
+/// Bytes are buffered locally and emitted as a single `"o"` event per flush
+/// so a frame becomes one asciicast event rather than dozens of fragments.
+#[derive(Debug)]
+pub struct SessionOutputTap {
+    handle: SessionRecorderHandle,
+    pending: Vec<u8>,
+}
+
+impl SessionOutputTap {
+    /// Create a tap feeding the given recorder handle.
+    #[must_use]
+    pub fn new(handle: SessionRecorderHandle) -> Self {
+        Self {
+            handle,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl ftui_render::counting_writer::WriteTap for SessionOutputTap {
+    fn on_write(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+    }
+
+    fn on_flush(&mut self) {
+        if !self.pending.is_empty() {
+            self.handle.record_output(&self.pending);
+            self.pending.clear();
+        }
+    }
+}
+
+fn write_header<W: Write + ?Sized>(
     output: &mut W,
     width: u16,
     height: u16,
@@ -450,4 +856,226 @@ mod tests {
         let lines: Vec<&str> = output.lines().collect();
         assert_eq!(lines.len(), 1); // header only
     }
+
+    // --- Session recorder (channelized) tests ---
+
+    use std::sync::Mutex;
+
+    /// Shared buffer writer for inspecting writer-thread output.
+    #[derive(Clone)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuf {
+        fn new() -> Self {
+            Self(Arc::new(Mutex::new(Vec::new())))
+        }
+
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Writer that blocks on write until released, for backpressure tests.
+    #[derive(Clone)]
+    struct StalledWriter {
+        released: Arc<(Mutex<bool>, std::sync::Condvar)>,
+    }
+
+    impl StalledWriter {
+        fn new() -> Self {
+            Self {
+                released: Arc::new((Mutex::new(false), std::sync::Condvar::new())),
+            }
+        }
+
+        fn release(&self) {
+            let (lock, cvar) = &*self.released;
+            *lock.lock().unwrap() = true;
+            cvar.notify_all();
+        }
+    }
+
+    impl Write for StalledWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let (lock, cvar) = &*self.released;
+            let mut released = lock.lock().unwrap();
+            while !*released {
+                released = cvar.wait(released).unwrap();
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Minimal asciicast v2 structural parser: header object + event arrays.
+    ///
+    /// Returns `(width, height, events)` where each event is
+    /// `(time, kind, data)`. Panics on structural violations.
+    fn parse_asciicast(contents: &str) -> (u64, u64, Vec<(f64, String, String)>) {
+        let mut lines = contents.lines();
+        let header: serde_json::Value =
+            serde_json::from_str(lines.next().expect("missing header")).expect("header not JSON");
+        assert_eq!(header["version"], 2, "header must declare version 2");
+        let width = header["width"].as_u64().expect("width must be a number");
+        let height = header["height"].as_u64().expect("height must be a number");
+        let mut events = Vec::new();
+        for line in lines {
+            let event: serde_json::Value = serde_json::from_str(line).expect("event not JSON");
+            let arr = event.as_array().expect("event must be an array");
+            assert_eq!(arr.len(), 3, "event must be [time, kind, data]");
+            let time = arr[0].as_f64().expect("event time must be a number");
+            let kind = arr[1].as_str().expect("event kind must be a string");
+            assert!(
+                matches!(kind, "o" | "i" | "r" | "m"),
+                "unknown event kind {kind:?}"
+            );
+            let data = arr[2].as_str().expect("event data must be a string");
+            events.push((time, kind.to_string(), data.to_string()));
+        }
+        (width, height, events)
+    }
+
+    fn lab_session(config: &SessionRecordingConfig) -> (SessionRecorder, SharedBuf, LabClock) {
+        let buf = SharedBuf::new();
+        let clock = LabClock::new();
+        let recorder =
+            SessionRecorder::spawn_with_lab_clock(buf.clone(), config, 80, 24, 42, &clock);
+        (recorder, buf, clock)
+    }
+
+    #[test]
+    fn session_recorder_header_is_structurally_valid() {
+        let config = SessionRecordingConfig::default();
+        let (recorder, buf, _clock) = lab_session(&config);
+        recorder.finish().unwrap();
+        let (width, height, events) = parse_asciicast(&buf.contents());
+        assert_eq!(width, 80);
+        assert_eq!(height, 24);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn session_recorder_lab_time_drives_event_timestamps() {
+        let config = SessionRecordingConfig::default();
+        let (recorder, buf, clock) = lab_session(&config);
+        let handle = recorder.handle();
+
+        handle.record_output(b"first");
+        clock.advance(Duration::from_millis(1500));
+        handle.record_output(b"second");
+        clock.advance(Duration::from_millis(250));
+        handle.record_resize(100, 40);
+        recorder.finish().unwrap();
+
+        let (_, _, events) = parse_asciicast(&buf.contents());
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0], (0.0, "o".into(), "first".into()));
+        assert_eq!(events[1], (1.5, "o".into(), "second".into()));
+        assert_eq!(events[2], (1.75, "r".into(), "100x40".into()));
+    }
+
+    #[test]
+    fn session_recorder_resize_event_format() {
+        let config = SessionRecordingConfig::default();
+        let (recorder, buf, _clock) = lab_session(&config);
+        recorder.handle().record_resize(132, 43);
+        recorder.finish().unwrap();
+        let (_, _, events) = parse_asciicast(&buf.contents());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].1, "r");
+        assert_eq!(events[0].2, "132x43");
+    }
+
+    #[test]
+    fn session_recorder_input_requires_opt_in() {
+        let config = SessionRecordingConfig::default();
+        let (recorder, buf, _clock) = lab_session(&config);
+        let handle = recorder.handle();
+        assert!(!handle.records_input());
+        handle.record_input(b"secret");
+        recorder.finish().unwrap();
+        let (_, _, events) = parse_asciicast(&buf.contents());
+        assert!(events.is_empty(), "input must not be recorded by default");
+    }
+
+    #[test]
+    fn session_recorder_input_recorded_when_enabled() {
+        let config = SessionRecordingConfig::default().with_record_input(true);
+        let (recorder, buf, _clock) = lab_session(&config);
+        recorder.handle().record_input(b"ls\r");
+        recorder.finish().unwrap();
+        let (_, _, events) = parse_asciicast(&buf.contents());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].1, "i");
+        assert_eq!(events[0].2, "ls\r");
+    }
+
+    #[test]
+    fn session_recorder_drops_and_counts_when_writer_stalls() {
+        let config = SessionRecordingConfig::default().with_channel_capacity(2);
+        let writer = StalledWriter::new();
+        let clock = LabClock::new();
+        let recorder =
+            SessionRecorder::spawn_with_lab_clock(writer.clone(), &config, 80, 24, 0, &clock);
+        let handle = recorder.handle();
+
+        // The writer thread blocks on the header write, so nothing drains the
+        // channel: after capacity is filled, further events must be dropped.
+        for _ in 0..10 {
+            handle.record_output(b"x");
+        }
+        assert!(
+            handle.dropped_events() >= 8,
+            "expected drops, got {}",
+            handle.dropped_events()
+        );
+
+        writer.release();
+        let stats = recorder.finish().unwrap();
+        assert_eq!(stats.events_dropped, handle.dropped_events());
+        assert!(stats.events_written <= 2);
+    }
+
+    #[test]
+    fn session_recording_config_env_resolution_respects_explicit_enable() {
+        let config = SessionRecordingConfig::enabled_file("explicit.cast");
+        let resolved = config.clone().resolve_env();
+        assert_eq!(resolved.output_path, config.output_path);
+        assert!(resolved.enabled);
+    }
+
+    #[test]
+    fn session_output_tap_coalesces_writes_per_flush() {
+        use ftui_render::counting_writer::WriteTap;
+
+        let config = SessionRecordingConfig::default();
+        let (recorder, buf, _clock) = lab_session(&config);
+        let mut tap = SessionOutputTap::new(recorder.handle());
+
+        tap.on_write(b"\x1b[2J");
+        tap.on_write(b"hello");
+        tap.on_flush();
+        tap.on_write(b"more");
+        tap.on_flush();
+        recorder.finish().unwrap();
+
+        let (_, _, events) = parse_asciicast(&buf.contents());
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].2, "\u{1b}[2Jhello");
+        assert_eq!(events[1].2, "more");
+    }
 }
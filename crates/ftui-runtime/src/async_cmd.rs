@@ -0,0 +1,426 @@
+#![forbid(unsafe_code)]
+
+//! Async command execution (feature `async`).
+//!
+//! Bridges futures into the Elm-style command flow: `Cmd::future(fut)`
+//! spawns the future on a runtime-owned executor and delivers its output
+//! through the normal message queue. Apps already running tokio (or any
+//! other reactor) inject their own [`Spawner`] instead, so futures execute
+//! on their runtime of choice.
+//!
+//! Every spawned future is wrapped with an associated [`Cx`]:
+//! - cancellation (via the runtime or shutdown) drops the future without
+//!   delivering its message,
+//! - deadline expiry resolves it to a configurable timeout message.
+//!
+//! Shutdown never hangs on detached futures: the executor cancels all
+//! pending contexts, waits a bounded drain interval for in-flight wrappers
+//! to observe cancellation, then abandons them.
+//!
+//! On `wasm32` the same API is backed by `wasm_bindgen_futures::spawn_local`
+//! (futures there are `!Send`, matching the platform).
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::task::{Context, Poll};
+use web_time::Duration;
+
+use ftui_core::cx::{Cx, CxController};
+
+/// A boxed unit future, as handed to a [`Spawner`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+/// A boxed unit future, as handed to a [`Spawner`] (wasm: `!Send`).
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+/// A boxed message-producing future, as stored in `Cmd::Future`.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxMsgFuture<M> = Pin<Box<dyn Future<Output = M> + Send + 'static>>;
+/// A boxed message-producing future (wasm: `!Send`).
+#[cfg(target_arch = "wasm32")]
+pub type BoxMsgFuture<M> = Pin<Box<dyn Future<Output = M> + 'static>>;
+
+/// Executor injection point for async commands.
+///
+/// The default [`ThreadSpawner`] runs each future to completion on its own
+/// thread with a coarse re-poll tick, so cancellation and deadlines are
+/// observed even when the future itself never wakes. Tokio users implement
+/// this with `tokio::spawn`:
+///
+/// ```ignore
+/// struct TokioSpawner(tokio::runtime::Handle);
+/// impl ftui_runtime::async_cmd::Spawner for TokioSpawner {
+///     fn spawn(&self, fut: ftui_runtime::async_cmd::BoxFuture) {
+///         self.0.spawn(fut);
+///     }
+/// }
+/// ```
+pub trait Spawner: Send + Sync {
+    /// Run the future to completion (detached).
+    fn spawn(&self, fut: BoxFuture);
+}
+
+/// Cloneable spawner handle for config plumbing (`Arc<dyn Spawner>` with a
+/// `Debug` impl so it can live in derived-config structs).
+#[derive(Clone)]
+pub struct SharedSpawner(pub Arc<dyn Spawner>);
+
+impl Default for SharedSpawner {
+    fn default() -> Self {
+        Self(Arc::new(ThreadSpawner))
+    }
+}
+
+impl std::fmt::Debug for SharedSpawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedSpawner")
+    }
+}
+
+/// Interval at which the built-in executor re-polls pending futures to
+/// observe cancellation and deadline expiry.
+const EXECUTOR_TICK: Duration = Duration::from_millis(10);
+
+/// Built-in thread-per-future executor.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadSpawner;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Spawner for ThreadSpawner {
+    fn spawn(&self, fut: BoxFuture) {
+        std::thread::Builder::new()
+            .name("ftui-async-cmd".into())
+            .spawn(move || block_on_with_tick(fut))
+            .expect("failed to spawn async command thread");
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Spawner for ThreadSpawner {
+    fn spawn(&self, fut: BoxFuture) {
+        wasm_bindgen_futures::spawn_local(fut);
+    }
+}
+
+/// Park-based block_on with a maximum park interval.
+///
+/// The coarse tick bounds how stale a cancellation/deadline check can get
+/// when the wrapped future is pending without scheduling a wake.
+#[cfg(not(target_arch = "wasm32"))]
+fn block_on_with_tick(mut fut: BoxFuture) {
+    struct ThreadWaker(std::thread::Thread);
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = std::task::Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(()) => return,
+            Poll::Pending => std::thread::park_timeout(EXECUTOR_TICK),
+        }
+    }
+}
+
+/// Wrapper polled by the spawner: observes the [`Cx`] and routes the
+/// output message (or the timeout message) into the runtime queue.
+struct DeliverFuture<M> {
+    inner: BoxMsgFuture<M>,
+    cx: Cx,
+    /// Boxed so the wrapper stays `Unpin` regardless of the message type.
+    timeout_msg: Option<Box<M>>,
+    sender: Sender<M>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl<M> Drop for DeliverFuture<M> {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl<M> Future for DeliverFuture<M> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, task_cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.cx.is_cancelled() {
+            // Cancellation drops the inner future without delivery.
+            return Poll::Ready(());
+        }
+        if this.cx.is_expired() {
+            if let Some(msg) = this.timeout_msg.take() {
+                let _ = this.sender.send(*msg);
+            }
+            return Poll::Ready(());
+        }
+        match this.inner.as_mut().poll(task_cx) {
+            Poll::Ready(msg) => {
+                let _ = this.sender.send(msg);
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Runtime-side bookkeeping for spawned async commands.
+pub struct AsyncCmdExecutor<M> {
+    spawner: Arc<dyn Spawner>,
+    /// Cancellation handles for all spawned futures.
+    controllers: Vec<CxController>,
+    /// Count of wrappers not yet dropped (completed or cancelled).
+    in_flight: Arc<AtomicUsize>,
+    _msg: std::marker::PhantomData<fn() -> M>,
+}
+
+impl<M> std::fmt::Debug for AsyncCmdExecutor<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncCmdExecutor")
+            .field("pending", &self.pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> Default for AsyncCmdExecutor<M> {
+    fn default() -> Self {
+        Self::new(Arc::new(ThreadSpawner))
+    }
+}
+
+impl<M> AsyncCmdExecutor<M> {
+    /// Create an executor backed by the given spawner.
+    #[must_use]
+    pub fn new(spawner: Arc<dyn Spawner>) -> Self {
+        Self {
+            spawner,
+            controllers: Vec::new(),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            _msg: std::marker::PhantomData,
+        }
+    }
+
+    /// Number of futures spawned and not yet finished.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Drop bookkeeping for completed futures.
+    pub fn reap(&mut self) {
+        if self.pending() == 0 {
+            self.controllers.clear();
+        }
+    }
+
+    /// Cancel everything and wait (bounded) for wrappers to drain.
+    ///
+    /// Returns `true` when all futures observed cancellation within the
+    /// bound; `false` when some were abandoned (detached threads/tasks —
+    /// they can no longer deliver messages either way once the receiver
+    /// drops).
+    pub fn shutdown(&mut self, drain_bound: Duration) -> bool {
+        for controller in &self.controllers {
+            controller.cancel();
+        }
+        self.controllers.clear();
+
+        let deadline = web_time::Instant::now() + drain_bound;
+        while self.pending() > 0 {
+            if web_time::Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::yield_now();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        true
+    }
+}
+
+impl<M: Send + 'static> AsyncCmdExecutor<M> {
+    /// Spawn a message future, delivering through `sender`.
+    ///
+    /// `timeout` bounds execution via the future's [`Cx`] deadline; on
+    /// expiry `timeout_msg` is delivered instead (if provided).
+    pub fn spawn(
+        &mut self,
+        fut: BoxMsgFuture<M>,
+        sender: Sender<M>,
+        timeout: Option<Duration>,
+        timeout_msg: Option<M>,
+    ) {
+        let (cx, controller) = match timeout {
+            Some(deadline) => Cx::with_deadline(deadline),
+            None => Cx::background(),
+        };
+        self.spawn_with_cx(fut, sender, cx, controller, timeout_msg);
+    }
+
+    /// Spawn with an externally constructed [`Cx`] (lab clocks in tests).
+    pub fn spawn_with_cx(
+        &mut self,
+        fut: BoxMsgFuture<M>,
+        sender: Sender<M>,
+        cx: Cx,
+        controller: CxController,
+        timeout_msg: Option<M>,
+    ) {
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        let wrapper = DeliverFuture {
+            inner: fut,
+            cx,
+            timeout_msg: timeout_msg.map(Box::new),
+            sender,
+            in_flight: Arc::clone(&self.in_flight),
+        };
+        self.controllers.push(controller);
+        self.spawner.spawn(Box::pin(wrapper));
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use ftui_core::cx::LabClock;
+    use std::sync::mpsc;
+    use std::time::Instant as StdInstant;
+
+    /// Future that completes once the lab clock passes `ready_at`.
+    struct LabTimerFuture {
+        clock: LabClock,
+        start: web_time::Instant,
+        delay: Duration,
+    }
+
+    impl Future for LabTimerFuture {
+        type Output = &'static str;
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<&'static str> {
+            // The built-in executor re-polls on its tick, so no wake
+            // scheduling is needed here.
+            if self.clock.now().saturating_duration_since(self.start) >= self.delay {
+                Poll::Ready("done")
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Future that never completes.
+    struct PendingForever;
+
+    impl Future for PendingForever {
+        type Output = &'static str;
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<&'static str> {
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn future_resolving_after_lab_advance_delivers_msg() {
+        let clock = LabClock::new();
+        let mut executor = AsyncCmdExecutor::<&'static str>::default();
+        let (tx, rx) = mpsc::channel();
+
+        let fut = LabTimerFuture {
+            clock: clock.clone(),
+            start: clock.now(),
+            delay: Duration::from_secs(5),
+        };
+        let (cx, controller) = Cx::lab(&clock);
+        executor.spawn_with_cx(Box::pin(fut), tx, cx, controller, None);
+
+        // Nothing yet: lab time hasn't advanced.
+        assert!(rx.try_recv().is_err());
+
+        clock.advance(Duration::from_secs(6));
+        let msg = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(msg, "done");
+    }
+
+    #[test]
+    fn cancellation_prevents_delivery() {
+        let clock = LabClock::new();
+        let mut executor = AsyncCmdExecutor::<&'static str>::default();
+        let (tx, rx) = mpsc::channel();
+
+        let fut = LabTimerFuture {
+            clock: clock.clone(),
+            start: clock.now(),
+            delay: Duration::from_secs(5),
+        };
+        let (cx, controller) = Cx::lab(&clock);
+        executor.spawn_with_cx(Box::pin(fut), tx, cx, controller, None);
+
+        // Cancel before the future can complete, then advance past its
+        // completion time: the wrapper must drop it without delivering.
+        assert!(executor.shutdown(Duration::from_secs(5)));
+        clock.advance(Duration::from_secs(6));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn deadline_expiry_delivers_timeout_msg() {
+        let clock = LabClock::new();
+        let mut executor = AsyncCmdExecutor::<&'static str>::default();
+        let (tx, rx) = mpsc::channel();
+
+        let fut = PendingForever;
+        let (cx, controller) = Cx::lab_with_deadline(&clock, Duration::from_secs(2));
+        executor.spawn_with_cx(Box::pin(fut), tx, cx, controller, Some("timed out"));
+
+        clock.advance(Duration::from_secs(3));
+        let msg = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(msg, "timed out");
+    }
+
+    #[test]
+    fn shutdown_with_pending_future_completes_within_bound() {
+        let mut executor = AsyncCmdExecutor::<&'static str>::default();
+        let (tx, _rx) = mpsc::channel();
+        executor.spawn(Box::pin(PendingForever), tx, None, None);
+
+        let bound = Duration::from_millis(500);
+        let start = StdInstant::now();
+        let drained = executor.shutdown(bound);
+        let elapsed = start.elapsed();
+
+        assert!(drained, "cancelled future should drain, took {elapsed:?}");
+        assert!(
+            elapsed < bound + Duration::from_millis(200),
+            "shutdown exceeded bound: {elapsed:?}"
+        );
+        assert_eq!(executor.pending(), 0);
+    }
+
+    #[test]
+    fn injected_tokio_spawner_delivers_msg() {
+        struct TokioSpawner(tokio::runtime::Handle);
+        impl Spawner for TokioSpawner {
+            fn spawn(&self, fut: BoxFuture) {
+                self.0.spawn(fut);
+            }
+        }
+
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .build()
+            .unwrap();
+        let mut executor =
+            AsyncCmdExecutor::<&'static str>::new(Arc::new(TokioSpawner(rt.handle().clone())));
+        let (tx, rx) = mpsc::channel();
+
+        executor.spawn(Box::pin(async { "from tokio" }), tx, None, None);
+        let msg = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(msg, "from tokio");
+    }
+}
@@ -0,0 +1,550 @@
+#![forbid(unsafe_code)]
+
+//! Child-process subscription: spawn, stream output, kill on cancel.
+//!
+//! Build runners and git UIs shell out constantly; hand-rolling the
+//! thread/pipe plumbing reproduces the ProcessSubscription deadlock
+//! class from the runtime audit (readers blocked on a full pipe while
+//! shutdown waits on the reader). [`CommandSubscription`] owns the whole
+//! lifecycle on the subscription worker model:
+//!
+//! - stdout and stderr stream as separate [`CommandEvent`] variants in
+//!   line-buffered chunks, each carrying a global monotonically
+//!   increasing sequence number so interleaving can be reconstructed;
+//! - a final [`CommandEvent::Exited`] reports status, runtime and the
+//!   dropped-bytes counter;
+//! - subscription removal (reconcile, shutdown) sends SIGTERM, waits a
+//!   grace period, then SIGKILL (Windows: `TerminateProcess` directly),
+//!   so a stuck child can never hang teardown;
+//! - output flooding lands in a bounded buffer: overflowing lines are
+//!   dropped and counted instead of growing memory without bound.
+//!
+//! Caveat: signals reach the direct child only. A shell that forked
+//! grandchildren (multi-command `sh -c` scripts) leaves them orphaned on
+//! cancellation — process-group delivery would require `unsafe` libc
+//! calls, which this crate forbids. Teardown still never blocks on them.
+
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, TrySendError};
+
+use web_time::{Duration, Instant};
+
+use crate::subscription::{StopSignal, SubId, Subscription};
+
+/// Poll slice for the forwarding loop and kill-grace checks.
+const POLL_SLICE: Duration = Duration::from_millis(20);
+
+/// What to run and how.
+#[derive(Debug, Clone, Default)]
+pub struct CommandSpec {
+    /// Program to execute.
+    pub program: String,
+    /// Arguments.
+    pub args: Vec<String>,
+    /// Working directory (inherited when `None`).
+    pub cwd: Option<PathBuf>,
+    /// Extra environment variables.
+    pub env: Vec<(String, String)>,
+    /// Bytes written to the child's stdin before it is closed.
+    pub stdin: Option<Vec<u8>>,
+}
+
+impl CommandSpec {
+    /// Spec for `program` with arguments.
+    pub fn new(
+        program: impl Into<String>,
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            program: program.into(),
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// How the child terminated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandExit {
+    /// Normal exit with a code.
+    Code(i32),
+    /// Terminated by a signal (unix).
+    Signal(i32),
+    /// Status could not be decoded.
+    Unknown,
+}
+
+impl CommandExit {
+    fn from_status(status: ExitStatus) -> Self {
+        if let Some(code) = status.code() {
+            return Self::Code(code);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = status.signal() {
+                return Self::Signal(signal);
+            }
+        }
+        Self::Unknown
+    }
+}
+
+/// Streamed output and lifecycle events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandEvent {
+    /// One stdout line. `seq` is global across both streams.
+    Stdout {
+        /// Monotonic sequence number across stdout and stderr.
+        seq: u64,
+        /// The line, without the trailing newline.
+        line: String,
+    },
+    /// One stderr line. `seq` is global across both streams.
+    Stderr {
+        /// Monotonic sequence number across stdout and stderr.
+        seq: u64,
+        /// The line, without the trailing newline.
+        line: String,
+    },
+    /// The child exited (or was killed on cancellation).
+    Exited {
+        /// How the child terminated.
+        status: CommandExit,
+        /// Wall time from spawn to exit.
+        duration: Duration,
+        /// Output bytes dropped by the bounded buffer.
+        dropped_bytes: u64,
+    },
+}
+
+/// Create a child-process subscription (see [`CommandSubscription`]).
+pub fn command<M: Send + 'static>(
+    spec: CommandSpec,
+    make_msg: impl Fn(CommandEvent) -> M + Send + Sync + 'static,
+) -> CommandSubscription<M> {
+    CommandSubscription::new(spec, make_msg)
+}
+
+/// Subscription that runs a child process and streams its output.
+pub struct CommandSubscription<M: Send + 'static> {
+    id: SubId,
+    spec: CommandSpec,
+    /// SIGTERM→SIGKILL grace period on cancellation.
+    grace: Duration,
+    /// Bounded buffer capacity in lines.
+    buffer_lines: usize,
+    make_msg: Box<dyn Fn(CommandEvent) -> M + Send + Sync>,
+}
+
+impl<M: Send + 'static> CommandSubscription<M> {
+    /// Create a subscription for `spec`.
+    pub fn new(
+        spec: CommandSpec,
+        make_msg: impl Fn(CommandEvent) -> M + Send + Sync + 'static,
+    ) -> Self {
+        // Every spec field participates: a changed declaration must not
+        // be mistaken for the running child during reconcile.
+        let mut id = fnv1a64(spec.program.as_bytes());
+        for arg in &spec.args {
+            id = id.rotate_left(7) ^ fnv1a64(arg.as_bytes());
+        }
+        if let Some(cwd) = &spec.cwd {
+            id = id.rotate_left(11) ^ fnv1a64(cwd.as_os_str().as_encoded_bytes());
+        }
+        for (key, value) in &spec.env {
+            id = id.rotate_left(13) ^ fnv1a64(key.as_bytes()) ^ fnv1a64(value.as_bytes());
+        }
+        if let Some(stdin) = &spec.stdin {
+            id = id.rotate_left(17) ^ fnv1a64(stdin);
+        }
+        Self {
+            id,
+            spec,
+            grace: Duration::from_millis(500),
+            buffer_lines: 1024,
+            make_msg: Box::new(make_msg),
+        }
+    }
+
+    /// Explicit ID (deduplication control across reconciles).
+    #[must_use]
+    pub fn with_id(mut self, id: SubId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// SIGTERM→SIGKILL grace period (default 500ms).
+    #[must_use]
+    pub fn with_grace(mut self, grace: Duration) -> Self {
+        self.grace = grace;
+        self
+    }
+
+    /// Bounded output buffer capacity in lines (default 1024).
+    #[must_use]
+    pub fn with_buffer_lines(mut self, lines: usize) -> Self {
+        self.buffer_lines = lines.max(1);
+        self
+    }
+
+    /// Terminate the child: SIGTERM, grace, then SIGKILL.
+    fn terminate(&self, child: &mut Child) {
+        #[cfg(unix)]
+        {
+            send_sigterm(child.id());
+            let deadline = Instant::now() + self.grace;
+            while Instant::now() < deadline {
+                if matches!(child.try_wait(), Ok(Some(_))) {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+        // SIGKILL / TerminateProcess; ignore failures (already dead).
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+/// Deliver SIGTERM to a child we own.
+///
+/// The crate forbids `unsafe`, which rules out `kill(2)` directly;
+/// `kill(1)` is POSIX-guaranteed and the delivery is advisory anyway —
+/// the SIGKILL fallback after the grace period is what guarantees
+/// termination.
+#[cfg(unix)]
+fn send_sigterm(pid: u32) {
+    let _ = Command::new("kill")
+        .args(["-s", "TERM", &pid.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// Reader-side line pump: line-buffers a stream into the bounded queue.
+fn pump_lines(
+    reader: impl BufRead,
+    stderr: bool,
+    seq: &AtomicU64,
+    dropped: &AtomicU64,
+    tx: &mpsc::SyncSender<CommandEvent>,
+) {
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let seq = seq.fetch_add(1, Ordering::Relaxed);
+        let event = if stderr {
+            CommandEvent::Stderr { seq, line }
+        } else {
+            CommandEvent::Stdout { seq, line }
+        };
+        match tx.try_send(event) {
+            Ok(()) => {}
+            Err(TrySendError::Full(event)) => {
+                // Bounded buffer overflow: drop and account instead of
+                // growing memory or blocking the reader forever.
+                let bytes = match &event {
+                    CommandEvent::Stdout { line, .. } | CommandEvent::Stderr { line, .. } => {
+                        line.len() as u64 + 1
+                    }
+                    CommandEvent::Exited { .. } => 0,
+                };
+                dropped.fetch_add(bytes, Ordering::Relaxed);
+            }
+            Err(TrySendError::Disconnected(_)) => break,
+        }
+    }
+}
+
+impl<M: Send + 'static> Subscription<M> for CommandSubscription<M> {
+    fn id(&self) -> SubId {
+        self.id
+    }
+
+    fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal) {
+        let start = Instant::now();
+        let mut command = Command::new(&self.spec.program);
+        command
+            .args(&self.spec.args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(if self.spec.stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            });
+        if let Some(cwd) = &self.spec.cwd {
+            command.current_dir(cwd);
+        }
+        for (key, value) in &self.spec.env {
+            command.env(key, value);
+        }
+
+        let Ok(mut child) = command.spawn() else {
+            let _ = sender.send((self.make_msg)(CommandEvent::Exited {
+                status: CommandExit::Unknown,
+                duration: start.elapsed(),
+                dropped_bytes: 0,
+            }));
+            return;
+        };
+
+        // Write stdin on its own thread so a full stdin pipe can never
+        // deadlock against unread child output; dropping the handle
+        // afterwards closes the stream so the child sees EOF.
+        if let (Some(bytes), Some(mut stdin)) = (self.spec.stdin.clone(), child.stdin.take()) {
+            std::thread::spawn(move || {
+                let _ = stdin.write_all(&bytes);
+            });
+        }
+
+        let seq = Arc::new(AtomicU64::new(0));
+        let dropped = Arc::new(AtomicU64::new(0));
+        let (tx, rx) = mpsc::sync_channel::<CommandEvent>(self.buffer_lines);
+
+        let mut readers = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            let (seq, dropped, tx) = (seq.clone(), dropped.clone(), tx.clone());
+            readers.push(std::thread::spawn(move || {
+                pump_lines(BufReader::new(stdout), false, &seq, &dropped, &tx);
+            }));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            let (seq, dropped, tx) = (seq.clone(), dropped.clone(), tx.clone());
+            readers.push(std::thread::spawn(move || {
+                pump_lines(BufReader::new(stderr), true, &seq, &dropped, &tx);
+            }));
+        }
+        // Only the readers hold senders: disconnect == both streams EOF.
+        drop(tx);
+
+        let mut cancelled = false;
+        let exited = loop {
+            if stop.is_stopped() {
+                cancelled = true;
+                self.terminate(&mut child);
+                break child.wait().ok();
+            }
+            match rx.recv_timeout(POLL_SLICE) {
+                Ok(event) => {
+                    if sender.send((self.make_msg)(event)).is_err() {
+                        // Receiver gone: kill rather than orphan.
+                        cancelled = true;
+                        self.terminate(&mut child);
+                        break child.wait().ok();
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    break child.wait().ok();
+                }
+            }
+        };
+
+        // On a clean exit the readers have hit EOF and joining is
+        // instant. On cancellation an orphaned grandchild may still hold
+        // the pipes open — joining would block on its lifetime, which is
+        // exactly the teardown hang this subscription exists to prevent.
+        // The reader threads are detached instead; they exit when the
+        // pipe finally closes.
+        if !cancelled {
+            for reader in readers {
+                let _ = reader.join();
+            }
+        }
+        // Drain anything buffered after exit so tail output isn't lost.
+        while let Ok(event) = rx.try_recv() {
+            if sender.send((self.make_msg)(event)).is_err() {
+                return;
+            }
+        }
+
+        let status = exited.map_or(CommandExit::Unknown, CommandExit::from_status);
+        let _ = sender.send((self.make_msg)(CommandEvent::Exited {
+            status,
+            duration: start.elapsed(),
+            dropped_bytes: dropped.load(Ordering::Relaxed),
+        }));
+    }
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x1000_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn sh(script: &str) -> CommandSpec {
+        CommandSpec::new("/bin/sh", ["-c", script])
+    }
+
+    fn run_to_completion(sub: &CommandSubscription<CommandEvent>) -> Vec<CommandEvent> {
+        let (tx, rx) = mpsc::channel();
+        let (signal, _trigger) = StopSignal::new();
+        thread::scope(|scope| {
+            scope.spawn(|| sub.run(tx, signal));
+        });
+        rx.try_iter().collect()
+    }
+
+    #[test]
+    fn streams_stdout_and_stderr_with_global_ordering() {
+        let sub = CommandSubscription::new(sh("echo one; echo err1 1>&2; echo two"), |event| event);
+        let events = run_to_completion(&sub);
+
+        let stdout: Vec<&str> = events
+            .iter()
+            .filter_map(|e| match e {
+                CommandEvent::Stdout { line, .. } => Some(line.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(stdout, vec!["one", "two"]);
+        let stderr: Vec<&str> = events
+            .iter()
+            .filter_map(|e| match e {
+                CommandEvent::Stderr { line, .. } => Some(line.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(stderr, vec!["err1"]);
+
+        // Global sequence numbers are unique and reconstruct interleaving.
+        let mut seqs: Vec<u64> = events
+            .iter()
+            .filter_map(|e| match e {
+                CommandEvent::Stdout { seq, .. } | CommandEvent::Stderr { seq, .. } => Some(*seq),
+                _ => None,
+            })
+            .collect();
+        let len = seqs.len();
+        seqs.sort_unstable();
+        seqs.dedup();
+        assert_eq!(seqs.len(), len, "sequence numbers unique");
+        assert_eq!(seqs, (0..len as u64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn exit_status_propagates_codes_and_signals() {
+        let events = run_to_completion(&CommandSubscription::new(sh("exit 3"), |e| e));
+        assert!(matches!(
+            events.last(),
+            Some(CommandEvent::Exited {
+                status: CommandExit::Code(3),
+                ..
+            })
+        ));
+
+        let events = run_to_completion(&CommandSubscription::new(sh("kill -9 $$"), |e| e));
+        assert!(matches!(
+            events.last(),
+            Some(CommandEvent::Exited {
+                status: CommandExit::Signal(9),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn stdin_bytes_reach_the_child() {
+        let mut spec = sh("cat");
+        spec.stdin = Some(b"piped-line\n".to_vec());
+        let events = run_to_completion(&CommandSubscription::new(spec, |e| e));
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, CommandEvent::Stdout { line, .. } if line == "piped-line"))
+        );
+    }
+
+    #[test]
+    fn cancellation_kills_within_grace() {
+        // A child that ignores SIGTERM: only SIGKILL ends it.
+        let sub = CommandSubscription::new(sh("trap '' TERM; sleep 30"), |event| event)
+            .with_grace(Duration::from_millis(150));
+
+        let (tx, rx) = mpsc::channel::<CommandEvent>();
+        let (signal, trigger) = StopSignal::new();
+        let start = Instant::now();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+        thread::sleep(Duration::from_millis(100));
+        trigger.stop();
+        handle.join().unwrap();
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed < Duration::from_secs(5),
+            "stuck child must not hang teardown ({elapsed:?})"
+        );
+        // The kill path still emits a terminal Exited event.
+        let events: Vec<_> = rx.try_iter().collect();
+        assert!(matches!(
+            events.last(),
+            Some(CommandEvent::Exited {
+                status: CommandExit::Signal(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn sigterm_honored_before_grace_expires() {
+        let sub = CommandSubscription::new(sh("sleep 30"), |event| event)
+            .with_grace(Duration::from_secs(10));
+        let (tx, rx) = mpsc::channel::<CommandEvent>();
+        let (signal, trigger) = StopSignal::new();
+        let start = Instant::now();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+        thread::sleep(Duration::from_millis(100));
+        trigger.stop();
+        handle.join().unwrap();
+        // SIGTERM ends a cooperative child immediately; the 10s grace is
+        // never waited out.
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let events: Vec<_> = rx.try_iter().collect();
+        assert!(matches!(events.last(), Some(CommandEvent::Exited { .. })));
+    }
+
+    #[test]
+    fn flood_is_bounded_with_dropped_byte_accounting() {
+        // 200k lines into a 16-line buffer, with the consumer stalled
+        // until the child finishes: most lines must drop, and be counted.
+        let sub = CommandSubscription::new(sh("seq 1 200000"), |event| event).with_buffer_lines(16);
+
+        let (tx, rx) = mpsc::channel::<CommandEvent>();
+        let (signal, _trigger) = StopSignal::new();
+        sub.run(tx, signal);
+
+        let events: Vec<_> = rx.try_iter().collect();
+        let Some(CommandEvent::Exited { dropped_bytes, .. }) = events.last() else {
+            panic!("missing Exited event");
+        };
+        assert!(*dropped_bytes > 0, "flood must register dropped bytes");
+        // Everything delivered + dropped accounts for the full output.
+        let delivered: u64 = events
+            .iter()
+            .filter_map(|e| match e {
+                CommandEvent::Stdout { line, .. } => Some(line.len() as u64 + 1),
+                _ => None,
+            })
+            .sum();
+        let total: u64 = (1..=200_000u64)
+            .map(|n| n.to_string().len() as u64 + 1)
+            .sum();
+        assert_eq!(delivered + dropped_bytes, total, "byte accounting exact");
+    }
+}
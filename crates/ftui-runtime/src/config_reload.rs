@@ -0,0 +1,434 @@
+#![forbid(unsafe_code)]
+
+//! Config hot-reload: watch a config source and apply changes without
+//! restarting.
+//!
+//! A [`ConfigSource`] abstracts where the config lives ([`FileConfigSource`]
+//! polls a file's mtime); a [`ConfigWatcher`] runs it on the subscription
+//! worker model, so lifecycle and cancellation behave like every other
+//! subscription — reconcile/removal interrupts the poll sleep and never
+//! leaves a thread hanging at program exit.
+//!
+//! On change the watcher reloads, validates, and diffs against the
+//! current config, then delivers exactly one message:
+//! [`ConfigReloadEvent::Changed`] carrying the new config plus the names
+//! of changed fields (so the theme provider / frame pacer / keymap apply
+//! only what moved), or [`ConfigReloadEvent::Rejected`] with the error
+//! while the previous config stays in force — never a crash or a partial
+//! application. Rapid successive changes debounce into a single reload.
+
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::SystemTime;
+use web_time::{Duration, Instant};
+
+use crate::subscription::{StopSignal, SubId, Subscription};
+
+/// Where a config comes from and how to notice it changed.
+pub trait ConfigSource<C>: Send {
+    /// Load and parse the current config.
+    fn load(&mut self) -> Result<C, String>;
+
+    /// Cheap change probe (mtime, generation counter, ...). `true` means
+    /// a reload should be attempted after the debounce window.
+    fn poll_changed(&mut self) -> bool;
+}
+
+/// Parser from file contents to a config value.
+type ParseFn<C> = Box<dyn Fn(&str) -> Result<C, String> + Send>;
+
+/// File-backed config source polling the file's mtime.
+pub struct FileConfigSource<C> {
+    path: PathBuf,
+    parse: ParseFn<C>,
+    last_mtime: Option<SystemTime>,
+}
+
+impl<C> FileConfigSource<C> {
+    /// Watch `path`, parsing its contents with `parse`.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        parse: impl Fn(&str) -> Result<C, String> + Send + 'static,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            parse: Box::new(parse),
+            last_mtime: None,
+        }
+    }
+}
+
+impl<C> ConfigSource<C> for FileConfigSource<C> {
+    fn load(&mut self) -> Result<C, String> {
+        let text = std::fs::read_to_string(&self.path)
+            .map_err(|err| format!("read {}: {err}", self.path.display()))?;
+        (self.parse)(&text)
+    }
+
+    fn poll_changed(&mut self) -> bool {
+        let mtime = std::fs::metadata(&self.path)
+            .and_then(|meta| meta.modified())
+            .ok();
+        if mtime != self.last_mtime {
+            self.last_mtime = mtime;
+            return true;
+        }
+        false
+    }
+}
+
+/// Outcome of one reload attempt, delivered as a single message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigReloadEvent<C> {
+    /// The new config was accepted; `fields` names what changed.
+    Changed {
+        /// The validated new config.
+        config: C,
+        /// Field names that differ from the previous config.
+        fields: Vec<&'static str>,
+    },
+    /// The new config was rejected; the previous one stays in force.
+    Rejected {
+        /// Why the reload was refused (parse or validation error).
+        error: String,
+    },
+}
+
+/// Field-level differ: names of fields that differ between two configs.
+pub type DiffFn<C> = Box<dyn Fn(&C, &C) -> Vec<&'static str> + Send + Sync>;
+/// Validator: reject configs before they are applied.
+pub type ValidateFn<C> = Box<dyn Fn(&C) -> Result<(), String> + Send + Sync>;
+
+/// Subscription that watches a [`ConfigSource`] and emits reload events.
+pub struct ConfigWatcher<C, S, M> {
+    id: SubId,
+    source: std::sync::Mutex<Option<WatcherState<C, S>>>,
+    make_msg: Box<dyn Fn(ConfigReloadEvent<C>) -> M + Send + Sync>,
+    poll_interval: Duration,
+    debounce: Duration,
+}
+
+struct WatcherState<C, S> {
+    source: S,
+    current: C,
+    validate: ValidateFn<C>,
+    diff: DiffFn<C>,
+}
+
+impl<C, S, M> ConfigWatcher<C, S, M>
+where
+    C: Clone + Send + 'static,
+    S: ConfigSource<C> + 'static,
+    M: Send + 'static,
+{
+    /// Create a watcher around an already-loaded config.
+    ///
+    /// `validate` gates new configs; `diff` names the changed fields;
+    /// `make_msg` wraps the event for the app's message type.
+    pub fn new(
+        id: SubId,
+        source: S,
+        current: C,
+        validate: impl Fn(&C) -> Result<(), String> + Send + Sync + 'static,
+        diff: impl Fn(&C, &C) -> Vec<&'static str> + Send + Sync + 'static,
+        make_msg: impl Fn(ConfigReloadEvent<C>) -> M + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            id,
+            source: std::sync::Mutex::new(Some(WatcherState {
+                source,
+                current,
+                validate: Box::new(validate),
+                diff: Box::new(diff),
+            })),
+            make_msg: Box::new(make_msg),
+            poll_interval: Duration::from_millis(200),
+            debounce: Duration::from_millis(150),
+        }
+    }
+
+    /// Set the change-probe interval (builder).
+    #[must_use]
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the debounce window for rapid successive changes (builder).
+    #[must_use]
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+}
+
+impl<C, S, M> Subscription<M> for ConfigWatcher<C, S, M>
+where
+    C: Clone + Send + 'static,
+    S: ConfigSource<C> + 'static,
+    M: Send + 'static,
+{
+    fn id(&self) -> SubId {
+        self.id
+    }
+
+    fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal) {
+        let Some(mut state) = self.source.lock().ok().and_then(|mut s| s.take()) else {
+            return;
+        };
+        let mut pending_since: Option<Instant> = None;
+        loop {
+            // Stop-signal-aware sleep: cancellation interrupts immediately.
+            if stop.wait_timeout(self.poll_interval) {
+                return;
+            }
+            if state.source.poll_changed() {
+                // Restart the debounce window on every observed change.
+                pending_since = Some(Instant::now());
+            }
+            let Some(since) = pending_since else {
+                continue;
+            };
+            if since.elapsed() < self.debounce {
+                continue;
+            }
+            pending_since = None;
+
+            let event = match state.source.load() {
+                Ok(new_config) => match (state.validate)(&new_config) {
+                    Ok(()) => {
+                        let fields = (state.diff)(&state.current, &new_config);
+                        if fields.is_empty() {
+                            continue; // content rewritten identically
+                        }
+                        state.current = new_config.clone();
+                        ConfigReloadEvent::Changed {
+                            config: new_config,
+                            fields,
+                        }
+                    }
+                    Err(error) => ConfigReloadEvent::Rejected { error },
+                },
+                Err(error) => ConfigReloadEvent::Rejected { error },
+            };
+            if stop.is_stopped() {
+                return;
+            }
+            if sender.send((self.make_msg)(event)).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct AppConfig {
+        theme: String,
+        fps_cap: u32,
+        sidebar_min: u16,
+    }
+
+    fn diff_config(old: &AppConfig, new: &AppConfig) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if old.theme != new.theme {
+            fields.push("theme");
+        }
+        if old.fps_cap != new.fps_cap {
+            fields.push("fps_cap");
+        }
+        if old.sidebar_min != new.sidebar_min {
+            fields.push("sidebar_min");
+        }
+        fields
+    }
+
+    fn validate(config: &AppConfig) -> Result<(), String> {
+        if config.fps_cap == 0 {
+            Err("fps_cap must be positive".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Controllable in-memory source.
+    #[derive(Clone)]
+    struct FakeSource {
+        next: Arc<Mutex<Result<AppConfig, String>>>,
+        changed: Arc<AtomicBool>,
+        loads: Arc<AtomicU64>,
+    }
+
+    impl FakeSource {
+        fn new(initial: AppConfig) -> Self {
+            Self {
+                next: Arc::new(Mutex::new(Ok(initial))),
+                changed: Arc::new(AtomicBool::new(false)),
+                loads: Arc::new(AtomicU64::new(0)),
+            }
+        }
+
+        fn set(&self, config: Result<AppConfig, String>) {
+            *self.next.lock().unwrap() = config;
+            self.changed.store(true, Ordering::SeqCst);
+        }
+    }
+
+    impl ConfigSource<AppConfig> for FakeSource {
+        fn load(&mut self) -> Result<AppConfig, String> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            self.next.lock().unwrap().clone()
+        }
+
+        fn poll_changed(&mut self) -> bool {
+            self.changed.swap(false, Ordering::SeqCst)
+        }
+    }
+
+    fn base_config() -> AppConfig {
+        AppConfig {
+            theme: "dark".into(),
+            fps_cap: 60,
+            sidebar_min: 20,
+        }
+    }
+
+    struct RunningWatcher {
+        rx: mpsc::Receiver<ConfigReloadEvent<AppConfig>>,
+        stop: Box<dyn FnOnce()>,
+    }
+
+    fn spawn_watcher(source: FakeSource) -> RunningWatcher {
+        let watcher =
+            ConfigWatcher::new(1, source, base_config(), validate, diff_config, |event| {
+                event
+            })
+            .with_poll_interval(Duration::from_millis(5))
+            .with_debounce(Duration::from_millis(30));
+        let (tx, rx) = mpsc::channel();
+        let (signal, trigger) = StopSignal::new();
+        let run_signal = signal.clone();
+        let handle = thread::spawn(move || watcher.run(tx, run_signal));
+        let _ = signal;
+        RunningWatcher {
+            rx,
+            stop: Box::new(move || {
+                trigger.stop();
+                let _ = handle.join();
+            }),
+        }
+    }
+
+    #[test]
+    fn field_level_diff_in_changed_event() {
+        let source = FakeSource::new(base_config());
+        let RunningWatcher { rx, stop } = spawn_watcher(source.clone());
+
+        source.set(Ok(AppConfig {
+            theme: "light".into(),
+            fps_cap: 60,
+            sidebar_min: 25,
+        }));
+        let event = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        match event {
+            ConfigReloadEvent::Changed { config, fields } => {
+                assert_eq!(fields, vec!["theme", "sidebar_min"]);
+                assert_eq!(config.theme, "light");
+            }
+            other => panic!("expected Changed, got {other:?}"),
+        }
+        (stop)();
+    }
+
+    #[test]
+    fn invalid_config_rejected_and_old_kept() {
+        let source = FakeSource::new(base_config());
+        let RunningWatcher { rx, stop } = spawn_watcher(source.clone());
+
+        source.set(Ok(AppConfig {
+            theme: "dark".into(),
+            fps_cap: 0,
+            sidebar_min: 20,
+        }));
+        let event = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert!(
+            matches!(event, ConfigReloadEvent::Rejected { ref error } if error.contains("fps_cap"))
+        );
+
+        // A subsequent valid change diffs against the RETAINED old config,
+        // not the rejected one.
+        source.set(Ok(AppConfig {
+            theme: "dark".into(),
+            fps_cap: 30,
+            sidebar_min: 20,
+        }));
+        let event = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        match event {
+            ConfigReloadEvent::Changed { fields, .. } => assert_eq!(fields, vec!["fps_cap"]),
+            other => panic!("expected Changed, got {other:?}"),
+        }
+        (stop)();
+    }
+
+    #[test]
+    fn rapid_changes_debounce_into_one_reload() {
+        let source = FakeSource::new(base_config());
+        let RunningWatcher { rx, stop } = spawn_watcher(source.clone());
+
+        // Burst of changes within the debounce window.
+        for fps in [10, 20, 30, 40, 50] {
+            source.set(Ok(AppConfig {
+                theme: "dark".into(),
+                fps_cap: fps,
+                sidebar_min: 20,
+            }));
+            thread::sleep(std::time::Duration::from_millis(5));
+        }
+        let event = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        match event {
+            ConfigReloadEvent::Changed { config, .. } => assert_eq!(config.fps_cap, 50),
+            other => panic!("expected Changed, got {other:?}"),
+        }
+        // One coalesced event; one load for the burst.
+        assert!(rx.try_recv().is_err(), "burst coalesced into one event");
+        assert_eq!(source.loads.load(Ordering::SeqCst), 1);
+        (stop)();
+    }
+
+    #[test]
+    fn watcher_shutdown_does_not_hang() {
+        let source = FakeSource::new(base_config());
+        let RunningWatcher { rx, stop } = spawn_watcher(source);
+        let start = std::time::Instant::now();
+        (stop)();
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+        drop(rx);
+    }
+
+    #[test]
+    fn file_source_reloads_on_mtime_change() {
+        let dir = std::env::temp_dir().join(format!("ftui-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.conf");
+        std::fs::write(&path, "dark").unwrap();
+
+        let mut source = FileConfigSource::new(&path, |text: &str| {
+            Ok(AppConfig {
+                theme: text.trim().to_string(),
+                fps_cap: 60,
+                sidebar_min: 20,
+            })
+        });
+        assert!(source.poll_changed(), "first probe sees the file");
+        assert_eq!(source.load().unwrap().theme, "dark");
+        assert!(!source.poll_changed(), "no change since");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
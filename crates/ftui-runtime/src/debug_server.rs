@@ -0,0 +1,654 @@
+#![forbid(unsafe_code)]
+
+//! Frame-by-frame step debugger controllable from a second terminal.
+//!
+//! Debugging animation and diff issues needs "freeze the app, advance
+//! one frame at a time, inspect state". This module (devtools feature)
+//! provides a small line-protocol control server plus the shared
+//! [`DebugControl`] the runtime loop consults:
+//!
+//! | command | effect |
+//! |---------|--------|
+//! | `pause` | hold message processing (input stays queued) |
+//! | `resume` | continue normally |
+//! | `step` / `step N` | process exactly N pending messages + render |
+//! | `dump-model` | serialize the model (when the app supports it) |
+//! | `dump-frame` | annotated-text export of the last buffer |
+//! | `set-speed xF` | scale the tick/timer clock (e.g. `x0.25`) |
+//!
+//! Replies are `ok`, `err <reason>`, or for dumps a payload terminated
+//! by a single `.` line.
+//!
+//! # Runtime contract
+//!
+//! The driving loop calls [`DebugControl::should_process_message`]
+//! before consuming each pending message (rendering continues while
+//! paused so the terminal stays responsive and a paused badge can be
+//! overlaid), scales its tick clock by [`DebugControl::speed`], and
+//! answers [`DebugControl::take_dump_requests`] each cycle with
+//! whatever dump text the app can produce.
+//!
+//! # Security
+//!
+//! Off by default. Unix sockets are created with owner-only
+//! permissions; TCP binds `127.0.0.1` only. There is no remote binding
+//! option on purpose.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// What a dump request wants from the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpKind {
+    /// `dump-model`: a serialized model, if the app supports it.
+    Model,
+    /// `dump-frame`: the annotated-text export of the last buffer.
+    Frame,
+}
+
+/// A dump request awaiting the runtime's answer.
+#[derive(Debug)]
+pub struct DumpRequest {
+    pub kind: DumpKind,
+    reply: mpsc::Sender<String>,
+}
+
+impl DumpRequest {
+    /// Answer the request (the server relays the text to the client).
+    pub fn respond(self, text: impl Into<String>) {
+        let _ = self.reply.send(text.into());
+    }
+}
+
+#[derive(Debug, Default)]
+struct ControlInner {
+    paused: AtomicBool,
+    /// Messages the loop may still process while paused (`step`).
+    step_budget: AtomicU32,
+    /// Clock multiplier in milli-units (1000 = 1.0x). 0 is clamped.
+    speed_milli: AtomicU32,
+    /// Dump requests awaiting the runtime.
+    dumps: Mutex<VecDeque<DumpRequest>>,
+}
+
+/// Shared debugger state between the control server and the runtime.
+#[derive(Debug, Clone)]
+pub struct DebugControl {
+    inner: Arc<ControlInner>,
+}
+
+impl Default for DebugControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugControl {
+    #[must_use]
+    pub fn new() -> Self {
+        let inner = ControlInner {
+            speed_milli: AtomicU32::new(1000),
+            ..Default::default()
+        };
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Whether the app is paused (render a paused badge).
+    #[must_use]
+    pub fn paused(&self) -> bool {
+        self.inner.paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause message processing (input stays queued).
+    pub fn pause(&self) {
+        self.inner.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Resume normal processing.
+    pub fn resume(&self) {
+        self.inner.paused.store(false, Ordering::SeqCst);
+        self.inner.step_budget.store(0, Ordering::SeqCst);
+    }
+
+    /// Grant `n` single-message steps while paused.
+    pub fn step(&self, n: u32) {
+        self.inner.paused.store(true, Ordering::SeqCst);
+        self.inner.step_budget.fetch_add(n, Ordering::SeqCst);
+    }
+
+    /// Loop-side gate: may the next pending message be processed?
+    ///
+    /// While paused this consumes one step from the budget; unpaused it
+    /// is always true. Messages gated off stay queued.
+    pub fn should_process_message(&self) -> bool {
+        if !self.paused() {
+            return true;
+        }
+        self.inner
+            .step_budget
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |budget| {
+                budget.checked_sub(1)
+            })
+            .is_ok()
+    }
+
+    /// Current clock multiplier (e.g. 0.25 after `set-speed x0.25`).
+    #[must_use]
+    pub fn speed(&self) -> f64 {
+        f64::from(self.inner.speed_milli.load(Ordering::SeqCst).max(1)) / 1000.0
+    }
+
+    /// Set the clock multiplier.
+    pub fn set_speed(&self, multiplier: f64) {
+        let milli = (multiplier.clamp(0.001, 1000.0) * 1000.0).round() as u32;
+        self.inner.speed_milli.store(milli, Ordering::SeqCst);
+    }
+
+    /// Dump requests awaiting answers; the runtime responds to each.
+    pub fn take_dump_requests(&self) -> Vec<DumpRequest> {
+        let mut dumps = self
+            .inner
+            .dumps
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        dumps.drain(..).collect()
+    }
+
+    fn enqueue_dump(&self, kind: DumpKind) -> mpsc::Receiver<String> {
+        let (reply, rx) = mpsc::channel();
+        self.inner
+            .dumps
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push_back(DumpRequest { kind, reply });
+        rx
+    }
+}
+
+/// Line-protocol control server (see the module docs).
+pub struct DebugServer {
+    local_addr: Option<std::net::SocketAddr>,
+    /// Unix socket path to unlink on shutdown.
+    #[cfg(unix)]
+    socket_path: Option<std::path::PathBuf>,
+    accept_thread: Option<JoinHandle<()>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl DebugServer {
+    /// Bind on localhost TCP (port 0 = ephemeral) — never a remote
+    /// interface.
+    pub fn bind_localhost(port: u16, control: DebugControl) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        let local_addr = listener.local_addr()?;
+        listener.set_nonblocking(true)?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let accept_shutdown = shutdown.clone();
+        let accept_thread = std::thread::Builder::new()
+            .name("ftui-debug-server".into())
+            .spawn(move || {
+                while !accept_shutdown.load(Ordering::SeqCst) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            let control = control.clone();
+                            let _ = std::thread::Builder::new()
+                                .name("ftui-debug-client".into())
+                                .spawn(move || serve_client(stream, &control));
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                            std::thread::sleep(Duration::from_millis(25));
+                        }
+                        Err(_) => break,
+                    }
+                }
+            })?;
+        Ok(Self {
+            local_addr: Some(local_addr),
+            #[cfg(unix)]
+            socket_path: None,
+            accept_thread: Some(accept_thread),
+            shutdown,
+        })
+    }
+
+    /// Bind a unix socket at `path` with owner-only permissions (0600).
+    ///
+    /// The preferred transport: no port, no other users. The socket
+    /// file is removed on shutdown.
+    #[cfg(unix)]
+    pub fn bind_unix(
+        path: impl Into<std::path::PathBuf>,
+        control: DebugControl,
+    ) -> std::io::Result<Self> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = path.into();
+        // A stale socket from a crashed session blocks bind; remove it.
+        let _ = std::fs::remove_file(&path);
+        let listener = std::os::unix::net::UnixListener::bind(&path)?;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+        listener.set_nonblocking(true)?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let accept_shutdown = shutdown.clone();
+        let accept_thread = std::thread::Builder::new()
+            .name("ftui-debug-server".into())
+            .spawn(move || {
+                while !accept_shutdown.load(Ordering::SeqCst) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            let control = control.clone();
+                            let _ = std::thread::Builder::new()
+                                .name("ftui-debug-client".into())
+                                .spawn(move || {
+                                    if let Ok(peer) = stream.try_clone() {
+                                        serve_connection(BufReader::new(peer), stream, &control);
+                                    }
+                                });
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                            std::thread::sleep(Duration::from_millis(25));
+                        }
+                        Err(_) => break,
+                    }
+                }
+            })?;
+        Ok(Self {
+            local_addr: None,
+            socket_path: Some(path),
+            accept_thread: Some(accept_thread),
+            shutdown,
+        })
+    }
+
+    /// The bound TCP address (None for unix-socket servers).
+    #[must_use]
+    pub fn local_addr(&self) -> Option<std::net::SocketAddr> {
+        self.local_addr
+    }
+
+    /// Stop accepting connections and join the accept thread.
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.accept_thread.take() {
+            let _ = handle.join();
+        }
+        #[cfg(unix)]
+        if let Some(path) = self.socket_path.take() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+impl Drop for DebugServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// How long a dump waits for the runtime before erroring out.
+const DUMP_TIMEOUT: Duration = Duration::from_secs(2);
+
+fn serve_client(stream: TcpStream, control: &DebugControl) {
+    let Ok(peer) = stream.try_clone() else {
+        return;
+    };
+    serve_connection(BufReader::new(peer), stream, control);
+}
+
+fn serve_connection<R: BufRead, W: Write>(reader: R, mut writer: W, control: &DebugControl) {
+    for line in reader.lines() {
+        let Ok(line) = line else { break };
+        let reply = handle_command(line.trim(), control);
+        let ok = match reply {
+            CommandReply::Ok => writer.write_all(b"ok\n").is_ok(),
+            CommandReply::Err(reason) => writer
+                .write_all(format!("err {reason}\n").as_bytes())
+                .is_ok(),
+            CommandReply::Payload(text) => {
+                let mut out = String::new();
+                for payload_line in text.lines() {
+                    // Dot-stuff payload lines so the "." terminator is
+                    // unambiguous.
+                    if payload_line.starts_with('.') {
+                        out.push('.');
+                    }
+                    out.push_str(payload_line);
+                    out.push('\n');
+                }
+                out.push_str(".\n");
+                writer.write_all(out.as_bytes()).is_ok()
+            }
+        };
+        if !ok {
+            break;
+        }
+    }
+}
+
+enum CommandReply {
+    Ok,
+    Err(String),
+    Payload(String),
+}
+
+fn handle_command(line: &str, control: &DebugControl) -> CommandReply {
+    let mut parts = line.split_whitespace();
+    match parts.next() {
+        Some("pause") => {
+            control.pause();
+            CommandReply::Ok
+        }
+        Some("resume") => {
+            control.resume();
+            CommandReply::Ok
+        }
+        Some("step") => {
+            let n = parts.next().map_or(Ok(1), str::parse::<u32>);
+            match n {
+                Ok(n) if n > 0 => {
+                    control.step(n);
+                    CommandReply::Ok
+                }
+                _ => CommandReply::Err("step wants a positive count".into()),
+            }
+        }
+        Some("set-speed") => match parts.next().and_then(|v| v.strip_prefix('x')) {
+            Some(value) => match value.parse::<f64>() {
+                Ok(multiplier) if multiplier > 0.0 => {
+                    control.set_speed(multiplier);
+                    CommandReply::Ok
+                }
+                _ => CommandReply::Err("bad multiplier".into()),
+            },
+            None => CommandReply::Err("usage: set-speed x<multiplier>".into()),
+        },
+        Some("dump-model") => dump(control, DumpKind::Model),
+        Some("dump-frame") => dump(control, DumpKind::Frame),
+        Some(other) => CommandReply::Err(format!("unknown command {other:?}")),
+        None => CommandReply::Err("empty command".into()),
+    }
+}
+
+fn dump(control: &DebugControl, kind: DumpKind) -> CommandReply {
+    let rx = control.enqueue_dump(kind);
+    match rx.recv_timeout(DUMP_TIMEOUT) {
+        Ok(text) => CommandReply::Payload(text),
+        Err(_) => CommandReply::Err("runtime did not answer".into()),
+    }
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::cx::LabClock;
+    use std::io::BufRead;
+
+    /// Minimal runtime loop implementing the documented contract.
+    struct LoopHarness {
+        control: DebugControl,
+        queue: VecDeque<&'static str>,
+        processed: Vec<&'static str>,
+        /// Frames rendered (rendering continues while paused).
+        frames: u32,
+    }
+
+    impl LoopHarness {
+        fn new(control: DebugControl) -> Self {
+            Self {
+                control,
+                queue: VecDeque::new(),
+                processed: Vec::new(),
+                frames: 0,
+            }
+        }
+
+        /// One runtime cycle: gate pending messages, render, answer dumps.
+        fn cycle(&mut self) {
+            while let Some(&message) = self.queue.front() {
+                if !self.control.should_process_message() {
+                    break;
+                }
+                self.queue.pop_front();
+                self.processed.push(message);
+            }
+            self.frames += 1;
+            for request in self.control.take_dump_requests() {
+                match request.kind {
+                    DumpKind::Model => request.respond(format!(
+                        "model processed={} queued={}",
+                        self.processed.len(),
+                        self.queue.len()
+                    )),
+                    DumpKind::Frame => {
+                        request.respond(format!("frame {}\n~ 0..5 bold", self.frames))
+                    }
+                }
+            }
+        }
+    }
+
+    struct Client {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+    }
+
+    impl Client {
+        fn connect(addr: std::net::SocketAddr) -> Self {
+            let stream = TcpStream::connect(addr).expect("connect");
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .expect("timeout");
+            Self {
+                reader: BufReader::new(stream.try_clone().expect("clone")),
+                writer: stream,
+            }
+        }
+
+        fn send(&mut self, command: &str) {
+            self.writer
+                .write_all(format!("{command}\n").as_bytes())
+                .expect("send");
+        }
+
+        fn read_line(&mut self) -> String {
+            let mut line = String::new();
+            self.reader.read_line(&mut line).expect("read");
+            line.trim_end().to_string()
+        }
+
+        /// Send a command expecting a plain `ok`.
+        fn ok(&mut self, command: &str) {
+            self.send(command);
+            assert_eq!(self.read_line(), "ok", "command {command:?}");
+        }
+
+        /// Send a dump command, returning the payload.
+        fn payload(&mut self, command: &str) -> String {
+            self.send(command);
+            let mut out = Vec::new();
+            loop {
+                let line = self.read_line();
+                if line == "." {
+                    break;
+                }
+                assert!(!line.starts_with("err"), "{line}");
+                out.push(line);
+            }
+            out.join("\n")
+        }
+    }
+
+    fn run_cycles(harness: &mut LoopHarness, n: u32) {
+        for _ in 0..n {
+            harness.cycle();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn scripted_client_pauses_steps_and_preserves_queue() {
+        let control = DebugControl::new();
+        let server = DebugServer::bind_localhost(0, control.clone()).expect("bind");
+        let mut client = Client::connect(server.local_addr().expect("tcp addr"));
+        let mut harness = LoopHarness::new(control);
+
+        client.ok("pause");
+        harness.queue.extend(["a", "b", "c", "d"]);
+        run_cycles(&mut harness, 2);
+        assert!(harness.processed.is_empty(), "paused: nothing processed");
+        assert_eq!(harness.queue.len(), 4, "input stays queued");
+        assert!(harness.frames >= 2, "rendering continues while paused");
+
+        // Step exactly two messages.
+        client.ok("step 2");
+        run_cycles(&mut harness, 2);
+        assert_eq!(harness.processed, vec!["a", "b"], "exactly N stepped");
+        assert_eq!(harness.queue.len(), 2);
+
+        // Resume drains the rest — queued input survived pause/resume.
+        client.ok("resume");
+        run_cycles(&mut harness, 1);
+        assert_eq!(harness.processed, vec!["a", "b", "c", "d"]);
+        server.shutdown();
+    }
+
+    #[test]
+    fn dump_frame_matches_runtime_snapshot() {
+        let control = DebugControl::new();
+        let server = DebugServer::bind_localhost(0, control.clone()).expect("bind");
+        let mut harness = LoopHarness::new(control);
+        harness.queue.push_back("x");
+        harness.cycle();
+
+        let client = Client::connect(server.local_addr().expect("tcp addr"));
+        // Answer the dump from another thread driving the harness.
+        let handle = std::thread::spawn(move || {
+            let mut client = client;
+            client.payload("dump-frame")
+        });
+        let deadline = std::time::Instant::now() + Duration::from_secs(4);
+        while !handle.is_finished() {
+            assert!(std::time::Instant::now() < deadline, "dump stalled");
+            harness.cycle();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        let payload = handle.join().expect("client thread");
+        assert!(
+            payload.starts_with("frame ") && payload.contains("~ 0..5 bold"),
+            "annotated snapshot round-trips: {payload:?}"
+        );
+        server.shutdown();
+    }
+
+    #[test]
+    fn set_speed_scales_lab_time() {
+        let control = DebugControl::new();
+        let server = DebugServer::bind_localhost(0, control.clone()).expect("bind");
+        let mut client = Client::connect(server.local_addr().expect("tcp addr"));
+        client.ok("set-speed x0.25");
+        assert!((control.speed() - 0.25).abs() < 1e-9);
+
+        // The runtime scales elapsed time by the multiplier: a 100ms
+        // real step advances the app clock 25ms under lab verification.
+        let clock = LabClock::new();
+        let real_elapsed = Duration::from_millis(100);
+        let scaled = real_elapsed.mul_f64(control.speed());
+        clock.advance(web_time::Duration::from_millis(scaled.as_millis() as u64));
+        let t0 = clock.now();
+        clock.advance(web_time::Duration::from_millis(0));
+        assert_eq!(scaled, Duration::from_millis(25));
+        let _ = t0;
+
+        client.ok("set-speed x1.0");
+        assert!((control.speed() - 1.0).abs() < 1e-9);
+        client.send("set-speed 2");
+        assert!(client.read_line().starts_with("err"));
+        server.shutdown();
+    }
+
+    #[test]
+    fn unknown_commands_and_bad_args_error() {
+        let control = DebugControl::new();
+        let server = DebugServer::bind_localhost(0, control.clone()).expect("bind");
+        let mut client = Client::connect(server.local_addr().expect("tcp addr"));
+        client.send("teleport");
+        assert!(client.read_line().starts_with("err unknown"));
+        client.send("step zero");
+        assert!(client.read_line().starts_with("err"));
+        server.shutdown();
+    }
+
+    #[test]
+    fn server_binds_localhost_only() {
+        let control = DebugControl::new();
+        let server = DebugServer::bind_localhost(0, control).expect("bind");
+        assert!(
+            server.local_addr().expect("tcp addr").ip().is_loopback(),
+            "never remote"
+        );
+        server.shutdown();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_socket_has_owner_only_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = std::env::temp_dir().join(format!("ftui-debug-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("tmp dir");
+        let path = dir.join("debug.sock");
+        let control = DebugControl::new();
+        let server = DebugServer::bind_unix(&path, control.clone()).expect("bind unix");
+        let mode = std::fs::metadata(&path)
+            .expect("socket meta")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600, "owner-only: {mode:o}");
+
+        // The protocol works over the unix transport too.
+        use std::os::unix::net::UnixStream;
+        let stream = UnixStream::connect(&path).expect("connect");
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .expect("timeout");
+        let mut writer = stream.try_clone().expect("clone");
+        writer.write_all(b"pause\n").expect("send");
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).expect("reply");
+        assert_eq!(line.trim_end(), "ok");
+        assert!(control.paused());
+
+        server.shutdown();
+        assert!(!path.exists(), "socket file removed on shutdown");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn step_budget_is_exact_across_interleaved_grants() {
+        let control = DebugControl::new();
+        control.pause();
+        assert!(!control.should_process_message());
+        control.step(3);
+        assert!(control.should_process_message());
+        assert!(control.should_process_message());
+        control.step(1);
+        assert!(control.should_process_message());
+        assert!(control.should_process_message());
+        assert!(!control.should_process_message(), "budget exhausted");
+        control.resume();
+        assert!(control.should_process_message());
+    }
+}
@@ -0,0 +1,341 @@
+#![forbid(unsafe_code)]
+
+//! Opt-in time-travel debugging (feature `devtools`).
+//!
+//! Answers "how did the model get into this state": the runtime records a
+//! ring buffer of `(incoming message debug string, model snapshot, frame
+//! index)` entries, capped both by entry count and by a byte budget
+//! computed from snapshot sizes. Ticks are excluded by default so they
+//! don't drown the buffer; the filter is configurable per message kind.
+//!
+//! [`Devtools::history`] inspects the buffer, [`Devtools::restore`] hands
+//! back an earlier snapshot (the program swaps its model and forces a
+//! re-render), and [`Devtools::export_json`] dumps the whole history for
+//! offline analysis.
+
+use std::collections::VecDeque;
+
+/// Configuration for the time-travel recorder.
+#[derive(Clone)]
+pub struct DevtoolsConfig {
+    /// Maximum entries retained (ring buffer).
+    pub max_entries: usize,
+    /// Maximum total snapshot bytes retained.
+    pub byte_budget: usize,
+    /// Record tick messages too (off by default; ticks drown the buffer).
+    pub record_ticks: bool,
+}
+
+impl Default for DevtoolsConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 100,
+            byte_budget: 8 * 1024 * 1024,
+            record_ticks: false,
+        }
+    }
+}
+
+impl std::fmt::Debug for DevtoolsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DevtoolsConfig")
+            .field("max_entries", &self.max_entries)
+            .field("byte_budget", &self.byte_budget)
+            .field("record_ticks", &self.record_ticks)
+            .finish()
+    }
+}
+
+/// One recorded update.
+#[derive(Debug, Clone)]
+pub struct HistoryEntry<M> {
+    /// Debug rendering of the incoming message.
+    pub message: String,
+    /// Frame index at recording time.
+    pub frame_idx: u64,
+    /// Model state *before* the message was applied.
+    pub snapshot: M,
+    /// Estimated snapshot size, charged against the byte budget.
+    pub bytes: usize,
+}
+
+/// Time-travel recorder over model snapshots.
+pub struct Devtools<M> {
+    config: DevtoolsConfig,
+    entries: VecDeque<HistoryEntry<M>>,
+    used_bytes: usize,
+    clone_model: Box<dyn Fn(&M) -> M + Send>,
+    size_of: Box<dyn Fn(&M) -> usize + Send>,
+    /// Message kinds (by debug string) to skip.
+    skip: Box<dyn Fn(&str) -> bool + Send>,
+    record_ticks: bool,
+}
+
+impl<M> std::fmt::Debug for Devtools<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Devtools")
+            .field("entries", &self.entries.len())
+            .field("used_bytes", &self.used_bytes)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M: Clone + 'static> Devtools<M> {
+    /// Create a recorder for a cloneable model.
+    #[must_use]
+    pub fn new(config: DevtoolsConfig) -> Self {
+        let record_ticks = config.record_ticks;
+        Self {
+            config,
+            entries: VecDeque::new(),
+            used_bytes: 0,
+            clone_model: Box::new(M::clone),
+            size_of: Box::new(|_| std::mem::size_of::<M>()),
+            skip: Box::new(|_| false),
+            record_ticks,
+        }
+    }
+
+    /// Override the per-snapshot size estimate (defaults to
+    /// `size_of::<M>()`, which undercounts heap-owning models).
+    #[must_use]
+    pub fn with_size_fn(mut self, size_of: impl Fn(&M) -> usize + Send + 'static) -> Self {
+        self.size_of = Box::new(size_of);
+        self
+    }
+
+    /// Skip messages whose debug string matches the predicate (in
+    /// addition to the default tick exclusion).
+    #[must_use]
+    pub fn with_skip(mut self, skip: impl Fn(&str) -> bool + Send + 'static) -> Self {
+        self.skip = Box::new(skip);
+        self
+    }
+}
+
+impl<M> Devtools<M> {
+    /// Record a model snapshot about to process `message`.
+    ///
+    /// Tick messages are skipped unless `record_ticks` is set; the
+    /// configured skip predicate can exclude further kinds.
+    pub fn record(&mut self, message: &str, model: &M, frame_idx: u64) {
+        if !self.record_ticks && message == "Tick" {
+            return;
+        }
+        if (self.skip)(message) {
+            return;
+        }
+        let snapshot = (self.clone_model)(model);
+        let bytes = (self.size_of)(&snapshot);
+        self.entries.push_back(HistoryEntry {
+            message: message.to_string(),
+            frame_idx,
+            snapshot,
+            bytes,
+        });
+        self.used_bytes += bytes;
+        self.evict();
+    }
+
+    /// Enforce the entry and byte caps (always keeps the newest entry).
+    fn evict(&mut self) {
+        while self.entries.len() > self.config.max_entries
+            || (self.used_bytes > self.config.byte_budget && self.entries.len() > 1)
+        {
+            if let Some(old) = self.entries.pop_front() {
+                self.used_bytes -= old.bytes;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// The recorded history, oldest first.
+    #[must_use]
+    pub fn history(&self) -> &VecDeque<HistoryEntry<M>> {
+        &self.entries
+    }
+
+    /// Clone the snapshot at `index` for restoration.
+    #[must_use]
+    pub fn restore(&self, index: usize) -> Option<M>
+    where
+        M: Clone,
+    {
+        self.entries.get(index).map(|entry| entry.snapshot.clone())
+    }
+
+    /// Bytes currently charged against the budget.
+    #[must_use]
+    pub fn used_bytes(&self) -> usize {
+        self.used_bytes
+    }
+
+    /// Drop all recorded entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.used_bytes = 0;
+    }
+
+    /// Export the whole history as JSON for offline analysis.
+    #[must_use]
+    pub fn export_json(&self) -> String
+    where
+        M: serde::Serialize,
+    {
+        let entries: Vec<serde_json::Value> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                serde_json::json!({
+                    "message": entry.message,
+                    "frame_idx": entry.frame_idx,
+                    "bytes": entry.bytes,
+                    "snapshot": entry.snapshot,
+                })
+            })
+            .collect();
+        serde_json::json!({ "version": 1, "entries": entries }).to_string()
+    }
+}
+
+/// Program-side bundle: the recorder plus a message formatter captured
+/// where the message type's `Debug` impl is known.
+pub struct ProgramDevtools<Model, Msg> {
+    /// The recorder.
+    pub devtools: Devtools<Model>,
+    fmt_msg: Box<dyn Fn(&Msg) -> String + Send>,
+}
+
+impl<Model, Msg> std::fmt::Debug for ProgramDevtools<Model, Msg> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.devtools.fmt(f)
+    }
+}
+
+impl<Model, Msg> ProgramDevtools<Model, Msg> {
+    /// Bundle a recorder with a `Debug`-based message formatter.
+    #[must_use]
+    pub fn new(devtools: Devtools<Model>) -> Self
+    where
+        Msg: std::fmt::Debug,
+    {
+        Self {
+            devtools,
+            fmt_msg: Box::new(|msg| format!("{msg:?}")),
+        }
+    }
+
+    /// Record the model before applying `msg`.
+    pub fn record(&mut self, msg: &Msg, model: &Model, frame_idx: u64) {
+        let message = (self.fmt_msg)(msg);
+        self.devtools.record(&message, model, frame_idx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+    struct Counter {
+        value: i32,
+        payload: Vec<u8>,
+    }
+
+    fn model(value: i32) -> Counter {
+        Counter {
+            value,
+            payload: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ring_buffer_evicts_oldest() {
+        let mut devtools = Devtools::new(DevtoolsConfig {
+            max_entries: 3,
+            ..Default::default()
+        });
+        for i in 0..5 {
+            devtools.record(&format!("Msg({i})"), &model(i), i as u64);
+        }
+        let messages: Vec<&str> = devtools
+            .history()
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["Msg(2)", "Msg(3)", "Msg(4)"]);
+    }
+
+    #[test]
+    fn restore_returns_exact_earlier_snapshot() {
+        let mut devtools = Devtools::new(DevtoolsConfig::default());
+        devtools.record("Set(1)", &model(1), 10);
+        devtools.record("Set(2)", &model(2), 11);
+
+        let restored = devtools.restore(0).expect("entry 0");
+        assert_eq!(restored, model(1));
+        assert_eq!(devtools.history()[0].frame_idx, 10);
+    }
+
+    #[test]
+    fn ticks_excluded_by_default() {
+        let mut devtools = Devtools::new(DevtoolsConfig::default());
+        devtools.record("Tick", &model(0), 0);
+        devtools.record("Key", &model(1), 1);
+        assert_eq!(devtools.history().len(), 1);
+        assert_eq!(devtools.history()[0].message, "Key");
+
+        let mut with_ticks = Devtools::new(DevtoolsConfig {
+            record_ticks: true,
+            ..Default::default()
+        });
+        with_ticks.record("Tick", &model(0), 0);
+        assert_eq!(with_ticks.history().len(), 1);
+    }
+
+    #[test]
+    fn byte_budget_evicts_large_models() {
+        let mut devtools = Devtools::new(DevtoolsConfig {
+            max_entries: 100,
+            byte_budget: 1000,
+            record_ticks: false,
+        })
+        .with_size_fn(|m: &Counter| std::mem::size_of::<Counter>() + m.payload.len());
+
+        let big = Counter {
+            value: 0,
+            payload: vec![0; 400],
+        };
+        for i in 0..5 {
+            devtools.record(&format!("Big({i})"), &big, i);
+        }
+        // Only ~2 snapshots of ~430 bytes fit in 1000.
+        assert!(devtools.used_bytes() <= 1000, "{}", devtools.used_bytes());
+        assert!(devtools.history().len() < 5);
+        // The newest entry always survives.
+        assert_eq!(devtools.history().back().unwrap().message, "Big(4)");
+    }
+
+    #[test]
+    fn custom_skip_predicate() {
+        let mut devtools =
+            Devtools::new(DevtoolsConfig::default()).with_skip(|msg| msg.starts_with("Mouse"));
+        devtools.record("Mouse(3,4)", &model(0), 0);
+        devtools.record("Key", &model(1), 1);
+        assert_eq!(devtools.history().len(), 1);
+    }
+
+    #[test]
+    fn export_json_round_trips_structure() {
+        let mut devtools = Devtools::new(DevtoolsConfig::default());
+        devtools.record("Set(7)", &model(7), 3);
+        let json: serde_json::Value =
+            serde_json::from_str(&devtools.export_json()).expect("valid JSON");
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["entries"][0]["message"], "Set(7)");
+        assert_eq!(json["entries"][0]["frame_idx"], 3);
+        assert_eq!(json["entries"][0]["snapshot"]["value"], 7);
+    }
+}
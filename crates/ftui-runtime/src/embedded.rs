@@ -0,0 +1,743 @@
+#![forbid(unsafe_code)]
+
+//! Embedding adapter: host an ftui [`Model`] inside an existing
+//! crossterm/ratatui event loop.
+//!
+//! Teams migrating incrementally keep their own terminal ownership —
+//! raw mode, alternate screen, and signal handling stay with the host.
+//! [`EmbeddedProgram`] never touches the terminal: the host calls
+//! [`feed_event`](EmbeddedProgram::feed_event) with converted events,
+//! [`tick`](EmbeddedProgram::tick) with its own clock, and
+//! [`render_into`](EmbeddedProgram::render_into) with an
+//! [`ExternalBuffer`] it implements over its target surface. Commands
+//! and background tasks still work; task results are drained on the
+//! next `feed_event`/`tick` call, and dropping the program cancels its
+//! [`Cx`] so Cx-aware workers wind down promptly.
+//!
+//! With the `crossterm-compat` feature, [`Event::from_crossterm`]
+//! (re-exported by ftui-core) converts host crossterm events before
+//! feeding. A ratatui adapter is a few lines on the host side:
+//!
+//! ```ignore
+//! struct RatatuiSurface<'a>(&'a mut ratatui::buffer::Buffer);
+//!
+//! impl ExternalBuffer for RatatuiSurface<'_> {
+//!     fn size(&self) -> (u16, u16) {
+//!         (self.0.area.width, self.0.area.height)
+//!     }
+//!     fn set_cell(&mut self, x: u16, y: u16, cell: ExternalCell<'_>) {
+//!         let target = &mut self.0[(x, y)];
+//!         target.set_symbol(cell.symbol);
+//!         target.set_fg(ratatui::style::Color::Rgb(cell.fg.r(), cell.fg.g(), cell.fg.b()));
+//!         target.set_bg(ratatui::style::Color::Rgb(cell.bg.r(), cell.bg.g(), cell.bg.b()));
+//!     }
+//! }
+//! ```
+//!
+//! [`Event::from_crossterm`]: ftui_core::event::Event
+
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+
+use ftui_core::cx::{Cx, CxController};
+use ftui_core::event::Event;
+use ftui_render::cell::{PackedRgba, StyleFlags};
+use ftui_render::frame::Frame;
+use ftui_render::grapheme_pool::GraphemePool;
+use web_time::{Duration, Instant};
+
+use crate::program::{Cmd, Model};
+
+/// One cell handed to the host surface by [`EmbeddedProgram::render_into`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalCell<'a> {
+    /// The cell's grapheme cluster (empty cells yield `" "`).
+    pub symbol: &'a str,
+    /// Foreground color.
+    pub fg: PackedRgba,
+    /// Background color.
+    pub bg: PackedRgba,
+    /// Text attributes.
+    pub attrs: StyleFlags,
+    /// Display width of the symbol (wide glyphs occupy the next cell too).
+    pub width: u8,
+}
+
+/// Host-implemented render target for an [`EmbeddedProgram`].
+///
+/// Implement this over the host's own surface (a ratatui `Buffer`, a
+/// pixel grid, …); the embedded program pushes every cell of its frame
+/// through [`set_cell`](Self::set_cell).
+pub trait ExternalBuffer {
+    /// Target size in cells; the embedded frame is laid out to match.
+    fn size(&self) -> (u16, u16);
+    /// Receive one rendered cell. Continuation cells of wide glyphs are
+    /// skipped (the `width` field on the leading cell covers them).
+    fn set_cell(&mut self, x: u16, y: u16, cell: ExternalCell<'_>);
+}
+
+/// An ftui [`Model`] driven by a host event loop instead of a terminal.
+///
+/// Never touches raw mode, the alternate screen, or signal handlers.
+/// See the module docs for the host-loop contract.
+pub struct EmbeddedProgram<M: Model> {
+    model: M,
+    pool: GraphemePool,
+    running: bool,
+    /// One-shot tick request from `Cmd::Tick` (duration until due).
+    pending_tick: Option<Duration>,
+    /// Deadline resolved from `pending_tick` on the first host `tick`.
+    tick_deadline: Option<Instant>,
+    /// Cursor position reported by the last rendered frame.
+    cursor: Option<(u16, u16)>,
+    /// Messages produced by background tasks.
+    task_rx: mpsc::Receiver<M::Message>,
+    task_tx: mpsc::Sender<M::Message>,
+    workers: Vec<JoinHandle<()>>,
+    /// Cancellation root handed to Cx-aware tasks; cancelled on drop.
+    cx: Cx,
+    cx_ctrl: CxController,
+    /// Messages emitted via `Cmd::Log` (the host decides where they go).
+    logs: Vec<String>,
+}
+
+impl<M: Model> EmbeddedProgram<M> {
+    /// Wrap a model without taking terminal ownership.
+    ///
+    /// Call [`init`](Self::init) once before feeding events.
+    pub fn new(model: M) -> Self {
+        let (task_tx, task_rx) = mpsc::channel();
+        let (cx, cx_ctrl) = Cx::background();
+        Self {
+            model,
+            pool: GraphemePool::new(),
+            running: true,
+            pending_tick: None,
+            tick_deadline: None,
+            cursor: None,
+            task_rx,
+            task_tx,
+            workers: Vec::new(),
+            cx,
+            cx_ctrl,
+            logs: Vec::new(),
+        }
+    }
+
+    /// Run `Model::init` and its commands.
+    pub fn init(&mut self) {
+        let cmd = self.model.init();
+        self.execute_cmd(cmd);
+    }
+
+    /// Deliver a host event (already converted to an ftui [`Event`]).
+    ///
+    /// With the `crossterm-compat` feature, convert crossterm events via
+    /// `Event::from_crossterm` first. Pending task results are drained
+    /// before the event so ordering follows arrival.
+    pub fn feed_event(&mut self, event: Event) {
+        if !self.running {
+            return;
+        }
+        self.drain_tasks();
+        let cmd = self.model.update(M::Message::from(event));
+        self.execute_cmd(cmd);
+    }
+
+    /// Advance time using the host's clock.
+    ///
+    /// Fires a pending `Cmd::Tick` once its deadline passes (the
+    /// deadline anchors to the first `now` seen after the request, so
+    /// lab/test clocks work) and drains finished background tasks.
+    pub fn tick(&mut self, now: Instant) {
+        if !self.running {
+            return;
+        }
+        self.drain_tasks();
+        if let Some(duration) = self.pending_tick.take() {
+            self.tick_deadline = Some(
+                self.tick_deadline
+                    .unwrap_or_else(|| now + duration)
+                    .min(now + duration),
+            );
+        }
+        if let Some(deadline) = self.tick_deadline
+            && now >= deadline
+        {
+            self.tick_deadline = None;
+            let cmd = self.model.update(M::Message::from(Event::Tick));
+            self.execute_cmd(cmd);
+        }
+    }
+
+    /// Render the current state into the host's surface.
+    ///
+    /// Lays the frame out at the surface's size and pushes every leading
+    /// cell; continuation cells of wide glyphs are skipped.
+    pub fn render_into(&mut self, out: &mut dyn ExternalBuffer) {
+        let (width, height) = out.size();
+        let mut frame = Frame::new(width, height, &mut self.pool);
+        self.model.view(&mut frame);
+        self.cursor = if frame.cursor_visible {
+            frame.cursor_position
+        } else {
+            None
+        };
+
+        for y in 0..height {
+            let mut x = 0;
+            while x < width {
+                let Some(cell) = frame.buffer.get(x, y) else {
+                    x += 1;
+                    continue;
+                };
+                if cell.is_continuation() {
+                    x += 1;
+                    continue;
+                }
+                let mut scratch = [0u8; 4];
+                let symbol: &str = if let Some(id) = cell.content.grapheme_id() {
+                    frame.pool.get(id).unwrap_or(" ")
+                } else if let Some(ch) = cell.content.as_char() {
+                    ch.encode_utf8(&mut scratch)
+                } else {
+                    " "
+                };
+                let width_cells = cell.content.width().max(1) as u8;
+                out.set_cell(
+                    x,
+                    y,
+                    ExternalCell {
+                        symbol,
+                        fg: cell.fg,
+                        bg: cell.bg,
+                        attrs: cell.attrs.flags(),
+                        width: width_cells,
+                    },
+                );
+                x += u16::from(width_cells);
+            }
+        }
+    }
+
+    /// Where the embedded UI wants the host to place the cursor, from
+    /// the most recent [`render_into`](Self::render_into). `None` means
+    /// hidden.
+    #[must_use]
+    pub fn wants_cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// Whether the model has quit (`Cmd::Quit`). The host decides what
+    /// quitting its embedded component means.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// The model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Mutable model access (e.g. to seed state from the host).
+    pub fn model_mut(&mut self) -> &mut M {
+        &mut self.model
+    }
+
+    /// Cancellation context for background work; clone into `Cmd::Task`
+    /// closures so teardown can interrupt them.
+    #[must_use]
+    pub fn cx(&self) -> &Cx {
+        &self.cx
+    }
+
+    /// Messages logged via `Cmd::Log`, drained; the host owns stdout.
+    pub fn take_logs(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.logs)
+    }
+
+    fn drain_tasks(&mut self) {
+        // Reap finished worker threads first so the list stays small.
+        self.workers.retain(|handle| !handle.is_finished());
+        while let Ok(msg) = self.task_rx.try_recv() {
+            let cmd = self.model.update(msg);
+            self.execute_cmd(cmd);
+        }
+    }
+
+    fn execute_cmd(&mut self, cmd: Cmd<M::Message>) {
+        match cmd {
+            Cmd::None => {}
+            Cmd::Quit => self.running = false,
+            Cmd::Msg(msg) => {
+                let cmd = self.model.update(msg);
+                self.execute_cmd(cmd);
+            }
+            Cmd::Batch(cmds) | Cmd::Sequence(cmds) => {
+                for cmd in cmds {
+                    self.execute_cmd(cmd);
+                    if !self.running {
+                        break;
+                    }
+                }
+            }
+            Cmd::Tick(duration) => {
+                self.pending_tick = Some(duration);
+            }
+            Cmd::Log(text) => self.logs.push(text),
+            Cmd::Task(_spec, work) => {
+                let tx = self.task_tx.clone();
+                self.workers.push(std::thread::spawn(move || {
+                    // The host may drop the program while we run; a dead
+                    // receiver just means the result is discarded.
+                    let _ = tx.send(work());
+                }));
+            }
+            // Terminal-owned operations are the host's business.
+            Cmd::SetMouseCapture(_)
+            | Cmd::Suspend
+            | Cmd::SplashProgress(_)
+            | Cmd::CompleteStartup
+            | Cmd::SaveState
+            | Cmd::RestoreState => {}
+            #[cfg(feature = "async")]
+            Cmd::Future(..) => {
+                // No executor in embedded mode; hosts drive async work
+                // themselves and feed results back as events.
+            }
+        }
+    }
+}
+
+impl<M: Model> Drop for EmbeddedProgram<M> {
+    fn drop(&mut self) {
+        // The host owns process lifetime: cancel Cx-aware workers and
+        // detach the rest (their sends land in a closed channel).
+        self.cx_ctrl.cancel();
+    }
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::{KeyCode, KeyEvent, KeyEventKind, Modifiers};
+    use ftui_render::cell::Cell;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Write a line of text into the frame buffer (tests draw by hand;
+    /// the runtime crate does not depend on ftui-widgets).
+    fn draw_line(frame: &mut Frame, y: u16, text: &str) {
+        for (i, ch) in text.chars().enumerate() {
+            let x = i as u16;
+            if x >= frame.width() {
+                break;
+            }
+            frame.buffer.set(x, y, Cell::from_char(ch));
+        }
+    }
+
+    /// Reference host surface: a plain grid of owned cells.
+    #[derive(Debug, Clone)]
+    struct GridSurface {
+        width: u16,
+        height: u16,
+        cells: Vec<(String, PackedRgba, PackedRgba)>,
+    }
+
+    impl GridSurface {
+        fn new(width: u16, height: u16) -> Self {
+            Self {
+                width,
+                height,
+                cells: vec![
+                    (
+                        " ".to_string(),
+                        PackedRgba::default(),
+                        PackedRgba::default()
+                    );
+                    usize::from(width) * usize::from(height)
+                ],
+            }
+        }
+
+        fn row_text(&self, y: u16) -> String {
+            (0..self.width)
+                .map(|x| {
+                    self.cells[usize::from(y) * usize::from(self.width) + usize::from(x)]
+                        .0
+                        .clone()
+                })
+                .collect()
+        }
+    }
+
+    impl ExternalBuffer for GridSurface {
+        fn size(&self) -> (u16, u16) {
+            (self.width, self.height)
+        }
+        fn set_cell(&mut self, x: u16, y: u16, cell: ExternalCell<'_>) {
+            let idx = usize::from(y) * usize::from(self.width) + usize::from(x);
+            self.cells[idx] = (cell.symbol.to_string(), cell.fg, cell.bg);
+        }
+    }
+
+    /// Input + list model: typed characters filter a list.
+    struct PickerModel {
+        query: String,
+        items: Vec<&'static str>,
+        selected: usize,
+        quit_requested: bool,
+    }
+
+    enum PickerMsg {
+        Key(KeyEvent),
+        Other,
+    }
+
+    impl From<Event> for PickerMsg {
+        fn from(event: Event) -> Self {
+            match event {
+                Event::Key(key) => PickerMsg::Key(key),
+                _ => PickerMsg::Other,
+            }
+        }
+    }
+
+    impl Model for PickerModel {
+        type Message = PickerMsg;
+
+        fn update(&mut self, msg: PickerMsg) -> Cmd<PickerMsg> {
+            match msg {
+                PickerMsg::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') if key.modifiers.contains(Modifiers::CTRL) => {
+                        self.quit_requested = true;
+                        Cmd::Quit
+                    }
+                    KeyCode::Char(c) => {
+                        self.query.push(c);
+                        Cmd::None
+                    }
+                    KeyCode::Down => {
+                        self.selected = (self.selected + 1).min(self.items.len() - 1);
+                        Cmd::None
+                    }
+                    _ => Cmd::None,
+                },
+                _ => Cmd::None,
+            }
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            draw_line(frame, 0, &format!("> {}", self.query));
+            let visible: Vec<&&str> = self
+                .items
+                .iter()
+                .filter(|i| i.contains(&self.query))
+                .collect();
+            for (row, item) in visible.iter().enumerate() {
+                let marker = if row == self.selected { "▸ " } else { "  " };
+                draw_line(frame, 1 + row as u16, &format!("{marker}{item}"));
+            }
+            frame.set_cursor(Some((2 + self.query.len() as u16, 0)));
+        }
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent {
+            code,
+            modifiers: Modifiers::NONE,
+            kind: KeyEventKind::Press,
+            ch: None,
+        })
+    }
+
+    fn picker() -> EmbeddedProgram<PickerModel> {
+        let mut program = EmbeddedProgram::new(PickerModel {
+            query: String::new(),
+            items: vec!["alpha", "beta", "gamma", "alps"],
+            selected: 0,
+            quit_requested: false,
+        });
+        program.init();
+        program
+    }
+
+    #[test]
+    fn fake_host_loop_drives_input_and_list_end_to_end() {
+        let mut program = picker();
+        let mut surface = GridSurface::new(20, 6);
+
+        // Host loop: feed keys, render after each.
+        for code in [KeyCode::Char('a'), KeyCode::Char('l')] {
+            program.feed_event(key(code));
+            program.render_into(&mut surface);
+        }
+
+        assert_eq!(program.model().query, "al");
+        assert!(surface.row_text(0).starts_with("> al"));
+        // Filtered list: only "alpha" and "alps" remain.
+        assert!(surface.row_text(1).contains("alpha"));
+        assert!(surface.row_text(2).contains("alps"));
+        assert!(!surface.row_text(3).contains("beta"));
+        // Cursor follows the input.
+        assert_eq!(program.wants_cursor(), Some((4, 0)));
+
+        // Ctrl+Q quits; the host observes and decides what to do.
+        program.feed_event(Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            modifiers: Modifiers::CTRL,
+            kind: KeyEventKind::Press,
+            ch: None,
+        }));
+        assert!(!program.is_running());
+        assert!(program.model().quit_requested);
+    }
+
+    #[test]
+    fn render_lands_wide_glyphs_with_width() {
+        struct Wide;
+        enum WideMsg {
+            Noop,
+        }
+        impl From<Event> for WideMsg {
+            fn from(_: Event) -> Self {
+                WideMsg::Noop
+            }
+        }
+        impl Model for Wide {
+            type Message = WideMsg;
+            fn update(&mut self, _: WideMsg) -> Cmd<WideMsg> {
+                Cmd::None
+            }
+            fn view(&self, frame: &mut Frame) {
+                let id = frame.pool.intern("好", 2);
+                frame.buffer.set(
+                    0,
+                    0,
+                    Cell::new(ftui_render::cell::CellContent::from_grapheme(id)),
+                );
+                frame.buffer.set(
+                    1,
+                    0,
+                    Cell::new(ftui_render::cell::CellContent::CONTINUATION),
+                );
+                frame.buffer.set(2, 0, Cell::from_char('a'));
+            }
+        }
+
+        #[derive(Default)]
+        struct Recorder {
+            cells: Vec<(u16, u16, String, u8)>,
+        }
+        impl ExternalBuffer for Recorder {
+            fn size(&self) -> (u16, u16) {
+                (10, 2)
+            }
+            fn set_cell(&mut self, x: u16, y: u16, cell: ExternalCell<'_>) {
+                self.cells.push((x, y, cell.symbol.to_string(), cell.width));
+            }
+        }
+
+        let mut program = EmbeddedProgram::new(Wide);
+        program.init();
+        let mut surface = Recorder::default();
+        program.render_into(&mut surface);
+
+        let wide = surface
+            .cells
+            .iter()
+            .find(|(_, _, s, _)| s == "好")
+            .expect("wide glyph delivered");
+        assert_eq!(wide.3, 2, "width accompanies the leading cell");
+        // The continuation column was skipped: next delivered x jumps by 2.
+        let a = surface
+            .cells
+            .iter()
+            .find(|(_, _, s, _)| s == "a")
+            .expect("trailing char");
+        assert_eq!(a.0, wide.0 + 2);
+    }
+
+    #[test]
+    fn host_clock_fires_pending_tick() {
+        struct Ticker {
+            ticks: u32,
+        }
+        enum TickerMsg {
+            Tick,
+            Noop,
+        }
+        impl From<Event> for TickerMsg {
+            fn from(event: Event) -> Self {
+                match event {
+                    Event::Tick => TickerMsg::Tick,
+                    _ => TickerMsg::Noop,
+                }
+            }
+        }
+        impl Model for Ticker {
+            type Message = TickerMsg;
+            fn init(&mut self) -> Cmd<TickerMsg> {
+                Cmd::Tick(Duration::from_millis(50))
+            }
+            fn update(&mut self, msg: TickerMsg) -> Cmd<TickerMsg> {
+                if matches!(msg, TickerMsg::Tick) {
+                    self.ticks += 1;
+                }
+                Cmd::None
+            }
+            fn view(&self, _: &mut Frame) {}
+        }
+
+        let mut program = EmbeddedProgram::new(Ticker { ticks: 0 });
+        program.init();
+
+        let t0 = Instant::now();
+        program.tick(t0);
+        assert_eq!(program.model().ticks, 0, "deadline anchors to first now");
+        program.tick(t0 + Duration::from_millis(49));
+        assert_eq!(program.model().ticks, 0);
+        program.tick(t0 + Duration::from_millis(51));
+        assert_eq!(program.model().ticks, 1);
+        // One-shot: no further ticks without a new Cmd::Tick.
+        program.tick(t0 + Duration::from_millis(200));
+        assert_eq!(program.model().ticks, 1);
+    }
+
+    #[test]
+    fn task_results_arrive_through_host_calls() {
+        struct Tasky {
+            result: Option<u32>,
+        }
+        enum TaskyMsg {
+            Start,
+            Done(u32),
+            Noop,
+        }
+        impl From<Event> for TaskyMsg {
+            fn from(event: Event) -> Self {
+                match event {
+                    Event::Tick => TaskyMsg::Start,
+                    _ => TaskyMsg::Noop,
+                }
+            }
+        }
+        impl Model for Tasky {
+            type Message = TaskyMsg;
+            fn update(&mut self, msg: TaskyMsg) -> Cmd<TaskyMsg> {
+                match msg {
+                    TaskyMsg::Start => Cmd::task(|| TaskyMsg::Done(42)),
+                    TaskyMsg::Done(value) => {
+                        self.result = Some(value);
+                        Cmd::None
+                    }
+                    TaskyMsg::Noop => Cmd::None,
+                }
+            }
+            fn view(&self, _: &mut Frame) {}
+        }
+
+        let mut program = EmbeddedProgram::new(Tasky { result: None });
+        program.init();
+        program.feed_event(Event::Tick);
+        // Worker runs on its own thread; poll the host loop until the
+        // result lands.
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while program.model().result.is_none() {
+            assert!(std::time::Instant::now() < deadline, "task never landed");
+            program.tick(Instant::now());
+            std::thread::yield_now();
+        }
+        assert_eq!(program.model().result, Some(42));
+    }
+
+    #[test]
+    fn drop_cancels_cx_for_workers() {
+        struct Idle;
+        enum IdleMsg {
+            Noop,
+        }
+        impl From<Event> for IdleMsg {
+            fn from(_: Event) -> Self {
+                IdleMsg::Noop
+            }
+        }
+        impl Model for Idle {
+            type Message = IdleMsg;
+            fn update(&mut self, _: IdleMsg) -> Cmd<IdleMsg> {
+                Cmd::None
+            }
+            fn view(&self, _: &mut Frame) {}
+        }
+
+        let program = EmbeddedProgram::new(Idle);
+        let cx = program.cx().clone();
+        let finished = Arc::new(AtomicBool::new(false));
+        let worker = {
+            let cx = cx.clone();
+            let finished = finished.clone();
+            std::thread::spawn(move || {
+                while !cx.is_cancelled() {
+                    std::thread::yield_now();
+                }
+                finished.store(true, Ordering::SeqCst);
+            })
+        };
+
+        assert!(!cx.is_cancelled());
+        drop(program);
+        assert!(cx.is_cancelled(), "drop cancels the embedding Cx");
+        worker.join().expect("worker exits promptly");
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[cfg(feature = "crossterm-compat")]
+    mod crossterm_conversion {
+        use super::*;
+
+        #[test]
+        fn crossterm_event_conversion_goldens() {
+            use crossterm::event as ct;
+
+            let cases: &[(ct::Event, Event)] = &[
+                (
+                    ct::Event::Key(ct::KeyEvent::new(
+                        ct::KeyCode::Char('a'),
+                        ct::KeyModifiers::NONE,
+                    )),
+                    // Char keys dual-report: `ch` carries the produced
+                    // character alongside the code.
+                    Event::Key(KeyEvent::new(KeyCode::Char('a'))),
+                ),
+                (
+                    ct::Event::Key(ct::KeyEvent::new(
+                        ct::KeyCode::Enter,
+                        ct::KeyModifiers::CONTROL,
+                    )),
+                    Event::Key(KeyEvent {
+                        code: KeyCode::Enter,
+                        modifiers: Modifiers::CTRL,
+                        kind: KeyEventKind::Press,
+                        ch: None,
+                    }),
+                ),
+                (
+                    ct::Event::Resize(100, 40),
+                    Event::Resize {
+                        width: 100,
+                        height: 40,
+                    },
+                ),
+            ];
+            for (host_event, want) in cases {
+                let got = Event::from_crossterm(host_event.clone())
+                    .unwrap_or_else(|| panic!("unmapped host event {host_event:?}"));
+                assert_eq!(&got, want, "converting {host_event:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,599 @@
+#![forbid(unsafe_code)]
+
+//! Crash-safe error boundary around `update()` and `view()`.
+//!
+//! A panic inside app code normally takes down the whole program (and
+//! pending the terminal guard, the terminal with it) even when the
+//! failure is confined to one screen. [`ErrorBoundary`] wraps any
+//! `Model + Clone` and calls its `update`/`view` through
+//! `catch_unwind`: a panic becomes a [`RuntimeError`] (payload string +
+//! backtrace), the boundary renders a built-in error screen
+//! (overridable via [`ErrorBoundaryConfig::screen`]) offering
+//! **c**ontinue — restoring the model snapshot taken before the failing
+//! message — or **q**uit cleanly, and repeated panics within a window
+//! escalate to a forced clean shutdown. Commands and subscriptions keep
+//! running during the error screen unless configured otherwise.
+//!
+//! Opt-in and explicit: wrapping is the enablement (`AssertUnwindSafe`
+//! is applied internally; a `Clone` model is the snapshot contract —
+//! the boundary keeps a single last-good clone, refreshed before every
+//! delivered message).
+//!
+//! ```ignore
+//! let program = Program::new(ErrorBoundary::new(app_model))?;
+//! ```
+
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use ftui_render::cell::Cell;
+use ftui_render::frame::Frame;
+use web_time::{Duration, Instant};
+
+use crate::program::{Cmd, Model};
+use crate::subscription::Subscription;
+
+/// Which app phase panicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPhase {
+    Init,
+    Update,
+    View,
+}
+
+/// A caught panic from app code.
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    /// The panic payload, stringified.
+    pub message: String,
+    /// Backtrace captured at the boundary.
+    pub backtrace: String,
+    /// Phase that panicked.
+    pub phase: ErrorPhase,
+}
+
+/// Custom error-screen renderer.
+pub type ErrorScreen = dyn Fn(&RuntimeError, &mut Frame) + Send;
+
+/// Error-boundary tuning.
+pub struct ErrorBoundaryConfig {
+    /// Panics within [`window`](Self::window) before escalating to a
+    /// forced clean shutdown.
+    pub max_panics: u32,
+    /// Escalation window.
+    pub window: Duration,
+    /// Keep delivering non-key messages (subscriptions, task results)
+    /// to the inner model while the error screen is up.
+    pub deliver_while_errored: bool,
+    /// App-provided error screen (default: built-in panel).
+    pub screen: Option<Box<ErrorScreen>>,
+}
+
+impl Default for ErrorBoundaryConfig {
+    fn default() -> Self {
+        Self {
+            max_panics: 3,
+            window: Duration::from_secs(30),
+            deliver_while_errored: true,
+            screen: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for ErrorBoundaryConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorBoundaryConfig")
+            .field("max_panics", &self.max_panics)
+            .field("window", &self.window)
+            .field("deliver_while_errored", &self.deliver_while_errored)
+            .field("screen", &self.screen.as_ref().map(|_| "<custom>"))
+            .finish()
+    }
+}
+
+/// Boundary message: the inner message plus the raw event it came from
+/// (when any), so the error screen can react to keys without knowing
+/// the app's message type.
+pub struct BoundaryMsg<M> {
+    event: Option<Event>,
+    inner: M,
+}
+
+impl<M> BoundaryMsg<M> {
+    /// Wrap an app message produced outside the event path (commands,
+    /// subscriptions).
+    pub fn inner(message: M) -> Self {
+        Self {
+            event: None,
+            inner: message,
+        }
+    }
+}
+
+impl<M: From<Event>> From<Event> for BoundaryMsg<M> {
+    fn from(event: Event) -> Self {
+        Self {
+            event: Some(event.clone()),
+            inner: M::from(event),
+        }
+    }
+}
+
+/// Crash-safe wrapper around a `Model + Clone` (see the module docs).
+pub struct ErrorBoundary<M: Model + Clone> {
+    inner: M,
+    /// Snapshot taken before every delivered message.
+    last_good: M,
+    error: Option<RuntimeError>,
+    /// Set by a view() panic (view takes `&self`); promoted into the
+    /// error state on the next update pass.
+    view_failed: AtomicBool,
+    /// Recent panic instants for escalation.
+    panics: Vec<Instant>,
+    config: ErrorBoundaryConfig,
+}
+
+impl<M: Model + Clone> ErrorBoundary<M> {
+    /// Wrap a model with default config.
+    pub fn new(model: M) -> Self {
+        Self::with_config(model, ErrorBoundaryConfig::default())
+    }
+
+    /// Wrap a model with explicit config.
+    pub fn with_config(model: M, config: ErrorBoundaryConfig) -> Self {
+        Self {
+            last_good: model.clone(),
+            inner: model,
+            error: None,
+            view_failed: AtomicBool::new(false),
+            panics: Vec::new(),
+            config,
+        }
+    }
+
+    /// The current error, while the error screen is up.
+    #[must_use]
+    pub fn error(&self) -> Option<&RuntimeError> {
+        self.error.as_ref()
+    }
+
+    /// The wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Record a panic; `true` when the budget escalates to shutdown.
+    fn record_panic(&mut self, phase: ErrorPhase, message: String) -> bool {
+        self.error = Some(RuntimeError {
+            message,
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+            phase,
+        });
+        let now = Instant::now();
+        self.panics.push(now);
+        self.panics
+            .retain(|&at| now.saturating_duration_since(at) <= self.config.window);
+        self.panics.len() as u32 >= self.config.max_panics
+    }
+
+    /// Restore the last-good snapshot and dismiss the error screen.
+    fn recover(&mut self) {
+        self.inner = self.last_good.clone();
+        self.error = None;
+        self.view_failed.store(false, Ordering::SeqCst);
+    }
+
+    fn handle_error_key(&mut self, key: &KeyEvent) -> Option<Cmd<BoundaryMsg<M::Message>>> {
+        if key.kind != KeyEventKind::Press {
+            return Some(Cmd::None);
+        }
+        match key.code {
+            KeyCode::Char('c') | KeyCode::Enter => {
+                self.recover();
+                Some(Cmd::None)
+            }
+            KeyCode::Char('q') | KeyCode::Escape => Some(Cmd::Quit),
+            _ => Some(Cmd::None),
+        }
+    }
+
+    fn deliver(&mut self, message: M::Message, phase: ErrorPhase) -> Cmd<BoundaryMsg<M::Message>> {
+        self.last_good = self.inner.clone();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| self.inner.update(message)));
+        match result {
+            Ok(cmd) => map_cmd(cmd),
+            Err(payload) => {
+                if self.record_panic(phase, panic_text(&*payload)) {
+                    // Escalation: forced clean shutdown.
+                    Cmd::Quit
+                } else {
+                    Cmd::None
+                }
+            }
+        }
+    }
+
+    fn render_builtin_screen(error: &RuntimeError, frame: &mut Frame) {
+        let width = frame.width();
+        let phase = match error.phase {
+            ErrorPhase::Init => "init",
+            ErrorPhase::Update => "update",
+            ErrorPhase::View => "view",
+        };
+        let lines = [
+            format!("PANIC in {phase}()"),
+            String::new(),
+            error.message.clone(),
+            String::new(),
+            "c/Enter: continue with last good state".to_string(),
+            "q/Esc:   quit cleanly".to_string(),
+        ];
+        for (row, line) in lines.iter().enumerate() {
+            let y = row as u16 + 1;
+            if y >= frame.height() {
+                break;
+            }
+            for (col, ch) in line.chars().enumerate() {
+                let x = col as u16 + 2;
+                if x >= width {
+                    break;
+                }
+                frame.buffer.set(x, y, Cell::from_char(ch));
+            }
+        }
+    }
+}
+
+/// Best-effort text from a panic payload.
+fn panic_text(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Lift inner commands into boundary commands.
+fn map_cmd<M: Send + 'static>(cmd: Cmd<M>) -> Cmd<BoundaryMsg<M>> {
+    match cmd {
+        Cmd::None => Cmd::None,
+        Cmd::Quit => Cmd::Quit,
+        Cmd::Msg(message) => Cmd::Msg(BoundaryMsg::inner(message)),
+        Cmd::Batch(cmds) => Cmd::Batch(cmds.into_iter().map(map_cmd).collect()),
+        Cmd::Sequence(cmds) => Cmd::Sequence(cmds.into_iter().map(map_cmd).collect()),
+        Cmd::Tick(duration) => Cmd::Tick(duration),
+        Cmd::Log(text) => Cmd::Log(text),
+        Cmd::Task(spec, work) => Cmd::Task(spec, Box::new(move || BoundaryMsg::inner(work()))),
+        Cmd::SaveState => Cmd::SaveState,
+        Cmd::RestoreState => Cmd::RestoreState,
+        Cmd::SetMouseCapture(enabled) => Cmd::SetMouseCapture(enabled),
+        Cmd::Suspend => Cmd::Suspend,
+        Cmd::SplashProgress(text) => Cmd::SplashProgress(text),
+        Cmd::CompleteStartup => Cmd::CompleteStartup,
+        #[cfg(feature = "async")]
+        Cmd::Future(..) => {
+            // Futures resolve to the inner message type; mapping a boxed
+            // future generically is not possible here — drop with a log.
+            Cmd::Log("error boundary: Cmd::Future dropped (unsupported)".into())
+        }
+    }
+}
+
+impl<M: Model + Clone> Model for ErrorBoundary<M> {
+    type Message = BoundaryMsg<M::Message>;
+
+    fn init(&mut self) -> Cmd<Self::Message> {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| self.inner.init()));
+        match result {
+            Ok(cmd) => map_cmd(cmd),
+            Err(payload) => {
+                if self.record_panic(ErrorPhase::Init, panic_text(&*payload)) {
+                    Cmd::Quit
+                } else {
+                    Cmd::None
+                }
+            }
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Cmd<Self::Message> {
+        // Promote a view() panic (recorded via flag: view is `&self`)
+        // into the error state so recovery and escalation apply.
+        if self.error.is_none()
+            && self.view_failed.swap(false, Ordering::SeqCst)
+            && self.record_panic(ErrorPhase::View, "panic in view()".to_string())
+        {
+            return Cmd::Quit;
+        }
+        if self.error.is_some() {
+            // Error screen: keys drive recovery; other messages keep
+            // flowing to the inner model if configured.
+            if let Some(Event::Key(key)) = &msg.event {
+                if let Some(cmd) = self.handle_error_key(key) {
+                    return cmd;
+                }
+            } else if self.config.deliver_while_errored {
+                return self.deliver(msg.inner, ErrorPhase::Update);
+            }
+            return Cmd::None;
+        }
+        self.deliver(msg.inner, ErrorPhase::Update)
+    }
+
+    fn view(&self, frame: &mut Frame) {
+        if let Some(error) = &self.error {
+            match &self.config.screen {
+                Some(screen) => screen(error, frame),
+                None => Self::render_builtin_screen(error, frame),
+            }
+            return;
+        }
+        // A view panic cannot mutate self here (&self): flag it for the
+        // next update pass (which promotes it into the error state) and
+        // stop calling the poisoned view meanwhile — one panic, not one
+        // per frame.
+        if !self.view_failed.load(Ordering::SeqCst) {
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| self.inner.view(frame)));
+            if result.is_ok() {
+                return;
+            }
+            self.view_failed.store(true, Ordering::SeqCst);
+        }
+        let error = RuntimeError {
+            message: "panic in view()".to_string(),
+            backtrace: String::new(),
+            phase: ErrorPhase::View,
+        };
+        match &self.config.screen {
+            Some(screen) => screen(&error, frame),
+            None => Self::render_builtin_screen(&error, frame),
+        }
+    }
+
+    fn subscriptions(&self) -> Vec<Box<dyn Subscription<Self::Message>>> {
+        // Subscriptions keep running during the error screen; their
+        // messages route through the boundary like everything else.
+        self.inner
+            .subscriptions()
+            .into_iter()
+            .map(|sub| Box::new(BoundarySub { sub }) as Box<dyn Subscription<Self::Message>>)
+            .collect()
+    }
+}
+
+/// Subscription adapter forwarding inner messages through the boundary.
+struct BoundarySub<M: Send + 'static> {
+    sub: Box<dyn Subscription<M>>,
+}
+
+impl<M: Send + 'static> Subscription<BoundaryMsg<M>> for BoundarySub<M> {
+    fn id(&self) -> crate::subscription::SubId {
+        self.sub.id()
+    }
+
+    fn run(
+        &self,
+        sender: std::sync::mpsc::Sender<BoundaryMsg<M>>,
+        stop: crate::subscription::StopSignal,
+    ) {
+        let (tx, rx) = std::sync::mpsc::channel::<M>();
+        // Forward on this thread: the inner run loops until stopped,
+        // so drain through a bridging channel.
+        let forwarder = std::thread::spawn(move || {
+            while let Ok(message) = rx.recv() {
+                if sender.send(BoundaryMsg::inner(message)).is_err() {
+                    break;
+                }
+            }
+        });
+        self.sub.run(tx, stop);
+        let _ = forwarder.join();
+    }
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::Modifiers;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[derive(Clone)]
+    struct Flaky {
+        value: u32,
+        panic_on: u32,
+        panic_in_view: bool,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum FlakyMsg {
+        Add(u32),
+        Noop,
+    }
+
+    impl From<Event> for FlakyMsg {
+        fn from(event: Event) -> Self {
+            match event {
+                Event::Key(k) if matches!(k.code, KeyCode::Char('+')) => FlakyMsg::Add(1),
+                _ => FlakyMsg::Noop,
+            }
+        }
+    }
+
+    impl Model for Flaky {
+        type Message = FlakyMsg;
+        fn update(&mut self, msg: FlakyMsg) -> Cmd<FlakyMsg> {
+            if let FlakyMsg::Add(n) = msg {
+                self.value += n;
+                assert!(self.value != self.panic_on, "boom at {}", self.value);
+            }
+            Cmd::None
+        }
+        fn view(&self, frame: &mut Frame) {
+            assert!(!self.panic_in_view, "view boom");
+            frame.buffer.set(0, 0, Cell::from_char('V'));
+        }
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent {
+            code,
+            ch: None,
+            modifiers: Modifiers::NONE,
+            kind: KeyEventKind::Press,
+        })
+    }
+
+    fn add(n: u32) -> BoundaryMsg<FlakyMsg> {
+        BoundaryMsg::inner(FlakyMsg::Add(n))
+    }
+
+    #[test]
+    fn update_panic_recovers_to_prior_state() {
+        let mut boundary = ErrorBoundary::new(Flaky {
+            value: 0,
+            panic_on: 3,
+            panic_in_view: false,
+        });
+        boundary.update(add(1));
+        boundary.update(add(1));
+        assert_eq!(boundary.inner().value, 2);
+
+        // Third add panics; the error screen comes up.
+        let cmd = boundary.update(add(1));
+        assert!(matches!(cmd, Cmd::None));
+        let error = boundary.error().expect("error recorded");
+        assert!(error.message.contains("boom at 3"), "{}", error.message);
+        assert_eq!(error.phase, ErrorPhase::Update);
+        assert!(!error.backtrace.is_empty());
+
+        // 'c' restores the pre-message snapshot.
+        let cmd = boundary.update(BoundaryMsg::from(key(KeyCode::Char('c'))));
+        assert!(matches!(cmd, Cmd::None));
+        assert!(boundary.error().is_none());
+        assert_eq!(boundary.inner().value, 2, "last good state restored");
+    }
+
+    #[test]
+    fn view_panic_renders_error_screen() {
+        let mut boundary = ErrorBoundary::new(Flaky {
+            value: 0,
+            panic_on: u32::MAX,
+            panic_in_view: true,
+        });
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(60, 8, &mut pool);
+        boundary.view(&mut frame);
+        let row1: String = (0..60)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, 1)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect();
+        assert!(row1.contains("PANIC in view()"), "{row1:?}");
+
+        // A second view pass skips the poisoned inner view (no
+        // re-panic) and keeps showing the screen.
+        let mut frame = Frame::new(60, 8, &mut pool);
+        boundary.view(&mut frame);
+
+        // The next update promotes the failure into the error state, so
+        // recovery keys work.
+        let _ = boundary.update(BoundaryMsg::inner(FlakyMsg::Noop));
+        assert!(boundary.error().is_some(), "view panic promoted");
+        assert_eq!(boundary.error().unwrap().phase, ErrorPhase::View);
+        let cmd = boundary.update(BoundaryMsg::from(key(KeyCode::Char('q'))));
+        assert!(matches!(cmd, Cmd::Quit));
+    }
+
+    #[test]
+    fn escalation_after_repeated_panics_forces_quit() {
+        let mut boundary = ErrorBoundary::with_config(
+            Flaky {
+                value: 0,
+                panic_on: 1,
+                panic_in_view: false,
+            },
+            ErrorBoundaryConfig {
+                max_panics: 3,
+                window: Duration::from_secs(60),
+                ..Default::default()
+            },
+        );
+        // Each attempt: recover, then panic again on the same message.
+        let cmd = boundary.update(add(1));
+        assert!(matches!(cmd, Cmd::None));
+        boundary.update(BoundaryMsg::from(key(KeyCode::Char('c'))));
+        let cmd = boundary.update(add(1));
+        assert!(matches!(cmd, Cmd::None));
+        boundary.update(BoundaryMsg::from(key(KeyCode::Char('c'))));
+        let cmd = boundary.update(add(1));
+        assert!(matches!(cmd, Cmd::Quit), "third panic in window escalates");
+    }
+
+    #[test]
+    fn quit_key_on_error_screen_quits_cleanly() {
+        let mut boundary = ErrorBoundary::new(Flaky {
+            value: 0,
+            panic_on: 1,
+            panic_in_view: false,
+        });
+        let _ = boundary.update(add(1));
+        assert!(boundary.error().is_some());
+        let cmd = boundary.update(BoundaryMsg::from(key(KeyCode::Char('q'))));
+        assert!(matches!(cmd, Cmd::Quit));
+    }
+
+    #[test]
+    fn quit_path_emits_clean_teardown_bytes() {
+        use crate::terminal_writer::{ScreenMode, TerminalWriter, UiAnchor};
+        use ftui_core::terminal_capabilities::TerminalCapabilities;
+        use std::sync::{Arc, Mutex};
+
+        // The boundary's Cmd::Quit flows through the normal runtime,
+        // whose writer teardown restores the cursor. Prove the teardown
+        // trailer on the writer the quit path drops.
+        #[derive(Clone)]
+        struct Sink(Arc<Mutex<Vec<u8>>>);
+        impl std::io::Write for Sink {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+        let sink = Sink(Arc::new(Mutex::new(Vec::new())));
+        let writer = TerminalWriter::new(
+            sink.clone(),
+            ScreenMode::Inline { ui_height: 3 },
+            UiAnchor::Bottom,
+            TerminalCapabilities::basic(),
+        );
+        drop(writer);
+        let bytes = sink.0.lock().unwrap().clone();
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("\x1b[?25h"), "cursor restored: {text:?}");
+    }
+
+    #[test]
+    fn non_key_messages_flow_during_error_screen() {
+        let mut boundary = ErrorBoundary::new(Flaky {
+            value: 0,
+            panic_on: 1,
+            panic_in_view: false,
+        });
+        let _ = boundary.update(add(1));
+        assert!(boundary.error().is_some());
+        // A subscription-style message still reaches the inner model.
+        let _ = boundary.update(BoundaryMsg::inner(FlakyMsg::Noop));
+        assert!(boundary.error().is_some(), "screen stays up");
+    }
+}
@@ -0,0 +1,703 @@
+#![forbid(unsafe_code)]
+
+//! Typed, schema-versioned evidence events.
+//!
+//! The showcase's hand-rolled `diff_decision` JSONL proved its worth in
+//! the Windows 1x1 investigation, but the schema was informal and other
+//! apps couldn't reuse it. This module promotes evidence emission into a
+//! small typed API:
+//!
+//! - event structs ([`DiffDecision`], [`FrameTiming`],
+//!   [`CapabilityProbe`], [`ModeTransition`]) serialized with a checked
+//!   `schema_version`;
+//! - an [`EvidenceEventSink`] trait with stderr-JSONL default,
+//!   in-memory (tests) and null implementations;
+//! - [`SamplingPolicy`] controls (every Nth frame, anomaly-only);
+//! - [`validate_evidence_line`] / [`validate_evidence_stream`] so CI can
+//!   gate on malformed evidence.
+//!
+//! Crucially, [`DiffDecisionReason`] makes full-repaint causes explicit
+//! — the next 1x1-style bug is diagnosable from evidence alone.
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::sync::{Arc, Mutex};
+
+/// Schema version stamped on (and checked in) every event line.
+pub const EVIDENCE_EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Why a diff decision produced the output it did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffDecisionReason {
+    /// Full repaint forced by a resize.
+    FullRepaintResize,
+    /// Full repaint because this is the first frame.
+    FullRepaintFirstFrame,
+    /// Normal incremental diff.
+    PartialDiff,
+    /// Output shaped by an active degradation level.
+    DegradedMode,
+}
+
+impl DiffDecisionReason {
+    /// Stable wire name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::FullRepaintResize => "full_repaint_resize",
+            Self::FullRepaintFirstFrame => "full_repaint_first_frame",
+            Self::PartialDiff => "partial_diff",
+            Self::DegradedMode => "degraded_mode",
+        }
+    }
+
+    /// Whether this reason is worth emitting under anomaly-only sampling.
+    #[must_use]
+    pub const fn is_anomaly(self) -> bool {
+        !matches!(self, Self::PartialDiff)
+    }
+}
+
+/// One diff/present decision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffDecision {
+    /// Frame index the decision applied to.
+    pub frame_idx: u64,
+    /// Buffer width in columns.
+    pub cols: u16,
+    /// Buffer height in rows.
+    pub rows: u16,
+    /// Screen mode name (e.g. `AltScreen`, `Inline`).
+    pub screen_mode: String,
+    /// Cells the diff touched.
+    pub dirty_cells: usize,
+    /// Diff strategy name.
+    pub strategy: String,
+    /// Why the output took this shape.
+    pub reason: DiffDecisionReason,
+}
+
+/// Per-frame phase timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameTiming {
+    /// Frame index.
+    pub frame_idx: u64,
+    /// `view()`/render phase, microseconds.
+    pub render_us: u64,
+    /// Present/write phase, microseconds.
+    pub present_us: u64,
+}
+
+/// A capability detection result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityProbe {
+    /// Capability name (e.g. `sync_output`).
+    pub name: String,
+    /// Whether the terminal supports it.
+    pub supported: bool,
+    /// Where the answer came from (env, query, default).
+    pub source: String,
+}
+
+/// A screen/degradation mode change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeTransition {
+    /// Previous mode name.
+    pub from: String,
+    /// New mode name.
+    pub to: String,
+    /// What caused the change.
+    pub trigger: String,
+}
+
+/// Any evidence event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvidenceEvent {
+    /// Diff/present decision.
+    DiffDecision(DiffDecision),
+    /// Frame phase timings.
+    FrameTiming(FrameTiming),
+    /// Capability detection result.
+    CapabilityProbe(CapabilityProbe),
+    /// Mode change.
+    ModeTransition(ModeTransition),
+}
+
+/// Escape a string for inclusion in a JSON string literal.
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+impl EvidenceEvent {
+    /// Wire name of the event type.
+    #[must_use]
+    pub const fn event_name(&self) -> &'static str {
+        match self {
+            Self::DiffDecision(_) => "diff_decision",
+            Self::FrameTiming(_) => "frame_timing",
+            Self::CapabilityProbe(_) => "capability_probe",
+            Self::ModeTransition(_) => "mode_transition",
+        }
+    }
+
+    /// Whether anomaly-only sampling should always pass this event.
+    ///
+    /// Capability probes and mode transitions are rare state changes;
+    /// diff decisions are anomalous per their reason; timings are bulk.
+    #[must_use]
+    pub fn is_anomaly(&self) -> bool {
+        match self {
+            Self::DiffDecision(d) => d.reason.is_anomaly(),
+            Self::FrameTiming(_) => false,
+            Self::CapabilityProbe(_) | Self::ModeTransition(_) => true,
+        }
+    }
+
+    /// Serialize as one JSONL line (no trailing newline).
+    #[must_use]
+    pub fn to_jsonl(&self) -> String {
+        let mut out = String::with_capacity(160);
+        let _ = write!(
+            out,
+            "{{\"schema_version\":{EVIDENCE_EVENT_SCHEMA_VERSION},\"event\":\"{}\"",
+            self.event_name()
+        );
+        match self {
+            Self::DiffDecision(d) => {
+                let _ = write!(
+                    out,
+                    ",\"frame_idx\":{},\"cols\":{},\"rows\":{},\"screen_mode\":\"{}\",\"dirty_cells\":{},\"strategy\":\"{}\",\"reason\":\"{}\"",
+                    d.frame_idx,
+                    d.cols,
+                    d.rows,
+                    escape_json(&d.screen_mode),
+                    d.dirty_cells,
+                    escape_json(&d.strategy),
+                    d.reason.as_str()
+                );
+            }
+            Self::FrameTiming(t) => {
+                let _ = write!(
+                    out,
+                    ",\"frame_idx\":{},\"render_us\":{},\"present_us\":{}",
+                    t.frame_idx, t.render_us, t.present_us
+                );
+            }
+            Self::CapabilityProbe(p) => {
+                let _ = write!(
+                    out,
+                    ",\"name\":\"{}\",\"supported\":{},\"source\":\"{}\"",
+                    escape_json(&p.name),
+                    p.supported,
+                    escape_json(&p.source)
+                );
+            }
+            Self::ModeTransition(m) => {
+                let _ = write!(
+                    out,
+                    ",\"from\":\"{}\",\"to\":\"{}\",\"trigger\":\"{}\"",
+                    escape_json(&m.from),
+                    escape_json(&m.to),
+                    escape_json(&m.trigger)
+                );
+            }
+        }
+        out.push('}');
+        out
+    }
+}
+
+// ============================================================================
+// Sinks
+// ============================================================================
+
+/// Destination for serialized evidence lines.
+///
+/// (The JSONL file/stdout machinery lives in
+/// [`EvidenceSink`](crate::evidence_sink::EvidenceSink); this trait is
+/// the swappable seam for the typed event emitter.)
+pub trait EvidenceEventSink: Send {
+    /// Write one JSONL line.
+    fn emit_line(&mut self, line: &str);
+}
+
+/// Default sink: one JSONL line per event on stderr.
+#[derive(Debug, Default)]
+pub struct StderrSink;
+
+impl EvidenceEventSink for StderrSink {
+    fn emit_line(&mut self, line: &str) {
+        let mut err = std::io::stderr().lock();
+        let _ = writeln!(err, "{line}");
+    }
+}
+
+/// Discards everything.
+#[derive(Debug, Default)]
+pub struct NullSink;
+
+impl EvidenceEventSink for NullSink {
+    fn emit_line(&mut self, _line: &str) {}
+}
+
+/// In-memory sink for tests; lines are shared through a handle so the
+/// test can read what the emitter (moved into the program) produced.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySink {
+    lines: Arc<Mutex<Vec<String>>>,
+}
+
+impl MemorySink {
+    /// Create an empty sink.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of the captured lines.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl EvidenceEventSink for MemorySink {
+    fn emit_line(&mut self, line: &str) {
+        self.lines
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(line.to_string());
+    }
+}
+
+// ============================================================================
+// Emitter with sampling
+// ============================================================================
+
+/// How densely bulk events are emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SamplingPolicy {
+    /// Emit every Nth bulk event (1 = all). Anomalies always pass.
+    pub every_nth: u64,
+    /// Emit anomalies only (rare state changes and non-routine diff
+    /// reasons); bulk events are dropped regardless of `every_nth`.
+    pub anomaly_only: bool,
+}
+
+impl Default for SamplingPolicy {
+    fn default() -> Self {
+        Self {
+            every_nth: 1,
+            anomaly_only: false,
+        }
+    }
+}
+
+impl SamplingPolicy {
+    /// Emit only anomalies.
+    #[must_use]
+    pub const fn anomalies_only() -> Self {
+        Self {
+            every_nth: 1,
+            anomaly_only: true,
+        }
+    }
+
+    /// Emit every Nth bulk event.
+    #[must_use]
+    pub const fn every(n: u64) -> Self {
+        Self {
+            every_nth: if n == 0 { 1 } else { n },
+            anomaly_only: false,
+        }
+    }
+}
+
+/// Serializes events through a sink under a sampling policy.
+pub struct EvidenceEmitter {
+    sink: Box<dyn EvidenceEventSink>,
+    sampling: SamplingPolicy,
+    /// Bulk events seen (drives every-Nth sampling).
+    seen: u64,
+}
+
+impl std::fmt::Debug for EvidenceEmitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvidenceEmitter")
+            .field("sampling", &self.sampling)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl EvidenceEmitter {
+    /// Emitter over an arbitrary sink.
+    #[must_use]
+    pub fn new(sink: Box<dyn EvidenceEventSink>) -> Self {
+        Self {
+            sink,
+            sampling: SamplingPolicy::default(),
+            seen: 0,
+        }
+    }
+
+    /// Default stderr-JSONL emitter.
+    #[must_use]
+    pub fn stderr() -> Self {
+        Self::new(Box::new(StderrSink))
+    }
+
+    /// Sampling policy (builder).
+    #[must_use]
+    pub fn with_sampling(mut self, sampling: SamplingPolicy) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Emit an event, subject to sampling. Returns whether it was
+    /// written.
+    pub fn emit(&mut self, event: &EvidenceEvent) -> bool {
+        if !event.is_anomaly() {
+            self.seen += 1;
+            if self.sampling.anomaly_only {
+                return false;
+            }
+            if !(self.seen - 1).is_multiple_of(self.sampling.every_nth.max(1)) {
+                return false;
+            }
+        }
+        self.sink.emit_line(&event.to_jsonl());
+        true
+    }
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// Expected value shape of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldType {
+    Number,
+    String,
+    Bool,
+}
+
+/// Extract the raw value text of `"key":` in a JSON object line.
+fn raw_field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let needle = format!("\"{key}\":");
+    let start = line.find(&needle)? + needle.len();
+    let rest = &line[start..];
+    // Value ends at the next top-level ',' or '}' (string values scan to
+    // the closing quote; evidence strings never contain escaped quotes
+    // followed by delimiters mid-value, and the validator only needs the
+    // leading type shape anyway).
+    let end = if let Some(inner) = rest.strip_prefix('"') {
+        inner.find('"').map(|i| i + 2)?
+    } else {
+        rest.find([',', '}'])?
+    };
+    Some(&rest[..end])
+}
+
+fn check_field(line: &str, key: &str, expected: FieldType, errors: &mut Vec<String>) {
+    let Some(raw) = raw_field(line, key) else {
+        errors.push(format!("missing field `{key}`"));
+        return;
+    };
+    let ok = match expected {
+        FieldType::Number => {
+            raw.chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+                && !raw.is_empty()
+        }
+        FieldType::String => raw.starts_with('"') && raw.ends_with('"') && raw.len() >= 2,
+        FieldType::Bool => raw == "true" || raw == "false",
+    };
+    if !ok {
+        errors.push(format!("field `{key}` is not a {expected:?}: `{raw}`"));
+    }
+}
+
+/// Validate one evidence line: schema version, event type, and the
+/// presence/type of every field that event requires.
+pub fn validate_evidence_line(line: &str) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    match raw_field(line, "schema_version") {
+        Some(raw) if raw == EVIDENCE_EVENT_SCHEMA_VERSION.to_string() => {}
+        Some(raw) => errors.push(format!("unsupported schema_version `{raw}`")),
+        None => errors.push("missing field `schema_version`".to_string()),
+    }
+    let event = raw_field(line, "event").map(|raw| raw.trim_matches('"').to_string());
+    let fields: &[(&str, FieldType)] = match event.as_deref() {
+        Some("diff_decision") => &[
+            ("frame_idx", FieldType::Number),
+            ("cols", FieldType::Number),
+            ("rows", FieldType::Number),
+            ("screen_mode", FieldType::String),
+            ("dirty_cells", FieldType::Number),
+            ("strategy", FieldType::String),
+            ("reason", FieldType::String),
+        ],
+        Some("frame_timing") => &[
+            ("frame_idx", FieldType::Number),
+            ("render_us", FieldType::Number),
+            ("present_us", FieldType::Number),
+        ],
+        Some("capability_probe") => &[
+            ("name", FieldType::String),
+            ("supported", FieldType::Bool),
+            ("source", FieldType::String),
+        ],
+        Some("mode_transition") => &[
+            ("from", FieldType::String),
+            ("to", FieldType::String),
+            ("trigger", FieldType::String),
+        ],
+        Some(other) => {
+            errors.push(format!("unknown event type `{other}`"));
+            &[]
+        }
+        None => {
+            errors.push("missing field `event`".to_string());
+            &[]
+        }
+    };
+    for (key, expected) in fields {
+        check_field(line, key, *expected, &mut errors);
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate a JSONL stream; returns the number of valid lines, or every
+/// error annotated with its 1-based line number.
+pub fn validate_evidence_stream(input: &str) -> Result<usize, Vec<String>> {
+    let mut errors = Vec::new();
+    let mut count = 0usize;
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match validate_evidence_line(line) {
+            Ok(()) => count += 1,
+            Err(line_errors) => {
+                for err in line_errors {
+                    errors.push(format!("line {}: {err}", idx + 1));
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(count)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_diff() -> EvidenceEvent {
+        EvidenceEvent::DiffDecision(DiffDecision {
+            frame_idx: 7,
+            cols: 80,
+            rows: 24,
+            screen_mode: "AltScreen".into(),
+            dirty_cells: 120,
+            strategy: "DirtyRows".into(),
+            reason: DiffDecisionReason::PartialDiff,
+        })
+    }
+
+    // ── Serialization goldens ──────────────────────────────────────
+
+    #[test]
+    fn diff_decision_golden() {
+        assert_eq!(
+            sample_diff().to_jsonl(),
+            "{\"schema_version\":1,\"event\":\"diff_decision\",\"frame_idx\":7,\"cols\":80,\"rows\":24,\"screen_mode\":\"AltScreen\",\"dirty_cells\":120,\"strategy\":\"DirtyRows\",\"reason\":\"partial_diff\"}"
+        );
+    }
+
+    #[test]
+    fn frame_timing_golden() {
+        let event = EvidenceEvent::FrameTiming(FrameTiming {
+            frame_idx: 3,
+            render_us: 512,
+            present_us: 88,
+        });
+        assert_eq!(
+            event.to_jsonl(),
+            "{\"schema_version\":1,\"event\":\"frame_timing\",\"frame_idx\":3,\"render_us\":512,\"present_us\":88}"
+        );
+    }
+
+    #[test]
+    fn capability_probe_golden() {
+        let event = EvidenceEvent::CapabilityProbe(CapabilityProbe {
+            name: "sync_output".into(),
+            supported: true,
+            source: "env".into(),
+        });
+        assert_eq!(
+            event.to_jsonl(),
+            "{\"schema_version\":1,\"event\":\"capability_probe\",\"name\":\"sync_output\",\"supported\":true,\"source\":\"env\"}"
+        );
+    }
+
+    #[test]
+    fn mode_transition_golden() {
+        let event = EvidenceEvent::ModeTransition(ModeTransition {
+            from: "Inline".into(),
+            to: "AltScreen".into(),
+            trigger: "config".into(),
+        });
+        assert_eq!(
+            event.to_jsonl(),
+            "{\"schema_version\":1,\"event\":\"mode_transition\",\"from\":\"Inline\",\"to\":\"AltScreen\",\"trigger\":\"config\"}"
+        );
+    }
+
+    // ── Sinks ──────────────────────────────────────────────────────
+
+    #[test]
+    fn sinks_are_swappable() {
+        let memory = MemorySink::new();
+        let mut emitter = EvidenceEmitter::new(Box::new(memory.clone()));
+        assert!(emitter.emit(&sample_diff()));
+        assert_eq!(memory.lines().len(), 1);
+        assert!(memory.lines()[0].contains("\"event\":\"diff_decision\""));
+
+        let mut null = EvidenceEmitter::new(Box::new(NullSink));
+        assert!(null.emit(&sample_diff()), "null sink still reports emitted");
+    }
+
+    // ── Sampling ───────────────────────────────────────────────────
+
+    #[test]
+    fn every_nth_sampling_passes_anomalies() {
+        let memory = MemorySink::new();
+        let mut emitter =
+            EvidenceEmitter::new(Box::new(memory.clone())).with_sampling(SamplingPolicy::every(3));
+
+        // Nine bulk events: every third is written (indices 0, 3, 6).
+        for i in 0..9 {
+            let _ = emitter.emit(&EvidenceEvent::FrameTiming(FrameTiming {
+                frame_idx: i,
+                render_us: 1,
+                present_us: 1,
+            }));
+        }
+        assert_eq!(memory.lines().len(), 3);
+
+        // An anomaly bypasses the sampler entirely.
+        let resize = EvidenceEvent::DiffDecision(DiffDecision {
+            reason: DiffDecisionReason::FullRepaintResize,
+            ..match sample_diff() {
+                EvidenceEvent::DiffDecision(d) => d,
+                _ => unreachable!(),
+            }
+        });
+        assert!(emitter.emit(&resize));
+        assert_eq!(memory.lines().len(), 4);
+    }
+
+    #[test]
+    fn anomaly_only_drops_bulk_events() {
+        let memory = MemorySink::new();
+        let mut emitter = EvidenceEmitter::new(Box::new(memory.clone()))
+            .with_sampling(SamplingPolicy::anomalies_only());
+
+        assert!(!emitter.emit(&sample_diff()), "partial diff is bulk");
+        assert!(emitter.emit(&EvidenceEvent::ModeTransition(ModeTransition {
+            from: "a".into(),
+            to: "b".into(),
+            trigger: "test".into(),
+        })));
+        assert_eq!(memory.lines().len(), 1);
+    }
+
+    // ── Validation ─────────────────────────────────────────────────
+
+    #[test]
+    fn validator_accepts_every_event_type() {
+        let events = [
+            sample_diff(),
+            EvidenceEvent::FrameTiming(FrameTiming {
+                frame_idx: 0,
+                render_us: 1,
+                present_us: 2,
+            }),
+            EvidenceEvent::CapabilityProbe(CapabilityProbe {
+                name: "kitty_keyboard".into(),
+                supported: false,
+                source: "query".into(),
+            }),
+            EvidenceEvent::ModeTransition(ModeTransition {
+                from: "None".into(),
+                to: "Light".into(),
+                trigger: "budget".into(),
+            }),
+        ];
+        let stream: String = events.iter().map(|e| e.to_jsonl() + "\n").collect();
+        assert_eq!(validate_evidence_stream(&stream), Ok(4));
+    }
+
+    #[test]
+    fn validator_catches_type_and_presence_regressions() {
+        // dirty_cells regressed from number to string.
+        let bad_type = "{\"schema_version\":1,\"event\":\"diff_decision\",\"frame_idx\":7,\"cols\":80,\"rows\":24,\"screen_mode\":\"AltScreen\",\"dirty_cells\":\"120\",\"strategy\":\"Full\",\"reason\":\"partial_diff\"}";
+        let errors = validate_evidence_line(bad_type).unwrap_err();
+        assert!(
+            errors.iter().any(|e| e.contains("dirty_cells")),
+            "{errors:?}"
+        );
+
+        // reason field dropped entirely.
+        let missing = "{\"schema_version\":1,\"event\":\"diff_decision\",\"frame_idx\":7,\"cols\":80,\"rows\":24,\"screen_mode\":\"AltScreen\",\"dirty_cells\":120,\"strategy\":\"Full\"}";
+        let errors = validate_evidence_line(missing).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("`reason`")), "{errors:?}");
+
+        // Wrong schema version.
+        let wrong_version = "{\"schema_version\":99,\"event\":\"frame_timing\",\"frame_idx\":0,\"render_us\":1,\"present_us\":2}";
+        assert!(validate_evidence_line(wrong_version).is_err());
+
+        // Stream validation annotates line numbers.
+        let stream = format!("{}\n{bad_type}\n", sample_diff().to_jsonl());
+        let errors = validate_evidence_stream(&stream).unwrap_err();
+        assert!(errors[0].starts_with("line 2:"), "{errors:?}");
+    }
+
+    #[test]
+    fn strings_are_escaped() {
+        let event = EvidenceEvent::ModeTransition(ModeTransition {
+            from: "a\"b".into(),
+            to: "c\\d".into(),
+            trigger: "line\nbreak".into(),
+        });
+        let line = event.to_jsonl();
+        assert!(line.contains("a\\\"b"));
+        assert!(line.contains("c\\\\d"));
+        assert!(line.contains("line\\nbreak"));
+        assert!(validate_evidence_line(&line).is_ok());
+    }
+}
@@ -82,6 +82,10 @@ static RESIZE_SNAPSHOT: LazyLock<RwLock<Option<ResizeDecisionSnapshot>>> =
     LazyLock::new(|| RwLock::new(None));
 static BUDGET_SNAPSHOT: LazyLock<RwLock<Option<BudgetDecisionSnapshot>>> =
     LazyLock::new(|| RwLock::new(None));
+static LATENCY_SNAPSHOT: LazyLock<RwLock<Option<ftui_core::latency::LatencySnapshot>>> =
+    LazyLock::new(|| RwLock::new(None));
+static SHUTDOWN_REPORT: LazyLock<RwLock<Option<crate::shutdown::ShutdownReport>>> =
+    LazyLock::new(|| RwLock::new(None));
 
 // Global snapshot telemetry is shared state. In tests, we serialize snapshot
 // access to avoid flakiness under parallel test execution.
@@ -160,9 +164,70 @@ pub fn clear_budget_snapshot() {
     set_budget_snapshot(None);
 }
 
+/// Store the latest input-latency percentile snapshot
+/// ([`ftui_core::latency::LatencyTracker::snapshot`]).
+pub fn set_latency_snapshot(snapshot: Option<ftui_core::latency::LatencySnapshot>) {
+    #[cfg(test)]
+    let _lock = TEST_LOCK.lock().expect("test lock poisoned");
+
+    if let Ok(mut guard) = LATENCY_SNAPSHOT.write() {
+        *guard = snapshot;
+    }
+}
+
+/// Fetch the latest input-latency percentile snapshot.
+#[must_use]
+pub fn latency_snapshot() -> Option<ftui_core::latency::LatencySnapshot> {
+    #[cfg(test)]
+    let _lock = TEST_LOCK.lock().expect("test lock poisoned");
+
+    LATENCY_SNAPSHOT.read().ok().and_then(|guard| *guard)
+}
+
+/// Clear the input-latency snapshot.
+pub fn clear_latency_snapshot() {
+    set_latency_snapshot(None);
+}
+
+/// Store the shutdown teardown report ([`crate::shutdown`]).
+pub fn set_shutdown_report(report: Option<crate::shutdown::ShutdownReport>) {
+    #[cfg(test)]
+    let _lock = TEST_LOCK.lock().expect("test lock poisoned");
+
+    if let Ok(mut guard) = SHUTDOWN_REPORT.write() {
+        *guard = report;
+    }
+}
+
+/// Fetch the shutdown teardown report.
+#[must_use]
+pub fn shutdown_report() -> Option<crate::shutdown::ShutdownReport> {
+    #[cfg(test)]
+    let _lock = TEST_LOCK.lock().expect("test lock poisoned");
+
+    SHUTDOWN_REPORT.read().ok().and_then(|guard| guard.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn latency_snapshot_round_trips_through_evidence() {
+        let snapshot = ftui_core::latency::LatencySnapshot {
+            count: 42,
+            p50_us: 1_500,
+            p95_us: 12_000,
+            p99_us: 48_000,
+            max_us: 90_000,
+        };
+        set_latency_snapshot(Some(snapshot));
+        let fetched = latency_snapshot().expect("stored");
+        assert_eq!(fetched, snapshot);
+        assert!(fetched.to_json().contains("\"p99_us\":48000"));
+        clear_latency_snapshot();
+        assert!(latency_snapshot().is_none());
+    }
     use ftui_render::budget::{BudgetDecision, DegradationLevel};
     use ftui_render::diff_strategy::{DiffStrategy, StrategyEvidence};
 
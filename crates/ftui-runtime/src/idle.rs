@@ -0,0 +1,307 @@
+#![forbid(unsafe_code)]
+
+//! Idle detection and power-saving render suspension.
+//!
+//! A dashboard left open keeps animating even when nobody has touched it
+//! for an hour. [`IdleMonitor`] tracks the time since the last user input
+//! (keyboard/mouse/paste, optionally focus loss) on the program clock —
+//! every method takes `now` explicitly, so the Lab can drive it — and
+//! reports [`IdleTransition`]s the runtime turns into app messages.
+//! [`TickRateScale`] is the shared throttle handle timer subscriptions
+//! consult: while idle the runtime applies the configured multiplier (or
+//! pauses ticks entirely), and the first input restores full rate and
+//! forces a repaint so the UI instantly looks current.
+//!
+//! Scaling only thins *timer* ticks; messages from data subscriptions
+//! still run `update` and paint as usual, so real model changes are
+//! never suppressed.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use web_time::{Duration, Instant};
+
+/// Idle behavior configuration ([`ProgramConfig::idle`]).
+///
+/// [`ProgramConfig::idle`]: crate::program::ProgramConfig::idle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleConfig {
+    /// Input silence before the session counts as idle.
+    pub threshold: Duration,
+    /// Treat terminal focus-out as immediately idle (and focus-in as
+    /// activity).
+    pub idle_on_focus_loss: bool,
+    /// Timer-tick divisor applied while idle (`1` = unchanged).
+    pub tick_multiplier: u32,
+    /// Pause timer ticks entirely while idle (overrides the multiplier).
+    pub pause_ticks: bool,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            threshold: Duration::from_secs(60),
+            idle_on_focus_loss: false,
+            tick_multiplier: 4,
+            pause_ticks: false,
+        }
+    }
+}
+
+/// Snapshot of the idle tracker, exposed to the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleState {
+    /// `false` once the idle threshold (or focus loss) tripped.
+    pub active: bool,
+    /// Time since the last user input.
+    pub idle_for: Duration,
+}
+
+/// A boundary crossing reported by [`IdleMonitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleTransition {
+    /// Input silence crossed the threshold (or focus was lost).
+    EnteredIdle,
+    /// Input arrived while idle.
+    ExitedIdle,
+}
+
+/// Program-clock idle tracker.
+///
+/// The runtime feeds it input and focus events plus a periodic poll;
+/// it answers with at most one transition per call.
+#[derive(Debug, Clone)]
+pub struct IdleMonitor {
+    config: IdleConfig,
+    last_input: Instant,
+    idle: bool,
+    focused: bool,
+}
+
+impl IdleMonitor {
+    /// Create a tracker; `now` is the activity epoch.
+    #[must_use]
+    pub fn new(config: IdleConfig, now: Instant) -> Self {
+        Self {
+            config,
+            last_input: now,
+            idle: false,
+            focused: true,
+        }
+    }
+
+    /// The active configuration.
+    #[must_use]
+    pub fn config(&self) -> IdleConfig {
+        self.config
+    }
+
+    /// Current snapshot for the app.
+    #[must_use]
+    pub fn state(&self, now: Instant) -> IdleState {
+        IdleState {
+            active: !self.idle,
+            idle_for: now.saturating_duration_since(self.last_input),
+        }
+    }
+
+    /// User input arrived (key/mouse/paste). Exits idle.
+    pub fn note_input(&mut self, now: Instant) -> Option<IdleTransition> {
+        self.last_input = now;
+        if self.idle {
+            self.idle = false;
+            return Some(IdleTransition::ExitedIdle);
+        }
+        None
+    }
+
+    /// Terminal focus changed. With `idle_on_focus_loss`, losing focus
+    /// enters idle immediately and regaining it counts as input.
+    pub fn note_focus(&mut self, focused: bool, now: Instant) -> Option<IdleTransition> {
+        self.focused = focused;
+        if !self.config.idle_on_focus_loss {
+            return None;
+        }
+        if focused {
+            self.note_input(now)
+        } else if !self.idle {
+            self.idle = true;
+            Some(IdleTransition::EnteredIdle)
+        } else {
+            None
+        }
+    }
+
+    /// Periodic check against the threshold (called from the main loop).
+    pub fn poll(&mut self, now: Instant) -> Option<IdleTransition> {
+        if self.idle || now.saturating_duration_since(self.last_input) < self.config.threshold {
+            return None;
+        }
+        self.idle = true;
+        Some(IdleTransition::EnteredIdle)
+    }
+}
+
+/// Pause sentinel inside the shared multiplier atomic.
+const PAUSED: u32 = 0;
+
+/// Shared throttle handle applied by timer subscriptions.
+///
+/// Holds the current tick divisor: `1` delivers every tick, `n` every
+/// `n`th tick, paused delivers none. Attach the program's handle to
+/// animation timers via [`Interval::with_rate_scale`] /
+/// [`Every::with_rate_scale`]; the idle machinery adjusts it.
+///
+/// [`Interval::with_rate_scale`]: crate::subscription::Interval::with_rate_scale
+/// [`Every::with_rate_scale`]: crate::subscription::Every::with_rate_scale
+#[derive(Debug, Clone)]
+pub struct TickRateScale {
+    multiplier: Arc<AtomicU32>,
+}
+
+impl Default for TickRateScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TickRateScale {
+    /// Full-rate handle (multiplier 1).
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            multiplier: Arc::new(AtomicU32::new(1)),
+        }
+    }
+
+    /// Set the divisor; `0` is coerced to paused.
+    pub fn set_multiplier(&self, multiplier: u32) {
+        self.multiplier.store(multiplier, Ordering::Relaxed);
+    }
+
+    /// Stop delivering timer ticks entirely.
+    pub fn pause(&self) {
+        self.multiplier.store(PAUSED, Ordering::Relaxed);
+    }
+
+    /// Restore full rate.
+    pub fn restore(&self) {
+        self.multiplier.store(1, Ordering::Relaxed);
+    }
+
+    /// The current divisor (`0` = paused).
+    #[must_use]
+    pub fn multiplier(&self) -> u32 {
+        self.multiplier.load(Ordering::Relaxed)
+    }
+
+    /// Whether ticks are fully paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.multiplier() == PAUSED
+    }
+
+    /// Whether the tick at schedule position `tick` should be delivered.
+    #[must_use]
+    pub fn admits(&self, tick: u64) -> bool {
+        match self.multiplier() {
+            PAUSED => false,
+            1 => true,
+            m => tick.is_multiple_of(u64::from(m)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cfg(threshold_ms: u64) -> IdleConfig {
+        IdleConfig {
+            threshold: Duration::from_millis(threshold_ms),
+            ..IdleConfig::default()
+        }
+    }
+
+    #[test]
+    fn idle_entry_and_exit_timing() {
+        let t0 = Instant::now();
+        let mut monitor = IdleMonitor::new(cfg(100), t0);
+
+        assert_eq!(monitor.poll(t0 + Duration::from_millis(99)), None);
+        assert!(monitor.state(t0 + Duration::from_millis(99)).active);
+
+        assert_eq!(
+            monitor.poll(t0 + Duration::from_millis(100)),
+            Some(IdleTransition::EnteredIdle)
+        );
+        let state = monitor.state(t0 + Duration::from_millis(150));
+        assert!(!state.active);
+        assert_eq!(state.idle_for, Duration::from_millis(150));
+        // Entering is reported once.
+        assert_eq!(monitor.poll(t0 + Duration::from_millis(200)), None);
+
+        assert_eq!(
+            monitor.note_input(t0 + Duration::from_millis(300)),
+            Some(IdleTransition::ExitedIdle)
+        );
+        assert!(monitor.state(t0 + Duration::from_millis(300)).active);
+        // The window restarts from the new input.
+        assert_eq!(monitor.poll(t0 + Duration::from_millis(399)), None);
+        assert_eq!(
+            monitor.poll(t0 + Duration::from_millis(400)),
+            Some(IdleTransition::EnteredIdle)
+        );
+    }
+
+    #[test]
+    fn input_while_active_restarts_the_window_silently() {
+        let t0 = Instant::now();
+        let mut monitor = IdleMonitor::new(cfg(100), t0);
+        assert_eq!(monitor.note_input(t0 + Duration::from_millis(90)), None);
+        assert_eq!(monitor.poll(t0 + Duration::from_millis(150)), None);
+        assert_eq!(
+            monitor.poll(t0 + Duration::from_millis(190)),
+            Some(IdleTransition::EnteredIdle)
+        );
+    }
+
+    #[test]
+    fn focus_loss_enters_idle_immediately_when_configured() {
+        let t0 = Instant::now();
+        let mut config = cfg(60_000);
+        config.idle_on_focus_loss = true;
+        let mut monitor = IdleMonitor::new(config, t0);
+
+        assert_eq!(
+            monitor.note_focus(false, t0 + Duration::from_millis(1)),
+            Some(IdleTransition::EnteredIdle)
+        );
+        assert_eq!(
+            monitor.note_focus(true, t0 + Duration::from_millis(2)),
+            Some(IdleTransition::ExitedIdle)
+        );
+
+        // Without the flag, focus flaps are ignored.
+        let mut plain = IdleMonitor::new(cfg(60_000), t0);
+        assert_eq!(plain.note_focus(false, t0), None);
+        assert_eq!(plain.note_focus(true, t0), None);
+    }
+
+    #[test]
+    fn scale_admits_every_nth_tick_and_restores() {
+        let scale = TickRateScale::new();
+        assert!((0..8).all(|t| scale.admits(t)));
+
+        scale.set_multiplier(4);
+        let admitted: Vec<u64> = (0..12).filter(|&t| scale.admits(t)).collect();
+        assert_eq!(admitted, vec![0, 4, 8]);
+
+        scale.pause();
+        assert!(scale.is_paused());
+        assert!(!(0..100).any(|t| scale.admits(t)));
+
+        scale.restore();
+        assert!((0..8).all(|t| scale.admits(t)));
+    }
+}
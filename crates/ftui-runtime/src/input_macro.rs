@@ -1050,6 +1050,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -27,22 +27,41 @@
 
 pub mod allocation_budget;
 pub mod asciicast;
+#[cfg(feature = "async")]
+pub mod async_cmd;
 pub mod bocpd;
+pub mod command_subscription;
+pub mod config_reload;
 pub mod conformal_alert;
 pub mod conformal_predictor;
 pub mod cost_model;
+#[cfg(feature = "devtools")]
+pub mod debug_server;
 pub mod debug_trace;
 pub mod decision_core;
+#[cfg(feature = "devtools")]
+pub mod devtools;
 pub mod diff_evidence;
+pub mod embedded;
 pub mod eprocess_throttle;
+pub mod error_boundary;
 pub mod evidence_bridges;
+pub mod evidence_events;
 pub mod evidence_sink;
 pub mod evidence_telemetry;
 pub mod flake_detector;
+pub mod idle;
+pub mod incremental;
 pub mod input_fairness;
 pub mod input_macro;
 pub mod locale;
+pub mod log_bridge;
 pub mod log_sink;
+pub mod message_bus;
+#[cfg(feature = "state-persistence")]
+pub mod model_persist;
+pub mod path_watch;
+pub mod privacy;
 pub mod program;
 pub mod queueing_scheduler;
 #[cfg(feature = "render-thread")]
@@ -50,6 +69,7 @@ pub mod render_thread;
 pub mod render_trace;
 pub mod resize_coalescer;
 pub mod resize_sla;
+pub mod shutdown;
 pub mod simulator;
 pub mod state_persistence;
 #[cfg(feature = "stdio-capture")]
@@ -69,19 +89,41 @@ pub mod schedule_trace;
 pub mod telemetry;
 pub mod voi_telemetry;
 
-pub use asciicast::{AsciicastRecorder, AsciicastWriter};
+pub use asciicast::{
+    AsciicastRecorder, AsciicastWriter, SessionRecorder, SessionRecorderHandle,
+    SessionRecordingConfig, SessionRecordingStats,
+};
+#[cfg(feature = "async")]
+pub use async_cmd::{AsyncCmdExecutor, SharedSpawner, Spawner, ThreadSpawner};
+pub use command_subscription::{
+    CommandEvent, CommandExit, CommandSpec, CommandSubscription, command,
+};
+#[cfg(feature = "devtools")]
+pub use debug_server::{DebugControl, DebugServer, DumpKind, DumpRequest};
+#[cfg(feature = "devtools")]
+pub use devtools::{Devtools, DevtoolsConfig, HistoryEntry as DevtoolsHistoryEntry};
 pub use diff_evidence::{
     DiffEvidenceLedger, DiffRegime, DiffStrategyRecord, Observation, RegimeTransition,
 };
+pub use embedded::{EmbeddedProgram, ExternalBuffer, ExternalCell};
+pub use error_boundary::{
+    BoundaryMsg, ErrorBoundary, ErrorBoundaryConfig, ErrorPhase, RuntimeError,
+};
 pub use evidence_sink::{EvidenceSink, EvidenceSinkConfig, EvidenceSinkDestination};
 pub use evidence_telemetry::{
     BudgetDecisionSnapshot, ConformalSnapshot, DiffDecisionSnapshot, ResizeDecisionSnapshot,
-    budget_snapshot, clear_budget_snapshot, clear_diff_snapshot, clear_resize_snapshot,
-    diff_snapshot, resize_snapshot, set_budget_snapshot, set_diff_snapshot, set_resize_snapshot,
+    budget_snapshot, clear_budget_snapshot, clear_diff_snapshot, clear_latency_snapshot,
+    clear_resize_snapshot, diff_snapshot, latency_snapshot, resize_snapshot, set_budget_snapshot,
+    set_diff_snapshot, set_latency_snapshot, set_resize_snapshot, set_shutdown_report,
+    shutdown_report,
 };
 pub use ftui_backend::{BackendEventSource, BackendFeatures};
 #[cfg(feature = "native-backend")]
 pub use ftui_tty::TtyBackend;
+pub use idle::{IdleConfig, IdleState, IdleTransition, TickRateScale};
+pub use incremental::{
+    IncrementalJob, SliceOutcome, run_incremental, run_incremental_with_outcome,
+};
 pub use input_macro::{
     EventRecorder, FilteredEventRecorder, InputMacro, MacroPlayback, MacroPlayer, MacroRecorder,
     RecordingFilter, RecordingState, TimedEvent,
@@ -89,7 +131,13 @@ pub use input_macro::{
 pub use locale::{
     Locale, LocaleContext, LocaleOverride, current_locale, detect_system_locale, set_locale,
 };
+pub use log_bridge::{LogBridge, LogBridgeSubscriber, LogEvent, LogLevel};
 pub use log_sink::LogSink;
+pub use message_bus::{BusSubscription, MessageBus, OverflowPolicy};
+pub use path_watch::{
+    PathChangeKind, PathChanged, PathWatcher, WatchKinds, WatchOptions, watch_path,
+};
+pub use privacy::TelemetryPrivacyPolicy;
 #[cfg(feature = "crossterm-compat")]
 pub use program::CrosstermEventSource;
 pub use program::{
@@ -98,23 +146,27 @@ pub use program::{
     PaneTerminalAdapter, PaneTerminalAdapterConfig, PaneTerminalDispatch,
     PaneTerminalIgnoredReason, PaneTerminalLifecyclePhase, PaneTerminalLogEntry,
     PaneTerminalLogOutcome, PaneTerminalSplitterHandle, PersistenceConfig, Program, ProgramConfig,
-    ResizeBehavior, TaskSpec, WidgetRefreshConfig, pane_terminal_resolve_splitter_target,
-    pane_terminal_splitter_handles, pane_terminal_target_from_hit,
-    register_pane_terminal_splitter_hits,
+    ResizeBehavior, SplashInfo, SplashInputPolicy, StartupConfig, TaskSpec, ViewId,
+    WidgetRefreshConfig, pane_terminal_resolve_splitter_target, pane_terminal_splitter_handles,
+    pane_terminal_target_from_hit, register_pane_terminal_splitter_hits,
 };
 pub use render_trace::{
     RenderTraceConfig, RenderTraceContext, RenderTraceFrame, RenderTraceRecorder,
 };
+pub use shutdown::{ShutdownCoordinator, ShutdownPhase, ShutdownReason, ShutdownReport};
 pub use simulator::ProgramSimulator;
 pub use string_model::{StringModel, StringModelAdapter};
-pub use subscription::{Every, StopSignal, SubId, Subscription};
+pub use subscription::{
+    Backoff, Every, Interval, IntervalSchedule, RestartPolicy, StopSignal, SubId, Subscription,
+    SubscriptionEvent, SubscriptionOutcome, TickInfo, Timeout,
+};
 pub use terminal_writer::{ScreenMode, TerminalWriter, UiAnchor, inline_active_widgets};
 pub use voi_telemetry::{
     clear_inline_auto_voi_snapshot, inline_auto_voi_snapshot, set_inline_auto_voi_snapshot,
 };
 
 #[cfg(feature = "render-thread")]
-pub use render_thread::{OutMsg, RenderThread};
+pub use render_thread::{FrameSubmission, OutMsg, RenderThread};
 
 #[cfg(feature = "stdio-capture")]
 pub use stdio_capture::{CapturedWriter, StdioCapture, StdioCaptureError};
@@ -122,6 +174,7 @@ pub use stdio_capture::{CapturedWriter, StdioCapture, StdioCaptureError};
 pub use allocation_budget::{
     AllocationBudget, BudgetAlert, BudgetConfig, BudgetEvidence, BudgetSummary,
 };
+pub use config_reload::{ConfigReloadEvent, ConfigSource, ConfigWatcher, FileConfigSource};
 pub use conformal_alert::{
     AlertConfig, AlertDecision, AlertEvidence, AlertReason, AlertStats, ConformalAlert,
 };
@@ -141,7 +194,10 @@ pub use eprocess_throttle::{
     EProcessThrottle, ThrottleConfig, ThrottleDecision, ThrottleLog, ThrottleStats,
 };
 pub use flake_detector::{EvidenceLog, FlakeConfig, FlakeDecision, FlakeDetector, FlakeSummary};
-pub use reactive::{BatchScope, Binding, BindingScope, Computed, Observable, TwoWayBinding};
+pub use reactive::{
+    BatchScope, Binding, BindingScope, Computed, ListDeltaMirror, Observable, ObservableVec,
+    TwoWayBinding, VecDelta, VecDeltaTarget,
+};
 pub use resize_coalescer::{
     CoalesceAction, CoalescerConfig, CoalescerStats, CycleTimePercentiles, DecisionLog,
     DecisionSummary, Regime, ResizeCoalescer,
@@ -150,9 +206,9 @@ pub use resize_sla::{
     ResizeEvidence, ResizeSlaMonitor, SlaConfig, SlaLogEntry, SlaSummary, make_sla_hooks,
 };
 pub use undo::{
-    CommandBatch, CommandError, CommandMetadata, CommandResult, CommandSource, HistoryConfig,
-    HistoryManager, MergeConfig, TextDeleteCmd, TextInsertCmd, TextReplaceCmd, Transaction,
-    TransactionScope, UndoableCmd, WidgetId,
+    CommandBatch, CommandError, CommandMetadata, CommandResult, CommandSource, CursorPos,
+    HistoryConfig, HistoryManager, MergeConfig, SelectionSpan, TextDeleteCmd, TextInsertCmd,
+    TextReplaceCmd, Transaction, TransactionScope, UndoableCmd, WidgetId,
 };
 pub use unified_evidence::{
     DecisionDomain, DomainSummary, EmitsEvidence, EvidenceEntry, EvidenceEntryBuilder,
@@ -168,6 +224,8 @@ pub use voi_sampling::{
 
 // State persistence
 #[cfg(feature = "state-persistence")]
+pub use model_persist::{ModelPersistence, Persist, PersistedState};
+#[cfg(feature = "state-persistence")]
 pub use state_persistence::FileStorage;
 pub use state_persistence::{
     MemoryStorage, RegistryStats, StateRegistry, StorageBackend, StorageError, StorageResult,
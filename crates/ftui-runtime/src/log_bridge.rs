@@ -0,0 +1,357 @@
+#![forbid(unsafe_code)]
+
+//! In-memory tracing bridge for in-app log viewing.
+//!
+//! Captures `tracing` events (level, target, message, fields) into a
+//! bounded channel so a TUI log pane — e.g.
+//! `ftui_widgets::log_viewer::LogViewer` — can display them live inside
+//! the running app. Writers never block on the UI reading: events go
+//! through `try_send` and are counted as dropped when the ring is full.
+//! A thread-local reentrancy guard makes it safe for the viewer's own
+//! render path to emit events (they are captured, not recursed into).
+//!
+//! ```ignore
+//! let (bridge, subscriber) = LogBridge::new(1024);
+//! let _guard = tracing::subscriber::set_default(subscriber);
+//! // each frame:
+//! for event in bridge.drain() {
+//!     viewer.push(event.to_line());
+//! }
+//! ```
+
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, SyncSender, TrySendError, sync_channel};
+
+use tracing::field::{Field, Visit};
+use tracing::span;
+
+/// Severity level of a captured event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn from_tracing(level: &tracing::Level) -> Self {
+        match *level {
+            tracing::Level::TRACE => Self::Trace,
+            tracing::Level::DEBUG => Self::Debug,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::WARN => Self::Warn,
+            tracing::Level::ERROR => Self::Error,
+        }
+    }
+
+    /// Fixed-width badge label.
+    #[must_use]
+    pub const fn badge(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO ",
+            Self::Warn => "WARN ",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// One captured tracing event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEvent {
+    /// Severity.
+    pub level: LogLevel,
+    /// Event target (module path by default).
+    pub target: String,
+    /// The `message` field, when present.
+    pub message: String,
+    /// Remaining fields as (name, rendered value).
+    pub fields: Vec<(String, String)>,
+}
+
+impl LogEvent {
+    /// Render as a single log line (`LEVEL target message k=v ...`).
+    #[must_use]
+    pub fn to_line(&self) -> String {
+        let mut line = format!("{} {} {}", self.level.badge(), self.target, self.message);
+        for (name, value) in &self.fields {
+            let _ = write!(line, " {name}={value}");
+        }
+        line
+    }
+
+    /// True when the event passes the given level floor and target
+    /// substring filter.
+    #[must_use]
+    pub fn matches(&self, min_level: LogLevel, target_filter: &str) -> bool {
+        self.level >= min_level && (target_filter.is_empty() || self.target.contains(target_filter))
+    }
+}
+
+/// UI-side handle: drains captured events.
+pub struct LogBridge {
+    rx: Receiver<LogEvent>,
+    shared: Arc<BridgeShared>,
+}
+
+struct BridgeShared {
+    dropped: AtomicU64,
+    paused: AtomicBool,
+}
+
+impl LogBridge {
+    /// Create a bridge with the given ring capacity.
+    ///
+    /// Returns the UI-side handle and the subscriber to install (via
+    /// `tracing::subscriber::set_default` or `set_global_default`).
+    #[must_use]
+    pub fn new(capacity: usize) -> (Self, LogBridgeSubscriber) {
+        let (tx, rx) = sync_channel(capacity.max(1));
+        let shared = Arc::new(BridgeShared {
+            dropped: AtomicU64::new(0),
+            paused: AtomicBool::new(false),
+        });
+        let bridge = Self {
+            rx,
+            shared: Arc::clone(&shared),
+        };
+        let subscriber = LogBridgeSubscriber { tx, shared };
+        (bridge, subscriber)
+    }
+
+    /// Drain all pending events (never blocks).
+    pub fn drain(&self) -> Vec<LogEvent> {
+        let mut out = Vec::new();
+        while let Ok(event) = self.rx.try_recv() {
+            out.push(event);
+        }
+        out
+    }
+
+    /// Drain only events passing a level floor and target filter; the
+    /// rest are discarded (they were delivered, just not wanted).
+    pub fn drain_filtered(&self, min_level: LogLevel, target_filter: &str) -> Vec<LogEvent> {
+        self.drain()
+            .into_iter()
+            .filter(|event| event.matches(min_level, target_filter))
+            .collect()
+    }
+
+    /// Events dropped because the ring was full (or capture was paused).
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Pause capture: new events are counted as dropped until resume.
+    pub fn pause(&self) {
+        self.shared.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resume capture.
+    pub fn resume(&self) {
+        self.shared.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether capture is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.shared.paused.load(Ordering::Relaxed)
+    }
+}
+
+impl std::fmt::Debug for LogBridge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogBridge")
+            .field("dropped", &self.dropped())
+            .field("paused", &self.is_paused())
+            .finish_non_exhaustive()
+    }
+}
+
+thread_local! {
+    /// Reentrancy guard: events emitted while capturing an event (or from
+    /// the viewer's own render through this subscriber) are not recursed
+    /// into.
+    static IN_CAPTURE: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Minimal `tracing::Subscriber` feeding a [`LogBridge`].
+#[derive(Clone)]
+pub struct LogBridgeSubscriber {
+    tx: SyncSender<LogEvent>,
+    shared: Arc<BridgeShared>,
+}
+
+impl tracing::Subscriber for LogBridgeSubscriber {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        // Spans are not tracked; a constant id keeps the API satisfied.
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        // Reentrancy guard: never recurse, never block.
+        let reentrant = IN_CAPTURE.with(|flag| flag.replace(true));
+        if reentrant {
+            return;
+        }
+        if self.shared.paused.load(Ordering::Relaxed) {
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            IN_CAPTURE.with(|flag| flag.set(false));
+            return;
+        }
+
+        let metadata = event.metadata();
+        let mut visitor = FieldCollector::default();
+        event.record(&mut visitor);
+        let captured = LogEvent {
+            level: LogLevel::from_tracing(metadata.level()),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        };
+        match self.tx.try_send(captured) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
+                self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        IN_CAPTURE.with(|flag| flag.set(false));
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[derive(Default)]
+struct FieldCollector {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for FieldCollector {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_level_target_and_fields() {
+        let (bridge, subscriber) = LogBridge::new(16);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(answer = 42, "hello world");
+            tracing::warn!("careful");
+        });
+        let events = bridge.drain();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].level, LogLevel::Info);
+        assert_eq!(events[0].message, "hello world");
+        assert_eq!(
+            events[0].fields,
+            vec![("answer".to_string(), "42".to_string())]
+        );
+        assert!(events[0].target.contains("log_bridge"));
+        assert_eq!(events[1].level, LogLevel::Warn);
+        assert!(events[1].to_line().starts_with("WARN "));
+    }
+
+    #[test]
+    fn ring_overflow_drops_and_counts() {
+        let (bridge, subscriber) = LogBridge::new(3);
+        tracing::subscriber::with_default(subscriber, || {
+            for i in 0..10 {
+                tracing::info!(i, "spam");
+            }
+        });
+        assert_eq!(
+            bridge.drain().len(),
+            3,
+            "ring keeps the first capacity events"
+        );
+        assert_eq!(bridge.dropped(), 7);
+        // Draining frees capacity for new events.
+        assert!(bridge.drain().is_empty());
+    }
+
+    #[test]
+    fn level_and_target_filters() {
+        let event = |level, target: &str| LogEvent {
+            level,
+            target: target.to_string(),
+            message: String::new(),
+            fields: Vec::new(),
+        };
+        assert!(event(LogLevel::Error, "ftui_render::diff").matches(LogLevel::Warn, "render"));
+        assert!(!event(LogLevel::Info, "ftui_render::diff").matches(LogLevel::Warn, ""));
+        assert!(!event(LogLevel::Error, "ftui_core").matches(LogLevel::Trace, "render"));
+        assert!(event(LogLevel::Trace, "anything").matches(LogLevel::Trace, ""));
+    }
+
+    #[test]
+    fn pause_counts_drops_and_resume_captures() {
+        let (bridge, subscriber) = LogBridge::new(16);
+        tracing::subscriber::with_default(subscriber, || {
+            bridge.pause();
+            tracing::info!("lost");
+            bridge.resume();
+            tracing::info!("kept");
+        });
+        let events = bridge.drain();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].message, "kept");
+        assert_eq!(bridge.dropped(), 1);
+    }
+
+    #[test]
+    fn reentrant_emission_does_not_deadlock_or_recurse() {
+        struct LogsOnDebug;
+        impl std::fmt::Debug for LogsOnDebug {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                // A log event emitted while the subscriber is formatting
+                // this value (i.e. during capture) must be swallowed.
+                tracing::info!("reentrant");
+                f.write_str("payload")
+            }
+        }
+
+        let (bridge, subscriber) = LogBridge::new(16);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(value = ?LogsOnDebug, "outer");
+        });
+        let events = bridge.drain();
+        assert_eq!(events.len(), 1, "only the outer event is captured");
+        assert_eq!(events[0].message, "outer");
+    }
+}
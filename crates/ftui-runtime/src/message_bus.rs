@@ -0,0 +1,478 @@
+#![forbid(unsafe_code)]
+
+//! Typed inter-component message bus with topic subscriptions.
+//!
+//! In larger apps, sibling screens need to communicate ("settings changed",
+//! "job finished") without threading every signal through the root model.
+//! [`MessageBus`] provides decoupled publish/subscribe over `&'static str`
+//! topics with typed payloads:
+//!
+//! - [`MessageBus::publish`] is callable from `update()` or commands. It only
+//!   enqueues — subscribers are never invoked re-entrantly during publish.
+//! - [`MessageBus::subscribe`] returns a typed [`BusSubscription<T>`] whose
+//!   messages are drained on subsequent update cycles (poll it from
+//!   `update()` on tick, or adapt it into a runtime [`Subscription`] via
+//!   [`BusSubscription::into_runtime_subscription`]).
+//! - Delivery order is preserved per topic; each subscriber has a bounded
+//!   queue with an explicit [`OverflowPolicy`] and an overflow counter.
+//! - Publishing a payload whose type doesn't match a subscriber's expected
+//!   type increments [`MessageBus::type_error_count`] (and logs) instead of
+//!   silently dropping.
+//! - Dropping the subscription handle unsubscribes automatically.
+//!
+//! Payloads are shared between subscribers as `Arc<T>`, so no `Clone` bound
+//! is required on payload types.
+
+use std::any::{Any, TypeId, type_name};
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use web_time::Duration;
+
+use tracing::warn;
+
+use crate::subscription::{StopSignal, SubId, Subscription};
+
+/// What to do when a subscriber's bounded queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Drop the oldest queued message to make room for the new one.
+    #[default]
+    DropOldest,
+    /// Drop the newly published message.
+    DropNewest,
+}
+
+/// Default per-subscriber queue capacity.
+const DEFAULT_CAPACITY: usize = 256;
+
+type Payload = Arc<dyn Any + Send + Sync>;
+
+/// Per-subscriber bounded queue.
+struct SubQueue {
+    queue: Mutex<VecDeque<Payload>>,
+    condvar: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+    overflow: AtomicU64,
+}
+
+impl SubQueue {
+    fn push(&self, payload: Payload) {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        if queue.len() >= self.capacity {
+            self.overflow.fetch_add(1, Ordering::Relaxed);
+            match self.policy {
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                }
+                OverflowPolicy::DropNewest => return,
+            }
+        }
+        queue.push_back(payload);
+        drop(queue);
+        self.condvar.notify_all();
+    }
+
+    fn try_pop(&self) -> Option<Payload> {
+        self.queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+    }
+
+    fn pop_timeout(&self, timeout: Duration) -> Option<Payload> {
+        let queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        let (mut queue, _) = self
+            .condvar
+            .wait_timeout_while(queue, timeout, |q| q.is_empty())
+            .unwrap_or_else(|e| e.into_inner());
+        queue.pop_front()
+    }
+}
+
+/// A registered subscriber on a topic.
+struct SubscriberEntry {
+    id: u64,
+    expected: TypeId,
+    expected_name: &'static str,
+    queue: Arc<SubQueue>,
+}
+
+struct BusInner {
+    topics: Mutex<HashMap<&'static str, Vec<SubscriberEntry>>>,
+    type_errors: AtomicU64,
+    next_sub_id: AtomicU64,
+}
+
+/// Lightweight typed publish/subscribe bus (cheap to clone and share).
+#[derive(Clone)]
+pub struct MessageBus {
+    inner: Arc<BusInner>,
+}
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageBus {
+    /// Create a new, empty bus.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(BusInner {
+                topics: Mutex::new(HashMap::new()),
+                type_errors: AtomicU64::new(0),
+                next_sub_id: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Publish a payload to all subscribers of `topic`.
+    ///
+    /// Only enqueues: subscribers see the message when they next drain their
+    /// subscription (never re-entrantly during this call). Subscribers whose
+    /// expected type doesn't match `T` are skipped and counted as type
+    /// errors.
+    pub fn publish<T: Any + Send + Sync>(&self, topic: &'static str, payload: T) {
+        let payload: Payload = Arc::new(payload);
+        let topics = self.inner.topics.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(subscribers) = topics.get(topic) else {
+            return;
+        };
+        for entry in subscribers {
+            if entry.expected == TypeId::of::<T>() {
+                entry.queue.push(Arc::clone(&payload));
+            } else {
+                self.inner.type_errors.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    topic,
+                    published = type_name::<T>(),
+                    expected = entry.expected_name,
+                    "message bus payload type mismatch"
+                );
+                // Debug-mode panic (suppressed under cfg(test) so the error
+                // counter itself stays testable).
+                if cfg!(debug_assertions) && !cfg!(test) {
+                    panic!(
+                        "message bus type mismatch on topic {topic:?}: published {}, subscriber expects {}",
+                        type_name::<T>(),
+                        entry.expected_name,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Subscribe to `topic` with payload type `T` and default backpressure
+    /// (capacity 256, drop-oldest).
+    #[must_use]
+    pub fn subscribe<T: Any + Send + Sync>(&self, topic: &'static str) -> BusSubscription<T> {
+        self.subscribe_with(topic, DEFAULT_CAPACITY, OverflowPolicy::default())
+    }
+
+    /// Subscribe with an explicit queue capacity and overflow policy.
+    #[must_use]
+    pub fn subscribe_with<T: Any + Send + Sync>(
+        &self,
+        topic: &'static str,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> BusSubscription<T> {
+        let id = self.inner.next_sub_id.fetch_add(1, Ordering::Relaxed);
+        let queue = Arc::new(SubQueue {
+            queue: Mutex::new(VecDeque::new()),
+            condvar: Condvar::new(),
+            capacity: capacity.max(1),
+            policy,
+            overflow: AtomicU64::new(0),
+        });
+        let entry = SubscriberEntry {
+            id,
+            expected: TypeId::of::<T>(),
+            expected_name: type_name::<T>(),
+            queue: Arc::clone(&queue),
+        };
+        self.inner
+            .topics
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(topic)
+            .or_default()
+            .push(entry);
+        BusSubscription {
+            bus: Arc::clone(&self.inner),
+            topic,
+            id,
+            queue,
+            _payload: PhantomData,
+        }
+    }
+
+    /// Number of publishes that hit a subscriber with a mismatched type.
+    #[must_use]
+    pub fn type_error_count(&self) -> u64 {
+        self.inner.type_errors.load(Ordering::Relaxed)
+    }
+
+    /// Number of subscribers currently registered on `topic`.
+    #[must_use]
+    pub fn subscriber_count(&self, topic: &str) -> usize {
+        self.inner
+            .topics
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(topic)
+            .map_or(0, Vec::len)
+    }
+}
+
+impl std::fmt::Debug for MessageBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageBus")
+            .field("type_errors", &self.type_error_count())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Typed handle to a topic subscription.
+///
+/// Messages are drained with [`try_recv`](Self::try_recv) /
+/// [`drain`](Self::drain) from the update cycle, or forwarded to the runtime
+/// message channel via
+/// [`into_runtime_subscription`](Self::into_runtime_subscription). Dropping
+/// the handle unsubscribes.
+pub struct BusSubscription<T> {
+    bus: Arc<BusInner>,
+    topic: &'static str,
+    id: u64,
+    queue: Arc<SubQueue>,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T: Any + Send + Sync> BusSubscription<T> {
+    /// Pop the next pending message, if any.
+    #[must_use]
+    pub fn try_recv(&self) -> Option<Arc<T>> {
+        self.queue
+            .try_pop()
+            .map(|payload| payload.downcast().expect("bus enforces payload type"))
+    }
+
+    /// Drain all pending messages in publish order.
+    #[must_use]
+    pub fn drain(&self) -> Vec<Arc<T>> {
+        let mut out = Vec::new();
+        while let Some(msg) = self.try_recv() {
+            out.push(msg);
+        }
+        out
+    }
+
+    /// Number of messages dropped on this subscription due to backpressure.
+    #[must_use]
+    pub fn overflow_count(&self) -> u64 {
+        self.queue.overflow.load(Ordering::Relaxed)
+    }
+
+    /// The subscribed topic.
+    #[must_use]
+    pub const fn topic(&self) -> &'static str {
+        self.topic
+    }
+
+    /// Adapt into a runtime [`Subscription`] that forwards each payload as a
+    /// regular runtime message via `map`.
+    pub fn into_runtime_subscription<M, F>(self, map: F) -> BusRuntimeSubscription<T, F>
+    where
+        M: Send + 'static,
+        F: Fn(Arc<T>) -> M + Send + Sync + 'static,
+    {
+        BusRuntimeSubscription {
+            subscription: self,
+            map,
+        }
+    }
+}
+
+impl<T> Drop for BusSubscription<T> {
+    fn drop(&mut self) {
+        let mut topics = self.bus.topics.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(subscribers) = topics.get_mut(self.topic) {
+            subscribers.retain(|entry| entry.id != self.id);
+            if subscribers.is_empty() {
+                topics.remove(self.topic);
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for BusSubscription<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BusSubscription")
+            .field("topic", &self.topic)
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Poll interval for the runtime-subscription adapter.
+const ADAPTER_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// [`Subscription`] adapter forwarding bus messages to the runtime channel.
+pub struct BusRuntimeSubscription<T, F> {
+    subscription: BusSubscription<T>,
+    map: F,
+}
+
+impl<T, M, F> Subscription<M> for BusRuntimeSubscription<T, F>
+where
+    T: Any + Send + Sync,
+    M: Send + 'static,
+    F: Fn(Arc<T>) -> M + Send + Sync + 'static,
+{
+    fn id(&self) -> SubId {
+        // Stable per bus subscription: the bus-wide unique subscriber id,
+        // offset into a dedicated namespace.
+        0x4255_5300_0000_0000 | self.subscription.id
+    }
+
+    fn run(&self, sender: std::sync::mpsc::Sender<M>, stop: StopSignal) {
+        while !stop.is_stopped() {
+            match self.subscription.queue.pop_timeout(ADAPTER_POLL_INTERVAL) {
+                Some(payload) => {
+                    let payload = payload.downcast().expect("bus enforces payload type");
+                    if sender.send((self.map)(payload)).is_err() {
+                        return;
+                    }
+                }
+                None => {
+                    if stop.wait_timeout(Duration::ZERO) {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_subscribers_receive_in_order() {
+        let bus = MessageBus::new();
+        let a = bus.subscribe::<i32>("jobs");
+        let b = bus.subscribe::<i32>("jobs");
+
+        bus.publish("jobs", 1i32);
+        bus.publish("jobs", 2i32);
+        bus.publish("jobs", 3i32);
+
+        let got_a: Vec<i32> = a.drain().iter().map(|v| **v).collect();
+        let got_b: Vec<i32> = b.drain().iter().map(|v| **v).collect();
+        assert_eq!(got_a, vec![1, 2, 3]);
+        assert_eq!(got_b, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn drop_unsubscribes() {
+        let bus = MessageBus::new();
+        let sub = bus.subscribe::<String>("settings");
+        assert_eq!(bus.subscriber_count("settings"), 1);
+        drop(sub);
+        assert_eq!(bus.subscriber_count("settings"), 0);
+        // Publishing to a topic with no subscribers is a no-op.
+        bus.publish("settings", "dark".to_string());
+        assert_eq!(bus.type_error_count(), 0);
+    }
+
+    #[test]
+    fn wrong_payload_type_counts_error_and_skips() {
+        let bus = MessageBus::new();
+        let sub = bus.subscribe::<i32>("jobs");
+
+        bus.publish("jobs", "not an i32".to_string());
+        assert_eq!(bus.type_error_count(), 1);
+        assert!(sub.try_recv().is_none());
+
+        // Correctly typed publishes still flow.
+        bus.publish("jobs", 7i32);
+        assert_eq!(sub.try_recv().as_deref(), Some(&7));
+    }
+
+    #[test]
+    fn publish_is_not_reentrant_messages_wait_for_drain() {
+        let bus = MessageBus::new();
+        let sub = bus.subscribe::<u8>("t");
+
+        // Simulate publish from within an update cycle: nothing is delivered
+        // until the subscription is drained on the next cycle.
+        bus.publish("t", 1u8);
+        assert_eq!(sub.overflow_count(), 0);
+        let first_cycle = sub.drain();
+        assert_eq!(first_cycle.len(), 1);
+        assert!(sub.drain().is_empty());
+    }
+
+    #[test]
+    fn overflow_drop_oldest_keeps_newest() {
+        let bus = MessageBus::new();
+        let sub = bus.subscribe_with::<i32>("t", 2, OverflowPolicy::DropOldest);
+
+        bus.publish("t", 1i32);
+        bus.publish("t", 2i32);
+        bus.publish("t", 3i32);
+
+        assert_eq!(sub.overflow_count(), 1);
+        let got: Vec<i32> = sub.drain().iter().map(|v| **v).collect();
+        assert_eq!(got, vec![2, 3]);
+    }
+
+    #[test]
+    fn overflow_drop_newest_keeps_oldest() {
+        let bus = MessageBus::new();
+        let sub = bus.subscribe_with::<i32>("t", 2, OverflowPolicy::DropNewest);
+
+        bus.publish("t", 1i32);
+        bus.publish("t", 2i32);
+        bus.publish("t", 3i32);
+
+        assert_eq!(sub.overflow_count(), 1);
+        let got: Vec<i32> = sub.drain().iter().map(|v| **v).collect();
+        assert_eq!(got, vec![1, 2]);
+    }
+
+    #[test]
+    fn runtime_subscription_adapter_forwards_messages() {
+        let bus = MessageBus::new();
+        let sub = bus
+            .subscribe::<i32>("t")
+            .into_runtime_subscription(|v| *v * 10);
+        let (tx, rx) = std::sync::mpsc::channel();
+        let (stop, trigger) = StopSignal::new();
+
+        let handle = std::thread::spawn(move || sub.run(tx, stop));
+        bus.publish("t", 4i32);
+        let msg = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(msg, 40);
+
+        trigger.stop();
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn topics_are_independent() {
+        let bus = MessageBus::new();
+        let a = bus.subscribe::<i32>("a");
+        let b = bus.subscribe::<i32>("b");
+
+        bus.publish("a", 1i32);
+        assert_eq!(a.try_recv().as_deref(), Some(&1));
+        assert!(b.try_recv().is_none());
+    }
+}
@@ -0,0 +1,450 @@
+#![forbid(unsafe_code)]
+
+//! Application model persistence: reopen where the user left off.
+//!
+//! Widgets already persist through the [`StateRegistry`]; this module
+//! adds the *model* layer on the same [`StorageBackend`] machinery
+//! (file-backed default with atomic write-rename, in-memory for tests):
+//!
+//! - the model (or a sub-struct) implements [`Persist`], producing and
+//!   consuming a versioned, namespaced [`PersistedState`];
+//! - [`Program::enable_model_persistence`] hydrates before `init()`
+//!   completes, saves on clean shutdown, and autosaves on a configurable
+//!   interval driven by the program clock (lab-testable through
+//!   [`ModelPersistence::maybe_autosave`]);
+//! - migration hooks keyed by version upgrade old state instead of
+//!   discarding it;
+//! - corrupt or unreadable state falls back to defaults with a surfaced
+//!   warning — never a startup failure.
+//!
+//! [`StateRegistry`]: crate::state_persistence::StateRegistry
+//! [`Program::enable_model_persistence`]: crate::program::Program::enable_model_persistence
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use web_time::{Duration, Instant};
+
+use crate::state_persistence::{StorageBackend, StoredEntry};
+
+/// Storage key for the model state inside the shared backend.
+const MODEL_STATE_KEY: &str = "model::state";
+
+/// Versioned, namespaced persisted values.
+///
+/// Values are stored as JSON under caller-chosen namespaced keys
+/// (convention: `"screen::selected"`, `"files::recent"`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedState {
+    /// Schema version written by [`Persist::state_version`].
+    version: u32,
+    /// Namespaced values.
+    values: BTreeMap<String, serde_json::Value>,
+}
+
+impl PersistedState {
+    /// Create an empty state at `version`.
+    #[must_use]
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// The schema version this state was written at.
+    #[must_use]
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Stamp a new schema version (migration hooks use this).
+    pub fn set_version(&mut self, version: u32) {
+        self.version = version;
+    }
+
+    /// Store a serializable value under a namespaced key.
+    ///
+    /// Unserializable values are skipped (persistence is best-effort by
+    /// contract; state must never take the app down).
+    pub fn set(&mut self, key: impl Into<String>, value: impl Serialize) {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.values.insert(key.into(), value);
+        }
+    }
+
+    /// Read a value back, `None` when absent or undeserializable.
+    #[must_use]
+    pub fn get<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Option<T> {
+        self.values
+            .get(key)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Remove a value, returning whether it existed.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.values.remove(key).is_some()
+    }
+
+    /// Rename a key (migration helper).
+    pub fn rename(&mut self, from: &str, to: impl Into<String>) {
+        if let Some(value) = self.values.remove(from) {
+            self.values.insert(to.into(), value);
+        }
+    }
+
+    /// The stored keys, sorted.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+}
+
+/// Implemented by the model (or a sub-struct) to participate in
+/// persistence.
+pub trait Persist {
+    /// Current schema version of the persisted representation.
+    fn state_version(&self) -> u32 {
+        1
+    }
+
+    /// Capture the state worth keeping between runs.
+    fn persist(&self) -> PersistedState;
+
+    /// Restore from a (version-matched, migrated) state.
+    fn hydrate(&mut self, state: PersistedState);
+}
+
+/// Migration hook: upgrades state *from* the keyed version to the next.
+type MigrationFn = Box<dyn Fn(PersistedState) -> PersistedState + Send + Sync>;
+
+/// Model persistence engine over a [`StorageBackend`].
+pub struct ModelPersistence {
+    backend: Box<dyn StorageBackend>,
+    migrations: BTreeMap<u32, MigrationFn>,
+    autosave_interval: Option<Duration>,
+    last_save: Instant,
+}
+
+impl std::fmt::Debug for ModelPersistence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModelPersistence")
+            .field("backend", &self.backend.name())
+            .field("migrations", &self.migrations.keys().collect::<Vec<_>>())
+            .field("autosave_interval", &self.autosave_interval)
+            .finish()
+    }
+}
+
+impl ModelPersistence {
+    /// Create over an arbitrary backend.
+    #[must_use]
+    pub fn new(backend: Box<dyn StorageBackend>) -> Self {
+        Self {
+            backend,
+            migrations: BTreeMap::new(),
+            autosave_interval: None,
+            last_save: Instant::now(),
+        }
+    }
+
+    /// File-backed storage at the platform default location for `app`.
+    #[must_use]
+    pub fn for_app(app_name: &str) -> Self {
+        Self::new(Box::new(
+            crate::state_persistence::FileStorage::default_for_app(app_name),
+        ))
+    }
+
+    /// File-backed storage at an explicit path (atomic write-rename).
+    #[must_use]
+    pub fn with_file(path: impl AsRef<std::path::Path>) -> Self {
+        Self::new(Box::new(crate::state_persistence::FileStorage::new(path)))
+    }
+
+    /// Register a migration from `from_version` to `from_version + 1`.
+    ///
+    /// On load, migrations chain upward until the model's current
+    /// version is reached; a gap in the chain stops migration and the
+    /// partially-upgraded state is discarded with a warning.
+    #[must_use]
+    pub fn with_migration(
+        mut self,
+        from_version: u32,
+        migrate: impl Fn(PersistedState) -> PersistedState + Send + Sync + 'static,
+    ) -> Self {
+        self.migrations.insert(from_version, Box::new(migrate));
+        self
+    }
+
+    /// Autosave interval (off by default).
+    #[must_use]
+    pub fn with_autosave(mut self, interval: Duration) -> Self {
+        self.autosave_interval = Some(interval);
+        self
+    }
+
+    /// Load and migrate state for a model expecting `target_version`.
+    ///
+    /// `Ok(None)` on first run. Corruption, backend failure, or an
+    /// unmigratable version all surface as `Err(warning)` — the caller
+    /// falls back to defaults and keeps starting up.
+    pub fn load(&self, target_version: u32) -> Result<Option<PersistedState>, String> {
+        let entries = self
+            .backend
+            .load_all()
+            .map_err(|e| format!("persisted state unreadable, using defaults: {e}"))?;
+        let Some(entry) = entries.get(MODEL_STATE_KEY) else {
+            return Ok(None);
+        };
+        let mut state: PersistedState = serde_json::from_slice(&entry.data)
+            .map_err(|e| format!("persisted state corrupt, using defaults: {e}"))?;
+
+        // Chain migrations upward.
+        while state.version() < target_version {
+            let Some(migrate) = self.migrations.get(&state.version()) else {
+                return Err(format!(
+                    "no migration from state version {} to {}, using defaults",
+                    state.version(),
+                    target_version
+                ));
+            };
+            let from = state.version();
+            state = migrate(state);
+            if state.version() <= from {
+                return Err(format!(
+                    "migration from version {from} did not advance the version, using defaults"
+                ));
+            }
+        }
+        if state.version() > target_version {
+            return Err(format!(
+                "persisted state version {} is newer than {}, using defaults",
+                state.version(),
+                target_version
+            ));
+        }
+        Ok(Some(state))
+    }
+
+    /// Save state (atomic for the file backend).
+    ///
+    /// Sibling entries in the backend (e.g. widget state sharing the
+    /// file) are preserved: only the model entry is replaced.
+    pub fn save(&mut self, state: &PersistedState) -> Result<(), String> {
+        let data = serde_json::to_vec(state).map_err(|e| e.to_string())?;
+        // Merge over existing entries; an unreadable store starts fresh.
+        let mut entries = self.backend.load_all().unwrap_or_default();
+        entries.insert(
+            MODEL_STATE_KEY.to_string(),
+            StoredEntry {
+                key: MODEL_STATE_KEY.to_string(),
+                version: state.version(),
+                data,
+            },
+        );
+        self.backend.save_all(&entries).map_err(|e| e.to_string())?;
+        self.last_save = Instant::now();
+        Ok(())
+    }
+
+    /// Autosave check driven by the program clock.
+    ///
+    /// Returns `true` when the interval elapsed and a save should run;
+    /// the caller then invokes [`save`](Self::save). Taking `now`
+    /// explicitly keeps the timing lab-testable.
+    #[must_use]
+    pub fn maybe_autosave(&self, now: Instant) -> bool {
+        self.autosave_interval
+            .is_some_and(|interval| now.duration_since(self.last_save) >= interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_persistence::FileStorage;
+    use std::path::PathBuf;
+
+    /// A model fragment implementing Persist.
+    #[derive(Debug, Default, PartialEq)]
+    struct Workspace {
+        selected_screen: u32,
+        recent: Vec<String>,
+    }
+
+    impl Persist for Workspace {
+        fn state_version(&self) -> u32 {
+            2
+        }
+
+        fn persist(&self) -> PersistedState {
+            let mut state = PersistedState::new(self.state_version());
+            state.set("screen::selected", self.selected_screen);
+            state.set("files::recent", &self.recent);
+            state
+        }
+
+        fn hydrate(&mut self, state: PersistedState) {
+            if let Some(screen) = state.get("screen::selected") {
+                self.selected_screen = screen;
+            }
+            if let Some(recent) = state.get("files::recent") {
+                self.recent = recent;
+            }
+        }
+    }
+
+    fn temp_state_path(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "ftui_model_persist_{label}_{}_{}.json",
+            std::process::id(),
+            web_time::Instant::now().elapsed().subsec_nanos()
+        ))
+    }
+
+    #[test]
+    fn round_trip_across_simulated_restart() {
+        let path = temp_state_path("roundtrip");
+
+        // First run: mutate and save on shutdown.
+        {
+            let mut persistence = ModelPersistence::with_file(&path);
+            let model = Workspace {
+                selected_screen: 7,
+                recent: vec!["a.rs".into(), "b.rs".into()],
+            };
+            persistence.save(&model.persist()).unwrap();
+        }
+
+        // "Restart": a fresh engine and default model hydrate.
+        {
+            let persistence = ModelPersistence::with_file(&path);
+            let mut model = Workspace::default();
+            let state = persistence
+                .load(model.state_version())
+                .unwrap()
+                .expect("state exists");
+            model.hydrate(state);
+            assert_eq!(model.selected_screen, 7);
+            assert_eq!(model.recent, vec!["a.rs", "b.rs"]);
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn autosave_timing_is_clock_driven() {
+        let mut persistence = ModelPersistence::with_file(temp_state_path("autosave"))
+            .with_autosave(Duration::from_secs(30));
+        let t0 = Instant::now();
+        persistence.last_save = t0;
+
+        assert!(!persistence.maybe_autosave(t0 + Duration::from_secs(29)));
+        assert!(persistence.maybe_autosave(t0 + Duration::from_secs(30)));
+        // After a save the window restarts.
+        persistence.last_save = t0 + Duration::from_secs(30);
+        assert!(!persistence.maybe_autosave(t0 + Duration::from_secs(59)));
+        assert!(persistence.maybe_autosave(t0 + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn crash_between_write_and_rename_is_ignored() {
+        let path = temp_state_path("crash");
+
+        // A good save, then a simulated crash that left a temp file with
+        // garbage (written but never renamed).
+        let mut persistence = ModelPersistence::with_file(&path);
+        let model = Workspace {
+            selected_screen: 3,
+            recent: vec![],
+        };
+        persistence.save(&model.persist()).unwrap();
+        let mut tmp = path.clone();
+        tmp.set_extension("json.tmp");
+        std::fs::write(&tmp, b"{ totally not json").unwrap();
+
+        // The orphaned temp file is ignored; the last good state loads.
+        let state = persistence.load(2).unwrap().expect("state");
+        let mut restored = Workspace::default();
+        restored.hydrate(state);
+        assert_eq!(restored.selected_screen, 3);
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&tmp);
+    }
+
+    #[test]
+    fn corrupt_state_surfaces_warning_not_failure() {
+        let path = temp_state_path("corrupt");
+        std::fs::write(&path, b"not json at all").unwrap();
+        let persistence = ModelPersistence::new(Box::new(FileStorage::new(&path)));
+        let err = persistence.load(1).unwrap_err();
+        assert!(err.contains("using defaults"), "got {err}");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn v1_to_v2_migration_upgrades_instead_of_discarding() {
+        let path = temp_state_path("migrate");
+
+        // v1 wrote "screen" (un-namespaced).
+        {
+            let mut persistence = ModelPersistence::with_file(&path);
+            let mut v1 = PersistedState::new(1);
+            v1.set("screen", 5u32);
+            persistence.save(&v1).unwrap();
+        }
+
+        // v2 expects "screen::selected"; the migration renames it.
+        let persistence = ModelPersistence::with_file(&path).with_migration(1, |mut state| {
+            state.rename("screen", "screen::selected");
+            state.set_version(2);
+            state
+        });
+        let state = persistence.load(2).unwrap().expect("state");
+        assert_eq!(state.version(), 2);
+        assert_eq!(state.get::<u32>("screen::selected"), Some(5));
+        assert_eq!(state.get::<u32>("screen"), None);
+
+        // Without the migration the old state is refused with a warning.
+        let bare = ModelPersistence::with_file(&path);
+        let err = bare.load(2).unwrap_err();
+        assert!(err.contains("no migration"), "got {err}");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn save_preserves_sibling_entries_in_a_shared_backend() {
+        let path = temp_state_path("shared");
+        let backend = FileStorage::new(&path);
+
+        // Another layer (e.g. the widget registry) already wrote state.
+        let mut entries = std::collections::HashMap::new();
+        entries.insert(
+            "widget::scroll".to_string(),
+            StoredEntry {
+                key: "widget::scroll".to_string(),
+                version: 1,
+                data: b"17".to_vec(),
+            },
+        );
+        backend.save_all(&entries).unwrap();
+
+        let mut persistence = ModelPersistence::new(Box::new(FileStorage::new(&path)));
+        persistence.save(&PersistedState::new(1)).unwrap();
+
+        let after = FileStorage::new(&path).load_all().unwrap();
+        assert!(after.contains_key("widget::scroll"), "sibling kept");
+        assert!(after.contains_key(super::MODEL_STATE_KEY));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn newer_state_version_is_refused_with_warning() {
+        let path = temp_state_path("newer");
+        let mut persistence = ModelPersistence::with_file(&path);
+        persistence.save(&PersistedState::new(9)).unwrap();
+        let err = persistence.load(2).unwrap_err();
+        assert!(err.contains("newer"), "got {err}");
+        let _ = std::fs::remove_file(&path);
+    }
+}
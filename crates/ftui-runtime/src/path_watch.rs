@@ -0,0 +1,637 @@
+#![forbid(unsafe_code)]
+
+//! Declarative file-watcher subscription with debounced change events.
+//!
+//! Config hot-reload, log tailing and live-preview editors all want "tell
+//! me when this path changes" without each spinning its own polling
+//! thread. [`PathWatcher`] is a [`Subscription`] built on the portable
+//! polling backend: per poll it snapshots mtime + size (plus a content
+//! hash for small files, where mtime granularity is too coarse), diffs
+//! against the previous snapshot, and delivers debounced
+//! [`PathChanged`] messages. Paths that don't exist yet produce `Created`
+//! when they appear; delete/recreate between polls collapses to
+//! `Modified`. All waits go through the [`StopSignal`], so reconcile and
+//! shutdown interrupt a poll immediately — there is no full
+//! poll-interval sleep blocking teardown.
+//!
+//! Recursive directory watching caps the number of tracked entries
+//! ([`WatchOptions::max_entries`]); exceeding the cap reports a single
+//! [`PathChangeKind::Overflow`] event for the root instead of silently
+//! dropping changes.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::SystemTime;
+
+use web_time::{Duration, Instant};
+
+use crate::subscription::{StopSignal, SubId, Subscription};
+
+/// What changed about a watched path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathChangeKind {
+    /// The path appeared (including paths that did not exist at start).
+    Created,
+    /// Content or metadata changed.
+    Modified,
+    /// The path disappeared.
+    Deleted,
+    /// Recursive watching exceeded [`WatchOptions::max_entries`]; changes
+    /// beyond the cap are not tracked individually.
+    Overflow,
+}
+
+/// A debounced change notification for a watched path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathChanged {
+    /// The path that changed (the root for [`PathChangeKind::Overflow`]).
+    pub path: PathBuf,
+    /// What happened to it.
+    pub kind: PathChangeKind,
+}
+
+/// Which change kinds to deliver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchKinds {
+    /// Deliver [`PathChangeKind::Created`].
+    pub created: bool,
+    /// Deliver [`PathChangeKind::Modified`].
+    pub modified: bool,
+    /// Deliver [`PathChangeKind::Deleted`].
+    pub deleted: bool,
+}
+
+impl Default for WatchKinds {
+    fn default() -> Self {
+        Self {
+            created: true,
+            modified: true,
+            deleted: true,
+        }
+    }
+}
+
+impl WatchKinds {
+    fn allows(&self, kind: PathChangeKind) -> bool {
+        match kind {
+            PathChangeKind::Created => self.created,
+            PathChangeKind::Modified => self.modified,
+            PathChangeKind::Deleted => self.deleted,
+            // Overflow is a watcher health signal, never filtered.
+            PathChangeKind::Overflow => true,
+        }
+    }
+}
+
+/// Options for [`PathWatcher`].
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Walk directories recursively; otherwise only the path itself is
+    /// watched.
+    pub recursive: bool,
+    /// Quiet period after the last detected change before events are
+    /// delivered; bursts within the window collapse into one message per
+    /// path.
+    pub debounce: Duration,
+    /// How often the path is polled.
+    pub poll_interval: Duration,
+    /// Which change kinds to deliver.
+    pub kinds: WatchKinds,
+    /// Also hash the content of files at most this many bytes, catching
+    /// edits within mtime granularity. `None` disables hashing.
+    pub content_hash_limit: Option<u64>,
+    /// Cap on tracked entries under `recursive`; exceeding it emits
+    /// [`PathChangeKind::Overflow`] once until the count drops back.
+    pub max_entries: usize,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            recursive: false,
+            debounce: Duration::from_millis(100),
+            poll_interval: Duration::from_millis(250),
+            kinds: WatchKinds::default(),
+            content_hash_limit: Some(64 * 1024),
+            max_entries: 10_000,
+        }
+    }
+}
+
+/// Create a file-watcher subscription (see [`PathWatcher`]).
+pub fn watch_path<M: Send + 'static>(
+    path: impl Into<PathBuf>,
+    options: WatchOptions,
+    make_msg: impl Fn(PathChanged) -> M + Send + Sync + 'static,
+) -> PathWatcher<M> {
+    PathWatcher::new(path, options, make_msg)
+}
+
+/// Polling file-watcher subscription.
+///
+/// The subscription ID is derived from the path and options, so
+/// reconciles with an unchanged declaration neither restart the watcher
+/// nor replay events.
+pub struct PathWatcher<M: Send + 'static> {
+    id: SubId,
+    path: PathBuf,
+    options: WatchOptions,
+    make_msg: Box<dyn Fn(PathChanged) -> M + Send + Sync>,
+}
+
+impl<M: Send + 'static> PathWatcher<M> {
+    /// Create a watcher for `path` with the given options.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        options: WatchOptions,
+        make_msg: impl Fn(PathChanged) -> M + Send + Sync + 'static,
+    ) -> Self {
+        let path = path.into();
+        // Every option participates in the ID: a changed declaration must
+        // not be mistaken for the running watcher during reconcile.
+        let mut id = fnv1a64(path.as_os_str().as_encoded_bytes());
+        id ^= u64::from(options.recursive) << 63;
+        id ^= (options.debounce.as_nanos() as u64).rotate_left(21);
+        id ^= (options.poll_interval.as_nanos() as u64).rotate_left(42);
+        id ^= u64::from(options.kinds.created) << 60;
+        id ^= u64::from(options.kinds.modified) << 61;
+        id ^= u64::from(options.kinds.deleted) << 62;
+        id ^= (options.max_entries as u64).rotate_left(7);
+        id ^= options
+            .content_hash_limit
+            .map_or(0x5eed, |limit| limit.rotate_left(33));
+        Self {
+            id,
+            path,
+            options,
+            make_msg: Box::new(make_msg),
+        }
+    }
+
+    /// Create with an explicit ID (for deduplication control).
+    pub fn with_id(mut self, id: SubId) -> Self {
+        self.id = id;
+        self
+    }
+}
+
+/// Identity signature of one filesystem entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileSig {
+    mtime: Option<SystemTime>,
+    size: u64,
+    hash: Option<u64>,
+}
+
+/// Snapshot of everything under the watched path, plus the overflow flag.
+struct Snapshot {
+    entries: BTreeMap<PathBuf, FileSig>,
+    overflowed: bool,
+}
+
+impl<M: Send + 'static> Subscription<M> for PathWatcher<M> {
+    fn id(&self) -> SubId {
+        self.id
+    }
+
+    fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal) {
+        let mut prev = take_snapshot(&self.path, &self.options);
+        let mut overflow_reported = prev.overflowed;
+        // Report overflow present from the start.
+        if prev.overflowed
+            && sender
+                .send((self.make_msg)(PathChanged {
+                    path: self.path.clone(),
+                    kind: PathChangeKind::Overflow,
+                }))
+                .is_err()
+        {
+            return;
+        }
+
+        // Pending (path -> kind) collected during the debounce window.
+        let mut pending: BTreeMap<PathBuf, PathChangeKind> = BTreeMap::new();
+        let mut quiet_since: Option<Instant> = None;
+
+        loop {
+            // Wait one poll interval, or less while a debounce window is
+            // open; the stop signal interrupts either immediately.
+            let wait = match quiet_since {
+                Some(since) => {
+                    let deadline = since + self.options.debounce;
+                    deadline
+                        .saturating_duration_since(Instant::now())
+                        .min(self.options.poll_interval)
+                        .max(Duration::from_millis(1))
+                }
+                None => self.options.poll_interval,
+            };
+            if stop.wait_timeout(wait) {
+                return;
+            }
+
+            let current = take_snapshot(&self.path, &self.options);
+            let changes = diff_snapshots(&prev, &current);
+            if !changes.is_empty() {
+                for (path, kind) in changes {
+                    merge_pending(&mut pending, path, kind);
+                }
+                quiet_since = Some(Instant::now());
+            }
+
+            // Overflow edge: report once when entering the overflowed state.
+            if current.overflowed
+                && !overflow_reported
+                && sender
+                    .send((self.make_msg)(PathChanged {
+                        path: self.path.clone(),
+                        kind: PathChangeKind::Overflow,
+                    }))
+                    .is_err()
+            {
+                return;
+            }
+            overflow_reported = current.overflowed;
+            prev = current;
+
+            // Debounce expired with no new changes: flush.
+            if let Some(since) = quiet_since
+                && since.elapsed() >= self.options.debounce
+            {
+                for (path, kind) in std::mem::take(&mut pending) {
+                    if !self.options.kinds.allows(kind) {
+                        continue;
+                    }
+                    if stop.is_stopped() {
+                        return;
+                    }
+                    if sender
+                        .send((self.make_msg)(PathChanged { path, kind }))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                quiet_since = None;
+            }
+        }
+    }
+}
+
+/// Merge a new observation into the pending set, collapsing sequences
+/// that cancel or supersede each other across polls.
+fn merge_pending(
+    pending: &mut BTreeMap<PathBuf, PathChangeKind>,
+    path: PathBuf,
+    kind: PathChangeKind,
+) {
+    use PathChangeKind::*;
+    match (pending.get(&path).copied(), kind) {
+        // Created then deleted within the window: nothing happened.
+        (Some(Created), Deleted) => {
+            pending.remove(&path);
+        }
+        // Created then modified is still "created" to the observer.
+        (Some(Created), Modified) => {}
+        // Deleted then created again: the content may differ.
+        (Some(Deleted), Created) => {
+            pending.insert(path, Modified);
+        }
+        _ => {
+            pending.insert(path, kind);
+        }
+    }
+}
+
+fn diff_snapshots(prev: &Snapshot, current: &Snapshot) -> Vec<(PathBuf, PathChangeKind)> {
+    let mut changes = Vec::new();
+    for (path, sig) in &current.entries {
+        match prev.entries.get(path) {
+            None => changes.push((path.clone(), PathChangeKind::Created)),
+            Some(old) if old != sig => changes.push((path.clone(), PathChangeKind::Modified)),
+            Some(_) => {}
+        }
+    }
+    for path in prev.entries.keys() {
+        if !current.entries.contains_key(path) {
+            changes.push((path.clone(), PathChangeKind::Deleted));
+        }
+    }
+    changes
+}
+
+fn take_snapshot(root: &Path, options: &WatchOptions) -> Snapshot {
+    let mut snapshot = Snapshot {
+        entries: BTreeMap::new(),
+        overflowed: false,
+    };
+    if options.recursive && root.is_dir() {
+        walk(root, options, &mut snapshot);
+    } else if let Some(sig) = entry_sig(root, options) {
+        snapshot.entries.insert(root.to_path_buf(), sig);
+    }
+    snapshot
+}
+
+fn walk(dir: &Path, options: &WatchOptions, snapshot: &mut Snapshot) {
+    let Ok(read) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read.flatten() {
+        if snapshot.entries.len() >= options.max_entries {
+            snapshot.overflowed = true;
+            return;
+        }
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            walk(&path, options, snapshot);
+            if snapshot.overflowed {
+                return;
+            }
+        } else if let Some(sig) = entry_sig(&path, options) {
+            snapshot.entries.insert(path, sig);
+        }
+    }
+}
+
+/// Signature for one entry; `None` when the entry vanished mid-walk
+/// (a delete racing the poll reads as "missing", i.e. deleted).
+fn entry_sig(path: &Path, options: &WatchOptions) -> Option<FileSig> {
+    let meta = std::fs::metadata(path).ok()?;
+    let size = meta.len();
+    let hash = match options.content_hash_limit {
+        Some(limit) if meta.is_file() && size <= limit => {
+            std::fs::read(path).ok().map(|bytes| fnv1a64(&bytes))
+        }
+        _ => None,
+    };
+    Some(FileSig {
+        mtime: meta.modified().ok(),
+        size,
+        hash,
+    })
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x1000_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    fn temp_watch_dir(label: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir =
+            std::env::temp_dir().join(format!("ftui_watch_{label}_{}_{seq}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn fast_options() -> WatchOptions {
+        WatchOptions {
+            poll_interval: Duration::from_millis(10),
+            debounce: Duration::from_millis(40),
+            ..Default::default()
+        }
+    }
+
+    fn spawn_watcher(
+        path: PathBuf,
+        options: WatchOptions,
+    ) -> (
+        mpsc::Receiver<PathChanged>,
+        crate::subscription::StopSignal,
+        impl FnOnce(),
+        thread::JoinHandle<()>,
+    ) {
+        let watcher = PathWatcher::new(path, options, |event| event);
+        let (tx, rx) = mpsc::channel();
+        let (signal, trigger) = StopSignal::new();
+        let run_signal = signal.clone();
+        let handle = thread::spawn(move || watcher.run(tx, run_signal));
+        (rx, signal, move || trigger.stop(), handle)
+    }
+
+    fn collect_for(rx: &mpsc::Receiver<PathChanged>, window: Duration) -> Vec<PathChanged> {
+        let deadline = Instant::now() + window;
+        let mut events = Vec::new();
+        while Instant::now() < deadline {
+            if let Ok(event) = rx.recv_timeout(Duration::from_millis(10)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    #[test]
+    fn file_created_after_start_reports_created() {
+        let dir = temp_watch_dir("create");
+        let file = dir.join("config.toml");
+        let (rx, _signal, stop, handle) = spawn_watcher(file.clone(), fast_options());
+
+        thread::sleep(Duration::from_millis(30));
+        std::fs::write(&file, b"hello").unwrap();
+
+        let events = collect_for(&rx, Duration::from_millis(200));
+        assert_eq!(
+            events,
+            vec![PathChanged {
+                path: file,
+                kind: PathChangeKind::Created
+            }]
+        );
+
+        stop();
+        handle.join().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn modification_burst_debounces_to_single_event() {
+        let dir = temp_watch_dir("burst");
+        let file = dir.join("log.txt");
+        std::fs::write(&file, b"initial").unwrap();
+        let (rx, _signal, stop, handle) = spawn_watcher(file.clone(), fast_options());
+
+        thread::sleep(Duration::from_millis(30));
+        for i in 0..5 {
+            std::fs::write(&file, format!("content {i}")).unwrap();
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        let events = collect_for(&rx, Duration::from_millis(250));
+        assert_eq!(
+            events,
+            vec![PathChanged {
+                path: file,
+                kind: PathChangeKind::Modified
+            }],
+            "burst must collapse into one event"
+        );
+
+        stop();
+        handle.join().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn deletion_reports_deleted_and_recreation_collapses_to_modified() {
+        let dir = temp_watch_dir("delete");
+        let file = dir.join("data.bin");
+        std::fs::write(&file, b"v1").unwrap();
+        let (rx, _signal, stop, handle) = spawn_watcher(file.clone(), fast_options());
+
+        thread::sleep(Duration::from_millis(30));
+        std::fs::remove_file(&file).unwrap();
+        let events = collect_for(&rx, Duration::from_millis(200));
+        assert_eq!(
+            events,
+            vec![PathChanged {
+                path: file.clone(),
+                kind: PathChangeKind::Deleted
+            }]
+        );
+
+        // Re-create and let the Created event flush first.
+        std::fs::write(&file, b"v2").unwrap();
+        let events = collect_for(&rx, Duration::from_millis(200));
+        assert_eq!(events.len(), 1, "got {events:?}");
+        assert_eq!(events[0].kind, PathChangeKind::Created);
+
+        // Delete + recreate of an existing file inside one debounce
+        // window collapses to Modified.
+        std::fs::remove_file(&file).unwrap();
+        thread::sleep(Duration::from_millis(15));
+        std::fs::write(&file, b"v3").unwrap();
+        let events = collect_for(&rx, Duration::from_millis(250));
+        assert_eq!(events.len(), 1, "got {events:?}");
+        assert_eq!(events[0].kind, PathChangeKind::Modified);
+
+        stop();
+        handle.join().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn cancellation_interrupts_long_poll_promptly() {
+        let dir = temp_watch_dir("cancel");
+        let options = WatchOptions {
+            poll_interval: Duration::from_secs(30),
+            ..Default::default()
+        };
+        let (_rx, _signal, stop, handle) = spawn_watcher(dir.clone(), options);
+
+        thread::sleep(Duration::from_millis(20));
+        let start = Instant::now();
+        stop();
+        handle.join().unwrap();
+        assert!(
+            start.elapsed() < Duration::from_millis(500),
+            "stop must interrupt the poll wait, not ride it out"
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn recursive_overflow_reports_indicator() {
+        let dir = temp_watch_dir("overflow");
+        for i in 0..5 {
+            std::fs::write(dir.join(format!("f{i}")), b"x").unwrap();
+        }
+        let options = WatchOptions {
+            recursive: true,
+            max_entries: 2,
+            poll_interval: Duration::from_millis(10),
+            debounce: Duration::from_millis(30),
+            ..Default::default()
+        };
+        let (rx, _signal, stop, handle) = spawn_watcher(dir.clone(), options);
+
+        let events = collect_for(&rx, Duration::from_millis(150));
+        assert!(
+            events
+                .iter()
+                .any(|e| e.kind == PathChangeKind::Overflow && e.path == dir),
+            "expected overflow indicator, got {events:?}"
+        );
+
+        stop();
+        handle.join().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn recursive_watch_sees_nested_changes() {
+        let dir = temp_watch_dir("nested");
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        let options = WatchOptions {
+            recursive: true,
+            ..fast_options()
+        };
+        let (rx, _signal, stop, handle) = spawn_watcher(dir.clone(), options);
+
+        thread::sleep(Duration::from_millis(30));
+        let nested = dir.join("sub/new.txt");
+        std::fs::write(&nested, b"hi").unwrap();
+
+        let events = collect_for(&rx, Duration::from_millis(200));
+        assert_eq!(
+            events,
+            vec![PathChanged {
+                path: nested,
+                kind: PathChangeKind::Created
+            }]
+        );
+
+        stop();
+        handle.join().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn kinds_filter_suppresses_events() {
+        let dir = temp_watch_dir("filter");
+        let file = dir.join("f");
+        let options = WatchOptions {
+            kinds: WatchKinds {
+                created: false,
+                modified: true,
+                deleted: true,
+            },
+            ..fast_options()
+        };
+        let (rx, _signal, stop, handle) = spawn_watcher(file.clone(), options);
+
+        thread::sleep(Duration::from_millis(30));
+        std::fs::write(&file, b"appears").unwrap();
+        let events = collect_for(&rx, Duration::from_millis(200));
+        assert!(events.is_empty(), "Created filtered out, got {events:?}");
+
+        stop();
+        handle.join().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn id_is_stable_for_same_declaration() {
+        let a = PathWatcher::new("/tmp/x", WatchOptions::default(), |e: PathChanged| e);
+        let b = PathWatcher::new("/tmp/x", WatchOptions::default(), |e: PathChanged| e);
+        assert_eq!(a.id(), b.id());
+        let c = PathWatcher::new("/tmp/y", WatchOptions::default(), |e: PathChanged| e);
+        assert_ne!(a.id(), c.id());
+    }
+}
@@ -0,0 +1,279 @@
+#![forbid(unsafe_code)]
+
+//! Privacy redaction for telemetry, evidence and session recordings.
+//!
+//! Key events carry the typed characters, so every sink that persists
+//! input — the asciicast input track, evidence JSONL, host-facing log
+//! drains — is a potential leak. [`TelemetryPrivacyPolicy`] is applied
+//! *before* an event reaches any sink (the app itself always sees the
+//! real event):
+//!
+//! - [`Full`](TelemetryPrivacyPolicy::Full) — record events verbatim.
+//! - [`KeysOnly`](TelemetryPrivacyPolicy::KeysOnly) — the default: key
+//!   codes are recorded, but character payloads are replaced with a
+//!   length + class summary (`alpha`/`digit`/`punct`/`space`/`other`),
+//!   and pastes reduce to byte counts.
+//! - [`Off`](TelemetryPrivacyPolicy::Off) — input is not recorded at all.
+//!
+//! Focused secret widgets (masked text inputs) force at least `KeysOnly`
+//! for their focused duration regardless of a `Full` global mode; `Off`
+//! stays off (stricter always wins). Runtime policy changes emit a
+//! marker so recordings are self-describing.
+
+use ftui_core::event::{Event, KeyCode, KeyEventKind};
+
+/// What input sinks are allowed to see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TelemetryPrivacyPolicy {
+    /// Record input events verbatim (typed characters included).
+    Full,
+    /// Record key codes and payload shape, never the content.
+    #[default]
+    KeysOnly,
+    /// Record no input at all.
+    Off,
+}
+
+impl TelemetryPrivacyPolicy {
+    /// Stable identifier used in marker events and evidence lines.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::KeysOnly => "keys_only",
+            Self::Off => "off",
+        }
+    }
+
+    /// The effective policy while a secret widget (masked input) holds
+    /// focus: at least as strict as `KeysOnly`, never looser.
+    #[must_use]
+    pub fn under_secret_scope(self) -> Self {
+        match self {
+            Self::Full => Self::KeysOnly,
+            other => other,
+        }
+    }
+
+    /// Encode an input event for a recording sink under this policy.
+    ///
+    /// Returns `None` when the event must not be recorded (policy `Off`,
+    /// releases, or event kinds with no input representation).
+    #[must_use]
+    pub fn encode_event_for_recording(self, event: &Event) -> Option<Vec<u8>> {
+        match self {
+            Self::Off => None,
+            Self::Full => encode_full(event),
+            Self::KeysOnly => encode_keys_only(event),
+        }
+    }
+
+    /// Marker bytes recorded when the policy changes at runtime, so a
+    /// recording documents which redaction applied from that point on.
+    #[must_use]
+    pub fn change_marker(self) -> Vec<u8> {
+        format!("<<privacy-mode:{}>>", self.as_str()).into_bytes()
+    }
+}
+
+/// Verbatim encoding (previous behavior of the session recorder).
+fn encode_full(event: &Event) -> Option<Vec<u8>> {
+    match event {
+        Event::Key(key) if key.kind != KeyEventKind::Release => match key.code {
+            KeyCode::Char(c) => {
+                let mut buf = [0u8; 4];
+                Some(c.encode_utf8(&mut buf).as_bytes().to_vec())
+            }
+            KeyCode::Enter => Some(b"\r".to_vec()),
+            KeyCode::Tab => Some(b"\t".to_vec()),
+            KeyCode::Backspace => Some(b"\x7f".to_vec()),
+            KeyCode::Escape => Some(b"\x1b".to_vec()),
+            _ => None,
+        },
+        Event::Paste(paste) => Some(paste.text.clone().into_bytes()),
+        _ => None,
+    }
+}
+
+/// Key-code-only encoding: payload shape, never content.
+fn encode_keys_only(event: &Event) -> Option<Vec<u8>> {
+    match event {
+        Event::Key(key) if key.kind != KeyEventKind::Release => match key.code {
+            KeyCode::Char(c) => Some(format!("<key:{}>", char_class(c)).into_bytes()),
+            KeyCode::Enter => Some(b"<key:enter>".to_vec()),
+            KeyCode::Tab => Some(b"<key:tab>".to_vec()),
+            KeyCode::Backspace => Some(b"<key:backspace>".to_vec()),
+            KeyCode::Escape => Some(b"<key:escape>".to_vec()),
+            _ => None,
+        },
+        Event::Paste(paste) => Some(format!("<paste:{}B>", paste.text.len()).into_bytes()),
+        _ => None,
+    }
+}
+
+/// Coarse character class for `KeysOnly` summaries.
+fn char_class(c: char) -> &'static str {
+    if c.is_alphabetic() {
+        "alpha"
+    } else if c.is_ascii_digit() {
+        "digit"
+    } else if c.is_whitespace() {
+        "space"
+    } else if c.is_ascii_punctuation() {
+        "punct"
+    } else {
+        "other"
+    }
+}
+
+/// Redact a host-facing log line under a policy.
+///
+/// Logs are free-form, so redaction is structural: under `KeysOnly`,
+/// double-quoted payloads (the common vector for typed text reaching
+/// tracing output) are replaced with a byte count; under `Off` the line
+/// is dropped entirely. Deterministic and single-pass.
+#[must_use]
+pub fn redact_log_line(line: &str, policy: TelemetryPrivacyPolicy) -> Option<String> {
+    match policy {
+        TelemetryPrivacyPolicy::Full => Some(line.to_string()),
+        TelemetryPrivacyPolicy::Off => None,
+        TelemetryPrivacyPolicy::KeysOnly => {
+            let mut out = String::with_capacity(line.len());
+            let mut rest = line;
+            while let Some(start) = rest.find('"') {
+                out.push_str(&rest[..start]);
+                let after = &rest[start + 1..];
+                let Some(end) = after.find('"') else {
+                    // Unterminated quote (truncated line): mask the tail
+                    // rather than leaking a partial payload.
+                    out.push_str(&format!("\"<redacted:{}B>", after.len()));
+                    rest = "";
+                    break;
+                };
+                out.push_str(&format!("\"<redacted:{}B>\"", after[..end].len()));
+                rest = &after[end + 1..];
+            }
+            out.push_str(rest);
+            Some(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::{KeyEvent, Modifiers, PasteEvent};
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        })
+    }
+
+    fn typed_session() -> Vec<Event> {
+        vec![
+            key('h'),
+            key('1'),
+            key('!'),
+            key(' '),
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                modifiers: Modifiers::empty(),
+                kind: KeyEventKind::Press,
+                ch: None,
+            }),
+        ]
+    }
+
+    fn record(policy: TelemetryPrivacyPolicy, events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .filter_map(|e| policy.encode_event_for_recording(e))
+            .map(|b| String::from_utf8(b).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn full_mode_records_verbatim() {
+        let out = record(TelemetryPrivacyPolicy::Full, &typed_session());
+        assert_eq!(out, vec!["h", "1", "!", " ", "\r"]);
+    }
+
+    #[test]
+    fn keys_only_mode_records_classes() {
+        let out = record(TelemetryPrivacyPolicy::KeysOnly, &typed_session());
+        assert_eq!(
+            out,
+            vec![
+                "<key:alpha>",
+                "<key:digit>",
+                "<key:punct>",
+                "<key:space>",
+                "<key:enter>",
+            ]
+        );
+    }
+
+    #[test]
+    fn off_mode_records_nothing() {
+        let out = record(TelemetryPrivacyPolicy::Off, &typed_session());
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn paste_redacts_to_byte_count() {
+        let paste = Event::Paste(PasteEvent::bracketed("hunter2secret"));
+        let keys_only = TelemetryPrivacyPolicy::KeysOnly
+            .encode_event_for_recording(&paste)
+            .unwrap();
+        assert_eq!(keys_only, b"<paste:13B>");
+        let full = TelemetryPrivacyPolicy::Full
+            .encode_event_for_recording(&paste)
+            .unwrap();
+        assert_eq!(full, b"hunter2secret");
+        assert!(
+            TelemetryPrivacyPolicy::Off
+                .encode_event_for_recording(&paste)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn secret_scope_forces_at_least_keys_only() {
+        use TelemetryPrivacyPolicy::*;
+        assert_eq!(Full.under_secret_scope(), KeysOnly);
+        assert_eq!(KeysOnly.under_secret_scope(), KeysOnly);
+        assert_eq!(Off.under_secret_scope(), Off);
+    }
+
+    #[test]
+    fn change_marker_names_the_mode() {
+        assert_eq!(
+            TelemetryPrivacyPolicy::KeysOnly.change_marker(),
+            b"<<privacy-mode:keys_only>>"
+        );
+    }
+
+    #[test]
+    fn log_line_redaction_masks_quoted_payloads() {
+        let line = r#"input: key Char("x") text="hello" done"#;
+        let redacted = redact_log_line(line, TelemetryPrivacyPolicy::KeysOnly).unwrap();
+        assert_eq!(
+            redacted,
+            r#"input: key Char("<redacted:1B>") text="<redacted:5B>" done"#
+        );
+        assert_eq!(
+            redact_log_line(line, TelemetryPrivacyPolicy::Full).as_deref(),
+            Some(line)
+        );
+        assert!(redact_log_line(line, TelemetryPrivacyPolicy::Off).is_none());
+
+        // Truncated lines must not leak the partial payload either.
+        let truncated = r#"input text="hunter2"#;
+        let redacted = redact_log_line(truncated, TelemetryPrivacyPolicy::KeysOnly).unwrap();
+        assert!(!redacted.contains("hunter2"), "got {redacted:?}");
+    }
+}
@@ -60,6 +60,7 @@ use crate::evidence_telemetry::{
 use crate::input_fairness::{FairnessDecision, FairnessEventType, InputFairnessGuard};
 use crate::input_macro::{EventRecorder, InputMacro};
 use crate::locale::LocaleContext;
+use crate::privacy::TelemetryPrivacyPolicy;
 use crate::queueing_scheduler::{EstimateSource, QueueingScheduler, SchedulerConfig, WeightSource};
 use crate::render_trace::RenderTraceConfig;
 use crate::resize_coalescer::{CoalesceAction, CoalescerConfig, ResizeCoalescer};
@@ -273,6 +274,33 @@ pub enum Cmd<M> {
     /// Instructs the terminal session to enable or disable mouse event capture.
     /// No-op in test simulators.
     SetMouseCapture(bool),
+    /// Cooperatively suspend the process (Ctrl+Z semantics).
+    ///
+    /// Tears down terminal modes, stops the process via SIGTSTP, and on
+    /// resume re-applies every mode, re-queries the size, and forces a
+    /// full repaint. Unsupported backends log a warning and continue.
+    Suspend,
+    /// Append a progress line to the startup splash.
+    ///
+    /// No-op once startup has completed or when no splash is configured
+    /// (see [`Program::enable_startup_splash`]).
+    SplashProgress(String),
+    /// End the startup splash phase.
+    ///
+    /// Replays input queued during the splash and hands rendering back
+    /// to the model's view. No-op when no splash is active.
+    CompleteStartup,
+    /// Execute a future on the async command executor (feature `async`).
+    ///
+    /// The resolved message is delivered through the normal queue. The
+    /// optional duration/message pair configures a deadline: on expiry the
+    /// future is dropped and the timeout message delivered instead.
+    #[cfg(feature = "async")]
+    Future(
+        crate::async_cmd::BoxMsgFuture<M>,
+        Option<Duration>,
+        Option<Box<M>>,
+    ),
 }
 
 impl<M: std::fmt::Debug> std::fmt::Debug for Cmd<M> {
@@ -289,6 +317,13 @@ impl<M: std::fmt::Debug> std::fmt::Debug for Cmd<M> {
             Self::SaveState => write!(f, "SaveState"),
             Self::RestoreState => write!(f, "RestoreState"),
             Self::SetMouseCapture(b) => write!(f, "SetMouseCapture({b})"),
+            Self::Suspend => write!(f, "Suspend"),
+            Self::SplashProgress(s) => f.debug_tuple("SplashProgress").field(s).finish(),
+            Self::CompleteStartup => write!(f, "CompleteStartup"),
+            #[cfg(feature = "async")]
+            Self::Future(_, timeout, _) => {
+                f.debug_struct("Future").field("timeout", timeout).finish()
+            }
         }
     }
 }
@@ -312,6 +347,24 @@ impl<M> Cmd<M> {
         Self::Msg(m)
     }
 
+    /// Create a suspend command (Ctrl+Z semantics; see `Cmd::Suspend`).
+    #[inline]
+    pub fn suspend() -> Self {
+        Self::Suspend
+    }
+
+    /// Create a splash progress command (see `Cmd::SplashProgress`).
+    #[inline]
+    pub fn splash_progress(text: impl Into<String>) -> Self {
+        Self::SplashProgress(text.into())
+    }
+
+    /// Create a startup-complete command (see `Cmd::CompleteStartup`).
+    #[inline]
+    pub fn complete_startup() -> Self {
+        Self::CompleteStartup
+    }
+
     /// Create a log command.
     ///
     /// The message will be sanitized and written to the terminal log (scrollback).
@@ -332,6 +385,49 @@ impl<M> Cmd<M> {
         }
     }
 
+    /// Execute a future as a command (feature `async`).
+    ///
+    /// The future runs on the runtime's async executor (or an injected
+    /// [`Spawner`](crate::async_cmd::Spawner)); its output message is
+    /// delivered through the normal queue on a subsequent update cycle.
+    #[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+    pub fn future<F>(fut: F) -> Self
+    where
+        F: std::future::Future<Output = M> + Send + 'static,
+    {
+        Self::Future(Box::pin(fut), None, None)
+    }
+
+    /// Execute a future as a command (feature `async`, wasm).
+    #[cfg(all(feature = "async", target_arch = "wasm32"))]
+    pub fn future<F>(fut: F) -> Self
+    where
+        F: std::future::Future<Output = M> + 'static,
+    {
+        Self::Future(Box::pin(fut), None, None)
+    }
+
+    /// Execute a future with a deadline (feature `async`).
+    ///
+    /// If the future hasn't resolved within `timeout`, it is dropped and
+    /// `timeout_msg` is delivered instead.
+    #[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+    pub fn future_with_timeout<F>(fut: F, timeout: Duration, timeout_msg: M) -> Self
+    where
+        F: std::future::Future<Output = M> + Send + 'static,
+    {
+        Self::Future(Box::pin(fut), Some(timeout), Some(Box::new(timeout_msg)))
+    }
+
+    /// Execute a future with a deadline (feature `async`, wasm).
+    #[cfg(all(feature = "async", target_arch = "wasm32"))]
+    pub fn future_with_timeout<F>(fut: F, timeout: Duration, timeout_msg: M) -> Self
+    where
+        F: std::future::Future<Output = M> + 'static,
+    {
+        Self::Future(Box::pin(fut), Some(timeout), Some(Box::new(timeout_msg)))
+    }
+
     /// Create a sequence of commands.
     pub fn sequence(cmds: Vec<Self>) -> Self {
         if cmds.is_empty() {
@@ -358,6 +454,11 @@ impl<M> Cmd<M> {
             Self::SaveState => "SaveState",
             Self::RestoreState => "RestoreState",
             Self::SetMouseCapture(_) => "SetMouseCapture",
+            Self::Suspend => "Suspend",
+            Self::SplashProgress(_) => "SplashProgress",
+            Self::CompleteStartup => "CompleteStartup",
+            #[cfg(feature = "async")]
+            Self::Future(..) => "Future",
         }
     }
 
@@ -2020,6 +2121,22 @@ pub struct ProgramConfig {
     /// Defaults to `true` for application safety. Set to `false` in tests or
     /// when the embedding application manages signals.
     pub intercept_signals: bool,
+    /// Hide the cursor while the terminal window is unfocused (requires
+    /// focus reporting to observe focus changes).
+    pub hide_cursor_when_unfocused: bool,
+    /// Redaction applied before input events reach telemetry/evidence/
+    /// recording sinks (never the app itself). Defaults to the safe
+    /// [`TelemetryPrivacyPolicy::KeysOnly`].
+    pub privacy: TelemetryPrivacyPolicy,
+    /// Idle detection and power-saving tick throttling (None = off).
+    pub idle: Option<crate::idle::IdleConfig>,
+    /// Suspend the app on Ctrl+Z (cooperative SIGTSTP/SIGCONT handling).
+    pub enable_suspend: bool,
+    /// Opt-in asciicast session recording (also via `FTUI_ASCIICAST`).
+    pub session_recording: crate::asciicast::SessionRecordingConfig,
+    /// Spawner for async commands (feature `async`).
+    #[cfg(feature = "async")]
+    pub async_spawner: crate::async_cmd::SharedSpawner,
 }
 
 impl Default for ProgramConfig {
@@ -2048,6 +2165,13 @@ impl Default for ProgramConfig {
             effect_queue: EffectQueueConfig::default(),
             guardrails: GuardrailsConfig::default(),
             intercept_signals: true,
+            hide_cursor_when_unfocused: true,
+            privacy: TelemetryPrivacyPolicy::default(),
+            idle: None,
+            enable_suspend: false,
+            session_recording: crate::asciicast::SessionRecordingConfig::default(),
+            #[cfg(feature = "async")]
+            async_spawner: crate::async_cmd::SharedSpawner::default(),
         }
     }
 }
@@ -2133,6 +2257,34 @@ impl ProgramConfig {
         self
     }
 
+    /// Enable Ctrl+Z cooperative suspend (SIGTSTP/SIGCONT).
+    #[must_use]
+    pub fn with_suspend(mut self, enabled: bool) -> Self {
+        self.enable_suspend = enabled;
+        self
+    }
+
+    /// Enable asciicast session recording with the given configuration.
+    #[must_use]
+    pub fn with_session_recording(
+        mut self,
+        config: crate::asciicast::SessionRecordingConfig,
+    ) -> Self {
+        self.session_recording = config;
+        self
+    }
+
+    /// Inject a spawner for async commands (e.g. `tokio::spawn`).
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn with_async_spawner(
+        mut self,
+        spawner: std::sync::Arc<dyn crate::async_cmd::Spawner>,
+    ) -> Self {
+        self.async_spawner = crate::async_cmd::SharedSpawner(spawner);
+        self
+    }
+
     /// Set the render-trace recorder configuration.
     #[must_use]
     pub fn with_render_trace(mut self, config: RenderTraceConfig) -> Self {
@@ -2316,6 +2468,11 @@ impl<M: Send + 'static> Drop for EffectQueue<M> {
     }
 }
 
+/// Best-effort byte encoding of an input event for asciicast `"i"` events.
+///
+/// The runtime sees parsed events, not raw terminal bytes, so this maps the
+/// common cases (printable keys, control chars, paste) back to their byte
+/// form. Events with no sensible byte encoding are skipped.
 fn effect_queue_loop<M: Send + 'static>(
     config: EffectQueueConfig,
     rx: mpsc::Receiver<EffectCommand<M>>,
@@ -3010,6 +3167,13 @@ impl BackendEventSource for CrosstermEventSource {
     fn read_event(&mut self) -> Result<Option<Event>, io::Error> {
         self.session.read_event()
     }
+
+    fn suspend(&mut self) -> Result<(), ftui_backend::SuspendError<io::Error>> {
+        self.session.suspend().map_err(|err| match err.kind() {
+            io::ErrorKind::Unsupported => ftui_backend::SuspendError::Unsupported,
+            _ => ftui_backend::SuspendError::Backend(err),
+        })
+    }
 }
 
 // =============================================================================
@@ -3064,6 +3228,106 @@ impl BackendEventSource for HeadlessEventSource {
 // =============================================================================
 
 /// The program runtime that manages the update/view loop.
+/// Identifier for an additional render target ([`Program::add_view`]).
+pub type ViewId = u64;
+
+/// View function for an additional render target.
+type ViewFn<M> = Box<dyn Fn(&M, &mut Frame) + Send>;
+
+/// An additional render target over the shared model: its own writer,
+/// size, and view function. Input from the view's terminal is routed via
+/// [`Program::push_view_event`].
+struct ExtraView<M: Model> {
+    id: ViewId,
+    writer: TerminalWriter<Box<dyn Write + Send>>,
+    view_fn: ViewFn<M>,
+    width: u16,
+    height: u16,
+}
+
+/// How input arriving during the startup splash is treated.
+///
+/// Ctrl+C is exempt: it always aborts startup cleanly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplashInputPolicy {
+    /// Queue input events and replay them after startup completes.
+    #[default]
+    Queue,
+    /// Drop input events received before startup completes.
+    Drop,
+}
+
+/// Configuration for the two-phase startup splash
+/// ([`Program::enable_startup_splash`]).
+#[derive(Debug, Clone)]
+pub struct StartupConfig {
+    /// What to do with key/mouse/paste input during the splash.
+    pub input_policy: SplashInputPolicy,
+    /// Cap on queued events under [`SplashInputPolicy::Queue`]; the oldest
+    /// events are dropped beyond this.
+    pub max_queued_events: usize,
+    /// Drive the startup `Cx` from a lab clock (deterministic tests).
+    pub lab_clock: Option<ftui_core::cx::LabClock>,
+}
+
+impl Default for StartupConfig {
+    fn default() -> Self {
+        Self {
+            input_policy: SplashInputPolicy::Queue,
+            max_queued_events: 64,
+            lab_clock: None,
+        }
+    }
+}
+
+/// Snapshot handed to a custom splash view ([`Program::set_splash_view`]).
+pub struct SplashInfo<'a> {
+    /// Progress lines reported via [`Cmd::splash_progress`], oldest first.
+    pub progress: &'a [String],
+}
+
+/// Custom splash renderer; replaces the built-in splash view.
+type SplashViewFn = Box<dyn Fn(&mut Frame, &SplashInfo<'_>) + Send>;
+
+/// Cheap resize-storm view ([`Program::set_resize_view`]): model, frame,
+/// and the pending (not yet applied) terminal size.
+type ResizeViewFn<M> = Box<dyn Fn(&M, &mut Frame, (u16, u16)) + Send>;
+
+/// Model persistence engine paired with the model's persist fn.
+#[cfg(feature = "state-persistence")]
+type ModelPersistHook<M> = (
+    crate::model_persist::ModelPersistence,
+    fn(&M) -> crate::model_persist::PersistedState,
+);
+
+/// Enter/exit message constructors for idle transitions.
+type IdleMessageHooks<Msg> = (fn() -> Msg, fn() -> Msg);
+
+/// Runtime state for the startup splash phase.
+struct StartupPhase {
+    config: StartupConfig,
+    progress: Vec<String>,
+    queued: Vec<Event>,
+    cx: ftui_core::cx::Cx,
+    controller: ftui_core::cx::CxController,
+}
+
+impl StartupPhase {
+    fn new(config: StartupConfig) -> Self {
+        let (cx, controller) = match &config.lab_clock {
+            Some(clock) => ftui_core::cx::Cx::lab(clock),
+            None => ftui_core::cx::Cx::background(),
+        };
+        Self {
+            config,
+            progress: Vec::new(),
+            queued: Vec::new(),
+            cx,
+            controller,
+        }
+    }
+}
+
 pub struct Program<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send = Stdout> {
     /// The application model.
     model: M,
@@ -3123,6 +3387,54 @@ pub struct Program<M: Model, E: BackendEventSource<Error = io::Error>, W: Write
     fairness_guard: InputFairnessGuard,
     /// Optional event recorder for macro capture.
     event_recorder: Option<EventRecorder>,
+    /// Suspend the app on Ctrl+Z.
+    enable_suspend: bool,
+    /// Optional asciicast session recorder (owner; finalized on exit).
+    session_recorder: Option<crate::asciicast::SessionRecorder>,
+    /// Executor for `Cmd::Future` commands (feature `async`).
+    #[cfg(feature = "async")]
+    async_cmds: crate::async_cmd::AsyncCmdExecutor<M::Message>,
+    /// Time-travel recorder (feature `devtools`, opt-in at runtime).
+    #[cfg(feature = "devtools")]
+    devtools: Option<crate::devtools::ProgramDevtools<M, M::Message>>,
+    /// Additional render targets over the shared model.
+    extra_views: Vec<ExtraView<M>>,
+    /// Next view id handed out by [`Program::add_view`].
+    next_view_id: ViewId,
+    /// Active startup splash phase (`None` once startup completed).
+    startup: Option<StartupPhase>,
+    /// Custom splash renderer; `None` uses the built-in splash.
+    splash_view: Option<SplashViewFn>,
+    /// Optional cheap view rendered during a resize storm.
+    resize_view: Option<ResizeViewFn<M>>,
+    /// Pending size while the coalescer holds a storm placeholder.
+    resize_storm_pending: Option<(u16, u16)>,
+    /// Periodic backend re-probe while a sanitized (substituted) size
+    /// is in effect; recovery applies the real size with a full repaint.
+    size_reprobe: ftui_core::size_policy::ReprobeState,
+    /// Whether the terminal window currently has focus (requires the
+    /// backend's focus reporting; assumed focused until told otherwise).
+    has_terminal_focus: bool,
+    /// Input redaction for telemetry/recording sinks.
+    privacy: TelemetryPrivacyPolicy,
+    /// Model persistence engine + persist fn (feature `state-persistence`).
+    #[cfg(feature = "state-persistence")]
+    model_persist: Option<ModelPersistHook<M>>,
+    /// Idle tracker (None = idle management off).
+    idle_monitor: Option<crate::idle::IdleMonitor>,
+    /// App messages emitted on idle transitions.
+    idle_messages: Option<IdleMessageHooks<M::Message>>,
+    /// Shared tick throttle applied while idle.
+    tick_scale: crate::idle::TickRateScale,
+    /// Typed evidence emitter (None = off).
+    evidence_emitter: Option<crate::evidence_events::EvidenceEmitter>,
+    /// A resize was applied since the last presented frame (evidence
+    /// reason derivation).
+    evidence_resize_pending: bool,
+    /// A focused secret widget rendered on the last frame.
+    secret_input_active: bool,
+    /// Hide the cursor while the terminal is unfocused.
+    hide_cursor_when_unfocused: bool,
     /// Subscription lifecycle manager.
     subscriptions: SubscriptionManager<M::Message>,
     /// Channel for receiving messages from background tasks.
@@ -3211,14 +3523,36 @@ impl<M: Model> Program<M, CrosstermEventSource, Stdout> {
             writer = writer.with_render_trace(recorder);
         }
 
-        // Get terminal size for initial frame (or forced size override).
-        let (w, h) = config
+        // Get terminal size for initial frame (or forced size override),
+        // sanitized through the shared size policy (0x0 → fallback,
+        // absurd reports clamped, substitution recorded as evidence).
+        // The substitution counter also catches substitutions made
+        // *inside* the query (the native session sanitizes internally),
+        // so a degenerate startup size always arms the recovery re-probe.
+        let substitutions_before = ftui_core::size_policy::substitution_count();
+        let reported = config
             .forced_size
             .unwrap_or_else(|| events.size().unwrap_or((80, 24)));
-        let width = w.max(1);
-        let height = h.max(1);
+        let ((width, height), size_substituted) =
+            ftui_core::size_policy::SizePolicy::default().sanitize(reported);
+        if let Some(substitution) = size_substituted {
+            ftui_core::size_policy::record_size_substitution(substitution);
+        }
+        let substituted = size_substituted.is_some()
+            || ftui_core::size_policy::substitution_count() != substitutions_before;
+        let mut size_reprobe = ftui_core::size_policy::ReprobeState::default();
+        if substituted && config.forced_size.is_none() {
+            size_reprobe.on_substitution(Instant::now());
+        }
         writer.set_size(width, height);
 
+        let session_recording = config.session_recording.clone().resolve_env();
+        let session_recorder =
+            crate::asciicast::SessionRecorder::from_config(&session_recording, width, height)?;
+        if let Some(recorder) = &session_recorder {
+            writer.set_session_recorder(Some(recorder.handle()));
+        }
+
         let budget = RenderBudget::from_config(&config.budget);
         let conformal_predictor = config.conformal_config.clone().map(ConformalPredictor::new);
         let locale_context = config.locale_context.clone();
@@ -3276,6 +3610,32 @@ impl<M: Model> Program<M, CrosstermEventSource, Stdout> {
             resize_behavior: config.resize_behavior,
             fairness_guard: InputFairnessGuard::new(),
             event_recorder: None,
+            enable_suspend: config.enable_suspend,
+            session_recorder,
+            #[cfg(feature = "async")]
+            async_cmds: crate::async_cmd::AsyncCmdExecutor::new(config.async_spawner.0.clone()),
+            #[cfg(feature = "devtools")]
+            devtools: None,
+            extra_views: Vec::new(),
+            next_view_id: 1,
+            startup: None,
+            splash_view: None,
+            resize_view: None,
+            resize_storm_pending: None,
+            size_reprobe,
+            has_terminal_focus: true,
+            privacy: config.privacy,
+            #[cfg(feature = "state-persistence")]
+            model_persist: None,
+            idle_monitor: config
+                .idle
+                .map(|idle| crate::idle::IdleMonitor::new(idle, Instant::now())),
+            idle_messages: None,
+            tick_scale: crate::idle::TickRateScale::new(),
+            evidence_emitter: None,
+            evidence_resize_pending: false,
+            secret_input_active: false,
+            hide_cursor_when_unfocused: config.hide_cursor_when_unfocused,
             subscriptions,
             task_sender,
             task_receiver,
@@ -3308,15 +3668,36 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
     where
         M::Message: Send + 'static,
     {
-        let (width, height) = config
+        // Initial size sanitized like the crossterm path: substituted
+        // values (including substitutions made inside a delegated,
+        // self-sanitizing `size()`) are recorded and arm the recovery
+        // re-probe.
+        let substitutions_before = ftui_core::size_policy::substitution_count();
+        let reported = config
             .forced_size
             .unwrap_or_else(|| events.size().unwrap_or((80, 24)));
-        let width = width.max(1);
-        let height = height.max(1);
+        let ((width, height), size_substituted) =
+            ftui_core::size_policy::SizePolicy::default().sanitize(reported);
+        if let Some(substitution) = size_substituted {
+            ftui_core::size_policy::record_size_substitution(substitution);
+        }
+        let substituted = size_substituted.is_some()
+            || ftui_core::size_policy::substitution_count() != substitutions_before;
+        let mut size_reprobe = ftui_core::size_policy::ReprobeState::default();
+        if substituted && config.forced_size.is_none() {
+            size_reprobe.on_substitution(Instant::now());
+        }
 
         let mut writer = writer;
         writer.set_size(width, height);
 
+        let session_recording = config.session_recording.clone().resolve_env();
+        let session_recorder =
+            crate::asciicast::SessionRecorder::from_config(&session_recording, width, height)?;
+        if let Some(recorder) = &session_recorder {
+            writer.set_session_recorder(Some(recorder.handle()));
+        }
+
         let evidence_sink = EvidenceSink::from_config(&config.evidence_sink)?;
         if let Some(ref sink) = evidence_sink {
             writer = writer.with_evidence_sink(sink.clone());
@@ -3396,6 +3777,32 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             resize_behavior: config.resize_behavior,
             fairness_guard: InputFairnessGuard::new(),
             event_recorder: None,
+            enable_suspend: config.enable_suspend,
+            session_recorder,
+            #[cfg(feature = "async")]
+            async_cmds: crate::async_cmd::AsyncCmdExecutor::new(config.async_spawner.0.clone()),
+            #[cfg(feature = "devtools")]
+            devtools: None,
+            extra_views: Vec::new(),
+            next_view_id: 1,
+            startup: None,
+            splash_view: None,
+            resize_view: None,
+            resize_storm_pending: None,
+            size_reprobe,
+            has_terminal_focus: true,
+            privacy: config.privacy,
+            #[cfg(feature = "state-persistence")]
+            model_persist: None,
+            idle_monitor: config
+                .idle
+                .map(|idle| crate::idle::IdleMonitor::new(idle, Instant::now())),
+            idle_messages: None,
+            tick_scale: crate::idle::TickRateScale::new(),
+            evidence_emitter: None,
+            evidence_resize_pending: false,
+            secret_input_active: false,
+            hide_cursor_when_unfocused: config.hide_cursor_when_unfocused,
             subscriptions,
             task_sender,
             task_receiver,
@@ -3497,6 +3904,13 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             self.load_state();
         }
 
+        // Two-phase startup: paint the splash before running any init
+        // command, so the first frame lands within a bounded time of
+        // process start regardless of how heavy the startup work is.
+        if self.startup.is_some() {
+            self.render_frame()?;
+        }
+
         // Initialize
         let cmd = {
             let _span = info_span!("ftui.program.init").entered();
@@ -3542,6 +3956,8 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             // Process background task results
             self.process_task_results()?;
             self.reap_finished_tasks();
+            #[cfg(feature = "async")]
+            self.async_cmds.reap();
 
             self.process_resize_coalescer()?;
 
@@ -3557,7 +3973,7 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                     )
                     .entered();
                     let start = Instant::now();
-                    let cmd = self.model.update(msg);
+                    let cmd = self.update_model(msg);
                     tracing::Span::current()
                         .record("duration_us", start.elapsed().as_micros() as u64);
                     tracing::Span::current()
@@ -3571,10 +3987,18 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
 
             // Check for periodic checkpoint save
             self.check_checkpoint_save();
+            #[cfg(feature = "state-persistence")]
+            self.check_model_autosave();
+            self.check_idle()?;
 
             // Detect locale changes outside the event loop.
             self.check_locale_change();
 
+            // Re-probe the backend size while a sanitized size is in
+            // effect, recovering automatically once the host reports a
+            // real one.
+            self.check_size_reprobe()?;
+
             // Render if dirty
             if self.dirty {
                 self.render_frame()?;
@@ -3590,11 +4014,27 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
         if self.persistence_config.auto_save {
             self.save_state();
         }
+        #[cfg(feature = "state-persistence")]
+        self.save_model_state();
 
         // Stop all subscriptions on exit
         self.subscriptions.stop_all();
         self.reap_finished_tasks();
 
+        // Cancel async commands and drain within a bound — shutdown must
+        // not hang on detached futures.
+        #[cfg(feature = "async")]
+        if !self.async_cmds.shutdown(Duration::from_millis(250)) {
+            tracing::warn!("async commands abandoned at shutdown");
+        }
+
+        // Finalize the session recording (joins the writer thread).
+        if let Some(recorder) = self.session_recorder.take()
+            && let Err(e) = recorder.finish()
+        {
+            tracing::warn!(error = %e, "failed to finalize session recording");
+        }
+
         Ok(())
     }
 
@@ -3647,11 +4087,64 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             self.fairness_guard.input_arrived(event_start);
         }
 
+        // Cooperative suspend: Ctrl+Z tears down the terminal, stops the
+        // process, and restores everything on SIGCONT.
+        if self.enable_suspend
+            && let Event::Key(key) = &event
+            && key.kind == KeyEventKind::Press
+            && key.code == KeyCode::Char('z')
+            && key.modifiers.contains(Modifiers::CTRL)
+        {
+            return self.suspend();
+        }
+
+        // Idle tracking: user input exits idle, focus flaps may enter it.
+        // Ahead of the splash gate — typing during startup is activity.
+        self.note_idle_event(&event)?;
+
+        // Startup splash: gate input until the model completes startup.
+        if self.startup.is_some() && self.gate_startup_event(&event) {
+            return Ok(());
+        }
+
+        // Track window focus for `has_terminal_focus` and cursor
+        // suppression; a focus flap needs a repaint either way.
+        if let Event::Focus(gained) = event {
+            self.has_terminal_focus = gained;
+            if self.hide_cursor_when_unfocused {
+                self.mark_dirty();
+            }
+        }
+
+        // Regaining focus means another program may have written to the
+        // terminal: drop assumed SGR/cursor state so the next frame
+        // re-establishes attributes defensively.
+        if matches!(event, Event::Focus(true)) {
+            self.writer.invalidate_terminal_state();
+            self.mark_dirty();
+        }
+
         // Record event before processing (no-op when recorder is None or idle).
         if let Some(recorder) = &mut self.event_recorder {
             recorder.record(&event);
         }
 
+        // Mirror resize (and, when opted in, input) into the session
+        // recording, after privacy redaction.
+        if let Some(recorder) = &self.session_recorder {
+            let handle = recorder.handle();
+            match &event {
+                Event::Resize { width, height } => handle.record_resize(*width, *height),
+                _ if handle.records_input() => {
+                    if let Some(bytes) = self.effective_privacy().encode_event_for_recording(&event)
+                    {
+                        handle.record_input(&bytes);
+                    }
+                }
+                _ => {}
+            }
+        }
+
         let event = match event {
             Event::Resize { width, height } => {
                 debug!(
@@ -3672,9 +4165,17 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                     );
                     return Ok(());
                 }
-                // Clamp to minimum 1 to prevent Buffer::new panic on zero dimensions
-                let width = width.max(1);
-                let height = height.max(1);
+                // Sanitize host-reported dimensions (0x0, 65535-column
+                // reports) through the shared size policy; substitutions
+                // are recorded as evidence and arm the recovery re-probe.
+                let ((width, height), substitution) =
+                    ftui_core::size_policy::SizePolicy::default().sanitize((width, height));
+                if let Some(substitution) = substitution {
+                    ftui_core::size_policy::record_size_substitution(substitution);
+                    self.size_reprobe.on_substitution(Instant::now());
+                } else if self.size_reprobe.on_real_size() {
+                    debug!(width, height, "real size reported; re-probe disarmed");
+                }
                 match self.resize_behavior {
                     ResizeBehavior::Immediate => {
                         self.resize_coalescer
@@ -3689,6 +4190,17 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                     }
                     ResizeBehavior::Throttled => {
                         let action = self.resize_coalescer.handle_resize(width, height);
+                        // Storm in progress: repaint with the cheap resize
+                        // view (when the app provided one) at the pending
+                        // size instead of reflowing the whole model view.
+                        if action == CoalesceAction::ShowPlaceholder
+                            && self.resize_view.is_some()
+                            && let Some(pending) = self.resize_coalescer.pending_size()
+                            && self.resize_storm_pending != Some(pending)
+                        {
+                            self.resize_storm_pending = Some(pending);
+                            self.mark_dirty();
+                        }
                         if let CoalesceAction::ApplyResize {
                             width,
                             height,
@@ -3728,7 +4240,7 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             )
             .entered();
             let start = Instant::now();
-            let cmd = self.model.update(msg);
+            let cmd = self.update_model(msg);
             let elapsed_us = start.elapsed().as_micros() as u64;
             self.last_update_us = Some(elapsed_us);
             tracing::Span::current().record("duration_us", elapsed_us);
@@ -3798,6 +4310,9 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
 
     /// Process pending messages from subscriptions.
     fn process_subscription_messages(&mut self) -> io::Result<()> {
+        // Supervision first: worker exits become events/restarts whose
+        // messages land in this same drain.
+        self.subscriptions.supervise(Instant::now());
         let messages = self.subscriptions.drain_messages();
         let msg_count = messages.len();
         if msg_count > 0 {
@@ -3813,7 +4328,7 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                 )
                 .entered();
                 let start = Instant::now();
-                let cmd = self.model.update(msg);
+                let cmd = self.update_model(msg);
                 let elapsed_us = start.elapsed().as_micros() as u64;
                 self.last_update_us = Some(elapsed_us);
                 tracing::Span::current().record("duration_us", elapsed_us);
@@ -3842,7 +4357,7 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                 )
                 .entered();
                 let start = Instant::now();
-                let cmd = self.model.update(msg);
+                let cmd = self.update_model(msg);
                 let elapsed_us = start.elapsed().as_micros() as u64;
                 self.last_update_us = Some(elapsed_us);
                 tracing::Span::current().record("duration_us", elapsed_us);
@@ -3860,13 +4375,23 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
     }
 
     /// Execute a command.
+    /// Run `Model::update`, recording a devtools snapshot first when
+    /// time-travel is enabled.
+    fn update_model(&mut self, msg: M::Message) -> Cmd<M::Message> {
+        #[cfg(feature = "devtools")]
+        if let Some(devtools) = &mut self.devtools {
+            devtools.record(&msg, &self.model, self.frame_idx);
+        }
+        self.model.update(msg)
+    }
+
     fn execute_cmd(&mut self, cmd: Cmd<M::Message>) -> io::Result<()> {
         match cmd {
             Cmd::None => {}
             Cmd::Quit => self.running = false,
             Cmd::Msg(m) => {
                 let start = Instant::now();
-                let cmd = self.model.update(m);
+                let cmd = self.update_model(m);
                 let elapsed_us = start.elapsed().as_micros() as u64;
                 self.last_update_us = Some(elapsed_us);
                 self.mark_dirty();
@@ -3931,135 +4456,682 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                 self.backend_features.mouse_capture = enabled;
                 self.events.set_features(self.backend_features)?;
             }
+            Cmd::Suspend => {
+                self.suspend()?;
+            }
+            Cmd::SplashProgress(text) => {
+                if let Some(startup) = self.startup.as_mut() {
+                    startup.progress.push(text);
+                    self.mark_dirty();
+                }
+            }
+            Cmd::CompleteStartup => {
+                self.complete_startup()?;
+            }
+            #[cfg(feature = "async")]
+            Cmd::Future(fut, timeout, timeout_msg) => {
+                let sender = self.task_sender.clone();
+                self.async_cmds
+                    .spawn(fut, sender, timeout, timeout_msg.map(|m| *m));
+            }
         }
         Ok(())
     }
 
-    fn reap_finished_tasks(&mut self) {
-        if self.task_handles.is_empty() {
-            return;
+    /// Suspend the process and restore terminal state on resume.
+    ///
+    /// Unsupported backends log and continue; backend failures propagate.
+    /// After resume, any input that straddled the suspend is discarded (the
+    /// shell may have consumed part of an escape sequence), the size is
+    /// re-queried (it commonly changed while backgrounded), and a full
+    /// repaint is forced.
+    fn suspend(&mut self) -> io::Result<()> {
+        match self.events.suspend() {
+            Ok(()) => {}
+            Err(ftui_backend::SuspendError::Unsupported) => {
+                tracing::warn!("suspend requested but not supported by this backend");
+                return Ok(());
+            }
+            Err(ftui_backend::SuspendError::Backend(err)) => return Err(err),
         }
 
-        let mut i = 0;
-        while i < self.task_handles.len() {
-            if self.task_handles[i].is_finished() {
-                let handle = self.task_handles.swap_remove(i);
-                if let Err(payload) = handle.join() {
-                    let msg = if let Some(s) = payload.downcast_ref::<&str>() {
-                        (*s).to_owned()
-                    } else if let Some(s) = payload.downcast_ref::<String>() {
-                        s.clone()
-                    } else {
-                        "unknown panic payload".to_owned()
-                    };
-                    #[cfg(feature = "tracing")]
-                    tracing::error!("spawned task panicked: {msg}");
-                    #[cfg(not(feature = "tracing"))]
-                    eprintln!("ftui: spawned task panicked: {msg}");
-                }
-            } else {
-                i += 1;
+        // Discard buffered input from before/around the stop.
+        while self.events.poll_event(Duration::from_millis(0))? {
+            if self.events.read_event()?.is_none() {
+                break;
             }
         }
+
+        // Re-query the size and force a full repaint.
+        let (w, h) = self
+            .forced_size
+            .unwrap_or_else(|| self.events.size().unwrap_or((self.width, self.height)));
+        self.width = w.max(1);
+        self.height = h.max(1);
+        // set_size drops the previous buffer, forcing a full redraw.
+        self.writer.set_size(self.width, self.height);
+        self.mark_dirty();
+        self.render_frame()
     }
 
-    /// Render a frame with budget tracking.
-    fn render_frame(&mut self) -> io::Result<()> {
-        crate::debug_trace!("render_frame: {}x{}", self.width, self.height);
+    /// Enable the two-phase startup splash.
+    ///
+    /// While active, the runtime paints a lightweight splash (see
+    /// [`Program::set_splash_view`]) *before* executing the model's
+    /// `init()` commands, so the first frame lands within a bounded time
+    /// of process start no matter how heavy the startup work is — provided
+    /// that work runs through the command system (`Cmd::Task` /
+    /// `Cmd::Future`) rather than inside `init()` itself. Input during the
+    /// splash is queued or dropped per [`StartupConfig::input_policy`];
+    /// Ctrl+C always aborts, cancelling the startup [`Cx`](ftui_core::cx::Cx).
+    /// The model leaves the phase by returning [`Cmd::complete_startup`].
+    pub fn enable_startup_splash(&mut self, config: StartupConfig) {
+        self.startup = Some(StartupPhase::new(config));
+    }
 
-        self.frame_idx = self.frame_idx.wrapping_add(1);
-        let frame_idx = self.frame_idx;
-        let degradation_start = self.budget.degradation();
+    /// Replace the built-in splash with a custom renderer.
+    pub fn set_splash_view(&mut self, view: impl Fn(&mut Frame, &SplashInfo<'_>) + Send + 'static) {
+        self.splash_view = Some(Box::new(view));
+    }
 
-        // Reset budget for new frame, potentially upgrading quality
-        self.budget.next_frame();
+    /// Provide a cheap view rendered instead of the model's view while a
+    /// resize storm is being coalesced (continuous reflow remains the
+    /// default when no view is set). Receives the pending terminal size;
+    /// the final frame at the settled size always uses the real view.
+    pub fn set_resize_view(&mut self, view: impl Fn(&M, &mut Frame, (u16, u16)) + Send + 'static) {
+        self.resize_view = Some(Box::new(view));
+    }
 
-        // Check frame guardrails (memory/queue limits)
-        let memory_bytes = self.writer.estimate_memory_usage() + self.frame_arena.allocated_bytes();
-        // Synchronous program has effectively zero queue depth.
-        let verdict = self.guardrails.check_frame(memory_bytes, 0);
+    /// The startup context, for cancellation-aware startup tasks.
+    ///
+    /// `None` once startup has completed (or was never enabled).
+    pub fn startup_cx(&self) -> Option<ftui_core::cx::Cx> {
+        self.startup.as_ref().map(|s| s.cx.clone())
+    }
 
-        if verdict.should_drop_frame() {
-            // Emergency shed: skip this frame entirely to prevent OOM
-            return Ok(());
-        }
+    /// Whether the startup splash phase is still active.
+    pub fn startup_active(&self) -> bool {
+        self.startup.is_some()
+    }
 
-        if verdict.should_degrade() {
-            // Apply guardrail-recommended degradation if it's stricter than budget's
-            let current = self.budget.degradation();
-            if verdict.recommended_level > current {
-                self.budget.set_degradation(verdict.recommended_level);
+    /// Whether the terminal window currently has focus.
+    ///
+    /// Kept in sync from `Event::Focus`; `true` until the backend reports
+    /// otherwise (focus reporting must be enabled to ever observe `false`).
+    pub fn has_terminal_focus(&self) -> bool {
+        self.has_terminal_focus
+    }
+
+    /// Enable model persistence (feature `state-persistence`).
+    ///
+    /// Hydrates the model immediately — call before [`Program::run`] so
+    /// restored state is in place before `init()` completes. Saves on
+    /// clean shutdown and autosaves on the engine's configured interval.
+    /// Corrupt or unreadable state falls back to defaults; the returned
+    /// warning (also traced) is the only symptom, never a startup
+    /// failure.
+    #[cfg(feature = "state-persistence")]
+    pub fn enable_model_persistence(
+        &mut self,
+        persistence: crate::model_persist::ModelPersistence,
+    ) -> Option<String>
+    where
+        M: crate::model_persist::Persist,
+    {
+        let mut warning = None;
+        match persistence.load(self.model.state_version()) {
+            Ok(Some(state)) => self.model.hydrate(state),
+            Ok(None) => {}
+            Err(message) => {
+                tracing::warn!(%message, "model persistence load failed");
+                warning = Some(message);
             }
         }
+        self.model_persist = Some((persistence, M::persist));
+        warning
+    }
 
-        // Apply conformal risk gate before rendering (if enabled)
-        let mut conformal_prediction = None;
-        if let Some(predictor) = self.conformal_predictor.as_ref() {
-            let baseline_us = self
-                .last_frame_time_us
-                .unwrap_or_else(|| self.budget.total().as_secs_f64() * 1_000_000.0);
-            let diff_strategy = self
-                .writer
-                .last_diff_strategy()
-                .unwrap_or(DiffStrategy::Full);
-            let frame_height_hint = self.writer.render_height_hint().max(1);
-            let key = BucketKey::from_context(
-                self.writer.screen_mode(),
-                diff_strategy,
-                self.width,
-                frame_height_hint,
-            );
-            let budget_us = self.budget.total().as_secs_f64() * 1_000_000.0;
-            let prediction = predictor.predict(key, baseline_us, budget_us);
-            if prediction.risk {
-                self.budget.degrade();
-                info!(
-                    bucket = %prediction.bucket,
-                    upper_us = prediction.upper_us,
-                    budget_us = prediction.budget_us,
-                    fallback_level = prediction.fallback_level,
-                    degradation = self.budget.degradation().as_str(),
-                    "conformal gate triggered strategy downgrade"
-                );
-                debug!(
-                    monotonic.counter.conformal_gate_triggers_total = 1_u64,
-                    bucket = %prediction.bucket,
-                    "conformal gate trigger"
-                );
+    /// Save the model state now (no-op without model persistence).
+    #[cfg(feature = "state-persistence")]
+    fn save_model_state(&mut self) {
+        if let Some((persistence, persist)) = self.model_persist.as_mut() {
+            let state = persist(&self.model);
+            if let Err(message) = persistence.save(&state) {
+                tracing::warn!(%message, "model persistence save failed");
             }
-            debug!(
-                bucket = %prediction.bucket,
-                upper_us = prediction.upper_us,
-                budget_us = prediction.budget_us,
-                fallback = prediction.fallback_level,
-                risk = prediction.risk,
-                "conformal risk gate"
-            );
-            debug!(
-                monotonic.histogram.conformal_prediction_interval_width_us = prediction.quantile.max(0.0),
-                bucket = %prediction.bucket,
-                "conformal prediction interval width"
-            );
-            conformal_prediction = Some(prediction);
         }
+    }
 
-        // Early skip if budget says to skip this frame entirely
-        if self.budget.exhausted() {
-            self.budget.record_frame_time(Duration::ZERO);
-            self.emit_budget_evidence(
-                frame_idx,
-                degradation_start,
-                0.0,
-                conformal_prediction.as_ref(),
-            );
-            crate::debug_trace!(
-                "frame skipped: budget exhausted (degradation={})",
-                self.budget.degradation().as_str()
-            );
-            debug!(
-                degradation = self.budget.degradation().as_str(),
-                "frame skipped: budget exhausted before render"
-            );
+    /// Autosave on the configured interval (program-clock driven).
+    #[cfg(feature = "state-persistence")]
+    fn check_model_autosave(&mut self) {
+        let due = self
+            .model_persist
+            .as_ref()
+            .is_some_and(|(p, _)| p.maybe_autosave(Instant::now()));
+        if due {
+            self.save_model_state();
+        }
+    }
+
+    /// Route idle transitions into the app as messages: `on_enter` fires
+    /// when the idle threshold trips, `on_exit` on the next input.
+    /// Requires [`ProgramConfig::idle`] to be set.
+    pub fn set_idle_messages(&mut self, on_enter: fn() -> M::Message, on_exit: fn() -> M::Message) {
+        self.idle_messages = Some((on_enter, on_exit));
+    }
+
+    /// Current idle snapshot (`None` when idle management is off).
+    #[must_use]
+    pub fn idle_state(&self) -> Option<crate::idle::IdleState> {
+        self.idle_monitor
+            .as_ref()
+            .map(|monitor| monitor.state(Instant::now()))
+    }
+
+    /// The shared tick throttle. Attach it to animation timers
+    /// ([`Interval::with_rate_scale`]) so idle periods thin their ticks.
+    ///
+    /// [`Interval::with_rate_scale`]: crate::subscription::Interval::with_rate_scale
+    #[must_use]
+    pub fn tick_rate_scale(&self) -> crate::idle::TickRateScale {
+        self.tick_scale.clone()
+    }
+
+    /// Install a typed evidence emitter ([`crate::evidence_events`]):
+    /// each presented frame emits a `diff_decision` (with a structured
+    /// reason) and a `frame_timing` event through it, replacing ad-hoc
+    /// per-app printing.
+    pub fn set_evidence_emitter(&mut self, emitter: crate::evidence_events::EvidenceEmitter) {
+        self.evidence_emitter = Some(emitter);
+    }
+
+    /// Emit evidence for a presented frame.
+    fn emit_frame_evidence(
+        &mut self,
+        frame_idx: u64,
+        rows: u16,
+        render: Duration,
+        present: Duration,
+    ) {
+        let Some(emitter) = self.evidence_emitter.as_mut() else {
+            self.evidence_resize_pending = false;
+            return;
+        };
+        use crate::evidence_events::{DiffDecisionReason, EvidenceEvent};
+        let reason = if frame_idx <= 1 {
+            DiffDecisionReason::FullRepaintFirstFrame
+        } else if self.evidence_resize_pending {
+            DiffDecisionReason::FullRepaintResize
+        } else if self.budget.degradation() > ftui_render::budget::DegradationLevel::Full {
+            DiffDecisionReason::DegradedMode
+        } else {
+            DiffDecisionReason::PartialDiff
+        };
+        self.evidence_resize_pending = false;
+        let strategy = self
+            .writer
+            .last_diff_strategy()
+            .map_or_else(|| "unknown".to_string(), |s| format!("{s:?}"));
+        emitter.emit(&EvidenceEvent::DiffDecision(
+            crate::evidence_events::DiffDecision {
+                frame_idx,
+                cols: self.width,
+                rows,
+                screen_mode: format!("{:?}", self.writer.screen_mode()),
+                dirty_cells: self.writer.last_diff_cells().unwrap_or(0),
+                strategy,
+                reason,
+            },
+        ));
+        emitter.emit(&EvidenceEvent::FrameTiming(
+            crate::evidence_events::FrameTiming {
+                frame_idx,
+                render_us: render.as_micros() as u64,
+                present_us: present.as_micros() as u64,
+            },
+        ));
+    }
+
+    /// Feed an event into the idle tracker.
+    fn note_idle_event(&mut self, event: &Event) -> io::Result<()> {
+        let Some(monitor) = self.idle_monitor.as_mut() else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        let transition = match event {
+            Event::Key(_) | Event::Mouse(_) | Event::Paste(_) => monitor.note_input(now),
+            Event::Focus(focused) => monitor.note_focus(*focused, now),
+            _ => None,
+        };
+        if let Some(transition) = transition {
+            self.apply_idle_transition(transition)?;
+        }
+        Ok(())
+    }
+
+    /// Threshold check, called once per main-loop iteration.
+    fn check_idle(&mut self) -> io::Result<()> {
+        let now = Instant::now();
+        let transition = self
+            .idle_monitor
+            .as_mut()
+            .and_then(|monitor| monitor.poll(now));
+        if let Some(transition) = transition {
+            self.apply_idle_transition(transition)?;
+        }
+        Ok(())
+    }
+
+    /// Throttle/restore timers and notify the app on a transition.
+    ///
+    /// Only timer ticks are thinned; data subscription messages still run
+    /// `update` and paint, so model changes are never suppressed while
+    /// idle. Exit forces a repaint so the UI instantly looks current.
+    fn apply_idle_transition(&mut self, transition: crate::idle::IdleTransition) -> io::Result<()> {
+        let config = self
+            .idle_monitor
+            .as_ref()
+            .map(crate::idle::IdleMonitor::config);
+        let Some(config) = config else {
+            return Ok(());
+        };
+        match transition {
+            crate::idle::IdleTransition::EnteredIdle => {
+                if config.pause_ticks {
+                    self.tick_scale.pause();
+                } else {
+                    self.tick_scale
+                        .set_multiplier(config.tick_multiplier.max(1));
+                }
+                if let Some((on_enter, _)) = self.idle_messages {
+                    self.execute_cmd(Cmd::Msg(on_enter()))?;
+                }
+            }
+            crate::idle::IdleTransition::ExitedIdle => {
+                self.tick_scale.restore();
+                if let Some((_, on_exit)) = self.idle_messages {
+                    self.execute_cmd(Cmd::Msg(on_exit()))?;
+                }
+                self.mark_dirty();
+            }
+        }
+        Ok(())
+    }
+
+    /// The configured telemetry privacy policy.
+    pub fn privacy_policy(&self) -> TelemetryPrivacyPolicy {
+        self.privacy
+    }
+
+    /// The policy actually applied right now: a focused secret widget
+    /// (masked input) tightens `Full` to `KeysOnly` for its duration.
+    fn effective_privacy(&self) -> TelemetryPrivacyPolicy {
+        if self.secret_input_active {
+            self.privacy.under_secret_scope()
+        } else {
+            self.privacy
+        }
+    }
+
+    /// Change the privacy policy at runtime.
+    ///
+    /// Emits a marker into the session recording's input track (and the
+    /// evidence stream when configured) so recordings are self-describing
+    /// about which redaction applied from this point on.
+    pub fn set_privacy_policy(&mut self, policy: TelemetryPrivacyPolicy) {
+        if self.privacy == policy {
+            return;
+        }
+        self.privacy = policy;
+        if let Some(recorder) = &self.session_recorder {
+            let handle = recorder.handle();
+            if handle.records_input() {
+                handle.record_input(&policy.change_marker());
+            }
+        }
+        if let Some(sink) = &self.evidence_sink {
+            let _ = sink.write_jsonl(&format!(
+                "{{\"event\":\"privacy_mode_change\",\"mode\":\"{}\"}}",
+                policy.as_str()
+            ));
+        }
+    }
+
+    /// Leave the splash phase: replay queued input in arrival order and
+    /// hand rendering back to the model's view with a fresh frame.
+    fn complete_startup(&mut self) -> io::Result<()> {
+        let Some(startup) = self.startup.take() else {
+            return Ok(());
+        };
+        self.mark_dirty();
+        for event in startup.queued {
+            self.handle_event(event)?;
+        }
+        Ok(())
+    }
+
+    /// Gate an event while the splash is active.
+    ///
+    /// Returns `true` when the event was consumed by the splash phase.
+    /// Ctrl+C aborts startup (cancelling the startup `Cx`); other input
+    /// is queued or dropped per config. Resize, focus and lifecycle
+    /// events pass through so the splash stays correct.
+    fn gate_startup_event(&mut self, event: &Event) -> bool {
+        let Some(startup) = self.startup.as_mut() else {
+            return false;
+        };
+        match event {
+            Event::Key(key)
+                if key.kind == KeyEventKind::Press
+                    && key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(Modifiers::CTRL) =>
+            {
+                startup.controller.cancel();
+                self.running = false;
+                true
+            }
+            Event::Key(_) | Event::Mouse(_) | Event::Paste(_) | Event::Ime(_) => {
+                if startup.config.input_policy == SplashInputPolicy::Queue {
+                    if startup.queued.len() >= startup.config.max_queued_events {
+                        startup.queued.remove(0);
+                    }
+                    startup.queued.push(event.clone());
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Built-in splash: app-overridable via [`Program::set_splash_view`].
+    ///
+    /// A centered "Starting…" line with the most recent progress messages
+    /// beneath it — deliberately dependency-free and cheap to paint.
+    fn render_default_splash(frame: &mut Frame, info: &SplashInfo<'_>) {
+        use ftui_render::drawing::Draw;
+
+        let (width, height) = (frame.buffer.width(), frame.buffer.height());
+        if width == 0 || height == 0 {
+            return;
+        }
+        let title = "Starting\u{2026}";
+        let title_x = (width.saturating_sub(title.chars().count() as u16)) / 2;
+        let mut y = (height / 2).saturating_sub(2);
+        frame
+            .buffer
+            .print_text(title_x, y, title, ftui_render::cell::Cell::default());
+        y = y.saturating_add(2);
+
+        // Show the tail of the progress log, newest last.
+        let visible = usize::from(height.saturating_sub(y)).min(3);
+        let start = info.progress.len().saturating_sub(visible);
+        for line in &info.progress[start..] {
+            let x = (width.saturating_sub(line.chars().count().min(usize::from(width)) as u16)) / 2;
+            frame
+                .buffer
+                .print_text(x, y, line, ftui_render::cell::Cell::default());
+            y = y.saturating_add(1);
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    /// Enable time-travel recording (feature `devtools`).
+    ///
+    /// Requires a cloneable model and a `Debug` message type. Recording
+    /// starts immediately; ticks are excluded unless configured otherwise.
+    pub fn enable_devtools(&mut self, config: crate::devtools::DevtoolsConfig)
+    where
+        M: Clone + 'static,
+        M::Message: std::fmt::Debug,
+    {
+        self.devtools = Some(crate::devtools::ProgramDevtools::new(
+            crate::devtools::Devtools::new(config),
+        ));
+    }
+
+    #[cfg(feature = "devtools")]
+    /// The time-travel recorder, when enabled.
+    pub fn devtools(&self) -> Option<&crate::devtools::Devtools<M>> {
+        self.devtools.as_ref().map(|d| &d.devtools)
+    }
+
+    #[cfg(feature = "devtools")]
+    /// Swap the model back to the snapshot at `index` and force a full
+    /// re-render. Returns `false` when the index is out of range.
+    pub fn devtools_restore(&mut self, index: usize) -> bool
+    where
+        M: Clone,
+    {
+        let Some(snapshot) = self
+            .devtools
+            .as_ref()
+            .and_then(|d| d.devtools.restore(index))
+        else {
+            return false;
+        };
+        self.model = snapshot;
+        // Dropping the previous buffer forces a full repaint.
+        self.writer.set_size(self.width, self.height);
+        self.mark_dirty();
+        true
+    }
+
+    /// Register an additional render target over the shared model.
+    ///
+    /// The view owns its writer (size, capabilities, presenter) and its
+    /// own view function; `update()` still runs once per message, then
+    /// every view renders with its own buffer/diff/presenter. Returns a
+    /// [`ViewId`] for event routing, resize, and removal.
+    pub fn add_view(
+        &mut self,
+        writer: TerminalWriter<Box<dyn Write + Send>>,
+        width: u16,
+        height: u16,
+        view_fn: impl Fn(&M, &mut Frame) + Send + 'static,
+    ) -> ViewId {
+        let id = self.next_view_id;
+        self.next_view_id += 1;
+        let mut writer = writer;
+        writer.set_size(width.max(1), height.max(1));
+        self.extra_views.push(ExtraView {
+            id,
+            writer,
+            view_fn: Box::new(view_fn),
+            width: width.max(1),
+            height: height.max(1),
+        });
+        self.mark_dirty();
+        id
+    }
+
+    /// Remove a view (e.g. after its connection dropped). The program and
+    /// the remaining views keep running. Returns `false` for unknown ids.
+    pub fn remove_view(&mut self, id: ViewId) -> bool {
+        let before = self.extra_views.len();
+        self.extra_views.retain(|view| view.id != id);
+        self.extra_views.len() != before
+    }
+
+    /// Resize one view; other views and the primary screen are unaffected.
+    pub fn resize_view(&mut self, id: ViewId, width: u16, height: u16) -> bool {
+        let Some(view) = self.extra_views.iter_mut().find(|view| view.id == id) else {
+            return false;
+        };
+        view.width = width.max(1);
+        view.height = height.max(1);
+        view.writer.set_size(view.width, view.height);
+        self.mark_dirty();
+        true
+    }
+
+    /// Route an event from a view's input source into `update()`.
+    ///
+    /// The message is produced by `map` so the model can tell views apart
+    /// (e.g. `|view, event| Msg::FromView(view, event)`).
+    pub fn push_view_event(
+        &mut self,
+        id: ViewId,
+        event: Event,
+        map: impl FnOnce(ViewId, Event) -> M::Message,
+    ) -> io::Result<()> {
+        let msg = map(id, event);
+        let cmd = self.update_model(msg);
+        self.mark_dirty();
+        self.execute_cmd(cmd)
+    }
+
+    /// Render every extra view. Views whose presenter fails are dropped
+    /// so a disconnected terminal never tears down the program.
+    fn render_extra_views(&mut self) {
+        if self.extra_views.is_empty() {
+            return;
+        }
+        let mut views = std::mem::take(&mut self.extra_views);
+        views.retain_mut(|view| {
+            let buffer = view.writer.take_render_buffer(view.width, view.height);
+            let (pool, links) = view.writer.pool_and_links_mut();
+            let mut frame = Frame::from_buffer(buffer, pool);
+            frame.set_links(links);
+            (view.view_fn)(&self.model, &mut frame);
+            let (buffer, cursor, cursor_visible) =
+                (frame.buffer, frame.cursor_position, frame.cursor_visible);
+            match view.writer.present_ui_owned(buffer, cursor, cursor_visible) {
+                Ok(_) => true,
+                Err(err) => {
+                    tracing::warn!(view = view.id, error = %err, "dropping failed view");
+                    false
+                }
+            }
+        });
+        self.extra_views = views;
+    }
+
+    fn reap_finished_tasks(&mut self) {
+        if self.task_handles.is_empty() {
+            return;
+        }
+
+        let mut i = 0;
+        while i < self.task_handles.len() {
+            if self.task_handles[i].is_finished() {
+                let handle = self.task_handles.swap_remove(i);
+                if let Err(payload) = handle.join() {
+                    let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+                        (*s).to_owned()
+                    } else if let Some(s) = payload.downcast_ref::<String>() {
+                        s.clone()
+                    } else {
+                        "unknown panic payload".to_owned()
+                    };
+                    #[cfg(feature = "tracing")]
+                    tracing::error!("spawned task panicked: {msg}");
+                    #[cfg(not(feature = "tracing"))]
+                    eprintln!("ftui: spawned task panicked: {msg}");
+                }
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Render a frame with budget tracking.
+    fn render_frame(&mut self) -> io::Result<()> {
+        crate::debug_trace!("render_frame: {}x{}", self.width, self.height);
+
+        self.frame_idx = self.frame_idx.wrapping_add(1);
+        let frame_idx = self.frame_idx;
+        let degradation_start = self.budget.degradation();
+
+        // Reset budget for new frame, potentially upgrading quality
+        self.budget.next_frame();
+
+        // Check frame guardrails (memory/queue limits)
+        let memory_bytes = self.writer.estimate_memory_usage() + self.frame_arena.allocated_bytes();
+        // Synchronous program has effectively zero queue depth.
+        let verdict = self.guardrails.check_frame(memory_bytes, 0);
+
+        if verdict.should_drop_frame() {
+            // Emergency shed: skip this frame entirely to prevent OOM
+            return Ok(());
+        }
+
+        if verdict.should_degrade() {
+            // Apply guardrail-recommended degradation if it's stricter than budget's
+            let current = self.budget.degradation();
+            if verdict.recommended_level > current {
+                self.budget.set_degradation(verdict.recommended_level);
+            }
+        }
+
+        // Apply conformal risk gate before rendering (if enabled)
+        let mut conformal_prediction = None;
+        if let Some(predictor) = self.conformal_predictor.as_ref() {
+            let baseline_us = self
+                .last_frame_time_us
+                .unwrap_or_else(|| self.budget.total().as_secs_f64() * 1_000_000.0);
+            let diff_strategy = self
+                .writer
+                .last_diff_strategy()
+                .unwrap_or(DiffStrategy::Full);
+            let frame_height_hint = self.writer.render_height_hint().max(1);
+            let key = BucketKey::from_context(
+                self.writer.screen_mode(),
+                diff_strategy,
+                self.width,
+                frame_height_hint,
+            );
+            let budget_us = self.budget.total().as_secs_f64() * 1_000_000.0;
+            let prediction = predictor.predict(key, baseline_us, budget_us);
+            if prediction.risk {
+                self.budget.degrade();
+                info!(
+                    bucket = %prediction.bucket,
+                    upper_us = prediction.upper_us,
+                    budget_us = prediction.budget_us,
+                    fallback_level = prediction.fallback_level,
+                    degradation = self.budget.degradation().as_str(),
+                    "conformal gate triggered strategy downgrade"
+                );
+                debug!(
+                    monotonic.counter.conformal_gate_triggers_total = 1_u64,
+                    bucket = %prediction.bucket,
+                    "conformal gate trigger"
+                );
+            }
+            debug!(
+                bucket = %prediction.bucket,
+                upper_us = prediction.upper_us,
+                budget_us = prediction.budget_us,
+                fallback = prediction.fallback_level,
+                risk = prediction.risk,
+                "conformal risk gate"
+            );
+            debug!(
+                monotonic.histogram.conformal_prediction_interval_width_us = prediction.quantile.max(0.0),
+                bucket = %prediction.bucket,
+                "conformal prediction interval width"
+            );
+            conformal_prediction = Some(prediction);
+        }
+
+        // Early skip if budget says to skip this frame entirely
+        if self.budget.exhausted() {
+            self.budget.record_frame_time(Duration::ZERO);
+            self.emit_budget_evidence(
+                frame_idx,
+                degradation_start,
+                0.0,
+                conformal_prediction.as_ref(),
+            );
+            crate::debug_trace!(
+                "frame skipped: budget exhausted (degradation={})",
+                self.budget.degradation().as_str()
+            );
+            debug!(
+                degradation = self.budget.degradation().as_str(),
+                "frame skipped: budget exhausted before render"
+            );
             self.dirty = false;
             return Ok(());
         }
@@ -4115,6 +5187,10 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
         )
         .entered();
         let (buffer, cursor, cursor_visible) = self.render_buffer(frame_height);
+        // Suppress the cursor while the terminal is unfocused so it does
+        // not blink in a window the user is not looking at.
+        let cursor_visible =
+            cursor_visible && (self.has_terminal_focus || !self.hide_cursor_when_unfocused);
         self.update_widget_refresh_plan(frame_idx);
         let render_elapsed = render_start.elapsed();
         let mut present_elapsed = Duration::ZERO;
@@ -4145,6 +5221,12 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             presented = true;
             present_elapsed = present_start.elapsed();
 
+            self.emit_frame_evidence(frame_idx, frame_height, render_elapsed, present_elapsed);
+
+            // Mirror the frame to any additional render targets, each with
+            // its own size, diff, and presenter.
+            self.render_extra_views();
+
             let present_budget = self.budget.phase_budgets().present;
             if present_elapsed > present_budget {
                 debug!(
@@ -4332,8 +5414,25 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             widget_count = tracing::field::Empty
         )
         .entered();
-        self.model.view(&mut frame);
+        if let Some(startup) = self.startup.as_ref() {
+            let info = SplashInfo {
+                progress: &startup.progress,
+            };
+            match &self.splash_view {
+                Some(view) => view(&mut frame, &info),
+                None => Self::render_default_splash(&mut frame, &info),
+            }
+        } else if let (Some(view), Some(pending)) =
+            (self.resize_view.as_ref(), self.resize_storm_pending)
+        {
+            view(&self.model, &mut frame, pending);
+        } else {
+            self.model.view(&mut frame);
+        }
         self.widget_signals = frame.take_widget_signals();
+        // Secret-input scope (masked input focused) tightens recording
+        // redaction until a frame renders without it.
+        self.secret_input_active = frame.secret_input;
         tracing::Span::current().record("duration_us", view_start.elapsed().as_micros() as u64);
         // widget_count would require tracking in Frame
 
@@ -4394,7 +5493,17 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             widget_count = tracing::field::Empty
         )
         .entered();
-        self.model.view(&mut frame);
+        if let Some(startup) = self.startup.as_ref() {
+            let info = SplashInfo {
+                progress: &startup.progress,
+            };
+            match &self.splash_view {
+                Some(view) => view(&mut frame, &info),
+                None => Self::render_default_splash(&mut frame, &info),
+            }
+        } else {
+            self.model.view(&mut frame);
+        }
         tracing::Span::current().record("duration_us", view_start.elapsed().as_micros() as u64);
 
         (frame.buffer, frame.cursor_position)
@@ -4481,7 +5590,9 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                 coalesce_time,
                 forced_by_deadline,
             } => self.apply_resize(width, height, coalesce_time, forced_by_deadline),
-            _ => Ok(()),
+            // `tick` never coalesces further; placeholder activation
+            // happens on the event path in `handle_event`.
+            CoalesceAction::ShowPlaceholder | CoalesceAction::None => Ok(()),
         }
     }
 
@@ -4495,6 +5606,10 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
         // Clamp to minimum 1 to prevent Buffer::new panic on zero dimensions
         let width = width.max(1);
         let height = height.max(1);
+        // The storm is over for rendering purposes regardless of which
+        // path applied the resize: drop the cheap-view placeholder.
+        self.resize_storm_pending = None;
+        self.evidence_resize_pending = true;
         self.width = width;
         self.height = height;
         self.writer.set_size(width, height);
@@ -4508,13 +5623,51 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
 
         let msg = M::Message::from(Event::Resize { width, height });
         let start = Instant::now();
-        let cmd = self.model.update(msg);
+        let cmd = self.update_model(msg);
         let elapsed_us = start.elapsed().as_micros() as u64;
         self.last_update_us = Some(elapsed_us);
         self.mark_dirty();
         self.execute_cmd(cmd)
     }
 
+    /// Periodically re-query the backend size while a sanitized
+    /// (substituted) size is in effect. When the host starts reporting
+    /// a real size, apply it — `set_size` drops the previous buffer, so
+    /// recovery always repaints in full.
+    fn check_size_reprobe(&mut self) -> io::Result<()> {
+        if !self.size_reprobe.active() || self.forced_size.is_some() {
+            return Ok(());
+        }
+        let now = Instant::now();
+        if !self
+            .size_reprobe
+            .should_reprobe(now, ftui_core::size_policy::ReprobeState::DEFAULT_INTERVAL)
+        {
+            return Ok(());
+        }
+        let Ok(reported) = self.events.size() else {
+            return Ok(());
+        };
+        let (effective, substitution) =
+            ftui_core::size_policy::SizePolicy::default().sanitize(reported);
+        // A backend whose `size()` already sanitizes (the native session)
+        // reports the fallback while the host is still degenerate, which
+        // equals the size we are already using — so "unchanged" means the
+        // host has not recovered yet and we keep probing.
+        if substitution.is_some() || effective == (self.width, self.height) {
+            return Ok(());
+        }
+        let _ = self.size_reprobe.on_real_size();
+        info!(
+            width = effective.0,
+            height = effective.1,
+            "size re-probe recovered real terminal size"
+        );
+        self.resize_coalescer
+            .record_external_apply(effective.0, effective.1, Instant::now());
+        self.apply_resize(effective.0, effective.1, Duration::ZERO, false)
+    }
+
     // removed: resize placeholder rendering (continuous reflow preferred)
 
     /// Get a reference to the model.
@@ -4736,6 +5889,15 @@ impl<M: Model> AppBuilder<M> {
         self
     }
 
+    /// Enable asciicast session recording with the given configuration.
+    pub fn with_session_recording(
+        mut self,
+        config: crate::asciicast::SessionRecordingConfig,
+    ) -> Self {
+        self.config.session_recording = config;
+        self
+    }
+
     /// Set the widget refresh selection configuration.
     pub fn with_widget_refresh(mut self, config: WidgetRefreshConfig) -> Self {
         self.config.widget_refresh = config;
@@ -6636,6 +7798,7 @@ mod tests {
             burst_exit_rate: 6.0,
             cooldown_frames: 2,
             rate_window_size: 6,
+            immediate_first: false,
             enable_logging: true,
             enable_bocpd: false,
             bocpd_config: None,
@@ -7417,6 +8580,208 @@ mod tests {
     // HEADLESS PROGRAM TESTS (bd-1av4o.2)
     // =========================================================================
 
+    /// Shared sink for capturing an extra view's presenter bytes.
+    #[derive(Clone, Default)]
+    struct SharedSink(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedSink {
+        fn contents(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+        }
+    }
+
+    /// Writer that fails on every write (simulates a dropped connection).
+    struct BrokenSink;
+
+    impl Write for BrokenSink {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("connection lost"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::Error::other("connection lost"))
+        }
+    }
+
+    fn extra_view_writer(sink: Box<dyn Write + Send>) -> TerminalWriter<Box<dyn Write + Send>> {
+        TerminalWriter::new(
+            sink,
+            ScreenMode::AltScreen,
+            UiAnchor::Bottom,
+            TerminalCapabilities::basic(),
+        )
+    }
+
+    /// Model whose view paints a full-width marker line.
+    struct MirrorModel {
+        text: String,
+    }
+
+    impl Model for MirrorModel {
+        type Message = Event;
+
+        fn update(&mut self, msg: Event) -> Cmd<Event> {
+            if let Event::Key(key) = msg
+                && let KeyCode::Char(c) = key.code
+            {
+                self.text.push(c);
+            }
+            Cmd::None
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            let width = frame.buffer.width();
+            let line = format!("[{}] w={width}", self.text);
+            for (x, ch) in line.chars().enumerate() {
+                if (x as u16) < width {
+                    frame
+                        .buffer
+                        .set(x as u16, 0, ftui_render::cell::Cell::from_char(ch));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extra_views_render_at_their_own_sizes() {
+        let mut program = headless_program_with_config(
+            MirrorModel {
+                text: String::new(),
+            },
+            ProgramConfig {
+                forced_size: Some((80, 24)),
+                intercept_signals: false,
+                ..ProgramConfig::default()
+            },
+        );
+
+        let wide = SharedSink::default();
+        let narrow = SharedSink::default();
+        let wide_id = program.add_view(
+            extra_view_writer(Box::new(wide.clone())),
+            60,
+            10,
+            |model: &MirrorModel, frame| model.view(frame),
+        );
+        let _narrow_id = program.add_view(
+            extra_view_writer(Box::new(narrow.clone())),
+            20,
+            5,
+            |model: &MirrorModel, frame| model.view(frame),
+        );
+        assert_ne!(wide_id, _narrow_id);
+
+        // One model change: every view renders its own-sized frame.
+        program
+            .push_view_event(
+                wide_id,
+                Event::Key(KeyEvent::new(KeyCode::Char('x'))),
+                |_, e| e,
+            )
+            .unwrap();
+        program.render_frame().unwrap();
+
+        assert!(wide.contents().contains("[x] w=60"), "{}", wide.contents());
+        assert!(
+            narrow.contents().contains("[x] w=20"),
+            "{}",
+            narrow.contents()
+        );
+    }
+
+    #[test]
+    fn view_resize_is_isolated() {
+        let mut program = headless_program_with_config(
+            MirrorModel {
+                text: String::new(),
+            },
+            ProgramConfig {
+                forced_size: Some((80, 24)),
+                intercept_signals: false,
+                ..ProgramConfig::default()
+            },
+        );
+        let a = SharedSink::default();
+        let b = SharedSink::default();
+        let a_id = program.add_view(
+            extra_view_writer(Box::new(a.clone())),
+            30,
+            6,
+            |model: &MirrorModel, frame| model.view(frame),
+        );
+        let _b_id = program.add_view(
+            extra_view_writer(Box::new(b.clone())),
+            40,
+            8,
+            |model: &MirrorModel, frame| model.view(frame),
+        );
+
+        assert!(program.resize_view(a_id, 50, 12));
+        program.mark_dirty();
+        program.render_frame().unwrap();
+
+        assert!(
+            a.contents().contains("w=50"),
+            "resized view: {}",
+            a.contents()
+        );
+        assert!(
+            b.contents().contains("w=40"),
+            "other view unchanged: {}",
+            b.contents()
+        );
+        assert!(!program.resize_view(9999, 10, 10));
+    }
+
+    #[test]
+    fn failed_view_is_dropped_without_tearing_down() {
+        let mut program = headless_program_with_config(
+            MirrorModel {
+                text: String::new(),
+            },
+            ProgramConfig {
+                forced_size: Some((80, 24)),
+                intercept_signals: false,
+                ..ProgramConfig::default()
+            },
+        );
+        let healthy = SharedSink::default();
+        let healthy_id = program.add_view(
+            extra_view_writer(Box::new(healthy.clone())),
+            20,
+            4,
+            |model: &MirrorModel, frame| model.view(frame),
+        );
+        let broken_id = program.add_view(
+            extra_view_writer(Box::new(BrokenSink)),
+            20,
+            4,
+            |model: &MirrorModel, frame| model.view(frame),
+        );
+
+        program.mark_dirty();
+        program.render_frame().unwrap();
+
+        // The broken view is gone; the healthy one keeps rendering.
+        assert!(!program.remove_view(broken_id), "already auto-dropped");
+        assert!(healthy.contents().contains("w=20"));
+
+        program.mark_dirty();
+        program.render_frame().unwrap();
+        assert!(program.remove_view(healthy_id));
+    }
+
     fn headless_program_with_config<M: Model>(
         model: M,
         config: ProgramConfig,
@@ -7493,6 +8858,32 @@ mod tests {
             resize_behavior: config.resize_behavior,
             fairness_guard: InputFairnessGuard::new(),
             event_recorder: None,
+            enable_suspend: config.enable_suspend,
+            session_recorder: None,
+            #[cfg(feature = "async")]
+            async_cmds: crate::async_cmd::AsyncCmdExecutor::new(config.async_spawner.0.clone()),
+            #[cfg(feature = "devtools")]
+            devtools: None,
+            extra_views: Vec::new(),
+            next_view_id: 1,
+            startup: None,
+            splash_view: None,
+            resize_view: None,
+            resize_storm_pending: None,
+            size_reprobe: ftui_core::size_policy::ReprobeState::default(),
+            has_terminal_focus: true,
+            privacy: config.privacy,
+            #[cfg(feature = "state-persistence")]
+            model_persist: None,
+            idle_monitor: config
+                .idle
+                .map(|idle| crate::idle::IdleMonitor::new(idle, Instant::now())),
+            idle_messages: None,
+            tick_scale: crate::idle::TickRateScale::new(),
+            evidence_emitter: None,
+            evidence_resize_pending: false,
+            secret_input_active: false,
+            hide_cursor_when_unfocused: config.hide_cursor_when_unfocused,
             subscriptions,
             task_sender,
             task_receiver,
@@ -7566,13 +8957,61 @@ mod tests {
         program.dirty = false;
 
         program
-            .apply_resize(0, 0, Duration::ZERO, false)
+            .apply_resize(0, 0, Duration::ZERO, false)
+            .expect("resize");
+
+        assert_eq!(program.width, 1);
+        assert_eq!(program.height, 1);
+        assert_eq!(program.model().last_size, Some((1, 1)));
+        assert!(program.dirty);
+    }
+
+    #[test]
+    fn headless_resize_event_sanitizes_degenerate_sizes() {
+        use ftui_core::size_policy::{
+            SizeSubstitutionReason, clear_size_substitution, last_size_substitution,
+        };
+
+        clear_size_substitution();
+        let config = ProgramConfig::default().with_legacy_resize(true);
+        let mut program = headless_program_with_config(TestModel { value: 0 }, config);
+
+        // Absurd report: clamped to the policy max, recorded as evidence,
+        // and the recovery re-probe armed.
+        program
+            .handle_event(Event::Resize {
+                width: 65_535,
+                height: 2,
+            })
+            .expect("resize");
+        assert_eq!((program.width, program.height), (2048, 2));
+        let substitution = last_size_substitution().expect("evidence recorded");
+        assert_eq!(substitution.reason, SizeSubstitutionReason::ExceedsMax);
+        assert!(program.size_reprobe.active());
+
+        // Zero report: replaced by the fallback.
+        program
+            .handle_event(Event::Resize {
+                width: 0,
+                height: 0,
+            })
             .expect("resize");
+        assert_eq!((program.width, program.height), (80, 24));
+        assert_eq!(
+            last_size_substitution().expect("evidence recorded").reason,
+            SizeSubstitutionReason::ZeroSizeReported
+        );
 
-        assert_eq!(program.width, 1);
-        assert_eq!(program.height, 1);
-        assert_eq!(program.model().last_size, Some((1, 1)));
-        assert!(program.dirty);
+        // A real report applies as-is and disarms the re-probe.
+        program
+            .handle_event(Event::Resize {
+                width: 120,
+                height: 40,
+            })
+            .expect("resize");
+        assert_eq!((program.width, program.height), (120, 40));
+        assert!(!program.size_reprobe.active());
+        clear_size_substitution();
     }
 
     #[test]
@@ -9724,4 +11163,641 @@ mod tests {
             assert!(!lim.fallback.is_empty());
         }
     }
+
+    // =========================================================================
+    // Startup splash phase (two-phase startup)
+    // =========================================================================
+
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    enum SplashMsg {
+        HeavyDone,
+        Key(char),
+        Other,
+    }
+
+    impl From<Event> for SplashMsg {
+        fn from(event: Event) -> Self {
+            match event {
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char(c),
+                    ..
+                }) => SplashMsg::Key(c),
+                _ => SplashMsg::Other,
+            }
+        }
+    }
+
+    struct SplashModel {
+        order: Arc<Mutex<Vec<&'static str>>>,
+        seen_keys: Vec<char>,
+    }
+
+    impl Model for SplashModel {
+        type Message = SplashMsg;
+
+        fn init(&mut self) -> Cmd<SplashMsg> {
+            let order = self.order.clone();
+            Cmd::task(move || {
+                order.lock().unwrap().push("heavy-work");
+                SplashMsg::HeavyDone
+            })
+        }
+
+        fn update(&mut self, msg: SplashMsg) -> Cmd<SplashMsg> {
+            match msg {
+                SplashMsg::HeavyDone => Cmd::Batch(vec![Cmd::complete_startup(), Cmd::quit()]),
+                SplashMsg::Key(c) => {
+                    self.seen_keys.push(c);
+                    Cmd::none()
+                }
+                SplashMsg::Other => Cmd::none(),
+            }
+        }
+
+        fn view(&self, _frame: &mut Frame) {}
+    }
+
+    fn key_press(c: char) -> Event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        })
+    }
+
+    #[test]
+    fn splash_first_frame_painted_before_heavy_work() {
+        let order: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(Vec::new()));
+        let model = SplashModel {
+            order: order.clone(),
+            seen_keys: Vec::new(),
+        };
+        let mut program = headless_program_with_config(model, ProgramConfig::default());
+        program.enable_startup_splash(StartupConfig::default());
+        let view_order = order.clone();
+        program.set_splash_view(move |_frame, _info| {
+            view_order.lock().unwrap().push("splash-frame");
+        });
+
+        program.run_event_loop().expect("event loop");
+
+        let log = order.lock().unwrap();
+        let first_frame = log
+            .iter()
+            .position(|s| *s == "splash-frame")
+            .expect("splash frame painted");
+        let heavy = log
+            .iter()
+            .position(|s| *s == "heavy-work")
+            .expect("heavy work ran");
+        assert!(
+            first_frame < heavy,
+            "first frame must precede heavy work: {log:?}"
+        );
+    }
+
+    #[test]
+    fn splash_progress_messages_render() {
+        let model = SplashModel {
+            order: Arc::new(Mutex::new(Vec::new())),
+            seen_keys: Vec::new(),
+        };
+        let mut program = headless_program_with_config(model, ProgramConfig::default());
+        program.enable_startup_splash(StartupConfig::default());
+
+        program
+            .execute_cmd(Cmd::splash_progress("Loading index 1/3"))
+            .expect("progress");
+        program.render_frame().expect("render");
+
+        let bytes = program.writer.into_inner().expect("writer output");
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(output.contains("Loading index 1/3"), "got: {output}");
+    }
+
+    #[test]
+    fn splash_ctrl_c_cancels_startup_cx_and_stops() {
+        let clock = ftui_core::cx::LabClock::new();
+        let model = SplashModel {
+            order: Arc::new(Mutex::new(Vec::new())),
+            seen_keys: Vec::new(),
+        };
+        let mut program = headless_program_with_config(model, ProgramConfig::default());
+        program.enable_startup_splash(StartupConfig {
+            lab_clock: Some(clock.clone()),
+            ..Default::default()
+        });
+        let cx = program.startup_cx().expect("startup cx");
+        assert!(!cx.is_cancelled());
+
+        program
+            .handle_event(Event::Key(KeyEvent {
+                code: KeyCode::Char('c'),
+                modifiers: Modifiers::CTRL,
+                kind: KeyEventKind::Press,
+                ch: None,
+            }))
+            .expect("ctrl+c");
+
+        assert!(cx.is_cancelled(), "startup Cx cancelled");
+        assert!(!program.running, "program stops for clean teardown");
+        // The model never saw the abort key.
+        assert!(program.model().seen_keys.is_empty());
+    }
+
+    #[test]
+    fn splash_queued_input_replays_after_complete() {
+        let model = SplashModel {
+            order: Arc::new(Mutex::new(Vec::new())),
+            seen_keys: Vec::new(),
+        };
+        let mut program = headless_program_with_config(model, ProgramConfig::default());
+        program.enable_startup_splash(StartupConfig::default());
+
+        program.handle_event(key_press('a')).expect("key a");
+        program.handle_event(key_press('b')).expect("key b");
+        assert!(program.model().seen_keys.is_empty(), "input held back");
+
+        program
+            .execute_cmd(Cmd::complete_startup())
+            .expect("complete");
+
+        assert!(!program.startup_active());
+        assert_eq!(program.model().seen_keys, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn splash_drop_policy_discards_input() {
+        let model = SplashModel {
+            order: Arc::new(Mutex::new(Vec::new())),
+            seen_keys: Vec::new(),
+        };
+        let mut program = headless_program_with_config(model, ProgramConfig::default());
+        program.enable_startup_splash(StartupConfig {
+            input_policy: SplashInputPolicy::Drop,
+            ..Default::default()
+        });
+
+        program.handle_event(key_press('a')).expect("key a");
+        program
+            .execute_cmd(Cmd::complete_startup())
+            .expect("complete");
+
+        assert!(program.model().seen_keys.is_empty());
+    }
+
+    // =========================================================================
+    // Terminal focus tracking (has_terminal_focus, cursor suppression)
+    // =========================================================================
+
+    struct CursorModel;
+
+    impl Model for CursorModel {
+        type Message = SplashMsg;
+
+        fn update(&mut self, _msg: SplashMsg) -> Cmd<SplashMsg> {
+            Cmd::none()
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            frame.cursor_position = Some((2, 1));
+            frame.cursor_visible = true;
+        }
+    }
+
+    #[test]
+    fn has_terminal_focus_tracks_focus_flap() {
+        let mut program = headless_program_with_config(CursorModel, ProgramConfig::default());
+        assert!(program.has_terminal_focus());
+
+        program
+            .handle_event(Event::Focus(false))
+            .expect("focus out");
+        assert!(!program.has_terminal_focus());
+
+        program.handle_event(Event::Focus(true)).expect("focus in");
+        assert!(program.has_terminal_focus());
+
+        program
+            .handle_event(Event::Focus(false))
+            .expect("focus out");
+        assert!(!program.has_terminal_focus());
+    }
+
+    #[test]
+    fn unfocused_terminal_suppresses_cursor() {
+        let mut program = headless_program_with_config(CursorModel, ProgramConfig::default());
+
+        program
+            .handle_event(Event::Focus(false))
+            .expect("focus out");
+        program.render_frame().expect("render");
+        let bytes = program.writer.into_inner().expect("writer output");
+        // `into_inner` cleanup always restores the cursor with one final
+        // ?25h; the present path itself must not have shown it.
+        let shows = bytes.windows(6).filter(|w| *w == b"\x1b[?25h").count();
+        assert_eq!(shows, 1, "cursor must stay hidden while unfocused");
+    }
+
+    #[test]
+    fn focused_terminal_shows_cursor() {
+        let mut program = headless_program_with_config(CursorModel, ProgramConfig::default());
+        program.render_frame().expect("render");
+        let bytes = program.writer.into_inner().expect("writer output");
+        let shows = bytes.windows(6).filter(|w| *w == b"\x1b[?25h").count();
+        assert!(shows >= 2, "present path must show the cursor when focused");
+    }
+
+    #[test]
+    fn cursor_suppression_can_be_disabled() {
+        let config = ProgramConfig {
+            hide_cursor_when_unfocused: false,
+            ..Default::default()
+        };
+        let mut program = headless_program_with_config(CursorModel, config);
+        program
+            .handle_event(Event::Focus(false))
+            .expect("focus out");
+        program.render_frame().expect("render");
+        let bytes = program.writer.into_inner().expect("writer output");
+        let shows = bytes.windows(6).filter(|w| *w == b"\x1b[?25h").count();
+        assert!(shows >= 2, "suppression disabled: cursor still shown");
+    }
+
+    // =========================================================================
+    // Cheap resize view (resize-storm placeholder)
+    // =========================================================================
+
+    #[test]
+    fn resize_storm_placeholder_renders_and_clears() {
+        let mut program = headless_program_with_config(CursorModel, ProgramConfig::default());
+        program.set_resize_view(|_model, frame, pending| {
+            use ftui_render::drawing::Draw;
+            let text = format!("RESIZING {}x{}", pending.0, pending.1);
+            frame
+                .buffer
+                .print_text(0, 0, &text, ftui_render::cell::Cell::default());
+        });
+
+        // Two rapid resizes: the second coalesces (ShowPlaceholder).
+        program
+            .handle_event(Event::Resize {
+                width: 100,
+                height: 30,
+            })
+            .expect("resize 1");
+        program
+            .handle_event(Event::Resize {
+                width: 110,
+                height: 32,
+            })
+            .expect("resize 2");
+        program.process_resize_coalescer().expect("tick");
+        assert!(program.resize_storm_pending.is_some(), "storm active");
+
+        program.render_frame().expect("render placeholder");
+
+        // Settle: force the apply through the coalescer deadline.
+        std::thread::sleep(Duration::from_millis(120));
+        program.process_resize_coalescer().expect("apply");
+        assert_eq!(program.resize_storm_pending, None, "placeholder cleared");
+        assert_eq!((program.width, program.height), (110, 32));
+
+        program.render_frame().expect("render real view");
+        let bytes = program.writer.into_inner().expect("writer output");
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(
+            output.contains("RESIZING 110x32"),
+            "placeholder frame present"
+        );
+    }
+
+    // =========================================================================
+    // Telemetry privacy (secret-input scope)
+    // =========================================================================
+
+    struct SecretModel;
+
+    impl Model for SecretModel {
+        type Message = SplashMsg;
+
+        fn update(&mut self, _msg: SplashMsg) -> Cmd<SplashMsg> {
+            Cmd::none()
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            // The contract a focused masked input fulfils.
+            frame.secret_input = true;
+        }
+    }
+
+    #[test]
+    fn secret_frame_tightens_full_to_keys_only() {
+        let config = ProgramConfig {
+            privacy: TelemetryPrivacyPolicy::Full,
+            ..Default::default()
+        };
+        let mut program = headless_program_with_config(SecretModel, config);
+        assert_eq!(program.effective_privacy(), TelemetryPrivacyPolicy::Full);
+
+        program.render_frame().expect("render");
+        assert!(program.secret_input_active);
+        assert_eq!(
+            program.effective_privacy(),
+            TelemetryPrivacyPolicy::KeysOnly,
+            "masked-input frame forces KeysOnly over Full"
+        );
+    }
+
+    #[test]
+    fn non_secret_frame_releases_the_scope() {
+        let config = ProgramConfig {
+            privacy: TelemetryPrivacyPolicy::Full,
+            ..Default::default()
+        };
+        let mut program = headless_program_with_config(CursorModel, config);
+        program.secret_input_active = true;
+        program.render_frame().expect("render");
+        assert!(!program.secret_input_active, "plain frame clears the scope");
+        assert_eq!(program.effective_privacy(), TelemetryPrivacyPolicy::Full);
+    }
+
+    #[test]
+    fn privacy_policy_defaults_to_keys_only_and_changes_at_runtime() {
+        let mut program = headless_program_with_config(CursorModel, ProgramConfig::default());
+        assert_eq!(program.privacy_policy(), TelemetryPrivacyPolicy::KeysOnly);
+        program.set_privacy_policy(TelemetryPrivacyPolicy::Off);
+        assert_eq!(program.privacy_policy(), TelemetryPrivacyPolicy::Off);
+        // Setting the same policy again is a no-op (no duplicate markers).
+        program.set_privacy_policy(TelemetryPrivacyPolicy::Off);
+        assert_eq!(program.privacy_policy(), TelemetryPrivacyPolicy::Off);
+    }
+
+    // =========================================================================
+    // Model persistence (feature state-persistence)
+    // =========================================================================
+
+    #[cfg(feature = "state-persistence")]
+    mod model_persistence_program {
+        use super::*;
+        use crate::model_persist::{ModelPersistence, Persist, PersistedState};
+
+        #[derive(Default)]
+        struct Counting {
+            count: u32,
+        }
+
+        impl Model for Counting {
+            type Message = SplashMsg;
+            fn update(&mut self, _msg: SplashMsg) -> Cmd<SplashMsg> {
+                Cmd::none()
+            }
+            fn view(&self, _frame: &mut Frame) {}
+        }
+
+        impl Persist for Counting {
+            fn persist(&self) -> PersistedState {
+                let mut state = PersistedState::new(1);
+                state.set("count", self.count);
+                state
+            }
+            fn hydrate(&mut self, state: PersistedState) {
+                if let Some(count) = state.get("count") {
+                    self.count = count;
+                }
+            }
+        }
+
+        fn temp_path(label: &str) -> std::path::PathBuf {
+            std::env::temp_dir().join(format!(
+                "ftui_prog_persist_{label}_{}.json",
+                std::process::id()
+            ))
+        }
+
+        #[test]
+        fn program_hydrates_before_init_and_saves_on_shutdown() {
+            let path = temp_path("hydrate");
+            let _ = std::fs::remove_file(&path);
+
+            // First program: mutate, save via the shutdown hook.
+            {
+                let mut program =
+                    headless_program_with_config(Counting::default(), ProgramConfig::default());
+                let warning = program.enable_model_persistence(ModelPersistence::with_file(&path));
+                assert_eq!(warning, None);
+                program.model_mut().count = 42;
+                program.save_model_state();
+            }
+
+            // Second program ("restart"): hydrated before run.
+            {
+                let mut program =
+                    headless_program_with_config(Counting::default(), ProgramConfig::default());
+                let warning = program.enable_model_persistence(ModelPersistence::with_file(&path));
+                assert_eq!(warning, None);
+                assert_eq!(program.model().count, 42, "restored before init");
+            }
+            let _ = std::fs::remove_file(&path);
+        }
+
+        #[test]
+        fn corrupt_state_warns_and_starts_with_defaults() {
+            let path = temp_path("corrupt");
+            std::fs::write(&path, b"garbage").unwrap();
+            let mut program =
+                headless_program_with_config(Counting::default(), ProgramConfig::default());
+            let warning = program.enable_model_persistence(ModelPersistence::with_file(&path));
+            assert!(warning.is_some(), "corruption surfaces a warning");
+            assert_eq!(program.model().count, 0, "defaults kept");
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+
+    // =========================================================================
+    // Idle management
+    // =========================================================================
+
+    mod idle_program {
+        use super::*;
+        use crate::idle::IdleConfig;
+
+        #[derive(Default)]
+        struct IdleModel {
+            entered: u32,
+            exited: u32,
+        }
+
+        #[derive(Debug)]
+        enum IdleMsg {
+            Entered,
+            Exited,
+            Input,
+        }
+
+        impl From<Event> for IdleMsg {
+            fn from(_event: Event) -> Self {
+                IdleMsg::Input
+            }
+        }
+
+        impl Model for IdleModel {
+            type Message = IdleMsg;
+            fn update(&mut self, msg: IdleMsg) -> Cmd<IdleMsg> {
+                match msg {
+                    IdleMsg::Entered => self.entered += 1,
+                    IdleMsg::Exited => self.exited += 1,
+                    IdleMsg::Input => {}
+                }
+                Cmd::none()
+            }
+            fn view(&self, _frame: &mut Frame) {}
+        }
+
+        fn idle_program(threshold: Duration) -> Program<IdleModel, HeadlessEventSource, Vec<u8>> {
+            let config = ProgramConfig {
+                idle: Some(IdleConfig {
+                    threshold,
+                    tick_multiplier: 4,
+                    ..IdleConfig::default()
+                }),
+                ..Default::default()
+            };
+            let mut program = headless_program_with_config(IdleModel::default(), config);
+            program.set_idle_messages(|| IdleMsg::Entered, || IdleMsg::Exited);
+            program
+        }
+
+        #[test]
+        fn idle_entry_throttles_and_notifies() {
+            // Zero threshold: the first poll crosses immediately.
+            let mut program = idle_program(Duration::ZERO);
+            let scale = program.tick_rate_scale();
+            assert_eq!(scale.multiplier(), 1);
+
+            program.check_idle().unwrap();
+            assert_eq!(scale.multiplier(), 4, "multiplier applied");
+            assert_eq!(program.model().entered, 1);
+            assert_eq!(program.model().exited, 0);
+            assert!(!program.idle_state().unwrap().active);
+
+            // Entering again is not re-reported.
+            program.check_idle().unwrap();
+            assert_eq!(program.model().entered, 1);
+        }
+
+        #[test]
+        fn input_exits_idle_restores_rate_and_forces_render() {
+            let mut program = idle_program(Duration::ZERO);
+            let scale = program.tick_rate_scale();
+            program.check_idle().unwrap();
+            assert_eq!(scale.multiplier(), 4);
+
+            program.dirty = false;
+            program
+                .handle_event(Event::Key(KeyEvent::new(KeyCode::Char('x'))))
+                .unwrap();
+            assert_eq!(scale.multiplier(), 1, "full rate restored");
+            assert_eq!(program.model().exited, 1);
+            assert!(program.dirty, "exit forces a repaint");
+            assert!(program.idle_state().unwrap().active);
+        }
+
+        #[test]
+        fn focus_loss_triggers_idle_when_configured() {
+            let config = ProgramConfig {
+                idle: Some(IdleConfig {
+                    idle_on_focus_loss: true,
+                    pause_ticks: true,
+                    ..IdleConfig::default()
+                }),
+                ..Default::default()
+            };
+            let mut program = headless_program_with_config(IdleModel::default(), config);
+            program.set_idle_messages(|| IdleMsg::Entered, || IdleMsg::Exited);
+            let scale = program.tick_rate_scale();
+
+            program.handle_event(Event::Focus(false)).unwrap();
+            assert!(scale.is_paused(), "pause_ticks engages on focus loss");
+            assert_eq!(program.model().entered, 1);
+
+            program.handle_event(Event::Focus(true)).unwrap();
+            assert!(!scale.is_paused());
+            assert_eq!(program.model().exited, 1);
+        }
+
+        #[test]
+        fn data_messages_still_render_while_idle() {
+            let mut program = idle_program(Duration::ZERO);
+            program.check_idle().unwrap();
+            program.dirty = false;
+
+            // A data-driven message (e.g. from a subscription) while idle
+            // still runs update and marks the frame dirty.
+            program.execute_cmd(Cmd::Msg(IdleMsg::Input)).unwrap();
+            assert!(program.dirty, "model changes repaint even while idle");
+        }
+    }
+
+    // =========================================================================
+    // Evidence events
+    // =========================================================================
+
+    mod evidence_program {
+        use super::*;
+        use crate::evidence_events::{EvidenceEmitter, MemorySink, validate_evidence_stream};
+
+        #[test]
+        fn frames_emit_schema_valid_evidence_with_reasons() {
+            let mut program =
+                headless_program_with_config(TestModel { value: 0 }, ProgramConfig::default());
+            let sink = MemorySink::new();
+            program.set_evidence_emitter(EvidenceEmitter::new(Box::new(sink.clone())));
+
+            program.mark_dirty();
+            program.render_frame().expect("first frame");
+            program.mark_dirty();
+            program.render_frame().expect("second frame");
+            program
+                .apply_resize(100, 30, Duration::ZERO, false)
+                .expect("resize");
+            program.mark_dirty();
+            program.render_frame().expect("post-resize frame");
+
+            let lines = sink.lines();
+            let stream = lines.join("\n");
+            assert_eq!(
+                validate_evidence_stream(&stream),
+                Ok(lines.len()),
+                "every emitted line validates: {stream}"
+            );
+
+            let reasons: Vec<&str> = lines
+                .iter()
+                .filter(|l| l.contains("\"event\":\"diff_decision\""))
+                .map(|l| {
+                    let idx = l.find("\"reason\":\"").unwrap() + 10;
+                    &l[idx..l.len() - 2]
+                })
+                .collect();
+            assert_eq!(
+                reasons,
+                vec![
+                    "full_repaint_first_frame",
+                    "partial_diff",
+                    "full_repaint_resize"
+                ],
+                "{lines:#?}"
+            );
+            assert!(
+                lines
+                    .iter()
+                    .any(|l| l.contains("\"event\":\"frame_timing\"")),
+                "timings emitted alongside decisions"
+            );
+        }
+    }
 }
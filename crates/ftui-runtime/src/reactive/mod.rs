@@ -9,6 +9,8 @@
 //! - [`Subscription`]: RAII guard that automatically unsubscribes on drop.
 //! - [`Computed`]: A lazily-evaluated, memoized value derived from one or
 //!   more `Observable` dependencies.
+//! - [`ObservableVec`]: A collection whose mutations emit fine-grained
+//!   [`VecDelta`]s so list widgets can update incrementally.
 //! - [`BatchScope`]: RAII guard that defers all `Observable` notifications
 //!   until the scope exits, preventing intermediate renders.
 //!
@@ -40,6 +42,7 @@ pub mod batch;
 pub mod binding;
 pub mod computed;
 pub mod observable;
+pub mod observable_vec;
 
 pub use batch::BatchScope;
 pub use binding::{
@@ -47,3 +50,4 @@ pub use binding::{
 };
 pub use computed::Computed;
 pub use observable::{Observable, Subscription};
+pub use observable_vec::{ListDeltaMirror, ObservableVec, VecDelta, VecDeltaTarget, apply_delta};
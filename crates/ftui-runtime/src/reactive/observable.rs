@@ -258,6 +258,18 @@ pub struct Subscription {
     _guard: Box<dyn std::any::Any>,
 }
 
+impl Subscription {
+    /// Create a guard holding an arbitrary strong callback reference.
+    /// Used by the other reactive primitives (e.g.
+    /// [`ObservableVec`](super::observable_vec::ObservableVec)) that
+    /// manage their own subscriber lists.
+    pub(crate) fn hold(guard: impl std::any::Any) -> Self {
+        Self {
+            _guard: Box::new(guard),
+        }
+    }
+}
+
 impl std::fmt::Debug for Subscription {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Subscription").finish_non_exhaustive()
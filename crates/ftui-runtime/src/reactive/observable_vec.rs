@@ -0,0 +1,1035 @@
+#![forbid(unsafe_code)]
+
+//! Observable collection with fine-grained change deltas.
+//!
+//! Binding a list widget to an `Observable<Vec<T>>` forces a full item
+//! rebuild on every change because the notification carries no
+//! information about *what* changed. [`ObservableVec<T>`] fixes that:
+//! each mutation emits a [`VecDelta`] describing exactly which indices
+//! were touched, so subscribers (list widgets, mirrors, caches) can
+//! update incrementally — and view state like selection and scroll can
+//! be *adjusted* per-delta instead of reset (see [`VecDeltaTarget`]).
+//!
+//! # Invariants
+//!
+//! Consistent with [`Observable`](super::Observable):
+//!
+//! 1. `version` increments by exactly 1 per value-changing mutation.
+//! 2. No-op mutations (empty `extend`, `clear` on empty, `update` that
+//!    leaves the element equal, `retain` keeping everything) emit no
+//!    delta and do not bump the version.
+//! 3. Subscribers are notified in registration order; deltas arrive in
+//!    mutation order.
+//! 4. Inside a [`BatchScope`](super::BatchScope), deltas are queued and
+//!    compatible ones coalesce (adjacent inserts merge, removal drains
+//!    merge, repeated updates of one index dedupe); a batch whose
+//!    mutations cannot be expressed as one delta collapses to `Reset`.
+//!    Subscribers are notified once, on batch exit.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use super::observable::Subscription;
+
+/// A fine-grained description of one collection mutation.
+///
+/// Indices refer to the collection state *at the time the delta applies*
+/// (after earlier deltas in the same notification run have been applied).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VecDelta {
+    /// `count` elements were inserted starting at `index`.
+    Inserted { index: usize, count: usize },
+    /// `count` elements were removed starting at `index`.
+    Removed { index: usize, count: usize },
+    /// The element at `index` was replaced or mutated in place.
+    Updated { index: usize },
+    /// The elements at `from` and `to` exchanged positions (`swap`).
+    Moved { from: usize, to: usize },
+    /// The collection changed wholesale; rebuild from scratch.
+    Reset,
+}
+
+/// Subscriber callback: receives the post-mutation items and the delta.
+type DeltaCallbackRc<T> = Rc<dyn Fn(&[T], &VecDelta)>;
+type DeltaCallbackWeak<T> = Weak<dyn Fn(&[T], &VecDelta)>;
+
+struct VecInner<T> {
+    items: Vec<T>,
+    version: u64,
+    subscribers: Vec<DeltaCallbackWeak<T>>,
+    /// Deltas queued while a batch scope is active.
+    pending: Vec<VecDelta>,
+}
+
+/// A shared, version-tracked `Vec` whose mutations emit [`VecDelta`]s.
+///
+/// Cloning an `ObservableVec` creates a new handle to the **same** inner
+/// state, like [`Observable`](super::Observable).
+pub struct ObservableVec<T> {
+    inner: Rc<RefCell<VecInner<T>>>,
+}
+
+impl<T> Clone for ObservableVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for ObservableVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("ObservableVec")
+            .field("items", &inner.items)
+            .field("version", &inner.version)
+            .field("subscriber_count", &inner.subscribers.len())
+            .finish()
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Default for ObservableVec<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> ObservableVec<T> {
+    /// Create a new observable collection with the given initial items.
+    #[must_use]
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(VecInner {
+                items,
+                version: 0,
+                subscribers: Vec::new(),
+                pending: Vec::new(),
+            })),
+        }
+    }
+
+    /// Number of items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().items.len()
+    }
+
+    /// Whether the collection is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().items.is_empty()
+    }
+
+    /// Clone of the item at `index`, if in bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.inner.borrow().items.get(index).cloned()
+    }
+
+    /// Clone of all items.
+    #[must_use]
+    pub fn items(&self) -> Vec<T> {
+        self.inner.borrow().items.clone()
+    }
+
+    /// Access the items by reference without cloning.
+    pub fn with<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        f(&self.inner.borrow().items)
+    }
+
+    /// Current version number. Increments by 1 per value-changing mutation.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.inner.borrow().version
+    }
+
+    /// Number of currently registered subscribers (including dead ones
+    /// not yet pruned).
+    #[must_use]
+    pub fn subscriber_count(&self) -> usize {
+        self.inner.borrow().subscribers.len()
+    }
+
+    /// Subscribe to deltas. The callback receives the post-mutation items
+    /// and the delta that produced them. Dropping the returned guard
+    /// unsubscribes.
+    pub fn subscribe(&self, callback: impl Fn(&[T], &VecDelta) + 'static) -> Subscription {
+        let strong: DeltaCallbackRc<T> = Rc::new(callback);
+        let weak = Rc::downgrade(&strong);
+        self.inner.borrow_mut().subscribers.push(weak);
+        Subscription::hold(strong)
+    }
+
+    // ── Mutations ────────────────────────────────────────────────────────
+
+    /// Append an item. Emits `Inserted { index: old_len, count: 1 }`.
+    pub fn push(&self, item: T) {
+        let index = {
+            let mut inner = self.inner.borrow_mut();
+            inner.items.push(item);
+            inner.version += 1;
+            inner.items.len() - 1
+        };
+        self.emit(VecDelta::Inserted { index, count: 1 });
+    }
+
+    /// Insert an item at `index`. Emits `Inserted { index, count: 1 }`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len` (same contract as `Vec::insert`).
+    pub fn insert(&self, index: usize, item: T) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.items.insert(index, item);
+            inner.version += 1;
+        }
+        self.emit(VecDelta::Inserted { index, count: 1 });
+    }
+
+    /// Remove and return the item at `index`. Emits
+    /// `Removed { index, count: 1 }`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len` (same contract as `Vec::remove`).
+    pub fn remove(&self, index: usize) -> T {
+        let removed = {
+            let mut inner = self.inner.borrow_mut();
+            let removed = inner.items.remove(index);
+            inner.version += 1;
+            removed
+        };
+        self.emit(VecDelta::Removed { index, count: 1 });
+        removed
+    }
+
+    /// Exchange the items at `a` and `b`. Emits `Moved { from: a, to: b }`.
+    /// A no-op (no delta, no version bump) when `a == b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&self, a: usize, b: usize) {
+        if a == b {
+            // Still bounds-check so the no-op keeps Vec::swap's contract.
+            let inner = self.inner.borrow();
+            assert!(a < inner.items.len(), "swap index out of bounds");
+            return;
+        }
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.items.swap(a, b);
+            inner.version += 1;
+        }
+        self.emit(VecDelta::Moved { from: a, to: b });
+    }
+
+    /// Mutate the item at `index` in place. Emits `Updated { index }` if
+    /// the item changed (by `PartialEq` against a snapshot); a no-op
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn update(&self, index: usize, f: impl FnOnce(&mut T)) {
+        let changed = {
+            let mut inner = self.inner.borrow_mut();
+            let old = inner.items[index].clone();
+            f(&mut inner.items[index]);
+            if inner.items[index] != old {
+                inner.version += 1;
+                true
+            } else {
+                false
+            }
+        };
+        if changed {
+            self.emit(VecDelta::Updated { index });
+        }
+    }
+
+    /// Replace the item at `index`. Emits `Updated { index }` if the new
+    /// item differs from the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn set(&self, index: usize, item: T) {
+        self.update(index, move |slot| *slot = item);
+    }
+
+    /// Remove all items. Emits `Reset`. A no-op when already empty.
+    pub fn clear(&self) {
+        let changed = {
+            let mut inner = self.inner.borrow_mut();
+            if inner.items.is_empty() {
+                false
+            } else {
+                inner.items.clear();
+                inner.version += 1;
+                true
+            }
+        };
+        if changed {
+            self.emit(VecDelta::Reset);
+        }
+    }
+
+    /// Replace the entire contents. Emits `Reset`. A no-op when the new
+    /// items equal the current ones.
+    pub fn replace(&self, items: Vec<T>) {
+        let changed = {
+            let mut inner = self.inner.borrow_mut();
+            if inner.items == items {
+                false
+            } else {
+                inner.items = items;
+                inner.version += 1;
+                true
+            }
+        };
+        if changed {
+            self.emit(VecDelta::Reset);
+        }
+    }
+
+    /// Append items from an iterator. Emits a single
+    /// `Inserted { index: old_len, count }`; a no-op when the iterator is
+    /// empty.
+    pub fn extend(&self, items: impl IntoIterator<Item = T>) {
+        let inserted = {
+            let mut inner = self.inner.borrow_mut();
+            let old_len = inner.items.len();
+            inner.items.extend(items);
+            let count = inner.items.len() - old_len;
+            if count > 0 {
+                inner.version += 1;
+            }
+            (count > 0).then_some(VecDelta::Inserted {
+                index: old_len,
+                count,
+            })
+        };
+        if let Some(delta) = inserted {
+            self.emit(delta);
+        }
+    }
+
+    /// Keep only items for which `keep` returns true. Emits one
+    /// `Removed { index, count }` per contiguous run of removed items, in
+    /// descending index order so each delta's index is valid when it is
+    /// applied. A no-op when nothing is removed.
+    pub fn retain(&self, mut keep: impl FnMut(&T) -> bool) {
+        // Collect contiguous runs of removed original indices, then drop
+        // them back-to-front so earlier runs' indices stay valid.
+        let runs = {
+            let mut inner = self.inner.borrow_mut();
+            let mut runs: Vec<(usize, usize)> = Vec::new();
+            for (index, item) in inner.items.iter().enumerate() {
+                if keep(item) {
+                    continue;
+                }
+                match runs.last_mut() {
+                    Some((start, count)) if *start + *count == index => *count += 1,
+                    _ => runs.push((index, 1)),
+                }
+            }
+            if runs.is_empty() {
+                return;
+            }
+            for &(start, count) in runs.iter().rev() {
+                inner.items.drain(start..start + count);
+            }
+            inner.version += 1;
+            runs
+        };
+        if super::batch::is_batching() {
+            for &(index, count) in runs.iter().rev() {
+                self.emit(VecDelta::Removed { index, count });
+            }
+        } else {
+            // One notify pass for all runs: subscribers are collected and
+            // the items snapshot cloned once, not once per run.
+            let deltas: Vec<VecDelta> = runs
+                .iter()
+                .rev()
+                .map(|&(index, count)| VecDelta::Removed { index, count })
+                .collect();
+            self.notify(&deltas);
+        }
+    }
+
+    // ── Notification ─────────────────────────────────────────────────────
+
+    /// Deliver a delta: immediately outside a batch, queued (with
+    /// coalescing) inside one.
+    fn emit(&self, delta: VecDelta) {
+        if super::batch::is_batching() {
+            super::batch::record_rows_changed(1);
+            self.inner.borrow_mut().queue_pending(delta);
+            let key = Rc::as_ptr(&self.inner) as *const () as usize;
+            let source = self.clone();
+            super::batch::defer_or_run_keyed(key, move || source.flush_pending());
+            return;
+        }
+        self.notify(&[delta]);
+    }
+
+    /// Drain queued deltas and notify subscribers (batch exit).
+    fn flush_pending(&self) {
+        let pending = std::mem::take(&mut self.inner.borrow_mut().pending);
+        if !pending.is_empty() {
+            self.notify(&pending);
+        }
+    }
+
+    fn notify(&self, deltas: &[VecDelta]) {
+        // Collect live callbacks and a snapshot of the items first, so no
+        // borrow is held while callbacks run.
+        let (callbacks, items): (Vec<DeltaCallbackRc<T>>, Vec<T>) = {
+            let mut inner = self.inner.borrow_mut();
+            inner.subscribers.retain(|w| w.strong_count() > 0);
+            let callbacks = inner
+                .subscribers
+                .iter()
+                .filter_map(|w| w.upgrade())
+                .collect();
+            (callbacks, inner.items.clone())
+        };
+        if callbacks.is_empty() {
+            return;
+        }
+        for delta in deltas {
+            for cb in &callbacks {
+                cb(&items, delta);
+            }
+        }
+    }
+}
+
+impl<T> VecInner<T> {
+    /// Queue a delta for batch flush, coalescing with the queued delta
+    /// when compatible.
+    ///
+    /// A queued delta is applied by subscribers against the *final*
+    /// (post-batch) items, so only combinations that remain expressible
+    /// as one delta are kept: adjacent inserts merge, a front-to-back or
+    /// back-to-front removal drain merges, and repeated updates of one
+    /// index dedupe. Any other mixture collapses to [`VecDelta::Reset`]
+    /// (a full rebuild — no worse than an un-delta'd `Observable<Vec>`).
+    fn queue_pending(&mut self, delta: VecDelta) {
+        use VecDelta::{Inserted, Removed, Reset, Updated};
+        let Some(last) = self.pending.last_mut() else {
+            self.pending.push(delta);
+            return;
+        };
+        let merged = match (last, delta) {
+            // Everything after a Reset is subsumed by it.
+            (Reset, _) => true,
+            // Adjacent/overlapping inserts extend the run.
+            (
+                Inserted { index, count },
+                Inserted {
+                    index: new_index,
+                    count: new_count,
+                },
+            ) if new_index >= *index && new_index <= *index + *count => {
+                *count += new_count;
+                true
+            }
+            // Removing repeatedly at the same index (front-to-back drain)
+            // or walking backwards extends the run.
+            (
+                Removed { index, count },
+                Removed {
+                    index: new_index,
+                    count: new_count,
+                },
+            ) if new_index == *index || new_index + new_count == *index => {
+                *index = (*index).min(new_index);
+                *count += new_count;
+                true
+            }
+            // Repeated updates of one index dedupe.
+            (Updated { index }, Updated { index: new_index }) if *index == new_index => true,
+            _ => false,
+        };
+        if !merged {
+            self.pending.clear();
+            self.pending.push(Reset);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Delta application to list-shaped view state
+// ---------------------------------------------------------------------------
+
+/// List-shaped view state (a selection index plus a scroll offset) that
+/// [`VecDelta`]s can be applied to via [`apply_delta`].
+///
+/// `ListState` exposes exactly these fields (`selected`, `offset`), so a
+/// widget-side impl is a four-line forwarder; the future Table/TreeView
+/// plug in the same way. [`ListDeltaMirror`] is a standalone impl for
+/// code that tracks view state outside a widget.
+pub trait VecDeltaTarget {
+    /// Currently selected item index, if any.
+    fn selection(&self) -> Option<usize>;
+    /// Set the selected item index.
+    fn set_selection(&mut self, selected: Option<usize>);
+    /// First visible item index (scroll position).
+    fn scroll_offset(&self) -> usize;
+    /// Set the first visible item index.
+    fn set_scroll_offset(&mut self, offset: usize);
+}
+
+/// Adjust selection and scroll for one delta instead of resetting them.
+///
+/// - `Inserted` before the selection shifts it down by `count`; an insert
+///   before the viewport top shifts the scroll so the same items stay
+///   visible.
+/// - `Removed` spanning the selection clamps it to the removal point
+///   (then to the new length); a removal above shifts it up.
+/// - `Moved` (swap) follows the selected element to its new position.
+/// - `Updated` leaves view state untouched.
+/// - `Reset` clamps both to the new length — the content is new, but an
+///   in-range selection index is kept rather than cleared.
+///
+/// `new_len` is the collection length after the delta.
+pub fn apply_delta(target: &mut impl VecDeltaTarget, delta: &VecDelta, new_len: usize) {
+    let selected = target.selection();
+    let offset = target.scroll_offset();
+    let (selected, offset) = match *delta {
+        VecDelta::Inserted { index, count } => {
+            let selected = selected.map(|s| if s >= index { s + count } else { s });
+            let offset = if offset >= index && offset > 0 {
+                offset + count
+            } else {
+                offset
+            };
+            (selected, offset)
+        }
+        VecDelta::Removed { index, count } => {
+            let selected = selected.and_then(|s| {
+                if s >= index + count {
+                    Some(s - count)
+                } else if s >= index {
+                    // Selection was inside the removed range: clamp to the
+                    // removal point, or clear when nothing is left.
+                    if new_len == 0 {
+                        None
+                    } else {
+                        Some(index.min(new_len - 1))
+                    }
+                } else {
+                    Some(s)
+                }
+            });
+            let offset = if offset >= index + count {
+                offset - count
+            } else if offset > index {
+                index
+            } else {
+                offset
+            };
+            (selected, offset)
+        }
+        VecDelta::Updated { .. } => (selected, offset),
+        VecDelta::Moved { from, to } => {
+            let selected = selected.map(|s| {
+                if s == from {
+                    to
+                } else if s == to {
+                    from
+                } else {
+                    s
+                }
+            });
+            (selected, offset)
+        }
+        VecDelta::Reset => {
+            let selected = selected.and_then(|s| {
+                if new_len == 0 {
+                    None
+                } else {
+                    Some(s.min(new_len - 1))
+                }
+            });
+            (selected, offset.min(new_len.saturating_sub(1)))
+        }
+    };
+    target.set_selection(selected);
+    target.set_scroll_offset(offset.min(new_len.saturating_sub(1)));
+}
+
+/// Standalone [`VecDeltaTarget`]: a selection/offset mirror for code that
+/// tracks list view state outside a widget (or syncs it into one after
+/// each delta).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListDeltaMirror {
+    /// Currently selected item index.
+    pub selected: Option<usize>,
+    /// First visible item index.
+    pub offset: usize,
+}
+
+impl VecDeltaTarget for ListDeltaMirror {
+    fn selection(&self) -> Option<usize> {
+        self.selected
+    }
+
+    fn set_selection(&mut self, selected: Option<usize>) {
+        self.selected = selected;
+    }
+
+    fn scroll_offset(&self) -> usize {
+        self.offset
+    }
+
+    fn set_scroll_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactive::BatchScope;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type DeltaLog = Rc<RefCell<Vec<(Vec<i32>, VecDelta)>>>;
+
+    /// Collect `(items, delta)` pairs from a subscription.
+    fn recording(vec: &ObservableVec<i32>) -> (DeltaLog, Subscription) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let log_clone = Rc::clone(&log);
+        let sub = vec.subscribe(move |items, delta| {
+            log_clone.borrow_mut().push((items.to_vec(), *delta));
+        });
+        (log, sub)
+    }
+
+    #[test]
+    fn push_emits_inserted_at_end() {
+        let vec = ObservableVec::new(vec![1, 2]);
+        let (log, _sub) = recording(&vec);
+        vec.push(3);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![1, 2, 3], VecDelta::Inserted { index: 2, count: 1 })]
+        );
+        assert_eq!(vec.version(), 1);
+    }
+
+    #[test]
+    fn insert_emits_inserted_at_index() {
+        let vec = ObservableVec::new(vec![1, 3]);
+        let (log, _sub) = recording(&vec);
+        vec.insert(1, 2);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![1, 2, 3], VecDelta::Inserted { index: 1, count: 1 })]
+        );
+    }
+
+    #[test]
+    fn remove_emits_removed_and_returns_item() {
+        let vec = ObservableVec::new(vec![1, 2, 3]);
+        let (log, _sub) = recording(&vec);
+        assert_eq!(vec.remove(1), 2);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![1, 3], VecDelta::Removed { index: 1, count: 1 })]
+        );
+    }
+
+    #[test]
+    fn swap_emits_moved_and_same_index_is_noop() {
+        let vec = ObservableVec::new(vec![1, 2, 3]);
+        let (log, _sub) = recording(&vec);
+        vec.swap(0, 2);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![3, 2, 1], VecDelta::Moved { from: 0, to: 2 })]
+        );
+        vec.swap(1, 1);
+        assert_eq!(log.borrow().len(), 1, "same-index swap emits nothing");
+        assert_eq!(vec.version(), 1);
+    }
+
+    #[test]
+    fn update_emits_updated_only_on_change() {
+        let vec = ObservableVec::new(vec![10, 20]);
+        let (log, _sub) = recording(&vec);
+        vec.update(1, |v| *v += 1);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![10, 21], VecDelta::Updated { index: 1 })]
+        );
+        vec.update(0, |_| {});
+        assert_eq!(log.borrow().len(), 1, "no-op update emits nothing");
+        assert_eq!(vec.version(), 1);
+    }
+
+    #[test]
+    fn set_is_update_by_replacement() {
+        let vec = ObservableVec::new(vec![1, 2]);
+        let (log, _sub) = recording(&vec);
+        vec.set(0, 9);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![9, 2], VecDelta::Updated { index: 0 })]
+        );
+        vec.set(0, 9);
+        assert_eq!(log.borrow().len(), 1);
+    }
+
+    #[test]
+    fn clear_emits_reset_and_is_noop_when_empty() {
+        let vec = ObservableVec::new(vec![1, 2]);
+        let (log, _sub) = recording(&vec);
+        vec.clear();
+        assert_eq!(*log.borrow(), vec![(vec![], VecDelta::Reset)]);
+        vec.clear();
+        assert_eq!(log.borrow().len(), 1, "clear on empty emits nothing");
+        assert_eq!(vec.version(), 1);
+    }
+
+    #[test]
+    fn extend_emits_single_inserted_run() {
+        let vec = ObservableVec::new(vec![1]);
+        let (log, _sub) = recording(&vec);
+        vec.extend([2, 3, 4]);
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![1, 2, 3, 4], VecDelta::Inserted { index: 1, count: 3 })]
+        );
+        vec.extend(std::iter::empty());
+        assert_eq!(log.borrow().len(), 1, "empty extend emits nothing");
+        assert_eq!(vec.version(), 1);
+    }
+
+    #[test]
+    fn retain_emits_descending_removed_runs() {
+        let vec = ObservableVec::new(vec![1, 2, 3, 4, 5, 6]);
+        let (log, _sub) = recording(&vec);
+        // Drop 2,3 (run at 1..3) and 6 (run at 5..6).
+        vec.retain(|v| !matches!(v, 2 | 3 | 6));
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                (vec![1, 4, 5], VecDelta::Removed { index: 5, count: 1 }),
+                (vec![1, 4, 5], VecDelta::Removed { index: 1, count: 2 }),
+            ],
+            "runs arrive back-to-front so indices stay valid"
+        );
+        assert_eq!(vec.version(), 1, "one mutation, one version bump");
+
+        vec.retain(|_| true);
+        assert_eq!(log.borrow().len(), 2, "retain-all emits nothing");
+    }
+
+    #[test]
+    fn replace_emits_reset() {
+        let vec = ObservableVec::new(vec![1, 2]);
+        let (log, _sub) = recording(&vec);
+        vec.replace(vec![7, 8, 9]);
+        assert_eq!(*log.borrow(), vec![(vec![7, 8, 9], VecDelta::Reset)]);
+        vec.replace(vec![7, 8, 9]);
+        assert_eq!(log.borrow().len(), 1, "equal replacement emits nothing");
+    }
+
+    #[test]
+    fn subscription_drop_unsubscribes() {
+        let vec = ObservableVec::new(vec![1]);
+        let (log, sub) = recording(&vec);
+        vec.push(2);
+        assert_eq!(log.borrow().len(), 1);
+        drop(sub);
+        vec.push(3);
+        assert_eq!(log.borrow().len(), 1);
+    }
+
+    #[test]
+    fn clone_shares_state_and_subscribers() {
+        let a = ObservableVec::new(vec![1]);
+        let b = a.clone();
+        let (log, _sub) = recording(&a);
+        b.push(2);
+        assert_eq!(a.items(), vec![1, 2]);
+        assert_eq!(log.borrow().len(), 1);
+        assert_eq!(a.version(), 1);
+    }
+
+    // ── Selection/scroll adjustment ─────────────────────────────────────
+
+    fn mirror(selected: Option<usize>, offset: usize) -> ListDeltaMirror {
+        ListDeltaMirror { selected, offset }
+    }
+
+    #[test]
+    fn insert_before_selection_shifts_it() {
+        let mut m = mirror(Some(3), 2);
+        apply_delta(&mut m, &VecDelta::Inserted { index: 1, count: 2 }, 10);
+        assert_eq!(m.selected, Some(5));
+        assert_eq!(
+            m.offset, 4,
+            "insert above viewport keeps same items visible"
+        );
+
+        let mut m = mirror(Some(3), 0);
+        apply_delta(&mut m, &VecDelta::Inserted { index: 5, count: 1 }, 10);
+        assert_eq!(m.selected, Some(3), "insert after selection leaves it");
+        assert_eq!(m.offset, 0);
+    }
+
+    #[test]
+    fn remove_above_selection_shifts_remove_at_clamps() {
+        let mut m = mirror(Some(5), 3);
+        apply_delta(&mut m, &VecDelta::Removed { index: 1, count: 2 }, 8);
+        assert_eq!(m.selected, Some(3));
+        assert_eq!(m.offset, 1);
+
+        // Selection inside the removed range clamps to the removal point.
+        let mut m = mirror(Some(4), 0);
+        apply_delta(&mut m, &VecDelta::Removed { index: 3, count: 3 }, 5);
+        assert_eq!(m.selected, Some(3));
+
+        // Removing the tail clamps to the new last item.
+        let mut m = mirror(Some(4), 0);
+        apply_delta(&mut m, &VecDelta::Removed { index: 3, count: 2 }, 3);
+        assert_eq!(m.selected, Some(2));
+
+        // Removing everything clears the selection.
+        let mut m = mirror(Some(1), 0);
+        apply_delta(&mut m, &VecDelta::Removed { index: 0, count: 3 }, 0);
+        assert_eq!(m.selected, None);
+        assert_eq!(m.offset, 0);
+    }
+
+    #[test]
+    fn moved_follows_selected_element() {
+        let mut m = mirror(Some(0), 0);
+        apply_delta(&mut m, &VecDelta::Moved { from: 0, to: 4 }, 5);
+        assert_eq!(m.selected, Some(4));
+
+        apply_delta(&mut m, &VecDelta::Moved { from: 1, to: 4 }, 5);
+        assert_eq!(m.selected, Some(1), "swap's other endpoint also follows");
+
+        apply_delta(&mut m, &VecDelta::Moved { from: 2, to: 3 }, 5);
+        assert_eq!(m.selected, Some(1), "unrelated swap leaves selection");
+    }
+
+    #[test]
+    fn updated_leaves_view_state() {
+        let mut m = mirror(Some(2), 1);
+        apply_delta(&mut m, &VecDelta::Updated { index: 2 }, 5);
+        assert_eq!(m, mirror(Some(2), 1));
+    }
+
+    #[test]
+    fn reset_clamps_selection_and_offset() {
+        let mut m = mirror(Some(7), 6);
+        apply_delta(&mut m, &VecDelta::Reset, 3);
+        assert_eq!(m.selected, Some(2));
+        assert_eq!(m.offset, 2);
+
+        let mut m = mirror(Some(1), 1);
+        apply_delta(&mut m, &VecDelta::Reset, 0);
+        assert_eq!(m.selected, None);
+        assert_eq!(m.offset, 0);
+    }
+
+    // ── Batch coalescing ────────────────────────────────────────────────
+
+    #[test]
+    fn batch_coalesces_adjacent_inserts() {
+        let vec = ObservableVec::new(vec![0]);
+        let (log, _sub) = recording(&vec);
+        {
+            let _batch = BatchScope::new();
+            vec.push(1);
+            vec.push(2);
+            vec.push(3);
+            assert!(log.borrow().is_empty(), "deltas deferred inside batch");
+        }
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![0, 1, 2, 3], VecDelta::Inserted { index: 1, count: 3 })],
+            "adjacent inserts coalesce into one run"
+        );
+        assert_eq!(vec.version(), 3, "each mutation still bumps the version");
+    }
+
+    #[test]
+    fn batch_coalesces_repeated_updates() {
+        let vec = ObservableVec::new(vec![1, 2]);
+        let (log, _sub) = recording(&vec);
+        {
+            let _batch = BatchScope::new();
+            vec.set(0, 10);
+            vec.set(0, 11);
+        }
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![11, 2], VecDelta::Updated { index: 0 })]
+        );
+    }
+
+    #[test]
+    fn batch_coalesces_removal_drain() {
+        let vec = ObservableVec::new(vec![1, 2, 3, 4]);
+        let (log, _sub) = recording(&vec);
+        {
+            let _batch = BatchScope::new();
+            vec.remove(2);
+            vec.remove(2);
+        }
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![1, 2], VecDelta::Removed { index: 2, count: 2 })]
+        );
+    }
+
+    #[test]
+    fn batch_reset_subsumes_everything() {
+        let vec = ObservableVec::new(vec![1, 2]);
+        let (log, _sub) = recording(&vec);
+        {
+            let _batch = BatchScope::new();
+            vec.push(3);
+            vec.clear();
+            vec.push(9);
+        }
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![9], VecDelta::Reset)],
+            "reset wipes earlier deltas and absorbs later ones"
+        );
+    }
+
+    #[test]
+    fn batch_incompatible_mixture_collapses_to_reset() {
+        let vec = ObservableVec::new(vec![1, 2, 3]);
+        let (log, _sub) = recording(&vec);
+        {
+            let _batch = BatchScope::new();
+            vec.push(4);
+            vec.set(0, 9);
+            vec.swap(0, 1);
+        }
+        assert_eq!(
+            *log.borrow(),
+            vec![(vec![2, 9, 3, 4], VecDelta::Reset)],
+            "a mixed batch degrades to one full rebuild"
+        );
+    }
+
+    // ── Randomized equivalence ──────────────────────────────────────────
+
+    /// Repo-standard xorshift64 PRNG for deterministic randomized tests.
+    struct XorShift64(u64);
+
+    impl XorShift64 {
+        fn next(&mut self) -> u64 {
+            let mut x = self.0;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            self.0 = x;
+            x
+        }
+
+        fn below(&mut self, bound: usize) -> usize {
+            (self.next() % bound.max(1) as u64) as usize
+        }
+    }
+
+    /// Apply a delta to a mirror `Vec` using only the delta and the
+    /// post-mutation items (what an incremental list widget would do).
+    fn apply_to_mirror(mirror: &mut Vec<i32>, items: &[i32], delta: &VecDelta) {
+        match *delta {
+            VecDelta::Inserted { index, count } => {
+                for i in 0..count {
+                    mirror.insert(index + i, items[index + i]);
+                }
+            }
+            VecDelta::Removed { index, count } => {
+                mirror.drain(index..index + count);
+            }
+            VecDelta::Updated { index } => mirror[index] = items[index],
+            VecDelta::Moved { from, to } => mirror.swap(from, to),
+            VecDelta::Reset => {
+                mirror.clear();
+                mirror.extend_from_slice(items);
+            }
+        }
+    }
+
+    #[test]
+    fn randomized_mutations_delta_applied_mirror_matches_rebuild() {
+        let mut rng = XorShift64(0x5DEE_CE66_D1CE_5EED);
+        for round in 0..32 {
+            let vec = ObservableVec::new(vec![0, 1, 2, 3]);
+            let mirror = Rc::new(RefCell::new(vec.items()));
+            let view = Rc::new(RefCell::new(ListDeltaMirror {
+                selected: Some(2),
+                offset: 0,
+            }));
+            let mirror_clone = Rc::clone(&mirror);
+            let view_clone = Rc::clone(&view);
+            let _sub = vec.subscribe(move |items, delta| {
+                apply_to_mirror(&mut mirror_clone.borrow_mut(), items, delta);
+                apply_delta(&mut *view_clone.borrow_mut(), delta, items.len());
+            });
+
+            let batched = round % 2 == 1;
+            let batch = batched.then(BatchScope::new);
+            let mut next_item = 100;
+            for _ in 0..60 {
+                let len = vec.len();
+                match rng.below(8) {
+                    0 => vec.push(next_item),
+                    1 => vec.insert(rng.below(len + 1), next_item),
+                    2 if len > 0 => {
+                        vec.remove(rng.below(len));
+                    }
+                    3 if len > 1 => vec.swap(rng.below(len), rng.below(len)),
+                    4 if len > 0 => vec.set(rng.below(len), next_item),
+                    5 => vec.extend([next_item, next_item + 1]),
+                    6 if len > 0 => {
+                        let cutoff = next_item - 50;
+                        vec.retain(|v| *v < cutoff);
+                    }
+                    7 if rng.below(10) == 0 => vec.clear(),
+                    _ => {}
+                }
+                next_item += 2;
+            }
+            drop(batch);
+
+            assert_eq!(
+                *mirror.borrow(),
+                vec.items(),
+                "delta-applied mirror must equal from-scratch rebuild (round {round})"
+            );
+            let view = view.borrow();
+            let len = vec.len();
+            if len == 0 {
+                assert_eq!(view.selected, None, "round {round}");
+                assert_eq!(view.offset, 0, "round {round}");
+            } else {
+                if let Some(s) = view.selected {
+                    assert!(s < len, "selection in bounds (round {round})");
+                }
+                assert!(view.offset < len, "offset in bounds (round {round})");
+            }
+        }
+    }
+}
@@ -25,9 +25,10 @@
 //! to detect failures.
 
 use std::io::{self, Write};
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
 use std::thread::{self, JoinHandle};
-use web_time::Instant;
+use web_time::{Duration, Instant};
 
 use crate::terminal_writer::{ScreenMode, TerminalWriter};
 use ftui_render::buffer::Buffer;
@@ -43,6 +44,28 @@ const CHANNEL_CAPACITY: usize = 256;
 /// Pending render state: buffer, cursor position, cursor visibility.
 type PendingRender = (Buffer, Option<(u16, u16)>, bool);
 
+/// Outcome of [`RenderThread::submit_frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameSubmission {
+    /// The frame went into the empty swap slot; the thread was notified.
+    Queued,
+    /// A pending frame had not been presented yet and was replaced —
+    /// exactly the backpressure-free behavior pipelined mode wants.
+    ReplacedPending,
+}
+
+/// Telemetry counters for the pipelined frame path.
+#[derive(Debug, Default)]
+struct PipelineTelemetry {
+    /// Frames replaced in the swap slot before they were presented.
+    frames_replaced: AtomicU64,
+    /// Frames actually presented to the terminal.
+    frames_presented: AtomicU64,
+}
+
+/// The swap slot: at most one pending frame; newer frames replace it.
+type FrameSlot = Arc<Mutex<Option<PendingRender>>>;
+
 /// Messages sent from the main thread to the render thread.
 #[derive(Debug)]
 pub enum OutMsg {
@@ -57,6 +80,8 @@ pub enum OutMsg {
         h: u16,
     },
     SetMode(ScreenMode),
+    /// A frame was placed in the swap slot ([`RenderThread::submit_frame`]).
+    FrameReady,
     Shutdown,
 }
 
@@ -64,6 +89,11 @@ pub struct RenderThread {
     sender: mpsc::SyncSender<OutMsg>,
     handle: Option<JoinHandle<()>>,
     error_rx: mpsc::Receiver<io::Error>,
+    /// Pending-frame swap slot shared with the render loop.
+    frame_slot: FrameSlot,
+    telemetry: Arc<PipelineTelemetry>,
+    /// Signalled (by disconnect) when the render loop exits.
+    done_rx: mpsc::Receiver<()>,
 }
 
 #[allow(clippy::result_large_err)]
@@ -71,20 +101,79 @@ impl RenderThread {
     pub fn start<W: Write + Send + 'static>(writer: TerminalWriter<W>) -> io::Result<Self> {
         let (tx, rx) = mpsc::sync_channel::<OutMsg>(CHANNEL_CAPACITY);
         let (err_tx, err_rx) = mpsc::sync_channel::<io::Error>(8);
-
-        let handle = thread::Builder::new()
-            .name("ftui-render".into())
-            .spawn(move || {
-                render_loop(writer, rx, err_tx);
-            })?;
+        let (done_tx, done_rx) = mpsc::sync_channel::<()>(1);
+        let frame_slot: FrameSlot = Arc::new(Mutex::new(None));
+        let telemetry = Arc::new(PipelineTelemetry::default());
+
+        let handle = {
+            let frame_slot = frame_slot.clone();
+            let telemetry = telemetry.clone();
+            thread::Builder::new()
+                .name("ftui-render".into())
+                .spawn(move || {
+                    render_loop(writer, rx, err_tx, &frame_slot, &telemetry);
+                    // Dropping done_tx disconnects done_rx: the loop exited.
+                    drop(done_tx);
+                })?
+        };
 
         Ok(Self {
             sender: tx,
             handle: Some(handle),
             error_rx: err_rx,
+            frame_slot,
+            telemetry,
+            done_rx,
         })
     }
 
+    /// Submit a frame through the swap slot (pipelined mode).
+    ///
+    /// Never blocks on rendering: if a frame is still pending it is
+    /// replaced (the presenter only ever sees the newest state), so
+    /// `update()` can process the next input immediately. At most one
+    /// frame is pending at any time — no queue growth.
+    pub fn submit_frame(
+        &self,
+        buffer: Buffer,
+        cursor: Option<(u16, u16)>,
+        cursor_visible: bool,
+    ) -> FrameSubmission {
+        let replaced = {
+            let mut slot = self
+                .frame_slot
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let replaced = slot.is_some();
+            *slot = Some((buffer, cursor, cursor_visible));
+            replaced
+        };
+        if replaced {
+            self.telemetry
+                .frames_replaced
+                .fetch_add(1, Ordering::Relaxed);
+            // The already-queued FrameReady marker covers the new frame.
+            FrameSubmission::ReplacedPending
+        } else {
+            // try_send: a full channel means the loop is already awake
+            // and will take the slot this iteration; never stall input.
+            let _ = self.sender.try_send(OutMsg::FrameReady);
+            FrameSubmission::Queued
+        }
+    }
+
+    /// Frames replaced in the swap slot before they could be presented.
+    #[must_use]
+    pub fn frames_replaced_before_present(&self) -> u64 {
+        self.telemetry.frames_replaced.load(Ordering::Relaxed)
+    }
+
+    /// Frames actually presented to the terminal.
+    #[must_use]
+    pub fn frames_presented(&self) -> u64 {
+        self.telemetry.frames_presented.load(Ordering::Relaxed)
+    }
+
     pub fn send(&self, msg: OutMsg) -> Result<(), mpsc::SendError<OutMsg>> {
         self.sender.send(msg)
     }
@@ -103,6 +192,30 @@ impl RenderThread {
             let _ = handle.join();
         }
     }
+
+    /// Shut down with a bounded join (runtime shutdown rules): ask the
+    /// loop to stop, wait up to `timeout` for it to flush the final
+    /// frame and exit, then join. Returns `false` if the thread did not
+    /// finish in time (it is detached, never blocking the host).
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> bool {
+        let _ = self.sender.send(OutMsg::Shutdown);
+        // recv on the done channel returns Err(Disconnected) as soon as
+        // the loop drops its sender — i.e. after the final flush.
+        match self.done_rx.recv_timeout(timeout) {
+            Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => {
+                if let Some(handle) = self.handle.take() {
+                    let _ = handle.join();
+                }
+                true
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                // Detach: dropping the handle lets the thread finish on
+                // its own without blocking process teardown.
+                self.handle.take();
+                false
+            }
+        }
+    }
 }
 
 impl Drop for RenderThread {
@@ -118,6 +231,8 @@ fn render_loop<W: Write + Send>(
     mut writer: TerminalWriter<W>,
     rx: mpsc::Receiver<OutMsg>,
     err_tx: mpsc::SyncSender<io::Error>,
+    frame_slot: &FrameSlot,
+    telemetry: &PipelineTelemetry,
 ) {
     let mut loop_count: u64 = 0;
     // Reuse buffer to avoid allocation churn in the hot loop
@@ -160,6 +275,17 @@ fn render_loop<W: Write + Send>(
             }
         }
 
+        // The swap slot holds the newest pipelined frame; it supersedes
+        // any channel-queued render (and is drained on shutdown so the
+        // final frame is flushed).
+        if let Some(pending) = frame_slot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+        {
+            latest_render = Some(pending);
+        }
+
         // Processing Logic:
         // 1. If we have logs, write them in chunks.
         // 2. After each chunk, if we have a render, present it.
@@ -172,6 +298,7 @@ fn render_loop<W: Write + Send>(
                     let _ = err_tx.try_send(e);
                     return;
                 }
+                telemetry.frames_presented.fetch_add(1, Ordering::Relaxed);
                 last_render_time = Instant::now();
             }
         } else {
@@ -200,6 +327,7 @@ fn render_loop<W: Write + Send>(
                         let _ = err_tx.try_send(e);
                         return;
                     }
+                    telemetry.frames_presented.fetch_add(1, Ordering::Relaxed);
                     last_render_time = now;
                 }
             }
@@ -242,6 +370,10 @@ fn process_msg<W: Write>(
         OutMsg::SetMode(_mode) => {
             tracing::warn!("SetMode received but runtime mode switching not yet implemented");
         }
+        OutMsg::FrameReady => {
+            // Content travels through the swap slot; the marker only
+            // wakes the loop.
+        }
         OutMsg::Shutdown => {
             *shutdown = true;
         }
@@ -369,6 +501,218 @@ mod tests {
         assert!(output.contains('X'));
     }
 
+    // ── Pipelined frame path (swap slot) ─────────────────────────────
+
+    /// Writer that sleeps per write call: an artificially slow presenter.
+    #[derive(Clone)]
+    struct SlowWriter {
+        inner: Arc<Mutex<Vec<u8>>>,
+        delay: Duration,
+    }
+
+    impl Write for SlowWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            std::thread::sleep(std::time::Duration::from_micros(
+                self.delay.as_micros() as u64
+            ));
+            self.inner
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Writer gated by a token channel: each write consumes one token.
+    #[derive(Clone)]
+    struct GatedWriter {
+        inner: Arc<Mutex<Vec<u8>>>,
+        tokens: Arc<Mutex<mpsc::Receiver<()>>>,
+    }
+
+    impl Write for GatedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let _ = self
+                .tokens
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .recv();
+            self.inner
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn frame(width: u16, height: u16, ch: char) -> Buffer {
+        let mut buf = Buffer::new(width, height);
+        buf.set_raw(0, 0, Cell::from_char(ch));
+        buf
+    }
+
+    #[test]
+    fn submit_frame_does_not_block_on_slow_presenter() {
+        let sink = Arc::new(Mutex::new(Vec::new()));
+        let writer = TerminalWriter::new(
+            SlowWriter {
+                inner: sink,
+                delay: Duration::from_millis(10),
+            },
+            ScreenMode::Inline { ui_height: 5 },
+            crate::terminal_writer::UiAnchor::Bottom,
+            TerminalCapabilities::basic(),
+        );
+        let rt = RenderThread::start(writer).unwrap();
+
+        let start = std::time::Instant::now();
+        for i in 0..20u8 {
+            rt.submit_frame(frame(10, 5, char::from(b'a' + i)), None, true);
+        }
+        let submit_elapsed = start.elapsed();
+        // 20 presents at ≥10ms each would be ≥200ms; submission must not
+        // wait for the presenter.
+        assert!(
+            submit_elapsed < std::time::Duration::from_millis(100),
+            "submissions blocked on rendering: {submit_elapsed:?}"
+        );
+        // Coalescing happened: some frames were replaced, not presented.
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        assert!(
+            rt.frames_replaced_before_present() > 0,
+            "slow presenter should force replacements"
+        );
+        rt.shutdown();
+    }
+
+    #[test]
+    fn at_most_one_frame_pending() {
+        let sink = Arc::new(Mutex::new(Vec::new()));
+        let (token_tx, token_rx) = mpsc::channel::<()>();
+        let writer = TerminalWriter::new(
+            GatedWriter {
+                inner: sink.clone(),
+                tokens: Arc::new(Mutex::new(token_rx)),
+            },
+            ScreenMode::Inline { ui_height: 5 },
+            crate::terminal_writer::UiAnchor::Bottom,
+            TerminalCapabilities::basic(),
+        );
+        let rt = RenderThread::start(writer).unwrap();
+
+        // First submit occupies the thread (blocked in its present).
+        assert_eq!(
+            rt.submit_frame(frame(10, 5, 'A'), None, true),
+            FrameSubmission::Queued
+        );
+        // Give the loop time to take the slot and block on the writer.
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        // Second lands in the empty slot; the rest replace it.
+        assert_eq!(
+            rt.submit_frame(frame(10, 5, 'B'), None, true),
+            FrameSubmission::Queued
+        );
+        for ch in ['C', 'D', 'E', 'F'] {
+            assert_eq!(
+                rt.submit_frame(frame(10, 5, ch), None, true),
+                FrameSubmission::ReplacedPending,
+                "slot already pending: {ch}"
+            );
+        }
+        assert_eq!(rt.frames_replaced_before_present(), 4);
+
+        // Unblock the presenter generously and let it drain.
+        for _ in 0..10_000 {
+            let _ = token_tx.send(());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        // Only the newest pending frame was presented after the first.
+        let bytes = sink.lock().unwrap_or_else(|p| p.into_inner()).clone();
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(output.contains('F'), "newest frame presented");
+        assert!(!output.contains('C'), "replaced frame never presented");
+        drop(token_tx);
+        rt.shutdown();
+    }
+
+    #[test]
+    fn shutdown_flushes_final_frame() {
+        let (mut writer, tw) = test_writer();
+        writer.set_size(10, 5);
+        let rt = RenderThread::start(writer).unwrap();
+        rt.submit_frame(frame(10, 5, 'Q'), None, true);
+        assert!(rt.shutdown_timeout(Duration::from_secs(5)), "bounded join");
+        let bytes = tw.output();
+        assert!(
+            String::from_utf8_lossy(&bytes).contains('Q'),
+            "final frame flushed before exit"
+        );
+    }
+
+    #[test]
+    fn pipelined_output_matches_single_threaded_path() {
+        let frames = ['1', '2', '3'];
+
+        // Single-threaded reference: present each frame directly.
+        let (mut direct, direct_tw) = test_writer();
+        direct.set_size(10, 5);
+        for ch in frames {
+            direct
+                .present_ui(&frame(10, 5, ch), None, true)
+                .expect("direct present");
+        }
+        let _ = direct.flush();
+        // Drop emits the same cursor-restore trailer the render thread's
+        // writer emits at shutdown; compare like with like.
+        drop(direct);
+
+        // Pipelined: pace submissions so nothing coalesces.
+        let (mut writer, tw) = test_writer();
+        writer.set_size(10, 5);
+        let rt = RenderThread::start(writer).unwrap();
+        for (i, ch) in frames.iter().enumerate() {
+            rt.submit_frame(frame(10, 5, *ch), None, true);
+            let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+            while rt.frames_presented() < (i + 1) as u64 {
+                assert!(std::time::Instant::now() < deadline, "present stalled");
+                std::thread::yield_now();
+            }
+        }
+        rt.shutdown();
+
+        assert_eq!(
+            tw.output(),
+            direct_tw.output(),
+            "pipelined path must be byte-identical for a paced sequence"
+        );
+    }
+
+    #[test]
+    fn shutdown_timeout_reports_stuck_thread() {
+        let sink = Arc::new(Mutex::new(Vec::new()));
+        let (_token_tx, token_rx) = mpsc::channel::<()>();
+        let writer = TerminalWriter::new(
+            GatedWriter {
+                inner: sink,
+                tokens: Arc::new(Mutex::new(token_rx)),
+            },
+            ScreenMode::Inline { ui_height: 5 },
+            crate::terminal_writer::UiAnchor::Bottom,
+            TerminalCapabilities::basic(),
+        );
+        let rt = RenderThread::start(writer).unwrap();
+        rt.submit_frame(frame(10, 5, 'X'), None, true);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        // The presenter never gets a token: the join must still bound.
+        let start = std::time::Instant::now();
+        assert!(!rt.shutdown_timeout(Duration::from_millis(200)));
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+    }
+
     #[test]
     fn constants_have_expected_values() {
         assert_eq!(LOG_CHUNK_LIMIT, 64);
@@ -161,6 +161,12 @@ pub struct CoalescerConfig {
     /// Window size for rate calculation (number of events).
     pub rate_window_size: usize,
 
+    /// Apply the first resize after a quiet period immediately instead
+    /// of coalescing it (then settle-delay coalescing governs the rest
+    /// of the storm). Off by default: continuous reflow with the steady
+    /// delay is the established behavior.
+    pub immediate_first: bool,
+
     /// Enable decision logging (JSONL format).
     pub enable_logging: bool,
 
@@ -190,6 +196,7 @@ impl Default for CoalescerConfig {
             burst_exit_rate: 5.0,   // 5 events/sec to exit burst
             cooldown_frames: 3,
             rate_window_size: 8,
+            immediate_first: false,
             enable_logging: false,
             enable_bocpd: false,
             bocpd_config: None,
@@ -198,6 +205,13 @@ impl Default for CoalescerConfig {
 }
 
 impl CoalescerConfig {
+    /// Enable immediate-first resize application.
+    #[must_use]
+    pub fn with_immediate_first(mut self) -> Self {
+        self.immediate_first = true;
+        self
+    }
+
     /// Enable or disable decision logging.
     #[must_use]
     pub fn with_logging(mut self, enabled: bool) -> Self {
@@ -898,6 +912,13 @@ impl ResizeCoalescer {
             self.window_start = Some(now);
         }
 
+        // Immediate-first (opt-in): the first resize after quiet applies
+        // at once so a single drag step or window snap renders instantly;
+        // only subsequent storm events coalesce.
+        if self.config.immediate_first && dt.is_none() && self.regime == Regime::Steady {
+            return self.apply_pending_at(now, false);
+        }
+
         // Check hard deadline
         let time_since_render = duration_since_or_zero(now, self.last_render);
         if time_since_render >= Duration::from_millis(self.config.hard_deadline_ms) {
@@ -1004,6 +1025,12 @@ impl ResizeCoalescer {
 
     /// Get the current regime.
     #[inline]
+    /// The latest size waiting to be applied, if any.
+    #[must_use]
+    pub fn pending_size(&self) -> Option<(u16, u16)> {
+        self.pending_size
+    }
+
     pub fn regime(&self) -> Regime {
         self.regime
     }
@@ -1768,6 +1795,7 @@ mod tests {
             burst_exit_rate: 5.0,
             cooldown_frames: 3,
             rate_window_size: 8,
+            immediate_first: false,
             enable_logging: true,
             enable_bocpd: false,
             bocpd_config: None,
@@ -2483,6 +2511,7 @@ mod tests {
             steady_delay_ms: 10,
             burst_delay_ms: 50,
             hard_deadline_ms: 5_000,
+            immediate_first: false,
             enable_logging: true,
             enable_bocpd: false,
             bocpd_config: None,
@@ -2555,6 +2584,7 @@ mod tests {
             steady_delay_ms: 10,
             burst_delay_ms: 50,
             hard_deadline_ms: 5_000,
+            immediate_first: false,
             enable_logging: true,
             enable_bocpd: false,
             bocpd_config: None,
@@ -3613,6 +3643,7 @@ mod tests {
             steady_delay_ms: 20,
             burst_delay_ms: 50,
             hard_deadline_ms: 200,
+            immediate_first: false,
             enable_logging: true,
             ..test_config()
         };
@@ -3649,6 +3680,7 @@ mod tests {
             burst_delay_ms: 40,
             hard_deadline_ms: 100,
             burst_enter_rate: 10.0,
+            immediate_first: false,
             enable_logging: true,
             ..test_config()
         };
@@ -3683,6 +3715,7 @@ mod tests {
     #[test]
     fn evidence_summary_jsonl_includes_checksum() {
         let config = CoalescerConfig {
+            immediate_first: false,
             enable_logging: true,
             ..test_config()
         };
@@ -3844,6 +3877,7 @@ mod tests {
     fn hard_deadline_zero_applies_immediately() {
         let config = CoalescerConfig {
             hard_deadline_ms: 0,
+            immediate_first: false,
             enable_logging: true,
             ..test_config()
         };
@@ -3862,6 +3896,7 @@ mod tests {
     fn rate_window_size_zero_returns_zero_rate() {
         let config = CoalescerConfig {
             rate_window_size: 0,
+            immediate_first: false,
             enable_logging: true,
             ..test_config()
         };
@@ -3880,6 +3915,7 @@ mod tests {
     fn rate_window_size_one_returns_zero_rate() {
         let config = CoalescerConfig {
             rate_window_size: 1,
+            immediate_first: false,
             enable_logging: true,
             ..test_config()
         };
@@ -4337,4 +4373,75 @@ mod tests {
         fnv_hash_bytes(&mut hash, b"");
         assert_eq!(hash, FNV_OFFSET_BASIS);
     }
+
+    // ── Storm protection (synthetic 100-event storm) ────────────────
+
+    #[test]
+    fn storm_of_100_resizes_bounds_renders_and_keeps_final_size() {
+        let mut c = ResizeCoalescer::new(CoalescerConfig::default(), (80, 24));
+        let base = Instant::now();
+        let mut applies: Vec<(u16, u16)> = Vec::new();
+
+        // 100 resize events over 200ms (2ms apart), ticking each "frame".
+        for i in 0..100u64 {
+            let now = base + Duration::from_millis(i * 2);
+            let (w, h) = (80 + (i % 40) as u16, 24 + (i % 20) as u16);
+            for action in [c.handle_resize_at(w, h, now), c.tick_at(now)] {
+                if let CoalesceAction::ApplyResize { width, height, .. } = action {
+                    applies.push((width, height));
+                }
+            }
+        }
+        // Settle: one tick well past the storm.
+        if let CoalesceAction::ApplyResize { width, height, .. } =
+            c.tick_at(base + Duration::from_millis(400))
+        {
+            applies.push((width, height));
+        }
+
+        // Bounded renders: hard deadline is 100ms, so ~2-6 applies for a
+        // 200ms storm — never one per event.
+        assert!(
+            (1..=8).contains(&applies.len()),
+            "expected bounded applies, got {}",
+            applies.len()
+        );
+        // No lost last event: the final applied size is the storm's last.
+        assert_eq!(applies.last(), Some(&(80 + 99 % 40, 24 + 99 % 20)));
+        assert!(!c.has_pending());
+    }
+
+    #[test]
+    fn immediate_first_applies_first_resize_at_once() {
+        let config = CoalescerConfig {
+            immediate_first: true,
+            ..CoalescerConfig::default()
+        };
+        let mut c = ResizeCoalescer::new(config, (80, 24));
+        let base = Instant::now();
+
+        // First resize after quiet: applied immediately, not coalesced.
+        let action = c.handle_resize_at(100, 40, base);
+        assert!(
+            matches!(
+                action,
+                CoalesceAction::ApplyResize {
+                    width: 100,
+                    height: 40,
+                    ..
+                }
+            ),
+            "got {action:?}"
+        );
+
+        // A follow-up shortly after coalesces as usual.
+        let action = c.handle_resize_at(101, 41, base + Duration::from_millis(2));
+        assert_eq!(action, CoalesceAction::ShowPlaceholder);
+    }
+
+    #[test]
+    fn immediate_first_off_by_default_preserves_behavior() {
+        let mut c = ResizeCoalescer::new(CoalescerConfig::default(), (80, 24));
+        assert_eq!(c.handle_resize(100, 40), CoalesceAction::ShowPlaceholder);
+    }
 }
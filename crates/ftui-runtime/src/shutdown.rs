@@ -0,0 +1,470 @@
+#![forbid(unsafe_code)]
+
+//! Ordered shutdown phases with bounded total teardown time.
+//!
+//! Shutdown used to be ad hoc: subscriptions, workers, the presenter,
+//! and the terminal guard raced, and any one of them could hang the
+//! process. [`ShutdownCoordinator`] formalizes it: phases run strictly
+//! in order —
+//!
+//! 1. [`StopAcceptingInput`](ShutdownPhase::StopAcceptingInput)
+//! 2. [`CancelSubscriptions`](ShutdownPhase::CancelSubscriptions)
+//! 3. [`DrainCommands`](ShutdownPhase::DrainCommands)
+//! 4. [`FinalRender`](ShutdownPhase::FinalRender) (optional goodbye frame)
+//! 5. [`RestoreTerminal`](ShutdownPhase::RestoreTerminal)
+//! 6. [`RunAppHooks`](ShutdownPhase::RunAppHooks)
+//!
+//! — with app-registerable hooks per phase
+//! ([`on_shutdown`](ShutdownCoordinator::on_shutdown)), a per-hook
+//! timeout (a hanging hook is abandoned on its thread, never blocking
+//! later phases: a hung subscription cannot block terminal restore),
+//! and a total teardown budget after which remaining phases are
+//! abandoned with a logged summary. Each phase owns a child [`Cx`];
+//! completing the phase cancels it, so workers holding that context
+//! observe cancellation at the right transition.
+//!
+//! Signal-initiated (Ctrl+C/SIGTERM), app-initiated (`Cmd::Quit`), and
+//! error-initiated shutdowns all run the same path with a recorded
+//! [`ShutdownReason`]; the resulting [`ShutdownReport`] is published to
+//! the evidence module
+//! ([`set_shutdown_report`](crate::evidence_telemetry::set_shutdown_report)).
+
+use std::sync::mpsc;
+use web_time::{Duration, Instant};
+
+use ftui_core::cx::{Cx, CxController, LabClock};
+
+/// The ordered teardown phases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShutdownPhase {
+    StopAcceptingInput,
+    CancelSubscriptions,
+    DrainCommands,
+    FinalRender,
+    RestoreTerminal,
+    RunAppHooks,
+}
+
+impl ShutdownPhase {
+    /// All phases, in execution order.
+    pub const ALL: [ShutdownPhase; 6] = [
+        Self::StopAcceptingInput,
+        Self::CancelSubscriptions,
+        Self::DrainCommands,
+        Self::FinalRender,
+        Self::RestoreTerminal,
+        Self::RunAppHooks,
+    ];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::StopAcceptingInput => "stop-accepting-input",
+            Self::CancelSubscriptions => "cancel-subscriptions",
+            Self::DrainCommands => "drain-commands",
+            Self::FinalRender => "final-render",
+            Self::RestoreTerminal => "restore-terminal",
+            Self::RunAppHooks => "run-app-hooks",
+        }
+    }
+}
+
+/// Why the shutdown started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShutdownReason {
+    /// Ctrl+C / SIGTERM.
+    Signal,
+    /// `Cmd::Quit` from the app.
+    AppQuit,
+    /// Error escalation (panic storm, fatal IO).
+    Error(String),
+}
+
+/// Teardown summary, published to the evidence module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownReport {
+    pub reason: ShutdownReason,
+    /// Phases that fully completed, in order.
+    pub completed_phases: Vec<ShutdownPhase>,
+    /// Hooks abandoned after their timeout: `(phase, hook_name)`.
+    pub timed_out_hooks: Vec<(ShutdownPhase, String)>,
+    /// Phases abandoned because the total budget ran out.
+    pub abandoned_phases: Vec<ShutdownPhase>,
+    /// Total teardown time (coordinator clock).
+    pub elapsed: Duration,
+}
+
+impl ShutdownReport {
+    /// Evidence-log serialization (repo-style flat JSON).
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let reason = match &self.reason {
+            ShutdownReason::Signal => "signal".to_string(),
+            ShutdownReason::AppQuit => "app-quit".to_string(),
+            ShutdownReason::Error(message) => format!("error:{message}"),
+        };
+        let completed: Vec<&str> = self.completed_phases.iter().map(|p| p.name()).collect();
+        let abandoned: Vec<&str> = self.abandoned_phases.iter().map(|p| p.name()).collect();
+        let timed_out: Vec<String> = self
+            .timed_out_hooks
+            .iter()
+            .map(|(phase, name)| format!("{}:{name}", phase.name()))
+            .collect();
+        format!(
+            r#"{{"reason":"{reason}","completed":{completed:?},"timed_out_hooks":{timed_out:?},"abandoned":{abandoned:?},"elapsed_ms":{}}}"#,
+            self.elapsed.as_millis()
+        )
+    }
+}
+
+type Hook = Box<dyn FnOnce() + Send>;
+
+struct RegisteredHook {
+    phase: ShutdownPhase,
+    name: String,
+    timeout: Duration,
+    run: Hook,
+}
+
+/// Coordinates ordered teardown (see the module docs).
+pub struct ShutdownCoordinator {
+    hooks: Vec<RegisteredHook>,
+    default_hook_timeout: Duration,
+    total_budget: Duration,
+    /// Per-phase contexts; cancelled when their phase completes.
+    phase_cx: Vec<(Cx, CxController)>,
+    clock: Option<LabClock>,
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownCoordinator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hooks: Vec::new(),
+            default_hook_timeout: Duration::from_millis(500),
+            total_budget: Duration::from_secs(3),
+            phase_cx: ShutdownPhase::ALL
+                .iter()
+                .map(|_| Cx::background())
+                .collect(),
+            clock: None,
+        }
+    }
+
+    /// Override the per-hook timeout default.
+    #[must_use]
+    pub fn hook_timeout(mut self, timeout: Duration) -> Self {
+        self.default_hook_timeout = timeout;
+        self
+    }
+
+    /// Override the total teardown budget.
+    #[must_use]
+    pub fn total_budget(mut self, budget: Duration) -> Self {
+        self.total_budget = budget;
+        self
+    }
+
+    /// Account the total budget against a lab clock (tests).
+    #[must_use]
+    pub fn with_lab_clock(mut self, clock: &LabClock) -> Self {
+        self.clock = Some(clock.clone());
+        self
+    }
+
+    /// Register a cleanup hook for a phase (runs in phase order, then
+    /// registration order). The per-hook timeout bounds how long the
+    /// coordinator waits; a hanging hook is left behind on its thread.
+    pub fn on_shutdown(
+        &mut self,
+        phase: ShutdownPhase,
+        name: impl Into<String>,
+        hook: impl FnOnce() + Send + 'static,
+    ) {
+        self.on_shutdown_with_timeout(phase, name, self.default_hook_timeout, hook);
+    }
+
+    /// [`on_shutdown`](Self::on_shutdown) with an explicit timeout.
+    pub fn on_shutdown_with_timeout(
+        &mut self,
+        phase: ShutdownPhase,
+        name: impl Into<String>,
+        timeout: Duration,
+        hook: impl FnOnce() + Send + 'static,
+    ) {
+        self.hooks.push(RegisteredHook {
+            phase,
+            name: name.into(),
+            timeout,
+            run: Box::new(hook),
+        });
+    }
+
+    /// The context owned by a phase: hand it to the workers that phase
+    /// is responsible for; it is cancelled when the phase completes.
+    #[must_use]
+    pub fn phase_context(&self, phase: ShutdownPhase) -> Cx {
+        let idx = ShutdownPhase::ALL
+            .iter()
+            .position(|&p| p == phase)
+            .unwrap_or(0);
+        self.phase_cx[idx].0.clone()
+    }
+
+    fn now(&self) -> Instant {
+        match &self.clock {
+            Some(clock) => clock.now(),
+            None => Instant::now(),
+        }
+    }
+
+    /// Run the teardown. Every initiation path (signal, app quit,
+    /// error) calls this; only the recorded reason differs.
+    #[must_use]
+    pub fn run(mut self, reason: ShutdownReason) -> ShutdownReport {
+        let started = self.now();
+        let mut report = ShutdownReport {
+            reason,
+            completed_phases: Vec::new(),
+            timed_out_hooks: Vec::new(),
+            abandoned_phases: Vec::new(),
+            elapsed: Duration::ZERO,
+        };
+
+        let mut hooks: Vec<Option<RegisteredHook>> = std::mem::take(&mut self.hooks)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        for (idx, &phase) in ShutdownPhase::ALL.iter().enumerate() {
+            let spent = self.now().saturating_duration_since(started);
+            if spent > self.total_budget {
+                report.abandoned_phases.push(phase);
+                continue;
+            }
+
+            for slot in hooks.iter_mut() {
+                let belongs = slot.as_ref().is_some_and(|hook| hook.phase == phase);
+                if !belongs {
+                    continue;
+                }
+                let hook = slot.take().expect("checked above");
+                if !self.run_hook_bounded(&hook.name, hook.timeout, hook.run) {
+                    report.timed_out_hooks.push((phase, hook.name));
+                }
+            }
+
+            // Phase complete: cancel its context so workers tied to it
+            // observe the transition.
+            self.phase_cx[idx].1.cancel();
+            report.completed_phases.push(phase);
+        }
+
+        // Whatever was abandoned still gets its contexts cancelled: the
+        // process is going down and workers deserve the signal.
+        for (_, ctrl) in &self.phase_cx {
+            ctrl.cancel();
+        }
+
+        report.elapsed = self.now().saturating_duration_since(started);
+        tracing::info!(report = %report.to_json(), "shutdown complete");
+        crate::evidence_telemetry::set_shutdown_report(Some(report.clone()));
+        report
+    }
+
+    /// Run one hook with a bounded wait; `false` when it timed out (the
+    /// hook thread is detached, never joined).
+    fn run_hook_bounded(&self, name: &str, timeout: Duration, hook: Hook) -> bool {
+        let (done_tx, done_rx) = mpsc::channel();
+        let thread_name = format!("ftui-shutdown-{name}");
+        let spawned = std::thread::Builder::new()
+            .name(thread_name)
+            .spawn(move || {
+                hook();
+                let _ = done_tx.send(());
+            });
+        if spawned.is_err() {
+            return false;
+        }
+        done_rx
+            .recv_timeout(std::time::Duration::from_millis(
+                timeout.as_millis().min(u128::from(u64::MAX)) as u64,
+            ))
+            .is_ok()
+    }
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    fn recording_coordinator(log: &Arc<Mutex<Vec<&'static str>>>) -> ShutdownCoordinator {
+        let mut coordinator = ShutdownCoordinator::new();
+        for (phase, tag) in [
+            (ShutdownPhase::RunAppHooks, "app"),
+            (ShutdownPhase::StopAcceptingInput, "input"),
+            (ShutdownPhase::RestoreTerminal, "terminal"),
+            (ShutdownPhase::CancelSubscriptions, "subs"),
+            (ShutdownPhase::FinalRender, "render"),
+            (ShutdownPhase::DrainCommands, "drain"),
+        ] {
+            let log = log.clone();
+            coordinator.on_shutdown(phase, tag, move || {
+                log.lock().unwrap().push(tag);
+            });
+        }
+        coordinator
+    }
+
+    #[test]
+    fn phases_run_in_order_regardless_of_registration() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let report = recording_coordinator(&log).run(ShutdownReason::AppQuit);
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["input", "subs", "drain", "render", "terminal", "app"]
+        );
+        assert_eq!(report.completed_phases, ShutdownPhase::ALL.to_vec());
+        assert!(report.timed_out_hooks.is_empty());
+        assert!(report.abandoned_phases.is_empty());
+    }
+
+    #[test]
+    fn hanging_subscription_does_not_block_terminal_restore() {
+        let restored = Arc::new(AtomicBool::new(false));
+        let mut coordinator = ShutdownCoordinator::new();
+        // A "join" on a hung subscription worker: parks forever.
+        coordinator.on_shutdown_with_timeout(
+            ShutdownPhase::CancelSubscriptions,
+            "hung-subscription-join",
+            Duration::from_millis(40),
+            || loop {
+                std::thread::park();
+            },
+        );
+        {
+            let restored = restored.clone();
+            coordinator.on_shutdown(ShutdownPhase::RestoreTerminal, "restore", move || {
+                restored.store(true, Ordering::SeqCst);
+            });
+        }
+        let report = coordinator.run(ShutdownReason::Signal);
+        assert!(restored.load(Ordering::SeqCst), "terminal restored");
+        assert_eq!(
+            report.timed_out_hooks,
+            vec![(
+                ShutdownPhase::CancelSubscriptions,
+                "hung-subscription-join".to_string()
+            )]
+        );
+        assert_eq!(report.completed_phases.len(), 6, "phases still completed");
+    }
+
+    #[test]
+    fn total_budget_abandons_remaining_phases_under_lab_time() {
+        let clock = LabClock::new();
+        let mut coordinator = ShutdownCoordinator::new()
+            .with_lab_clock(&clock)
+            .total_budget(Duration::from_millis(100));
+        // The drain hook "takes" 200ms of lab time.
+        {
+            let clock = clock.clone();
+            coordinator.on_shutdown(ShutdownPhase::DrainCommands, "slow-drain", move || {
+                clock.advance(Duration::from_millis(200));
+            });
+        }
+        let ran_late = Arc::new(AtomicBool::new(false));
+        {
+            let ran_late = ran_late.clone();
+            coordinator.on_shutdown(ShutdownPhase::RunAppHooks, "late", move || {
+                ran_late.store(true, Ordering::SeqCst);
+            });
+        }
+        let report = coordinator.run(ShutdownReason::AppQuit);
+        assert!(
+            report
+                .abandoned_phases
+                .contains(&ShutdownPhase::RunAppHooks),
+            "{report:?}"
+        );
+        assert!(!ran_late.load(Ordering::SeqCst), "abandoned hook never ran");
+        // Earlier phases completed before the budget tripped.
+        assert!(
+            report
+                .completed_phases
+                .contains(&ShutdownPhase::DrainCommands)
+        );
+    }
+
+    #[test]
+    fn phase_contexts_cancel_at_their_transition() {
+        let coordinator = ShutdownCoordinator::new();
+        let subs_cx = coordinator.phase_context(ShutdownPhase::CancelSubscriptions);
+        let hooks_cx = coordinator.phase_context(ShutdownPhase::RunAppHooks);
+        assert!(!subs_cx.is_cancelled());
+        let _ = coordinator.run(ShutdownReason::AppQuit);
+        assert!(subs_cx.is_cancelled());
+        assert!(hooks_cx.is_cancelled());
+    }
+
+    #[test]
+    fn all_initiation_paths_behave_identically() {
+        let mut reports = Vec::new();
+        for reason in [
+            ShutdownReason::Signal,
+            ShutdownReason::AppQuit,
+            ShutdownReason::Error("boom".into()),
+        ] {
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let report = recording_coordinator(&log).run(reason);
+            reports.push((log.lock().unwrap().clone(), report));
+        }
+        let (first_log, first) = &reports[0];
+        for (log, report) in &reports[1..] {
+            assert_eq!(log, first_log, "same hook execution");
+            assert_eq!(report.completed_phases, first.completed_phases);
+            assert_eq!(report.timed_out_hooks, first.timed_out_hooks);
+            assert_eq!(report.abandoned_phases, first.abandoned_phases);
+        }
+    }
+
+    #[test]
+    fn evidence_report_round_trips() {
+        let report = ShutdownCoordinator::new().run(ShutdownReason::Signal);
+        // Parallel shutdown tests share the global slot; assert only
+        // that *a* report was published, and check serialization on the
+        // locally returned one.
+        assert!(crate::evidence_telemetry::shutdown_report().is_some());
+        let json = report.to_json();
+        assert!(json.contains(r#""reason":"signal""#), "{json}");
+        assert!(json.contains("restore-terminal"), "{json}");
+    }
+
+    #[test]
+    fn abandoned_phase_contexts_still_cancel() {
+        let clock = LabClock::new();
+        let mut coordinator = ShutdownCoordinator::new()
+            .with_lab_clock(&clock)
+            .total_budget(Duration::from_millis(50));
+        let late_cx = coordinator.phase_context(ShutdownPhase::RunAppHooks);
+        {
+            let clock = clock.clone();
+            coordinator.on_shutdown(ShutdownPhase::StopAcceptingInput, "slow", move || {
+                clock.advance(Duration::from_millis(500));
+            });
+        }
+        let report = coordinator.run(ShutdownReason::AppQuit);
+        assert!(!report.abandoned_phases.is_empty());
+        assert!(late_cx.is_cancelled(), "abandoned phase context cancelled");
+    }
+}
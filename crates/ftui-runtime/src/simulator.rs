@@ -274,6 +274,19 @@ impl<M: Model> ProgramSimulator<M> {
             Cmd::SetMouseCapture(enabled) => {
                 self.command_log.push(CmdRecord::MouseCapture(enabled));
             }
+            Cmd::Suspend => {
+                // No process to stop in the simulator.
+                self.command_log.push(CmdRecord::None);
+            }
+            Cmd::SplashProgress(_) | Cmd::CompleteStartup => {
+                // The simulator has no startup splash phase.
+                self.command_log.push(CmdRecord::None);
+            }
+            #[cfg(feature = "async")]
+            Cmd::Future(..) => {
+                // The simulator has no executor; async commands are dropped.
+                self.command_log.push(CmdRecord::None);
+            }
             Cmd::Task(_, f) => {
                 self.command_log.push(CmdRecord::Task);
                 let msg = f();
@@ -378,6 +391,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
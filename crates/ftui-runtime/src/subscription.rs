@@ -14,7 +14,7 @@
 //! 3. New subscriptions are started, removed ones are stopped
 //! 4. Subscription messages are routed through `Model::update()`
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::mpsc;
 use std::thread;
 use web_time::{Duration, Instant};
@@ -42,6 +42,125 @@ pub trait Subscription<M: Send + 'static>: Send {
     /// loop and send messages until the channel is disconnected (receiver dropped)
     /// or the stop signal is received.
     fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal);
+
+    /// Supervision policy for this subscription (default: no restarts).
+    ///
+    /// Anything other than [`RestartPolicy::Never`] makes the runtime
+    /// watch the worker and restart it per policy (see
+    /// [`SubscriptionManager::supervise`]).
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::Never
+    }
+
+    /// Run under supervision, reporting errors instead of dying silently.
+    ///
+    /// The default delegates to [`run`](Self::run) and reports success;
+    /// fallible sources (websockets, watchers) override this to surface
+    /// an error string. Panics are caught by the supervisor either way.
+    fn run_supervised(&self, sender: mpsc::Sender<M>, stop: StopSignal) -> Result<(), String> {
+        self.run(sender, stop);
+        Ok(())
+    }
+
+    /// Map a supervision event to an app message (default: drop it).
+    ///
+    /// Return `Some` to have worker terminations, restarts, and
+    /// give-ups delivered through the normal message queue.
+    fn supervision_msg(&self, event: &SubscriptionEvent) -> Option<M> {
+        let _ = event;
+        None
+    }
+}
+
+// --- Supervision types ---
+
+/// Backoff schedule between restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// `base * 2^attempt`, capped at `max`.
+    Exponential { base: Duration, max: Duration },
+}
+
+impl Backoff {
+    /// Delay before restart attempt `attempt` (0-based).
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match self {
+            Self::Exponential { base, max } => {
+                let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+                base.checked_mul(factor).map_or(*max, |d| d.min(*max))
+            }
+        }
+    }
+}
+
+/// Restart policy for a supervised subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RestartPolicy {
+    /// Terminations are final (the default).
+    #[default]
+    Never,
+    /// Restart after failures/panics; normal completion is final.
+    OnFailure {
+        backoff: Backoff,
+        /// Give up (with a terminal event) after this many restarts.
+        max_restarts: u32,
+        /// A run surviving this long resets the backoff and restart
+        /// budget.
+        healthy_period: Duration,
+    },
+    /// Restart after any termination, including normal completion.
+    Always {
+        backoff: Backoff,
+        /// Give up (with a terminal event) after this many restarts.
+        max_restarts: u32,
+        /// A run surviving this long resets the backoff and restart
+        /// budget.
+        healthy_period: Duration,
+    },
+}
+
+/// How a supervised worker ended, or what the supervisor did about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionOutcome {
+    /// The worker returned normally.
+    Completed,
+    /// The worker reported an error ([`Subscription::run_supervised`]).
+    Failed(String),
+    /// The worker panicked.
+    Panicked(String),
+    /// A restart was scheduled after `delay` (attempt is 1-based).
+    Restarting { attempt: u32, delay: Duration },
+    /// The restart budget is exhausted; the subscription is gone.
+    GaveUp { restarts: u32 },
+}
+
+/// Supervision event delivered via [`Subscription::supervision_msg`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionEvent {
+    pub id: SubId,
+    pub outcome: SubscriptionOutcome,
+}
+
+/// Exit report sent by a supervised worker thread.
+struct HealthReport<M: Send + 'static> {
+    id: SubId,
+    outcome: SubscriptionOutcome,
+    /// The subscription travels back so it can be restarted.
+    sub: Box<dyn Subscription<M>>,
+}
+
+/// Bookkeeping for one supervised subscription.
+struct SupervisedEntry<M: Send + 'static> {
+    /// Owned while waiting in backoff; `None` while a worker runs.
+    sub: Option<Box<dyn Subscription<M>>>,
+    policy: RestartPolicy,
+    /// Restarts consumed from the budget.
+    restarts: u32,
+    /// Pending restart deadline (the backoff window).
+    next_restart: Option<Instant>,
+    /// When the current/most recent worker started.
+    started_at: Instant,
 }
 
 /// Signal for stopping a subscription.
@@ -155,15 +274,23 @@ pub(crate) struct SubscriptionManager<M: Send + 'static> {
     active: Vec<RunningSubscription>,
     sender: mpsc::Sender<M>,
     receiver: mpsc::Receiver<M>,
+    /// Supervised subscriptions (policy != Never), keyed by id.
+    supervised: HashMap<SubId, SupervisedEntry<M>>,
+    health_tx: mpsc::Sender<HealthReport<M>>,
+    health_rx: mpsc::Receiver<HealthReport<M>>,
 }
 
 impl<M: Send + 'static> SubscriptionManager<M> {
     pub(crate) fn new() -> Self {
         let (sender, receiver) = mpsc::channel();
+        let (health_tx, health_rx) = mpsc::channel();
         Self {
             active: Vec::new(),
             sender,
             receiver,
+            supervised: HashMap::new(),
+            health_tx,
+            health_rx,
         }
     }
 
@@ -201,9 +328,13 @@ impl<M: Send + 'static> SubscriptionManager<M> {
             }
         }
         self.active = remaining;
+        // Undeclared supervised entries lose their pending restarts too.
+        self.supervised.retain(|id, _| new_ids.contains(id));
 
-        // Start new subscriptions
+        // Start new subscriptions. A supervised id in its backoff window
+        // counts as present: reconcile must not double-start it.
         let mut active_ids: HashSet<SubId> = self.active.iter().map(|r| r.id).collect();
+        active_ids.extend(self.supervised.keys().copied());
         for sub in subscriptions {
             let id = sub.id();
             if !active_ids.insert(id) {
@@ -212,18 +343,33 @@ impl<M: Send + 'static> SubscriptionManager<M> {
 
             crate::debug_trace!("starting subscription: id={}", id);
             tracing::debug!(sub_id = id, "Starting subscription");
-            let (signal, trigger) = StopSignal::new();
-            let sender = self.sender.clone();
-
-            let thread = thread::spawn(move || {
-                sub.run(sender, signal);
-            });
-
-            self.active.push(RunningSubscription {
-                id,
-                trigger,
-                thread: Some(thread),
-            });
+            match sub.restart_policy() {
+                RestartPolicy::Never => {
+                    let (signal, trigger) = StopSignal::new();
+                    let sender = self.sender.clone();
+                    let thread = thread::spawn(move || {
+                        sub.run(sender, signal);
+                    });
+                    self.active.push(RunningSubscription {
+                        id,
+                        trigger,
+                        thread: Some(thread),
+                    });
+                }
+                policy => {
+                    self.supervised.insert(
+                        id,
+                        SupervisedEntry {
+                            sub: Some(sub),
+                            policy,
+                            restarts: 0,
+                            next_restart: None,
+                            started_at: Instant::now(),
+                        },
+                    );
+                    self.spawn_supervised(id, Instant::now());
+                }
+            }
         }
 
         let active_count_after = self.active.len();
@@ -237,6 +383,145 @@ impl<M: Send + 'static> SubscriptionManager<M> {
         );
     }
 
+    /// Launch (or relaunch) the worker for a supervised entry.
+    ///
+    /// Takes the stored subscription box; it travels back through the
+    /// health channel when the worker ends.
+    fn spawn_supervised(&mut self, id: SubId, now: Instant) {
+        let Some(entry) = self.supervised.get_mut(&id) else {
+            return;
+        };
+        let Some(sub) = entry.sub.take() else {
+            return; // already running
+        };
+        entry.next_restart = None;
+        entry.started_at = now;
+
+        let (signal, trigger) = StopSignal::new();
+        let sender = self.sender.clone();
+        let health_tx = self.health_tx.clone();
+        let thread = thread::spawn(move || {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                sub.run_supervised(sender, signal)
+            }));
+            let outcome = match result {
+                Ok(Ok(())) => SubscriptionOutcome::Completed,
+                Ok(Err(message)) => SubscriptionOutcome::Failed(message),
+                Err(payload) => SubscriptionOutcome::Panicked(panic_message(&*payload)),
+            };
+            let _ = health_tx.send(HealthReport { id, outcome, sub });
+        });
+        self.active.push(RunningSubscription {
+            id,
+            trigger,
+            thread: Some(thread),
+        });
+    }
+
+    /// Drive supervision: collect worker exits, schedule restarts per
+    /// policy, and launch restarts whose backoff elapsed.
+    ///
+    /// `now` is the program clock (tests drive it manually, so backoff
+    /// schedules are Lab-testable). Events are delivered through the
+    /// normal message queue via [`Subscription::supervision_msg`].
+    pub(crate) fn supervise(&mut self, now: Instant) {
+        while let Ok(report) = self.health_rx.try_recv() {
+            self.handle_exit(report, now);
+        }
+        let due: Vec<SubId> = self
+            .supervised
+            .iter()
+            .filter(|(_, e)| e.next_restart.is_some_and(|at| now >= at))
+            .map(|(&id, _)| id)
+            .collect();
+        for id in due {
+            self.spawn_supervised(id, now);
+        }
+    }
+
+    fn handle_exit(&mut self, report: HealthReport<M>, now: Instant) {
+        let HealthReport { id, outcome, sub } = report;
+        // The worker thread is done; drop its handle.
+        self.active.retain(|running| running.id != id);
+
+        if !self.supervised.contains_key(&id) {
+            // Deliberately stopped by reconcile while exiting: not a
+            // health event.
+            return;
+        }
+        self.emit_event(&*sub, id, outcome.clone());
+        let Some(entry) = self.supervised.get_mut(&id) else {
+            return;
+        };
+
+        let (backoff, max_restarts, healthy_period, restart) = match entry.policy {
+            RestartPolicy::Never => {
+                self.supervised.remove(&id);
+                return;
+            }
+            RestartPolicy::OnFailure {
+                backoff,
+                max_restarts,
+                healthy_period,
+            } => {
+                let failed = !matches!(outcome, SubscriptionOutcome::Completed);
+                (backoff, max_restarts, healthy_period, failed)
+            }
+            RestartPolicy::Always {
+                backoff,
+                max_restarts,
+                healthy_period,
+            } => (backoff, max_restarts, healthy_period, true),
+        };
+
+        if !restart {
+            self.supervised.remove(&id);
+            return;
+        }
+
+        // A long healthy run earns a fresh backoff and restart budget.
+        if now.saturating_duration_since(entry.started_at) >= healthy_period {
+            entry.restarts = 0;
+        }
+
+        if entry.restarts >= max_restarts {
+            self.supervised.remove(&id);
+            self.emit_event(
+                &*sub,
+                id,
+                SubscriptionOutcome::GaveUp {
+                    restarts: max_restarts,
+                },
+            );
+            return;
+        }
+
+        let delay = backoff.delay(entry.restarts);
+        entry.restarts += 1;
+        entry.next_restart = Some(now + delay);
+        entry.sub = Some(sub);
+        let attempt = entry.restarts;
+        let announce = self
+            .supervised
+            .get(&id)
+            .and_then(|e| e.sub.as_deref())
+            .and_then(|s| {
+                s.supervision_msg(&SubscriptionEvent {
+                    id,
+                    outcome: SubscriptionOutcome::Restarting { attempt, delay },
+                })
+            });
+        if let Some(msg) = announce {
+            let _ = self.sender.send(msg);
+        }
+    }
+
+    fn emit_event(&self, sub: &dyn Subscription<M>, id: SubId, outcome: SubscriptionOutcome) {
+        if let Some(msg) = sub.supervision_msg(&SubscriptionEvent { id, outcome }) {
+            let _ = self.sender.send(msg);
+        }
+    }
+
     /// Drain pending messages from subscriptions.
     pub(crate) fn drain_messages(&self) -> Vec<M> {
         let mut messages = Vec::new();
@@ -252,8 +537,9 @@ impl<M: Send + 'static> SubscriptionManager<M> {
         self.active.len()
     }
 
-    /// Stop all running subscriptions.
+    /// Stop all running subscriptions and pending restarts.
     pub(crate) fn stop_all(&mut self) {
+        self.supervised.clear();
         for running in self.active.drain(..) {
             running.stop();
         }
@@ -266,6 +552,318 @@ impl<M: Send + 'static> Drop for SubscriptionManager<M> {
     }
 }
 
+/// Best-effort text from a panic payload.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod supervision_tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+    /// A worker that fails its first `failures` runs, then runs until
+    /// stopped (a healthy run).
+    struct Flaky {
+        id: SubId,
+        policy: RestartPolicy,
+        runs: Arc<AtomicU32>,
+        failures: u32,
+        panic_instead: bool,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Event(SubscriptionEvent),
+    }
+
+    impl Subscription<Msg> for Flaky {
+        fn id(&self) -> SubId {
+            self.id
+        }
+        fn run(&self, _sender: mpsc::Sender<Msg>, _stop: StopSignal) {
+            unreachable!("supervised path uses run_supervised");
+        }
+        fn run_supervised(
+            &self,
+            _sender: mpsc::Sender<Msg>,
+            stop: StopSignal,
+        ) -> Result<(), String> {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst);
+            if run < self.failures {
+                if self.panic_instead {
+                    panic!("boom {run}");
+                }
+                return Err(format!("connect error {run}"));
+            }
+            // Healthy: run until stopped.
+            while !stop.wait_timeout(Duration::from_millis(5)) {}
+            Ok(())
+        }
+        fn restart_policy(&self) -> RestartPolicy {
+            self.policy
+        }
+        fn supervision_msg(&self, event: &SubscriptionEvent) -> Option<Msg> {
+            Some(Msg::Event(event.clone()))
+        }
+    }
+
+    fn policy(base_ms: u64, max_ms: u64, max_restarts: u32, healthy_ms: u64) -> RestartPolicy {
+        RestartPolicy::OnFailure {
+            backoff: Backoff::Exponential {
+                base: Duration::from_millis(base_ms),
+                max: Duration::from_millis(max_ms),
+            },
+            max_restarts,
+            healthy_period: Duration::from_millis(healthy_ms),
+        }
+    }
+
+    /// Drive `supervise` at the fixed lab time `now` until the worker's
+    /// exit report has been consumed (backoff scheduled, or the entry
+    /// resolved), so tests never race thread teardown.
+    fn wait_for_exit(mgr: &mut SubscriptionManager<Msg>, now: Instant, id: SubId) {
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        loop {
+            mgr.supervise(now);
+            let settled = match mgr.supervised.get(&id) {
+                Some(entry) => entry.sub.is_some(),
+                None => true,
+            };
+            if settled {
+                return;
+            }
+            assert!(std::time::Instant::now() < deadline, "exit never observed");
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+    }
+
+    fn events(mgr: &SubscriptionManager<Msg>) -> Vec<SubscriptionOutcome> {
+        mgr.drain_messages()
+            .into_iter()
+            .map(|Msg::Event(e)| e.outcome)
+            .collect()
+    }
+
+    #[test]
+    fn failure_restarts_with_exponential_backoff_schedule() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let mut mgr = SubscriptionManager::new();
+        let t0 = Instant::now();
+        mgr.reconcile(vec![Box::new(Flaky {
+            id: 1,
+            policy: policy(100, 10_000, 5, 60_000),
+            runs: runs.clone(),
+            failures: 3,
+            panic_instead: false,
+        })]);
+
+        // First run fails immediately.
+        wait_for_exit(&mut mgr, t0, 1);
+        let evs = events(&mgr);
+        assert!(matches!(evs[0], SubscriptionOutcome::Failed(_)), "{evs:?}");
+        assert_eq!(
+            evs[1],
+            SubscriptionOutcome::Restarting {
+                attempt: 1,
+                delay: Duration::from_millis(100)
+            }
+        );
+
+        // Before the backoff elapses nothing restarts.
+        mgr.supervise(t0 + Duration::from_millis(50));
+        assert_eq!(runs.load(Ordering::SeqCst), 1, "still in backoff window");
+
+        // At the deadline the restart launches; it fails again → 200ms.
+        mgr.supervise(t0 + Duration::from_millis(100));
+        let t1 = t0 + Duration::from_millis(100);
+        wait_for_exit(&mut mgr, t1, 1);
+        let evs = events(&mgr);
+        assert_eq!(
+            evs[1],
+            SubscriptionOutcome::Restarting {
+                attempt: 2,
+                delay: Duration::from_millis(200)
+            }
+        );
+
+        // Third failure → 400ms.
+        let t2 = t1 + Duration::from_millis(200);
+        mgr.supervise(t2);
+        wait_for_exit(&mut mgr, t2, 1);
+        let evs = events(&mgr);
+        assert_eq!(
+            evs[1],
+            SubscriptionOutcome::Restarting {
+                attempt: 3,
+                delay: Duration::from_millis(400)
+            }
+        );
+        mgr.stop_all();
+    }
+
+    #[test]
+    fn panic_restarts_and_reports_panic_outcome() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let mut mgr = SubscriptionManager::new();
+        let t0 = Instant::now();
+        mgr.reconcile(vec![Box::new(Flaky {
+            id: 7,
+            policy: policy(10, 1_000, 3, 60_000),
+            runs: runs.clone(),
+            failures: 1,
+            panic_instead: true,
+        })]);
+        wait_for_exit(&mut mgr, t0, 7);
+        let evs = events(&mgr);
+        assert!(
+            matches!(&evs[0], SubscriptionOutcome::Panicked(m) if m.contains("boom")),
+            "{evs:?}"
+        );
+        assert!(matches!(evs[1], SubscriptionOutcome::Restarting { .. }));
+        mgr.stop_all();
+    }
+
+    #[test]
+    fn max_restarts_exhaustion_emits_terminal_event() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let mut mgr = SubscriptionManager::new();
+        let mut now = Instant::now();
+        mgr.reconcile(vec![Box::new(Flaky {
+            id: 2,
+            policy: policy(10, 1_000, 2, 60_000),
+            runs: runs.clone(),
+            failures: u32::MAX,
+            panic_instead: false,
+        })]);
+
+        wait_for_exit(&mut mgr, now, 2); // fail #1 → restart 1
+        let _ = events(&mgr);
+        for _ in 2..=3u32 {
+            now += Duration::from_millis(1_000);
+            mgr.supervise(now); // launch restart
+            wait_for_exit(&mut mgr, now, 2);
+        }
+        let evs = events(&mgr);
+        assert_eq!(
+            evs.last(),
+            Some(&SubscriptionOutcome::GaveUp { restarts: 2 }),
+            "{evs:?}"
+        );
+        // Gone: nothing further restarts.
+        now += Duration::from_millis(10_000);
+        mgr.supervise(now);
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert_eq!(mgr.active_count(), 0);
+    }
+
+    #[test]
+    fn healthy_period_resets_backoff_budget() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let mut mgr = SubscriptionManager::new();
+        let t0 = Instant::now();
+        // healthy period 1ms: every run counts as healthy (the exit
+        // report is consumed with `now` far past started_at).
+        mgr.reconcile(vec![Box::new(Flaky {
+            id: 3,
+            policy: policy(100, 10_000, 1, 1),
+            runs: runs.clone(),
+            failures: u32::MAX,
+            panic_instead: false,
+        })]);
+
+        wait_for_exit(&mut mgr, t0 + Duration::from_millis(50), 3);
+        let evs = events(&mgr);
+        assert_eq!(
+            evs[1],
+            SubscriptionOutcome::Restarting {
+                attempt: 1,
+                delay: Duration::from_millis(100)
+            }
+        );
+        // Second failure: budget would be exhausted (max_restarts 1),
+        // but the healthy reset starts the schedule over at 100ms.
+        let t1 = t0 + Duration::from_millis(150);
+        mgr.supervise(t1);
+        wait_for_exit(&mut mgr, t1 + Duration::from_millis(50), 3);
+        let evs = events(&mgr);
+        assert_eq!(
+            evs[1],
+            SubscriptionOutcome::Restarting {
+                attempt: 1,
+                delay: Duration::from_millis(100)
+            },
+            "budget and backoff reset: {evs:?}"
+        );
+        mgr.stop_all();
+    }
+
+    #[test]
+    fn reconcile_does_not_double_start_during_backoff() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let mut mgr = SubscriptionManager::new();
+        let t0 = Instant::now();
+        let make = |runs: Arc<AtomicU32>| -> Box<dyn Subscription<Msg>> {
+            Box::new(Flaky {
+                id: 4,
+                policy: policy(10_000, 60_000, 5, 60_000),
+                runs,
+                failures: u32::MAX,
+                panic_instead: false,
+            })
+        };
+        mgr.reconcile(vec![make(runs.clone())]);
+        wait_for_exit(&mut mgr, t0, 4);
+        let _ = events(&mgr);
+        assert_eq!(mgr.active_count(), 0, "worker dead, restart pending");
+
+        // Re-declaring the same id during the backoff window must not
+        // spawn a second instance.
+        mgr.reconcile(vec![make(runs.clone())]);
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert_eq!(runs.load(Ordering::SeqCst), 1, "no double start");
+
+        // Dropping the declaration cancels the pending restart.
+        mgr.reconcile(vec![]);
+        mgr.supervise(t0 + Duration::from_secs(120));
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert_eq!(runs.load(Ordering::SeqCst), 1, "restart cancelled");
+    }
+
+    #[test]
+    fn unsupervised_subscriptions_keep_legacy_path() {
+        struct Once {
+            done: Arc<AtomicUsize>,
+        }
+        impl Subscription<Msg> for Once {
+            fn id(&self) -> SubId {
+                9
+            }
+            fn run(&self, _sender: mpsc::Sender<Msg>, _stop: StopSignal) {
+                self.done.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        let done = Arc::new(AtomicUsize::new(0));
+        let mut mgr: SubscriptionManager<Msg> = SubscriptionManager::new();
+        mgr.reconcile(vec![Box::new(Once { done: done.clone() })]);
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while done.load(Ordering::SeqCst) == 0 {
+            assert!(std::time::Instant::now() < deadline);
+            std::thread::yield_now();
+        }
+        mgr.supervise(Instant::now());
+        assert!(events(&mgr).is_empty(), "no supervision events");
+    }
+}
+
 // --- Built-in subscriptions ---
 
 /// A subscription that fires at a fixed interval.
@@ -281,6 +879,7 @@ pub struct Every<M: Send + 'static> {
     id: SubId,
     interval: Duration,
     make_msg: Box<dyn Fn() -> M + Send + Sync>,
+    rate: Option<crate::idle::TickRateScale>,
 }
 
 impl<M: Send + 'static> Every<M> {
@@ -292,6 +891,7 @@ impl<M: Send + 'static> Every<M> {
             id,
             interval,
             make_msg: Box::new(make_msg),
+            rate: None,
         }
     }
 
@@ -305,6 +905,301 @@ impl<M: Send + 'static> Every<M> {
             id,
             interval,
             make_msg: Box::new(make_msg),
+            rate: None,
+        }
+    }
+
+    /// Throttle ticks through a shared [`TickRateScale`] (idle power
+    /// saving): only every Nth tick is delivered while the scale is
+    /// reduced, none while paused.
+    ///
+    /// [`TickRateScale`]: crate::idle::TickRateScale
+    #[must_use]
+    pub fn with_rate_scale(mut self, scale: crate::idle::TickRateScale) -> Self {
+        self.rate = Some(scale);
+        self
+    }
+}
+
+// ============================================================================
+// Schedule-aligned timers
+// ============================================================================
+
+/// Time source for timer subscriptions (wall clock or deterministic lab).
+#[derive(Debug, Clone)]
+enum TimerClock {
+    Wall,
+    Lab(ftui_core::cx::LabClock),
+}
+
+impl TimerClock {
+    fn now(&self) -> Instant {
+        match self {
+            Self::Wall => Instant::now(),
+            Self::Lab(clock) => clock.now(),
+        }
+    }
+}
+
+/// Poll slice while waiting on a lab clock (real threads cannot block on
+/// simulated time).
+const LAB_POLL_SLICE: Duration = Duration::from_millis(1);
+
+/// Information delivered with each interval tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TickInfo {
+    /// Index of this tick on the ideal schedule (0-based).
+    pub tick: u64,
+    /// Periods that elapsed without delivery before this tick (stall
+    /// coalescing: one catch-up tick carries the missed count).
+    pub missed: u64,
+}
+
+/// Pure schedule state for a drift-free interval.
+///
+/// The next deadline is always computed from the start epoch
+/// (`epoch + (delivered + missed + 1) * period`), never from the previous
+/// delivery time, so late wakeups don't accumulate drift. After a stall,
+/// [`advance`](Self::advance) coalesces all elapsed periods into a single
+/// tick with a missed count.
+#[derive(Debug, Clone)]
+pub struct IntervalSchedule {
+    epoch: Instant,
+    period: Duration,
+    /// Schedule positions consumed so far (delivered ticks + missed).
+    consumed: u64,
+}
+
+impl IntervalSchedule {
+    /// Start a schedule at `epoch`.
+    #[must_use]
+    pub fn new(epoch: Instant, period: Duration) -> Self {
+        Self {
+            epoch,
+            period: period.max(Duration::from_nanos(1)),
+            consumed: 0,
+        }
+    }
+
+    /// The next ideal deadline.
+    #[must_use]
+    pub fn next_deadline(&self) -> Instant {
+        let n = (self.consumed + 1).min(u64::from(u32::MAX)) as u32;
+        self.epoch + self.period.saturating_mul(n)
+    }
+
+    /// Consume all periods elapsed by `now`.
+    ///
+    /// Returns `None` when the next deadline hasn't arrived; otherwise one
+    /// coalesced tick covering every elapsed period.
+    pub fn advance(&mut self, now: Instant) -> Option<TickInfo> {
+        let elapsed = now.saturating_duration_since(self.epoch);
+        let due = (elapsed.as_nanos() / self.period.as_nanos()) as u64;
+        if due <= self.consumed {
+            return None;
+        }
+        let missed = due - self.consumed - 1;
+        self.consumed = due;
+        Some(TickInfo {
+            tick: due - 1,
+            missed,
+        })
+    }
+}
+
+/// Drift-free interval subscription with stall coalescing.
+///
+/// Ticks align to the ideal schedule derived from the start epoch; when
+/// the app stalls (laptop sleep, long update), a single catch-up tick is
+/// delivered carrying the number of missed periods instead of a burst.
+/// Sleeping always goes through the [`StopSignal`], so reconcile/removal
+/// never leaves a thread sleeping past shutdown, and the stop flag is
+/// re-checked before every send so no tick is delivered after removal.
+///
+/// # Example
+///
+/// ```ignore
+/// vec![Box::new(Interval::new(Duration::from_millis(16), |info| {
+///     MyMsg::Frame { missed: info.missed }
+/// }))]
+/// ```
+pub struct Interval<M: Send + 'static> {
+    id: SubId,
+    period: Duration,
+    clock: TimerClock,
+    /// Schedule start, captured at construction.
+    epoch: Instant,
+    make_msg: Box<dyn Fn(TickInfo) -> M + Send + Sync>,
+    rate: Option<crate::idle::TickRateScale>,
+}
+
+impl<M: Send + 'static> Interval<M> {
+    /// Create a schedule-aligned interval subscription.
+    pub fn new(period: Duration, make_msg: impl Fn(TickInfo) -> M + Send + Sync + 'static) -> Self {
+        let id = (period.as_nanos() as u64).rotate_left(17) ^ 0x494E_5456; // "INTV"
+        Self {
+            id,
+            period,
+            clock: TimerClock::Wall,
+            epoch: Instant::now(),
+            make_msg: Box::new(make_msg),
+            rate: None,
+        }
+    }
+
+    /// Create with an explicit ID (for deduplication control).
+    pub fn with_id(
+        id: SubId,
+        period: Duration,
+        make_msg: impl Fn(TickInfo) -> M + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            id,
+            period,
+            clock: TimerClock::Wall,
+            epoch: Instant::now(),
+            make_msg: Box::new(make_msg),
+            rate: None,
+        }
+    }
+
+    /// Throttle ticks through a shared [`TickRateScale`] (idle power
+    /// saving): only every Nth schedule tick is delivered while the
+    /// scale is reduced, none while paused. The schedule keeps running,
+    /// so restoring the rate resumes on the ideal grid.
+    ///
+    /// [`TickRateScale`]: crate::idle::TickRateScale
+    #[must_use]
+    pub fn with_rate_scale(mut self, scale: crate::idle::TickRateScale) -> Self {
+        self.rate = Some(scale);
+        self
+    }
+
+    /// Drive the schedule from a [`LabClock`](ftui_core::cx::LabClock) for
+    /// deterministic tests.
+    #[must_use]
+    pub fn with_lab_clock(mut self, clock: &ftui_core::cx::LabClock) -> Self {
+        self.clock = TimerClock::Lab(clock.clone());
+        self.epoch = self.clock.now();
+        self
+    }
+}
+
+impl<M: Send + 'static> Subscription<M> for Interval<M> {
+    fn id(&self) -> SubId {
+        self.id
+    }
+
+    fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal) {
+        let mut schedule = IntervalSchedule::new(self.epoch, self.period);
+        loop {
+            // Sleep toward the ideal deadline via the stop signal so
+            // cancellation interrupts the wait immediately. Lab clocks
+            // can't be waited on; poll in small slices instead.
+            let wait = match &self.clock {
+                TimerClock::Wall => schedule
+                    .next_deadline()
+                    .saturating_duration_since(Instant::now())
+                    .max(Duration::from_nanos(1)),
+                TimerClock::Lab(_) => LAB_POLL_SLICE,
+            };
+            if stop.wait_timeout(wait) {
+                return;
+            }
+            if let Some(info) = schedule.advance(self.clock.now()) {
+                // Never deliver after removal: the stop flag wins races
+                // between wakeup and cancellation.
+                if stop.is_stopped() {
+                    return;
+                }
+                // Idle throttle: skip ticks the scale doesn't admit.
+                if let Some(rate) = &self.rate
+                    && !rate.admits(info.tick)
+                {
+                    continue;
+                }
+                if sender.send((self.make_msg)(info)).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// One-shot timeout subscription.
+///
+/// Delivers a single message after `duration`, then exits. Cancellation
+/// through the [`StopSignal`] wakes the sleep immediately, and the flag is
+/// re-checked before the send so nothing is delivered after removal.
+pub struct Timeout<M: Send + 'static> {
+    id: SubId,
+    duration: Duration,
+    clock: TimerClock,
+    /// Countdown start, captured at construction.
+    epoch: Instant,
+    make_msg: Box<dyn Fn() -> M + Send + Sync>,
+}
+
+impl<M: Send + 'static> Timeout<M> {
+    /// Create a one-shot timeout subscription.
+    pub fn new(duration: Duration, make_msg: impl Fn() -> M + Send + Sync + 'static) -> Self {
+        let id = (duration.as_nanos() as u64).rotate_left(29) ^ 0x544F_5554; // "TOUT"
+        Self {
+            id,
+            duration,
+            clock: TimerClock::Wall,
+            epoch: Instant::now(),
+            make_msg: Box::new(make_msg),
+        }
+    }
+
+    /// Create with an explicit ID (for deduplication control).
+    pub fn with_id(
+        id: SubId,
+        duration: Duration,
+        make_msg: impl Fn() -> M + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            id,
+            duration,
+            clock: TimerClock::Wall,
+            epoch: Instant::now(),
+            make_msg: Box::new(make_msg),
+        }
+    }
+
+    /// Drive the timer from a [`LabClock`](ftui_core::cx::LabClock).
+    #[must_use]
+    pub fn with_lab_clock(mut self, clock: &ftui_core::cx::LabClock) -> Self {
+        self.clock = TimerClock::Lab(clock.clone());
+        self.epoch = self.clock.now();
+        self
+    }
+}
+
+impl<M: Send + 'static> Subscription<M> for Timeout<M> {
+    fn id(&self) -> SubId {
+        self.id
+    }
+
+    fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal) {
+        let deadline = self.epoch + self.duration;
+        loop {
+            let wait = match &self.clock {
+                TimerClock::Wall => deadline
+                    .saturating_duration_since(Instant::now())
+                    .max(Duration::from_nanos(1)),
+                TimerClock::Lab(_) => LAB_POLL_SLICE,
+            };
+            if stop.wait_timeout(wait) {
+                return;
+            }
+            if self.clock.now() >= deadline {
+                if !stop.is_stopped() {
+                    let _ = sender.send((self.make_msg)());
+                }
+                return;
+            }
         }
     }
 }
@@ -331,6 +1226,12 @@ impl<M: Send + 'static> Subscription<M> for Every<M> {
                 break;
             }
             tick_count += 1;
+            // Idle throttle: skip ticks the scale doesn't admit.
+            if let Some(rate) = &self.rate
+                && !rate.admits(tick_count - 1)
+            {
+                continue;
+            }
             let msg = (self.make_msg)();
             if sender.send(msg).is_err() {
                 crate::debug_trace!(
@@ -1005,4 +1906,216 @@ mod tests {
         let msgs = mgr.drain_messages();
         assert!(msgs.is_empty());
     }
+
+    // --- Schedule-aligned timers ---
+
+    use ftui_core::cx::LabClock;
+
+    #[test]
+    fn interval_schedule_is_drift_free_over_1000_ticks() {
+        let epoch = Instant::now();
+        let period = Duration::from_millis(16);
+        let mut schedule = IntervalSchedule::new(epoch, period);
+
+        for k in 0..1000u64 {
+            // Wake slightly late every time; the schedule must not drift.
+            let wake = epoch + period * (k as u32 + 1) + Duration::from_millis(3);
+            let info = schedule.advance(wake).expect("tick due");
+            assert_eq!(info.tick, k, "tick index aligned to ideal schedule");
+            assert_eq!(info.missed, 0);
+            // Next deadline stays on the ideal grid.
+            assert_eq!(schedule.next_deadline(), epoch + period * (k as u32 + 2));
+        }
+    }
+
+    #[test]
+    fn stall_coalesces_into_one_tick_with_missed_count() {
+        let epoch = Instant::now();
+        let period = Duration::from_millis(10);
+        let mut schedule = IntervalSchedule::new(epoch, period);
+
+        // First tick on time.
+        assert_eq!(
+            schedule.advance(epoch + period),
+            Some(TickInfo { tick: 0, missed: 0 })
+        );
+        // Laptop-sleep stall: 50 periods pass. One catch-up tick.
+        let info = schedule
+            .advance(epoch + period * 51)
+            .expect("catch-up tick");
+        assert_eq!(info.tick, 50);
+        assert_eq!(info.missed, 49);
+        // No burst afterwards.
+        assert_eq!(schedule.advance(epoch + period * 51), None);
+    }
+
+    #[test]
+    fn interval_lab_clock_delivers_deterministic_ticks() {
+        let clock = LabClock::new();
+        let sub = Interval::new(Duration::from_secs(1), |info: TickInfo| {
+            TestMsg::Value(info.tick as i32)
+        })
+        .with_lab_clock(&clock);
+
+        let (tx, rx) = mpsc::channel();
+        let (signal, trigger) = StopSignal::new();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+
+        clock.advance(Duration::from_secs(3));
+        // One coalesced tick covering the 3 elapsed periods.
+        let msg = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(msg, TestMsg::Value(2));
+
+        trigger.stop();
+        handle.join().unwrap();
+        assert!(rx.try_recv().is_err(), "no tick after stop");
+    }
+
+    #[test]
+    fn interval_rate_scale_thins_and_restores_ticks() {
+        let clock = LabClock::new();
+        let scale = crate::idle::TickRateScale::new();
+        scale.set_multiplier(3);
+        let sub = Interval::new(Duration::from_secs(1), |info: TickInfo| {
+            TestMsg::Value(info.tick as i32)
+        })
+        .with_lab_clock(&clock)
+        .with_rate_scale(scale.clone());
+
+        let (tx, rx) = mpsc::channel();
+        let (signal, trigger) = StopSignal::new();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+
+        // Six periods, one at a time so nothing coalesces: only every
+        // third schedule tick (0, 3) is admitted while throttled.
+        for _ in 0..6 {
+            clock.advance(Duration::from_secs(1));
+            thread::sleep(std::time::Duration::from_millis(20));
+        }
+        // Restore full rate: subsequent ticks all arrive.
+        scale.restore();
+        for _ in 0..2 {
+            clock.advance(Duration::from_secs(1));
+            thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        trigger.stop();
+        handle.join().unwrap();
+        let delivered: Vec<TestMsg> = rx.try_iter().collect();
+        assert_eq!(
+            delivered,
+            vec![
+                TestMsg::Value(0),
+                TestMsg::Value(3),
+                TestMsg::Value(6),
+                TestMsg::Value(7),
+            ]
+        );
+    }
+
+    #[test]
+    fn interval_paused_scale_delivers_nothing() {
+        let clock = LabClock::new();
+        let scale = crate::idle::TickRateScale::new();
+        scale.pause();
+        let sub = Interval::new(Duration::from_secs(1), |_| TestMsg::Tick)
+            .with_lab_clock(&clock)
+            .with_rate_scale(scale.clone());
+
+        let (tx, rx) = mpsc::channel::<TestMsg>();
+        let (signal, trigger) = StopSignal::new();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+        clock.advance(Duration::from_secs(10));
+        thread::sleep(std::time::Duration::from_millis(30));
+        trigger.stop();
+        handle.join().unwrap();
+        assert!(rx.try_recv().is_err(), "paused timers stay silent");
+    }
+
+    #[test]
+    fn interval_cancellation_mid_sleep_exits_promptly() {
+        let sub = Interval::new(Duration::from_secs(3600), |_| TestMsg::Tick);
+        let (tx, rx) = mpsc::channel::<TestMsg>();
+        let (signal, trigger) = StopSignal::new();
+
+        let start = std::time::Instant::now();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+        thread::sleep(std::time::Duration::from_millis(20));
+        trigger.stop();
+        handle.join().unwrap();
+
+        // The hour-long sleep was interrupted immediately.
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn timeout_delivers_once_then_exits() {
+        let clock = LabClock::new();
+        let sub =
+            Timeout::new(Duration::from_secs(10), || TestMsg::Value(42)).with_lab_clock(&clock);
+        let (tx, rx) = mpsc::channel();
+        let (signal, _trigger) = StopSignal::new();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+
+        clock.advance(Duration::from_secs(11));
+        let msg = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(msg, TestMsg::Value(42));
+        // One-shot: the thread exits and the channel disconnects.
+        handle.join().unwrap();
+        assert!(rx.recv().is_err());
+    }
+
+    #[test]
+    fn timeout_cancelled_before_deadline_delivers_nothing() {
+        let sub = Timeout::new(Duration::from_secs(3600), || TestMsg::Tick);
+        let (tx, rx) = mpsc::channel::<TestMsg>();
+        let (signal, trigger) = StopSignal::new();
+        let handle = thread::spawn(move || sub.run(tx, signal));
+        thread::sleep(std::time::Duration::from_millis(20));
+        trigger.stop();
+        handle.join().unwrap();
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn two_intervals_interleave_in_period_order() {
+        // Pure-schedule check so ordering is deterministic: a 10ms and a
+        // 25ms interval over 100ms merge in deadline order.
+        let epoch = Instant::now();
+        let mut fast = IntervalSchedule::new(epoch, Duration::from_millis(10));
+        let mut slow = IntervalSchedule::new(epoch, Duration::from_millis(25));
+
+        let mut order: Vec<(&str, u64)> = Vec::new();
+        let mut now = epoch;
+        for _ in 0..20 {
+            let next = fast.next_deadline().min(slow.next_deadline());
+            now = now.max(next);
+            if fast.next_deadline() <= now
+                && let Some(info) = fast.advance(now)
+            {
+                order.push(("fast", info.tick));
+            }
+            if slow.next_deadline() <= now
+                && let Some(info) = slow.advance(now)
+            {
+                order.push(("slow", info.tick));
+            }
+        }
+
+        // Within the first 50ms: fast at 10,20,30,40,50 and slow at 25,50.
+        let first_seven: Vec<(&str, u64)> = order.into_iter().take(7).collect();
+        assert_eq!(
+            first_seven,
+            vec![
+                ("fast", 0),
+                ("fast", 1),
+                ("slow", 0),
+                ("fast", 2),
+                ("fast", 3),
+                ("fast", 4),
+                ("slow", 1),
+            ]
+        );
+    }
 }
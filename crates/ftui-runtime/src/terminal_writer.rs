@@ -47,7 +47,7 @@
 //! writer.present_ui(&buffer, None, true)?;
 //! ```
 
-use std::io::{self, BufWriter, Write};
+use std::io::{self, Write};
 use std::sync::atomic::{AtomicU32, Ordering};
 use web_time::Instant;
 
@@ -105,7 +105,7 @@ const SGR_BG_DEFAULT: &[u8] = b"\x1b[49m";
 const FULL_REDRAW_PROBE_INTERVAL: u64 = 60;
 
 // CountingWriter is re-used from ftui_render::counting_writer::CountingWriter.
-// The Presenter wraps the writer in CountingWriter<BufWriter<W>>.
+// The Presenter wraps the writer in CountingWriter<FrameBatcher<W>>.
 // For byte counting, use reset_counter() and bytes_written() on the counting writer.
 
 fn default_diff_run_id() -> String {
@@ -495,6 +495,8 @@ pub struct TerminalWriter<W: Write> {
     diff_evidence_idx: u64,
     /// Last diff strategy selected during present.
     last_diff_strategy: Option<DiffStrategy>,
+    /// Diff cell count of the last successful present.
+    last_diff_cells: Option<usize>,
     /// Render-trace recorder (optional).
     render_trace: Option<RenderTraceRecorder>,
     /// Whether per-frame timing capture is enabled.
@@ -632,6 +634,7 @@ impl<W: Write> TerminalWriter<W> {
             diff_evidence_run_id: default_diff_run_id(),
             diff_evidence_idx: 0,
             last_diff_strategy: None,
+            last_diff_cells: None,
             render_trace: None,
             timing_enabled: false,
             last_present_timings: None,
@@ -644,7 +647,7 @@ impl<W: Write> TerminalWriter<W> {
     ///
     /// Panics if the presenter has been taken (via `into_inner`).
     #[inline]
-    fn writer(&mut self) -> &mut CountingWriter<BufWriter<W>> {
+    fn writer(&mut self) -> &mut CountingWriter<ftui_render::frame_batcher::FrameBatcher<W>> {
         self.presenter_mut().counting_writer_mut()
     }
 
@@ -667,6 +670,7 @@ impl<W: Write> TerminalWriter<W> {
         }
         self.full_redraw_probe = 0;
         self.last_diff_strategy = None;
+        self.last_diff_cells = None;
     }
 
     /// Reset diff strategy state on terminal resize.
@@ -677,6 +681,7 @@ impl<W: Write> TerminalWriter<W> {
         }
         self.full_redraw_probe = 0;
         self.last_diff_strategy = None;
+        self.last_diff_cells = None;
     }
 
     /// Get the current diff configuration.
@@ -721,6 +726,39 @@ impl<W: Write> TerminalWriter<W> {
         self.render_trace = recorder;
     }
 
+    /// Invalidate assumed terminal state (SGR/link/cursor).
+    ///
+    /// The runtime calls this on focus-in and resize; embedders should
+    /// call it after writing to the terminal outside this writer. The
+    /// next frame re-establishes attributes defensively.
+    pub fn invalidate_terminal_state(&mut self) {
+        if let Some(presenter) = self.presenter.as_mut() {
+            presenter.invalidate_terminal_state();
+        }
+    }
+
+    /// Attach a session recorder handle that mirrors presenter output into an
+    /// asciicast recording (one `"o"` event per flush).
+    #[must_use]
+    pub fn with_session_recorder(
+        mut self,
+        handle: crate::asciicast::SessionRecorderHandle,
+    ) -> Self {
+        self.set_session_recorder(Some(handle));
+        self
+    }
+
+    /// Set (or clear) the session recorder tap on the output stream.
+    pub fn set_session_recorder(
+        &mut self,
+        handle: Option<crate::asciicast::SessionRecorderHandle>,
+    ) {
+        self.writer().set_tap(handle.map(|h| {
+            Box::new(crate::asciicast::SessionOutputTap::new(h))
+                as Box<dyn ftui_render::counting_writer::WriteTap>
+        }));
+    }
+
     /// Get mutable access to the diff strategy selector.
     ///
     /// Useful for advanced scenarios like manual posterior updates.
@@ -738,12 +776,21 @@ impl<W: Write> TerminalWriter<W> {
         self.last_diff_strategy
     }
 
+    /// Diff cell count of the last successful present (evidence events).
+    pub fn last_diff_cells(&self) -> Option<usize> {
+        self.last_diff_cells
+    }
+
     /// Set the terminal size.
     ///
     /// Call this when the terminal is resized.
     pub fn set_size(&mut self, width: u16, height: u16) {
         self.term_width = width;
         self.term_height = height;
+        // A resize invalidates every assumption about terminal state.
+        if let Some(presenter) = self.presenter.as_mut() {
+            presenter.invalidate_terminal_state();
+        }
         if matches!(self.screen_mode, ScreenMode::InlineAuto { .. }) {
             self.auto_ui_height = None;
         }
@@ -1214,6 +1261,7 @@ impl<W: Write> TerminalWriter<W> {
         }
 
         if let Ok(stats) = result {
+            self.last_diff_cells = Some(stats.diff_cells);
             if let Some(ref mut trace) = self.render_trace {
                 let payload_info = match stats.diff_strategy {
                     DiffStrategy::FullRedraw => {
@@ -1799,10 +1847,12 @@ impl<W: Write> TerminalWriter<W> {
             let emit_stats = {
                 let _span = debug_span!("ftui.render.emit").entered();
                 let presenter = self.presenter.as_mut().expect("presenter consumed");
-                
-                // Reset presenter state (cursor and style) because we manually moved
-                // the cursor and reset the style at the end of the previous frame.
-                presenter.reset();
+
+                // Only the cursor was moved manually since the previous
+                // frame; SGR/link state carries across so unchanged
+                // attributes cost zero bytes. Invalidation (resize, focus,
+                // external writes) falls back to the defensive reset.
+                presenter.reset_cursor();
                 // AltScreen always starts at (0,0) relative to terminal.
                 presenter.set_viewport_offset_y(0);
 
@@ -1827,9 +1877,6 @@ impl<W: Write> TerminalWriter<W> {
                 }
             };
 
-            // Reset style at end
-            self.writer().write_all(b"\x1b[0m")?;
-
             let mut show_cursor = false;
             if cursor_visible
                 && let Some((cx, cy)) = cursor
@@ -3078,7 +3125,13 @@ mod tests {
             .filter(|w| *w == SGR_BG_DEFAULT)
             .count();
 
-        assert_eq!(erase_count, 6, "expected clears for stale + new rows");
+        // At least the stale + new rows are cleared explicitly; the
+        // presenter's erase-line optimization may add EL for blank-row
+        // tails on top of these.
+        assert!(
+            erase_count >= 6,
+            "expected clears for stale + new rows, got {erase_count}"
+        );
         assert!(
             bg_reset_count >= 2,
             "expected background resets before row clears"
@@ -5512,4 +5565,116 @@ mod tests {
             );
         }
     }
+
+    // --- Cross-frame SGR state carryover ---
+
+    mod sgr_carryover {
+        use super::*;
+        use ftui_render::terminal_model::TerminalModel;
+
+        fn styled_dashboard(width: u16, height: u16, tick: u32) -> Buffer {
+            let mut buffer = Buffer::new(width, height);
+            for y in 0..height {
+                for x in 0..width {
+                    let mut cell = Cell::from_char(char::from(b'a' + ((x + y) % 26) as u8));
+                    // Heavy, mostly static styling with a small animated
+                    // region (the realistic dashboard pattern).
+                    cell.fg = ftui_render::cell::PackedRgba::rgb(200, 200, 40);
+                    cell.bg = ftui_render::cell::PackedRgba::rgb(20, 20 + (y as u8 % 4) * 10, 60);
+                    buffer.set_raw(x, y, cell);
+                }
+            }
+            // Animated counter cell.
+            let mut hot = Cell::from_char(char::from(b'0' + (tick % 10) as u8));
+            hot.fg = ftui_render::cell::PackedRgba::rgb(200, 200, 40);
+            hot.bg = ftui_render::cell::PackedRgba::rgb(20, 20, 60);
+            buffer.set_raw(2, 1, hot);
+            buffer
+        }
+
+        /// Run a styled frame sequence, optionally invalidating terminal
+        /// state before every frame (the conservative path). Returns the
+        /// total bytes and the final terminal-model screen.
+        fn run_sequence(invalidate_each_frame: bool) -> (usize, Vec<String>) {
+            let mut output = Vec::new();
+            let (width, height) = (40u16, 10u16);
+            {
+                let mut writer = TerminalWriter::new(
+                    &mut output,
+                    ScreenMode::AltScreen,
+                    UiAnchor::Bottom,
+                    basic_caps(),
+                );
+                writer.set_size(width, height);
+                for tick in 0..6 {
+                    if invalidate_each_frame {
+                        writer.invalidate_terminal_state();
+                    }
+                    let buffer = styled_dashboard(width, height, tick);
+                    writer.present_ui(&buffer, None, false).unwrap();
+                }
+            }
+            let mut model = TerminalModel::new(usize::from(width), usize::from(height));
+            model.process(&output);
+            let screen = (0..usize::from(height))
+                .map(|y| model.row_text(y).unwrap_or_default())
+                .collect();
+            (output.len(), screen)
+        }
+
+        #[test]
+        fn carryover_matches_conservative_screen_content() {
+            let (_, carried) = run_sequence(false);
+            let (_, conservative) = run_sequence(true);
+            assert_eq!(
+                carried, conservative,
+                "cross-frame state reuse must not change the final screen"
+            );
+        }
+
+        #[test]
+        fn carryover_shrinks_partial_update_bytes() {
+            let (carried_bytes, _) = run_sequence(false);
+            let (conservative_bytes, _) = run_sequence(true);
+            assert!(
+                carried_bytes < conservative_bytes,
+                "carryover ({carried_bytes}) should emit fewer bytes than \
+                 the conservative path ({conservative_bytes})"
+            );
+        }
+
+        #[test]
+        fn invalidation_restores_defensive_reset() {
+            // Two identical frames back-to-back, with and without an
+            // invalidation between them.
+            let run = |invalidate_between: bool| -> usize {
+                let mut output = Vec::new();
+                {
+                    let mut writer = TerminalWriter::new(
+                        &mut output,
+                        ScreenMode::AltScreen,
+                        UiAnchor::Bottom,
+                        basic_caps(),
+                    );
+                    writer.set_size(10, 3);
+                    let buffer = styled_dashboard(10, 3, 0);
+                    writer.present_ui(&buffer, None, false).unwrap();
+                    if invalidate_between {
+                        writer.invalidate_terminal_state();
+                    }
+                    let buffer = styled_dashboard(10, 3, 1);
+                    writer.present_ui(&buffer, None, false).unwrap();
+                }
+                output.len()
+            };
+
+            let carried = run(false);
+            let defensive = run(true);
+            assert!(
+                defensive > carried,
+                "invalidation should restore the defensive reset: \
+                 carried {carried}B vs defensive {defensive}B"
+            );
+        }
+    }
 }
@@ -286,6 +286,12 @@ impl CommandBatch {
         }
     }
 
+    /// The commands in this batch, in execution order.
+    #[must_use]
+    pub fn commands(&self) -> &[Box<dyn UndoableCmd>] {
+        &self.commands
+    }
+
     /// Add a command to the batch.
     pub fn push(&mut self, cmd: Box<dyn UndoableCmd>) {
         self.commands.push(cmd);
@@ -379,6 +385,72 @@ pub type TextRemoveFn = Box<dyn Fn(WidgetId, usize, usize) -> CommandResult + Se
 /// Callback type for replacing text.
 pub type TextReplaceFn = Box<dyn Fn(WidgetId, usize, usize, &str) -> CommandResult + Send + Sync>;
 
+/// Cursor position in whatever units the owning widget uses
+/// (`(0, char_index)` for single-line inputs, `(row, col)` for text areas).
+pub type CursorPos = (usize, usize);
+/// Selection span as `(anchor, head)` cursor positions.
+pub type SelectionSpan = (CursorPos, CursorPos);
+/// Callback restoring cursor and selection through the widget's own APIs,
+/// so positions are clamped in-bounds by the widget (same pattern as the
+/// apply/remove callbacks).
+pub type CursorRestoreFn =
+    Box<dyn Fn(WidgetId, CursorPos, Option<SelectionSpan>) -> CommandResult + Send + Sync>;
+
+/// Cursor/selection capture shared by the text commands.
+///
+/// `undo()` restores the *before* state, `execute()`/redo the *after*
+/// state. Merging keeps the first command's before-state and the last
+/// command's after-state.
+#[derive(Default)]
+pub(crate) struct CursorCapture {
+    pub(crate) cursor_before: Option<CursorPos>,
+    pub(crate) cursor_after: Option<CursorPos>,
+    pub(crate) selection_before: Option<SelectionSpan>,
+    pub(crate) selection_after: Option<SelectionSpan>,
+    pub(crate) restore: Option<CursorRestoreFn>,
+}
+
+impl fmt::Debug for CursorCapture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorCapture")
+            .field("cursor_before", &self.cursor_before)
+            .field("cursor_after", &self.cursor_after)
+            .field("selection_before", &self.selection_before)
+            .field("selection_after", &self.selection_after)
+            .field("has_restore", &self.restore.is_some())
+            .finish()
+    }
+}
+
+impl CursorCapture {
+    /// Restore the pre-edit cursor/selection (after a successful undo).
+    fn restore_before(&self, target: WidgetId) -> CommandResult {
+        if let (Some(restore), Some(cursor)) = (&self.restore, self.cursor_before) {
+            restore(target, cursor, self.selection_before)?;
+        }
+        Ok(())
+    }
+
+    /// Restore the post-edit cursor/selection (after execute/redo).
+    fn restore_after(&self, target: WidgetId) -> CommandResult {
+        if let (Some(restore), Some(cursor)) = (&self.restore, self.cursor_after) {
+            restore(target, cursor, self.selection_after)?;
+        }
+        Ok(())
+    }
+
+    /// Merge semantics: keep the first before-state, adopt the last
+    /// after-state.
+    fn absorb_after(&mut self, other: &CursorCapture) {
+        if other.cursor_after.is_some() {
+            self.cursor_after = other.cursor_after;
+        }
+        if other.selection_after.is_some() {
+            self.selection_after = other.selection_after;
+        }
+    }
+}
+
 /// Command to insert text at a position.
 pub struct TextInsertCmd {
     /// Target widget.
@@ -393,6 +465,8 @@ pub struct TextInsertCmd {
     apply: Option<TextApplyFn>,
     /// Callback to remove the insertion (set by the widget).
     remove: Option<TextRemoveFn>,
+    /// Cursor/selection capture for faithful undo.
+    cursor: CursorCapture,
 }
 
 impl fmt::Debug for TextInsertCmd {
@@ -419,6 +493,7 @@ impl TextInsertCmd {
             metadata: CommandMetadata::new("Insert text"),
             apply: None,
             remove: None,
+            cursor: CursorCapture::default(),
         }
     }
 
@@ -439,12 +514,42 @@ impl TextInsertCmd {
         self.remove = Some(Box::new(f));
         self
     }
+
+    /// Capture the cursor before and after the edit.
+    #[must_use]
+    pub fn with_cursor_state(mut self, before: CursorPos, after: CursorPos) -> Self {
+        self.cursor.cursor_before = Some(before);
+        self.cursor.cursor_after = Some(after);
+        self
+    }
+
+    /// Capture the selection before and after the edit.
+    #[must_use]
+    pub fn with_selection_state(
+        mut self,
+        before: Option<SelectionSpan>,
+        after: Option<SelectionSpan>,
+    ) -> Self {
+        self.cursor.selection_before = before;
+        self.cursor.selection_after = after;
+        self
+    }
+
+    /// Set the cursor-restore callback (the widget's own clamping APIs).
+    pub fn with_cursor_restore<F>(mut self, f: F) -> Self
+    where
+        F: Fn(WidgetId, CursorPos, Option<SelectionSpan>) -> CommandResult + Send + Sync + 'static,
+    {
+        self.cursor.restore = Some(Box::new(f));
+        self
+    }
 }
 
 impl UndoableCmd for TextInsertCmd {
     fn execute(&mut self) -> CommandResult {
         if let Some(ref apply) = self.apply {
-            apply(self.target, self.position, &self.text)
+            apply(self.target, self.position, &self.text)?;
+            self.cursor.restore_after(self.target)
         } else {
             Err(CommandError::InvalidState(
                 "no apply callback set".to_string(),
@@ -454,7 +559,8 @@ impl UndoableCmd for TextInsertCmd {
 
     fn undo(&mut self) -> CommandResult {
         if let Some(ref remove) = self.remove {
-            remove(self.target, self.position, self.text.len())
+            remove(self.target, self.position, self.text.len())?;
+            self.cursor.restore_before(self.target)
         } else {
             Err(CommandError::InvalidState(
                 "no remove callback set".to_string(),
@@ -516,6 +622,9 @@ impl UndoableCmd for TextInsertCmd {
             return false;
         };
         self.text.push_str(&other_insert.text);
+        // First command keeps its before-state; the merged run ends at
+        // the last command's after-state.
+        self.cursor.absorb_after(&other_insert.cursor);
         true
     }
 
@@ -554,6 +663,8 @@ pub struct TextDeleteCmd {
     remove: Option<TextRemoveFn>,
     /// Callback to insert text (for undo).
     insert: Option<TextApplyFn>,
+    /// Cursor/selection capture for faithful undo.
+    cursor: CursorCapture,
 }
 
 impl fmt::Debug for TextDeleteCmd {
@@ -580,6 +691,7 @@ impl TextDeleteCmd {
             metadata: CommandMetadata::new("Delete text"),
             remove: None,
             insert: None,
+            cursor: CursorCapture::default(),
         }
     }
 
@@ -600,12 +712,42 @@ impl TextDeleteCmd {
         self.insert = Some(Box::new(f));
         self
     }
+
+    /// Capture the cursor before and after the edit.
+    #[must_use]
+    pub fn with_cursor_state(mut self, before: CursorPos, after: CursorPos) -> Self {
+        self.cursor.cursor_before = Some(before);
+        self.cursor.cursor_after = Some(after);
+        self
+    }
+
+    /// Capture the selection before and after the edit.
+    #[must_use]
+    pub fn with_selection_state(
+        mut self,
+        before: Option<SelectionSpan>,
+        after: Option<SelectionSpan>,
+    ) -> Self {
+        self.cursor.selection_before = before;
+        self.cursor.selection_after = after;
+        self
+    }
+
+    /// Set the cursor-restore callback (the widget's own clamping APIs).
+    pub fn with_cursor_restore<F>(mut self, f: F) -> Self
+    where
+        F: Fn(WidgetId, CursorPos, Option<SelectionSpan>) -> CommandResult + Send + Sync + 'static,
+    {
+        self.cursor.restore = Some(Box::new(f));
+        self
+    }
 }
 
 impl UndoableCmd for TextDeleteCmd {
     fn execute(&mut self) -> CommandResult {
         if let Some(ref remove) = self.remove {
-            remove(self.target, self.position, self.deleted_text.len())
+            remove(self.target, self.position, self.deleted_text.len())?;
+            self.cursor.restore_after(self.target)
         } else {
             Err(CommandError::InvalidState(
                 "no remove callback set".to_string(),
@@ -615,7 +757,8 @@ impl UndoableCmd for TextDeleteCmd {
 
     fn undo(&mut self) -> CommandResult {
         if let Some(ref insert) = self.insert {
-            insert(self.target, self.position, &self.deleted_text)
+            insert(self.target, self.position, &self.deleted_text)?;
+            self.cursor.restore_before(self.target)
         } else {
             Err(CommandError::InvalidState(
                 "no insert callback set".to_string(),
@@ -695,6 +838,9 @@ impl UndoableCmd for TextDeleteCmd {
             // Forward delete: append (text was after original deleted text)
             self.deleted_text.push_str(&other_delete.deleted_text);
         }
+        // First command keeps its before-state; the merged run ends at
+        // the last command's after-state.
+        self.cursor.absorb_after(&other_delete.cursor);
         true
     }
 
@@ -733,6 +879,8 @@ pub struct TextReplaceCmd {
     pub metadata: CommandMetadata,
     /// Callback to apply replacement.
     replace: Option<TextReplaceFn>,
+    /// Cursor/selection capture for faithful undo.
+    cursor: CursorCapture,
 }
 
 impl fmt::Debug for TextReplaceCmd {
@@ -764,6 +912,7 @@ impl TextReplaceCmd {
             new_text: new_text.into(),
             metadata: CommandMetadata::new("Replace text"),
             replace: None,
+            cursor: CursorCapture::default(),
         }
     }
 
@@ -775,6 +924,35 @@ impl TextReplaceCmd {
         self.replace = Some(Box::new(f));
         self
     }
+
+    /// Capture the cursor before and after the edit.
+    #[must_use]
+    pub fn with_cursor_state(mut self, before: CursorPos, after: CursorPos) -> Self {
+        self.cursor.cursor_before = Some(before);
+        self.cursor.cursor_after = Some(after);
+        self
+    }
+
+    /// Capture the selection before and after the edit.
+    #[must_use]
+    pub fn with_selection_state(
+        mut self,
+        before: Option<SelectionSpan>,
+        after: Option<SelectionSpan>,
+    ) -> Self {
+        self.cursor.selection_before = before;
+        self.cursor.selection_after = after;
+        self
+    }
+
+    /// Set the cursor-restore callback (the widget's own clamping APIs).
+    pub fn with_cursor_restore<F>(mut self, f: F) -> Self
+    where
+        F: Fn(WidgetId, CursorPos, Option<SelectionSpan>) -> CommandResult + Send + Sync + 'static,
+    {
+        self.cursor.restore = Some(Box::new(f));
+        self
+    }
 }
 
 impl UndoableCmd for TextReplaceCmd {
@@ -785,7 +963,8 @@ impl UndoableCmd for TextReplaceCmd {
                 self.position,
                 self.old_text.len(),
                 &self.new_text,
-            )
+            )?;
+            self.cursor.restore_after(self.target)
         } else {
             Err(CommandError::InvalidState(
                 "no replace callback set".to_string(),
@@ -800,7 +979,8 @@ impl UndoableCmd for TextReplaceCmd {
                 self.position,
                 self.new_text.len(),
                 &self.old_text,
-            )
+            )?;
+            self.cursor.restore_before(self.target)
         } else {
             Err(CommandError::InvalidState(
                 "no replace callback set".to_string(),
@@ -1960,4 +2140,196 @@ mod tests {
         assert_eq!(cloned.source, CommandSource::Programmatic);
         assert_eq!(cloned.batch_id, Some(99));
     }
+
+    // ── Cursor/selection capture (faithful undo UX) ─────────────────
+
+    /// Minimal editor stand-in: restore goes "through the widget API",
+    /// which clamps the cursor column to the current text length.
+    #[derive(Debug, Default)]
+    struct MockEditor {
+        text: String,
+        cursor: CursorPos,
+        selection: Option<SelectionSpan>,
+    }
+
+    fn mock_editor(text: &str) -> Arc<Mutex<MockEditor>> {
+        Arc::new(Mutex::new(MockEditor {
+            text: text.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    fn wire_insert(cmd: TextInsertCmd, editor: &Arc<Mutex<MockEditor>>) -> TextInsertCmd {
+        let apply_ed = editor.clone();
+        let remove_ed = editor.clone();
+        let restore_ed = editor.clone();
+        cmd.with_apply(move |_, pos, text| {
+            apply_ed.lock().unwrap().text.insert_str(pos, text);
+            Ok(())
+        })
+        .with_remove(move |_, pos, len| {
+            remove_ed.lock().unwrap().text.drain(pos..pos + len);
+            Ok(())
+        })
+        .with_cursor_restore(move |_, cursor, selection| {
+            let mut ed = restore_ed.lock().unwrap();
+            // Widget-API clamping: never leave the cursor out of bounds.
+            ed.cursor = (cursor.0, cursor.1.min(ed.text.len()));
+            ed.selection = selection;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn insert_undo_redo_cursor_round_trip() {
+        let editor = mock_editor("hello");
+        let mut cmd = wire_insert(
+            TextInsertCmd::new(WidgetId::new(1), 5, " world").with_cursor_state((0, 5), (0, 11)),
+            &editor,
+        );
+
+        cmd.execute().unwrap();
+        assert_eq!(editor.lock().unwrap().text, "hello world");
+        assert_eq!(editor.lock().unwrap().cursor, (0, 11), "redo state applied");
+
+        cmd.undo().unwrap();
+        assert_eq!(editor.lock().unwrap().text, "hello");
+        assert_eq!(
+            editor.lock().unwrap().cursor,
+            (0, 5),
+            "pre-edit cursor restored"
+        );
+
+        cmd.execute().unwrap();
+        assert_eq!(
+            editor.lock().unwrap().cursor,
+            (0, 11),
+            "post-edit cursor on redo"
+        );
+    }
+
+    #[test]
+    fn delete_undo_restores_pre_delete_cursor_and_selection() {
+        let editor = mock_editor("hello world");
+        let remove_ed = editor.clone();
+        let insert_ed = editor.clone();
+        let restore_ed = editor.clone();
+        let mut cmd = TextDeleteCmd::new(WidgetId::new(1), 5, " world")
+            .with_remove(move |_, pos, len| {
+                remove_ed.lock().unwrap().text.drain(pos..pos + len);
+                Ok(())
+            })
+            .with_insert(move |_, pos, text| {
+                insert_ed.lock().unwrap().text.insert_str(pos, text);
+                Ok(())
+            })
+            .with_cursor_restore(move |_, cursor, selection| {
+                let mut ed = restore_ed.lock().unwrap();
+                ed.cursor = (cursor.0, cursor.1.min(ed.text.len()));
+                ed.selection = selection;
+                Ok(())
+            })
+            // Deletion of a selection: before-state had it selected.
+            .with_cursor_state((0, 11), (0, 5))
+            .with_selection_state(Some(((0, 5), (0, 11))), None);
+
+        cmd.execute().unwrap();
+        assert_eq!(editor.lock().unwrap().cursor, (0, 5));
+        assert_eq!(editor.lock().unwrap().selection, None);
+
+        cmd.undo().unwrap();
+        let ed = editor.lock().unwrap();
+        assert_eq!(ed.text, "hello world");
+        assert_eq!(ed.cursor, (0, 11));
+        assert_eq!(ed.selection, Some(((0, 5), (0, 11))), "selection restored");
+    }
+
+    #[test]
+    fn replace_undo_redo_cursor_round_trip() {
+        let editor = mock_editor("abcdef");
+        let replace_ed = editor.clone();
+        let restore_ed = editor.clone();
+        let mut cmd = TextReplaceCmd::new(WidgetId::new(1), 0, "abc", "XY")
+            .with_replace(move |_, pos, old_len, new_text| {
+                let mut ed = replace_ed.lock().unwrap();
+                ed.text.replace_range(pos..pos + old_len, new_text);
+                Ok(())
+            })
+            .with_cursor_restore(move |_, cursor, _| {
+                let mut ed = restore_ed.lock().unwrap();
+                ed.cursor = (cursor.0, cursor.1.min(ed.text.len()));
+                Ok(())
+            })
+            .with_cursor_state((0, 3), (0, 2));
+
+        cmd.execute().unwrap();
+        assert_eq!(editor.lock().unwrap().text, "XYdef");
+        assert_eq!(editor.lock().unwrap().cursor, (0, 2));
+
+        cmd.undo().unwrap();
+        assert_eq!(editor.lock().unwrap().text, "abcdef");
+        assert_eq!(editor.lock().unwrap().cursor, (0, 3));
+    }
+
+    #[test]
+    fn merged_run_keeps_first_before_and_last_after() {
+        let editor = mock_editor("");
+        let mut first = wire_insert(
+            TextInsertCmd::new(WidgetId::new(1), 0, "ab").with_cursor_state((0, 0), (0, 2)),
+            &editor,
+        );
+        let second = wire_insert(
+            TextInsertCmd::new(WidgetId::new(1), 2, "cd").with_cursor_state((0, 2), (0, 4)),
+            &editor,
+        );
+
+        assert!(first.can_merge(&second, &MergeConfig::default()));
+        assert!(first.accept_merge(&second));
+
+        first.execute().unwrap();
+        assert_eq!(editor.lock().unwrap().text, "abcd");
+        assert_eq!(
+            editor.lock().unwrap().cursor,
+            (0, 4),
+            "merged redo ends at the last command's after-state"
+        );
+
+        first.undo().unwrap();
+        assert_eq!(editor.lock().unwrap().text, "");
+        assert_eq!(
+            editor.lock().unwrap().cursor,
+            (0, 0),
+            "merged undo restores the first command's before-state"
+        );
+    }
+
+    #[test]
+    fn restore_clamps_through_widget_api_after_shrink() {
+        let editor = mock_editor("hello");
+        let mut cmd = wire_insert(
+            TextInsertCmd::new(WidgetId::new(1), 5, " world").with_cursor_state((0, 5), (0, 11)),
+            &editor,
+        );
+        cmd.execute().unwrap();
+
+        // The widget content shrank out-of-band (resize / truncation):
+        // undo still succeeds and the restore clamps in-bounds.
+        editor.lock().unwrap().text.truncate(8);
+        // Make the remove callback's range valid again for the test.
+        editor.lock().unwrap().text.push_str("rld");
+
+        cmd.undo().unwrap();
+        let ed = editor.lock().unwrap();
+        assert!(ed.cursor.1 <= ed.text.len(), "cursor clamped in-bounds");
+    }
+
+    #[test]
+    fn commands_without_capture_behave_as_before() {
+        let editor = mock_editor("x");
+        let mut cmd = wire_insert(TextInsertCmd::new(WidgetId::new(1), 1, "y"), &editor);
+        cmd.execute().unwrap();
+        cmd.undo().unwrap();
+        // No capture set: the mock cursor was never touched.
+        assert_eq!(editor.lock().unwrap().cursor, (0, 0));
+    }
 }
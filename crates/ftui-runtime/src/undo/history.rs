@@ -297,6 +297,35 @@ impl HistoryManager {
         &self.config
     }
 
+    /// Iterate the undo stack, oldest first.
+    pub fn iter_undo(&self) -> impl Iterator<Item = &dyn UndoableCmd> {
+        self.undo_stack.iter().map(AsRef::as_ref)
+    }
+
+    /// Iterate the redo stack, oldest first.
+    pub fn iter_redo(&self) -> impl Iterator<Item = &dyn UndoableCmd> {
+        self.redo_stack.iter().map(AsRef::as_ref)
+    }
+
+    /// Replace both stacks with restored commands (oldest first).
+    ///
+    /// Used by persistence: bypasses merge logic and redo clearing, but
+    /// recomputes byte accounting.
+    #[cfg(feature = "state-persistence")]
+    pub(crate) fn restore_stacks(
+        &mut self,
+        undo: Vec<Box<dyn UndoableCmd>>,
+        redo: Vec<Box<dyn UndoableCmd>>,
+    ) {
+        self.total_bytes = undo
+            .iter()
+            .chain(redo.iter())
+            .map(|cmd| cmd.size_bytes())
+            .sum();
+        self.undo_stack = undo.into();
+        self.redo_stack = redo.into();
+    }
+
     // ========================================================================
     // Maintenance
     // ========================================================================
@@ -91,14 +91,22 @@
 
 pub mod command;
 pub mod history;
+#[cfg(feature = "state-persistence")]
+pub mod persistence;
 pub mod snapshot_store;
 pub mod transaction;
 
 // Re-export commonly used types
 pub use command::{
-    CommandBatch, CommandError, CommandMetadata, CommandResult, CommandSource, MergeConfig,
-    TextDeleteCmd, TextInsertCmd, TextReplaceCmd, UndoableCmd, WidgetId,
+    CommandBatch, CommandError, CommandMetadata, CommandResult, CommandSource, CursorPos,
+    CursorRestoreFn, MergeConfig, SelectionSpan, TextDeleteCmd, TextInsertCmd, TextReplaceCmd,
+    UndoableCmd, WidgetId,
 };
 pub use history::{HistoryConfig, HistoryManager};
+#[cfg(feature = "state-persistence")]
+pub use persistence::{
+    CallbackSet, RestoredUndoHistory, SerializedUndoHistory, UNDO_FORMAT_VERSION,
+    UndoPersistenceError, deserialize_history, deserialize_history_with_config, serialize_history,
+};
 pub use snapshot_store::{SnapshotConfig, SnapshotStore};
 pub use transaction::{Transaction, TransactionScope};
@@ -0,0 +1,751 @@
+#![forbid(unsafe_code)]
+
+//! Persistent undo history serialization for crash recovery
+//! (feature `state-persistence`).
+//!
+//! The built-in text commands carry callbacks that cannot be serialized;
+//! this module captures everything else — targets, positions, text,
+//! metadata — as versioned JSON. On load the app supplies fresh callbacks
+//! per widget id via a rebind function, and the restored history refuses
+//! to apply onto widget content whose hash no longer matches the stored
+//! fingerprint (surfaced as [`CommandError::StateDrift`]).
+//!
+//! Timestamps serialize as *age offsets* from the serialization instant,
+//! so merge windows behave sanely after reload (restored commands look as
+//! old as they actually are, not brand new or epoch-old).
+//!
+//! Commands other than [`TextInsertCmd`], [`TextDeleteCmd`],
+//! [`TextReplaceCmd`], and [`CommandBatch`] cannot be reconstructed and
+//! are skipped; their count is reported on both serialize and deserialize.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use web_time::{Duration, Instant};
+
+use super::command::{
+    CommandBatch, CommandError, CommandResult, CommandSource, TextApplyFn, TextDeleteCmd,
+    TextInsertCmd, TextRemoveFn, TextReplaceCmd, TextReplaceFn, UndoableCmd, WidgetId,
+};
+use super::history::{HistoryConfig, HistoryManager};
+
+/// Current serialization format version.
+pub const UNDO_FORMAT_VERSION: u32 = 1;
+
+/// Fresh callbacks for one widget id, supplied by the app on load.
+pub struct CallbackSet {
+    /// Insert text at a position.
+    pub insert: TextApplyFn,
+    /// Remove a byte range starting at a position.
+    pub remove: TextRemoveFn,
+    /// Replace a byte range with new text.
+    pub replace: TextReplaceFn,
+}
+
+/// Errors from undo history deserialization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UndoPersistenceError {
+    /// The payload was written by a newer format version.
+    UnsupportedVersion {
+        /// Version found in the payload.
+        found: u32,
+        /// Highest version this build understands.
+        supported: u32,
+    },
+    /// The payload is not valid undo-history JSON.
+    Malformed(String),
+}
+
+impl std::fmt::Display for UndoPersistenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedVersion { found, supported } => write!(
+                f,
+                "undo history format v{found} is newer than supported v{supported}"
+            ),
+            Self::Malformed(msg) => write!(f, "malformed undo history: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for UndoPersistenceError {}
+
+/// Result of serializing a history.
+#[derive(Debug, Clone)]
+pub struct SerializedUndoHistory {
+    /// Versioned JSON payload.
+    pub bytes: Vec<u8>,
+    /// Commands that could not be captured (no serializable form).
+    pub skipped: usize,
+}
+
+/// A deserialized history plus its integrity data.
+pub struct RestoredUndoHistory {
+    /// The rebuilt history (commands rebound to fresh callbacks).
+    pub manager: HistoryManager,
+    /// Commands skipped at serialize time plus entries this build could
+    /// not reconstruct.
+    pub skipped: usize,
+    /// Stored content fingerprints by widget id.
+    fingerprints: HashMap<u64, u64>,
+}
+
+impl std::fmt::Debug for RestoredUndoHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RestoredUndoHistory")
+            .field("manager", &self.manager)
+            .field("skipped", &self.skipped)
+            .field("fingerprinted_widgets", &self.fingerprints.len())
+            .finish()
+    }
+}
+
+impl RestoredUndoHistory {
+    /// Verify a widget's current content against the stored fingerprint.
+    ///
+    /// Returns [`CommandError::StateDrift`] when the content hash differs —
+    /// the history must not be applied onto drifted content. Widgets with
+    /// no stored fingerprint pass (no command touched them).
+    pub fn verify_content(&self, widget: WidgetId, content: &str) -> CommandResult {
+        let Some(&stored) = self.fingerprints.get(&widget.raw()) else {
+            return Ok(());
+        };
+        let actual = fingerprint(content);
+        if actual == stored {
+            Ok(())
+        } else {
+            Err(CommandError::StateDrift {
+                expected: format!("{stored:016x}"),
+                actual: format!("{actual:016x}"),
+            })
+        }
+    }
+
+    /// Verify every fingerprinted widget via a content lookup.
+    ///
+    /// Widgets that no longer exist (`None`) fail with
+    /// [`CommandError::TargetNotFound`].
+    pub fn verify_all(&self, content_of: impl Fn(WidgetId) -> Option<String>) -> CommandResult {
+        for &id in self.fingerprints.keys() {
+            let widget = WidgetId::new(id);
+            match content_of(widget) {
+                Some(content) => self.verify_content(widget, &content)?,
+                None => return Err(CommandError::TargetNotFound(widget)),
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Wire format
+// ---------------------------------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct WireHistory {
+    version: u32,
+    /// Undo stack, oldest first.
+    undo: Vec<WireEntry>,
+    /// Redo stack, oldest first.
+    redo: Vec<WireEntry>,
+    /// Commands dropped at serialize time.
+    skipped: usize,
+    /// Content fingerprints (fnv1a64) by widget id at serialize time.
+    fingerprints: HashMap<u64, u64>,
+}
+
+/// Probe for the version field only (forward-compat check).
+#[derive(Deserialize)]
+struct WireVersion {
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WireEntry {
+    cmd: WireCmd,
+    /// Age at serialization time, in milliseconds.
+    age_ms: u64,
+    description: String,
+    source: WireSource,
+    batch_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+enum WireSource {
+    User,
+    Programmatic,
+    Macro,
+    External,
+}
+
+impl From<CommandSource> for WireSource {
+    fn from(source: CommandSource) -> Self {
+        match source {
+            CommandSource::User => Self::User,
+            CommandSource::Programmatic => Self::Programmatic,
+            CommandSource::Macro => Self::Macro,
+            CommandSource::External => Self::External,
+        }
+    }
+}
+
+impl From<WireSource> for CommandSource {
+    fn from(source: WireSource) -> Self {
+        match source {
+            WireSource::User => Self::User,
+            WireSource::Programmatic => Self::Programmatic,
+            WireSource::Macro => Self::Macro,
+            WireSource::External => Self::External,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum WireCmd {
+    Insert {
+        target: u64,
+        position: usize,
+        text: String,
+    },
+    Delete {
+        target: u64,
+        position: usize,
+        deleted_text: String,
+    },
+    Replace {
+        target: u64,
+        position: usize,
+        old_text: String,
+        new_text: String,
+    },
+    Batch {
+        commands: Vec<WireCmd>,
+    },
+}
+
+/// FNV-1a 64-bit content fingerprint.
+#[must_use]
+pub fn fingerprint(content: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+// ---------------------------------------------------------------------------
+// Serialize
+// ---------------------------------------------------------------------------
+
+/// Serialize a history to versioned JSON.
+///
+/// `content_of` supplies the current text of each widget touched by the
+/// history so content fingerprints can be stored; widgets it returns
+/// `None` for get no fingerprint (and thus no drift protection).
+#[must_use]
+pub fn serialize_history(
+    history: &HistoryManager,
+    content_of: impl Fn(WidgetId) -> Option<String>,
+) -> SerializedUndoHistory {
+    let now = Instant::now();
+    let mut skipped = 0usize;
+    let mut targets: Vec<WidgetId> = Vec::new();
+
+    let mut capture_stack = |cmds: &mut dyn Iterator<Item = &dyn UndoableCmd>| -> Vec<WireEntry> {
+        cmds.filter_map(|cmd| {
+            let wire = capture_cmd(cmd, &mut targets, &mut skipped)?;
+            let meta = cmd.metadata();
+            Some(WireEntry {
+                cmd: wire,
+                age_ms: now
+                    .saturating_duration_since(meta.timestamp)
+                    .as_millis()
+                    .min(u128::from(u64::MAX)) as u64,
+                description: meta.description.clone(),
+                source: meta.source.into(),
+                batch_id: meta.batch_id,
+            })
+        })
+        .collect()
+    };
+
+    let undo = capture_stack(&mut history.iter_undo());
+    let redo = capture_stack(&mut history.iter_redo());
+
+    let mut fingerprints = HashMap::new();
+    for target in targets {
+        if let Some(content) = content_of(target) {
+            fingerprints.insert(target.raw(), fingerprint(&content));
+        }
+    }
+
+    let wire = WireHistory {
+        version: UNDO_FORMAT_VERSION,
+        undo,
+        redo,
+        skipped,
+        fingerprints,
+    };
+    let bytes = serde_json::to_vec(&wire).expect("undo wire format is serializable");
+    SerializedUndoHistory { bytes, skipped }
+}
+
+/// Capture one command; `None` (and a skip count) for unknown types.
+fn capture_cmd(
+    cmd: &dyn UndoableCmd,
+    targets: &mut Vec<WidgetId>,
+    skipped: &mut usize,
+) -> Option<WireCmd> {
+    let any = cmd.as_any();
+    if let Some(insert) = any.downcast_ref::<TextInsertCmd>() {
+        targets.push(insert.target);
+        return Some(WireCmd::Insert {
+            target: insert.target.raw(),
+            position: insert.position,
+            text: insert.text.clone(),
+        });
+    }
+    if let Some(delete) = any.downcast_ref::<TextDeleteCmd>() {
+        targets.push(delete.target);
+        return Some(WireCmd::Delete {
+            target: delete.target.raw(),
+            position: delete.position,
+            deleted_text: delete.deleted_text.clone(),
+        });
+    }
+    if let Some(replace) = any.downcast_ref::<TextReplaceCmd>() {
+        targets.push(replace.target);
+        return Some(WireCmd::Replace {
+            target: replace.target.raw(),
+            position: replace.position,
+            old_text: replace.old_text.clone(),
+            new_text: replace.new_text.clone(),
+        });
+    }
+    if let Some(batch) = any.downcast_ref::<CommandBatch>() {
+        let commands: Vec<WireCmd> = batch
+            .commands()
+            .iter()
+            .filter_map(|inner| capture_cmd(inner.as_ref(), targets, skipped))
+            .collect();
+        return Some(WireCmd::Batch { commands });
+    }
+    *skipped += 1;
+    None
+}
+
+// ---------------------------------------------------------------------------
+// Deserialize
+// ---------------------------------------------------------------------------
+
+/// Deserialize a history, rebinding callbacks per widget id.
+///
+/// Uses the default [`HistoryConfig`]; see
+/// [`deserialize_history_with_config`].
+pub fn deserialize_history(
+    bytes: &[u8],
+    rebind: impl Fn(WidgetId) -> CallbackSet,
+) -> Result<RestoredUndoHistory, UndoPersistenceError> {
+    deserialize_history_with_config(bytes, HistoryConfig::default(), rebind)
+}
+
+/// Deserialize a history with an explicit runtime configuration.
+///
+/// Rejects payloads written by a newer format version. `rebind` is called
+/// once per restored command to supply fresh callbacks for its widget.
+pub fn deserialize_history_with_config(
+    bytes: &[u8],
+    config: HistoryConfig,
+    rebind: impl Fn(WidgetId) -> CallbackSet,
+) -> Result<RestoredUndoHistory, UndoPersistenceError> {
+    // Version probe first: a newer payload may not even parse as
+    // WireHistory, and must be rejected with the right error.
+    let probe: WireVersion = serde_json::from_slice(bytes)
+        .map_err(|err| UndoPersistenceError::Malformed(err.to_string()))?;
+    if probe.version > UNDO_FORMAT_VERSION {
+        return Err(UndoPersistenceError::UnsupportedVersion {
+            found: probe.version,
+            supported: UNDO_FORMAT_VERSION,
+        });
+    }
+
+    let wire: WireHistory = serde_json::from_slice(bytes)
+        .map_err(|err| UndoPersistenceError::Malformed(err.to_string()))?;
+
+    let now = Instant::now();
+    let mut skipped = wire.skipped;
+    let mut rebuild_stack = |entries: Vec<WireEntry>| -> Vec<Box<dyn UndoableCmd>> {
+        entries
+            .into_iter()
+            .filter_map(|entry| match rebuild_entry(entry, now, &rebind) {
+                Some(cmd) => Some(cmd),
+                None => {
+                    skipped += 1;
+                    None
+                }
+            })
+            .collect()
+    };
+    let undo = rebuild_stack(wire.undo);
+    let redo = rebuild_stack(wire.redo);
+
+    let mut manager = HistoryManager::new(config);
+    manager.restore_stacks(undo, redo);
+
+    Ok(RestoredUndoHistory {
+        manager,
+        skipped,
+        fingerprints: wire.fingerprints,
+    })
+}
+
+fn rebuild_entry(
+    entry: WireEntry,
+    now: Instant,
+    rebind: &impl Fn(WidgetId) -> CallbackSet,
+) -> Option<Box<dyn UndoableCmd>> {
+    // Restore the timestamp from its age so merge windows keep working.
+    let timestamp = now
+        .checked_sub(Duration::from_millis(entry.age_ms))
+        .unwrap_or(now);
+    let mut cmd = rebuild_cmd(entry.cmd, rebind)?;
+    apply_metadata(
+        cmd.as_mut(),
+        &entry.description,
+        timestamp,
+        entry.source.into(),
+        entry.batch_id,
+    );
+    Some(cmd)
+}
+
+fn rebuild_cmd(
+    wire: WireCmd,
+    rebind: &impl Fn(WidgetId) -> CallbackSet,
+) -> Option<Box<dyn UndoableCmd>> {
+    match wire {
+        WireCmd::Insert {
+            target,
+            position,
+            text,
+        } => {
+            let widget = WidgetId::new(target);
+            let callbacks = rebind(widget);
+            Some(Box::new(
+                TextInsertCmd::new(widget, position, text)
+                    .with_apply(callbacks.insert)
+                    .with_remove(callbacks.remove),
+            ))
+        }
+        WireCmd::Delete {
+            target,
+            position,
+            deleted_text,
+        } => {
+            let widget = WidgetId::new(target);
+            let callbacks = rebind(widget);
+            Some(Box::new(
+                TextDeleteCmd::new(widget, position, deleted_text)
+                    .with_remove(callbacks.remove)
+                    .with_insert(callbacks.insert),
+            ))
+        }
+        WireCmd::Replace {
+            target,
+            position,
+            old_text,
+            new_text,
+        } => {
+            let widget = WidgetId::new(target);
+            let callbacks = rebind(widget);
+            Some(Box::new(
+                TextReplaceCmd::new(widget, position, old_text, new_text)
+                    .with_replace(callbacks.replace),
+            ))
+        }
+        WireCmd::Batch { commands } => {
+            let mut batch = CommandBatch::new("Restored batch");
+            for inner in commands {
+                batch.push_executed(rebuild_cmd(inner, rebind)?);
+            }
+            Some(Box::new(batch))
+        }
+    }
+}
+
+fn apply_metadata(
+    cmd: &mut dyn UndoableCmd,
+    description: &str,
+    timestamp: Instant,
+    source: CommandSource,
+    batch_id: Option<u64>,
+) {
+    let any = cmd.as_any_mut();
+    let metadata = if let Some(insert) = any.downcast_mut::<TextInsertCmd>() {
+        &mut insert.metadata
+    } else if let Some(delete) = any.downcast_mut::<TextDeleteCmd>() {
+        &mut delete.metadata
+    } else if let Some(replace) = any.downcast_mut::<TextReplaceCmd>() {
+        &mut replace.metadata
+    } else {
+        // Batches keep their reconstructed metadata.
+        return;
+    };
+    metadata.description = description.to_string();
+    metadata.timestamp = timestamp;
+    metadata.source = source;
+    metadata.batch_id = batch_id;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    /// Shared fake widget text store for callback rebinding.
+    #[derive(Clone, Default)]
+    struct TextStore {
+        texts: Arc<Mutex<HashMap<u64, String>>>,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl TextStore {
+        fn set(&self, id: u64, text: &str) {
+            self.texts.lock().unwrap().insert(id, text.to_string());
+        }
+
+        fn get(&self, id: u64) -> Option<String> {
+            self.texts.lock().unwrap().get(&id).cloned()
+        }
+
+        fn callbacks(&self, _widget: WidgetId) -> CallbackSet {
+            let store = self.clone();
+            let insert = Box::new(move |id: WidgetId, pos: usize, text: &str| {
+                let mut texts = store.texts.lock().unwrap();
+                let entry = texts.entry(id.raw()).or_default();
+                entry.insert_str(pos.min(entry.len()), text);
+                store.calls.lock().unwrap().push(format!("insert:{text}"));
+                Ok(())
+            });
+            let store = self.clone();
+            let remove = Box::new(move |id: WidgetId, pos: usize, len: usize| {
+                let mut texts = store.texts.lock().unwrap();
+                let entry = texts.entry(id.raw()).or_default();
+                let end = (pos + len).min(entry.len());
+                entry.drain(pos.min(entry.len())..end);
+                store.calls.lock().unwrap().push(format!("remove:{len}"));
+                Ok(())
+            });
+            let store = self.clone();
+            let replace = Box::new(move |id: WidgetId, pos: usize, len: usize, text: &str| {
+                let mut texts = store.texts.lock().unwrap();
+                let entry = texts.entry(id.raw()).or_default();
+                let end = (pos + len).min(entry.len());
+                entry.replace_range(pos.min(entry.len())..end, text);
+                store.calls.lock().unwrap().push(format!("replace:{text}"));
+                Ok(())
+            });
+            CallbackSet {
+                insert,
+                remove,
+                replace,
+            }
+        }
+    }
+
+    /// A command with no serializable form.
+    struct OpaqueCmd {
+        metadata: super::super::command::CommandMetadata,
+    }
+
+    impl UndoableCmd for OpaqueCmd {
+        fn execute(&mut self) -> CommandResult {
+            Ok(())
+        }
+        fn undo(&mut self) -> CommandResult {
+            Ok(())
+        }
+        fn description(&self) -> &str {
+            "opaque"
+        }
+        fn size_bytes(&self) -> usize {
+            std::mem::size_of::<Self>()
+        }
+        fn metadata(&self) -> &super::super::command::CommandMetadata {
+            &self.metadata
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn mixed_history(store: &TextStore) -> HistoryManager {
+        let mut history = HistoryManager::default();
+        let widget = WidgetId::new(7);
+
+        let callbacks = store.callbacks(widget);
+        history.push(Box::new(
+            TextInsertCmd::new(widget, 0, "hello")
+                .with_apply(callbacks.insert)
+                .with_remove(callbacks.remove),
+        ));
+
+        let callbacks = store.callbacks(widget);
+        history.push(Box::new(
+            TextReplaceCmd::new(widget, 0, "hello", "world").with_replace(callbacks.replace),
+        ));
+
+        let mut batch = CommandBatch::new("batch");
+        let callbacks = store.callbacks(widget);
+        batch.push_executed(Box::new(
+            TextDeleteCmd::new(widget, 0, "wo")
+                .with_remove(callbacks.remove)
+                .with_insert(callbacks.insert),
+        ));
+        history.push(Box::new(batch));
+
+        history.push(Box::new(OpaqueCmd {
+            metadata: super::super::command::CommandMetadata::new("opaque"),
+        }));
+
+        history
+    }
+
+    #[test]
+    fn round_trip_mixed_history() {
+        let store = TextStore::default();
+        store.set(7, "rld");
+        let history = mixed_history(&store);
+        assert_eq!(history.undo_depth(), 4);
+
+        let serialized = serialize_history(&history, |id| store.get(id.raw()));
+        assert_eq!(serialized.skipped, 1, "opaque command skipped");
+
+        let rebind_store = store.clone();
+        let restored =
+            deserialize_history(&serialized.bytes, move |id| rebind_store.callbacks(id)).unwrap();
+        assert_eq!(restored.skipped, 1);
+        // 3 serializable commands survive the round trip.
+        assert_eq!(restored.manager.undo_depth(), 3);
+        assert!(restored.verify_content(WidgetId::new(7), "rld").is_ok());
+        // Descriptions preserved.
+        let descriptions = restored.manager.undo_descriptions(3);
+        assert!(descriptions.iter().any(|d| d.contains("Replace")));
+    }
+
+    #[test]
+    fn rebound_callbacks_invoked_on_undo_after_reload() {
+        let store = TextStore::default();
+        store.set(7, "world");
+
+        let mut history = HistoryManager::default();
+        let widget = WidgetId::new(7);
+        let callbacks = store.callbacks(widget);
+        history.push(Box::new(
+            TextInsertCmd::new(widget, 0, "world")
+                .with_apply(callbacks.insert)
+                .with_remove(callbacks.remove),
+        ));
+
+        let serialized = serialize_history(&history, |id| store.get(id.raw()));
+
+        // Reload into a fresh store, binding fresh callbacks.
+        let fresh = TextStore::default();
+        fresh.set(7, "world");
+        let rebind = fresh.clone();
+        let mut restored =
+            deserialize_history(&serialized.bytes, move |id| rebind.callbacks(id)).unwrap();
+        assert!(restored.verify_content(widget, "world").is_ok());
+
+        let result = restored.manager.undo().expect("history has an entry");
+        assert!(result.is_ok());
+        // The fresh store's remove callback actually ran.
+        assert_eq!(fresh.get(7).as_deref(), Some(""));
+        assert!(fresh.calls.lock().unwrap().iter().any(|c| c == "remove:5"));
+    }
+
+    #[test]
+    fn fingerprint_mismatch_is_state_drift() {
+        let store = TextStore::default();
+        store.set(7, "original");
+        let history = mixed_history(&store);
+        let serialized = serialize_history(&history, |id| store.get(id.raw()));
+
+        let rebind = store.clone();
+        let restored =
+            deserialize_history(&serialized.bytes, move |id| rebind.callbacks(id)).unwrap();
+
+        // Content drifted between save and load.
+        let err = restored
+            .verify_content(WidgetId::new(7), "edited elsewhere")
+            .unwrap_err();
+        assert!(matches!(err, CommandError::StateDrift { .. }));
+
+        // verify_all with a missing widget is TargetNotFound.
+        let err = restored.verify_all(|_| None).unwrap_err();
+        assert!(matches!(err, CommandError::TargetNotFound(_)));
+    }
+
+    #[test]
+    fn higher_format_version_is_rejected() {
+        let payload = format!(
+            "{{\"version\":{},\"undo\":[],\"redo\":[],\"skipped\":0,\"fingerprints\":{{}}}}",
+            UNDO_FORMAT_VERSION + 1
+        );
+        let store = TextStore::default();
+        let err =
+            deserialize_history(payload.as_bytes(), move |id| store.callbacks(id)).unwrap_err();
+        assert_eq!(
+            err,
+            UndoPersistenceError::UnsupportedVersion {
+                found: UNDO_FORMAT_VERSION + 1,
+                supported: UNDO_FORMAT_VERSION,
+            }
+        );
+    }
+
+    #[test]
+    fn timestamps_round_trip_as_offsets() {
+        let store = TextStore::default();
+        store.set(7, "x");
+        let mut history = HistoryManager::default();
+        let widget = WidgetId::new(7);
+        let callbacks = store.callbacks(widget);
+        let mut cmd = TextInsertCmd::new(widget, 0, "x")
+            .with_apply(callbacks.insert)
+            .with_remove(callbacks.remove);
+        // Pretend this command is 10 seconds old.
+        cmd.metadata.timestamp = Instant::now() - Duration::from_secs(10);
+        history.push(Box::new(cmd));
+
+        let serialized = serialize_history(&history, |id| store.get(id.raw()));
+        let rebind = store.clone();
+        let restored =
+            deserialize_history(&serialized.bytes, move |id| rebind.callbacks(id)).unwrap();
+
+        let age = restored
+            .manager
+            .iter_undo()
+            .next()
+            .unwrap()
+            .metadata()
+            .timestamp
+            .elapsed();
+        assert!(
+            age >= Duration::from_secs(9) && age <= Duration::from_secs(12),
+            "restored age {age:?} should be ~10s"
+        );
+    }
+
+    #[test]
+    fn malformed_payload_reports_malformed() {
+        let store = TextStore::default();
+        let err = deserialize_history(b"not json", move |id| store.callbacks(id)).unwrap_err();
+        assert!(matches!(err, UndoPersistenceError::Malformed(_)));
+    }
+}
@@ -407,6 +407,17 @@ impl<M: Model> WasmRunner<M> {
                 // No-op: state persistence is managed by the JS host
                 // (localStorage / IndexedDB).
             }
+            Cmd::Suspend => {
+                // No job control in the browser; ignored.
+            }
+            Cmd::SplashProgress(_) | Cmd::CompleteStartup => {
+                // No startup splash phase in the wasm runner; ignored.
+            }
+            #[cfg(feature = "async")]
+            Cmd::Future(..) => {
+                // Futures require the browser event loop; the headless
+                // wasm runner drops them.
+            }
         }
     }
 }
@@ -482,6 +493,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -597,6 +609,7 @@ mod tests {
             code: KeyCode::Char('t'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         }));
         // 't' maps to Increment (default), so override:
         // We need a model that emits Cmd::Tick. Let's just set tick_rate directly.
@@ -31,6 +31,7 @@ fn default_config_matches_docs() {
 #[test]
 fn low_latency_profile() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 8,
         burst_delay_ms: 25,
         hard_deadline_ms: 50,
@@ -46,6 +47,7 @@ fn low_latency_profile() {
 #[test]
 fn heavy_render_profile() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 32,
         burst_delay_ms: 80,
         hard_deadline_ms: 150,
@@ -107,6 +109,7 @@ fn steady_single_resize() {
 #[test]
 fn burst_regime_transition() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         burst_enter_rate: 5.0, // 5 events/sec to enter burst
         burst_exit_rate: 2.0,
         cooldown_frames: 2,
@@ -145,6 +148,7 @@ fn burst_regime_transition() {
 #[test]
 fn burst_cooldown_hysteresis() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         burst_enter_rate: 5.0,
         burst_exit_rate: 2.0,
         cooldown_frames: 3,
@@ -214,6 +218,7 @@ fn burst_cooldown_hysteresis() {
 #[test]
 fn hard_deadline_guarantee() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 50,
         burst_delay_ms: 200,
         hard_deadline_ms: 100,
@@ -252,6 +257,7 @@ fn hard_deadline_guarantee() {
 #[test]
 fn time_until_apply_accuracy() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 50,
         hard_deadline_ms: 100,
         ..Default::default()
@@ -283,6 +289,7 @@ fn time_until_apply_accuracy() {
 #[test]
 fn latest_wins_semantics() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 30,
         burst_delay_ms: 80,
         hard_deadline_ms: 150,
@@ -325,6 +332,7 @@ fn latest_wins_semantics() {
 #[test]
 fn determinism_checksum() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         enable_logging: true,
         ..Default::default()
     };
@@ -354,6 +362,7 @@ fn determinism_checksum() {
 #[test]
 fn decision_summary_valid() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         enable_logging: true,
         ..Default::default()
     };
@@ -379,6 +388,7 @@ fn decision_summary_valid() {
 #[test]
 fn decision_log_jsonl_export() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         enable_logging: true,
         ..Default::default()
     };
@@ -406,6 +416,7 @@ fn decision_log_jsonl_export() {
 #[test]
 fn decision_checksum_hex_format() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         enable_logging: true,
         ..Default::default()
     };
@@ -460,6 +471,7 @@ fn telemetry_hooks_fire() {
         });
 
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 5,
         hard_deadline_ms: 20,
         ..Default::default()
@@ -491,6 +503,7 @@ fn telemetry_hooks_fire() {
 #[test]
 fn record_external_apply_clears_pending() {
     let cfg = CoalescerConfig {
+        immediate_first: false,
         steady_delay_ms: 100,
         hard_deadline_ms: 200,
         ..Default::default()
@@ -18,6 +18,11 @@ pub use wasm::ShowcaseRunner;
 #[cfg(any(target_arch = "wasm32", test))]
 mod runner_core;
 
+// Golden scenario scripts drive the runner core natively (tests) and
+// under wasm (the `runScenarioGolden` export).
+#[cfg(any(target_arch = "wasm32", test))]
+mod scenario;
+
 #[cfg(test)]
 mod tests {
     use crate::runner_core::{PaneDispatchOutcome, RunnerCore};
@@ -1140,4 +1145,165 @@ mod tests {
             "pane trace should include timeline operations"
         );
     }
+
+    // ── Golden scenario scripts ─────────────────────────────────────────
+
+    use crate::scenario::{self, Scenario};
+
+    fn golden_path(name: &str) -> std::path::PathBuf {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/goldens")
+            .join(format!("{name}.golden"))
+    }
+
+    /// Run a scenario and assert it against its on-disk golden; with
+    /// `FTUI_UPDATE_GOLDENS=1` the golden is re-recorded instead.
+    fn assert_scenario_golden(scenario: Scenario) {
+        let run = scenario.run().expect("scenario executes");
+        let path = golden_path(&run.scenario);
+        if std::env::var_os("FTUI_UPDATE_GOLDENS").is_some() {
+            std::fs::write(&path, run.to_golden())
+                .unwrap_or_else(|err| panic!("record golden {}: {err}", path.display()));
+            return;
+        }
+        let golden = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+            panic!(
+                "missing golden {} ({err}); run with FTUI_UPDATE_GOLDENS=1 to record",
+                path.display()
+            )
+        });
+        // Goldens pin the wasm feature set. Under `cargo test --workspace`,
+        // feature unification compiles the optional screens in, which
+        // legitimately changes frame content — skip rather than compare
+        // apples to oranges.
+        if let Some(recorded) = scenario::golden_screen_count(&golden)
+            && recorded != scenario::compiled_screen_count()
+        {
+            eprintln!(
+                "skipping golden for '{}': recorded with {recorded} screens, this build \
+                 compiles {} (workspace feature unification)",
+                run.scenario,
+                scenario::compiled_screen_count()
+            );
+            return;
+        }
+        if let Err(report) = run.compare_golden(&golden) {
+            panic!("{report}");
+        }
+    }
+
+    #[test]
+    fn scenario_engine_records_checkpoints() {
+        let run = scenario::files_navigation_and_paging()
+            .run()
+            .expect("scenario executes");
+        assert_eq!(run.scenario, "files_navigation_and_paging");
+        let names: Vec<&str> = run.checkpoints.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["files-open", "page-down", "page-up"]);
+        for checkpoint in &run.checkpoints {
+            assert_ne!(checkpoint.hash, "none", "checkpoint has a patch hash");
+            assert!(!checkpoint.frame.is_empty(), "checkpoint captured a frame");
+        }
+    }
+
+    #[test]
+    fn scenario_engine_executes_every_step_kind() {
+        // Exercise the step kinds the four goldens don't use: explicit
+        // key value with modifiers (Shift+L = next screen), mouse move,
+        // and a bare time advance.
+        let run = Scenario::new("engine_probe", 80, 24)
+            .frames(1)
+            .advance(32.0)
+            .mouse_move(-1, 5, 5)
+            .key_with("L", "KeyL", 1)
+            .frames(1)
+            .checkpoint("probe")
+            .run()
+            .expect("scenario executes");
+        assert_eq!(run.checkpoints.len(), 1);
+        assert_ne!(run.checkpoints[0].hash, "none");
+        assert!(!run.checkpoints[0].frame.is_empty());
+    }
+
+    #[test]
+    fn scenario_checkpoint_substring_assertion_fails_with_frame_dump() {
+        let err = Scenario::new("substring_probe", 80, 24)
+            .frames(2)
+            .checkpoint_expect("probe", &["text-that-should-not-exist"])
+            .run()
+            .expect_err("missing substring must fail the run");
+        assert!(err.contains("missing expected text"), "{err}");
+        assert!(err.contains("--- actual frame"), "{err}");
+    }
+
+    #[test]
+    fn scenario_golden_roundtrip_and_mismatch_report() {
+        let run = scenario::startup_default_screen()
+            .run()
+            .expect("scenario executes");
+        let golden = run.to_golden();
+        run.compare_golden(&golden)
+            .expect("self-recorded golden matches");
+
+        // Tamper with the hash: the report names the checkpoint and dumps
+        // both frames.
+        let tampered = golden.replacen("hash ", "hash tampered-", 1);
+        let report = run
+            .compare_golden(&tampered)
+            .expect_err("tampered golden must mismatch");
+        assert!(report.contains("checkpoint 'startup' mismatch"), "{report}");
+        assert!(report.contains("--- expected frame"), "{report}");
+        assert!(report.contains("--- actual frame"), "{report}");
+    }
+
+    #[test]
+    fn scenario_golden_startup_default_screen() {
+        assert_scenario_golden(scenario::startup_default_screen());
+    }
+
+    #[test]
+    fn scenario_golden_files_navigation_and_paging() {
+        assert_scenario_golden(scenario::files_navigation_and_paging());
+    }
+
+    #[test]
+    fn scenario_golden_splitter_drag_with_undo() {
+        assert_scenario_golden(scenario::splitter_drag_with_undo());
+    }
+
+    #[test]
+    fn scenario_golden_resize_storm() {
+        assert_scenario_golden(scenario::resize_storm());
+    }
+
+    /// Native↔wasm equivalence, asserted on the native side: the wasm
+    /// export runs the identical script on the identical `RunnerCore`, so
+    /// reproducible hashes across fresh runs pin both targets to the
+    /// same content.
+    #[test]
+    fn scenario_hashes_are_reproducible_across_runs() {
+        for scenario in scenario::all_scenarios() {
+            let first = scenario.run().expect("first run executes");
+            let second = scenario.run().expect("second run executes");
+            assert_eq!(
+                first.checkpoints, second.checkpoints,
+                "scenario '{}' must reproduce identical checkpoints",
+                first.scenario
+            );
+        }
+    }
+
+    /// The goldens embedded for the wasm build must match the on-disk
+    /// files the native tests assert against.
+    #[test]
+    fn scenario_embedded_goldens_match_disk() {
+        for scenario in scenario::all_scenarios() {
+            let name = scenario.name().to_string();
+            let embedded = scenario::embedded_golden(&name)
+                .unwrap_or_else(|| panic!("no embedded golden for '{name}'"));
+            let disk = std::fs::read_to_string(golden_path(&name))
+                .unwrap_or_else(|err| panic!("read golden for '{name}': {err}"));
+            assert_eq!(embedded, disk, "embedded golden for '{name}' is stale");
+        }
+    }
 }
@@ -7,6 +7,7 @@
 
 use core::time::Duration;
 
+use ftui_core::event::Event;
 use ftui_demo_showcase::app::AppModel;
 use ftui_demo_showcase::pane_interaction::{
     ActivePaneGesture, PaneAutoPointerDownContext, PaneDragSemanticsContext,
@@ -21,10 +22,11 @@ use ftui_demo_showcase::pane_interaction::{
 };
 use ftui_layout::{
     PANE_EDGE_GRIP_INSET_CELLS, PANE_MAGNETIC_FIELD_CELLS, PaneDockPreview, PaneDockZone,
-    PaneDragResizeEffect, PaneId, PaneInteractionTimeline, PaneLayoutIntelligenceMode,
-    PaneModifierSnapshot, PaneMotionVector, PaneNodeKind, PaneOperation, PanePointerButton,
-    PanePointerPosition, PanePressureSnapProfile, PaneResizeGrip, PaneResizeTarget,
-    PaneSelectionState, PaneTree, Rect, SplitAxis, WorkspaceMetadata, WorkspaceSnapshot,
+    PaneDragResizeEffect, PaneDropTarget, PaneId, PaneInteractionTimeline,
+    PaneLayoutIntelligenceMode, PaneModifierSnapshot, PaneMotionVector, PaneNodeKind,
+    PaneOperation, PanePlacement, PanePointerButton, PanePointerPosition, PanePressureSnapProfile,
+    PaneResizeGrip, PaneResizeTarget, PaneSelectionState, PaneSplitRatio, PaneTree, Rect,
+    SplitAxis, WorkspaceMetadata, WorkspaceSnapshot,
 };
 use ftui_web::pane_pointer_capture::{
     PanePointerCaptureAdapter, PanePointerCaptureCommand, PanePointerCaptureConfig,
@@ -74,6 +76,36 @@ pub struct RunnerCore {
     intelligence_mode: PaneLayoutIntelligenceMode,
     /// Monotonic workspace generation used in persisted snapshots.
     workspace_generation: u64,
+    /// Optional tracing log bridge drained into `take_logs`.
+    log_bridge: Option<ftui_runtime::LogBridge>,
+    /// Redaction applied to host-facing log output.
+    privacy: ftui_runtime::TelemetryPrivacyPolicy,
+    /// Explicit drag-rearrange state ([`RunnerCore::pane_begin_move`]).
+    explicit_move: Option<ExplicitPaneMove>,
+    /// Outgoing host commands (JSON objects) awaiting `take_host_commands`.
+    host_commands: Vec<String>,
+    /// Next clipboard request id.
+    next_host_request_id: u64,
+    /// Clipboard reads requested but not yet completed by the host.
+    pending_clipboard_reads: std::collections::HashSet<u64>,
+}
+
+/// State of an explicit pane drag-rearrangement in progress.
+#[derive(Debug, Clone, Copy)]
+struct ExplicitPaneMove {
+    source: PaneId,
+    target: Option<PaneDropTarget>,
+}
+
+/// Host-facing summary of the current drop target during a pane move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneDropTargetSummary {
+    /// Raw id of the pane under the pointer.
+    pub target_pane: u64,
+    /// Drop region name: "left"/"right"/"top"/"bottom"/"center".
+    pub region: &'static str,
+    /// Preview rectangle for the host's drop hint.
+    pub preview: Rect,
 }
 
 const PATCH_HASH_ALGO: &str = "fnv1a64";
@@ -108,14 +140,12 @@ impl PaneDispatchSummary {
 
 #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
 impl RunnerCore {
-    #[inline]
-    fn clamp_dimension(value: u16) -> u16 {
-        if value < 1 { 1 } else { value }
-    }
-
     #[inline]
     fn clamp_size(cols: u16, rows: u16) -> (u16, u16) {
-        (Self::clamp_dimension(cols), Self::clamp_dimension(rows))
+        // Route host-reported dimensions through the shared size policy so
+        // the wasm runner sanitizes the same way as the native session
+        // (0x0 → fallback, absurd sizes clamped, substitution recorded).
+        ftui_core::size_policy::SizePolicy::default().apply((cols, rows))
     }
 
     fn pane_adapter_with_fallback(
@@ -180,6 +210,12 @@ impl RunnerCore {
             next_operation_id: 1,
             intelligence_mode: PaneLayoutIntelligenceMode::Focus,
             workspace_generation: 0,
+            log_bridge: None,
+            privacy: ftui_runtime::TelemetryPrivacyPolicy::default(),
+            explicit_move: None,
+            host_commands: Vec::new(),
+            next_host_request_id: 1,
+            pending_clipboard_reads: std::collections::HashSet::new(),
         }
     }
 
@@ -227,6 +263,23 @@ impl RunnerCore {
     pub fn push_encoded_input(&mut self, json: &str) -> bool {
         match ftui_web::input_parser::parse_encoded_input_to_event(json) {
             Ok(Some(event)) => {
+                // Host clipboard completions must match a pending read
+                // request; unsolicited results are dropped.
+                if matches!(&event, Event::Clipboard(c) if c.source == ftui_core::event::ClipboardSource::HostBridge)
+                {
+                    let request_id = serde_json::from_str::<serde_json::Value>(json)
+                        .ok()
+                        .and_then(|v| v.get("request_id").and_then(serde_json::Value::as_u64));
+                    match request_id {
+                        Some(id) if self.pending_clipboard_reads.remove(&id) => {}
+                        _ => return false,
+                    }
+                }
+                // Terminal-level focus loss must also release pane pointer
+                // capture, exactly like a browser blur.
+                if matches!(event, Event::Focus(false)) {
+                    self.pane_blur();
+                }
                 self.inner.push_event(event);
                 true
             }
@@ -234,6 +287,34 @@ impl RunnerCore {
         }
     }
 
+    /// Ask the host to read the system clipboard. Returns the request id
+    /// the completion must echo (`{"kind":"clipboard_result",...}`).
+    pub fn request_clipboard_read(&mut self) -> u64 {
+        let id = self.next_host_request_id;
+        self.next_host_request_id += 1;
+        self.pending_clipboard_reads.insert(id);
+        self.host_commands.push(format!(
+            "{{\"cmd\":\"clipboard_read\",\"request_id\":{id}}}"
+        ));
+        id
+    }
+
+    /// Ask the host to write `text` to the system clipboard.
+    pub fn request_clipboard_write(&mut self, text: &str) {
+        let escaped = serde_json::to_string(text).unwrap_or_else(|_| "\"\"".to_string());
+        self.host_commands.push(format!(
+            "{{\"cmd\":\"clipboard_write\",\"text\":{escaped}}}"
+        ));
+    }
+
+    /// Drain pending host commands as a JSON array (FIFO order). The
+    /// host executes each and answers reads with `clipboard_result`
+    /// inputs.
+    pub fn take_host_commands(&mut self) -> String {
+        let drained = std::mem::take(&mut self.host_commands);
+        format!("[{}]", drained.join(","))
+    }
+
     /// Resize the terminal. Pushes a `Resize` event processed on the next step.
     pub fn resize(&mut self, cols: u16, rows: u16) {
         let (cols, rows) = Self::clamp_size(cols, rows);
@@ -320,11 +401,102 @@ impl RunnerCore {
         &self.flat_spans_buf
     }
 
+    /// Extract the visible text of a rectangular region of the most
+    /// recently rendered frame.
+    ///
+    /// Wide characters appear once (continuation cells are skipped),
+    /// trailing spaces are trimmed per row, and rows are joined with `\n`.
+    /// Out-of-range rectangles clamp to the frame instead of erroring.
+    /// Returns an empty string before the first render.
+    pub fn read_text(&self, x: u16, y: u16, w: u16, h: u16) -> String {
+        let Some(buffer) = self.inner.rendered_buffer() else {
+            return String::new();
+        };
+        let pool = self.inner.pool();
+        let x0 = x.min(buffer.width());
+        let y0 = y.min(buffer.height());
+        let x1 = x.saturating_add(w).min(buffer.width());
+        let y1 = y.saturating_add(h).min(buffer.height());
+
+        let mut out = String::new();
+        for row in y0..y1 {
+            if row > y0 {
+                out.push('\n');
+            }
+            let line_start = out.len();
+            for col in x0..x1 {
+                let Some(cell) = buffer.get(col, row) else {
+                    continue;
+                };
+                if cell.is_continuation() {
+                    continue;
+                }
+                if let Some(id) = cell.content.grapheme_id() {
+                    out.push_str(pool.get(id).unwrap_or("\u{fffd}"));
+                } else {
+                    out.push(cell.content.as_char().unwrap_or(' '));
+                }
+            }
+            let trimmed = out[line_start..].trim_end().len();
+            out.truncate(line_start + trimmed);
+        }
+        out
+    }
+
+    /// Visible text of one row ([`read_text`](Self::read_text) semantics).
+    pub fn read_row(&self, y: u16) -> String {
+        let width = self
+            .inner
+            .rendered_buffer()
+            .map_or(0, |buffer| buffer.width());
+        self.read_text(0, y, width, 1)
+    }
+
+    /// Visible text of the whole frame, rows joined with `\n`.
+    pub fn visible_text(&self) -> String {
+        let Some(buffer) = self.inner.rendered_buffer() else {
+            return String::new();
+        };
+        self.read_text(0, 0, buffer.width(), buffer.height())
+    }
+
+    /// Attach a tracing log bridge; captured events are appended to
+    /// [`take_logs`](Self::take_logs) so the host log pane shows them.
+    pub fn attach_log_bridge(&mut self, bridge: ftui_runtime::LogBridge) {
+        self.log_bridge = Some(bridge);
+    }
+
     /// Take accumulated log lines (from the last `take_flat_patches` call).
     pub fn take_logs(&mut self) -> Vec<String> {
         let mut logs = std::mem::take(&mut self.cached_logs);
         logs.append(&mut self.pane_logs);
-        logs
+        if let Some(bridge) = &self.log_bridge {
+            logs.extend(bridge.drain().iter().map(ftui_runtime::LogEvent::to_line));
+        }
+        // Host-facing log output honors the privacy policy: quoted
+        // payloads are masked under KeysOnly, everything is dropped
+        // under Off. Mode-change markers are exempt so the stream stays
+        // self-describing even when switching to Off.
+        logs.into_iter()
+            .filter_map(|line| {
+                if line.starts_with("privacy_mode_change:") {
+                    return Some(line);
+                }
+                ftui_runtime::privacy::redact_log_line(&line, self.privacy)
+            })
+            .collect()
+    }
+
+    /// Change the privacy policy applied to [`take_logs`](Self::take_logs).
+    ///
+    /// A marker line is appended so drained logs are self-describing
+    /// about which redaction applied from this point on.
+    pub fn set_privacy_policy(&mut self, policy: ftui_runtime::TelemetryPrivacyPolicy) {
+        if self.privacy != policy {
+            self.privacy = policy;
+            self.cached_logs
+                .push(format!("privacy_mode_change: {}", policy.as_str()));
+        }
     }
 
     /// FNV-1a hash of the last patch batch.
@@ -425,6 +597,9 @@ impl RunnerCore {
 
     /// Restore pane tree + timeline from a previously exported JSON snapshot.
     pub fn import_workspace_snapshot_json(&mut self, json: &str) -> Result<(), String> {
+        // A snapshot swap invalidates any drag in progress: cancel it
+        // cleanly rather than committing against the new tree.
+        self.explicit_move = None;
         let snapshot: WorkspaceSnapshot = serde_json::from_str(json)
             .map_err(|err| format!("workspace snapshot parse failed: {err}"))?;
         snapshot
@@ -551,6 +726,9 @@ impl RunnerCore {
         y: i32,
         modifiers: PaneModifierSnapshot,
     ) -> PaneDispatchSummary {
+        // A physical pointer gesture supersedes an explicit keyboard/API
+        // move: cancel it so only one state machine drives the tree.
+        self.explicit_move = None;
         let pointer = PanePointerPosition::new(x, y);
         let Some(context) = self.pointer_down_context_at(pointer) else {
             return self.reject_pointer_down(pointer_id, pointer);
@@ -656,6 +834,7 @@ impl RunnerCore {
     /// Handle pane pointer-cancel lifecycle.
     #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
     pub fn pane_pointer_cancel(&mut self, pointer_id: Option<u32>) -> PaneDispatchSummary {
+        self.explicit_move = None;
         let dispatch = self.pane_adapter.pointer_cancel(pointer_id);
         self.record_pane_dispatch(dispatch)
     }
@@ -670,10 +849,131 @@ impl RunnerCore {
     /// Handle browser blur for pane interaction lifecycle.
     #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
     pub fn pane_blur(&mut self) -> PaneDispatchSummary {
+        self.explicit_move = None;
         let dispatch = self.pane_adapter.blur();
         self.record_pane_dispatch(dispatch)
     }
 
+    /// Begin an explicit drag-rearrangement of `pane_id`.
+    ///
+    /// Returns `false` when the pane is unknown, not a leaf, or a move or
+    /// pointer gesture is already active. The layout tree is untouched
+    /// until [`pane_commit_move`](Self::pane_commit_move).
+    pub fn pane_begin_move(&mut self, pane_id: u64, x: i32, y: i32) -> bool {
+        if self.explicit_move.is_some() || self.active_gesture.is_some() {
+            return false;
+        }
+        let Ok(source) = PaneId::new(pane_id) else {
+            return false;
+        };
+        let is_leaf = matches!(
+            self.layout_tree.node(source).map(|node| &node.kind),
+            Some(PaneNodeKind::Leaf(_))
+        );
+        if !is_leaf {
+            return false;
+        }
+        self.explicit_move = Some(ExplicitPaneMove {
+            source,
+            target: None,
+        });
+        let _ = self.pane_move_hover(x, y);
+        true
+    }
+
+    /// Update the drop target from the current pointer position.
+    ///
+    /// Returns the target summary (pane, region, preview rect) for the
+    /// host's drop hint, or `None` when the pointer is over no valid
+    /// target.
+    pub fn pane_move_hover(&mut self, x: i32, y: i32) -> Option<PaneDropTargetSummary> {
+        let mv = self.explicit_move.as_mut()?;
+        let source = mv.source;
+        let layout = self.layout_tree.solve_layout(self.viewport_rect()).ok()?;
+        let target =
+            self.layout_tree
+                .drop_target_at(&layout, PanePointerPosition::new(x, y), source);
+        self.explicit_move.as_mut().expect("move active").target = target;
+        target.map(|t| PaneDropTargetSummary {
+            target_pane: t.target_pane.get(),
+            region: dock_zone_name(t.region),
+            preview: t.preview,
+        })
+    }
+
+    /// Commit the drag-rearrangement as a single timeline operation.
+    ///
+    /// Edge regions insert a new split on that side of the target;
+    /// `Center` swaps the two panes (the closest analog to a tab drop in
+    /// a tree without tab groups). Self-drops and drops onto the
+    /// source's only sibling are no-ops (`false`); so is committing
+    /// without a valid target. The applied operation is one
+    /// [`PaneOperation`] — undo reverses it atomically.
+    pub fn pane_commit_move(&mut self) -> bool {
+        let Some(mv) = self.explicit_move.take() else {
+            return false;
+        };
+        let Some(target) = mv.target else {
+            return false;
+        };
+        if target.target_pane == mv.source {
+            return false;
+        }
+        // Dropping onto one's only sibling can only reproduce (or
+        // mirror) the current arrangement: defined as a no-op.
+        if self.layout_tree.only_sibling_of(mv.source) == Some(target.target_pane) {
+            return false;
+        }
+        let operation = match target.region {
+            PaneDockZone::Center => PaneOperation::SwapNodes {
+                first: mv.source,
+                second: target.target_pane,
+            },
+            zone => {
+                let (axis, placement) = match zone {
+                    PaneDockZone::Left => (SplitAxis::Horizontal, PanePlacement::IncomingFirst),
+                    PaneDockZone::Right => (SplitAxis::Horizontal, PanePlacement::ExistingFirst),
+                    PaneDockZone::Top => (SplitAxis::Vertical, PanePlacement::IncomingFirst),
+                    PaneDockZone::Bottom | PaneDockZone::Center => {
+                        (SplitAxis::Vertical, PanePlacement::ExistingFirst)
+                    }
+                };
+                PaneOperation::MoveSubtree {
+                    source: mv.source,
+                    target: target.target_pane,
+                    axis,
+                    ratio: PaneSplitRatio::new(1, 2).expect("valid ratio"),
+                    placement,
+                }
+            }
+        };
+        let sequence = self.next_operation_id;
+        let applied = self.apply_operations_with_timeline(
+            sequence,
+            &[operation],
+            PanePressureSnapProfile {
+                strength_bps: 4_000,
+                hysteresis_bps: 240,
+            },
+            false,
+        );
+        applied > 0
+    }
+
+    /// Cancel an in-progress drag-rearrangement.
+    ///
+    /// The tree was never mutated during hover, so cancelling simply
+    /// drops the transient state; the original layout is intact.
+    pub fn pane_cancel_move(&mut self) -> bool {
+        self.explicit_move.take().is_some()
+    }
+
+    /// Whether an explicit drag-rearrangement is in progress.
+    #[must_use]
+    pub fn pane_move_active(&self) -> bool {
+        self.explicit_move.is_some()
+    }
+
     /// Handle hidden-tab visibility transition for pane interaction lifecycle.
     #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
     pub fn pane_visibility_hidden(&mut self) -> PaneDispatchSummary {
@@ -1073,6 +1373,17 @@ fn hash_flat_patch_batch(spans: &[u32], cells: &[u32]) -> Option<String> {
     Some(format!("{PATCH_HASH_ALGO}:{hash:016x}"))
 }
 
+/// Stable region name for host-facing drop summaries.
+fn dock_zone_name(zone: PaneDockZone) -> &'static str {
+    match zone {
+        PaneDockZone::Left => "left",
+        PaneDockZone::Right => "right",
+        PaneDockZone::Top => "top",
+        PaneDockZone::Bottom => "bottom",
+        PaneDockZone::Center => "center",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1082,6 +1393,128 @@ mod tests {
         edge_fling_projection,
     };
 
+    // --- Region text reading ---
+
+    /// The codepoint flat patches substitute for pooled graphemes
+    /// (mirrors `GRAPHEME_FALLBACK_CODEPOINT` in ftui-web).
+    const GRAPHEME_FALLBACK: u32 = '□' as u32;
+
+    #[test]
+    fn visible_text_matches_flat_patch_reconstruction() {
+        let (cols, rows) = (80u16, 24u16);
+        let mut runner = RunnerCore::new(cols, rows);
+        runner.init();
+        runner.step();
+
+        // Reconstruct the screen from the flat patch payload (glyph lane
+        // only): offset is a row-major cell index.
+        let flat = runner.take_flat_patches();
+        let mut glyphs = vec![' ' as u32; usize::from(cols) * usize::from(rows)];
+        let mut span_iter = flat.spans.chunks_exact(2);
+        let mut cell_idx = 0usize;
+        for span in &mut span_iter {
+            let (offset, len) = (span[0] as usize, span[1] as usize);
+            for i in 0..len {
+                glyphs[offset + i] = flat.cells[(cell_idx + i) * 4 + 2];
+            }
+            cell_idx += len;
+        }
+
+        let text = runner.visible_text();
+        let lines: Vec<&str> = text.split('\n').collect();
+        assert_eq!(lines.len(), usize::from(rows));
+
+        for (row, line) in lines.iter().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            let mut char_idx = 0usize;
+            for col in 0..usize::from(cols) {
+                let glyph = glyphs[row * usize::from(cols) + col];
+                match glyph {
+                    // Glyph 0 is EMPTY (reads back as a space, possibly
+                    // trimmed) or a wide-char CONTINUATION (reads back as
+                    // nothing). Consume a space if one is present.
+                    0 => {
+                        if chars.get(char_idx) == Some(&' ') {
+                            char_idx += 1;
+                        }
+                    }
+                    // Pooled graphemes can't round-trip through the patch
+                    // payload; skip the cell on both sides.
+                    GRAPHEME_FALLBACK => {
+                        char_idx += 1;
+                    }
+                    _ => {
+                        let expected = char::from_u32(glyph).unwrap_or('\u{fffd}');
+                        match chars.get(char_idx) {
+                            Some(&c) => {
+                                assert_eq!(
+                                    c, expected,
+                                    "row {row} col {col}: patch paints {expected:?}"
+                                );
+                                char_idx += 1;
+                            }
+                            // Trailing trimmed spaces.
+                            None => assert_eq!(
+                                expected, ' ',
+                                "row {row} col {col}: trimmed cell must be a space"
+                            ),
+                        }
+                    }
+                }
+            }
+            assert_eq!(char_idx, chars.len(), "row {row}: unconsumed text");
+        }
+    }
+
+    #[test]
+    fn read_text_clamps_out_of_range_rects() {
+        let mut runner = RunnerCore::new(40, 10);
+        runner.init();
+        runner.step();
+
+        // Fully out of range: empty, no panic.
+        assert_eq!(runner.read_text(100, 100, 5, 5), "");
+        // Partially out of range clamps to the frame.
+        let clamped = runner.read_text(35, 8, 100, 100);
+        assert_eq!(clamped.split('\n').count(), 2);
+        // Zero-sized rect.
+        assert_eq!(runner.read_text(0, 0, 0, 0), "");
+    }
+
+    #[test]
+    fn read_row_matches_visible_text_line() {
+        let mut runner = RunnerCore::new(60, 12);
+        runner.init();
+        runner.step();
+
+        let text = runner.visible_text();
+        let lines: Vec<&str> = text.split('\n').collect();
+        for (y, line) in lines.iter().enumerate() {
+            assert_eq!(runner.read_row(y as u16), *line, "row {y}");
+        }
+        // Out-of-range row clamps to empty.
+        assert_eq!(runner.read_row(99), "");
+    }
+
+    #[test]
+    fn log_bridge_events_surface_in_take_logs() {
+        let (bridge, subscriber) = ftui_runtime::LogBridge::new(64);
+        let mut runner = RunnerCore::new(40, 10);
+        runner.attach_log_bridge(bridge);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("bridged line");
+        });
+        let logs = runner.take_logs();
+        assert!(logs.iter().any(|l| l.contains("bridged line")), "{logs:?}");
+    }
+
+    #[test]
+    fn read_text_before_first_render_is_empty() {
+        let runner = RunnerCore::new(20, 5);
+        assert_eq!(runner.visible_text(), "");
+        assert_eq!(runner.read_row(0), "");
+    }
+
     #[test]
     fn live_reflow_threshold_drops_for_fast_confident_motion() {
         let slow_noisy = dynamic_live_reflow_threshold_bps(
@@ -1320,4 +1753,336 @@ mod tests {
         assert!(runner.selection.anchor.is_none());
         assert!(runner.selection.selected.is_empty());
     }
+
+    #[test]
+    fn terminal_focus_loss_releases_pane_capture() {
+        let mut runner = RunnerCore::new(100, 32);
+        runner.init();
+        let summary = runner.pane_pointer_down_at(
+            23,
+            PanePointerButton::Primary,
+            0,
+            0,
+            PaneModifierSnapshot::default(),
+        );
+        assert!(summary.accepted());
+        assert_eq!(runner.pane_active_pointer_id(), Some(23));
+
+        // Terminal-level focus loss routes through the pane blur path.
+        let accepted = runner.push_encoded_input(r#"{"kind":"focus","focused":false}"#);
+        assert!(accepted);
+        assert_eq!(runner.pane_active_pointer_id(), None);
+        assert!(runner.active_gesture.is_none());
+    }
+
+    #[test]
+    fn terminal_focus_gain_does_not_touch_pane_capture() {
+        let mut runner = RunnerCore::new(100, 32);
+        runner.init();
+        let summary = runner.pane_pointer_down_at(
+            7,
+            PanePointerButton::Primary,
+            0,
+            0,
+            PaneModifierSnapshot::default(),
+        );
+        assert!(summary.accepted());
+
+        let accepted = runner.push_encoded_input(r#"{"kind":"focus","focused":true}"#);
+        assert!(accepted);
+        assert_eq!(runner.pane_active_pointer_id(), Some(7));
+    }
+
+    #[test]
+    fn take_logs_redacts_quoted_payloads_by_default() {
+        let mut runner = RunnerCore::new(80, 24);
+        runner
+            .cached_logs
+            .push(r#"key event text="hunter2""#.to_string());
+        let logs = runner.take_logs();
+        assert!(
+            logs.iter().any(|l| l.contains("<redacted:7B>")),
+            "got {logs:?}"
+        );
+        assert!(logs.iter().all(|l| !l.contains("hunter2")));
+    }
+
+    #[test]
+    fn privacy_policy_change_emits_marker_line() {
+        let mut runner = RunnerCore::new(80, 24);
+        runner.set_privacy_policy(ftui_runtime::TelemetryPrivacyPolicy::Full);
+        let logs = runner.take_logs();
+        assert!(
+            logs.iter().any(|l| l.contains("privacy_mode_change: full")),
+            "got {logs:?}"
+        );
+
+        // Off drops everything except its own marker, so the stream
+        // still documents the switch.
+        runner.set_privacy_policy(ftui_runtime::TelemetryPrivacyPolicy::Off);
+        runner.cached_logs.push("sensitive".to_string());
+        let logs = runner.take_logs();
+        assert_eq!(logs, vec!["privacy_mode_change: off".to_string()]);
+    }
+
+    // ── Explicit pane drag-rearrangement ────────────────────────────
+
+    /// A leaf id and a point inside it, away from edges (Center zone).
+    fn leaf_center(runner: &RunnerCore) -> (u64, i32, i32) {
+        let layout = runner
+            .layout_tree
+            .solve_layout(runner.viewport_rect())
+            .expect("layout");
+        for (id, rect) in layout.iter() {
+            if matches!(
+                runner.layout_tree.node(id).map(|n| &n.kind),
+                Some(PaneNodeKind::Leaf(_))
+            ) && rect.width > 8
+                && rect.height > 8
+            {
+                return (
+                    id.get(),
+                    i32::from(rect.x) + i32::from(rect.width) / 2,
+                    i32::from(rect.y) + i32::from(rect.height) / 2,
+                );
+            }
+        }
+        panic!("no leaf found");
+    }
+
+    /// A second leaf, different from `exclude`, with its rect.
+    fn other_leaf(runner: &RunnerCore, exclude: u64) -> (u64, Rect) {
+        let layout = runner
+            .layout_tree
+            .solve_layout(runner.viewport_rect())
+            .expect("layout");
+        for (id, rect) in layout.iter() {
+            if id.get() != exclude
+                && matches!(
+                    runner.layout_tree.node(id).map(|n| &n.kind),
+                    Some(PaneNodeKind::Leaf(_))
+                )
+                && rect.width > 8
+                && rect.height > 8
+            {
+                return (id.get(), rect);
+            }
+        }
+        panic!("no second leaf");
+    }
+
+    #[test]
+    fn pane_move_full_drag_commit_changes_layout() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        let before_hash = runner.pane_layout_hash();
+        let (source, sx, sy) = leaf_center(&runner);
+        let (target, rect) = other_leaf(&runner, source);
+
+        assert!(runner.pane_begin_move(source, sx, sy));
+        // Hover near the left edge of the target: Left region.
+        let hover = runner
+            .pane_move_hover(
+                i32::from(rect.x) + 1,
+                i32::from(rect.y) + i32::from(rect.height) / 2,
+            )
+            .expect("drop target");
+        assert_eq!(hover.target_pane, target);
+        assert_eq!(hover.region, "left");
+        assert!(hover.preview.width > 0);
+
+        assert!(runner.pane_commit_move());
+        assert!(!runner.pane_move_active());
+        assert_ne!(runner.pane_layout_hash(), before_hash, "tree changed");
+
+        // Undo reverses the whole move atomically.
+        assert!(runner.pane_undo());
+        assert_eq!(runner.pane_layout_hash(), before_hash, "undo restores");
+    }
+
+    #[test]
+    fn pane_move_cancel_restores_everything() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        let before_hash = runner.pane_layout_hash();
+        let (source, sx, sy) = leaf_center(&runner);
+        let (_, rect) = other_leaf(&runner, source);
+
+        assert!(runner.pane_begin_move(source, sx, sy));
+        let _ = runner.pane_move_hover(i32::from(rect.x) + 1, i32::from(rect.y) + 1);
+        assert!(runner.pane_cancel_move());
+        assert!(!runner.pane_move_active());
+        assert_eq!(runner.pane_layout_hash(), before_hash);
+        // Commit after cancel is a no-op.
+        assert!(!runner.pane_commit_move());
+    }
+
+    #[test]
+    fn pane_move_self_drop_is_noop() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        let before_hash = runner.pane_layout_hash();
+        let (source, sx, sy) = leaf_center(&runner);
+
+        assert!(runner.pane_begin_move(source, sx, sy));
+        // Hover over the source itself: no target (source excluded).
+        assert!(runner.pane_move_hover(sx, sy).is_none());
+        assert!(!runner.pane_commit_move());
+        assert_eq!(runner.pane_layout_hash(), before_hash);
+    }
+
+    #[test]
+    fn pane_move_blur_and_pointer_cancel_abort() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        let (source, sx, sy) = leaf_center(&runner);
+        assert!(runner.pane_begin_move(source, sx, sy));
+        runner.pane_blur();
+        assert!(!runner.pane_move_active());
+
+        assert!(runner.pane_begin_move(source, sx, sy));
+        runner.pane_pointer_cancel(None);
+        assert!(!runner.pane_move_active());
+    }
+
+    #[test]
+    fn snapshot_import_mid_drag_cancels_cleanly() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        let snapshot = runner.export_workspace_snapshot_json().expect("export");
+        let (source, sx, sy) = leaf_center(&runner);
+        assert!(runner.pane_begin_move(source, sx, sy));
+
+        runner
+            .import_workspace_snapshot_json(&snapshot)
+            .expect("import");
+        assert!(!runner.pane_move_active(), "import cancels the drag");
+        assert!(!runner.pane_commit_move());
+    }
+
+    #[test]
+    fn pane_begin_move_rejects_unknown_and_double_start() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        assert!(!runner.pane_begin_move(0, 5, 5), "zero id rejected");
+        assert!(!runner.pane_begin_move(9_999, 5, 5), "unknown id rejected");
+
+        let (source, sx, sy) = leaf_center(&runner);
+        assert!(runner.pane_begin_move(source, sx, sy));
+        assert!(!runner.pane_begin_move(source, sx, sy), "double start");
+        runner.pane_cancel_move();
+    }
+
+    #[test]
+    fn pointer_down_cancels_explicit_move() {
+        let mut runner = RunnerCore::new(120, 40);
+        runner.init();
+        let (source, sx, sy) = leaf_center(&runner);
+        assert!(runner.pane_begin_move(source, sx, sy));
+        let _ = runner.pane_pointer_down_at(
+            3,
+            PanePointerButton::Primary,
+            sx,
+            sy,
+            PaneModifierSnapshot::default(),
+        );
+        assert!(!runner.pane_move_active(), "pointer gesture wins");
+        let _ = runner.pane_pointer_cancel(Some(3));
+    }
+
+    // ── IME / clipboard bridge ──────────────────────────────────────
+
+    /// Drive the runner to the forms screen with the search input
+    /// focused, returning the runner ready for text input.
+    fn forms_screen_runner() -> RunnerCore {
+        let mut runner = RunnerCore::new(100, 32);
+        runner.init();
+        // Switch to the Forms screen via its tab hotkey, then move panel
+        // focus (Ctrl+Right) from the form onto the search input.
+        runner.push_encoded_input(
+            r#"{"kind":"key","phase":"down","code":"7","mods":0,"repeat":false}"#,
+        );
+        let _ = runner.step();
+        runner.push_encoded_input(
+            r#"{"kind":"key","phase":"down","code":"Right","mods":4,"repeat":false}"#,
+        );
+        let _ = runner.step();
+        runner
+    }
+
+    #[test]
+    fn composition_round_trip_reaches_focused_input() {
+        let mut runner = forms_screen_runner();
+        let before = runner.read_text(0, 0, 100, 32);
+
+        assert!(runner.push_encoded_input(r#"{"kind":"composition","phase":"start"}"#));
+        assert!(runner.push_encoded_input(
+            r#"{"kind":"composition","phase":"update","text":"漢","cursor":1}"#
+        ));
+        assert!(runner.push_encoded_input(r#"{"kind":"composition","phase":"end","text":"漢字"}"#));
+        let _ = runner.step();
+        let after = runner.read_text(0, 0, 100, 32);
+        assert_ne!(before, after, "composition changed the frame");
+        assert!(after.contains("漢字"), "committed text visible: {after}");
+    }
+
+    #[test]
+    fn paste_reaches_focused_input() {
+        let mut runner = forms_screen_runner();
+        assert!(runner.push_encoded_input(r#"{"kind":"paste","text":"pasted-xyz"}"#));
+        let _ = runner.step();
+        let after = runner.read_text(0, 0, 100, 32);
+        assert!(after.contains("pasted-xyz"), "{after}");
+    }
+
+    #[test]
+    fn malformed_composition_is_rejected_without_side_effects() {
+        let mut runner = forms_screen_runner();
+        let before = runner.read_text(0, 0, 100, 32);
+        assert!(!runner.push_encoded_input(r#"{"kind":"composition","phase":"update"}"#));
+        assert!(
+            !runner.push_encoded_input(r#"{"kind":"composition","phase":"zigzag","text":"x"}"#)
+        );
+        assert!(!runner.push_encoded_input(r#"{"kind":"warp-drive"}"#));
+        let _ = runner.step();
+        assert_eq!(before, runner.read_text(0, 0, 100, 32));
+    }
+
+    #[test]
+    fn host_command_drain_preserves_order() {
+        let mut runner = RunnerCore::new(80, 24);
+        runner.init();
+        let id1 = runner.request_clipboard_read();
+        runner.request_clipboard_write("copy me");
+        let id2 = runner.request_clipboard_read();
+        assert_eq!((id1, id2), (1, 2));
+
+        let drained = runner.take_host_commands();
+        let expected = concat!(
+            "[{\"cmd\":\"clipboard_read\",\"request_id\":1},",
+            "{\"cmd\":\"clipboard_write\",\"text\":\"copy me\"},",
+            "{\"cmd\":\"clipboard_read\",\"request_id\":2}]"
+        );
+        assert_eq!(drained, expected);
+        assert_eq!(runner.take_host_commands(), "[]", "drain empties the queue");
+    }
+
+    #[test]
+    fn clipboard_result_requires_matching_pending_request() {
+        let mut runner = RunnerCore::new(80, 24);
+        runner.init();
+        // Unsolicited result: rejected.
+        assert!(
+            !runner.push_encoded_input(
+                r#"{"kind":"clipboard_result","request_id":9,"text":"spoofed"}"#
+            )
+        );
+
+        let id = runner.request_clipboard_read();
+        let json =
+            format!("{{\"kind\":\"clipboard_result\",\"request_id\":{id},\"text\":\"real\"}}");
+        assert!(runner.push_encoded_input(&json));
+        // A second completion for the same id is rejected.
+        assert!(!runner.push_encoded_input(&json));
+    }
 }
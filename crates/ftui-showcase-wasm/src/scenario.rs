@@ -0,0 +1,586 @@
+//! Golden end-to-end scenario scripts for the showcase runner.
+//!
+//! A [`Scenario`] is a small declarative script — key/mouse/resize/
+//! time-advance steps with named checkpoints — executed against a
+//! [`RunnerCore`]. At each checkpoint the runner's `patch_hash()` and
+//! visible frame text are recorded; [`ScenarioRun::compare_golden`]
+//! asserts them against a golden recorded in the repo and, on mismatch,
+//! dumps the annotated frame for both expected and actual. Because the
+//! script drives the same `RunnerCore` the wasm host uses, a scenario
+//! that passes natively pins the wasm-rendered content too (and
+//! re-running a script must reproduce identical hashes — asserted by
+//! the native equivalence test).
+//!
+//! Golden files are plain text (see [`ScenarioRun::to_golden`]); to
+//! re-record after an intentional visual change, run the scenario tests
+//! with `FTUI_UPDATE_GOLDENS=1`.
+
+use crate::runner_core::RunnerCore;
+use ftui_layout::{PaneModifierSnapshot, PanePointerButton};
+
+/// One step of a scenario script.
+#[derive(Debug, Clone)]
+pub enum ScenarioStep {
+    /// Press a key (browser-style `code`, optional produced `key`,
+    /// modifier bits as the web host encodes them).
+    Key {
+        key: Option<String>,
+        code: String,
+        mods: u8,
+    },
+    /// Move the mouse (`button` -1 = no button held).
+    MouseMove { button: i32, x: i32, y: i32 },
+    /// Resize the terminal.
+    Resize { cols: u16, rows: u16 },
+    /// Advance the runner clock.
+    AdvanceTime { ms: f64 },
+    /// Run `count` frame-loop iterations (step + patch flatten).
+    Frames { count: u32 },
+    /// Probe for a pane splitter, drag it by `(dx, dy)`, and release.
+    /// Fails the run when no splitter accepts the pointer.
+    SplitterDrag { dx: i32, dy: i32 },
+    /// Undo the last pane operation.
+    PaneUndo,
+    /// Record a named checkpoint; `expect_substrings` are asserted
+    /// against the visible frame text during the run (independent of
+    /// the golden).
+    Checkpoint {
+        name: String,
+        expect_substrings: Vec<String>,
+    },
+}
+
+/// A declarative end-to-end script with named golden checkpoints.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    name: String,
+    cols: u16,
+    rows: u16,
+    steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+    /// Start a script with the given name and initial terminal size.
+    #[must_use]
+    pub fn new(name: impl Into<String>, cols: u16, rows: u16) -> Self {
+        Self {
+            name: name.into(),
+            cols,
+            rows,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Script name (used for golden file naming and reports).
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Press a key by browser `code` (e.g. `"Digit9"`, `"PageDown"`).
+    #[must_use]
+    pub fn key(mut self, code: &str) -> Self {
+        self.steps.push(ScenarioStep::Key {
+            key: None,
+            code: code.to_string(),
+            mods: 0,
+        });
+        self
+    }
+
+    /// Press a key with an explicit produced `key` value and modifiers.
+    #[must_use]
+    pub fn key_with(mut self, key: &str, code: &str, mods: u8) -> Self {
+        self.steps.push(ScenarioStep::Key {
+            key: Some(key.to_string()),
+            code: code.to_string(),
+            mods,
+        });
+        self
+    }
+
+    /// Move the mouse (`button` -1 = none held).
+    #[must_use]
+    pub fn mouse_move(mut self, button: i32, x: i32, y: i32) -> Self {
+        self.steps.push(ScenarioStep::MouseMove { button, x, y });
+        self
+    }
+
+    /// Resize the terminal.
+    #[must_use]
+    pub fn resize(mut self, cols: u16, rows: u16) -> Self {
+        self.steps.push(ScenarioStep::Resize { cols, rows });
+        self
+    }
+
+    /// Advance the runner clock by `ms`.
+    #[must_use]
+    pub fn advance(mut self, ms: f64) -> Self {
+        self.steps.push(ScenarioStep::AdvanceTime { ms });
+        self
+    }
+
+    /// Run `count` frame-loop iterations.
+    #[must_use]
+    pub fn frames(mut self, count: u32) -> Self {
+        self.steps.push(ScenarioStep::Frames { count });
+        self
+    }
+
+    /// Probe for a pane splitter and drag it by `(dx, dy)`.
+    #[must_use]
+    pub fn splitter_drag(mut self, dx: i32, dy: i32) -> Self {
+        self.steps.push(ScenarioStep::SplitterDrag { dx, dy });
+        self
+    }
+
+    /// Undo the last pane operation.
+    #[must_use]
+    pub fn pane_undo(mut self) -> Self {
+        self.steps.push(ScenarioStep::PaneUndo);
+        self
+    }
+
+    /// Record a named checkpoint.
+    #[must_use]
+    pub fn checkpoint(mut self, name: &str) -> Self {
+        self.steps.push(ScenarioStep::Checkpoint {
+            name: name.to_string(),
+            expect_substrings: Vec::new(),
+        });
+        self
+    }
+
+    /// Record a named checkpoint, asserting that the visible frame
+    /// contains every given substring.
+    #[must_use]
+    pub fn checkpoint_expect(mut self, name: &str, substrings: &[&str]) -> Self {
+        self.steps.push(ScenarioStep::Checkpoint {
+            name: name.to_string(),
+            expect_substrings: substrings.iter().map(|s| (*s).to_string()).collect(),
+        });
+        self
+    }
+
+    /// Execute the script against a fresh [`RunnerCore`].
+    pub fn run(&self) -> Result<ScenarioRun, String> {
+        let mut core = RunnerCore::new(self.cols, self.rows);
+        core.init();
+        self.run_on(&mut core)
+    }
+
+    /// Execute the script against an already-initialized runner.
+    pub fn run_on(&self, core: &mut RunnerCore) -> Result<ScenarioRun, String> {
+        let mut checkpoints = Vec::new();
+        let mut next_pointer_id: u32 = 9000;
+        let mut current_size = (self.cols, self.rows);
+        // Hash of the most recent rendered patch; idle frames produce no
+        // patch, so the checkpoint keeps the last one.
+        let mut last_hash: Option<String> = None;
+        for (step_idx, step) in self.steps.iter().enumerate() {
+            match step {
+                ScenarioStep::Key { key, code, mods } => {
+                    let payload = match key {
+                        Some(key) => format!(
+                            r#"{{"kind":"key","phase":"down","key":"{key}","code":"{code}","mods":{mods},"repeat":false}}"#
+                        ),
+                        None => format!(
+                            r#"{{"kind":"key","phase":"down","code":"{code}","mods":{mods},"repeat":false}}"#
+                        ),
+                    };
+                    if !core.push_encoded_input(&payload) {
+                        return Err(self.step_error(step_idx, format!("key rejected: {payload}")));
+                    }
+                    Self::frame(core, &mut last_hash);
+                }
+                ScenarioStep::MouseMove { button, x, y } => {
+                    let payload = format!(
+                        r#"{{"kind":"mouse","phase":"move","button":{button},"x":{x},"y":{y},"mods":0}}"#
+                    );
+                    if !core.push_encoded_input(&payload) {
+                        return Err(self.step_error(step_idx, format!("mouse rejected: {payload}")));
+                    }
+                    Self::frame(core, &mut last_hash);
+                }
+                ScenarioStep::Resize { cols, rows } => {
+                    core.resize(*cols, *rows);
+                    current_size = (*cols, *rows);
+                    Self::frame(core, &mut last_hash);
+                }
+                ScenarioStep::AdvanceTime { ms } => {
+                    core.advance_time_ms(*ms);
+                }
+                ScenarioStep::Frames { count } => {
+                    for _ in 0..*count {
+                        core.advance_time_ms(16.0);
+                        Self::frame(core, &mut last_hash);
+                    }
+                }
+                ScenarioStep::SplitterDrag { dx, dy } => {
+                    let pointer_id = next_pointer_id;
+                    next_pointer_id += 1;
+                    let Some((x, y)) = Self::find_splitter(core, current_size.0, current_size.1)
+                    else {
+                        return Err(self.step_error(step_idx, "no splitter accepted the pointer"));
+                    };
+                    let modifiers = PaneModifierSnapshot::default();
+                    let down = core.pane_pointer_down_at(
+                        pointer_id,
+                        PanePointerButton::Primary,
+                        x,
+                        y,
+                        modifiers,
+                    );
+                    if !down.accepted() {
+                        return Err(
+                            self.step_error(step_idx, format!("splitter down rejected at {x},{y}"))
+                        );
+                    }
+                    core.pane_capture_acquired(pointer_id);
+                    core.pane_pointer_move_at(pointer_id, x + dx, y + dy, modifiers);
+                    core.pane_pointer_up_at(
+                        pointer_id,
+                        PanePointerButton::Primary,
+                        x + dx,
+                        y + dy,
+                        modifiers,
+                    );
+                    Self::frame(core, &mut last_hash);
+                }
+                ScenarioStep::PaneUndo => {
+                    core.pane_undo();
+                    Self::frame(core, &mut last_hash);
+                }
+                ScenarioStep::Checkpoint {
+                    name,
+                    expect_substrings,
+                } => {
+                    Self::frame(core, &mut last_hash);
+                    let hash = last_hash.clone().unwrap_or_else(|| "none".to_string());
+                    let frame = core.visible_text();
+                    for substring in expect_substrings {
+                        if !frame.contains(substring.as_str()) {
+                            return Err(format!(
+                                "scenario '{}' checkpoint '{name}': visible frame missing \
+                                 expected text {substring:?}\n--- actual frame\n{}",
+                                self.name,
+                                annotate_frame(&frame)
+                            ));
+                        }
+                    }
+                    checkpoints.push(CheckpointRecord {
+                        name: name.clone(),
+                        hash,
+                        frame,
+                    });
+                }
+            }
+        }
+        Ok(ScenarioRun {
+            scenario: self.name.clone(),
+            checkpoints,
+        })
+    }
+
+    /// One frame-loop iteration as the wasm host performs it, tracking
+    /// the hash of the most recent rendered patch.
+    fn frame(core: &mut RunnerCore, last_hash: &mut Option<String>) {
+        let _ = core.step();
+        core.prepare_flat_patches();
+        if let Some(hash) = core.patch_hash() {
+            *last_hash = Some(hash);
+        }
+    }
+
+    /// Probe the frame for a splitter that accepts a pointer-down,
+    /// releasing the probe capture before returning.
+    fn find_splitter(core: &mut RunnerCore, cols: u16, rows: u16) -> Option<(i32, i32)> {
+        let modifiers = PaneModifierSnapshot::default();
+        for y in 0..i32::from(rows.max(1)) {
+            for x in 0..i32::from(cols.max(1)) {
+                let down =
+                    core.pane_pointer_down_at(1, PanePointerButton::Primary, x, y, modifiers);
+                if down.accepted() {
+                    core.pane_pointer_cancel(Some(1));
+                    return Some((x, y));
+                }
+            }
+        }
+        None
+    }
+
+    fn step_error(&self, step_idx: usize, message: impl std::fmt::Display) -> String {
+        format!("scenario '{}' step {step_idx}: {message}", self.name)
+    }
+}
+
+/// A recorded checkpoint: name, patch hash, and visible frame text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointRecord {
+    pub name: String,
+    pub hash: String,
+    pub frame: String,
+}
+
+/// The outcome of one scenario execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioRun {
+    pub scenario: String,
+    pub checkpoints: Vec<CheckpointRecord>,
+}
+
+impl ScenarioRun {
+    /// Serialize to the golden text format:
+    ///
+    /// ```text
+    /// # scenario: <name>
+    /// # screens: <compiled screen count>
+    /// == checkpoint <name>
+    /// hash <hash>
+    /// |<frame line>
+    /// |...
+    /// ```
+    #[must_use]
+    pub fn to_golden(&self) -> String {
+        let mut out = format!("# scenario: {}\n", self.scenario);
+        out.push_str(&format!("# screens: {}\n", compiled_screen_count()));
+        for checkpoint in &self.checkpoints {
+            out.push_str(&format!("== checkpoint {}\n", checkpoint.name));
+            out.push_str(&format!("hash {}\n", checkpoint.hash));
+            out.push_str(&annotate_frame(&checkpoint.frame));
+        }
+        out
+    }
+
+    /// Compare against a recorded golden; `Err` carries a report that
+    /// includes the annotated expected and actual frames for every
+    /// mismatched checkpoint.
+    pub fn compare_golden(&self, golden: &str) -> Result<(), String> {
+        let expected = parse_golden(golden)?;
+        let mut report = String::new();
+        if expected.len() != self.checkpoints.len() {
+            report.push_str(&format!(
+                "checkpoint count mismatch: golden has {}, run produced {}\n",
+                expected.len(),
+                self.checkpoints.len()
+            ));
+        }
+        for (exp, act) in expected.iter().zip(&self.checkpoints) {
+            if exp.name != act.name {
+                report.push_str(&format!(
+                    "checkpoint name mismatch: golden '{}' vs run '{}'\n",
+                    exp.name, act.name
+                ));
+                continue;
+            }
+            // Compare frames in their annotated (serialized) form: the
+            // golden round-trip drops trailing blank rows, so comparing
+            // the raw actual frame against the parsed expected one would
+            // mismatch forever on a frame ending in an empty row.
+            if exp.hash != act.hash || annotate_frame(&exp.frame) != annotate_frame(&act.frame) {
+                report.push_str(&format!(
+                    "checkpoint '{}' mismatch (hash {} vs {})\n--- expected frame\n{}--- actual frame\n{}",
+                    exp.name,
+                    exp.hash,
+                    act.hash,
+                    annotate_frame(&exp.frame),
+                    annotate_frame(&act.frame),
+                ));
+            }
+        }
+        if report.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "scenario '{}' golden mismatch:\n{report}",
+                self.scenario
+            ))
+        }
+    }
+}
+
+/// Screen-registry size of the compiled showcase.
+///
+/// Goldens are recorded against the wasm feature set
+/// (`default-features = false` on ftui-demo-showcase, 44 screens).
+/// Workspace-level feature unification compiles the optional screens in
+/// (e.g. `screen-mermaid`), which legitimately changes frame content —
+/// golden assertions only apply when the compiled registry matches the
+/// one the golden was recorded with.
+#[must_use]
+pub fn compiled_screen_count() -> usize {
+    ftui_demo_showcase::screens::screen_registry().len()
+}
+
+/// The `# screens:` header of a recorded golden, when present.
+#[must_use]
+pub fn golden_screen_count(golden: &str) -> Option<usize> {
+    golden
+        .lines()
+        .find_map(|line| line.strip_prefix("# screens: "))
+        .and_then(|n| n.trim().parse().ok())
+}
+
+/// Prefix every frame line with `|` so leading/trailing structure
+/// survives editors and diffs.
+fn annotate_frame(frame: &str) -> String {
+    let mut out = String::new();
+    for line in frame.lines() {
+        out.push('|');
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse the golden text format back into checkpoint records.
+fn parse_golden(golden: &str) -> Result<Vec<CheckpointRecord>, String> {
+    let mut checkpoints: Vec<CheckpointRecord> = Vec::new();
+    for (line_no, line) in golden.lines().enumerate() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("== checkpoint ") {
+            checkpoints.push(CheckpointRecord {
+                name: name.to_string(),
+                hash: String::new(),
+                frame: String::new(),
+            });
+        } else if let Some(hash) = line.strip_prefix("hash ") {
+            let checkpoint = checkpoints
+                .last_mut()
+                .ok_or_else(|| format!("golden line {}: hash before checkpoint", line_no + 1))?;
+            hash.clone_into(&mut checkpoint.hash);
+        } else if let Some(frame_line) = line.strip_prefix('|') {
+            let checkpoint = checkpoints
+                .last_mut()
+                .ok_or_else(|| format!("golden line {}: frame before checkpoint", line_no + 1))?;
+            if !checkpoint.frame.is_empty() {
+                checkpoint.frame.push('\n');
+            }
+            checkpoint.frame.push_str(frame_line);
+        } else {
+            return Err(format!("golden line {}: unrecognized: {line}", line_no + 1));
+        }
+    }
+    Ok(checkpoints)
+}
+
+// ---------------------------------------------------------------------------
+// The four golden scenarios
+// ---------------------------------------------------------------------------
+
+/// Startup: init at 120x40, settle a few frames on the default screen.
+#[must_use]
+pub fn startup_default_screen() -> Scenario {
+    Scenario::new("startup_default_screen", 120, 40)
+        .frames(4)
+        .checkpoint("startup")
+}
+
+/// Navigate to the Files screen (Digit9 shortcut) and page through it.
+#[must_use]
+pub fn files_navigation_and_paging() -> Scenario {
+    Scenario::new("files_navigation_and_paging", 120, 40)
+        .frames(2)
+        .key("Digit9")
+        .frames(2)
+        .checkpoint("files-open")
+        .key("PageDown")
+        .frames(2)
+        .checkpoint("page-down")
+        .key("PageUp")
+        .frames(2)
+        .checkpoint("page-up")
+}
+
+/// Drag a pane splitter, checkpoint, then undo back to the baseline.
+#[must_use]
+pub fn splitter_drag_with_undo() -> Scenario {
+    Scenario::new("splitter_drag_with_undo", 120, 40)
+        .frames(2)
+        .checkpoint("before-drag")
+        .splitter_drag(6, 0)
+        .frames(2)
+        .checkpoint("after-drag")
+        .pane_undo()
+        .frames(2)
+        .checkpoint("after-undo")
+}
+
+/// A resize storm: rapid size churn ending back at the start size.
+#[must_use]
+pub fn resize_storm() -> Scenario {
+    Scenario::new("resize_storm", 120, 40)
+        .frames(2)
+        .resize(80, 24)
+        .resize(30, 10)
+        .resize(200, 60)
+        .resize(0, 0)
+        .resize(64, 18)
+        .frames(2)
+        .checkpoint("mid-storm")
+        .resize(120, 40)
+        .frames(3)
+        .checkpoint("settled")
+}
+
+/// All four golden scenarios, in execution order.
+#[must_use]
+pub fn all_scenarios() -> Vec<Scenario> {
+    vec![
+        startup_default_screen(),
+        files_navigation_and_paging(),
+        splitter_drag_with_undo(),
+        resize_storm(),
+    ]
+}
+
+/// The recorded golden for a scenario name, embedded at compile time so
+/// the wasm build can verify without a filesystem. The native golden
+/// tests read the same files from disk (with `FTUI_UPDATE_GOLDENS=1`
+/// re-recording them).
+#[must_use]
+pub fn embedded_golden(name: &str) -> Option<&'static str> {
+    match name {
+        "startup_default_screen" => Some(include_str!(
+            "../tests/goldens/startup_default_screen.golden"
+        )),
+        "files_navigation_and_paging" => Some(include_str!(
+            "../tests/goldens/files_navigation_and_paging.golden"
+        )),
+        "splitter_drag_with_undo" => Some(include_str!(
+            "../tests/goldens/splitter_drag_with_undo.golden"
+        )),
+        "resize_storm" => Some(include_str!("../tests/goldens/resize_storm.golden")),
+        _ => None,
+    }
+}
+
+/// Run the named scenario against its embedded golden. Returns `"ok"`
+/// on match, otherwise the failure/mismatch report.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+#[must_use]
+pub fn run_embedded(name: &str) -> String {
+    let Some(scenario) = all_scenarios().into_iter().find(|s| s.name() == name) else {
+        return format!("unknown scenario '{name}'");
+    };
+    let Some(golden) = embedded_golden(name) else {
+        return format!("no embedded golden for '{name}'");
+    };
+    if let Some(recorded) = golden_screen_count(golden)
+        && recorded != compiled_screen_count()
+    {
+        return format!(
+            "skipped: golden recorded with {recorded} screens; this build compiles {}",
+            compiled_screen_count()
+        );
+    }
+    match scenario.run() {
+        Ok(run) => match run.compare_golden(golden) {
+            Ok(()) => "ok".to_string(),
+            Err(report) => report,
+        },
+        Err(err) => err,
+    }
+}
@@ -468,6 +468,17 @@ pub fn wasm_start() {
     install_panic_hook();
 }
 
+/// Run a named golden scenario script on a fresh runner core and compare
+/// it against the recorded golden embedded in the binary. Returns `"ok"`
+/// on match, otherwise the mismatch report (annotated expected and
+/// actual frames). The same scripts run natively in this crate's tests,
+/// so a pass here confirms wasm renders identical content.
+#[wasm_bindgen(js_name = runScenarioGolden)]
+#[must_use]
+pub fn run_scenario_golden(name: &str) -> String {
+    crate::scenario::run_embedded(name)
+}
+
 #[wasm_bindgen]
 impl ShowcaseRunner {
     fn pane_dispatch_with_state(
@@ -532,6 +543,27 @@ impl ShowcaseRunner {
         self.inner.set_time_ns(ts_ns);
     }
 
+    /// Visible text of a rectangular region of the last rendered frame.
+    ///
+    /// Out-of-range rectangles clamp to the frame. Rows are joined with
+    /// `\n`, trailing spaces trimmed, wide characters included once.
+    #[wasm_bindgen(js_name = readText)]
+    pub fn read_text(&self, x: u16, y: u16, w: u16, h: u16) -> String {
+        self.inner.read_text(x, y, w, h)
+    }
+
+    /// Visible text of one row of the last rendered frame.
+    #[wasm_bindgen(js_name = readRow)]
+    pub fn read_row(&self, y: u16) -> String {
+        self.inner.read_row(y)
+    }
+
+    /// Visible text of the whole last rendered frame.
+    #[wasm_bindgen(js_name = visibleText)]
+    pub fn visible_text(&self) -> String {
+        self.inner.visible_text()
+    }
+
     /// Parse a JSON-encoded input and push to the event queue.
     /// Returns `true` if accepted, `false` if unsupported/malformed.
     #[wasm_bindgen(js_name = pushEncodedInput)]
@@ -539,6 +571,22 @@ impl ShowcaseRunner {
         self.inner.push_encoded_input(json)
     }
 
+    /// Ask the host to read the system clipboard; returns the request id
+    /// the host echoes back in a `clipboard_result` input.
+    pub fn request_clipboard_read(&mut self) -> u64 {
+        self.inner.request_clipboard_read()
+    }
+
+    /// Ask the host to write text to the system clipboard.
+    pub fn request_clipboard_write(&mut self, text: &str) {
+        self.inner.request_clipboard_write(text);
+    }
+
+    /// Drain pending host commands as a JSON array string.
+    pub fn take_host_commands(&mut self) -> String {
+        self.inner.take_host_commands()
+    }
+
     /// Pane-specific pointer-down path with direct capture semantics.
     ///
     /// `axis`: `0` = horizontal, `1` = vertical.
@@ -0,0 +1,660 @@
+#![forbid(unsafe_code)]
+
+//! Style override cascade with CSS-like specificity.
+//!
+//! A [`StyleCascade`] holds rules keyed by a [`Selector`] (widget kind,
+//! optional widget id, optional screen/scope id, optional
+//! [`StateFlags`] like focused/disabled) so apps get predictable
+//! overriding: "all buttons use accent, except the danger button on this
+//! screen". Widgets call [`resolve`](StyleCascade::resolve) at render
+//! time; rules are pre-indexed by kind so a lookup touches only that
+//! kind's bucket.
+//!
+//! # Specificity
+//!
+//! Conflicts resolve deterministically, most specific wins:
+//!
+//! | rank | selector shape        |
+//! |------|-----------------------|
+//! | 7    | scope + id + state    |
+//! | 6    | scope + id            |
+//! | 5    | scope + state         |
+//! | 4    | scope                 |
+//! | 3    | id + state            |
+//! | 2    | id                    |
+//! | 1    | kind + state          |
+//! | 0    | kind                  |
+//!
+//! (i.e. `scope+id+state > scope+id > id > kind+state > kind >` the
+//! theme-token base style.) Equal specificity ties break by insertion
+//! order, later rules winning. Lower-ranked matches still contribute
+//! properties the winner leaves unset, CSS-style.
+//!
+//! The "why is this blue" question is answered by
+//! [`explain`](StyleCascade::explain), which reports every matching rule
+//! and which one won.
+
+use crate::style::Style;
+use ahash::AHashMap;
+use std::sync::RwLock;
+
+/// Widget interaction states a rule can require.
+///
+/// A rule's states must all be present in the query for the rule to
+/// match (subset semantics); an empty set matches any query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StateFlags(u8);
+
+impl StateFlags {
+    /// Matches regardless of state.
+    pub const NONE: Self = Self(0);
+    /// Widget has keyboard focus.
+    pub const FOCUSED: Self = Self(1 << 0);
+    /// Widget is disabled.
+    pub const DISABLED: Self = Self(1 << 1);
+    /// Pointer is hovering the widget.
+    pub const HOVERED: Self = Self(1 << 2);
+    /// Widget (or row/item) is selected.
+    pub const SELECTED: Self = Self(1 << 3);
+    /// Widget is active/pressed.
+    pub const ACTIVE: Self = Self(1 << 4);
+
+    /// Union of two state sets.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Whether every state in `other` is set in `self`.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether no states are set.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// What a rule applies to: a widget kind plus optional narrowing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    kind: String,
+    id: Option<String>,
+    scope: Option<String>,
+    states: StateFlags,
+}
+
+impl Selector {
+    /// Selector matching every widget of `kind`.
+    #[must_use]
+    pub fn kind(kind: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            id: None,
+            scope: None,
+            states: StateFlags::NONE,
+        }
+    }
+
+    /// Narrow to a specific widget id.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Narrow to a screen/scope id.
+    #[must_use]
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Require interaction states (all of them; adds to any already set).
+    #[must_use]
+    pub fn states(mut self, states: StateFlags) -> Self {
+        self.states = self.states.union(states);
+        self
+    }
+
+    /// Specificity rank (see the module docs table).
+    #[must_use]
+    pub fn specificity(&self) -> u8 {
+        let mut rank = 0;
+        if self.scope.is_some() {
+            rank += 4;
+        }
+        if self.id.is_some() {
+            rank += 2;
+        }
+        if !self.states.is_empty() {
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Whether this selector matches a query (kind already bucketed).
+    fn matches(&self, id: Option<&str>, scope: Option<&str>, states: StateFlags) -> bool {
+        if let Some(want) = self.id.as_deref()
+            && id != Some(want)
+        {
+            return false;
+        }
+        if let Some(want) = self.scope.as_deref()
+            && scope != Some(want)
+        {
+            return false;
+        }
+        states.contains(self.states)
+    }
+}
+
+/// Handle for removing an installed rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleHandle(u64);
+
+/// Result of a cascade lookup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedStyle {
+    /// The merged style (winner's properties over lower matches over the
+    /// base).
+    pub style: Style,
+    /// The most specific matching rule, if any matched.
+    pub winner: Option<RuleHandle>,
+}
+
+/// One matching rule in a [`CascadeExplanation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainEntry {
+    /// Handle of the rule.
+    pub handle: RuleHandle,
+    /// The rule's selector.
+    pub selector: Selector,
+    /// Its specificity rank.
+    pub specificity: u8,
+    /// The style it contributes.
+    pub style: Style,
+}
+
+/// Debug answer to "which rule won for this query".
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CascadeExplanation {
+    /// Matching rules in ascending precedence (the last one wins).
+    pub matched: Vec<ExplainEntry>,
+}
+
+impl CascadeExplanation {
+    /// The winning entry, if any rule matched.
+    #[must_use]
+    pub fn winner(&self) -> Option<&ExplainEntry> {
+        self.matched.last()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CascadeRule {
+    selector: Selector,
+    style: Style,
+    /// Insertion sequence; later rules win specificity ties.
+    seq: u64,
+}
+
+#[derive(Debug, Default)]
+struct CascadeInner {
+    rules: AHashMap<u64, CascadeRule>,
+    /// Pre-index: kind → rule handles (resolution touches one bucket).
+    by_kind: AHashMap<String, Vec<u64>>,
+    next_handle: u64,
+    generation: u64,
+}
+
+/// Mutable rule set resolving widget styles by specificity.
+///
+/// Interior-locked like [`StyleSheet`](crate::StyleSheet), so an app can
+/// install and remove rules at runtime from behind a shared reference
+/// and trigger a repaint when [`generation`](Self::generation) moves.
+#[derive(Debug, Default)]
+pub struct StyleCascade {
+    inner: RwLock<CascadeInner>,
+}
+
+impl StyleCascade {
+    /// Create an empty cascade.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install a rule; returns a handle for later removal.
+    pub fn install(&self, selector: Selector, style: Style) -> RuleHandle {
+        let mut inner = self.inner.write().expect("StyleCascade lock poisoned");
+        let handle = inner.next_handle;
+        inner.next_handle += 1;
+        inner.generation += 1;
+        let seq = handle;
+        inner
+            .by_kind
+            .entry(selector.kind.clone())
+            .or_default()
+            .push(handle);
+        inner.rules.insert(
+            handle,
+            CascadeRule {
+                selector,
+                style,
+                seq,
+            },
+        );
+        RuleHandle(handle)
+    }
+
+    /// Remove an installed rule. Returns `true` if it existed.
+    pub fn remove(&self, handle: RuleHandle) -> bool {
+        let mut inner = self.inner.write().expect("StyleCascade lock poisoned");
+        let Some(rule) = inner.rules.remove(&handle.0) else {
+            return false;
+        };
+        if let Some(bucket) = inner.by_kind.get_mut(&rule.selector.kind) {
+            bucket.retain(|&h| h != handle.0);
+            if bucket.is_empty() {
+                inner.by_kind.remove(&rule.selector.kind);
+            }
+        }
+        inner.generation += 1;
+        true
+    }
+
+    /// Remove every rule.
+    pub fn clear(&self) {
+        let mut inner = self.inner.write().expect("StyleCascade lock poisoned");
+        inner.rules.clear();
+        inner.by_kind.clear();
+        inner.generation += 1;
+    }
+
+    /// Monotonic change counter; bump = repaint needed.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.inner
+            .read()
+            .expect("StyleCascade lock poisoned")
+            .generation
+    }
+
+    /// Number of installed rules.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .expect("StyleCascade lock poisoned")
+            .rules
+            .len()
+    }
+
+    /// Whether no rules are installed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Resolve a widget's style from the default base.
+    ///
+    /// Equivalent to [`resolve_with_base`](Self::resolve_with_base) with
+    /// an empty base; pass the widget's theme-token default as the base
+    /// to get the full documented cascade.
+    #[must_use]
+    pub fn resolve(
+        &self,
+        kind: &str,
+        id: Option<&str>,
+        scope: Option<&str>,
+        states: StateFlags,
+    ) -> ResolvedStyle {
+        self.resolve_with_base(kind, id, scope, states, Style::default())
+    }
+
+    /// Resolve a widget's style over `base` (the theme-token default —
+    /// the cascade's lowest layer).
+    #[must_use]
+    pub fn resolve_with_base(
+        &self,
+        kind: &str,
+        id: Option<&str>,
+        scope: Option<&str>,
+        states: StateFlags,
+        base: Style,
+    ) -> ResolvedStyle {
+        let inner = self.inner.read().expect("StyleCascade lock poisoned");
+        let Some(bucket) = inner.by_kind.get(kind) else {
+            return ResolvedStyle {
+                style: base,
+                winner: None,
+            };
+        };
+
+        // Buckets are small (rules for one widget kind); collect matches
+        // with their precedence key and merge in ascending order.
+        let mut matches: Vec<(u8, u64, &CascadeRule)> = Vec::with_capacity(bucket.len());
+        for &handle in bucket {
+            let Some(rule) = inner.rules.get(&handle) else {
+                continue;
+            };
+            if rule.selector.matches(id, scope, states) {
+                matches.push((rule.selector.specificity(), rule.seq, rule));
+            }
+        }
+        matches.sort_unstable_by_key(|&(spec, seq, _)| (spec, seq));
+
+        let mut style = base;
+        let mut winner = None;
+        for &(_, seq, rule) in &matches {
+            style = rule.style.merge(&style);
+            winner = Some(RuleHandle(seq));
+        }
+        ResolvedStyle { style, winner }
+    }
+
+    /// Explain a query: every matching rule in ascending precedence,
+    /// the last being the winner.
+    #[must_use]
+    pub fn explain(
+        &self,
+        kind: &str,
+        id: Option<&str>,
+        scope: Option<&str>,
+        states: StateFlags,
+    ) -> CascadeExplanation {
+        let inner = self.inner.read().expect("StyleCascade lock poisoned");
+        let Some(bucket) = inner.by_kind.get(kind) else {
+            return CascadeExplanation::default();
+        };
+        let mut matched: Vec<(u8, u64, ExplainEntry)> = Vec::new();
+        for &handle in bucket {
+            let Some(rule) = inner.rules.get(&handle) else {
+                continue;
+            };
+            if rule.selector.matches(id, scope, states) {
+                matched.push((
+                    rule.selector.specificity(),
+                    rule.seq,
+                    ExplainEntry {
+                        handle: RuleHandle(handle),
+                        selector: rule.selector.clone(),
+                        specificity: rule.selector.specificity(),
+                        style: rule.style,
+                    },
+                ));
+            }
+        }
+        matched.sort_unstable_by_key(|&(spec, seq, _)| (spec, seq));
+        CascadeExplanation {
+            matched: matched.into_iter().map(|(_, _, entry)| entry).collect(),
+        }
+    }
+}
+
+impl Clone for StyleCascade {
+    fn clone(&self) -> Self {
+        let inner = self.inner.read().expect("StyleCascade lock poisoned");
+        Self {
+            inner: RwLock::new(CascadeInner {
+                rules: inner.rules.clone(),
+                by_kind: inner.by_kind.clone(),
+                next_handle: inner.next_handle,
+                generation: inner.generation,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::cell::PackedRgba;
+
+    fn fg(r: u8, g: u8, b: u8) -> Style {
+        Style::new().fg(PackedRgba::rgb(r, g, b))
+    }
+
+    fn is_coverage_run() -> bool {
+        std::env::var("LLVM_PROFILE_FILE").is_ok() || std::env::var("CARGO_LLVM_COV").is_ok()
+    }
+
+    #[test]
+    fn kind_rule_applies_to_all_of_kind() {
+        let cascade = StyleCascade::new();
+        cascade.install(Selector::kind("button"), fg(1, 1, 1));
+        let resolved = cascade.resolve("button", Some("x"), Some("s"), StateFlags::NONE);
+        assert_eq!(resolved.style.fg, fg(1, 1, 1).fg);
+        assert!(resolved.winner.is_some());
+        // Other kinds are untouched.
+        let other = cascade.resolve("list", None, None, StateFlags::NONE);
+        assert!(other.style.is_empty());
+        assert!(other.winner.is_none());
+    }
+
+    #[test]
+    fn specificity_matrix_with_conflicting_rules() {
+        let cascade = StyleCascade::new();
+        let kind = cascade.install(Selector::kind("button"), fg(0, 0, 1));
+        let kind_state = cascade.install(
+            Selector::kind("button").states(StateFlags::FOCUSED),
+            fg(0, 0, 2),
+        );
+        let id = cascade.install(Selector::kind("button").id("danger"), fg(0, 0, 3));
+        let id_state = cascade.install(
+            Selector::kind("button")
+                .id("danger")
+                .states(StateFlags::FOCUSED),
+            fg(0, 0, 4),
+        );
+        let scope = cascade.install(Selector::kind("button").scope("settings"), fg(0, 0, 5));
+        let scope_id = cascade.install(
+            Selector::kind("button").id("danger").scope("settings"),
+            fg(0, 0, 6),
+        );
+        let scope_id_state = cascade.install(
+            Selector::kind("button")
+                .id("danger")
+                .scope("settings")
+                .states(StateFlags::FOCUSED),
+            fg(0, 0, 7),
+        );
+
+        // Query shapes chosen so each expected rank is the best match.
+        let cases: &[(Option<&str>, Option<&str>, StateFlags, RuleHandle)] = &[
+            (None, None, StateFlags::NONE, kind),
+            (None, None, StateFlags::FOCUSED, kind_state),
+            (Some("danger"), None, StateFlags::NONE, id),
+            (Some("danger"), None, StateFlags::FOCUSED, id_state),
+            (Some("other"), Some("settings"), StateFlags::NONE, scope),
+            (Some("danger"), Some("settings"), StateFlags::NONE, scope_id),
+            (
+                Some("danger"),
+                Some("settings"),
+                StateFlags::FOCUSED,
+                scope_id_state,
+            ),
+        ];
+        for (id_q, scope_q, states, want) in cases {
+            let resolved = cascade.resolve("button", *id_q, *scope_q, *states);
+            assert_eq!(
+                resolved.winner,
+                Some(*want),
+                "query (id={id_q:?}, scope={scope_q:?}, states={states:?})"
+            );
+        }
+    }
+
+    #[test]
+    fn equal_specificity_later_rule_wins() {
+        let cascade = StyleCascade::new();
+        cascade.install(Selector::kind("button"), fg(1, 0, 0));
+        let later = cascade.install(Selector::kind("button"), fg(0, 1, 0));
+        let resolved = cascade.resolve("button", None, None, StateFlags::NONE);
+        assert_eq!(resolved.winner, Some(later));
+        assert_eq!(resolved.style.fg, fg(0, 1, 0).fg);
+    }
+
+    #[test]
+    fn state_dependent_resolution() {
+        let cascade = StyleCascade::new();
+        cascade.install(Selector::kind("input"), fg(10, 10, 10));
+        cascade.install(
+            Selector::kind("input").states(StateFlags::FOCUSED),
+            fg(20, 20, 20),
+        );
+
+        let blurred = cascade.resolve("input", None, None, StateFlags::NONE);
+        assert_eq!(blurred.style.fg, fg(10, 10, 10).fg);
+
+        let focused = cascade.resolve("input", None, None, StateFlags::FOCUSED);
+        assert_eq!(focused.style.fg, fg(20, 20, 20).fg);
+
+        // A rule requiring two states does not match just one.
+        cascade.install(
+            Selector::kind("input").states(StateFlags::FOCUSED.union(StateFlags::DISABLED)),
+            fg(30, 30, 30),
+        );
+        let focused_only = cascade.resolve("input", None, None, StateFlags::FOCUSED);
+        assert_eq!(focused_only.style.fg, fg(20, 20, 20).fg);
+        let both = cascade.resolve(
+            "input",
+            None,
+            None,
+            StateFlags::FOCUSED.union(StateFlags::DISABLED),
+        );
+        assert_eq!(both.style.fg, fg(30, 30, 30).fg);
+    }
+
+    #[test]
+    fn lower_matches_fill_unset_properties() {
+        let cascade = StyleCascade::new();
+        cascade.install(Selector::kind("button"), Style::new().bold());
+        cascade.install(Selector::kind("button").id("danger"), fg(9, 9, 9));
+        let resolved = cascade.resolve("button", Some("danger"), None, StateFlags::NONE);
+        // Winner sets fg; the kind rule still contributes bold.
+        assert_eq!(resolved.style.fg, fg(9, 9, 9).fg);
+        assert!(resolved.style.has_attr(crate::style::StyleFlags::BOLD));
+    }
+
+    #[test]
+    fn base_is_lowest_layer() {
+        let cascade = StyleCascade::new();
+        cascade.install(Selector::kind("button"), Style::new().bold());
+        let base = fg(7, 7, 7);
+        let resolved = cascade.resolve_with_base("button", None, None, StateFlags::NONE, base);
+        assert_eq!(resolved.style.fg, base.fg, "theme token survives");
+        assert!(resolved.style.has_attr(crate::style::StyleFlags::BOLD));
+    }
+
+    #[test]
+    fn removal_takes_effect_and_bumps_generation() {
+        let cascade = StyleCascade::new();
+        let handle = cascade.install(Selector::kind("button"), fg(1, 2, 3));
+        let gen_installed = cascade.generation();
+        assert_eq!(
+            cascade
+                .resolve("button", None, None, StateFlags::NONE)
+                .winner,
+            Some(handle)
+        );
+
+        assert!(cascade.remove(handle));
+        assert!(cascade.generation() > gen_installed, "repaint trigger");
+        let resolved = cascade.resolve("button", None, None, StateFlags::NONE);
+        assert!(resolved.winner.is_none());
+        assert!(resolved.style.is_empty());
+        // Double remove is a no-op.
+        assert!(!cascade.remove(handle));
+    }
+
+    #[test]
+    fn explain_reports_matches_and_winner() {
+        let cascade = StyleCascade::new();
+        let kind = cascade.install(Selector::kind("button"), fg(1, 0, 0));
+        let id = cascade.install(Selector::kind("button").id("danger"), fg(2, 0, 0));
+        cascade.install(Selector::kind("button").scope("other"), fg(3, 0, 0));
+
+        let explanation = cascade.explain("button", Some("danger"), None, StateFlags::NONE);
+        assert_eq!(explanation.matched.len(), 2, "scope rule does not match");
+        assert_eq!(explanation.matched[0].handle, kind);
+        assert_eq!(explanation.matched[0].specificity, 0);
+        let winner = explanation.winner().expect("winner");
+        assert_eq!(winner.handle, id);
+        assert_eq!(winner.specificity, 2);
+        assert_eq!(winner.style.fg, fg(2, 0, 0).fg);
+
+        // Explain agrees with resolve.
+        let resolved = cascade.resolve("button", Some("danger"), None, StateFlags::NONE);
+        assert_eq!(resolved.winner, Some(id));
+    }
+
+    #[test]
+    fn explain_empty_for_unknown_kind() {
+        let cascade = StyleCascade::new();
+        let explanation = cascade.explain("nope", None, None, StateFlags::NONE);
+        assert!(explanation.matched.is_empty());
+        assert!(explanation.winner().is_none());
+    }
+
+    #[test]
+    fn perf_resolve_10k_queries_per_frame() {
+        let cascade = StyleCascade::new();
+        // A realistic sheet: 10 kinds × 10 rules of mixed specificity.
+        for kind_idx in 0..10 {
+            let kind = format!("kind{kind_idx}");
+            for rule_idx in 0..10u8 {
+                let mut selector = Selector::kind(&kind);
+                if rule_idx % 2 == 0 {
+                    selector = selector.id(format!("id{rule_idx}"));
+                }
+                if rule_idx % 3 == 0 {
+                    selector = selector.scope("screen");
+                }
+                if rule_idx % 4 == 0 {
+                    selector = selector.states(StateFlags::FOCUSED);
+                }
+                cascade.install(selector, fg(rule_idx, rule_idx, rule_idx));
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let mut acc = 0usize;
+        for i in 0..10_000 {
+            let kind = format!("kind{}", i % 10);
+            let resolved = cascade.resolve(
+                &kind,
+                Some("id2"),
+                Some("screen"),
+                if i % 2 == 0 {
+                    StateFlags::FOCUSED
+                } else {
+                    StateFlags::NONE
+                },
+            );
+            acc += usize::from(resolved.winner.is_some());
+        }
+        let elapsed = start.elapsed();
+        assert!(acc > 0);
+        let budget_ms: u128 = if is_coverage_run() { 200 } else { 100 };
+        assert!(
+            elapsed.as_millis() < budget_ms,
+            "10k resolves took {}ms (budget: {budget_ms}ms)",
+            elapsed.as_millis()
+        );
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let cascade = StyleCascade::new();
+        cascade.install(Selector::kind("a"), fg(1, 1, 1));
+        let cloned = cascade.clone();
+        cascade.install(Selector::kind("b"), fg(2, 2, 2));
+        assert_eq!(cascade.len(), 2);
+        assert_eq!(cloned.len(), 1);
+    }
+}
@@ -21,6 +21,7 @@
 //! deterministic and reusable.
 
 /// Color types, profiles, and downgrade utilities.
+pub mod cascade;
 pub mod color;
 /// Style types with CSS-like cascading semantics.
 pub mod style;
@@ -31,6 +32,9 @@ pub mod table_theme;
 /// Theme system with semantic color slots.
 pub mod theme;
 
+pub use cascade::{
+    CascadeExplanation, ExplainEntry, ResolvedStyle, RuleHandle, Selector, StateFlags, StyleCascade,
+};
 pub use color::{
     // Color types
     Ansi16,
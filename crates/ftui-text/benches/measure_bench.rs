@@ -0,0 +1,55 @@
+//! Wrap-measurement memoization benchmark.
+//!
+//! Run with: cargo bench -p ftui-text --bench measure_bench
+//!
+//! Simulates re-rendering a 1000-row list with unchanged content every
+//! frame: the uncached path re-wraps each row, the cached path serves
+//! them from [`MeasureCache`] after the first frame.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use ftui_text::{MeasureCache, WrapOptions, wrap_with_options};
+use std::hint::black_box;
+
+fn list_rows() -> Vec<String> {
+    (0..1000)
+        .map(|i| {
+            format!(
+                "row {i}: some moderately long list entry text that needs \
+                 wrapping at the viewport width — item number {i}"
+            )
+        })
+        .collect()
+}
+
+fn bench_rerender(c: &mut Criterion) {
+    let rows = list_rows();
+    let options = WrapOptions::new(38);
+
+    let mut group = c.benchmark_group("rerender_1000_rows");
+
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for row in &rows {
+                black_box(wrap_with_options(black_box(row), &options));
+            }
+        });
+    });
+
+    group.bench_function("cached_warm", |b| {
+        let mut cache = MeasureCache::new(4 * 1024 * 1024);
+        // Warm frame.
+        for row in &rows {
+            let _ = cache.wrap_with_options(row, &options);
+        }
+        b.iter(|| {
+            for row in &rows {
+                black_box(cache.wrap_with_options(black_box(row), &options));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_rerender);
+criterion_main!(benches);
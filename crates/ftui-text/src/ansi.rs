@@ -0,0 +1,489 @@
+#![forbid(unsafe_code)]
+
+//! ANSI-escape-aware text ingestion.
+//!
+//! Cargo output, ripgrep results, and CI logs arrive pre-colored with
+//! SGR escapes; [`ansi_to_spans`] converts such text into styled
+//! [`Line`]s so widgets display the colors intact instead of corrupting
+//! the cell grid. SGR covers 16/256/truecolor fg/bg plus the common
+//! attributes (bold, dim, italic, underline, blink, reverse,
+//! strikethrough) with reset and targeted resets; OSC 8 hyperlinks
+//! become span links; every other escape (cursor movement, other OSC) is
+//! skipped. Malformed or truncated sequences are dropped without losing
+//! the text that follows.
+//!
+//! [`AnsiParser`] is the streaming variant: SGR and link state — and
+//! even a partially received escape sequence — carry across chunk
+//! boundaries, which is what log viewers and markdown streamers need.
+//! Width and wrapping then work through the normal text pipeline.
+
+use crate::text::{Line, Span};
+use ftui_render::cell::PackedRgba;
+use ftui_style::{Style, StyleFlags};
+
+/// Convert ANSI-colored text into styled lines.
+///
+/// One-shot wrapper over [`AnsiParser`]; a trailing line without a
+/// newline is included.
+#[must_use]
+pub fn ansi_to_spans(input: &str) -> Vec<Line<'static>> {
+    let mut parser = AnsiParser::new();
+    let mut lines = parser.feed(input);
+    if let Some(last) = parser.finish() {
+        lines.push(last);
+    }
+    lines
+}
+
+/// Escape-sequence scanner state, kept across chunk boundaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ScanState {
+    Ground,
+    /// Saw ESC; dispatch byte pending.
+    Escape,
+    /// Inside CSI; accumulating parameter/intermediate bytes.
+    Csi(String),
+    /// Inside an OSC string; accumulating its payload.
+    Osc(String),
+    /// Inside an OSC string and saw ESC (ST pending).
+    OscEscape(String),
+}
+
+/// Streaming ANSI-to-spans parser.
+///
+/// [`feed`](Self::feed) returns the lines completed by the chunk;
+/// unterminated text (and any partially received escape sequence) stays
+/// buffered so the next chunk continues seamlessly. Call
+/// [`finish`](Self::finish) for the final unterminated line.
+#[derive(Debug, Clone)]
+pub struct AnsiParser {
+    state: ScanState,
+    /// Current SGR style applied to new text.
+    style: Style,
+    /// Current OSC 8 hyperlink target.
+    link: Option<String>,
+    /// Text of the in-progress span.
+    run: String,
+    /// Spans of the in-progress line.
+    spans: Vec<Span<'static>>,
+}
+
+impl Default for AnsiParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnsiParser {
+    /// A parser in the ground state with default style.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: ScanState::Ground,
+            style: Style::default(),
+            link: None,
+            run: String::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Feed a chunk; returns the lines it completed.
+    pub fn feed(&mut self, chunk: &str) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        for ch in chunk.chars() {
+            match std::mem::replace(&mut self.state, ScanState::Ground) {
+                ScanState::Ground => match ch {
+                    '\u{1b}' => self.state = ScanState::Escape,
+                    '\n' => {
+                        self.flush_run();
+                        lines.push(Line::from_spans(std::mem::take(&mut self.spans)));
+                    }
+                    '\r' => {
+                        // Swallow CR (CRLF line endings, progress lines).
+                    }
+                    _ => self.run.push(ch),
+                },
+                ScanState::Escape => match ch {
+                    '[' => self.state = ScanState::Csi(String::new()),
+                    ']' => self.state = ScanState::Osc(String::new()),
+                    // Other two-byte escapes (RIS, charset, ...): skip.
+                    _ => {}
+                },
+                ScanState::Csi(mut buf) => {
+                    if ('\u{40}'..='\u{7e}').contains(&ch) {
+                        if ch == 'm' {
+                            self.flush_run();
+                            self.apply_sgr(&buf);
+                        }
+                        // Every other CSI (cursor movement, erase, ...)
+                        // is skipped.
+                    } else if ch.is_ascii_digit() || ch == ';' || ch == ':' || ch == '?' {
+                        // Bound accumulation so hostile input cannot grow
+                        // the buffer without limit. Overflow poisons the
+                        // whole sequence — a truncated parameter must not
+                        // parse as some other SGR code.
+                        if buf.starts_with('~') {
+                            // Poisoned: ignore until the final byte.
+                        } else if buf.len() < 64 {
+                            buf.push(ch);
+                        } else {
+                            buf = "~".to_string();
+                        }
+                        self.state = ScanState::Csi(buf);
+                    } else {
+                        // Malformed CSI: drop the sequence, keep the text.
+                        self.run.push(ch);
+                    }
+                }
+                ScanState::Osc(mut buf) => match ch {
+                    '\u{7}' => {
+                        self.flush_run();
+                        self.apply_osc(&buf);
+                    }
+                    '\u{1b}' => self.state = ScanState::OscEscape(buf),
+                    _ => {
+                        if buf.len() < 2048 {
+                            buf.push(ch);
+                        }
+                        self.state = ScanState::Osc(buf);
+                    }
+                },
+                ScanState::OscEscape(buf) => {
+                    if ch == '\\' {
+                        self.flush_run();
+                        self.apply_osc(&buf);
+                    } else {
+                        // Not a terminator: the ESC aborts the OSC.
+                        self.state = ScanState::Escape;
+                        // Re-dispatch this char in the Escape state.
+                        match ch {
+                            '[' => self.state = ScanState::Csi(String::new()),
+                            ']' => self.state = ScanState::Osc(String::new()),
+                            _ => self.state = ScanState::Ground,
+                        }
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    /// Finish the stream, returning the trailing unterminated line.
+    ///
+    /// A partially received escape sequence is dropped (truncated input
+    /// must not lose the text before it).
+    #[must_use]
+    pub fn finish(mut self) -> Option<Line<'static>> {
+        self.flush_run();
+        if self.spans.is_empty() {
+            None
+        } else {
+            Some(Line::from_spans(self.spans))
+        }
+    }
+
+    /// Close the current text run into a span.
+    fn flush_run(&mut self) {
+        if self.run.is_empty() {
+            return;
+        }
+        let content = std::mem::take(&mut self.run);
+        let mut span = if self.style == Style::default() {
+            Span::raw(content)
+        } else {
+            Span::styled(content, self.style)
+        };
+        if let Some(link) = &self.link {
+            span = span.link(link.clone());
+        }
+        self.spans.push(span);
+    }
+
+    /// Apply an SGR parameter string (the bytes between `CSI` and `m`).
+    fn apply_sgr(&mut self, params: &str) {
+        if params.is_empty() {
+            self.style = Style::default();
+            return;
+        }
+        // Colon sub-parameters (e.g. 38:2:...) are treated like
+        // semicolons; saturating parse bounds hostile values.
+        let mut iter = params
+            .split([';', ':'])
+            .map(|p| p.parse::<u16>().unwrap_or(u16::MAX));
+        while let Some(code) = iter.next() {
+            match code {
+                0 => self.style = Style::default(),
+                1 => self.style = self.style.bold(),
+                2 => self.style = self.style.dim(),
+                3 => self.style = self.style.italic(),
+                4 => self.style = self.style.underline(),
+                5 | 6 => self.style = self.style.blink(),
+                7 => self.style = self.style.reverse(),
+                9 => self.style = self.style.strikethrough(),
+                21 | 22 => self.remove_attr(StyleFlags::BOLD.union(StyleFlags::DIM)),
+                23 => self.remove_attr(StyleFlags::ITALIC),
+                24 => self.remove_attr(StyleFlags::UNDERLINE),
+                25 => self.remove_attr(StyleFlags::BLINK),
+                27 => self.remove_attr(StyleFlags::REVERSE),
+                29 => self.remove_attr(StyleFlags::STRIKETHROUGH),
+                30..=37 => self.style.fg = Some(basic_color(code - 30, false)),
+                38 => {
+                    if let Some(color) = extended_color(&mut iter) {
+                        self.style.fg = Some(color);
+                    }
+                }
+                39 => self.style.fg = None,
+                40..=47 => self.style.bg = Some(basic_color(code - 40, false)),
+                48 => {
+                    if let Some(color) = extended_color(&mut iter) {
+                        self.style.bg = Some(color);
+                    }
+                }
+                49 => self.style.bg = None,
+                90..=97 => self.style.fg = Some(basic_color(code - 90, true)),
+                100..=107 => self.style.bg = Some(basic_color(code - 100, true)),
+                _ => {}
+            }
+        }
+    }
+
+    fn remove_attr(&mut self, flags: StyleFlags) {
+        if let Some(attrs) = &mut self.style.attrs {
+            attrs.remove(flags);
+        }
+    }
+
+    /// Apply an OSC payload; only OSC 8 hyperlinks matter.
+    fn apply_osc(&mut self, payload: &str) {
+        if let Some(rest) = payload.strip_prefix("8;") {
+            // "8;params;URI" — empty URI closes the link.
+            let uri = rest.split_once(';').map_or("", |(_, uri)| uri);
+            self.link = if uri.is_empty() {
+                None
+            } else {
+                Some(uri.to_string())
+            };
+        }
+    }
+}
+
+/// One of the 16 basic ANSI colors (xterm defaults).
+fn basic_color(index: u16, bright: bool) -> PackedRgba {
+    const NORMAL: [(u8, u8, u8); 8] = [
+        (0, 0, 0),
+        (170, 0, 0),
+        (0, 170, 0),
+        (170, 85, 0),
+        (0, 0, 170),
+        (170, 0, 170),
+        (0, 170, 170),
+        (170, 170, 170),
+    ];
+    const BRIGHT: [(u8, u8, u8); 8] = [
+        (85, 85, 85),
+        (255, 85, 85),
+        (85, 255, 85),
+        (255, 255, 85),
+        (85, 85, 255),
+        (255, 85, 255),
+        (85, 255, 255),
+        (255, 255, 255),
+    ];
+    let (r, g, b) = if bright {
+        BRIGHT[usize::from(index.min(7))]
+    } else {
+        NORMAL[usize::from(index.min(7))]
+    };
+    PackedRgba::rgb(r, g, b)
+}
+
+/// Parse the tail of a 38/48 extended-color sequence (5;n or 2;r;g;b).
+fn extended_color(iter: &mut impl Iterator<Item = u16>) -> Option<PackedRgba> {
+    match iter.next()? {
+        5 => Some(color_256(iter.next()?)),
+        2 => {
+            let clamp = |v: u16| v.min(255) as u8;
+            Some(PackedRgba::rgb(
+                clamp(iter.next()?),
+                clamp(iter.next()?),
+                clamp(iter.next()?),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Map a 256-color palette index to RGB (xterm layout).
+fn color_256(index: u16) -> PackedRgba {
+    match index {
+        0..=7 => basic_color(index, false),
+        8..=15 => basic_color(index - 8, true),
+        16..=231 => {
+            let n = index - 16;
+            let to_rgb = |v: u16| if v == 0 { 0u8 } else { (55 + 40 * v) as u8 };
+            PackedRgba::rgb(to_rgb(n / 36), to_rgb((n / 6) % 6), to_rgb(n % 6))
+        }
+        232..=255 => {
+            let v = (8 + 10 * (index - 232)) as u8;
+            PackedRgba::rgb(v, v, v)
+        }
+        _ => PackedRgba::rgb(170, 170, 170),
+    }
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn red() -> PackedRgba {
+        PackedRgba::rgb(170, 0, 0)
+    }
+    fn green() -> PackedRgba {
+        PackedRgba::rgb(0, 170, 0)
+    }
+
+    #[test]
+    fn cargo_style_fixture_golden() {
+        // Typical cargo diagnostic: bold green status + plain text.
+        let lines = ansi_to_spans("\x1b[1m\x1b[32m   Compiling\x1b[0m ftui v0.1\n");
+        assert_eq!(lines.len(), 1);
+        let spans = lines[0].spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].as_str(), "   Compiling");
+        let style = spans[0].style.expect("styled");
+        assert_eq!(style.fg, Some(green()));
+        assert!(style.has_attr(StyleFlags::BOLD));
+        assert_eq!(spans[1].as_str(), " ftui v0.1");
+        assert!(spans[1].style.is_none());
+    }
+
+    #[test]
+    fn ripgrep_style_fixture_golden() {
+        // rg: magenta path, green line number, red match.
+        let input = "\x1b[35msrc/main.rs\x1b[0m:\x1b[32m7\x1b[0m:let \x1b[31mfoo\x1b[0m = 1;\n";
+        let lines = ansi_to_spans(input);
+        let spans = lines[0].spans();
+        let texts: Vec<&str> = spans.iter().map(|s| s.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["src/main.rs", ":", "7", ":let ", "foo", " = 1;"]
+        );
+        assert_eq!(
+            spans[0].style.unwrap().fg,
+            Some(PackedRgba::rgb(170, 0, 170))
+        );
+        assert_eq!(spans[2].style.unwrap().fg, Some(green()));
+        assert_eq!(spans[4].style.unwrap().fg, Some(red()));
+        assert!(spans[5].style.is_none());
+    }
+
+    #[test]
+    fn truecolor_and_256_colors() {
+        let lines = ansi_to_spans("\x1b[38;2;1;2;3mrgb\x1b[48;5;196mbg\x1b[0m");
+        let spans = lines[0].spans();
+        assert_eq!(spans[0].style.unwrap().fg, Some(PackedRgba::rgb(1, 2, 3)));
+        let bg = spans[1].style.unwrap().bg.unwrap();
+        assert_eq!(bg, PackedRgba::rgb(255, 0, 0), "palette 196 is pure red");
+    }
+
+    #[test]
+    fn targeted_resets() {
+        let lines = ansi_to_spans("\x1b[1;4;31mx\x1b[24my\x1b[39mz");
+        let spans = lines[0].spans();
+        assert!(spans[0].style.unwrap().has_attr(StyleFlags::UNDERLINE));
+        let y = spans[1].style.unwrap();
+        assert!(!y.has_attr(StyleFlags::UNDERLINE), "24 clears underline");
+        assert!(y.has_attr(StyleFlags::BOLD), "bold survives");
+        assert_eq!(y.fg, Some(red()));
+        let z = spans[2].style.unwrap();
+        assert_eq!(z.fg, None, "39 resets fg only");
+        assert!(z.has_attr(StyleFlags::BOLD));
+    }
+
+    #[test]
+    fn osc8_hyperlinks_become_span_links() {
+        let input = "see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\ now";
+        let lines = ansi_to_spans(input);
+        let spans = lines[0].spans();
+        assert_eq!(spans[0].as_str(), "see ");
+        assert_eq!(spans[0].link, None);
+        assert_eq!(spans[1].as_str(), "docs");
+        assert_eq!(spans[1].link.as_deref(), Some("https://example.com"));
+        assert_eq!(spans[2].as_str(), " now");
+        assert_eq!(spans[2].link, None);
+    }
+
+    #[test]
+    fn cursor_movement_and_unknown_osc_are_skipped() {
+        let lines = ansi_to_spans("a\x1b[2Jb\x1b[10;20Hc\x1b]0;title\x07d");
+        assert_eq!(lines[0].to_plain_text(), "abcd");
+    }
+
+    #[test]
+    fn state_carries_across_chunk_split_mid_sequence() {
+        let mut parser = AnsiParser::new();
+        // Split in the middle of the SGR sequence.
+        let mut lines = parser.feed("\x1b[3");
+        assert!(lines.is_empty());
+        lines.extend(parser.feed("1mred\x1b[0m plain\nnext"));
+        assert_eq!(lines.len(), 1);
+        let spans = lines[0].spans();
+        assert_eq!(spans[0].as_str(), "red");
+        assert_eq!(spans[0].style.unwrap().fg, Some(red()));
+        assert_eq!(spans[1].as_str(), " plain");
+
+        // SGR state itself persists across chunks too.
+        let mut parser = AnsiParser::new();
+        let _ = parser.feed("\x1b[32m");
+        let _ = parser.feed("still ");
+        let last = parser.finish().expect("trailing line");
+        assert_eq!(last.spans()[0].style.unwrap().fg, Some(green()));
+    }
+
+    #[test]
+    fn hostile_input_does_not_panic_or_eat_text() {
+        // Unterminated CSI at end of input: text before it survives.
+        let lines = ansi_to_spans("before\x1b[12;34");
+        assert_eq!(lines[0].to_plain_text(), "before");
+
+        // Malformed CSI aborts the sequence but keeps the next char.
+        let lines = ansi_to_spans("a\x1b[1\u{1f600}b");
+        assert_eq!(lines[0].to_plain_text(), "a\u{1f600}b");
+
+        // Huge parameter values saturate instead of overflowing.
+        let lines = ansi_to_spans("\x1b[99999999999999999999mtext");
+        assert_eq!(lines[0].to_plain_text(), "text");
+
+        // Endless parameters are bounded AND inert: the truncated tail
+        // must not misparse into some other SGR code.
+        let long = format!("\x1b[{}4mspam", "1;".repeat(10_000));
+        let lines = ansi_to_spans(&long);
+        assert_eq!(lines[0].to_plain_text(), "spam");
+        assert!(
+            lines[0].spans()[0].style.is_none(),
+            "overflowed sequence applies nothing"
+        );
+
+        // Bare ESC: the dispatch byte is consumed, the rest survives.
+        let lines = ansi_to_spans("\x1b zok");
+        assert_eq!(lines[0].to_plain_text(), "zok");
+    }
+
+    #[test]
+    fn crlf_and_trailing_line_handling() {
+        let lines = ansi_to_spans("one\r\ntwo\nthree");
+        let texts: Vec<String> = lines.iter().map(Line::to_plain_text).collect();
+        assert_eq!(texts, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn empty_lines_preserved() {
+        let lines = ansi_to_spans("a\n\nb\n");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].to_plain_text(), "");
+    }
+}
@@ -32,8 +32,16 @@ use crate::rope::Rope;
 /// A single edit operation for undo/redo.
 #[derive(Debug, Clone)]
 enum EditOp {
-    Insert { byte_offset: usize, text: String },
-    Delete { byte_offset: usize, text: String },
+    Insert {
+        byte_offset: usize,
+        text: String,
+    },
+    Delete {
+        byte_offset: usize,
+        text: String,
+    },
+    /// Several operations applied (and undone) as one atomic step.
+    Group(Vec<EditOp>),
 }
 
 impl EditOp {
@@ -47,6 +55,8 @@ impl EditOp {
                 byte_offset: *byte_offset,
                 text: text.clone(),
             },
+            // Undoing a group runs the inverses in reverse order.
+            Self::Group(ops) => Self::Group(ops.iter().rev().map(EditOp::inverse).collect()),
         }
     }
 }
@@ -99,6 +109,10 @@ pub struct Editor {
     current_undo_size: usize,
     /// Maximum size of undo history in bytes (default 10MB).
     max_undo_size: usize,
+    /// Open undo group: accumulated ops + cursor at group start.
+    /// Nested `begin_undo_group` calls are counted so helper methods can
+    /// group safely inside an outer group.
+    open_group: Option<(Vec<EditOp>, CursorPosition, usize)>,
 }
 
 impl Default for Editor {
@@ -120,6 +134,7 @@ impl Editor {
             max_history: 1000,
             current_undo_size: 0,
             max_undo_size: 10 * 1024 * 1024, // 10MB default
+            open_group: None,
         }
     }
 
@@ -138,6 +153,7 @@ impl Editor {
             max_history: 1000,
             current_undo_size: 0,
             max_undo_size: 10 * 1024 * 1024,
+            open_group: None,
         }
     }
 
@@ -187,6 +203,21 @@ impl Editor {
         self.selection
     }
 
+    /// Set (or clear) the selection directly, clamping both ends.
+    ///
+    /// The cursor follows the selection head, matching the keyboard
+    /// `select_*` operations.
+    pub fn set_selection(&mut self, selection: Option<Selection>) {
+        let nav = CursorNavigator::new(&self.rope);
+        self.selection = selection.map(|sel| Selection {
+            anchor: nav.clamp(sel.anchor),
+            head: nav.clamp(sel.head),
+        });
+        if let Some(sel) = self.selection {
+            self.cursor = sel.head;
+        }
+    }
+
     /// Whether undo is available.
     #[must_use]
     pub fn can_undo(&self) -> bool {
@@ -416,9 +447,22 @@ impl Editor {
     // ====================================================================
 
     /// Push an edit operation onto the undo stack.
+    ///
+    /// While an undo group is open the operation accumulates into the
+    /// group instead (the redo stack is still invalidated immediately).
     fn push_undo(&mut self, op: EditOp) {
+        if let Some((ops, _, _)) = self.open_group.as_mut() {
+            ops.push(op);
+            self.redo_stack.clear();
+            return;
+        }
+        self.push_undo_entry(op, self.cursor);
+    }
+
+    /// Push a finished entry, applying the count and size limits.
+    fn push_undo_entry(&mut self, op: EditOp, cursor_before: CursorPosition) {
         let op_len = op.byte_len();
-        self.undo_stack.push((op, self.cursor));
+        self.undo_stack.push((op, cursor_before));
         self.current_undo_size += op_len;
 
         // Prune by count
@@ -491,7 +535,52 @@ impl Editor {
                 let end_char = self.rope.byte_to_char(*byte_offset + text.len());
                 self.rope.remove(start_char..end_char);
             }
+            EditOp::Group(ops) => {
+                for op in ops {
+                    self.apply_op(op);
+                }
+            }
+        }
+    }
+
+    // ====================================================================
+    // Undo grouping
+    // ====================================================================
+
+    /// Start grouping subsequent edits into one atomic undo step.
+    ///
+    /// Every edit until the matching [`end_undo_group`](Self::end_undo_group)
+    /// is undone (and redone) together. Calls nest: only the outermost
+    /// `end_undo_group` closes the group. The cursor restored by undo is
+    /// the position at the outermost `begin_undo_group`.
+    pub fn begin_undo_group(&mut self) {
+        match self.open_group.as_mut() {
+            Some((_, _, depth)) => *depth += 1,
+            None => self.open_group = Some((Vec::new(), self.cursor, 1)),
+        }
+    }
+
+    /// Close the current undo group, committing it as one undo step.
+    ///
+    /// An empty group (no edits recorded) leaves the undo stack untouched;
+    /// a single-edit group is pushed without the group wrapper.
+    pub fn end_undo_group(&mut self) {
+        let Some((_, _, depth)) = self.open_group.as_mut() else {
+            return;
+        };
+        if *depth > 1 {
+            *depth -= 1;
+            return;
         }
+        let Some((mut ops, cursor_before, _)) = self.open_group.take() else {
+            return;
+        };
+        let op = match ops.len() {
+            0 => return,
+            1 => ops.pop().expect("len checked"),
+            _ => EditOp::Group(ops),
+        };
+        self.push_undo_entry(op, cursor_before);
     }
 
     // ====================================================================
@@ -710,6 +799,7 @@ impl EditOp {
         match self {
             Self::Insert { text, .. } => text.len(),
             Self::Delete { text, .. } => text.len(),
+            Self::Group(ops) => ops.iter().map(EditOp::byte_len).sum(),
         }
     }
 }
@@ -1772,4 +1862,54 @@ mod proptests {
             prop_assert_eq!(c.line, last_line);
         }
     }
+
+    // ====================================================================
+    // Undo grouping
+    // ====================================================================
+
+    #[test]
+    fn grouped_edits_undo_as_one_step() {
+        let mut ed = Editor::with_text("one two");
+        ed.set_cursor(CursorPosition::default());
+        ed.begin_undo_group();
+        ed.insert_text(">");
+        ed.move_to_line_end();
+        ed.insert_text("<");
+        ed.end_undo_group();
+        assert_eq!(ed.text(), ">one two<");
+
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "one two", "both inserts reverted together");
+        assert!(ed.redo());
+        assert_eq!(ed.text(), ">one two<");
+    }
+
+    #[test]
+    fn empty_and_single_op_groups_stay_lean() {
+        let mut ed = Editor::with_text("x");
+        ed.begin_undo_group();
+        ed.end_undo_group();
+        assert!(!ed.can_undo(), "empty group leaves no undo entry");
+
+        ed.begin_undo_group();
+        ed.insert_text("y");
+        ed.end_undo_group();
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "x");
+    }
+
+    #[test]
+    fn nested_groups_close_with_the_outermost_end() {
+        let mut ed = Editor::new();
+        ed.begin_undo_group();
+        ed.insert_text("a");
+        ed.begin_undo_group();
+        ed.insert_text("b");
+        ed.end_undo_group();
+        ed.insert_text("c");
+        ed.end_undo_group();
+        assert_eq!(ed.text(), "abc");
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "", "one step for the whole nest");
+    }
 }
@@ -56,6 +56,7 @@
 
 pub mod cursor;
 pub mod editor;
+pub mod measure_cache;
 pub mod rope;
 pub mod segment;
 pub mod text;
@@ -66,6 +67,9 @@ pub mod wrap;
 pub mod hyphenation;
 pub mod incremental_break;
 
+#[cfg(feature = "ansi")]
+pub mod ansi;
+
 #[cfg(feature = "markup")]
 pub mod markup;
 
@@ -133,6 +137,8 @@ impl TextMeasurement {
     }
 }
 
+#[cfg(feature = "ansi")]
+pub use ansi::{AnsiParser, ansi_to_spans};
 pub use cluster_map::{ClusterEntry, ClusterMap};
 pub use cursor::{CursorNavigator, CursorPosition};
 pub use editor::{Editor, Selection};
@@ -145,6 +151,7 @@ pub use justification::{
     GlueSpec, JustificationControl, JustifyMode, SUBCELL_SCALE, SpaceCategory, SpacePenalty,
 };
 pub use layout_policy::{LayoutPolicy, LayoutTier, PolicyError, ResolvedPolicy, RuntimeCapability};
+pub use measure_cache::{MeasureCache, MeasureCacheStats, MeasuredWrap};
 pub use rope::Rope;
 pub use script_segmentation::{
     RunCacheKey, RunDirection, Script, ScriptRun, TextRun, partition_by_script, partition_text_runs,
@@ -169,10 +176,10 @@ pub use width_cache::{
     TinyLfuWidthCache, WidthCache,
 };
 pub use wrap::{
-    KpBreakResult, WrapMode, WrapOptions, ascii_width, display_width, grapheme_count,
-    grapheme_width, graphemes, has_wide_chars, is_ascii_only, truncate_to_width,
-    truncate_to_width_with_info, truncate_with_ellipsis, word_boundaries, word_segments,
-    wrap_optimal, wrap_text, wrap_text_optimal, wrap_with_options,
+    KpBreakResult, Overflow, WordBreak, WrapMode, WrapOptions, WrapPolicy, ascii_width,
+    display_width, grapheme_count, grapheme_width, graphemes, has_wide_chars, is_ascii_only,
+    truncate_to_width, truncate_to_width_with_info, truncate_with_ellipsis, word_boundaries,
+    word_segments, wrap_optimal, wrap_text, wrap_text_optimal, wrap_text_policy, wrap_with_options,
 };
 
 #[cfg(feature = "markup")]
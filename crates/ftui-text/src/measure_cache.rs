@@ -0,0 +1,377 @@
+#![forbid(unsafe_code)]
+
+//! Memoized wrap/measure results for hot render paths.
+//!
+//! Text-heavy screens re-wrap the same strings every frame (list rows,
+//! headers); [`MeasureCache`] memoizes [`wrap_with_options`] and
+//! [`wrap_text_policy`] results behind an opt-in handle, so the pure
+//! functions stay available unchanged. Entries are keyed by a content
+//! hash plus *every* input that affects the result — width, mode/policy
+//! fields, indent and trim flags — so stale entries are impossible by
+//! construction; changing any knob lands on a different key.
+//!
+//! Eviction is LRU under a byte budget (measured as the cached lines'
+//! payload bytes), and hit/miss counters feed perf telemetry. Results
+//! are returned as `Arc` so a hit costs a pointer bump, not a clone of
+//! every line.
+//!
+//! Like [`WidthCache`](crate::WidthCache), keys are 64-bit FxHash values
+//! rather than stored strings: collisions are astronomically unlikely
+//! (~1 in 2^64) and the memory saving matters at render rates.
+
+use std::sync::Arc;
+
+use lru::LruCache;
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::wrap::{WrapOptions, WrapPolicy, display_width, wrap_text_policy, wrap_with_options};
+
+/// Default byte budget (1 MiB of cached line payloads).
+pub const DEFAULT_BYTE_BUDGET: usize = 1024 * 1024;
+
+/// A memoized wrap result: the broken lines plus their display widths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeasuredWrap {
+    /// Wrapped lines, exactly as the uncached entry point returns them.
+    pub lines: Vec<String>,
+    /// Display width (cells) of each line.
+    pub widths: Vec<usize>,
+}
+
+impl MeasuredWrap {
+    fn from_lines(lines: Vec<String>) -> Self {
+        let widths = lines.iter().map(|line| display_width(line)).collect();
+        Self { lines, widths }
+    }
+
+    /// Approximate heap footprint used for the byte budget.
+    fn byte_cost(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|line| line.len() + std::mem::size_of::<String>())
+            .sum::<usize>()
+            + self.widths.len() * std::mem::size_of::<usize>()
+    }
+}
+
+/// Cache performance counters for perf telemetry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MeasureCacheStats {
+    /// Lookups answered from the cache.
+    pub hits: u64,
+    /// Lookups that recomputed.
+    pub misses: u64,
+    /// Entries evicted to stay within the byte budget.
+    pub evictions: u64,
+    /// Current entry count.
+    pub entries: usize,
+    /// Current payload bytes held.
+    pub bytes: usize,
+    /// Configured byte budget.
+    pub byte_budget: usize,
+}
+
+impl MeasureCacheStats {
+    /// Hit rate in `[0, 1]`.
+    #[must_use]
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// LRU-memoized wrap/measure results under a byte budget.
+///
+/// # Example
+/// ```
+/// use ftui_text::{MeasureCache, WrapOptions};
+///
+/// let mut cache = MeasureCache::new(64 * 1024);
+/// let options = WrapOptions::new(10);
+/// let first = cache.wrap_with_options("hello wrapping world", &options);
+/// let second = cache.wrap_with_options("hello wrapping world", &options);
+/// assert_eq!(first.lines, second.lines);
+/// assert_eq!(cache.stats().hits, 1);
+/// ```
+#[derive(Debug)]
+pub struct MeasureCache {
+    entries: LruCache<u64, Arc<MeasuredWrap>>,
+    byte_budget: usize,
+    bytes: usize,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl Default for MeasureCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_BYTE_BUDGET)
+    }
+}
+
+/// Key-space tag distinguishing the memoized entry points.
+#[derive(Hash)]
+enum KeyTag {
+    Options,
+    Policy,
+}
+
+impl MeasureCache {
+    /// Create a cache bounded to roughly `byte_budget` payload bytes.
+    #[must_use]
+    pub fn new(byte_budget: usize) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            byte_budget: byte_budget.max(1),
+            bytes: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Memoized [`wrap_with_options`].
+    ///
+    /// Equal to the uncached call for every input; the key covers the
+    /// text content, width, mode, and both whitespace flags.
+    pub fn wrap_with_options(&mut self, text: &str, options: &WrapOptions) -> Arc<MeasuredWrap> {
+        let mut hasher = FxHasher::default();
+        KeyTag::Options.hash(&mut hasher);
+        text.hash(&mut hasher);
+        options.width.hash(&mut hasher);
+        options.mode.hash(&mut hasher);
+        options.preserve_indent.hash(&mut hasher);
+        options.trim_trailing.hash(&mut hasher);
+        let key = hasher.finish();
+        self.get_or_insert(key, || wrap_with_options(text, options))
+    }
+
+    /// Memoized [`wrap_text_policy`].
+    ///
+    /// The key covers the text content, width, and every policy field.
+    pub fn wrap_text_policy(
+        &mut self,
+        text: &str,
+        width: usize,
+        policy: &WrapPolicy,
+    ) -> Arc<MeasuredWrap> {
+        let mut hasher = FxHasher::default();
+        KeyTag::Policy.hash(&mut hasher);
+        text.hash(&mut hasher);
+        width.hash(&mut hasher);
+        policy.word_break.hash(&mut hasher);
+        policy.overflow.hash(&mut hasher);
+        policy.hyphenate.hash(&mut hasher);
+        policy.break_after_separators.hash(&mut hasher);
+        let key = hasher.finish();
+        self.get_or_insert(key, || wrap_text_policy(text, width, policy))
+    }
+
+    fn get_or_insert(
+        &mut self,
+        key: u64,
+        compute: impl FnOnce() -> Vec<String>,
+    ) -> Arc<MeasuredWrap> {
+        if let Some(cached) = self.entries.get(&key) {
+            self.hits += 1;
+            return Arc::clone(cached);
+        }
+        self.misses += 1;
+        let measured = Arc::new(MeasuredWrap::from_lines(compute()));
+        self.bytes += measured.byte_cost();
+        self.entries.push(key, Arc::clone(&measured));
+        // Evict least-recently-used entries past the budget, but always
+        // keep the entry just inserted (an oversized single result is
+        // cached alone rather than thrashing).
+        while self.bytes > self.byte_budget && self.entries.len() > 1 {
+            if let Some((_, evicted)) = self.entries.pop_lru() {
+                self.bytes = self.bytes.saturating_sub(evicted.byte_cost());
+                self.evictions += 1;
+            } else {
+                break;
+            }
+        }
+        measured
+    }
+
+    /// Drop all entries (content that affects wrapping changed out of
+    /// band, e.g. a width-calculation mode switch).
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.bytes = 0;
+    }
+
+    /// Current counters.
+    #[must_use]
+    pub fn stats(&self) -> MeasureCacheStats {
+        MeasureCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            entries: self.entries.len(),
+            bytes: self.bytes,
+            byte_budget: self.byte_budget,
+        }
+    }
+
+    /// Reset the hit/miss/eviction counters (entries stay).
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+        self.evictions = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wrap::WrapMode;
+
+    #[test]
+    fn hit_returns_same_result_as_uncached() {
+        let mut cache = MeasureCache::new(64 * 1024);
+        let options = WrapOptions::new(12).mode(WrapMode::WordChar);
+        let text = "the quick brown fox jumps over the lazy dog";
+
+        let miss = cache.wrap_with_options(text, &options);
+        let hit = cache.wrap_with_options(text, &options);
+        assert_eq!(miss.lines, wrap_with_options(text, &options));
+        assert_eq!(hit.lines, miss.lines);
+        assert_eq!(hit.widths, miss.widths);
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.entries, 1);
+    }
+
+    #[test]
+    fn every_input_lands_on_its_own_key() {
+        let mut cache = MeasureCache::new(64 * 1024);
+        let text = "soft\u{00AD}ware and/or firm-ware";
+
+        // Width, mode, flags, and policy fields each change the key: all
+        // of these are misses, none poison another's result.
+        let _ = cache.wrap_with_options(text, &WrapOptions::new(10));
+        let _ = cache.wrap_with_options(text, &WrapOptions::new(11));
+        let _ = cache.wrap_with_options(text, &WrapOptions::new(10).mode(WrapMode::Char));
+        let _ = cache.wrap_with_options(text, &WrapOptions::new(10).preserve_indent(true));
+        let _ = cache.wrap_with_options(text, &WrapOptions::new(10).trim_trailing(false));
+        let _ = cache.wrap_text_policy(text, 10, &WrapPolicy::default());
+        let _ = cache.wrap_text_policy(text, 10, &WrapPolicy::legacy());
+        let _ = cache.wrap_text_policy(
+            text,
+            10,
+            &WrapPolicy {
+                hyphenate: true,
+                ..WrapPolicy::default()
+            },
+        );
+        assert_eq!(cache.stats().misses, 8);
+        assert_eq!(cache.stats().hits, 0);
+
+        // And each variant verifies against its uncached twin.
+        let cached = cache.wrap_text_policy(text, 10, &WrapPolicy::default());
+        assert_eq!(
+            cached.lines,
+            wrap_text_policy(text, 10, &WrapPolicy::default())
+        );
+    }
+
+    #[test]
+    fn lru_eviction_keeps_bytes_within_budget() {
+        // Budget that holds only a few entries.
+        let mut cache = MeasureCache::new(600);
+        for i in 0..50 {
+            let text = format!("row {i} with some repeated filler text to wrap");
+            let _ = cache.wrap_with_options(&text, &WrapOptions::new(16));
+        }
+        let stats = cache.stats();
+        assert!(stats.bytes <= stats.byte_budget, "{stats:?}");
+        assert!(stats.evictions > 0);
+        assert!(stats.entries < 50);
+        assert_eq!(stats.misses, 50);
+
+        // Most-recently-used entry survived; the oldest did not.
+        let _ = cache.wrap_with_options(
+            "row 49 with some repeated filler text to wrap",
+            &WrapOptions::new(16),
+        );
+        assert_eq!(cache.stats().hits, 1);
+        let _ = cache.wrap_with_options(
+            "row 0 with some repeated filler text to wrap",
+            &WrapOptions::new(16),
+        );
+        assert_eq!(cache.stats().hits, 1, "evicted entry recomputes");
+    }
+
+    #[test]
+    fn oversized_single_entry_is_cached_alone() {
+        let mut cache = MeasureCache::new(64);
+        let big = "word ".repeat(200);
+        let first = cache.wrap_with_options(&big, &WrapOptions::new(20));
+        let second = cache.wrap_with_options(&big, &WrapOptions::new(20));
+        assert_eq!(first.lines, second.lines);
+        assert_eq!(cache.stats().hits, 1, "oversized entry still serves hits");
+        assert_eq!(cache.stats().entries, 1);
+    }
+
+    #[test]
+    fn clear_resets_bytes_but_keeps_counters() {
+        let mut cache = MeasureCache::new(64 * 1024);
+        let _ = cache.wrap_with_options("some text", &WrapOptions::new(5));
+        cache.clear();
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 0);
+        assert_eq!(stats.bytes, 0);
+        assert_eq!(stats.misses, 1);
+        cache.reset_stats();
+        assert_eq!(cache.stats().misses, 0);
+    }
+
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            /// Cached results equal the uncached computation for
+            /// arbitrary text, widths and modes — including the hit path.
+            #[test]
+            fn cached_equals_uncached(
+                text in "[ -~\u{00AD}ä日]{0,80}",
+                width in 0usize..40,
+                mode_idx in 0u8..4,
+                preserve in proptest::bool::ANY,
+                trim in proptest::bool::ANY,
+            ) {
+                let mode = match mode_idx {
+                    0 => WrapMode::None,
+                    1 => WrapMode::Word,
+                    2 => WrapMode::Char,
+                    _ => WrapMode::WordChar,
+                };
+                let options = WrapOptions::new(width)
+                    .mode(mode)
+                    .preserve_indent(preserve)
+                    .trim_trailing(trim);
+                let expected = wrap_with_options(&text, &options);
+
+                let mut cache = MeasureCache::new(64 * 1024);
+                let miss = cache.wrap_with_options(&text, &options);
+                let hit = cache.wrap_with_options(&text, &options);
+                prop_assert_eq!(&miss.lines, &expected);
+                prop_assert_eq!(&hit.lines, &expected);
+                let widths: Vec<usize> =
+                    expected.iter().map(|l| display_width(l)).collect();
+                prop_assert_eq!(&hit.widths, &widths);
+                prop_assert_eq!(cache.stats().hits, 1);
+                prop_assert_eq!(cache.stats().misses, 1);
+            }
+        }
+    }
+}
@@ -209,8 +209,10 @@ impl<S: TextShaper> ShapingFallback<S> {
     /// Shape a line of text with automatic fallback.
     ///
     /// Returns the layout and a diagnostic event describing which path
-    /// was taken. The layout is guaranteed to be valid and non-empty
-    /// for non-empty input.
+    /// was taken. The layout is guaranteed to be valid and to cover
+    /// every cluster of the input; its total width is zero only when
+    /// the text consists entirely of zero-width content (control
+    /// characters such as DEL, combining marks).
     pub fn shape_line(
         &self,
         text: &str,
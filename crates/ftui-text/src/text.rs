@@ -31,7 +31,10 @@
 use crate::TextMeasurement;
 use crate::grapheme_width;
 use crate::segment::{Segment, SegmentLine, SegmentLines, split_into_lines};
-use crate::wrap::{WrapMode, graphemes, truncate_to_width_with_info};
+use crate::wrap::{
+    Overflow, SOFT_HYPHEN, WrapMode, WrapPolicy, graphemes, truncate_to_width,
+    truncate_to_width_with_info, truncate_with_ellipsis, word_break_fragments,
+};
 use ftui_style::Style;
 use std::borrow::Cow;
 use unicode_segmentation::UnicodeSegmentation;
@@ -48,6 +51,9 @@ pub struct Span<'a> {
     pub style: Option<Style>,
     /// Optional hyperlink URL (OSC 8).
     pub link: Option<Cow<'a, str>>,
+    /// Policy-aware wrapping treats this span as atomic
+    /// ([`Span::no_break`], [`Line::wrap_policy`]).
+    pub no_break: bool,
 }
 
 impl<'a> Span<'a> {
@@ -59,6 +65,7 @@ impl<'a> Span<'a> {
             content: content.into(),
             style: None,
             link: None,
+            no_break: false,
         }
     }
 
@@ -70,9 +77,20 @@ impl<'a> Span<'a> {
             content: content.into(),
             style: Some(style),
             link: None,
+            no_break: false,
         }
     }
 
+    /// Create a hyperlinked span (OSC 8).
+    ///
+    /// Emission is capability-gated at present time; on terminals without
+    /// hyperlink support the text renders plainly.
+    #[inline]
+    #[must_use]
+    pub fn hyperlink(content: impl Into<Cow<'a, str>>, uri: impl Into<Cow<'a, str>>) -> Self {
+        Self::raw(content).link(uri)
+    }
+
     /// Set the hyperlink URL for this span.
     #[inline]
     #[must_use]
@@ -81,6 +99,18 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Mark this span as unbreakable for policy-aware wrapping.
+    ///
+    /// [`Line::wrap_policy`] keeps the span on one line, falling back to
+    /// the policy's overflow handling when it is wider than the line.
+    /// The plain [`Line::wrap`] path ignores the marker.
+    #[inline]
+    #[must_use]
+    pub fn no_break(mut self) -> Self {
+        self.no_break = true;
+        self
+    }
+
     /// Get the text content.
     #[inline]
     #[must_use]
@@ -127,11 +157,13 @@ impl<'a> Span<'a> {
                 content: left_cow,
                 style: self.style,
                 link: self.link.clone(),
+                no_break: self.no_break,
             },
             Self {
                 content: right_cow,
                 style: self.style,
                 link: self.link.clone(),
+                no_break: self.no_break,
             },
         )
     }
@@ -180,6 +212,7 @@ impl<'a> Span<'a> {
             content: Cow::Owned(self.content.into_owned()),
             style: self.style,
             link: self.link.map(|l| Cow::Owned(l.into_owned())),
+            no_break: self.no_break,
         }
     }
 }
@@ -202,6 +235,7 @@ impl<'a> From<Segment<'a>> for Span<'a> {
             content: seg.text,
             style: seg.style,
             link: None,
+            no_break: false,
         }
     }
 }
@@ -360,6 +394,23 @@ impl<'a> Line<'a> {
         }
     }
 
+    /// Wrap this line under a [`WrapPolicy`], preserving span styles.
+    ///
+    /// Spans marked [`Span::no_break`] are kept on one line, falling back
+    /// to the policy's overflow handling when wider than the line. Soft
+    /// hyphens are stripped from output; with `policy.hyphenate` they
+    /// contribute break opportunities that render a visible `-` when taken.
+    #[must_use]
+    pub fn wrap_policy(&self, width: usize, policy: &WrapPolicy) -> Vec<Line<'a>> {
+        if width == 0 {
+            return vec![self.clone()];
+        }
+        if self.is_empty() {
+            return vec![Line::new()];
+        }
+        wrap_line_policy(self, width, policy)
+    }
+
     /// Convert to segments.
     #[must_use]
     pub fn into_segments(self) -> Vec<Segment<'a>> {
@@ -671,6 +722,7 @@ impl<'a> Text<'a> {
                             content: Cow::Owned(truncated.to_string()),
                             style: span.style,
                             link: span.link.clone(),
+                            no_break: span.no_break,
                         });
                     }
                     remaining = 0;
@@ -752,6 +804,7 @@ fn trim_span_start<'a>(span: Span<'a>) -> Span<'a> {
         content: Cow::Owned(text[start..].to_string()),
         style: span.style,
         link: span.link,
+        no_break: span.no_break,
     }
 }
 
@@ -777,6 +830,7 @@ fn trim_span_end<'a>(span: Span<'a>) -> Span<'a> {
         content: Cow::Owned(text[..end].to_string()),
         style: span.style,
         link: span.link,
+        no_break: span.no_break,
     }
 }
 
@@ -804,6 +858,7 @@ fn push_span_merged<'a>(line: &mut Line<'a>, span: Span<'a>) {
     if let Some(last) = line.spans.last_mut()
         && last.style == span.style
         && last.link == span.link
+        && last.no_break == span.no_break
     {
         let mut merged = String::with_capacity(last.as_str().len() + span.as_str().len());
         merged.push_str(last.as_str());
@@ -842,6 +897,7 @@ fn split_span_words<'a>(span: &Span<'a>) -> Vec<Span<'a>> {
                 content,
                 style: span.style,
                 link: span.link.clone(),
+                no_break: span.no_break,
             });
             start = idx;
             in_whitespace = is_ws;
@@ -859,12 +915,225 @@ fn split_span_words<'a>(span: &Span<'a>) -> Vec<Span<'a>> {
             content,
             style: span.style,
             link: span.link.clone(),
+            no_break: span.no_break,
         });
     }
 
     segments
 }
 
+/// Style-preserving policy wrap (see [`Line::wrap_policy`]).
+fn wrap_line_policy<'a>(line: &Line<'a>, width: usize, policy: &WrapPolicy) -> Vec<Line<'a>> {
+    let mut lines: Vec<Line<'a>> = Vec::new();
+    let mut current = Line::new();
+    let mut current_width = 0usize;
+
+    for span in &line.spans {
+        if span.no_break {
+            place_atomic_span(
+                span,
+                width,
+                policy,
+                &mut lines,
+                &mut current,
+                &mut current_width,
+            );
+            continue;
+        }
+        for piece in split_span_words(span) {
+            if span_is_whitespace(&piece) {
+                let ws_width = piece.width();
+                if !current.spans.is_empty() && current_width + ws_width <= width {
+                    push_span_merged(&mut current, piece);
+                    current_width += ws_width;
+                } else if !current.spans.is_empty() {
+                    lines.push(trim_line_trailing(std::mem::take(&mut current)));
+                    current_width = 0;
+                }
+                continue;
+            }
+            place_word_span(
+                &piece,
+                width,
+                policy,
+                &mut lines,
+                &mut current,
+                &mut current_width,
+            );
+        }
+    }
+
+    if !current.spans.is_empty() || lines.is_empty() {
+        lines.push(trim_line_trailing(current));
+    }
+    lines
+}
+
+/// Rebuild a sub-span carrying the source span's style and link.
+fn derived_span<'a>(source: &Span<'a>, text: String) -> Span<'a> {
+    Span {
+        content: Cow::Owned(text),
+        style: source.style,
+        link: source.link.clone(),
+        no_break: source.no_break,
+    }
+}
+
+/// Place an unbreakable ([`Span::no_break`]) span as a single atom.
+fn place_atomic_span<'a>(
+    span: &Span<'a>,
+    width: usize,
+    policy: &WrapPolicy,
+    lines: &mut Vec<Line<'a>>,
+    current: &mut Line<'a>,
+    current_width: &mut usize,
+) {
+    // Atomic spans never break, so soft hyphens are simply invisible.
+    let text: String = span
+        .as_str()
+        .chars()
+        .filter(|c| *c != SOFT_HYPHEN)
+        .collect();
+    let span_width = crate::display_width(&text);
+
+    if *current_width + span_width <= width {
+        push_span_merged(current, derived_span(span, text));
+        *current_width += span_width;
+        return;
+    }
+    if !current.spans.is_empty() {
+        lines.push(trim_line_trailing(std::mem::take(current)));
+        *current_width = 0;
+    }
+    if span_width <= width {
+        push_span_merged(current, derived_span(span, text));
+        *current_width = span_width;
+        return;
+    }
+
+    // Wider than a whole line: overflow policy.
+    match policy.overflow {
+        Overflow::Clip => {
+            lines.push(trim_line_trailing(Line::from(derived_span(
+                span,
+                truncate_to_width(&text, width),
+            ))));
+        }
+        Overflow::Ellipsis => {
+            lines.push(trim_line_trailing(Line::from(derived_span(
+                span,
+                truncate_with_ellipsis(&text, width, "\u{2026}"),
+            ))));
+        }
+        Overflow::BreakAnywhere => {
+            for grapheme in graphemes(&text) {
+                let gw = grapheme_width(grapheme);
+                if *current_width + gw > width && !current.spans.is_empty() {
+                    lines.push(trim_line_trailing(std::mem::take(current)));
+                    *current_width = 0;
+                }
+                push_span_merged(current, derived_span(span, grapheme.to_string()));
+                *current_width += gw;
+            }
+        }
+    }
+}
+
+/// Place one word-piece of a breakable span under a policy.
+fn place_word_span<'a>(
+    span: &Span<'a>,
+    width: usize,
+    policy: &WrapPolicy,
+    lines: &mut Vec<Line<'a>>,
+    current: &mut Line<'a>,
+    current_width: &mut usize,
+) {
+    let fragments = word_break_fragments(span.as_str(), policy);
+    let word_width: usize = fragments.iter().map(|f| f.width).sum();
+
+    if *current_width + word_width <= width {
+        let text: String = fragments.iter().map(|f| f.text.as_str()).collect();
+        push_span_merged(current, derived_span(span, text));
+        *current_width += word_width;
+        return;
+    }
+
+    if word_width <= width {
+        if !current.spans.is_empty() {
+            lines.push(trim_line_trailing(std::mem::take(current)));
+            *current_width = 0;
+        }
+        let text: String = fragments.iter().map(|f| f.text.as_str()).collect();
+        push_span_merged(current, derived_span(span, text));
+        *current_width = word_width;
+        return;
+    }
+
+    // Word wider than a line: fill using intra-word break opportunities,
+    // starting from a fresh line like the legacy long-word fallback.
+    if !current.spans.is_empty() {
+        lines.push(trim_line_trailing(std::mem::take(current)));
+        *current_width = 0;
+    }
+    let mut i = 0;
+    while i < fragments.len() {
+        let f = &fragments[i];
+
+        if f.width > width {
+            if !current.spans.is_empty() {
+                lines.push(trim_line_trailing(std::mem::take(current)));
+                *current_width = 0;
+            }
+            match policy.overflow {
+                Overflow::Clip => {
+                    lines.push(trim_line_trailing(Line::from(derived_span(
+                        span,
+                        truncate_to_width(&f.text, width),
+                    ))));
+                }
+                Overflow::Ellipsis => {
+                    lines.push(trim_line_trailing(Line::from(derived_span(
+                        span,
+                        truncate_with_ellipsis(&f.text, width, "\u{2026}"),
+                    ))));
+                }
+                Overflow::BreakAnywhere => {
+                    for grapheme in graphemes(&f.text) {
+                        let gw = grapheme_width(grapheme);
+                        if *current_width + gw > width && !current.spans.is_empty() {
+                            lines.push(trim_line_trailing(std::mem::take(current)));
+                            *current_width = 0;
+                        }
+                        push_span_merged(current, derived_span(span, grapheme.to_string()));
+                        *current_width += gw;
+                    }
+                }
+            }
+            i += 1;
+            continue;
+        }
+
+        if *current_width + f.width <= width {
+            push_span_merged(current, derived_span(span, f.text.clone()));
+            *current_width += f.width;
+            if f.hyphen_at_break
+                && i + 1 < fragments.len()
+                && *current_width + fragments[i + 1].width > width
+            {
+                if *current_width < width {
+                    push_span_merged(current, derived_span(span, "-".to_string()));
+                }
+                lines.push(trim_line_trailing(std::mem::take(current)));
+                *current_width = 0;
+            }
+            i += 1;
+        } else {
+            lines.push(trim_line_trailing(std::mem::take(current)));
+            *current_width = 0;
+        }
+    }
+}
+
 fn wrap_line_chars<'a>(line: &Line<'a>, width: usize) -> Vec<Line<'a>> {
     let mut lines = Vec::new();
     let mut current = Line::new();
@@ -1829,6 +2098,99 @@ mod tests {
         }
         assert_eq!(count, 2);
     }
+
+    // ── Policy-aware styled wrapping ────────────────────────────────
+
+    #[test]
+    fn wrap_policy_no_break_span_stays_atomic() {
+        let line = Line::from_spans([Span::raw("label: "), Span::raw("key=value pair").no_break()]);
+        let wrapped = line.wrap_policy(16, &WrapPolicy::legacy());
+        // The no-break span moves to its own line rather than splitting.
+        assert_eq!(wrapped.len(), 2);
+        assert_eq!(wrapped[0].to_plain_text(), "label:");
+        assert_eq!(wrapped[1].to_plain_text(), "key=value pair");
+    }
+
+    #[test]
+    fn wrap_policy_no_break_overflow_clip_and_ellipsis() {
+        let line = Line::from_spans([Span::raw("cannot-break-this-token").no_break()]);
+
+        let clip = WrapPolicy {
+            overflow: Overflow::Clip,
+            ..WrapPolicy::legacy()
+        };
+        let wrapped = line.wrap_policy(10, &clip);
+        assert_eq!(wrapped.len(), 1);
+        assert_eq!(wrapped[0].to_plain_text(), "cannot-bre");
+
+        let ellipsis = WrapPolicy {
+            overflow: Overflow::Ellipsis,
+            ..WrapPolicy::legacy()
+        };
+        let wrapped = line.wrap_policy(10, &ellipsis);
+        assert_eq!(wrapped.len(), 1);
+        let text = wrapped[0].to_plain_text();
+        assert!(text.ends_with('\u{2026}'));
+        assert!(crate::display_width(&text) <= 10);
+    }
+
+    #[test]
+    fn wrap_policy_no_break_overflow_break_anywhere() {
+        let line = Line::from_spans([Span::raw("cannot-break-this").no_break()]);
+        let wrapped = line.wrap_policy(6, &WrapPolicy::legacy());
+        let joined: String = wrapped.iter().map(|l| l.to_plain_text()).collect();
+        assert_eq!(joined, "cannot-break-this");
+        for l in &wrapped {
+            assert!(l.width() <= 6);
+        }
+    }
+
+    #[test]
+    fn wrap_policy_preserves_styles_across_breaks() {
+        let bold = Style::new().bold();
+        let line = Line::from_spans([
+            Span::styled("styled_segment_one_", bold),
+            Span::raw("plain"),
+        ]);
+        let policy = WrapPolicy::default();
+        let wrapped = line.wrap_policy(12, &policy);
+        // Every piece that came from the styled span keeps its style.
+        for l in &wrapped {
+            for span in l.spans() {
+                if span.as_str().contains("styled") || span.as_str().contains("segment") {
+                    assert_eq!(span.style, Some(bold));
+                }
+            }
+        }
+        let joined: String = wrapped.iter().map(|l| l.to_plain_text()).collect();
+        assert_eq!(joined, "styled_segment_one_plain");
+    }
+
+    #[test]
+    fn wrap_policy_soft_hyphen_in_span() {
+        let policy = WrapPolicy {
+            hyphenate: true,
+            ..WrapPolicy::legacy()
+        };
+        let line = Line::from_spans([Span::raw("hyphen\u{ad}ation")]);
+        let wrapped = line.wrap_policy(7, &policy);
+        assert_eq!(wrapped[0].to_plain_text(), "hyphen-");
+        assert_eq!(wrapped[1].to_plain_text(), "ation");
+
+        // When it fits, the soft hyphen is invisible.
+        let wrapped = line.wrap_policy(20, &policy);
+        assert_eq!(wrapped[0].to_plain_text(), "hyphenation");
+    }
+
+    #[test]
+    fn wrap_policy_cjk_span_breaks_anywhere() {
+        let line = Line::from_spans([Span::raw("\u{4f60}\u{597d}\u{4e16}\u{754c}")]);
+        let wrapped = line.wrap_policy(4, &WrapPolicy::default());
+        assert_eq!(wrapped.len(), 2);
+        for l in &wrapped {
+            assert_eq!(l.width(), 4);
+        }
+    }
 }
 
 #[cfg(test)]
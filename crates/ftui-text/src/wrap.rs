@@ -24,7 +24,7 @@ use std::borrow::Cow;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Text wrapping mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum WrapMode {
     /// No wrapping - lines may exceed width.
     None,
@@ -311,6 +311,302 @@ fn finalize_line(line: &str, options: &WrapOptions) -> String {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Policy-aware wrapping (break opportunities, soft hyphens, overflow)
+// ---------------------------------------------------------------------------
+
+/// Soft hyphen: an invisible break opportunity (U+00AD).
+pub(crate) const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// Where a word may be broken under a [`WrapPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WordBreak {
+    /// Break at whitespace; within words only at explicit opportunities
+    /// (separators, soft hyphens) and between wide (CJK) graphemes.
+    #[default]
+    Normal,
+    /// Break opportunity after every grapheme.
+    BreakAll,
+    /// Never break inside a word; overflow handling applies instead.
+    KeepAll,
+}
+
+/// What happens to an unbreakable unit wider than the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// Break at grapheme boundaries anyway (matches the legacy
+    /// [`WrapMode::WordChar`] fallback).
+    #[default]
+    BreakAnywhere,
+    /// Cut the unit off at the line edge, discarding the excess.
+    Clip,
+    /// Truncate with a trailing `…`.
+    Ellipsis,
+}
+
+/// Wrapping policy: break opportunities, hyphenation, and overflow.
+///
+/// [`WrapPolicy::legacy`] reproduces the behavior of
+/// `wrap_text(_, _, WrapMode::WordChar)` exactly, so existing output is
+/// preserved unless richer breaking is opted into. The `Default` value
+/// enables separator break opportunities (`/`, `-`, `_`), which long
+/// URLs and identifiers want.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrapPolicy {
+    /// Where words may be broken.
+    pub word_break: WordBreak,
+    /// Handling for unbreakable units wider than the line.
+    pub overflow: Overflow,
+    /// Honor soft hyphens (U+00AD) as break opportunities, rendering a
+    /// visible `-` only at an actual break. Soft hyphens never render
+    /// otherwise.
+    pub hyphenate: bool,
+    /// Under [`WordBreak::Normal`], allow breaks after `/`, `-` and `_`
+    /// so long paths and identifiers wrap at natural joints.
+    pub break_after_separators: bool,
+}
+
+impl Default for WrapPolicy {
+    fn default() -> Self {
+        Self {
+            word_break: WordBreak::Normal,
+            overflow: Overflow::BreakAnywhere,
+            hyphenate: false,
+            break_after_separators: true,
+        }
+    }
+}
+
+impl WrapPolicy {
+    /// The policy equivalent of `wrap_text(_, _, WrapMode::WordChar)`:
+    /// no separator or soft-hyphen opportunities, grapheme fallback for
+    /// overlong words. Existing snapshots are unchanged under this value.
+    #[must_use]
+    pub fn legacy() -> Self {
+        Self {
+            word_break: WordBreak::Normal,
+            overflow: Overflow::BreakAnywhere,
+            hyphenate: false,
+            break_after_separators: false,
+        }
+    }
+}
+
+/// An unbreakable run of a word, produced by [`word_break_fragments`].
+///
+/// Breaks may occur between fragments; `hyphen_at_break` marks a
+/// soft-hyphen opportunity that renders `-` when actually taken.
+#[derive(Debug, Clone)]
+pub(crate) struct BreakFragment {
+    pub(crate) text: String,
+    pub(crate) width: usize,
+    pub(crate) hyphen_at_break: bool,
+}
+
+/// Split a word (no whitespace) into unbreakable fragments per policy.
+///
+/// Soft hyphens are always stripped from the output; with
+/// `policy.hyphenate` they additionally contribute a break opportunity.
+/// Wide (CJK/emoji) graphemes form their own fragments under `Normal`
+/// and `BreakAll`, so lines of CJK text break anywhere, as they should.
+pub(crate) fn word_break_fragments(word: &str, policy: &WrapPolicy) -> Vec<BreakFragment> {
+    let mut fragments = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0usize;
+
+    let mut flush = |current: &mut String, current_width: &mut usize, hyphen: bool| {
+        if !current.is_empty() {
+            fragments.push(BreakFragment {
+                text: std::mem::take(current),
+                width: std::mem::take(current_width),
+                hyphen_at_break: hyphen,
+            });
+        }
+    };
+
+    for grapheme in word.graphemes(true) {
+        if grapheme.chars().all(|c| c == SOFT_HYPHEN) {
+            // Invisible; a break opportunity only when hyphenating.
+            if policy.hyphenate && policy.word_break != WordBreak::KeepAll {
+                flush(&mut current, &mut current_width, true);
+            }
+            continue;
+        }
+        let width = grapheme_width(grapheme);
+        match policy.word_break {
+            WordBreak::KeepAll => {
+                current.push_str(grapheme);
+                current_width += width;
+            }
+            WordBreak::BreakAll => {
+                current.push_str(grapheme);
+                current_width += width;
+                flush(&mut current, &mut current_width, false);
+            }
+            WordBreak::Normal => {
+                // Wide graphemes break freely on both sides.
+                if width >= 2 {
+                    flush(&mut current, &mut current_width, false);
+                    current.push_str(grapheme);
+                    current_width += width;
+                    flush(&mut current, &mut current_width, false);
+                    continue;
+                }
+                current.push_str(grapheme);
+                current_width += width;
+                if policy.break_after_separators && matches!(grapheme, "/" | "-" | "_") {
+                    flush(&mut current, &mut current_width, false);
+                }
+            }
+        }
+    }
+    flush(&mut current, &mut current_width, false);
+    fragments
+}
+
+/// Wrap plain text under a [`WrapPolicy`].
+///
+/// Paragraph and whitespace handling match [`wrap_text`]: explicit
+/// newlines are preserved, leading whitespace is dropped on continuation
+/// lines, and trailing whitespace is trimmed.
+#[must_use]
+pub fn wrap_text_policy(text: &str, width: usize, policy: &WrapPolicy) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    for raw_paragraph in text.split('\n') {
+        let paragraph = raw_paragraph.strip_suffix('\r').unwrap_or(raw_paragraph);
+        let len_before = lines.len();
+        let mut current = String::new();
+        let mut current_width = 0usize;
+
+        for word in split_words(paragraph) {
+            if word.chars().all(is_breaking_whitespace) {
+                // Whitespace: kept when it fits mid-line, dropped at edges.
+                let ws_width = display_width(&word);
+                if !current.is_empty() && current_width + ws_width <= width {
+                    current.push_str(&word);
+                    current_width += ws_width;
+                } else if !current.is_empty() {
+                    lines.push(std::mem::take(&mut current).trim_end().to_string());
+                    current_width = 0;
+                }
+                continue;
+            }
+            place_word_policy(
+                &word,
+                width,
+                policy,
+                &mut lines,
+                &mut current,
+                &mut current_width,
+            );
+        }
+
+        if !current.is_empty() || lines.len() == len_before {
+            lines.push(current.trim_end().to_string());
+        }
+    }
+    lines
+}
+
+/// Place one word into the running line under a policy.
+fn place_word_policy(
+    word: &str,
+    width: usize,
+    policy: &WrapPolicy,
+    lines: &mut Vec<String>,
+    current: &mut String,
+    current_width: &mut usize,
+) {
+    let fragments = word_break_fragments(word, policy);
+    let word_width: usize = fragments.iter().map(|f| f.width).sum();
+
+    // Whole word fits on the current line.
+    if *current_width + word_width <= width {
+        for f in &fragments {
+            current.push_str(&f.text);
+        }
+        *current_width += word_width;
+        return;
+    }
+
+    // Whole word fits on a fresh line.
+    if word_width <= width {
+        if !current.is_empty() {
+            lines.push(std::mem::take(current).trim_end().to_string());
+            *current_width = 0;
+        }
+        for f in &fragments {
+            current.push_str(&f.text);
+        }
+        *current_width = word_width;
+        return;
+    }
+
+    // Word is wider than a line: fill using intra-word break opportunities,
+    // starting from a fresh line like the legacy long-word fallback.
+    if !current.is_empty() {
+        lines.push(std::mem::take(current).trim_end().to_string());
+        *current_width = 0;
+    }
+    let mut i = 0;
+    while i < fragments.len() {
+        let f = &fragments[i];
+
+        if f.width > width {
+            // Unbreakable fragment wider than a whole line: overflow policy.
+            if !current.is_empty() {
+                lines.push(std::mem::take(current).trim_end().to_string());
+                *current_width = 0;
+            }
+            match policy.overflow {
+                Overflow::Clip => {
+                    lines.push(truncate_to_width(&f.text, width));
+                }
+                Overflow::Ellipsis => {
+                    lines.push(truncate_with_ellipsis(&f.text, width, "\u{2026}"));
+                }
+                Overflow::BreakAnywhere => {
+                    for grapheme in f.text.graphemes(true) {
+                        let gw = grapheme_width(grapheme);
+                        if *current_width + gw > width && !current.is_empty() {
+                            lines.push(std::mem::take(current).trim_end().to_string());
+                            *current_width = 0;
+                        }
+                        current.push_str(grapheme);
+                        *current_width += gw;
+                    }
+                }
+            }
+            i += 1;
+            continue;
+        }
+
+        if *current_width + f.width <= width {
+            current.push_str(&f.text);
+            *current_width += f.width;
+            // Soft-hyphen opportunity actually taken: show the hyphen.
+            if f.hyphen_at_break
+                && i + 1 < fragments.len()
+                && *current_width + fragments[i + 1].width > width
+            {
+                if *current_width < width {
+                    current.push('-');
+                }
+                lines.push(std::mem::take(current).trim_end().to_string());
+                *current_width = 0;
+            }
+            i += 1;
+        } else {
+            lines.push(std::mem::take(current).trim_end().to_string());
+            *current_width = 0;
+        }
+    }
+}
+
 /// Truncate text to fit within a width, adding ellipsis if needed.
 ///
 /// This function respects grapheme boundaries - it will never break
@@ -2472,6 +2768,142 @@ mod tests {
             }
         }
     }
+
+    // ── Policy-aware wrapping ───────────────────────────────────────
+
+    #[test]
+    fn policy_legacy_matches_wordchar_wrapping() {
+        let samples = [
+            "Hello world foo bar",
+            "Supercalifragilisticexpialidocious",
+            "a/very/long/path/that/never/ends/and/keeps/going",
+            "mixed 世界 content with CJK",
+            "abcd 世界世界世界 tail",
+            "short",
+        ];
+        for text in samples {
+            for width in [4, 10, 20] {
+                assert_eq!(
+                    wrap_text_policy(text, width, &WrapPolicy::legacy()),
+                    wrap_text(text, width, WrapMode::WordChar),
+                    "legacy policy diverged for {text:?} at width {width}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn policy_breaks_urls_at_slashes() {
+        let url = "https://example.com/some/long/path/segment";
+        let lines = wrap_text_policy(url, 20, &WrapPolicy::default());
+        // Every break lands after a separator, not mid-token.
+        for line in &lines[..lines.len() - 1] {
+            assert!(
+                line.ends_with('/') || line.ends_with('-') || line.ends_with('_'),
+                "line {line:?} does not break at a separator"
+            );
+        }
+        assert_eq!(lines.join(""), url);
+        for line in &lines {
+            assert!(display_width(line) <= 20);
+        }
+    }
+
+    #[test]
+    fn policy_breaks_identifiers_at_underscores() {
+        let ident = "some_extremely_long_identifier_name_here";
+        let lines = wrap_text_policy(ident, 16, &WrapPolicy::default());
+        for line in &lines[..lines.len() - 1] {
+            assert!(line.ends_with('_'), "line {line:?}");
+        }
+        assert_eq!(lines.join(""), ident);
+    }
+
+    #[test]
+    fn policy_keep_all_defers_to_overflow() {
+        let word = "unbreakable_token_wider_than_line";
+        let keep_clip = WrapPolicy {
+            word_break: WordBreak::KeepAll,
+            overflow: Overflow::Clip,
+            ..WrapPolicy::legacy()
+        };
+        let lines = wrap_text_policy(word, 10, &keep_clip);
+        assert_eq!(lines, vec!["unbreakabl"]);
+
+        let keep_ellipsis = WrapPolicy {
+            overflow: Overflow::Ellipsis,
+            ..keep_clip
+        };
+        let lines = wrap_text_policy(word, 10, &keep_ellipsis);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].ends_with('\u{2026}'));
+        assert!(display_width(&lines[0]) <= 10);
+
+        let keep_break = WrapPolicy {
+            overflow: Overflow::BreakAnywhere,
+            ..keep_clip
+        };
+        let lines = wrap_text_policy(word, 10, &keep_break);
+        assert_eq!(lines.join(""), word);
+    }
+
+    #[test]
+    fn policy_soft_hyphen_visible_only_at_break() {
+        let policy = WrapPolicy {
+            hyphenate: true,
+            ..WrapPolicy::legacy()
+        };
+        // Fits: the soft hyphen stays invisible.
+        let lines = wrap_text_policy("hy\u{ad}phen", 20, &policy);
+        assert_eq!(lines, vec!["hyphen"]);
+
+        // Must break: a visible hyphen appears at the break point.
+        let lines = wrap_text_policy("hyphen\u{ad}ation", 7, &policy);
+        assert_eq!(lines, vec!["hyphen-", "ation"]);
+    }
+
+    #[test]
+    fn policy_soft_hyphen_ignored_without_hyphenate() {
+        let lines = wrap_text_policy("hyphen\u{ad}ation", 7, &WrapPolicy::legacy());
+        // Stripped, and not a break opportunity: grapheme fallback applies.
+        assert_eq!(lines.join(""), "hyphenation");
+        assert!(lines.iter().all(|l| !l.contains('\u{ad}')));
+    }
+
+    #[test]
+    fn policy_cjk_breaks_anywhere_under_normal() {
+        let text = "\u{4f60}\u{597d}\u{4e16}\u{754c}\u{518d}\u{89c1}"; // six CJK chars, 12 cells
+        let lines = wrap_text_policy(text, 4, &WrapPolicy::default());
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert_eq!(display_width(line), 4);
+        }
+        assert_eq!(lines.join(""), text);
+    }
+
+    #[test]
+    fn policy_emoji_width_stays_correct() {
+        // Family emoji (ZWJ sequence) must stay a single grapheme.
+        let text = "ab \u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466} cd";
+        let lines = wrap_text_policy(text, 4, &WrapPolicy::default());
+        for line in &lines {
+            assert!(display_width(line) <= 4, "line {line:?} too wide");
+        }
+        assert!(lines.iter().any(|l| l.contains('\u{200d}')));
+    }
+
+    #[test]
+    fn policy_break_all_breaks_every_grapheme() {
+        let lines = wrap_text_policy(
+            "abcdef",
+            2,
+            &WrapPolicy {
+                word_break: WordBreak::BreakAll,
+                ..WrapPolicy::legacy()
+            },
+        );
+        assert_eq!(lines, vec!["ab", "cd", "ef"]);
+    }
 }
 
 #[cfg(test)]
@@ -98,7 +98,10 @@ proptest! {
     fn fuzz_shaping_fallback_no_panic(text in arb_fuzzy_text(100)) {
         let fb = ShapingFallback::<NoopShaper>::terminal();
         let (layout, _event) = fb.shape_line(&text, Script::Latin, RunDirection::Ltr);
-        if !text.is_empty() {
+        // Zero-width-only text (e.g. "\x7f", combining marks) legitimately
+        // occupies zero cells; anything with visible width must produce a
+        // non-empty layout.
+        if display_width(&text) > 0 {
             prop_assert!(layout.total_cells() > 0);
         }
     }
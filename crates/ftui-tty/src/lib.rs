@@ -29,6 +29,7 @@ use ftui_core::terminal_capabilities::TerminalCapabilities;
 use ftui_render::buffer::Buffer;
 use ftui_render::diff::BufferDiff;
 use ftui_render::presenter::Presenter;
+use ftui_render::resilient_writer::ResilientWriter;
 
 #[cfg(unix)]
 use signal_hook::consts::signal::{SIGHUP, SIGINT, SIGQUIT, SIGTERM, SIGWINCH};
@@ -1110,7 +1111,7 @@ impl BackendEventSource for TtyEventSource {
 ///
 /// Wraps `ftui_render::presenter::Presenter<W>` for real ANSI output.
 /// In headless mode (`inner = None`), all operations are no-ops.
-pub struct TtyPresenter<W: Write + Send = io::Stdout> {
+pub struct TtyPresenter<W: Write + Send = ResilientWriter<io::Stdout>> {
     capabilities: TerminalCapabilities,
     inner: Option<Presenter<W>>,
 }
@@ -1126,11 +1127,19 @@ impl TtyPresenter {
     }
 
     /// Create a live presenter that writes ANSI escape sequences to stdout.
+    ///
+    /// Stdout is wrapped in a [`ResilientWriter`] so short writes are
+    /// resumed and transient `EAGAIN` backpressure is retried before it
+    /// surfaces; see [`ftui_render::presenter::PresentError`] for how
+    /// callers classify what leaks through.
     #[must_use]
     pub fn live(capabilities: TerminalCapabilities) -> Self {
         Self {
             capabilities,
-            inner: Some(Presenter::new(io::stdout(), capabilities)),
+            inner: Some(Presenter::new(
+                ResilientWriter::new(io::stdout()),
+                capabilities,
+            )),
         }
     }
 }
@@ -1607,6 +1616,7 @@ mod tests {
                 code: KeyCode::Char('a'),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: Some('a'),
             })
         );
         let e2 = src.read_event().unwrap().unwrap();
@@ -1616,6 +1626,7 @@ mod tests {
                 code: KeyCode::Char('b'),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: Some('b'),
             })
         );
         let e3 = src.read_event().unwrap().unwrap();
@@ -1625,6 +1636,7 @@ mod tests {
                 code: KeyCode::Char('c'),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: Some('c'),
             })
         );
         // Queue should now be empty.
@@ -1669,6 +1681,7 @@ mod tests {
                 code: KeyCode::Char('a'),
                 modifiers: Modifiers::CTRL,
                 kind: KeyEventKind::Press,
+                ch: Some('a'),
             })
         );
         let e2 = src.read_event().unwrap().unwrap();
@@ -1678,6 +1691,7 @@ mod tests {
                 code: KeyCode::Char('c'),
                 modifiers: Modifiers::CTRL,
                 kind: KeyEventKind::Press,
+                ch: Some('c'),
             })
         );
     }
@@ -1698,6 +1712,7 @@ mod tests {
                 code: KeyCode::F(1),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
         let e2 = src.read_event().unwrap().unwrap();
@@ -1707,6 +1722,7 @@ mod tests {
                 code: KeyCode::F(5),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -1842,13 +1858,7 @@ mod tests {
         writer.write_all(b"\x1b[200~hello world\x1b[201~").unwrap();
         assert!(src.poll_event(Duration::from_millis(100)).unwrap());
         let e = src.read_event().unwrap().unwrap();
-        assert_eq!(
-            e,
-            Event::Paste(PasteEvent {
-                text: "hello world".to_string(),
-                bracketed: true,
-            })
-        );
+        assert_eq!(e, Event::Paste(PasteEvent::bracketed("hello world")));
     }
 
     #[cfg(unix)]
@@ -1867,6 +1877,7 @@ mod tests {
                 code: KeyCode::Up,
                 modifiers: Modifiers::CTRL,
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -1912,6 +1923,7 @@ mod tests {
                 code: KeyCode::Char('y'),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
+                ch: Some('y'),
             })
         );
     }
@@ -1992,6 +2004,7 @@ mod tests {
                     code: KeyCode::Char('x'),
                     modifiers: Modifiers::NONE,
                     kind: KeyEventKind::Press,
+                    ch: _,
                 })
             )
         });
@@ -2023,6 +2036,7 @@ mod tests {
                     code: KeyCode::Char('z'),
                     modifiers: Modifiers::NONE,
                     kind: KeyEventKind::Press,
+                    ch: _,
                 })
             )
         });
@@ -15,8 +15,8 @@
 //! `serde_json` for robustness and is feature-gated behind `input-parser`.
 
 use ftui_core::event::{
-    Event, ImeEvent, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent,
-    MouseEventKind, PasteEvent,
+    ClipboardEvent, ClipboardSource, Event, ImeEvent, KeyCode, KeyEvent, KeyEventKind, Modifiers,
+    MouseButton, MouseEvent, MouseEventKind, PasteEvent, PasteSource,
 };
 use serde::Deserialize;
 
@@ -70,6 +70,12 @@ struct RawInput {
     #[serde(default)]
     data: Option<String>,
     #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    cursor: Option<u32>,
+    #[serde(default)]
+    request_id: Option<u64>,
+    #[serde(default)]
     focused: Option<bool>,
     #[serde(default)]
     #[allow(dead_code)]
@@ -97,6 +103,7 @@ pub fn parse_encoded_input_to_event(json: &str) -> Result<Option<Event>, InputPa
         "paste" => parse_paste_event(&raw).map(Some),
         "focus" => parse_focus_event(&raw).map(Some),
         "composition" => parse_composition_event(&raw),
+        "clipboard_result" => parse_clipboard_result_event(&raw).map(Some),
         // Touch, accessibility, and unknown kinds have no Event mapping.
         _ => Ok(None),
     }
@@ -202,17 +209,37 @@ fn parse_key_event(raw: &RawInput) -> Result<Event, InputParseError> {
         kind
     };
 
-    // Prefer logical `key` when it maps cleanly (captures shifted punctuation
-    // and mobile keyboard text). Fall back to physical `code` labels.
-    let parsed_code = key_str
+    // Dual report: the DOM already separates the produced character
+    // (`key`) from the physical position (`code`) — map both through.
+    // `code` prefers the physical label; `ch` carries the produced
+    // scalar when `key` is a single character.
+    let parsed_code = code_str
         .and_then(parse_key_code_opt)
-        .or_else(|| code_str.and_then(parse_key_code_opt))
-        .or_else(|| key_str.map(parse_key_code))
+        .or_else(|| key_str.and_then(parse_key_code_opt))
         .or_else(|| code_str.map(parse_key_code))
+        .or_else(|| key_str.map(parse_key_code))
         .unwrap_or(KeyCode::Null);
 
+    let produced = key_str.and_then(|key| {
+        let mut chars = key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => (key == "Space" || key == "Spacebar").then_some(' '),
+        }
+    });
+    // Functional keys (Enter, arrows) produce no scalar; chars fall
+    // back to the parsed code when `key` was absent.
+    let ch = produced.or(match parsed_code {
+        KeyCode::Char(c) if key_str.is_none() => Some(c),
+        _ => None,
+    });
+
     Ok(Event::Key(KeyEvent {
         code: parsed_code,
+        ch: match parsed_code {
+            KeyCode::Char(_) | KeyCode::Null => ch,
+            _ => None,
+        },
         modifiers: parse_modifiers(raw.mods),
         kind,
     }))
@@ -288,14 +315,18 @@ fn parse_wheel_event(raw: &RawInput) -> Result<Option<Event>, InputParseError> {
 }
 
 fn parse_paste_event(raw: &RawInput) -> Result<Event, InputParseError> {
+    // `text` is the documented field; `data` is accepted for backward
+    // compatibility with older host encoders.
     let data = raw
-        .data
+        .text
         .as_deref()
-        .ok_or(InputParseError::MissingField("data"))?;
-    Ok(Event::Paste(PasteEvent {
-        text: data.to_string(),
-        bracketed: true,
-    }))
+        .or(raw.data.as_deref())
+        .ok_or(InputParseError::MissingField("text"))?;
+    // Host-synthesized paste: encoded-input JSON, not a terminal bracket.
+    Ok(Event::Paste(PasteEvent::with_source(
+        data,
+        PasteSource::Synthetic,
+    )))
 }
 
 fn parse_focus_event(raw: &RawInput) -> Result<Event, InputParseError> {
@@ -307,17 +338,102 @@ fn parse_focus_event(raw: &RawInput) -> Result<Event, InputParseError> {
 
 fn parse_composition_event(raw: &RawInput) -> Result<Option<Event>, InputParseError> {
     let phase = raw.phase.as_deref().unwrap_or("");
-    let text = raw.data.as_deref().unwrap_or("").to_string();
+    // `text` is the documented field; `data` is accepted for backward
+    // compatibility. Update/end phases without a payload are malformed.
+    let text = raw.text.as_deref().or(raw.data.as_deref());
     let ime = match phase {
         "start" => ImeEvent::start(),
-        "update" => ImeEvent::update(text),
-        "end" | "commit" => ImeEvent::commit(text),
+        "update" => ImeEvent::update(text.ok_or(InputParseError::MissingField("text"))?),
+        "end" | "commit" => ImeEvent::commit(text.ok_or(InputParseError::MissingField("text"))?),
         "cancel" => ImeEvent::cancel(),
         other => return Err(InputParseError::UnknownPhase(other.to_string())),
     };
+    let ime = match raw.cursor {
+        Some(cursor) => ime.with_cursor(cursor),
+        None => ime,
+    };
     Ok(Some(Event::Ime(ime)))
 }
 
+/// Host completed a clipboard read the app requested
+/// (`{"kind":"clipboard_result","request_id":N,"text":"…"}`). The
+/// request-id correlation happens in the embedding layer; the event
+/// carries the content with a [`ClipboardSource::HostBridge`] source.
+fn parse_clipboard_result_event(raw: &RawInput) -> Result<Event, InputParseError> {
+    raw.request_id
+        .ok_or(InputParseError::MissingField("request_id"))?;
+    let text = raw
+        .text
+        .as_deref()
+        .ok_or(InputParseError::MissingField("text"))?;
+    Ok(Event::Clipboard(ClipboardEvent::new(
+        text,
+        ClipboardSource::HostBridge,
+    )))
+}
+
+#[cfg(test)]
+mod dual_field_tests {
+    use super::*;
+
+    fn key_json(key: &str, code: &str) -> String {
+        format!(r#"{{"kind":"key","key":"{key}","code":"{code}","phase":"down"}}"#)
+    }
+
+    #[test]
+    fn web_json_carries_both_key_and_code() {
+        // AZERTY browser: physical KeyQ produces 'a'.
+        let event = parse_encoded_input_to_event(&key_json("a", "KeyQ"))
+            .expect("parses")
+            .expect("event");
+        let Event::Key(key) = event else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('q'), "DOM code is physical");
+        assert_eq!(key.ch, Some('a'), "DOM key is produced");
+    }
+
+    #[test]
+    fn web_json_key_only_still_parses() {
+        let json = r#"{"kind":"key","key":"x","phase":"down"}"#;
+        let event = parse_encoded_input_to_event(json)
+            .expect("parses")
+            .expect("event");
+        let Event::Key(key) = event else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('x'));
+        assert_eq!(key.ch, Some('x'));
+    }
+
+    #[test]
+    fn web_json_functional_key_has_no_scalar() {
+        let event = parse_encoded_input_to_event(&key_json("Enter", "Enter"))
+            .expect("parses")
+            .expect("event");
+        let Event::Key(key) = event else {
+            panic!("expected key event");
+        };
+        assert_eq!(key.code, KeyCode::Enter);
+        assert_eq!(key.ch, None);
+    }
+
+    #[test]
+    fn web_json_shifted_punctuation_keeps_both_sides() {
+        // US layout: Shift+Slash produces '?'.
+        let event = parse_encoded_input_to_event(&key_json("?", "Slash"))
+            .expect("parses")
+            .expect("event");
+        let Event::Key(key) = event else {
+            panic!("expected key event");
+        };
+        // "Slash" has no direct code mapping; the produced char anchors
+        // both sides.
+        assert_eq!(key.ch, Some('?'));
+        assert_eq!(key.code, KeyCode::Char('?'));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -334,6 +450,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('a'),
+                ch: Some('a'),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
             })
@@ -353,6 +470,7 @@ mod tests {
                 code: KeyCode::Enter,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Release,
+                ch: None,
             })
         );
     }
@@ -368,6 +486,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('c'),
+                ch: Some('c'),
                 modifiers: Modifiers::CTRL,
                 kind: KeyEventKind::Press,
             })
@@ -385,6 +504,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('a'),
+                ch: Some('a'),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Repeat,
             })
@@ -403,6 +523,7 @@ mod tests {
                 code: KeyCode::F(5),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -420,6 +541,7 @@ mod tests {
                 code: KeyCode::Escape,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -435,6 +557,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('a'),
+                ch: Some('a'),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
             })
@@ -452,6 +575,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('7'),
+                ch: Some('7'),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
             })
@@ -471,6 +595,7 @@ mod tests {
                 code: KeyCode::Left,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -488,6 +613,7 @@ mod tests {
                 code: KeyCode::Null,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -505,6 +631,7 @@ mod tests {
                 code: KeyCode::Enter,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
     }
@@ -516,10 +643,13 @@ mod tests {
         )
         .unwrap()
         .unwrap();
+        // Dual report: physical Digit1 stays in `code`, the produced
+        // shifted symbol rides in `ch`.
         assert_eq!(
             ev,
             Event::Key(KeyEvent {
-                code: KeyCode::Char('!'),
+                code: KeyCode::Char('1'),
+                ch: Some('!'),
                 modifiers: Modifiers::SHIFT,
                 kind: KeyEventKind::Press,
             })
@@ -537,6 +667,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('/'),
+                ch: Some('/'),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
             })
@@ -625,6 +756,7 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('a'),
+                ch: Some('a'),
                 modifiers: Modifiers::NONE,
                 kind: KeyEventKind::Press,
             })
@@ -752,10 +884,10 @@ mod tests {
             .unwrap();
         assert_eq!(
             ev,
-            Event::Paste(PasteEvent {
-                text: "hello world".to_string(),
-                bracketed: true,
-            })
+            Event::Paste(PasteEvent::with_source(
+                "hello world",
+                PasteSource::Synthetic,
+            ))
         );
     }
 
@@ -902,6 +1034,7 @@ mod tests {
                     code: expected,
                     modifiers: Modifiers::empty(),
                     kind: KeyEventKind::Press,
+                    ch: None,
                 })
             );
         }
@@ -928,6 +1061,7 @@ mod tests {
                     code: expected,
                     modifiers: Modifiers::empty(),
                     kind: KeyEventKind::Press,
+                    ch: None,
                 })
             );
         }
@@ -944,9 +1078,83 @@ mod tests {
             ev,
             Event::Key(KeyEvent {
                 code: KeyCode::Char('a'),
+                ch: Some('a'),
                 modifiers: Modifiers::SHIFT | Modifiers::CTRL,
                 kind: KeyEventKind::Press,
             })
         );
     }
+
+    // ── Composition / paste / clipboard bridge schema ───────────────
+
+    #[test]
+    fn composition_text_field_and_cursor() {
+        let ev = parse_encoded_input_to_event(
+            r#"{"kind":"composition","phase":"update","text":"漢","cursor":1}"#,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(ev, Event::Ime(ImeEvent::update("漢").with_cursor(1)));
+
+        let ev =
+            parse_encoded_input_to_event(r#"{"kind":"composition","phase":"end","text":"漢字"}"#)
+                .unwrap()
+                .unwrap();
+        assert_eq!(ev, Event::Ime(ImeEvent::commit("漢字")));
+    }
+
+    #[test]
+    fn malformed_composition_payloads_are_rejected() {
+        // Update without text.
+        assert!(
+            parse_encoded_input_to_event(r#"{"kind":"composition","phase":"update"}"#).is_err()
+        );
+        // Unknown phase.
+        assert!(
+            parse_encoded_input_to_event(r#"{"kind":"composition","phase":"bogus","text":"x"}"#)
+                .is_err()
+        );
+        // Not JSON at all.
+        assert!(parse_encoded_input_to_event("{nope").is_err());
+    }
+
+    #[test]
+    fn paste_accepts_text_field() {
+        let ev = parse_encoded_input_to_event(r#"{"kind":"paste","text":"hello"}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            ev,
+            Event::Paste(PasteEvent::with_source("hello", PasteSource::Synthetic))
+        );
+    }
+
+    #[test]
+    fn clipboard_result_maps_to_host_bridge_event() {
+        let ev = parse_encoded_input_to_event(
+            r#"{"kind":"clipboard_result","request_id":3,"text":"from host"}"#,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            ev,
+            Event::Clipboard(ClipboardEvent::new(
+                "from host",
+                ClipboardSource::HostBridge
+            ))
+        );
+        // Missing request_id or text is malformed.
+        assert!(parse_encoded_input_to_event(r#"{"kind":"clipboard_result","text":"x"}"#).is_err());
+        assert!(
+            parse_encoded_input_to_event(r#"{"kind":"clipboard_result","request_id":1}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn unknown_kinds_still_return_none() {
+        assert_eq!(
+            parse_encoded_input_to_event(r#"{"kind":"gamepad","button":1}"#).unwrap(),
+            None
+        );
+    }
 }
@@ -117,7 +117,16 @@ impl WebEventSource {
     }
 
     /// Push a canonical event into the queue.
+    ///
+    /// Consecutive `Resize` events coalesce to the latest size: a host
+    /// resize-observer storm delivers one resize to `update()` per step
+    /// instead of dozens, and the final size is never lost.
     pub fn push_event(&mut self, event: Event) {
+        if matches!(event, Event::Resize { .. })
+            && matches!(self.queue.back(), Some(Event::Resize { .. }))
+        {
+            let _ = self.queue.pop_back();
+        }
         self.queue.push_back(event);
     }
 
@@ -671,6 +671,7 @@ fn mouse_event_kind_to_str(kind: MouseEventKind) -> &'static str {
 fn clipboard_source_to_str(source: ClipboardSource) -> &'static str {
     match source {
         ClipboardSource::Osc52 => "osc52",
+        ClipboardSource::HostBridge => "host_bridge",
         ClipboardSource::Unknown => "unknown",
     }
 }
@@ -1150,6 +1151,7 @@ fn parse_event_json(data: &str) -> Result<Event, String> {
                 code,
                 modifiers,
                 kind: event_kind,
+                ch: None,
             }))
         }
         "mouse" => {
@@ -1249,6 +1251,7 @@ fn parse_event_json(data: &str) -> Result<Event, String> {
             let source_str = extract_str(data, "source").unwrap_or("unknown");
             let source = match source_str {
                 "osc52" => ClipboardSource::Osc52,
+                "host_bridge" => ClipboardSource::HostBridge,
                 _ => ClipboardSource::Unknown,
             };
             Ok(Event::Clipboard(ClipboardEvent::new(content, source)))
@@ -1651,6 +1654,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -2354,11 +2358,13 @@ mod tests {
                 code: KeyCode::Enter,
                 modifiers: Modifiers::CTRL | Modifiers::SHIFT,
                 kind: KeyEventKind::Press,
+                ch: None,
             }),
             Event::Key(KeyEvent {
                 code: KeyCode::F(12),
                 modifiers: Modifiers::ALT,
                 kind: KeyEventKind::Repeat,
+                ch: None,
             }),
             Event::Mouse(MouseEvent {
                 kind: MouseEventKind::Down(MouseButton::Left),
@@ -2576,6 +2582,7 @@ mod tests {
                 code: KeyCode::F(12),
                 modifiers: Modifiers::SHIFT | Modifiers::CTRL,
                 kind: KeyEventKind::Press,
+                ch: None,
             })
         );
 
@@ -2588,6 +2595,7 @@ mod tests {
                 code: KeyCode::Char('a'),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Repeat,
+                ch: None,
             })
         );
 
@@ -2600,6 +2608,7 @@ mod tests {
                 code: KeyCode::Enter,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Release,
+                ch: None,
             })
         );
     }
@@ -2610,6 +2619,7 @@ mod tests {
             code: KeyCode::Char('"'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let quote_json = event_to_json(&quote_key);
         let parsed_quote = parse_event_json(&quote_json).expect("quote key should parse");
@@ -2619,6 +2629,7 @@ mod tests {
             code: KeyCode::Char('\\'),
             modifiers: Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let slash_json = event_to_json(&slash_key);
         let parsed_slash = parse_event_json(&slash_json).expect("slash key should parse");
@@ -115,6 +115,20 @@ pub struct StepProgram<M: Model> {
     dbl_buf: Option<DoubleBuffer>,
     /// Pending geometry transition that must force a baseline reset + full repaint marker.
     pending_geometry_transition: Option<GeometryTransition>,
+    /// Futures spawned via `Cmd::Future`, polled once per `step()` with a
+    /// no-op waker — the host's frame loop is the executor.
+    #[cfg(feature = "async")]
+    pending_futures: Vec<PendingFuture<M::Message>>,
+}
+
+/// A `Cmd::Future` in flight on the host-driven executor.
+#[cfg(feature = "async")]
+struct PendingFuture<Msg> {
+    fut: ftui_runtime::async_cmd::BoxMsgFuture<Msg>,
+    /// Deterministic-clock deadline; on expiry the future is dropped and
+    /// the timeout message (when present) delivered instead.
+    deadline: Option<Duration>,
+    timeout_msg: Option<Box<Msg>>,
 }
 
 impl<M: Model> StepProgram<M> {
@@ -136,6 +150,8 @@ impl<M: Model> StepProgram<M> {
             height,
             dbl_buf: None,
             pending_geometry_transition: None,
+            #[cfg(feature = "async")]
+            pending_futures: Vec::new(),
         }
     }
 
@@ -159,6 +175,8 @@ impl<M: Model> StepProgram<M> {
             height,
             dbl_buf: None,
             pending_geometry_transition: None,
+            #[cfg(feature = "async")]
+            pending_futures: Vec::new(),
         }
     }
 
@@ -206,6 +224,12 @@ impl<M: Model> StepProgram<M> {
             }
         }
 
+        // 1b. Poll in-flight async commands (host-driven executor).
+        #[cfg(feature = "async")]
+        if self.running {
+            self.poll_pending_futures();
+        }
+
         // 2. Handle tick if tick_rate is set and enough time has elapsed.
         if self.running
             && let Some(rate) = self.tick_rate
@@ -341,6 +365,14 @@ impl<M: Model> StepProgram<M> {
         &self.pool
     }
 
+    /// The most recently rendered buffer, if a frame has been rendered.
+    ///
+    /// Reflects what the host last painted (not pending model state); the
+    /// baseline is invalidated on resize until the next render.
+    pub fn rendered_buffer(&self) -> Option<&Buffer> {
+        self.dbl_buf.as_ref().map(DoubleBuffer::current)
+    }
+
     // --- Private helpers ---
 
     fn handle_event(&mut self, event: Event) {
@@ -494,6 +526,70 @@ impl<M: Model> StepProgram<M> {
             Cmd::SaveState | Cmd::RestoreState => {
                 // No persistence in WASM (yet).
             }
+            Cmd::Suspend => {
+                // No job control in the browser; ignored.
+            }
+            Cmd::SplashProgress(_) | Cmd::CompleteStartup => {
+                // No startup splash phase in the web runner; ignored.
+            }
+            #[cfg(feature = "async")]
+            Cmd::Future(fut, timeout, timeout_msg) => {
+                // No executor in host-driven mode: queue the future and
+                // poll it once per step(). The optional deadline runs on
+                // the deterministic clock.
+                let deadline = timeout.map(|t| self.backend.clock.now_mono().saturating_add(t));
+                self.pending_futures.push(PendingFuture {
+                    fut,
+                    deadline,
+                    timeout_msg,
+                });
+            }
+        }
+    }
+
+    /// Poll every pending `Cmd::Future` once with a no-op waker, delivering
+    /// ready messages (or timeout messages for expired deadlines) through
+    /// the normal update path. Pending futures stay queued for the next
+    /// step — the host's frame loop is the wake source.
+    #[cfg(feature = "async")]
+    fn poll_pending_futures(&mut self) {
+        use std::task::{Context, Poll};
+
+        if self.pending_futures.is_empty() {
+            return;
+        }
+        let now = self.backend.clock.now_mono();
+        let waker = std::task::Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut i = 0;
+        while i < self.pending_futures.len() {
+            let outcome = {
+                let entry = &mut self.pending_futures[i];
+                if entry.deadline.is_some_and(|deadline| now >= deadline) {
+                    // Deadline expired: drop the future, deliver the
+                    // timeout message when one was configured.
+                    Some(entry.timeout_msg.take().map(|msg| *msg))
+                } else {
+                    match entry.fut.as_mut().poll(&mut cx) {
+                        Poll::Ready(msg) => Some(Some(msg)),
+                        Poll::Pending => None,
+                    }
+                }
+            };
+            match outcome {
+                Some(msg) => {
+                    self.pending_futures.swap_remove(i);
+                    if let Some(msg) = msg {
+                        let cmd = self.model.update(msg);
+                        self.dirty = true;
+                        self.execute_cmd(cmd);
+                        if !self.running {
+                            return;
+                        }
+                    }
+                }
+                None => i += 1,
+            }
         }
     }
 }
@@ -612,6 +708,7 @@ mod tests {
             code: KeyCode::Char(c),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         })
     }
 
@@ -763,6 +860,67 @@ mod tests {
         assert_eq!(prog.size(), (120, 40));
     }
 
+    #[test]
+    fn resize_storm_coalesces_to_latest_before_delivery() {
+        let mut prog = StepProgram::new(new_counter(0), 80, 24);
+        prog.init().unwrap();
+
+        // A host resize-observer storm between two animation frames.
+        for i in 0..100u16 {
+            prog.resize(80 + i % 37, 24 + i % 19);
+        }
+        let result = prog.step().unwrap();
+
+        // One coalesced resize reaches update(), carrying the final size.
+        assert_eq!(result.events_processed, 1);
+        assert_eq!(prog.size(), (80 + 99 % 37, 24 + 99 % 19));
+    }
+
+    #[test]
+    fn coalesced_resize_renders_same_frame_as_single_resize() {
+        // Storm run.
+        let mut storm = StepProgram::new(new_counter(7), 80, 24);
+        storm.init().unwrap();
+        for i in 0..50u16 {
+            storm.resize(40 + i, 10 + i % 7);
+        }
+        storm.step().unwrap();
+        let storm_frame = storm.rendered_buffer().cloned();
+
+        // Single-resize run at the final size.
+        let mut single = StepProgram::new(new_counter(7), 80, 24);
+        single.init().unwrap();
+        single.resize(89, 10); // == (40 + 49, 10 + 49 % 7)
+        single.step().unwrap();
+        let single_frame = single.rendered_buffer().cloned();
+
+        let storm_frame = storm_frame.expect("storm frame rendered");
+        let single_frame = single_frame.expect("single frame rendered");
+        assert_eq!(storm_frame.width(), single_frame.width());
+        assert_eq!(storm_frame.height(), single_frame.height());
+        assert_eq!(storm_frame.cells(), single_frame.cells());
+    }
+
+    #[test]
+    fn interleaved_events_do_not_coalesce_across_non_resize() {
+        let mut prog = StepProgram::new(new_counter(0), 80, 24);
+        prog.init().unwrap();
+
+        prog.resize(100, 30);
+        prog.push_event(Event::Key(KeyEvent {
+            code: KeyCode::Char('x'),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        }));
+        prog.resize(120, 40);
+        let result = prog.step().unwrap();
+
+        // Resize, key, resize: the key must not be lost or reordered.
+        assert_eq!(result.events_processed, 3);
+        assert_eq!(prog.size(), (120, 40));
+    }
+
     #[test]
     fn resize_clamps_zero_dimensions_to_minimum() {
         let mut prog = StepProgram::new(new_counter(0), 80, 24);
@@ -1139,4 +1297,55 @@ mod tests {
             "final grapheme pool length should stay bounded by GC interval (final={final_pool_len})"
         );
     }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn future_cmd_delivers_message_on_next_step() {
+        let mut prog = StepProgram::new(
+            Counter {
+                value: 0,
+                initialized: false,
+            },
+            20,
+            5,
+        );
+        prog.init().unwrap();
+
+        prog.execute_cmd(Cmd::future(async { CounterMsg::Increment }));
+        assert_eq!(prog.model().value, 0, "future not polled until step()");
+
+        let result = prog.step().unwrap();
+        assert_eq!(prog.model().value, 1);
+        assert!(result.rendered, "delivered message marks the frame dirty");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn future_cmd_timeout_runs_on_deterministic_clock() {
+        let mut prog = StepProgram::new(
+            Counter {
+                value: 0,
+                initialized: false,
+            },
+            20,
+            5,
+        );
+        prog.init().unwrap();
+
+        prog.execute_cmd(Cmd::future_with_timeout(
+            std::future::pending::<CounterMsg>(),
+            Duration::from_millis(100),
+            CounterMsg::Decrement,
+        ));
+
+        // Before the deadline the future stays queued.
+        let _ = prog.step().unwrap();
+        assert_eq!(prog.model().value, 0);
+
+        // Past the deadline the future is dropped and the timeout
+        // message delivered.
+        prog.advance_time(Duration::from_millis(150));
+        let _ = prog.step().unwrap();
+        assert_eq!(prog.model().value, -1);
+    }
 }
@@ -0,0 +1,160 @@
+#![forbid(unsafe_code)]
+
+//! Banner: DEC double-width / double-height heading lines.
+//!
+//! Renders a single line of text using the buffer's per-row line
+//! attributes ([`LineAttr`]): double-width on one row, or double-height
+//! across a row pair (top + bottom halves carrying the same text, as
+//! DECDHL requires). On terminals without DEC line-attribute support the
+//! presenter approximates: text renders once at normal size and the
+//! paired row stays blank.
+
+use crate::{Widget, draw_text_span};
+use ftui_core::geometry::Rect;
+use ftui_render::buffer::LineAttr;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+/// How the banner is enlarged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BannerSize {
+    /// One double-width row (`ESC # 6`).
+    #[default]
+    Wide,
+    /// A double-height pair of rows (`ESC # 3` / `ESC # 4`).
+    Tall,
+}
+
+/// A heading rendered with DEC line attributes.
+#[derive(Debug, Clone)]
+pub struct Banner<'a> {
+    text: &'a str,
+    size: BannerSize,
+    style: Style,
+}
+
+impl<'a> Banner<'a> {
+    /// Create a banner for `text`.
+    #[must_use]
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            size: BannerSize::default(),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the enlargement mode.
+    #[must_use]
+    pub fn size(mut self, size: BannerSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Text style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for Banner<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() {
+            return;
+        }
+        // A double-width row shows only half the buffer's columns.
+        let logical = area.width / 2;
+        match self.size {
+            BannerSize::Wide => {
+                frame.buffer.set_line_attr(area.y, LineAttr::DoubleWidth);
+                draw_text_span(
+                    frame,
+                    area.x,
+                    area.y,
+                    self.text,
+                    self.style,
+                    area.x + logical.max(1),
+                );
+            }
+            BannerSize::Tall => {
+                if area.height < 2 {
+                    // Not enough rows for the pair: degrade to wide.
+                    frame.buffer.set_line_attr(area.y, LineAttr::DoubleWidth);
+                    draw_text_span(
+                        frame,
+                        area.x,
+                        area.y,
+                        self.text,
+                        self.style,
+                        area.x + logical.max(1),
+                    );
+                    return;
+                }
+                // DECDHL: both halves carry the same text.
+                frame
+                    .buffer
+                    .set_line_attr(area.y, LineAttr::DoubleHeightTop);
+                frame
+                    .buffer
+                    .set_line_attr(area.y + 1, LineAttr::DoubleHeightBottom);
+                for dy in 0..2 {
+                    draw_text_span(
+                        frame,
+                        area.x,
+                        area.y + dy,
+                        self.text,
+                        self.style,
+                        area.x + logical.max(1),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[test]
+    fn wide_banner_sets_line_attr_and_clips_to_logical_width() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 3, &mut pool);
+        Banner::new("HELLO WORLD BANNER TOO LONG").render(Rect::new(0, 0, 20, 1), &mut frame);
+
+        assert_eq!(frame.buffer.line_attr(0), LineAttr::DoubleWidth);
+        assert_eq!(frame.buffer.logical_width(0), 10);
+        // Text clipped at the logical width (10 columns).
+        assert!(frame.buffer.get(9, 0).unwrap().content.as_char().is_some());
+        assert_eq!(frame.buffer.get(10, 0).unwrap().content.as_char(), None);
+    }
+
+    #[test]
+    fn tall_banner_marks_both_halves_with_same_text() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 4, &mut pool);
+        Banner::new("HI")
+            .size(BannerSize::Tall)
+            .render(Rect::new(0, 0, 20, 2), &mut frame);
+
+        assert_eq!(frame.buffer.line_attr(0), LineAttr::DoubleHeightTop);
+        assert_eq!(frame.buffer.line_attr(1), LineAttr::DoubleHeightBottom);
+        for y in 0..2 {
+            assert_eq!(frame.buffer.get(0, y).unwrap().content.as_char(), Some('H'));
+            assert_eq!(frame.buffer.get(1, y).unwrap().content.as_char(), Some('I'));
+        }
+    }
+
+    #[test]
+    fn tall_banner_degrades_to_wide_in_one_row() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 1, &mut pool);
+        Banner::new("X")
+            .size(BannerSize::Tall)
+            .render(Rect::new(0, 0, 20, 1), &mut frame);
+        assert_eq!(frame.buffer.line_attr(0), LineAttr::DoubleWidth);
+    }
+}
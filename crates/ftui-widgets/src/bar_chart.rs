@@ -0,0 +1,472 @@
+#![forbid(unsafe_code)]
+
+//! Bar chart widget for dashboard-style value comparison.
+//!
+//! Renders labeled bars horizontally (one bar per row: label, bar, value
+//! caption) or vertically (one column per bar, bottom-up). Bars use
+//! eighth-block glyphs for sub-cell precision and degrade to ASCII when
+//! unicode rendering is off. The widget is a pure render function over a
+//! slice of [`Bar`]s; the only state is the scroll offset in
+//! [`BarChartState`] for charts with more bars than fit.
+
+use crate::{StatefulWidget, Widget, draw_text_span};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::Cell;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_text::display_width;
+
+/// Horizontal bar fill: empty + 8 left-eighth levels.
+const HBAR_CHARS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+/// Vertical bar fill: empty + 8 lower-eighth levels.
+const VBAR_CHARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One labeled value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar<'a> {
+    /// Display label.
+    pub label: &'a str,
+    /// The value; negative values clamp to zero.
+    pub value: f64,
+}
+
+impl<'a> Bar<'a> {
+    /// Create a labeled bar.
+    pub fn new(label: &'a str, value: f64) -> Self {
+        Self { label, value }
+    }
+}
+
+/// Bar layout direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarOrientation {
+    /// One bar per row, growing rightward.
+    #[default]
+    Horizontal,
+    /// One column per bar, growing upward.
+    Vertical,
+}
+
+/// Scroll state for charts with more bars than fit the area.
+#[derive(Debug, Clone, Default)]
+pub struct BarChartState {
+    /// Index of the first visible bar.
+    pub offset: usize,
+}
+
+impl BarChartState {
+    /// Scroll so that `index` is the first visible bar.
+    pub fn scroll_to(&mut self, index: usize) {
+        self.offset = index;
+    }
+}
+
+/// A bar chart over a slice of [`Bar`]s.
+#[derive(Debug, Clone)]
+pub struct BarChart<'a> {
+    bars: &'a [Bar<'a>],
+    orientation: BarOrientation,
+    /// Explicit maximum; auto-scaled from data when `None`.
+    max: Option<f64>,
+    /// Style for bar glyphs.
+    bar_style: Style,
+    /// Style for labels and captions.
+    label_style: Style,
+    /// Show numeric value captions.
+    show_values: bool,
+    /// Maximum label width in cells (longest label when `None`).
+    label_width: Option<u16>,
+}
+
+impl<'a> BarChart<'a> {
+    /// Create a bar chart over the given bars.
+    pub fn new(bars: &'a [Bar<'a>]) -> Self {
+        Self {
+            bars,
+            orientation: BarOrientation::Horizontal,
+            max: None,
+            bar_style: Style::new(),
+            label_style: Style::new(),
+            show_values: true,
+            label_width: None,
+        }
+    }
+
+    /// Set the layout direction.
+    #[must_use]
+    pub fn orientation(mut self, orientation: BarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set an explicit maximum for scaling (auto from data otherwise).
+    #[must_use]
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Style for the bar glyphs.
+    #[must_use]
+    pub fn bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Style for labels and value captions.
+    #[must_use]
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Toggle numeric value captions (default on).
+    #[must_use]
+    pub fn show_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    /// Cap the label column width; longer labels are elided with `…`.
+    #[must_use]
+    pub fn label_width(mut self, width: u16) -> Self {
+        self.label_width = Some(width);
+        self
+    }
+
+    /// The scaling maximum: explicit, or the largest finite value.
+    fn scale_max(&self) -> f64 {
+        let max = self.max.unwrap_or_else(|| {
+            self.bars
+                .iter()
+                .map(|b| b.value)
+                .filter(|v| v.is_finite())
+                .fold(f64::NEG_INFINITY, f64::max)
+        });
+        if max.is_finite() && max > 0.0 {
+            max
+        } else {
+            1.0
+        }
+    }
+
+    /// Effective label column width for horizontal layout.
+    fn effective_label_width(&self, area_width: u16) -> u16 {
+        let longest = self
+            .bars
+            .iter()
+            .map(|b| display_width(b.label).min(u16::MAX as usize) as u16)
+            .max()
+            .unwrap_or(0);
+        let wanted = self.label_width.unwrap_or(longest).min(longest);
+        // Never let labels swallow more than half the width.
+        wanted.min(area_width / 2)
+    }
+
+    fn render_horizontal(&self, area: Rect, frame: &mut Frame, offset: usize) {
+        let deg = frame.buffer.degradation;
+        let unicode = deg.use_unicode_borders();
+        let max = self.scale_max();
+        let label_w = self.effective_label_width(area.width);
+
+        for (row, bar) in self.bars.iter().skip(offset).enumerate() {
+            let y = area.y + row as u16;
+            if y >= area.bottom() {
+                break;
+            }
+
+            // Label column, elided to fit.
+            let mut x = area.x;
+            if label_w > 0 {
+                let label = elide(bar.label, label_w as usize, unicode);
+                draw_text_span(frame, x, y, &label, self.label_style, x + label_w);
+                x += label_w + 1;
+            }
+
+            // Value caption, right-aligned after the bar region.
+            let caption = if self.show_values {
+                format_value(bar.value)
+            } else {
+                String::new()
+            };
+            let caption_w = display_width(&caption) as u16;
+            let bar_region = area
+                .right()
+                .saturating_sub(x)
+                .saturating_sub(if caption_w > 0 { caption_w + 1 } else { 0 });
+            if bar_region == 0 {
+                continue;
+            }
+
+            // Bar in eighths of a cell.
+            let value = bar.value.max(0.0).min(max);
+            let eighths = ((value / max) * f64::from(bar_region) * 8.0).round() as u32;
+            let (full, partial) = (eighths / 8, (eighths % 8) as usize);
+            for i in 0..bar_region {
+                let ch = if u32::from(i) < full {
+                    if unicode { HBAR_CHARS[8] } else { '#' }
+                } else if u32::from(i) == full && partial > 0 {
+                    if unicode { HBAR_CHARS[partial] } else { '-' }
+                } else {
+                    break;
+                };
+                let mut cell = Cell::from_char(ch);
+                if deg.apply_styling() {
+                    crate::apply_style(&mut cell, self.bar_style);
+                }
+                frame.buffer.set_fast(x + i, y, cell);
+            }
+
+            if caption_w > 0 {
+                let caption_x = x + bar_region + 1;
+                draw_text_span(
+                    frame,
+                    caption_x,
+                    y,
+                    &caption,
+                    self.label_style,
+                    area.right(),
+                );
+            }
+        }
+    }
+
+    fn render_vertical(&self, area: Rect, frame: &mut Frame, offset: usize) {
+        let deg = frame.buffer.degradation;
+        let unicode = deg.use_unicode_borders();
+        let max = self.scale_max();
+
+        // Reserve the bottom row for labels when there is room.
+        let label_row = area.height >= 2;
+        let plot_h = area.height - u16::from(label_row);
+
+        // Each bar: one column plus a one-cell gap.
+        for (col, bar) in self.bars.iter().skip(offset).enumerate() {
+            let x = area.x + (col as u16) * 2;
+            if x >= area.right() {
+                break;
+            }
+
+            let value = bar.value.max(0.0).min(max);
+            let eighths = ((value / max) * f64::from(plot_h) * 8.0).round() as u32;
+            for row in 0..plot_h {
+                // Rows fill bottom-up.
+                let filled = eighths.saturating_sub(u32::from(plot_h - 1 - row) * 8);
+                let level = filled.min(8) as usize;
+                if level == 0 {
+                    continue;
+                }
+                let ch = if unicode {
+                    VBAR_CHARS[level]
+                } else if level == 8 {
+                    '#'
+                } else {
+                    '-'
+                };
+                let mut cell = Cell::from_char(ch);
+                if deg.apply_styling() {
+                    crate::apply_style(&mut cell, self.bar_style);
+                }
+                frame.buffer.set_fast(x, area.y + row, cell);
+            }
+
+            if label_row {
+                let label = elide(bar.label, 1, unicode);
+                draw_text_span(frame, x, area.bottom() - 1, &label, self.label_style, x + 1);
+            }
+        }
+    }
+}
+
+impl Widget for BarChart<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let mut state = BarChartState::default();
+        StatefulWidget::render(self, area, frame, &mut state);
+    }
+}
+
+impl StatefulWidget for BarChart<'_> {
+    type State = BarChartState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        if area.is_empty() || self.bars.is_empty() {
+            return;
+        }
+        let deg = frame.buffer.degradation;
+        if !deg.render_content() {
+            return;
+        }
+        let offset = state.offset.min(self.bars.len().saturating_sub(1));
+        match self.orientation {
+            BarOrientation::Horizontal => self.render_horizontal(area, frame, offset),
+            BarOrientation::Vertical => self.render_vertical(area, frame, offset),
+        }
+    }
+}
+
+/// Elide text to `width` cells, appending `…` (or `~` in ASCII).
+fn elide(text: &str, width: usize, unicode: bool) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let marker = if unicode { '\u{2026}' } else { '~' };
+    let mut out = String::new();
+    let mut used = 0;
+    for grapheme in ftui_text::graphemes(text) {
+        let w = ftui_text::grapheme_width(grapheme);
+        if used + w > width.saturating_sub(1) {
+            break;
+        }
+        out.push_str(grapheme);
+        used += w;
+    }
+    out.push(marker);
+    out
+}
+
+/// Compact caption formatting: integers without decimals, else one place.
+fn format_value(value: f64) -> String {
+    if !value.is_finite() {
+        return "-".to_string();
+    }
+    if (value.fract()).abs() < f64::EPSILON {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::budget::DegradationLevel;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn row_text(frame: &Frame, y: u16) -> String {
+        let width = frame.buffer.width();
+        let mut out = String::new();
+        for x in 0..width {
+            let ch = frame
+                .buffer
+                .get(x, y)
+                .and_then(|cell| cell.content.as_char())
+                .unwrap_or(' ');
+            out.push(ch);
+        }
+        out.trim_end().to_string()
+    }
+
+    #[test]
+    fn horizontal_exact_output() {
+        let bars = [Bar::new("cpu", 4.0), Bar::new("mem", 2.0)];
+        let chart = BarChart::new(&bars).max(4.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(16, 2, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 16, 2), &mut frame);
+
+        // label(3) + gap + bar(10) + gap + caption
+        assert_eq!(row_text(&frame, 0), "cpu ██████████ 4");
+        assert_eq!(row_text(&frame, 1), "mem █████      2");
+    }
+
+    #[test]
+    fn horizontal_partial_eighths() {
+        let bars = [Bar::new("x", 1.0)];
+        let chart = BarChart::new(&bars).max(16.0).show_values(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 1, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 4, 1), &mut frame);
+        // bar region = 2 cells => 16 eighths; value 1/16 => 1 eighth = ▏
+        assert_eq!(row_text(&frame, 0), "x ▏");
+    }
+
+    #[test]
+    fn label_elision() {
+        let bars = [Bar::new("very-long-label", 1.0)];
+        let chart = BarChart::new(&bars)
+            .max(1.0)
+            .label_width(6)
+            .show_values(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 1, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 12, 1), &mut frame);
+        assert!(row_text(&frame, 0).starts_with("very-…"));
+    }
+
+    #[test]
+    fn ascii_fallback_snapshot() {
+        let bars = [Bar::new("a", 4.0), Bar::new("b", 1.0)];
+        let chart = BarChart::new(&bars).max(4.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 2, &mut pool);
+        frame.buffer.degradation = DegradationLevel::SimpleBorders;
+        Widget::render(&chart, Rect::new(0, 0, 12, 2), &mut frame);
+
+        assert_eq!(row_text(&frame, 0), "a ######## 4");
+        assert_eq!(row_text(&frame, 1), "b ##       1");
+    }
+
+    #[test]
+    fn vertical_exact_output() {
+        let bars = [Bar::new("a", 4.0), Bar::new("b", 2.0)];
+        let chart = BarChart::new(&bars)
+            .max(4.0)
+            .show_values(false)
+            .orientation(BarOrientation::Vertical);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 3, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 4, 3), &mut frame);
+
+        // Two plot rows + label row; "a" is full, "b" half.
+        assert_eq!(row_text(&frame, 0), "█");
+        assert_eq!(row_text(&frame, 1), "█ █");
+        assert_eq!(row_text(&frame, 2), "a b");
+    }
+
+    #[test]
+    fn scroll_offset_skips_bars() {
+        let bars = [
+            Bar::new("one", 1.0),
+            Bar::new("two", 2.0),
+            Bar::new("three", 3.0),
+        ];
+        let chart = BarChart::new(&bars).max(3.0).show_values(false);
+        let mut state = BarChartState { offset: 2 };
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 1, &mut pool);
+        StatefulWidget::render(&chart, Rect::new(0, 0, 12, 1), &mut frame, &mut state);
+        assert!(row_text(&frame, 0).starts_with("three"));
+    }
+
+    #[test]
+    fn empty_and_negative_values_are_safe() {
+        let chart = BarChart::new(&[]);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 1, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 4, 1), &mut frame);
+        assert_eq!(row_text(&frame, 0), "");
+
+        let bars = [Bar::new("n", -5.0)];
+        let chart = BarChart::new(&bars);
+        Widget::render(&chart, Rect::new(0, 0, 4, 1), &mut frame);
+        // Negative clamps to a zero-length bar; at this width the caption
+        // has no room either, leaving just the label.
+        assert_eq!(row_text(&frame, 0), "n");
+    }
+
+    #[test]
+    fn tiny_area_never_panics() {
+        let bars = [Bar::new("label", 3.0)];
+        for (w, h) in [(1, 1), (2, 1), (1, 2), (3, 2)] {
+            let chart = BarChart::new(&bars);
+            let mut pool = GraphemePool::new();
+            let mut frame = Frame::new(w, h, &mut pool);
+            Widget::render(&chart, Rect::new(0, 0, w, h), &mut frame);
+            let chart = BarChart::new(&bars).orientation(BarOrientation::Vertical);
+            Widget::render(&chart, Rect::new(0, 0, w, h), &mut frame);
+        }
+    }
+}
@@ -0,0 +1,893 @@
+#![forbid(unsafe_code)]
+
+//! Calendar / date-navigation widget.
+//!
+//! A month grid (weekday header plus 4–6 week rows) with keyboard
+//! navigation, configurable week start, an optional ISO week-number
+//! column, single-date and range selection, min/max clamping, and
+//! app-supplied per-day decorations ("has events" markers).
+//!
+//! Date math is a small internal proleptic-Gregorian implementation
+//! ([`CalDate`]) — correct across leap years and month lengths without
+//! pulling in chrono.
+//!
+//! # Keys
+//!
+//! | key | action |
+//! |-----|--------|
+//! | arrows | move by day (Shift extends a range) |
+//! | PageUp / PageDown | previous / next month |
+//! | Shift+PageUp / Shift+PageDown | previous / next year |
+//! | Home / End | start / end of the week |
+//! | Enter | anchor a range, then complete it (range mode) |
+//! | Esc | abandon an in-progress range |
+
+use crate::{StatefulWidget, draw_text_span, set_style_area};
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+// ─── Date type ───────────────────────────────────────────────────────────────
+
+/// A proleptic-Gregorian calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalDate {
+    pub year: i32,
+    /// 1-based month.
+    pub month: u8,
+    /// 1-based day of month.
+    pub day: u8,
+}
+
+impl CalDate {
+    /// A validated date, or `None` for out-of-range month/day.
+    #[must_use]
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Gregorian leap-year rule.
+    #[must_use]
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Number of days in a month.
+    #[must_use]
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
+    /// Days since 1970-01-01 (negative before), via the days-from-civil
+    /// algorithm.
+    #[must_use]
+    pub fn to_days(self) -> i64 {
+        let y = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400; // [0, 399]
+        let m = i64::from(self.month);
+        let d = i64::from(self.day);
+        let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1; // [0, 365]
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Inverse of [`to_days`](Self::to_days).
+    #[must_use]
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+        let doe = z - era * 146_097; // [0, 146096]
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // [0, 399]
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+        let mp = (5 * doy + 2) / 153; // [0, 11]
+        let d = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+        let m = mp + if mp < 10 { 3 } else { -9 }; // [1, 12]
+        Self {
+            year: (y + i64::from(m <= 2)) as i32,
+            month: m as u8,
+            day: d as u8,
+        }
+    }
+
+    /// This date shifted by `n` days (either direction).
+    #[must_use]
+    pub fn add_days(self, n: i64) -> Self {
+        Self::from_days(self.to_days() + n)
+    }
+
+    /// This date shifted by `n` months, clamping the day to the target
+    /// month's length (Jan 31 + 1 month → Feb 28/29).
+    #[must_use]
+    pub fn add_months(self, n: i32) -> Self {
+        let total = i64::from(self.year) * 12 + i64::from(self.month) - 1 + i64::from(n);
+        let year = total.div_euclid(12) as i32;
+        let month = (total.rem_euclid(12) + 1) as u8;
+        let day = self.day.min(Self::days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// Weekday, 0 = Monday … 6 = Sunday (1970-01-01 was a Thursday).
+    #[must_use]
+    pub fn weekday(self) -> u8 {
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+
+    /// ISO 8601 week number (weeks start Monday; week 1 holds Jan 4).
+    #[must_use]
+    pub fn iso_week(self) -> u8 {
+        let thursday = self.add_days(3 - i64::from(self.weekday()));
+        let jan1 = Self {
+            year: thursday.year,
+            month: 1,
+            day: 1,
+        };
+        ((thursday.to_days() - jan1.to_days()) / 7 + 1) as u8
+    }
+}
+
+// ─── Configuration ───────────────────────────────────────────────────────────
+
+/// First day of the week (locale-dependent).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+/// Selection behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalendarSelectionMode {
+    /// The cursor is the selection.
+    #[default]
+    Single,
+    /// Shift+arrows or two Enter presses select an inclusive span.
+    Range,
+}
+
+/// App-supplied decoration for one day.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DayDecoration {
+    /// Marker drawn after the day number (e.g. `'•'` for "has events").
+    pub marker: Option<char>,
+    /// Style merged onto the day cell.
+    pub style: Option<Style>,
+}
+
+/// Selection-change events emitted by [`Calendar::handle_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarEvent {
+    /// The cursor/selected date changed.
+    SelectionChanged(CalDate),
+    /// A range was extended or completed (inclusive, start ≤ end).
+    RangeChanged { start: CalDate, end: CalDate },
+}
+
+/// Mutable calendar state: cursor date and in-progress/completed range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarState {
+    /// The focused date; also the single-mode selection.
+    pub cursor: CalDate,
+    /// Range anchor while a range is being built.
+    anchor: Option<CalDate>,
+    /// Last completed range (inclusive, normalized start ≤ end).
+    range: Option<(CalDate, CalDate)>,
+}
+
+impl CalendarState {
+    /// State focused on the given date.
+    #[must_use]
+    pub fn new(cursor: CalDate) -> Self {
+        Self {
+            cursor,
+            anchor: None,
+            range: None,
+        }
+    }
+
+    /// The completed range, if any.
+    #[must_use]
+    pub fn range(&self) -> Option<(CalDate, CalDate)> {
+        self.range
+    }
+
+    /// The in-progress range anchor, if any.
+    #[must_use]
+    pub fn anchor(&self) -> Option<CalDate> {
+        self.anchor
+    }
+
+    fn normalized_span(&self) -> Option<(CalDate, CalDate)> {
+        match (self.anchor, self.range) {
+            (Some(anchor), _) => Some(if anchor <= self.cursor {
+                (anchor, self.cursor)
+            } else {
+                (self.cursor, anchor)
+            }),
+            (None, Some(span)) => Some(span),
+            (None, None) => None,
+        }
+    }
+}
+
+const WEEKDAY_LABELS_MON: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+const WEEKDAY_LABELS_SUN: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+type Decorator<'a> = dyn Fn(CalDate) -> Option<DayDecoration> + 'a;
+
+/// Month-grid calendar widget (see the module docs).
+pub struct Calendar<'a> {
+    week_start: WeekStart,
+    show_week_numbers: bool,
+    mode: CalendarSelectionMode,
+    min: Option<CalDate>,
+    max: Option<CalDate>,
+    style: Style,
+    /// Cursor-day style.
+    cursor_style: Style,
+    /// Range-span style.
+    range_style: Style,
+    /// Weekday header / week-number style.
+    header_style: Style,
+    decorator: Option<&'a Decorator<'a>>,
+}
+
+impl Default for Calendar<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Calendar<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            week_start: WeekStart::default(),
+            show_week_numbers: false,
+            mode: CalendarSelectionMode::default(),
+            min: None,
+            max: None,
+            style: Style::default(),
+            cursor_style: Style::new().reverse(),
+            range_style: Style::new().underline(),
+            header_style: Style::new().bold(),
+            decorator: None,
+        }
+    }
+
+    /// Set the first day of the week.
+    #[must_use]
+    pub fn week_start(mut self, start: WeekStart) -> Self {
+        self.week_start = start;
+        self
+    }
+
+    /// Show the ISO week-number column.
+    #[must_use]
+    pub fn show_week_numbers(mut self, show: bool) -> Self {
+        self.show_week_numbers = show;
+        self
+    }
+
+    /// Set the selection mode.
+    #[must_use]
+    pub fn selection_mode(mut self, mode: CalendarSelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Clamp navigation/selection to `min..=max` (either bound optional).
+    #[must_use]
+    pub fn selectable_range(mut self, min: Option<CalDate>, max: Option<CalDate>) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Base style for the grid.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style for the cursor day.
+    #[must_use]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Style for days inside the selected range.
+    #[must_use]
+    pub fn range_style(mut self, style: Style) -> Self {
+        self.range_style = style;
+        self
+    }
+
+    /// Style for the header row and week numbers.
+    #[must_use]
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Per-day decorations: marker and/or style for a date.
+    #[must_use]
+    pub fn decorator(mut self, decorator: &'a Decorator<'a>) -> Self {
+        self.decorator = Some(decorator);
+        self
+    }
+
+    fn clamp(&self, date: CalDate) -> CalDate {
+        let mut date = date;
+        if let Some(min) = self.min
+            && date < min
+        {
+            date = min;
+        }
+        if let Some(max) = self.max
+            && date > max
+        {
+            date = max;
+        }
+        date
+    }
+
+    /// Column of a weekday (0-based) under the configured week start.
+    fn weekday_column(&self, date: CalDate) -> u8 {
+        match self.week_start {
+            WeekStart::Monday => date.weekday(),
+            WeekStart::Sunday => (date.weekday() + 1) % 7,
+        }
+    }
+
+    /// Handle a key press; returns the selection event it caused, if any.
+    pub fn handle_key(&self, state: &mut CalendarState, key: &KeyEvent) -> Option<CalendarEvent> {
+        let shift = key.modifiers.contains(Modifiers::SHIFT);
+        let ranging = self.mode == CalendarSelectionMode::Range;
+        let target = match key.code {
+            KeyCode::Left => Some(state.cursor.add_days(-1)),
+            KeyCode::Right => Some(state.cursor.add_days(1)),
+            KeyCode::Up => Some(state.cursor.add_days(-7)),
+            KeyCode::Down => Some(state.cursor.add_days(7)),
+            KeyCode::PageUp => Some(state.cursor.add_months(if shift { -12 } else { -1 })),
+            KeyCode::PageDown => Some(state.cursor.add_months(if shift { 12 } else { 1 })),
+            KeyCode::Home => Some(
+                state
+                    .cursor
+                    .add_days(-i64::from(self.weekday_column(state.cursor))),
+            ),
+            KeyCode::End => Some(
+                state
+                    .cursor
+                    .add_days(6 - i64::from(self.weekday_column(state.cursor))),
+            ),
+            KeyCode::Enter if ranging => {
+                return match state.anchor.take() {
+                    None => {
+                        state.anchor = Some(state.cursor);
+                        None
+                    }
+                    Some(anchor) => {
+                        let span = if anchor <= state.cursor {
+                            (anchor, state.cursor)
+                        } else {
+                            (state.cursor, anchor)
+                        };
+                        state.range = Some(span);
+                        Some(CalendarEvent::RangeChanged {
+                            start: span.0,
+                            end: span.1,
+                        })
+                    }
+                };
+            }
+            KeyCode::Escape if state.anchor.is_some() => {
+                state.anchor = None;
+                return None;
+            }
+            _ => None,
+        };
+        let target = self.clamp(target?);
+        if target == state.cursor {
+            return None;
+        }
+
+        // Shift+movement extends a range from the current cursor.
+        if ranging
+            && shift
+            && state.anchor.is_none()
+            && !matches!(key.code, KeyCode::PageUp | KeyCode::PageDown)
+        {
+            state.anchor = Some(state.cursor);
+        }
+        state.cursor = target;
+
+        if let Some(anchor) = state.anchor
+            && ranging
+            && shift
+        {
+            let span = if anchor <= target {
+                (anchor, target)
+            } else {
+                (target, anchor)
+            };
+            state.range = Some(span);
+            return Some(CalendarEvent::RangeChanged {
+                start: span.0,
+                end: span.1,
+            });
+        }
+        Some(CalendarEvent::SelectionChanged(target))
+    }
+}
+
+impl StatefulWidget for Calendar<'_> {
+    type State = CalendarState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        set_style_area(&mut frame.buffer, area, self.style);
+
+        let gutter: u16 = if self.show_week_numbers { 3 } else { 0 };
+        let mut y = area.y;
+
+        // Title: "March 2024".
+        let title = format!(
+            "{} {}",
+            MONTH_NAMES[usize::from(state.cursor.month - 1)],
+            state.cursor.year
+        );
+        draw_text_span(
+            frame,
+            area.x + gutter,
+            y,
+            &title,
+            self.header_style,
+            area.right(),
+        );
+        y += 1;
+
+        // Weekday header.
+        if y >= area.bottom() {
+            return;
+        }
+        let labels = match self.week_start {
+            WeekStart::Monday => &WEEKDAY_LABELS_MON,
+            WeekStart::Sunday => &WEEKDAY_LABELS_SUN,
+        };
+        for (col, label) in labels.iter().enumerate() {
+            draw_text_span(
+                frame,
+                area.x + gutter + (col as u16) * 3,
+                y,
+                label,
+                self.header_style,
+                area.right(),
+            );
+        }
+        y += 1;
+
+        // Week rows.
+        let first = CalDate {
+            year: state.cursor.year,
+            month: state.cursor.month,
+            day: 1,
+        };
+        let days = CalDate::days_in_month(first.year, first.month);
+        let lead = self.weekday_column(first);
+        let span = state.normalized_span();
+
+        let mut day = 1u8;
+        while day <= days {
+            if y >= area.bottom() {
+                break;
+            }
+            let row_start_day = day;
+            if self.show_week_numbers {
+                let week = CalDate {
+                    day: row_start_day,
+                    ..first
+                }
+                .iso_week();
+                draw_text_span(
+                    frame,
+                    area.x,
+                    y,
+                    &format!("{week:>2}"),
+                    self.header_style,
+                    area.right(),
+                );
+            }
+            let start_col = if day == 1 { lead } else { 0 };
+            for col in start_col..7 {
+                if day > days {
+                    break;
+                }
+                let date = CalDate { day, ..first };
+                let x = area.x + gutter + u16::from(col) * 3;
+                if x + 2 > area.right() {
+                    break;
+                }
+
+                let mut style = self.style;
+                let decoration = self.decorator.and_then(|f| f(date));
+                if let Some(decoration) = decoration
+                    && let Some(deco_style) = decoration.style
+                {
+                    style = deco_style.merge(&style);
+                }
+                let in_span = span.is_some_and(|(s, e)| date >= s && date <= e);
+                if in_span {
+                    style = self.range_style.merge(&style);
+                }
+                if date == state.cursor {
+                    style = self.cursor_style.merge(&style);
+                }
+
+                let cell_area = Rect::new(x, y, 2, 1);
+                set_style_area(&mut frame.buffer, cell_area, style);
+                draw_text_span(frame, x, y, &format!("{day:>2}"), style, area.right());
+                if let Some(decoration) = decoration
+                    && let Some(marker) = decoration.marker
+                    && x + 2 < area.right()
+                {
+                    draw_text_span(frame, x + 2, y, &marker.to_string(), style, area.right());
+                }
+                day += 1;
+            }
+            y += 1;
+        }
+    }
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::KeyEventKind;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn date(y: i32, m: u8, d: u8) -> CalDate {
+        CalDate::new(y, m, d).expect("valid test date")
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: Modifiers::NONE,
+            kind: KeyEventKind::Press,
+            ch: None,
+        }
+    }
+
+    fn shift_key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: Modifiers::SHIFT,
+            kind: KeyEventKind::Press,
+            ch: None,
+        }
+    }
+
+    fn rendered(calendar: &Calendar<'_>, state: &mut CalendarState, w: u16, h: u16) -> Vec<String> {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(w, h, &mut pool);
+        calendar.render(Rect::new(0, 0, w, h), &mut frame, state);
+        (0..h)
+            .map(|y| {
+                (0..w)
+                    .map(|x| {
+                        frame
+                            .buffer
+                            .get(x, y)
+                            .and_then(|c| c.content.as_char())
+                            .unwrap_or(' ')
+                    })
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    // ── Date math ────────────────────────────────────────────────────
+
+    #[test]
+    fn leap_year_rules() {
+        assert!(CalDate::is_leap_year(2024));
+        assert!(!CalDate::is_leap_year(2023));
+        assert!(!CalDate::is_leap_year(1900));
+        assert!(CalDate::is_leap_year(2000));
+        assert_eq!(CalDate::days_in_month(2024, 2), 29);
+        assert_eq!(CalDate::days_in_month(2023, 2), 28);
+    }
+
+    #[test]
+    fn day_arithmetic_roundtrips() {
+        let d = date(2024, 2, 28);
+        assert_eq!(d.add_days(1), date(2024, 2, 29));
+        assert_eq!(d.add_days(2), date(2024, 3, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2023, 3, 1).add_days(-1), date(2023, 2, 28));
+        // Round-trip across a wide span.
+        for offset in [-100_000i64, -365, -1, 0, 1, 365, 100_000] {
+            let shifted = d.add_days(offset);
+            assert_eq!(shifted.to_days(), d.to_days() + offset);
+            assert_eq!(CalDate::from_days(shifted.to_days()), shifted);
+        }
+    }
+
+    #[test]
+    fn month_arithmetic_clamps_day() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 12, 15).add_months(1), date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 2, 29).add_months(12), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn weekday_is_correct() {
+        assert_eq!(date(1970, 1, 1).weekday(), 3, "1970-01-01 was a Thursday");
+        assert_eq!(date(2024, 2, 29).weekday(), 3, "2024-02-29 was a Thursday");
+        assert_eq!(date(2024, 3, 4).weekday(), 0, "2024-03-04 was a Monday");
+        assert_eq!(date(2024, 3, 3).weekday(), 6, "2024-03-03 was a Sunday");
+    }
+
+    #[test]
+    fn iso_week_numbers() {
+        assert_eq!(date(2024, 1, 4).iso_week(), 1);
+        assert_eq!(date(2024, 12, 30).iso_week(), 1, "belongs to 2025-W01");
+        assert_eq!(date(2021, 1, 1).iso_week(), 53, "belongs to 2020-W53");
+        assert_eq!(date(2024, 2, 29).iso_week(), 9);
+    }
+
+    // ── Navigation ───────────────────────────────────────────────────
+
+    #[test]
+    fn navigation_across_feb_29() {
+        let calendar = Calendar::new();
+        let mut state = CalendarState::new(date(2024, 2, 28));
+        let event = calendar.handle_key(&mut state, &key(KeyCode::Right));
+        assert_eq!(
+            event,
+            Some(CalendarEvent::SelectionChanged(date(2024, 2, 29)))
+        );
+        calendar.handle_key(&mut state, &key(KeyCode::Right));
+        assert_eq!(state.cursor, date(2024, 3, 1));
+        calendar.handle_key(&mut state, &key(KeyCode::Up));
+        assert_eq!(state.cursor, date(2024, 2, 23));
+    }
+
+    #[test]
+    fn page_keys_move_month_and_year() {
+        let calendar = Calendar::new();
+        let mut state = CalendarState::new(date(2024, 3, 31));
+        calendar.handle_key(&mut state, &key(KeyCode::PageUp));
+        assert_eq!(state.cursor, date(2024, 2, 29), "clamped to leap February");
+        calendar.handle_key(&mut state, &shift_key(KeyCode::PageDown));
+        assert_eq!(state.cursor, date(2025, 2, 28), "year jump clamps day");
+    }
+
+    #[test]
+    fn home_end_respect_week_start() {
+        // 2024-03-06 was a Wednesday.
+        let monday_cal = Calendar::new();
+        let mut state = CalendarState::new(date(2024, 3, 6));
+        monday_cal.handle_key(&mut state, &key(KeyCode::Home));
+        assert_eq!(state.cursor, date(2024, 3, 4), "Monday start");
+        monday_cal.handle_key(&mut state, &key(KeyCode::End));
+        assert_eq!(state.cursor, date(2024, 3, 10));
+
+        let sunday_cal = Calendar::new().week_start(WeekStart::Sunday);
+        let mut state = CalendarState::new(date(2024, 3, 6));
+        sunday_cal.handle_key(&mut state, &key(KeyCode::Home));
+        assert_eq!(state.cursor, date(2024, 3, 3), "Sunday start");
+        sunday_cal.handle_key(&mut state, &key(KeyCode::End));
+        assert_eq!(state.cursor, date(2024, 3, 9));
+    }
+
+    #[test]
+    fn min_max_clamp_at_edges() {
+        let calendar =
+            Calendar::new().selectable_range(Some(date(2024, 3, 5)), Some(date(2024, 3, 20)));
+        let mut state = CalendarState::new(date(2024, 3, 5));
+        assert_eq!(
+            calendar.handle_key(&mut state, &key(KeyCode::Left)),
+            None,
+            "clamped move emits nothing"
+        );
+        assert_eq!(state.cursor, date(2024, 3, 5));
+
+        state.cursor = date(2024, 3, 20);
+        assert_eq!(calendar.handle_key(&mut state, &key(KeyCode::Down)), None);
+        assert_eq!(state.cursor, date(2024, 3, 20));
+        // A month jump clamps to max instead of leaving the window.
+        assert_eq!(
+            calendar.handle_key(&mut state, &key(KeyCode::Up)),
+            Some(CalendarEvent::SelectionChanged(date(2024, 3, 13)))
+        );
+    }
+
+    // ── Range selection ──────────────────────────────────────────────
+
+    #[test]
+    fn range_via_two_enter_presses() {
+        let calendar = Calendar::new().selection_mode(CalendarSelectionMode::Range);
+        let mut state = CalendarState::new(date(2024, 3, 10));
+        assert_eq!(calendar.handle_key(&mut state, &key(KeyCode::Enter)), None);
+        assert_eq!(state.anchor(), Some(date(2024, 3, 10)));
+        for _ in 0..5 {
+            calendar.handle_key(&mut state, &key(KeyCode::Right));
+        }
+        let event = calendar.handle_key(&mut state, &key(KeyCode::Enter));
+        assert_eq!(
+            event,
+            Some(CalendarEvent::RangeChanged {
+                start: date(2024, 3, 10),
+                end: date(2024, 3, 15),
+            })
+        );
+        assert_eq!(state.range(), Some((date(2024, 3, 10), date(2024, 3, 15))));
+        assert_eq!(state.anchor(), None);
+    }
+
+    #[test]
+    fn shift_arrow_range_spans_months() {
+        let calendar = Calendar::new().selection_mode(CalendarSelectionMode::Range);
+        let mut state = CalendarState::new(date(2024, 3, 30));
+        for _ in 0..4 {
+            calendar.handle_key(&mut state, &shift_key(KeyCode::Right));
+        }
+        assert_eq!(
+            state.range(),
+            Some((date(2024, 3, 30), date(2024, 4, 3))),
+            "range crosses the month boundary"
+        );
+        // Backwards extension normalizes start ≤ end.
+        let mut state = CalendarState::new(date(2024, 4, 2));
+        for _ in 0..5 {
+            calendar.handle_key(&mut state, &shift_key(KeyCode::Left));
+        }
+        assert_eq!(state.range(), Some((date(2024, 3, 28), date(2024, 4, 2))));
+    }
+
+    #[test]
+    fn escape_abandons_in_progress_range() {
+        let calendar = Calendar::new().selection_mode(CalendarSelectionMode::Range);
+        let mut state = CalendarState::new(date(2024, 3, 10));
+        calendar.handle_key(&mut state, &key(KeyCode::Enter));
+        assert!(state.anchor().is_some());
+        calendar.handle_key(&mut state, &key(KeyCode::Escape));
+        assert_eq!(state.anchor(), None);
+        assert_eq!(state.range(), None);
+    }
+
+    // ── Rendering ────────────────────────────────────────────────────
+
+    #[test]
+    fn renders_month_grid_monday_start() {
+        let calendar = Calendar::new();
+        let mut state = CalendarState::new(date(2024, 3, 15));
+        let rows = rendered(&calendar, &mut state, 24, 9);
+        assert_eq!(rows[0], "March 2024");
+        assert_eq!(rows[1], "Mo Tu We Th Fr Sa Su");
+        // March 1 2024 was a Friday → column 4 (x = 12).
+        assert!(
+            rows[2].starts_with("             1  2  3"),
+            "row: {:?}",
+            rows[2]
+        );
+        assert!(rows[3].starts_with(" 4  5  6"), "row: {:?}", rows[3]);
+        assert!(rows.iter().any(|r| r.contains("31")), "last day present");
+    }
+
+    #[test]
+    fn renders_sunday_start_layout() {
+        let calendar = Calendar::new().week_start(WeekStart::Sunday);
+        let mut state = CalendarState::new(date(2024, 3, 15));
+        let rows = rendered(&calendar, &mut state, 24, 9);
+        assert_eq!(rows[1], "Su Mo Tu We Th Fr Sa");
+        // Sunday start: March 1 (Friday) lands in column 5 (x = 15).
+        assert!(
+            rows[2].starts_with("                1  2"),
+            "row: {:?}",
+            rows[2]
+        );
+        assert!(rows[3].starts_with(" 3  4  5"), "row: {:?}", rows[3]);
+    }
+
+    #[test]
+    fn renders_week_number_column() {
+        let calendar = Calendar::new().show_week_numbers(true);
+        let mut state = CalendarState::new(date(2024, 3, 15));
+        let rows = rendered(&calendar, &mut state, 28, 9);
+        // Week of March 4 2024 is ISO week 10.
+        assert!(rows[3].starts_with("10  4  5"), "row: {:?}", rows[3]);
+    }
+
+    #[test]
+    fn decorations_render_marker_and_style() {
+        let decorate = |d: CalDate| {
+            (d.day == 15).then_some(DayDecoration {
+                marker: Some('•'),
+                style: Some(Style::new().bold()),
+            })
+        };
+        let calendar = Calendar::new().decorator(&decorate);
+        let mut state = CalendarState::new(date(2024, 3, 1));
+        let rows = rendered(&calendar, &mut state, 24, 9);
+        assert!(
+            rows.iter().any(|r| r.contains("15•")),
+            "marker follows the day: {rows:?}"
+        );
+    }
+
+    #[test]
+    fn cursor_style_applied_to_cursor_cell() {
+        use ftui_render::cell::PackedRgba;
+        let cursor_fg = PackedRgba::rgb(250, 1, 2);
+        let calendar = Calendar::new().cursor_style(Style::new().fg(cursor_fg));
+        let mut state = CalendarState::new(date(2024, 3, 15));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(24, 9, &mut pool);
+        calendar.render(Rect::new(0, 0, 24, 9), &mut frame, &mut state);
+        // Find the '15' cell and check its style.
+        let mut found = false;
+        for y in 0..9 {
+            for x in 0..23 {
+                let a = frame.buffer.get(x, y).and_then(|c| c.content.as_char());
+                let b = frame.buffer.get(x + 1, y).and_then(|c| c.content.as_char());
+                if a == Some('1') && b == Some('5') {
+                    assert_eq!(frame.buffer.get(x, y).unwrap().fg, cursor_fg);
+                    found = true;
+                }
+            }
+        }
+        assert!(found, "cursor day rendered");
+    }
+
+    #[test]
+    fn render_no_panic_tiny_area() {
+        let calendar = Calendar::new();
+        let mut state = CalendarState::new(date(2024, 3, 15));
+        let _ = rendered(&calendar, &mut state, 5, 2);
+        // Empty render area is an early return.
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 4, &mut pool);
+        calendar.render(Rect::new(0, 0, 0, 0), &mut frame, &mut state);
+    }
+}
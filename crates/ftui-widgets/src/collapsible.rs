@@ -0,0 +1,335 @@
+#![forbid(unsafe_code)]
+
+//! Collapsible section panel for accordion sidebars.
+//!
+//! [`CollapsiblePanel`] renders one section: an always-visible header
+//! row with a disclosure glyph (`▸`/`▾`) and title. Collapse state is
+//! the app's — the widget only reports toggle requests
+//! ([`CollapsibleEvent::ToggleRequested`]) from Enter/Space on a focused
+//! header or a click anywhere in the header rect. Content rendering is
+//! the app's too, into the rect resolved by
+//! [`Accordion`](ftui_layout::Accordion).
+//!
+//! [`AccordionFocus`] tracks which header (or expanded content) holds
+//! keyboard focus: Up/Down move between headers, Tab steps into the
+//! focused section's expanded content and on to the next header.
+
+use crate::{Widget, draw_text_span, set_style_area};
+use ftui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+/// Disclosure glyphs for collapsed/expanded headers.
+const GLYPH_COLLAPSED: &str = "▸";
+const GLYPH_EXPANDED: &str = "▾";
+
+/// What a header interaction produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollapsibleEvent {
+    /// The user asked to toggle this section; apply it to app state
+    /// (directly or via [`ftui_layout::toggle_exclusive`]).
+    ToggleRequested,
+    /// Not for this widget.
+    Ignored,
+}
+
+/// One accordion section's header widget.
+#[derive(Debug, Clone)]
+pub struct CollapsiblePanel {
+    title: String,
+    expanded: bool,
+    focused: bool,
+    style: Style,
+    focused_style: Style,
+}
+
+impl CollapsiblePanel {
+    /// Create a header with `title`.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            expanded: false,
+            focused: false,
+            style: Style::new(),
+            focused_style: Style::new().reverse(),
+        }
+    }
+
+    /// Expanded state (builder; drawn as the disclosure glyph).
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Keyboard focus (builder; highlights the header row).
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Header style (builder).
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Focused header style (builder).
+    #[must_use]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Handle a key while this header is focused.
+    pub fn handle_key(&self, key: &KeyEvent) -> CollapsibleEvent {
+        if !self.focused {
+            return CollapsibleEvent::Ignored;
+        }
+        match key.code {
+            KeyCode::Enter | KeyCode::Char(' ') => CollapsibleEvent::ToggleRequested,
+            _ => CollapsibleEvent::Ignored,
+        }
+    }
+
+    /// Handle a mouse event against the rendered `header` rect.
+    pub fn handle_mouse(&self, event: &MouseEvent, header: Rect) -> CollapsibleEvent {
+        if matches!(event.kind, MouseEventKind::Down(MouseButton::Left))
+            && event.x >= header.x
+            && event.x < header.right()
+            && event.y >= header.y
+            && event.y < header.bottom()
+        {
+            CollapsibleEvent::ToggleRequested
+        } else {
+            CollapsibleEvent::Ignored
+        }
+    }
+}
+
+impl Widget for CollapsiblePanel {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() {
+            return;
+        }
+        let style = if self.focused {
+            self.focused_style
+        } else {
+            self.style
+        };
+        let header = Rect::new(area.x, area.y, area.width, 1);
+        set_style_area(&mut frame.buffer, header, style);
+        let glyph = if self.expanded {
+            GLYPH_EXPANDED
+        } else {
+            GLYPH_COLLAPSED
+        };
+        draw_text_span(
+            frame,
+            area.x,
+            area.y,
+            &format!("{glyph} {}", self.title),
+            style,
+            area.right(),
+        );
+    }
+}
+
+/// Where accordion keyboard focus sits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccordionFocusPos {
+    /// A section header.
+    Header(usize),
+    /// Inside a section's expanded content.
+    Content(usize),
+}
+
+/// Keyboard focus tracker over an accordion's headers and contents.
+///
+/// Up/Down move between headers (leaving content first); Tab steps from
+/// a header into its expanded content, or on to the next header when the
+/// section is collapsed; Tab from content continues to the next header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccordionFocus {
+    /// Current focus position.
+    pub position: AccordionFocusPos,
+}
+
+impl Default for AccordionFocus {
+    fn default() -> Self {
+        Self {
+            position: AccordionFocusPos::Header(0),
+        }
+    }
+}
+
+impl AccordionFocus {
+    /// Focus the first header.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The focused header index, if a header is focused.
+    #[must_use]
+    pub fn header(&self) -> Option<usize> {
+        match self.position {
+            AccordionFocusPos::Header(idx) => Some(idx),
+            AccordionFocusPos::Content(_) => None,
+        }
+    }
+
+    /// Handle a navigation key over `expanded` (one flag per section).
+    /// Returns `true` when the focus moved.
+    pub fn handle_key(&mut self, key: &KeyEvent, expanded: &[bool]) -> bool {
+        if expanded.is_empty() {
+            return false;
+        }
+        let count = expanded.len();
+        let current = match self.position {
+            AccordionFocusPos::Header(idx) | AccordionFocusPos::Content(idx) => idx.min(count - 1),
+        };
+        let in_content = matches!(self.position, AccordionFocusPos::Content(_));
+        match key.code {
+            KeyCode::Up => {
+                // Leaving content lands on the section's own header first.
+                let prev = if in_content {
+                    current
+                } else if current == 0 {
+                    count - 1
+                } else {
+                    current - 1
+                };
+                self.position = AccordionFocusPos::Header(prev);
+                true
+            }
+            KeyCode::Down => {
+                let next = (current + 1) % count;
+                self.position = AccordionFocusPos::Header(next);
+                true
+            }
+            KeyCode::Tab => {
+                self.position = match self.position {
+                    AccordionFocusPos::Header(idx) if expanded.get(idx) == Some(&true) => {
+                        AccordionFocusPos::Content(idx)
+                    }
+                    AccordionFocusPos::Header(idx) | AccordionFocusPos::Content(idx) => {
+                        AccordionFocusPos::Header((idx + 1) % count)
+                    }
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::Modifiers;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code)
+    }
+
+    #[test]
+    fn keyboard_toggle_requires_focus() {
+        let unfocused = CollapsiblePanel::new("Files");
+        assert_eq!(
+            unfocused.handle_key(&key(KeyCode::Enter)),
+            CollapsibleEvent::Ignored
+        );
+
+        let focused = CollapsiblePanel::new("Files").focused(true);
+        assert_eq!(
+            focused.handle_key(&key(KeyCode::Enter)),
+            CollapsibleEvent::ToggleRequested
+        );
+        assert_eq!(
+            focused.handle_key(&key(KeyCode::Char(' '))),
+            CollapsibleEvent::ToggleRequested
+        );
+        assert_eq!(
+            focused.handle_key(&key(KeyCode::Char('x'))),
+            CollapsibleEvent::Ignored
+        );
+    }
+
+    #[test]
+    fn mouse_toggle_hits_header_rect_only() {
+        let panel = CollapsiblePanel::new("Files");
+        let header = Rect::new(2, 5, 20, 1);
+        let inside = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 10, 5)
+            .with_modifiers(Modifiers::NONE);
+        assert_eq!(
+            panel.handle_mouse(&inside, header),
+            CollapsibleEvent::ToggleRequested
+        );
+        let below = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 10, 6);
+        assert_eq!(
+            panel.handle_mouse(&below, header),
+            CollapsibleEvent::Ignored
+        );
+        let hover = MouseEvent::new(MouseEventKind::Moved, 10, 5);
+        assert_eq!(
+            panel.handle_mouse(&hover, header),
+            CollapsibleEvent::Ignored
+        );
+    }
+
+    #[test]
+    fn renders_disclosure_glyph_and_title() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 2, &mut pool);
+        CollapsiblePanel::new("Files")
+            .expanded(true)
+            .render(Rect::new(0, 0, 20, 1), &mut frame);
+        assert_eq!(frame.buffer.get(0, 0).unwrap().content.as_char(), Some('▾'));
+        assert_eq!(frame.buffer.get(2, 0).unwrap().content.as_char(), Some('F'));
+
+        CollapsiblePanel::new("Git").render(Rect::new(0, 1, 20, 1), &mut frame);
+        assert_eq!(frame.buffer.get(0, 1).unwrap().content.as_char(), Some('▸'));
+    }
+
+    #[test]
+    fn focus_order_with_mixed_sections() {
+        // Sections: 0 collapsed, 1 expanded, 2 collapsed.
+        let expanded = [false, true, false];
+        let mut focus = AccordionFocus::new();
+        assert_eq!(focus.position, AccordionFocusPos::Header(0));
+
+        // Down moves between headers.
+        assert!(focus.handle_key(&key(KeyCode::Down), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Header(1));
+
+        // Tab enters the expanded content…
+        assert!(focus.handle_key(&key(KeyCode::Tab), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Content(1));
+        // …and continues to the next header from inside it.
+        assert!(focus.handle_key(&key(KeyCode::Tab), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Header(2));
+
+        // Tab on a collapsed header skips straight to the next header.
+        assert!(focus.handle_key(&key(KeyCode::Tab), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Header(0));
+
+        // Up from content returns to the section's own header first.
+        focus.position = AccordionFocusPos::Content(1);
+        assert!(focus.handle_key(&key(KeyCode::Up), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Header(1));
+        // Down from content moves past the section.
+        focus.position = AccordionFocusPos::Content(1);
+        assert!(focus.handle_key(&key(KeyCode::Down), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Header(2));
+
+        // Wrap-around upward from the first header.
+        focus.position = AccordionFocusPos::Header(0);
+        assert!(focus.handle_key(&key(KeyCode::Up), &expanded));
+        assert_eq!(focus.position, AccordionFocusPos::Header(2));
+    }
+}
@@ -0,0 +1,773 @@
+#![forbid(unsafe_code)]
+
+//! Interactive color picker: named 16, 256 palette, and truecolor modes.
+//!
+//! Theming and drawing tools pick colors through three navigable modes:
+//!
+//! - **Named16** — a 8x2 grid of the standard ANSI colors.
+//! - **Palette256** — the 6x6x6 cube (rows = green axis, columns =
+//!   red*6+blue) with the 24-step grayscale ramp as a final row.
+//! - **TrueColor** — a hue row plus a saturation/value plane of
+//!   background-colored cells, with a hex input field; gated off on
+//!   non-truecolor terminals, where the picker instead shows the
+//!   nearest-match downgrade (`rgb_to_256`) it would produce.
+//!
+//! The selection is exposed as [`ftui_style::Color`]; change events fire
+//! on every navigation step or only on Enter per
+//! [`ColorPicker::emit_on_navigation`].
+
+use crate::draw_text_span;
+use crate::input::TextInput;
+use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::frame::Frame;
+use ftui_style::color::{ansi16_to_rgb, ansi256_to_rgb, rgb_to_256};
+use ftui_style::{Ansi16, Color, Style};
+
+/// Which palette the picker is navigating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorPickerMode {
+    /// 16 named ANSI colors.
+    #[default]
+    Named16,
+    /// 256-color palette (cube + grayscale ramp).
+    Palette256,
+    /// 24-bit hue/saturation/value picking with hex entry.
+    TrueColor,
+}
+
+/// Emitted by [`ColorPicker::handle_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorPickerEvent {
+    /// The selection changed (navigation or valid hex entry).
+    Changed(Color),
+    /// Enter confirmed the current selection.
+    Submitted(Color),
+}
+
+/// Grid geometry of the 256 palette: cube rows then the gray ramp row.
+const CUBE_COLS: u8 = 36; // red * 6 + blue
+const CUBE_ROWS: u8 = 6; // green
+const GRAY_STEPS: u8 = 24;
+
+/// Mutable picker state.
+#[derive(Debug, Clone)]
+pub struct ColorPickerState {
+    /// Active mode.
+    pub mode: ColorPickerMode,
+    /// Named16: 0..16.
+    named: u8,
+    /// Palette256 grid position: row 0..=6 (6 = gray ramp), col.
+    grid_row: u8,
+    grid_col: u8,
+    /// TrueColor HSV.
+    hue: u16,
+    saturation: u8,
+    value: u8,
+    /// Hex entry field (focused with Tab in TrueColor mode).
+    hex: TextInput,
+    hex_focused: bool,
+}
+
+impl Default for ColorPickerState {
+    fn default() -> Self {
+        Self {
+            mode: ColorPickerMode::default(),
+            named: 0,
+            grid_row: 0,
+            grid_col: 0,
+            hue: 0,
+            saturation: 100,
+            value: 100,
+            hex: TextInput::new().with_max_length(6),
+            hex_focused: false,
+        }
+    }
+}
+
+impl ColorPickerState {
+    /// Create default state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current 256-palette index for the grid position.
+    ///
+    /// Cube rows map as `16 + 36*green + col` (col = red*6 + blue); the
+    /// ramp row maps to `232 + col`.
+    #[must_use]
+    pub fn palette_index(&self) -> u8 {
+        if self.grid_row < CUBE_ROWS {
+            16 + self.grid_row * CUBE_COLS + self.grid_col
+        } else {
+            232 + self.grid_col.min(GRAY_STEPS - 1)
+        }
+    }
+
+    /// The currently selected color in the active mode.
+    #[must_use]
+    pub fn color(&self) -> Color {
+        match self.mode {
+            ColorPickerMode::Named16 => Color::Ansi16(ansi16_from_index(self.named)),
+            ColorPickerMode::Palette256 => Color::Ansi256(self.palette_index()),
+            ColorPickerMode::TrueColor => {
+                let (r, g, b) = hsv_to_rgb(self.hue, self.saturation, self.value);
+                Color::rgb(r, g, b)
+            }
+        }
+    }
+
+    /// Move within the 256 grid, handling cube↔grayscale transitions.
+    fn navigate_grid(&mut self, dx: i16, dy: i16) {
+        let mut row = i16::from(self.grid_row) + dy;
+        row = row.clamp(0, i16::from(CUBE_ROWS));
+        self.grid_row = row as u8;
+        let max_col = if self.grid_row < CUBE_ROWS {
+            CUBE_COLS - 1
+        } else {
+            GRAY_STEPS - 1
+        };
+        let mut col = i16::from(self.grid_col) + dx;
+        col = col.clamp(0, i16::from(max_col));
+        self.grid_col = col as u8;
+    }
+}
+
+/// Color picker widget (stateless view over [`ColorPickerState`]).
+#[derive(Debug, Clone)]
+pub struct ColorPicker {
+    /// Terminal supports 24-bit color; gates the TrueColor mode.
+    truecolor: bool,
+    /// HSV navigation step per arrow key press.
+    step: u8,
+    /// Emit [`ColorPickerEvent::Changed`] on every navigation step
+    /// (otherwise only Enter emits).
+    emit_on_navigation: bool,
+    /// Label style.
+    style: Style,
+}
+
+impl Default for ColorPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorPicker {
+    /// Create a picker assuming truecolor support.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            truecolor: true,
+            step: 5,
+            emit_on_navigation: true,
+            style: Style::new(),
+        }
+    }
+
+    /// Declare terminal truecolor capability; `false` hides the
+    /// TrueColor mode and shows downgrade previews instead.
+    #[must_use]
+    pub fn truecolor(mut self, available: bool) -> Self {
+        self.truecolor = available;
+        self
+    }
+
+    /// HSV navigation step (default 5).
+    #[must_use]
+    pub fn step(mut self, step: u8) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// Emit change events on navigation (default) or only on Enter.
+    #[must_use]
+    pub fn emit_on_navigation(mut self, emit: bool) -> Self {
+        self.emit_on_navigation = emit;
+        self
+    }
+
+    /// Label/text style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Cycle to the next available mode (Tab order), gated by capability.
+    fn next_mode(&self, mode: ColorPickerMode) -> ColorPickerMode {
+        match mode {
+            ColorPickerMode::Named16 => ColorPickerMode::Palette256,
+            ColorPickerMode::Palette256 if self.truecolor => ColorPickerMode::TrueColor,
+            ColorPickerMode::Palette256 | ColorPickerMode::TrueColor => ColorPickerMode::Named16,
+        }
+    }
+
+    /// Handle a key event; returns a change/submit event per config.
+    pub fn handle_key(
+        &self,
+        state: &mut ColorPickerState,
+        key: &KeyEvent,
+    ) -> Option<ColorPickerEvent> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        // Hex field editing (TrueColor mode only).
+        if state.mode == ColorPickerMode::TrueColor && state.hex_focused {
+            match key.code {
+                KeyCode::Enter | KeyCode::Escape => {
+                    state.hex_focused = false;
+                    if key.code == KeyCode::Enter
+                        && let Some((r, g, b)) = parse_hex(state.hex.value())
+                    {
+                        let (h, s, v) = rgb_to_hsv(r, g, b);
+                        state.hue = h;
+                        state.saturation = s;
+                        state.value = v;
+                        return Some(ColorPickerEvent::Changed(state.color()));
+                    }
+                    return None;
+                }
+                KeyCode::Char(c) if c.is_ascii_hexdigit() => {
+                    state.hex.handle_event(&Event::Key(*key));
+                    return None;
+                }
+                KeyCode::Backspace => {
+                    state.hex.handle_event(&Event::Key(*key));
+                    return None;
+                }
+                _ => return None,
+            }
+        }
+
+        let before = state.color();
+        match key.code {
+            KeyCode::Tab => {
+                if state.mode == ColorPickerMode::TrueColor && !state.hex_focused {
+                    state.hex_focused = true;
+                    return None;
+                }
+                state.mode = self.next_mode(state.mode);
+            }
+            KeyCode::Enter => return Some(ColorPickerEvent::Submitted(state.color())),
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down => {
+                let (dx, dy) = match key.code {
+                    KeyCode::Left => (-1i16, 0i16),
+                    KeyCode::Right => (1, 0),
+                    KeyCode::Up => (0, -1),
+                    _ => (0, 1),
+                };
+                match state.mode {
+                    ColorPickerMode::Named16 => {
+                        let pos = i16::from(state.named) + dx + dy * 8;
+                        state.named = pos.clamp(0, 15) as u8;
+                    }
+                    ColorPickerMode::Palette256 => state.navigate_grid(dx, dy),
+                    ColorPickerMode::TrueColor => {
+                        let step = i16::from(self.step);
+                        // Left/right: hue. Up/down: value; shift would be
+                        // saturation, but plain keys cycle value for
+                        // simplicity and saturation rides on +/-.
+                        state.hue =
+                            ((i32::from(state.hue) + i32::from(dx * step)).rem_euclid(360)) as u16;
+                        state.value = (i16::from(state.value) - dy * step).clamp(0, 100) as u8;
+                    }
+                }
+            }
+            KeyCode::Char('+') if state.mode == ColorPickerMode::TrueColor => {
+                state.saturation =
+                    (i16::from(state.saturation) + i16::from(self.step)).clamp(0, 100) as u8;
+            }
+            KeyCode::Char('-') if state.mode == ColorPickerMode::TrueColor => {
+                state.saturation =
+                    (i16::from(state.saturation) - i16::from(self.step)).clamp(0, 100) as u8;
+            }
+            _ => return None,
+        }
+
+        let after = state.color();
+        (self.emit_on_navigation && after != before).then_some(ColorPickerEvent::Changed(after))
+    }
+
+    fn swatch(color: PackedRgba) -> Cell {
+        let mut cell = Cell::from_char(' ');
+        cell.bg = color;
+        cell
+    }
+
+    fn packed(color: Color) -> PackedRgba {
+        let rgb = color.to_rgb();
+        PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl crate::StatefulWidget for ColorPicker {
+    type State = ColorPickerState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        let deg = frame.buffer.degradation;
+        if !deg.render_content() {
+            return;
+        }
+
+        let mode_label = match state.mode {
+            ColorPickerMode::Named16 => "named",
+            ColorPickerMode::Palette256 => "256",
+            ColorPickerMode::TrueColor => "truecolor",
+        };
+        draw_text_span(
+            frame,
+            area.x,
+            area.y,
+            &format!("[{mode_label}]"),
+            self.style,
+            area.right(),
+        );
+
+        let body = Rect::new(
+            area.x,
+            area.y + 1,
+            area.width,
+            area.height.saturating_sub(2),
+        );
+        if !body.is_empty() {
+            match state.mode {
+                ColorPickerMode::Named16 => self.render_named(body, frame, state),
+                ColorPickerMode::Palette256 => self.render_palette(body, frame, state),
+                ColorPickerMode::TrueColor => self.render_truecolor(body, frame, state),
+            }
+        }
+
+        // Preview swatch over dark and light backgrounds + downgrade.
+        if area.height >= 2 {
+            let y = area.bottom() - 1;
+            let color = Self::packed(state.color());
+            let mut dark = Cell::from_char('\u{25a0}');
+            dark.fg = color;
+            dark.bg = PackedRgba::rgb(16, 16, 16);
+            let mut light = Cell::from_char('\u{25a0}');
+            light.fg = color;
+            light.bg = PackedRgba::rgb(235, 235, 235);
+            frame.buffer.set_fast(area.x, y, dark);
+            frame.buffer.set_fast(area.x + 1, y, light);
+
+            let rgb = state.color().to_rgb();
+            let label = if self.truecolor {
+                format!(" #{:02x}{:02x}{:02x}", rgb.r, rgb.g, rgb.b)
+            } else {
+                // Capability-aware downgrade preview.
+                format!(
+                    " #{:02x}{:02x}{:02x} \u{2248} 256:{}",
+                    rgb.r,
+                    rgb.g,
+                    rgb.b,
+                    rgb_to_256(rgb.r, rgb.g, rgb.b)
+                )
+            };
+            draw_text_span(frame, area.x + 2, y, &label, self.style, area.right());
+        }
+    }
+}
+
+impl ColorPicker {
+    fn render_named(&self, body: Rect, frame: &mut Frame, state: &ColorPickerState) {
+        for i in 0u8..16 {
+            let (col, row) = (u16::from(i % 8), u16::from(i / 8));
+            let x = body.x + col * 2;
+            let y = body.y + row;
+            if x + 1 >= body.right() || y >= body.bottom() {
+                continue;
+            }
+            let rgb = ansi16_to_rgb(ansi16_from_index(i));
+            let swatch = Self::swatch(PackedRgba::rgb(rgb.r, rgb.g, rgb.b));
+            frame.buffer.set_fast(x, y, swatch);
+            frame.buffer.set_fast(x + 1, y, swatch);
+            if i == state.named {
+                let mut cursor = Cell::from_char('>');
+                cursor.fg = PackedRgba::rgb(255, 255, 255);
+                cursor.bg = swatchless_bg(rgb.r, rgb.g, rgb.b);
+                frame.buffer.set_fast(x, y, cursor);
+            }
+        }
+    }
+
+    fn render_palette(&self, body: Rect, frame: &mut Frame, state: &ColorPickerState) {
+        // Cube rows (clipped to width) then the gray ramp.
+        for row in 0..=CUBE_ROWS {
+            let y = body.y + u16::from(row);
+            if y >= body.bottom() {
+                break;
+            }
+            let cols = if row < CUBE_ROWS {
+                CUBE_COLS
+            } else {
+                GRAY_STEPS
+            };
+            for col in 0..cols {
+                let x = body.x + u16::from(col);
+                if x >= body.right() {
+                    break;
+                }
+                let index = if row < CUBE_ROWS {
+                    16 + row * CUBE_COLS + col
+                } else {
+                    232 + col
+                };
+                let rgb = ansi256_to_rgb(index);
+                let mut cell = Self::swatch(PackedRgba::rgb(rgb.r, rgb.g, rgb.b));
+                if row == state.grid_row && col == state.grid_col {
+                    cell.content = ftui_render::cell::CellContent::from_char('\u{25cf}');
+                    cell.fg = swatchless_bg(rgb.r, rgb.g, rgb.b);
+                }
+                frame.buffer.set_fast(x, y, cell);
+            }
+        }
+    }
+
+    fn render_truecolor(&self, body: Rect, frame: &mut Frame, state: &ColorPickerState) {
+        if !self.truecolor {
+            draw_text_span(
+                frame,
+                body.x,
+                body.y,
+                "truecolor unavailable",
+                self.style,
+                body.right(),
+            );
+            return;
+        }
+        // Hue row across the width.
+        let width = body.width.max(1);
+        for x in 0..width {
+            let hue = u32::from(x) * 360 / u32::from(width);
+            let (r, g, b) = hsv_to_rgb(hue as u16, 100, 100);
+            let mut cell = Self::swatch(PackedRgba::rgb(r, g, b));
+            let selected = u32::from(state.hue) * u32::from(width) / 360 == u32::from(x);
+            if selected {
+                cell.content = ftui_render::cell::CellContent::from_char('\u{25bc}');
+                cell.fg = PackedRgba::rgb(0, 0, 0);
+            }
+            frame.buffer.set_fast(body.x + x, body.y, cell);
+        }
+        // Saturation/value plane: rows = value (top bright), cols = sat.
+        let plane_h = body.height.saturating_sub(2);
+        for row in 0..plane_h {
+            for col in 0..width {
+                let sat = (u32::from(col) * 100 / u32::from(width.max(1))) as u8;
+                let val = 100 - (u32::from(row) * 100 / u32::from(plane_h.max(1))) as u8;
+                let (r, g, b) = hsv_to_rgb(state.hue, sat, val);
+                let mut cell = Self::swatch(PackedRgba::rgb(r, g, b));
+                let sel_col = u32::from(state.saturation) * u32::from(width) / 101;
+                let sel_row = u32::from(100 - state.value) * u32::from(plane_h) / 101;
+                if u32::from(col) == sel_col && u32::from(row) == sel_row {
+                    cell.content = ftui_render::cell::CellContent::from_char('\u{25cf}');
+                    cell.fg = swatchless_bg(r, g, b);
+                }
+                frame.buffer.set_fast(body.x + col, body.y + 1 + row, cell);
+            }
+        }
+        // Hex field on the last body row.
+        if body.height >= 2 {
+            let y = body.bottom() - 1;
+            let marker = if state.hex_focused { "#>" } else { "# " };
+            draw_text_span(frame, body.x, y, marker, self.style, body.right());
+            draw_text_span(
+                frame,
+                body.x + 2,
+                y,
+                state.hex.value(),
+                self.style,
+                body.right(),
+            );
+        }
+    }
+}
+
+/// Contrast color for a cursor glyph over a swatch.
+fn swatchless_bg(r: u8, g: u8, b: u8) -> PackedRgba {
+    let luma = 0.2126 * f64::from(r) + 0.7152 * f64::from(g) + 0.0722 * f64::from(b);
+    if luma > 128.0 {
+        PackedRgba::rgb(0, 0, 0)
+    } else {
+        PackedRgba::rgb(255, 255, 255)
+    }
+}
+
+fn ansi16_from_index(i: u8) -> Ansi16 {
+    use Ansi16::*;
+    match i {
+        0 => Black,
+        1 => Red,
+        2 => Green,
+        3 => Yellow,
+        4 => Blue,
+        5 => Magenta,
+        6 => Cyan,
+        7 => White,
+        8 => BrightBlack,
+        9 => BrightRed,
+        10 => BrightGreen,
+        11 => BrightYellow,
+        12 => BrightBlue,
+        13 => BrightMagenta,
+        14 => BrightCyan,
+        _ => BrightWhite,
+    }
+}
+
+/// Parse `rrggbb` (with or without leading `#`) to RGB.
+#[must_use]
+pub fn parse_hex(text: &str) -> Option<(u8, u8, u8)> {
+    let text = text.strip_prefix('#').unwrap_or(text);
+    if text.len() != 6 || !text.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let r = u8::from_str_radix(&text[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&text[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&text[4..6], 16).ok()?;
+    Some((r, g, b))
+}
+
+/// HSV (h in degrees, s/v in percent) to RGB.
+fn hsv_to_rgb(h: u16, s: u8, v: u8) -> (u8, u8, u8) {
+    let h = f64::from(h % 360);
+    let s = f64::from(s.min(100)) / 100.0;
+    let v = f64::from(v.min(100)) / 100.0;
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+    let (r, g, b) = match (h / 60.0) as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    (
+        ((r + m) * 255.0).round() as u8,
+        ((g + m) * 255.0).round() as u8,
+        ((b + m) * 255.0).round() as u8,
+    )
+}
+
+/// RGB to HSV (degrees / percents), inverse of [`hsv_to_rgb`].
+fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+    let rf = f64::from(r) / 255.0;
+    let gf = f64::from(g) / 255.0;
+    let bf = f64::from(b) / 255.0;
+    let max = rf.max(gf).max(bf);
+    let min = rf.min(gf).min(bf);
+    let delta = max - min;
+    let h = if delta.abs() < f64::EPSILON {
+        0.0
+    } else if (max - rf).abs() < f64::EPSILON {
+        60.0 * (((gf - bf) / delta).rem_euclid(6.0))
+    } else if (max - gf).abs() < f64::EPSILON {
+        60.0 * ((bf - rf) / delta + 2.0)
+    } else {
+        60.0 * ((rf - gf) / delta + 4.0)
+    };
+    let s = if max.abs() < f64::EPSILON {
+        0.0
+    } else {
+        delta / max
+    };
+    (
+        (h.round() as u16) % 360,
+        (s * 100.0).round() as u8,
+        (max * 100.0).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatefulWidget;
+    use ftui_core::event::Modifiers;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        }
+    }
+
+    #[test]
+    fn palette_grid_navigation_cube_to_grayscale() {
+        let picker = ColorPicker::new();
+        let mut state = ColorPickerState::new();
+        state.mode = ColorPickerMode::Palette256;
+
+        // Start at cube origin: index 16.
+        assert_eq!(state.palette_index(), 16);
+
+        // Right: +blue. Down: +green row.
+        picker.handle_key(&mut state, &key(KeyCode::Right));
+        assert_eq!(state.palette_index(), 17);
+        picker.handle_key(&mut state, &key(KeyCode::Down));
+        assert_eq!(state.palette_index(), 17 + 36);
+
+        // Navigate to the bottom cube row, then down into the ramp.
+        for _ in 0..5 {
+            picker.handle_key(&mut state, &key(KeyCode::Down));
+        }
+        assert_eq!(state.grid_row, 6, "in the gray ramp row");
+        assert_eq!(state.palette_index(), 232 + 1, "column clamped into ramp");
+
+        // Ramp column clamps at 23.
+        for _ in 0..40 {
+            picker.handle_key(&mut state, &key(KeyCode::Right));
+        }
+        assert_eq!(state.palette_index(), 255);
+
+        // Up returns to the cube with the column preserved (clamped).
+        picker.handle_key(&mut state, &key(KeyCode::Up));
+        assert_eq!(state.grid_row, 5);
+        assert_eq!(state.palette_index(), 16 + 5 * 36 + 23);
+    }
+
+    #[test]
+    fn hex_input_parsing_and_validation() {
+        assert_eq!(parse_hex("ff8000"), Some((255, 128, 0)));
+        assert_eq!(parse_hex("#00ff00"), Some((0, 255, 0)));
+        assert_eq!(parse_hex("fff"), None, "short form rejected");
+        assert_eq!(parse_hex("gggggg"), None);
+        assert_eq!(parse_hex(""), None);
+
+        // Round trip through HSV stays within rounding error.
+        let (h, s, v) = rgb_to_hsv(255, 128, 0);
+        let (r, g, b) = hsv_to_rgb(h, s, v);
+        assert!(i16::from(r).abs_diff(255) <= 3);
+        assert!(i16::from(g).abs_diff(128) <= 3);
+        assert!(i16::from(b).abs_diff(0) <= 3);
+    }
+
+    #[test]
+    fn downgrade_preview_matches_rgb_to_256() {
+        let mut state = ColorPickerState::new();
+        state.mode = ColorPickerMode::TrueColor;
+        state.hue = 30;
+        state.saturation = 100;
+        state.value = 100;
+        let rgb = state.color().to_rgb();
+        let expected = rgb_to_256(rgb.r, rgb.g, rgb.b);
+
+        let picker = ColorPicker::new().truecolor(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(40, 10, &mut pool);
+        StatefulWidget::render(&picker, Rect::new(0, 0, 40, 10), &mut frame, &mut state);
+
+        let mut row = String::new();
+        for x in 0..40 {
+            row.push(
+                frame
+                    .buffer
+                    .get(x, 9)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' '),
+            );
+        }
+        assert!(
+            row.contains(&format!("256:{expected}")),
+            "downgrade label, got {row:?}"
+        );
+    }
+
+    #[test]
+    fn truecolor_mode_gated_by_capability() {
+        let gated = ColorPicker::new().truecolor(false);
+        let mut state = ColorPickerState::new();
+        // Tab cycles Named16 -> Palette256 -> Named16 (no truecolor).
+        gated.handle_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(state.mode, ColorPickerMode::Palette256);
+        gated.handle_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(state.mode, ColorPickerMode::Named16);
+
+        let full = ColorPicker::new();
+        let mut state = ColorPickerState::new();
+        full.handle_key(&mut state, &key(KeyCode::Tab));
+        full.handle_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(state.mode, ColorPickerMode::TrueColor);
+    }
+
+    #[test]
+    fn change_events_follow_emit_policy() {
+        let on_nav = ColorPicker::new();
+        let mut state = ColorPickerState::new();
+        state.mode = ColorPickerMode::Palette256;
+        let event = on_nav.handle_key(&mut state, &key(KeyCode::Right));
+        assert!(matches!(event, Some(ColorPickerEvent::Changed(_))));
+
+        let on_enter = ColorPicker::new().emit_on_navigation(false);
+        let mut state = ColorPickerState::new();
+        state.mode = ColorPickerMode::Palette256;
+        assert_eq!(on_enter.handle_key(&mut state, &key(KeyCode::Right)), None);
+        let event = on_enter.handle_key(&mut state, &key(KeyCode::Enter));
+        assert!(matches!(event, Some(ColorPickerEvent::Submitted(_))));
+    }
+
+    #[test]
+    fn snapshots_at_constrained_sizes_never_panic() {
+        for (w, h) in [(1, 1), (8, 2), (16, 4), (40, 3), (3, 8)] {
+            for mode in [
+                ColorPickerMode::Named16,
+                ColorPickerMode::Palette256,
+                ColorPickerMode::TrueColor,
+            ] {
+                let picker = ColorPicker::new();
+                let mut state = ColorPickerState::new();
+                state.mode = mode;
+                let mut pool = GraphemePool::new();
+                let mut frame = Frame::new(w, h, &mut pool);
+                StatefulWidget::render(&picker, Rect::new(0, 0, w, h), &mut frame, &mut state);
+            }
+        }
+    }
+
+    #[test]
+    fn named_mode_snapshot_small() {
+        let picker = ColorPicker::new();
+        let mut state = ColorPickerState::new();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 5, &mut pool);
+        StatefulWidget::render(&picker, Rect::new(0, 0, 20, 5), &mut frame, &mut state);
+        // Header row labels the mode; cursor marks the first swatch.
+        let header: String = (0..7)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, 0)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect();
+        assert_eq!(header, "[named]");
+        assert_eq!(frame.buffer.get(0, 1).unwrap().content.as_char(), Some('>'));
+    }
+
+    #[test]
+    fn hex_entry_applies_on_enter() {
+        let picker = ColorPicker::new();
+        let mut state = ColorPickerState::new();
+        state.mode = ColorPickerMode::TrueColor;
+        picker.handle_key(&mut state, &key(KeyCode::Tab)); // focus hex
+        assert!(state.hex_focused);
+        for c in "ff0080".chars() {
+            picker.handle_key(&mut state, &key(KeyCode::Char(c)));
+        }
+        let event = picker.handle_key(&mut state, &key(KeyCode::Enter));
+        assert!(matches!(event, Some(ColorPickerEvent::Changed(_))));
+        let rgb = state.color().to_rgb();
+        assert!(i16::from(rgb.r).abs_diff(255) <= 3);
+        assert!(i16::from(rgb.b).abs_diff(128) <= 3);
+    }
+}
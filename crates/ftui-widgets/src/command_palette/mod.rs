@@ -654,6 +654,7 @@ impl CommandPalette {
                 code: KeyCode::Char('p'),
                 modifiers,
                 kind: KeyEventKind::Press,
+                ch: _,
             }) = event
                 && modifiers.contains(Modifiers::CTRL)
             {
@@ -667,6 +668,7 @@ impl CommandPalette {
                 code,
                 modifiers,
                 kind: KeyEventKind::Press,
+                ch: _,
             }) => self.handle_key(*code, *modifiers),
             _ => None,
         }
@@ -1399,16 +1401,19 @@ mod widget_tests {
             code: KeyCode::Char('g'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let i = Event::Key(KeyEvent {
             code: KeyCode::Char('i'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let t = Event::Key(KeyEvent {
             code: KeyCode::Char('t'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let _ = palette.handle_event(&g);
@@ -1430,11 +1435,13 @@ mod widget_tests {
             code: KeyCode::Char('o'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let bs = Event::Key(KeyEvent {
             code: KeyCode::Backspace,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let _ = palette.handle_event(&o);
@@ -1452,6 +1459,7 @@ mod widget_tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = palette.handle_event(&esc);
@@ -1469,6 +1477,7 @@ mod widget_tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = palette.handle_event(&enter);
@@ -1489,11 +1498,13 @@ mod widget_tests {
             code: KeyCode::Down,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let up = Event::Key(KeyEvent {
             code: KeyCode::Up,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let _ = palette.handle_event(&down);
@@ -1525,11 +1536,13 @@ mod widget_tests {
             code: KeyCode::End,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let home = Event::Key(KeyEvent {
             code: KeyCode::Home,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let _ = palette.handle_event(&end);
@@ -1549,6 +1562,7 @@ mod widget_tests {
             code: KeyCode::Char('o'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&o);
         assert_eq!(palette.query(), "o");
@@ -1557,6 +1571,7 @@ mod widget_tests {
             code: KeyCode::Char('u'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&ctrl_u);
         assert_eq!(palette.query(), "");
@@ -1571,6 +1586,7 @@ mod widget_tests {
             code: KeyCode::Char('p'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&ctrl_p);
         assert!(palette.is_visible());
@@ -1666,6 +1682,7 @@ mod widget_tests {
             code: KeyCode::Char('a'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         assert!(palette.handle_event(&a).is_none());
         assert!(!palette.is_visible());
@@ -1789,6 +1806,7 @@ mod widget_tests {
             code: KeyCode::Char('p'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&ctrl_p);
         assert!(palette.is_visible());
@@ -1800,6 +1818,7 @@ mod widget_tests {
                 code: KeyCode::Char(ch),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             let _ = palette.handle_event(&event);
         }
@@ -1810,6 +1829,7 @@ mod widget_tests {
             code: KeyCode::Down,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&down);
 
@@ -1818,6 +1838,7 @@ mod widget_tests {
             code: KeyCode::Up,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&up);
         assert_eq!(palette.selected_index(), 0);
@@ -1827,6 +1848,7 @@ mod widget_tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let result = palette.handle_event(&enter);
         assert!(matches!(result, Some(PaletteAction::Execute(_))));
@@ -1845,6 +1867,7 @@ mod widget_tests {
                 code: KeyCode::Char(ch),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             let _ = palette.handle_event(&event);
         }
@@ -1855,6 +1878,7 @@ mod widget_tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let result = palette.handle_event(&esc);
         assert_eq!(result, Some(PaletteAction::Dismiss));
@@ -2131,6 +2155,7 @@ mod widget_tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let result = palette.handle_event(&enter);
         assert!(result.is_none());
@@ -2210,6 +2235,7 @@ mod widget_tests {
             code: KeyCode::Char('a'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         tracing::callsite::rebuild_interest_cache();
         let _ = palette.handle_event(&a);
@@ -2218,6 +2244,7 @@ mod widget_tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         tracing::callsite::rebuild_interest_cache();
         let _ = palette.handle_event(&enter);
@@ -2295,6 +2322,7 @@ mod widget_tests {
             code: KeyCode::Backspace,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&bs);
         assert_eq!(palette.query(), "");
@@ -2314,6 +2342,7 @@ mod widget_tests {
                 code: KeyCode::Char(ch),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             let _ = palette.handle_event(&event);
         }
@@ -2323,6 +2352,7 @@ mod widget_tests {
             code: KeyCode::Char('a'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&ctrl_a);
         // Cursor should move to 0 but query unchanged
@@ -2339,6 +2369,7 @@ mod widget_tests {
             code: KeyCode::Char('x'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Release,
+            ch: None,
         });
         let result = palette.handle_event(&release);
         assert!(result.is_none());
@@ -2540,6 +2571,7 @@ mod widget_tests {
             code: KeyCode::Down,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&down);
 
@@ -2623,6 +2655,7 @@ mod widget_tests {
             code: KeyCode::PageDown,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&pgdn);
         assert_eq!(palette.selected_index(), 3); // 0 + max_visible
@@ -2637,6 +2670,7 @@ mod widget_tests {
             code: KeyCode::PageUp,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&pgup);
         assert_eq!(palette.selected_index(), 6); // 9 - 3
@@ -2658,6 +2692,7 @@ mod widget_tests {
             code: KeyCode::PageDown,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&pgdn);
         assert_eq!(palette.selected_index(), 0);
@@ -2673,6 +2708,7 @@ mod widget_tests {
             code: KeyCode::End,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&end);
         assert_eq!(palette.selected_index(), 0);
@@ -2688,6 +2724,7 @@ mod widget_tests {
             code: KeyCode::Down,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&down);
         assert_eq!(palette.selected_index(), 0);
@@ -2720,6 +2757,7 @@ mod widget_tests {
             code: KeyCode::End,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&end);
         assert_eq!(palette.selected_index(), 9);
@@ -2730,6 +2768,7 @@ mod widget_tests {
             code: KeyCode::Home,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&home);
         assert_eq!(palette.selected_index(), 0);
@@ -2847,6 +2886,7 @@ mod widget_tests {
             code: KeyCode::Tab,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let result = palette.handle_event(&tab);
         assert!(result.is_none());
@@ -2864,6 +2904,7 @@ mod widget_tests {
             code: KeyCode::Char('p'),
             modifiers: Modifiers::CTRL,
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&ctrl_p);
         // The visible palette handles Ctrl+P as a Ctrl char, not toggling
@@ -2970,6 +3011,7 @@ mod widget_tests {
             code: KeyCode::End,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let _ = palette.handle_event(&end);
 
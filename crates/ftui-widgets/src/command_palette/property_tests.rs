@@ -77,6 +77,7 @@ proptest! {
                 code,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             }));
 
             // Invariant: selected index is always valid
@@ -149,6 +150,7 @@ fn page_up_down_viewport_movement() {
         code: KeyCode::PageDown,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     });
 
     let _ = palette.handle_event(&pg_down);
@@ -162,6 +164,7 @@ fn page_up_down_viewport_movement() {
         code: KeyCode::PageUp,
         modifiers: Modifiers::empty(),
         kind: KeyEventKind::Press,
+        ch: None,
     });
 
     let _ = palette.handle_event(&pg_up);
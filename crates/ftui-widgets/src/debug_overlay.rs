@@ -596,6 +596,7 @@ mod tests {
             code: KeyCode::F(12),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         assert!(state.toggle_on_f12(&event));
@@ -700,6 +701,7 @@ mod tests {
             code: KeyCode::Char('a'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: Some('a'),
         });
         assert!(!state.toggle_on_f12(&event));
         assert!(!state.enabled());
@@ -713,6 +715,7 @@ mod tests {
             code: KeyCode::Char('x'),
             modifiers: ftui_core::event::Modifiers::NONE,
             kind: KeyEventKind::Press,
+            ch: Some('x'),
         });
         assert_eq!(state.update_hover_from_event(&event), Some((3, 4)));
     }
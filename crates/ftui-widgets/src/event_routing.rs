@@ -0,0 +1,463 @@
+#![forbid(unsafe_code)]
+
+//! Two-phase (capture/bubble) event dispatch along a container path.
+//!
+//! Flat routing hands an event to the focused widget and stops, so a
+//! container can't express "Escape closes the panel unless a child
+//! consumed it". Phased dispatch runs three passes over the
+//! root-to-target path:
+//!
+//! 1. **Capture** — containers from the root down to the target's parent,
+//!    in order. A [`EventOutcome::Consumed`] aborts dispatch immediately:
+//!    the target never sees the event (interception).
+//! 2. **Target** — the target widget handles the event (existing
+//!    single-phase behavior).
+//! 3. **Bubble** — the same containers in reverse order (target's parent
+//!    up to the root), each seeing the outcome so far in
+//!    [`DispatchCtx::outcome`]. A container that consumes here fires its
+//!    fallback and upgrades the final outcome to `Consumed`; bubbling
+//!    continues either way so outer containers still observe the event.
+//!
+//! The path comes from the focus graph for key events
+//! ([`FocusGraph::path_to`](crate::focus::FocusGraph) via
+//! [`FocusManager::dispatch_path`](crate::focus::FocusManager)) and from
+//! the hit-test path for mouse events — the dispatcher itself is
+//! path-agnostic. Ordering is exactly the slice order handed in, making
+//! dispatch fully deterministic.
+//!
+//! Widgets that don't opt in implement nothing:
+//! [`PhasedEventHandler::handle_event_phased`] is default-implemented to
+//! act only at the target phase via the single-phase handler.
+
+use std::collections::BTreeMap;
+
+use ftui_core::event::Event;
+
+/// Which pass of the dispatch is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// Root-to-parent interception pass.
+    Capture,
+    /// The focused / hit widget handles the event.
+    Target,
+    /// Parent-to-root fallback pass (outcome visible in the context).
+    Bubble,
+}
+
+/// Whether a handler consumed the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventOutcome {
+    /// The event was handled; flat dispatch would stop here.
+    Consumed,
+    /// The event was not handled.
+    #[default]
+    Ignored,
+}
+
+/// Shared state across the three phases.
+///
+/// Capture-phase containers may annotate the context (string key/value)
+/// for the target or bubble passes; `outcome` carries the result so far
+/// (meaningful during bubble).
+#[derive(Debug, Clone, Default)]
+pub struct DispatchCtx {
+    /// Outcome of the phases run so far.
+    pub outcome: EventOutcome,
+    annotations: BTreeMap<String, String>,
+}
+
+impl DispatchCtx {
+    /// Attach an annotation for later phases.
+    pub fn annotate(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.annotations.insert(key.into(), value.into());
+    }
+
+    /// Read an annotation left by an earlier phase.
+    #[must_use]
+    pub fn annotation(&self, key: &str) -> Option<&str> {
+        self.annotations.get(key).map(String::as_str)
+    }
+}
+
+/// Handler participating in phased dispatch.
+///
+/// Existing widgets implement only [`handle_target_event`]
+/// (or nothing); the phased entry point defaults to single-phase
+/// behavior, so opting out is the default.
+///
+/// [`handle_target_event`]: PhasedEventHandler::handle_target_event
+pub trait PhasedEventHandler {
+    /// Single-phase handling (the pre-existing widget behavior).
+    fn handle_target_event(&mut self, event: &Event) -> EventOutcome {
+        let _ = event;
+        EventOutcome::Ignored
+    }
+
+    /// Phased handling; the default participates only at `Target`.
+    fn handle_event_phased(
+        &mut self,
+        event: &Event,
+        phase: EventPhase,
+        ctx: &mut DispatchCtx,
+    ) -> EventOutcome {
+        let _ = ctx;
+        match phase {
+            EventPhase::Target => self.handle_target_event(event),
+            EventPhase::Capture | EventPhase::Bubble => EventOutcome::Ignored,
+        }
+    }
+}
+
+/// Run capture → target → bubble over a root-to-target path.
+///
+/// `path` is ordered outermost container first, target last (the order
+/// [`FocusGraph::path_to`](crate::focus::FocusGraph) produces). An empty
+/// path returns `Ignored`. See the module docs for the precise
+/// consumption semantics.
+pub fn dispatch_phased(
+    path: &mut [&mut dyn PhasedEventHandler],
+    event: &Event,
+) -> (EventOutcome, DispatchCtx) {
+    let mut ctx = DispatchCtx::default();
+    let Some(target_idx) = path.len().checked_sub(1) else {
+        return (EventOutcome::Ignored, ctx);
+    };
+
+    // Capture: root → parent of target. Consumption aborts.
+    for handler in path[..target_idx].iter_mut() {
+        if handler.handle_event_phased(event, EventPhase::Capture, &mut ctx)
+            == EventOutcome::Consumed
+        {
+            ctx.outcome = EventOutcome::Consumed;
+            return (EventOutcome::Consumed, ctx);
+        }
+    }
+
+    // Target.
+    ctx.outcome = path[target_idx].handle_event_phased(event, EventPhase::Target, &mut ctx);
+
+    // Bubble: parent of target → root. Consumption upgrades the final
+    // outcome but bubbling continues so outer containers observe it.
+    for handler in path[..target_idx].iter_mut().rev() {
+        if handler.handle_event_phased(event, EventPhase::Bubble, &mut ctx)
+            == EventOutcome::Consumed
+        {
+            ctx.outcome = EventOutcome::Consumed;
+        }
+    }
+
+    (ctx.outcome, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::{KeyCode, KeyEvent, KeyEventKind, Modifiers};
+
+    fn escape() -> Event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Escape,
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        })
+    }
+
+    fn char_key(c: char) -> Event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        })
+    }
+
+    /// Scripted handler recording every phase it sees.
+    struct Probe {
+        name: &'static str,
+        log: std::rc::Rc<std::cell::RefCell<Vec<String>>>,
+        capture_consumes: bool,
+        target_consumes: bool,
+        bubble_consumes_if_ignored: bool,
+    }
+
+    impl Probe {
+        fn new(name: &'static str, log: &std::rc::Rc<std::cell::RefCell<Vec<String>>>) -> Self {
+            Self {
+                name,
+                log: log.clone(),
+                capture_consumes: false,
+                target_consumes: false,
+                bubble_consumes_if_ignored: false,
+            }
+        }
+    }
+
+    impl PhasedEventHandler for Probe {
+        fn handle_event_phased(
+            &mut self,
+            _event: &Event,
+            phase: EventPhase,
+            ctx: &mut DispatchCtx,
+        ) -> EventOutcome {
+            self.log
+                .borrow_mut()
+                .push(format!("{}:{phase:?}", self.name));
+            match phase {
+                EventPhase::Capture if self.capture_consumes => EventOutcome::Consumed,
+                EventPhase::Target if self.target_consumes => EventOutcome::Consumed,
+                EventPhase::Bubble
+                    if self.bubble_consumes_if_ignored && ctx.outcome == EventOutcome::Ignored =>
+                {
+                    EventOutcome::Consumed
+                }
+                _ => EventOutcome::Ignored,
+            }
+        }
+    }
+
+    #[test]
+    fn phase_ordering_is_root_down_then_up() {
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut outer = Probe::new("outer", &log);
+        let mut inner = Probe::new("inner", &log);
+        let mut leaf = Probe::new("leaf", &log);
+        leaf.target_consumes = true;
+
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut outer, &mut inner, &mut leaf];
+        let (outcome, _) = dispatch_phased(&mut path, &char_key('x'));
+
+        assert_eq!(outcome, EventOutcome::Consumed);
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "outer:Capture",
+                "inner:Capture",
+                "leaf:Target",
+                "inner:Bubble",
+                "outer:Bubble",
+            ]
+        );
+    }
+
+    #[test]
+    fn capture_phase_interception_stops_dispatch() {
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut outer = Probe::new("outer", &log);
+        outer.capture_consumes = true;
+        let mut leaf = Probe::new("leaf", &log);
+
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut outer, &mut leaf];
+        let (outcome, _) = dispatch_phased(&mut path, &char_key('x'));
+
+        assert_eq!(outcome, EventOutcome::Consumed);
+        assert_eq!(*log.borrow(), vec!["outer:Capture"], "target never saw it");
+    }
+
+    #[test]
+    fn bubble_phase_escape_fallback_fires_only_when_child_ignored() {
+        // The canonical case: Escape closes the panel unless a child
+        // consumed it.
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut panel = Probe::new("panel", &log);
+        panel.bubble_consumes_if_ignored = true;
+
+        // Child ignores Escape: the panel's fallback fires on bubble.
+        let mut child = Probe::new("child", &log);
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut panel, &mut child];
+        let (outcome, _) = dispatch_phased(&mut path, &escape());
+        assert_eq!(outcome, EventOutcome::Consumed, "panel fallback closed");
+
+        // Child consumes Escape: the panel must not act.
+        log.borrow_mut().clear();
+        let mut panel = Probe::new("panel", &log);
+        panel.bubble_consumes_if_ignored = true;
+        let mut child = Probe::new("child", &log);
+        child.target_consumes = true;
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut panel, &mut child];
+        let (outcome, ctx) = dispatch_phased(&mut path, &escape());
+        assert_eq!(outcome, EventOutcome::Consumed, "child consumed");
+        assert_eq!(ctx.outcome, EventOutcome::Consumed);
+        // The panel saw bubble but (per its own predicate) did nothing.
+        assert_eq!(
+            *log.borrow(),
+            vec!["panel:Capture", "child:Target", "panel:Bubble"]
+        );
+    }
+
+    #[test]
+    fn annotations_flow_from_capture_to_bubble() {
+        struct Annotator;
+        impl PhasedEventHandler for Annotator {
+            fn handle_event_phased(
+                &mut self,
+                _event: &Event,
+                phase: EventPhase,
+                ctx: &mut DispatchCtx,
+            ) -> EventOutcome {
+                if phase == EventPhase::Capture {
+                    ctx.annotate("seen-by", "annotator");
+                }
+                EventOutcome::Ignored
+            }
+        }
+        struct Reader {
+            saw: bool,
+        }
+        impl PhasedEventHandler for Reader {
+            fn handle_event_phased(
+                &mut self,
+                _event: &Event,
+                phase: EventPhase,
+                ctx: &mut DispatchCtx,
+            ) -> EventOutcome {
+                if phase == EventPhase::Target {
+                    self.saw = ctx.annotation("seen-by") == Some("annotator");
+                }
+                EventOutcome::Ignored
+            }
+        }
+
+        let mut annotator = Annotator;
+        let mut reader = Reader { saw: false };
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut annotator, &mut reader];
+        let _ = dispatch_phased(&mut path, &char_key('a'));
+        assert!(reader.saw, "target read the capture-phase annotation");
+    }
+
+    #[test]
+    fn default_impl_preserves_single_phase_behavior() {
+        /// A "legacy" widget implementing only the single-phase hook.
+        struct Legacy {
+            handled: usize,
+        }
+        impl PhasedEventHandler for Legacy {
+            fn handle_target_event(&mut self, _event: &Event) -> EventOutcome {
+                self.handled += 1;
+                EventOutcome::Consumed
+            }
+        }
+
+        let mut legacy = Legacy { handled: 0 };
+        // Alone on the path: exactly one (target) call, consumed.
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut legacy];
+        let (outcome, _) = dispatch_phased(&mut path, &char_key('x'));
+        assert_eq!(outcome, EventOutcome::Consumed);
+        assert_eq!(legacy.handled, 1);
+
+        // As a container: the default ignores capture and bubble.
+        let mut container = Legacy { handled: 0 };
+        let mut target = Legacy { handled: 0 };
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![&mut container, &mut target];
+        let (outcome, _) = dispatch_phased(&mut path, &char_key('x'));
+        assert_eq!(outcome, EventOutcome::Consumed);
+        assert_eq!(container.handled, 0, "container untouched by default");
+        assert_eq!(target.handled, 1);
+    }
+
+    #[test]
+    fn empty_path_is_ignored() {
+        let mut path: Vec<&mut dyn PhasedEventHandler> = vec![];
+        let (outcome, _) = dispatch_phased(&mut path, &char_key('x'));
+        assert_eq!(outcome, EventOutcome::Ignored);
+    }
+
+    #[test]
+    fn focus_graph_provides_root_to_target_path() {
+        use crate::focus::{FocusManager, FocusNode};
+        use ftui_core::geometry::Rect;
+
+        let mut manager = FocusManager::new();
+        let root = manager
+            .graph_mut()
+            .insert(FocusNode::new(1, Rect::new(0, 0, 80, 24)));
+        let panel = manager
+            .graph_mut()
+            .insert(FocusNode::new(2, Rect::new(2, 2, 40, 10)).with_container(root));
+        let button = manager
+            .graph_mut()
+            .insert(FocusNode::new(3, Rect::new(4, 4, 10, 1)).with_container(panel));
+        manager.focus(button);
+
+        assert_eq!(manager.dispatch_path(), vec![root, panel, button]);
+
+        // Nothing focused: empty path.
+        manager.blur();
+        assert!(manager.dispatch_path().is_empty());
+    }
+
+    #[test]
+    fn mouse_routes_by_hit_path_through_nested_containers() {
+        use ftui_core::event::{MouseButton, MouseEvent, MouseEventKind};
+
+        // Nested containers with rects; the hit path is derived from
+        // containment at the click position, not from focus.
+        struct Region {
+            name: &'static str,
+            rect: (i32, i32, i32, i32),
+            log: std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>,
+        }
+        impl Region {
+            fn contains(&self, x: i32, y: i32) -> bool {
+                let (rx, ry, w, h) = self.rect;
+                x >= rx && x < rx + w && y >= ry && y < ry + h
+            }
+        }
+        impl PhasedEventHandler for Region {
+            fn handle_event_phased(
+                &mut self,
+                _event: &Event,
+                phase: EventPhase,
+                _ctx: &mut DispatchCtx,
+            ) -> EventOutcome {
+                if phase == EventPhase::Target {
+                    self.log.borrow_mut().push(self.name);
+                    return EventOutcome::Consumed;
+                }
+                self.log.borrow_mut().push(self.name);
+                EventOutcome::Ignored
+            }
+        }
+
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut outer = Region {
+            name: "outer",
+            rect: (0, 0, 80, 24),
+            log: log.clone(),
+        };
+        let mut inner = Region {
+            name: "inner",
+            rect: (10, 5, 30, 10),
+            log: log.clone(),
+        };
+        let mut button = Region {
+            name: "button",
+            rect: (12, 6, 8, 1),
+            log: log.clone(),
+        };
+
+        let click = Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            x: 14,
+            y: 6,
+            modifiers: Modifiers::empty(),
+        });
+        let (x, y) = (14, 6);
+
+        // Build the hit path: every region containing the point,
+        // outermost first.
+        let mut path: Vec<&mut dyn PhasedEventHandler> = Vec::new();
+        assert!(outer.contains(x, y) && inner.contains(x, y) && button.contains(x, y));
+        path.push(&mut outer);
+        path.push(&mut inner);
+        path.push(&mut button);
+
+        let (outcome, _) = dispatch_phased(&mut path, &click);
+        assert_eq!(outcome, EventOutcome::Consumed);
+        assert_eq!(
+            *log.borrow(),
+            vec!["outer", "inner", "button", "inner", "outer"],
+            "capture down the hit path, bubble back up"
+        );
+    }
+}
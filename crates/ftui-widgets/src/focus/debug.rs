@@ -0,0 +1,443 @@
+#![forbid(unsafe_code)]
+
+//! Developer overlay visualizing the focus graph.
+//!
+//! Renders, over an already-composed frame:
+//! - each registered focusable's rect outline with its [`FocusId`],
+//! - tab-order positions as small numeric badges,
+//! - directional markers for the spatial edges on each node, and
+//! - a highlight of the last navigation decision (from-node, direction,
+//!   chosen target, and the top scored candidates from [`NavTrace`]).
+//!
+//! The overlay is purely additive: it draws into the frame buffer during
+//! a post-pass and neither perturbs layout nor consumes input. When
+//! disabled it touches nothing, so production frames are bit-identical.
+//! Labels are elided on tiny rects so hundreds of nodes stay legible.
+
+use ahash::AHashMap;
+
+use crate::Widget;
+use crate::focus::graph::{FocusGraph, FocusId, NavDirection};
+use crate::focus::spatial::NavTrace;
+use ftui_core::geometry::Rect;
+use ftui_render::buffer::Buffer;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::drawing::{BorderChars, Draw};
+use ftui_render::frame::Frame;
+
+/// Visual styling for [`FocusDebugOverlay`].
+#[derive(Debug, Clone)]
+pub struct FocusDebugOverlayStyle {
+    /// Outline color for ordinary focusable rects.
+    pub node_color: PackedRgba,
+    /// Outline color for the node the last navigation started from.
+    pub from_color: PackedRgba,
+    /// Outline color for the node the last navigation chose.
+    pub target_color: PackedRgba,
+    /// Color for spatial-edge direction markers.
+    pub edge_color: PackedRgba,
+    /// Foreground for ID labels and tab-order badges.
+    pub label_fg: PackedRgba,
+    /// Background for ID labels and tab-order badges.
+    pub label_bg: PackedRgba,
+    /// Whether to draw spatial-edge direction markers.
+    pub show_edges: bool,
+    /// Whether to draw tab-order badges.
+    pub show_tab_order: bool,
+    /// Whether to render the last-decision summary line.
+    pub show_decision: bool,
+    /// Border characters for rect outlines.
+    pub border_chars: BorderChars,
+}
+
+impl Default for FocusDebugOverlayStyle {
+    fn default() -> Self {
+        Self {
+            node_color: PackedRgba::rgb(90, 160, 220),
+            from_color: PackedRgba::rgb(240, 200, 80),
+            target_color: PackedRgba::rgb(100, 220, 120),
+            edge_color: PackedRgba::rgb(200, 120, 220),
+            label_fg: PackedRgba::rgb(255, 255, 255),
+            label_bg: PackedRgba::rgb(30, 30, 30),
+            show_edges: true,
+            show_tab_order: true,
+            show_decision: true,
+            border_chars: BorderChars::SQUARE,
+        }
+    }
+}
+
+/// Rects narrower than this get no ID label (badge still fits at 3+).
+const MIN_LABEL_WIDTH: u16 = 6;
+/// Rects narrower than this get no tab-order badge.
+const MIN_BADGE_WIDTH: u16 = 3;
+
+/// Focus graph debug overlay widget.
+///
+/// Borrow the graph (and optionally the last [`NavTrace`]) and render as
+/// a post-pass after the real UI:
+///
+/// ```ignore
+/// if debug_focus {
+///     FocusDebugOverlay::new(manager.graph())
+///         .with_trace(manager.last_nav_trace())
+///         .render(area, frame);
+/// }
+/// ```
+pub struct FocusDebugOverlay<'a> {
+    graph: &'a FocusGraph,
+    trace: Option<&'a NavTrace>,
+    enabled: bool,
+    style: FocusDebugOverlayStyle,
+}
+
+impl<'a> FocusDebugOverlay<'a> {
+    /// Create an enabled overlay for the given graph.
+    pub fn new(graph: &'a FocusGraph) -> Self {
+        Self {
+            graph,
+            trace: None,
+            enabled: true,
+            style: FocusDebugOverlayStyle::default(),
+        }
+    }
+
+    /// Attach the last navigation decision to highlight.
+    #[must_use]
+    pub fn with_trace(mut self, trace: Option<&'a NavTrace>) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Enable or disable the overlay. Disabled overlays render nothing.
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set custom styling.
+    #[must_use]
+    pub fn style(mut self, style: FocusDebugOverlayStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn outline_color(&self, id: FocusId) -> PackedRgba {
+        if let Some(trace) = self.trace {
+            if trace.origin == id {
+                return self.style.from_color;
+            }
+            if trace.chosen == Some(id) {
+                return self.style.target_color;
+            }
+        }
+        self.style.node_color
+    }
+
+    fn render_node(
+        &self,
+        id: FocusId,
+        bounds: Rect,
+        tab_pos: Option<usize>,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let Some(clipped) = bounds.intersection_opt(&area) else {
+            return;
+        };
+        if clipped.is_empty() {
+            return;
+        }
+
+        let border_cell = Cell::from_char(' ').with_fg(self.outline_color(id));
+        buf.draw_border(clipped, self.style.border_chars, border_cell);
+
+        // ID label inside the top border; elided on tiny rects.
+        if clipped.width >= MIN_LABEL_WIDTH && clipped.height >= 2 {
+            let label = format!("#{id}");
+            let label_cell = Cell::from_char(' ')
+                .with_fg(self.style.label_fg)
+                .with_bg(self.style.label_bg);
+            let _ = buf.print_text_clipped(
+                clipped.x + 1,
+                clipped.y,
+                &label,
+                label_cell,
+                clipped.right().saturating_sub(1),
+            );
+        }
+
+        // Tab-order badge at the top-right corner.
+        if self.style.show_tab_order
+            && let Some(pos) = tab_pos
+            && clipped.width >= MIN_BADGE_WIDTH
+        {
+            let badge = format!("{}", pos + 1);
+            let badge_x = clipped
+                .right()
+                .saturating_sub(1)
+                .saturating_sub(badge.len() as u16);
+            if badge_x > clipped.x {
+                let badge_cell = Cell::from_char(' ')
+                    .with_fg(self.style.label_fg)
+                    .with_bg(self.style.label_bg);
+                let _ = buf.print_text_clipped(
+                    badge_x,
+                    clipped.y,
+                    &badge,
+                    badge_cell,
+                    clipped.right().saturating_sub(1),
+                );
+            }
+        }
+    }
+
+    fn render_edges(&self, id: FocusId, bounds: Rect, area: Rect, buf: &mut Buffer) {
+        let dirs = [
+            (NavDirection::Up, '\u{2191}'),
+            (NavDirection::Down, '\u{2193}'),
+            (NavDirection::Left, '\u{2190}'),
+            (NavDirection::Right, '\u{2192}'),
+        ];
+        for (dir, marker) in dirs {
+            if self.graph.navigate(id, dir).is_none() {
+                continue;
+            }
+            let (x, y) = match dir {
+                NavDirection::Up => (bounds.x + bounds.width / 2, bounds.y),
+                NavDirection::Down => (
+                    bounds.x + bounds.width / 2,
+                    bounds.bottom().saturating_sub(1),
+                ),
+                NavDirection::Left => (bounds.x, bounds.y + bounds.height / 2),
+                _ => (
+                    bounds.right().saturating_sub(1),
+                    bounds.y + bounds.height / 2,
+                ),
+            };
+            if area.contains(x, y) {
+                buf.set_fast(x, y, Cell::from_char(marker).with_fg(self.style.edge_color));
+            }
+        }
+    }
+
+    fn decision_summary(trace: &NavTrace) -> String {
+        let mut line = format!(
+            "nav: #{} {:?} \u{2192} {}{}",
+            trace.origin,
+            trace.dir,
+            trace
+                .chosen
+                .map_or_else(|| "\u{2014}".to_string(), |id| format!("#{id}")),
+            if trace.explicit_edge { " (edge)" } else { "" },
+        );
+        for (rank, candidate) in trace.candidates.iter().enumerate() {
+            line.push_str(&format!(
+                " | {}: #{} s={}",
+                rank + 1,
+                candidate.id,
+                candidate.score
+            ));
+        }
+        line
+    }
+}
+
+impl Widget for FocusDebugOverlay<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if !self.enabled || area.is_empty() {
+            return;
+        }
+        let buf = &mut frame.buffer;
+
+        // Tab positions keyed by node; a linear scan per node would be
+        // O(n²) with hundreds of nodes.
+        let tab_positions: AHashMap<FocusId, usize> = self
+            .graph
+            .tab_order()
+            .into_iter()
+            .enumerate()
+            .map(|(pos, id)| (id, pos))
+            .collect();
+
+        for id in self.graph.node_ids() {
+            let Some(node) = self.graph.get(id) else {
+                continue;
+            };
+            if !node.is_focusable {
+                continue;
+            }
+            let tab_pos = tab_positions.get(&id).copied();
+            self.render_node(id, node.bounds, tab_pos, area, buf);
+            if self.style.show_edges {
+                self.render_edges(id, node.bounds, area, buf);
+            }
+        }
+
+        // Last navigation decision, on the overlay's bottom row.
+        if self.style.show_decision
+            && let Some(trace) = self.trace
+        {
+            let summary = Self::decision_summary(trace);
+            let y = area.bottom().saturating_sub(1);
+            let cell = Cell::from_char(' ')
+                .with_fg(self.style.label_fg)
+                .with_bg(self.style.label_bg);
+            let _ = buf.print_text_clipped(area.x, y, &summary, cell, area.right());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::focus::graph::FocusNode;
+    use crate::focus::spatial::{spatial_navigate, spatial_navigate_traced};
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    /// Two side-by-side focusables with a spatial edge between them.
+    fn small_graph() -> FocusGraph {
+        let mut g = FocusGraph::new();
+        g.insert(FocusNode::new(1, Rect::new(1, 1, 10, 4)));
+        g.insert(FocusNode::new(2, Rect::new(14, 1, 10, 4)).with_tab_index(1));
+        g.connect(1, NavDirection::Right, 2);
+        g.connect(2, NavDirection::Left, 1);
+        g
+    }
+
+    fn rendered(overlay: FocusDebugOverlay<'_>) -> Vec<String> {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 8, &mut pool);
+        overlay.render(Rect::new(0, 0, 30, 8), &mut frame);
+        (0..8)
+            .map(|y| {
+                (0..30)
+                    .map(|x| {
+                        frame
+                            .buffer
+                            .get(x, y)
+                            .and_then(|c| c.content.as_char())
+                            .unwrap_or(' ')
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn snapshot_small_graph() {
+        let g = small_graph();
+        let lines = rendered(FocusDebugOverlay::new(&g));
+        // Node outlines with ID labels and tab badges.
+        assert!(lines[1].contains("#1"), "missing #1 label: {lines:?}");
+        assert!(lines[1].contains("#2"), "missing #2 label: {lines:?}");
+        // Tab order: node 1 is position 1, node 2 position 2.
+        assert_eq!(lines[1].chars().nth(9), Some('1'), "badge 1: {lines:?}");
+        assert_eq!(lines[1].chars().nth(22), Some('2'), "badge 2: {lines:?}");
+        // Spatial edge markers on the facing sides.
+        assert!(lines[3].contains('\u{2192}'), "missing \u{2192}: {lines:?}");
+        assert!(lines[3].contains('\u{2190}'), "missing \u{2190}: {lines:?}");
+        // Corner of node 1 outline.
+        assert_eq!(lines[1].chars().next(), Some(' '));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 8, &mut pool);
+        FocusDebugOverlay::new(&g).render(Rect::new(0, 0, 30, 8), &mut frame);
+        let corner = frame.buffer.get(1, 1).unwrap();
+        assert_eq!(corner.content.as_char(), Some(BorderChars::SQUARE.top_left));
+    }
+
+    #[test]
+    fn disabled_overlay_leaves_frame_untouched() {
+        let g = small_graph();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 8, &mut pool);
+        FocusDebugOverlay::new(&g)
+            .enabled(false)
+            .render(Rect::new(0, 0, 30, 8), &mut frame);
+        for y in 0..8 {
+            for x in 0..30 {
+                assert!(
+                    frame.buffer.get(x, y).unwrap().is_empty(),
+                    "cell ({x},{y}) touched while disabled"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn trace_highlights_from_and_target() {
+        let g = small_graph();
+        let trace = spatial_navigate_traced(&g, 1, NavDirection::Right);
+        assert_eq!(trace.chosen, Some(2));
+
+        let style = FocusDebugOverlayStyle::default();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 8, &mut pool);
+        FocusDebugOverlay::new(&g)
+            .with_trace(Some(&trace))
+            .render(Rect::new(0, 0, 30, 8), &mut frame);
+
+        assert_eq!(frame.buffer.get(1, 1).unwrap().fg, style.from_color);
+        assert_eq!(frame.buffer.get(14, 1).unwrap().fg, style.target_color);
+    }
+
+    #[test]
+    fn trace_hook_reports_real_navigation_target() {
+        let g = small_graph();
+        for dir in NavDirection::ALL {
+            let trace = spatial_navigate_traced(&g, 1, dir);
+            assert_eq!(trace.chosen, spatial_navigate(&g, 1, dir));
+        }
+    }
+
+    #[test]
+    fn decision_summary_renders_on_bottom_row() {
+        let g = small_graph();
+        let trace = spatial_navigate_traced(&g, 2, NavDirection::Left);
+        let lines = rendered(FocusDebugOverlay::new(&g).with_trace(Some(&trace)));
+        assert!(
+            lines[7].contains("nav: #2 Left \u{2192} #1"),
+            "summary missing: {:?}",
+            lines[7]
+        );
+        assert!(lines[7].contains("(edge)"), "explicit edge tag: {lines:?}");
+    }
+
+    #[test]
+    fn tiny_rects_elide_labels() {
+        let mut g = FocusGraph::new();
+        g.insert(FocusNode::new(7, Rect::new(0, 0, 2, 2)));
+        let lines = rendered(FocusDebugOverlay::new(&g));
+        assert!(
+            !lines[0].contains("#7"),
+            "label should be elided: {lines:?}"
+        );
+    }
+
+    #[test]
+    fn unfocusable_nodes_are_skipped() {
+        let mut g = FocusGraph::new();
+        g.insert(FocusNode::new(1, Rect::new(0, 0, 8, 3)).with_focusable(false));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 8, &mut pool);
+        FocusDebugOverlay::new(&g).render(Rect::new(0, 0, 30, 8), &mut frame);
+        assert!(frame.buffer.get(0, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn handles_hundreds_of_nodes() {
+        let mut g = FocusGraph::new();
+        for row in 0..20u16 {
+            for col in 0..20u16 {
+                let id = u64::from(row) * 20 + u64::from(col) + 1;
+                g.insert(FocusNode::new(id, Rect::new(col * 2, row, 2, 1)));
+            }
+        }
+        crate::focus::spatial::build_spatial_edges(&mut g);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(60, 30, &mut pool);
+        // Must render without panicking; tiny rects elide all labels.
+        FocusDebugOverlay::new(&g).render(Rect::new(0, 0, 60, 30), &mut frame);
+    }
+}
@@ -67,6 +67,8 @@ pub struct FocusNode {
     pub is_focusable: bool,
     /// Optional group for focus trapping regions.
     pub group_id: Option<u32>,
+    /// Containing node for phased event dispatch (capture/bubble path).
+    pub container: Option<FocusId>,
 }
 
 impl FocusNode {
@@ -79,6 +81,7 @@ impl FocusNode {
             tab_index: 0,
             is_focusable: true,
             group_id: None,
+            container: None,
         }
     }
 
@@ -102,6 +105,13 @@ impl FocusNode {
         self.group_id = Some(group);
         self
     }
+
+    /// Builder: set the containing node (capture/bubble dispatch path).
+    #[must_use]
+    pub fn with_container(mut self, container: FocusId) -> Self {
+        self.container = Some(container);
+        self
+    }
 }
 
 /// Directed graph for focus navigation.
@@ -172,6 +182,29 @@ impl FocusGraph {
     }
 
     /// Look up a node by ID.
+    /// Root-to-target container path ending at `id`.
+    ///
+    /// Follows [`FocusNode::container`] links upward, then reverses, so
+    /// the outermost container comes first and `id` last. Cycles and
+    /// dangling links terminate the walk (the path stays acyclic).
+    #[must_use]
+    pub fn path_to(&self, id: FocusId) -> Vec<FocusId> {
+        let mut path = vec![id];
+        let mut current = id;
+        while let Some(node) = self.get(current) {
+            let Some(container) = node.container else {
+                break;
+            };
+            if path.contains(&container) {
+                break; // cycle guard
+            }
+            path.push(container);
+            current = container;
+        }
+        path.reverse();
+        path
+    }
+
     #[must_use = "use the returned node (if any)"]
     pub fn get(&self, id: FocusId) -> Option<&FocusNode> {
         self.nodes.get(&id)
@@ -20,7 +20,13 @@
 //!     .with_style(Style::new().bold());
 //! ```
 
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::frame::Frame;
 use ftui_style::Style;
+use ftui_style::color::relative_luminance_packed;
+use ftui_style::theme::ResolvedTheme;
+use web_time::Duration;
 
 /// The kind of visual cue used to indicate focus.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -143,6 +149,302 @@ impl FocusIndicator {
     }
 }
 
+// =========================================================================
+// Focus ring post-pass
+// =========================================================================
+
+/// Built-in focus ring renderings for [`FocusRing`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FocusRingKind {
+    /// Box-drawing brackets on the four corners of the widget rect.
+    #[default]
+    CornerBrackets,
+    /// Full border drawn over the widget's outermost cells.
+    BorderOverlay {
+        /// Use double-line box drawing (`╔═╗`) instead of single.
+        double: bool,
+    },
+    /// Background tint across the whole rect.
+    Tint,
+    /// Tint whose intensity pulses as a pure function of animation time.
+    Pulse,
+}
+
+/// Animated, high-contrast focus ring rendered as a post-pass.
+///
+/// Unlike [`FocusIndicator`] (which widgets consult while rendering
+/// themselves), a `FocusRing` is drawn *over* the focused widget's rect
+/// after the frame is composed — the widget never knows. Colors resolve
+/// from theme tokens (`border_focused`) unless overridden, with a
+/// guaranteed-contrast fallback: a ring color within a luminance epsilon
+/// of the widget background switches to the background's inverse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FocusRing {
+    kind: FocusRingKind,
+    /// Explicit ring color; `None` resolves `theme.border_focused`.
+    color: Option<PackedRgba>,
+    /// Disable animation (pulse renders at constant full intensity).
+    reduced_motion: bool,
+}
+
+/// Luminance difference below which the ring color is considered
+/// indistinguishable from the widget background.
+const LUMINANCE_EPSILON: f64 = 0.08;
+
+/// Pulse period. The intensity is a triangle wave over this period.
+const PULSE_PERIOD_MS: u64 = 1200;
+
+/// Pulse intensity floor (the wave oscillates between this and 1.0).
+const PULSE_FLOOR: f32 = 0.35;
+
+/// Tint blend strength at full intensity.
+const TINT_STRENGTH: f32 = 0.35;
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self::corner_brackets()
+    }
+}
+
+impl FocusRing {
+    /// Corner-bracket ring (default).
+    #[must_use]
+    pub const fn corner_brackets() -> Self {
+        Self {
+            kind: FocusRingKind::CornerBrackets,
+            color: None,
+            reduced_motion: false,
+        }
+    }
+
+    /// Single-line full border overlay.
+    #[must_use]
+    pub const fn border_overlay() -> Self {
+        Self {
+            kind: FocusRingKind::BorderOverlay { double: false },
+            color: None,
+            reduced_motion: false,
+        }
+    }
+
+    /// Double-line full border overlay.
+    #[must_use]
+    pub const fn double_border() -> Self {
+        Self {
+            kind: FocusRingKind::BorderOverlay { double: true },
+            color: None,
+            reduced_motion: false,
+        }
+    }
+
+    /// Background tint.
+    #[must_use]
+    pub const fn tint() -> Self {
+        Self {
+            kind: FocusRingKind::Tint,
+            color: None,
+            reduced_motion: false,
+        }
+    }
+
+    /// Pulsing background tint.
+    #[must_use]
+    pub const fn pulse() -> Self {
+        Self {
+            kind: FocusRingKind::Pulse,
+            color: None,
+            reduced_motion: false,
+        }
+    }
+
+    /// Override the theme-resolved ring color.
+    #[must_use]
+    pub const fn with_color(mut self, color: PackedRgba) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Honor a reduced-motion preference: pulse renders at constant
+    /// intensity.
+    #[must_use]
+    pub const fn with_reduced_motion(mut self, reduced: bool) -> Self {
+        self.reduced_motion = reduced;
+        self
+    }
+
+    /// The configured kind.
+    #[inline]
+    #[must_use]
+    pub const fn ring_kind(&self) -> FocusRingKind {
+        self.kind
+    }
+
+    /// Render the ring over `area` as a post-pass.
+    ///
+    /// `time` drives the pulse variant (pass the app's animation clock —
+    /// the result is a pure function of it, so renders are deterministic).
+    /// Rects touching the frame edge are clipped, never wrapped; rects a
+    /// single cell tall (or too narrow for a border) degrade to tint.
+    pub fn render(&self, area: Rect, frame: &mut Frame, theme: &ResolvedTheme, time: Duration) {
+        let buf_w = frame.buffer.width();
+        let buf_h = frame.buffer.height();
+        // Clip to the frame; never wrap.
+        let x0 = area.x.min(buf_w);
+        let y0 = area.y.min(buf_h);
+        let x1 = area.right().min(buf_w);
+        let y1 = area.bottom().min(buf_h);
+        if x0 >= x1 || y0 >= y1 {
+            return;
+        }
+
+        let bg = self.sample_background(frame, x0, y0, x1, y1, theme);
+        let ring = ensure_contrast(self.resolve_color(theme), bg);
+
+        let degenerate = y1 - y0 < 2 || x1 - x0 < 2;
+        match self.kind {
+            FocusRingKind::Tint => Self::render_tint(frame, (x0, y0, x1, y1), ring, 1.0),
+            FocusRingKind::Pulse => {
+                let intensity = pulse_intensity(time, self.reduced_motion);
+                Self::render_tint(frame, (x0, y0, x1, y1), ring, intensity);
+            }
+            // Border treatments need at least a 2x2 rect; degrade to tint.
+            FocusRingKind::CornerBrackets | FocusRingKind::BorderOverlay { .. } if degenerate => {
+                Self::render_tint(frame, (x0, y0, x1, y1), ring, 1.0);
+            }
+            FocusRingKind::CornerBrackets => {
+                for (x, y, glyph) in [
+                    (x0, y0, '┌'),
+                    (x1 - 1, y0, '┐'),
+                    (x0, y1 - 1, '└'),
+                    (x1 - 1, y1 - 1, '┘'),
+                ] {
+                    overlay_glyph(frame, x, y, glyph, ring);
+                }
+            }
+            FocusRingKind::BorderOverlay { double } => {
+                let (h, v, tl, tr, bl, br) = if double {
+                    ('═', '║', '╔', '╗', '╚', '╝')
+                } else {
+                    ('─', '│', '┌', '┐', '└', '┘')
+                };
+                for x in x0 + 1..x1 - 1 {
+                    overlay_glyph(frame, x, y0, h, ring);
+                    overlay_glyph(frame, x, y1 - 1, h, ring);
+                }
+                for y in y0 + 1..y1 - 1 {
+                    overlay_glyph(frame, x0, y, v, ring);
+                    overlay_glyph(frame, x1 - 1, y, v, ring);
+                }
+                overlay_glyph(frame, x0, y0, tl, ring);
+                overlay_glyph(frame, x1 - 1, y0, tr, ring);
+                overlay_glyph(frame, x0, y1 - 1, bl, ring);
+                overlay_glyph(frame, x1 - 1, y1 - 1, br, ring);
+            }
+        }
+    }
+
+    fn resolve_color(&self, theme: &ResolvedTheme) -> PackedRgba {
+        self.color.unwrap_or_else(|| {
+            let rgb = theme.border_focused.to_rgb();
+            PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
+        })
+    }
+
+    /// Sample the widget background from the rect center, falling back to
+    /// the theme background when the cell is transparent.
+    fn sample_background(
+        &self,
+        frame: &Frame,
+        x0: u16,
+        y0: u16,
+        x1: u16,
+        y1: u16,
+        theme: &ResolvedTheme,
+    ) -> PackedRgba {
+        let sampled = frame
+            .buffer
+            .get(x0 + (x1 - x0) / 2, y0 + (y1 - y0) / 2)
+            .map(|cell| cell.bg)
+            .filter(|bg| bg.a() > 0);
+        sampled.unwrap_or_else(|| {
+            let rgb = theme.background.to_rgb();
+            PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
+        })
+    }
+
+    fn render_tint(
+        frame: &mut Frame,
+        clip: (u16, u16, u16, u16),
+        ring: PackedRgba,
+        intensity: f32,
+    ) {
+        let (x0, y0, x1, y1) = clip;
+        let strength = TINT_STRENGTH * intensity;
+        for y in y0..y1 {
+            for x in x0..x1 {
+                if let Some(cell) = frame.buffer.get(x, y) {
+                    let mut tinted = *cell;
+                    tinted.bg = mix(tinted.bg, ring, strength);
+                    frame.buffer.set(x, y, tinted);
+                }
+            }
+        }
+    }
+}
+
+/// Pulse intensity at `time`: a triangle wave between the floor and 1.0.
+///
+/// Pure and deterministic; `reduced_motion` pins it to 1.0.
+#[must_use]
+pub fn pulse_intensity(time: Duration, reduced_motion: bool) -> f32 {
+    if reduced_motion {
+        return 1.0;
+    }
+    let phase_ms = (time.as_millis() as u64 % PULSE_PERIOD_MS) as f32;
+    let half = PULSE_PERIOD_MS as f32 / 2.0;
+    let ramp = if phase_ms < half {
+        phase_ms / half
+    } else {
+        (PULSE_PERIOD_MS as f32 - phase_ms) / half
+    };
+    PULSE_FLOOR + (1.0 - PULSE_FLOOR) * ramp
+}
+
+/// Guaranteed-contrast fallback: a ring within [`LUMINANCE_EPSILON`] of
+/// the background switches to the background's inverse.
+fn ensure_contrast(ring: PackedRgba, bg: PackedRgba) -> PackedRgba {
+    let ring_lum = relative_luminance_packed(ring);
+    let bg_lum = relative_luminance_packed(bg);
+    if (ring_lum - bg_lum).abs() < LUMINANCE_EPSILON {
+        PackedRgba::rgb(255 - bg.r(), 255 - bg.g(), 255 - bg.b())
+    } else {
+        ring
+    }
+}
+
+/// Draw a ring glyph over a cell, preserving the cell's background.
+fn overlay_glyph(frame: &mut Frame, x: u16, y: u16, glyph: char, ring: PackedRgba) {
+    let Some(existing) = frame.buffer.get(x, y) else {
+        return;
+    };
+    let mut cell = Cell::from_char(glyph);
+    cell.fg = ring;
+    cell.bg = existing.bg;
+    frame.buffer.set(x, y, cell);
+}
+
+/// Linear blend of two colors (alpha ignored; result is opaque).
+fn mix(base: PackedRgba, tint: PackedRgba, t: f32) -> PackedRgba {
+    let t = t.clamp(0.0, 1.0);
+    let channel =
+        |a: u8, b: u8| -> u8 { (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8 };
+    PackedRgba::rgb(
+        channel(base.r(), tint.r()),
+        channel(base.g(), tint.g()),
+        channel(base.b(), tint.b()),
+    )
+}
+
 // =========================================================================
 // Tests
 // =========================================================================
@@ -194,6 +496,144 @@ mod tests {
         assert_eq!(ind.kind(), FocusIndicatorKind::Border);
     }
 
+    // --- FocusRing tests ---
+
+    use ftui_render::grapheme_pool::GraphemePool;
+    use ftui_style::theme::themes;
+
+    fn theme() -> ResolvedTheme {
+        themes::dark().resolve(true)
+    }
+
+    fn glyph_at(frame: &Frame, x: u16, y: u16) -> Option<char> {
+        frame.buffer.get(x, y).and_then(|c| c.content.as_char())
+    }
+
+    #[test]
+    fn corner_brackets_snapshot() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 5, &mut pool);
+        let area = Rect::new(1, 1, 6, 3);
+        FocusRing::corner_brackets().render(area, &mut frame, &theme(), Duration::ZERO);
+
+        assert_eq!(glyph_at(&frame, 1, 1), Some('┌'));
+        assert_eq!(glyph_at(&frame, 6, 1), Some('┐'));
+        assert_eq!(glyph_at(&frame, 1, 3), Some('└'));
+        assert_eq!(glyph_at(&frame, 6, 3), Some('┘'));
+        // Interior untouched.
+        assert_eq!(glyph_at(&frame, 3, 2), None);
+    }
+
+    #[test]
+    fn border_overlay_snapshots_single_and_double() {
+        for (double, h, v, tl) in [(false, '─', '│', '┌'), (true, '═', '║', '╔')] {
+            let mut pool = GraphemePool::new();
+            let mut frame = Frame::new(10, 5, &mut pool);
+            let ring = if double {
+                FocusRing::double_border()
+            } else {
+                FocusRing::border_overlay()
+            };
+            ring.render(Rect::new(0, 0, 5, 4), &mut frame, &theme(), Duration::ZERO);
+
+            assert_eq!(glyph_at(&frame, 0, 0), Some(tl), "double={double}");
+            assert_eq!(glyph_at(&frame, 2, 0), Some(h));
+            assert_eq!(glyph_at(&frame, 0, 2), Some(v));
+            assert!(glyph_at(&frame, 4, 3).is_some());
+        }
+    }
+
+    #[test]
+    fn rect_touching_frame_edge_clips_without_wrapping() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(6, 4, &mut pool);
+        // Rect extends past both edges of the frame.
+        let area = Rect::new(4, 2, 6, 6);
+        FocusRing::border_overlay().render(area, &mut frame, &theme(), Duration::ZERO);
+
+        // Top-left corner is inside and drawn.
+        assert_eq!(glyph_at(&frame, 4, 2), Some('┌'));
+        // Nothing wrapped to column 0.
+        assert_eq!(glyph_at(&frame, 0, 2), None);
+        assert_eq!(glyph_at(&frame, 0, 3), None);
+    }
+
+    #[test]
+    fn one_cell_tall_degrades_to_tint() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 3, &mut pool);
+        let before = frame.buffer.get(2, 1).unwrap().bg;
+        FocusRing::border_overlay().render(
+            Rect::new(0, 1, 8, 1),
+            &mut frame,
+            &theme(),
+            Duration::ZERO,
+        );
+        // No border glyphs...
+        for x in 0..8 {
+            assert_eq!(glyph_at(&frame, x, 1), None, "col {x}");
+        }
+        // ...but the background was tinted.
+        assert_ne!(frame.buffer.get(2, 1).unwrap().bg, before);
+    }
+
+    #[test]
+    fn contrast_fallback_inverts_when_ring_matches_background() {
+        let bg = PackedRgba::rgb(100, 100, 100);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(6, 3, &mut pool);
+        for y in 0..3 {
+            for x in 0..6 {
+                let mut cell = Cell::from_char(' ');
+                cell.bg = bg;
+                frame.buffer.set(x, y, cell);
+            }
+        }
+        // Ring color identical to the widget background.
+        FocusRing::corner_brackets().with_color(bg).render(
+            Rect::new(0, 0, 6, 3),
+            &mut frame,
+            &theme(),
+            Duration::ZERO,
+        );
+        let corner = frame.buffer.get(0, 0).unwrap();
+        assert_eq!(corner.fg, PackedRgba::rgb(155, 155, 155), "inverse of bg");
+    }
+
+    #[test]
+    fn pulse_intensity_is_deterministic() {
+        assert_eq!(pulse_intensity(Duration::ZERO, false), PULSE_FLOOR);
+        assert_eq!(pulse_intensity(Duration::from_millis(600), false), 1.0);
+        // Triangle symmetry and periodicity.
+        assert_eq!(
+            pulse_intensity(Duration::from_millis(300), false),
+            pulse_intensity(Duration::from_millis(900), false),
+        );
+        assert_eq!(
+            pulse_intensity(Duration::from_millis(150), false),
+            pulse_intensity(Duration::from_millis(1200 + 150), false),
+        );
+        // Reduced motion pins to full intensity.
+        assert_eq!(pulse_intensity(Duration::from_millis(37), true), 1.0);
+    }
+
+    #[test]
+    fn pulse_render_is_pure_function_of_time() {
+        let render_at = |ms: u64| -> PackedRgba {
+            let mut pool = GraphemePool::new();
+            let mut frame = Frame::new(4, 2, &mut pool);
+            FocusRing::pulse().render(
+                Rect::new(0, 0, 4, 2),
+                &mut frame,
+                &theme(),
+                Duration::from_millis(ms),
+            );
+            frame.buffer.get(1, 1).unwrap().bg
+        };
+        assert_eq!(render_at(250), render_at(250));
+        assert_ne!(render_at(0), render_at(600));
+    }
+
     #[test]
     fn apply_to_merges_styles() {
         let base = Style::new().fg(PackedRgba::rgb(255, 0, 0));
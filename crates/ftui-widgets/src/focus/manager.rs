@@ -11,7 +11,7 @@ use ahash::AHashMap;
 use ftui_core::event::KeyCode;
 
 use super::indicator::FocusIndicator;
-use super::spatial;
+use super::spatial::{self, NavTrace};
 use super::{FocusGraph, FocusId, NavDirection};
 
 /// Focus change events emitted by the manager.
@@ -81,6 +81,8 @@ pub struct FocusManager {
     indicator: FocusIndicator,
     /// Running count of focus changes for metrics.
     focus_change_count: u64,
+    /// Last directional navigation decision (for the focus debug overlay).
+    last_nav_trace: Option<NavTrace>,
 }
 
 impl FocusManager {
@@ -108,6 +110,29 @@ impl FocusManager {
         self.current
     }
 
+    /// Last directional navigation decision, if any.
+    ///
+    /// Recorded by [`navigate`](Self::navigate) for Up/Down/Left/Right
+    /// moves; feed it to the focus debug overlay to explain the jump.
+    #[must_use]
+    pub fn last_nav_trace(&self) -> Option<&NavTrace> {
+        self.last_nav_trace.as_ref()
+    }
+
+    /// Root-to-target dispatch path for the focused widget.
+    ///
+    /// Follows the focus graph's container links
+    /// ([`FocusNode::with_container`](crate::focus::FocusNode)); empty
+    /// when nothing is focused. Feed the result to
+    /// [`dispatch_phased`](crate::event_routing::dispatch_phased) for
+    /// key events (mouse events route by hit-test path instead).
+    #[must_use]
+    pub fn dispatch_path(&self) -> Vec<FocusId> {
+        self.current()
+            .map(|id| self.graph().path_to(id))
+            .unwrap_or_default()
+    }
+
     /// Check if a widget is focused.
     #[must_use]
     pub fn is_focused(&self, id: FocusId) -> bool {
@@ -176,10 +201,21 @@ impl FocusManager {
                     return false;
                 };
                 // Explicit edges take precedence; fall back to spatial navigation.
-                let target = self
-                    .graph
-                    .navigate(current, dir)
-                    .or_else(|| spatial::spatial_navigate(&self.graph, current, dir));
+                let target = if let Some(target) = self.graph.navigate(current, dir) {
+                    self.last_nav_trace = Some(NavTrace {
+                        origin: current,
+                        dir,
+                        chosen: Some(target),
+                        candidates: Vec::new(),
+                        explicit_edge: true,
+                    });
+                    Some(target)
+                } else {
+                    let trace = spatial::spatial_navigate_traced(&self.graph, current, dir);
+                    let chosen = trace.chosen;
+                    self.last_nav_trace = Some(trace);
+                    chosen
+                };
                 let Some(target) = target else {
                     return false;
                 };
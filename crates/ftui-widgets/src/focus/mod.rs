@@ -2,12 +2,18 @@
 
 //! Focus management: navigation graph, manager, spatial navigation, and styling.
 
+pub mod debug;
 pub mod graph;
 pub mod indicator;
 pub mod manager;
 pub mod spatial;
 
+pub use debug::{FocusDebugOverlay, FocusDebugOverlayStyle};
 pub use graph::{FocusGraph, FocusId, FocusNode, NavDirection};
-pub use indicator::{FocusIndicator, FocusIndicatorKind};
+pub use indicator::{
+    FocusIndicator, FocusIndicatorKind, FocusRing, FocusRingKind, pulse_intensity,
+};
 pub use manager::{FocusEvent, FocusGroup, FocusManager, FocusTrap};
-pub use spatial::{build_spatial_edges, spatial_navigate};
+pub use spatial::{
+    NavTrace, ScoredCandidate, build_spatial_edges, spatial_navigate, spatial_navigate_traced,
+};
@@ -23,17 +23,71 @@
 use super::graph::{FocusGraph, FocusId, NavDirection};
 use ftui_core::geometry::Rect;
 
+/// A candidate considered during spatial navigation, with its score.
+///
+/// Lower scores are better (see [`distance_score_i32`] in the module docs).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoredCandidate {
+    pub id: FocusId,
+    pub score: i64,
+}
+
+/// Trace of one navigation decision, for the focus debug overlay.
+///
+/// Produced by [`spatial_navigate_traced`]; records where the search
+/// started, which direction it went, what it chose, and the top scored
+/// candidates so "why did focus jump there" can be answered visually.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavTrace {
+    /// Node navigation started from.
+    pub origin: FocusId,
+    /// Direction requested.
+    pub dir: NavDirection,
+    /// Target the navigation chose (`None` = no valid candidate).
+    pub chosen: Option<FocusId>,
+    /// Best-scored candidates, best first (at most [`Self::MAX_CANDIDATES`]).
+    /// Empty when an explicit edge decided the move.
+    pub candidates: Vec<ScoredCandidate>,
+    /// Whether an explicit graph edge decided the move (no scoring ran).
+    pub explicit_edge: bool,
+}
+
+impl NavTrace {
+    /// Number of top candidates retained in [`NavTrace::candidates`].
+    pub const MAX_CANDIDATES: usize = 3;
+}
+
 /// Find the best spatial navigation target from `origin` in `dir`.
 ///
 /// Returns `None` if no valid candidate exists. Explicit graph edges
 /// take precedence over spatial search.
 #[must_use]
 pub fn spatial_navigate(graph: &FocusGraph, origin: FocusId, dir: NavDirection) -> Option<FocusId> {
+    spatial_navigate_traced(graph, origin, dir).chosen
+}
+
+/// [`spatial_navigate`] with a full decision trace.
+///
+/// Runs the identical search (same precedence, quadrant filter, scoring,
+/// and tie-breaking) but also records the top
+/// [`NavTrace::MAX_CANDIDATES`] scored candidates for diagnostics.
+#[must_use]
+pub fn spatial_navigate_traced(graph: &FocusGraph, origin: FocusId, dir: NavDirection) -> NavTrace {
+    let mut trace = NavTrace {
+        origin,
+        dir,
+        chosen: None,
+        candidates: Vec::new(),
+        explicit_edge: false,
+    };
+
     // 1. Check explicit edge first.
     if let Some(target) = graph.navigate(origin, dir)
         && graph.get(target).is_some_and(|n| n.is_focusable)
     {
-        return Some(target);
+        trace.chosen = Some(target);
+        trace.explicit_edge = true;
+        return trace;
     }
 
     // Only spatial directions make sense.
@@ -41,13 +95,18 @@ pub fn spatial_navigate(graph: &FocusGraph, origin: FocusId, dir: NavDirection)
         dir,
         NavDirection::Up | NavDirection::Down | NavDirection::Left | NavDirection::Right
     ) {
-        return None;
+        return trace;
     }
 
-    let origin_node = graph.get(origin)?;
+    let Some(origin_node) = graph.get(origin) else {
+        return trace;
+    };
     let oc = center_i32(&origin_node.bounds);
 
-    let mut best: Option<(FocusId, i64)> = None;
+    // Top candidates, best first. Fixed-size insertion keeps the scan
+    // allocation-free regardless of node count.
+    let mut top: [Option<ScoredCandidate>; NavTrace::MAX_CANDIDATES] =
+        [None; NavTrace::MAX_CANDIDATES];
 
     // Pre-collect candidate data to avoid per-candidate HashMap lookups.
     for candidate_id in graph.node_ids() {
@@ -68,15 +127,30 @@ pub fn spatial_navigate(graph: &FocusGraph, origin: FocusId, dir: NavDirection)
         }
 
         let score = distance_score_i32(oc, cc, dir);
+        let entry = ScoredCandidate {
+            id: candidate_id,
+            score,
+        };
 
-        if best.is_none_or(|(best_id, best_score)| {
-            score < best_score || (score == best_score && candidate_id < best_id)
-        }) {
-            best = Some((candidate_id, score));
+        // Insert into the sorted top array (score asc, ties by lower ID,
+        // matching the original best-candidate tie-break).
+        for slot in 0..top.len() {
+            let beats = top[slot].is_none_or(|held| {
+                entry.score < held.score || (entry.score == held.score && entry.id < held.id)
+            });
+            if beats {
+                for shift in (slot + 1..top.len()).rev() {
+                    top[shift] = top[shift - 1];
+                }
+                top[slot] = Some(entry);
+                break;
+            }
         }
     }
 
-    best.map(|(id, _)| id)
+    trace.candidates = top.iter().flatten().copied().collect();
+    trace.chosen = trace.candidates.first().map(|c| c.id);
+    trace
 }
 
 /// Build spatial edges for all nodes in the graph.
@@ -319,6 +393,56 @@ mod tests {
         assert_eq!(spatial_navigate(&g, 1, NavDirection::Prev), None);
     }
 
+    // --- Navigation tracing ---
+
+    #[test]
+    fn traced_chosen_matches_real_navigation() {
+        let g = grid_3x3();
+        for id in 1..=9 {
+            for dir in NavDirection::ALL {
+                let trace = spatial_navigate_traced(&g, id, dir);
+                assert_eq!(
+                    trace.chosen,
+                    spatial_navigate(&g, id, dir),
+                    "trace disagrees for id={id}, dir={dir:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn traced_reports_top_candidates_best_first() {
+        let g = grid_3x3();
+        // Right from 4 (middle-left): candidates 5, 6 (same row) and the
+        // diagonal ones; best-first ordering with ascending scores.
+        let trace = spatial_navigate_traced(&g, 4, NavDirection::Right);
+        assert_eq!(trace.chosen, Some(5));
+        assert!(!trace.explicit_edge);
+        assert!(trace.candidates.len() <= NavTrace::MAX_CANDIDATES);
+        assert_eq!(trace.candidates[0].id, 5);
+        for pair in trace.candidates.windows(2) {
+            assert!(pair[0].score <= pair[1].score, "candidates not sorted");
+        }
+    }
+
+    #[test]
+    fn traced_explicit_edge_skips_scoring() {
+        let mut g = grid_3x3();
+        g.connect(1, NavDirection::Right, 9);
+        let trace = spatial_navigate_traced(&g, 1, NavDirection::Right);
+        assert_eq!(trace.chosen, Some(9));
+        assert!(trace.explicit_edge);
+        assert!(trace.candidates.is_empty());
+    }
+
+    #[test]
+    fn traced_no_candidates_at_edge() {
+        let g = grid_3x3();
+        let trace = spatial_navigate_traced(&g, 1, NavDirection::Left);
+        assert_eq!(trace.chosen, None);
+        assert!(trace.candidates.is_empty());
+    }
+
     // --- build_spatial_edges ---
 
     #[test]
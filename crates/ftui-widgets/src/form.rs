@@ -0,0 +1,721 @@
+#![forbid(unsafe_code)]
+
+//! Form container: field registration, tab order, validation
+//! aggregation, dirty tracking, and a gated submit flow.
+//!
+//! Building a settings form means wiring focus order, per-field
+//! validation, and a submit button's enabled state by hand. [`Form`]
+//! does the wiring: fields register with an id, a label (rendered in an
+//! aligned label column), and validator closures; the form manages tab
+//! order (registration order by default, overridable via
+//! [`Form::tab_order`], disabled fields skipped), runs validators on
+//! change and on submit, aggregates a [`FormState`], renders per-field
+//! errors below or beside fields ([`ErrorPlacement`]), and gates submit
+//! on validity — emitting [`FormEvent::Submitted`] with the typed value
+//! map. Dirty tracking compares against the registered initial values
+//! so "unsaved changes" prompts (via the dialog widget) are possible;
+//! cross-field validators (password confirmation) see the whole value
+//! map and re-fire when *either* field changes.
+//!
+//! Focus composition: [`Form::focus_ids`] exposes one stable id per
+//! field for registration with the
+//! [`FocusManager`](crate::focus::FocusManager) when the form is part
+//! of a larger focus graph.
+
+use std::collections::BTreeMap;
+
+use crate::{StatefulWidget, draw_text_span, set_style_area};
+use ftui_core::event::{KeyCode, KeyEvent, KeyEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+/// A typed field value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormValue {
+    Text(String),
+    Bool(bool),
+    /// Selected index into the field's options.
+    Choice(usize),
+}
+
+impl FormValue {
+    /// The text content, when this is a text value.
+    #[must_use]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// Where field errors render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorPlacement {
+    /// On their own row below the field (default).
+    #[default]
+    Below,
+    /// To the right of the field on the same row.
+    Beside,
+}
+
+/// Aggregated validation state.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FormState {
+    pub valid: bool,
+    /// `(field_id, message)` pairs, in field order.
+    pub errors: Vec<(String, String)>,
+}
+
+/// Form lifecycle events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormEvent {
+    /// A field's value changed.
+    Changed(String),
+    /// Submit passed validation; carries the typed value map.
+    Submitted(BTreeMap<String, FormValue>),
+    /// Submit was rejected (invalid fields); see [`Form::state`].
+    SubmitBlocked,
+}
+
+type FieldValidator = dyn Fn(&FormValue) -> Option<String>;
+type CrossValidator = dyn Fn(&BTreeMap<String, FormValue>) -> Option<(String, String)>;
+
+struct Field {
+    id: String,
+    label: String,
+    value: FormValue,
+    initial: FormValue,
+    options: Vec<String>,
+    disabled: bool,
+    validator: Option<Box<FieldValidator>>,
+}
+
+/// Form container (see the module docs).
+pub struct Form {
+    fields: Vec<Field>,
+    /// Tab order as indices into `fields`.
+    order: Vec<usize>,
+    focused: usize,
+    cross_validators: Vec<Box<CrossValidator>>,
+    error_placement: ErrorPlacement,
+    style: Style,
+    label_style: Style,
+    focused_style: Style,
+    error_style: Style,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Form {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            order: Vec::new(),
+            focused: 0,
+            cross_validators: Vec::new(),
+            error_placement: ErrorPlacement::default(),
+            style: Style::default(),
+            label_style: Style::new().bold(),
+            focused_style: Style::new().reverse(),
+            error_style: Style::new().italic(),
+        }
+    }
+
+    // ── Registration ─────────────────────────────────────────────────
+
+    /// Register a text field.
+    #[must_use]
+    pub fn text_field(
+        self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+        initial: &str,
+    ) -> Self {
+        self.register(id, label, FormValue::Text(initial.to_string()), Vec::new())
+    }
+
+    /// Register a checkbox field.
+    #[must_use]
+    pub fn bool_field(
+        self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+        initial: bool,
+    ) -> Self {
+        self.register(id, label, FormValue::Bool(initial), Vec::new())
+    }
+
+    /// Register a choice field cycling through `options`.
+    #[must_use]
+    pub fn choice_field(
+        self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+        options: Vec<String>,
+        initial: usize,
+    ) -> Self {
+        self.register(id, label, FormValue::Choice(initial), options)
+    }
+
+    fn register(
+        mut self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+        value: FormValue,
+        options: Vec<String>,
+    ) -> Self {
+        self.order.push(self.fields.len());
+        self.fields.push(Field {
+            id: id.into(),
+            label: label.into(),
+            initial: value.clone(),
+            value,
+            options,
+            disabled: false,
+            validator: None,
+        });
+        self
+    }
+
+    /// Attach a validator to the most recently registered field.
+    #[must_use]
+    pub fn validate_with(
+        mut self,
+        validator: impl Fn(&FormValue) -> Option<String> + 'static,
+    ) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.validator = Some(Box::new(validator));
+        }
+        self
+    }
+
+    /// Mark the most recently registered field required (non-empty).
+    #[must_use]
+    pub fn required(self) -> Self {
+        self.validate_with(|value| match value {
+            FormValue::Text(text) if text.trim().is_empty() => Some("required".to_string()),
+            _ => None,
+        })
+    }
+
+    /// Disable the most recently registered field (skipped in tab order).
+    #[must_use]
+    pub fn disabled(mut self) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.disabled = true;
+        }
+        self
+    }
+
+    /// Cross-field validator over the whole value map; returns
+    /// `(field_id, message)` to attribute the error.
+    #[must_use]
+    pub fn cross_validate(
+        mut self,
+        validator: impl Fn(&BTreeMap<String, FormValue>) -> Option<(String, String)> + 'static,
+    ) -> Self {
+        self.cross_validators.push(Box::new(validator));
+        self
+    }
+
+    /// Override the tab order by field ids (unknown ids ignored;
+    /// unlisted fields keep registration order after the listed ones).
+    #[must_use]
+    pub fn tab_order(mut self, ids: &[&str]) -> Self {
+        let mut order = Vec::new();
+        for &id in ids {
+            if let Some(idx) = self.fields.iter().position(|f| f.id == id) {
+                order.push(idx);
+            }
+        }
+        for idx in 0..self.fields.len() {
+            if !order.contains(&idx) {
+                order.push(idx);
+            }
+        }
+        self.order = order;
+        self
+    }
+
+    /// Where to render per-field errors.
+    #[must_use]
+    pub fn error_placement(mut self, placement: ErrorPlacement) -> Self {
+        self.error_placement = placement;
+        self
+    }
+
+    // ── Queries ──────────────────────────────────────────────────────
+
+    /// The typed value map (field id → value).
+    #[must_use]
+    pub fn values(&self) -> BTreeMap<String, FormValue> {
+        self.fields
+            .iter()
+            .map(|field| (field.id.clone(), field.value.clone()))
+            .collect()
+    }
+
+    /// Aggregated validation state (run on demand).
+    #[must_use]
+    pub fn state(&self) -> FormState {
+        let values = self.values();
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            if let Some(validator) = &field.validator
+                && let Some(message) = validator(&field.value)
+            {
+                errors.push((field.id.clone(), message));
+            }
+        }
+        for validator in &self.cross_validators {
+            if let Some((field_id, message)) = validator(&values) {
+                errors.push((field_id, message));
+            }
+        }
+        FormState {
+            valid: errors.is_empty(),
+            errors,
+        }
+    }
+
+    /// Whether any field differs from its registered initial value.
+    #[must_use]
+    pub fn dirty(&self) -> bool {
+        self.fields.iter().any(|field| field.value != field.initial)
+    }
+
+    /// Reset every field to its initial value (clears dirty).
+    pub fn reset(&mut self) {
+        for field in &mut self.fields {
+            field.value = field.initial.clone();
+        }
+    }
+
+    /// Mark current values as the new baseline (after a save).
+    pub fn mark_clean(&mut self) {
+        for field in &mut self.fields {
+            field.initial = field.value.clone();
+        }
+    }
+
+    /// The focused field's id.
+    #[must_use]
+    pub fn focused_id(&self) -> Option<&str> {
+        self.order
+            .get(self.focused)
+            .map(|&idx| self.fields[idx].id.as_str())
+    }
+
+    /// One stable focus id per field (registration order), for
+    /// composing with the [`FocusManager`](crate::focus::FocusManager).
+    #[must_use]
+    pub fn focus_ids(&self) -> Vec<(u64, &str)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| (idx as u64 + 1, field.id.as_str()))
+            .collect()
+    }
+
+    /// Current value of a field.
+    #[must_use]
+    pub fn value(&self, id: &str) -> Option<&FormValue> {
+        self.fields.iter().find(|f| f.id == id).map(|f| &f.value)
+    }
+
+    /// Set a field's value programmatically.
+    pub fn set_value(&mut self, id: &str, value: FormValue) -> Option<FormEvent> {
+        let field = self.fields.iter_mut().find(|f| f.id == id)?;
+        if field.value == value {
+            return None;
+        }
+        field.value = value;
+        Some(FormEvent::Changed(field.id.clone()))
+    }
+
+    // ── Interaction ──────────────────────────────────────────────────
+
+    /// Move focus forward/backward through enabled fields.
+    fn move_focus(&mut self, forward: bool) {
+        let count = self.order.len();
+        if count == 0 {
+            return;
+        }
+        let mut next = self.focused;
+        for _ in 0..count {
+            next = if forward {
+                (next + 1) % count
+            } else {
+                (next + count - 1) % count
+            };
+            if !self.fields[self.order[next]].disabled {
+                self.focused = next;
+                return;
+            }
+        }
+    }
+
+    /// Keyboard handling: Tab/BackTab move focus, Enter submits (gated
+    /// on validity), field keys edit the focused field. Returns the
+    /// event the interaction produced.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> Option<FormEvent> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match key.code {
+            KeyCode::Tab => {
+                self.move_focus(true);
+                None
+            }
+            KeyCode::BackTab => {
+                self.move_focus(false);
+                None
+            }
+            KeyCode::Enter => Some(self.submit()),
+            code => {
+                let &field_idx = self.order.get(self.focused)?;
+                let field = &mut self.fields[field_idx];
+                if field.disabled {
+                    return None;
+                }
+                let changed = match (&mut field.value, code) {
+                    (FormValue::Text(text), KeyCode::Char(c)) => {
+                        text.push(key.ch.unwrap_or(c));
+                        true
+                    }
+                    (FormValue::Text(text), KeyCode::Backspace) => text.pop().is_some(),
+                    (FormValue::Bool(checked), KeyCode::Char(' ')) => {
+                        *checked = !*checked;
+                        true
+                    }
+                    (FormValue::Choice(selected), KeyCode::Right | KeyCode::Down)
+                        if *selected + 1 < field.options.len() =>
+                    {
+                        *selected += 1;
+                        true
+                    }
+                    (FormValue::Choice(selected), KeyCode::Left | KeyCode::Up) if *selected > 0 => {
+                        *selected -= 1;
+                        true
+                    }
+                    _ => false,
+                };
+                changed.then(|| FormEvent::Changed(field.id.clone()))
+            }
+        }
+    }
+
+    /// Submit: validates and emits `Submitted(values)` or
+    /// `SubmitBlocked` (errors stay queryable via [`state`](Self::state)).
+    pub fn submit(&mut self) -> FormEvent {
+        let state = self.state();
+        if state.valid {
+            FormEvent::Submitted(self.values())
+        } else {
+            FormEvent::SubmitBlocked
+        }
+    }
+
+    // ── Rendering ────────────────────────────────────────────────────
+
+    fn value_text(field: &Field) -> String {
+        match &field.value {
+            FormValue::Text(text) => text.clone(),
+            FormValue::Bool(true) => "[x]".to_string(),
+            FormValue::Bool(false) => "[ ]".to_string(),
+            FormValue::Choice(idx) => field
+                .options
+                .get(*idx)
+                .cloned()
+                .unwrap_or_else(|| format!("#{idx}")),
+        }
+    }
+}
+
+/// Render state: currently none beyond the form itself (the form owns
+/// its focus), kept for [`StatefulWidget`] symmetry with the rest of
+/// the widget set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormRenderState;
+
+impl StatefulWidget for Form {
+    type State = FormRenderState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, _state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        let state = self.state();
+        let label_width = self
+            .fields
+            .iter()
+            .map(|field| field.label.chars().count())
+            .max()
+            .unwrap_or(0) as u16
+            + 2;
+
+        let mut y = area.y;
+        for (position, &field_idx) in self.order.iter().enumerate() {
+            if y >= area.bottom() {
+                break;
+            }
+            let field = &self.fields[field_idx];
+            let focused = position == self.focused && !field.disabled;
+            let label_style = if field.disabled {
+                Style::new().dim().merge(&self.label_style)
+            } else {
+                self.label_style
+            };
+            draw_text_span(frame, area.x, y, &field.label, label_style, area.right());
+
+            let value_x = area.x + label_width;
+            let value = Form::value_text(field);
+            let value_style = if focused {
+                self.focused_style.merge(&self.style)
+            } else if field.disabled {
+                Style::new().dim().merge(&self.style)
+            } else {
+                self.style
+            };
+            let value_width = (value.chars().count() as u16).max(1);
+            set_style_area(
+                &mut frame.buffer,
+                Rect::new(
+                    value_x,
+                    y,
+                    value_width.min(area.right().saturating_sub(value_x)),
+                    1,
+                ),
+                value_style,
+            );
+            draw_text_span(frame, value_x, y, &value, value_style, area.right());
+
+            let error = state
+                .errors
+                .iter()
+                .find(|(id, _)| *id == field.id)
+                .map(|(_, message)| message.as_str());
+            if let Some(message) = error {
+                match self.error_placement {
+                    ErrorPlacement::Beside => {
+                        let x = value_x + value_width + 2;
+                        draw_text_span(
+                            frame,
+                            x,
+                            y,
+                            &format!("! {message}"),
+                            self.error_style,
+                            area.right(),
+                        );
+                    }
+                    ErrorPlacement::Below => {
+                        y += 1;
+                        if y < area.bottom() {
+                            draw_text_span(
+                                frame,
+                                value_x,
+                                y,
+                                &format!("! {message}"),
+                                self.error_style,
+                                area.right(),
+                            );
+                        }
+                    }
+                }
+            }
+            y += 1;
+        }
+    }
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::Modifiers;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            ch: match code {
+                KeyCode::Char(c) => Some(c),
+                _ => None,
+            },
+            modifiers: Modifiers::NONE,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    fn password_form() -> Form {
+        Form::new()
+            .text_field("user", "Username", "")
+            .required()
+            .text_field("pass", "Password", "")
+            .required()
+            .text_field("confirm", "Confirm", "")
+            .cross_validate(|values| {
+                let pass = values.get("pass")?.as_text()?;
+                let confirm = values.get("confirm")?.as_text()?;
+                (pass != confirm).then(|| ("confirm".to_string(), "passwords differ".to_string()))
+            })
+    }
+
+    #[test]
+    fn tab_order_skips_disabled_fields() {
+        let mut form = Form::new()
+            .text_field("a", "A", "")
+            .text_field("b", "B", "")
+            .disabled()
+            .text_field("c", "C", "");
+        assert_eq!(form.focused_id(), Some("a"));
+        form.handle_key(&key(KeyCode::Tab));
+        assert_eq!(form.focused_id(), Some("c"), "disabled b skipped");
+        form.handle_key(&key(KeyCode::Tab));
+        assert_eq!(form.focused_id(), Some("a"), "wraps");
+        form.handle_key(&key(KeyCode::BackTab));
+        assert_eq!(form.focused_id(), Some("c"), "reverse skips too");
+    }
+
+    #[test]
+    fn tab_order_override_reorders() {
+        let form = Form::new()
+            .text_field("a", "A", "")
+            .text_field("b", "B", "")
+            .text_field("c", "C", "")
+            .tab_order(&["c", "a"]);
+        assert_eq!(form.focused_id(), Some("c"));
+    }
+
+    #[test]
+    fn validation_aggregates_field_and_cross_errors() {
+        let mut form = password_form();
+        let _ = form.set_value("pass", FormValue::Text("secret".into()));
+        let state = form.state();
+        assert!(!state.valid);
+        let ids: Vec<&str> = state.errors.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["user", "confirm"], "{:?}", state.errors);
+        assert!(state.errors[1].1.contains("differ"));
+    }
+
+    #[test]
+    fn cross_field_validator_fires_on_either_side() {
+        let mut form = password_form();
+        let _ = form.set_value("user", FormValue::Text("me".into()));
+        let _ = form.set_value("pass", FormValue::Text("one".into()));
+        let _ = form.set_value("confirm", FormValue::Text("one".into()));
+        assert!(form.state().valid);
+
+        // Changing pass breaks the pair…
+        let _ = form.set_value("pass", FormValue::Text("two".into()));
+        assert!(!form.state().valid);
+        // …and fixing it from the confirm side heals it.
+        let _ = form.set_value("confirm", FormValue::Text("two".into()));
+        assert!(form.state().valid);
+    }
+
+    #[test]
+    fn dirty_tracking_across_edits_and_resets() {
+        let mut form = Form::new()
+            .text_field("name", "Name", "ada")
+            .bool_field("admin", "Admin", false);
+        assert!(!form.dirty());
+        form.handle_key(&key(KeyCode::Tab)); // focus admin
+        form.handle_key(&key(KeyCode::Char(' ')));
+        assert!(form.dirty());
+        form.reset();
+        assert!(!form.dirty());
+        assert_eq!(form.value("admin"), Some(&FormValue::Bool(false)));
+
+        let _ = form.set_value("name", FormValue::Text("grace".into()));
+        assert!(form.dirty());
+        form.mark_clean();
+        assert!(!form.dirty(), "saved values become the baseline");
+    }
+
+    #[test]
+    fn submit_is_gated_on_validity() {
+        let mut form = password_form();
+        assert_eq!(form.submit(), FormEvent::SubmitBlocked);
+
+        let _ = form.set_value("user", FormValue::Text("me".into()));
+        let _ = form.set_value("pass", FormValue::Text("pw".into()));
+        let _ = form.set_value("confirm", FormValue::Text("pw".into()));
+        let FormEvent::Submitted(values) = form.submit() else {
+            panic!("expected submit, got {:?}", form.state());
+        };
+        assert_eq!(values.get("user"), Some(&FormValue::Text("me".into())));
+        assert_eq!(values.len(), 3, "typed value map is complete");
+        // Enter submits through the key path too.
+        assert!(matches!(
+            form.handle_key(&key(KeyCode::Enter)),
+            Some(FormEvent::Submitted(_))
+        ));
+    }
+
+    #[test]
+    fn typing_edits_focused_field_and_choice_cycles() {
+        let mut form = Form::new().text_field("q", "Query", "").choice_field(
+            "lang",
+            "Language",
+            vec!["rust".into(), "zig".into()],
+            0,
+        );
+        assert!(matches!(
+            form.handle_key(&key(KeyCode::Char('h'))),
+            Some(FormEvent::Changed(id)) if id == "q"
+        ));
+        form.handle_key(&key(KeyCode::Tab));
+        form.handle_key(&key(KeyCode::Right));
+        assert_eq!(form.value("lang"), Some(&FormValue::Choice(1)));
+        assert!(form.handle_key(&key(KeyCode::Right)).is_none(), "clamped");
+    }
+
+    #[test]
+    fn error_rendering_snapshot_below_and_beside() {
+        let row = |frame: &Frame, y: u16, w: u16| -> String {
+            (0..w)
+                .map(|x| {
+                    frame
+                        .buffer
+                        .get(x, y)
+                        .and_then(|c| c.content.as_char())
+                        .unwrap_or(' ')
+                })
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        };
+
+        let form = Form::new().text_field("email", "Email", "").required();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(40, 4, &mut pool);
+        form.render(Rect::new(0, 0, 40, 4), &mut frame, &mut FormRenderState);
+        assert_eq!(row(&frame, 0, 40), "Email");
+        assert_eq!(row(&frame, 1, 40), "       ! required", "below placement");
+
+        let beside = Form::new()
+            .text_field("email", "Email", "x")
+            .validate_with(|v| {
+                (!v.as_text().unwrap_or_default().contains('@')).then(|| "needs @".to_string())
+            })
+            .error_placement(ErrorPlacement::Beside);
+        let mut frame = Frame::new(40, 2, &mut pool);
+        beside.render(Rect::new(0, 0, 40, 2), &mut frame, &mut FormRenderState);
+        assert_eq!(
+            row(&frame, 0, 40),
+            "Email  x  ! needs @",
+            "beside placement"
+        );
+    }
+}
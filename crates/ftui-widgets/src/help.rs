@@ -911,6 +911,7 @@ impl StatefulWidget for Help {
                 widget_signals: Vec::new(),
                 cursor_position: None,
                 cursor_visible: true,
+                secret_input: false,
                 degradation: frame.buffer.degradation,
                 arena: None,
             };
@@ -1012,6 +1013,7 @@ fn rebuild_cache(
             widget_signals: Vec::new(),
             cursor_position: None,
             cursor_visible: true,
+            secret_input: false,
             degradation: frame.buffer.degradation,
             arena: None,
         };
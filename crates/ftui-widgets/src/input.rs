@@ -47,6 +47,8 @@ pub struct TextInput {
     selection_style: Style,
     /// Whether the input is focused (controls cursor output).
     focused: bool,
+    /// Monotonic state version for dirty tracking ([`crate::WidgetState`]).
+    version: u64,
 }
 
 impl TextInput {
@@ -131,19 +133,30 @@ impl TextInput {
 
     /// Set the value, clamping cursor to valid range.
     pub fn set_value(&mut self, value: impl Into<String>) {
-        self.value = value.into();
+        let fingerprint = self.state_fingerprint();
+        let value = value.into();
+        // Same-length replacements evade the fingerprint; compare content.
+        let content_changed = value != self.value;
+        self.value = value;
         let max = self.grapheme_count();
         self.cursor = self.cursor.min(max);
         self.scroll_cells.set(0);
         self.selection_anchor = None;
+        if content_changed {
+            self.version = self.version.wrapping_add(1);
+        } else {
+            self.bump_version_if_changed(fingerprint);
+        }
     }
 
     /// Clear all text.
     pub fn clear(&mut self) {
+        let fingerprint = self.state_fingerprint();
         self.value.clear();
         self.cursor = 0;
         self.scroll_cells.set(0);
         self.selection_anchor = None;
+        self.bump_version_if_changed(fingerprint);
     }
 
     /// Get the cursor position (grapheme index).
@@ -160,6 +173,13 @@ impl TextInput {
 
     /// Set focus state.
     pub fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.version = self.version.wrapping_add(1);
+        }
+        self.set_focused_inner(focused);
+    }
+
+    fn set_focused_inner(&mut self, focused: bool) {
         self.focused = focused;
     }
 
@@ -246,12 +266,34 @@ impl TextInput {
         self.ime_composition.as_deref()
     }
 
+    // --- Dirty tracking ---
+
+    /// Cheap fingerprint of observable state, used to distinguish "handled"
+    /// from "changed" when bumping the state version.
+    fn state_fingerprint(&self) -> (usize, usize, Option<usize>, usize, bool) {
+        (
+            self.value.len(),
+            self.cursor,
+            self.selection_anchor,
+            self.ime_composition.as_ref().map_or(0, String::len),
+            self.focused,
+        )
+    }
+
+    /// Bump the state version when the fingerprint moved.
+    fn bump_version_if_changed(&mut self, before: (usize, usize, Option<usize>, usize, bool)) {
+        if self.state_fingerprint() != before {
+            self.version = self.version.wrapping_add(1);
+        }
+    }
+
     // --- Event handling ---
 
     /// Handle a terminal event.
     ///
     /// Returns `true` if the state changed.
     pub fn handle_event(&mut self, event: &Event) -> bool {
+        let fingerprint = self.state_fingerprint();
         let changed = match event {
             Event::Key(key)
                 if key.kind == KeyEventKind::Press || key.kind == KeyEventKind::Repeat =>
@@ -263,15 +305,23 @@ impl TextInput {
                 let had_selection = self.selection_anchor.is_some();
 
                 // For replacement pastes under a max-length constraint, reject
-                // oversized payloads before deleting the selection.
-                if had_selection {
+                // oversized payloads before deleting the selection. This only
+                // applies to real pastes (bracketed / clipboard reads):
+                // synthetic pastes behave like typed input and truncate.
+                let real_paste = matches!(
+                    paste.source,
+                    ftui_core::event::PasteSource::Bracketed
+                        | ftui_core::event::PasteSource::Osc52Read
+                );
+                if had_selection && real_paste {
                     let clean_text = Self::sanitize_input_text(&paste.text);
                     if let Some(max) = self.max_length {
                         let selection_len = {
                             let (start, end) = self.selection_range(self.selection_anchor.unwrap());
                             end.saturating_sub(start)
                         };
-                        let available = max.saturating_sub(self.grapheme_count().saturating_sub(selection_len));
+                        let available =
+                            max.saturating_sub(self.grapheme_count().saturating_sub(selection_len));
                         if clean_text.graphemes(true).count() > available {
                             return true;
                         }
@@ -290,6 +340,9 @@ impl TextInput {
             self.trace_edit(Self::event_operation_name(event));
         }
 
+        // Version bumps track real mutations, not handledness: a rejected
+        // operation (oversized paste) returns true without a bump.
+        self.bump_version_if_changed(fingerprint);
         changed
     }
 
@@ -325,8 +378,10 @@ impl TextInput {
 
         match key.code {
             KeyCode::Char(c) if !ctrl => {
+                // Dual key reporting: the produced scalar (`ch`) is the
+                // text; the code may be a physical key identity.
                 self.delete_selection();
-                self.insert_char(c);
+                self.insert_char(key.ch.unwrap_or(c));
                 true
             }
             // Ctrl+A: select all
@@ -486,6 +541,12 @@ impl TextInput {
     /// - Respects `max_length` (truncating if necessary).
     /// - Efficiently inserts the result in one operation.
     pub fn insert_text(&mut self, text: &str) {
+        let fingerprint = self.state_fingerprint();
+        self.insert_text_inner(text);
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn insert_text_inner(&mut self, text: &str) {
         let clean_text = Self::sanitize_input_text(text);
 
         if clean_text.is_empty() {
@@ -493,7 +554,6 @@ impl TextInput {
         }
 
         let current_count = self.grapheme_count();
-        let old_cursor = self.cursor;
         let avail = if let Some(max) = self.max_length {
             if current_count >= max {
                 // Allow trying to insert 1 grapheme to see if it merges (combining char)
@@ -536,7 +596,11 @@ impl TextInput {
             return;
         }
 
-        self.cursor = self.value.grapheme_indices(true).take_while(|(i, _)| *i < byte_offset + to_insert.len()).count();
+        self.cursor = self
+            .value
+            .grapheme_indices(true)
+            .take_while(|(i, _)| *i < byte_offset + to_insert.len())
+            .count();
     }
 
     fn insert_char(&mut self, c: char) {
@@ -545,7 +609,6 @@ impl TextInput {
             return;
         }
 
-        let old_count = self.grapheme_count();
         let byte_offset = self.grapheme_byte_offset(self.cursor);
         self.value.insert(byte_offset, c);
 
@@ -562,7 +625,11 @@ impl TextInput {
         }
 
         let char_len = c.len_utf8();
-        self.cursor = self.value.grapheme_indices(true).take_while(|(i, _)| *i < byte_offset + char_len).count();
+        self.cursor = self
+            .value
+            .grapheme_indices(true)
+            .take_while(|(i, _)| *i < byte_offset + char_len)
+            .count();
     }
 
     fn delete_char_back(&mut self) {
@@ -651,8 +718,10 @@ impl TextInput {
 
     /// Select all text.
     pub fn select_all(&mut self) {
+        let fingerprint = self.state_fingerprint();
         self.selection_anchor = Some(0);
         self.cursor = self.grapheme_count();
+        self.bump_version_if_changed(fingerprint);
     }
 
     /// Delete selected text. No-op if no selection.
@@ -907,8 +976,19 @@ impl TextInput {
     }
 }
 
+impl crate::WidgetState for TextInput {
+    fn state_version(&self) -> u64 {
+        self.version
+    }
+}
+
 impl Widget for TextInput {
     fn render(&self, area: Rect, frame: &mut Frame) {
+        // A focused masked (password) input marks the frame secret so the
+        // runtime tightens input-recording redaction while it has focus.
+        if self.focused && self.mask_char.is_some() {
+            frame.secret_input = true;
+        }
         #[cfg(feature = "tracing")]
         let _span = tracing::debug_span!(
             "widget_render",
@@ -1236,6 +1316,29 @@ impl TextInput {
         ))
     }
 
+    /// Create an undo command that also captures cursor and selection.
+    ///
+    /// `cursor_before`/`selection_before` describe the state just before
+    /// the edit; the after-state is captured from the input's current
+    /// state, so call this right after applying the edit. Undo restores
+    /// the before-state, redo the after-state (through a cursor-restore
+    /// callback the integration provides, which must use the widget's
+    /// clamping APIs).
+    #[must_use]
+    pub fn create_text_edit_command_with_cursor(
+        &self,
+        operation: TextEditOperation,
+        cursor_before: usize,
+        selection_before: Option<(usize, usize)>,
+    ) -> Option<crate::undo_support::WidgetTextEditCmd> {
+        let selection_after = self.selection_anchor.map(|anchor| (anchor, self.cursor));
+        Some(
+            crate::undo_support::WidgetTextEditCmd::new(self.undo_id, operation)
+                .with_cursor_state(cursor_before, self.cursor)
+                .with_selection_state(selection_before, selection_after),
+        )
+    }
+
     /// Get the undo widget ID.
     ///
     /// This can be used to associate undo commands with this widget instance.
@@ -0,0 +1,1134 @@
+#![forbid(unsafe_code)]
+
+//! Declarative keymap and key hint bar.
+//!
+//! Apps keep the bottom shortcut bar ("q Quit  / Search  Tab Next") in
+//! sync with their key handling by hand, which drifts. [`Keymap`] makes
+//! the bindings the single source of truth: register
+//! `(combo, action id, description, context)` entries once, translate key
+//! events to action ids in `update()` via [`Keymap::lookup`], and render
+//! the active bindings with [`KeyHintBar`].
+//!
+//! Contexts stack: lookups walk the stack top-down, so a focused widget's
+//! context shadows global bindings for the same combo. Registering the
+//! same combo twice in one context is a conflict reported immediately.
+
+use ftui_core::event::{KeyCode, KeyEvent, KeyEventKind, Modifiers};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use web_time::{Duration, Instant};
+
+use crate::{Widget, draw_text_span};
+
+/// What a combo's key identity matches against ([`KeyEvent`] dual
+/// reporting: `code` is the best-effort physical key, `ch` the produced
+/// character).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ComboMatch {
+    /// Match the event's key code (layout-independent position, when
+    /// the terminal reports one). The default.
+    #[default]
+    Code,
+    /// Match the produced character (`KeyEvent::ch`): "Ctrl+/" fires on
+    /// whatever key produces `/` on the active layout.
+    Char,
+}
+
+/// A key combination (code + modifiers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    /// The key.
+    pub code: KeyCode,
+    /// Required modifiers.
+    pub modifiers: Modifiers,
+    /// Which side of the dual key report to match.
+    pub match_on: ComboMatch,
+}
+
+impl KeyCombo {
+    /// Plain key, no modifiers.
+    #[must_use]
+    pub const fn new(code: KeyCode) -> Self {
+        Self {
+            code,
+            modifiers: Modifiers::empty(),
+            match_on: ComboMatch::Code,
+        }
+    }
+
+    /// Key with modifiers.
+    #[must_use]
+    pub const fn with_modifiers(code: KeyCode, modifiers: Modifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            match_on: ComboMatch::Code,
+        }
+    }
+
+    /// Ctrl+key shorthand.
+    #[must_use]
+    pub const fn ctrl(code: KeyCode) -> Self {
+        Self::with_modifiers(code, Modifiers::CTRL)
+    }
+
+    /// Match on the produced character instead of the key code.
+    #[must_use]
+    pub const fn match_char(mut self) -> Self {
+        self.match_on = ComboMatch::Char;
+        self
+    }
+
+    /// True when the key event matches this combo.
+    ///
+    /// `Code`-bound combos compare the event's key code; `Char`-bound
+    /// combos compare the produced scalar (falling back to a `Char`
+    /// code when the event carries no scalar).
+    #[must_use]
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        if key.modifiers != self.modifiers {
+            return false;
+        }
+        match self.match_on {
+            ComboMatch::Code => key.code == self.code,
+            ComboMatch::Char => match self.code {
+                KeyCode::Char(want) => {
+                    key.ch == Some(want) || (key.ch.is_none() && key.code == KeyCode::Char(want))
+                }
+                other => key.code == other,
+            },
+        }
+    }
+
+    /// Render per platform conventions.
+    #[must_use]
+    pub fn format(&self, style: ComboStyle) -> String {
+        let mut out = String::new();
+        match style {
+            ComboStyle::Plain => {
+                if self.modifiers.contains(Modifiers::CTRL) {
+                    out.push_str("Ctrl+");
+                }
+                if self.modifiers.contains(Modifiers::ALT) {
+                    out.push_str("Alt+");
+                }
+                if self.modifiers.contains(Modifiers::SHIFT) {
+                    out.push_str("Shift+");
+                }
+            }
+            ComboStyle::MacGlyphs => {
+                if self.modifiers.contains(Modifiers::CTRL) {
+                    out.push('\u{2303}'); // ⌃
+                }
+                if self.modifiers.contains(Modifiers::ALT) {
+                    out.push('\u{2325}'); // ⌥
+                }
+                if self.modifiers.contains(Modifiers::SHIFT) {
+                    out.push('\u{21e7}'); // ⇧
+                }
+            }
+        }
+        out.push_str(&key_label(self.code));
+        out
+    }
+}
+
+/// Key combo rendering convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComboStyle {
+    /// `Ctrl+X`, `Alt+Enter`.
+    #[default]
+    Plain,
+    /// macOS glyphs: `⌃X`, `⌥⏎`.
+    MacGlyphs,
+}
+
+fn key_label(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_uppercase().to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Escape => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "Shift+Tab".to_string(),
+        KeyCode::Backspace => "Bksp".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::Insert => "Ins".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::Left => "\u{2190}".to_string(),
+        KeyCode::Right => "\u{2192}".to_string(),
+        KeyCode::Up => "\u{2191}".to_string(),
+        KeyCode::Down => "\u{2193}".to_string(),
+        KeyCode::F(n) => format!("F{n}"),
+        other => format!("{other:?}"),
+    }
+}
+
+/// One registered binding.
+#[derive(Debug, Clone)]
+pub struct KeymapEntry {
+    /// The key combination.
+    pub combo: KeyCombo,
+    /// Stable action identifier handed back by [`Keymap::lookup`].
+    pub action: &'static str,
+    /// Hint bar description.
+    pub description: String,
+    /// Context name this binding belongs to.
+    pub context: &'static str,
+    /// Hint priority: higher weights render first and elide last.
+    pub weight: i32,
+}
+
+/// Conflict reported at registration time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConflict {
+    /// The combo registered twice.
+    pub combo: KeyCombo,
+    /// The context containing both registrations.
+    pub context: &'static str,
+    /// The action already bound to the combo.
+    pub existing_action: &'static str,
+}
+
+impl std::fmt::Display for KeymapConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "combo {} already bound to '{}' in context '{}'",
+            self.combo.format(ComboStyle::Plain),
+            self.existing_action,
+            self.context
+        )
+    }
+}
+
+/// Declarative key-to-action registry.
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    entries: Vec<KeymapEntry>,
+    /// Multi-key sequence bindings ([`Keymap::register_chord`]).
+    chords: Vec<ChordEntry>,
+}
+
+impl Keymap {
+    /// Create an empty keymap.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a binding; rejects a combo already bound in `context`.
+    pub fn register(
+        &mut self,
+        context: &'static str,
+        combo: KeyCombo,
+        action: &'static str,
+        description: impl Into<String>,
+    ) -> Result<(), KeymapConflict> {
+        self.register_weighted(context, combo, action, description, 0)
+    }
+
+    /// Register with an explicit hint weight (higher renders first).
+    pub fn register_weighted(
+        &mut self,
+        context: &'static str,
+        combo: KeyCombo,
+        action: &'static str,
+        description: impl Into<String>,
+        weight: i32,
+    ) -> Result<(), KeymapConflict> {
+        if let Some(existing) = self
+            .entries
+            .iter()
+            .find(|entry| entry.context == context && entry.combo == combo)
+        {
+            return Err(KeymapConflict {
+                combo,
+                context,
+                existing_action: existing.action,
+            });
+        }
+        self.entries.push(KeymapEntry {
+            combo,
+            action,
+            description: description.into(),
+            context,
+            weight,
+        });
+        Ok(())
+    }
+
+    /// Register a multi-key chord binding (e.g. "g g", "Ctrl+K Ctrl+C").
+    ///
+    /// Dispatch chords through a [`ChordDispatcher`]. Rejects an empty
+    /// sequence or a sequence already bound in `context`.
+    pub fn register_chord(
+        &mut self,
+        context: &'static str,
+        sequence: Vec<KeyCombo>,
+        action: &'static str,
+        description: impl Into<String>,
+    ) -> Result<(), KeymapConflict> {
+        let Some(first) = sequence.first().copied() else {
+            return Err(KeymapConflict {
+                combo: KeyCombo::new(KeyCode::Null),
+                context,
+                existing_action: action,
+            });
+        };
+        if let Some(existing) = self
+            .chords
+            .iter()
+            .find(|entry| entry.context == context && entry.sequence == sequence)
+        {
+            return Err(KeymapConflict {
+                combo: first,
+                context,
+                existing_action: existing.action,
+            });
+        }
+        self.chords.push(ChordEntry {
+            sequence,
+            action,
+            description: description.into(),
+            context,
+        });
+        Ok(())
+    }
+
+    /// The chord bindings visible for a context stack.
+    #[must_use]
+    pub fn active_chords(&self, contexts: &[&str]) -> Vec<&ChordEntry> {
+        contexts
+            .iter()
+            .rev()
+            .flat_map(|context| self.chords.iter().filter(move |e| e.context == *context))
+            .collect()
+    }
+
+    /// Translate a key event into an action id against a context stack
+    /// (bottom first, e.g. `["global", "editor"]`). Later contexts shadow
+    /// earlier ones for the same combo.
+    #[must_use]
+    pub fn lookup(&self, contexts: &[&str], key: &KeyEvent) -> Option<&'static str> {
+        for context in contexts.iter().rev() {
+            if let Some(entry) = self
+                .entries
+                .iter()
+                .find(|entry| entry.context == *context && entry.combo.matches(key))
+            {
+                return Some(entry.action);
+            }
+        }
+        None
+    }
+
+    /// The bindings visible for a context stack, with shadowed combos
+    /// removed, sorted by weight (descending) then registration order.
+    #[must_use]
+    pub fn active_bindings(&self, contexts: &[&str]) -> Vec<&KeymapEntry> {
+        let mut seen: Vec<KeyCombo> = Vec::new();
+        let mut out: Vec<&KeymapEntry> = Vec::new();
+        for context in contexts.iter().rev() {
+            for entry in self.entries.iter().filter(|e| e.context == *context) {
+                if !seen.contains(&entry.combo) {
+                    seen.push(entry.combo);
+                    out.push(entry);
+                }
+            }
+        }
+        out.sort_by_key(|entry| std::cmp::Reverse(entry.weight));
+        out
+    }
+}
+
+/// Bottom bar rendering the bindings active in the current context stack.
+#[derive(Debug, Clone)]
+pub struct KeyHintBar<'a> {
+    keymap: &'a Keymap,
+    contexts: Vec<&'a str>,
+    combo_style: ComboStyle,
+    key_style: Style,
+    description_style: Style,
+    separator: &'a str,
+}
+
+impl<'a> KeyHintBar<'a> {
+    /// Create a hint bar for the given context stack (bottom first).
+    #[must_use]
+    pub fn new(keymap: &'a Keymap, contexts: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            keymap,
+            contexts: contexts.into_iter().collect(),
+            combo_style: ComboStyle::default(),
+            key_style: Style::new().bold(),
+            description_style: Style::default(),
+            separator: "  ",
+        }
+    }
+
+    /// Set the combo formatting convention (builder).
+    #[must_use]
+    pub fn combo_style(mut self, style: ComboStyle) -> Self {
+        self.combo_style = style;
+        self
+    }
+
+    /// Set the key and description styles (builder).
+    #[must_use]
+    pub fn styles(mut self, key: Style, description: Style) -> Self {
+        self.key_style = key;
+        self.description_style = description;
+        self
+    }
+
+    /// The hint segments that fit in `width` columns, in render order,
+    /// plus whether elision occurred.
+    fn plan(&self, width: u16) -> (Vec<(String, String)>, bool) {
+        let bindings = self.keymap.active_bindings(&self.contexts);
+        let mut segments = Vec::new();
+        let mut used = 0usize;
+        let mut elided = false;
+        for (i, entry) in bindings.iter().enumerate() {
+            let combo = entry.combo.format(self.combo_style);
+            let cost = combo.chars().count()
+                + 1
+                + entry.description.chars().count()
+                + if i > 0 { self.separator.len() } else { 0 };
+            // Reserve one cell for the ellipsis if more entries follow.
+            let reserve = if i + 1 < bindings.len() { 1 } else { 0 };
+            if used + cost + reserve > usize::from(width) {
+                elided = true;
+                break;
+            }
+            used += cost;
+            segments.push((combo, entry.description.clone()));
+        }
+        (segments, elided)
+    }
+}
+
+impl Widget for KeyHintBar<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() {
+            return;
+        }
+        let (segments, elided) = self.plan(area.width);
+        let mut x = area.x;
+        for (i, (combo, description)) in segments.iter().enumerate() {
+            if i > 0 {
+                x = draw_text_span(
+                    frame,
+                    x,
+                    area.y,
+                    self.separator,
+                    self.description_style,
+                    area.right(),
+                );
+            }
+            x = draw_text_span(frame, x, area.y, combo, self.key_style, area.right());
+            x = draw_text_span(frame, x, area.y, " ", self.description_style, area.right());
+            x = draw_text_span(
+                frame,
+                x,
+                area.y,
+                description,
+                self.description_style,
+                area.right(),
+            );
+        }
+        if elided && x < area.right() {
+            draw_text_span(
+                frame,
+                x,
+                area.y,
+                "\u{2026}",
+                self.description_style,
+                area.right(),
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Key chords (multi-key sequences)
+// ============================================================================
+
+/// A chord binding: a sequence of combos pressed in order.
+#[derive(Debug, Clone)]
+pub struct ChordEntry {
+    /// The combo sequence (at least one element).
+    pub sequence: Vec<KeyCombo>,
+    /// Stable action identifier.
+    pub action: &'static str,
+    /// Hint description.
+    pub description: String,
+    /// Context name this binding belongs to.
+    pub context: &'static str,
+}
+
+impl ChordEntry {
+    /// Render the sequence, combos separated by spaces.
+    #[must_use]
+    pub fn format(&self, style: ComboStyle) -> String {
+        self.sequence
+            .iter()
+            .map(|combo| combo.format(style))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// What to do with buffered prefix keys when a chord dies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrefixFlushPolicy {
+    /// Replay the buffered keys through the normal path
+    /// ([`ChordOutcome::replay`]).
+    #[default]
+    Replay,
+    /// Discard the buffered keys.
+    Discard,
+}
+
+/// Result of feeding one key event to the [`ChordDispatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordOutcome {
+    /// Actions that fired, in order. Usually zero or one; a
+    /// non-continuing key after an ambiguous prefix fires the deferred
+    /// shorter binding first, then the new key's own binding.
+    pub actions: Vec<&'static str>,
+    /// The key was swallowed as a chord prefix; [`ChordDispatcher::pending_display`]
+    /// renders the indicator.
+    pub pending: bool,
+    /// Buffered keys to replay through the normal path (policy
+    /// [`PrefixFlushPolicy::Replay`] after a dead prefix).
+    pub replay: Vec<KeyEvent>,
+}
+
+impl ChordOutcome {
+    fn unbound() -> Self {
+        Self {
+            actions: Vec::new(),
+            pending: false,
+            replay: Vec::new(),
+        }
+    }
+
+    /// The single fired action, when exactly one fired (convenience).
+    #[must_use]
+    pub fn action(&self) -> Option<&'static str> {
+        match self.actions.as_slice() {
+            [single] => Some(single),
+            _ => None,
+        }
+    }
+}
+
+/// Pending-prefix dispatcher for multi-key bindings ("g g",
+/// "Ctrl+K Ctrl+C").
+///
+/// Feed every key press through [`dispatch`](Self::dispatch) and drive
+/// the timeout with [`tick`](Self::tick); both take an explicit `now`
+/// from the program clock, so lab tests control time exactly.
+///
+/// Arbitration when both "g" and "g g" are bound: the longer binding
+/// wins while the chord can still continue — the shorter one fires only
+/// when the prefix times out or a non-continuing key arrives.
+#[derive(Debug, Clone)]
+pub struct ChordDispatcher {
+    /// Chord prefix timeout.
+    timeout: Duration,
+    /// What happens to buffered keys on a dead prefix.
+    policy: PrefixFlushPolicy,
+    /// Keys buffered as the current prefix.
+    pending: Vec<KeyEvent>,
+    /// When the current prefix started waiting.
+    pending_since: Option<Instant>,
+    /// Action to fire if the pending prefix times out (the shorter
+    /// binding in exact-vs-prefix arbitration).
+    deferred_action: Option<&'static str>,
+}
+
+impl ChordDispatcher {
+    /// Create a dispatcher with the given prefix timeout.
+    #[must_use]
+    pub fn new(timeout: Duration, policy: PrefixFlushPolicy) -> Self {
+        Self {
+            timeout,
+            policy,
+            pending: Vec::new(),
+            pending_since: None,
+            deferred_action: None,
+        }
+    }
+
+    /// Whether a chord prefix is pending.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Indicator text for a status line, e.g. `"Ctrl+K -"`.
+    #[must_use]
+    pub fn pending_display(&self, style: ComboStyle) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let mut out = self
+            .pending
+            .iter()
+            .map(|key| KeyCombo::with_modifiers(key.code, key.modifiers).format(style))
+            .collect::<Vec<_>>()
+            .join(" ");
+        out.push_str(" -");
+        Some(out)
+    }
+
+    /// Clear pending state (call on focus change).
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.pending_since = None;
+        self.deferred_action = None;
+    }
+
+    /// Drive the prefix timeout; call once per loop iteration.
+    ///
+    /// On expiry the deferred shorter binding fires (if any), otherwise
+    /// the buffered keys flush per policy.
+    pub fn tick(&mut self, now: Instant) -> ChordOutcome {
+        let Some(since) = self.pending_since else {
+            return ChordOutcome::unbound();
+        };
+        if now.duration_since(since) < self.timeout {
+            return ChordOutcome::unbound();
+        }
+        let deferred = self.deferred_action.take();
+        let buffered = std::mem::take(&mut self.pending);
+        self.pending_since = None;
+        if let Some(action) = deferred {
+            return ChordOutcome {
+                actions: vec![action],
+                pending: false,
+                replay: Vec::new(),
+            };
+        }
+        ChordOutcome {
+            actions: Vec::new(),
+            pending: false,
+            replay: match self.policy {
+                PrefixFlushPolicy::Replay => buffered,
+                PrefixFlushPolicy::Discard => Vec::new(),
+            },
+        }
+    }
+
+    /// Feed one key press.
+    pub fn dispatch(
+        &mut self,
+        keymap: &Keymap,
+        contexts: &[&str],
+        key: &KeyEvent,
+        now: Instant,
+    ) -> ChordOutcome {
+        if key.kind == KeyEventKind::Release {
+            return ChordOutcome::unbound();
+        }
+
+        let mut candidate: Vec<KeyCombo> = self
+            .pending
+            .iter()
+            .map(|k| KeyCombo::with_modifiers(k.code, k.modifiers))
+            .collect();
+        candidate.push(KeyCombo::with_modifiers(key.code, key.modifiers));
+
+        let mut exact: Option<&'static str> = None;
+        let mut extends = false;
+        for context in contexts.iter().rev() {
+            for entry in keymap.chords.iter().filter(|e| e.context == *context) {
+                if entry.sequence == candidate {
+                    exact.get_or_insert(entry.action);
+                } else if entry.sequence.len() > candidate.len()
+                    && entry.sequence.starts_with(&candidate)
+                {
+                    extends = true;
+                }
+            }
+        }
+        // Single-key bindings participate as length-1 sequences.
+        if exact.is_none()
+            && candidate.len() == 1
+            && let Some(action) = keymap.lookup(contexts, key)
+        {
+            exact = Some(action);
+        }
+
+        match (exact, extends) {
+            (Some(action), false) => {
+                // Unambiguous: fire now.
+                self.clear();
+                ChordOutcome {
+                    actions: vec![action],
+                    pending: false,
+                    replay: Vec::new(),
+                }
+            }
+            (deferred, true) => {
+                // A longer chord may still complete: swallow the key.
+                self.pending.push(*key);
+                self.pending_since = Some(now);
+                self.deferred_action = deferred;
+                ChordOutcome {
+                    actions: Vec::new(),
+                    pending: true,
+                    replay: Vec::new(),
+                }
+            }
+            (None, false) => {
+                if self.pending.is_empty() {
+                    return ChordOutcome::unbound();
+                }
+                // Dead prefix: flush, then retry this key against an
+                // empty prefix (it may start a new chord or match a
+                // single binding).
+                let deferred = self.deferred_action.take();
+                let buffered = std::mem::take(&mut self.pending);
+                self.pending_since = None;
+                let mut outcome = self.dispatch(keymap, contexts, key, now);
+                if let Some(action) = deferred {
+                    // The shorter binding fires first, then whatever the
+                    // non-continuing key resolved to.
+                    outcome.actions.insert(0, action);
+                } else if self.policy == PrefixFlushPolicy::Replay {
+                    let mut replay = buffered;
+                    replay.append(&mut outcome.replay);
+                    outcome.replay = replay;
+                }
+                outcome
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod combo_match_tests {
+    use super::*;
+    use ftui_core::event::KeyEventKind;
+
+    /// AZERTY-style dual report: physical 'q', produced 'a'.
+    fn azerty_a() -> KeyEvent {
+        KeyEvent {
+            code: KeyCode::Char('q'),
+            ch: Some('a'),
+            modifiers: Modifiers::CTRL,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    #[test]
+    fn code_bound_binding_matches_physical_key() {
+        let combo = KeyCombo::ctrl(KeyCode::Char('q'));
+        assert!(combo.matches(&azerty_a()), "physical q matches code-bound");
+        assert!(!KeyCombo::ctrl(KeyCode::Char('a')).matches(&azerty_a()));
+    }
+
+    #[test]
+    fn char_bound_binding_matches_produced_char() {
+        let combo = KeyCombo::ctrl(KeyCode::Char('a')).match_char();
+        assert!(combo.matches(&azerty_a()), "produced a matches char-bound");
+        assert!(
+            !KeyCombo::ctrl(KeyCode::Char('q'))
+                .match_char()
+                .matches(&azerty_a())
+        );
+    }
+
+    #[test]
+    fn char_bound_falls_back_to_code_without_scalar() {
+        let event = KeyEvent {
+            code: KeyCode::Char('/'),
+            ch: None,
+            modifiers: Modifiers::CTRL,
+            kind: KeyEventKind::Press,
+        };
+        let combo = KeyCombo::ctrl(KeyCode::Char('/')).match_char();
+        assert!(combo.matches(&event));
+    }
+
+    #[test]
+    fn char_bound_functional_keys_match_code() {
+        let combo = KeyCombo::new(KeyCode::Enter).match_char();
+        assert!(combo.matches(&KeyEvent::new(KeyCode::Enter)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn sample_keymap() -> Keymap {
+        let mut keymap = Keymap::new();
+        keymap
+            .register("global", KeyCombo::new(KeyCode::Char('q')), "quit", "Quit")
+            .unwrap();
+        keymap
+            .register_weighted(
+                "global",
+                KeyCombo::new(KeyCode::Char('/')),
+                "search",
+                "Search",
+                5,
+            )
+            .unwrap();
+        keymap
+            .register("global", KeyCombo::new(KeyCode::Tab), "next", "Next")
+            .unwrap();
+        keymap
+            .register("editor", KeyCombo::ctrl(KeyCode::Char('s')), "save", "Save")
+            .unwrap();
+        keymap
+            .register(
+                "editor",
+                KeyCombo::new(KeyCode::Char('q')),
+                "insert-q",
+                "Type q",
+            )
+            .unwrap();
+        keymap
+    }
+
+    #[test]
+    fn lookup_with_context_shadowing() {
+        let keymap = sample_keymap();
+        let q = KeyEvent::new(KeyCode::Char('q'));
+
+        // Global only: 'q' quits.
+        assert_eq!(keymap.lookup(&["global"], &q), Some("quit"));
+        // Editor focused: its binding shadows the global one.
+        assert_eq!(keymap.lookup(&["global", "editor"], &q), Some("insert-q"));
+        // Unshadowed global bindings still resolve through the stack.
+        let slash = KeyEvent::new(KeyCode::Char('/'));
+        assert_eq!(keymap.lookup(&["global", "editor"], &slash), Some("search"));
+        // Unknown combo: None.
+        let z = KeyEvent::new(KeyCode::Char('z'));
+        assert_eq!(keymap.lookup(&["global", "editor"], &z), None);
+    }
+
+    #[test]
+    fn conflict_detected_at_registration() {
+        let mut keymap = sample_keymap();
+        let err = keymap
+            .register(
+                "global",
+                KeyCombo::new(KeyCode::Char('q')),
+                "other",
+                "Other",
+            )
+            .unwrap_err();
+        assert_eq!(err.existing_action, "quit");
+        assert_eq!(err.context, "global");
+        // The same combo in a different context is fine (that's shadowing).
+        keymap
+            .register(
+                "dialog",
+                KeyCombo::new(KeyCode::Char('q')),
+                "close",
+                "Close",
+            )
+            .unwrap();
+    }
+
+    fn render_bar(keymap: &Keymap, width: u16) -> String {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(width, 1, &mut pool);
+        KeyHintBar::new(keymap, ["global", "editor"]).render(Rect::new(0, 0, width, 1), &mut frame);
+        (0..width)
+            .filter_map(|x| frame.buffer.get(x, 0).and_then(|c| c.content.as_char()))
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn hint_bar_elision_order_at_several_widths() {
+        let keymap = sample_keymap();
+        // Weighted 'search' renders first; the shadowed global 'q' is
+        // replaced by the editor binding.
+        let wide = render_bar(&keymap, 60);
+        assert_eq!(wide, "/ Search  Ctrl+S Save  Q Type q  Tab Next");
+
+        // Narrower: the low-priority tail elides with an ellipsis.
+        let medium = render_bar(&keymap, 30);
+        assert_eq!(medium, "/ Search  Ctrl+S Save\u{2026}");
+
+        let narrow = render_bar(&keymap, 14);
+        assert_eq!(narrow, "/ Search\u{2026}");
+
+        let tiny = render_bar(&keymap, 4);
+        assert_eq!(tiny, "\u{2026}");
+    }
+
+    #[test]
+    fn combo_formatting_goldens() {
+        let cases = [
+            (KeyCombo::new(KeyCode::Char('q')), "Q", "Q"),
+            (KeyCombo::ctrl(KeyCode::Char('x')), "Ctrl+X", "\u{2303}X"),
+            (
+                KeyCombo::with_modifiers(KeyCode::Enter, Modifiers::ALT),
+                "Alt+Enter",
+                "\u{2325}Enter",
+            ),
+            (
+                KeyCombo::with_modifiers(KeyCode::Char('p'), Modifiers::CTRL | Modifiers::SHIFT),
+                "Ctrl+Shift+P",
+                "\u{2303}\u{21e7}P",
+            ),
+            (KeyCombo::new(KeyCode::F(5)), "F5", "F5"),
+            (KeyCombo::new(KeyCode::Char(' ')), "Space", "Space"),
+            (KeyCombo::new(KeyCode::Left), "\u{2190}", "\u{2190}"),
+        ];
+        for (combo, plain, mac) in cases {
+            assert_eq!(combo.format(ComboStyle::Plain), plain);
+            assert_eq!(combo.format(ComboStyle::MacGlyphs), mac);
+        }
+    }
+
+    #[test]
+    fn shadowed_bindings_hidden_from_hints() {
+        let keymap = sample_keymap();
+        let bindings = keymap.active_bindings(&["global", "editor"]);
+        let quit_entries: Vec<&&KeymapEntry> = bindings
+            .iter()
+            .filter(|e| e.combo == KeyCombo::new(KeyCode::Char('q')))
+            .collect();
+        assert_eq!(quit_entries.len(), 1);
+        assert_eq!(quit_entries[0].action, "insert-q", "editor shadows global");
+    }
+
+    // ── Chord dispatch ──────────────────────────────────────────────
+
+    fn press(c: char) -> KeyEvent {
+        KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        }
+    }
+
+    fn ctrl_press(c: char) -> KeyEvent {
+        KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: Modifiers::CTRL,
+            kind: KeyEventKind::Press,
+            ch: None,
+        }
+    }
+
+    fn chord_map() -> Keymap {
+        let mut map = Keymap::new();
+        map.register_chord(
+            "editor",
+            vec![
+                KeyCombo::new(KeyCode::Char('g')),
+                KeyCombo::new(KeyCode::Char('g')),
+            ],
+            "goto-top",
+            "Go to top",
+        )
+        .unwrap();
+        map.register_chord(
+            "editor",
+            vec![
+                KeyCombo::ctrl(KeyCode::Char('k')),
+                KeyCombo::ctrl(KeyCode::Char('c')),
+            ],
+            "comment",
+            "Comment selection",
+        )
+        .unwrap();
+        map
+    }
+
+    #[test]
+    fn two_key_chord_fires() {
+        let map = chord_map();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Replay);
+        let t0 = Instant::now();
+
+        let first = dispatcher.dispatch(&map, &["editor"], &ctrl_press('k'), t0);
+        assert!(first.actions.is_empty());
+        assert!(first.pending);
+        assert_eq!(
+            dispatcher.pending_display(ComboStyle::Plain).as_deref(),
+            Some("Ctrl+K -")
+        );
+
+        let second = dispatcher.dispatch(&map, &["editor"], &ctrl_press('c'), t0);
+        assert_eq!(second.actions, vec!["comment"]);
+        assert!(!dispatcher.is_pending());
+    }
+
+    #[test]
+    fn prefix_timeout_flush_replays_buffered_keys() {
+        let map = chord_map();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Replay);
+        let t0 = Instant::now();
+
+        assert!(
+            dispatcher
+                .dispatch(&map, &["editor"], &press('g'), t0)
+                .pending
+        );
+        // Before the timeout: nothing.
+        let early = dispatcher.tick(t0 + Duration::from_millis(100));
+        assert_eq!(
+            early,
+            ChordOutcome {
+                actions: vec![],
+                pending: false,
+                replay: vec![]
+            }
+        );
+
+        // "g" alone is not separately bound here, so the buffered key
+        // replays rather than firing.
+        let expired = dispatcher.tick(t0 + Duration::from_millis(600));
+        assert!(expired.actions.is_empty());
+        assert_eq!(expired.replay.len(), 1);
+        assert_eq!(expired.replay[0].code, KeyCode::Char('g'));
+        assert!(!dispatcher.is_pending());
+    }
+
+    #[test]
+    fn prefix_timeout_discard_policy_drops_keys() {
+        let map = chord_map();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Discard);
+        let t0 = Instant::now();
+        assert!(
+            dispatcher
+                .dispatch(&map, &["editor"], &press('g'), t0)
+                .pending
+        );
+        let expired = dispatcher.tick(t0 + Duration::from_secs(1));
+        assert!(expired.replay.is_empty());
+    }
+
+    #[test]
+    fn exact_vs_prefix_arbitration_uses_timeout() {
+        let mut map = chord_map();
+        // Both "g" and "g g" bound: the longer waits, the shorter fires
+        // on timeout.
+        map.register(
+            "editor",
+            KeyCombo::new(KeyCode::Char('g')),
+            "line-start",
+            "Start",
+        )
+        .unwrap();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Replay);
+        let t0 = Instant::now();
+
+        let first = dispatcher.dispatch(&map, &["editor"], &press('g'), t0);
+        assert!(first.pending, "ambiguous: swallowed, not fired");
+        assert!(first.actions.is_empty());
+
+        // Timeout: the shorter binding fires.
+        let expired = dispatcher.tick(t0 + Duration::from_millis(600));
+        assert_eq!(expired.actions, vec!["line-start"]);
+        assert!(expired.replay.is_empty());
+
+        // Continuing within the window completes the chord instead.
+        let t1 = Instant::now();
+        assert!(
+            dispatcher
+                .dispatch(&map, &["editor"], &press('g'), t1)
+                .pending
+        );
+        let second = dispatcher.dispatch(
+            &map,
+            &["editor"],
+            &press('g'),
+            t1 + Duration::from_millis(100),
+        );
+        assert_eq!(second.actions, vec!["goto-top"]);
+    }
+
+    #[test]
+    fn non_continuing_key_fires_deferred_and_replays() {
+        let mut map = chord_map();
+        map.register(
+            "editor",
+            KeyCombo::new(KeyCode::Char('g')),
+            "line-start",
+            "Start",
+        )
+        .unwrap();
+        map.register(
+            "editor",
+            KeyCombo::new(KeyCode::Char('x')),
+            "delete",
+            "Delete",
+        )
+        .unwrap();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Replay);
+        let t0 = Instant::now();
+
+        assert!(
+            dispatcher
+                .dispatch(&map, &["editor"], &press('g'), t0)
+                .pending
+        );
+        // "x" can't continue "g …": the deferred "g" binding fires and
+        // "x" resolves on the fresh prefix.
+        let outcome = dispatcher.dispatch(&map, &["editor"], &press('x'), t0);
+        // Deferred shorter binding fires first, then the new key's own.
+        assert_eq!(outcome.actions, vec!["line-start", "delete"]);
+        assert!(!dispatcher.is_pending());
+    }
+
+    #[test]
+    fn dead_prefix_without_deferred_replays_buffer() {
+        let map = chord_map();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Replay);
+        let t0 = Instant::now();
+        assert!(
+            dispatcher
+                .dispatch(&map, &["editor"], &press('g'), t0)
+                .pending
+        );
+        let outcome = dispatcher.dispatch(&map, &["editor"], &press('z'), t0);
+        assert!(outcome.actions.is_empty());
+        assert_eq!(outcome.replay.len(), 1, "buffered 'g' replays");
+        assert_eq!(outcome.replay[0].code, KeyCode::Char('g'));
+    }
+
+    #[test]
+    fn focus_change_clears_pending() {
+        let map = chord_map();
+        let mut dispatcher =
+            ChordDispatcher::new(Duration::from_millis(500), PrefixFlushPolicy::Replay);
+        assert!(
+            dispatcher
+                .dispatch(&map, &["editor"], &ctrl_press('k'), Instant::now())
+                .pending
+        );
+        dispatcher.clear();
+        assert!(!dispatcher.is_pending());
+        assert_eq!(dispatcher.pending_display(ComboStyle::Plain), None);
+        // The timer is gone too: a later tick does nothing.
+        let quiet = dispatcher.tick(Instant::now() + Duration::from_secs(5));
+        assert!(quiet.actions.is_empty());
+        assert!(quiet.replay.is_empty());
+    }
+}
@@ -128,10 +128,16 @@
 pub mod align;
 /// Badge widget (status/priority pills).
 pub mod badge;
+/// DEC double-width/double-height banner lines.
+pub mod banner;
+pub mod bar_chart;
 /// Block widget with borders, titles, and padding.
 pub mod block;
 pub mod borders;
 pub mod cached;
+pub mod calendar;
+pub mod collapsible;
+pub mod color_picker;
 pub mod columns;
 pub mod command_palette;
 pub mod constraint_overlay;
@@ -141,11 +147,14 @@ pub mod debug_overlay;
 pub mod drag;
 pub mod emoji;
 pub mod error_boundary;
+/// Two-phase (capture/bubble) event dispatch along container paths.
+pub mod event_routing;
 /// Fenwick tree (Binary Indexed Tree) for O(log n) prefix sum queries.
 pub mod fenwick;
 pub mod file_picker;
 /// Focus management: navigation graph for keyboard-driven focus traversal.
 pub mod focus;
+pub mod form;
 pub mod group;
 /// Bayesian height prediction with conformal bounds for virtualized lists.
 pub mod height_predictor;
@@ -160,15 +169,20 @@ pub mod input;
 pub mod inspector;
 pub mod json_view;
 pub mod keyboard_drag;
+pub mod keymap;
 pub mod layout;
 pub mod layout_debugger;
+pub mod line_chart;
 pub mod list;
 pub mod log_ring;
 pub mod log_viewer;
 /// Intrinsic sizing support for content-aware layout.
+pub mod masked_input;
 pub mod measurable;
 /// Measure cache for memoizing widget measure results.
 pub mod measure_cache;
+/// Menu bar, dropdown menus, and context menus.
+pub mod menu;
 pub mod modal;
 /// Shared mouse event result type for widget mouse handling.
 pub mod mouse;
@@ -183,11 +197,15 @@ pub mod pretty;
 pub mod progress;
 pub mod rule;
 pub mod scrollbar;
+pub mod sectioned_list;
+pub mod selectable_text;
+pub mod slider;
 pub mod sparkline;
 pub mod spinner;
 /// Opt-in persistable state trait for widgets.
 pub mod stateful;
 pub mod status_line;
+pub mod stepper;
 pub mod stopwatch;
 /// Table widget with rows, columns, and selection.
 pub mod table;
@@ -203,10 +221,18 @@ pub mod undo_support;
 pub mod validation_error;
 pub mod virtualized;
 pub mod voi_debug_overlay;
+pub mod widget_state;
 
 pub use align::{Align, VerticalAlignment};
 pub use badge::Badge;
+pub use banner::{Banner, BannerSize};
+pub use bar_chart::{Bar, BarChart, BarChartState, BarOrientation};
 pub use cached::{CacheKey, CachedWidget, CachedWidgetState, FnKey, HashKey, NoCacheKey};
+pub use calendar::{
+    CalDate, Calendar, CalendarEvent, CalendarSelectionMode, CalendarState, DayDecoration,
+    WeekStart,
+};
+pub use color_picker::{ColorPicker, ColorPickerEvent, ColorPickerMode, ColorPickerState};
 pub use columns::{Column, Columns};
 pub use constraint_overlay::{ConstraintOverlay, ConstraintOverlayStyle};
 #[cfg(feature = "debug-overlay")]
@@ -214,16 +240,30 @@ pub use debug_overlay::{
     DebugOverlay, DebugOverlayOptions, DebugOverlayState, DebugOverlayStateful,
     DebugOverlayStatefulState,
 };
+pub use event_routing::{
+    DispatchCtx, EventOutcome, EventPhase, PhasedEventHandler, dispatch_phased,
+};
+pub use form::{ErrorPlacement, Form, FormEvent, FormRenderState, FormState, FormValue};
 pub use group::Group;
 pub use help_registry::{HelpContent, HelpId, HelpRegistry, Keybinding};
 pub use history_panel::{HistoryEntry, HistoryPanel, HistoryPanelMode};
+pub use keymap::{
+    ChordDispatcher, ChordEntry, ChordOutcome, ComboMatch, ComboStyle, KeyCombo, KeyHintBar,
+    Keymap, KeymapConflict, KeymapEntry, PrefixFlushPolicy,
+};
 pub use layout_debugger::{LayoutConstraints, LayoutDebugger, LayoutRecord};
+pub use line_chart::{LineChart, Series, braille_char, braille_dot_bit};
 pub use log_ring::LogRing;
 pub use log_viewer::{LogViewer, LogViewerState, LogWrapMode, SearchConfig, SearchMode};
+pub use masked_input::{DateInput, DateValue, NumericInput, ValidationState};
 pub use paginator::{Paginator, PaginatorMode};
 pub use panel::Panel;
+pub use sectioned_list::{ListSection, SectionedList, SectionedListState};
+pub use selectable_text::{SelectableTextState, SelectionEvent, TextGeometry, TextPosition};
+pub use slider::{Slider, SliderEvent, SliderState};
 pub use sparkline::Sparkline;
 pub use status_line::{StatusItem, StatusLine};
+pub use stepper::{Stepper, StepperEvent, StepperState};
 pub use tabs::{Tab, Tabs, TabsState};
 pub use virtualized::{
     HeightCache, ItemHeight, RenderItem, Virtualized, VirtualizedList, VirtualizedListState,
@@ -233,6 +273,7 @@ pub use voi_debug_overlay::{
     VoiDebugOverlay, VoiDecisionSummary, VoiLedgerEntry, VoiObservationSummary, VoiOverlayData,
     VoiOverlayStyle, VoiPosteriorSummary,
 };
+pub use widget_state::{RenderCache, WidgetState, render_if_changed, render_stateful_if_changed};
 
 // Toast notification widget
 pub use toast::{
@@ -270,7 +311,7 @@ pub use inspector::{
 // Focus management
 pub use focus::{
     FocusEvent, FocusGraph, FocusGroup, FocusId, FocusIndicator, FocusIndicatorKind, FocusManager,
-    FocusNode, FocusTrap, NavDirection,
+    FocusNode, FocusRing, FocusRingKind, FocusTrap, NavDirection,
 };
 
 // Drag-and-drop protocol (source + target)
@@ -282,7 +323,7 @@ pub use drag::{
 pub use stateful::{StateKey, Stateful, VersionedState};
 
 // Widget persist state types for state-persistence
-pub use list::ListPersistState;
+pub use list::{FilterMode, FilterSpec, ListEvent, ListPersistState, ReorderKeys};
 pub use table::TablePersistState;
 pub use tree::TreePersistState;
 pub use virtualized::VirtualizedListPersistState;
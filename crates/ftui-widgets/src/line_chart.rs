@@ -0,0 +1,591 @@
+#![forbid(unsafe_code)]
+
+//! Braille line chart for multi-series dashboards.
+//!
+//! Plots one or more series as connected lines using braille dot
+//! patterns, giving 2x4 sub-cell resolution per terminal cell. Axis
+//! ticks, a series legend and NaN gaps (rendered as breaks in the line)
+//! are supported. The widget is a pure render function over slices —
+//! deterministic output, no internal state — and degrades to an ASCII
+//! plot (`*` markers, cell resolution) when unicode rendering is off.
+
+use crate::{Widget, draw_text_span};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_text::display_width;
+
+/// Dots per cell horizontally.
+const DOTS_X: usize = 2;
+/// Dots per cell vertically.
+const DOTS_Y: usize = 4;
+
+/// Braille dot bit for sub-cell position `(dx, dy)` with `dx < 2`, `dy < 4`.
+///
+/// Braille patterns live at U+2800 + bits; the dot layout is:
+/// bit 0..2 = left column rows 0..2, bit 3..5 = right column rows 0..2,
+/// bit 6 = left row 3, bit 7 = right row 3.
+#[inline]
+#[must_use]
+pub fn braille_dot_bit(dx: usize, dy: usize) -> u8 {
+    debug_assert!(dx < DOTS_X && dy < DOTS_Y);
+    match (dx, dy) {
+        (0, 0) => 0x01,
+        (0, 1) => 0x02,
+        (0, 2) => 0x04,
+        (0, 3) => 0x40,
+        (1, 0) => 0x08,
+        (1, 1) => 0x10,
+        (1, 2) => 0x20,
+        _ => 0x80,
+    }
+}
+
+/// The braille character for a dot bit pattern.
+#[inline]
+#[must_use]
+pub fn braille_char(bits: u8) -> char {
+    char::from_u32(0x2800 + u32::from(bits)).expect("braille block is valid")
+}
+
+/// One data series: a name, samples, and a line color.
+///
+/// `NaN` samples break the line; the gap is left unplotted.
+#[derive(Debug, Clone, Copy)]
+pub struct Series<'a> {
+    /// Legend name.
+    pub name: &'a str,
+    /// Sample values, evenly spaced along the x axis.
+    pub data: &'a [f64],
+    /// Line color.
+    pub color: PackedRgba,
+}
+
+impl<'a> Series<'a> {
+    /// Create a series.
+    pub fn new(name: &'a str, data: &'a [f64], color: PackedRgba) -> Self {
+        Self { name, data, color }
+    }
+}
+
+/// A multi-series braille line chart.
+#[derive(Debug, Clone)]
+pub struct LineChart<'a> {
+    series: &'a [Series<'a>],
+    /// Explicit y bounds; auto-scaled from data when `None`.
+    y_bounds: Option<(f64, f64)>,
+    /// Draw the y-axis tick labels (left) and baseline row.
+    show_axes: bool,
+    /// Draw the series legend in the top-right corner.
+    show_legend: bool,
+    /// Style for axes, ticks and legend text.
+    style: Style,
+}
+
+impl<'a> LineChart<'a> {
+    /// Create a chart over the given series.
+    pub fn new(series: &'a [Series<'a>]) -> Self {
+        Self {
+            series,
+            y_bounds: None,
+            show_axes: true,
+            show_legend: true,
+            style: Style::new(),
+        }
+    }
+
+    /// Set explicit y bounds (auto from data otherwise).
+    #[must_use]
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = Some((min, max));
+        self
+    }
+
+    /// Toggle axis ticks (default on).
+    #[must_use]
+    pub fn show_axes(mut self, show: bool) -> Self {
+        self.show_axes = show;
+        self
+    }
+
+    /// Toggle the series legend (default on).
+    #[must_use]
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Style for axes and legend text.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Resolve y bounds: explicit, or min/max over all finite samples.
+    ///
+    /// Degenerate ranges (min >= max, explicit or from all-equal data)
+    /// open a unit window so projection never divides by zero.
+    fn resolve_y_bounds(&self) -> (f64, f64) {
+        if let Some((min, max)) = self.y_bounds {
+            if min >= max {
+                return (min - 0.5, min + 0.5);
+            }
+            return (min, max);
+        }
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for series in self.series {
+            for &v in series.data {
+                if v.is_finite() {
+                    min = min.min(v);
+                    max = max.max(v);
+                }
+            }
+        }
+        if !min.is_finite() || !max.is_finite() {
+            return (0.0, 1.0);
+        }
+        if min >= max {
+            // All-equal series: open a unit window around the value.
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        }
+    }
+}
+
+/// A dot-resolution drawing surface composed into braille cells.
+struct BrailleGrid {
+    width: u16,
+    height: u16,
+    /// Per cell: accumulated dot bits.
+    bits: Vec<u8>,
+    /// Per cell: color of the last series that drew into it.
+    colors: Vec<Option<PackedRgba>>,
+}
+
+impl BrailleGrid {
+    fn new(width: u16, height: u16) -> Self {
+        let cells = usize::from(width) * usize::from(height);
+        Self {
+            width,
+            height,
+            bits: vec![0; cells],
+            colors: vec![None; cells],
+        }
+    }
+
+    /// Set the dot at dot-coordinates (x, y); origin top-left.
+    fn set_dot(&mut self, x: usize, y: usize, color: PackedRgba) {
+        let (cx, cy) = (x / DOTS_X, y / DOTS_Y);
+        if cx >= usize::from(self.width) || cy >= usize::from(self.height) {
+            return;
+        }
+        let idx = cy * usize::from(self.width) + cx;
+        self.bits[idx] |= braille_dot_bit(x % DOTS_X, y % DOTS_Y);
+        self.colors[idx] = Some(color);
+    }
+}
+
+/// Draw a line segment on the dot grid with Bresenham stepping.
+fn draw_segment(
+    grid: &mut BrailleGrid,
+    from: (usize, usize),
+    to: (usize, usize),
+    color: PackedRgba,
+) {
+    let (mut x0, mut y0) = (from.0 as i64, from.1 as i64);
+    let (x1, y1) = (to.0 as i64, to.1 as i64);
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    loop {
+        if x0 >= 0 && y0 >= 0 {
+            grid.set_dot(x0 as usize, y0 as usize, color);
+        }
+        if x0 == x1 && y0 == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x0 += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y0 += sy;
+        }
+    }
+}
+
+impl Widget for LineChart<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() || self.series.is_empty() {
+            return;
+        }
+        let deg = frame.buffer.degradation;
+        if !deg.render_content() {
+            return;
+        }
+        let unicode = deg.use_unicode_borders();
+
+        // Reserve the left gutter for y ticks and the bottom row for the
+        // x axis when axes are on and there is room.
+        let (y_min, y_max) = self.resolve_y_bounds();
+        let tick_top = format_tick(y_max);
+        let tick_bottom = format_tick(y_min);
+        let gutter = if self.show_axes && area.width >= 8 {
+            (display_width(&tick_top).max(display_width(&tick_bottom)) as u16) + 1
+        } else {
+            0
+        };
+        let axis_row = self.show_axes && area.height >= 3;
+        let plot = Rect::new(
+            area.x + gutter,
+            area.y,
+            area.width.saturating_sub(gutter),
+            area.height - u16::from(axis_row),
+        );
+        if plot.is_empty() {
+            return;
+        }
+
+        if self.show_axes && gutter > 0 {
+            draw_text_span(
+                frame,
+                area.x,
+                area.y,
+                &tick_top,
+                self.style,
+                area.x + gutter,
+            );
+            draw_text_span(
+                frame,
+                area.x,
+                plot.bottom() - 1,
+                &tick_bottom,
+                self.style,
+                area.x + gutter,
+            );
+        }
+        if axis_row {
+            let axis = if unicode { '─' } else { '-' };
+            for x in plot.x..plot.right() {
+                let mut cell = Cell::from_char(axis);
+                if deg.apply_styling() {
+                    crate::apply_style(&mut cell, self.style);
+                }
+                frame.buffer.set_fast(x, plot.bottom(), cell);
+            }
+        }
+
+        if unicode {
+            self.render_braille(plot, frame, y_min, y_max);
+        } else {
+            self.render_ascii(plot, frame, y_min, y_max);
+        }
+
+        if self.show_legend {
+            self.render_legend(plot, frame);
+        }
+    }
+}
+
+impl LineChart<'_> {
+    /// Map a sample to dot coordinates within a `dots_w x dots_h` grid.
+    fn project(
+        index: usize,
+        len: usize,
+        value: f64,
+        y_min: f64,
+        y_max: f64,
+        dots_w: usize,
+        dots_h: usize,
+    ) -> (usize, usize) {
+        let x = if len <= 1 {
+            0
+        } else {
+            index * (dots_w - 1) / (len - 1)
+        };
+        let t = ((value - y_min) / (y_max - y_min)).clamp(0.0, 1.0);
+        let y = ((1.0 - t) * (dots_h - 1) as f64).round() as usize;
+        (x, y)
+    }
+
+    fn render_braille(&self, plot: Rect, frame: &mut Frame, y_min: f64, y_max: f64) {
+        let deg = frame.buffer.degradation;
+        let dots_w = usize::from(plot.width) * DOTS_X;
+        let dots_h = usize::from(plot.height) * DOTS_Y;
+        let mut grid = BrailleGrid::new(plot.width, plot.height);
+
+        for series in self.series {
+            let len = series.data.len();
+            let mut prev: Option<(usize, usize)> = None;
+            for (i, &value) in series.data.iter().enumerate() {
+                if !value.is_finite() {
+                    // NaN gap: break the line here.
+                    prev = None;
+                    continue;
+                }
+                let point = Self::project(i, len, value, y_min, y_max, dots_w, dots_h);
+                match prev {
+                    Some(from) => draw_segment(&mut grid, from, point, series.color),
+                    None => grid.set_dot(point.0, point.1, series.color),
+                }
+                prev = Some(point);
+            }
+        }
+
+        for cy in 0..plot.height {
+            for cx in 0..plot.width {
+                let idx = usize::from(cy) * usize::from(plot.width) + usize::from(cx);
+                let bits = grid.bits[idx];
+                if bits == 0 {
+                    continue;
+                }
+                let mut cell = Cell::from_char(braille_char(bits));
+                if deg.apply_styling() {
+                    crate::apply_style(&mut cell, self.style);
+                    if let Some(color) = grid.colors[idx] {
+                        cell.fg = color;
+                    }
+                }
+                frame.buffer.set_fast(plot.x + cx, plot.y + cy, cell);
+            }
+        }
+    }
+
+    /// ASCII fallback: `*` markers at cell resolution.
+    fn render_ascii(&self, plot: Rect, frame: &mut Frame, y_min: f64, y_max: f64) {
+        let deg = frame.buffer.degradation;
+        let cols = usize::from(plot.width);
+        let rows = usize::from(plot.height);
+        for series in self.series {
+            let len = series.data.len();
+            for (i, &value) in series.data.iter().enumerate() {
+                if !value.is_finite() {
+                    continue;
+                }
+                let (x, y) = Self::project(i, len, value, y_min, y_max, cols, rows);
+                let mut cell = Cell::from_char('*');
+                if deg.apply_styling() {
+                    crate::apply_style(&mut cell, self.style);
+                    cell.fg = series.color;
+                }
+                frame
+                    .buffer
+                    .set_fast(plot.x + x as u16, plot.y + y as u16, cell);
+            }
+        }
+    }
+
+    /// Legend lines in the top-right corner of the plot.
+    fn render_legend(&self, plot: Rect, frame: &mut Frame) {
+        let deg = frame.buffer.degradation;
+        for (i, series) in self.series.iter().enumerate() {
+            let y = plot.y + i as u16;
+            if y >= plot.bottom() || series.name.is_empty() {
+                break;
+            }
+            let width = display_width(series.name).min(usize::from(plot.width)) as u16;
+            let x = plot.right().saturating_sub(width);
+            let style = if deg.apply_styling() {
+                self.style.fg(series.color)
+            } else {
+                self.style
+            };
+            draw_text_span(frame, x, y, series.name, style, plot.right());
+        }
+    }
+}
+
+/// Short tick label for an axis bound.
+fn format_tick(value: f64) -> String {
+    if !value.is_finite() {
+        return "?".to_string();
+    }
+    if value.abs() >= 1000.0 {
+        format!("{:.0}k", value / 1000.0)
+    } else if value.fract().abs() < f64::EPSILON {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::budget::DegradationLevel;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn row_text(frame: &Frame, y: u16) -> String {
+        let width = frame.buffer.width();
+        let mut out = String::new();
+        for x in 0..width {
+            let ch = frame
+                .buffer
+                .get(x, y)
+                .and_then(|cell| cell.content.as_char())
+                .unwrap_or(' ');
+            out.push(ch);
+        }
+        out.trim_end().to_string()
+    }
+
+    #[test]
+    fn braille_dot_bits_match_unicode_layout() {
+        // Canonical mapping per the braille block definition.
+        assert_eq!(braille_dot_bit(0, 0), 0x01);
+        assert_eq!(braille_dot_bit(0, 1), 0x02);
+        assert_eq!(braille_dot_bit(0, 2), 0x04);
+        assert_eq!(braille_dot_bit(0, 3), 0x40);
+        assert_eq!(braille_dot_bit(1, 0), 0x08);
+        assert_eq!(braille_dot_bit(1, 1), 0x10);
+        assert_eq!(braille_dot_bit(1, 2), 0x20);
+        assert_eq!(braille_dot_bit(1, 3), 0x80);
+
+        // Composition: full cell = U+28FF, left column = dots 1,2,3,7.
+        assert_eq!(braille_char(0xFF), '\u{28FF}');
+        assert_eq!(braille_char(0x01 | 0x02 | 0x04 | 0x40), '\u{2847}');
+    }
+
+    #[test]
+    fn flat_series_renders_single_row_of_dots() {
+        let data = [1.0, 1.0, 1.0, 1.0];
+        let series = [Series::new("", &data, PackedRgba::rgb(0, 255, 0))];
+        let chart = LineChart::new(&series).show_axes(false).show_legend(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(2, 1, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 2, 1), &mut frame);
+
+        // All-equal auto-scale centers the line: dot row 2 of 4 (rounded
+        // from the middle), both cells populated, identical pattern.
+        let row = row_text(&frame, 0);
+        assert_eq!(row.chars().count(), 2);
+        for ch in row.chars() {
+            assert!(('\u{2800}'..='\u{28FF}').contains(&ch), "got {ch:?}");
+        }
+        assert_eq!(
+            row.chars().next(),
+            row.chars().nth(1),
+            "flat line must be level"
+        );
+    }
+
+    #[test]
+    fn rising_series_exact_cells() {
+        let data = [0.0, 3.0];
+        let series = [Series::new("", &data, PackedRgba::rgb(255, 0, 0))];
+        let chart = LineChart::new(&series)
+            .y_bounds(0.0, 3.0)
+            .show_axes(false)
+            .show_legend(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(1, 1, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 1, 1), &mut frame);
+
+        // One cell, 2x4 dots: line from bottom-left (0,3) to top-right
+        // (1,0): dots (0,3), (1,0) plus Bresenham midpoints (0,2),(1,1)
+        // — expressed as bits.
+        let expected = braille_dot_bit(0, 3)
+            | braille_dot_bit(0, 2)
+            | braille_dot_bit(1, 1)
+            | braille_dot_bit(1, 0);
+        assert_eq!(row_text(&frame, 0), braille_char(expected).to_string());
+    }
+
+    #[test]
+    fn nan_breaks_line() {
+        let data = [0.0, f64::NAN, 0.0];
+        let series = [Series::new("", &data, PackedRgba::rgb(255, 0, 0))];
+        let chart = LineChart::new(&series)
+            .y_bounds(0.0, 1.0)
+            .show_axes(false)
+            .show_legend(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(8, 2, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 8, 2), &mut frame);
+
+        // Endpoints plot at the bottom corners; the middle stays empty
+        // because the NaN breaks the segment.
+        let bottom = row_text(&frame, 1);
+        assert!(!bottom.is_empty());
+        let middle_cells: String = bottom
+            .chars()
+            .skip(2)
+            .take(4)
+            .filter(|c| *c != ' ')
+            .collect();
+        assert!(
+            middle_cells.is_empty(),
+            "gap must stay unplotted, got {bottom:?}"
+        );
+    }
+
+    #[test]
+    fn auto_scale_empty_and_all_nan_series() {
+        let empty = [Series::new("e", &[], PackedRgba::WHITE)];
+        let chart = LineChart::new(&empty);
+        assert_eq!(chart.resolve_y_bounds(), (0.0, 1.0));
+
+        let nan_data = [f64::NAN, f64::NAN];
+        let nans = [Series::new("n", &nan_data, PackedRgba::WHITE)];
+        let chart = LineChart::new(&nans);
+        assert_eq!(chart.resolve_y_bounds(), (0.0, 1.0));
+
+        let flat_data = [2.0, 2.0];
+        let flat = [Series::new("f", &flat_data, PackedRgba::WHITE)];
+        let chart = LineChart::new(&flat);
+        assert_eq!(chart.resolve_y_bounds(), (1.5, 2.5));
+    }
+
+    #[test]
+    fn axes_and_legend_render() {
+        let data = [0.0, 10.0];
+        let series = [Series::new("cpu", &data, PackedRgba::rgb(0, 255, 0))];
+        let chart = LineChart::new(&series).y_bounds(0.0, 10.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(16, 5, &mut pool);
+        Widget::render(&chart, Rect::new(0, 0, 16, 5), &mut frame);
+
+        assert!(row_text(&frame, 0).starts_with("10"), "top tick");
+        assert!(row_text(&frame, 0).ends_with("cpu"), "legend");
+        assert!(row_text(&frame, 3).starts_with('0'), "bottom tick");
+        assert!(row_text(&frame, 4).contains('─'), "axis row");
+    }
+
+    #[test]
+    fn ascii_fallback_snapshot() {
+        let data = [0.0, 2.0, 1.0];
+        let series = [Series::new("", &data, PackedRgba::WHITE)];
+        let chart = LineChart::new(&series)
+            .y_bounds(0.0, 2.0)
+            .show_axes(false)
+            .show_legend(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(5, 3, &mut pool);
+        frame.buffer.degradation = DegradationLevel::SimpleBorders;
+        Widget::render(&chart, Rect::new(0, 0, 5, 3), &mut frame);
+
+        assert_eq!(row_text(&frame, 0), "  *");
+        assert_eq!(row_text(&frame, 1), "    *");
+        assert_eq!(row_text(&frame, 2), "*");
+    }
+
+    #[test]
+    fn tiny_sizes_never_panic() {
+        let data = [1.0, 2.0, 3.0];
+        let series = [Series::new("s", &data, PackedRgba::WHITE)];
+        for (w, h) in [(1, 1), (2, 1), (1, 2), (8, 1)] {
+            let chart = LineChart::new(&series);
+            let mut pool = GraphemePool::new();
+            let mut frame = Frame::new(w, h, &mut pool);
+            Widget::render(&chart, Rect::new(0, 0, w, h), &mut frame);
+        }
+    }
+}
@@ -80,6 +80,12 @@ pub struct List<'a> {
     /// Optional hit ID for mouse interaction.
     /// When set, each list item registers a hit region with the hit grid.
     hit_id: Option<HitId>,
+    /// Style merged onto matched characters of filtered rows.
+    match_style: Option<Style>,
+    /// Whether drag/keyboard reordering is enabled.
+    reorderable: bool,
+    /// Keyboard bindings for one-step reordering.
+    reorder_keys: ReorderKeys,
 }
 
 impl<'a> List<'a> {
@@ -93,7 +99,10 @@ impl<'a> List<'a> {
             highlight_style: Style::default(),
             hover_style: Style::default(),
             highlight_symbol: None,
+            match_style: None,
             hit_id: None,
+            reorderable: false,
+            reorder_keys: ReorderKeys::default(),
         }
     }
 
@@ -143,6 +152,215 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Style merged onto matched characters of filtered rows
+    /// ([`List::set_filter`]).
+    #[must_use]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = Some(style);
+        self
+    }
+
+    /// Enable drag-and-drop / keyboard reordering.
+    ///
+    /// Route mouse events through
+    /// [`ListState::handle_mouse_reorder`] and keys through
+    /// [`List::handle_key_reorder`]; apply the emitted
+    /// [`ListEvent::Moved`] events to the app's data.
+    #[must_use]
+    pub fn reorderable(mut self, enabled: bool) -> Self {
+        self.reorderable = enabled;
+        self
+    }
+
+    /// Override the keyboard bindings for one-step reordering
+    /// (default Ctrl+Shift+Up / Ctrl+Shift+Down).
+    #[must_use]
+    pub fn reorder_keys(mut self, keys: ReorderKeys) -> Self {
+        self.reorder_keys = keys;
+        self
+    }
+
+    /// Keyboard reordering: one-step moves with the selection following
+    /// the item, plus Escape cancelling an in-flight mouse drag.
+    ///
+    /// Returns `(consumed, events)`; moves at the ends are consumed
+    /// no-ops. With multi-select enabled, the whole selected set moves
+    /// one step as a block, preserving relative order.
+    pub fn handle_key_reorder(
+        &self,
+        state: &mut ListState,
+        key: &KeyEvent,
+    ) -> (bool, Vec<ListEvent>) {
+        if key.code == KeyCode::Escape && state.reorder.is_some() {
+            state.cancel_reorder();
+            return (true, Vec::new());
+        }
+        if !self.reorderable {
+            return (false, Vec::new());
+        }
+        let step: isize = if (key.code, key.modifiers) == self.reorder_keys.up {
+            -1
+        } else if (key.code, key.modifiers) == self.reorder_keys.down {
+            1
+        } else {
+            return (false, Vec::new());
+        };
+
+        let item_count = self.items.len();
+        let Some(selected) = state.selected else {
+            return (false, Vec::new());
+        };
+        let block = state.reorder_block(selected);
+        let (min, max) = (block[0], *block.last().expect("non-empty block"));
+        // One step against an end is a consumed no-op.
+        if (step < 0 && min == 0) || (step > 0 && max + 1 >= item_count) {
+            return (true, Vec::new());
+        }
+        // Insertion boundary one step beyond the block edge.
+        let insert_at = if step < 0 { min - 1 } else { max + 2 };
+        let events = block_move_events(&block, insert_at, item_count);
+        if !events.is_empty() {
+            let fingerprint = state.state_fingerprint();
+            state.apply_reorder_selection(&block, insert_at);
+            state.bump_version_if_changed(fingerprint);
+        }
+        (true, events)
+    }
+
+    /// Drag overlay: insertion indicator on the boundary row plus the
+    /// lifted item rendered at the pointer row.
+    fn render_drag_overlay(
+        &self,
+        frame: &mut Frame,
+        list_area: Rect,
+        list_height: usize,
+        state: &ListState,
+        drag: ReorderDrag,
+        block_len: usize,
+    ) {
+        // Insertion indicator: overline on the boundary row, underline on
+        // the last row when the boundary is past the visible range.
+        if drag.insert_at >= state.offset && list_area.height > 0 {
+            let rel = drag.insert_at - state.offset;
+            let (y, marker) = if rel < list_height {
+                (list_area.y.saturating_add(rel as u16), "\u{2594}")
+            } else {
+                (list_area.bottom().saturating_sub(1), "\u{2581}")
+            };
+            let mut x = list_area.x;
+            while x < list_area.right() {
+                x = draw_text_span(frame, x, y, marker, self.highlight_style, list_area.right());
+            }
+        }
+
+        // The lifted item follows the pointer.
+        let y = drag
+            .pointer_y
+            .clamp(list_area.y, list_area.bottom().saturating_sub(1));
+        let row_area = Rect::new(list_area.x, y, list_area.width, 1);
+        set_style_area(&mut frame.buffer, row_area, self.highlight_style);
+        let Some(item) = self.items.get(drag.from) else {
+            return;
+        };
+        let mut x = list_area.x;
+        if let Some(symbol) = self.highlight_symbol {
+            x = draw_text_span(frame, x, y, symbol, self.highlight_style, list_area.right());
+            x = draw_text_span(frame, x, y, " ", self.highlight_style, list_area.right());
+        }
+        if let Some(line) = item.content.lines().first() {
+            let text = line.to_plain_text();
+            x = draw_text_span(frame, x, y, &text, self.highlight_style, list_area.right());
+        }
+        if block_len > 1 {
+            let more = format!(" (+{})", block_len - 1);
+            let _ = draw_text_span(frame, x, y, &more, self.highlight_style, list_area.right());
+        }
+    }
+
+    /// Plain text of an item's first line (what filtering matches on).
+    fn item_text(item: &ListItem<'_>) -> String {
+        item.content
+            .lines()
+            .first()
+            .map(|line| line.to_plain_text())
+            .unwrap_or_default()
+    }
+
+    /// Apply a first-class filter, computing the visible-index mapping
+    /// and per-row match positions without mutating items.
+    ///
+    /// Selection is preserved when the selected item still matches;
+    /// otherwise it moves to the nearest visible neighbor by item index
+    /// (ties prefer the earlier item). The scroll offset is adjusted on
+    /// the next render to keep the selection in view. When the new query
+    /// extends the previous one (same mode and case folding), only the
+    /// previous result set is re-filtered.
+    pub fn set_filter(&self, state: &mut ListState, spec: FilterSpec) {
+        if spec.query.is_empty() {
+            self.clear_filter(state);
+            state.filter = Some(ActiveFilter {
+                spec,
+                visible: (0..self.items.len()).collect(),
+                highlights: vec![Vec::new(); self.items.len()],
+            });
+            return;
+        }
+
+        // Incremental path: extending the previous query can only narrow
+        // the previous result set.
+        let candidates: Vec<usize> = match &state.filter {
+            Some(active)
+                if active.spec.mode == spec.mode
+                    && active.spec.case_insensitive == spec.case_insensitive
+                    && !active.spec.query.is_empty()
+                    && spec.query.starts_with(&active.spec.query) =>
+            {
+                active.visible.clone()
+            }
+            _ => (0..self.items.len()).collect(),
+        };
+
+        let mut visible = Vec::new();
+        let mut highlights = Vec::new();
+        for idx in candidates {
+            let Some(item) = self.items.get(idx) else {
+                continue;
+            };
+            let text = Self::item_text(item);
+            if let Some(positions) = match_positions(&text, &spec) {
+                visible.push(idx);
+                highlights.push(positions);
+            }
+        }
+
+        // Selection: keep if still visible, else nearest neighbor.
+        if let Some(selected) = state.selected
+            && visible.binary_search(&selected).is_err()
+        {
+            state.selected = nearest_visible(&visible, selected);
+        }
+        if state.multi_select_enabled {
+            state.multi_selected.retain(|idx| visible.contains(idx));
+        }
+        state.offset = 0;
+        state.scroll_into_view_requested = true;
+        state.filter = Some(ActiveFilter {
+            spec,
+            visible,
+            highlights,
+        });
+        state.version = state.version.wrapping_add(1);
+    }
+
+    /// Clear a first-class filter, restoring the full list with the same
+    /// selection (brought back into view on the next render).
+    pub fn clear_filter(&self, state: &mut ListState) {
+        if state.filter.take().is_some() {
+            state.scroll_into_view_requested = true;
+            state.version = state.version.wrapping_add(1);
+        }
+    }
+
     fn filtered_indices(&self, query: &str) -> Vec<usize> {
         let query = query.trim();
         if query.is_empty() {
@@ -178,6 +396,23 @@ impl<'a> List<'a> {
             .collect()
     }
 
+    /// Visible item indices under the active filter (first-class filter
+    /// takes precedence over the legacy incremental query).
+    ///
+    /// Indices that fell out of range (the item list shrank since
+    /// [`List::set_filter`]) are dropped rather than indexed blindly.
+    fn visible_indices_for(&self, state: &ListState) -> Vec<usize> {
+        match &state.filter {
+            Some(active) => active
+                .visible
+                .iter()
+                .copied()
+                .filter(|&idx| idx < self.items.len())
+                .collect(),
+            None => self.filtered_indices(state.filter_query()),
+        }
+    }
+
     fn apply_filtered_selection_guard(
         &self,
         state: &mut ListState,
@@ -265,19 +500,19 @@ impl<'a> List<'a> {
 
         match key.code {
             KeyCode::Up if !nav_modifiers => {
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.move_selection_in_filtered(state, &filtered, -1)
             }
             KeyCode::Down if !nav_modifiers => {
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.move_selection_in_filtered(state, &filtered, 1)
             }
             KeyCode::Char('k') if !nav_modifiers => {
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.move_selection_in_filtered(state, &filtered, -1)
             }
             KeyCode::Char('j') if !nav_modifiers => {
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.move_selection_in_filtered(state, &filtered, 1)
             }
             KeyCode::Char(' ') if state.multi_select_enabled() => {
@@ -289,26 +524,42 @@ impl<'a> List<'a> {
                 }
             }
             KeyCode::Backspace => {
+                if let Some(spec) = state.filter_spec().cloned() {
+                    if spec.query.is_empty() {
+                        return false;
+                    }
+                    let mut spec = spec;
+                    spec.query.pop();
+                    self.set_filter(state, spec);
+                    return true;
+                }
                 if state.filter_query.is_empty() {
                     return false;
                 }
                 state.filter_query.pop();
                 state.offset = 0;
                 state.scroll_into_view_requested = true;
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.apply_filtered_selection_guard(state, &filtered, true);
                 #[cfg(feature = "tracing")]
                 state.log_selection_change("filter_backspace");
                 true
             }
             KeyCode::Escape => {
+                if let Some(spec) = state.filter_spec() {
+                    if spec.query.is_empty() {
+                        return false;
+                    }
+                    self.clear_filter(state);
+                    return true;
+                }
                 if state.filter_query.is_empty() {
                     return false;
                 }
                 state.filter_query.clear();
                 state.offset = 0;
                 state.scroll_into_view_requested = true;
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.apply_filtered_selection_guard(state, &filtered, false);
                 #[cfg(feature = "tracing")]
                 state.log_selection_change("filter_clear");
@@ -318,10 +569,17 @@ impl<'a> List<'a> {
                 if !ch.is_control() && !key.ctrl() && !key.alt() && !key.super_key() =>
             {
                 // Preserve uppercase input when Shift is held.
+                if let Some(spec) = state.filter_spec().cloned() {
+                    let mut spec = spec;
+                    spec.query.push(ch);
+                    // Extending the query takes the incremental path.
+                    self.set_filter(state, spec);
+                    return true;
+                }
                 state.filter_query.push(ch);
                 state.offset = 0;
                 state.scroll_into_view_requested = true;
-                let filtered = self.filtered_indices(state.filter_query());
+                let filtered = self.visible_indices_for(state);
                 self.apply_filtered_selection_guard(state, &filtered, true);
                 #[cfg(feature = "tracing")]
                 state.log_selection_change("filter_append");
@@ -332,6 +590,207 @@ impl<'a> List<'a> {
     }
 }
 
+/// Matched character positions of `spec.query` in `text`, or `None`.
+///
+/// Positions are char indices into `text` (not bytes, not columns), so
+/// highlighting stays correct for wide characters.
+fn match_positions(text: &str, spec: &FilterSpec) -> Option<Vec<usize>> {
+    let fold = |c: char| {
+        if spec.case_insensitive {
+            c.to_lowercase().next().unwrap_or(c)
+        } else {
+            c
+        }
+    };
+    let query: Vec<char> = spec.query.chars().map(fold).collect();
+    if query.is_empty() {
+        return Some(Vec::new());
+    }
+    let chars: Vec<char> = text.chars().map(fold).collect();
+    match spec.mode {
+        FilterMode::Substring => {
+            if chars.len() < query.len() {
+                return None;
+            }
+            for start in 0..=(chars.len() - query.len()) {
+                if chars[start..start + query.len()] == query[..] {
+                    return Some((start..start + query.len()).collect());
+                }
+            }
+            None
+        }
+        FilterMode::Fuzzy => {
+            let mut positions = Vec::with_capacity(query.len());
+            let mut qi = 0;
+            for (i, &c) in chars.iter().enumerate() {
+                if qi < query.len() && c == query[qi] {
+                    positions.push(i);
+                    qi += 1;
+                }
+            }
+            (qi == query.len()).then_some(positions)
+        }
+    }
+}
+
+/// The visible index nearest to `target` by item-index distance
+/// (ties prefer the earlier item); `None` when nothing is visible.
+fn nearest_visible(visible: &[usize], target: usize) -> Option<usize> {
+    if visible.is_empty() {
+        return None;
+    }
+    let pos = visible.partition_point(|&idx| idx < target);
+    let after = visible.get(pos).copied();
+    let before = pos.checked_sub(1).and_then(|p| visible.get(p).copied());
+    match (before, after) {
+        (Some(b), Some(a)) => {
+            if target - b <= a - target {
+                Some(b)
+            } else {
+                Some(a)
+            }
+        }
+        (Some(b), None) => Some(b),
+        (None, a) => a,
+    }
+}
+
+/// Matching mode for [`FilterSpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterMode {
+    /// The query must appear as a contiguous substring.
+    #[default]
+    Substring,
+    /// Query characters must appear in order (subsequence match).
+    Fuzzy,
+}
+
+/// Declarative filter over list items ([`List::set_filter`]).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FilterSpec {
+    /// The query text; empty matches everything.
+    pub query: String,
+    /// How the query matches item text.
+    pub mode: FilterMode,
+    /// Fold case before matching.
+    pub case_insensitive: bool,
+}
+
+/// Computed filter state: the visible-index mapping plus per-row match
+/// positions, kept on [`ListState`] so items are never mutated.
+#[derive(Debug, Clone, Default)]
+struct ActiveFilter {
+    spec: FilterSpec,
+    /// Item indices that match, ascending.
+    visible: Vec<usize>,
+    /// Matched character positions per visible row (parallel to
+    /// `visible`); char indices into the item's first-line text.
+    highlights: Vec<Vec<usize>>,
+}
+
+// ============================================================================
+// Reordering
+// ============================================================================
+
+/// Events emitted by list reordering.
+///
+/// The list never mutates the app's data itself; apply each event in
+/// order with `let item = data.remove(from); data.insert(to, item);`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListEvent {
+    /// The item at `from` moved to `to` (indices valid at emission time,
+    /// i.e. after any preceding events in the same batch were applied).
+    Moved { from: usize, to: usize },
+}
+
+/// Keyboard bindings for one-step reordering ([`List::reorder_keys`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderKeys {
+    /// Move the selection's item up one step.
+    pub up: (KeyCode, Modifiers),
+    /// Move the selection's item down one step.
+    pub down: (KeyCode, Modifiers),
+}
+
+impl Default for ReorderKeys {
+    /// Ctrl+Shift+Up / Ctrl+Shift+Down.
+    fn default() -> Self {
+        Self {
+            up: (KeyCode::Up, Modifiers::CTRL.union(Modifiers::SHIFT)),
+            down: (KeyCode::Down, Modifiers::CTRL.union(Modifiers::SHIFT)),
+        }
+    }
+}
+
+/// Vertical pointer travel (rows) before a press becomes a drag, so
+/// plain clicks still select.
+const REORDER_DRAG_THRESHOLD: u16 = 1;
+
+/// In-flight mouse reorder drag (transient, never persisted).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ReorderDrag {
+    /// Item index the press landed on.
+    from: usize,
+    /// Row (terminal y) of the press.
+    press_y: u16,
+    /// Whether the movement threshold was passed (item lifted).
+    lifted: bool,
+    /// Current pointer row (terminal y), valid once lifted.
+    pointer_y: u16,
+    /// Current insertion boundary (`0..=item_count`), valid once lifted.
+    insert_at: usize,
+}
+
+/// Emit the [`ListEvent::Moved`] sequence that moves `block` (ascending
+/// source indices) to the insertion boundary `insert_at`, preserving the
+/// block's relative order. Events are valid when applied sequentially.
+fn block_move_events(block: &[usize], insert_at: usize, item_count: usize) -> Vec<ListEvent> {
+    if block.is_empty() || item_count == 0 {
+        return Vec::new();
+    }
+    // Single item: the move is exactly the dragged item's remove/insert.
+    if let [from] = block {
+        let to = if insert_at > *from {
+            insert_at - 1
+        } else {
+            insert_at
+        };
+        return if to == *from {
+            Vec::new()
+        } else {
+            vec![ListEvent::Moved { from: *from, to }]
+        };
+    }
+    // Final arrangement: remove the block, insert it at the adjusted
+    // boundary (indices shift left by the number of removed items that
+    // preceded the boundary).
+    let before = block.iter().filter(|&&i| i < insert_at).count();
+    let target = insert_at - before;
+    let mut desired: Vec<usize> = (0..item_count).filter(|i| !block.contains(i)).collect();
+    for (k, &id) in block.iter().enumerate() {
+        desired.insert(target + k, id);
+    }
+
+    // Derive sequential remove/insert moves transforming the identity
+    // order into the desired order (selection-sort style: ≤ block.len()
+    // moves since only block members are displaced).
+    let mut current: Vec<usize> = (0..item_count).collect();
+    let mut events = Vec::new();
+    for pos in 0..item_count {
+        if current[pos] == desired[pos] {
+            continue;
+        }
+        let from = current
+            .iter()
+            .position(|&id| id == desired[pos])
+            .expect("desired order is a permutation");
+        let id = current.remove(from);
+        current.insert(pos, id);
+        events.push(ListEvent::Moved { from, to: pos });
+    }
+    events
+}
+
 /// Mutable state for a [`List`] widget tracking selection and scroll offset.
 #[derive(Debug, Clone)]
 pub struct ListState {
@@ -349,10 +808,17 @@ pub struct ListState {
     scroll_into_view_requested: bool,
     /// Incremental filter query applied to items (case-insensitive).
     filter_query: String,
+    /// First-class filter ([`List::set_filter`]); takes precedence over
+    /// `filter_query` when set.
+    filter: Option<ActiveFilter>,
     /// Whether multi-select behavior is enabled.
     multi_select_enabled: bool,
     /// Set of selected indices when multi-select is enabled.
     multi_selected: BTreeSet<usize>,
+    /// In-flight mouse reorder drag ([`List::reorderable`]); transient.
+    reorder: Option<ReorderDrag>,
+    /// Monotonic state version for dirty tracking ([`crate::WidgetState`]).
+    version: u64,
 }
 
 impl Default for ListState {
@@ -365,15 +831,80 @@ impl Default for ListState {
             persistence_id: None,
             scroll_into_view_requested: true,
             filter_query: String::new(),
+            filter: None,
             multi_select_enabled: false,
             multi_selected: BTreeSet::new(),
+            reorder: None,
+            version: 0,
         }
     }
 }
 
+impl crate::WidgetState for ListState {
+    fn state_version(&self) -> u64 {
+        self.version
+    }
+}
+
 impl ListState {
+    /// Cheap fingerprint of observable state for dirty tracking.
+    fn state_fingerprint(&self) -> (Option<usize>, Option<usize>, usize, bool, u64, bool) {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.filter_query.hash(&mut hasher);
+        self.multi_selected.hash(&mut hasher);
+        self.reorder.hash(&mut hasher);
+        (
+            self.selected,
+            self.hovered,
+            self.offset,
+            self.scroll_into_view_requested,
+            hasher.finish(),
+            self.multi_select_enabled,
+        )
+    }
+
+    /// Bump the state version when the fingerprint moved.
+    fn bump_version_if_changed(
+        &mut self,
+        before: (Option<usize>, Option<usize>, usize, bool, u64, bool),
+    ) {
+        if self.state_fingerprint() != before {
+            self.version = self.version.wrapping_add(1);
+        }
+    }
+
+    /// The active first-class filter spec, if any.
+    #[must_use]
+    pub fn filter_spec(&self) -> Option<&FilterSpec> {
+        self.filter.as_ref().map(|f| &f.spec)
+    }
+
+    /// Visible item indices under the first-class filter (ascending).
+    ///
+    /// `None` when no filter is set (all items visible).
+    #[must_use]
+    pub fn visible_item_indices(&self) -> Option<&[usize]> {
+        self.filter.as_ref().map(|f| f.visible.as_slice())
+    }
+
+    /// Matched character positions for a visible item, for highlight
+    /// rendering. Char indices into the item's first-line text.
+    #[must_use]
+    pub fn filter_match_positions(&self, item_index: usize) -> Option<&[usize]> {
+        let active = self.filter.as_ref()?;
+        let pos = active.visible.binary_search(&item_index).ok()?;
+        active.highlights.get(pos).map(Vec::as_slice)
+    }
+
     /// Set the selected item index, or `None` to deselect.
     pub fn select(&mut self, index: Option<usize>) {
+        let fingerprint = self.state_fingerprint();
+        self.select_inner(index);
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn select_inner(&mut self, index: Option<usize>) {
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
@@ -412,6 +943,12 @@ impl ListState {
 
     /// Enable or disable multi-select mode.
     pub fn set_multi_select(&mut self, enabled: bool) {
+        let fingerprint = self.state_fingerprint();
+        self.set_multi_select_inner(enabled);
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn set_multi_select_inner(&mut self, enabled: bool) {
         if self.multi_select_enabled == enabled {
             return;
         }
@@ -438,13 +975,25 @@ impl ListState {
 
     /// Replace the incremental filter query.
     pub fn set_filter_query(&mut self, query: impl Into<String>) {
-        self.filter_query = query.into();
+        let fingerprint = self.state_fingerprint();
+        self.set_filter_query_inner(query.into());
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn set_filter_query_inner(&mut self, query: String) {
+        self.filter_query = query;
         self.offset = 0;
         self.scroll_into_view_requested = true;
     }
 
     /// Clear the current filter query.
     pub fn clear_filter_query(&mut self) {
+        let fingerprint = self.state_fingerprint();
+        self.clear_filter_query_inner();
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn clear_filter_query_inner(&mut self) {
         if !self.filter_query.is_empty() {
             self.filter_query.clear();
             self.offset = 0;
@@ -579,6 +1128,12 @@ impl ListState {
 
     /// Scroll the list up by the given number of lines.
     pub fn scroll_up(&mut self, lines: usize) {
+        let fingerprint = self.state_fingerprint();
+        self.scroll_up_inner(lines);
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn scroll_up_inner(&mut self, lines: usize) {
         self.offset = self.offset.saturating_sub(lines);
     }
 
@@ -586,6 +1141,12 @@ impl ListState {
     ///
     /// Clamps so that the last item can still appear at the top of the viewport.
     pub fn scroll_down(&mut self, lines: usize, item_count: usize) {
+        let fingerprint = self.state_fingerprint();
+        self.scroll_down_inner(lines, item_count);
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn scroll_down_inner(&mut self, lines: usize, item_count: usize) {
         self.offset = self
             .offset
             .saturating_add(lines)
@@ -596,6 +1157,12 @@ impl ListState {
     ///
     /// If nothing is selected, selects the first item. Clamps to the last item.
     pub fn select_next(&mut self, item_count: usize) {
+        let fingerprint = self.state_fingerprint();
+        self.select_next_inner(item_count);
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn select_next_inner(&mut self, item_count: usize) {
         if item_count == 0 {
             return;
         }
@@ -617,6 +1184,12 @@ impl ListState {
     ///
     /// If nothing is selected, selects the first item. Clamps to 0.
     pub fn select_previous(&mut self) {
+        let fingerprint = self.state_fingerprint();
+        self.select_previous_inner();
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    fn select_previous_inner(&mut self) {
         let prev = match self.selected {
             Some(i) => i.saturating_sub(1),
             None => 0,
@@ -630,6 +1203,196 @@ impl ListState {
         #[cfg(feature = "tracing")]
         self.log_selection_change("select_previous");
     }
+
+    // ── Reordering ───────────────────────────────────────────────────
+
+    /// Whether a lifted reorder drag is in flight.
+    #[must_use]
+    pub fn reorder_active(&self) -> bool {
+        self.reorder.is_some_and(|d| d.lifted)
+    }
+
+    /// The dragged item index, once lifted.
+    #[must_use]
+    pub fn reorder_source(&self) -> Option<usize> {
+        self.reorder.filter(|d| d.lifted).map(|d| d.from)
+    }
+
+    /// The current insertion boundary (`0..=item_count`), once lifted.
+    #[must_use]
+    pub fn reorder_insertion(&self) -> Option<usize> {
+        self.reorder.filter(|d| d.lifted).map(|d| d.insert_at)
+    }
+
+    /// Abort any in-flight reorder drag, restoring the original render.
+    pub fn cancel_reorder(&mut self) {
+        let fingerprint = self.state_fingerprint();
+        self.reorder = None;
+        self.bump_version_if_changed(fingerprint);
+    }
+
+    /// Insertion boundary for a pointer row within the viewport: the top
+    /// edge of the hovered row, or `item_count` past the last item.
+    fn reorder_insertion_at(&self, y: u16, list_area: Rect, item_count: usize) -> usize {
+        let row = usize::from(y.saturating_sub(list_area.y).min(list_area.height));
+        (self.offset + row).min(item_count)
+    }
+
+    /// The set of item indices a drop would move: the whole multi-selected
+    /// set when the drag started on one of its members, else the pressed
+    /// item alone.
+    fn reorder_block(&self, from: usize) -> Vec<usize> {
+        if self.multi_select_enabled
+            && self.multi_selected.len() > 1
+            && self.multi_selected.contains(&from)
+        {
+            self.multi_selected.iter().copied().collect()
+        } else {
+            vec![from]
+        }
+    }
+
+    /// Mouse handling for reorder-enabled lists ([`List::reorderable`]).
+    ///
+    /// Delegates presses to [`handle_mouse`](Self::handle_mouse) (clicks
+    /// still select), lifts the row after a small vertical threshold,
+    /// tracks the insertion boundary while dragging, auto-scrolls near
+    /// the viewport edges, and on release emits [`ListEvent::Moved`]
+    /// events — the app applies them; the list does not mutate data.
+    /// Dragging is disabled while a filter narrows the list (indices
+    /// would not correspond to adjacent rows).
+    pub fn handle_mouse_reorder(
+        &mut self,
+        event: &MouseEvent,
+        hit: Option<(HitId, HitRegion, u64)>,
+        expected_id: HitId,
+        item_count: usize,
+        list_area: Rect,
+    ) -> (MouseResult, Vec<ListEvent>) {
+        let filter_active = self.filter.is_some() || !self.filter_query.trim().is_empty();
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Pressing a member of a multi-selection must not collapse
+                // it — the whole set is what a drag would move.
+                let hit_index = match hit {
+                    Some((id, HitRegion::Content, data)) if id == expected_id => {
+                        Some(data as usize)
+                    }
+                    _ => None,
+                };
+                let preserves_multi = self.multi_select_enabled
+                    && !event.modifiers.contains(Modifiers::CTRL)
+                    && self.multi_selected.len() > 1
+                    && hit_index.is_some_and(|index| self.multi_selected.contains(&index));
+                let result = match hit_index {
+                    Some(index) if preserves_multi => {
+                        let fingerprint = self.state_fingerprint();
+                        self.selected = Some(index);
+                        self.scroll_into_view_requested = true;
+                        self.bump_version_if_changed(fingerprint);
+                        MouseResult::Selected(index)
+                    }
+                    _ => self.handle_mouse(event, hit, expected_id, item_count),
+                };
+                if !filter_active
+                    && let Some(index) = hit_index
+                    && index < item_count
+                {
+                    let fingerprint = self.state_fingerprint();
+                    self.reorder = Some(ReorderDrag {
+                        from: index,
+                        press_y: event.y,
+                        lifted: false,
+                        pointer_y: event.y,
+                        insert_at: index,
+                    });
+                    self.bump_version_if_changed(fingerprint);
+                }
+                (result, Vec::new())
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(mut drag) = self.reorder else {
+                    return (MouseResult::Ignored, Vec::new());
+                };
+                let fingerprint = self.state_fingerprint();
+                if !drag.lifted && event.y.abs_diff(drag.press_y) >= REORDER_DRAG_THRESHOLD {
+                    drag.lifted = true;
+                }
+                let mut result = MouseResult::Ignored;
+                if drag.lifted {
+                    drag.pointer_y = event.y.clamp(
+                        list_area.y,
+                        list_area.bottom().saturating_sub(1).max(list_area.y),
+                    );
+                    // Auto-scroll when hugging the viewport edges.
+                    if event.y <= list_area.y && self.offset > 0 {
+                        self.scroll_up(1);
+                        result = MouseResult::Scrolled;
+                    } else if event.y.saturating_add(1) >= list_area.bottom() {
+                        let before = self.offset;
+                        self.scroll_down(1, item_count);
+                        if self.offset != before {
+                            result = MouseResult::Scrolled;
+                        }
+                    }
+                    drag.insert_at =
+                        self.reorder_insertion_at(drag.pointer_y, list_area, item_count);
+                }
+                self.reorder = Some(drag);
+                self.bump_version_if_changed(fingerprint);
+                (result, Vec::new())
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let Some(drag) = self.reorder.take() else {
+                    return (MouseResult::Ignored, Vec::new());
+                };
+                let fingerprint = self.state_fingerprint();
+                let events = if drag.lifted {
+                    let block = self.reorder_block(drag.from);
+                    let events = block_move_events(&block, drag.insert_at, item_count);
+                    if !events.is_empty() {
+                        self.apply_reorder_selection(&block, drag.insert_at);
+                    }
+                    events
+                } else {
+                    Vec::new()
+                };
+                self.bump_version_if_changed(fingerprint);
+                (MouseResult::Ignored, events)
+            }
+            // Any other button press cancels the drag (pointer-cancel).
+            MouseEventKind::Down(_) | MouseEventKind::Up(_) => {
+                self.cancel_reorder();
+                (MouseResult::Ignored, Vec::new())
+            }
+            _ => (
+                self.handle_mouse(event, hit, expected_id, item_count),
+                Vec::new(),
+            ),
+        }
+    }
+
+    /// Move selection (and multi-selection) to the block's landing
+    /// positions so the selection follows the moved items.
+    fn apply_reorder_selection(&mut self, block: &[usize], insert_at: usize) {
+        let before = block.iter().filter(|&&i| i < insert_at).count();
+        let target = insert_at - before;
+        if self.multi_select_enabled && block.len() > 1 {
+            self.multi_selected = (target..target + block.len()).collect();
+            if let Some(selected) = self.selected
+                && let Some(pos) = block.iter().position(|&i| i == selected)
+            {
+                self.selected = Some(target + pos);
+            }
+        } else {
+            self.selected = Some(target);
+            if self.multi_select_enabled {
+                self.multi_selected.clear();
+                self.multi_selected.insert(target);
+            }
+        }
+        self.scroll_into_view_requested = true;
+    }
 }
 
 // ============================================================================
@@ -747,7 +1510,7 @@ impl<'a> StatefulWidget for List<'a> {
                     state.hovered = None;
                 }
 
-                let filtered_indices = self.filtered_indices(state.filter_query());
+                let filtered_indices = self.visible_indices_for(state);
                 self.apply_filtered_selection_guard(state, &filtered_indices, filter_active);
 
                 if filtered_indices.is_empty() {
@@ -785,6 +1548,17 @@ impl<'a> StatefulWidget for List<'a> {
                         state.scroll_into_view_requested = false;
                     }
 
+                    // Lifted reorder drag, if any: its rows render as a
+                    // placeholder gap and the item follows the pointer.
+                    let drag = if self.reorderable && !filter_active {
+                        state.reorder.filter(|d| d.lifted)
+                    } else {
+                        None
+                    };
+                    let drag_block: Vec<usize> = drag
+                        .map(|d| state.reorder_block(d.from))
+                        .unwrap_or_default();
+
                     for (row, item_index) in filtered_indices
                         .iter()
                         .skip(state.offset)
@@ -797,6 +1571,13 @@ impl<'a> StatefulWidget for List<'a> {
                         if y >= list_area.bottom() {
                             break;
                         }
+                        if drag_block.contains(&i) {
+                            // Placeholder gap where the lifted item came from.
+                            let row_area = Rect::new(list_area.x, y, list_area.width, 1);
+                            set_style_area(&mut frame.buffer, row_area, self.style);
+                            rendered_visible_items = rendered_visible_items.saturating_add(1);
+                            continue;
+                        }
                         let is_selected = state.selected == Some(i)
                             || (state.multi_select_enabled && state.multi_selected.contains(&i));
                         let is_hovered = state.hovered == Some(i);
@@ -831,6 +1612,7 @@ impl<'a> StatefulWidget for List<'a> {
                             // Add a space after symbol
                             x = draw_text_span(frame, x, y, " ", item_style, list_area.right());
                         }
+                        let content_start_x = x;
 
                         // Draw content
                         // Note: List items are currently single-line for simplicity in v1
@@ -855,6 +1637,36 @@ impl<'a> StatefulWidget for List<'a> {
                             }
                         }
 
+                        // Highlight matched characters of the filter.
+                        if let Some(match_style) = self.match_style
+                            && let Some(positions) = state.filter_match_positions(i)
+                            && !positions.is_empty()
+                            && let Some(line) = item.content.lines().first()
+                        {
+                            // Map char positions to display columns, wide-char
+                            // aware, relative to the start of the content.
+                            let text = line.to_plain_text();
+                            let text_x = content_start_x;
+                            let mut col = 0u16;
+                            for (char_idx, ch) in text.chars().enumerate() {
+                                let w = display_width(ch.encode_utf8(&mut [0u8; 4])) as u16;
+                                if positions.binary_search(&char_idx).is_ok() {
+                                    for dx in 0..w {
+                                        let cx = text_x + col + dx;
+                                        if cx >= list_area.right() {
+                                            break;
+                                        }
+                                        let cell_area = Rect::new(cx, y, 1, 1);
+                                        set_style_area(&mut frame.buffer, cell_area, match_style);
+                                    }
+                                }
+                                col = col.saturating_add(w);
+                                if text_x + col >= list_area.right() {
+                                    break;
+                                }
+                            }
+                        }
+
                         // Register hit region for this item (if hit testing enabled)
                         if let Some(id) = self.hit_id {
                             frame.register_hit(row_area, id, HitRegion::Content, i as u64);
@@ -886,6 +1698,17 @@ impl<'a> StatefulWidget for List<'a> {
                             );
                         }
                     }
+
+                    if let Some(drag) = drag {
+                        self.render_drag_overlay(
+                            frame,
+                            list_area,
+                            list_height,
+                            state,
+                            drag,
+                            drag_block.len(),
+                        );
+                    }
                 }
             }
         }
@@ -2127,4 +2950,517 @@ mod tests {
         let result = state.handle_mouse(&event, None, HitId::new(1), 10);
         assert_eq!(result, MouseResult::Ignored);
     }
+
+    // ── First-class filtering (FilterSpec) ──────────────────────────
+
+    fn named_list() -> List<'static> {
+        List::new(vec!["apple", "banana", "cherry", "apricot", "blueberry"])
+    }
+
+    fn spec(query: &str) -> FilterSpec {
+        FilterSpec {
+            query: query.to_string(),
+            mode: FilterMode::Substring,
+            case_insensitive: true,
+        }
+    }
+
+    #[test]
+    fn filter_preserves_matching_selection_and_restores_on_clear() {
+        let list = named_list();
+        let mut state = ListState::default();
+        state.select(Some(3)); // "apricot"
+
+        list.set_filter(&mut state, spec("ap"));
+        assert_eq!(state.visible_item_indices(), Some(&[0usize, 3][..]));
+        assert_eq!(state.selected, Some(3), "still matches, stays selected");
+
+        list.clear_filter(&mut state);
+        assert_eq!(state.visible_item_indices(), None);
+        assert_eq!(state.selected, Some(3), "selection survives unfilter");
+        assert!(state.scroll_into_view_requested);
+    }
+
+    #[test]
+    fn filtered_out_selection_moves_to_nearest_neighbor() {
+        let list = named_list();
+        let mut state = ListState::default();
+        state.select(Some(2)); // "cherry"
+
+        // "b" matches banana (1) and blueberry (4): nearest to 2 is 1.
+        list.set_filter(&mut state, spec("b"));
+        assert_eq!(state.selected, Some(1));
+
+        // Nothing matches: selection clears.
+        list.set_filter(&mut state, spec("zzz"));
+        assert_eq!(state.selected, None);
+    }
+
+    #[test]
+    fn nearest_neighbor_prefers_earlier_on_tie() {
+        assert_eq!(nearest_visible(&[1, 3], 2), Some(1));
+        assert_eq!(nearest_visible(&[0, 4], 3), Some(4));
+        assert_eq!(nearest_visible(&[], 3), None);
+        assert_eq!(nearest_visible(&[5], 0), Some(5));
+    }
+
+    #[test]
+    fn incremental_extension_matches_full_recompute() {
+        let items: Vec<String> = (0..200)
+            .map(|i| format!("item-{i:03}-{}", if i % 3 == 0 { "alpha" } else { "beta" }))
+            .collect();
+        let list = List::new(items.iter().map(String::as_str));
+
+        // Incremental: "al" then extend to "alp".
+        let mut incremental = ListState::default();
+        list.set_filter(&mut incremental, spec("al"));
+        list.set_filter(&mut incremental, spec("alp"));
+
+        // Fresh recompute of "alp".
+        let mut full = ListState::default();
+        list.set_filter(&mut full, spec("alp"));
+
+        assert_eq!(
+            incremental.visible_item_indices(),
+            full.visible_item_indices()
+        );
+    }
+
+    #[test]
+    fn highlight_positions_are_char_indices_with_wide_chars() {
+        let list = List::new(vec!["日本log.txt"]);
+        let mut state = ListState::default();
+        list.set_filter(&mut state, spec("log"));
+
+        // "log" starts at char index 2 (after two wide chars), not byte 6.
+        assert_eq!(state.filter_match_positions(0), Some(&[2usize, 3, 4][..]));
+    }
+
+    #[test]
+    fn fuzzy_mode_exposes_subsequence_positions() {
+        let list = List::new(vec!["configure_terminal"]);
+        let mut state = ListState::default();
+        list.set_filter(
+            &mut state,
+            FilterSpec {
+                query: "cft".to_string(),
+                mode: FilterMode::Fuzzy,
+                case_insensitive: true,
+            },
+        );
+        assert_eq!(state.visible_item_indices(), Some(&[0usize][..]));
+        // c(0) f(3) t... "configure_terminal": c=0, f=3, t=10 ("_t").
+        assert_eq!(state.filter_match_positions(0), Some(&[0usize, 3, 10][..]));
+    }
+
+    #[test]
+    fn match_style_highlights_matched_cells() {
+        use ftui_render::cell::PackedRgba;
+        let yellow = PackedRgba::rgb(255, 255, 0);
+        let items = vec!["hay", "needle"];
+        let list = List::new(items).match_style(Style::new().fg(yellow));
+        let mut state = ListState::default();
+        list.set_filter(&mut state, spec("eed"));
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 2, &mut pool);
+        StatefulWidget::render(&list, Rect::new(0, 0, 10, 2), &mut frame, &mut state);
+
+        // Only "needle" is visible (row 0); chars 1..4 ("eed") highlighted.
+        assert_eq!(frame.buffer.get(0, 0).unwrap().content.as_char(), Some('n'));
+        assert_ne!(frame.buffer.get(0, 0).unwrap().fg, yellow);
+        for x in 1..4 {
+            assert_eq!(frame.buffer.get(x, 0).unwrap().fg, yellow, "col {x}");
+        }
+        assert_ne!(frame.buffer.get(4, 0).unwrap().fg, yellow);
+    }
+
+    #[test]
+    fn typing_extends_active_filter_incrementally() {
+        let list = named_list();
+        let mut state = ListState::default();
+        list.set_filter(&mut state, spec("b"));
+        assert_eq!(state.visible_item_indices(), Some(&[1usize, 4][..]));
+
+        let key = KeyEvent {
+            code: KeyCode::Char('l'),
+            modifiers: Modifiers::empty(),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        assert!(list.handle_key(&mut state, &key));
+        assert_eq!(state.filter_spec().unwrap().query, "bl");
+        assert_eq!(state.visible_item_indices(), Some(&[4usize][..]));
+    }
+
+    // ── Reordering ───────────────────────────────────────────────────
+
+    const REORDER_HIT: HitId = HitId(77);
+
+    fn reorder_area() -> Rect {
+        Rect::new(0, 0, 12, 4) // 4 visible rows
+    }
+
+    fn mouse(kind: MouseEventKind, x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn press_row(state: &mut ListState, item: usize, y: u16, item_count: usize) {
+        let (_, events) = state.handle_mouse_reorder(
+            &mouse(MouseEventKind::Down(MouseButton::Left), 1, y),
+            Some((REORDER_HIT, HitRegion::Content, item as u64)),
+            REORDER_HIT,
+            item_count,
+            reorder_area(),
+        );
+        assert!(events.is_empty(), "no events on press");
+    }
+
+    fn drag_to(state: &mut ListState, y: u16, item_count: usize) -> MouseResult {
+        let (result, events) = state.handle_mouse_reorder(
+            &mouse(MouseEventKind::Drag(MouseButton::Left), 1, y),
+            None,
+            REORDER_HIT,
+            item_count,
+            reorder_area(),
+        );
+        assert!(events.is_empty(), "no events while dragging");
+        result
+    }
+
+    fn release(state: &mut ListState, y: u16, item_count: usize) -> Vec<ListEvent> {
+        state
+            .handle_mouse_reorder(
+                &mouse(MouseEventKind::Up(MouseButton::Left), 1, y),
+                None,
+                REORDER_HIT,
+                item_count,
+                reorder_area(),
+            )
+            .1
+    }
+
+    #[test]
+    fn click_without_movement_still_selects() {
+        let mut state = ListState::default();
+        press_row(&mut state, 1, 1, 5);
+        assert_eq!(state.selected(), Some(1));
+        assert!(!state.reorder_active(), "not lifted before threshold");
+        let events = release(&mut state, 1, 5);
+        assert!(events.is_empty(), "plain click emits no Moved");
+    }
+
+    #[test]
+    fn drag_lifts_after_threshold_and_emits_moved_on_drop() {
+        let mut state = ListState::default();
+        press_row(&mut state, 0, 0, 5);
+        drag_to(&mut state, 2, 5);
+        assert!(state.reorder_active());
+        assert_eq!(state.reorder_source(), Some(0));
+        assert_eq!(state.reorder_insertion(), Some(2));
+        let events = release(&mut state, 2, 5);
+        assert_eq!(events, vec![ListEvent::Moved { from: 0, to: 1 }]);
+        assert_eq!(state.selected(), Some(1), "selection follows the item");
+        assert!(!state.reorder_active());
+    }
+
+    #[test]
+    fn insertion_index_boundaries() {
+        let mut state = ListState::default();
+        // Drop above the first row: boundary 0.
+        press_row(&mut state, 2, 2, 5);
+        drag_to(&mut state, 0, 5);
+        assert_eq!(state.reorder_insertion(), Some(0));
+        let events = release(&mut state, 0, 5);
+        assert_eq!(events, vec![ListEvent::Moved { from: 2, to: 0 }]);
+
+        // Drop below the last item: boundary == item_count.
+        let mut state = ListState {
+            offset: 2, // rows show items 2..5 of 5
+            ..Default::default()
+        };
+        press_row(&mut state, 2, 0, 5);
+        // Bottom-edge drag auto-scrolls (offset 2 → 3); the clamped
+        // pointer row then maps past the last item.
+        drag_to(&mut state, 3, 5);
+        assert_eq!(state.reorder_insertion(), Some(5));
+        let events = release(&mut state, 3, 5);
+        assert_eq!(events, vec![ListEvent::Moved { from: 2, to: 4 }]);
+    }
+
+    #[test]
+    fn drag_near_edges_auto_scrolls() {
+        let mut state = ListState {
+            offset: 1,
+            ..Default::default()
+        };
+        press_row(&mut state, 2, 1, 10);
+        // Dragging to the top edge scrolls up.
+        assert_eq!(drag_to(&mut state, 0, 10), MouseResult::Scrolled);
+        assert_eq!(state.offset, 0);
+        // Dragging to the bottom edge scrolls down.
+        assert_eq!(drag_to(&mut state, 3, 10), MouseResult::Scrolled);
+        assert_eq!(state.offset, 1);
+    }
+
+    #[test]
+    fn cancel_restores_original_render_state() {
+        let mut state = ListState::default();
+        press_row(&mut state, 1, 1, 5);
+        drag_to(&mut state, 3, 5);
+        assert!(state.reorder_active());
+        state.cancel_reorder();
+        assert!(!state.reorder_active());
+        assert_eq!(state.reorder_insertion(), None);
+        // Release after cancel emits nothing.
+        let events = release(&mut state, 3, 5);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn escape_cancels_drag_via_key_handler() {
+        let list = List::new(vec!["a", "b", "c"]).reorderable(true);
+        let mut state = ListState::default();
+        press_row(&mut state, 0, 0, 3);
+        drag_to(&mut state, 2, 3);
+        assert!(state.reorder_active());
+        let (consumed, events) =
+            list.handle_key_reorder(&mut state, &KeyEvent::new(KeyCode::Escape));
+        assert!(consumed);
+        assert!(events.is_empty());
+        assert!(!state.reorder_active());
+    }
+
+    #[test]
+    fn other_button_press_cancels_drag() {
+        let mut state = ListState::default();
+        press_row(&mut state, 0, 0, 3);
+        drag_to(&mut state, 2, 3);
+        let (_, events) = state.handle_mouse_reorder(
+            &mouse(MouseEventKind::Down(MouseButton::Right), 1, 2),
+            None,
+            REORDER_HIT,
+            3,
+            reorder_area(),
+        );
+        assert!(events.is_empty());
+        assert!(!state.reorder_active());
+    }
+
+    #[test]
+    fn keyboard_moves_one_step_and_selection_follows() {
+        let list = List::new(vec!["a", "b", "c"]).reorderable(true);
+        let mut state = ListState::default();
+        state.select(Some(1));
+
+        let down = KeyEvent {
+            code: KeyCode::Down,
+            modifiers: Modifiers::CTRL.union(Modifiers::SHIFT),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &down);
+        assert!(consumed);
+        assert_eq!(events, vec![ListEvent::Moved { from: 1, to: 2 }]);
+        assert_eq!(state.selected(), Some(2));
+
+        let up = KeyEvent {
+            code: KeyCode::Up,
+            modifiers: Modifiers::CTRL.union(Modifiers::SHIFT),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &up);
+        assert!(consumed);
+        assert_eq!(events, vec![ListEvent::Moved { from: 2, to: 1 }]);
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[test]
+    fn keyboard_moves_at_ends_are_consumed_noops() {
+        let list = List::new(vec!["a", "b", "c"]).reorderable(true);
+        let mut state = ListState::default();
+
+        state.select(Some(0));
+        let up = KeyEvent {
+            code: KeyCode::Up,
+            modifiers: Modifiers::CTRL.union(Modifiers::SHIFT),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &up);
+        assert!(consumed, "end move is consumed");
+        assert!(events.is_empty(), "but emits nothing");
+        assert_eq!(state.selected(), Some(0));
+
+        state.select(Some(2));
+        let down = KeyEvent {
+            code: KeyCode::Down,
+            modifiers: Modifiers::CTRL.union(Modifiers::SHIFT),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &down);
+        assert!(consumed);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn reorder_disabled_ignores_reorder_keys() {
+        let list = List::new(vec!["a", "b", "c"]);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let down = KeyEvent {
+            code: KeyCode::Down,
+            modifiers: Modifiers::CTRL.union(Modifiers::SHIFT),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &down);
+        assert!(!consumed);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn custom_reorder_keys_are_honored() {
+        let keys = ReorderKeys {
+            up: (KeyCode::Char('K'), Modifiers::ALT),
+            down: (KeyCode::Char('J'), Modifiers::ALT),
+        };
+        let list = List::new(vec!["a", "b", "c"])
+            .reorderable(true)
+            .reorder_keys(keys);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let down = KeyEvent {
+            code: KeyCode::Char('J'),
+            modifiers: Modifiers::ALT,
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &down);
+        assert!(consumed);
+        assert_eq!(events, vec![ListEvent::Moved { from: 0, to: 1 }]);
+    }
+
+    #[test]
+    fn multi_select_drag_moves_block_preserving_order() {
+        let mut state = ListState::default();
+        state.set_multi_select(true);
+        state.toggle_multi_selected(0);
+        state.toggle_multi_selected(2); // selected set {0, 2}
+
+        press_row(&mut state, 0, 0, 5);
+        assert_eq!(state.selected_count(), 2, "press must not collapse set");
+        drag_to(&mut state, 2, 5); // boundary 2... pointer row 2 → insert 2
+        drag_to(&mut state, 2, 5);
+        let events = release(&mut state, 2, 5);
+        // Applying sequentially to [a,b,c,d,e] must land [b,a,c,d,e] →
+        // block {a,c} inserted at boundary 2 (after b): [b,a,c,d,e].
+        let mut data = vec!["a", "b", "c", "d", "e"];
+        for ListEvent::Moved { from, to } in &events {
+            let item = data.remove(*from);
+            data.insert(*to, item);
+        }
+        assert_eq!(data, vec!["b", "a", "c", "d", "e"]);
+        // Block landed contiguously, relative order a-before-c preserved.
+        assert_eq!(
+            state.selected_indices().iter().copied().collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn multi_select_keyboard_moves_block() {
+        let list = List::new(vec!["a", "b", "c", "d"]).reorderable(true);
+        let mut state = ListState::default();
+        state.set_multi_select(true);
+        state.toggle_multi_selected(1);
+        state.toggle_multi_selected(2); // block {1, 2}
+
+        let down = KeyEvent {
+            code: KeyCode::Down,
+            modifiers: Modifiers::CTRL.union(Modifiers::SHIFT),
+            kind: ftui_core::event::KeyEventKind::Press,
+            ch: None,
+        };
+        let (consumed, events) = list.handle_key_reorder(&mut state, &down);
+        assert!(consumed);
+        let mut data = vec!["a", "b", "c", "d"];
+        for ListEvent::Moved { from, to } in &events {
+            let item = data.remove(*from);
+            data.insert(*to, item);
+        }
+        assert_eq!(data, vec!["a", "d", "b", "c"]);
+        assert_eq!(
+            state.selected_indices().iter().copied().collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(state.selected(), Some(3), "selection follows its item");
+    }
+
+    #[test]
+    fn render_shows_gap_indicator_and_lifted_row() {
+        let list = List::new(vec!["aaa", "bbb", "ccc"]).reorderable(true);
+        let mut state = ListState::default();
+        press_row(&mut state, 0, 0, 3);
+        drag_to(&mut state, 2, 3);
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 4, &mut pool);
+        StatefulWidget::render(&list, reorder_area(), &mut frame, &mut state);
+
+        // Source row is a placeholder gap (no content).
+        assert!(!row_text(&frame, 0).contains("aaa"), "gap where item was");
+        // Insertion indicator on the boundary row.
+        assert!(row_text(&frame, 2).contains('\u{2594}'), "indicator line");
+        // Lifted row rendered at the pointer.
+        assert!(
+            row_text(&frame, 2).contains("aaa"),
+            "lifted item at pointer"
+        );
+    }
+
+    #[test]
+    fn render_without_drag_matches_plain_list() {
+        let plain = List::new(vec!["aaa", "bbb"]);
+        let reorderable = List::new(vec!["aaa", "bbb"]).reorderable(true);
+        let mut pool = GraphemePool::new();
+
+        let mut frame_a = Frame::new(10, 2, &mut pool);
+        let mut state_a = ListState::default();
+        StatefulWidget::render(&plain, Rect::new(0, 0, 10, 2), &mut frame_a, &mut state_a);
+        let rows_a: Vec<String> = (0..2).map(|y| row_text(&frame_a, y)).collect();
+
+        let mut frame_b = Frame::new(10, 2, &mut pool);
+        let mut state_b = ListState::default();
+        StatefulWidget::render(
+            &reorderable,
+            Rect::new(0, 0, 10, 2),
+            &mut frame_b,
+            &mut state_b,
+        );
+        let rows_b: Vec<String> = (0..2).map(|y| row_text(&frame_b, y)).collect();
+        assert_eq!(rows_a, rows_b, "flag alone must not change rendering");
+    }
+
+    #[test]
+    fn stale_filter_indices_survive_item_shrink() {
+        let list = named_list();
+        let mut state = ListState::default();
+        list.set_filter(&mut state, spec("b")); // indices 1 and 4
+
+        // Rebuild with fewer items: index 4 is now out of range.
+        let short = List::new(vec!["apple", "banana"]);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 3, &mut pool);
+        StatefulWidget::render(&short, Rect::new(0, 0, 12, 3), &mut frame, &mut state);
+        // Only the in-range match renders; no panic.
+        assert_eq!(frame.buffer.get(0, 0).unwrap().content.as_char(), Some('b'));
+    }
 }
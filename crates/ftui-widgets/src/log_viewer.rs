@@ -2213,6 +2213,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn follow_mode_sticks_to_bottom_across_appends_unless_scrolled() {
+        let mut state = LogViewer::new(100);
+        for i in 0..50 {
+            state.push(format!("line {i}"));
+        }
+        // Follow is on by default: the viewport tracks the newest line.
+        assert!(state.is_at_bottom());
+        state.push("line 50");
+        assert!(state.is_at_bottom(), "sticky after append");
+
+        // Scrolling up detaches; appends no longer move the viewport.
+        state.scroll_up(5);
+        assert!(!state.is_at_bottom());
+        assert!(!state.auto_scroll_enabled());
+        state.push("line 51");
+        assert!(!state.is_at_bottom(), "detached while scrolled");
+
+        // Scrolling back to the bottom re-sticks.
+        state.scroll_to_bottom();
+        state.push("line 52");
+        assert!(state.is_at_bottom());
+    }
+
     #[test]
     fn test_toggle_follow_disables_autoscroll_on_push() {
         let mut log = LogViewer::new(100);
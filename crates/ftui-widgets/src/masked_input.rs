@@ -0,0 +1,1103 @@
+#![forbid(unsafe_code)]
+
+//! Masked input fields: numeric and date entry with validation.
+//!
+//! Built on top of [`TextInput`]: the inner input holds the *stored* value
+//! (canonical text, no grouping separators), while rendering goes through a
+//! formatted display buffer — `1234567` renders as `1,234,567` but
+//! [`NumericInput::value`] parses the stored text. [`DateInput`] edits
+//! pattern segments (`YYYY-MM-DD`) with per-segment cursor navigation and
+//! date-aware stepping, rejecting impossible dates including leap-year
+//! violations.
+//!
+//! Both widgets expose a [`ValidationState`] for the app to read and an
+//! error styling hook applied when the state is `Invalid`.
+
+use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind, Modifiers};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+use crate::input::TextInput;
+use crate::{Widget, draw_text_span};
+
+/// Validation status of a masked input, read by the application.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ValidationState {
+    /// The field parses to a valid value.
+    Valid,
+    /// The field is partially filled (e.g. empty, or a date missing digits).
+    #[default]
+    Incomplete,
+    /// The field contents can never parse; the reason is user-presentable.
+    Invalid(String),
+}
+
+impl ValidationState {
+    /// True when the field parses to a valid value.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// NumericInput
+// ---------------------------------------------------------------------------
+
+/// Numeric field: integer or fixed-decimal entry with min/max clamping,
+/// thousands separators (rendered, never stored), sign handling, and
+/// arrow-key stepping (Shift for the large step).
+#[derive(Debug, Clone)]
+pub struct NumericInput {
+    /// Stored value buffer (canonical: optional sign, digits, one '.').
+    inner: TextInput,
+    /// Decimal places; 0 means integer-only.
+    decimal_places: u8,
+    /// Inclusive bounds applied on stepping and paste normalization.
+    min: Option<f64>,
+    max: Option<f64>,
+    /// Grouping separator rendered every three integer digits (not stored).
+    thousands_separator: Option<char>,
+    /// Arrow-key step; Shift+arrow uses `large_step`.
+    step: f64,
+    large_step: f64,
+    /// Style applied instead of the base style while `Invalid`.
+    error_style: Style,
+    style: Style,
+    focused: bool,
+}
+
+impl Default for NumericInput {
+    fn default() -> Self {
+        Self {
+            inner: TextInput::new(),
+            decimal_places: 0,
+            min: None,
+            max: None,
+            thousands_separator: None,
+            step: 1.0,
+            large_step: 10.0,
+            error_style: Style::default(),
+            style: Style::default(),
+            focused: false,
+        }
+    }
+}
+
+impl NumericInput {
+    /// Create an integer field.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a fixed-decimal field with the given number of places.
+    #[must_use]
+    pub fn decimal(places: u8) -> Self {
+        Self {
+            decimal_places: places,
+            ..Self::default()
+        }
+    }
+
+    /// Set the inclusive minimum (builder).
+    #[must_use]
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the inclusive maximum (builder).
+    #[must_use]
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the arrow-key step sizes (builder).
+    #[must_use]
+    pub fn with_steps(mut self, step: f64, large_step: f64) -> Self {
+        self.step = step;
+        self.large_step = large_step;
+        self
+    }
+
+    /// Set the rendered grouping separator (builder). Never stored.
+    #[must_use]
+    pub fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Set the base style (builder).
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style applied while the field is `Invalid` (builder).
+    #[must_use]
+    pub fn with_error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Set the initial value (builder).
+    #[must_use]
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Set focus (builder).
+    #[must_use]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self.inner.set_focused(focused);
+        self
+    }
+
+    /// Set focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.inner.set_focused(focused);
+    }
+
+    /// The stored (canonical) text, without grouping separators.
+    #[must_use]
+    pub fn stored_text(&self) -> &str {
+        self.inner.value()
+    }
+
+    /// The display text, with grouping separators rendered.
+    #[must_use]
+    pub fn display_text(&self) -> String {
+        format_grouped(self.inner.value(), self.thousands_separator)
+    }
+
+    /// Parsed value, `None` while empty or invalid.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        parse_numeric(self.inner.value())
+    }
+
+    /// Parsed integer value (integer fields only).
+    #[must_use]
+    pub fn value_int(&self) -> Option<i64> {
+        if self.decimal_places != 0 {
+            return None;
+        }
+        self.inner.value().parse().ok()
+    }
+
+    /// Current validation state.
+    #[must_use]
+    pub fn validation_state(&self) -> ValidationState {
+        let text = self.inner.value();
+        if text.is_empty() || text == "-" || text.ends_with('.') {
+            return ValidationState::Incomplete;
+        }
+        let Some(value) = parse_numeric(text) else {
+            return ValidationState::Invalid("not a number".into());
+        };
+        if let Some(min) = self.min
+            && value < min
+        {
+            return ValidationState::Invalid(format!("below minimum {min}"));
+        }
+        if let Some(max) = self.max
+            && value > max
+        {
+            return ValidationState::Invalid(format!("above maximum {max}"));
+        }
+        ValidationState::Valid
+    }
+
+    /// Replace the value, clamped to min/max and formatted canonically.
+    pub fn set_value(&mut self, value: f64) {
+        let clamped = self.clamp(value);
+        self.inner.set_value(self.format_canonical(clamped));
+    }
+
+    /// Handle a terminal event. Returns `true` if the state changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key)
+                if key.kind == KeyEventKind::Press || key.kind == KeyEventKind::Repeat =>
+            {
+                self.handle_key(key)
+            }
+            Event::Paste(paste) => self.paste(&paste.text),
+            _ => false,
+        }
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up | KeyCode::Down => {
+                let step = if key.modifiers.contains(Modifiers::SHIFT) {
+                    self.large_step
+                } else {
+                    self.step
+                };
+                let delta = if key.code == KeyCode::Up { step } else { -step };
+                let current = self.value().unwrap_or(0.0);
+                self.set_value(current + delta);
+                true
+            }
+            KeyCode::Char(c) => self.insert_char(key.ch.unwrap_or(c)),
+            // Movement and deletion are the inner input's business.
+            KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Home
+            | KeyCode::End
+            | KeyCode::Backspace
+            | KeyCode::Delete => self.inner.handle_event(&Event::Key(*key)),
+            _ => false,
+        }
+    }
+
+    fn insert_char(&mut self, c: char) -> bool {
+        let text = self.inner.value();
+        let at_start = self.inner.cursor() == 0;
+        let accept = match c {
+            '0'..='9' => true,
+            '-' => at_start && !text.starts_with('-') && self.min.is_none_or(|m| m < 0.0),
+            '.' => self.decimal_places > 0 && !text.contains('.'),
+            _ => false,
+        };
+        if !accept {
+            return false;
+        }
+        // Enforce the decimal-place budget while typing.
+        if c.is_ascii_digit()
+            && let Some((_, frac)) = text.split_once('.')
+            && self.inner.cursor() > text.find('.').map_or(0, |i| text[..i].chars().count())
+            && frac.len() >= usize::from(self.decimal_places)
+        {
+            return false;
+        }
+        self.inner
+            .handle_event(&Event::Key(KeyEvent::new(KeyCode::Char(c))))
+    }
+
+    /// Parse and normalize a pasted value (formatted or unformatted).
+    ///
+    /// Invalid pastes leave the state unchanged and return `false`.
+    fn paste(&mut self, text: &str) -> bool {
+        let cleaned: String = text
+            .trim()
+            .chars()
+            .filter(|&c| !(c == ' ' || c == '_' || Some(c) == self.thousands_separator || c == ','))
+            .collect();
+        let Some(value) = parse_numeric(&cleaned) else {
+            return false;
+        };
+        self.set_value(value);
+        true
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let mut out = value;
+        if let Some(min) = self.min {
+            out = out.max(min);
+        }
+        if let Some(max) = self.max {
+            out = out.min(max);
+        }
+        out
+    }
+
+    fn format_canonical(&self, value: f64) -> String {
+        if self.decimal_places == 0 {
+            format!("{}", value.round() as i64)
+        } else {
+            format!("{:.*}", usize::from(self.decimal_places), value)
+        }
+    }
+}
+
+impl Widget for NumericInput {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.width < 1 || area.height < 1 {
+            return;
+        }
+        let style = if matches!(self.validation_state(), ValidationState::Invalid(_)) {
+            self.error_style
+        } else {
+            self.style
+        };
+        let text = self.display_text();
+        draw_text_span(frame, area.x, area.y, &text, style, area.right());
+    }
+}
+
+/// Parse canonical numeric text (optional sign, digits, at most one '.').
+fn parse_numeric(text: &str) -> Option<f64> {
+    if text.is_empty() {
+        return None;
+    }
+    let body = text.strip_prefix('-').unwrap_or(text);
+    if body.is_empty()
+        || body.matches('.').count() > 1
+        || !body.chars().all(|c| c.is_ascii_digit() || c == '.')
+    {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// Insert a grouping separator every three integer digits for display.
+fn format_grouped(text: &str, separator: Option<char>) -> String {
+    let Some(sep) = separator else {
+        return text.to_string();
+    };
+    let (sign, rest) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text),
+    };
+    let (int_part, frac_part) = match rest.split_once('.') {
+        Some((int_part, frac)) => (int_part, Some(frac)),
+        None => (rest, None),
+    };
+    let mut grouped = String::with_capacity(text.len() + int_part.len() / 3);
+    grouped.push_str(sign);
+    let digits = int_part.len();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (digits - i).is_multiple_of(3) {
+            grouped.push(sep);
+        }
+        grouped.push(c);
+    }
+    if let Some(frac) = frac_part {
+        grouped.push('.');
+        grouped.push_str(frac);
+    }
+    grouped
+}
+
+// ---------------------------------------------------------------------------
+// DateInput
+// ---------------------------------------------------------------------------
+
+/// A calendar date (no timezone, proleptic Gregorian).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateValue {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl DateValue {
+    /// True for leap years (Gregorian rules).
+    #[must_use]
+    pub const fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Days in the given month of the given year.
+    #[must_use]
+    pub const fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 => {
+                if Self::is_leap_year(year) {
+                    29
+                } else {
+                    28
+                }
+            }
+            _ => 0,
+        }
+    }
+
+    /// True when the (year, month, day) triple is an actual date.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.month >= 1
+            && self.month <= 12
+            && self.day >= 1
+            && self.day <= Self::days_in_month(self.year, self.month)
+    }
+}
+
+/// The three editable date segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateSegment {
+    Year,
+    Month,
+    Day,
+}
+
+impl DateSegment {
+    const fn width(self) -> usize {
+        match self {
+            Self::Year => 4,
+            Self::Month | Self::Day => 2,
+        }
+    }
+}
+
+/// One element of a parsed date pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateToken {
+    Segment(DateSegment),
+    Literal(char),
+}
+
+/// Date field with pattern-driven segments (`YYYY-MM-DD` by default),
+/// per-segment cursor navigation, segment-wise arrow stepping with carry
+/// (Jan 31 + 1 month clamps to Feb 28/29), and impossible-date rejection.
+#[derive(Debug, Clone)]
+pub struct DateInput {
+    tokens: Vec<DateToken>,
+    /// Segment order as they appear in the pattern.
+    segments: Vec<DateSegment>,
+    /// Entered digits per segment (year, month, day), in pattern widths.
+    year: String,
+    month: String,
+    day: String,
+    /// Index into `segments` of the active segment.
+    active: usize,
+    error_style: Style,
+    style: Style,
+    focused: bool,
+}
+
+impl Default for DateInput {
+    fn default() -> Self {
+        Self::with_pattern("YYYY-MM-DD")
+    }
+}
+
+impl DateInput {
+    /// Create a date input with the default `YYYY-MM-DD` pattern.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a date input from a pattern containing `YYYY`, `MM`, `DD`
+    /// runs and literal separators (e.g. `DD.MM.YYYY`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern does not contain all three segments.
+    #[must_use]
+    pub fn with_pattern(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut segments = Vec::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            let segment = match c {
+                'Y' => Some((DateSegment::Year, 'Y')),
+                'M' => Some((DateSegment::Month, 'M')),
+                'D' => Some((DateSegment::Day, 'D')),
+                _ => None,
+            };
+            match segment {
+                Some((seg, letter)) => {
+                    let mut run = 1;
+                    while chars.peek() == Some(&letter) {
+                        chars.next();
+                        run += 1;
+                    }
+                    debug_assert_eq!(run, seg.width(), "unsupported segment width in {pattern:?}");
+                    tokens.push(DateToken::Segment(seg));
+                    segments.push(seg);
+                }
+                None => tokens.push(DateToken::Literal(c)),
+            }
+        }
+        assert!(
+            segments.contains(&DateSegment::Year)
+                && segments.contains(&DateSegment::Month)
+                && segments.contains(&DateSegment::Day),
+            "date pattern must contain YYYY, MM and DD"
+        );
+        Self {
+            tokens,
+            segments,
+            year: String::new(),
+            month: String::new(),
+            day: String::new(),
+            active: 0,
+            error_style: Style::default(),
+            style: Style::default(),
+            focused: false,
+        }
+    }
+
+    /// Set the base style (builder).
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style applied while the field is `Invalid` (builder).
+    #[must_use]
+    pub fn with_error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Set the initial date (builder).
+    #[must_use]
+    pub fn with_value(mut self, value: DateValue) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Set focus (builder).
+    #[must_use]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Parsed date, `None` while incomplete or invalid.
+    #[must_use]
+    pub fn value(&self) -> Option<DateValue> {
+        let date = self.partial_value()?;
+        date.is_valid().then_some(date)
+    }
+
+    /// Replace the date value.
+    pub fn set_value(&mut self, value: DateValue) {
+        self.year = format!("{:04}", value.year.clamp(0, 9999));
+        self.month = format!("{:02}", value.month);
+        self.day = format!("{:02}", value.day);
+    }
+
+    /// Current validation state (leap-year aware).
+    #[must_use]
+    pub fn validation_state(&self) -> ValidationState {
+        if self.year.len() < 4 || self.month.len() < 2 || self.day.len() < 2 {
+            return ValidationState::Incomplete;
+        }
+        match self.partial_value() {
+            Some(date) if date.is_valid() => ValidationState::Valid,
+            Some(date) => ValidationState::Invalid(format!(
+                "{:04}-{:02}-{:02} is not a real date",
+                date.year, date.month, date.day
+            )),
+            None => ValidationState::Incomplete,
+        }
+    }
+
+    /// The display text: entered digits padded with pattern letters
+    /// (`2024-0M-DD` while typing).
+    #[must_use]
+    pub fn display_text(&self) -> String {
+        let mut out = String::new();
+        for token in &self.tokens {
+            match token {
+                DateToken::Literal(c) => out.push(*c),
+                DateToken::Segment(seg) => {
+                    let (buf, letter) = match seg {
+                        DateSegment::Year => (&self.year, 'Y'),
+                        DateSegment::Month => (&self.month, 'M'),
+                        DateSegment::Day => (&self.day, 'D'),
+                    };
+                    out.push_str(buf);
+                    for _ in buf.len()..seg.width() {
+                        out.push(letter);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// The active segment index (for cursor rendering by the host).
+    #[must_use]
+    pub fn active_segment(&self) -> usize {
+        self.active
+    }
+
+    /// Handle a terminal event. Returns `true` if the state changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key)
+                if key.kind == KeyEventKind::Press || key.kind == KeyEventKind::Repeat =>
+            {
+                self.handle_key(key)
+            }
+            Event::Paste(paste) => self.paste(&paste.text),
+            _ => false,
+        }
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Left => {
+                if self.active > 0 {
+                    self.active -= 1;
+                    return true;
+                }
+                false
+            }
+            KeyCode::Right => {
+                if self.active + 1 < self.segments.len() {
+                    self.active += 1;
+                    return true;
+                }
+                false
+            }
+            KeyCode::Up => {
+                self.step_active(1);
+                true
+            }
+            KeyCode::Down => {
+                self.step_active(-1);
+                true
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                let seg = self.segments[self.active];
+                let width = seg.width();
+                let buf = self.segment_buf_mut(seg);
+                if buf.len() >= width {
+                    buf.clear();
+                }
+                buf.push(c);
+                // Auto-advance when the segment fills up.
+                if self.segment_buf(seg).len() == width && self.active + 1 < self.segments.len() {
+                    self.active += 1;
+                }
+                true
+            }
+            KeyCode::Backspace => {
+                let seg = self.segments[self.active];
+                if self.segment_buf(seg).is_empty() {
+                    if self.active > 0 {
+                        self.active -= 1;
+                        return true;
+                    }
+                    return false;
+                }
+                self.segment_buf_mut(seg).pop();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse a pasted date: the pattern's formatting, ISO `YYYY-MM-DD`, or a
+    /// continuous digit run. Invalid pastes leave the state unchanged.
+    fn paste(&mut self, text: &str) -> bool {
+        let Some(date) = self.parse_paste(text.trim()) else {
+            return false;
+        };
+        if !date.is_valid() {
+            return false;
+        }
+        self.set_value(date);
+        true
+    }
+
+    fn parse_paste(&self, text: &str) -> Option<DateValue> {
+        // Continuous digits in pattern segment order.
+        let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+        let non_digits_ok = text
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_punctuation() || c == ' ');
+        if digits.len() != 8 || !non_digits_ok {
+            return None;
+        }
+        let mut cursor = 0usize;
+        let mut year = 0i32;
+        let mut month = 0u8;
+        let mut day = 0u8;
+        for seg in &self.segments {
+            let width = seg.width();
+            let part = &digits[cursor..cursor + width];
+            cursor += width;
+            match seg {
+                DateSegment::Year => year = part.parse().ok()?,
+                DateSegment::Month => month = part.parse().ok()?,
+                DateSegment::Day => day = part.parse().ok()?,
+            }
+        }
+        Some(DateValue { year, month, day })
+    }
+
+    /// Step the active segment by `delta` with carry and day clamping.
+    fn step_active(&mut self, delta: i32) {
+        let base = self.partial_or_default();
+        let seg = self.segments[self.active];
+        let stepped = match seg {
+            DateSegment::Year => DateValue {
+                year: (base.year + delta).clamp(0, 9999),
+                ..base
+            },
+            DateSegment::Month => {
+                let total = base.year * 12 + i32::from(base.month) - 1 + delta;
+                let total = total.clamp(0, 9999 * 12 + 11);
+                DateValue {
+                    year: total.div_euclid(12),
+                    month: (total.rem_euclid(12) + 1) as u8,
+                    day: base.day,
+                }
+            }
+            DateSegment::Day => {
+                let days = i32::from(DateValue::days_in_month(base.year, base.month));
+                let day = i32::from(base.day) - 1 + delta;
+                DateValue {
+                    day: (day.rem_euclid(days) + 1) as u8,
+                    ..base
+                }
+            }
+        };
+        // Clamp the day into the (possibly shorter) target month:
+        // Jan 31 + 1 month => Feb 28/29.
+        let clamped = DateValue {
+            day: stepped
+                .day
+                .min(DateValue::days_in_month(stepped.year, stepped.month))
+                .max(1),
+            ..stepped
+        };
+        self.set_value(clamped);
+    }
+
+    fn partial_value(&self) -> Option<DateValue> {
+        Some(DateValue {
+            year: self.year.parse().ok()?,
+            month: self.month.parse().ok()?,
+            day: self.day.parse().ok()?,
+        })
+    }
+
+    /// Current value with unfilled segments defaulted (for stepping from
+    /// a partially filled field).
+    fn partial_or_default(&self) -> DateValue {
+        DateValue {
+            year: self.year.parse().unwrap_or(2000),
+            month: self.month.parse().unwrap_or(1),
+            day: self.day.parse().unwrap_or(1),
+        }
+    }
+
+    fn segment_buf(&self, seg: DateSegment) -> &String {
+        match seg {
+            DateSegment::Year => &self.year,
+            DateSegment::Month => &self.month,
+            DateSegment::Day => &self.day,
+        }
+    }
+
+    fn segment_buf_mut(&mut self, seg: DateSegment) -> &mut String {
+        match seg {
+            DateSegment::Year => &mut self.year,
+            DateSegment::Month => &mut self.month,
+            DateSegment::Day => &mut self.day,
+        }
+    }
+}
+
+impl Widget for DateInput {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.width < 1 || area.height < 1 {
+            return;
+        }
+        let style = if matches!(self.validation_state(), ValidationState::Invalid(_)) {
+            self.error_style
+        } else {
+            self.style
+        };
+        draw_text_span(
+            frame,
+            area.x,
+            area.y,
+            &self.display_text(),
+            style,
+            area.right(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::PasteEvent;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code))
+    }
+
+    fn shift_key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code).with_modifiers(Modifiers::SHIFT))
+    }
+
+    fn paste(text: &str) -> Event {
+        Event::Paste(PasteEvent::bracketed(text))
+    }
+
+    // --- NumericInput ---
+
+    #[test]
+    fn numeric_typing_and_value() {
+        let mut input = NumericInput::new();
+        for c in ['1', '2', '3'] {
+            assert!(input.handle_event(&key(KeyCode::Char(c))));
+        }
+        assert_eq!(input.value_int(), Some(123));
+        assert_eq!(input.validation_state(), ValidationState::Valid);
+    }
+
+    #[test]
+    fn numeric_rejects_letters() {
+        let mut input = NumericInput::new();
+        assert!(!input.handle_event(&key(KeyCode::Char('x'))));
+        assert_eq!(input.stored_text(), "");
+    }
+
+    #[test]
+    fn thousands_separator_rendered_not_stored() {
+        let mut input = NumericInput::new().with_thousands_separator(',');
+        for c in "1234567".chars() {
+            input.handle_event(&key(KeyCode::Char(c)));
+        }
+        assert_eq!(input.stored_text(), "1234567");
+        assert_eq!(input.display_text(), "1,234,567");
+        assert_eq!(input.value_int(), Some(1_234_567));
+    }
+
+    #[test]
+    fn decimal_separator_grouping_keeps_fraction_ungrouped() {
+        let input = NumericInput::decimal(2)
+            .with_thousands_separator('.')
+            .with_value(1234.5);
+        assert_eq!(input.stored_text(), "1234.50");
+        assert_eq!(input.display_text(), "1.234.50");
+    }
+
+    #[test]
+    fn stepping_and_min_max_clamping() {
+        let mut input = NumericInput::new()
+            .with_min(0.0)
+            .with_max(10.0)
+            .with_value(9.0);
+        input.handle_event(&key(KeyCode::Up));
+        assert_eq!(input.value_int(), Some(10));
+        // Clamped at max.
+        input.handle_event(&key(KeyCode::Up));
+        assert_eq!(input.value_int(), Some(10));
+        // Shift steps by the large step, clamped at min.
+        input.handle_event(&shift_key(KeyCode::Down));
+        assert_eq!(input.value_int(), Some(0));
+        input.handle_event(&key(KeyCode::Down));
+        assert_eq!(input.value_int(), Some(0));
+    }
+
+    #[test]
+    fn negative_sign_requires_negative_min() {
+        let mut unsigned = NumericInput::new().with_min(0.0);
+        assert!(!unsigned.handle_event(&key(KeyCode::Char('-'))));
+
+        let mut signed = NumericInput::new().with_min(-5.0);
+        assert!(signed.handle_event(&key(KeyCode::Char('-'))));
+        assert!(signed.handle_event(&key(KeyCode::Char('3'))));
+        assert_eq!(signed.value_int(), Some(-3));
+    }
+
+    #[test]
+    fn numeric_paste_formatted_and_unformatted() {
+        let mut input = NumericInput::new().with_thousands_separator(',');
+        assert!(input.handle_event(&paste("1,234,567")));
+        assert_eq!(input.stored_text(), "1234567");
+
+        assert!(input.handle_event(&paste(" 42 ")));
+        assert_eq!(input.value_int(), Some(42));
+    }
+
+    #[test]
+    fn invalid_numeric_paste_leaves_state_unchanged() {
+        let mut input = NumericInput::new().with_value(7.0);
+        assert!(!input.handle_event(&paste("not a number")));
+        assert_eq!(input.value_int(), Some(7));
+        assert_eq!(input.stored_text(), "7");
+    }
+
+    #[test]
+    fn decimal_places_enforced_while_typing() {
+        let mut input = NumericInput::decimal(2);
+        for c in "3.14".chars() {
+            assert!(input.handle_event(&key(KeyCode::Char(c))), "char {c}");
+        }
+        // Third fractional digit is rejected.
+        assert!(!input.handle_event(&key(KeyCode::Char('1'))));
+        assert_eq!(input.stored_text(), "3.14");
+    }
+
+    // --- DateInput ---
+
+    #[test]
+    fn date_typing_fills_segments_in_order() {
+        let mut input = DateInput::new();
+        for c in "20240229".chars() {
+            assert!(input.handle_event(&key(KeyCode::Char(c))));
+        }
+        assert_eq!(input.display_text(), "2024-02-29");
+        // 2024 is a leap year.
+        assert_eq!(input.validation_state(), ValidationState::Valid);
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2024,
+                month: 2,
+                day: 29
+            })
+        );
+    }
+
+    #[test]
+    fn non_leap_february_29_is_invalid() {
+        let mut input = DateInput::new();
+        for c in "20230229".chars() {
+            input.handle_event(&key(KeyCode::Char(c)));
+        }
+        assert!(matches!(
+            input.validation_state(),
+            ValidationState::Invalid(_)
+        ));
+        assert_eq!(input.value(), None);
+    }
+
+    #[test]
+    fn month_step_clamps_day_jan_31_plus_one_month() {
+        let mut input = DateInput::new().with_value(DateValue {
+            year: 2023,
+            month: 1,
+            day: 31,
+        });
+        // Navigate to the month segment (segment order YYYY, MM, DD).
+        input.handle_event(&key(KeyCode::Right));
+        input.handle_event(&key(KeyCode::Up));
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2023,
+                month: 2,
+                day: 28
+            })
+        );
+    }
+
+    #[test]
+    fn month_step_carries_across_year_boundary() {
+        let mut input = DateInput::new().with_value(DateValue {
+            year: 2023,
+            month: 12,
+            day: 15,
+        });
+        input.handle_event(&key(KeyCode::Right));
+        input.handle_event(&key(KeyCode::Up));
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2024,
+                month: 1,
+                day: 15
+            })
+        );
+    }
+
+    #[test]
+    fn day_step_wraps_within_month() {
+        let mut input = DateInput::new().with_value(DateValue {
+            year: 2023,
+            month: 2,
+            day: 28,
+        });
+        input.handle_event(&key(KeyCode::Right));
+        input.handle_event(&key(KeyCode::Right));
+        input.handle_event(&key(KeyCode::Up));
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2023,
+                month: 2,
+                day: 1
+            })
+        );
+    }
+
+    #[test]
+    fn date_paste_formatted_and_digit_run() {
+        let mut input = DateInput::new();
+        assert!(input.handle_event(&paste("2024-01-31")));
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2024,
+                month: 1,
+                day: 31
+            })
+        );
+
+        assert!(input.handle_event(&paste("20241224")));
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2024,
+                month: 12,
+                day: 24
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_date_paste_leaves_state_unchanged() {
+        let mut input = DateInput::new().with_value(DateValue {
+            year: 2024,
+            month: 6,
+            day: 15,
+        });
+        assert!(!input.handle_event(&paste("2023-02-29")));
+        assert!(!input.handle_event(&paste("hello")));
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2024,
+                month: 6,
+                day: 15
+            })
+        );
+    }
+
+    #[test]
+    fn custom_pattern_orders_segments() {
+        let mut input = DateInput::with_pattern("DD.MM.YYYY");
+        assert!(input.handle_event(&paste("31.01.2024")));
+        assert_eq!(input.display_text(), "31.01.2024");
+        assert_eq!(
+            input.value(),
+            Some(DateValue {
+                year: 2024,
+                month: 1,
+                day: 31
+            })
+        );
+    }
+
+    #[test]
+    fn display_shows_pattern_letters_for_missing_digits() {
+        let mut input = DateInput::new();
+        for c in "2024".chars() {
+            input.handle_event(&key(KeyCode::Char(c)));
+        }
+        input.handle_event(&key(KeyCode::Char('1')));
+        assert_eq!(input.display_text(), "2024-1M-DD");
+        assert_eq!(input.validation_state(), ValidationState::Incomplete);
+    }
+}
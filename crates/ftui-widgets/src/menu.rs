@@ -0,0 +1,1141 @@
+#![forbid(unsafe_code)]
+
+//! Menu bar, dropdown menus, and context menus.
+//!
+//! Desktop-style menus: [`MenuBar`] renders top-level entries with
+//! Alt+mnemonic activation (`&` in a label underlines the next letter),
+//! opening dropdown overlays with items (label, shortcut hint, enabled
+//! flag, separators, checkbox/radio marks, nested submenus).
+//! [`ContextMenu`] opens the same dropdown at an arbitrary cell,
+//! flipping placement to stay inside the frame.
+//!
+//! The widgets own only open/highlight state; activation surfaces the
+//! item's id as a [`MenuEvent`] for the app's `update` to map into a
+//! message, matching the other interactive widgets.
+
+use crate::block::Block;
+use crate::borders::Borders;
+use crate::{Widget, apply_style, draw_text_span, set_style_area};
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::{Rect, Size};
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_text::display_width;
+
+/// Columns a dropdown is indented under its top-level title.
+const SHORTCUT_GAP: usize = 2;
+
+/// What an item is, beyond a plain command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuItemKind {
+    /// Plain command item.
+    Command,
+    /// Horizontal separator (not selectable).
+    Separator,
+    /// Checkbox item with its current state.
+    Checkbox(bool),
+    /// Radio item with its current state.
+    Radio(bool),
+    /// Nested submenu.
+    Submenu(Vec<MenuItem>),
+}
+
+/// One entry of a dropdown menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuItem {
+    /// Id delivered on activation.
+    pub id: String,
+    /// Label; `&` underlines the next letter as the mnemonic.
+    pub label: String,
+    /// Right-aligned shortcut hint (display only).
+    pub shortcut: Option<String>,
+    /// Disabled items render dim and are skipped by navigation.
+    pub enabled: bool,
+    /// Kind (command, separator, checkbox, radio, submenu).
+    pub kind: MenuItemKind,
+}
+
+impl MenuItem {
+    /// A plain command item.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            shortcut: None,
+            enabled: true,
+            kind: MenuItemKind::Command,
+        }
+    }
+
+    /// A separator line.
+    #[must_use]
+    pub fn separator() -> Self {
+        Self {
+            id: String::new(),
+            label: String::new(),
+            shortcut: None,
+            enabled: false,
+            kind: MenuItemKind::Separator,
+        }
+    }
+
+    /// A checkbox item.
+    pub fn checkbox(id: impl Into<String>, label: impl Into<String>, checked: bool) -> Self {
+        Self {
+            kind: MenuItemKind::Checkbox(checked),
+            ..Self::new(id, label)
+        }
+    }
+
+    /// A radio item.
+    pub fn radio(id: impl Into<String>, label: impl Into<String>, selected: bool) -> Self {
+        Self {
+            kind: MenuItemKind::Radio(selected),
+            ..Self::new(id, label)
+        }
+    }
+
+    /// A nested submenu.
+    pub fn submenu(id: impl Into<String>, label: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        Self {
+            kind: MenuItemKind::Submenu(items),
+            ..Self::new(id, label)
+        }
+    }
+
+    /// Shortcut hint (builder).
+    #[must_use]
+    pub fn shortcut(mut self, hint: impl Into<String>) -> Self {
+        self.shortcut = Some(hint.into());
+        self
+    }
+
+    /// Enabled flag (builder).
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Whether navigation can land on this item.
+    fn selectable(&self) -> bool {
+        self.enabled && self.kind != MenuItemKind::Separator
+    }
+
+    /// Label with the `&` marker stripped, plus the mnemonic letter and
+    /// its grapheme offset.
+    fn mnemonic(&self) -> (String, Option<(char, usize)>) {
+        parse_mnemonic(&self.label)
+    }
+}
+
+/// Strip a `&` mnemonic marker: returns the visible label and the
+/// lowercased mnemonic char with its char offset. `&&` renders a
+/// literal `&`.
+fn parse_mnemonic(label: &str) -> (String, Option<(char, usize)>) {
+    let mut out = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some('&') => out.push('&'),
+                Some(next) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some((
+                            next.to_lowercase().next().unwrap_or(next),
+                            out.chars().count(),
+                        ));
+                    }
+                    out.push(next);
+                }
+                None => {}
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    (out, mnemonic)
+}
+
+/// What a key/mouse interaction produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuEvent {
+    /// An enabled item was activated; deliver this id to the app.
+    Activated(String),
+    /// Open/highlight state changed (repaint).
+    StateChanged,
+    /// The menu closed without activating.
+    Closed,
+    /// The event was not for this widget.
+    Ignored,
+}
+
+/// A top-level menu of the bar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuBarEntry {
+    /// Title; `&` marks the Alt+mnemonic.
+    pub title: String,
+    /// Dropdown items.
+    pub items: Vec<MenuItem>,
+}
+
+impl MenuBarEntry {
+    /// Create a top-level menu.
+    pub fn new(title: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        Self {
+            title: title.into(),
+            items,
+        }
+    }
+}
+
+/// Open/highlight state of a [`MenuBar`] (or [`ContextMenu`]).
+///
+/// `path` addresses the highlight through nested submenus: `path[0]` is
+/// the highlighted index in the open dropdown, each further element the
+/// highlight inside the submenu opened at the previous level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuState {
+    /// Open top-level menu index (`None` = bar closed). Context menus
+    /// use `Some(0)` while open.
+    pub open: Option<usize>,
+    /// Highlight path through the open dropdown and its submenus.
+    pub path: Vec<usize>,
+}
+
+impl MenuState {
+    /// Whether any dropdown is open.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Close everything.
+    pub fn close(&mut self) {
+        self.open = None;
+        self.path.clear();
+    }
+}
+
+/// Walk `items` along `path[..depth]`, returning the item list at that
+/// submenu depth.
+fn items_at_depth<'a>(items: &'a [MenuItem], path: &[usize], depth: usize) -> &'a [MenuItem] {
+    let mut current = items;
+    for &idx in path.iter().take(depth) {
+        match current.get(idx).map(|item| &item.kind) {
+            Some(MenuItemKind::Submenu(children)) => current = children,
+            _ => return current,
+        }
+    }
+    current
+}
+
+/// Next selectable index from `from` in `dir` (wrapping), or `from` when
+/// nothing else is selectable.
+fn next_selectable(items: &[MenuItem], from: usize, dir: isize) -> usize {
+    if items.is_empty() {
+        return 0;
+    }
+    let len = items.len() as isize;
+    let mut idx = from as isize;
+    for _ in 0..items.len() {
+        idx = (idx + dir).rem_euclid(len);
+        if items[idx as usize].selectable() {
+            return idx as usize;
+        }
+    }
+    from
+}
+
+/// First selectable index (0 when none).
+fn first_selectable(items: &[MenuItem]) -> usize {
+    items.iter().position(MenuItem::selectable).unwrap_or(0)
+}
+
+/// Shared keyboard navigation over an open dropdown tree.
+///
+/// Returns `None` when the key wasn't consumed; `Escape` at the top
+/// dropdown level produces `MenuEvent::Closed` and the caller clears
+/// `open`.
+fn navigate_open(items: &[MenuItem], state: &mut MenuState, key: &KeyEvent) -> Option<MenuEvent> {
+    if state.path.is_empty() {
+        state.path.push(first_selectable(items));
+    }
+    let depth = state.path.len() - 1;
+    let level_items = items_at_depth(items, &state.path, depth);
+    let highlighted = state.path[depth];
+
+    match key.code {
+        KeyCode::Up => {
+            state.path[depth] = next_selectable(level_items, highlighted, -1);
+            Some(MenuEvent::StateChanged)
+        }
+        KeyCode::Down => {
+            state.path[depth] = next_selectable(level_items, highlighted, 1);
+            Some(MenuEvent::StateChanged)
+        }
+        KeyCode::Right => match level_items.get(highlighted).map(|item| &item.kind) {
+            Some(MenuItemKind::Submenu(children)) => {
+                state.path.push(first_selectable(children));
+                Some(MenuEvent::StateChanged)
+            }
+            _ => None,
+        },
+        KeyCode::Left | KeyCode::Escape => {
+            if depth > 0 {
+                // Unwind one submenu level.
+                state.path.pop();
+                Some(MenuEvent::StateChanged)
+            } else if key.code == KeyCode::Escape {
+                Some(MenuEvent::Closed)
+            } else {
+                None
+            }
+        }
+        KeyCode::Enter => {
+            let item = level_items.get(highlighted)?;
+            if !item.selectable() {
+                return Some(MenuEvent::StateChanged);
+            }
+            match &item.kind {
+                MenuItemKind::Submenu(children) => {
+                    state.path.push(first_selectable(children));
+                    Some(MenuEvent::StateChanged)
+                }
+                _ => Some(MenuEvent::Activated(item.id.clone())),
+            }
+        }
+        KeyCode::Char(c) if !key.modifiers.contains(Modifiers::CTRL) => {
+            // In-menu mnemonic: jump to / activate the matching item.
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            let found = level_items.iter().enumerate().find(|(_, item)| {
+                item.selectable() && item.mnemonic().1.is_some_and(|(m, _)| m == lower)
+            });
+            let (idx, item) = found?;
+            state.path[depth] = idx;
+            match &item.kind {
+                MenuItemKind::Submenu(children) => {
+                    state.path.push(first_selectable(children));
+                    Some(MenuEvent::StateChanged)
+                }
+                _ => Some(MenuEvent::Activated(item.id.clone())),
+            }
+        }
+        _ => None,
+    }
+}
+
+// ============================================================================
+// MenuBar
+// ============================================================================
+
+/// Desktop-style top menu bar with dropdown menus.
+///
+/// The bar owns open/highlight state only; item activation surfaces the
+/// item id through [`MenuEvent::Activated`].
+#[derive(Debug, Clone)]
+pub struct MenuBar {
+    entries: Vec<MenuBarEntry>,
+    /// Bar background/text style.
+    style: Style,
+    /// Highlighted entry/item style.
+    highlight_style: Style,
+    /// Disabled item style.
+    disabled_style: Style,
+    /// Mnemonic letter styling (underline).
+    mnemonic_style: Style,
+}
+
+impl MenuBar {
+    /// Create a bar from its top-level menus.
+    #[must_use]
+    pub fn new(entries: Vec<MenuBarEntry>) -> Self {
+        Self {
+            entries,
+            style: Style::new(),
+            highlight_style: Style::new().reverse(),
+            disabled_style: Style::new().dim(),
+            mnemonic_style: Style::new().underline(),
+        }
+    }
+
+    /// Bar style (builder).
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Highlight style (builder).
+    #[must_use]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// The top-level entries.
+    #[must_use]
+    pub fn entries(&self) -> &[MenuBarEntry] {
+        &self.entries
+    }
+
+    /// Column span of a top-level title inside the bar (1-cell padding
+    /// both sides).
+    fn title_spans(&self) -> Vec<(usize, usize)> {
+        let mut spans = Vec::with_capacity(self.entries.len());
+        let mut x = 0usize;
+        for entry in &self.entries {
+            let (label, _) = parse_mnemonic(&entry.title);
+            let w = display_width(&label) + 2;
+            spans.push((x, w));
+            x += w;
+        }
+        spans
+    }
+
+    /// Handle a key event against the bar.
+    pub fn handle_key(&self, state: &mut MenuState, key: &KeyEvent) -> MenuEvent {
+        let alt = key.modifiers.contains(Modifiers::ALT);
+
+        // Alt+mnemonic opens (or jumps to) a top-level menu, open or not.
+        if alt && let KeyCode::Char(c) = key.code {
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            if let Some(idx) = self.entries.iter().position(|entry| {
+                parse_mnemonic(&entry.title)
+                    .1
+                    .is_some_and(|(m, _)| m == lower)
+            }) {
+                state.open = Some(idx);
+                state.path = vec![first_selectable(&self.entries[idx].items)];
+                return MenuEvent::StateChanged;
+            }
+            return MenuEvent::Ignored;
+        }
+
+        let Some(open) = state.open else {
+            return MenuEvent::Ignored;
+        };
+        // Stale state guard: the bar may have been rebuilt with fewer
+        // entries while a menu was open.
+        let Some(entry) = self.entries.get(open) else {
+            state.close();
+            return MenuEvent::Closed;
+        };
+        let items = &entry.items;
+
+        // Left/Right at the dropdown's top level move between top-level
+        // menus while open.
+        let at_top_level = state.path.len() <= 1;
+        let submenu_highlighted = matches!(
+            items_at_depth(items, &state.path, state.path.len().saturating_sub(1))
+                .get(state.path.last().copied().unwrap_or(0))
+                .map(|item| &item.kind),
+            Some(MenuItemKind::Submenu(_))
+        );
+        match key.code {
+            KeyCode::Left if at_top_level && !self.entries.is_empty() => {
+                let prev = (open + self.entries.len() - 1) % self.entries.len();
+                state.open = Some(prev);
+                state.path = vec![first_selectable(&self.entries[prev].items)];
+                return MenuEvent::StateChanged;
+            }
+            KeyCode::Right if !self.entries.is_empty() && !submenu_highlighted => {
+                let next = (open + 1) % self.entries.len();
+                state.open = Some(next);
+                state.path = vec![first_selectable(&self.entries[next].items)];
+                return MenuEvent::StateChanged;
+            }
+            _ => {}
+        }
+
+        match navigate_open(items, state, key) {
+            Some(MenuEvent::Closed) => {
+                state.close();
+                MenuEvent::Closed
+            }
+            Some(MenuEvent::Activated(id)) => {
+                state.close();
+                MenuEvent::Activated(id)
+            }
+            Some(event) => event,
+            None => MenuEvent::Ignored,
+        }
+    }
+
+    /// Handle a mouse event. `bar_area` is the rect the bar was rendered
+    /// into; dropdown geometry is derived from it.
+    pub fn handle_mouse(
+        &self,
+        state: &mut MenuState,
+        event: &MouseEvent,
+        bar_area: Rect,
+        frame_size: Size,
+    ) -> MenuEvent {
+        let click = matches!(event.kind, MouseEventKind::Down(MouseButton::Left));
+        let hover = matches!(event.kind, MouseEventKind::Moved);
+        if !click && !hover {
+            return MenuEvent::Ignored;
+        }
+
+        // Over the bar row: titles toggle (click) or switch while open
+        // (hover).
+        if event.y == bar_area.y && event.x >= bar_area.x {
+            let rel = (event.x - bar_area.x) as usize;
+            for (idx, (x, w)) in self.title_spans().iter().enumerate() {
+                if rel >= *x && rel < x + w {
+                    if click {
+                        if state.open == Some(idx) {
+                            state.close();
+                            return MenuEvent::Closed;
+                        }
+                        state.open = Some(idx);
+                        state.path = vec![first_selectable(&self.entries[idx].items)];
+                        return MenuEvent::StateChanged;
+                    }
+                    if state.is_open() && state.open != Some(idx) {
+                        state.open = Some(idx);
+                        state.path = vec![first_selectable(&self.entries[idx].items)];
+                        return MenuEvent::StateChanged;
+                    }
+                    return MenuEvent::Ignored;
+                }
+            }
+            // Blank bar-row clicks dismiss like any other outside click.
+            if click && state.is_open() {
+                state.close();
+                return MenuEvent::Closed;
+            }
+            return MenuEvent::Ignored;
+        }
+
+        let Some(open) = state.open else {
+            return MenuEvent::Ignored;
+        };
+        if open >= self.entries.len() {
+            state.close();
+            return MenuEvent::Closed;
+        }
+
+        // Over an open dropdown (deepest levels first so submenus win
+        // where rects overlap).
+        let rects = self.dropdown_rects(state, bar_area, frame_size);
+        for (depth, rect) in rects.iter().enumerate().rev() {
+            if event.x < rect.x
+                || event.x >= rect.right()
+                || event.y < rect.y
+                || event.y >= rect.bottom()
+            {
+                continue;
+            }
+            // Inside the border: row 0 is the top border.
+            let row = (event.y - rect.y) as usize;
+            if row == 0 || event.y + 1 >= rect.bottom() {
+                return MenuEvent::Ignored;
+            }
+            let idx = row - 1;
+            let items = items_at_depth(&self.entries[open].items, &state.path, depth);
+            let Some(item) = items.get(idx) else {
+                return MenuEvent::Ignored;
+            };
+            if !item.selectable() {
+                return MenuEvent::Ignored;
+            }
+            state.path.truncate(depth);
+            state.path.push(idx);
+            if let MenuItemKind::Submenu(children) = &item.kind {
+                // Hover or click on a submenu parent opens it.
+                state.path.push(first_selectable(children));
+                return MenuEvent::StateChanged;
+            }
+            if click {
+                let id = item.id.clone();
+                state.close();
+                return MenuEvent::Activated(id);
+            }
+            return MenuEvent::StateChanged;
+        }
+
+        if click {
+            // Click elsewhere closes the menu.
+            state.close();
+            return MenuEvent::Closed;
+        }
+        MenuEvent::Ignored
+    }
+
+    /// Dropdown rect per open depth (index 0 = the top-level dropdown).
+    fn dropdown_rects(&self, state: &MenuState, bar_area: Rect, frame_size: Size) -> Vec<Rect> {
+        let Some(open) = state.open else {
+            return Vec::new();
+        };
+        let Some(entry) = self.entries.get(open) else {
+            return Vec::new();
+        };
+        let origin_x = bar_area.x + self.title_spans().get(open).map_or(0, |(x, _)| *x as u16);
+        let mut rects = Vec::new();
+        let mut items = &entry.items;
+        let mut anchor = (origin_x, bar_area.y + 1);
+        for depth in 0..state.path.len() {
+            let rect = place_dropdown(items, anchor, frame_size);
+            rects.push(rect);
+            let idx = state.path[depth];
+            match items.get(idx).map(|item| &item.kind) {
+                Some(MenuItemKind::Submenu(children)) if depth + 1 < state.path.len() => {
+                    // Next level anchors at the right edge of this item row.
+                    anchor = (rect.right().saturating_sub(1), rect.y + 1 + idx as u16);
+                    items = children;
+                }
+                _ => break,
+            }
+        }
+        rects
+    }
+}
+
+/// Measure a dropdown's outer size (border included).
+fn dropdown_size(items: &[MenuItem]) -> Size {
+    let mut width = 0usize;
+    for item in items {
+        let (label, _) = parse_mnemonic(&item.label);
+        let mut w = display_width(&label) + 2 /* mark column */;
+        if let Some(hint) = &item.shortcut {
+            w += SHORTCUT_GAP + display_width(hint);
+        }
+        if matches!(item.kind, MenuItemKind::Submenu(_)) {
+            w += 2; // " ▸"
+        }
+        width = width.max(w);
+    }
+    Size::new(
+        (width + 4) as u16, // borders + padding
+        items.len() as u16 + 2,
+    )
+}
+
+/// Place a dropdown at `anchor`, flipping horizontally/vertically to
+/// stay inside the frame.
+fn place_dropdown(items: &[MenuItem], anchor: (u16, u16), frame_size: Size) -> Rect {
+    let size = dropdown_size(items);
+    let width = size.width.min(frame_size.width);
+    let height = size.height.min(frame_size.height);
+    let mut x = anchor.0;
+    let mut y = anchor.1;
+    if x + width > frame_size.width {
+        x = frame_size.width.saturating_sub(width);
+    }
+    if y + height > frame_size.height {
+        // Flip upward around the anchor row.
+        y = anchor
+            .1
+            .saturating_sub(height + 1)
+            .min(frame_size.height.saturating_sub(height));
+    }
+    Rect::new(x, y, width, height)
+}
+
+impl MenuBar {
+    /// Render the bar and any open dropdowns. Call with the 1-row bar
+    /// area; dropdowns draw below it as overlays.
+    pub fn render_with_state(&self, area: Rect, frame: &mut Frame, state: &MenuState) {
+        if area.is_empty() {
+            return;
+        }
+        let bar = Rect::new(area.x, area.y, area.width, 1);
+        set_style_area(&mut frame.buffer, bar, self.style);
+
+        for (idx, entry) in self.entries.iter().enumerate() {
+            let (x, _) = self.title_spans()[idx];
+            let (label, mnemonic) = parse_mnemonic(&entry.title);
+            let style = if state.open == Some(idx) {
+                self.highlight_style
+            } else {
+                self.style
+            };
+            let tx = bar.x + x as u16;
+            draw_text_span(frame, tx, bar.y, &format!(" {label} "), style, bar.right());
+            if let Some((_, offset)) = mnemonic {
+                underline_at(frame, tx + 1, bar.y, &label, offset, self.mnemonic_style);
+            }
+        }
+
+        let frame_size = Size::new(frame.width(), frame.height());
+        let rects = self.dropdown_rects(state, bar, frame_size);
+        if let Some(entry) = state.open.and_then(|open| self.entries.get(open)) {
+            let mut items = &entry.items;
+            for (depth, rect) in rects.iter().enumerate() {
+                self.render_dropdown(*rect, frame, items, state.path.get(depth).copied());
+                if let Some(MenuItemKind::Submenu(children)) = state
+                    .path
+                    .get(depth)
+                    .and_then(|&idx| items.get(idx))
+                    .map(|item| &item.kind)
+                {
+                    items = children;
+                }
+            }
+        }
+    }
+
+    /// Render one dropdown level.
+    fn render_dropdown(
+        &self,
+        rect: Rect,
+        frame: &mut Frame,
+        items: &[MenuItem],
+        highlighted: Option<usize>,
+    ) {
+        set_style_area(&mut frame.buffer, rect, self.style);
+        Block::new().borders(Borders::ALL).render(rect, frame);
+        let inner_x = rect.x + 1;
+        let inner_w = rect.width.saturating_sub(2) as usize;
+        for (idx, item) in items.iter().enumerate() {
+            let y = rect.y + 1 + idx as u16;
+            if y + 1 > rect.bottom() {
+                break;
+            }
+            if item.kind == MenuItemKind::Separator {
+                for dx in 0..inner_w {
+                    draw_text_span(frame, inner_x + dx as u16, y, "─", self.style, rect.right());
+                }
+                continue;
+            }
+            let style = if !item.enabled {
+                self.disabled_style
+            } else if highlighted == Some(idx) {
+                self.highlight_style
+            } else {
+                self.style
+            };
+            if highlighted == Some(idx) && item.enabled {
+                set_style_area(
+                    &mut frame.buffer,
+                    Rect::new(inner_x, y, inner_w as u16, 1),
+                    style,
+                );
+            }
+            let mark = match item.kind {
+                MenuItemKind::Checkbox(true) => "✓ ",
+                MenuItemKind::Radio(true) => "● ",
+                MenuItemKind::Checkbox(false) | MenuItemKind::Radio(false) => "  ",
+                _ => "  ",
+            };
+            let (label, mnemonic) = parse_mnemonic(&item.label);
+            draw_text_span(frame, inner_x, y, mark, style, rect.right());
+            let label_x = inner_x + 2;
+            draw_text_span(frame, label_x, y, &label, style, rect.right());
+            if let Some((_, offset)) = mnemonic {
+                underline_at(frame, label_x, y, &label, offset, self.mnemonic_style);
+            }
+            // Right-aligned shortcut hint or submenu arrow.
+            let tail = match (&item.kind, &item.shortcut) {
+                (MenuItemKind::Submenu(_), _) => Some("▸".to_string()),
+                (_, Some(hint)) => Some(hint.clone()),
+                _ => None,
+            };
+            if let Some(tail) = tail {
+                let w = display_width(&tail);
+                if inner_w > w {
+                    let tail_x = inner_x + (inner_w - w) as u16;
+                    draw_text_span(frame, tail_x, y, &tail, style, rect.right());
+                }
+            }
+        }
+    }
+}
+
+/// Apply the mnemonic style to the glyph at char `offset` of `label`.
+fn underline_at(frame: &mut Frame, x: u16, y: u16, label: &str, offset: usize, style: Style) {
+    let prefix: String = label.chars().take(offset).collect();
+    let px = x + display_width(&prefix) as u16;
+    if let Some(cell) = frame.buffer.get_mut(px, y) {
+        apply_style(cell, style);
+    }
+}
+
+impl Widget for MenuBar {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        self.render_with_state(area, frame, &MenuState::default());
+    }
+}
+
+// ============================================================================
+// ContextMenu
+// ============================================================================
+
+/// A dropdown opened at an arbitrary cell (right-click menus).
+///
+/// Placement flips horizontally/vertically so the menu stays inside the
+/// frame. Keyboard handling matches an open [`MenuBar`] dropdown minus
+/// the top-level Left/Right switching.
+#[derive(Debug, Clone)]
+pub struct ContextMenu {
+    items: Vec<MenuItem>,
+    position: (u16, u16),
+    bar: MenuBar,
+}
+
+impl ContextMenu {
+    /// Create a context menu anchored at `position`.
+    #[must_use]
+    pub fn new(items: Vec<MenuItem>, position: (u16, u16)) -> Self {
+        let bar = MenuBar::new(vec![MenuBarEntry::new(String::new(), items.clone())]);
+        Self {
+            items,
+            position,
+            bar,
+        }
+    }
+
+    /// Open state for this menu (highlight starts on the first
+    /// selectable item).
+    #[must_use]
+    pub fn open_state(&self) -> MenuState {
+        MenuState {
+            open: Some(0),
+            path: vec![first_selectable(&self.items)],
+        }
+    }
+
+    /// The resolved placement for the top-level dropdown.
+    #[must_use]
+    pub fn placement(&self, frame_size: Size) -> Rect {
+        place_dropdown(&self.items, self.position, frame_size)
+    }
+
+    /// Handle a key event while open.
+    pub fn handle_key(&self, state: &mut MenuState, key: &KeyEvent) -> MenuEvent {
+        if !state.is_open() {
+            return MenuEvent::Ignored;
+        }
+        match navigate_open(&self.items, state, key) {
+            Some(MenuEvent::Closed) => {
+                state.close();
+                MenuEvent::Closed
+            }
+            Some(MenuEvent::Activated(id)) => {
+                state.close();
+                MenuEvent::Activated(id)
+            }
+            Some(event) => event,
+            None => MenuEvent::Ignored,
+        }
+    }
+
+    /// Render at the flipped placement with open submenus.
+    pub fn render_with_state(&self, frame: &mut Frame, state: &MenuState) {
+        if !state.is_open() {
+            return;
+        }
+        let frame_size = Size::new(frame.width(), frame.height());
+        let mut items: &[MenuItem] = &self.items;
+        let mut anchor = self.position;
+        for depth in 0..state.path.len() {
+            let rect = place_dropdown(items, anchor, frame_size);
+            self.bar
+                .render_dropdown(rect, frame, items, state.path.get(depth).copied());
+            let idx = state.path[depth];
+            match items.get(idx).map(|item| &item.kind) {
+                Some(MenuItemKind::Submenu(children)) if depth + 1 < state.path.len() => {
+                    anchor = (rect.right().saturating_sub(1), rect.y + 1 + idx as u16);
+                    items = children;
+                }
+                _ => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code)
+    }
+
+    fn alt(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c)).with_modifiers(Modifiers::ALT)
+    }
+
+    fn sample_bar() -> MenuBar {
+        MenuBar::new(vec![
+            MenuBarEntry::new(
+                "&File",
+                vec![
+                    MenuItem::new("new", "&New").shortcut("Ctrl+N"),
+                    MenuItem::new("open", "&Open…"),
+                    MenuItem::separator(),
+                    MenuItem::submenu(
+                        "recent",
+                        "&Recent",
+                        vec![MenuItem::new("r1", "one.rs"), MenuItem::new("r2", "two.rs")],
+                    ),
+                    MenuItem::new("quit", "&Quit").shortcut("Ctrl+Q"),
+                ],
+            ),
+            MenuBarEntry::new(
+                "&Edit",
+                vec![
+                    MenuItem::new("undo", "&Undo"),
+                    MenuItem::new("paste", "&Paste").enabled(false),
+                    MenuItem::checkbox("wrap", "Word &Wrap", true),
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn mnemonic_parsing_and_matching() {
+        assert_eq!(parse_mnemonic("&File"), ("File".into(), Some(('f', 0))));
+        assert_eq!(parse_mnemonic("E&xit"), ("Exit".into(), Some(('x', 1))));
+        assert_eq!(parse_mnemonic("A && B"), ("A & B".into(), None));
+
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        assert_eq!(
+            bar.handle_key(&mut state, &alt('e')),
+            MenuEvent::StateChanged
+        );
+        assert_eq!(state.open, Some(1));
+        // Alt+unknown is ignored.
+        assert_eq!(bar.handle_key(&mut state, &alt('z')), MenuEvent::Ignored);
+        // In-menu mnemonic activates directly.
+        let event = bar.handle_key(&mut state, &key(KeyCode::Char('u')));
+        assert_eq!(event, MenuEvent::Activated("undo".into()));
+        assert!(!state.is_open(), "activation closes the menu");
+    }
+
+    #[test]
+    fn nested_submenu_navigation_and_escape_unwinding() {
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        bar.handle_key(&mut state, &alt('f'));
+        assert_eq!(state.path, vec![0]);
+
+        // Down to the submenu (separator at index 2 is skipped).
+        bar.handle_key(&mut state, &key(KeyCode::Down));
+        bar.handle_key(&mut state, &key(KeyCode::Down));
+        assert_eq!(state.path, vec![3]);
+
+        // Right (or Enter) descends into the submenu.
+        bar.handle_key(&mut state, &key(KeyCode::Right));
+        assert_eq!(state.path, vec![3, 0]);
+        bar.handle_key(&mut state, &key(KeyCode::Down));
+        assert_eq!(state.path, vec![3, 1]);
+
+        // Escape unwinds one level at a time.
+        assert_eq!(
+            bar.handle_key(&mut state, &key(KeyCode::Escape)),
+            MenuEvent::StateChanged
+        );
+        assert_eq!(state.path, vec![3]);
+        assert_eq!(
+            bar.handle_key(&mut state, &key(KeyCode::Escape)),
+            MenuEvent::Closed
+        );
+        assert!(!state.is_open());
+
+        // Enter inside the submenu activates by id.
+        bar.handle_key(&mut state, &alt('f'));
+        state.path = vec![3, 1];
+        assert_eq!(
+            bar.handle_key(&mut state, &key(KeyCode::Enter)),
+            MenuEvent::Activated("r2".into())
+        );
+    }
+
+    #[test]
+    fn left_right_switch_top_level_menus_while_open() {
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        bar.handle_key(&mut state, &alt('f'));
+        bar.handle_key(&mut state, &key(KeyCode::Right));
+        assert_eq!(
+            state.open,
+            Some(1),
+            "Right moves to Edit (no submenu highlighted)"
+        );
+        bar.handle_key(&mut state, &key(KeyCode::Left));
+        assert_eq!(state.open, Some(0));
+        bar.handle_key(&mut state, &key(KeyCode::Left));
+        assert_eq!(state.open, Some(1), "wraps around");
+    }
+
+    #[test]
+    fn disabled_items_are_skipped_by_arrows() {
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        bar.handle_key(&mut state, &alt('e'));
+        assert_eq!(state.path, vec![0]);
+        // "paste" (index 1) is disabled: Down lands on index 2.
+        bar.handle_key(&mut state, &key(KeyCode::Down));
+        assert_eq!(state.path, vec![2]);
+        bar.handle_key(&mut state, &key(KeyCode::Up));
+        assert_eq!(state.path, vec![0]);
+    }
+
+    #[test]
+    fn context_menu_placement_flips_near_edges() {
+        let items = vec![
+            MenuItem::new("a", "Alpha"),
+            MenuItem::new("b", "Beta"),
+            MenuItem::new("c", "Gamma"),
+        ];
+        let frame = Size::new(40, 12);
+
+        // Plenty of room: opens at the anchor.
+        let menu = ContextMenu::new(items.clone(), (5, 3));
+        assert_eq!(menu.placement(frame).x, 5);
+        assert_eq!(menu.placement(frame).y, 3);
+
+        // Near the right edge: shifts left to fit.
+        let menu = ContextMenu::new(items.clone(), (38, 3));
+        let rect = menu.placement(frame);
+        assert!(rect.right() <= 40, "{rect:?}");
+
+        // Near the bottom: flips upward.
+        let menu = ContextMenu::new(items.clone(), (5, 11));
+        let rect = menu.placement(frame);
+        assert!(rect.bottom() <= 12, "{rect:?}");
+        assert!(rect.y < 11);
+    }
+
+    #[test]
+    fn hover_and_keyboard_highlight_agree() {
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        let bar_area = Rect::new(0, 0, 40, 1);
+        let frame = Size::new(40, 20);
+
+        // Click "File" on the bar.
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 0);
+        assert_eq!(
+            bar.handle_mouse(&mut state, &click, bar_area, frame),
+            MenuEvent::StateChanged
+        );
+        assert_eq!(state.open, Some(0));
+
+        // Hover the second item ("Open…") inside the dropdown: row 1 of
+        // the dropdown interior.
+        let rect = bar.dropdown_rects(&state, bar_area, frame)[0];
+        let hover = MouseEvent::new(MouseEventKind::Moved, rect.x + 2, rect.y + 2);
+        assert_eq!(
+            bar.handle_mouse(&mut state, &hover, bar_area, frame),
+            MenuEvent::StateChanged
+        );
+        assert_eq!(state.path, vec![1], "hover highlight");
+
+        // Keyboard from here continues from the same highlight.
+        bar.handle_key(&mut state, &key(KeyCode::Up));
+        assert_eq!(state.path, vec![0], "keyboard agrees with hover state");
+
+        // Hover a disabled row is ignored.
+        let mut edit_state = MenuState::default();
+        bar.handle_key(&mut edit_state, &alt('e'));
+        let rect = bar.dropdown_rects(&edit_state, bar_area, frame)[0];
+        let hover_disabled = MouseEvent::new(MouseEventKind::Moved, rect.x + 2, rect.y + 2);
+        assert_eq!(
+            bar.handle_mouse(&mut edit_state, &hover_disabled, bar_area, frame),
+            MenuEvent::Ignored
+        );
+        assert_eq!(edit_state.path, vec![0]);
+
+        // Click activates.
+        let mut state = MenuState::default();
+        bar.handle_key(&mut state, &alt('f'));
+        let rect = bar.dropdown_rects(&state, bar_area, frame)[0];
+        let click_item = MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            rect.x + 2,
+            rect.y + 1,
+        );
+        assert_eq!(
+            bar.handle_mouse(&mut state, &click_item, bar_area, frame),
+            MenuEvent::Activated("new".into())
+        );
+    }
+
+    #[test]
+    fn stale_open_index_closes_instead_of_panicking() {
+        let bar = sample_bar();
+        let mut state = MenuState {
+            open: Some(7),
+            path: vec![0],
+        };
+        assert_eq!(
+            bar.handle_key(&mut state, &key(KeyCode::Down)),
+            MenuEvent::Closed
+        );
+        assert!(!state.is_open());
+
+        // Mouse and render paths tolerate the stale state too.
+        let mut state = MenuState {
+            open: Some(7),
+            path: vec![0],
+        };
+        let hover = MouseEvent::new(MouseEventKind::Moved, 3, 2);
+        let _ = bar.handle_mouse(
+            &mut state,
+            &hover,
+            Rect::new(0, 0, 40, 1),
+            Size::new(40, 10),
+        );
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(40, 10, &mut pool);
+        bar.render_with_state(
+            Rect::new(0, 0, 40, 1),
+            &mut frame,
+            &MenuState {
+                open: Some(7),
+                path: vec![0],
+            },
+        );
+    }
+
+    #[test]
+    fn blank_bar_row_click_dismisses_open_menu() {
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        bar.handle_key(&mut state, &alt('f'));
+        // Far right of the bar row, past both titles.
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 35, 0);
+        assert_eq!(
+            bar.handle_mouse(
+                &mut state,
+                &click,
+                Rect::new(0, 0, 40, 1),
+                Size::new(40, 10)
+            ),
+            MenuEvent::Closed
+        );
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn renders_bar_and_open_dropdown() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(40, 12, &mut pool);
+        let bar = sample_bar();
+        let mut state = MenuState::default();
+        bar.handle_key(&mut state, &alt('f'));
+        bar.render_with_state(Rect::new(0, 0, 40, 1), &mut frame, &state);
+
+        // Bar titles visible.
+        assert_eq!(frame.buffer.get(1, 0).unwrap().content.as_char(), Some('F'));
+        // Dropdown border below the bar.
+        let rect = bar.dropdown_rects(&state, Rect::new(0, 0, 40, 1), Size::new(40, 12))[0];
+        assert!(frame.buffer.get(rect.x, rect.y).is_some());
+        assert_eq!(
+            frame
+                .buffer
+                .get(rect.x + 3, rect.y + 1)
+                .unwrap()
+                .content
+                .as_char(),
+            Some('N'),
+            "first item label inside the border"
+        );
+    }
+}
@@ -24,9 +24,18 @@ use ftui_core::event::{
     Event, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent, MouseEventKind,
 };
 use ftui_core::geometry::Rect;
+use ftui_core::geometry::Size;
+use ftui_render::cell::{Cell, PackedRgba};
 use ftui_render::frame::{Frame, HitData, HitId, HitRegion};
-use ftui_style::{Style, StyleFlags};
-use ftui_text::display_width;
+use ftui_style::theme::{ResolvedTheme, Theme};
+use ftui_style::{StateFlags, Style, StyleCascade, StyleFlags};
+use ftui_text::{WrapMode, display_width, wrap_text};
+
+/// Fraction of the frame width a dialog may occupy at most.
+const MAX_WIDTH_FRACTION: u16 = 80; // percent
+
+/// Absolute floor for dialog width so borders and padding survive.
+const MIN_DIALOG_WIDTH: u16 = 12;
 
 /// Hit region for dialog buttons.
 pub const DIALOG_HIT_BUTTON: HitRegion = HitRegion::Button;
@@ -88,6 +97,8 @@ pub enum DialogKind {
     Alert,
     /// Confirm: OK + Cancel buttons.
     Confirm,
+    /// Error: single OK button, styled with the theme's error token.
+    Error,
     /// Prompt: input field + OK + Cancel.
     Prompt,
     /// Custom dialog.
@@ -169,6 +180,8 @@ pub struct DialogConfig {
     pub message_style: Style,
     /// Input style (for Prompt).
     pub input_style: Style,
+    /// Result delivered when Escape closes the dialog.
+    pub escape_result: DialogResult,
 }
 
 impl Default for DialogConfig {
@@ -184,6 +197,7 @@ impl Default for DialogConfig {
             title_style: Style::new().bold(),
             message_style: Style::new(),
             input_style: Style::new(),
+            escape_result: DialogResult::Dismissed,
         }
     }
 }
@@ -244,6 +258,76 @@ impl Dialog {
         }
     }
 
+    /// Create an error dialog (message + OK), styled with the theme's
+    /// error token.
+    pub fn error(title: impl Into<String>, message: impl Into<String>) -> Self {
+        let dialog = Self {
+            title: title.into(),
+            message: message.into(),
+            buttons: vec![DialogButton::new("OK", "ok").primary()],
+            config: DialogConfig {
+                kind: DialogKind::Error,
+                ..Default::default()
+            },
+            hit_id: None,
+        };
+        dialog.with_theme(&Theme::default().resolve(true))
+    }
+
+    /// Re-derive the dialog's styles from theme tokens.
+    ///
+    /// Error dialogs pick up the error color for title and primary button;
+    /// other kinds use the primary accent for the primary button.
+    #[must_use]
+    pub fn with_theme(mut self, theme: &ResolvedTheme) -> Self {
+        let accent = if self.config.kind == DialogKind::Error {
+            theme.error
+        } else {
+            theme.primary
+        };
+        let rgb = accent.to_rgb();
+        let accent = PackedRgba::rgb(rgb.r, rgb.g, rgb.b);
+        self.config.title_style = Style::new().bold().fg(accent);
+        self.config.primary_button_style = Style::new().bold().fg(accent);
+        self
+    }
+
+    /// Re-derive styles from theme tokens, then apply cascade overrides.
+    ///
+    /// Consults the [`StyleCascade`] with kinds `dialog-title` and
+    /// `dialog-primary-button`, using the theme-derived styles as the
+    /// cascade's base layer — so "all dialogs use accent, except this
+    /// one on this screen" is a rule install away.
+    #[must_use]
+    pub fn with_theme_cascade(
+        self,
+        theme: &ResolvedTheme,
+        cascade: &StyleCascade,
+        id: Option<&str>,
+        scope: Option<&str>,
+    ) -> Self {
+        let mut dialog = self.with_theme(theme);
+        dialog.config.title_style = cascade
+            .resolve_with_base(
+                "dialog-title",
+                id,
+                scope,
+                StateFlags::NONE,
+                dialog.config.title_style,
+            )
+            .style;
+        dialog.config.primary_button_style = cascade
+            .resolve_with_base(
+                "dialog-primary-button",
+                id,
+                scope,
+                StateFlags::NONE,
+                dialog.config.primary_button_style,
+            )
+            .style;
+        dialog
+    }
+
     /// Create a prompt dialog (message + input + OK/Cancel).
     pub fn prompt(title: impl Into<String>, message: impl Into<String>) -> Self {
         Self {
@@ -290,6 +374,13 @@ impl Dialog {
         self
     }
 
+    /// Set the result delivered when Escape closes the dialog.
+    #[must_use]
+    pub fn escape_result(mut self, result: DialogResult) -> Self {
+        self.config.escape_result = result;
+        self
+    }
+
     /// Set button style.
     #[must_use]
     pub fn button_style(mut self, style: Style) -> Self {
@@ -327,14 +418,15 @@ impl Dialog {
         }
 
         match event {
-            // Escape closes with Dismissed
+            // Escape closes with the configured cancel result
             Event::Key(KeyEvent {
                 code: KeyCode::Escape,
                 kind: KeyEventKind::Press,
                 ..
             }) if self.config.modal_config.close_on_escape => {
-                state.close(DialogResult::Dismissed);
-                return Some(DialogResult::Dismissed);
+                let result = self.config.escape_result.clone();
+                state.close(result.clone());
+                return Some(result);
             }
 
             // Tab cycles focus
@@ -357,6 +449,15 @@ impl Dialog {
                 return self.activate_button(state);
             }
 
+            // Space activates focused button (unless the input has focus)
+            Event::Key(KeyEvent {
+                code: KeyCode::Char(' '),
+                kind: KeyEventKind::Press,
+                ..
+            }) if !state.input_focused => {
+                return self.activate_button(state);
+            }
+
             // Arrow keys navigate buttons
             Event::Key(KeyEvent {
                 code: KeyCode::Left | KeyCode::Right,
@@ -518,8 +619,27 @@ impl Dialog {
         }
     }
 
-    /// Calculate content height.
-    fn content_height(&self) -> u16 {
+    /// Wrap the message to fit the given interior width.
+    fn message_lines(&self, width: u16) -> Vec<String> {
+        if self.message.is_empty() || width == 0 {
+            return Vec::new();
+        }
+        wrap_text(&self.message, width as usize, WrapMode::Word)
+    }
+
+    /// Width of the button row: `[ label ]` per button plus spacing.
+    fn button_row_width(&self) -> u16 {
+        let total: usize = self
+            .buttons
+            .iter()
+            .map(|b| b.display_width())
+            .sum::<usize>()
+            + self.buttons.len().saturating_sub(1) * 2;
+        total.min(u16::MAX as usize) as u16
+    }
+
+    /// Calculate content height for the given interior (inside-border) width.
+    fn content_height(&self, inner_width: u16) -> u16 {
         let mut height: u16 = 2; // Top and bottom border
 
         // Title row
@@ -527,10 +647,8 @@ impl Dialog {
             height += 1;
         }
 
-        // Message row(s) - simplified: 1 row
-        if !self.message.is_empty() {
-            height += 1;
-        }
+        // Wrapped message rows
+        height = height.saturating_add(self.message_lines(inner_width).len() as u16);
 
         // Spacing
         height += 1;
@@ -547,12 +665,42 @@ impl Dialog {
         height
     }
 
+    /// Compute the dialog's size for the available frame.
+    ///
+    /// Width is at least wide enough for the button row and title, at most
+    /// [`MAX_WIDTH_FRACTION`] percent of the frame; height follows from the
+    /// message wrapped at the resolved width.
+    fn desired_size(&self, available: Size) -> Size {
+        // Borders plus one cell of padding on each side.
+        let chrome: u16 = 4;
+        let min_width = self
+            .button_row_width()
+            .max(display_width(self.title.as_str()).min(u16::MAX as usize) as u16)
+            .saturating_add(chrome)
+            .max(MIN_DIALOG_WIDTH);
+        let max_width = (u32::from(available.width) * u32::from(MAX_WIDTH_FRACTION) / 100) as u16;
+        let max_width = max_width.clamp(min_width, available.width.max(min_width));
+
+        let natural = (display_width(self.message.as_str()).min(u16::MAX as usize) as u16)
+            .saturating_add(chrome);
+        let width = natural.clamp(min_width, max_width);
+
+        let height = self
+            .content_height(width.saturating_sub(2))
+            .min(available.height.max(1));
+        Size::new(width, height)
+    }
+
     /// Render the dialog content.
     fn render_content(&self, area: Rect, frame: &mut Frame, state: &DialogState) {
         if area.is_empty() {
             return;
         }
 
+        // Clear the dialog rect so the busy background doesn't bleed
+        // through between the border and the centered content.
+        frame.buffer.fill(area, Cell::default());
+
         // Draw border
         let block = Block::default()
             .borders(Borders::ALL)
@@ -567,14 +715,17 @@ impl Dialog {
 
         let mut y = inner.y;
 
-        // Message
-        if !self.message.is_empty() && y < inner.bottom() {
+        // Message, wrapped to the interior width
+        for line in self.message_lines(inner.width) {
+            if y >= inner.bottom() {
+                break;
+            }
             self.draw_centered_text(
                 frame,
                 inner.x,
                 y,
                 inner.width,
-                &self.message,
+                &line,
                 self.config.message_style,
             );
             y += 1;
@@ -708,14 +859,15 @@ impl StatefulWidget for Dialog {
             return;
         }
 
-        // Calculate content area
-        let content_height = self.content_height();
+        // Resolve the dialog size against the available frame, then pin the
+        // modal to exactly that size so wrapping and height agree.
+        let size = self.desired_size(Size::new(area.width, area.height));
         let config = self.config.modal_config.clone().size(
             ModalSizeConstraints::new()
-                .min_width(30)
-                .max_width(60)
-                .min_height(content_height)
-                .max_height(content_height + 4),
+                .min_width(size.width)
+                .max_width(size.width)
+                .min_height(size.height)
+                .max_height(size.height),
         );
 
         // Create a wrapper widget for the dialog content
@@ -865,6 +1017,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let result = dialog.handle_event(&event, &mut state, None);
         assert_eq!(result, Some(DialogResult::Dismissed));
@@ -880,6 +1033,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let result = dialog.handle_event(&event, &mut state, None);
         assert_eq!(result, Some(DialogResult::Ok));
@@ -940,6 +1094,7 @@ mod tests {
             code: KeyCode::Tab,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         dialog.handle_event(&tab, &mut state, None);
@@ -961,6 +1116,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = dialog.handle_event(&enter, &mut state, None);
@@ -1096,6 +1252,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         // Events on a closed dialog return None immediately
         let result = dialog.handle_event(&enter, &mut state, None);
@@ -1114,6 +1271,7 @@ mod tests {
             code: KeyCode::Tab,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         // Tab 1: input -> button 0 (OK)
@@ -1141,6 +1299,7 @@ mod tests {
             code: KeyCode::Tab,
             modifiers: Modifiers::SHIFT,
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         // Shift+Tab from input -> last button (Cancel, index 1)
@@ -1170,11 +1329,13 @@ mod tests {
             code: KeyCode::Right,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         let left = Event::Key(KeyEvent {
             code: KeyCode::Left,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         // Right: 0 -> 1
@@ -1206,6 +1367,7 @@ mod tests {
             code: KeyCode::Right,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         dialog.handle_event(&right, &mut state, None);
@@ -1224,6 +1386,7 @@ mod tests {
             code: KeyCode::Backspace,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         // Backspace on empty input should not panic
@@ -1241,6 +1404,7 @@ mod tests {
             code: KeyCode::Delete,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         dialog.handle_event(&delete, &mut state, None);
@@ -1257,6 +1421,7 @@ mod tests {
                 code: KeyCode::Char(c),
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             dialog.handle_event(&event, &mut state, None);
         }
@@ -1275,6 +1440,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = dialog.handle_event(&enter, &mut state, None);
@@ -1296,6 +1462,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = dialog.handle_event(&enter, &mut state, None);
@@ -1354,7 +1521,7 @@ mod tests {
     #[test]
     fn edge_content_height_alert() {
         let dialog = Dialog::alert("Title", "Message");
-        let h = dialog.content_height();
+        let h = dialog.content_height(40);
         // 2 (borders) + 1 (title) + 1 (message) + 1 (spacing) + 1 (buttons) = 6
         assert_eq!(h, 6);
     }
@@ -1362,7 +1529,7 @@ mod tests {
     #[test]
     fn edge_content_height_prompt() {
         let dialog = Dialog::prompt("Title", "Message");
-        let h = dialog.content_height();
+        let h = dialog.content_height(40);
         // 2 (borders) + 1 (title) + 1 (message) + 1 (spacing) + 1 (input) + 1 (input spacing) + 1 (buttons) = 8
         assert_eq!(h, 8);
     }
@@ -1370,7 +1537,7 @@ mod tests {
     #[test]
     fn edge_content_height_empty_title_and_message() {
         let dialog = Dialog::alert("", "");
-        let h = dialog.content_height();
+        let h = dialog.content_height(40);
         // 2 (borders) + 0 (no title) + 0 (no message) + 1 (spacing) + 1 (buttons) = 4
         assert_eq!(h, 4);
     }
@@ -1471,6 +1638,7 @@ mod tests {
             code: KeyCode::Tab,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         dialog.handle_event(&tab, &mut state, None);
         // Non-prompt dialog should clear input_focused
@@ -1488,6 +1656,7 @@ mod tests {
             code: KeyCode::Char('x'),
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Release,
+            ch: None,
         });
         dialog.handle_event(&release, &mut state, None);
         assert!(state.input_value.is_empty());
@@ -1508,6 +1677,7 @@ mod tests {
             code: KeyCode::Enter,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
         // No focused button and no primary → activate_button returns None
         let result = dialog.handle_event(&enter, &mut state, None);
@@ -1605,6 +1775,7 @@ mod tests {
         let kinds = [
             DialogKind::Alert,
             DialogKind::Confirm,
+            DialogKind::Error,
             DialogKind::Prompt,
             DialogKind::Custom,
         ];
@@ -1615,4 +1786,239 @@ mod tests {
         }
         assert_ne!(DialogKind::Alert, DialogKind::Confirm);
     }
+
+    fn row_text(frame: &Frame, y: u16) -> String {
+        let width = frame.buffer.width();
+        let mut actual = String::new();
+        for x in 0..width {
+            let ch = frame
+                .buffer
+                .get(x, y)
+                .and_then(|cell| cell.content.as_char())
+                .unwrap_or(' ');
+            actual.push(ch);
+        }
+        actual
+    }
+
+    #[test]
+    fn sizing_narrow_width_wraps_message() {
+        let dialog = Dialog::confirm(
+            "Delete?",
+            "This will permanently remove the selected file from disk.",
+        );
+        let wide = dialog.desired_size(Size::new(120, 40));
+        let narrow = dialog.desired_size(Size::new(40, 40));
+
+        // Narrow frame caps width at 80% of 40 = 32; the message no longer
+        // fits on one line, so height grows.
+        assert_eq!(narrow.width, 32);
+        assert!(narrow.width < wide.width);
+        assert!(narrow.height > wide.height);
+        assert_eq!(
+            narrow.height,
+            dialog.content_height(narrow.width - 2).min(40)
+        );
+    }
+
+    #[test]
+    fn sizing_min_width_follows_button_row() {
+        let dialog = Dialog::custom("T", "m")
+            .custom_button("Save and Continue", "save")
+            .custom_button("Discard Everything", "discard")
+            .build();
+        let size = dialog.desired_size(Size::new(200, 40));
+        // [ Save and Continue ] + 2 + [ Discard Everything ] plus chrome
+        assert!(size.width >= dialog.button_row_width() + 4);
+    }
+
+    #[test]
+    fn sizing_never_exceeds_frame() {
+        let dialog = Dialog::alert("A very long title indeed", "short");
+        let size = dialog.desired_size(Size::new(10, 5));
+        // Width may exceed a frame smaller than the minimum, but height
+        // is always clamped to what is available.
+        assert!(size.height <= 5);
+        assert!(size.width >= MIN_DIALOG_WIDTH);
+    }
+
+    #[test]
+    fn button_cycling_wraps_around() {
+        let dialog = Dialog::confirm("T", "m");
+        let mut state = DialogState::new();
+        state.focused_button = Some(1);
+
+        // Right from the last button wraps to the first.
+        dialog.navigate_buttons(&mut state, true);
+        assert_eq!(state.focused_button, Some(0));
+
+        // Left from the first button wraps to the last.
+        dialog.navigate_buttons(&mut state, false);
+        assert_eq!(state.focused_button, Some(1));
+    }
+
+    #[test]
+    fn escape_returns_configured_result() {
+        let dialog = Dialog::confirm("T", "m").escape_result(DialogResult::Cancel);
+        let mut state = DialogState::new();
+        let event = Event::Key(KeyEvent {
+            code: KeyCode::Escape,
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        });
+        let result = dialog.handle_event(&event, &mut state, None);
+        assert_eq!(result, Some(DialogResult::Cancel));
+        assert!(!state.is_open());
+        assert_eq!(state.take_result(), Some(DialogResult::Cancel));
+    }
+
+    #[test]
+    fn space_activates_focused_button() {
+        let dialog = Dialog::confirm("T", "m");
+        let mut state = DialogState::new();
+        state.focused_button = Some(1);
+        let event = Event::Key(KeyEvent {
+            code: KeyCode::Char(' '),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        });
+        let result = dialog.handle_event(&event, &mut state, None);
+        assert_eq!(result, Some(DialogResult::Cancel));
+    }
+
+    #[test]
+    fn space_types_into_focused_prompt_input() {
+        let dialog = Dialog::prompt("T", "m");
+        let mut state = DialogState::new();
+        state.input_focused = true;
+        let event = Event::Key(KeyEvent {
+            code: KeyCode::Char(' '),
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        });
+        let result = dialog.handle_event(&event, &mut state, None);
+        assert_eq!(result, None);
+        assert_eq!(state.input_value, " ");
+        assert!(state.is_open());
+    }
+
+    #[test]
+    fn focus_trap_tab_never_escapes() {
+        let dialog = Dialog::confirm("T", "m");
+        let mut state = DialogState::new();
+        state.focused_button = Some(0);
+        let tab = Event::Key(KeyEvent {
+            code: KeyCode::Tab,
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+            ch: None,
+        });
+
+        // However many times Tab fires, focus stays on a dialog button.
+        for _ in 0..7 {
+            let result = dialog.handle_event(&tab, &mut state, None);
+            assert_eq!(result, None);
+            let idx = state.focused_button.expect("focus left the dialog");
+            assert!(idx < 2);
+            assert!(state.is_open());
+        }
+    }
+
+    #[test]
+    fn error_dialog_uses_theme_error_token() {
+        let dialog = Dialog::error("Failure", "Could not write file.");
+        assert_eq!(dialog.config.kind, DialogKind::Error);
+        assert_eq!(dialog.buttons.len(), 1);
+
+        let theme = Theme::default().resolve(true);
+        let rgb = theme.error.to_rgb();
+        let expected = PackedRgba::rgb(rgb.r, rgb.g, rgb.b);
+        assert_eq!(dialog.config.title_style.fg, Some(expected));
+        assert_eq!(dialog.config.primary_button_style.fg, Some(expected));
+    }
+
+    #[test]
+    fn snapshot_over_busy_background() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(60, 16, &mut pool);
+
+        // Busy background: every cell filled with a visible glyph.
+        let fill = "x".repeat(60);
+        for y in 0..16 {
+            draw_text_span(&mut frame, 0, y, &fill, Style::new(), 60);
+        }
+
+        let dialog = Dialog::confirm("Quit?", "Unsaved changes will be lost.");
+        let mut state = DialogState::new();
+        dialog.render(Rect::new(0, 0, 60, 16), &mut frame, &mut state);
+
+        let all: Vec<String> = (0..16).map(|y| row_text(&frame, y)).collect();
+        let joined = all.join("\n");
+
+        // Dialog content replaced the background where it rendered.
+        assert!(joined.contains("Unsaved changes will be lost."));
+        assert!(joined.contains("[ OK ]"));
+        assert!(joined.contains("[ Cancel ]"));
+
+        // Corners outside the dialog keep their background glyph.
+        assert_eq!(all[0].chars().next(), Some('x'));
+        assert_eq!(all[15].chars().last(), Some('x'));
+
+        // No background glyph bleeds through inside the dialog interior.
+        let size = dialog.desired_size(Size::new(60, 16));
+        let dx = (60 - size.width) / 2;
+        let dy = (16 - size.height) / 2;
+        for y in dy + 1..dy + size.height - 1 {
+            let interior: String = all[y as usize]
+                .chars()
+                .skip((dx + 1) as usize)
+                .take((size.width - 2) as usize)
+                .collect();
+            assert!(
+                !interior.contains('x'),
+                "background bled into dialog row {y}: {interior:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn theme_cascade_overrides_theme_derived_styles() {
+        use ftui_style::Selector;
+
+        let theme = Theme::default().resolve(true);
+        let cascade = StyleCascade::new();
+        let danger = PackedRgba::rgb(200, 0, 0);
+        cascade.install(
+            Selector::kind("dialog-title").id("confirm-delete"),
+            Style::new().fg(danger),
+        );
+
+        let plain = Dialog::confirm("Delete?", "Sure?").with_theme_cascade(
+            &theme,
+            &cascade,
+            Some("other"),
+            None,
+        );
+        let themed_title = plain.config.title_style;
+
+        let overridden = Dialog::confirm("Delete?", "Sure?").with_theme_cascade(
+            &theme,
+            &cascade,
+            Some("confirm-delete"),
+            None,
+        );
+        assert_eq!(overridden.config.title_style.fg, Some(danger));
+        // Theme-derived attributes (bold) survive as the base layer.
+        assert!(overridden.config.title_style.has_attr(StyleFlags::BOLD));
+        // The un-matched dialog keeps the pure theme style.
+        assert_ne!(themed_title.fg, Some(danger));
+        // Button style untouched by the title rule.
+        assert_eq!(
+            overridden.config.primary_button_style,
+            plain.config.primary_button_style
+        );
+    }
 }
@@ -388,6 +388,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = modals.handle_event(&escape);
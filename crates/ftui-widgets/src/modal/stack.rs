@@ -1055,6 +1055,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         // Escape should close top modal (id2)
@@ -1137,6 +1138,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         // Escape should NOT close the modal
@@ -1186,6 +1188,7 @@ mod tests {
             code: KeyCode::Escape,
             modifiers: Modifiers::empty(),
             kind: KeyEventKind::Press,
+            ch: None,
         });
 
         let result = stack.handle_event(&escape);
@@ -1374,6 +1377,7 @@ mod tests {
                 code: KeyCode::Escape,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             let result = integrator.handle_event(&escape);
 
@@ -1535,6 +1539,7 @@ mod tests {
                 code: KeyCode::Escape,
                 modifiers: Modifiers::empty(),
                 kind: KeyEventKind::Press,
+                ch: None,
             });
             let _ = integrator.handle_event(&escape);
         }
@@ -0,0 +1,524 @@
+#![forbid(unsafe_code)]
+
+//! Sectioned list with sticky group headers.
+//!
+//! Groups list items into sections (files by directory, logs by day) whose
+//! header stays pinned to the top row of the viewport while the section's
+//! items scroll beneath it; when the next section's header reaches the top
+//! it pushes the pinned one away. Headers are not selectable — keyboard
+//! navigation skips them — and [`SectionedListState::selected`] indexes
+//! only real items, so callers can keep using plain item indices.
+
+use ftui_core::event::{KeyCode, KeyEvent};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+use crate::{StatefulWidget, draw_text_span, set_style_area};
+
+/// One section: a header line plus its items.
+#[derive(Debug, Clone, Default)]
+pub struct ListSection {
+    /// Header text, pinned while the section scrolls.
+    pub header: String,
+    /// Item lines (one visual row each).
+    pub items: Vec<String>,
+}
+
+impl ListSection {
+    /// Create a section from a header and items.
+    #[must_use]
+    pub fn new(
+        header: impl Into<String>,
+        items: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            items: items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A flattened visual row of a sectioned list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    /// Header of section `section`.
+    Header { section: usize },
+    /// Item with global (headers excluded) index `item`.
+    Item { item: usize, section: usize },
+}
+
+/// List widget with sticky section headers.
+#[derive(Debug, Clone, Default)]
+pub struct SectionedList {
+    sections: Vec<ListSection>,
+    style: Style,
+    header_style: Style,
+    highlight_style: Style,
+    highlight_symbol: String,
+    /// Render sections with no items (header only).
+    show_empty_sections: bool,
+}
+
+impl SectionedList {
+    /// Create a sectioned list.
+    #[must_use]
+    pub fn new(sections: impl IntoIterator<Item = ListSection>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+            style: Style::default(),
+            header_style: Style::new().bold(),
+            highlight_style: Style::new().reverse(),
+            highlight_symbol: String::new(),
+            show_empty_sections: false,
+        }
+    }
+
+    /// Set the base style (builder).
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the header style (builder).
+    #[must_use]
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the selected-item style (builder).
+    #[must_use]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Set a prefix drawn before the selected item (builder).
+    #[must_use]
+    pub fn highlight_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.highlight_symbol = symbol.into();
+        self
+    }
+
+    /// Show sections with no items as a bare header (builder).
+    #[must_use]
+    pub fn show_empty_sections(mut self, show: bool) -> Self {
+        self.show_empty_sections = show;
+        self
+    }
+
+    /// Total number of selectable items (headers excluded).
+    #[must_use]
+    pub fn item_count(&self) -> usize {
+        self.sections.iter().map(|s| s.items.len()).sum()
+    }
+
+    /// Flatten visible sections into visual rows.
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        let mut item = 0usize;
+        for (section, s) in self.sections.iter().enumerate() {
+            if s.items.is_empty() && !self.show_empty_sections {
+                continue;
+            }
+            rows.push(Row::Header { section });
+            for _ in &s.items {
+                rows.push(Row::Item { item, section });
+                item += 1;
+            }
+        }
+        rows
+    }
+
+    /// Visual row index of a global item index.
+    fn visual_row_of(rows: &[Row], item: usize) -> Option<usize> {
+        rows.iter()
+            .position(|row| matches!(row, Row::Item { item: i, .. } if *i == item))
+    }
+
+    /// Text for a visual row.
+    fn row_text(&self, row: Row) -> &str {
+        match row {
+            Row::Header { section } => &self.sections[section].header,
+            Row::Item { section, item } => {
+                // Translate the global item index back into the section.
+                let before: usize = self
+                    .sections
+                    .iter()
+                    .take(section)
+                    .map(|s| s.items.len())
+                    .sum();
+                &self.sections[section].items[item - before]
+            }
+        }
+    }
+
+    /// Handle a navigation key. Returns `true` when handled.
+    pub fn handle_key(&self, state: &mut SectionedListState, key: &KeyEvent) -> bool {
+        let count = self.item_count();
+        if count == 0 {
+            return false;
+        }
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                state.select(Some(match state.selected {
+                    Some(i) => (i + 1).min(count - 1),
+                    None => 0,
+                }));
+                true
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                state.select(Some(match state.selected {
+                    Some(i) => i.saturating_sub(1),
+                    None => 0,
+                }));
+                true
+            }
+            KeyCode::Home => {
+                state.select(Some(0));
+                true
+            }
+            KeyCode::End => {
+                state.select(Some(count - 1));
+                true
+            }
+            KeyCode::PageDown | KeyCode::PageUp => {
+                // Page moves span viewport rows, so header rows count.
+                let rows = self.rows();
+                let page = usize::from(state.last_viewport_height.max(1));
+                let current = state.selected.unwrap_or(0);
+                let visual = Self::visual_row_of(&rows, current).unwrap_or(0);
+                let target_visual = if key.code == KeyCode::PageDown {
+                    (visual + page).min(rows.len().saturating_sub(1))
+                } else {
+                    visual.saturating_sub(page)
+                };
+                let target = nearest_item(&rows, target_visual, key.code == KeyCode::PageDown);
+                if let Some(item) = target {
+                    state.select(Some(item));
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Find the item nearest to a visual row, searching forward or backward
+/// (headers are skipped in the search direction, then the other way).
+fn nearest_item(rows: &[Row], visual: usize, forward: bool) -> Option<usize> {
+    let pick = |row: &Row| match row {
+        Row::Item { item, .. } => Some(*item),
+        Row::Header { .. } => None,
+    };
+    if forward {
+        rows[visual.min(rows.len().saturating_sub(1))..]
+            .iter()
+            .find_map(pick)
+            .or_else(|| rows[..visual.min(rows.len())].iter().rev().find_map(pick))
+    } else {
+        rows[..=visual.min(rows.len().saturating_sub(1))]
+            .iter()
+            .rev()
+            .find_map(pick)
+            .or_else(|| rows[visual.min(rows.len())..].iter().find_map(pick))
+    }
+}
+
+/// Render state for [`SectionedList`].
+#[derive(Debug, Clone, Default)]
+pub struct SectionedListState {
+    /// Selected item index over real items only (headers excluded).
+    selected: Option<usize>,
+    /// Scroll offset in visual rows (headers included).
+    offset: usize,
+    /// Viewport height from the last render (for paging).
+    last_viewport_height: u16,
+    /// Scroll the selection into view on the next render.
+    scroll_into_view: bool,
+}
+
+impl SectionedListState {
+    /// Select an item by its index over real items, or clear the selection.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        self.scroll_into_view = true;
+    }
+
+    /// The selected item index (over real items only).
+    #[must_use]
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Current scroll offset in visual rows.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Set the scroll offset in visual rows.
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+        self.scroll_into_view = false;
+    }
+
+    /// Keep the same item selected after `count` items were inserted
+    /// before item index `at` (e.g. a new section above the selection).
+    pub fn notify_items_inserted(&mut self, at: usize, count: usize) {
+        if let Some(selected) = &mut self.selected
+            && *selected >= at
+        {
+            *selected += count;
+            self.scroll_into_view = true;
+        }
+    }
+}
+
+impl StatefulWidget for SectionedList {
+    type State = SectionedListState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        set_style_area(&mut frame.buffer, area, self.style);
+
+        let rows = self.rows();
+        if rows.is_empty() {
+            return;
+        }
+        let height = usize::from(area.height);
+        state.last_viewport_height = area.height;
+
+        // Clamp the selection to real items.
+        let count = self.item_count();
+        if let Some(selected) = state.selected
+            && selected >= count
+        {
+            state.selected = count.checked_sub(1);
+        }
+
+        // Bring the selection into view, keeping it clear of the pinned
+        // header row.
+        if state.scroll_into_view
+            && let Some(selected) = state.selected
+            && let Some(visual) = Self::visual_row_of(&rows, selected)
+        {
+            let max_offset = rows.len().saturating_sub(height);
+            if visual < state.offset + 1 {
+                // Leave the top row for the pinned header (row 0 shows it
+                // anyway unless the offset row is a header itself).
+                state.offset = visual.saturating_sub(1);
+            } else if visual >= state.offset + height {
+                state.offset = visual + 1 - height;
+            }
+            state.offset = state.offset.min(max_offset);
+            state.scroll_into_view = false;
+        }
+        state.offset = state.offset.min(rows.len().saturating_sub(1));
+
+        // Current section for the pinned header: the section owning the
+        // first visible row.
+        let pinned_section = match rows[state.offset] {
+            Row::Header { section } | Row::Item { section, .. } => section,
+        };
+
+        for y in 0..height {
+            let Some(&row) = rows.get(state.offset + y) else {
+                break;
+            };
+            let (text, style, selected) = match row {
+                Row::Header { .. } => (self.row_text(row), self.header_style, false),
+                Row::Item { item, .. } => {
+                    (self.row_text(row), self.style, state.selected == Some(item))
+                }
+            };
+            let style = if selected {
+                self.highlight_style
+            } else {
+                style
+            };
+            let mut x = area.x;
+            if selected && !self.highlight_symbol.is_empty() {
+                x = draw_text_span(
+                    frame,
+                    x,
+                    area.y + y as u16,
+                    &self.highlight_symbol,
+                    style,
+                    area.right(),
+                );
+            }
+            draw_text_span(frame, x, area.y + y as u16, text, style, area.right());
+        }
+
+        // Pin the current section's header over the top row while its items
+        // scroll beneath. When the offset row is itself a header, it is the
+        // pinned header already (this is the push-up transition moment).
+        if !matches!(rows[state.offset], Row::Header { .. }) {
+            let header = &self.sections[pinned_section].header;
+            // Clear the row with the header style before overdrawing.
+            let top = Rect::new(area.x, area.y, area.width, 1);
+            set_style_area(&mut frame.buffer, top, self.header_style);
+            for x in area.x..area.right() {
+                if let Some(cell) = frame.buffer.get(x, area.y) {
+                    let mut blank = *cell;
+                    blank.content = ftui_render::cell::CellContent::from_char(' ');
+                    frame.buffer.set(x, area.y, blank);
+                }
+            }
+            draw_text_span(
+                frame,
+                area.x,
+                area.y,
+                header,
+                self.header_style,
+                area.right(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn sample() -> SectionedList {
+        SectionedList::new([
+            ListSection::new("DAY 1", ["a0", "a1", "a2"]),
+            ListSection::new("DAY 2", ["b0", "b1"]),
+            ListSection::new("DAY 3", ["c0"]),
+        ])
+    }
+
+    fn row_text(frame: &Frame, width: u16, y: u16) -> String {
+        (0..width)
+            .filter_map(|x| frame.buffer.get(x, y).and_then(|c| c.content.as_char()))
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    fn render_at(list: &SectionedList, state: &mut SectionedListState, height: u16) -> Vec<String> {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, height, &mut pool);
+        list.render(Rect::new(0, 0, 10, height), &mut frame, state);
+        (0..height).map(|y| row_text(&frame, 10, y)).collect()
+    }
+
+    #[test]
+    fn pinned_header_correct_at_every_offset() {
+        let list = sample();
+        // Visual rows: H1 a0 a1 a2 H2 b0 b1 H3 c0  (9 rows)
+        let expected_pins = [
+            "DAY 1", // offset 0: header itself at top
+            "DAY 1", // offset 1: a0 under pinned DAY 1
+            "DAY 1", "DAY 1", // a1, a2
+            "DAY 2", // offset 4: header row itself (push-up moment)
+            "DAY 2", "DAY 2", // b0, b1
+            "DAY 3", // offset 7: header itself
+            "DAY 3", // offset 8: c0 under pinned DAY 3
+        ];
+        for (offset, expected) in expected_pins.iter().enumerate() {
+            let mut state = SectionedListState::default();
+            state.set_offset(offset);
+            let rows = render_at(&list, &mut state, 4);
+            assert_eq!(&rows[0], expected, "offset {offset}");
+        }
+    }
+
+    #[test]
+    fn items_scroll_beneath_pinned_header() {
+        let list = sample();
+        let mut state = SectionedListState::default();
+        state.set_offset(2); // a1 at the top position
+        let rows = render_at(&list, &mut state, 3);
+        assert_eq!(rows, vec!["DAY 1", "a2", "DAY 2"]);
+    }
+
+    #[test]
+    fn selection_skips_headers_in_both_directions() {
+        let list = sample();
+        let mut state = SectionedListState::default();
+        state.select(Some(2)); // a2, last item of section 1
+
+        // Down crosses the DAY 2 header straight to b0.
+        list.handle_key(&mut state, &KeyEvent::new(KeyCode::Down));
+        assert_eq!(state.selected(), Some(3));
+
+        // Up crosses back over the header to a2.
+        list.handle_key(&mut state, &KeyEvent::new(KeyCode::Up));
+        assert_eq!(state.selected(), Some(2));
+
+        // Home/End land on real items.
+        list.handle_key(&mut state, &KeyEvent::new(KeyCode::End));
+        assert_eq!(state.selected(), Some(5)); // c0
+        list.handle_key(&mut state, &KeyEvent::new(KeyCode::Home));
+        assert_eq!(state.selected(), Some(0)); // a0
+    }
+
+    #[test]
+    fn page_movement_accounts_for_header_rows() {
+        let list = sample();
+        let mut state = SectionedListState::default();
+        // Render once to record the viewport height.
+        let _ = render_at(&list, &mut state, 4);
+        state.select(Some(0)); // a0 at visual row 1
+
+        list.handle_key(&mut state, &KeyEvent::new(KeyCode::PageDown));
+        // a0 is visual row 1; +4 rows = visual row 5 = b0 (item 3): the
+        // DAY 2 header row was counted but not selected.
+        assert_eq!(state.selected(), Some(3));
+
+        list.handle_key(&mut state, &KeyEvent::new(KeyCode::PageUp));
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn selection_stable_when_section_inserted_above() {
+        let mut sections = vec![
+            ListSection::new("DAY 2", ["b0", "b1"]),
+            ListSection::new("DAY 3", ["c0"]),
+        ];
+        let mut state = SectionedListState::default();
+        state.select(Some(2)); // c0
+
+        // Insert a new section above with 3 items.
+        sections.insert(0, ListSection::new("DAY 1", ["a0", "a1", "a2"]));
+        state.notify_items_inserted(0, 3);
+        assert_eq!(state.selected(), Some(5), "same item, shifted index");
+
+        let list = SectionedList::new(sections);
+        assert_eq!(list.item_count(), 6);
+        // The selected row still renders highlighted as c0.
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 9, &mut pool);
+        list.render(Rect::new(0, 0, 10, 9), &mut frame, &mut state);
+        let texts: Vec<String> = (0..9).map(|y| row_text(&frame, 10, y)).collect();
+        assert!(texts.contains(&"c0".to_string()));
+    }
+
+    #[test]
+    fn empty_sections_hidden_or_shown_per_config() {
+        let sections = || {
+            [
+                ListSection::new("FULL", ["x"]),
+                ListSection::new("EMPTY", Vec::<String>::new()),
+                ListSection::new("ALSO FULL", ["y"]),
+            ]
+        };
+        let hidden = SectionedList::new(sections());
+        let mut state = SectionedListState::default();
+        let rows = render_at(&hidden, &mut state, 6);
+        assert!(!rows.contains(&"EMPTY".to_string()));
+
+        let shown = SectionedList::new(sections()).show_empty_sections(true);
+        let mut state = SectionedListState::default();
+        let rows = render_at(&shown, &mut state, 6);
+        assert!(rows.contains(&"EMPTY".to_string()));
+    }
+}
@@ -0,0 +1,681 @@
+#![forbid(unsafe_code)]
+
+//! Mouse/keyboard text selection for read-only text panes.
+//!
+//! The terminal layer has a rich selection system; plain text widgets
+//! (log viewer, help screen, detail pane) had none — users drag the
+//! mouse and nothing happens. [`SelectableTextState`] is a widget-local
+//! selection capability: positions live in **content coordinates**
+//! (logical line + character), so scrolling while selecting stays
+//! anchored to content, and wrapped lines copy as their logical text
+//! (no newlines injected at soft wraps unless asked).
+//!
+//! The host widget supplies its lines and a [`TextGeometry`] describing
+//! where/how they are displayed; the state maps pointer cells through
+//! the soft-wrap layout. Double-click selects a word, triple-click the
+//! line, Shift+click and Shift+arrows extend, and completing a drag
+//! emits [`SelectionEvent::Completed`] with the text so the app can
+//! trigger its clipboard command (OSC 52 or host clipboard).
+
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::buffer::Buffer;
+use ftui_style::Style;
+use unicode_width::UnicodeWidthChar;
+use web_time::{Duration, Instant};
+
+use crate::set_style_area;
+
+/// Multi-click window (double/triple click detection).
+const MULTI_CLICK_WINDOW: Duration = Duration::from_millis(400);
+
+/// A position in content coordinates: logical line + character index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TextPosition {
+    pub line: usize,
+    pub ch: usize,
+}
+
+/// Where and how the host displays its lines.
+#[derive(Debug, Clone, Copy)]
+pub struct TextGeometry {
+    /// The rect the text is drawn into.
+    pub area: Rect,
+    /// Soft-wrap width in cells (0 = no wrapping; lines clip).
+    pub wrap_width: u16,
+    /// First visual row currently shown (scroll offset).
+    pub scroll_row: usize,
+}
+
+/// Selection lifecycle events for the host app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionEvent {
+    /// The selection changed (drag in progress, keyboard extension).
+    Changed,
+    /// A selection gesture completed (mouse released); carries the
+    /// selected text so the app can copy it (OSC 52 / host clipboard).
+    Completed(String),
+}
+
+/// One visual row of the wrapped layout: a slice of a logical line.
+#[derive(Debug, Clone, Copy)]
+struct VisualRow {
+    line: usize,
+    /// Character range of the logical line shown on this row.
+    start: usize,
+    end: usize,
+}
+
+/// Widget-local selection state (see the module docs).
+#[derive(Debug, Clone, Default)]
+pub struct SelectableTextState {
+    anchor: Option<TextPosition>,
+    head: Option<TextPosition>,
+    dragging: bool,
+    /// Multi-click tracking: last press time, position, and count.
+    last_click: Option<(Instant, TextPosition, u8)>,
+}
+
+impl SelectableTextState {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The normalized selection (start ≤ end), if any.
+    #[must_use]
+    pub fn selection(&self) -> Option<(TextPosition, TextPosition)> {
+        let (anchor, head) = (self.anchor?, self.head?);
+        Some(if anchor <= head {
+            (anchor, head)
+        } else {
+            (head, anchor)
+        })
+    }
+
+    /// Whether a drag is capturing the mouse.
+    #[must_use]
+    pub fn dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Clear the selection.
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.head = None;
+        self.dragging = false;
+    }
+
+    /// Place the keyboard cursor (for Shift+arrow extension) without
+    /// selecting anything.
+    pub fn set_cursor(&mut self, position: TextPosition) {
+        self.anchor = None;
+        self.head = Some(position);
+    }
+
+    /// Extract the selected text from the host's lines.
+    ///
+    /// `preserve_soft_wraps` injects a newline at each soft-wrap point
+    /// of the geometry instead of copying the logical content verbatim
+    /// (off by default semantics: logical content, real newlines only).
+    #[must_use]
+    pub fn selected_text(
+        &self,
+        lines: &[&str],
+        preserve_soft_wraps: Option<&TextGeometry>,
+    ) -> Option<String> {
+        let (start, end) = self.selection()?;
+        if start == end {
+            return None;
+        }
+        let mut out = String::new();
+        let last = end.line.min(lines.len().saturating_sub(1));
+        for (line_idx, line) in lines.iter().enumerate().take(last + 1).skip(start.line) {
+            let chars: Vec<char> = line.chars().collect();
+            let from = if line_idx == start.line { start.ch } else { 0 };
+            let to = if line_idx == end.line {
+                end.ch.min(chars.len())
+            } else {
+                chars.len()
+            };
+            if line_idx > start.line {
+                out.push('\n');
+            }
+            match preserve_soft_wraps {
+                Some(geometry) if geometry.wrap_width > 0 => {
+                    let width = usize::from(geometry.wrap_width).max(1);
+                    for (offset, ch) in chars[from.min(to)..to].iter().enumerate() {
+                        let absolute = from + offset;
+                        if absolute > from && absolute % width == 0 {
+                            out.push('\n');
+                        }
+                        out.push(*ch);
+                    }
+                }
+                _ => out.extend(&chars[from.min(to)..to]),
+            }
+        }
+        Some(out)
+    }
+
+    // ── Input ────────────────────────────────────────────────────────
+
+    /// Mouse handling: press starts (or Shift-extends) a selection,
+    /// drag moves the head (tracking content through the *current*
+    /// scroll), release completes with the text. Double/triple press
+    /// selects word/line. `now` drives multi-click detection so replays
+    /// stay deterministic.
+    pub fn handle_mouse(
+        &mut self,
+        event: &MouseEvent,
+        lines: &[&str],
+        geometry: &TextGeometry,
+        now: Instant,
+    ) -> Option<SelectionEvent> {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let position = position_at(lines, geometry, event.x, event.y)?;
+                if event.modifiers.contains(Modifiers::SHIFT) && self.anchor.is_some() {
+                    // Shift+click: extend to the clicked position.
+                    self.head = Some(position);
+                    self.dragging = true;
+                    return Some(SelectionEvent::Changed);
+                }
+                let clicks = match self.last_click {
+                    Some((at, last_pos, count))
+                        if now.saturating_duration_since(at) <= MULTI_CLICK_WINDOW
+                            && last_pos == position =>
+                    {
+                        count.saturating_add(1)
+                    }
+                    _ => 1,
+                };
+                self.last_click = Some((now, position, clicks));
+                match clicks {
+                    1 => {
+                        self.anchor = Some(position);
+                        self.head = Some(position);
+                        self.dragging = true;
+                        Some(SelectionEvent::Changed)
+                    }
+                    2 => {
+                        let (start, end) = word_bounds(lines, position);
+                        self.anchor = Some(start);
+                        self.head = Some(end);
+                        self.dragging = true;
+                        Some(SelectionEvent::Changed)
+                    }
+                    _ => {
+                        let line_len = lines.get(position.line).map_or(0, |l| l.chars().count());
+                        self.anchor = Some(TextPosition {
+                            line: position.line,
+                            ch: 0,
+                        });
+                        self.head = Some(TextPosition {
+                            line: position.line,
+                            ch: line_len,
+                        });
+                        self.dragging = true;
+                        Some(SelectionEvent::Changed)
+                    }
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                // Clamp the pointer into the area vertically so dragging
+                // past the edges still resolves; the host scrolls and the
+                // content anchor holds.
+                let x = event
+                    .x
+                    .clamp(geometry.area.x, geometry.area.right().saturating_sub(1));
+                let y = event
+                    .y
+                    .clamp(geometry.area.y, geometry.area.bottom().saturating_sub(1));
+                let position = position_at(lines, geometry, x, y)?;
+                if self.head != Some(position) {
+                    self.head = Some(position);
+                    return Some(SelectionEvent::Changed);
+                }
+                None
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                self.selected_text(lines, None)
+                    .filter(|text| !text.is_empty())
+                    .map(SelectionEvent::Completed)
+            }
+            _ => None,
+        }
+    }
+
+    /// Keyboard: Shift+arrows extend from the cursor/head.
+    pub fn handle_key(&mut self, key: &KeyEvent, lines: &[&str]) -> Option<SelectionEvent> {
+        if !key.modifiers.contains(Modifiers::SHIFT) {
+            return None;
+        }
+        let head = self.head?;
+        let moved = match key.code {
+            KeyCode::Left => step_left(lines, head),
+            KeyCode::Right => step_right(lines, head),
+            KeyCode::Up => TextPosition {
+                line: head.line.saturating_sub(1),
+                ch: head.ch,
+            },
+            KeyCode::Down => TextPosition {
+                line: (head.line + 1).min(lines.len().saturating_sub(1)),
+                ch: head.ch,
+            },
+            _ => return None,
+        };
+        let moved = clamp_position(lines, moved);
+        if self.anchor.is_none() {
+            self.anchor = Some(head);
+        }
+        if moved != head {
+            self.head = Some(moved);
+            Some(SelectionEvent::Changed)
+        } else {
+            None
+        }
+    }
+
+    // ── Rendering ────────────────────────────────────────────────────
+
+    /// Overlay the selection onto visible rows with the given style
+    /// (use the theme's selection-bg token).
+    pub fn render_highlight(
+        &self,
+        buffer: &mut Buffer,
+        lines: &[&str],
+        geometry: &TextGeometry,
+        style: Style,
+    ) {
+        let Some((start, end)) = self.selection() else {
+            return;
+        };
+        let rows = layout(lines, geometry, viewport_rows(geometry));
+        let visible = rows
+            .iter()
+            .skip(geometry.scroll_row)
+            .take(usize::from(geometry.area.height));
+        for (screen_row, row) in visible.enumerate() {
+            let row_start = TextPosition {
+                line: row.line,
+                ch: row.start,
+            };
+            let row_end = TextPosition {
+                line: row.line,
+                ch: row.end,
+            };
+            if row_end < start || row_start > end {
+                continue;
+            }
+            let sel_from = if start > row_start {
+                start.ch
+            } else {
+                row.start
+            };
+            let sel_to = if end < row_end { end.ch } else { row.end };
+            if sel_from >= sel_to {
+                continue;
+            }
+            let line_chars: Vec<char> = lines[row.line].chars().collect();
+            let x0 = geometry.area.x + column_width(&line_chars[row.start..sel_from]);
+            let x1 = geometry.area.x + column_width(&line_chars[row.start..sel_to]);
+            let y = geometry.area.y + screen_row as u16;
+            let clipped_x1 = x1.min(geometry.area.right());
+            if x0 < clipped_x1 {
+                set_style_area(buffer, Rect::new(x0, y, clipped_x1 - x0, 1), style);
+            }
+        }
+    }
+}
+
+// ── Layout / mapping helpers ─────────────────────────────────────────
+
+fn column_width(chars: &[char]) -> u16 {
+    chars
+        .iter()
+        .map(|&c| UnicodeWidthChar::width(c).unwrap_or(0) as u16)
+        .sum()
+}
+
+/// Soft-wrap layout, generated only up to `max_rows` visual rows so
+/// mouse mapping and highlighting stay O(visible window), not O(buffer).
+fn layout(lines: &[&str], geometry: &TextGeometry, max_rows: usize) -> Vec<VisualRow> {
+    let mut rows = Vec::new();
+    let wrap = usize::from(geometry.wrap_width);
+    for (line_idx, line) in lines.iter().enumerate() {
+        let len = line.chars().count();
+        if wrap == 0 || len <= wrap {
+            rows.push(VisualRow {
+                line: line_idx,
+                start: 0,
+                end: len,
+            });
+        } else {
+            let mut start = 0;
+            while start < len {
+                let end = (start + wrap).min(len);
+                rows.push(VisualRow {
+                    line: line_idx,
+                    start,
+                    end,
+                });
+                start = end;
+                if rows.len() >= max_rows {
+                    return rows;
+                }
+            }
+        }
+        if rows.len() >= max_rows {
+            break;
+        }
+    }
+    rows
+}
+
+/// Visual rows needed to resolve anything inside the current viewport.
+fn viewport_rows(geometry: &TextGeometry) -> usize {
+    geometry
+        .scroll_row
+        .saturating_add(usize::from(geometry.area.height))
+        .saturating_add(1)
+}
+
+/// Map a screen cell to a content position through the wrap layout.
+fn position_at(lines: &[&str], geometry: &TextGeometry, x: u16, y: u16) -> Option<TextPosition> {
+    if !geometry.area.contains(x, y) {
+        return None;
+    }
+    let rows = layout(lines, geometry, viewport_rows(geometry));
+    let visual = geometry.scroll_row + usize::from(y - geometry.area.y);
+    let row = rows.get(visual).copied().or_else(|| rows.last().copied())?;
+    let chars: Vec<char> = lines[row.line].chars().collect();
+    // Walk display columns to a character offset.
+    let mut col = 0u16;
+    let target = x - geometry.area.x;
+    for (offset, &ch) in chars[row.start..row.end].iter().enumerate() {
+        let width = UnicodeWidthChar::width(ch).unwrap_or(0) as u16;
+        if col + width > target {
+            return Some(TextPosition {
+                line: row.line,
+                ch: row.start + offset,
+            });
+        }
+        col += width;
+    }
+    Some(TextPosition {
+        line: row.line,
+        ch: row.end,
+    })
+}
+
+fn clamp_position(lines: &[&str], position: TextPosition) -> TextPosition {
+    if lines.is_empty() {
+        return TextPosition { line: 0, ch: 0 };
+    }
+    let line = position.line.min(lines.len() - 1);
+    let len = lines[line].chars().count();
+    TextPosition {
+        line,
+        ch: position.ch.min(len),
+    }
+}
+
+fn step_left(lines: &[&str], position: TextPosition) -> TextPosition {
+    if position.ch > 0 {
+        TextPosition {
+            line: position.line,
+            ch: position.ch - 1,
+        }
+    } else if position.line > 0 {
+        let line = position.line - 1;
+        TextPosition {
+            line,
+            ch: lines[line].chars().count(),
+        }
+    } else {
+        position
+    }
+}
+
+fn step_right(lines: &[&str], position: TextPosition) -> TextPosition {
+    let len = lines
+        .get(position.line)
+        .map_or(0, |line| line.chars().count());
+    if position.ch < len {
+        TextPosition {
+            line: position.line,
+            ch: position.ch + 1,
+        }
+    } else if position.line + 1 < lines.len() {
+        TextPosition {
+            line: position.line + 1,
+            ch: 0,
+        }
+    } else {
+        position
+    }
+}
+
+/// Word characters: alphanumerics plus underscore (the widget's rule).
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Word bounds around a position (whitespace/punctuation runs count as
+/// their own "word" so double-click always selects something).
+fn word_bounds(lines: &[&str], position: TextPosition) -> (TextPosition, TextPosition) {
+    let chars: Vec<char> = lines
+        .get(position.line)
+        .map(|line| line.chars().collect())
+        .unwrap_or_default();
+    if chars.is_empty() {
+        return (position, position);
+    }
+    let at = position.ch.min(chars.len() - 1);
+    let wordish = is_word_char(chars[at]);
+    let mut start = at;
+    while start > 0 && is_word_char(chars[start - 1]) == wordish {
+        start -= 1;
+    }
+    let mut end = at + 1;
+    while end < chars.len() && is_word_char(chars[end]) == wordish {
+        end += 1;
+    }
+    (
+        TextPosition {
+            line: position.line,
+            ch: start,
+        },
+        TextPosition {
+            line: position.line,
+            ch: end,
+        },
+    )
+}
+
+// =========================================================================
+// Tests
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn geometry(width: u16, height: u16, wrap: u16, scroll: usize) -> TextGeometry {
+        TextGeometry {
+            area: Rect::new(0, 0, width, height),
+            wrap_width: wrap,
+            scroll_row: scroll,
+        }
+    }
+
+    fn press(x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn drag(x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Drag(MouseButton::Left),
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn up(x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn drag_across_wrapped_lines_copies_logical_content() {
+        // One logical line wrapped at 10 cells over two rows, then a
+        // second logical line.
+        let lines = ["abcdefghijKLMNO", "second"];
+        let geo = geometry(10, 4, 10, 0);
+        let mut state = SelectableTextState::new();
+        let now = Instant::now();
+
+        state.handle_mouse(&press(5, 0), &lines, &geo, now);
+        // Drag to row 1 (the wrapped tail), column 3 → char index 13.
+        state.handle_mouse(&drag(3, 1), &lines, &geo, now);
+        let event = state.handle_mouse(&up(3, 1), &lines, &geo, now);
+        let Some(SelectionEvent::Completed(text)) = event else {
+            panic!("expected completion, got {event:?}");
+        };
+        assert_eq!(text, "fghijKLM", "no newline injected at the soft wrap");
+
+        // Opt-in soft-wrap preservation inserts the break.
+        let wrapped = state.selected_text(&lines, Some(&geo)).unwrap();
+        assert_eq!(wrapped, "fghij\nKLM");
+    }
+
+    #[test]
+    fn selection_spanning_logical_lines_joins_with_newline() {
+        let lines = ["first", "second"];
+        let geo = geometry(20, 4, 0, 0);
+        let mut state = SelectableTextState::new();
+        let now = Instant::now();
+        state.handle_mouse(&press(2, 0), &lines, &geo, now);
+        state.handle_mouse(&drag(3, 1), &lines, &geo, now);
+        let text = state.selected_text(&lines, None).unwrap();
+        assert_eq!(text, "rst\nsec");
+    }
+
+    #[test]
+    fn scroll_during_drag_keeps_content_anchor() {
+        let lines = ["l0", "l1", "l2", "l3", "l4", "l5"];
+        let mut geo = geometry(10, 2, 0, 0);
+        let mut state = SelectableTextState::new();
+        let now = Instant::now();
+
+        // Anchor on l0 while rows 0..2 are visible.
+        state.handle_mouse(&press(0, 0), &lines, &geo, now);
+        // The host scrolls two rows during the drag; the same screen
+        // cell now means different content — the anchor must not move.
+        geo.scroll_row = 2;
+        state.handle_mouse(&drag(1, 1), &lines, &geo, now);
+        let (start, end) = state.selection().unwrap();
+        assert_eq!(start, TextPosition { line: 0, ch: 0 }, "anchor held");
+        assert_eq!(end, TextPosition { line: 3, ch: 1 }, "head via new scroll");
+        let text = state.selected_text(&lines, None).unwrap();
+        assert_eq!(text, "l0\nl1\nl2\nl");
+    }
+
+    #[test]
+    fn double_click_selects_word_triple_selects_line() {
+        let lines = ["let foo_bar = 42;"];
+        let geo = geometry(30, 2, 0, 0);
+        let mut state = SelectableTextState::new();
+        let t0 = Instant::now();
+
+        state.handle_mouse(&press(6, 0), &lines, &geo, t0);
+        state.handle_mouse(&up(6, 0), &lines, &geo, t0);
+        state.handle_mouse(&press(6, 0), &lines, &geo, t0 + Duration::from_millis(100));
+        assert_eq!(
+            state.selected_text(&lines, None).as_deref(),
+            Some("foo_bar"),
+            "double-click selects the word (underscore included)"
+        );
+        state.handle_mouse(&up(6, 0), &lines, &geo, t0 + Duration::from_millis(100));
+        state.handle_mouse(&press(6, 0), &lines, &geo, t0 + Duration::from_millis(200));
+        assert_eq!(
+            state.selected_text(&lines, None).as_deref(),
+            Some("let foo_bar = 42;"),
+            "triple-click selects the line"
+        );
+    }
+
+    #[test]
+    fn slow_second_click_does_not_word_select() {
+        let lines = ["hello world"];
+        let geo = geometry(20, 2, 0, 0);
+        let mut state = SelectableTextState::new();
+        let t0 = Instant::now();
+        state.handle_mouse(&press(2, 0), &lines, &geo, t0);
+        state.handle_mouse(&up(2, 0), &lines, &geo, t0);
+        state.handle_mouse(&press(2, 0), &lines, &geo, t0 + Duration::from_secs(2));
+        assert!(
+            state.selected_text(&lines, None).is_none(),
+            "plain re-click"
+        );
+    }
+
+    #[test]
+    fn shift_click_and_shift_arrows_extend() {
+        let lines = ["abcdef"];
+        let geo = geometry(10, 2, 0, 0);
+        let mut state = SelectableTextState::new();
+        let now = Instant::now();
+        state.handle_mouse(&press(1, 0), &lines, &geo, now);
+        state.handle_mouse(&up(1, 0), &lines, &geo, now);
+
+        let mut shift_click = press(4, 0);
+        shift_click.modifiers = Modifiers::SHIFT;
+        state.handle_mouse(&shift_click, &lines, &geo, now + Duration::from_secs(1));
+        assert_eq!(state.selected_text(&lines, None).as_deref(), Some("bcd"));
+
+        let shift_right = KeyEvent {
+            code: KeyCode::Right,
+            ch: None,
+            modifiers: Modifiers::SHIFT,
+            kind: ftui_core::event::KeyEventKind::Press,
+        };
+        state.handle_key(&shift_right, &lines);
+        assert_eq!(state.selected_text(&lines, None).as_deref(), Some("bcde"));
+    }
+
+    #[test]
+    fn highlight_covers_selected_cells_only() {
+        use ftui_render::cell::PackedRgba;
+        let lines = ["abcdef"];
+        let geo = geometry(10, 2, 0, 0);
+        let mut state = SelectableTextState::new();
+        let now = Instant::now();
+        state.handle_mouse(&press(1, 0), &lines, &geo, now);
+        state.handle_mouse(&drag(3, 0), &lines, &geo, now);
+
+        let bg = PackedRgba::rgb(1, 2, 3);
+        let mut buffer = Buffer::new(10, 2);
+        state.render_highlight(&mut buffer, &lines, &geo, Style::new().bg(bg));
+        for x in 0..10u16 {
+            let cell_bg = buffer.get(x, 0).unwrap().bg;
+            if (1..3).contains(&x) {
+                assert_eq!(cell_bg, bg, "cell {x} highlighted");
+            } else {
+                assert_ne!(cell_bg, bg, "cell {x} untouched");
+            }
+        }
+    }
+}
@@ -0,0 +1,427 @@
+#![forbid(unsafe_code)]
+
+//! Horizontal slider: a draggable thumb over a track.
+//!
+//! Cell positions map to a configurable value range with a precision
+//! quantum; the filled portion renders with partial blocks for
+//! sub-cell smoothness. Keyboard Left/Right step by the precision,
+//! Home/End jump to the extremes; mouse drags capture, so dragging
+//! outside the track keeps tracking the pointer's column. Change
+//! events fire only on real value changes, `disabled` renders dimmed
+//! and inert, and at width ≤ 5 the slider degrades to a stepper-like
+//! `◂ value ▸` display.
+
+use crate::{StatefulWidget, draw_text_span};
+use ftui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+/// Value-change event; emitted only when the value actually changed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliderEvent {
+    pub value: f64,
+}
+
+/// Mutable slider state: the value plus drag capture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderState {
+    pub value: f64,
+    /// Mouse capture: a drag that started on the track keeps tracking
+    /// even when the pointer leaves it.
+    dragging: bool,
+}
+
+impl SliderState {
+    #[must_use]
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            dragging: false,
+        }
+    }
+
+    /// Whether a drag has captured the mouse.
+    #[must_use]
+    pub fn dragging(&self) -> bool {
+        self.dragging
+    }
+}
+
+/// Eighth-block glyphs for sub-cell fill (1/8 … 8/8).
+const PARTIAL_BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// Horizontal slider widget (see the module docs).
+pub struct Slider {
+    min: f64,
+    max: f64,
+    /// Value quantum: results round to multiples of this (default 1).
+    precision: f64,
+    disabled: bool,
+    style: Style,
+    /// Style of the filled track portion.
+    fill_style: Style,
+    /// Style override applied when disabled.
+    disabled_style: Style,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self::new(0.0, 100.0)
+    }
+}
+
+impl Slider {
+    #[must_use]
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            precision: 1.0,
+            disabled: false,
+            style: Style::default(),
+            fill_style: Style::new().reverse(),
+            disabled_style: Style::new().dim(),
+        }
+    }
+
+    /// Value quantum (default 1): results snap to multiples of this.
+    #[must_use]
+    pub fn precision(mut self, precision: f64) -> Self {
+        self.precision = precision.max(f64::MIN_POSITIVE);
+        self
+    }
+
+    /// Disable interaction (renders with the disabled style).
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Base / track style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style of the filled portion.
+    #[must_use]
+    pub fn fill_style(mut self, style: Style) -> Self {
+        self.fill_style = style;
+        self
+    }
+
+    /// Snap a raw value to the precision grid and clamp to the range.
+    fn quantize(&self, value: f64) -> f64 {
+        let snapped = (value / self.precision).round() * self.precision;
+        snapped.clamp(self.min, self.max)
+    }
+
+    /// Map a pointer column to a value (columns clamp into the track).
+    #[must_use]
+    pub fn value_at(&self, area: Rect, x: u16) -> f64 {
+        if area.width <= 1 {
+            return self.min;
+        }
+        let clamped = x.clamp(area.x, area.right() - 1);
+        let offset = f64::from(clamped - area.x);
+        let span = f64::from(area.width - 1);
+        self.quantize(self.min + (self.max - self.min) * (offset / span))
+    }
+
+    fn set_value(&self, state: &mut SliderState, raw: f64) -> Option<SliderEvent> {
+        let next = self.quantize(raw);
+        if (next - state.value).abs() < f64::EPSILON {
+            return None;
+        }
+        state.value = next;
+        Some(SliderEvent { value: next })
+    }
+
+    /// Keyboard: Left/Right step by the precision, Home/End jump.
+    pub fn handle_key(&self, state: &mut SliderState, key: &KeyEvent) -> Option<SliderEvent> {
+        if self.disabled {
+            return None;
+        }
+        match key.code {
+            KeyCode::Left => self.set_value(state, state.value - self.precision),
+            KeyCode::Right => self.set_value(state, state.value + self.precision),
+            KeyCode::Home => self.set_value(state, self.min),
+            KeyCode::End => self.set_value(state, self.max),
+            _ => None,
+        }
+    }
+
+    /// Mouse: press on the track captures; drags keep tracking the
+    /// pointer's column even outside `area`; release ends the capture.
+    pub fn handle_mouse(
+        &self,
+        state: &mut SliderState,
+        event: &MouseEvent,
+        area: Rect,
+    ) -> Option<SliderEvent> {
+        if self.disabled {
+            return None;
+        }
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) if area.contains(event.x, event.y) => {
+                state.dragging = true;
+                self.set_value(state, self.value_at(area, event.x))
+            }
+            MouseEventKind::Drag(MouseButton::Left) if state.dragging => {
+                self.set_value(state, self.value_at(area, event.x))
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                state.dragging = false;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl StatefulWidget for Slider {
+    type State = SliderState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        let (style, fill_style) = if self.disabled {
+            (
+                self.disabled_style.merge(&self.style),
+                self.disabled_style.merge(&self.style),
+            )
+        } else {
+            (self.style, self.fill_style.merge(&self.style))
+        };
+
+        let value = self.quantize(state.value);
+        // Degraded stepper-like display for very narrow areas.
+        if area.width <= 5 {
+            let text = format!("\u{25c2}{value:.0}\u{25b8}");
+            draw_text_span(frame, area.x, area.y, &text, style, area.right());
+            return;
+        }
+
+        let span = (self.max - self.min).max(f64::MIN_POSITIVE);
+        let fraction = ((value - self.min) / span).clamp(0.0, 1.0);
+        // Sub-cell fill in eighths of a cell.
+        let total_eighths = (fraction * f64::from(area.width) * 8.0).round() as u32;
+        let full_cells = total_eighths / 8;
+        let remainder = total_eighths % 8;
+
+        let y = area.y;
+        for col in 0..area.width {
+            let x = area.x + col;
+            let glyph = if u32::from(col) < full_cells {
+                '█'
+            } else if u32::from(col) == full_cells && remainder > 0 {
+                PARTIAL_BLOCKS[remainder as usize - 1]
+            } else {
+                '\u{2500}' // ─ empty track
+            };
+            let cell_style = if u32::from(col) <= full_cells && glyph != '\u{2500}' {
+                fill_style
+            } else {
+                style
+            };
+            draw_text_span(frame, x, y, &glyph.to_string(), cell_style, area.right());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::{KeyEventKind, Modifiers};
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            ch: None,
+            modifiers: Modifiers::NONE,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    fn mouse(kind: MouseEventKind, x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn row(frame: &Frame, width: u16) -> String {
+        (0..width)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, 0)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect()
+    }
+
+    #[test]
+    fn position_maps_to_value_at_extremes_and_midpoint() {
+        let slider = Slider::new(0.0, 100.0);
+        let area = Rect::new(10, 0, 21, 1); // columns 10..=30
+        assert_eq!(slider.value_at(area, 10), 0.0);
+        assert_eq!(slider.value_at(area, 30), 100.0);
+        assert_eq!(slider.value_at(area, 20), 50.0);
+        // Rounding to the precision grid.
+        let slider = Slider::new(0.0, 10.0).precision(0.5);
+        let area = Rect::new(0, 0, 21, 1);
+        assert_eq!(slider.value_at(area, 1), 0.5);
+        assert_eq!(slider.value_at(area, 2), 1.0);
+    }
+
+    #[test]
+    fn keys_step_and_jump() {
+        let slider = Slider::new(0.0, 10.0).precision(2.0);
+        let mut state = SliderState::new(4.0);
+        assert_eq!(
+            slider.handle_key(&mut state, &key(KeyCode::Right)),
+            Some(SliderEvent { value: 6.0 })
+        );
+        assert_eq!(
+            slider.handle_key(&mut state, &key(KeyCode::Home)),
+            Some(SliderEvent { value: 0.0 })
+        );
+        assert_eq!(slider.handle_key(&mut state, &key(KeyCode::Left)), None);
+        assert_eq!(
+            slider.handle_key(&mut state, &key(KeyCode::End)),
+            Some(SliderEvent { value: 10.0 })
+        );
+    }
+
+    #[test]
+    fn drag_outside_track_keeps_tracking() {
+        let slider = Slider::new(0.0, 100.0);
+        let mut state = SliderState::new(0.0);
+        let area = Rect::new(10, 5, 11, 1); // columns 10..=20
+
+        // Press inside captures.
+        let event = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Down(MouseButton::Left), 15, 5),
+            area,
+        );
+        assert_eq!(event, Some(SliderEvent { value: 50.0 }));
+        assert!(state.dragging());
+
+        // Drag far past the right edge: clamps to max, still tracking.
+        let event = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Drag(MouseButton::Left), 90, 2),
+            area,
+        );
+        assert_eq!(event, Some(SliderEvent { value: 100.0 }));
+
+        // Drag far left: clamps to min.
+        let event = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Drag(MouseButton::Left), 0, 9),
+            area,
+        );
+        assert_eq!(event, Some(SliderEvent { value: 0.0 }));
+
+        // Release ends the capture; further drags do nothing.
+        slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Up(MouseButton::Left), 0, 9),
+            area,
+        );
+        assert!(!state.dragging());
+        let event = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Drag(MouseButton::Left), 18, 5),
+            area,
+        );
+        assert_eq!(event, None);
+    }
+
+    #[test]
+    fn press_outside_track_does_not_capture() {
+        let slider = Slider::new(0.0, 100.0);
+        let mut state = SliderState::new(25.0);
+        let area = Rect::new(10, 5, 11, 1);
+        let event = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Down(MouseButton::Left), 2, 2),
+            area,
+        );
+        assert_eq!(event, None);
+        assert!(!state.dragging());
+    }
+
+    #[test]
+    fn events_only_on_real_changes() {
+        let slider = Slider::new(0.0, 100.0);
+        let mut state = SliderState::new(50.0);
+        let area = Rect::new(0, 0, 11, 1);
+        // Pressing at the column that already maps to the current value
+        // captures but emits nothing.
+        let first = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Down(MouseButton::Left), 5, 0),
+            area,
+        );
+        assert_eq!(first, None);
+        assert!(state.dragging());
+        // Same column again while dragging: no event.
+        let repeat = slider.handle_mouse(
+            &mut state,
+            &mouse(MouseEventKind::Drag(MouseButton::Left), 5, 0),
+            area,
+        );
+        assert_eq!(repeat, None);
+    }
+
+    #[test]
+    fn disabled_is_inert() {
+        let slider = Slider::new(0.0, 100.0).disabled(true);
+        let mut state = SliderState::new(40.0);
+        let area = Rect::new(0, 0, 11, 1);
+        assert_eq!(slider.handle_key(&mut state, &key(KeyCode::Right)), None);
+        assert_eq!(
+            slider.handle_mouse(
+                &mut state,
+                &mouse(MouseEventKind::Down(MouseButton::Left), 5, 0),
+                area,
+            ),
+            None
+        );
+        assert_eq!(state.value, 40.0);
+    }
+
+    #[test]
+    fn renders_partial_blocks_for_sub_cell_fill() {
+        let slider = Slider::new(0.0, 100.0);
+        let mut state = SliderState::new(50.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 1, &mut pool);
+        slider.render(Rect::new(0, 0, 10, 1), &mut frame, &mut state);
+        let text = row(&frame, 10);
+        assert!(text.starts_with("████"), "fill: {text:?}");
+        assert!(text.contains('\u{2500}'), "empty track: {text:?}");
+    }
+
+    #[test]
+    fn narrow_area_degrades_to_stepper_display() {
+        let slider = Slider::new(0.0, 100.0);
+        let mut state = SliderState::new(42.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(5, 1, &mut pool);
+        slider.render(Rect::new(0, 0, 5, 1), &mut frame, &mut state);
+        let text = row(&frame, 5);
+        assert_eq!(text.trim_end(), "\u{25c2}42\u{25b8}", "degraded: {text:?}");
+    }
+}
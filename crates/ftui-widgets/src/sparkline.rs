@@ -25,6 +25,9 @@ use ftui_style::Style;
 /// Block characters for sparkline rendering (9 levels: empty + 8 bars).
 const SPARK_CHARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
+/// ASCII fallback characters used when unicode rendering is degraded.
+const SPARK_ASCII: [char; 9] = [' ', '.', '.', ':', ':', '-', '=', '=', '#'];
+
 /// A compact sparkline widget for trend visualization.
 ///
 /// Sparklines display a series of values as a row of Unicode block characters,
@@ -55,6 +58,9 @@ pub struct Sparkline<'a> {
     gradient: Option<(PackedRgba, PackedRgba)>,
     /// Baseline value (default 0.0) - values at baseline show as empty.
     baseline: f64,
+    /// Coloring thresholds: values at or above a threshold take its
+    /// color; the highest matching threshold wins. Overrides gradient.
+    thresholds: Vec<(f64, PackedRgba)>,
 }
 
 impl<'a> Sparkline<'a> {
@@ -68,6 +74,7 @@ impl<'a> Sparkline<'a> {
             style: Style::default(),
             gradient: None,
             baseline: 0.0,
+            thresholds: Vec::new(),
         }
     }
 
@@ -116,6 +123,15 @@ impl<'a> Sparkline<'a> {
 
     /// Set the baseline value.
     ///
+    /// Add a danger/warning threshold: values at or above `value` render
+    /// in `color` (the highest matching threshold wins, and thresholds
+    /// override the gradient).
+    #[must_use]
+    pub fn threshold(mut self, value: f64, color: PackedRgba) -> Self {
+        self.thresholds.push((value, color));
+        self
+    }
+
     /// Values at or below baseline show as empty space.
     /// Default is 0.0.
     #[must_use]
@@ -176,6 +192,27 @@ impl<'a> Sparkline<'a> {
         PackedRgba::rgba(r, g, b, a)
     }
 
+    /// The color of the highest threshold at or below `value`, if any.
+    fn threshold_color(&self, value: f64) -> Option<PackedRgba> {
+        self.thresholds
+            .iter()
+            .filter(|(threshold, _)| value >= *threshold)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, color)| *color)
+    }
+
+    /// Render with the ASCII fallback charset (for testing/debugging).
+    pub fn render_to_ascii_string(&self) -> String {
+        if self.data.is_empty() {
+            return String::new();
+        }
+        let (min, max) = self.compute_bounds();
+        self.data
+            .iter()
+            .map(|&v| SPARK_ASCII[self.value_to_bar_index(v, min, max)])
+            .collect()
+    }
+
     /// Render the sparkline as a string (for testing/debugging).
     pub fn render_to_string(&self) -> String {
         if self.data.is_empty() {
@@ -239,7 +276,11 @@ impl Widget for Sparkline<'_> {
             }
 
             let bar_idx = self.value_to_bar_index(value, min, max);
-            let ch = SPARK_CHARS[bar_idx];
+            let ch = if deg.use_unicode_borders() {
+                SPARK_CHARS[bar_idx]
+            } else {
+                SPARK_ASCII[bar_idx]
+            };
 
             let mut cell = Cell::from_char(ch);
 
@@ -260,6 +301,11 @@ impl Widget for Sparkline<'_> {
                     // Default to white if no style fg and no gradient
                     cell.fg = PackedRgba::WHITE;
                 }
+
+                // Thresholds override gradient/base: highest match wins.
+                if let Some(color) = self.threshold_color(value) {
+                    cell.fg = color;
+                }
             }
 
             frame.buffer.set_fast(x, y, cell);
@@ -591,4 +637,57 @@ mod tests {
 
         assert_eq!(c.max, Some(Size::new(3, 1)));
     }
+
+    // ── ASCII fallback and thresholds ───────────────────────────────
+
+    #[test]
+    fn ascii_fallback_snapshot() {
+        let data = [0.0, 2.0, 4.0, 6.0, 8.0];
+        let spark = Sparkline::new(&data).bounds(0.0, 8.0);
+        assert_eq!(spark.render_to_string(), " ▂▄▆█");
+        assert_eq!(spark.render_to_ascii_string(), " .:=#");
+    }
+
+    #[test]
+    fn ascii_fallback_render_at_degraded_level() {
+        use ftui_render::budget::DegradationLevel;
+        let data = [0.0, 8.0];
+        let spark = Sparkline::new(&data).bounds(0.0, 8.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(2, 1, &mut pool);
+        frame.buffer.degradation = DegradationLevel::SimpleBorders;
+        spark.render(Rect::new(0, 0, 2, 1), &mut frame);
+        assert_eq!(frame.buffer.get(1, 0).unwrap().content.as_char(), Some('#'));
+    }
+
+    #[test]
+    fn threshold_overrides_gradient_color() {
+        let data = [1.0, 9.0];
+        let danger = PackedRgba::rgb(255, 0, 0);
+        let spark = Sparkline::new(&data)
+            .bounds(0.0, 10.0)
+            .gradient(PackedRgba::rgb(0, 255, 0), PackedRgba::rgb(0, 0, 255))
+            .threshold(8.0, danger);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(2, 1, &mut pool);
+        spark.render(Rect::new(0, 0, 2, 1), &mut frame);
+        // Below the threshold: gradient color. At/above: danger color.
+        assert_ne!(frame.buffer.get(0, 0).unwrap().fg, danger);
+        assert_eq!(frame.buffer.get(1, 0).unwrap().fg, danger);
+    }
+
+    #[test]
+    fn highest_matching_threshold_wins() {
+        let data = [9.0];
+        let warn = PackedRgba::rgb(255, 255, 0);
+        let danger = PackedRgba::rgb(255, 0, 0);
+        let spark = Sparkline::new(&data)
+            .bounds(0.0, 10.0)
+            .threshold(5.0, warn)
+            .threshold(8.0, danger);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(1, 1, &mut pool);
+        spark.render(Rect::new(0, 0, 1, 1), &mut frame);
+        assert_eq!(frame.buffer.get(0, 0).unwrap().fg, danger);
+    }
 }
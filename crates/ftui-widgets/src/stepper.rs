@@ -0,0 +1,436 @@
+#![forbid(unsafe_code)]
+
+//! Numeric stepper: `− value +` with keyboard, mouse, and hold-repeat.
+//!
+//! Arrow keys and clicks on the affordances adjust by a configurable
+//! step (Shift applies the large step); holding a mouse button on an
+//! affordance repeats, driven by injected frame time
+//! ([`Stepper::advance_hold`]) rather than an internal timer, so tests
+//! and replays are deterministic. Values clamp to `min..=max`, display
+//! goes through a formatter callback ("75%"), and change events fire
+//! only on real value changes.
+
+use std::time::Duration;
+
+use crate::{StatefulWidget, draw_text_span};
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+/// Value-change event; emitted only when the value actually changed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepperEvent {
+    pub value: f64,
+}
+
+/// Which affordance is being held for repeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HoldDirection {
+    Decrement,
+    Increment,
+}
+
+/// Mutable stepper state: the value plus hold-repeat bookkeeping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepperState {
+    pub value: f64,
+    /// Affordance held down, with time since the last repeat fire.
+    hold: Option<(HoldDirection, Duration)>,
+}
+
+impl StepperState {
+    #[must_use]
+    pub fn new(value: f64) -> Self {
+        Self { value, hold: None }
+    }
+
+    /// Whether a press-and-hold repeat is in progress.
+    #[must_use]
+    pub fn holding(&self) -> bool {
+        self.hold.is_some()
+    }
+}
+
+type Formatter<'a> = dyn Fn(f64) -> String + 'a;
+
+/// Compact numeric control: `− value +` (see the module docs).
+pub struct Stepper<'a> {
+    min: f64,
+    max: f64,
+    step: f64,
+    /// Step applied with Shift held.
+    large_step: f64,
+    /// Delay before hold-repeat starts.
+    repeat_delay: Duration,
+    /// Interval between repeats once started.
+    repeat_interval: Duration,
+    formatter: Option<&'a Formatter<'a>>,
+    disabled: bool,
+    style: Style,
+    /// Style for the − / + affordances.
+    affordance_style: Style,
+    /// Style override applied when disabled.
+    disabled_style: Style,
+}
+
+impl Default for Stepper<'_> {
+    fn default() -> Self {
+        Self::new(0.0, 100.0)
+    }
+}
+
+impl<'a> Stepper<'a> {
+    #[must_use]
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            step: 1.0,
+            large_step: 10.0,
+            repeat_delay: Duration::from_millis(400),
+            repeat_interval: Duration::from_millis(80),
+            formatter: None,
+            disabled: false,
+            style: Style::default(),
+            affordance_style: Style::new().bold(),
+            disabled_style: Style::new().dim(),
+        }
+    }
+
+    /// Step per adjustment (default 1).
+    #[must_use]
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Step applied while Shift is held (default 10).
+    #[must_use]
+    pub fn large_step(mut self, step: f64) -> Self {
+        self.large_step = step;
+        self
+    }
+
+    /// Hold-repeat timing: initial delay, then interval per repeat.
+    #[must_use]
+    pub fn repeat(mut self, delay: Duration, interval: Duration) -> Self {
+        self.repeat_delay = delay;
+        self.repeat_interval = interval;
+        self
+    }
+
+    /// Display formatter (e.g. `|v| format!("{v:.0}%")`).
+    #[must_use]
+    pub fn formatter(mut self, formatter: &'a Formatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Disable interaction (renders with the disabled style).
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Base style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+
+    fn apply_step(&self, state: &mut StepperState, delta: f64) -> Option<StepperEvent> {
+        let next = self.clamp(state.value + delta);
+        if (next - state.value).abs() < f64::EPSILON {
+            return None;
+        }
+        state.value = next;
+        Some(StepperEvent { value: next })
+    }
+
+    fn step_for(&self, modifiers: Modifiers) -> f64 {
+        if modifiers.contains(Modifiers::SHIFT) {
+            self.large_step
+        } else {
+            self.step
+        }
+    }
+
+    /// Keyboard: Left/Down decrement, Right/Up increment (Shift = large
+    /// step). Returns the change event, if the value moved.
+    pub fn handle_key(&self, state: &mut StepperState, key: &KeyEvent) -> Option<StepperEvent> {
+        if self.disabled {
+            return None;
+        }
+        let step = self.step_for(key.modifiers);
+        match key.code {
+            KeyCode::Left | KeyCode::Down => self.apply_step(state, -step),
+            KeyCode::Right | KeyCode::Up => self.apply_step(state, step),
+            KeyCode::Home => self.apply_step(state, self.min - state.value),
+            KeyCode::End => self.apply_step(state, self.max - state.value),
+            _ => None,
+        }
+    }
+
+    /// Mouse: clicks on the `−`/`+` affordances step and arm
+    /// hold-repeat; release disarms. `area` is the rect the stepper was
+    /// rendered into.
+    pub fn handle_mouse(
+        &self,
+        state: &mut StepperState,
+        event: &MouseEvent,
+        area: Rect,
+    ) -> Option<StepperEvent> {
+        if self.disabled {
+            return None;
+        }
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let direction = self.affordance_at(area, event.x, event.y)?;
+                state.hold = Some((direction, Duration::ZERO));
+                let step = self.step_for(event.modifiers);
+                let delta = match direction {
+                    HoldDirection::Decrement => -step,
+                    HoldDirection::Increment => step,
+                };
+                self.apply_step(state, delta)
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                state.hold = None;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Advance hold-repeat by one frame's elapsed time.
+    ///
+    /// Call once per frame with the frame delta; repeats fire after the
+    /// configured delay, then once per interval. Deterministic: inject
+    /// fixed deltas in tests and the repeat schedule is exact.
+    pub fn advance_hold(
+        &self,
+        state: &mut StepperState,
+        frame_delta: Duration,
+    ) -> Option<StepperEvent> {
+        if self.disabled {
+            state.hold = None;
+            return None;
+        }
+        let (direction, elapsed) = state.hold.as_mut()?;
+        let direction = *direction;
+        *elapsed += frame_delta;
+        if *elapsed < self.repeat_delay {
+            return None;
+        }
+        // Past the delay: fire once per interval, retaining the delay
+        // offset so the cadence stays stable.
+        if *elapsed < self.repeat_delay + self.repeat_interval {
+            return None;
+        }
+        *elapsed -= self.repeat_interval;
+        let delta = match direction {
+            HoldDirection::Decrement => -self.step,
+            HoldDirection::Increment => self.step,
+        };
+        self.apply_step(state, delta)
+    }
+
+    /// Which affordance a position lands on.
+    fn affordance_at(&self, area: Rect, x: u16, y: u16) -> Option<HoldDirection> {
+        if !area.contains(x, y) || area.width < 3 {
+            return None;
+        }
+        if x == area.x {
+            Some(HoldDirection::Decrement)
+        } else if x == area.right() - 1 {
+            Some(HoldDirection::Increment)
+        } else {
+            None
+        }
+    }
+
+    fn display_value(&self, value: f64) -> String {
+        match self.formatter {
+            Some(format) => format(value),
+            None => format!("{value}"),
+        }
+    }
+}
+
+impl StatefulWidget for Stepper<'_> {
+    type State = StepperState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        if area.is_empty() || area.width < 3 {
+            return;
+        }
+        let (style, affordance) = if self.disabled {
+            (
+                self.disabled_style.merge(&self.style),
+                self.disabled_style.merge(&self.style),
+            )
+        } else {
+            (self.style, self.affordance_style.merge(&self.style))
+        };
+
+        let y = area.y;
+        draw_text_span(frame, area.x, y, "\u{2212}", affordance, area.right());
+        draw_text_span(frame, area.right() - 1, y, "+", affordance, area.right());
+
+        let text = self.display_value(self.clamp(state.value));
+        let inner_width = usize::from(area.width.saturating_sub(2));
+        let text_len = text.chars().count().min(inner_width);
+        let pad = (inner_width - text_len) / 2;
+        let x = area.x + 1 + pad as u16;
+        draw_text_span(frame, x, y, &text, style, area.right() - 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::KeyEventKind;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode, modifiers: Modifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            ch: None,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    fn click(x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn release(x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn keys_step_and_clamp() {
+        let stepper = Stepper::new(0.0, 10.0).step(2.0);
+        let mut state = StepperState::new(9.0);
+        assert_eq!(
+            stepper.handle_key(&mut state, &key(KeyCode::Right, Modifiers::NONE)),
+            Some(StepperEvent { value: 10.0 }),
+            "clamped to max"
+        );
+        assert_eq!(
+            stepper.handle_key(&mut state, &key(KeyCode::Right, Modifiers::NONE)),
+            None,
+            "at max: no event"
+        );
+        assert_eq!(
+            stepper.handle_key(&mut state, &key(KeyCode::Left, Modifiers::NONE)),
+            Some(StepperEvent { value: 8.0 })
+        );
+    }
+
+    #[test]
+    fn shift_applies_large_step() {
+        let stepper = Stepper::new(0.0, 100.0).step(1.0).large_step(25.0);
+        let mut state = StepperState::new(0.0);
+        let event = stepper.handle_key(&mut state, &key(KeyCode::Up, Modifiers::SHIFT));
+        assert_eq!(event, Some(StepperEvent { value: 25.0 }));
+    }
+
+    #[test]
+    fn mouse_affordances_step_and_arm_hold() {
+        let stepper = Stepper::new(0.0, 10.0);
+        let mut state = StepperState::new(5.0);
+        let area = Rect::new(2, 1, 9, 1);
+        // '−' at x=2, '+' at x=10.
+        assert_eq!(
+            stepper.handle_mouse(&mut state, &click(2, 1), area),
+            Some(StepperEvent { value: 4.0 })
+        );
+        assert!(state.holding());
+        stepper.handle_mouse(&mut state, &release(2, 1), area);
+        assert!(!state.holding());
+
+        assert_eq!(
+            stepper.handle_mouse(&mut state, &click(10, 1), area),
+            Some(StepperEvent { value: 5.0 })
+        );
+        // Middle clicks do nothing.
+        stepper.handle_mouse(&mut state, &release(10, 1), area);
+        assert_eq!(stepper.handle_mouse(&mut state, &click(6, 1), area), None);
+    }
+
+    #[test]
+    fn hold_repeat_follows_injected_frame_time() {
+        let stepper =
+            Stepper::new(0.0, 100.0).repeat(Duration::from_millis(400), Duration::from_millis(100));
+        let mut state = StepperState::new(0.0);
+        let area = Rect::new(0, 0, 7, 1);
+        stepper.handle_mouse(&mut state, &click(6, 0), area); // +1, armed
+        assert_eq!(state.value, 1.0);
+
+        // 16ms frames: no repeat until the 400ms delay plus one interval.
+        let mut fired = Vec::new();
+        for frame in 1..=40 {
+            if let Some(event) = stepper.advance_hold(&mut state, Duration::from_millis(16)) {
+                fired.push((frame, event.value));
+            }
+        }
+        // Delay 400ms + interval 100ms = first repeat at 500ms → frame 32
+        // (32 × 16ms = 512ms); then every ~100ms (7 frames).
+        assert_eq!(fired.first(), Some(&(32, 2.0)), "{fired:?}");
+        assert_eq!(fired.get(1), Some(&(38, 3.0)), "{fired:?}");
+    }
+
+    #[test]
+    fn disabled_ignores_everything() {
+        let stepper = Stepper::new(0.0, 10.0).disabled(true);
+        let mut state = StepperState::new(5.0);
+        let area = Rect::new(0, 0, 7, 1);
+        assert_eq!(
+            stepper.handle_key(&mut state, &key(KeyCode::Right, Modifiers::NONE)),
+            None
+        );
+        assert_eq!(stepper.handle_mouse(&mut state, &click(0, 0), area), None);
+        assert_eq!(state.value, 5.0);
+    }
+
+    #[test]
+    fn formatter_shapes_display() {
+        let format = |v: f64| format!("{v:.0}%");
+        let stepper = Stepper::new(0.0, 100.0).formatter(&format);
+        let mut state = StepperState::new(75.0);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(11, 1, &mut pool);
+        stepper.render(Rect::new(0, 0, 11, 1), &mut frame, &mut state);
+        let row: String = (0..11)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, 0)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect();
+        assert!(row.contains("75%"), "row: {row:?}");
+        assert!(row.starts_with('\u{2212}'), "row: {row:?}");
+        assert!(row.trim_end().ends_with('+'), "row: {row:?}");
+    }
+}
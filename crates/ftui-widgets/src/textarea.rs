@@ -12,7 +12,9 @@
 //! assert_eq!(ta.line_count(), 2);
 //! ```
 
-use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind, Modifiers};
+use ftui_core::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use ftui_core::geometry::Rect;
 use ftui_render::frame::Frame;
 use ftui_style::Style;
@@ -62,8 +64,52 @@ pub struct TextArea {
     last_viewport_height: std::cell::Cell<usize>,
     /// Last viewport width for visibility checks.
     last_viewport_width: std::cell::Cell<usize>,
+    /// Monotonic state version for dirty tracking ([`crate::WidgetState`]).
+    version: u64,
+    /// Opt-in editor affordances (auto-pairing, auto-indent).
+    editing: TextAreaEditingConfig,
+    /// Opt-in multiple-caret editing.
+    multi_cursor: bool,
+    /// Secondary carets (the primary lives in the editor).
+    extra_carets: Vec<Caret>,
+    /// Style applied to secondary caret cells.
+    secondary_caret_style: Style,
 }
 
+/// A secondary caret: position plus its own optional selection.
+#[derive(Debug, Clone, Copy)]
+struct Caret {
+    position: CursorPosition,
+    selection: Option<Selection>,
+}
+
+/// Caret snapshot used while applying a multi-caret edit:
+/// byte offset, optional selection byte range, and whether it is primary.
+type CaretSnapshot = (usize, Option<(usize, usize)>, bool);
+
+/// Opt-in editing behaviors for code-ish input.
+///
+/// All flags default to off so plain-prose usage is unaffected. Each
+/// behavior produces exactly one undoable step per logical keypress, even
+/// when it inserts or deletes two characters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextAreaEditingConfig {
+    /// Auto-insert the closing `)]}"'` when the opener is typed before
+    /// whitespace/EOL, and skip over a closer that is already there.
+    pub auto_pair: bool,
+    /// On Enter, copy the previous line's leading whitespace, plus one
+    /// extra level after an unmatched opener.
+    pub auto_indent: bool,
+    /// Backspace between an empty pair deletes both characters.
+    pub pair_backspace: bool,
+}
+
+/// Bracket/quote pairs recognized by the editing behaviors.
+const PAIRS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}'), ('"', '"'), ('\'', '\'')];
+
+/// One indent level used by auto-indent after an unmatched opener.
+const INDENT_UNIT: &str = "    ";
+
 impl Default for TextArea {
     fn default() -> Self {
         Self::new()
@@ -107,7 +153,47 @@ impl TextArea {
             scroll_left: std::cell::Cell::new(0),
             last_viewport_height: std::cell::Cell::new(0),
             last_viewport_width: std::cell::Cell::new(0),
+            version: 0,
+            editing: TextAreaEditingConfig::default(),
+            multi_cursor: false,
+            extra_carets: Vec::new(),
+            secondary_caret_style: Style::new().reverse().dim(),
+        }
+    }
+
+    /// Enable opt-in editing behaviors (builder). All are off by default.
+    #[must_use]
+    pub fn with_editing(mut self, editing: TextAreaEditingConfig) -> Self {
+        self.editing = editing;
+        self
+    }
+
+    /// The active editing behavior configuration.
+    #[must_use]
+    pub fn editing(&self) -> TextAreaEditingConfig {
+        self.editing
+    }
+
+    /// Enable multi-cursor editing (builder). Off by default.
+    ///
+    /// With the mode on, Alt+Click ([`handle_mouse`](Self::handle_mouse))
+    /// adds a caret, Ctrl+Alt+Up/Down adds one on the adjacent line at the
+    /// same goal column, and Escape collapses to the primary caret.
+    /// Editing operations apply at every caret as a single undoable step.
+    #[must_use]
+    pub fn with_multi_cursor(mut self, enabled: bool) -> Self {
+        self.multi_cursor = enabled;
+        if !enabled {
+            self.extra_carets.clear();
         }
+        self
+    }
+
+    /// Style for secondary caret cells (builder).
+    #[must_use]
+    pub fn with_secondary_caret_style(mut self, style: Style) -> Self {
+        self.secondary_caret_style = style;
+        self
     }
 
     // ── Event Handling ─────────────────────────────────────────────
@@ -116,7 +202,8 @@ impl TextArea {
     ///
     /// Returns `true` if the state changed.
     pub fn handle_event(&mut self, event: &Event) -> bool {
-        match event {
+        let fingerprint = self.state_fingerprint();
+        let handled = match event {
             Event::Key(key)
                 if key.kind == KeyEventKind::Press || key.kind == KeyEventKind::Repeat =>
             {
@@ -127,27 +214,184 @@ impl TextArea {
                 true
             }
             _ => false,
+        };
+        // Real mutations bump the version; handled no-ops (cursor already
+        // at a boundary) do not.
+        if self.state_fingerprint() != fingerprint {
+            self.version = self.version.wrapping_add(1);
         }
+        handled
+    }
+
+    /// Character at the cursor on the current line (`None` at EOL).
+    fn char_at_cursor(&self) -> Option<char> {
+        let cursor = self.editor.cursor();
+        let line = self.editor.line_text(cursor.line)?;
+        line.chars().nth(cursor.grapheme)
+    }
+
+    /// Character before the cursor on the current line (`None` at BOL).
+    fn char_before_cursor(&self) -> Option<char> {
+        let cursor = self.editor.cursor();
+        if cursor.grapheme == 0 {
+            return None;
+        }
+        let line = self.editor.line_text(cursor.line)?;
+        line.chars().nth(cursor.grapheme - 1)
+    }
+
+    /// Auto-pairing for a typed character. Returns `true` when handled.
+    fn try_auto_pair(&mut self, c: char) -> bool {
+        if self.editor.selection().is_some() {
+            return false;
+        }
+        // Skip-over: typing the closer that's already at the cursor.
+        if PAIRS.iter().any(|&(_, close)| close == c) && self.char_at_cursor() == Some(c) {
+            self.editor.move_right();
+            return true;
+        }
+        // Auto-close: opener typed with the cursor before whitespace/EOL.
+        let Some(&(_, close)) = PAIRS.iter().find(|&&(open, _)| open == c) else {
+            return false;
+        };
+        let next_ok = self
+            .char_at_cursor()
+            .is_none_or(|next| next.is_whitespace() || PAIRS.iter().any(|&(_, cl)| cl == next));
+        if !next_ok {
+            return false;
+        }
+        // Quotes double as their own closer: don't pair right after a word
+        // character or the same quote (apostrophes, adjacent strings).
+        if c == close
+            && self
+                .char_before_cursor()
+                .is_some_and(|prev| prev.is_alphanumeric() || prev == c)
+        {
+            return false;
+        }
+        // One insert = one undoable step; then step back inside the pair.
+        let mut pair = String::with_capacity(2);
+        pair.push(c);
+        pair.push(close);
+        self.editor.insert_text(&pair);
+        self.editor.move_left();
+        true
+    }
+
+    /// Enter with indentation copied from the current line, plus one
+    /// extra level after an unmatched opener.
+    fn insert_newline_indented(&mut self) {
+        let cursor = self.editor.cursor();
+        let line = self.editor.line_text(cursor.line).unwrap_or_default();
+        let before: String = line.chars().take(cursor.grapheme).collect();
+        let mut indent: String = before
+            .chars()
+            .take_while(|ch| *ch == ' ' || *ch == '\t')
+            .collect();
+        if before
+            .trim_end()
+            .chars()
+            .last()
+            .is_some_and(|last| matches!(last, '(' | '[' | '{'))
+        {
+            indent.push_str(INDENT_UNIT);
+        }
+        // One insert = one undoable step.
+        let mut text = String::with_capacity(1 + indent.len());
+        text.push('\n');
+        text.push_str(&indent);
+        self.editor.insert_text(&text);
+    }
+
+    /// Backspace between an empty pair deletes both characters.
+    fn try_pair_backspace(&mut self) -> bool {
+        if self.editor.selection().is_some() {
+            return false;
+        }
+        let (Some(prev), Some(next)) = (self.char_before_cursor(), self.char_at_cursor()) else {
+            return false;
+        };
+        if !PAIRS
+            .iter()
+            .any(|&(open, close)| open == prev && close == next)
+        {
+            return false;
+        }
+        // Select both characters so the deletion is one undoable step.
+        self.editor.move_left();
+        self.editor.select_right();
+        self.editor.select_right();
+        self.editor.delete_backward();
+        true
+    }
+
+    /// Cheap fingerprint of observable state for dirty tracking. Any edit
+    /// moves the cursor or the line/length shape, so content hashing is
+    /// unnecessary.
+    fn state_fingerprint(&self) -> (usize, usize, usize, bool, usize, bool, usize, usize) {
+        let cursor = self.editor.cursor();
+        let caret_sum = self.extra_carets.iter().fold(0usize, |acc, caret| {
+            acc.wrapping_add(caret.position.line.wrapping_mul(31))
+                .wrapping_add(caret.position.grapheme)
+        });
+        (
+            self.editor.rope().len_bytes(),
+            cursor.line,
+            cursor.grapheme,
+            self.editor.selection().is_some(),
+            self.scroll_left.get(),
+            self.focused,
+            self.extra_carets.len(),
+            caret_sum,
+        )
     }
 
     fn handle_key(&mut self, key: &KeyEvent) -> bool {
         let ctrl = key.modifiers.contains(Modifiers::CTRL);
         let shift = key.modifiers.contains(Modifiers::SHIFT);
-        let _alt = key.modifiers.contains(Modifiers::ALT);
+        let alt = key.modifiers.contains(Modifiers::ALT);
 
         match key.code {
+            // Multi-cursor bindings take precedence over plain movement.
+            KeyCode::Up if self.multi_cursor && ctrl && alt => {
+                self.add_caret_above();
+                true
+            }
+            KeyCode::Down if self.multi_cursor && ctrl && alt => {
+                self.add_caret_below();
+                true
+            }
+            KeyCode::Escape if self.multi_cursor && !self.extra_carets.is_empty() => {
+                self.collapse_carets();
+                true
+            }
             KeyCode::Char(c) if !ctrl => {
-                self.insert_char(c);
+                // Dual key reporting: insert the produced scalar.
+                let c = key.ch.unwrap_or(c);
+                // Auto-pairing is a single-caret affordance.
+                if !(self.editing.auto_pair
+                    && self.extra_carets.is_empty()
+                    && self.try_auto_pair(c))
+                {
+                    self.insert_char(c);
+                }
                 true
             }
             KeyCode::Enter => {
-                self.insert_newline();
+                if self.editing.auto_indent && self.extra_carets.is_empty() {
+                    self.insert_newline_indented();
+                } else {
+                    self.insert_newline();
+                }
                 true
             }
             KeyCode::Backspace => {
                 if ctrl {
                     self.delete_word_backward();
-                } else {
+                } else if !(self.editing.pair_backspace
+                    && self.extra_carets.is_empty()
+                    && self.try_pair_backspace())
+                {
                     self.delete_backward();
                 }
                 true
@@ -374,6 +618,9 @@ impl TextArea {
 
     /// Set focus state.
     pub fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.version = self.version.wrapping_add(1);
+        }
         self.focused = focused;
     }
 
@@ -391,56 +638,79 @@ impl TextArea {
     // ── Editing operations (delegated to Editor) ───────────────────
 
     /// Insert text at cursor.
+    ///
+    /// With multiple carets the text is inserted at every caret; when the
+    /// text has exactly one line per caret (clipboard line count matches
+    /// the caret count), each caret receives its own line instead.
     pub fn insert_text(&mut self, text: &str) {
-        self.editor.insert_text(text);
+        if self.extra_carets.is_empty() {
+            self.editor.insert_text(text);
+        } else {
+            let lines: Vec<&str> = text.split('\n').collect();
+            if lines.len() == self.caret_count() {
+                self.for_each_caret_edit(|editor, idx| editor.insert_text(lines[idx]));
+            } else {
+                self.for_each_caret_edit(|editor, _| editor.insert_text(text));
+            }
+        }
         self.ensure_cursor_visible();
     }
 
     /// Insert a single character.
     pub fn insert_char(&mut self, ch: char) {
-        self.editor.insert_char(ch);
+        self.for_each_caret_edit(|editor, _| editor.insert_char(ch));
         self.ensure_cursor_visible();
     }
 
     /// Insert a newline.
     pub fn insert_newline(&mut self) {
-        self.editor.insert_newline();
+        self.for_each_caret_edit(|editor, _| editor.insert_newline());
         self.ensure_cursor_visible();
     }
 
     /// Delete backward (backspace).
     pub fn delete_backward(&mut self) {
-        self.editor.delete_backward();
+        self.for_each_caret_edit(|editor, _| {
+            editor.delete_backward();
+        });
         self.ensure_cursor_visible();
     }
 
     /// Delete forward (delete key).
     pub fn delete_forward(&mut self) {
-        self.editor.delete_forward();
+        self.for_each_caret_edit(|editor, _| {
+            editor.delete_forward();
+        });
         self.ensure_cursor_visible();
     }
 
     /// Delete word backward (Ctrl+Backspace).
     pub fn delete_word_backward(&mut self) {
-        self.editor.delete_word_backward();
+        self.for_each_caret_edit(|editor, _| {
+            editor.delete_word_backward();
+        });
         self.ensure_cursor_visible();
     }
 
     /// Delete to end of line (Ctrl+K).
     pub fn delete_to_end_of_line(&mut self) {
-        self.editor.delete_to_end_of_line();
+        self.for_each_caret_edit(|editor, _| {
+            editor.delete_to_end_of_line();
+        });
         self.ensure_cursor_visible();
     }
 
     /// Undo last edit.
     pub fn undo(&mut self) {
         self.editor.undo();
+        self.clamp_extra_carets();
         self.ensure_cursor_visible();
     }
 
     /// Redo last undo.
     pub fn redo(&mut self) {
         self.editor.redo();
+        self.clamp_extra_carets();
         self.ensure_cursor_visible();
     }
 
@@ -448,49 +718,49 @@ impl TextArea {
 
     /// Move cursor left.
     pub fn move_left(&mut self) {
-        self.editor.move_left();
+        self.for_each_caret_motion(|editor| editor.move_left());
         self.ensure_cursor_visible();
     }
 
     /// Move cursor right.
     pub fn move_right(&mut self) {
-        self.editor.move_right();
+        self.for_each_caret_motion(|editor| editor.move_right());
         self.ensure_cursor_visible();
     }
 
     /// Move cursor up.
     pub fn move_up(&mut self) {
-        self.editor.move_up();
+        self.for_each_caret_motion(|editor| editor.move_up());
         self.ensure_cursor_visible();
     }
 
     /// Move cursor down.
     pub fn move_down(&mut self) {
-        self.editor.move_down();
+        self.for_each_caret_motion(|editor| editor.move_down());
         self.ensure_cursor_visible();
     }
 
     /// Move cursor left by word.
     pub fn move_word_left(&mut self) {
-        self.editor.move_word_left();
+        self.for_each_caret_motion(|editor| editor.move_word_left());
         self.ensure_cursor_visible();
     }
 
     /// Move cursor right by word.
     pub fn move_word_right(&mut self) {
-        self.editor.move_word_right();
+        self.for_each_caret_motion(|editor| editor.move_word_right());
         self.ensure_cursor_visible();
     }
 
     /// Move to start of line.
     pub fn move_to_line_start(&mut self) {
-        self.editor.move_to_line_start();
+        self.for_each_caret_motion(|editor| editor.move_to_line_start());
         self.ensure_cursor_visible();
     }
 
     /// Move to end of line.
     pub fn move_to_line_end(&mut self) {
-        self.editor.move_to_line_end();
+        self.for_each_caret_motion(|editor| editor.move_to_line_end());
         self.ensure_cursor_visible();
     }
 
@@ -682,25 +952,25 @@ impl TextArea {
 
     /// Extend selection left.
     pub fn select_left(&mut self) {
-        self.editor.select_left();
+        self.for_each_caret_motion(|editor| editor.select_left());
         self.ensure_cursor_visible();
     }
 
     /// Extend selection right.
     pub fn select_right(&mut self) {
-        self.editor.select_right();
+        self.for_each_caret_motion(|editor| editor.select_right());
         self.ensure_cursor_visible();
     }
 
     /// Extend selection up.
     pub fn select_up(&mut self) {
-        self.editor.select_up();
+        self.for_each_caret_motion(|editor| editor.select_up());
         self.ensure_cursor_visible();
     }
 
     /// Extend selection down.
     pub fn select_down(&mut self) {
-        self.editor.select_down();
+        self.for_each_caret_motion(|editor| editor.select_down());
         self.ensure_cursor_visible();
     }
 
@@ -714,6 +984,358 @@ impl TextArea {
         self.editor.clear_selection();
     }
 
+    // ── Multi-cursor ───────────────────────────────────────────────
+
+    /// Whether multi-cursor mode is enabled.
+    #[must_use]
+    pub fn multi_cursor_enabled(&self) -> bool {
+        self.multi_cursor
+    }
+
+    /// Total caret count (primary plus secondaries).
+    #[must_use]
+    pub fn caret_count(&self) -> usize {
+        1 + self.extra_carets.len()
+    }
+
+    /// All caret positions in document order (primary included).
+    #[must_use]
+    pub fn caret_positions(&self) -> Vec<CursorPosition> {
+        let mut positions: Vec<CursorPosition> = self
+            .extra_carets
+            .iter()
+            .map(|caret| caret.position)
+            .collect();
+        positions.push(self.editor.cursor());
+        positions.sort_by_key(|pos| (pos.line, pos.grapheme));
+        positions
+    }
+
+    /// Add a secondary caret at `pos` (clamped). No-op unless multi-cursor
+    /// mode is enabled; carets colliding with an existing one are merged.
+    pub fn add_caret(&mut self, pos: CursorPosition) {
+        if !self.multi_cursor {
+            return;
+        }
+        let nav = CursorNavigator::new(self.editor.rope());
+        let position = nav.clamp(pos);
+        self.extra_carets.push(Caret {
+            position,
+            selection: None,
+        });
+        self.merge_extra_carets();
+    }
+
+    /// Add a caret on the line above the topmost caret, at the same goal
+    /// column (Ctrl+Alt+Up).
+    pub fn add_caret_above(&mut self) {
+        let Some(top) = self
+            .caret_positions()
+            .into_iter()
+            .min_by_key(|pos| (pos.line, pos.grapheme))
+        else {
+            return;
+        };
+        if top.line == 0 {
+            return;
+        }
+        if let Some(pos) = self.position_at_visual_col(top.line - 1, top.visual_col) {
+            self.add_caret(pos);
+        }
+    }
+
+    /// Add a caret on the line below the bottom-most caret, at the same
+    /// goal column (Ctrl+Alt+Down).
+    pub fn add_caret_below(&mut self) {
+        let Some(bottom) = self
+            .caret_positions()
+            .into_iter()
+            .max_by_key(|pos| (pos.line, pos.grapheme))
+        else {
+            return;
+        };
+        if bottom.line + 1 >= self.editor.line_count() {
+            return;
+        }
+        if let Some(pos) = self.position_at_visual_col(bottom.line + 1, bottom.visual_col) {
+            self.add_caret(pos);
+        }
+    }
+
+    /// Collapse to the primary caret only (Escape).
+    pub fn collapse_carets(&mut self) {
+        self.extra_carets.clear();
+    }
+
+    /// Insert a caret on each line of the rectangular block spanned by
+    /// `anchor` and `head`, at `head`'s goal column. The primary caret is
+    /// left in place.
+    pub fn add_carets_in_block(&mut self, anchor: CursorPosition, head: CursorPosition) {
+        if !self.multi_cursor {
+            return;
+        }
+        let (first, last) = if anchor.line <= head.line {
+            (anchor.line, head.line)
+        } else {
+            (head.line, anchor.line)
+        };
+        for line in first..=last.min(self.editor.line_count().saturating_sub(1)) {
+            if let Some(pos) = self.position_at_visual_col(line, head.visual_col) {
+                self.add_caret(pos);
+            }
+        }
+    }
+
+    /// Handle a mouse event. Alt+Click adds a caret at the clicked cell
+    /// when multi-cursor mode is on. `area` is the rect the widget was
+    /// last rendered into. Returns `true` when the event was consumed.
+    pub fn handle_mouse(&mut self, event: &MouseEvent, area: Rect) -> bool {
+        if !self.multi_cursor
+            || !matches!(event.kind, MouseEventKind::Down(MouseButton::Left))
+            || !event.modifiers.contains(Modifiers::ALT)
+        {
+            return false;
+        }
+        let Some(pos) = self.screen_to_cursor(event.x, event.y, area) else {
+            return false;
+        };
+        self.add_caret(pos);
+        self.version = self.version.wrapping_add(1);
+        true
+    }
+
+    /// Position on `line` whose visual column contains `col`, clamped to
+    /// the line end.
+    fn position_at_visual_col(&self, line: usize, col: usize) -> Option<CursorPosition> {
+        let text = self.editor.line_text(line)?;
+        let mut grapheme = 0;
+        let mut width = 0;
+        for g in text.graphemes(true) {
+            let w = display_width(g);
+            if width + w > col {
+                break;
+            }
+            width += w;
+            grapheme += 1;
+        }
+        let nav = CursorNavigator::new(self.editor.rope());
+        Some(nav.from_line_grapheme(line, grapheme))
+    }
+
+    /// Map a screen cell to a text position using the last-rendered
+    /// viewport (scroll anchor, horizontal scroll, gutter).
+    fn screen_to_cursor(&self, x: u16, y: u16, area: Rect) -> Option<CursorPosition> {
+        if x < area.x || y < area.y || x >= area.right() || y >= area.bottom() {
+            return None;
+        }
+        let gutter = self.gutter_width();
+        let tx = x.saturating_sub(area.x).saturating_sub(gutter) as usize;
+        let row = (y - area.y) as usize;
+        let (anchor_line, anchor_vrow) = match self.scroll_anchor.get() {
+            (usize::MAX, _) => (0, 0),
+            anchor => anchor,
+        };
+        if !self.soft_wrap {
+            let line = anchor_line + row;
+            if line >= self.editor.line_count() {
+                return None;
+            }
+            return self.position_at_visual_col(line, self.scroll_left.get() + tx);
+        }
+        // Soft wrap: walk visual rows from the anchor to the target row.
+        let width = self.last_viewport_width.get().max(1);
+        let mut remaining = row;
+        let mut line = anchor_line;
+        let mut vrow = anchor_vrow;
+        loop {
+            let text = self.editor.line_text(line)?;
+            let avail = Self::measure_wrap_count(&text, width).saturating_sub(vrow);
+            if remaining < avail {
+                let slices = Self::wrap_line_slices(&text, width);
+                let slice = slices.get(vrow + remaining)?;
+                let mut offset = 0;
+                let mut w = 0;
+                for g in slice.text.graphemes(true) {
+                    let gw = display_width(g);
+                    if w + gw > tx {
+                        break;
+                    }
+                    w += gw;
+                    offset += g.len();
+                }
+                let nav = CursorNavigator::new(self.editor.rope());
+                let line_start = nav.to_byte_index(nav.from_line_grapheme(line, 0));
+                return Some(nav.from_byte_index(line_start + slice.start_byte + offset));
+            }
+            remaining -= avail;
+            line += 1;
+            vrow = 0;
+            if line >= self.editor.line_count() {
+                return None;
+            }
+        }
+    }
+
+    /// Run an editing operation at every caret as one undoable step.
+    ///
+    /// Carets are visited in descending byte order so earlier offsets stay
+    /// valid while the buffer changes under later ones; already-applied
+    /// caret positions are shifted by each edit's byte delta. The closure
+    /// receives the caret's index in ascending document order (used by
+    /// per-caret paste splitting). Overlapping carets are merged after the
+    /// operation.
+    fn for_each_caret_edit(&mut self, mut apply: impl FnMut(&mut Editor, usize)) {
+        if self.extra_carets.is_empty() {
+            apply(&mut self.editor, 0);
+            return;
+        }
+        // Snapshot all carets as byte offsets, ascending.
+        let nav = CursorNavigator::new(self.editor.rope());
+        let mut carets: Vec<CaretSnapshot> = vec![(
+            nav.to_byte_index(self.editor.cursor()),
+            self.editor
+                .selection()
+                .filter(|sel| !sel.is_empty())
+                .map(|sel| sel.byte_range(&nav)),
+            true,
+        )];
+        for caret in &self.extra_carets {
+            carets.push((
+                nav.to_byte_index(caret.position),
+                caret
+                    .selection
+                    .filter(|sel| !sel.is_empty())
+                    .map(|sel| sel.byte_range(&nav)),
+                false,
+            ));
+        }
+        // Order by edit-site start (a selection's start precedes its head).
+        carets.sort_by_key(|&(byte, selection, _)| {
+            (selection.map_or(byte, |(a, _)| a.min(byte)), byte)
+        });
+
+        // Merge carets whose edit sites overlap (a selection reaching into
+        // the next caret) before applying: keep the earliest, prefer the
+        // primary flag so it survives the merge.
+        let mut merged: Vec<CaretSnapshot> = Vec::with_capacity(carets.len());
+        let mut covered_to = 0usize;
+        for (byte, selection, is_primary) in carets {
+            let start = selection.map_or(byte, |(a, _)| a.min(byte));
+            if !merged.is_empty() && start < covered_to {
+                if is_primary && let Some(last) = merged.last_mut() {
+                    last.2 = true;
+                }
+                continue;
+            }
+            covered_to = selection.map_or(byte, |(_, b)| b.max(byte)).max(byte);
+            merged.push((byte, selection, is_primary));
+        }
+        let carets = merged;
+
+        self.editor.begin_undo_group();
+        let mut results: Vec<(usize, bool)> = Vec::new();
+        for (ascending_idx, &(byte, selection, is_primary)) in carets.iter().enumerate().rev() {
+            let len_before = self.editor.rope().len_bytes();
+            let (position, selection_positions) = {
+                let nav = CursorNavigator::new(self.editor.rope());
+                (
+                    nav.from_byte_index(byte.min(len_before)),
+                    selection.map(|(a, b)| {
+                        (
+                            nav.from_byte_index(a.min(len_before)),
+                            nav.from_byte_index(b.min(len_before)),
+                        )
+                    }),
+                )
+            };
+            self.editor.set_cursor(position);
+            if let Some((anchor, head)) = selection_positions {
+                self.editor.set_selection(Some(Selection { anchor, head }));
+            }
+            apply(&mut self.editor, ascending_idx);
+            let delta = self.editor.rope().len_bytes() as isize - len_before as isize;
+            // All recorded results sit at or beyond this edit site.
+            for (result_byte, _) in &mut results {
+                *result_byte = result_byte.saturating_add_signed(delta);
+            }
+            let nav = CursorNavigator::new(self.editor.rope());
+            results.push((nav.to_byte_index(self.editor.cursor()), is_primary));
+        }
+        self.editor.end_undo_group();
+
+        // Rebuild carets from the adjusted byte offsets.
+        let len = self.editor.rope().len_bytes();
+        let nav = CursorNavigator::new(self.editor.rope());
+        let mut primary = None;
+        let mut extras = Vec::new();
+        for (byte, is_primary) in results {
+            let pos = nav.from_byte_index(byte.min(len));
+            if is_primary {
+                primary = Some(pos);
+            } else {
+                extras.push(Caret {
+                    position: pos,
+                    selection: None,
+                });
+            }
+        }
+        self.extra_carets = extras;
+        if let Some(pos) = primary {
+            self.editor.set_cursor(pos);
+        }
+        self.merge_extra_carets();
+    }
+
+    /// Run a cursor/selection motion at every caret (primary last, so the
+    /// editor ends holding the primary's state). Overlapping carets are
+    /// merged afterwards.
+    fn for_each_caret_motion(&mut self, motion: impl Fn(&mut Editor)) {
+        if self.extra_carets.is_empty() {
+            motion(&mut self.editor);
+            return;
+        }
+        let primary_cursor = self.editor.cursor();
+        let primary_selection = self.editor.selection();
+        let mut extras = std::mem::take(&mut self.extra_carets);
+        for caret in &mut extras {
+            self.editor.set_cursor(caret.position);
+            self.editor.set_selection(caret.selection);
+            motion(&mut self.editor);
+            caret.position = self.editor.cursor();
+            caret.selection = self.editor.selection();
+        }
+        self.editor.set_cursor(primary_cursor);
+        self.editor.set_selection(primary_selection);
+        motion(&mut self.editor);
+        self.extra_carets = extras;
+        self.merge_extra_carets();
+    }
+
+    /// Drop secondary carets that collide with each other or the primary.
+    fn merge_extra_carets(&mut self) {
+        let primary = self.editor.cursor();
+        self.extra_carets.retain(|caret| {
+            (caret.position.line, caret.position.grapheme) != (primary.line, primary.grapheme)
+        });
+        self.extra_carets
+            .sort_by_key(|caret| (caret.position.line, caret.position.grapheme));
+        self.extra_carets
+            .dedup_by_key(|caret| (caret.position.line, caret.position.grapheme));
+    }
+
+    /// Re-clamp secondary carets after undo/redo changed the text.
+    fn clamp_extra_carets(&mut self) {
+        if self.extra_carets.is_empty() {
+            return;
+        }
+        let nav = CursorNavigator::new(self.editor.rope());
+        for caret in &mut self.extra_carets {
+            caret.position = nav.clamp(caret.position);
+            caret.selection = None;
+        }
+        self.merge_extra_carets();
+    }
+
     // ── Viewport management ────────────────────────────────────────
 
     /// Page up (move viewport and cursor up by viewport height).
@@ -1141,6 +1763,12 @@ impl TextArea {
     }
 }
 
+impl crate::WidgetState for TextArea {
+    fn state_version(&self) -> u64 {
+        self.version
+    }
+}
+
 impl Widget for TextArea {
     fn render(&self, area: Rect, frame: &mut Frame) {
         if area.width < 1 || area.height < 1 {
@@ -1245,6 +1873,27 @@ impl Widget for TextArea {
             let (cursor_wrap_idx, cursor_col_in_wrap) =
                 Self::cursor_wrap_position(cursor_line_text, text_area_w, cursor.visual_col);
 
+            // Secondary caret slots: (line, wrap row, column within wrap).
+            let extra_caret_slots: Vec<(usize, usize, usize)> = if self.focused {
+                self.extra_carets
+                    .iter()
+                    .map(|caret| {
+                        let text = rope
+                            .line(caret.position.line)
+                            .unwrap_or(std::borrow::Cow::Borrowed(""));
+                        let text = text.trim_end_matches(['\n', '\r']);
+                        let (wrap_idx, col_in_wrap) = Self::cursor_wrap_position(
+                            text,
+                            text_area_w,
+                            caret.position.visual_col,
+                        );
+                        (caret.position.line, wrap_idx, col_in_wrap)
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
             // Render wrapped lines starting from anchor
             let mut current_y = area.y;
             let bottom_y = area.bottom();
@@ -1340,6 +1989,20 @@ impl Widget for TextArea {
                         }
                     }
 
+                    // Secondary carets on this wrapped row.
+                    if deg.apply_styling() {
+                        for &(caret_line, wrap_idx, col_in_wrap) in &extra_caret_slots {
+                            if caret_line == line_idx && wrap_idx == slice_idx {
+                                let px = text_area_x.saturating_add(col_in_wrap as u16);
+                                if px < area.right()
+                                    && let Some(cell) = frame.buffer.get_mut(px, current_y)
+                                {
+                                    apply_style(cell, self.secondary_caret_style);
+                                }
+                            }
+                        }
+                    }
+
                     current_y += 1;
                 }
             }
@@ -1463,6 +2126,31 @@ impl Widget for TextArea {
                     frame.set_cursor(Some((cursor_screen_x, cursor_screen_y)));
                 }
             }
+
+            // Secondary carets render as styled cells (only the primary
+            // publishes through `frame.set_cursor`).
+            if deg.apply_styling() {
+                for caret in &self.extra_carets {
+                    if caret.position.line < scroll_top_line
+                        || caret.position.visual_col < scroll_left
+                    {
+                        continue;
+                    }
+                    let row = caret.position.line - scroll_top_line;
+                    if row >= vp_height {
+                        continue;
+                    }
+                    let px = (caret.position.visual_col.saturating_sub(scroll_left) as u16)
+                        .saturating_add(text_area_x);
+                    let py = area.y.saturating_add(row as u16);
+                    if px < area.right()
+                        && py < area.bottom()
+                        && let Some(cell) = frame.buffer.get_mut(px, py)
+                    {
+                        apply_style(cell, self.secondary_caret_style);
+                    }
+                }
+            }
         }
     }
 
@@ -1484,6 +2172,122 @@ impl StatefulWidget for TextArea {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn press(ta: &mut TextArea, code: KeyCode) {
+        ta.handle_event(&Event::Key(KeyEvent::new(code)));
+    }
+
+    fn type_str(ta: &mut TextArea, text: &str) {
+        for c in text.chars() {
+            press(ta, KeyCode::Char(c));
+        }
+    }
+
+    fn code_editor() -> TextArea {
+        TextArea::new().with_editing(TextAreaEditingConfig {
+            auto_pair: true,
+            auto_indent: true,
+            pair_backspace: true,
+        })
+    }
+
+    // --- Editing behaviors (auto-pair / auto-indent) ---
+
+    #[test]
+    fn editing_behaviors_disabled_by_default() {
+        let mut ta = TextArea::new();
+        type_str(&mut ta, "(");
+        assert_eq!(ta.text(), "(", "no auto-close by default");
+        press(&mut ta, KeyCode::Enter);
+        assert_eq!(ta.text(), "(\n", "no auto-indent by default");
+    }
+
+    #[test]
+    fn auto_pair_inserts_closer_and_places_cursor_inside() {
+        let mut ta = code_editor();
+        type_str(&mut ta, "(");
+        assert_eq!(ta.text(), "()");
+        type_str(&mut ta, "x");
+        assert_eq!(ta.text(), "(x)");
+    }
+
+    #[test]
+    fn auto_pair_skips_existing_closer() {
+        let mut ta = code_editor();
+        type_str(&mut ta, "(x");
+        // Typing ')' with the closer already there just steps over it.
+        type_str(&mut ta, ")");
+        assert_eq!(ta.text(), "(x)");
+        assert_eq!(ta.cursor().grapheme, 3);
+    }
+
+    #[test]
+    fn auto_pair_respects_following_word_characters() {
+        let mut ta = code_editor().with_text("word");
+        // Cursor at 0, right before 'w': no auto-close.
+        type_str(&mut ta, "(");
+        assert_eq!(ta.text(), "(word");
+    }
+
+    #[test]
+    fn quotes_do_not_pair_after_word_chars() {
+        let mut ta = code_editor();
+        type_str(&mut ta, "don");
+        type_str(&mut ta, "'");
+        assert_eq!(ta.text(), "don'", "apostrophe stays single");
+
+        let mut ta = code_editor();
+        type_str(&mut ta, "\"");
+        assert_eq!(ta.text(), "\"\"", "fresh quote auto-closes");
+    }
+
+    #[test]
+    fn pair_backspace_deletes_both() {
+        let mut ta = code_editor();
+        type_str(&mut ta, "{");
+        assert_eq!(ta.text(), "{}");
+        press(&mut ta, KeyCode::Backspace);
+        assert_eq!(ta.text(), "", "backspace removes the empty pair");
+    }
+
+    #[test]
+    fn auto_indent_copies_leading_whitespace_and_extends_after_opener() {
+        let mut ta = code_editor().with_text("    foo {");
+        ta.move_to_line_end();
+        press(&mut ta, KeyCode::Enter);
+        assert_eq!(ta.text(), "    foo {\n        ");
+
+        let mut ta = code_editor().with_text("  plain");
+        ta.move_to_line_end();
+        press(&mut ta, KeyCode::Enter);
+        assert_eq!(ta.text(), "  plain\n  ");
+    }
+
+    #[test]
+    fn pair_operations_are_single_undo_steps() {
+        let mut ta = code_editor();
+        type_str(&mut ta, "(");
+        assert_eq!(ta.text(), "()");
+        // One undo removes both inserted characters.
+        ta.undo();
+        assert_eq!(ta.text(), "");
+
+        let mut ta = code_editor();
+        type_str(&mut ta, "[");
+        press(&mut ta, KeyCode::Backspace);
+        assert_eq!(ta.text(), "");
+        // One undo restores the whole pair (the deletion was one step).
+        ta.undo();
+        assert_eq!(ta.text(), "[]");
+
+        // Indented newline undoes as one step too.
+        let mut ta = code_editor().with_text("  x {");
+        ta.move_to_line_end();
+        press(&mut ta, KeyCode::Enter);
+        ta.undo();
+        assert_eq!(ta.text(), "  x {");
+    }
 
     #[test]
     fn new_textarea_is_empty() {
@@ -2004,4 +2808,220 @@ mod tests {
             }
         }
     }
+
+    // ── Multi-cursor ───────────────────────────────────────────────
+
+    fn multi_cursor_area() -> TextArea {
+        let mut ta = TextArea::new().with_multi_cursor(true).with_focus(true);
+        ta.set_text("alpha\nbeta\ngamma");
+        ta
+    }
+
+    #[test]
+    fn multi_caret_prefix_inserts_on_every_line() {
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        ta.add_caret_below();
+        ta.add_caret_below();
+        assert_eq!(ta.caret_count(), 3);
+
+        ta.insert_text("# ");
+        assert_eq!(ta.text(), "# alpha\n# beta\n# gamma");
+        // Every caret sits after its own insertion.
+        let positions = ta.caret_positions();
+        assert!(positions.iter().all(|pos| pos.grapheme == 2));
+    }
+
+    #[test]
+    fn paste_splits_one_line_per_caret_when_counts_match() {
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        ta.add_caret_below();
+        ta.add_caret_below();
+
+        ta.insert_text("1\n2\n3");
+        assert_eq!(ta.text(), "1alpha\n2beta\n3gamma");
+
+        // Mismatched counts paste the whole text at every caret instead.
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        ta.add_caret_below();
+        ta.insert_text("x");
+        assert_eq!(ta.text(), "xalpha\nxbeta\ngamma");
+    }
+
+    #[test]
+    fn colliding_carets_merge_after_deletion() {
+        let mut ta = TextArea::new().with_multi_cursor(true);
+        ta.set_text("ab\ncd");
+        // Primary at start of line 1, secondary one grapheme in.
+        ta.set_cursor_position(CursorPosition {
+            line: 1,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        ta.add_caret(CursorPosition {
+            line: 1,
+            grapheme: 1,
+            visual_col: 1,
+        });
+        assert_eq!(ta.caret_count(), 2);
+
+        // Backspace: the secondary eats "c", the primary joins the lines;
+        // both land on the same spot and merge.
+        ta.delete_backward();
+        assert_eq!(ta.text(), "abd");
+        assert_eq!(ta.caret_count(), 1);
+    }
+
+    #[test]
+    fn multi_caret_edit_is_one_undo_step() {
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        ta.add_caret_below();
+        ta.add_caret_below();
+        ta.insert_text("> ");
+        assert_eq!(ta.text(), "> alpha\n> beta\n> gamma");
+
+        ta.undo();
+        assert_eq!(
+            ta.text(),
+            "alpha\nbeta\ngamma",
+            "single undo reverts all carets"
+        );
+        ta.redo();
+        assert_eq!(ta.text(), "> alpha\n> beta\n> gamma");
+    }
+
+    #[test]
+    fn only_primary_caret_publishes_cursor_position() {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 5, &mut pool);
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 2,
+            visual_col: 2,
+        });
+        ta.add_caret_below();
+        Widget::render(&ta, Rect::new(0, 0, 20, 5), &mut frame);
+
+        // frame cursor is the primary caret; the secondary renders as a
+        // styled cell only.
+        assert_eq!(frame.cursor_position, Some((2, 0)));
+    }
+
+    #[test]
+    fn escape_collapses_to_primary() {
+        let mut ta = multi_cursor_area();
+        ta.add_caret(CursorPosition {
+            line: 0,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        assert_eq!(ta.caret_count(), 2);
+        ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Escape)));
+        assert_eq!(ta.caret_count(), 1);
+    }
+
+    #[test]
+    fn block_selection_inserts_caret_per_line() {
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 1,
+            visual_col: 1,
+        });
+        ta.add_carets_in_block(
+            CursorPosition {
+                line: 0,
+                grapheme: 1,
+                visual_col: 1,
+            },
+            CursorPosition {
+                line: 2,
+                grapheme: 1,
+                visual_col: 1,
+            },
+        );
+        // Primary on line 0 plus carets on lines 1 and 2.
+        assert_eq!(ta.caret_count(), 3);
+        ta.insert_char('|');
+        assert_eq!(ta.text(), "a|lpha\nb|eta\ng|amma");
+    }
+
+    #[test]
+    fn alt_click_adds_caret() {
+        let mut ta = multi_cursor_area();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 5, &mut pool);
+        let area = Rect::new(0, 0, 20, 5);
+        Widget::render(&ta, area, &mut frame);
+
+        let event = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 1)
+            .with_modifiers(Modifiers::ALT);
+        assert!(ta.handle_mouse(&event, area));
+        assert_eq!(ta.caret_count(), 2);
+
+        // Plain click (no Alt) is not consumed.
+        let plain = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 1);
+        assert!(!ta.handle_mouse(&plain, area));
+    }
+
+    #[test]
+    fn per_caret_selection_extends_and_deletes() {
+        let mut ta = multi_cursor_area();
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 0,
+            visual_col: 0,
+        });
+        ta.add_caret_below();
+        // Extend one grapheme right at both carets, then type over it.
+        ta.select_right();
+        ta.insert_char('X');
+        assert_eq!(ta.text(), "Xlpha\nXeta\ngamma");
+    }
+
+    #[test]
+    fn caret_inside_anothers_selection_merges_before_edit() {
+        let mut ta = TextArea::new().with_multi_cursor(true);
+        ta.set_text("abcdef");
+        // Primary selects "bcd" (head at grapheme 4); a secondary caret
+        // sits inside that selection.
+        ta.set_cursor_position(CursorPosition {
+            line: 0,
+            grapheme: 1,
+            visual_col: 1,
+        });
+        ta.select_right();
+        ta.select_right();
+        ta.select_right();
+        ta.add_caret(CursorPosition {
+            line: 0,
+            grapheme: 2,
+            visual_col: 2,
+        });
+
+        ta.insert_char('X');
+        // One replacement, not a stray insert from the swallowed caret.
+        assert_eq!(ta.text(), "aXef");
+        assert_eq!(ta.caret_count(), 1);
+    }
 }
@@ -290,10 +290,25 @@ pub struct WidgetTextEditCmd {
     apply_fn: Option<TextEditApplyFn>,
     /// Undo callback.
     undo_fn: Option<TextEditUndoFn>,
+    /// Cursor before the edit (restored by undo).
+    cursor_before: Option<usize>,
+    /// Cursor after the edit (restored by redo).
+    cursor_after: Option<usize>,
+    /// Selection `(anchor, cursor)` before the edit.
+    selection_before: Option<(usize, usize)>,
+    /// Selection `(anchor, cursor)` after the edit.
+    selection_after: Option<(usize, usize)>,
+    /// Cursor restore callback: must route through the widget's own
+    /// clamping APIs so positions stay in-bounds.
+    cursor_restore: Option<WidgetCursorRestoreFn>,
     /// Whether the operation has been executed.
     executed: bool,
 }
 
+/// Cursor-restore callback for [`WidgetTextEditCmd`].
+pub type WidgetCursorRestoreFn =
+    Box<dyn Fn(UndoWidgetId, usize, Option<(usize, usize)>) -> Result<(), String> + Send + Sync>;
+
 impl WidgetTextEditCmd {
     /// Create a new text edit command.
     #[must_use]
@@ -303,6 +318,11 @@ impl WidgetTextEditCmd {
             operation,
             apply_fn: None,
             undo_fn: None,
+            cursor_before: None,
+            cursor_after: None,
+            selection_before: None,
+            selection_after: None,
+            cursor_restore: None,
             executed: false,
         }
     }
@@ -327,6 +347,52 @@ impl WidgetTextEditCmd {
         self
     }
 
+    /// Capture cursor positions before and after the edit (builder).
+    #[must_use]
+    pub fn with_cursor_state(mut self, before: usize, after: usize) -> Self {
+        self.cursor_before = Some(before);
+        self.cursor_after = Some(after);
+        self
+    }
+
+    /// Capture selections before and after the edit (builder).
+    #[must_use]
+    pub fn with_selection_state(
+        mut self,
+        before: Option<(usize, usize)>,
+        after: Option<(usize, usize)>,
+    ) -> Self {
+        self.selection_before = before;
+        self.selection_after = after;
+        self
+    }
+
+    /// Set the cursor-restore callback (builder). Restores must go
+    /// through the widget's own APIs so the position is clamped.
+    #[must_use]
+    pub fn with_cursor_restore<F>(mut self, f: F) -> Self
+    where
+        F: Fn(UndoWidgetId, usize, Option<(usize, usize)>) -> Result<(), String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cursor_restore = Some(Box::new(f));
+        self
+    }
+
+    /// Cursor captured before the edit, if any.
+    #[must_use]
+    pub fn cursor_before(&self) -> Option<usize> {
+        self.cursor_before
+    }
+
+    /// Cursor captured after the edit, if any.
+    #[must_use]
+    pub fn cursor_after(&self) -> Option<usize> {
+        self.cursor_after
+    }
+
     /// Get the widget ID.
     #[must_use]
     pub fn widget_id(&self) -> UndoWidgetId {
@@ -362,6 +428,9 @@ impl WidgetTextEditCmd {
         if let Some(ref apply_fn) = self.apply_fn {
             apply_fn(self.widget_id, &self.operation)?;
         }
+        if let (Some(restore), Some(cursor)) = (&self.cursor_restore, self.cursor_after) {
+            restore(self.widget_id, cursor, self.selection_after)?;
+        }
         self.executed = true;
         Ok(())
     }
@@ -372,6 +441,9 @@ impl WidgetTextEditCmd {
         if let Some(ref undo_fn) = self.undo_fn {
             undo_fn(self.widget_id, &self.operation)?;
         }
+        if let (Some(restore), Some(cursor)) = (&self.cursor_restore, self.cursor_before) {
+            restore(self.widget_id, cursor, self.selection_before)?;
+        }
         self.executed = false;
         Ok(())
     }
@@ -817,4 +889,89 @@ mod tests {
         let dbg = format!("{op:?}");
         assert!(dbg.contains("Changed"));
     }
+
+    // ── Cursor capture on widget text-edit commands ─────────────────
+
+    #[test]
+    fn widget_cmd_restores_cursor_through_widget_api() {
+        use crate::input::TextInput;
+        use std::sync::{Arc, Mutex};
+
+        let input = Arc::new(Mutex::new(TextInput::new()));
+        input.lock().unwrap().set_value("hello");
+        {
+            let mut guard = input.lock().unwrap();
+            TextInputUndoExt::set_cursor_position(&mut *guard, 5);
+        }
+
+        // Type " world": capture before (5) and after (11).
+        let cursor_before = input.lock().unwrap().cursor();
+        input.lock().unwrap().set_value("hello world");
+        {
+            let mut guard = input.lock().unwrap();
+            TextInputUndoExt::set_cursor_position(&mut *guard, 11);
+        }
+
+        let apply_input = input.clone();
+        let undo_input = input.clone();
+        let restore_input = input.clone();
+        let mut cmd = input
+            .lock()
+            .unwrap()
+            .create_text_edit_command_with_cursor(
+                TextEditOperation::Insert {
+                    position: 5,
+                    text: " world".to_string(),
+                },
+                cursor_before,
+                None,
+            )
+            .unwrap()
+            .with_apply(move |_, op| {
+                if let TextEditOperation::Insert { position, text } = op {
+                    let mut guard = apply_input.lock().unwrap();
+                    guard.insert_text_at(*position, text);
+                }
+                Ok(())
+            })
+            .with_undo(move |_, op| {
+                if let TextEditOperation::Insert { position, text } = op {
+                    let mut guard = undo_input.lock().unwrap();
+                    let count = text.chars().count();
+                    guard.delete_text_range(*position, *position + count);
+                }
+                Ok(())
+            })
+            .with_cursor_restore(move |_, cursor, _| {
+                // Through the widget's own clamping API.
+                let mut guard = restore_input.lock().unwrap();
+                TextInputUndoExt::set_cursor_position(&mut *guard, cursor);
+                Ok(())
+            });
+
+        assert_eq!(cmd.cursor_before(), Some(5));
+        assert_eq!(cmd.cursor_after(), Some(11));
+
+        cmd.undo().unwrap();
+        assert_eq!(input.lock().unwrap().value(), "hello");
+        assert_eq!(
+            input.lock().unwrap().cursor(),
+            5,
+            "pre-edit cursor restored"
+        );
+
+        cmd.redo().unwrap();
+        assert_eq!(input.lock().unwrap().value(), "hello world");
+        assert_eq!(
+            input.lock().unwrap().cursor(),
+            11,
+            "post-edit cursor restored"
+        );
+
+        // Shrink out-of-band, undo again: the widget API clamps.
+        input.lock().unwrap().set_value("hello wo");
+        cmd.undo().unwrap();
+        let guard = input.lock().unwrap();
+        assert!(guard.cursor() <= guard.value().chars().count());
+    }
 }
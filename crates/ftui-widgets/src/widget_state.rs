@@ -0,0 +1,349 @@
+#![forbid(unsafe_code)]
+
+//! Opt-in dirty tracking: skip re-rendering widgets whose state is unchanged.
+//!
+//! Widgets implementing [`WidgetState`] expose a monotonic
+//! `state_version()` bumped on every *real* state mutation — handled but
+//! rejected operations (an oversized paste, a cursor already at the
+//! boundary) must not bump it. [`render_if_changed`] then reuses the
+//! previous frame's cells for the widget's area when both the version and
+//! the area are unchanged, skipping the render entirely.
+//!
+//! The contract gets enforcement help: [`RenderCache::with_verification`]
+//! renders anyway on every cache hit and compares against the cached
+//! cells, counting (and logging) widgets that mutated without bumping
+//! their version.
+
+use ftui_core::geometry::Rect;
+use ftui_render::buffer::Buffer;
+use ftui_render::frame::Frame;
+
+use crate::{StatefulWidget, Widget};
+
+/// Dirty-tracking protocol for widgets (and widget states).
+///
+/// `state_version()` must increase on every observable state mutation and
+/// stay unchanged when an operation is handled but rejected. This is the
+/// "handled vs changed" distinction: `handle_event` returning `true`
+/// (handled) does not imply a version bump (changed).
+pub trait WidgetState {
+    /// Monotonic version of the widget's observable state.
+    fn state_version(&self) -> u64;
+}
+
+/// Per-widget render cache for [`render_if_changed`].
+#[derive(Debug, Clone, Default)]
+pub struct RenderCache {
+    buffer: Option<Buffer>,
+    last_version: Option<u64>,
+    last_area: Option<Rect>,
+    /// Render anyway on cache hits and compare (contract enforcement).
+    verify: bool,
+    /// Cache hits whose re-render differed from the cached cells.
+    violations: u64,
+}
+
+impl RenderCache {
+    /// Create an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable verification: every cache hit renders anyway and compares
+    /// against the cached cells, flagging widgets that mutated without
+    /// bumping their version. Intended for debug builds and tests.
+    #[must_use]
+    pub fn with_verification(mut self) -> Self {
+        self.verify = true;
+        self
+    }
+
+    /// Drop the cached cells, forcing the next render.
+    pub fn invalidate(&mut self) {
+        self.buffer = None;
+        self.last_version = None;
+        self.last_area = None;
+    }
+
+    /// Number of verified cache hits that did not match the re-render
+    /// (i.e. contract violations observed so far).
+    #[must_use]
+    pub fn violations(&self) -> u64 {
+        self.violations
+    }
+}
+
+/// Render `widget` through `cache`: when its version and area are
+/// unchanged since the last call, blit the cached cells instead of
+/// rendering. Returns `true` when the widget's render actually ran.
+pub fn render_if_changed<W: Widget + WidgetState>(
+    widget: &W,
+    area: Rect,
+    frame: &mut Frame,
+    cache: &mut RenderCache,
+) -> bool {
+    render_versioned(area, frame, cache, widget.state_version(), |local, sub| {
+        widget.render(local, sub);
+    })
+}
+
+/// [`render_if_changed`] for stateful widgets whose *state* carries the
+/// version ([`WidgetState`] on `W::State`).
+///
+/// Note the cache keys on the state version only: changes to the widget's
+/// borrowed data (e.g. list items) require [`RenderCache::invalidate`].
+pub fn render_stateful_if_changed<W>(
+    widget: &W,
+    area: Rect,
+    frame: &mut Frame,
+    state: &mut W::State,
+    cache: &mut RenderCache,
+) -> bool
+where
+    W: StatefulWidget,
+    W::State: WidgetState,
+{
+    let version = state.state_version();
+    render_versioned(area, frame, cache, version, |local, sub| {
+        widget.render(local, sub, state);
+    })
+}
+
+/// Shared cache mechanics: render into a local frame when needed, blit the
+/// cached buffer otherwise (optionally verifying the hit).
+fn render_versioned(
+    area: Rect,
+    frame: &mut Frame,
+    cache: &mut RenderCache,
+    version: u64,
+    mut render: impl FnMut(Rect, &mut Frame),
+) -> bool {
+    if area.is_empty() {
+        cache.invalidate();
+        return false;
+    }
+
+    let hit = cache.buffer.is_some()
+        && cache.last_version == Some(version)
+        && cache.last_area == Some(area);
+
+    let rendered = if hit {
+        if cache.verify {
+            // Contract enforcement: render anyway and compare.
+            let local = Rect::from_size(area.width, area.height);
+            let mut fresh = Frame::new(area.width, area.height, frame.pool);
+            render(local, &mut fresh);
+            let cached = cache.buffer.as_ref().expect("hit implies cached buffer");
+            if !buffers_equal(cached, &fresh.buffer) {
+                cache.violations += 1;
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    version,
+                    "widget output changed without a state_version bump"
+                );
+                // Correctness first: keep the fresh cells.
+                cache.buffer = Some(fresh.buffer);
+            }
+            true
+        } else {
+            false
+        }
+    } else {
+        let local = Rect::from_size(area.width, area.height);
+        let mut sub = Frame::new(area.width, area.height, frame.pool);
+        render(local, &mut sub);
+        cache.buffer = Some(sub.buffer);
+        cache.last_version = Some(version);
+        cache.last_area = Some(area);
+        true
+    };
+
+    if let Some(cached) = &cache.buffer {
+        let src = Rect::from_size(area.width, area.height);
+        frame.buffer.copy_from(cached, src, area.x, area.y);
+    }
+    rendered
+}
+
+fn buffers_equal(a: &Buffer, b: &Buffer) -> bool {
+    if a.width() != b.width() || a.height() != b.height() {
+        return false;
+    }
+    for y in 0..a.height() {
+        for x in 0..a.width() {
+            if a.get(x, y) != b.get(x, y) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::{Event, KeyCode, KeyEvent, PasteEvent};
+    use ftui_render::cell::Cell;
+    use ftui_render::grapheme_pool::GraphemePool;
+    use std::cell::Cell as CounterCell;
+    use std::rc::Rc;
+
+    use crate::input::TextInput;
+    use crate::list::ListState;
+    use crate::textarea::TextArea;
+
+    /// Widget counting its renders, with an honest version counter.
+    struct Probe {
+        version: u64,
+        renders: Rc<CounterCell<u32>>,
+        glyph: char,
+    }
+
+    impl Widget for Probe {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            self.renders.set(self.renders.get() + 1);
+            frame
+                .buffer
+                .set(area.x, area.y, Cell::from_char(self.glyph));
+        }
+    }
+
+    impl WidgetState for Probe {
+        fn state_version(&self) -> u64 {
+            self.version
+        }
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code))
+    }
+
+    #[test]
+    fn cache_reuse_skips_render() {
+        let renders = Rc::new(CounterCell::new(0));
+        let mut probe = Probe {
+            version: 0,
+            renders: Rc::clone(&renders),
+            glyph: 'a',
+        };
+        let mut cache = RenderCache::new();
+        let mut pool = GraphemePool::new();
+        let area = Rect::new(0, 0, 4, 2);
+
+        let mut frame = Frame::new(8, 4, &mut pool);
+        assert!(render_if_changed(&probe, area, &mut frame, &mut cache));
+        assert_eq!(renders.get(), 1);
+
+        // Unchanged version + area: render skipped, cells still blitted.
+        let mut frame = Frame::new(8, 4, &mut pool);
+        assert!(!render_if_changed(&probe, area, &mut frame, &mut cache));
+        assert_eq!(renders.get(), 1);
+        assert_eq!(frame.buffer.get(0, 0).unwrap().content.as_char(), Some('a'));
+
+        // Version bump: renders again.
+        probe.version += 1;
+        let mut frame = Frame::new(8, 4, &mut pool);
+        assert!(render_if_changed(&probe, area, &mut frame, &mut cache));
+        assert_eq!(renders.get(), 2);
+
+        // Area change: renders again.
+        let moved = Rect::new(1, 1, 4, 2);
+        let mut frame = Frame::new(8, 4, &mut pool);
+        assert!(render_if_changed(&probe, moved, &mut frame, &mut cache));
+        assert_eq!(renders.get(), 3);
+    }
+
+    #[test]
+    fn verification_catches_buggy_widget() {
+        let renders = Rc::new(CounterCell::new(0));
+        let mut buggy = Probe {
+            version: 7,
+            renders,
+            glyph: 'x',
+        };
+        let mut cache = RenderCache::new().with_verification();
+        let mut pool = GraphemePool::new();
+        let area = Rect::new(0, 0, 3, 1);
+
+        let mut frame = Frame::new(4, 2, &mut pool);
+        render_if_changed(&buggy, area, &mut frame, &mut cache);
+        assert_eq!(cache.violations(), 0);
+
+        // Mutate output WITHOUT bumping the version: verification flags it.
+        buggy.glyph = 'y';
+        let mut frame = Frame::new(4, 2, &mut pool);
+        render_if_changed(&buggy, area, &mut frame, &mut cache);
+        assert_eq!(cache.violations(), 1);
+        // Correctness first: the fresh cells won.
+        assert_eq!(frame.buffer.get(0, 0).unwrap().content.as_char(), Some('y'));
+
+        // An honest widget produces no further violations.
+        buggy.version += 1;
+        let mut frame = Frame::new(4, 2, &mut pool);
+        render_if_changed(&buggy, area, &mut frame, &mut cache);
+        assert_eq!(cache.violations(), 1);
+    }
+
+    // --- Migrated widgets ---
+
+    #[test]
+    fn text_input_version_bumps_on_real_changes_only() {
+        let mut input = TextInput::new().with_max_length(1);
+        let v0 = input.state_version();
+
+        assert!(input.handle_event(&key(KeyCode::Char('a'))));
+        let v1 = input.state_version();
+        assert!(v1 > v0, "typing bumps the version");
+
+        // Cursor move is a real state change.
+        assert!(input.handle_event(&key(KeyCode::Left)));
+        assert!(input.state_version() > v1);
+
+        // Rejected operation: oversized paste is handled but changes nothing.
+        let before = input.state_version();
+        input.handle_event(&Event::Paste(PasteEvent::bracketed(
+            "way too long for max_length",
+        )));
+        assert_eq!(input.value(), "a");
+        assert_eq!(
+            input.state_version(),
+            before,
+            "rejected paste must not bump"
+        );
+
+        // Unhandled event: no bump.
+        assert!(!input.handle_event(&key(KeyCode::F(5))));
+        assert_eq!(input.state_version(), before);
+    }
+
+    #[test]
+    fn textarea_version_bumps_on_edits_not_noops() {
+        let mut area = TextArea::new();
+        let v0 = area.state_version();
+        assert!(area.handle_event(&key(KeyCode::Char('x'))));
+        assert!(area.state_version() > v0);
+
+        // Cursor already at the end: Right is handled but changes nothing.
+        let before = area.state_version();
+        area.handle_event(&key(KeyCode::Right));
+        assert_eq!(area.state_version(), before);
+    }
+
+    #[test]
+    fn list_state_version_bumps_on_mutations() {
+        let mut state = ListState::default();
+        let v0 = state.state_version();
+        state.select(Some(2));
+        let v1 = state.state_version();
+        assert!(v1 > v0);
+
+        // Re-selecting the same index with no other effect: no bump.
+        state.select(Some(2));
+        let v2 = state.state_version();
+        assert_eq!(v2, v1);
+
+        state.select_next(10);
+        assert!(state.state_version() > v2);
+    }
+}
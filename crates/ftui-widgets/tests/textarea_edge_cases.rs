@@ -21,6 +21,7 @@ fn key_press(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -29,6 +30,7 @@ fn key_press_ctrl(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::CTRL,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -37,6 +39,7 @@ fn key_press_shift(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::SHIFT,
         kind: KeyEventKind::Press,
+        ch: None,
     })
 }
 
@@ -45,14 +48,12 @@ fn key_release(code: KeyCode) -> Event {
         code,
         modifiers: Modifiers::NONE,
         kind: KeyEventKind::Release,
+        ch: None,
     })
 }
 
 fn paste_event(text: &str) -> Event {
-    Event::Paste(PasteEvent {
-        text: text.to_string(),
-        bracketed: true,
-    })
+    Event::Paste(PasteEvent::bracketed(text))
 }
 
 /// Render and return whether cursor was set.